@@ -1,5 +1,6 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use r_data_core_core::admin_user::ApiKey;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use ts_rs::TS;
@@ -18,6 +19,12 @@ pub struct CreateApiKeyRequest {
     #[serde(default)]
     #[ts(type = "number | null")]
     pub expires_in_days: Option<i64>,
+    /// Public API scopes to restrict the key to (e.g. `entities:read`); empty for full access
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// Source IP ranges (CIDR) to restrict the key to; empty for any address
+    #[serde(default)]
+    pub allowed_cidrs: Vec<String>,
 }
 
 /// Response containing API key information
@@ -53,6 +60,32 @@ pub struct ApiKeyResponse {
     pub user_uuid: Uuid,
     /// Whether the key is published
     pub published: bool,
+    /// Public API scopes the key is restricted to; empty for full access
+    pub scopes: Vec<String>,
+    /// Maximum public API requests per minute; null for unlimited
+    pub rate_limit_per_minute: Option<i32>,
+    /// Source IP ranges (CIDR) the key may be used from; empty for any address
+    pub allowed_cidrs: Vec<String>,
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+    fn from(key: ApiKey) -> Self {
+        Self {
+            uuid: key.uuid,
+            name: key.name,
+            description: key.description,
+            is_active: key.is_active,
+            created_at: key.created_at,
+            expires_at: key.expires_at,
+            last_used_at: key.last_used_at,
+            created_by: key.created_by,
+            user_uuid: key.user_uuid,
+            published: key.published,
+            scopes: key.scopes,
+            rate_limit_per_minute: key.rate_limit_per_minute,
+            allowed_cidrs: key.allowed_cidrs,
+        }
+    }
 }
 
 /// Response when an API key is created (includes the actual key value)
@@ -90,6 +123,35 @@ pub struct ApiKeyCreatedResponse {
     #[serde(with = "time::serde::rfc3339::option")]
     #[ts(type = "string | null")]
     pub last_used_at: Option<OffsetDateTime>,
+    /// Public API scopes the key is restricted to; empty for full access
+    pub scopes: Vec<String>,
+    /// Maximum public API requests per minute; null for unlimited
+    pub rate_limit_per_minute: Option<i32>,
+    /// Source IP ranges (CIDR) the key may be used from; empty for any address
+    pub allowed_cidrs: Vec<String>,
+}
+
+impl ApiKeyCreatedResponse {
+    /// Build the response for a freshly issued key and its plain value
+    #[must_use]
+    pub fn from_key(key: ApiKey, api_key: String) -> Self {
+        Self {
+            uuid: key.uuid,
+            name: key.name,
+            api_key,
+            description: key.description,
+            is_active: key.is_active,
+            created_at: key.created_at,
+            expires_at: key.expires_at,
+            created_by: key.created_by,
+            user_uuid: key.user_uuid,
+            published: key.published,
+            last_used_at: key.last_used_at,
+            scopes: key.scopes,
+            rate_limit_per_minute: key.rate_limit_per_minute,
+            allowed_cidrs: key.allowed_cidrs,
+        }
+    }
 }
 
 /// Request to reassign an API key to a different user
@@ -100,3 +162,39 @@ pub struct ReassignApiKeyRequest {
     #[ts(type = "string")]
     pub user_uuid: Uuid,
 }
+
+/// Request to set the public API rate limit of an API key
+#[derive(Debug, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct UpdateApiKeyRateLimitRequest {
+    /// Maximum requests per minute; null removes the limit
+    pub rate_limit_per_minute: Option<i32>,
+}
+
+/// Query parameters for listing API keys nearing expiry
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct ExpiringApiKeysQuery {
+    /// Days ahead to look for expiring keys (default: 14, max: 365)
+    #[ts(type = "number | null")]
+    pub within_days: Option<i64>,
+}
+
+/// Query parameters for rotating an API key
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct RotateApiKeyQuery {
+    /// Hours the old key keeps working after rotation (default: 24, max: 720)
+    #[ts(type = "number | null")]
+    pub grace_period_hours: Option<i64>,
+}
+
+/// Response when an API key is rotated
+#[derive(Debug, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct RotatedApiKeyResponse {
+    /// The new key, including its value (only shown once)
+    pub new_key: ApiKeyCreatedResponse,
+    /// The old key, expiring at the end of the grace period
+    pub old_key: ApiKeyResponse,
+}
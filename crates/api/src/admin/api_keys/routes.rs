@@ -6,7 +6,8 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::admin::api_keys::models::{
-    ApiKeyCreatedResponse, ApiKeyResponse, CreateApiKeyRequest, ReassignApiKeyRequest,
+    ApiKeyCreatedResponse, ApiKeyResponse, CreateApiKeyRequest, ExpiringApiKeysQuery,
+    ReassignApiKeyRequest, RotateApiKeyQuery, RotatedApiKeyResponse, UpdateApiKeyRateLimitRequest,
 };
 use crate::admin::query_helpers::to_list_query_params;
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
@@ -18,12 +19,21 @@ use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
 use r_data_core_persistence::{ApiKeyRepository, ApiKeyRepositoryTrait};
 use r_data_core_services::query_validation::FieldValidator;
 
+/// Expiry window used when no `within_days` is given
+const DEFAULT_EXPIRING_WITHIN_DAYS: i64 = 14;
+
+/// Grace period used when no `grace_period_hours` is given
+const DEFAULT_ROTATION_GRACE_HOURS: i64 = 24;
+
 /// Register API key routes
 pub fn register_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(create_api_key)
         .service(list_api_keys)
+        .service(list_expiring_api_keys)
         .service(revoke_api_key)
-        .service(reassign_api_key);
+        .service(reassign_api_key)
+        .service(update_api_key_rate_limit)
+        .service(rotate_api_key);
 }
 
 /// List API keys for the authenticated user with pagination and sorting
@@ -36,7 +46,7 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
         ("per_page" = Option<i64>, Query, description = "Number of items per page (default: 20, max: 100, or -1 for unlimited)"),
         ("limit" = Option<i64>, Query, description = "Maximum number of items to return (alternative to per_page)"),
         ("offset" = Option<i64>, Query, description = "Number of items to skip (alternative to page-based pagination)"),
-        ("sort_by" = Option<String>, Query, description = "Field to sort by (e.g., name, is_active, last_used_at, created_at)"),
+        ("sort_by" = Option<String>, Query, description = "Field to sort by (e.g., name, is_active, last_used_at, created_at); comma-separate several fields with optional directions, e.g. name:asc,created_at:desc"),
         ("sort_order" = Option<String>, Query, description = "Sort order: 'asc' or 'desc' (default: 'asc')")
     ),
     responses(
@@ -90,23 +100,10 @@ pub async fn list_api_keys(
             let repo = ApiKeyRepository::new(pool);
             let total = repo.count_by_user(user_uuid).await.unwrap_or(0);
 
-            let api_keys: Vec<ApiKeyResponse> = rows
-                .into_iter()
-                .map(|row| ApiKeyResponse {
-                    uuid: row.uuid,
-                    name: row.name.clone(),
-                    description: row.description.clone(),
-                    is_active: row.is_active,
-                    created_at: row.created_at,
-                    expires_at: row.expires_at,
-                    last_used_at: row.last_used_at,
-                    created_by: row.created_by,
-                    user_uuid: row.user_uuid,
-                    published: row.published,
-                })
-                .collect::<Vec<_>>();
-
-            ApiResponse::ok_paginated(api_keys, total, validated.page, validated.per_page)
+            let api_keys: Vec<ApiKeyResponse> =
+                rows.into_iter().map(ApiKeyResponse::from).collect();
+
+            ApiResponse::ok_paginated_list(api_keys, total, &validated)
         }
         Err(e) => {
             error!("Failed to list API keys: {e}");
@@ -130,6 +127,7 @@ pub async fn list_api_keys(
         (status = 201, description = "API key created successfully", body = ApiKeyCreatedResponse),
         (status = 401, description = "Unauthorized"),
         (status = 409, description = "Conflict - API key name already exists"),
+        (status = 422, description = "Unknown scope or invalid CIDR"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -187,26 +185,20 @@ pub async fn create_api_key(
         .map_or(365, |v| i32::try_from(v).unwrap_or(365));
 
     match service
-        .create_api_key(&req.name, &description, creator_uuid, expires_in_days)
+        .create_api_key(
+            &req.name,
+            &description,
+            creator_uuid,
+            expires_in_days,
+            &req.scopes,
+            &req.allowed_cidrs,
+        )
         .await
     {
         Ok((uuid, api_key)) => match service.get_key(uuid).await {
-            Ok(Some(key)) => {
-                let response: ApiKeyCreatedResponse = ApiKeyCreatedResponse {
-                    uuid: key.uuid,
-                    name: key.name.clone(),
-                    api_key,
-                    description: key.description.clone(),
-                    is_active: key.is_active,
-                    created_at: key.created_at,
-                    expires_at: key.expires_at,
-                    created_by: key.created_by,
-                    user_uuid: key.user_uuid,
-                    published: key.published,
-                    last_used_at: key.last_used_at,
-                };
-                ApiResponse::<ApiKeyCreatedResponse>::created(response)
-            }
+            Ok(Some(key)) => ApiResponse::<ApiKeyCreatedResponse>::created(
+                ApiKeyCreatedResponse::from_key(key, api_key),
+            ),
             Ok(None) => {
                 error!("API key created but not found: {uuid}");
                 ApiResponse::<()>::internal_error("API key created but not found")
@@ -216,6 +208,9 @@ pub async fn create_api_key(
                 ApiResponse::<()>::internal_error("Failed to retrieve created API key")
             }
         },
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            ApiResponse::<()>::unprocessable_entity(&msg)
+        }
         Err(e) => {
             error!("Failed to create API key: {e}");
             ApiResponse::<()>::internal_error("Failed to create API key")
@@ -375,3 +370,197 @@ pub async fn reassign_api_key(
         }
     }
 }
+
+/// Set the public API rate limit of an API key
+#[utoipa::path(
+    put,
+    path = "/admin/api/v1/api-keys/{uuid}/rate-limit",
+    tag = "api-keys",
+    params(
+        ("uuid" = Uuid, Path, description = "UUID of the API key")
+    ),
+    request_body = UpdateApiKeyRateLimitRequest,
+    responses(
+        (status = 200, description = "Rate limit updated successfully"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - API key does not belong to user"),
+        (status = 404, description = "API key not found"),
+        (status = 422, description = "Rate limit is not positive"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[put("/{uuid}/rate-limit")]
+pub async fn update_api_key_rate_limit(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<Uuid>,
+    req: web::Json<UpdateApiKeyRateLimitRequest>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    let user_uuid = match Uuid::parse_str(&auth.0.sub) {
+        Ok(uuid) => uuid,
+        Err(e) => {
+            return ApiResponse::<()>::unauthorized(&format!("Invalid UUID in auth token: {e}"));
+        }
+    };
+
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::ApiKeys,
+        &PermissionType::Update,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to update API keys");
+    }
+
+    let service = state.api_key_service();
+    match service
+        .set_rate_limit(path.into_inner(), req.rate_limit_per_minute, user_uuid)
+        .await
+    {
+        Ok(()) => ApiResponse::<()>::message("API key rate limit updated successfully"),
+        Err(r_data_core_core::error::Error::Forbidden(msg)) => ApiResponse::<()>::forbidden(&msg),
+        Err(r_data_core_core::error::Error::NotFound(_)) => ApiResponse::<()>::not_found("API key"),
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            ApiResponse::<()>::unprocessable_entity(&msg)
+        }
+        Err(e) => {
+            error!("Failed to update API key rate limit: {e}");
+            ApiResponse::<()>::internal_error("Failed to update API key rate limit")
+        }
+    }
+}
+
+/// List the authenticated user's active API keys that expire soon, soonest first
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/api-keys/expiring",
+    tag = "api-keys",
+    params(
+        ("within_days" = Option<i64>, Query, description = "Days ahead to look for expiring keys (default: 14, max: 365)")
+    ),
+    responses(
+        (status = 200, description = "API keys expiring within the window", body = Vec<ApiKeyResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 422, description = "Invalid window"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[get("/expiring")]
+pub async fn list_expiring_api_keys(
+    state: web::Data<ApiStateWrapper>,
+    auth: RequiredAuth,
+    query: web::Query<ExpiringApiKeysQuery>,
+) -> impl Responder {
+    let user_uuid = match Uuid::parse_str(&auth.0.sub) {
+        Ok(uuid) => uuid,
+        Err(e) => {
+            return ApiResponse::<()>::unauthorized(&format!("Invalid UUID in auth token: {e}"));
+        }
+    };
+
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::ApiKeys,
+        &PermissionType::Read,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to list API keys");
+    }
+
+    let within_days = query.within_days.unwrap_or(DEFAULT_EXPIRING_WITHIN_DAYS);
+    match state
+        .api_key_service()
+        .list_expiring(user_uuid, within_days)
+        .await
+    {
+        Ok(keys) => ApiResponse::ok(
+            keys.into_iter()
+                .map(ApiKeyResponse::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            ApiResponse::<()>::unprocessable_entity(&msg)
+        }
+        Err(e) => {
+            error!("Failed to list expiring API keys: {e}");
+            ApiResponse::<()>::internal_error("Failed to retrieve API keys")
+        }
+    }
+}
+
+/// Rotate an API key
+///
+/// Issues a new key with the same name, description, scopes, limits and roles. The old key keeps
+/// working until the grace period ends so clients can switch over.
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/api-keys/{uuid}/rotate",
+    tag = "api-keys",
+    params(
+        ("uuid" = Uuid, Path, description = "UUID of the API key to rotate"),
+        ("grace_period_hours" = Option<i64>, Query, description = "Hours the old key keeps working (default: 24, max: 720)")
+    ),
+    responses(
+        (status = 201, description = "API key rotated successfully", body = RotatedApiKeyResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - API key does not belong to user"),
+        (status = 404, description = "API key not found"),
+        (status = 422, description = "Invalid grace period, or key revoked or expired"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/{uuid}/rotate")]
+pub async fn rotate_api_key(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<Uuid>,
+    query: web::Query<RotateApiKeyQuery>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    let user_uuid = match Uuid::parse_str(&auth.0.sub) {
+        Ok(uuid) => uuid,
+        Err(e) => {
+            return ApiResponse::<()>::unauthorized(&format!("Invalid UUID in auth token: {e}"));
+        }
+    };
+
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::ApiKeys,
+        &PermissionType::Create,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to rotate API keys");
+    }
+
+    let grace_period_hours = query
+        .grace_period_hours
+        .unwrap_or(DEFAULT_ROTATION_GRACE_HOURS);
+    match state
+        .api_key_service()
+        .rotate_key(path.into_inner(), user_uuid, grace_period_hours)
+        .await
+    {
+        Ok(rotated) => ApiResponse::<RotatedApiKeyResponse>::created(RotatedApiKeyResponse {
+            new_key: ApiKeyCreatedResponse::from_key(rotated.new_key, rotated.key_value),
+            old_key: ApiKeyResponse::from(rotated.old_key),
+        }),
+        Err(r_data_core_core::error::Error::Forbidden(msg)) => ApiResponse::<()>::forbidden(&msg),
+        Err(r_data_core_core::error::Error::NotFound(_)) => ApiResponse::<()>::not_found("API key"),
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            ApiResponse::<()>::unprocessable_entity(&msg)
+        }
+        Err(e) => {
+            error!("Failed to rotate API key: {e}");
+            ApiResponse::<()>::internal_error("Failed to rotate API key")
+        }
+    }
+}
@@ -92,6 +92,55 @@ pub struct AdminLoginResponse {
     pub using_default_password: bool,
 }
 
+/// Login response for users with two-factor authentication enabled
+///
+/// The challenge token must be exchanged together with a TOTP code at
+/// `/admin/api/v1/auth/2fa/login` for access and refresh tokens.
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct AdminLoginChallengeResponse {
+    /// Always true; distinguishes this response from a completed login
+    pub two_factor_required: bool,
+
+    /// Short-lived token identifying the login attempt
+    pub challenge_token: String,
+
+    /// Challenge expiration (RFC3339 timestamp)
+    #[serde(with = "time::serde::rfc3339")]
+    #[ts(type = "string")]
+    pub challenge_expires_at: OffsetDateTime,
+}
+
+/// Second login step for users with two-factor authentication enabled
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct TwoFactorLoginRequest {
+    /// Challenge token from the password step
+    pub challenge_token: String,
+
+    /// Current code from the authenticator app
+    pub code: String,
+}
+
+/// TOTP code request body
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct TotpCodeRequest {
+    /// Current code from the authenticator app
+    pub code: String,
+}
+
+/// Secret for adding the account to an authenticator app
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct TotpEnrollmentResponse {
+    /// Base32 secret for manual entry
+    pub secret: String,
+
+    /// `otpauth://` URI, usually rendered as a QR code
+    pub provisioning_uri: String,
+}
+
 /// Admin registration request body
 #[derive(Debug, Deserialize, ToSchema, Validate, TS)]
 #[ts(export)]
@@ -7,8 +7,12 @@ use uuid::Uuid;
 
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
 use crate::auth::auth_enum::{OptionalAuth, RequiredAuth};
+use crate::auth::revocation::{revoke_access_token, revoke_user_access_tokens};
 use crate::response::ApiResponse;
 use crate::token_service::TokenService;
+use r_data_core_core::admin_jwt::{
+    generate_two_factor_challenge, verify_two_factor_challenge, TWO_FACTOR_CHALLENGE_EXPIRY_SECONDS,
+};
 use r_data_core_core::admin_user::AdminUser;
 use r_data_core_core::refresh_token::RefreshToken;
 use r_data_core_core::system_log::SystemLogStatus;
@@ -16,8 +20,9 @@ use r_data_core_persistence::{AdminUserRepository, AdminUserRepositoryTrait};
 use r_data_core_persistence::{RefreshTokenRepository, RefreshTokenRepositoryTrait};
 
 use crate::admin::auth::models::{
-    AdminLoginRequest, AdminLoginResponse, AdminRegisterRequest, ForgotPasswordRequest,
-    LogoutRequest, RefreshTokenRequest, RefreshTokenResponse, ResetPasswordRequest,
+    AdminLoginChallengeResponse, AdminLoginRequest, AdminLoginResponse, AdminRegisterRequest,
+    ForgotPasswordRequest, LogoutRequest, RefreshTokenRequest, RefreshTokenResponse,
+    ResetPasswordRequest, TotpCodeRequest, TotpEnrollmentResponse, TwoFactorLoginRequest,
 };
 use validator::Validate;
 
@@ -99,6 +104,83 @@ fn build_refresh_response(
     })
 }
 
+/// Issue tokens for an authenticated user and build the login response
+async fn complete_login(
+    data: &ApiStateWrapper,
+    repo: &AdminUserRepository,
+    user: &AdminUser,
+) -> actix_web::HttpResponse {
+    // Update last login time
+    if let Err(e) = repo.update_last_login(&user.uuid).await {
+        // Log the error but continue with authentication
+        log::error!("Failed to update last login: {e:?}");
+    }
+
+    // Load all roles for user
+    let roles = load_user_roles(user, data, repo).await;
+
+    // Generate token pair via TokenService
+    let token_service = TokenService::new(data.api_config());
+    let token_pair = match token_service.generate_token_pair(user, &roles) {
+        Ok(pair) => pair,
+        Err(e) => {
+            log::error!("Failed to generate tokens: {e:?}");
+            return ApiResponse::internal_error("Authentication failed");
+        }
+    };
+
+    // Store refresh token in database
+    let refresh_repo = RefreshTokenRepository::new(data.db_pool().clone());
+    let device_info = Some(serde_json::json!({
+        "user_agent": "login",
+        "login_time": OffsetDateTime::now_utc()
+    }));
+
+    if let Err(e) = refresh_repo
+        .create(
+            user.uuid,
+            token_pair.refresh_token_hash,
+            token_pair.refresh_expires_at,
+            device_info,
+        )
+        .await
+    {
+        log::error!("Failed to store refresh token: {e:?}");
+        return ApiResponse::internal_error("Authentication failed");
+    }
+
+    // Check if default admin password is still in use (if enabled)
+    let using_default_password = if data.api_config().check_default_admin_password {
+        check_admin_default_password(repo).await
+    } else {
+        false
+    };
+
+    // Log successful login
+    if let Some(log_svc) = data.system_log_service() {
+        let user_uuid = user.uuid;
+        log_svc
+            .log_auth_event(
+                Some(user_uuid),
+                Some(user_uuid),
+                "User logged in",
+                Some(serde_json::json!({"action": "login"})),
+                SystemLogStatus::Success,
+            )
+            .await;
+    }
+
+    // Build response
+    build_login_response(
+        user,
+        token_pair.access_token,
+        token_pair.refresh_token,
+        token_pair.access_expires_at,
+        token_pair.refresh_expires_at,
+        using_default_password,
+    )
+}
+
 /// Login endpoint for admin users
 #[utoipa::path(
     post,
@@ -106,7 +188,7 @@ fn build_refresh_response(
     tag = "admin-auth",
     request_body = AdminLoginRequest,
     responses(
-        (status = 200, description = "Login successful. Copy the token and click the Authorize button at the top to use it.", body = AdminLoginResponse),
+        (status = 200, description = "Login successful. Copy the token and click the Authorize button at the top to use it. Users with two-factor authentication get an AdminLoginChallengeResponse instead.", body = AdminLoginResponse),
         (status = 400, description = "Invalid request format or missing JSON body"),
         (status = 401, description = "Invalid credentials"),
         (status = 403, description = "Account locked or inactive"),
@@ -188,75 +270,24 @@ pub async fn admin_login(
         return ApiResponse::inactive("Account not active");
     }
 
-    // Update last login time
-    if let Err(e) = repo.update_last_login(&user.uuid).await {
-        // Log the error but continue with authentication
-        log::error!("Failed to update last login: {e:?}");
-    }
-
-    // Load all roles for user
-    let roles = load_user_roles(&user, &data, &repo).await;
-
-    // Generate token pair via TokenService
-    let token_service = TokenService::new(data.api_config());
-    let token_pair = match token_service.generate_token_pair(&user, &roles) {
-        Ok(pair) => pair,
-        Err(e) => {
-            log::error!("Failed to generate tokens: {e:?}");
-            return ApiResponse::internal_error("Authentication failed");
-        }
-    };
-
-    // Store refresh token in database
-    let refresh_repo = RefreshTokenRepository::new(data.db_pool().clone());
-    let device_info = Some(serde_json::json!({
-        "user_agent": "login",
-        "login_time": OffsetDateTime::now_utc()
-    }));
-
-    if let Err(e) = refresh_repo
-        .create(
-            user.uuid,
-            token_pair.refresh_token_hash,
-            token_pair.refresh_expires_at,
-            device_info,
-        )
-        .await
-    {
-        log::error!("Failed to store refresh token: {e:?}");
-        return ApiResponse::internal_error("Authentication failed");
-    }
-
-    // Check if default admin password is still in use (if enabled)
-    let using_default_password = if data.api_config().check_default_admin_password {
-        check_admin_default_password(&repo).await
-    } else {
-        false
-    };
-
-    // Log successful login
-    if let Some(log_svc) = data.system_log_service() {
-        let user_uuid = user.uuid;
-        log_svc
-            .log_auth_event(
-                Some(user_uuid),
-                Some(user_uuid),
-                "User logged in",
-                Some(serde_json::json!({"action": "login"})),
-                SystemLogStatus::Success,
-            )
-            .await;
+    // With two-factor authentication, tokens are only issued for a valid TOTP code
+    if user.totp_enabled {
+        return match generate_two_factor_challenge(&user, data.api_config()) {
+            Ok((challenge_token, challenge_expires_at)) => {
+                ApiResponse::ok(AdminLoginChallengeResponse {
+                    two_factor_required: true,
+                    challenge_token,
+                    challenge_expires_at,
+                })
+            }
+            Err(e) => {
+                log::error!("Failed to generate two-factor challenge: {e:?}");
+                ApiResponse::internal_error("Authentication failed")
+            }
+        };
     }
 
-    // Build response
-    build_login_response(
-        &user,
-        token_pair.access_token,
-        token_pair.refresh_token,
-        token_pair.access_expires_at,
-        token_pair.refresh_expires_at,
-        using_default_password,
-    )
+    complete_login(&data, &repo, &user).await
 }
 
 /// Register a new admin user endpoint
@@ -294,6 +325,12 @@ pub async fn admin_register(
         let error_message = format!("Validation error: {errors}");
         return ApiResponse::unprocessable_entity(&error_message);
     }
+    if let Err(r_data_core_core::error::Error::Validation(msg)) = data
+        .admin_user_service()
+        .validate_password(&register_req.password)
+    {
+        return ApiResponse::unprocessable_entity(&msg);
+    }
 
     // Get authentication info from the OptionalAuth extractor
     let (is_authenticated, creator_uuid) = auth.0.as_ref().map_or_else(
@@ -386,6 +423,8 @@ pub async fn admin_register(
 }
 
 /// Logout endpoint for admin users
+///
+/// Revokes the refresh token and, if sent in the `Authorization` header, the access token.
 #[utoipa::path(
     post,
     path = "/admin/api/v1/auth/logout",
@@ -402,7 +441,13 @@ pub async fn admin_register(
 pub async fn admin_logout(
     data: web::Data<ApiStateWrapper>,
     request: web::Json<LogoutRequest>,
+    auth: OptionalAuth,
 ) -> impl Responder {
+    // The access token sent along stops working immediately instead of at its expiry
+    if let Some(claims) = &auth.0 {
+        revoke_access_token(data.cache_manager(), claims).await;
+    }
+
     let refresh_repo = RefreshTokenRepository::new(data.db_pool().clone());
 
     // Hash the provided refresh token
@@ -605,6 +650,10 @@ pub async fn admin_revoke_all_tokens(
         return ApiResponse::unauthorized("Invalid user ID in token");
     };
 
+    // Access tokens of all sessions stop working immediately as well
+    revoke_user_access_tokens(data.cache_manager(), &claims.sub).await;
+    revoke_access_token(data.cache_manager(), &claims).await;
+
     let refresh_repo = RefreshTokenRepository::new(data.db_pool().clone());
 
     // Revoke all refresh tokens for the user
@@ -781,9 +830,199 @@ pub async fn reset_password(
     }
 }
 
+/// Wrong codes accepted per user within one challenge lifetime before logins are refused
+const MAX_TWO_FACTOR_ATTEMPTS: u64 = 5;
+
+/// Second login step: exchange a two-factor challenge and a TOTP code for tokens
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/auth/2fa/login",
+    tag = "admin-auth",
+    request_body = TwoFactorLoginRequest,
+    responses(
+        (status = 200, description = "Login successful", body = AdminLoginResponse),
+        (status = 400, description = "Invalid request format or missing JSON body"),
+        (status = 401, description = "Invalid or expired challenge, or invalid code"),
+        (status = 403, description = "Account inactive"),
+        (status = 429, description = "Too many invalid codes"),
+        (status = 500, description = "Internal server error")
+    ),
+    security()
+)]
+#[post("/auth/2fa/login")]
+pub async fn admin_two_factor_login(
+    data: web::Data<ApiStateWrapper>,
+    req: Option<web::Json<TwoFactorLoginRequest>>,
+) -> impl Responder {
+    let Some(req) = req else {
+        return ApiResponse::bad_request("Missing or invalid JSON body");
+    };
+
+    let Ok(claims) = verify_two_factor_challenge(&req.challenge_token, data.jwt_secret()) else {
+        return ApiResponse::unauthorized("Invalid or expired challenge");
+    };
+    let Ok(user_uuid) = Uuid::parse_str(&claims.sub) else {
+        return ApiResponse::unauthorized("Invalid or expired challenge");
+    };
+
+    let repo = AdminUserRepository::new(Arc::new(data.db_pool().clone()));
+    let user = match repo.find_by_uuid(&user_uuid).await {
+        Ok(Some(user)) if user.totp_enabled => user,
+        Ok(_) => return ApiResponse::unauthorized("Invalid or expired challenge"),
+        Err(e) => {
+            log::error!("Database error: {e:?}");
+            return ApiResponse::internal_error("Authentication failed");
+        }
+    };
+    if !user.is_active {
+        return ApiResponse::inactive("Account not active");
+    }
+
+    // Bound guessing: codes are short, so only a few attempts are allowed per challenge lifetime
+    let attempts_key = format!("two_factor:attempts:{user_uuid}");
+    match data
+        .cache_manager()
+        .increment(&attempts_key, TWO_FACTOR_CHALLENGE_EXPIRY_SECONDS)
+        .await
+    {
+        Ok((attempts, retry_after)) if attempts > MAX_TWO_FACTOR_ATTEMPTS => {
+            return ApiResponse::<()>::too_many_requests(
+                "Too many invalid two-factor codes",
+                retry_after.max(1),
+            );
+        }
+        Ok(_) => {}
+        Err(e) => log::warn!("Failed to count two-factor attempts: {e}"),
+    }
+
+    match data
+        .admin_user_service()
+        .verify_totp_code(&user, &req.code)
+        .await
+    {
+        Ok(true) => {
+            if let Err(e) = data.cache_manager().delete(&attempts_key).await {
+                log::warn!("Failed to reset two-factor attempts: {e}");
+            }
+            complete_login(&data, &repo, &user).await
+        }
+        Ok(false) => {
+            if let Some(log_svc) = data.system_log_service() {
+                log_svc
+                    .log_auth_event(
+                        Some(user.uuid),
+                        Some(user.uuid),
+                        "Login failed: invalid two-factor code",
+                        Some(serde_json::json!({"action": "login", "reason": "invalid_totp_code"})),
+                        SystemLogStatus::Failed,
+                    )
+                    .await;
+            }
+            ApiResponse::unauthorized("Invalid two-factor code")
+        }
+        Err(e) => {
+            log::error!("Failed to verify two-factor code: {e:?}");
+            ApiResponse::internal_error("Authentication failed")
+        }
+    }
+}
+
+/// Start two-factor enrollment for the authenticated user
+///
+/// Returns a new secret; two-factor authentication is enabled once a code generated from it is
+/// confirmed at `/admin/api/v1/auth/2fa/verify`.
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/auth/2fa/enroll",
+    tag = "admin-auth",
+    responses(
+        (status = 200, description = "Secret to add to an authenticator app", body = TotpEnrollmentResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Two-factor authentication already enabled"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/auth/2fa/enroll")]
+pub async fn enroll_two_factor(
+    data: web::Data<ApiStateWrapper>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    let Ok(user_uuid) = Uuid::parse_str(&auth.0.sub) else {
+        return ApiResponse::unauthorized("Invalid user ID in token");
+    };
+
+    match data
+        .admin_user_service()
+        .begin_totp_enrollment(&user_uuid)
+        .await
+    {
+        Ok(enrollment) => ApiResponse::ok(TotpEnrollmentResponse {
+            secret: enrollment.secret,
+            provisioning_uri: enrollment.provisioning_uri,
+        }),
+        Err(r_data_core_core::error::Error::Conflict(msg)) => ApiResponse::<()>::conflict(&msg),
+        Err(r_data_core_core::error::Error::NotFound(_)) => ApiResponse::<()>::not_found("User"),
+        Err(e) => {
+            log::error!("Failed to start two-factor enrollment: {e:?}");
+            ApiResponse::<()>::internal_error("Failed to start two-factor enrollment")
+        }
+    }
+}
+
+/// Confirm two-factor enrollment with a code from the authenticator app
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/auth/2fa/verify",
+    tag = "admin-auth",
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, description = "Two-factor authentication enabled"),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Two-factor authentication already enabled"),
+        (status = 422, description = "Invalid code or no enrollment in progress"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/auth/2fa/verify")]
+pub async fn verify_two_factor(
+    data: web::Data<ApiStateWrapper>,
+    req: web::Json<TotpCodeRequest>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    let Ok(user_uuid) = Uuid::parse_str(&auth.0.sub) else {
+        return ApiResponse::unauthorized("Invalid user ID in token");
+    };
+
+    match data
+        .admin_user_service()
+        .confirm_totp_enrollment(&user_uuid, &req.code)
+        .await
+    {
+        Ok(()) => ApiResponse::<()>::message("Two-factor authentication enabled"),
+        Err(r_data_core_core::error::Error::Conflict(msg)) => ApiResponse::<()>::conflict(&msg),
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            ApiResponse::<()>::unprocessable_entity(&msg)
+        }
+        Err(r_data_core_core::error::Error::NotFound(_)) => ApiResponse::<()>::not_found("User"),
+        Err(e) => {
+            log::error!("Failed to confirm two-factor enrollment: {e:?}");
+            ApiResponse::<()>::internal_error("Failed to confirm two-factor enrollment")
+        }
+    }
+}
+
 /// Register auth routes
 pub fn register_routes(cfg: &mut actix_web::web::ServiceConfig) {
     cfg.service(admin_login)
+        .service(admin_two_factor_login)
+        .service(enroll_two_factor)
+        .service(verify_two_factor)
         .service(admin_register)
         .service(admin_logout)
         .service(admin_refresh_token)
@@ -2,6 +2,7 @@
 
 pub mod models;
 pub mod routes;
+pub mod test_batch;
 
 pub use routes::register_routes;
 
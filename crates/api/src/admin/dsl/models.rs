@@ -88,3 +88,46 @@ pub struct DslOptionsAndExamplesResponse {
     #[ts(type = "unknown[]")]
     pub examples: Vec<Value>,
 }
+
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct DslTestBatchRequest {
+    /// The DSL steps array (JSON), same shape as for `/dsl/validate`
+    #[schema(value_type = Vec<Value>)]
+    #[ts(type = "unknown[]")]
+    pub steps: Vec<Value>,
+    /// Sample input rows; each is executed independently
+    #[schema(value_type = Vec<Value>, example = json!([{ "price": 10 }, { "price": "n/a" }]))]
+    #[ts(type = "unknown[]")]
+    pub inputs: Vec<Value>,
+}
+
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct DslTestStepOutput {
+    /// Kind of target the step writes to (`format`, `entity`, `next_step` or `email`)
+    pub target: String,
+    /// Entity type that would be written; entity writes are never persisted in a test run
+    pub entity_definition: Option<String>,
+    /// Output produced by the step
+    #[schema(value_type = Value)]
+    #[ts(type = "unknown")]
+    pub produced: Value,
+}
+
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct DslTestRowResult {
+    /// Position of the row in the submitted inputs
+    pub index: usize,
+    /// Outputs of the steps that ran, in execution order (empty if the row failed)
+    pub outputs: Vec<DslTestStepOutput>,
+    /// Execution error for this row, if any
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct DslTestBatchResponse {
+    pub results: Vec<DslTestRowResult>,
+}
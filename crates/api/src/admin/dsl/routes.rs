@@ -317,6 +317,8 @@ fn build_arithmetic_transform_fields() -> Vec<DslFieldSpec> {
             options: Some(vec![
                 "field".into(),
                 "const".into(),
+                "now".into(),
+                "today".into(),
                 "external_entity_field".into(),
             ]),
         },
@@ -363,6 +365,8 @@ fn build_arithmetic_transform_fields() -> Vec<DslFieldSpec> {
             options: Some(vec![
                 "field".into(),
                 "const".into(),
+                "now".into(),
+                "today".into(),
                 "external_entity_field".into(),
             ]),
         },
@@ -507,6 +511,130 @@ fn build_send_email_transform_fields() -> Vec<DslFieldSpec> {
     ]
 }
 
+/// Build field specifications for the round transform type
+fn build_round_transform_fields() -> Vec<DslFieldSpec> {
+    vec![
+        DslFieldSpec {
+            name: "source".into(),
+            r#type: "string".into(),
+            required: true,
+            options: None,
+        },
+        DslFieldSpec {
+            name: "target".into(),
+            r#type: "string".into(),
+            required: true,
+            options: None,
+        },
+        DslFieldSpec {
+            name: "precision".into(),
+            r#type: "number".into(),
+            required: false,
+            options: None,
+        },
+        DslFieldSpec {
+            name: "mode".into(),
+            r#type: "enum".into(),
+            required: false,
+            options: Some(vec![
+                "half_up".into(),
+                "half_even".into(),
+                "floor".into(),
+                "ceil".into(),
+            ]),
+        },
+    ]
+}
+
+/// Build field specifications for the lookup transform type
+fn build_lookup_transform_fields() -> Vec<DslFieldSpec> {
+    vec![
+        DslFieldSpec {
+            name: "source".into(),
+            r#type: "string".into(),
+            required: true,
+            options: None,
+        },
+        DslFieldSpec {
+            name: "target".into(),
+            r#type: "string".into(),
+            required: true,
+            options: None,
+        },
+        DslFieldSpec {
+            name: "table".into(),
+            r#type: "object".into(),
+            required: true,
+            options: None,
+        },
+        DslFieldSpec {
+            name: "default".into(),
+            r#type: "string".into(),
+            required: false,
+            options: None,
+        },
+    ]
+}
+
+/// Build field specifications for the cast transform type
+fn build_cast_transform_fields() -> Vec<DslFieldSpec> {
+    vec![
+        DslFieldSpec {
+            name: "source".into(),
+            r#type: "string".into(),
+            required: true,
+            options: None,
+        },
+        DslFieldSpec {
+            name: "target".into(),
+            r#type: "string".into(),
+            required: true,
+            options: None,
+        },
+        DslFieldSpec {
+            name: "to".into(),
+            r#type: "enum".into(),
+            required: true,
+            options: Some(vec![
+                "string".into(),
+                "integer".into(),
+                "float".into(),
+                "boolean".into(),
+            ]),
+        },
+        DslFieldSpec {
+            name: "strict".into(),
+            r#type: "boolean".into(),
+            required: false,
+            options: None,
+        },
+    ]
+}
+
+/// Build field specifications for the `date_parse` and `date_format` transform types
+fn build_date_transform_fields() -> Vec<DslFieldSpec> {
+    vec![
+        DslFieldSpec {
+            name: "source".into(),
+            r#type: "string".into(),
+            required: true,
+            options: None,
+        },
+        DslFieldSpec {
+            name: "target".into(),
+            r#type: "string".into(),
+            required: true,
+            options: None,
+        },
+        DslFieldSpec {
+            name: "format".into(),
+            r#type: "string".into(),
+            required: true,
+            options: None,
+        },
+    ]
+}
+
 /// Build transform type specifications
 fn build_transform_type_specs(workflow_mail_configured: bool) -> Vec<DslTypeSpec> {
     let mut specs = vec![
@@ -522,6 +650,26 @@ fn build_transform_type_specs(workflow_mail_configured: bool) -> Vec<DslTypeSpec
             r#type: "concat".to_string(),
             fields: build_concat_transform_fields(),
         },
+        DslTypeSpec {
+            r#type: "round".to_string(),
+            fields: build_round_transform_fields(),
+        },
+        DslTypeSpec {
+            r#type: "lookup".to_string(),
+            fields: build_lookup_transform_fields(),
+        },
+        DslTypeSpec {
+            r#type: "cast".to_string(),
+            fields: build_cast_transform_fields(),
+        },
+        DslTypeSpec {
+            r#type: "date_parse".to_string(),
+            fields: build_date_transform_fields(),
+        },
+        DslTypeSpec {
+            r#type: "date_format".to_string(),
+            fields: build_date_transform_fields(),
+        },
         DslTypeSpec {
             r#type: "authenticate".to_string(),
             fields: build_authenticate_transform_fields(),
@@ -575,6 +723,7 @@ pub async fn validate_dsl(
     let program = DslProgram {
         steps,
         on_complete: None,
+        ..DslProgram::default()
     };
     match program.validate() {
         Ok(()) => ApiResponse::ok(DslValidateResponse { valid: true }),
@@ -803,6 +952,7 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("")
             .service(validate_dsl)
+            .service(super::test_batch::test_dsl_batch)
             .service(list_from_options)
             .service(list_to_options)
             .service(list_transform_options),
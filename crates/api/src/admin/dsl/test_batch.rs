@@ -0,0 +1,123 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::{post, web, Responder};
+use serde_json::Value;
+
+use crate::admin::dsl::models::{
+    DslTestBatchRequest, DslTestBatchResponse, DslTestRowResult, DslTestStepOutput,
+};
+use crate::auth::auth_enum::RequiredAuth;
+use crate::auth::permission_check;
+use crate::response::{ApiResponse, ValidationViolation};
+use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
+use r_data_core_workflow::dsl::{DslProgram, DslStep, ToDef};
+
+/// Maximum number of sample rows accepted by a single test run
+pub const MAX_TEST_BATCH_SIZE: usize = 100;
+
+/// Describe where a step's output would go without performing any write
+fn step_output(to: &ToDef, produced: Value) -> DslTestStepOutput {
+    let (target, entity_definition) = match to {
+        ToDef::Format { .. } => ("format", None),
+        ToDef::Entity {
+            entity_definition, ..
+        } => ("entity", Some(entity_definition.clone())),
+        ToDef::NextStep { .. } => ("next_step", None),
+        ToDef::Email { .. } => ("email", None),
+    };
+    DslTestStepOutput {
+        target: target.to_string(),
+        entity_definition,
+        produced,
+    }
+}
+
+/// Execute the program on every input independently; a failing row does not stop the batch
+#[must_use]
+pub fn run_batch(program: &DslProgram, inputs: &[Value]) -> Vec<DslTestRowResult> {
+    program
+        .execute_stream(inputs)
+        .enumerate()
+        .map(|(index, result)| match result {
+            Ok(outputs) => DslTestRowResult {
+                index,
+                outputs: outputs
+                    .into_iter()
+                    .map(|(to, produced)| step_output(&to, produced))
+                    .collect(),
+                error: None,
+            },
+            Err(e) => DslTestRowResult {
+                index,
+                outputs: Vec::new(),
+                error: Some(e.to_string()),
+            },
+        })
+        .collect()
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/dsl/test-batch",
+    tag = "DSL",
+    request_body = DslTestBatchRequest,
+    responses(
+        (status = 200, description = "Per-row outputs or errors; entity writes are not persisted", body = DslTestBatchResponse),
+        (status = 422, description = "Invalid DSL or batch too large", body = Value),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("jwt" = []))
+)]
+#[post("/test-batch")]
+pub async fn test_dsl_batch(
+    payload: web::Json<DslTestBatchRequest>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::Workflows,
+        &PermissionType::Read,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to test DSL");
+    }
+
+    if payload.inputs.len() > MAX_TEST_BATCH_SIZE {
+        return ApiResponse::<()>::unprocessable_entity_with_violations(
+            "Batch too large",
+            vec![ValidationViolation {
+                field: "inputs".to_string(),
+                message: format!("At most {MAX_TEST_BATCH_SIZE} sample inputs are allowed"),
+                code: Some("BATCH_TOO_LARGE".to_string()),
+            }],
+        );
+    }
+
+    let steps: Result<Vec<DslStep>, _> = payload
+        .steps
+        .iter()
+        .map(|v| serde_json::from_value(v.clone()))
+        .collect();
+    let Ok(steps) = steps else {
+        return ApiResponse::<()>::unprocessable_entity("Invalid DSL steps format");
+    };
+    let program = DslProgram {
+        steps,
+        on_complete: None,
+        ..DslProgram::default()
+    };
+    if let Err(e) = program.validate() {
+        return ApiResponse::<()>::unprocessable_entity_with_violations(
+            "Invalid DSL",
+            vec![ValidationViolation {
+                field: "dsl".to_string(),
+                message: e.to_string(),
+                code: Some("DSL_INVALID".to_string()),
+            }],
+        );
+    }
+
+    ApiResponse::ok(DslTestBatchResponse {
+        results: run_batch(&program, &payload.inputs),
+    })
+}
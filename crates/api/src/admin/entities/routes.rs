@@ -0,0 +1,180 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use actix_web::{post, web, Responder};
+use log::error;
+use uuid::Uuid;
+
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::{CombinedRequiredAuth, RequiredAuth};
+use crate::auth::{permission_check, RequiredAuthExt};
+use crate::public::entity_permissions::ensure_owned;
+use crate::response::ApiResponse;
+use r_data_core_core::error::Error;
+use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
+use r_data_core_persistence::AdminUserRepository;
+use r_data_core_services::VersionService;
+
+/// Restore an entity to a previous version
+///
+/// The selected version is written as a new current version. Fields that no longer
+/// exist in the entity definition are dropped; the result is validated like an update.
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/entities/{uuid}/versions/{version_number}/restore",
+    tag = "entities",
+    params(
+        ("uuid" = Uuid, Path, description = "Entity UUID"),
+        ("version_number" = i32, Path, description = "Version number to restore")
+    ),
+    responses(
+        (status = 200, description = "Entity restored"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Entity or version not found"),
+        (status = 422, description = "Version is current or its data is invalid under the current definition"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/{uuid}/versions/{version_number}/restore")]
+pub async fn restore_entity_version(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<(Uuid, i32)>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if let Err(resp) =
+        auth.require_permission(&ResourceNamespace::Entities, &PermissionType::Update, None)
+    {
+        return resp;
+    }
+
+    let Some(user_uuid) = auth.user_uuid() else {
+        return ApiResponse::<()>::internal_error("No authentication claims found");
+    };
+
+    let (entity_uuid, version_number) = path.into_inner();
+
+    // Callers limited to their own entities may only revert those
+    let Some(entity_service) = state.dynamic_entity_service() else {
+        return ApiResponse::<()>::internal_error("Dynamic entity service not initialized");
+    };
+    let entity_type = match entity_service
+        .get_entity_by_uuid_any_type(entity_uuid)
+        .await
+    {
+        Ok(entity) => entity.entity_type,
+        Err(Error::NotFound(msg)) => return ApiResponse::<()>::not_found(&msg),
+        Err(e) => {
+            error!("Failed to load entity {entity_uuid} for version restore: {e}");
+            return ApiResponse::<()>::internal_error("Failed to restore entity version");
+        }
+    };
+    let combined_auth = CombinedRequiredAuth {
+        jwt_claims: Some(auth.0.clone()),
+        api_key_info: None,
+        pre_shared_key_valid: false,
+        entity_jwt_claims: None,
+    };
+    if let Err(resp) = ensure_owned(
+        &state,
+        &combined_auth,
+        &entity_type,
+        entity_uuid,
+        &PermissionType::Update,
+    )
+    .await
+    {
+        return resp;
+    }
+
+    let service =
+        VersionService::new(state.db_pool().clone()).with_entity_service(entity_service.clone());
+
+    match service
+        .restore(entity_uuid, version_number, user_uuid)
+        .await
+    {
+        Ok(()) => ApiResponse::<()>::message(&format!("Reverted to version {version_number}")),
+        Err(Error::NotFound(msg)) => ApiResponse::<()>::not_found(&msg),
+        Err(Error::Validation(msg)) => ApiResponse::<()>::unprocessable_entity(&msg),
+        Err(e) => {
+            error!("Failed to restore entity {entity_uuid} to version {version_number}: {e}");
+            ApiResponse::<()>::internal_error("Failed to restore entity version")
+        }
+    }
+}
+
+/// Restore a soft-deleted entity
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/entities/{entity_type}/{uuid}/restore",
+    tag = "entities",
+    params(
+        ("entity_type" = String, Path, description = "Entity type"),
+        ("uuid" = Uuid, Path, description = "Entity UUID")
+    ),
+    responses(
+        (status = 200, description = "Entity restored"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Deleted entity not found"),
+        (status = 409, description = "A live entity already uses the same path and key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/{entity_type}/{uuid}/restore")]
+pub async fn restore_deleted_entity(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<(String, Uuid)>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    let (entity_type, entity_uuid) = path.into_inner();
+    match permission_check::has_entity_permission(
+        &auth.0,
+        &entity_type,
+        &PermissionType::Delete,
+        None,
+        state.role_service(),
+        &AdminUserRepository::new(Arc::new(state.db_pool().clone())),
+    )
+    .await
+    {
+        Ok(true) => {}
+        Ok(false) => {
+            return ApiResponse::<()>::forbidden(&format!(
+                "Insufficient permissions to perform Delete on entities:{entity_type}"
+            ))
+        }
+        Err(e) => {
+            error!("Failed to check permissions for entity type {entity_type}: {e}");
+            return ApiResponse::<()>::internal_error("Failed to check permissions");
+        }
+    }
+
+    let Some(service) = state.dynamic_entity_service() else {
+        return ApiResponse::<()>::internal_error("Dynamic entity service not initialized");
+    };
+
+    match service.restore_entity(&entity_type, &entity_uuid).await {
+        Ok(()) => ApiResponse::<()>::message("Entity restored"),
+        Err(Error::NotFound(msg)) => ApiResponse::<()>::not_found(&msg),
+        Err(Error::ValidationFailed(msg)) => ApiResponse::<()>::conflict(&msg),
+        Err(e) => {
+            error!("Failed to restore deleted entity {entity_uuid}: {e}");
+            ApiResponse::<()>::internal_error("Failed to restore entity")
+        }
+    }
+}
+
+/// Register entity routes
+pub fn register_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(restore_entity_version)
+        .service(restore_deleted_entity);
+}
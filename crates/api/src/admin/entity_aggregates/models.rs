@@ -0,0 +1,54 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::BTreeMap;
+
+use r_data_core_core::entity_aggregate::EntityAggregate;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Request body for enabling or reconfiguring the aggregate of an entity type
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct ConfigureEntityAggregateRequest {
+    /// Numeric (Integer or Float) fields whose sums are maintained; may be empty to only count
+    pub sum_fields: Vec<String>,
+}
+
+/// Cached entity aggregate response DTO
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct EntityAggregateResponse {
+    /// Aggregated entity type
+    pub entity_type: String,
+    /// Summed field names
+    pub sum_fields: Vec<String>,
+    /// Number of live (not deleted) entities
+    #[ts(type = "number")]
+    pub entity_count: i64,
+    /// Sum per summed field
+    pub sums: BTreeMap<String, f64>,
+    /// ISO 8601 timestamp of the last full rebuild
+    pub rebuilt_at: Option<String>,
+    /// ISO 8601 timestamp of the last change
+    pub updated_at: String,
+}
+
+fn format_timestamp(value: OffsetDateTime) -> String {
+    value.format(&Rfc3339).unwrap_or_else(|_| value.to_string())
+}
+
+impl From<EntityAggregate> for EntityAggregateResponse {
+    fn from(a: EntityAggregate) -> Self {
+        Self {
+            entity_type: a.entity_type,
+            sum_fields: a.sum_fields,
+            entity_count: a.entity_count,
+            sums: a.sums,
+            rebuilt_at: a.rebuilt_at.map(format_timestamp),
+            updated_at: format_timestamp(a.updated_at),
+        }
+    }
+}
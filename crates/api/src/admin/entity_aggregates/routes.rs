@@ -0,0 +1,247 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
+
+use crate::admin::entity_aggregates::models::{
+    ConfigureEntityAggregateRequest, EntityAggregateResponse,
+};
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::RequiredAuth;
+use crate::auth::permission_check;
+use crate::response::ApiResponse;
+use r_data_core_core::entity_aggregate::EntityAggregate;
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
+use r_data_core_persistence::EntityAggregateRepository;
+use r_data_core_services::EntityAggregateService;
+
+fn aggregate_service(data: &ApiStateWrapper) -> EntityAggregateService {
+    EntityAggregateService::new(Arc::new(EntityAggregateRepository::new(
+        data.db_pool().clone(),
+    )))
+}
+
+fn has_permission(auth: &RequiredAuth, permission: &PermissionType) -> bool {
+    permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::EntityDefinitions,
+        permission,
+        None,
+    )
+}
+
+async fn load_definition(
+    data: &ApiStateWrapper,
+    entity_type: &str,
+) -> std::result::Result<EntityDefinition, HttpResponse> {
+    match data
+        .entity_definition_service()
+        .get_entity_definition_by_entity_type(entity_type)
+        .await
+    {
+        Ok(definition) => Ok(definition),
+        Err(Error::NotFound(_)) => Err(ApiResponse::<()>::not_found("Entity definition")),
+        Err(e) => {
+            log::error!("Failed to load entity definition {entity_type}: {e}");
+            Err(ApiResponse::<()>::internal_error(
+                "Failed to load entity definition",
+            ))
+        }
+    }
+}
+
+/// Map the outcome of a configure/rebuild call to a response
+fn aggregate_response(result: Result<EntityAggregate>, action: &str) -> HttpResponse {
+    match result {
+        Ok(aggregate) => ApiResponse::ok(EntityAggregateResponse::from(aggregate)),
+        Err(Error::NotFound(_)) => ApiResponse::<()>::not_found("Entity aggregate"),
+        Err(Error::Validation(msg)) => ApiResponse::<()>::unprocessable_entity(&msg),
+        Err(e) => {
+            log::error!("Failed to {action} entity aggregate: {e}");
+            ApiResponse::<()>::internal_error(&format!("Failed to {action} entity aggregate"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/entity-aggregates",
+    tag = "entity-aggregates",
+    responses(
+        (status = 200, description = "All configured entity aggregates", body = [EntityAggregateResponse]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[get("")]
+pub async fn list_aggregates(
+    data: web::Data<ApiStateWrapper>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !has_permission(&auth, &PermissionType::Read) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to view aggregates");
+    }
+
+    match aggregate_service(&data).list().await {
+        Ok(aggregates) => {
+            let dtos: Vec<EntityAggregateResponse> = aggregates
+                .into_iter()
+                .map(EntityAggregateResponse::from)
+                .collect();
+            ApiResponse::ok(dtos)
+        }
+        Err(e) => {
+            log::error!("Failed to list entity aggregates: {e}");
+            ApiResponse::<()>::internal_error("Failed to list entity aggregates")
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/entity-aggregates/{entity_type}",
+    tag = "entity-aggregates",
+    params(("entity_type" = String, Path, description = "Entity type")),
+    responses(
+        (status = 200, description = "Cached aggregate", body = EntityAggregateResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Aggregates not enabled for the entity type"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[get("/{entity_type}")]
+pub async fn get_aggregate(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<String>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !has_permission(&auth, &PermissionType::Read) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to view aggregates");
+    }
+
+    aggregate_response(aggregate_service(&data).get(&path).await, "load")
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/api/v1/entity-aggregates/{entity_type}",
+    tag = "entity-aggregates",
+    params(("entity_type" = String, Path, description = "Entity type")),
+    request_body = ConfigureEntityAggregateRequest,
+    responses(
+        (status = 200, description = "Aggregate enabled and rebuilt", body = EntityAggregateResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Entity type not found"),
+        (status = 422, description = "Unknown or non-numeric sum field"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[put("/{entity_type}")]
+pub async fn configure_aggregate(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<String>,
+    body: web::Json<ConfigureEntityAggregateRequest>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !has_permission(&auth, &PermissionType::Update) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to configure aggregates");
+    }
+
+    let definition = match load_definition(&data, &path).await {
+        Ok(definition) => definition,
+        Err(response) => return response,
+    };
+    aggregate_response(
+        aggregate_service(&data)
+            .configure(&definition, &body.sum_fields)
+            .await,
+        "configure",
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/entity-aggregates/{entity_type}/rebuild",
+    tag = "entity-aggregates",
+    params(("entity_type" = String, Path, description = "Entity type")),
+    responses(
+        (status = 200, description = "Aggregate recomputed from the live entities", body = EntityAggregateResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Entity type not found or aggregates not enabled"),
+        (status = 422, description = "A summed field is no longer numeric"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[post("/{entity_type}/rebuild")]
+pub async fn rebuild_aggregate(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<String>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !has_permission(&auth, &PermissionType::Update) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to rebuild aggregates");
+    }
+
+    let definition = match load_definition(&data, &path).await {
+        Ok(definition) => definition,
+        Err(response) => return response,
+    };
+    aggregate_response(
+        aggregate_service(&data).rebuild(&definition).await,
+        "rebuild",
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/api/v1/entity-aggregates/{entity_type}",
+    tag = "entity-aggregates",
+    params(("entity_type" = String, Path, description = "Entity type")),
+    responses(
+        (status = 200, description = "Aggregates disabled"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Aggregates not enabled for the entity type"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[delete("/{entity_type}")]
+pub async fn delete_aggregate(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<String>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !has_permission(&auth, &PermissionType::Update) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to configure aggregates");
+    }
+
+    match aggregate_service(&data).disable(&path).await {
+        Ok(()) => ApiResponse::<()>::message("Aggregates disabled"),
+        Err(Error::NotFound(_)) => ApiResponse::<()>::not_found("Entity aggregate"),
+        Err(e) => {
+            log::error!("Failed to disable entity aggregate: {e}");
+            ApiResponse::<()>::internal_error("Failed to disable entity aggregate")
+        }
+    }
+}
+
+/// Register entity aggregate routes
+pub fn register_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_aggregates)
+        .service(get_aggregate)
+        .service(configure_aggregate)
+        .service(rebuild_aggregate)
+        .service(delete_aggregate);
+}
@@ -3,7 +3,7 @@
 use crate::admin::entity_definitions::models::{
     DateTimeConstraints, EntityDefinitionSchema, FieldConstraints, FieldDefinitionSchema,
     FieldTypeSchema, NumericConstraints, RelationConstraints, SchemaConstraints, SelectConstraints,
-    StringConstraints, UiSettingsSchema,
+    StringConstraints, UiSettingsSchema, ValidationRuleSchema,
 };
 use r_data_core_core::entity_definition::definition::EntityDefinition;
 use r_data_core_core::field::FieldDefinition;
@@ -25,6 +25,18 @@ pub fn entity_definition_to_schema_model(def: &EntityDefinition) -> EntityDefini
             .iter()
             .map(field_definition_to_schema_model)
             .collect(),
+        extends: def.extends.clone(),
+        unique_constraints: def.unique_constraints.clone(),
+        validation_rules: def
+            .validation_rules
+            .iter()
+            .map(|rule| ValidationRuleSchema {
+                name: rule.name.clone(),
+                expression: rule.expression.clone(),
+                message: rule.message.clone(),
+            })
+            .collect(),
+        table_name: def.table_name.clone(),
         published: Some(def.published),
         created_at: Some(def.created_at.format(&Rfc3339).unwrap_or_default()),
         updated_at: Some(def.updated_at.format(&Rfc3339).unwrap_or_default()),
@@ -81,6 +93,11 @@ pub fn field_definition_to_schema_model(field: &FieldDefinition) -> FieldDefinit
         FieldType::ManyToOne | FieldType::ManyToMany => {
             FieldConstraints::Relation(RelationConstraints {
                 target_class: field.validation.target_class.clone().unwrap_or_default(),
+                on_delete: if field.field_type == FieldType::ManyToOne {
+                    field.on_delete_policy().ok()
+                } else {
+                    None
+                },
             })
         }
         FieldType::Select => {
@@ -140,6 +157,7 @@ pub fn field_definition_to_schema_model(field: &FieldDefinition) -> FieldDefinit
             wysiwyg_toolbar: field.ui_settings.wysiwyg_toolbar.clone(),
             input_type: field.ui_settings.input_type.clone(),
         },
+        display_order: field.display_order,
     }
 }
 
@@ -152,9 +170,11 @@ pub const fn field_type_to_schema(field_type: &FieldType) -> FieldTypeSchema {
         FieldType::Wysiwyg => FieldTypeSchema::Wysiwyg,
         FieldType::Integer => FieldTypeSchema::Integer,
         FieldType::Float => FieldTypeSchema::Float,
+        FieldType::Money => FieldTypeSchema::Money,
         FieldType::Boolean => FieldTypeSchema::Boolean,
         FieldType::DateTime => FieldTypeSchema::DateTime,
         FieldType::Date => FieldTypeSchema::Date,
+        FieldType::Duration => FieldTypeSchema::Duration,
         FieldType::Json => FieldTypeSchema::Json,
         FieldType::Object => FieldTypeSchema::Object,
         FieldType::Array => FieldTypeSchema::Array,
@@ -210,9 +230,11 @@ mod tests {
             FieldType::Wysiwyg,
             FieldType::Integer,
             FieldType::Float,
+            FieldType::Money,
             FieldType::Boolean,
             FieldType::DateTime,
             FieldType::Date,
+            FieldType::Duration,
             FieldType::Object,
             FieldType::Array,
             FieldType::Uuid,
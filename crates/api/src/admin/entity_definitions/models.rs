@@ -1,5 +1,6 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use r_data_core_core::field::OnDeletePolicy;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use ts_rs::TS;
@@ -68,6 +69,8 @@ pub struct SelectConstraints {
 pub struct RelationConstraints {
     /// Name of the related entity type
     pub target_class: String,
+    /// What happens to referencing entities when the target is deleted (`ManyToOne` only)
+    pub on_delete: Option<OnDeletePolicy>,
 }
 
 /// Object/Array field constraints
@@ -202,12 +205,16 @@ pub enum FieldTypeSchema {
     Integer,
     /// Decimal number field (float in database)
     Float,
+    /// Amount with an ISO-4217 currency code (jsonb `{ amount, currency }` in database)
+    Money,
     /// True/false field (boolean in database)
     Boolean,
     /// Date and time field (timestamp in database)
     DateTime,
     /// Date only field (date in database)
     Date,
+    /// ISO-8601 duration such as `PT1H30M` (interval in database)
+    Duration,
     /// JSON object field (jsonb in database, must be an object)
     Object,
     /// JSON array field (jsonb in database)
@@ -261,6 +268,22 @@ pub struct FieldDefinitionSchema {
     /// UI settings for the field
     #[serde(default)]
     pub ui_settings: UiSettingsSchema,
+    /// Position of the field in the definition (lower numbers first, 0 for unordered)
+    #[serde(default)]
+    pub display_order: i32,
+}
+
+/// Entity-level validation rule, e.g. `end_date >= start_date`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export)]
+#[schema(title = "ValidationRuleSchema")]
+pub struct ValidationRuleSchema {
+    /// Name identifying the rule in validation errors
+    pub name: String,
+    /// Comparison of a field with another field or a literal: `==`, `!=`, `<`, `<=`, `>`, `>=`
+    pub expression: String,
+    /// Message returned when the rule is violated
+    pub message: String,
 }
 
 /// Schema for entity definitions in `OpenAPI` docs
@@ -286,6 +309,21 @@ pub struct EntityDefinitionSchema {
     pub icon: Option<String>,
     /// Field definitions for this entity type
     pub fields: Vec<FieldDefinitionSchema>,
+    /// UUIDs of the entity definitions whose fields are inherited, in precedence order.
+    /// Own fields and later parents override earlier fields with the same name.
+    #[serde(default)]
+    #[ts(type = "string[]")]
+    pub extends: Vec<Uuid>,
+    /// Groups of field names whose combined values must be unique, e.g. `[["tenant_id", "email"]]`
+    #[serde(default)]
+    pub unique_constraints: Vec<Vec<String>>,
+    /// Entity-level invariants checked after the per-field validation
+    #[serde(default)]
+    pub validation_rules: Vec<ValidationRuleSchema>,
+    /// Table holding the entities, e.g. an existing legacy table (defaults to `entity_<type>`,
+    /// cannot be changed after creation)
+    #[serde(default)]
+    pub table_name: Option<String>,
     /// Published &**state (whether visible to users)
     pub published: Option<bool>,
     /// Created at timestamp
@@ -318,6 +356,45 @@ pub struct ApplySchemaRequest {
     pub uuid: Option<Uuid>,
 }
 
+/// Query parameters for importing an entity definition bundle
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct ImportEntityDefinitionQuery {
+    /// Replace an existing definition with the same entity type instead of failing
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+/// Result of importing an entity definition bundle
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct ImportEntityDefinitionResponse {
+    /// UUID of the created or overwritten definition
+    #[ts(type = "string")]
+    pub uuid: Uuid,
+    /// Whether the definition was created (false when an existing one was overwritten)
+    pub created: bool,
+}
+
+/// Request to rename a field of an entity definition
+#[derive(Debug, Deserialize, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct RenameFieldRequest {
+    /// New field name
+    pub new_name: String,
+    /// Also rename the field in stored entity versions
+    #[serde(default)]
+    pub rename_in_versions: bool,
+}
+
+/// Request to reorder the fields of an entity definition
+#[derive(Debug, Deserialize, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct ReorderFieldsRequest {
+    /// Field names in their new order; fields not listed follow in their current order
+    pub fields: Vec<String>,
+}
+
 #[derive(Serialize, ToSchema, TS)]
 #[ts(export)]
 pub struct EntityDefinitionVersionMeta {
@@ -14,11 +14,14 @@ use crate::admin::entity_definitions::conversions::entity_definition_to_schema_m
 use crate::admin::entity_definitions::models::EntityDefinitionSchema;
 use crate::admin::entity_definitions::models::PaginationQuery;
 use crate::admin::entity_definitions::models::{
-    ApplySchemaRequest, EntityDefinitionVersionMeta, EntityDefinitionVersionPayload, PathUuid,
+    ApplySchemaRequest, EntityDefinitionVersionMeta, EntityDefinitionVersionPayload,
+    ImportEntityDefinitionQuery, ImportEntityDefinitionResponse, PathUuid, RenameFieldRequest,
+    ReorderFieldsRequest,
 };
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
 use crate::response::ApiResponse;
 use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::entity_definition::{EntityDefinitionBundle, SchemaDiff};
 use r_data_core_persistence::EntityDefinitionVersioningRepository;
 use utoipa::ToSchema;
 
@@ -348,6 +351,7 @@ async fn update_entity_definition(
     let mut updated_def = definition.into_inner();
     updated_def.uuid = path.uuid; // Ensure UUID matches the path
     updated_def.entity_type = existing_def.entity_type;
+    updated_def.table_name = existing_def.table_name;
     updated_def.created_at = existing_def.created_at;
     updated_def.created_by = existing_def.created_by;
     updated_def.updated_at = OffsetDateTime::now_utc();
@@ -503,6 +507,393 @@ async fn apply_entity_definition_schema(
     }
 }
 
+/// Export an entity definition as a portable bundle
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/entity-definitions/{uuid}/export",
+    tag = "entity-definitions",
+    params(
+        ("uuid" = Uuid, Path, description = "Entity definition UUID")
+    ),
+    responses(
+        (status = 200, description = "Entity definition bundle", body = EntityDefinitionBundle),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Entity definition not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[get("/{uuid}/export")]
+async fn export_entity_definition(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<PathUuid>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::check_permission_with_log(
+        &auth.0,
+        &ResourceNamespace::EntityDefinitions,
+        &PermissionType::Read,
+        None,
+        "Export entity definition",
+    ) {
+        return ApiResponse::<()>::forbidden(
+            "Insufficient permissions to export entity definition",
+        );
+    }
+
+    match data.entity_definition_service().export(&path.uuid).await {
+        Ok(bundle) => ApiResponse::ok(bundle),
+        Err(r_data_core_core::error::Error::NotFound(_)) => {
+            ApiResponse::<()>::not_found("Entity definition")
+        }
+        Err(e) => {
+            error!("Failed to export entity definition: {e}");
+            ApiResponse::<()>::internal_error("Failed to export entity definition")
+        }
+    }
+}
+
+/// Import an entity definition bundle
+///
+/// Creates the entity type of the bundle. An existing definition with the same entity type
+/// is only replaced with `overwrite=true`.
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/entity-definitions/import",
+    tag = "entity-definitions",
+    params(
+        ("overwrite" = Option<bool>, Query, description = "Replace an existing definition with the same entity type (default: false)")
+    ),
+    request_body = EntityDefinitionBundle,
+    responses(
+        (status = 201, description = "Entity definition created", body = ImportEntityDefinitionResponse),
+        (status = 200, description = "Existing entity definition overwritten", body = ImportEntityDefinitionResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 409, description = "Entity type already exists"),
+        (status = 422, description = "Invalid bundle"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/import")]
+async fn import_entity_definition(
+    data: web::Data<ApiStateWrapper>,
+    query: web::Query<ImportEntityDefinitionQuery>,
+    bundle: web::Json<EntityDefinitionBundle>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    let action = if query.overwrite {
+        PermissionType::Update
+    } else {
+        PermissionType::Create
+    };
+    if !permission_check::check_permission_with_log(
+        &auth.0,
+        &ResourceNamespace::EntityDefinitions,
+        &action,
+        None,
+        "Import entity definition",
+    ) {
+        return ApiResponse::<()>::forbidden(
+            "Insufficient permissions to import entity definition",
+        );
+    }
+    let Ok(actor_uuid) = Uuid::parse_str(&auth.0.sub) else {
+        return ApiResponse::<()>::internal_error("Invalid authentication");
+    };
+
+    match data
+        .entity_definition_service()
+        .import(bundle.into_inner(), query.overwrite, actor_uuid)
+        .await
+    {
+        Ok((uuid, true)) => {
+            ApiResponse::<ImportEntityDefinitionResponse>::created(ImportEntityDefinitionResponse {
+                uuid,
+                created: true,
+            })
+        }
+        Ok((uuid, false)) => ApiResponse::ok(ImportEntityDefinitionResponse {
+            uuid,
+            created: false,
+        }),
+        Err(r_data_core_core::error::Error::ClassAlreadyExists(msg)) => {
+            ApiResponse::<()>::conflict(&msg)
+        }
+        Err(
+            r_data_core_core::error::Error::Validation(msg)
+            | r_data_core_core::error::Error::ValidationFailed(msg),
+        ) => ApiResponse::<()>::unprocessable_entity(&msg),
+        Err(e) => {
+            error!("Failed to import entity definition: {e}");
+            ApiResponse::<()>::internal_error("Failed to import entity definition")
+        }
+    }
+}
+
+/// Rename a field of an entity definition
+///
+/// Renames the column in place so existing values are kept, unlike removing the field and
+/// adding it under the new name.
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/entity-definitions/{uuid}/fields/{field_name}/rename",
+    tag = "entity-definitions",
+    params(
+        ("uuid" = Uuid, Path, description = "Entity definition UUID"),
+        ("field_name" = String, Path, description = "Current field name")
+    ),
+    request_body = RenameFieldRequest,
+    responses(
+        (status = 200, description = "Field renamed", body = EntityDefinitionSchema),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Entity definition not found"),
+        (status = 422, description = "Unknown field, or new name invalid or already taken"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/{uuid}/fields/{field_name}/rename")]
+async fn rename_entity_definition_field(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<(Uuid, String)>,
+    body: web::Json<RenameFieldRequest>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::check_permission_with_log(
+        &auth.0,
+        &ResourceNamespace::EntityDefinitions,
+        &PermissionType::Update,
+        None,
+        "Rename entity definition field",
+    ) {
+        return ApiResponse::<()>::forbidden(
+            "Insufficient permissions to update entity definition",
+        );
+    }
+    let Ok(actor_uuid) = Uuid::parse_str(&auth.0.sub) else {
+        return ApiResponse::<()>::internal_error("Invalid authentication");
+    };
+    let (uuid, field_name) = path.into_inner();
+
+    match data
+        .entity_definition_service()
+        .rename_field(
+            &uuid,
+            &field_name,
+            &body.new_name,
+            body.rename_in_versions,
+            actor_uuid,
+        )
+        .await
+    {
+        Ok(definition) => ApiResponse::ok(entity_definition_to_schema_model(&definition)),
+        Err(r_data_core_core::error::Error::NotFound(_)) => {
+            ApiResponse::<()>::not_found("Entity definition")
+        }
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            ApiResponse::<()>::unprocessable_entity(&msg)
+        }
+        Err(e) => {
+            error!("Failed to rename entity definition field: {e}");
+            ApiResponse::<()>::internal_error("Failed to rename field")
+        }
+    }
+}
+
+/// Reorder the fields of an entity definition
+///
+/// The listed fields come first, in the given order; the remaining fields follow in their
+/// current order. Only the stored definition changes, not the entity table.
+#[utoipa::path(
+    put,
+    path = "/admin/api/v1/entity-definitions/{uuid}/fields/order",
+    tag = "entity-definitions",
+    params(
+        ("uuid" = Uuid, Path, description = "Entity definition UUID")
+    ),
+    request_body = ReorderFieldsRequest,
+    responses(
+        (status = 200, description = "Fields reordered", body = EntityDefinitionSchema),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Entity definition not found"),
+        (status = 422, description = "Unknown or duplicate field name"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[put("/{uuid}/fields/order")]
+async fn reorder_entity_definition_fields(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<PathUuid>,
+    body: web::Json<ReorderFieldsRequest>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::check_permission_with_log(
+        &auth.0,
+        &ResourceNamespace::EntityDefinitions,
+        &PermissionType::Update,
+        None,
+        "Reorder entity definition fields",
+    ) {
+        return ApiResponse::<()>::forbidden(
+            "Insufficient permissions to update entity definition",
+        );
+    }
+    let Ok(actor_uuid) = Uuid::parse_str(&auth.0.sub) else {
+        return ApiResponse::<()>::internal_error("Invalid authentication");
+    };
+
+    match data
+        .entity_definition_service()
+        .reorder_fields(&path.uuid, &body.fields, actor_uuid)
+        .await
+    {
+        Ok(definition) => ApiResponse::ok(entity_definition_to_schema_model(&definition)),
+        Err(r_data_core_core::error::Error::NotFound(_)) => {
+            ApiResponse::<()>::not_found("Entity definition")
+        }
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            ApiResponse::<()>::unprocessable_entity(&msg)
+        }
+        Err(e) => {
+            error!("Failed to reorder entity definition fields: {e}");
+            ApiResponse::<()>::internal_error("Failed to reorder fields")
+        }
+    }
+}
+
+/// Preview the table changes of the stored entity definition
+///
+/// Lists the columns the stored definition would add, drop or change, e.g. after an earlier
+/// schema application failed. Nothing is applied.
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/entity-definitions/{uuid}/schema/preview",
+    tag = "entity-definitions",
+    params(
+        ("uuid" = Uuid, Path, description = "Entity definition UUID")
+    ),
+    responses(
+        (status = 200, description = "Planned column changes and ALTER TABLE statements", body = SchemaDiff),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Entity definition not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[get("/{uuid}/schema/preview")]
+async fn get_entity_definition_schema_preview(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<PathUuid>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    schema_preview(&data, &auth, &path.uuid, None).await
+}
+
+/// Preview the table changes of an updated entity definition
+///
+/// Takes the same body as `PUT /admin/api/v1/entity-definitions/{uuid}` and lists the
+/// columns updating the definition would add, drop or change. Nothing is applied.
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/entity-definitions/{uuid}/schema/preview",
+    tag = "entity-definitions",
+    params(
+        ("uuid" = Uuid, Path, description = "Entity definition UUID")
+    ),
+    request_body = EntityDefinitionSchema,
+    responses(
+        (status = 200, description = "Planned column changes and ALTER TABLE statements", body = SchemaDiff),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Entity definition not found"),
+        (status = 422, description = "Validation failed"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/{uuid}/schema/preview")]
+async fn preview_entity_definition_schema(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<PathUuid>,
+    definition: web::Json<EntityDefinition>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    schema_preview(&data, &auth, &path.uuid, Some(definition.into_inner())).await
+}
+
+/// Diff the entity table of `uuid` against `updated`, or the stored definition without it
+async fn schema_preview(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &RequiredAuth,
+    uuid: &Uuid,
+    updated: Option<EntityDefinition>,
+) -> HttpResponse {
+    if !permission_check::check_permission_with_log(
+        &auth.0,
+        &ResourceNamespace::EntityDefinitions,
+        &PermissionType::Read,
+        None,
+        "Preview entity definition schema",
+    ) {
+        return ApiResponse::<()>::forbidden(
+            "Insufficient permissions to preview entity definition schema",
+        );
+    }
+
+    let service = data.entity_definition_service();
+    let existing = match service.get_entity_definition(uuid).await {
+        Ok(definition) => definition,
+        Err(r_data_core_core::error::Error::NotFound(_)) => {
+            return ApiResponse::<()>::not_found("Entity definition");
+        }
+        Err(e) => {
+            error!("Failed to retrieve entity definition: {e}");
+            return ApiResponse::<()>::internal_error("Failed to retrieve entity definition");
+        }
+    };
+
+    let definition = match updated {
+        Some(mut definition) => {
+            // The entity type, and with it the table, cannot change on update
+            definition.uuid = *uuid;
+            definition.entity_type = existing.entity_type;
+            definition.table_name = existing.table_name;
+            if let Err(e) = definition.validate() {
+                return ApiResponse::<()>::unprocessable_entity(&format!("Validation failed: {e}"));
+            }
+            definition
+        }
+        None => existing,
+    };
+
+    match service.preview_schema(&definition).await {
+        Ok(diff) => ApiResponse::ok(diff),
+        Err(e) => {
+            error!("Failed to preview schema: {e}");
+            ApiResponse::<()>::internal_error("Failed to preview schema")
+        }
+    }
+}
+
 /// Register routes for entity definitions
 pub fn register_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(list_entity_definitions)
@@ -511,6 +902,12 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
         .service(update_entity_definition)
         .service(delete_entity_definition)
         .service(apply_entity_definition_schema)
+        .service(import_entity_definition)
+        .service(export_entity_definition)
+        .service(get_entity_definition_schema_preview)
+        .service(rename_entity_definition_field)
+        .service(reorder_entity_definition_fields)
+        .service(preview_entity_definition_schema)
         .service(list_entity_fields_by_type)
         .service(list_entity_definition_versions)
         .service(get_entity_definition_version);
@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::admin::workflows::models::WorkflowRunStatsResponse;
 use r_data_core_persistence::dashboard_stats_repository_trait::{
     DashboardStats as RepoDashboardStats, EntityStats as RepoEntityStats,
     EntityTypeCount as RepoEntityTypeCount, WorkflowStats as RepoWorkflowStats,
@@ -45,6 +46,8 @@ pub struct WorkflowStats {
     pub total: i64,
     /// List of workflows with their latest run status
     pub workflows: Vec<WorkflowWithLatestStatus>,
+    /// Run statistics across all workflows
+    pub runs: WorkflowRunStatsResponse,
 }
 
 /// Dashboard statistics response
@@ -94,6 +97,7 @@ impl From<RepoWorkflowStats> for WorkflowStats {
         Self {
             total: repo.total,
             workflows: repo.workflows.into_iter().map(Into::into).collect(),
+            runs: repo.runs.into(),
         }
     }
 }
@@ -4,12 +4,16 @@ pub mod api_keys;
 pub mod auth;
 pub mod dsl;
 pub mod email_templates;
+pub mod entities;
+pub mod entity_aggregates;
 pub mod entity_definitions;
 pub mod meta;
+pub mod notifications;
 pub mod permissions;
 pub mod query_helpers;
 pub mod system;
 pub mod users;
+pub mod webhooks;
 pub mod workflows;
 
 use actix_web::web;
@@ -23,6 +27,8 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
             .service(
                 web::scope("/entity-definitions").configure(entity_definitions::register_routes),
             )
+            .service(web::scope("/entities").configure(entities::register_routes))
+            .service(web::scope("/entity-aggregates").configure(entity_aggregates::register_routes))
             .service(web::scope("/workflows").configure(workflows::register_routes))
             .service(web::scope("/dsl").configure(dsl::register_routes))
             .service(web::scope("/api-keys").configure(api_keys::register_routes))
@@ -30,6 +36,8 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
             .service(web::scope("/users").configure(users::register_routes))
             .service(web::scope("/system").configure(system::register_routes))
             .service(web::scope("/email-templates").configure(email_templates::register_routes))
+            .service(web::scope("/webhooks").configure(webhooks::register_routes))
+            .service(web::scope("/notifications").configure(notifications::register_routes))
             .service(web::scope("/meta").configure(meta::register_routes)),
     );
 }
@@ -0,0 +1,52 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use r_data_core_core::notification::Notification;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Query parameters for listing the caller's notifications
+#[derive(Debug, Default, Deserialize, IntoParams, ToSchema, TS)]
+#[ts(export)]
+pub struct NotificationListQuery {
+    /// Also list notifications that were already read (default: false)
+    #[serde(default)]
+    pub include_read: bool,
+}
+
+/// Notification response DTO
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct NotificationResponse {
+    /// Notification UUID
+    #[ts(type = "string")]
+    pub uuid: Uuid,
+    pub title: String,
+    pub message: String,
+    /// Free-form context attached by the sender
+    #[ts(type = "unknown")]
+    pub data: Option<serde_json::Value>,
+    /// ISO 8601 timestamp of when the notification was sent
+    pub sent_at: Option<String>,
+    /// ISO 8601 timestamp of when the notification was read, null while unread
+    pub read_at: Option<String>,
+    /// ISO 8601 creation timestamp
+    pub created_at: String,
+}
+
+impl From<Notification> for NotificationResponse {
+    fn from(n: Notification) -> Self {
+        use time::format_description::well_known::Rfc3339;
+        let format = |t: time::OffsetDateTime| t.format(&Rfc3339).unwrap_or_else(|_| t.to_string());
+        Self {
+            uuid: n.uuid,
+            title: n.title,
+            message: n.message,
+            data: n.data,
+            sent_at: n.sent_at.map(format),
+            read_at: n.read_at.map(format),
+            created_at: format(n.created_at),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use actix_web::{get, post, web, HttpResponse, Responder};
+use uuid::Uuid;
+
+use crate::admin::notifications::models::{NotificationListQuery, NotificationResponse};
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::RequiredAuth;
+use crate::response::ApiResponse;
+use r_data_core_core::error::Error;
+use r_data_core_core::notification::NotificationManager;
+use r_data_core_persistence::NotificationRepository;
+use r_data_core_services::PgNotificationService;
+
+pub(crate) fn notification_service(data: &ApiStateWrapper) -> PgNotificationService {
+    PgNotificationService::new(Arc::new(NotificationRepository::new(
+        data.db_pool().clone(),
+    )))
+}
+
+/// List `user_uuid`'s notifications, shared by the admin and public routes
+pub(crate) async fn list_for_user(
+    data: &ApiStateWrapper,
+    user_uuid: Uuid,
+    include_read: bool,
+) -> HttpResponse {
+    match notification_service(data)
+        .get_user_notifications(user_uuid, include_read)
+        .await
+    {
+        Ok(notifications) => {
+            let dtos: Vec<NotificationResponse> = notifications
+                .into_iter()
+                .map(NotificationResponse::from)
+                .collect();
+            ApiResponse::ok(dtos)
+        }
+        Err(e) => {
+            log::error!("Failed to list notifications of user {user_uuid}: {e}");
+            ApiResponse::<()>::internal_error("Failed to list notifications")
+        }
+    }
+}
+
+/// Mark one of `user_uuid`'s notifications as read, shared by the admin and public routes
+pub(crate) async fn mark_read_for_user(
+    data: &ApiStateWrapper,
+    user_uuid: Uuid,
+    uuid: Uuid,
+) -> HttpResponse {
+    match notification_service(data)
+        .mark_as_read(user_uuid, uuid)
+        .await
+    {
+        Ok(()) => ApiResponse::<()>::message("Marked as read"),
+        Err(Error::NotFound(_)) => ApiResponse::<()>::not_found("Notification not found"),
+        Err(e) => {
+            log::error!("Failed to mark notification {uuid} as read: {e}");
+            ApiResponse::<()>::internal_error("Failed to mark notification as read")
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/notifications",
+    tag = "notifications",
+    params(NotificationListQuery),
+    responses(
+        (status = 200, description = "Notifications of the current user, newest first", body = [NotificationResponse]),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[get("")]
+pub async fn list_notifications(
+    data: web::Data<ApiStateWrapper>,
+    query: web::Query<NotificationListQuery>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    let Some(user_uuid) = auth.user_uuid() else {
+        return ApiResponse::<()>::unauthorized("Invalid user ID in token");
+    };
+    list_for_user(&data, user_uuid, query.include_read).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/notifications/{uuid}/read",
+    tag = "notifications",
+    params(("uuid" = Uuid, Path, description = "Notification UUID")),
+    responses(
+        (status = 200, description = "Marked as read"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Notification not found"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[post("/{uuid}/read")]
+pub async fn mark_notification_read(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<Uuid>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    let Some(user_uuid) = auth.user_uuid() else {
+        return ApiResponse::<()>::unauthorized("Invalid user ID in token");
+    };
+    mark_read_for_user(&data, user_uuid, path.into_inner()).await
+}
+
+/// Register notification routes
+pub fn register_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_notifications)
+        .service(mark_notification_read);
+}
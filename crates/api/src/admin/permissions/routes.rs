@@ -42,7 +42,7 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
         ("per_page" = Option<i64>, Query, description = "Number of items per page (default: 20, max: 100, or -1 for unlimited)"),
         ("limit" = Option<i64>, Query, description = "Maximum number of items to return (alternative to per_page)"),
         ("offset" = Option<i64>, Query, description = "Number of items to skip (alternative to page-based pagination)"),
-        ("sort_by" = Option<String>, Query, description = "Field to sort by (e.g., name, description, created_at)"),
+        ("sort_by" = Option<String>, Query, description = "Field to sort by (e.g., name, description, created_at); comma-separate several fields with optional directions, e.g. name:asc,created_at:desc"),
         ("sort_order" = Option<String>, Query, description = "Sort order: 'asc' or 'desc' (default: 'asc')")
     ),
     responses(
@@ -86,7 +86,7 @@ pub async fn list_roles(
 
             let responses: Vec<RoleResponse> = roles.iter().map(RoleResponse::from).collect();
 
-            ApiResponse::ok_paginated(responses, total, validated.page, validated.per_page)
+            ApiResponse::ok_paginated_list(responses, total, &validated)
         }
         Err(e) => {
             error!("Failed to list roles: {e}");
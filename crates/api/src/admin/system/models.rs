@@ -1,10 +1,14 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use r_data_core_core::audit_log::AuditAction;
 use r_data_core_core::system_log::{SystemLogResourceType, SystemLogStatus, SystemLogType};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use utoipa::ToSchema;
 
+use r_data_core_persistence::{MigrationService, MigrationStatus};
+use r_data_core_services::SchemaReconcileAction;
+
 use r_data_core_core::settings::{
     EntityVersioningSettings, OutboxSettings, WorkflowRunLogSettings,
 };
@@ -307,6 +311,83 @@ impl From<r_data_core_core::system_log::SystemLog> for SystemLogDto {
     }
 }
 
+/// Query parameters for filtering the audit log
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct AuditLogQuery {
+    /// Page number (1-based, default: 1)
+    pub page: Option<i64>,
+    /// Items per page (default: 20, max: 100)
+    pub page_size: Option<i64>,
+    /// Filter by the UUID of the user that made the change
+    pub actor_uuid: Option<String>,
+    /// Filter by resource type
+    #[ts(type = "string | null")]
+    pub resource_type: Option<SystemLogResourceType>,
+    /// Filter by resource UUID
+    pub resource_uuid: Option<String>,
+    /// Filter entries created after this timestamp (ISO 8601)
+    pub date_from: Option<String>,
+    /// Filter entries created before this timestamp (ISO 8601)
+    pub date_to: Option<String>,
+}
+
+impl AuditLogQuery {
+    /// Convert to (limit, offset, page, `per_page`) with defaults
+    #[must_use]
+    pub fn to_pagination(&self) -> (i64, i64, i64, i64) {
+        let per_page = self.page_size.unwrap_or(20).clamp(1, 100);
+        let page = self.page.unwrap_or(1).max(1);
+        let offset = (page - 1) * per_page;
+        (per_page, offset, page, per_page)
+    }
+}
+
+/// Single audit log entry response
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct AuditLogDto {
+    /// Entry UUID
+    pub uuid: String,
+    /// When the change was made
+    pub created_at: String,
+    /// UUID of the user that made the change (if known)
+    pub actor_uuid: Option<String>,
+    /// Kind of change
+    #[ts(type = "string")]
+    pub action: AuditAction,
+    /// Type of the changed resource
+    #[ts(type = "string")]
+    pub resource_type: SystemLogResourceType,
+    /// UUID of the changed resource
+    pub resource_uuid: String,
+    /// Resource before the change (absent for creations)
+    #[ts(type = "unknown")]
+    pub before: Option<serde_json::Value>,
+    /// Resource after the change (absent for deletions)
+    #[ts(type = "unknown")]
+    pub after: Option<serde_json::Value>,
+}
+
+impl From<r_data_core_core::audit_log::AuditLogEntry> for AuditLogDto {
+    fn from(entry: r_data_core_core::audit_log::AuditLogEntry) -> Self {
+        use time::format_description::well_known::Rfc3339;
+        Self {
+            uuid: entry.uuid.to_string(),
+            created_at: entry
+                .created_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| entry.created_at.to_string()),
+            actor_uuid: entry.actor_uuid.map(|u| u.to_string()),
+            action: entry.action,
+            resource_type: entry.resource_type,
+            resource_uuid: entry.resource_uuid.to_string(),
+            before: entry.before,
+            after: entry.after,
+        }
+    }
+}
+
 /// Component version information
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ComponentVersionDto {
@@ -329,3 +410,120 @@ pub struct SystemVersionsDto {
     /// Maintenance component version (if available)
     pub maintenance: Option<ComponentVersionDto>,
 }
+
+/// A database migration, applied or pending
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MigrationDto {
+    /// Migration version number
+    pub version: i64,
+    /// Migration description
+    pub description: String,
+}
+
+/// Applied and pending database migrations
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MigrationStatusDto {
+    /// Migrations recorded in the database, oldest first
+    pub applied: Vec<MigrationDto>,
+    /// Migrations bundled with this build but not applied yet, oldest first
+    pub pending: Vec<MigrationDto>,
+    /// Version of the most recently applied migration
+    pub current_version: Option<i64>,
+    /// Version of the newest migration bundled with this build
+    pub expected_version: Option<i64>,
+    /// Whether every bundled migration has been applied
+    pub up_to_date: bool,
+}
+
+impl From<MigrationStatus> for MigrationStatusDto {
+    fn from(status: MigrationStatus) -> Self {
+        let pending: Vec<MigrationDto> = MigrationService::pending_migrations(&status)
+            .into_iter()
+            .map(|m| MigrationDto {
+                version: m.version,
+                description: m.description,
+            })
+            .collect();
+        Self {
+            current_version: status.current_version(),
+            expected_version: MigrationService::expected_version(),
+            up_to_date: pending.is_empty(),
+            applied: status
+                .applied_migrations
+                .into_iter()
+                .map(|m| MigrationDto {
+                    version: m.version,
+                    description: m.description,
+                })
+                .collect(),
+            pending,
+        }
+    }
+}
+
+/// A mismatch between an entity definition and the entity tables
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SchemaReconcileActionDto {
+    /// `drop_orphan_table`, `create_table` or `sync_columns`
+    pub action: String,
+    /// Entity type of the definition (not set for orphan tables)
+    pub entity_type: Option<String>,
+    /// Affected table
+    pub table_name: String,
+    /// Definition fields without a column
+    pub missing_columns: Vec<String>,
+    /// Columns without a definition field
+    pub extra_columns: Vec<String>,
+}
+
+impl From<SchemaReconcileAction> for SchemaReconcileActionDto {
+    fn from(action: SchemaReconcileAction) -> Self {
+        match action {
+            SchemaReconcileAction::DropOrphanTable { table_name } => Self {
+                action: "drop_orphan_table".to_string(),
+                entity_type: None,
+                table_name,
+                missing_columns: Vec::new(),
+                extra_columns: Vec::new(),
+            },
+            SchemaReconcileAction::CreateTable {
+                entity_type,
+                table_name,
+            } => Self {
+                action: "create_table".to_string(),
+                table_name,
+                entity_type: Some(entity_type),
+                missing_columns: Vec::new(),
+                extra_columns: Vec::new(),
+            },
+            SchemaReconcileAction::SyncColumns {
+                entity_type,
+                table_name,
+                missing_columns,
+                extra_columns,
+            } => Self {
+                action: "sync_columns".to_string(),
+                table_name,
+                entity_type: Some(entity_type),
+                missing_columns,
+                extra_columns,
+            },
+        }
+    }
+}
+
+/// Request body for clearing the cache
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ClearCacheBody {
+    /// Namespace to clear, e.g. `entity_definitions` or `api_keys`; clears everything if unset
+    pub namespace: Option<String>,
+}
+
+/// Result of clearing the cache
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CacheClearedDto {
+    /// Namespace that was cleared (unset for a full clear)
+    pub namespace: Option<String>,
+    /// Number of cache entries removed
+    pub cleared: usize,
+}
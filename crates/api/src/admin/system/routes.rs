@@ -1,9 +1,10 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use crate::admin::system::models::{
-    CapabilitiesResponse, ComponentVersionDto, EntityVersioningSettingsDto, LicenseStatusDto,
-    LicenseVerificationRequest, LicenseVerificationResponse, OutboxSettingsDto, SystemLogDto,
-    SystemLogQuery, SystemVersionsDto, UpdateOutboxSettingsBody, UpdateSettingsBody,
+    AuditLogDto, AuditLogQuery, CacheClearedDto, CapabilitiesResponse, ClearCacheBody,
+    ComponentVersionDto, EntityVersioningSettingsDto, LicenseStatusDto, LicenseVerificationRequest,
+    LicenseVerificationResponse, MigrationStatusDto, OutboxSettingsDto, SchemaReconcileActionDto,
+    SystemLogDto, SystemLogQuery, SystemVersionsDto, UpdateOutboxSettingsBody, UpdateSettingsBody,
     UpdateWorkflowRunLogSettingsBody, WorkflowRunLogSettingsDto,
 };
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
@@ -11,10 +12,14 @@ use crate::auth::auth_enum::RequiredAuth;
 use crate::auth::permission_check;
 use crate::response::ApiResponse;
 use actix_web::{get, post, put, web, Responder};
+use r_data_core_core::error::Error;
 use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
-use r_data_core_persistence::ComponentVersionRepository;
-use r_data_core_persistence::{SystemLogRepository, SystemLogRepositoryTrait};
-use r_data_core_services::SettingsService;
+use r_data_core_persistence::{
+    AuditLogFilter, AuditLogRepository, AuditLogRepositoryTrait, SystemLogRepository,
+    SystemLogRepositoryTrait,
+};
+use r_data_core_persistence::{ComponentVersionRepository, MigrationService};
+use r_data_core_services::{CacheService, SchemaReconcileService, SettingsService};
 use time::format_description::well_known::Rfc3339;
 
 /// Core version from Cargo.toml
@@ -30,9 +35,13 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(update_outbox_settings);
     cfg.service(get_license_status);
     cfg.service(get_system_versions);
+    cfg.service(get_migration_status);
+    cfg.service(get_schema_reconciliation);
+    cfg.service(clear_cache);
     cfg.service(get_capabilities);
     cfg.service(list_system_logs);
     cfg.service(get_system_log);
+    cfg.service(list_audit_logs);
     // Internal endpoint (not in Swagger)
     cfg.service(verify_license_internal);
 }
@@ -81,6 +90,7 @@ pub async fn get_entity_versioning_settings(
     responses(
         (status = 200, description = "Updated entity versioning settings", body = EntityVersioningSettingsDto),
         (status = 401, description = "Unauthorized"),
+        (status = 422, description = "Invalid retention limits"),
         (status = 500, description = "Server error")
     ),
     security(("jwt" = []))
@@ -122,6 +132,10 @@ pub async fn update_entity_versioning_settings(
         current.max_age_days = body.max_age_days;
     }
 
+    if let Err(e) = current.validate() {
+        return ApiResponse::<()>::unprocessable_entity(&e.to_string());
+    }
+
     // Determine user performing the update
     let Some(updated_by) = auth.user_uuid() else {
         return ApiResponse::<()>::internal_error("No authentication claims found for update");
@@ -487,6 +501,144 @@ pub async fn get_system_versions(
     })
 }
 
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/system/migrations",
+    tag = "system",
+    responses(
+        (status = 200, description = "Applied and pending database migrations", body = MigrationStatusDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[get("/migrations")]
+pub async fn get_migration_status(
+    data: web::Data<ApiStateWrapper>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::System,
+        &PermissionType::Admin,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to view migration status");
+    }
+
+    let service = MigrationService::new(data.db_pool().clone());
+    match service.check_status().await {
+        Ok(status) => ApiResponse::ok(MigrationStatusDto::from(status)),
+        Err(e) => {
+            log::error!("Failed to check migration status: {e}");
+            ApiResponse::<()>::internal_error("Failed to check migration status")
+        }
+    }
+}
+
+/// Report mismatches between entity definitions and entity tables
+///
+/// Read-only; fixes are applied by the maintenance worker when `SCHEMA_RECONCILE_FIX` is set.
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/system/schema-reconciliation",
+    tag = "system",
+    responses(
+        (status = 200, description = "Actions needed to bring entity tables in line with their definitions", body = [SchemaReconcileActionDto]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[get("/schema-reconciliation")]
+pub async fn get_schema_reconciliation(
+    data: web::Data<ApiStateWrapper>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::System,
+        &PermissionType::Admin,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden(
+            "Insufficient permissions to view schema reconciliation",
+        );
+    }
+
+    let service = SchemaReconcileService::new(data.db_pool().clone());
+    match service.report().await {
+        Ok(actions) => ApiResponse::ok(
+            actions
+                .into_iter()
+                .map(SchemaReconcileActionDto::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(e) => {
+            log::error!("Failed to compare entity schemas: {e}");
+            ApiResponse::<()>::internal_error("Failed to compare entity schemas")
+        }
+    }
+}
+
+/// Clear the whole cache or a single namespace of it
+///
+/// Cached data is reloaded from the database on next use.
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/system/cache/clear",
+    tag = "system",
+    request_body = ClearCacheBody,
+    responses(
+        (status = 200, description = "Number of cache entries removed", body = CacheClearedDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 422, description = "Unknown cache namespace"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[post("/cache/clear")]
+pub async fn clear_cache(
+    data: web::Data<ApiStateWrapper>,
+    body: web::Json<ClearCacheBody>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::System,
+        &PermissionType::Admin,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to clear the cache");
+    }
+
+    let namespace = body.into_inner().namespace;
+    let service = CacheService::new(data.cache_manager().clone());
+    let result = match namespace.as_deref() {
+        Some(namespace) => service.clear_namespace(namespace).await,
+        None => service.clear_all().await,
+    };
+
+    match result {
+        Ok(cleared) => {
+            log::info!(
+                "Cache cleared by {} ({}): {cleared} entries",
+                auth.0.name,
+                namespace.as_deref().unwrap_or("all namespaces")
+            );
+            ApiResponse::ok(CacheClearedDto { namespace, cleared })
+        }
+        Err(Error::Validation(msg)) => ApiResponse::<()>::unprocessable_entity(&msg),
+        Err(e) => {
+            log::error!("Failed to clear cache: {e}");
+            ApiResponse::<()>::internal_error("Failed to clear cache")
+        }
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/admin/api/v1/system/capabilities",
@@ -669,3 +821,88 @@ pub async fn verify_license_internal(
 
     ApiResponse::ok(verification_result)
 }
+
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/system/audit-logs",
+    tag = "system",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (1-based, default: 1)"),
+        ("page_size" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)"),
+        ("actor_uuid" = Option<String>, Query, description = "Filter by the user that made the change"),
+        ("resource_type" = Option<String>, Query, description = "Filter by resource type"),
+        ("resource_uuid" = Option<String>, Query, description = "Filter by resource UUID"),
+        ("date_from" = Option<String>, Query, description = "Only entries created at or after this timestamp (RFC 3339)"),
+        ("date_to" = Option<String>, Query, description = "Only entries created at or before this timestamp (RFC 3339)")
+    ),
+    responses(
+        (status = 200, description = "Paginated audit log, newest first", body = [AuditLogDto]),
+        (status = 400, description = "Invalid filter"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[get("/audit-logs")]
+pub async fn list_audit_logs(
+    data: web::Data<ApiStateWrapper>,
+    query: web::Query<AuditLogQuery>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::System,
+        &PermissionType::Read,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to view the audit log");
+    }
+
+    let parse_uuid = |value: Option<&str>| {
+        value
+            .filter(|s| !s.is_empty())
+            .map(uuid::Uuid::parse_str)
+            .transpose()
+    };
+    let parse_date = |value: Option<&str>| {
+        value
+            .filter(|s| !s.is_empty())
+            .map(|s| time::OffsetDateTime::parse(s, &Rfc3339))
+            .transpose()
+    };
+
+    let (Ok(actor_uuid), Ok(resource_uuid)) = (
+        parse_uuid(query.actor_uuid.as_deref()),
+        parse_uuid(query.resource_uuid.as_deref()),
+    ) else {
+        return ApiResponse::<()>::bad_request("actor_uuid and resource_uuid must be UUIDs");
+    };
+    let (Ok(date_from), Ok(date_to)) = (
+        parse_date(query.date_from.as_deref()),
+        parse_date(query.date_to.as_deref()),
+    ) else {
+        return ApiResponse::<()>::bad_request("date_from and date_to must be RFC 3339 timestamps");
+    };
+
+    let filter = AuditLogFilter {
+        actor_uuid,
+        resource_type: query.resource_type.clone(),
+        resource_uuid,
+        date_from,
+        date_to,
+    };
+
+    let (limit, offset, page, per_page) = query.to_pagination();
+    let repo = AuditLogRepository::new(data.db_pool().clone());
+    match repo.list_paginated(limit, offset, &filter).await {
+        Ok((entries, total)) => {
+            let dtos: Vec<AuditLogDto> = entries.into_iter().map(AuditLogDto::from).collect();
+            ApiResponse::ok_paginated(dtos, total, page, per_page)
+        }
+        Err(e) => {
+            log::error!("Failed to list audit log: {e}");
+            ApiResponse::<()>::internal_error("Failed to list audit log")
+        }
+    }
+}
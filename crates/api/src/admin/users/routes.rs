@@ -38,7 +38,7 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
         ("per_page" = Option<i64>, Query, description = "Number of items per page (default: 20, max: 100, or -1 for unlimited)"),
         ("limit" = Option<i64>, Query, description = "Maximum number of items to return (alternative to per_page)"),
         ("offset" = Option<i64>, Query, description = "Number of items to skip (alternative to page-based pagination)"),
-        ("sort_by" = Option<String>, Query, description = "Field to sort by (e.g., username, email, created_at)"),
+        ("sort_by" = Option<String>, Query, description = "Field to sort by (e.g., username, email, created_at); comma-separate several fields with optional directions, e.g. name:asc,created_at:desc"),
         ("sort_order" = Option<String>, Query, description = "Sort order: 'asc' or 'desc' (default: 'asc')")
     ),
     responses(
@@ -88,7 +88,7 @@ pub async fn list_users(
                 let role_uuids = repo.get_user_roles(user.uuid).await.unwrap_or_default();
                 responses.push(UserResponse::from_with_roles(user, &role_uuids));
             }
-            ApiResponse::ok_paginated(responses, total, validated.page, validated.per_page)
+            ApiResponse::ok_paginated_list(responses, total, &validated)
         }
         Err(e) => {
             error!("Failed to list users: {e}");
@@ -217,6 +217,9 @@ pub async fn create_user(
         {
             return ApiResponse::conflict(&msg);
         }
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            return ApiResponse::unprocessable_entity(&msg);
+        }
         Err(e) => {
             error!("Failed to create user: {e}");
             return ApiResponse::<()>::internal_error("Failed to create user");
@@ -332,6 +335,21 @@ pub async fn update_user(
         }
     };
 
+    // Update password first so a policy violation leaves the user untouched
+    let service = state.admin_user_service();
+    if let Some(password) = &req.password {
+        match service.set_password(&mut user, password) {
+            Ok(()) => {}
+            Err(r_data_core_core::error::Error::Validation(msg)) => {
+                return ApiResponse::<()>::unprocessable_entity(&msg);
+            }
+            Err(e) => {
+                error!("Failed to hash password: {e}");
+                return ApiResponse::<()>::internal_error("Failed to update password");
+            }
+        }
+    }
+
     // Update fields if provided
     if let Some(email) = &req.email {
         // Check if email is already in use by another user
@@ -381,26 +399,7 @@ pub async fn update_user(
         user.super_admin = super_admin;
     }
 
-    // Update password if provided
-    if let Some(password) = &req.password {
-        use argon2::password_hash::{rand_core::OsRng, PasswordHasher, SaltString};
-        use argon2::Argon2;
-
-        let salt = SaltString::generate(&mut OsRng);
-        let argon2 = Argon2::default();
-        match argon2.hash_password(password.as_bytes(), &salt) {
-            Ok(hash) => {
-                user.password_hash = hash.to_string();
-            }
-            Err(e) => {
-                error!("Failed to hash password: {e}");
-                return ApiResponse::<()>::internal_error("Failed to update password");
-            }
-        }
-    }
-
     // Update user via service (handles audit logging)
-    let service = state.admin_user_service();
     match service.update_user(&user, actor_uuid).await {
         Ok(()) => {
             // Invalidate cache for the updated user
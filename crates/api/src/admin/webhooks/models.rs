@@ -0,0 +1,67 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use r_data_core_core::entity_webhook::EntityWebhookSubscription;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Query parameters for listing webhook subscriptions
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct EntityWebhookListQuery {
+    /// Only list subscriptions of this entity type
+    pub entity_type: Option<String>,
+}
+
+/// Request body for creating a webhook subscription
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct CreateEntityWebhookRequest {
+    /// Entity type whose updates are watched
+    pub entity_type: String,
+    /// HTTP(S) URL the field diff is posted to
+    pub url: String,
+    /// Field names; the webhook fires when any of them changes
+    pub fields: Vec<String>,
+}
+
+/// Webhook subscription response DTO
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct EntityWebhookResponse {
+    /// Subscription UUID
+    #[ts(type = "string")]
+    pub uuid: Uuid,
+    /// Watched entity type
+    pub entity_type: String,
+    /// Delivery URL
+    pub url: String,
+    /// Watched field names
+    pub fields: Vec<String>,
+    /// Whether deliveries are enqueued for this subscription
+    pub active: bool,
+    /// ISO 8601 creation timestamp
+    pub created_at: String,
+    /// Creator UUID
+    #[ts(type = "string | null")]
+    pub created_by: Option<Uuid>,
+}
+
+impl From<EntityWebhookSubscription> for EntityWebhookResponse {
+    fn from(s: EntityWebhookSubscription) -> Self {
+        use time::format_description::well_known::Rfc3339;
+        Self {
+            uuid: s.uuid,
+            entity_type: s.entity_type,
+            url: s.url,
+            fields: s.fields,
+            active: s.active,
+            created_at: s
+                .created_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| s.created_at.to_string()),
+            created_by: s.created_by,
+        }
+    }
+}
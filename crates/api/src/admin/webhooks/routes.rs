@@ -0,0 +1,163 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, web, Responder};
+use uuid::Uuid;
+
+use crate::admin::webhooks::models::{
+    CreateEntityWebhookRequest, EntityWebhookListQuery, EntityWebhookResponse,
+};
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::RequiredAuth;
+use crate::auth::permission_check;
+use crate::response::ApiResponse;
+use r_data_core_core::error::Error;
+use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
+use r_data_core_persistence::{EntityWebhookRepository, OutboxRepository};
+use r_data_core_services::EntityWebhookService;
+
+fn webhook_service(data: &ApiStateWrapper) -> EntityWebhookService {
+    let pool = data.db_pool().clone();
+    EntityWebhookService::new(
+        Arc::new(EntityWebhookRepository::new(pool.clone())),
+        Arc::new(OutboxRepository::new(pool)),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/webhooks",
+    tag = "webhooks",
+    params(
+        ("entity_type" = Option<String>, Query, description = "Filter by entity type")
+    ),
+    responses(
+        (status = 200, description = "List of webhook subscriptions", body = [EntityWebhookResponse]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[get("")]
+pub async fn list_webhooks(
+    data: web::Data<ApiStateWrapper>,
+    query: web::Query<EntityWebhookListQuery>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::EntityDefinitions,
+        &PermissionType::Read,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to view webhooks");
+    }
+
+    match webhook_service(&data)
+        .list_subscriptions(query.entity_type.as_deref())
+        .await
+    {
+        Ok(subscriptions) => {
+            let dtos: Vec<EntityWebhookResponse> = subscriptions
+                .into_iter()
+                .map(EntityWebhookResponse::from)
+                .collect();
+            ApiResponse::ok(dtos)
+        }
+        Err(e) => {
+            log::error!("Failed to list webhooks: {e}");
+            ApiResponse::<()>::internal_error("Failed to list webhooks")
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/webhooks",
+    tag = "webhooks",
+    request_body = CreateEntityWebhookRequest,
+    responses(
+        (status = 201, description = "Created"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 422, description = "Invalid URL or field list"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[post("")]
+pub async fn create_webhook(
+    data: web::Data<ApiStateWrapper>,
+    body: web::Json<CreateEntityWebhookRequest>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::EntityDefinitions,
+        &PermissionType::Create,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to create webhooks");
+    }
+
+    match webhook_service(&data)
+        .create_subscription(&body.entity_type, &body.url, &body.fields, auth.user_uuid())
+        .await
+    {
+        Ok(uuid) => ApiResponse::<serde_json::Value>::created(serde_json::json!({ "uuid": uuid })),
+        Err(Error::Validation(msg)) => ApiResponse::<()>::unprocessable_entity(&msg),
+        Err(e) => {
+            log::error!("Failed to create webhook: {e}");
+            ApiResponse::<()>::internal_error("Failed to create webhook")
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/api/v1/webhooks/{uuid}",
+    tag = "webhooks",
+    params(("uuid" = Uuid, Path, description = "Webhook subscription UUID")),
+    responses(
+        (status = 200, description = "Deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error")
+    ),
+    security(("jwt" = []))
+)]
+#[delete("/{uuid}")]
+pub async fn delete_webhook(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<Uuid>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::EntityDefinitions,
+        &PermissionType::Delete,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to delete webhooks");
+    }
+
+    let uuid = path.into_inner();
+    match webhook_service(&data).delete_subscription(uuid).await {
+        Ok(()) => ApiResponse::<()>::message("Deleted"),
+        Err(Error::NotFound(_)) => ApiResponse::<()>::not_found("Webhook not found"),
+        Err(e) => {
+            log::error!("Failed to delete webhook {uuid}: {e}");
+            ApiResponse::<()>::internal_error("Failed to delete webhook")
+        }
+    }
+}
+
+/// Register entity webhook routes
+pub fn register_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_webhooks)
+        .service(create_webhook)
+        .service(delete_webhook);
+}
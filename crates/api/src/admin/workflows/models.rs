@@ -17,6 +17,9 @@ pub struct WorkflowSummary {
     pub kind: String, // Will be WorkflowKind once migrated
     pub enabled: bool,
     pub schedule_cron: Option<String>,
+    /// Scheduled runs are skipped while paused; the schedule is kept
+    #[serde(default)]
+    pub paused: bool,
     /// Indicates if this workflow has a from.api source type (accepts POST, cron disabled)
     #[serde(default)]
     pub has_api_endpoint: bool,
@@ -35,10 +38,16 @@ pub struct WorkflowDetail {
     pub kind: String, // Will be WorkflowKind once migrated
     pub enabled: bool,
     pub schedule_cron: Option<String>,
+    /// Scheduled runs are skipped while paused; the schedule is kept
+    #[serde(default)]
+    pub paused: bool,
     #[ts(type = "unknown")]
     pub config: serde_json::Value,
     #[serde(default)]
     pub versioning_disabled: bool,
+    /// URL notified with a signed payload when a run finishes
+    #[serde(default)]
+    pub completion_webhook_url: Option<String>,
 }
 
 // Re-export from workflow crate
@@ -81,6 +90,66 @@ pub struct WorkflowRunLogDto {
     pub meta: Option<serde_json::Value>,
 }
 
+/// Error of a single record that failed during a workflow run
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct WorkflowRunErrorDto {
+    #[ts(type = "string")]
+    pub uuid: Uuid,
+    /// Zero-based position of the record in the staged input of the run
+    #[ts(type = "number")]
+    pub record_index: i64,
+    pub error_message: String,
+    /// The record as it was fed into the pipeline
+    #[ts(type = "unknown")]
+    pub raw_payload: serde_json::Value,
+    /// ISO 8601 timestamp
+    pub created_at: String,
+}
+
+impl From<r_data_core_workflow::data::WorkflowRunError> for WorkflowRunErrorDto {
+    fn from(e: r_data_core_workflow::data::WorkflowRunError) -> Self {
+        use time::format_description::well_known::Rfc3339;
+        Self {
+            uuid: e.uuid,
+            record_index: e.record_index,
+            error_message: e.error_message,
+            raw_payload: e.raw_payload,
+            created_at: e
+                .created_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| e.created_at.to_string()),
+        }
+    }
+}
+
+/// Progress of a workflow run through its staged items
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct WorkflowRunProgressDto {
+    pub status: String,
+    /// Items processed successfully so far
+    #[ts(type = "number")]
+    pub processed_count: i64,
+    /// Items that failed so far
+    #[ts(type = "number")]
+    pub failed_count: i64,
+    /// Items staged for the run; zero until processing starts
+    #[ts(type = "number")]
+    pub total_count: i64,
+}
+
+impl From<r_data_core_workflow::data::WorkflowRunProgress> for WorkflowRunProgressDto {
+    fn from(p: r_data_core_workflow::data::WorkflowRunProgress) -> Self {
+        Self {
+            status: p.status.as_str().to_string(),
+            processed_count: p.processed_count,
+            failed_count: p.failed_count,
+            total_count: p.total_count,
+        }
+    }
+}
+
 /// Multipart upload body for run-now file upload
 #[derive(Debug, Serialize, ToSchema)]
 pub struct WorkflowRunUpload {
@@ -89,6 +158,106 @@ pub struct WorkflowRunUpload {
     pub file: String,
 }
 
+/// Upcoming fire times of a cron expression
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct CronPreviewResponse {
+    /// Timezone the schedule is evaluated in
+    pub timezone: String,
+    /// Next occurrences as RFC3339 timestamps; empty if the schedule never fires again
+    pub runs: Vec<String>,
+}
+
+/// Query parameters for workflow run metrics
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct WorkflowMetricsQuery {
+    /// Window to aggregate over, in hours or days (e.g. `24h`, `7d`; default `7d`, max `90d`)
+    pub range: Option<String>,
+}
+
+/// Aggregated run metrics of a workflow over a time window
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct WorkflowMetricsResponse {
+    /// Requested window, e.g. `7d`
+    pub range: String,
+    #[ts(type = "number")]
+    pub total_runs: i64,
+    #[ts(type = "number")]
+    pub successful_runs: i64,
+    #[ts(type = "number")]
+    pub failed_runs: i64,
+    /// Successful share of finished runs (0..1); null if no run finished in the window
+    pub success_rate: Option<f64>,
+    /// Average run duration in milliseconds; null if no run finished in the window
+    pub avg_duration_ms: Option<f64>,
+    /// 95th percentile run duration in milliseconds
+    pub p95_duration_ms: Option<f64>,
+    #[ts(type = "number")]
+    pub total_processed_items: i64,
+    #[ts(type = "number")]
+    pub total_failed_items: i64,
+}
+
+/// All-time run statistics of a workflow
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct WorkflowRunStatsResponse {
+    #[ts(type = "number")]
+    pub total_runs: i64,
+    #[ts(type = "number")]
+    pub queued_runs: i64,
+    #[ts(type = "number")]
+    pub running_runs: i64,
+    #[ts(type = "number")]
+    pub successful_runs: i64,
+    #[ts(type = "number")]
+    pub failed_runs: i64,
+    #[ts(type = "number")]
+    pub cancelled_runs: i64,
+    /// Successful share of finished runs (0..1); null if no run finished yet
+    pub success_rate: Option<f64>,
+    /// Runs queued within the last 24 hours
+    #[ts(type = "number")]
+    pub runs_last_24h: i64,
+    #[ts(type = "number")]
+    pub successful_runs_last_24h: i64,
+    #[ts(type = "number")]
+    pub failed_runs_last_24h: i64,
+    /// Average run duration in milliseconds; null if no run finished yet
+    pub avg_duration_ms: Option<f64>,
+    /// 95th percentile run duration in milliseconds
+    pub p95_duration_ms: Option<f64>,
+    /// Status of the most recently queued run
+    pub last_run_status: Option<String>,
+    /// When the most recent run was queued
+    #[serde(with = "time::serde::rfc3339::option")]
+    #[ts(type = "string | null")]
+    pub last_run_at: Option<time::OffsetDateTime>,
+}
+
+impl From<r_data_core_workflow::data::WorkflowRunStats> for WorkflowRunStatsResponse {
+    fn from(stats: r_data_core_workflow::data::WorkflowRunStats) -> Self {
+        Self {
+            success_rate: stats.success_rate(),
+            total_runs: stats.total_runs,
+            queued_runs: stats.queued_runs,
+            running_runs: stats.running_runs,
+            successful_runs: stats.successful_runs,
+            failed_runs: stats.failed_runs,
+            cancelled_runs: stats.cancelled_runs,
+            runs_last_24h: stats.runs_last_24h,
+            successful_runs_last_24h: stats.successful_runs_last_24h,
+            failed_runs_last_24h: stats.failed_runs_last_24h,
+            avg_duration_ms: stats.avg_duration_ms,
+            p95_duration_ms: stats.p95_duration_ms,
+            last_run_status: stats.last_run_status,
+            last_run_at: stats.last_run_at,
+        }
+    }
+}
+
 #[derive(Serialize, ToSchema, TS)]
 #[ts(export)]
 pub struct WorkflowVersionMeta {
@@ -113,3 +282,36 @@ pub struct WorkflowVersionPayload {
     #[ts(type = "unknown")]
     pub data: serde_json::Value,
 }
+
+/// Request body for a workflow dry run
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct WorkflowDryRunRequest {
+    /// Sample input record the workflow is executed on
+    #[ts(type = "unknown")]
+    pub input: serde_json::Value,
+}
+
+/// One output produced by a dry run
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct WorkflowDryRunOutput {
+    /// Output target: `entity`, `format`, `next_step` or `email`
+    pub target: String,
+    /// Whether a real run would write `produced` as an entity (nothing is persisted here)
+    pub would_write_entity: bool,
+    /// Entity definition written to, for entity targets
+    pub entity_definition: Option<String>,
+    /// Entity write mode (`create`, `update`, `create_or_update`), for entity targets
+    pub entity_mode: Option<String>,
+    /// Values the step produced
+    #[ts(type = "unknown")]
+    pub produced: serde_json::Value,
+}
+
+/// Dry-run result: the outputs of every executed step
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export)]
+pub struct WorkflowDryRunResponse {
+    pub outputs: Vec<WorkflowDryRunOutput>,
+}
@@ -1,21 +1,30 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use crate::admin::workflows::models::CronPreviewResponse;
 use crate::auth::auth_enum::RequiredAuth;
 use crate::auth::permission_check;
 use crate::response::ApiResponse;
 use actix_web::{get, web, Responder};
 use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
 use r_data_core_core::utils;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+/// Number of occurrences returned when `count` is not given
+const DEFAULT_PREVIEW_COUNT: usize = 5;
 
 /// Preview next run times for a cron expression
 #[utoipa::path(
     get,
     path = "/admin/api/v1/workflows/cron/preview",
     tag = "workflows",
-    params(("expr" = String, Query, description = "Cron expression")),
+    params(
+        ("expr" = String, Query, description = "Cron expression"),
+        ("count" = Option<usize>, Query, description = "Number of occurrences (default 5, max 20)")
+    ),
     responses(
-        (status = 200, description = "Preview next run times", body = [String]),
-        (status = 422, description = "Invalid cron expression")
+        (status = 200, description = "Preview next run times", body = CronPreviewResponse),
+        (status = 422, description = "Invalid cron expression or count")
     ),
     security(("jwt" = []))
 )]
@@ -41,8 +50,28 @@ pub async fn cron_preview(
         _ => return ApiResponse::<()>::unprocessable_entity("Missing expr parameter"),
     };
 
-    match utils::preview_next(&expr, 5) {
-        Ok(next) => ApiResponse::ok(next),
-        Err(e) => ApiResponse::<()>::unprocessable_entity(&format!("Invalid cron: {e}")),
+    let count = match query.get("count").map(|v| v.trim().parse::<usize>()) {
+        None => DEFAULT_PREVIEW_COUNT,
+        Some(Ok(n)) if n > 0 => n.min(utils::MAX_CRON_PREVIEW_COUNT),
+        Some(_) => {
+            return ApiResponse::<()>::unprocessable_entity("count must be a positive integer")
+        }
+    };
+
+    let runs = match utils::preview_runs_after(&expr, OffsetDateTime::now_utc(), count) {
+        Ok(runs) => runs,
+        Err(e) => return ApiResponse::<()>::unprocessable_entity(&format!("Invalid cron: {e}")),
+    };
+
+    match runs
+        .iter()
+        .map(|run| run.format(&Rfc3339))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(runs) => ApiResponse::ok(CronPreviewResponse {
+            timezone: utils::CRON_TIMEZONE.to_string(),
+            runs,
+        }),
+        Err(e) => ApiResponse::<()>::internal_error(&format!("Failed to format run time: {e}")),
     }
 }
@@ -56,8 +56,10 @@ pub async fn get_workflow_details(
                 kind: format!("{:?}", workflow.kind),
                 enabled: workflow.enabled,
                 schedule_cron: workflow.schedule_cron,
+                paused: workflow.paused,
                 config: workflow.config,
                 versioning_disabled: workflow.versioning_disabled,
+                completion_webhook_url: workflow.completion_webhook_url,
             };
             ApiResponse::ok(detail)
         }
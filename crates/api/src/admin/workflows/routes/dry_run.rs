@@ -0,0 +1,118 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::{post, web, Responder};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::admin::workflows::models::{
+    WorkflowDryRunOutput, WorkflowDryRunRequest, WorkflowDryRunResponse,
+};
+use crate::admin::workflows::routes::utils::handle_workflow_error;
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::RequiredAuth;
+use crate::auth::permission_check;
+use crate::response::{ApiResponse, ValidationViolation};
+use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
+use r_data_core_workflow::dsl::{DslProgram, ToDef};
+
+/// Describe a produced output and whether a real run would persist it
+fn dry_run_output(to: &ToDef, produced: Value) -> WorkflowDryRunOutput {
+    let (target, entity_definition, entity_mode) = match to {
+        ToDef::Format { .. } => ("format", None, None),
+        ToDef::Entity {
+            entity_definition,
+            mode,
+            ..
+        } => (
+            "entity",
+            Some(entity_definition.clone()),
+            serde_json::to_value(mode)
+                .ok()
+                .and_then(|v| v.as_str().map(ToString::to_string)),
+        ),
+        ToDef::NextStep { .. } => ("next_step", None, None),
+        ToDef::Email { .. } => ("email", None, None),
+    };
+    WorkflowDryRunOutput {
+        target: target.to_string(),
+        would_write_entity: entity_definition.is_some(),
+        entity_definition,
+        entity_mode,
+        produced,
+    }
+}
+
+/// Execute a workflow on a sample input without writing entities or delivering outputs
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/workflows/{uuid}/dry-run",
+    tag = "workflows",
+    params(("uuid" = Uuid, Path, description = "Workflow UUID")),
+    request_body = WorkflowDryRunRequest,
+    responses(
+        (status = 200, description = "Outputs the workflow would produce; nothing is persisted", body = WorkflowDryRunResponse),
+        (status = 404, description = "Workflow not found"),
+        (status = 422, description = "Invalid workflow config or execution failed for the input"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("jwt" = []))
+)]
+#[post("/{uuid}/dry-run")]
+pub async fn dry_run_workflow(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<Uuid>,
+    body: web::Json<WorkflowDryRunRequest>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::Workflows,
+        &PermissionType::Execute,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to execute workflows");
+    }
+
+    let uuid = path.into_inner();
+    let workflow = match state.workflow_service().get(uuid).await {
+        Ok(Some(workflow)) => workflow,
+        Ok(None) => return ApiResponse::<()>::not_found("Workflow not found"),
+        Err(e) => return handle_workflow_error(e),
+    };
+
+    let program = match DslProgram::from_config(&workflow.config) {
+        Ok(program) => program,
+        Err(e) => {
+            return ApiResponse::<()>::unprocessable_entity(&format!(
+                "Invalid workflow config: {e}"
+            ))
+        }
+    };
+    if let Err(e) = program.validate() {
+        return ApiResponse::<()>::unprocessable_entity_with_violations(
+            "Invalid DSL",
+            vec![ValidationViolation {
+                field: "dsl".to_string(),
+                message: e.to_string(),
+                code: Some("DSL_INVALID".to_string()),
+            }],
+        );
+    }
+
+    match program.execute(&body.input) {
+        Ok(outputs) => ApiResponse::ok(WorkflowDryRunResponse {
+            outputs: outputs
+                .into_iter()
+                .map(|(to, produced)| dry_run_output(&to, produced))
+                .collect(),
+        }),
+        Err(e) => ApiResponse::<()>::unprocessable_entity_with_violations(
+            "Dry run failed",
+            vec![ValidationViolation {
+                field: "input".to_string(),
+                message: e.to_string(),
+                code: Some("DRY_RUN_FAILED".to_string()),
+            }],
+        ),
+    }
+}
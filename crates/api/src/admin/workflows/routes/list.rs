@@ -92,7 +92,7 @@ pub async fn list_all_workflow_runs(
         ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100, or -1 for unlimited)"),
         ("limit" = Option<i64>, Query, description = "Alternative to per_page"),
         ("offset" = Option<i64>, Query, description = "Alternative to page-based"),
-        ("sort_by" = Option<String>, Query, description = "Field to sort by (e.g., name, enabled, created_at)"),
+        ("sort_by" = Option<String>, Query, description = "Field to sort by (e.g., name, enabled, created_at); comma-separate several fields with optional directions, e.g. name:asc,created_at:desc"),
         ("sort_order" = Option<String>, Query, description = "Sort order: 'asc' or 'desc' (default: 'asc')")
     ),
     responses(
@@ -143,12 +143,13 @@ pub async fn list_workflows(
                         kind: format!("{:?}", workflow.kind),
                         enabled: workflow.enabled,
                         schedule_cron: workflow.schedule_cron,
+                        paused: workflow.paused,
                         has_api_endpoint,
                         versioning_disabled: workflow.versioning_disabled,
                     }
                 })
                 .collect();
-            ApiResponse::ok_paginated(summaries, total, validated.page, validated.per_page)
+            ApiResponse::ok_paginated_list(summaries, total, &validated)
         }
         Err(e) => {
             error!("Failed to list workflows: {e}");
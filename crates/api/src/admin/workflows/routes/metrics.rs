@@ -0,0 +1,116 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::{get, web, Responder};
+use uuid::Uuid;
+
+use crate::admin::workflows::models::{
+    WorkflowMetricsQuery, WorkflowMetricsResponse, WorkflowRunStatsResponse,
+};
+use crate::admin::workflows::routes::utils::handle_workflow_error;
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::RequiredAuth;
+use crate::auth::permission_check;
+use crate::response::ApiResponse;
+use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
+
+/// Window used when no `range` is given
+const DEFAULT_METRICS_RANGE: &str = "7d";
+
+/// Aggregate run metrics of a workflow
+///
+/// Success rate, durations and item totals are computed over runs queued within the range.
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/workflows/{uuid}/metrics",
+    tag = "workflows",
+    params(
+        ("uuid" = Uuid, Path, description = "Workflow UUID"),
+        ("range" = Option<String>, Query, description = "Window in hours or days, e.g. 24h or 7d (default 7d, max 90d)")
+    ),
+    responses(
+        (status = 200, description = "Aggregated run metrics", body = WorkflowMetricsResponse),
+        (status = 404, description = "Workflow not found"),
+        (status = 422, description = "Invalid range")
+    ),
+    security(("jwt" = []))
+)]
+#[get("/{uuid}/metrics")]
+pub async fn get_workflow_metrics(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<Uuid>,
+    query: web::Query<WorkflowMetricsQuery>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::Workflows,
+        &PermissionType::Read,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to view workflow metrics");
+    }
+
+    let range = query
+        .into_inner()
+        .range
+        .unwrap_or_else(|| DEFAULT_METRICS_RANGE.to_string());
+    match state
+        .workflow_service()
+        .run_metrics(path.into_inner(), &range)
+        .await
+    {
+        Ok(metrics) => ApiResponse::ok(WorkflowMetricsResponse {
+            success_rate: metrics.success_rate(),
+            range,
+            total_runs: metrics.total_runs,
+            successful_runs: metrics.successful_runs,
+            failed_runs: metrics.failed_runs,
+            avg_duration_ms: metrics.avg_duration_ms,
+            p95_duration_ms: metrics.p95_duration_ms,
+            total_processed_items: metrics.processed_items,
+            total_failed_items: metrics.failed_items,
+        }),
+        Err(e) => handle_workflow_error(e),
+    }
+}
+
+/// All-time run statistics of a workflow
+///
+/// Counts by status, last-24h counts, durations and the latest run.
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/workflows/{uuid}/stats",
+    tag = "workflows",
+    params(("uuid" = Uuid, Path, description = "Workflow UUID")),
+    responses(
+        (status = 200, description = "Run statistics", body = WorkflowRunStatsResponse),
+        (status = 404, description = "Workflow not found")
+    ),
+    security(("jwt" = []))
+)]
+#[get("/{uuid}/stats")]
+pub async fn get_workflow_run_stats(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<Uuid>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::Workflows,
+        &PermissionType::Read,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden(
+            "Insufficient permissions to view workflow statistics",
+        );
+    }
+
+    match state
+        .workflow_service()
+        .run_statistics(path.into_inner())
+        .await
+    {
+        Ok(run_stats) => ApiResponse::ok(WorkflowRunStatsResponse::from(run_stats)),
+        Err(e) => handle_workflow_error(e),
+    }
+}
@@ -2,8 +2,12 @@
 
 pub mod cron;
 pub mod crud;
+pub mod dry_run;
 pub mod list;
+pub mod metrics;
+pub mod pause;
 pub mod runs;
+pub mod stream;
 pub mod utils;
 pub mod versions;
 
@@ -24,6 +28,14 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
         .service(crud::update_workflow)
         .service(crud::delete_workflow)
         .service(runs::run_workflow_now)
+        .service(runs::list_workflow_run_errors)
+        .service(runs::get_workflow_run_progress)
+        .service(stream::stream_workflow_run)
+        .service(dry_run::dry_run_workflow)
+        .service(pause::pause_workflow)
+        .service(pause::resume_workflow)
+        .service(metrics::get_workflow_metrics)
+        .service(metrics::get_workflow_run_stats)
         .service(versions::list_workflow_versions)
         .service(versions::get_workflow_version);
 }
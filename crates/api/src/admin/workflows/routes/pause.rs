@@ -0,0 +1,86 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::{post, web, HttpResponse, Responder};
+use uuid::Uuid;
+
+use crate::admin::workflows::routes::utils::handle_workflow_error;
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::RequiredAuth;
+use crate::auth::permission_check;
+use crate::response::ApiResponse;
+use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
+
+async fn set_paused(
+    state: &web::Data<ApiStateWrapper>,
+    uuid: Uuid,
+    paused: bool,
+    auth: &RequiredAuth,
+) -> HttpResponse {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::Workflows,
+        &PermissionType::Update,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to update workflows");
+    }
+    let Some(actor_uuid) = auth.user_uuid() else {
+        return ApiResponse::<()>::internal_error("No authentication claims found");
+    };
+
+    match state
+        .workflow_service()
+        .set_paused(uuid, paused, actor_uuid)
+        .await
+    {
+        Ok(()) if paused => ApiResponse::<()>::message("Paused"),
+        Ok(()) => ApiResponse::<()>::message("Resumed"),
+        Err(e) => handle_workflow_error(e),
+    }
+}
+
+/// Pause scheduled runs of a workflow; the cron schedule is kept
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/workflows/{uuid}/pause",
+    tag = "workflows",
+    params(("uuid" = Uuid, Path, description = "Workflow UUID")),
+    responses(
+        (status = 200, description = "Paused"),
+        (status = 404, description = "Workflow not found")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/{uuid}/pause")]
+pub async fn pause_workflow(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<Uuid>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    set_paused(&state, path.into_inner(), true, &auth).await
+}
+
+/// Resume scheduled runs of a paused workflow
+#[utoipa::path(
+    post,
+    path = "/admin/api/v1/workflows/{uuid}/resume",
+    tag = "workflows",
+    params(("uuid" = Uuid, Path, description = "Workflow UUID")),
+    responses(
+        (status = 200, description = "Resumed"),
+        (status = 404, description = "Workflow not found")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/{uuid}/resume")]
+pub async fn resume_workflow(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<Uuid>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    set_paused(&state, path.into_inner(), false, &auth).await
+}
@@ -8,7 +8,9 @@ use log::{error, info};
 use serde_json::json;
 use uuid::Uuid;
 
-use crate::admin::workflows::models::WorkflowRunLogDto;
+use crate::admin::workflows::models::{
+    WorkflowRunErrorDto, WorkflowRunLogDto, WorkflowRunProgressDto,
+};
 use crate::admin::workflows::routes::utils::handle_workflow_error;
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
 use crate::auth::auth_enum::RequiredAuth;
@@ -17,6 +19,7 @@ use crate::query::PaginationQuery;
 use crate::response::ApiResponse;
 use r_data_core_core::error::Error;
 use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
+use r_data_core_workflow::data::requests::RunWorkflowRequest;
 
 /// Extract file from multipart payload
 /// This function processes the multipart stream and returns the file bytes.
@@ -39,14 +42,18 @@ async fn extract_file_from_multipart(mut payload: Multipart) -> Result<Vec<u8>,
 }
 
 /// Trigger a workflow by UUID immediately
+///
+/// An optional `config` body is merged over the stored workflow config for this run only.
 #[utoipa::path(
     post,
     path = "/admin/api/v1/workflows/{uuid}/run",
     tag = "workflows",
     params(("uuid" = Uuid, Path, description = "Workflow UUID")),
+    request_body(content = Option<RunWorkflowRequest>, description = "Optional per-run config override"),
     responses(
         (status = 202, description = "Enqueued"),
-        (status = 404, description = "Workflow not found")
+        (status = 404, description = "Workflow not found"),
+        (status = 422, description = "Invalid config override")
     ),
     security(
         ("jwt" = [])
@@ -56,6 +63,7 @@ async fn extract_file_from_multipart(mut payload: Multipart) -> Result<Vec<u8>,
 pub async fn run_workflow_now(
     state: web::Data<ApiStateWrapper>,
     path: web::Path<Uuid>,
+    body: Option<web::Json<RunWorkflowRequest>>,
     auth: RequiredAuth,
 ) -> impl Responder {
     // Check permission
@@ -69,10 +77,11 @@ pub async fn run_workflow_now(
     }
 
     let uuid = path.into_inner();
+    let config_override = body.and_then(|b| b.into_inner().config);
     match state.workflow_service().get(uuid).await {
         Ok(Some(_)) => match state
             .workflow_service()
-            .enqueue_run_for_fetch(uuid, None)
+            .enqueue_run_for_fetch_with_override(uuid, config_override.as_ref())
             .await
         {
             Ok(run_uuid) => {
@@ -169,7 +178,9 @@ pub async fn run_workflow_now_upload(
                 "staged_items": staged
             }))
         }
-        Err(Error::Validation(msg)) => ApiResponse::<()>::unprocessable_entity(&msg),
+        Err(Error::Validation(msg) | Error::Deserialization(msg)) => {
+            ApiResponse::<()>::unprocessable_entity(&msg)
+        }
         Err(e) => {
             error!(target: "workflows", "run_workflow_now_upload failed: {e:#?}");
             handle_workflow_error(e)
@@ -243,3 +254,103 @@ pub async fn list_workflow_run_logs(
         }
     }
 }
+
+/// List per-record errors of a workflow run
+///
+/// Records that failed during staged processing, ordered by their position in the input.
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/workflows/{uuid}/runs/{run_uuid}/errors",
+    tag = "workflows",
+    params(
+        ("uuid" = Uuid, Path, description = "Workflow UUID"),
+        ("run_uuid" = Uuid, Path, description = "Workflow run UUID"),
+        ("page" = Option<i64>, Query, description = "Page number (1-based, default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 50, max: 200)")
+    ),
+    responses(
+        (status = 200, description = "List workflow run record errors (paginated)", body = [WorkflowRunErrorDto]),
+        (status = 404, description = "Run not found for this workflow")
+    ),
+    security(("jwt" = []))
+)]
+#[get("/{uuid}/runs/{run_uuid}/errors")]
+pub async fn list_workflow_run_errors(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<(Uuid, Uuid)>,
+    query: web::Query<PaginationQuery>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::Workflows,
+        &PermissionType::Read,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden(
+            "Insufficient permissions to view workflow run errors",
+        );
+    }
+
+    let (workflow_uuid, run_uuid) = path.into_inner();
+    let (limit, offset) = query.to_limit_offset(50, 200);
+    let page = query.get_page(1);
+    let per_page = query.get_per_page(50, 200);
+
+    match state
+        .workflow_service()
+        .list_run_errors_paginated(workflow_uuid, run_uuid, limit, offset)
+        .await
+    {
+        Ok((items, total)) => {
+            let errors: Vec<WorkflowRunErrorDto> =
+                items.into_iter().map(WorkflowRunErrorDto::from).collect();
+            ApiResponse::ok_paginated(errors, total, page, per_page)
+        }
+        Err(e) => handle_workflow_error(e),
+    }
+}
+
+/// Get the progress of a workflow run
+///
+/// Processed and failed counts are updated after each batch of staged items, so they can be
+/// polled while the run is still running.
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/workflows/{uuid}/runs/{run_uuid}/progress",
+    tag = "workflows",
+    params(
+        ("uuid" = Uuid, Path, description = "Workflow UUID"),
+        ("run_uuid" = Uuid, Path, description = "Workflow run UUID")
+    ),
+    responses(
+        (status = 200, description = "Workflow run progress", body = WorkflowRunProgressDto),
+        (status = 404, description = "Run not found for this workflow")
+    ),
+    security(("jwt" = []))
+)]
+#[get("/{uuid}/runs/{run_uuid}/progress")]
+pub async fn get_workflow_run_progress(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<(Uuid, Uuid)>,
+    auth: RequiredAuth,
+) -> impl Responder {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::Workflows,
+        &PermissionType::Read,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to view workflow runs");
+    }
+
+    let (workflow_uuid, run_uuid) = path.into_inner();
+    match state
+        .workflow_service()
+        .get_run_progress(workflow_uuid, run_uuid)
+        .await
+    {
+        Ok(progress) => ApiResponse::ok(WorkflowRunProgressDto::from(progress)),
+        Err(e) => handle_workflow_error(e),
+    }
+}
@@ -0,0 +1,258 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::time::Duration;
+
+use actix_web::http::header;
+use actix_web::web::Bytes;
+use actix_web::{get, web, HttpResponse};
+use futures::stream;
+use log::warn;
+use tokio::sync::broadcast::{self, error::RecvError};
+use uuid::Uuid;
+
+use crate::admin::workflows::routes::utils::handle_workflow_error;
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::RequiredAuth;
+use crate::auth::permission_check;
+use crate::response::ApiResponse;
+use r_data_core_core::error::Result;
+use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
+use r_data_core_services::RunEvent;
+use r_data_core_workflow::data::RunStatus;
+
+/// Interval of the database poll catching changes made by other processes
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Most log entries read per poll; older unseen ones are skipped
+const MAX_LOGS_PER_POLL: i64 = 200;
+
+/// State of one run progress stream
+struct RunProgress {
+    state: web::Data<ApiStateWrapper>,
+    run_uuid: Uuid,
+    events: Option<broadcast::Receiver<RunEvent>>,
+    poll: tokio::time::Interval,
+    status: RunStatus,
+    seen_logs: i64,
+    pending: VecDeque<RunEvent>,
+    finished: bool,
+}
+
+impl RunProgress {
+    /// Next SSE frame; `None` once the terminal status was sent
+    async fn next_frame(&mut self) -> Option<Bytes> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                self.finished |= event.is_terminal();
+                return Some(sse_frame(&event));
+            }
+            if self.finished {
+                return None;
+            }
+
+            // `Some(true)`: poll logs and status, `Some(false)`: poll logs only
+            let poll = tokio::select! {
+                received = recv(&mut self.events) => match received {
+                    Ok(RunEvent::Status { run_uuid, status, processed_items, failed_items, error })
+                        if run_uuid == self.run_uuid =>
+                    {
+                        if status != self.status {
+                            self.status = status;
+                            self.pending.push_back(RunEvent::Status {
+                                run_uuid,
+                                status,
+                                processed_items,
+                                failed_items,
+                                error,
+                            });
+                        }
+                        None
+                    }
+                    Ok(event @ RunEvent::Progress { run_uuid, .. }) if run_uuid == self.run_uuid => {
+                        self.pending.push_back(event);
+                        None
+                    }
+                    // Log entries are read back from the database, so entries are not sent twice
+                    // when a poll and a published event race
+                    Ok(event) => (event.run_uuid() == self.run_uuid).then_some(false),
+                    Err(RecvError::Lagged(_)) => Some(true),
+                    Err(RecvError::Closed) => {
+                        self.events = None;
+                        None
+                    }
+                },
+                _ = self.poll.tick() => Some(true),
+            };
+            let Some(with_status) = poll else {
+                continue;
+            };
+            match self.poll_database(with_status).await {
+                // Keep idle connections (and proxies in between) alive
+                Ok(()) if self.pending.is_empty() => {
+                    return Some(Bytes::from_static(b": ping\n\n"))
+                }
+                Ok(()) => {}
+                Err(e) => warn!("Polling progress of run {} failed: {e}", self.run_uuid),
+            }
+        }
+    }
+
+    /// Queue log entries appended since the last poll and, `with_status`, a changed status
+    async fn poll_database(&mut self, with_status: bool) -> Result<()> {
+        let service = self.state.workflow_service();
+        let (_, total) = service.list_run_logs_paginated(self.run_uuid, 1, 0).await?;
+        if total > self.seen_logs {
+            let (mut logs, _) = service
+                .list_run_logs_paginated(
+                    self.run_uuid,
+                    (total - self.seen_logs).min(MAX_LOGS_PER_POLL),
+                    0,
+                )
+                .await?;
+            // Newest first from the database
+            logs.reverse();
+            self.pending
+                .extend(
+                    logs.into_iter()
+                        .map(|(_, ts, level, message, meta)| RunEvent::Log {
+                            run_uuid: self.run_uuid,
+                            ts,
+                            level,
+                            message,
+                            meta,
+                        }),
+                );
+            self.seen_logs = total;
+        }
+
+        if !with_status {
+            return Ok(());
+        }
+        if let Some(status) = service
+            .get_run_status(self.run_uuid)
+            .await?
+            .and_then(|status| RunStatus::from_str(&status).ok())
+        {
+            if status != self.status {
+                self.status = status;
+                self.pending.push_back(status_event(self.run_uuid, status));
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn recv(
+    events: &mut Option<broadcast::Receiver<RunEvent>>,
+) -> std::result::Result<RunEvent, RecvError> {
+    match events {
+        Some(events) => events.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+const fn status_event(run_uuid: Uuid, status: RunStatus) -> RunEvent {
+    RunEvent::Status {
+        run_uuid,
+        status,
+        processed_items: None,
+        failed_items: None,
+        error: None,
+    }
+}
+
+fn sse_frame(event: &RunEvent) -> Bytes {
+    let data = serde_json::to_string(event).unwrap_or_default();
+    Bytes::from(format!("event: {}\ndata: {data}\n\n", event.name()))
+}
+
+/// Stream progress of a workflow run as server-sent events
+///
+/// Sends the current status first, then `status` events on transitions, `log` events for
+/// appended log entries and `progress` events after each processed batch of staged items. The
+/// stream ends after the terminal (`success`, `failed`, `cancelled`) status event. Runs executed
+/// by this process are reported as they happen, runs handled by a worker are picked up by
+/// polling every two seconds (status and logs only).
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/workflows/{uuid}/runs/{run_uuid}/stream",
+    tag = "workflows",
+    params(
+        ("uuid" = Uuid, Path, description = "Workflow UUID"),
+        ("run_uuid" = Uuid, Path, description = "Workflow run UUID")
+    ),
+    responses(
+        (status = 200, description = "Run progress events", content_type = "text/event-stream", body = String),
+        (status = 404, description = "Run not found for this workflow")
+    ),
+    security(("jwt" = []))
+)]
+#[get("/{uuid}/runs/{run_uuid}/stream")]
+pub async fn stream_workflow_run(
+    state: web::Data<ApiStateWrapper>,
+    path: web::Path<(Uuid, Uuid)>,
+    auth: RequiredAuth,
+) -> HttpResponse {
+    if !permission_check::has_permission(
+        &auth.0,
+        &ResourceNamespace::Workflows,
+        &PermissionType::Read,
+        None,
+    ) {
+        return ApiResponse::<()>::forbidden("Insufficient permissions to view workflow runs");
+    }
+
+    let (workflow_uuid, run_uuid) = path.into_inner();
+    // Subscribe before reading the current state so no transition falls in between
+    let events = state
+        .workflow_service()
+        .run_events()
+        .map(r_data_core_services::RunEventBus::subscribe);
+    let status = match state
+        .workflow_service()
+        .get_workflow_run_status(workflow_uuid, run_uuid)
+        .await
+    {
+        Ok(status) => status,
+        Err(e) => return handle_workflow_error(e),
+    };
+    let Ok(status) = RunStatus::from_str(&status) else {
+        return ApiResponse::<()>::internal_error("Invalid workflow run status");
+    };
+    let seen_logs = match state
+        .workflow_service()
+        .list_run_logs_paginated(run_uuid, 1, 0)
+        .await
+    {
+        Ok((_, total)) => total,
+        Err(e) => return handle_workflow_error(e),
+    };
+
+    let mut poll = tokio::time::interval(POLL_INTERVAL);
+    poll.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // The first tick completes immediately; the initial state is sent without polling
+    poll.reset();
+    let progress = RunProgress {
+        state,
+        run_uuid,
+        events,
+        poll,
+        status,
+        seen_logs,
+        pending: VecDeque::from([status_event(run_uuid, status)]),
+        finished: false,
+    };
+    let body = stream::unfold(progress, |mut progress| async move {
+        progress
+            .next_frame()
+            .await
+            .map(|frame| (Ok::<_, actix_web::Error>(frame), progress))
+    });
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .streaming(body)
+}
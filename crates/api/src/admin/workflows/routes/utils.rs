@@ -4,7 +4,7 @@ use actix_web::HttpResponse;
 use log::error;
 use serde_json::Value;
 
-use crate::response::ApiResponse;
+use crate::response::{ApiResponse, ValidationViolation};
 use r_data_core_core::error::Error;
 
 /// Check if a workflow config has from.api source type (accepts POST, cron disabled)
@@ -80,7 +80,14 @@ pub(crate) fn handle_workflow_error(err: Error) -> HttpResponse {
             error!("Database error in workflow operation: {err}");
             ApiResponse::<()>::internal_error("Database error")
         }
-        Error::Validation(msg) => ApiResponse::<()>::unprocessable_entity(&msg),
+        // Invalid DSL configurations surface as `Deserialization` (parse) or `Validation` errors
+        Error::Validation(msg) | Error::Deserialization(msg) => {
+            ApiResponse::<()>::unprocessable_entity(&msg)
+        }
+        Error::FieldValidation(errors) => ApiResponse::<()>::unprocessable_entity_with_violations(
+            "Validation failed",
+            ValidationViolation::from_field_errors(&errors),
+        ),
         Error::NotFound(msg) => ApiResponse::<()>::not_found(&msg),
         Error::Api(msg) => {
             error!("API error in workflow operation: {msg}");
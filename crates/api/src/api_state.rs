@@ -2,6 +2,7 @@ use actix_web::web;
 use sqlx::PgPool;
 
 use crate::health;
+use crate::metrics;
 use crate::middleware;
 use crate::response::ApiResponse;
 
@@ -232,7 +233,10 @@ async fn not_found_handler() -> impl actix_web::Responder {
 pub fn configure_app_with_options(cfg: &mut web::ServiceConfig, options: &ApiConfiguration) {
     // Add health check endpoints
     cfg.service(health::admin_health_check)
-        .service(health::public_health_check);
+        .service(health::admin_readiness_check)
+        .service(health::public_health_check)
+        .service(health::public_readiness_check)
+        .service(metrics::metrics_endpoint);
 
     let mut scope = web::scope("").wrap(middleware::ErrorHandler);
 
@@ -1,5 +1,6 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use r_data_core_core::admin_user::ApiKey;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
@@ -11,4 +12,30 @@ pub struct ApiKeyInfo {
     pub name: String,
     pub created_at: OffsetDateTime,
     pub expires_at: Option<OffsetDateTime>,
+    /// Public API scopes of the key; empty means unrestricted
+    pub scopes: Vec<String>,
+    /// Requests the key may make per minute; `None` means unlimited
+    pub rate_limit_per_minute: Option<i32>,
+}
+
+impl ApiKeyInfo {
+    /// Info of a validated `key` owned by `user_uuid`
+    #[must_use]
+    pub fn new(key: ApiKey, user_uuid: Uuid) -> Self {
+        Self {
+            uuid: key.uuid,
+            user_uuid,
+            name: key.name,
+            created_at: key.created_at,
+            expires_at: key.expires_at,
+            scopes: key.scopes,
+            rate_limit_per_minute: key.rate_limit_per_minute,
+        }
+    }
+
+    /// Whether the key may be used for `scope`; keys without scopes are unrestricted
+    #[must_use]
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.is_empty() || self.scopes.iter().any(|s| s == scope)
+    }
 }
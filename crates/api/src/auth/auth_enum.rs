@@ -1,14 +1,13 @@
 use actix_web::{
     dev::Payload, error::ErrorUnauthorized, web, Error, FromRequest, HttpMessage, HttpRequest,
 };
-use futures::future::{ready, Ready};
 use log::debug;
 use std::future::Future;
 use std::pin::Pin;
 use uuid::Uuid;
 
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
-use crate::auth::{extract_and_validate_api_key, extract_jwt_token_string, ApiKeyInfo};
+use crate::auth::{extract_and_validate_api_key, extract_and_validate_jwt, ApiKeyInfo};
 use r_data_core_core::admin_jwt::AuthUserClaims;
 use r_data_core_core::entity_jwt::EntityAuthClaims;
 
@@ -18,34 +17,30 @@ pub enum AuthMethod {
     ApiKey,
 }
 
-/// Extract and verify JWT from the Authorization header
-fn extract_jwt_from_request(req: &HttpRequest) -> Option<AuthUserClaims> {
-    if let Some(state) = req.app_data::<web::Data<ApiStateWrapper>>() {
-        if let Some(token) = extract_jwt_token_string(req) {
-            match r_data_core_core::admin_jwt::verify_jwt(token, state.jwt_secret()) {
-                Ok(claims) => {
-                    let name = &claims.name;
-                    debug!("JWT validation successful for user: {name}");
-                    return Some(claims);
-                }
-                Err(e) => {
-                    debug!("JWT validation failed: {e:?}");
-                }
-            }
+/// Extract and verify JWT from the Authorization header, rejecting revoked tokens
+#[allow(clippy::future_not_send)] // Takes &HttpRequest which is !Send
+async fn extract_jwt_from_request(req: &HttpRequest) -> Option<AuthUserClaims> {
+    let state = req.app_data::<web::Data<ApiStateWrapper>>()?;
+    match extract_and_validate_jwt(req, state.jwt_secret()).await {
+        Ok(claims) => claims,
+        Err(e) => {
+            debug!("JWT validation failed: {e:?}");
+            None
         }
     }
-    None
 }
 
 /// Safely get JWT claims from request by first checking extensions
-fn get_or_validate_jwt(req: &HttpRequest) -> Option<AuthUserClaims> {
+#[allow(clippy::future_not_send)] // Takes &HttpRequest which is !Send
+async fn get_or_validate_jwt(req: &HttpRequest) -> Option<AuthUserClaims> {
     // First, check extensions without modifying them
-    if let Some(claims) = req.extensions().get::<AuthUserClaims>() {
-        return Some(claims.clone());
+    let claims = req.extensions().get::<AuthUserClaims>().cloned();
+    if claims.is_some() {
+        return claims;
     }
 
     // If not found in extensions, try to extract from the header
-    extract_jwt_from_request(req)
+    extract_jwt_from_request(req).await
 }
 
 /// Extractor for required authentication
@@ -57,15 +52,18 @@ pub struct OptionalAuth(pub Option<AuthUserClaims>);
 
 impl FromRequest for RequiredAuth {
     type Error = Error;
-    type Future = Ready<Result<Self, Self::Error>>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
 
     fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
         debug!("Handling required authentication FromRequest");
 
-        get_or_validate_jwt(req).map_or_else(
-            || ready(Err(ErrorUnauthorized("Authentication required"))),
-            |claims| ready(Ok(Self(claims))),
-        )
+        let req = req.clone();
+        Box::pin(async move {
+            get_or_validate_jwt(&req)
+                .await
+                .map(Self)
+                .ok_or_else(|| ErrorUnauthorized("Authentication required"))
+        })
     }
 }
 
@@ -80,14 +78,14 @@ impl RequiredAuth {
 
 impl FromRequest for OptionalAuth {
     type Error = Error;
-    type Future = Ready<Result<Self, Self::Error>>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
 
     fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
         debug!("Handling optional authentication FromRequest");
 
         // Return option based on whether claims were found
-        let claims = get_or_validate_jwt(req);
-        ready(Ok(Self(claims)))
+        let req = req.clone();
+        Box::pin(async move { Ok(Self(get_or_validate_jwt(&req).await)) })
     }
 }
 
@@ -113,7 +111,7 @@ impl FromRequest for CombinedRequiredAuth {
 
         Box::pin(async move {
             // Check for JWT auth first
-            if let Some(jwt_claims) = get_or_validate_jwt(&req) {
+            if let Some(jwt_claims) = get_or_validate_jwt(&req).await {
                 return Ok(Self {
                     jwt_claims: Some(jwt_claims),
                     api_key_info: None,
@@ -143,24 +141,12 @@ impl FromRequest for CombinedRequiredAuth {
                     // Try to validate an API key
                     match extract_and_validate_api_key(&req).await {
                         Ok(Some((key, user_uuid))) => {
-                            let key_uuid = key.uuid;
-                            req.extensions_mut().insert(ApiKeyInfo {
-                                uuid: key_uuid,
-                                user_uuid,
-                                name: key.name.clone(),
-                                created_at: key.created_at,
-                                expires_at: key.expires_at,
-                            });
+                            let api_key_info = ApiKeyInfo::new(key, user_uuid);
+                            req.extensions_mut().insert(api_key_info.clone());
 
                             return Ok(Self {
                                 jwt_claims: None,
-                                api_key_info: Some(ApiKeyInfo {
-                                    uuid: key_uuid,
-                                    user_uuid,
-                                    name: key.name.clone(),
-                                    created_at: key.created_at,
-                                    expires_at: key.expires_at,
-                                }),
+                                api_key_info: Some(api_key_info),
                                 pre_shared_key_valid: false,
                                 entity_jwt_claims: None,
                             });
@@ -169,7 +155,9 @@ impl FromRequest for CombinedRequiredAuth {
                             debug!("API key not found or invalid");
                         }
                         Err(e) => {
+                            // e.g. a key used from an address outside its allowed ranges
                             debug!("API key validation error: {e:?}");
+                            return Err(e);
                         }
                     }
                 }
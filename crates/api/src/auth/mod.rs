@@ -2,8 +2,12 @@ pub mod api_key_info;
 pub mod auth_enum;
 pub mod permission_check;
 pub mod permission_required;
+pub mod revocation;
 pub mod utils;
 
 pub use api_key_info::ApiKeyInfo;
 pub use permission_required::{check_permission_and_respond, RequiredAuthExt};
-pub use utils::{extract_and_validate_api_key, extract_and_validate_jwt, extract_jwt_token_string};
+pub use utils::{
+    extract_and_validate_api_key, extract_and_validate_jwt, extract_jwt_token_string,
+    get_or_validate_api_key,
+};
@@ -3,6 +3,7 @@
 use log::debug;
 use r_data_core_core::admin_jwt::AuthUserClaims;
 use r_data_core_core::permissions::role::{PermissionType, ResourceNamespace};
+use r_data_core_core::permissions::roles_allow_entity;
 
 /// Check if a user has permission to perform an action
 ///
@@ -180,6 +181,55 @@ pub async fn has_permission_for_api_key(
     Ok(false)
 }
 
+/// Check if a user has permission to perform an action on entities of `entity_type`
+///
+/// `has_permission` only honours permissions covering every entity type. Here the user's
+/// roles are loaded and resolved by resource pattern, so `entities:Customer` grants
+/// `Customer` only and a more specific permission with access level `None` denies despite a
+/// broader grant.
+///
+/// # Arguments
+/// * `claims` - JWT claims of the user
+/// * `entity_type` - Entity type being accessed
+/// * `permission_type` - Permission type (read, create, update, delete, etc.)
+/// * `path` - Optional path constraint
+/// * `role_service` - Role service for loading roles
+/// * `admin_user_repo` - Admin user repository for loading role assignments
+///
+/// # Errors
+/// Returns an error if the user's roles cannot be loaded
+pub async fn has_entity_permission(
+    claims: &AuthUserClaims,
+    entity_type: &str,
+    permission_type: &PermissionType,
+    path: Option<&str>,
+    role_service: &r_data_core_services::RoleService,
+    admin_user_repo: &r_data_core_persistence::AdminUserRepository,
+) -> r_data_core_core::error::Result<bool> {
+    if claims.is_super_admin {
+        return Ok(true);
+    }
+    let Ok(user_uuid) = uuid::Uuid::parse_str(&claims.sub) else {
+        return Ok(false);
+    };
+
+    let roles = role_service
+        .get_roles_for_user(user_uuid, admin_user_repo)
+        .await?;
+    let allowed = roles_allow_entity(&roles, entity_type, permission_type, path);
+
+    debug!(
+        "Permission check: user '{}' {} permission for entities:{}:{} (path: {:?})",
+        claims.name,
+        if allowed { "has" } else { "does not have" },
+        entity_type,
+        permission_type,
+        path
+    );
+
+    Ok(allowed)
+}
+
 /// Check if a user has permission to perform an action and log the result
 ///
 /// This is a convenience wrapper around `has_permission` that also logs
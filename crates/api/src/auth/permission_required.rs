@@ -29,6 +29,7 @@ use crate::response::ApiResponse;
 /// let auth = RequiredAuth(AuthUserClaims {
 ///     sub: user_uuid.to_string(),
 ///     iss: String::new(),
+///     jti: String::new(),
 ///     name: "test_user".to_string(),
 ///     email: "test@example.com".to_string(),
 ///     is_super_admin: false,
@@ -112,6 +113,7 @@ impl FromRequest for PermissionRequired {
 ///     auth: RequiredAuth(AuthUserClaims {
 ///         sub: "".to_string(),
 ///         iss: String::new(),
+///         jti: String::new(),
 ///         name: "".to_string(),
 ///         email: "".to_string(),
 ///         is_super_admin: false,
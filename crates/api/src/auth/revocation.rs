@@ -0,0 +1,120 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Denylist for admin access tokens revoked before they expire.
+//!
+//! Access tokens are stateless JWTs, so logging out only revokes the refresh token. Revoked
+//! access tokens are remembered by `jti` in the cache until their original expiry; revoking
+//! all sessions of a user also records a cut-off that rejects every token issued before it.
+
+use r_data_core_core::admin_jwt::{AuthUserClaims, ACCESS_TOKEN_EXPIRY_SECONDS};
+use r_data_core_core::cache::CacheManager;
+use time::OffsetDateTime;
+
+const REVOKED_TOKEN_PREFIX: &str = "jwt:revoked:";
+const REVOKED_BEFORE_PREFIX: &str = "jwt:revoked_before:";
+
+/// Seconds until `claims` expires, at least one so the entry is written
+fn remaining_lifetime(claims: &AuthUserClaims) -> u64 {
+    let now = u64::try_from(OffsetDateTime::now_utc().unix_timestamp()).unwrap_or(0);
+    u64::try_from(claims.exp)
+        .unwrap_or(0)
+        .saturating_sub(now)
+        .max(1)
+}
+
+/// Reject the token described by `claims` until it expires
+///
+/// Tokens without a `jti` cannot be revoked individually and are left untouched.
+pub async fn revoke_access_token(cache: &CacheManager, claims: &AuthUserClaims) {
+    if claims.jti.is_empty() {
+        return;
+    }
+    let key = format!("{REVOKED_TOKEN_PREFIX}{}", claims.jti);
+    if let Err(e) = cache
+        .set(&key, &true, Some(remaining_lifetime(claims)))
+        .await
+    {
+        log::warn!("Failed to revoke access token: {e}");
+    }
+}
+
+/// Reject every access token of a user issued before now
+pub async fn revoke_user_access_tokens(cache: &CacheManager, user_uuid: &str) {
+    let key = format!("{REVOKED_BEFORE_PREFIX}{user_uuid}");
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    // Tokens issued before the cut-off expire within one access token lifetime
+    if let Err(e) = cache
+        .set(&key, &now, Some(ACCESS_TOKEN_EXPIRY_SECONDS))
+        .await
+    {
+        log::warn!("Failed to revoke access tokens of user {user_uuid}: {e}");
+    }
+}
+
+/// Whether the token described by `claims` was revoked
+pub async fn is_access_token_revoked(cache: &CacheManager, claims: &AuthUserClaims) -> bool {
+    if !claims.jti.is_empty() {
+        let key = format!("{REVOKED_TOKEN_PREFIX}{}", claims.jti);
+        if matches!(cache.get::<bool>(&key).await, Ok(Some(true))) {
+            return true;
+        }
+    }
+
+    let key = format!("{REVOKED_BEFORE_PREFIX}{}", claims.sub);
+    match cache.get::<i64>(&key).await {
+        // Tokens from the same second as the cut-off are kept, so a login right after
+        // revoking all sessions still works
+        Ok(Some(revoked_before)) => i64::try_from(claims.iat).unwrap_or(i64::MAX) < revoked_before,
+        Ok(None) => false,
+        Err(e) => {
+            log::warn!("Failed to check access token revocation: {e}");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use r_data_core_core::config::CacheConfig;
+
+    fn claims(jti: &str, sub: &str, iat: i64) -> AuthUserClaims {
+        let exp = OffsetDateTime::now_utc().unix_timestamp() + 600;
+        AuthUserClaims {
+            sub: sub.to_string(),
+            iss: String::new(),
+            jti: jti.to_string(),
+            name: "test_user".to_string(),
+            email: "test@example.com".to_string(),
+            is_super_admin: false,
+            permissions: vec![],
+            exp: usize::try_from(exp).unwrap(),
+            iat: usize::try_from(iat).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn revoked_token_is_rejected_and_others_are_not() {
+        let cache = CacheManager::new(CacheConfig::default());
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let revoked = claims("jti-1", "user-1", now);
+        let other = claims("jti-2", "user-1", now);
+
+        revoke_access_token(&cache, &revoked).await;
+
+        assert!(is_access_token_revoked(&cache, &revoked).await);
+        assert!(!is_access_token_revoked(&cache, &other).await);
+    }
+
+    #[tokio::test]
+    async fn revoking_user_tokens_rejects_earlier_tokens_only() {
+        let cache = CacheManager::new(CacheConfig::default());
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+
+        revoke_user_access_tokens(&cache, "user-1").await;
+
+        assert!(is_access_token_revoked(&cache, &claims("a", "user-1", now - 60)).await);
+        assert!(!is_access_token_revoked(&cache, &claims("b", "user-1", now + 1)).await);
+        assert!(!is_access_token_revoked(&cache, &claims("c", "user-2", now - 60)).await);
+    }
+}
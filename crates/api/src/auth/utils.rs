@@ -2,16 +2,19 @@
 #![allow(clippy::future_not_send)] // Functions take &HttpRequest which is !Send
 
 use actix_web::{
-    error::{Error as ActixError, ErrorUnauthorized},
+    error::{Error as ActixError, ErrorForbidden, ErrorUnauthorized},
     http::header,
-    web, HttpRequest,
+    web, HttpMessage, HttpRequest,
 };
 use log::{debug, error};
+use std::net::{IpAddr, SocketAddr};
 use uuid::Uuid;
 
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::revocation::is_access_token_revoked;
+use crate::auth::ApiKeyInfo;
 use r_data_core_core::admin_jwt::{verify_jwt, AuthUserClaims};
-use r_data_core_core::admin_user::ApiKey;
+use r_data_core_core::admin_user::{api_key_cidrs, ApiKey};
 
 use std::result::Result as StdResult;
 
@@ -27,6 +30,8 @@ pub fn extract_jwt_token_string(req: &HttpRequest) -> Option<&str> {
 
 /// Extract and validate JWT token from request headers
 ///
+/// Revoked tokens (see [`crate::auth::revocation`]) are treated like invalid ones.
+///
 /// # Errors
 /// Returns an error if JWT validation fails
 pub async fn extract_and_validate_jwt(
@@ -46,6 +51,12 @@ pub async fn extract_and_validate_jwt(
         return match verify_jwt(token, jwt_secret) {
             Ok(claims) => {
                 let name = &claims.name;
+                if let Some(state) = req.app_data::<web::Data<ApiStateWrapper>>() {
+                    if is_access_token_revoked(state.cache_manager(), &claims).await {
+                        debug!("Rejected revoked JWT for user: {name}");
+                        return Ok(None);
+                    }
+                }
                 debug!("JWT auth successful for user: {name}");
                 Ok(Some(claims))
             }
@@ -61,12 +72,57 @@ pub async fn extract_and_validate_jwt(
     Ok(None)
 }
 
+/// Client IP address of a request
+///
+/// `X-Forwarded-For` is only honored when the socket peer is one of `trusted_proxies`, as
+/// clients can set it freely. Its hops are then read from the right, skipping trusted proxies,
+/// and the first untrusted hop is the client: entries left of it may be forged by the client.
+#[must_use]
+pub fn client_ip(req: &HttpRequest, trusted_proxies: &[String]) -> Option<IpAddr> {
+    let peer = req.peer_addr().map(|addr| addr.ip());
+    let is_trusted = |ip: IpAddr| {
+        trusted_proxies
+            .iter()
+            .any(|cidr| api_key_cidrs::contains(cidr, ip))
+    };
+    let Some(mut client) = peer.filter(|ip| is_trusted(*ip)) else {
+        return peer;
+    };
+
+    let hops: Vec<&str> = req
+        .headers()
+        .get_all("x-forwarded-for")
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect();
+    for hop in hops.into_iter().rev() {
+        // An unreadable hop ends the chain; the last trusted proxy is used instead
+        let Some(ip) = parse_forwarded_hop(hop) else {
+            break;
+        };
+        client = ip;
+        if !is_trusted(ip) {
+            break;
+        }
+    }
+    Some(client)
+}
+
+/// Address of an `X-Forwarded-For` entry, with or without a port
+fn parse_forwarded_hop(hop: &str) -> Option<IpAddr> {
+    hop.parse::<IpAddr>()
+        .ok()
+        .or_else(|| hop.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+}
+
 /// Extract and validate API key from request headers
 /// This function uses `ApiKeyService` with caching support
 /// `ApiState` should always be available in normal operation
 ///
 /// # Errors
-/// Returns an error if API key validation fails or if the API state is missing
+/// Returns an error if API key validation fails, if the key is not allowed from the client IP
+/// (403) or if the API state is missing
 pub async fn extract_and_validate_api_key(
     req: &HttpRequest,
 ) -> StdResult<Option<(ApiKey, Uuid)>, ActixError> {
@@ -85,6 +141,13 @@ pub async fn extract_and_validate_api_key(
             let validation_result = state.api_key_service().validate_api_key(api_key).await;
             match validation_result {
                 Ok(Some((key, user_uuid))) => {
+                    let ip = client_ip(req, &state.api_config().trusted_proxies);
+                    if !key.allows_ip(ip) {
+                        debug!("API key '{}' used from disallowed address {ip:?}", key.name);
+                        return Err(ErrorForbidden(
+                            "API key is not allowed from this IP address",
+                        ));
+                    }
                     debug!("API key authentication successful for user: {user_uuid}");
                     return Ok(Some((key, user_uuid)));
                 }
@@ -116,3 +179,20 @@ pub async fn extract_and_validate_api_key(
 
     Ok(None)
 }
+
+/// API key of the request, validated once and kept in the request extensions
+///
+/// Middleware and extractors running later reuse the stored [`ApiKeyInfo`] instead of
+/// validating the key again. Missing and invalid keys yield `None` and are left to the route,
+/// which answers 401 or 403.
+pub async fn get_or_validate_api_key(req: &HttpRequest) -> Option<ApiKeyInfo> {
+    let info = req.extensions().get::<ApiKeyInfo>().cloned();
+    if info.is_some() {
+        return info;
+    }
+
+    let (key, user_uuid) = extract_and_validate_api_key(req).await.ok()??;
+    let info = ApiKeyInfo::new(key, user_uuid);
+    req.extensions_mut().insert(info.clone());
+    Some(info)
+}
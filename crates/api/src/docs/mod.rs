@@ -14,20 +14,33 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
 #[openapi(
     paths(
         crate::admin::auth::routes::admin_login,
+        crate::admin::auth::routes::admin_two_factor_login,
+        crate::admin::auth::routes::enroll_two_factor,
+        crate::admin::auth::routes::verify_two_factor,
         crate::admin::auth::routes::admin_register,
         crate::admin::auth::routes::admin_logout,
         crate::admin::auth::routes::admin_refresh_token,
         crate::admin::auth::routes::admin_revoke_all_tokens,
         crate::health::admin_health_check,
+        crate::health::admin_readiness_check,
         crate::admin::entity_definitions::routes::list_entity_definitions,
         crate::admin::entity_definitions::routes::get_entity_definition,
         crate::admin::entity_definitions::routes::create_entity_definition,
         crate::admin::entity_definitions::routes::update_entity_definition,
         crate::admin::entity_definitions::routes::delete_entity_definition,
         crate::admin::entity_definitions::routes::apply_entity_definition_schema,
+        crate::admin::entity_definitions::routes::get_entity_definition_schema_preview,
+        crate::admin::entity_definitions::routes::preview_entity_definition_schema,
+        crate::admin::entity_definitions::routes::rename_entity_definition_field,
+        crate::admin::entity_definitions::routes::reorder_entity_definition_fields,
+        crate::admin::entity_definitions::routes::export_entity_definition,
+        crate::admin::entity_definitions::routes::import_entity_definition,
         crate::admin::api_keys::routes::create_api_key,
         crate::admin::api_keys::routes::list_api_keys,
         crate::admin::api_keys::routes::revoke_api_key,
+        crate::admin::api_keys::routes::update_api_key_rate_limit,
+        crate::admin::api_keys::routes::list_expiring_api_keys,
+        crate::admin::api_keys::routes::rotate_api_key,
         crate::admin::workflows::routes::list::list_workflows,
         crate::admin::workflows::routes::crud::get_workflow_details,
         crate::admin::workflows::routes::crud::create_workflow,
@@ -35,8 +48,16 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
         crate::admin::workflows::routes::crud::delete_workflow,
         crate::admin::workflows::routes::runs::run_workflow_now,
         crate::admin::workflows::routes::runs::run_workflow_now_upload,
+        crate::admin::workflows::routes::dry_run::dry_run_workflow,
+        crate::admin::workflows::routes::pause::pause_workflow,
+        crate::admin::workflows::routes::pause::resume_workflow,
+        crate::admin::workflows::routes::metrics::get_workflow_metrics,
+        crate::admin::workflows::routes::metrics::get_workflow_run_stats,
         crate::admin::workflows::routes::list::list_workflow_runs,
         crate::admin::workflows::routes::runs::list_workflow_run_logs,
+        crate::admin::workflows::routes::runs::list_workflow_run_errors,
+        crate::admin::workflows::routes::runs::get_workflow_run_progress,
+        crate::admin::workflows::routes::stream::stream_workflow_run,
         crate::admin::workflows::routes::list::list_all_workflow_runs,
         crate::admin::workflows::routes::cron::cron_preview,
         crate::admin::workflows::routes::versions::list_workflow_versions,
@@ -44,6 +65,7 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
         crate::admin::entity_definitions::routes::list_entity_definition_versions,
         crate::admin::entity_definitions::routes::get_entity_definition_version,
         crate::admin::dsl::routes::validate_dsl,
+        crate::admin::dsl::test_batch::test_dsl_batch,
         crate::admin::dsl::routes::list_from_options,
         crate::admin::dsl::routes::list_to_options,
         crate::admin::dsl::routes::list_transform_options,
@@ -54,14 +76,28 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
         crate::admin::system::routes::get_outbox_settings,
         crate::admin::system::routes::update_outbox_settings,
         crate::admin::system::routes::get_license_status,
+        crate::admin::system::routes::get_migration_status,
+        crate::admin::system::routes::get_schema_reconciliation,
+        crate::admin::system::routes::clear_cache,
         crate::admin::system::routes::get_capabilities,
         crate::admin::system::routes::list_system_logs,
+        crate::admin::system::routes::list_audit_logs,
         crate::admin::system::routes::get_system_log,
         crate::admin::email_templates::routes::list_email_templates,
         crate::admin::email_templates::routes::get_email_template,
         crate::admin::email_templates::routes::create_email_template,
         crate::admin::email_templates::routes::update_email_template,
         crate::admin::email_templates::routes::delete_email_template,
+        crate::admin::webhooks::routes::list_webhooks,
+        crate::admin::webhooks::routes::create_webhook,
+        crate::admin::webhooks::routes::delete_webhook,
+        crate::admin::notifications::routes::list_notifications,
+        crate::admin::notifications::routes::mark_notification_read,
+        crate::admin::entity_aggregates::routes::list_aggregates,
+        crate::admin::entity_aggregates::routes::get_aggregate,
+        crate::admin::entity_aggregates::routes::configure_aggregate,
+        crate::admin::entity_aggregates::routes::rebuild_aggregate,
+        crate::admin::entity_aggregates::routes::delete_aggregate,
         crate::admin::permissions::routes::list_roles,
         crate::admin::permissions::routes::get_role,
         crate::admin::permissions::routes::create_role,
@@ -80,20 +116,40 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
         crate::admin::auth::routes::forgot_password,
         crate::admin::auth::routes::reset_password,
         crate::admin::meta::routes::get_dashboard_stats,
+        crate::admin::entities::routes::restore_entity_version,
+        crate::admin::entities::routes::restore_deleted_entity,
     ),
     components(
         schemas(
             crate::models::HealthData,
+            crate::models::ReadinessData,
+            crate::models::ComponentStatus,
             crate::admin::entity_definitions::models::EntityDefinitionSchema,
             crate::admin::entity_definitions::models::PathUuid,
             crate::admin::entity_definitions::models::ApplySchemaRequest,
+            r_data_core_core::entity_definition::SchemaDiff,
+            r_data_core_core::entity_definition::SchemaChange,
+            r_data_core_core::entity_definition::SchemaChangeKind,
+            crate::admin::entity_definitions::models::RenameFieldRequest,
+            crate::admin::entity_definitions::models::ReorderFieldsRequest,
+            crate::admin::entity_definitions::models::ImportEntityDefinitionQuery,
+            crate::admin::entity_definitions::models::ImportEntityDefinitionResponse,
+            r_data_core_core::entity_definition::EntityDefinitionBundle,
             crate::admin::api_keys::models::CreateApiKeyRequest,
             crate::admin::api_keys::models::ApiKeyResponse,
             crate::admin::api_keys::models::ApiKeyCreatedResponse,
             crate::admin::api_keys::models::ReassignApiKeyRequest,
+            crate::admin::api_keys::models::UpdateApiKeyRateLimitRequest,
+            crate::admin::api_keys::models::ExpiringApiKeysQuery,
+            crate::admin::api_keys::models::RotateApiKeyQuery,
+            crate::admin::api_keys::models::RotatedApiKeyResponse,
             crate::query::PaginationQuery,
             crate::admin::auth::models::AdminLoginRequest,
             crate::admin::auth::models::AdminLoginResponse,
+            crate::admin::auth::models::AdminLoginChallengeResponse,
+            crate::admin::auth::models::TwoFactorLoginRequest,
+            crate::admin::auth::models::TotpCodeRequest,
+            crate::admin::auth::models::TotpEnrollmentResponse,
             crate::admin::auth::models::AdminRegisterRequest,
             crate::admin::auth::models::AdminRegisterResponse,
             crate::admin::auth::models::EmptyRequest,
@@ -118,6 +174,7 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
             crate::admin::entity_definitions::models::DateTimeConstraints,
             crate::admin::entity_definitions::models::SelectConstraints,
             crate::admin::entity_definitions::models::RelationConstraints,
+            r_data_core_core::field::OnDeletePolicy,
             crate::admin::entity_definitions::models::SchemaConstraints,
             crate::admin::api_keys::models::CreateApiKeyRequest,
             crate::admin::api_keys::models::ApiKeyResponse,
@@ -126,17 +183,31 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
             crate::admin::workflows::models::WorkflowSummary,
             crate::admin::workflows::models::CreateWorkflowRequest,
             crate::admin::workflows::models::UpdateWorkflowRequest,
+            r_data_core_workflow::data::requests::RunWorkflowRequest,
             crate::admin::workflows::models::CreateWorkflowResponse,
             crate::admin::workflows::models::WorkflowDetail,
             crate::admin::workflows::models::WorkflowRunSummary,
             crate::admin::workflows::models::WorkflowRunLogDto,
+            crate::admin::workflows::models::WorkflowRunErrorDto,
+            crate::admin::workflows::models::WorkflowRunProgressDto,
+            crate::admin::workflows::models::WorkflowDryRunRequest,
+            crate::admin::workflows::models::WorkflowDryRunOutput,
+            crate::admin::workflows::models::WorkflowDryRunResponse,
             crate::admin::workflows::models::WorkflowRunUpload,
+            crate::admin::workflows::models::CronPreviewResponse,
+            crate::admin::workflows::models::WorkflowMetricsQuery,
+            crate::admin::workflows::models::WorkflowMetricsResponse,
+            crate::admin::workflows::models::WorkflowRunStatsResponse,
             crate::admin::workflows::models::WorkflowVersionMeta,
             crate::admin::workflows::models::WorkflowVersionPayload,
             crate::admin::entity_definitions::models::EntityDefinitionVersionMeta,
             crate::admin::entity_definitions::models::EntityDefinitionVersionPayload,
             crate::admin::dsl::models::DslValidateRequest,
             crate::admin::dsl::models::DslValidateResponse,
+            crate::admin::dsl::models::DslTestBatchRequest,
+            crate::admin::dsl::models::DslTestBatchResponse,
+            crate::admin::dsl::models::DslTestRowResult,
+            crate::admin::dsl::models::DslTestStepOutput,
             crate::admin::dsl::models::DslFieldSpec,
             crate::admin::dsl::models::DslTypeSpec,
             crate::admin::dsl::models::DslOptionsResponse,
@@ -147,8 +218,16 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
             crate::admin::system::models::OutboxSettingsDto,
             crate::admin::system::models::UpdateOutboxSettingsBody,
             crate::admin::system::models::CapabilitiesResponse,
+            crate::admin::system::models::MigrationDto,
+            crate::admin::system::models::MigrationStatusDto,
+            crate::admin::system::models::SchemaReconcileActionDto,
+            crate::admin::system::models::ClearCacheBody,
+            crate::admin::system::models::CacheClearedDto,
             crate::admin::system::models::SystemLogDto,
             crate::admin::system::models::SystemLogQuery,
+            crate::admin::system::models::AuditLogDto,
+            crate::admin::system::models::AuditLogQuery,
+            r_data_core_core::audit_log::AuditAction,
             r_data_core_core::system_log::SystemLogStatus,
             r_data_core_core::system_log::SystemLogType,
             r_data_core_core::system_log::SystemLogResourceType,
@@ -156,6 +235,13 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
             crate::admin::email_templates::models::CreateEmailTemplateRequest,
             crate::admin::email_templates::models::UpdateEmailTemplateRequest,
             crate::admin::email_templates::models::EmailTemplateListQuery,
+            crate::admin::webhooks::models::EntityWebhookResponse,
+            crate::admin::webhooks::models::CreateEntityWebhookRequest,
+            crate::admin::webhooks::models::EntityWebhookListQuery,
+            crate::admin::notifications::models::NotificationResponse,
+            crate::admin::notifications::models::NotificationListQuery,
+            crate::admin::entity_aggregates::models::EntityAggregateResponse,
+            crate::admin::entity_aggregates::models::ConfigureEntityAggregateRequest,
             r_data_core_core::email_template::EmailTemplateType,
             crate::admin::permissions::models::RoleResponse,
             crate::admin::permissions::models::CreateRoleRequest,
@@ -177,6 +263,7 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
         (name = "admin-health", description = "Admin health check endpoints"),
         (name = "admin-auth", description = "Admin authentication endpoints"),
         (name = "entity-definitions", description = "Entity definition management"),
+        (name = "entities", description = "Entity version and soft-delete management"),
         (name = "api-keys", description = "API key management"),
         (name = "workflows", description = "Workflow management"),
         (name = "DSL", description = "Workflow DSL validation and options"),
@@ -185,6 +272,9 @@ use utoipa_swagger_ui::{Config, SwaggerUi};
         (name = "users", description = "User management"),
         (name = "meta", description = "Dashboard metadata and statistics"),
         (name = "email-templates", description = "Email template management"),
+        (name = "webhooks", description = "Entity field-change webhook subscriptions"),
+        (name = "notifications", description = "In-app notifications of the current user"),
+        (name = "entity-aggregates", description = "Cached per-entity-type counts and field sums"),
     ),
     info(
         title = "R Data Core Admin API",
@@ -325,24 +415,32 @@ impl Modify for JsonValueSchemaAddon {
 #[openapi(
     paths(
         crate::health::public_health_check,
+        crate::health::public_readiness_check,
         crate::public::entities::routes::list_available_entities,
         crate::public::entities::routes::list_by_path,
         crate::public::queries::routes::query_entities,
         crate::public::dynamic_entities::routes::list_entities,
+        crate::public::dynamic_entities::routes::entities_exist,
         crate::public::dynamic_entities::routes::create_entity,
         crate::public::dynamic_entities::routes::get_entity,
         crate::public::dynamic_entities::routes::update_entity,
         crate::public::dynamic_entities::routes::delete_entity,
+        crate::public::dynamic_entities::export::export_entities_csv,
         crate::public::workflows::routes::get_workflow_data,
         crate::public::workflows::routes::trigger_workflow,
         crate::public::workflows::routes::get_workflow_stats,
         crate::public::workflows::routes::post_workflow_ingest,
         crate::public::entities::routes::list_entity_versions,
-        crate::public::entities::routes::get_entity_version
+        crate::public::entities::routes::get_entity_version,
+        crate::public::entities::routes::get_entity_schema,
+        crate::public::notifications::routes::list_notifications,
+        crate::public::notifications::routes::mark_notification_read
     ),
     components(
         schemas(
             crate::models::HealthData,
+            crate::models::ReadinessData,
+            crate::models::ComponentStatus,
             crate::public::entities::models::EntityTypeInfo,
             crate::public::entities::models::EntityQuery,
             crate::public::entities::models::BrowseKind,
@@ -353,7 +451,8 @@ impl Modify for JsonValueSchemaAddon {
             crate::public::dynamic_entities::models::DynamicEntityResponse,
             crate::public::dynamic_entities::models::EntityResponse,
             crate::public::entities::models::VersionMeta,
-            crate::public::entities::models::VersionPayload
+            crate::public::entities::models::VersionPayload,
+            crate::admin::notifications::models::NotificationResponse
         )
     ),
     modifiers(&SecurityAddon, &UuidSchemaAddon, &DateTimeSchemaAddon, &ModelSchemaAddon, &JsonValueSchemaAddon),
@@ -361,7 +460,8 @@ impl Modify for JsonValueSchemaAddon {
         (name = "public-health", description = "Public health check endpoints"),
         (name = "public", description = "Public API endpoints"),
         (name = "dynamic-entities", description = "Dynamic entity CRUD operations"),
-        (name = "workflows", description = "Workflow provider and consumer endpoints")
+        (name = "workflows", description = "Workflow provider and consumer endpoints"),
+        (name = "notifications", description = "In-app notifications of the authenticated user")
     ),
     info(
         title = "R Data Core Public API",
@@ -0,0 +1,85 @@
+use actix_web::http::header::{self, EntityTag, IfNoneMatch};
+use actix_web::HttpResponse;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::response::ApiResponse;
+
+/// Strong `ETag` for a response payload
+///
+/// Hashes the row version marker (e.g. version number and `updated_at`) together with the
+/// serialized payload. The payload goes through `serde_json::Value` first so map keys are
+/// ordered and equal payloads always hash the same.
+#[must_use]
+pub fn strong_etag<T: Serialize>(version: &str, data: &T) -> EntityTag {
+    let mut hasher = Sha256::new();
+    hasher.update(version.as_bytes());
+    hasher.update([0]);
+    if let Ok(value) = serde_json::to_value(data) {
+        hasher.update(value.to_string().as_bytes());
+    }
+    EntityTag::new_strong(hex::encode(hasher.finalize()))
+}
+
+/// Whether `If-None-Match` lists `etag` (weak comparison, as RFC 9110 prescribes for GET)
+#[must_use]
+pub fn is_not_modified(if_none_match: Option<&IfNoneMatch>, etag: &EntityTag) -> bool {
+    match if_none_match {
+        Some(IfNoneMatch::Any) => true,
+        Some(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(etag)),
+        None => false,
+    }
+}
+
+/// `ApiResponse::ok` carrying an `ETag`, or `304 Not Modified` when the client already has it
+pub fn ok_with_etag<T: Serialize>(
+    if_none_match: Option<&IfNoneMatch>,
+    version: &str,
+    data: T,
+) -> HttpResponse {
+    let etag = strong_etag(version, &data);
+    if is_not_modified(if_none_match, &etag) {
+        return HttpResponse::NotModified()
+            .insert_header(header::ETag(etag))
+            .finish();
+    }
+
+    let mut response = ApiResponse::ok(data);
+    if let Ok(value) = header::HeaderValue::from_str(&etag.to_string()) {
+        response.headers_mut().insert(header::ETAG, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_strong_etag_depends_on_version_and_payload() {
+        let etag = strong_etag("3", &json!({"a": 1, "b": 2}));
+        assert!(!etag.weak);
+        assert_eq!(etag, strong_etag("3", &json!({"b": 2, "a": 1})));
+        assert_ne!(etag, strong_etag("4", &json!({"a": 1, "b": 2})));
+        assert_ne!(etag, strong_etag("3", &json!({"a": 1, "b": 3})));
+    }
+
+    #[test]
+    fn test_is_not_modified() {
+        let etag = strong_etag("1", &json!({}));
+        let other = EntityTag::new_strong("other".to_string());
+        let weak = EntityTag::new_weak(etag.tag().to_string());
+
+        assert!(!is_not_modified(None, &etag));
+        assert!(is_not_modified(Some(&IfNoneMatch::Any), &etag));
+        assert!(is_not_modified(
+            Some(&IfNoneMatch::Items(vec![other.clone(), weak])),
+            &etag
+        ));
+        assert!(!is_not_modified(
+            Some(&IfNoneMatch::Items(vec![other])),
+            &etag
+        ));
+    }
+}
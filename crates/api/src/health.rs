@@ -1,11 +1,14 @@
 #![allow(clippy::future_not_send, clippy::unused_async)] // Actix handlers take HttpRequest (!Send) and must be async
 
-use actix_web::{get, HttpRequest, HttpResponse, Responder};
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder};
+use sqlx::PgPool;
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::models::HealthData;
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::models::{ComponentStatus, HealthData, ReadinessData};
+use r_data_core_core::cache::CacheManager;
 
 /// Common health check handler for both admin and public API routes
 ///
@@ -69,3 +72,119 @@ pub async fn admin_health_check(req: HttpRequest) -> impl Responder {
 pub async fn public_health_check(req: HttpRequest) -> impl Responder {
     health_check_handler(req).await
 }
+
+/// Probe the database with `SELECT 1`
+async fn check_database(pool: &PgPool) -> ComponentStatus {
+    match sqlx::query("SELECT 1").execute(pool).await {
+        Ok(_) => ComponentStatus::Ok,
+        Err(e) => {
+            log::warn!("Readiness check: database unreachable: {e}");
+            ComponentStatus::Error
+        }
+    }
+}
+
+/// Probe the Redis cache backend with `PING`
+async fn check_redis(cache_manager: &CacheManager) -> ComponentStatus {
+    match cache_manager.ping_redis().await {
+        None => ComponentStatus::Disabled,
+        Some(Ok(())) => ComponentStatus::Ok,
+        Some(Err(e)) => {
+            log::warn!("Readiness check: Redis unreachable: {e}");
+            ComponentStatus::Error
+        }
+    }
+}
+
+/// Build the readiness response: 200 when no component failed, 503 otherwise
+fn readiness_response(database: ComponentStatus, redis: ComponentStatus) -> HttpResponse {
+    let ready = database != ComponentStatus::Error && redis != ComponentStatus::Error;
+    let data = ReadinessData {
+        status: if ready { "ok" } else { "degraded" }.to_string(),
+        database,
+        redis,
+    };
+
+    if ready {
+        HttpResponse::Ok().json(serde_json::json!({
+            "status": "Success",
+            "message": "Service ready",
+            "data": data
+        }))
+    } else {
+        HttpResponse::ServiceUnavailable().json(serde_json::json!({
+            "status": "Error",
+            "message": "Service not ready",
+            "data": data
+        }))
+    }
+}
+
+/// Common readiness check handler probing the database and Redis
+pub async fn readiness_check_handler(state: web::Data<ApiStateWrapper>) -> HttpResponse {
+    let database = check_database(state.db_pool()).await;
+    let redis = check_redis(state.cache_manager()).await;
+    readiness_response(database, redis)
+}
+
+/// Admin API readiness check endpoint
+#[utoipa::path(
+    get,
+    path = "/admin/api/v1/health/ready",
+    tag = "admin-health",
+    responses(
+        (status = 200, description = "All dependencies are reachable", body = ReadinessData),
+        (status = 503, description = "At least one dependency is unreachable", body = ReadinessData),
+    ),
+    security()
+)]
+#[get("/admin/api/v1/health/ready")]
+pub async fn admin_readiness_check(state: web::Data<ApiStateWrapper>) -> impl Responder {
+    readiness_check_handler(state).await
+}
+
+/// Public API readiness check endpoint
+#[utoipa::path(
+    get,
+    path = "/api/v1/health/ready",
+    tag = "public-health",
+    responses(
+        (status = 200, description = "All dependencies are reachable", body = ReadinessData),
+        (status = 503, description = "At least one dependency is unreachable", body = ReadinessData),
+    ),
+    security()
+)]
+#[get("/api/v1/health/ready")]
+pub async fn public_readiness_check(state: web::Data<ApiStateWrapper>) -> impl Responder {
+    readiness_check_handler(state).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+
+    async fn body_json(response: HttpResponse) -> serde_json::Value {
+        let bytes = to_bytes(response.into_body()).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_readiness_is_degraded_when_redis_fails() {
+        let response = readiness_response(ComponentStatus::Ok, ComponentStatus::Error);
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let body = body_json(response).await;
+        assert_eq!(body["data"]["status"], "degraded");
+        assert_eq!(body["data"]["database"], "ok");
+        assert_eq!(body["data"]["redis"], "error");
+    }
+
+    #[tokio::test]
+    async fn test_readiness_ignores_disabled_redis() {
+        let response = readiness_response(ComponentStatus::Ok, ComponentStatus::Disabled);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await["data"]["status"], "ok");
+    }
+}
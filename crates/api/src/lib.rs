@@ -5,7 +5,9 @@ pub mod api_state;
 pub mod api_state_impl;
 pub mod auth;
 pub mod docs;
+pub mod etag;
 pub mod health;
+pub mod metrics;
 pub mod middleware;
 pub mod models;
 pub mod public;
@@ -0,0 +1,292 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::{LazyLock, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::PgPool;
+
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use r_data_core_core::cache::CacheStats;
+use r_data_core_persistence::WorkflowRepository;
+
+/// Path of the metrics endpoint; requests to it are not recorded
+pub const METRICS_PATH: &str = "/metrics";
+
+/// Content type of the Prometheus text exposition format
+pub const TEXT_FORMAT: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Upper bounds (seconds) of the request latency histogram buckets
+const LATENCY_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Process-wide HTTP metrics
+static HTTP_METRICS: LazyLock<HttpMetrics> = LazyLock::new(HttpMetrics::default);
+
+/// Process-wide HTTP metrics recorded by the request metrics middleware
+#[must_use]
+pub fn http_metrics() -> &'static HttpMetrics {
+    &HTTP_METRICS
+}
+
+#[derive(Debug, Default, Clone)]
+struct Histogram {
+    /// Cumulative count per bucket of `LATENCY_BUCKETS`
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        for (bucket, bound) in self.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if seconds <= bound {
+                *bucket += 1;
+            }
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+/// HTTP request counters and latency histograms, labelled by method and route pattern
+#[derive(Debug, Default)]
+pub struct HttpMetrics {
+    requests: Mutex<BTreeMap<(String, String, u16), u64>>,
+    latencies: Mutex<BTreeMap<(String, String), Histogram>>,
+}
+
+impl HttpMetrics {
+    /// Record one finished request
+    ///
+    /// `route` should be the matched route pattern (not the raw path) to keep the label
+    /// cardinality bounded.
+    pub fn record(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        *lock(&self.requests)
+            .entry((method.to_string(), route.to_string(), status))
+            .or_default() += 1;
+        lock(&self.latencies)
+            .entry((method.to_string(), route.to_string()))
+            .or_default()
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Append the HTTP metrics in text exposition format
+    fn render(&self, out: &mut String) {
+        out.push_str("# HELP http_requests_total Total number of HTTP requests.\n");
+        out.push_str("# TYPE http_requests_total counter\n");
+        for ((method, route, status), count) in lock(&self.requests).iter() {
+            let _ = writeln!(
+                out,
+                "http_requests_total{{method=\"{}\",route=\"{}\",status=\"{status}\"}} {count}",
+                escape(method),
+                escape(route)
+            );
+        }
+
+        out.push_str("# HELP http_request_duration_seconds HTTP request latency in seconds.\n");
+        out.push_str("# TYPE http_request_duration_seconds histogram\n");
+        for ((method, route), histogram) in lock(&self.latencies).iter() {
+            let labels = format!("method=\"{}\",route=\"{}\"", escape(method), escape(route));
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(histogram.buckets) {
+                let _ = writeln!(
+                    out,
+                    "http_request_duration_seconds_bucket{{{labels},le=\"{bound}\"}} {count}"
+                );
+            }
+            let _ = writeln!(
+                out,
+                "http_request_duration_seconds_bucket{{{labels},le=\"+Inf\"}} {}",
+                histogram.count
+            );
+            let _ = writeln!(
+                out,
+                "http_request_duration_seconds_sum{{{labels}}} {}",
+                histogram.sum
+            );
+            let _ = writeln!(
+                out,
+                "http_request_duration_seconds_count{{{labels}}} {}",
+                histogram.count
+            );
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Escape a label value for the text exposition format
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Connection counts of a database pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Open connections, idle or in use
+    pub size: u32,
+    /// Open connections waiting to be acquired
+    pub idle: u32,
+    /// Connections currently acquired
+    pub in_use: u32,
+    /// Configured pool limit
+    pub max_connections: u32,
+}
+
+impl PoolStats {
+    /// Snapshot the connection counts of `pool`
+    #[must_use]
+    pub fn from_pool(pool: &PgPool) -> Self {
+        let size = pool.size();
+        let idle = u32::try_from(pool.num_idle()).unwrap_or(u32::MAX).min(size);
+        Self {
+            size,
+            idle,
+            in_use: size - idle,
+            max_connections: pool.options().get_max_connections(),
+        }
+    }
+
+    /// Append the pool gauges in text exposition format
+    fn render(self, out: &mut String) {
+        out.push_str("# HELP db_pool_connections Open database pool connections by state.\n");
+        out.push_str("# TYPE db_pool_connections gauge\n");
+        let _ = writeln!(out, "db_pool_connections{{state=\"idle\"}} {}", self.idle);
+        let _ = writeln!(
+            out,
+            "db_pool_connections{{state=\"in_use\"}} {}",
+            self.in_use
+        );
+        out.push_str("# HELP db_pool_size Open database pool connections.\n");
+        out.push_str("# TYPE db_pool_size gauge\n");
+        let _ = writeln!(out, "db_pool_size {}", self.size);
+        out.push_str("# HELP db_pool_max_connections Configured database pool limit.\n");
+        out.push_str("# TYPE db_pool_max_connections gauge\n");
+        let _ = writeln!(out, "db_pool_max_connections {}", self.max_connections);
+    }
+}
+
+/// Render all metrics in text exposition format
+fn render_metrics(
+    http: &HttpMetrics,
+    cache: CacheStats,
+    pool: PoolStats,
+    workflow_runs: Option<&[(String, i64)]>,
+) -> String {
+    let mut out = String::new();
+    http.render(&mut out);
+
+    if let Some(runs) = workflow_runs {
+        out.push_str("# HELP workflow_runs Number of workflow runs by status.\n");
+        out.push_str("# TYPE workflow_runs gauge\n");
+        for (status, count) in runs {
+            let _ = writeln!(
+                out,
+                "workflow_runs{{status=\"{}\"}} {count}",
+                escape(status)
+            );
+        }
+    }
+
+    out.push_str("# HELP cache_hits_total Cache lookups that found a value.\n");
+    out.push_str("# TYPE cache_hits_total counter\n");
+    let _ = writeln!(out, "cache_hits_total {}", cache.hits);
+    out.push_str("# HELP cache_misses_total Cache lookups that found no value.\n");
+    out.push_str("# TYPE cache_misses_total counter\n");
+    let _ = writeln!(out, "cache_misses_total {}", cache.misses);
+    out.push_str("# HELP cache_entries Entries held by the in-memory cache.\n");
+    out.push_str("# TYPE cache_entries gauge\n");
+    let _ = writeln!(out, "cache_entries {}", cache.entries);
+    out.push_str("# HELP cache_evictions_total Entries evicted from the full in-memory cache.\n");
+    out.push_str("# TYPE cache_evictions_total counter\n");
+    let _ = writeln!(out, "cache_evictions_total {}", cache.evictions);
+
+    pool.render(&mut out);
+    out
+}
+
+/// Prometheus metrics endpoint
+///
+/// Not authenticated, like the health checks; restrict access at the network level.
+#[get("/metrics")]
+pub async fn metrics_endpoint(state: web::Data<ApiStateWrapper>) -> impl Responder {
+    let workflow_runs = match WorkflowRepository::new(state.db_pool().clone())
+        .count_runs_by_status()
+        .await
+    {
+        Ok(runs) => Some(runs),
+        Err(e) => {
+            log::warn!("Failed to collect workflow run metrics: {e}");
+            None
+        }
+    };
+    let body = render_metrics(
+        http_metrics(),
+        state.cache_manager().stats(),
+        PoolStats::from_pool(state.db_pool()),
+        workflow_runs.as_deref(),
+    );
+
+    HttpResponse::Ok().content_type(TEXT_FORMAT).body(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_metrics_exposition_format() {
+        let http = HttpMetrics::default();
+        http.record(
+            "GET",
+            "/api/v1/{entity_type}",
+            200,
+            Duration::from_millis(20),
+        );
+        http.record("GET", "/api/v1/{entity_type}", 200, Duration::from_secs(3));
+
+        let runs = vec![("success".to_string(), 4), ("failed".to_string(), 1)];
+        let pool = PoolStats {
+            size: 3,
+            idle: 1,
+            in_use: 2,
+            max_connections: 10,
+        };
+        let cache = CacheStats {
+            hits: 2,
+            misses: 1,
+            entries: 5,
+            evictions: 3,
+        };
+        let body = render_metrics(&http, cache, pool, Some(&runs));
+
+        assert!(body.contains(
+            "http_requests_total{method=\"GET\",route=\"/api/v1/{entity_type}\",status=\"200\"} 2"
+        ));
+        assert!(body.contains(
+            "http_request_duration_seconds_bucket{method=\"GET\",route=\"/api/v1/{entity_type}\",le=\"0.025\"} 1"
+        ));
+        assert!(body.contains(
+            "http_request_duration_seconds_count{method=\"GET\",route=\"/api/v1/{entity_type}\"} 2"
+        ));
+        assert!(body.contains("workflow_runs{status=\"failed\"} 1"));
+        assert!(body.contains("cache_hits_total 2"));
+        assert!(body.contains("cache_misses_total 1"));
+        assert!(body.contains("cache_entries 5"));
+        assert!(body.contains("cache_evictions_total 3"));
+        assert!(body.contains("db_pool_connections{state=\"in_use\"} 2"));
+        assert!(body.contains("db_pool_size 3"));
+        assert!(body.contains("db_pool_max_connections 10"));
+    }
+
+    #[test]
+    fn test_escape_label_values() {
+        assert_eq!(escape("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+}
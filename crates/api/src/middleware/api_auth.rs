@@ -89,7 +89,7 @@ where
             };
 
             // Try API key authentication
-            if let Ok(Some((key, user_uuid))) = extract_and_validate_api_key(&request).await {
+            if let Some((key, user_uuid)) = extract_and_validate_api_key(&request).await? {
                 log::debug!("API key authentication successful, user_uuid: {user_uuid}");
                 log::debug!("API key UUID: {:?}", key.uuid);
 
@@ -103,6 +103,7 @@ where
                     name: key.name,
                     created_at: key.created_at,
                     expires_at: key.expires_at,
+                    scopes: key.scopes,
                 });
                 log::debug!("API key info inserted into request extensions");
 
@@ -0,0 +1,164 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::future::{ready, Ready};
+use std::rc::Rc;
+
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    web, Error,
+};
+use futures::future::LocalBoxFuture;
+
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::get_or_validate_api_key;
+use crate::auth::utils::client_ip;
+use crate::response::ApiResponse;
+
+/// Time in seconds for an empty bucket to refill; limits are stored per minute
+const RATE_LIMIT_WINDOW_SECS: u64 = 60;
+
+/// Middleware enforcing request limits on the public API
+///
+/// Each request takes a token from the bucket of its client IP, if `ip_rate_limit_per_minute`
+/// is configured, and from the bucket of its API key, if the key has a `rate_limit_per_minute`.
+/// Buckets hold up to a minute's worth of requests and refill continuously with elapsed time,
+/// so bursts at a window boundary cannot exceed the limit. They are kept by the cache manager
+/// (shared via Redis when configured). Requests finding a bucket empty are rejected with 429
+/// and a `Retry-After` header.
+///
+/// The API key is validated once and kept in the request extensions, where the scope
+/// middleware and the route's authentication extractor reuse it.
+#[derive(Debug, Clone, Copy)]
+pub struct ApiKeyRateLimit {
+    window_secs: u64,
+}
+
+impl Default for ApiKeyRateLimit {
+    fn default() -> Self {
+        Self::with_window(RATE_LIMIT_WINDOW_SECS)
+    }
+}
+
+impl ApiKeyRateLimit {
+    /// Refill empty buckets in `window_secs` seconds instead of a minute
+    #[must_use]
+    pub const fn with_window(window_secs: u64) -> Self {
+        Self { window_secs }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for ApiKeyRateLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = ApiKeyRateLimitMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(ApiKeyRateLimitMiddleware {
+            service: Rc::new(service),
+            window_secs: self.window_secs,
+        }))
+    }
+}
+
+pub struct ApiKeyRateLimitMiddleware<S> {
+    service: Rc<S>,
+    window_secs: u64,
+}
+
+impl<S, B> Service<ServiceRequest> for ApiKeyRateLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = Rc::clone(&self.service);
+        let window_secs = self.window_secs;
+
+        Box::pin(async move {
+            let request = req.request().clone();
+            let Some(state) = request.app_data::<web::Data<ApiStateWrapper>>() else {
+                return service
+                    .call(req)
+                    .await
+                    .map(ServiceResponse::map_into_left_body);
+            };
+
+            if let (Some(limit), Some(ip)) = (
+                state.api_config().ip_rate_limit_per_minute,
+                client_ip(&request, &state.api_config().trusted_proxies),
+            ) {
+                let bucket = format!("rate_limit:ip:{ip}");
+                if let Some(retry_after) =
+                    take_token(state, &bucket, u64::from(limit), window_secs).await
+                {
+                    log::debug!("Client {ip} exceeded {limit} requests");
+                    let response = ApiResponse::<()>::too_many_requests(
+                        "Rate limit exceeded for this IP address",
+                        retry_after,
+                    );
+                    return Ok(req.into_response(response).map_into_right_body());
+                }
+            }
+
+            // Invalid keys are left to the route, which answers 401
+            if let Some(key) = get_or_validate_api_key(&request).await {
+                if let Some(limit) = key.rate_limit_per_minute {
+                    let bucket = format!("rate_limit:api_key:{}", key.uuid);
+                    let capacity = u64::try_from(limit).unwrap_or(0);
+                    if let Some(retry_after) =
+                        take_token(state, &bucket, capacity, window_secs).await
+                    {
+                        log::debug!("API key '{}' exceeded {limit} requests", key.name);
+                        let response = ApiResponse::<()>::too_many_requests(
+                            "Rate limit exceeded for this API key",
+                            retry_after,
+                        );
+                        return Ok(req.into_response(response).map_into_right_body());
+                    }
+                }
+            }
+
+            service
+                .call(req)
+                .await
+                .map(ServiceResponse::map_into_left_body)
+        })
+    }
+}
+
+/// Take a token from `bucket`, returning the seconds to wait if it is empty
+///
+/// Requests are not blocked because the bucket is unavailable.
+async fn take_token(
+    state: &ApiStateWrapper,
+    bucket: &str,
+    capacity: u64,
+    window_secs: u64,
+) -> Option<u64> {
+    match state
+        .cache_manager()
+        .take_token(bucket, capacity, window_secs)
+        .await
+    {
+        Ok(retry_after) => retry_after,
+        Err(e) => {
+            log::warn!("Failed to take rate limit token: {e}");
+            None
+        }
+    }
+}
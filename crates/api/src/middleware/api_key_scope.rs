@@ -0,0 +1,133 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::future::{ready, Ready};
+use std::rc::Rc;
+
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::Method,
+    web, Error,
+};
+use futures::future::LocalBoxFuture;
+
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::{extract_and_validate_jwt, get_or_validate_api_key};
+use crate::response::ApiResponse;
+
+/// Middleware requiring an API key scope for the wrapped routes
+///
+/// Only requests authenticated by an API key are checked; JWT and unauthenticated requests pass
+/// through and are handled by the route. A key restricted to other scopes is rejected with 403.
+/// The key is validated once per request and kept as [`ApiKeyInfo`](crate::auth::ApiKeyInfo)
+/// in the request extensions for the route's authentication extractor.
+#[derive(Debug, Clone, Copy)]
+pub struct RequireApiKeyScope {
+    read: &'static str,
+    write: &'static str,
+}
+
+impl RequireApiKeyScope {
+    /// Require `scope` for every request method
+    #[must_use]
+    pub const fn new(scope: &'static str) -> Self {
+        Self {
+            read: scope,
+            write: scope,
+        }
+    }
+
+    /// Require `read` for GET and HEAD requests and `write` for all other methods
+    #[must_use]
+    pub const fn read_write(read: &'static str, write: &'static str) -> Self {
+        Self { read, write }
+    }
+
+    fn required_scope(self, method: &Method) -> &'static str {
+        if method == Method::GET || method == Method::HEAD {
+            self.read
+        } else {
+            self.write
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequireApiKeyScope
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = RequireApiKeyScopeMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequireApiKeyScopeMiddleware {
+            service: Rc::new(service),
+            scopes: *self,
+        }))
+    }
+}
+
+pub struct RequireApiKeyScopeMiddleware<S> {
+    service: Rc<S>,
+    scopes: RequireApiKeyScope,
+}
+
+impl<S, B> Service<ServiceRequest> for RequireApiKeyScopeMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = Rc::clone(&self.service);
+        let required = self.scopes.required_scope(req.method());
+
+        Box::pin(async move {
+            let request = req.request().clone();
+            if !has_valid_jwt(&request).await {
+                // Invalid keys are left to the route, which answers 401
+                if let Some(key) = get_or_validate_api_key(&request).await {
+                    if !key.has_scope(required) {
+                        log::debug!(
+                            "API key '{}' lacks scope {required} for {}",
+                            key.name,
+                            request.path()
+                        );
+                        let response = ApiResponse::<()>::forbidden(&format!(
+                            "API key is missing the required scope '{required}'"
+                        ));
+                        return Ok(req.into_response(response).map_into_right_body());
+                    }
+                }
+            }
+
+            service
+                .call(req)
+                .await
+                .map(ServiceResponse::map_into_left_body)
+        })
+    }
+}
+
+/// A valid admin JWT takes precedence over an API key, as in `CombinedRequiredAuth`
+#[allow(clippy::future_not_send)] // Takes &HttpRequest which is !Send
+async fn has_valid_jwt(req: &actix_web::HttpRequest) -> bool {
+    let Some(state) = req.app_data::<web::Data<ApiStateWrapper>>() else {
+        return false;
+    };
+    matches!(
+        extract_and_validate_jwt(req, state.jwt_secret()).await,
+        Ok(Some(_))
+    )
+}
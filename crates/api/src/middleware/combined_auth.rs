@@ -121,6 +121,7 @@ where
                         name: key.name,
                         created_at: key.created_at,
                         expires_at: key.expires_at,
+                        scopes: key.scopes,
                     });
 
                     // Set auth method for context
@@ -186,4 +187,6 @@ pub struct ApiKeyInfo {
     pub name: String,
     pub created_at: OffsetDateTime,
     pub expires_at: Option<OffsetDateTime>,
+    /// Public API scopes of the key; empty means unrestricted
+    pub scopes: Vec<String>,
 }
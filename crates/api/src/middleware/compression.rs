@@ -0,0 +1,121 @@
+use actix_http::encoding::Encoder;
+use actix_web::{
+    body::{BodySize, MessageBody},
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::{AcceptEncoding, ContentEncoding, Encoding},
+    Error, HttpMessage,
+};
+use futures_util::future::{ok, LocalBoxFuture, Ready};
+
+/// Encodings offered to clients, in server preference order
+const SUPPORTED_ENCODINGS: [Encoding; 3] =
+    [Encoding::gzip(), Encoding::deflate(), Encoding::identity()];
+
+/// Middleware compressing responses with gzip or deflate
+///
+/// The encoding is negotiated from `Accept-Encoding`; clients that accept neither get the
+/// response unencoded. Bodies smaller than `min_size` bytes are left as they are, streamed
+/// bodies of unknown size (e.g. CSV exports) are always compressed.
+pub struct ResponseCompression {
+    min_size: u64,
+}
+
+impl ResponseCompression {
+    #[must_use]
+    pub fn new(min_size: usize) -> Self {
+        Self {
+            min_size: u64::try_from(min_size).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for ResponseCompression
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<Encoder<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = ResponseCompressionMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(ResponseCompressionMiddleware {
+            service,
+            min_size: self.min_size,
+        })
+    }
+}
+
+pub struct ResponseCompressionMiddleware<S> {
+    service: S,
+    min_size: u64,
+}
+
+impl<S, B> Service<ServiceRequest> for ResponseCompressionMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<Encoder<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let encoding = negotiate(req.get_header::<AcceptEncoding>().as_ref());
+        let min_size = self.min_size;
+        let fut = self.service.call(req);
+
+        Box::pin(async move {
+            let response = fut.await?;
+            Ok(response.map_body(move |head, body| {
+                let encoding = match body.size() {
+                    BodySize::Sized(size) if size < min_size => ContentEncoding::Identity,
+                    _ => encoding,
+                };
+                Encoder::response(encoding, head, body)
+            }))
+        })
+    }
+}
+
+/// Content encoding for a request's `Accept-Encoding`, identity when none is acceptable
+fn negotiate(accept_encoding: Option<&AcceptEncoding>) -> ContentEncoding {
+    match accept_encoding.and_then(|accept| accept.negotiate(SUPPORTED_ENCODINGS.iter())) {
+        Some(Encoding::Known(encoding)) => encoding,
+        _ => ContentEncoding::Identity,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accept(items: &[&str]) -> AcceptEncoding {
+        AcceptEncoding(
+            items
+                .iter()
+                .map(|item| item.parse().expect("accept-encoding item"))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_negotiate_encoding() {
+        assert_eq!(negotiate(None), ContentEncoding::Identity);
+        assert_eq!(
+            negotiate(Some(&accept(&["gzip", "deflate", "br"]))),
+            ContentEncoding::Gzip
+        );
+        assert_eq!(
+            negotiate(Some(&accept(&["gzip;q=0.5", "deflate"]))),
+            ContentEncoding::Deflate
+        );
+        assert_eq!(negotiate(Some(&accept(&["br"]))), ContentEncoding::Identity);
+    }
+}
@@ -7,7 +7,7 @@ use futures_util::future::{ok, LocalBoxFuture, Ready};
 use log::{error, warn};
 use uuid::Uuid;
 
-use crate::response::{ApiResponse, ResponseMeta, Status};
+use crate::response::{response_request_id, ApiResponse, ResponseMeta, Status};
 
 /// Error handler middleware to ensure all responses follow our API standards
 pub struct ErrorHandler;
@@ -49,11 +49,11 @@ where
         // Capture request context for logging
         let method = req.method().clone();
         let path = req.path().to_string();
-        let request_id = Uuid::now_v7();
 
         let fut = self.service.call(req);
 
         Box::pin(async move {
+            let request_id = response_request_id();
             match fut.await {
                 Ok(response) => {
                     let status_code = response.status();
@@ -107,7 +107,7 @@ fn handle_error(err: &Error, req_id: Option<Uuid>) -> HttpResponse {
         data: None as Option<()>,
         meta: Some(ResponseMeta {
             pagination: None,
-            request_id: req_id.or_else(|| Some(Uuid::now_v7())),
+            request_id: req_id.or_else(|| Some(response_request_id())),
             timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
             custom: Some(serde_json::json!({"error_code": error_code})),
         }),
@@ -1,14 +1,24 @@
 mod api_auth;
+mod api_key_rate_limit;
+mod api_key_scope;
 mod base_auth;
 mod combined_auth;
+mod compression;
 mod error_handler;
 mod error_handlers;
 mod jwt_auth;
+mod request_id;
+mod request_metrics;
 
 #[allow(unused_imports)] // Re-exported for use in tests
 pub use api_auth::ApiAuth;
+pub use api_key_rate_limit::ApiKeyRateLimit;
+pub use api_key_scope::RequireApiKeyScope;
 pub use base_auth::AuthMiddlewareService;
 #[allow(unused_imports)] // Re-exported for use in tests
 pub use combined_auth::{ApiKeyInfo, CombinedAuth};
+pub use compression::ResponseCompression;
 pub use error_handler::ErrorHandler;
 pub use error_handlers::create_error_handlers;
+pub use request_id::{request_id, RequestId, RequestIdPropagation};
+pub use request_metrics::RequestMetrics;
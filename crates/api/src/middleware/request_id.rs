@@ -0,0 +1,87 @@
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::{HeaderName, HeaderValue},
+    Error, HttpMessage, HttpRequest,
+};
+use futures_util::future::{ok, LocalBoxFuture, Ready};
+use uuid::Uuid;
+
+use r_data_core_core::request_id::{
+    call_with_request_id, is_valid_request_id, with_request_id, REQUEST_ID_HEADER,
+};
+
+/// Request ID stored in the request extensions by [`RequestIdPropagation`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+/// Request ID of `req`, if the request passed through [`RequestIdPropagation`]
+#[must_use]
+pub fn request_id(req: &HttpRequest) -> Option<String> {
+    req.extensions().get::<RequestId>().map(|id| id.0.clone())
+}
+
+/// Middleware propagating a request ID
+///
+/// Adopts the incoming `X-Request-Id` header or generates a `UUIDv7`, stores it in the request
+/// extensions, makes it the current request ID for logging and workflow run logs while the
+/// request is handled, and echoes it in the response header. Wrap it outermost so every other
+/// middleware runs inside its scope.
+pub struct RequestIdPropagation;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestIdPropagation
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = RequestIdPropagationMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(RequestIdPropagationMiddleware { service })
+    }
+}
+
+pub struct RequestIdPropagationMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestIdPropagationMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let id = req
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| is_valid_request_id(value))
+            .map_or_else(|| Uuid::now_v7().to_string(), ToString::to_string);
+        req.extensions_mut().insert(RequestId(id.clone()));
+
+        let header_value = HeaderValue::from_str(&id).ok();
+        // Downstream middleware may already log in `call`, so enter the scope before it
+        let fut = call_with_request_id(id.clone(), || self.service.call(req));
+
+        Box::pin(with_request_id(id, async move {
+            let mut response = fut.await?;
+            if let Some(value) = header_value {
+                response
+                    .headers_mut()
+                    .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+            }
+            Ok(response)
+        }))
+    }
+}
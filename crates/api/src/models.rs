@@ -20,3 +20,30 @@ pub struct HealthData {
     /// User agent that made the request
     pub agent: String,
 }
+
+/// Status of a single dependency probed by the readiness check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentStatus {
+    /// The dependency responded
+    Ok,
+    /// The dependency could not be reached
+    Error,
+    /// The dependency is not configured
+    Disabled,
+}
+
+/// Readiness check response data
+#[derive(Debug, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export)]
+pub struct ReadinessData {
+    /// Overall status: `ok` when every configured dependency responded, `degraded` otherwise
+    pub status: String,
+
+    /// `PostgreSQL` connectivity
+    pub database: ComponentStatus,
+
+    /// Redis connectivity (`disabled` without a Redis cache backend)
+    pub redis: ComponentStatus,
+}
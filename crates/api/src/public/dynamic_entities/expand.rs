@@ -0,0 +1,199 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::HashMap;
+
+use actix_web::{web, HttpResponse};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::CombinedRequiredAuth;
+use crate::public::dynamic_entities::routes::handle_entity_error;
+use crate::public::entity_permissions::{is_owned_by, owner_scope, redact_entity, visible_fields};
+use crate::response::ApiResponse;
+use r_data_core_core::field::FieldType;
+use r_data_core_core::permissions::role::PermissionType;
+use r_data_core_core::DynamicEntity;
+
+/// Relation levels resolved by `?expand=`; embedded entities keep their own references as UUIDs
+pub const MAX_EXPAND_DEPTH: usize = 1;
+
+/// A relation field whose referenced entities are embedded in the response
+pub(super) struct Expansion {
+    field: String,
+    target_type: String,
+    many: bool,
+    /// Fields of the target type the caller may read, `None` when none is hidden
+    visible: Option<Vec<String>>,
+    /// Creator the caller is limited to on the target type
+    owner: Option<Uuid>,
+}
+
+/// Resolve the `?expand=` field names of `entity_type` to relation expansions
+///
+/// Only `ManyToOne` and `ManyToMany` fields with a target type that the caller may read can be
+/// expanded. Read permissions (visible fields, owner scope) of the target type are resolved
+/// here and applied to the embedded entities.
+///
+/// # Errors
+/// Returns 400 for paths deeper than [`MAX_EXPAND_DEPTH`], 422 for fields that are unknown,
+/// hidden or not relations, or the response of a failed permission lookup
+pub(super) async fn resolve_expansions(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &CombinedRequiredAuth,
+    entity_type: &str,
+    expand: &[String],
+    visible: Option<&Vec<String>>,
+) -> Result<Vec<Expansion>, HttpResponse> {
+    if expand.is_empty() {
+        return Ok(Vec::new());
+    }
+    if let Some(nested) = expand
+        .iter()
+        .find(|name| name.split('.').count() > MAX_EXPAND_DEPTH)
+    {
+        return Err(ApiResponse::<()>::bad_request(&format!(
+            "Cannot expand '{nested}': expansion is limited to a depth of {MAX_EXPAND_DEPTH}"
+        )));
+    }
+
+    let definition = data
+        .entity_definition_service()
+        .get_entity_definition_by_entity_type(entity_type)
+        .await
+        .map_err(|e| handle_entity_error(e, entity_type))?;
+
+    let mut expansions: Vec<Expansion> = Vec::with_capacity(expand.len());
+    for name in expand {
+        if expansions.iter().any(|expansion| &expansion.field == name) {
+            continue;
+        }
+        let target = definition
+            .get_field(name)
+            .filter(|field| field.field_type.is_relation())
+            .filter(|_| visible.is_none_or(|visible| visible.contains(name)))
+            .and_then(|field| {
+                field
+                    .validation
+                    .target_class
+                    .clone()
+                    .map(|target| (target, field.field_type == FieldType::ManyToMany))
+            });
+        let Some((target_type, many)) = target else {
+            return Err(ApiResponse::<()>::unprocessable_entity(&format!(
+                "Field '{name}' is not a relation and cannot be expanded"
+            )));
+        };
+
+        let visible = visible_fields(data, auth, &target_type).await?;
+        let owner = owner_scope(data, auth, &target_type, &PermissionType::Read).await?;
+        expansions.push(Expansion {
+            field: name.clone(),
+            target_type,
+            many,
+            visible,
+            owner,
+        });
+    }
+    Ok(expansions)
+}
+
+/// UUIDs referenced by a relation value: a single UUID or an array of them
+fn referenced_uuids(value: &Value) -> Vec<Uuid> {
+    match value {
+        Value::String(uuid) => Uuid::parse_str(uuid).into_iter().collect(),
+        Value::Array(values) => values.iter().flat_map(referenced_uuids).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Replace the references of each expansion in `entities` by the referenced entities
+///
+/// The referenced entities of all `entities` are loaded with one query per expansion and
+/// embedded as their field data. References the caller cannot read (missing, deleted or owned
+/// by someone else) become `null` for `ManyToOne` fields and are left out of `ManyToMany` lists.
+///
+/// # Errors
+/// Returns an error response if the referenced entities cannot be loaded
+pub(super) async fn expand_entities(
+    data: &web::Data<ApiStateWrapper>,
+    expansions: &[Expansion],
+    entities: &mut [DynamicEntity],
+) -> Result<(), HttpResponse> {
+    if expansions.is_empty() || entities.is_empty() {
+        return Ok(());
+    }
+    let Some(service) = data.dynamic_entity_service() else {
+        return Err(ApiResponse::<()>::internal_error(
+            "Dynamic entity service not initialized",
+        ));
+    };
+
+    for expansion in expansions {
+        let mut uuids: Vec<Uuid> = entities
+            .iter()
+            .filter_map(|entity| entity.field_data.get(&expansion.field))
+            .flat_map(referenced_uuids)
+            .collect();
+        uuids.sort_unstable();
+        uuids.dedup();
+
+        let embedded: HashMap<Uuid, Value> = if uuids.is_empty() {
+            HashMap::new()
+        } else {
+            service
+                .get_entities_by_uuids(&expansion.target_type, &uuids, None)
+                .await
+                .map_err(|e| handle_entity_error(e, &expansion.target_type))?
+                .into_iter()
+                .filter_map(|(uuid, entity)| Some((uuid, entity?)))
+                .filter(|(_, entity)| {
+                    expansion
+                        .owner
+                        .is_none_or(|owner| is_owned_by(entity, owner))
+                })
+                .map(|(uuid, mut entity)| {
+                    redact_entity(&mut entity, expansion.visible.as_deref());
+                    (uuid, Value::Object(entity.field_data.into_iter().collect()))
+                })
+                .collect()
+        };
+
+        for entity in entities.iter_mut() {
+            let Some(value) = entity.field_data.get_mut(&expansion.field) else {
+                continue;
+            };
+            let mut resolved = referenced_uuids(value)
+                .into_iter()
+                .filter_map(|uuid| embedded.get(&uuid).cloned());
+            *value = if expansion.many {
+                Value::Array(resolved.collect())
+            } else {
+                resolved.next().unwrap_or(Value::Null)
+            };
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn referenced_uuids_reads_single_and_many_references() {
+        let first = Uuid::now_v7();
+        let second = Uuid::now_v7();
+        assert_eq!(referenced_uuids(&json!(first.to_string())), vec![first]);
+        assert_eq!(
+            referenced_uuids(&json!([
+                first.to_string(),
+                "not-a-uuid",
+                second.to_string()
+            ])),
+            vec![first, second]
+        );
+        assert!(referenced_uuids(&Value::Null).is_empty());
+    }
+}
@@ -0,0 +1,291 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use actix_web::http::header::{self, ContentDisposition, DispositionParam, DispositionType};
+use actix_web::web;
+use actix_web::HttpResponse;
+use futures::stream;
+use log::error;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::CombinedRequiredAuth;
+use crate::public::dynamic_entities::routes::{
+    criteria_fields, handle_entity_error, validate_requested_fields,
+};
+use crate::public::entity_permissions::{ensure_visible_criteria, owner_scope, visible_fields};
+use crate::public::entity_type_guard::ensure_entity_type;
+use crate::query::StandardQuery;
+use crate::response::negotiation::{csv_rows, encode_csv, TabularRow};
+use crate::response::ApiResponse;
+use r_data_core_core::count::CountMode;
+use r_data_core_core::permissions::role::PermissionType;
+use r_data_core_core::sort::SortCollation;
+use r_data_core_core::DynamicEntity;
+use r_data_core_services::DynamicEntityService;
+
+/// Entities fetched per query while streaming an export
+const EXPORT_BATCH_SIZE: i64 = 500;
+
+/// System columns placed before the definition fields
+const LEADING_COLUMNS: [&str; 2] = ["uuid", "path"];
+
+/// System columns placed after the definition fields
+const TRAILING_COLUMNS: [&str; 4] = ["created_at", "updated_at", "created_by", "version"];
+
+/// Query of one export, re-run per batch with a growing offset
+struct ExportQuery {
+    service: Arc<DynamicEntityService>,
+    entity_type: String,
+    fields: Option<Vec<String>>,
+    sort_by: Option<String>,
+    sort_direction: Option<String>,
+    collation: SortCollation,
+    filter: Option<Value>,
+    search_query: Option<String>,
+    owner: Option<Uuid>,
+}
+
+impl ExportQuery {
+    async fn batch(&self, offset: i64) -> r_data_core_core::error::Result<Vec<DynamicEntity>> {
+        self.service
+            .list_entities_with_filters(
+                &self.entity_type,
+                EXPORT_BATCH_SIZE,
+                offset,
+                self.fields.clone(),
+                self.sort_by.clone(),
+                self.sort_direction.clone(),
+                self.collation,
+                self.filter.clone(),
+                self.search_query.clone(),
+                false,
+                self.owner,
+                // Batches only need the rows
+                CountMode::None,
+            )
+            .await
+            .map(|(entities, _)| entities)
+    }
+}
+
+/// CSV columns: the requested fields, or the system columns around all definition fields.
+/// Definition fields hidden from the caller are left out either way.
+fn export_columns(
+    definition_fields: &[String],
+    requested: Option<&Vec<String>>,
+    visible: Option<&Vec<String>>,
+) -> Vec<String> {
+    let columns: Vec<String> = requested.cloned().unwrap_or_else(|| {
+        LEADING_COLUMNS
+            .iter()
+            .map(ToString::to_string)
+            .chain(definition_fields.iter().cloned())
+            .chain(TRAILING_COLUMNS.iter().map(ToString::to_string))
+            .collect()
+    });
+    columns
+        .into_iter()
+        .filter(|column| {
+            !definition_fields.contains(column)
+                || visible.is_none_or(|visible| visible.contains(column))
+        })
+        .collect()
+}
+
+/// CSV columns of `entity_type` for the caller, see [`export_columns`]
+///
+/// # Errors
+/// Returns an error response if the entity definition cannot be loaded
+pub(super) async fn entity_columns(
+    data: &web::Data<ApiStateWrapper>,
+    entity_type: &str,
+    requested: Option<&Vec<String>>,
+    visible: Option<&Vec<String>>,
+) -> Result<Vec<String>, HttpResponse> {
+    let definition = data
+        .entity_definition_service()
+        .get_entity_definition_by_entity_type(entity_type)
+        .await
+        .map_err(|e| handle_entity_error(e, entity_type))?;
+    let definition_fields: Vec<String> = definition.fields.iter().map(|f| f.name.clone()).collect();
+    Ok(export_columns(&definition_fields, requested, visible))
+}
+
+impl TabularRow for DynamicEntity {
+    fn column(&self, column: &str) -> Option<&Value> {
+        self.field_data.get(column)
+    }
+}
+
+fn csv_error(e: &csv::Error) -> actix_web::Error {
+    error!("Failed to encode CSV export: {e}");
+    actix_web::error::ErrorInternalServerError("Failed to encode CSV export")
+}
+
+/// Export entities of a type as CSV
+///
+/// Accepts the filter, search, sorting and field parameters of the list route; pagination is
+/// ignored and every matching entity is exported. Rows are fetched in batches and streamed as
+/// a chunked body.
+#[utoipa::path(
+    get,
+    path = "/api/v1/entities/{entity_type}/export.csv",
+    tag = "dynamic-entities",
+    params(
+        ("entity_type" = String, Path, description = "Type of entity to export"),
+        ("sort_by" = Option<String>, Query, description = "Field to sort by"),
+        ("sort_order" = Option<String>, Query, description = "Sort order: 'asc' or 'desc' (default: 'asc')"),
+        ("collation" = Option<String>, Query, description = "Text sort ordering: 'default', 'ci' (case-insensitive) or 'ci_ai' (case- and accent-insensitive)"),
+        ("fields" = Option<Vec<String>>, Query, description = "Columns to export (default: all readable fields)"),
+        ("filter" = Option<String>, Query, description = "Filter criteria"),
+        ("q" = Option<String>, Query, description = "Search text")
+    ),
+    responses(
+        (status = 200, description = "CSV file with a header row", content_type = "text/csv"),
+        (status = 400, description = "Invalid collation, or filter or sort by a field the caller may not read"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "API key lacks the required scope"),
+        (status = 404, description = "Entity type not found"),
+        (status = 422, description = "Invalid field requested"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = []),
+        ("apiKey" = [])
+    )
+)]
+pub async fn export_entities_csv(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<String>,
+    query: web::Query<StandardQuery>,
+    auth: CombinedRequiredAuth,
+) -> HttpResponse {
+    let entity_type = path.into_inner();
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
+    let visible = match visible_fields(&data, &auth, &entity_type).await {
+        Ok(visible) => visible,
+        Err(response) => return response,
+    };
+    let owner = match owner_scope(&data, &auth, &entity_type, &PermissionType::Read).await {
+        Ok(owner) => owner,
+        Err(response) => return response,
+    };
+    if let Err(response) =
+        ensure_visible_criteria(&data, &auth, &entity_type, &criteria_fields(&query)).await
+    {
+        return response;
+    }
+    let collation = match query.sorting.validate_collation() {
+        Ok(collation) => collation,
+        Err(message) => return ApiResponse::<()>::bad_request(&message),
+    };
+    let fields = query.fields.get_fields();
+    if let Err(response) = validate_requested_fields(&data, &entity_type, fields.as_ref()).await {
+        return response;
+    }
+    let columns = match entity_columns(&data, &entity_type, fields.as_ref(), visible.as_ref()).await
+    {
+        Ok(columns) => columns,
+        Err(response) => return response,
+    };
+
+    let Some(service) = data.dynamic_entity_service() else {
+        return ApiResponse::<()>::internal_error("Dynamic entity service not initialized");
+    };
+    let export = ExportQuery {
+        service: service.clone(),
+        entity_type: entity_type.clone(),
+        fields,
+        sort_by: query.sorting.sort_by.clone(),
+        sort_direction: Some(query.sorting.get_sort_order()),
+        collation,
+        filter: query.filter.parse_filter(),
+        search_query: query.filter.q.clone(),
+        owner,
+    };
+
+    // The first batch is fetched up front so query errors still get a regular error response
+    let first = match export.batch(0).await {
+        Ok(entities) => entities,
+        Err(e) => return handle_entity_error(e, &entity_type),
+    };
+    let head = match encode_csv(std::iter::once(columns.clone()).chain(csv_rows(&first, &columns)))
+    {
+        Ok(bytes) => bytes,
+        Err(e) => return HttpResponse::from_error(csv_error(&e)),
+    };
+    let has_more = first.len() >= usize::try_from(EXPORT_BATCH_SIZE).unwrap_or(usize::MAX);
+
+    let rest = stream::try_unfold(
+        (export, columns, EXPORT_BATCH_SIZE, has_more),
+        |(export, columns, offset, has_more)| async move {
+            if !has_more {
+                return Ok(None);
+            }
+            let entities = export.batch(offset).await.map_err(|e| {
+                error!("Failed to fetch CSV export batch at offset {offset}: {e}");
+                actix_web::error::ErrorInternalServerError("Failed to fetch entities")
+            })?;
+            let has_more =
+                entities.len() >= usize::try_from(EXPORT_BATCH_SIZE).unwrap_or(usize::MAX);
+            let chunk = encode_csv(csv_rows(&entities, &columns)).map_err(|e| csv_error(&e))?;
+            Ok(Some((
+                chunk,
+                (export, columns, offset + EXPORT_BATCH_SIZE, has_more),
+            )))
+        },
+    );
+    let body = futures::StreamExt::chain(
+        stream::once(async move { Ok::<_, actix_web::Error>(head) }),
+        rest,
+    );
+
+    HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!("{entity_type}.csv"))],
+        })
+        .insert_header((header::CACHE_CONTROL, "no-store"))
+        .streaming(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_export_columns_default_order_and_visibility() {
+        let definition = names(&["name", "email", "salary"]);
+        assert_eq!(
+            export_columns(&definition, None, None),
+            names(&[
+                "uuid",
+                "path",
+                "name",
+                "email",
+                "salary",
+                "created_at",
+                "updated_at",
+                "created_by",
+                "version"
+            ])
+        );
+
+        let visible = names(&["name", "email"]);
+        let requested = names(&["salary", "name", "version"]);
+        assert_eq!(
+            export_columns(&definition, Some(&requested), Some(&visible)),
+            names(&["name", "version"])
+        );
+    }
+}
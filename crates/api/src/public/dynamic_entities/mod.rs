@@ -1,5 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+pub mod expand;
+pub mod export;
 pub mod models;
 pub mod routes;
 
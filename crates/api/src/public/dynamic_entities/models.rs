@@ -4,6 +4,10 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use utoipa::ToSchema;
+
+use crate::public::entity_permissions::redact_entity;
+use crate::response::negotiation::TabularRow;
+use r_data_core_core::DynamicEntity;
 use uuid::Uuid;
 
 /// Schema for dynamic entity serialization
@@ -22,5 +26,28 @@ pub struct EntityResponse {
     pub entity_type: String,
 }
 
-// Note: From<DynamicEntity> implementation must be in the main crate
-// since DynamicEntity is defined in r_data_core_core
+impl DynamicEntityResponse {
+    /// Map an entity to its response, leaving out the definition fields outside `visible`
+    ///
+    /// Public read paths build their entity responses here, so fields the caller may not read
+    /// never reach a response.
+    #[must_use]
+    pub fn from_entity(
+        mut entity: DynamicEntity,
+        visible: Option<&[String]>,
+        children_count: Option<i64>,
+    ) -> Self {
+        redact_entity(&mut entity, visible);
+        Self {
+            entity_type: entity.entity_type,
+            field_data: entity.field_data,
+            children_count,
+        }
+    }
+}
+
+impl TabularRow for DynamicEntityResponse {
+    fn column(&self, column: &str) -> Option<&Value> {
+        self.field_data.get(column)
+    }
+}
@@ -1,24 +1,44 @@
-use actix_web::{web, HttpResponse};
+use actix_web::{
+    http::header::{Accept, IfNoneMatch},
+    web, HttpResponse,
+};
 use log::{error, info};
 use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
 use crate::auth::auth_enum::CombinedRequiredAuth;
-use crate::query::StandardQuery;
+use crate::auth::permission_check::has_entity_permission;
+use crate::etag::ok_with_etag;
+use crate::middleware::RequireApiKeyScope;
+use crate::query::{CountOnlyQuery, StandardQuery};
+use crate::response::negotiation::{self, FormatQuery, ListFormat};
 use crate::response::{ApiResponse, ValidationViolation};
+use r_data_core_core::admin_user::api_key_scopes;
 use r_data_core_core::domain::dynamic_entity::validator::{
     validate_entity_with_violations, FieldViolation,
 };
+use r_data_core_core::permissions::role::PermissionType;
 use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::{AdminUserRepository, DynamicEntityQueryRepository};
 
 /// Register routes for dynamic entities
 pub fn register_routes(cfg: &mut web::ServiceConfig) {
     info!("Registering dynamic entity routes");
     cfg.service(
         web::scope("")
+            .wrap(RequireApiKeyScope::read_write(
+                api_key_scopes::ENTITIES_READ,
+                api_key_scopes::ENTITIES_WRITE,
+            ))
+            .route(
+                "/entities/{entity_type}/export.csv",
+                web::get().to(export_entities_csv),
+            )
             .route("/{entity_type}", web::get().to(list_entities))
+            .route("/{entity_type}", web::head().to(entities_exist))
             .route("/{entity_type}", web::post().to(create_entity))
             .route("/{entity_type}/{uuid}", web::get().to(get_entity))
             .route("/{entity_type}/{uuid}", web::put().to(update_entity))
@@ -26,32 +46,55 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
     );
 }
 
+use crate::public::dynamic_entities::expand::{expand_entities, resolve_expansions};
+use crate::public::dynamic_entities::export::{entity_columns, export_entities_csv};
 use crate::public::dynamic_entities::models::{DynamicEntityResponse, EntityResponse};
+use crate::public::entity_permissions::{
+    ensure_visible_criteria, is_owned_by, owner_scope, redact_entity, visible_fields,
+};
+use crate::public::entity_type_guard::ensure_entity_type;
 
-// Helper function to convert DynamicEntity to DynamicEntityResponse
-// Cannot use From trait since DynamicEntity is from another crate
-fn to_dynamic_entity_response(entity: DynamicEntity) -> DynamicEntityResponse {
-    DynamicEntityResponse {
-        entity_type: entity.entity_type,
-        field_data: entity.field_data,
-        children_count: None,
-    }
+/// Whether soft-deleted entities of `entity_type` are included, given that they were `requested`
+///
+/// Only admin users with `Admin` permission on the entity type see them.
+async fn include_deleted(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &CombinedRequiredAuth,
+    entity_type: &str,
+    requested: bool,
+) -> Result<bool, HttpResponse> {
+    let Some(claims) = auth.jwt_claims.as_ref().filter(|_| requested) else {
+        return Ok(false);
+    };
+    has_entity_permission(
+        claims,
+        entity_type,
+        &PermissionType::Admin,
+        None,
+        data.role_service(),
+        &AdminUserRepository::new(Arc::new(data.db_pool().clone())),
+    )
+    .await
+    .map_err(|e| {
+        error!("Failed to check admin permission for {entity_type}: {e}");
+        ApiResponse::<()>::internal_error("Failed to check permissions")
+    })
 }
 
-// Helper function to convert DynamicEntity to DynamicEntityResponse with children count
-fn to_dynamic_entity_response_with_children_count(
-    entity: DynamicEntity,
-    children_count: Option<i64>,
-) -> DynamicEntityResponse {
-    DynamicEntityResponse {
-        entity_type: entity.entity_type,
-        field_data: entity.field_data,
-        children_count,
-    }
+/// Row version marker of an entity for its `ETag`
+fn row_version(entity: &DynamicEntity) -> String {
+    let field = |name: &str| {
+        entity
+            .field_data
+            .get(name)
+            .map(ToString::to_string)
+            .unwrap_or_default()
+    };
+    format!("{}:{}", field("version"), field("updated_at"))
 }
 
 /// Helper to validate requested fields against entity definition
-async fn validate_requested_fields(
+pub(super) async fn validate_requested_fields(
     data: &web::Data<ApiStateWrapper>,
     entity_type: &str,
     fields: Option<&Vec<String>>,
@@ -112,14 +155,23 @@ async fn validate_requested_fields(
         ("include" = Option<String>, Query, description = "Comma-separated list of related entities to include"),
         ("sort_by" = Option<String>, Query, description = "Field to sort by"),
         ("sort_order" = Option<String>, Query, description = "Sort order: 'asc' or 'desc' (default: 'asc')"),
+        ("collation" = Option<String>, Query, description = "Text sort ordering: 'default', 'ci' (case-insensitive) or 'ci_ai' (case- and accent-insensitive)"),
         ("fields" = Option<Vec<String>>, Query, description = "Fields to include in the response"),
-        ("filter" = Option<HashMap<String, Value>>, Query, description = "Filter criteria")
+        ("filter" = Option<HashMap<String, Value>>, Query, description = "Filter criteria"),
+        ("include_deleted" = Option<bool>, Query, description = "Include soft-deleted entities (requires entities admin permission)"),
+        ("expand" = Option<String>, Query, description = "Comma-separated relation fields whose referenced entities are embedded inline (one level deep)"),
+        ("count_only" = Option<bool>, Query, description = "Return only `{\"total\": n}`, the number of entities matching `filter` (search `q` is not supported)"),
+        ("count" = Option<String>, Query, description = "How the pagination total is counted: 'exact' (default), 'approximate' (estimate from table statistics, flagged with `total_is_estimate`) or 'none' (`total: null`)"),
+        ("format" = Option<String>, Query, description = "Output format: json (default), csv or ndjson; overrides the Accept header")
     ),
     responses(
-        (status = 200, description = "List of entities with pagination", body = Vec<DynamicEntityResponse>),
+        (status = 200, description = "List of entities with pagination (JSON, CSV or NDJSON)", body = Vec<DynamicEntityResponse>),
         (status = 400, description = "Bad request - invalid parameters"),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 404, description = "Entity type not found"),
+        (status = 406, description = "Requested format not supported"),
+        (status = 422, description = "Invalid field requested or field cannot be expanded"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -131,13 +183,59 @@ pub async fn list_entities(
     data: web::Data<ApiStateWrapper>,
     path: web::Path<String>,
     query: web::Query<StandardQuery>,
-    _: CombinedRequiredAuth,
+    format: web::Query<FormatQuery>,
+    count: web::Query<CountOnlyQuery>,
+    accept: Option<web::Header<Accept>>,
+    auth: CombinedRequiredAuth,
 ) -> HttpResponse {
+    let format = match ListFormat::negotiate(format.format.as_deref(), accept.as_deref()) {
+        Ok(format) => format,
+        Err(response) => return response,
+    };
     let entity_type = path.into_inner();
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
+    let visible = match visible_fields(&data, &auth, &entity_type).await {
+        Ok(visible) => visible,
+        Err(response) => return response,
+    };
+    let owner = match owner_scope(&data, &auth, &entity_type, &PermissionType::Read).await {
+        Ok(owner) => owner,
+        Err(response) => return response,
+    };
+    if let Err(response) =
+        ensure_visible_criteria(&data, &auth, &entity_type, &criteria_fields(&query)).await
+    {
+        return response;
+    }
+    if count.is_count_only() {
+        return count_entities(&data, &entity_type, &query, owner).await;
+    }
     let (limit, offset) = query.pagination.to_limit_offset(20, 100);
+    // Soft-deleted entities are only visible to admins; the flag is ignored otherwise
+    let include_deleted = match include_deleted(
+        &data,
+        &auth,
+        &entity_type,
+        query.include.should_include_deleted(),
+    )
+    .await
+    {
+        Ok(include_deleted) => include_deleted,
+        Err(response) => return response,
+    };
     let fields = query.fields.get_fields();
     let sort_by = query.sorting.sort_by.clone();
     let sort_direction = Some(query.sorting.get_sort_order());
+    let collation = match query.sorting.validate_collation() {
+        Ok(collation) => collation,
+        Err(message) => return ApiResponse::<()>::bad_request(&message),
+    };
+    let count_mode = match count.validate_count_mode() {
+        Ok(count_mode) => count_mode,
+        Err(message) => return ApiResponse::<()>::bad_request(&message),
+    };
 
     // Handle filters and also accept a "path" query param for folder-style browsing
     let filter = query.filter.parse_filter();
@@ -149,6 +247,26 @@ pub async fn list_entities(
     if let Err(response) = validate_requested_fields(&data, &entity_type, fields.as_ref()).await {
         return response;
     }
+    let expansions = match resolve_expansions(
+        &data,
+        &auth,
+        &entity_type,
+        &query.include.get_expands(),
+        visible.as_ref(),
+    )
+    .await
+    {
+        Ok(expansions) => expansions,
+        Err(response) => return response,
+    };
+    let columns = if format == ListFormat::Csv {
+        match entity_columns(&data, &entity_type, fields.as_ref(), visible.as_ref()).await {
+            Ok(columns) => columns,
+            Err(response) => return response,
+        }
+    } else {
+        Vec::new()
+    };
 
     if let Some(service) = data.dynamic_entity_service() {
         // If validation passed, proceed with the query
@@ -160,21 +278,30 @@ pub async fn list_entities(
                 fields,
                 sort_by,
                 sort_direction,
+                collation,
                 filter,
                 search_query,
+                include_deleted,
+                owner,
+                count_mode,
             )
             .await
         {
-            Ok((entities, total)) => {
+            Ok((mut entities, total)) => {
+                if let Err(response) = expand_entities(&data, &expansions, &mut entities).await {
+                    return response;
+                }
                 let entity_responses: Vec<DynamicEntityResponse> = entities
                     .into_iter()
-                    .map(to_dynamic_entity_response)
+                    .map(|entity| {
+                        DynamicEntityResponse::from_entity(entity, visible.as_deref(), None)
+                    })
                     .collect();
 
                 let page = query.pagination.get_page(1);
                 let per_page = query.pagination.get_per_page(20, 100);
 
-                ApiResponse::ok_paginated(entity_responses, total, page, per_page)
+                negotiation::paginated(format, entity_responses, &columns, total, page, per_page)
             }
             Err(e) => handle_entity_error(e, &entity_type),
         }
@@ -183,6 +310,111 @@ pub async fn list_entities(
     }
 }
 
+/// Answer a `count_only` listing with the number of entities matching the filter
+async fn count_entities(
+    data: &web::Data<ApiStateWrapper>,
+    entity_type: &str,
+    query: &StandardQuery,
+    owner: Option<Uuid>,
+) -> HttpResponse {
+    let filter = match count_filter(query) {
+        Ok(filter) => filter,
+        Err(response) => return response,
+    };
+    match DynamicEntityQueryRepository::new(data.db_pool().clone())
+        .with_owner(owner)
+        .count(entity_type, filter.as_ref())
+        .await
+    {
+        Ok(total) => ApiResponse::ok(json!({ "total": total })),
+        Err(e) => handle_entity_error(e, entity_type),
+    }
+}
+
+/// Fields named by the filter and the sort of a list query
+pub(super) fn criteria_fields(query: &StandardQuery) -> Vec<String> {
+    let mut fields: Vec<String> = match query.filter.parse_filter() {
+        Some(Value::Object(filter)) => filter.into_iter().map(|(field, _)| field).collect(),
+        _ => Vec::new(),
+    };
+    fields.extend(query.sorting.sort_by.clone());
+    fields
+}
+
+/// Field filter of a count or existence check, applied like the advanced query's filter
+///
+/// # Errors
+/// Returns a 400 response if the filter is not an object or a search text is given
+fn count_filter(query: &StandardQuery) -> Result<Option<HashMap<String, Value>>, HttpResponse> {
+    if query.filter.q.is_some() {
+        return Err(ApiResponse::<()>::bad_request(
+            "Search (q) is not supported when only counting entities",
+        ));
+    }
+    match query.filter.parse_filter() {
+        None => Ok(None),
+        Some(Value::Object(filter)) => Ok(Some(filter.into_iter().collect())),
+        Some(_) => Err(ApiResponse::<()>::bad_request("Filter must be an object")),
+    }
+}
+
+/// Check whether any entity of a type matches the filter, without fetching rows
+#[utoipa::path(
+    head,
+    path = "/api/v1/{entity_type}",
+    tag = "dynamic-entities",
+    params(
+        ("entity_type" = String, Path, description = "Type of entity to check"),
+        ("filter" = Option<HashMap<String, Value>>, Query, description = "Filter criteria, as for `count_only` listing")
+    ),
+    responses(
+        (status = 200, description = "At least one entity matches"),
+        (status = 400, description = "Bad request - invalid filter or filter by a field the caller may not read"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "API key lacks the required scope"),
+        (status = 404, description = "Entity type not found or no entity matches"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = []),
+        ("apiKey" = [])
+    )
+)]
+pub async fn entities_exist(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<String>,
+    query: web::Query<StandardQuery>,
+    auth: CombinedRequiredAuth,
+) -> HttpResponse {
+    let entity_type = path.into_inner();
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
+    let owner = match owner_scope(&data, &auth, &entity_type, &PermissionType::Read).await {
+        Ok(owner) => owner,
+        Err(response) => return response,
+    };
+    let filter = match count_filter(&query) {
+        Ok(filter) => filter,
+        Err(response) => return response,
+    };
+    if let Err(response) =
+        ensure_visible_criteria(&data, &auth, &entity_type, &criteria_fields(&query)).await
+    {
+        return response;
+    }
+
+    match DynamicEntityQueryRepository::new(data.db_pool().clone())
+        .with_owner(owner)
+        .exists(&entity_type, filter.as_ref())
+        .await
+    {
+        Ok(true) => HttpResponse::Ok().finish(),
+        Ok(false) => HttpResponse::NotFound().finish(),
+        Err(e) => handle_entity_error(e, &entity_type),
+    }
+}
+
 /// Handler for creating a new entity
 #[utoipa::path(
     post,
@@ -195,6 +427,7 @@ pub async fn list_entities(
     responses(
         (status = 201, description = "Entity created successfully", body = EntityResponse),
         (status = 400, description = "Invalid entity data"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 404, description = "Entity type not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -211,6 +444,9 @@ pub async fn create_entity(
     auth: CombinedRequiredAuth,
 ) -> HttpResponse {
     let entity_type = path.into_inner();
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
 
     // Get the user's UUID from either API key or JWT
     let Some(user_uuid) = auth.get_user_uuid() else {
@@ -318,14 +554,18 @@ pub async fn create_entity(
         ("uuid" = Uuid, Path, description = "Entity UUID"),
         ("include" = Option<String>, Query, description = "Comma-separated list of related entities to include"),
         ("include_children_count" = Option<bool>, Query, description = "Include count of child entities"),
-        ("fields" = Option<Vec<String>>, Query, description = "Fields to include in the response")
+        ("fields" = Option<Vec<String>>, Query, description = "Fields to include in the response"),
+        ("expand" = Option<String>, Query, description = "Comma-separated relation fields whose referenced entities are embedded inline (one level deep)")
     ),
     responses(
-        (status = 200, description = "Entity found", body = DynamicEntityResponse),
+        (status = 200, description = "Entity found (with `ETag` header)", body = DynamicEntityResponse),
+        (status = 304, description = "Entity unchanged since the `If-None-Match` ETag"),
         (status = 400, description = "Bad request - invalid parameters"),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 404, description = "Entity not found"),
-        (status = 422, description = "Invalid field requested"),
+        (status = 410, description = "Entity was deleted (only when deleted entities are exposed)"),
+        (status = 422, description = "Invalid field requested or field cannot be expanded"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -337,9 +577,21 @@ pub async fn get_entity(
     data: web::Data<ApiStateWrapper>,
     path: web::Path<(String, String)>,
     query: web::Query<StandardQuery>,
-    _: CombinedRequiredAuth,
+    if_none_match: Option<web::Header<IfNoneMatch>>,
+    auth: CombinedRequiredAuth,
 ) -> HttpResponse {
     let (entity_type, uuid_str) = path.into_inner();
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
+    let visible = match visible_fields(&data, &auth, &entity_type).await {
+        Ok(visible) => visible,
+        Err(response) => return response,
+    };
+    let owner = match owner_scope(&data, &auth, &entity_type, &PermissionType::Read).await {
+        Ok(owner) => owner,
+        Err(response) => return response,
+    };
     let fields = query.fields.get_fields();
     let _includes = query.include.get_includes();
     let include_children_count = query.include.should_include_children_count();
@@ -348,6 +600,18 @@ pub async fn get_entity(
     if let Err(response) = validate_requested_fields(&data, &entity_type, fields.as_ref()).await {
         return response;
     }
+    let expansions = match resolve_expansions(
+        &data,
+        &auth,
+        &entity_type,
+        &query.include.get_expands(),
+        visible.as_ref(),
+    )
+    .await
+    {
+        Ok(expansions) => expansions,
+        Err(response) => return response,
+    };
 
     // Parse UUID
     let Ok(uuid) = Uuid::parse_str(&uuid_str) else {
@@ -364,14 +628,30 @@ pub async fn get_entity(
             )
             .await
         {
-            Ok((Some(entity), children_count)) => {
-                let response =
-                    to_dynamic_entity_response_with_children_count(entity, children_count);
-                ApiResponse::ok(response)
+            // Entities of other owners are reported as missing, not forbidden
+            Ok((Some(entity), _)) if owner.is_some_and(|owner| !is_owned_by(&entity, owner)) => {
+                ApiResponse::<()>::not_found(&format!(
+                    "Entity of type '{entity_type}' with UUID '{uuid}' not found"
+                ))
+            }
+            Ok((Some(mut entity), children_count)) => {
+                let version = row_version(&entity);
+                redact_entity(&mut entity, visible.as_deref());
+                if let Err(response) =
+                    expand_entities(&data, &expansions, std::slice::from_mut(&mut entity)).await
+                {
+                    return response;
+                }
+                let response = DynamicEntityResponse::from_entity(entity, None, children_count);
+                ok_with_etag(if_none_match.as_deref(), &version, response)
             }
-            Ok((None, _)) => ApiResponse::<()>::not_found(&format!(
-                "Entity of type '{entity_type}' with UUID '{uuid}' not found"
-            )),
+            Ok((None, _)) => deleted_entity_response(&data, &entity_type, &uuid)
+                .await
+                .unwrap_or_else(|| {
+                    ApiResponse::<()>::not_found(&format!(
+                        "Entity of type '{entity_type}' with UUID '{uuid}' not found"
+                    ))
+                }),
             Err(e) => handle_entity_error(e, &entity_type),
         }
     } else {
@@ -392,7 +672,9 @@ pub async fn get_entity(
     responses(
         (status = 200, description = "Entity updated successfully", body = EntityResponse),
         (status = 400, description = "Invalid entity data"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 404, description = "Entity or field not found"),
+        (status = 410, description = "Entity was deleted (only when deleted entities are exposed)"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -408,6 +690,9 @@ pub async fn update_entity(
     auth: CombinedRequiredAuth,
 ) -> HttpResponse {
     let (entity_type, uuid_str) = path.into_inner();
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
     let Ok(uuid) = Uuid::parse_str(&uuid_str) else {
         return ApiResponse::<()>::bad_request(&format!("Invalid UUID: {uuid_str}"));
     };
@@ -418,10 +703,19 @@ pub async fn update_entity(
             "User UUID could not be determined from authentication",
         );
     };
+    let owner = match owner_scope(&data, &auth, &entity_type, &PermissionType::Update).await {
+        Ok(owner) => owner,
+        Err(response) => return response,
+    };
 
     if let Some(service) = data.dynamic_entity_service() {
         // First, we need to get the existing entity
         match service.get_entity_by_uuid(&entity_type, &uuid, None).await {
+            Ok(Some(entity)) if owner.is_some_and(|owner| !is_owned_by(&entity, owner)) => {
+                ApiResponse::<()>::not_found(&format!(
+                    "Entity of type '{entity_type}' with UUID '{uuid}' not found"
+                ))
+            }
             Ok(Some(mut existing_entity)) => {
                 // Update the entity with the new data
                 let mut new_data = entity_data.into_inner();
@@ -465,9 +759,13 @@ pub async fn update_entity(
                     }
                 }
             }
-            Ok(None) => ApiResponse::<()>::not_found(&format!(
-                "Entity with UUID {uuid} not found in type {entity_type}"
-            )),
+            Ok(None) => deleted_entity_response(&data, &entity_type, &uuid)
+                .await
+                .unwrap_or_else(|| {
+                    ApiResponse::<()>::not_found(&format!(
+                        "Entity with UUID {uuid} not found in type {entity_type}"
+                    ))
+                }),
             Err(e) => handle_entity_error(e, &entity_type),
         }
     } else {
@@ -486,7 +784,10 @@ pub async fn update_entity(
     ),
     responses(
         (status = 200, description = "Entity deleted successfully"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 404, description = "Entity not found"),
+        (status = 410, description = "Entity was already deleted (only when deleted entities are exposed)"),
+        (status = 409, description = "Entity is still referenced by restrict relations"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -497,16 +798,39 @@ pub async fn update_entity(
 pub async fn delete_entity(
     data: web::Data<ApiStateWrapper>,
     path: web::Path<(String, String)>,
-    _: CombinedRequiredAuth,
+    auth: CombinedRequiredAuth,
 ) -> HttpResponse {
     let (entity_type, uuid_str) = path.into_inner();
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
     let Ok(uuid) = Uuid::parse_str(&uuid_str) else {
         return ApiResponse::<()>::bad_request(&format!("Invalid UUID: {uuid_str}"));
     };
+    let owner = match owner_scope(&data, &auth, &entity_type, &PermissionType::Delete).await {
+        Ok(owner) => owner,
+        Err(response) => return response,
+    };
 
     if let Some(service) = data.dynamic_entity_service() {
+        if let Some(owner) = owner {
+            match service.get_entity_by_uuid(&entity_type, &uuid, None).await {
+                Ok(Some(entity)) if is_owned_by(&entity, owner) => {}
+                Ok(_) => {
+                    return ApiResponse::<()>::not_found(&format!(
+                        "Entity of type '{entity_type}' with UUID '{uuid}' not found"
+                    ))
+                }
+                Err(e) => return handle_entity_error(e, &entity_type),
+            }
+        }
         match service.delete_entity(&entity_type, &uuid).await {
             Ok(()) => ApiResponse::<()>::message("Successfully deleted the entity"),
+            Err(e @ r_data_core_core::error::Error::NotFound(_)) => {
+                deleted_entity_response(&data, &entity_type, &uuid)
+                    .await
+                    .unwrap_or_else(|| handle_entity_error(e, &entity_type))
+            }
             Err(e) => handle_entity_error(e, &entity_type),
         }
     } else {
@@ -526,8 +850,36 @@ fn extract_field_from_unique_message(msg: &str) -> String {
     "unknown".to_string()
 }
 
+/// `410 Gone` for a soft-deleted entity when the API is configured to expose deletions
+///
+/// Returns `None` when the entity was never there, when the check fails, or when deletions are
+/// hidden, so the caller answers with its regular `404`.
+async fn deleted_entity_response(
+    data: &web::Data<ApiStateWrapper>,
+    entity_type: &str,
+    uuid: &Uuid,
+) -> Option<HttpResponse> {
+    if !data.api_config().expose_deleted_entities {
+        return None;
+    }
+    let service = data.dynamic_entity_service()?;
+    match service.is_entity_deleted(entity_type, uuid).await {
+        Ok(true) => Some(ApiResponse::<()>::gone(&format!(
+            "Entity of type '{entity_type}' with UUID '{uuid}' has been deleted"
+        ))),
+        Ok(false) => None,
+        Err(e) => {
+            error!("Failed to check deletion state of {entity_type} {uuid}: {e}");
+            None
+        }
+    }
+}
+
 /// Helper function to handle entity-related errors
-fn handle_entity_error(error: r_data_core_core::error::Error, entity_type: &str) -> HttpResponse {
+pub(super) fn handle_entity_error(
+    error: r_data_core_core::error::Error,
+    entity_type: &str,
+) -> HttpResponse {
     match error {
         r_data_core_core::error::Error::NotFound(_) => ApiResponse::<()>::not_found(&format!(
             "Entity type '{entity_type}' not found or not published"
@@ -535,6 +887,13 @@ fn handle_entity_error(error: r_data_core_core::error::Error, entity_type: &str)
         r_data_core_core::error::Error::Validation(msg) => {
             ApiResponse::<()>::unprocessable_entity(&msg)
         }
+        r_data_core_core::error::Error::FieldValidation(errors) => {
+            ApiResponse::<()>::unprocessable_entity_with_violations(
+                "Validation failed",
+                ValidationViolation::from_field_errors(&errors),
+            )
+        }
+        r_data_core_core::error::Error::Conflict(msg) => ApiResponse::<()>::conflict(&msg),
         r_data_core_core::error::Error::Database(_) => {
             error!("Database error: {error}");
             ApiResponse::<()>::internal_error("Database error")
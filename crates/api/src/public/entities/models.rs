@@ -63,6 +63,7 @@ pub struct VersionMeta {
     pub created_at: time::OffsetDateTime,
     pub created_by: Option<Uuid>,
     pub created_by_name: Option<String>,
+    pub comment: Option<String>,
 }
 
 /// Version payload containing the actual entity data
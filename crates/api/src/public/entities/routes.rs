@@ -1,16 +1,25 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
-use actix_web::{get, post, web, HttpResponse, Responder};
+use actix_web::{get, http::header::IfNoneMatch, post, web, HttpResponse, Responder};
 use serde::Deserialize;
 use serde_json::json;
 use uuid::Uuid;
 
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
 use crate::auth::auth_enum::CombinedRequiredAuth;
+use crate::etag::ok_with_etag;
+use crate::middleware::RequireApiKeyScope;
 use crate::public::dynamic_entities::models::DynamicEntityResponse;
 use crate::public::entities::models::{EntityQueryRequest, VersionMeta, VersionPayload};
+use crate::public::entity_permissions::{
+    ensure_owned, ensure_permitted, hidden_fields, owner_scope, redact_schema, redact_snapshot,
+    visible_fields,
+};
+use crate::public::entity_type_guard::ensure_entity_type;
 use crate::response::ApiResponse;
 #[allow(unused_imports)] // Used in utoipa attributes for OpenAPI docs
+use r_data_core_core::admin_user::api_key_scopes;
+use r_data_core_core::permissions::role::PermissionType;
 use r_data_core_core::public_api::{BrowseNode, EntityTypeInfo};
 use r_data_core_persistence::DynamicEntityPublicRepository;
 use r_data_core_persistence::DynamicEntityRepository;
@@ -25,6 +34,7 @@ use r_data_core_services::VersionService;
     responses(
         (status = 200, description = "List of available entities", body = Vec<EntityTypeInfo>),
         (status = 401, description = "Unauthorized - No valid authentication provided"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -32,7 +42,10 @@ use r_data_core_services::VersionService;
         ("apiKey" = [])
     )
 )]
-#[get("/entities")]
+#[get(
+    "/entities",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::ENTITIES_READ)"
+)]
 pub async fn list_available_entities(
     data: web::Data<ApiStateWrapper>,
     _: CombinedRequiredAuth,
@@ -47,6 +60,64 @@ pub async fn list_available_entities(
     }
 }
 
+/// JSON Schema of an entity type, for building forms and validating payloads client-side
+#[utoipa::path(
+    get,
+    path = "/api/v1/entities/{entity_type}/schema",
+    tag = "public",
+    params(
+        ("entity_type" = String, Path, description = "Entity type")
+    ),
+    responses(
+        (status = 200, description = "JSON Schema (draft 2020-12) of the entity type's fields the caller may read", body = Object),
+        (status = 401, description = "Unauthorized - No valid authentication provided"),
+        (status = 403, description = "API key lacks the required scope or the caller may not read the entity type"),
+        (status = 404, description = "Entity type not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = []),
+        ("apiKey" = [])
+    )
+)]
+#[get(
+    "/entities/{entity_type}/schema",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::ENTITIES_READ)"
+)]
+pub async fn get_entity_schema(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<String>,
+    auth: CombinedRequiredAuth,
+) -> impl Responder {
+    let entity_type = path.into_inner();
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
+    if let Err(response) = ensure_permitted(&data, &auth, &entity_type, &PermissionType::Read).await
+    {
+        return response;
+    }
+    let hidden = match hidden_fields(&data, &auth, &entity_type).await {
+        Ok(hidden) => hidden,
+        Err(response) => return response,
+    };
+
+    match data
+        .entity_definition_service()
+        .json_schema(&entity_type)
+        .await
+    {
+        Ok(mut schema) => {
+            redact_schema(&mut schema, &hidden);
+            HttpResponse::Ok().json(schema)
+        }
+        Err(e) => {
+            log::error!("Failed to build JSON schema for '{entity_type}': {e}");
+            ApiResponse::<()>::internal_error("Failed to build JSON schema")
+        }
+    }
+}
+
 /// Register entity routes
 pub fn register_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(list_available_entities);
@@ -54,6 +125,7 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(query_entities);
     cfg.service(list_entity_versions);
     cfg.service(get_entity_version);
+    cfg.service(get_entity_schema);
 }
 
 #[derive(Debug, Deserialize)]
@@ -82,6 +154,7 @@ struct BrowseQuery {
     responses(
         (status = 200, description = "Browse result (folders first, then files)", body = Vec<BrowseNode>),
         (status = 401, description = "Unauthorized - No valid authentication provided"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -89,7 +162,10 @@ struct BrowseQuery {
         ("apiKey" = [])
     )
 )]
-#[get("/entities/by-path")]
+#[get(
+    "/entities/by-path",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::ENTITIES_READ)"
+)]
 pub async fn list_by_path(
     data: web::Data<ApiStateWrapper>,
     query: web::Query<BrowseQuery>,
@@ -141,9 +217,11 @@ pub async fn list_by_path(
         ("uuid" = Uuid, Path, description = "Entity UUID")
     ),
     responses(
-        (status = 200, description = "List of versions", body = Vec<VersionMeta>),
+        (status = 200, description = "List of versions (with `ETag` header)", body = Vec<VersionMeta>),
+        (status = 304, description = "Versions unchanged since the `If-None-Match` ETag"),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Entity not found"),
+        (status = 403, description = "API key lacks the required scope"),
+        (status = 404, description = "Entity or entity type not found"),
         (status = 500, description = "Server error")
     ),
     security(
@@ -151,13 +229,25 @@ pub async fn list_by_path(
         ("apiKey" = [])
     )
 )]
-#[get("/entities/{entity_type}/{uuid}/versions")]
+#[get(
+    "/entities/{entity_type}/{uuid}/versions",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::ENTITIES_READ)"
+)]
 pub async fn list_entity_versions(
     data: web::Data<ApiStateWrapper>,
     path: web::Path<(String, Uuid)>,
-    _: CombinedRequiredAuth,
+    if_none_match: Option<web::Header<IfNoneMatch>>,
+    auth: CombinedRequiredAuth,
 ) -> impl Responder {
-    let (_entity_type, uuid) = path.into_inner();
+    let (entity_type, uuid) = path.into_inner();
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
+    if let Err(response) =
+        ensure_owned(&data, &auth, &entity_type, uuid, &PermissionType::Read).await
+    {
+        return response;
+    }
 
     let version_service = VersionService::new(data.db_pool().clone());
 
@@ -173,9 +263,17 @@ pub async fn list_entity_versions(
                     created_at: v.created_at,
                     created_by: v.created_by,
                     created_by_name: v.created_by_name,
+                    comment: v.comment,
                 })
                 .collect();
-            ApiResponse::ok(out)
+            // A new version changes the list, so the newest version number marks it
+            let version = out
+                .iter()
+                .map(|v| v.version_number)
+                .max()
+                .unwrap_or_default()
+                .to_string();
+            ok_with_etag(if_none_match.as_deref(), &version, out)
         }
         Err(e) => {
             log::error!("Failed to list versions: {e}");
@@ -195,9 +293,11 @@ pub async fn list_entity_versions(
         ("version_number" = i32, Path, description = "Version number")
     ),
     responses(
-        (status = 200, description = "Version payload", body = VersionPayload),
+        (status = 200, description = "Version payload (with `ETag` header)", body = VersionPayload),
+        (status = 304, description = "Version unchanged since the `If-None-Match` ETag"),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Version not found"),
+        (status = 403, description = "API key lacks the required scope"),
+        (status = 404, description = "Version or entity type not found"),
         (status = 500, description = "Server error")
     ),
     security(
@@ -205,26 +305,44 @@ pub async fn list_entity_versions(
         ("apiKey" = [])
     )
 )]
-#[get("/entities/{entity_type}/{uuid}/versions/{version_number}")]
+#[get(
+    "/entities/{entity_type}/{uuid}/versions/{version_number}",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::ENTITIES_READ)"
+)]
 pub async fn get_entity_version(
     data: web::Data<ApiStateWrapper>,
     path: web::Path<(String, Uuid, i32)>,
-    _: CombinedRequiredAuth,
+    if_none_match: Option<web::Header<IfNoneMatch>>,
+    auth: CombinedRequiredAuth,
 ) -> impl Responder {
     let (entity_type, uuid, version_number) = path.into_inner();
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
+    if let Err(response) =
+        ensure_owned(&data, &auth, &entity_type, uuid, &PermissionType::Read).await
+    {
+        return response;
+    }
+    let hidden = match hidden_fields(&data, &auth, &entity_type).await {
+        Ok(hidden) => hidden,
+        Err(response) => return response,
+    };
 
     let repo = VersionRepository::new(data.db_pool().clone());
 
     // First try to get from versions table
     match repo.get_entity_version(uuid, version_number).await {
-        Ok(Some(row)) => {
+        Ok(Some(mut row)) => {
+            redact_snapshot(&mut row.data, &hidden);
             let payload = VersionPayload {
                 version_number: row.version_number,
                 created_at: row.created_at,
                 created_by: row.created_by,
                 data: row.data,
             };
-            return ApiResponse::ok(payload);
+            let version = format!("{}:{}", payload.version_number, payload.created_at);
+            return ok_with_etag(if_none_match.as_deref(), &version, payload);
         }
         Ok(None) => {
             // Not in versions table, check if it's the current version
@@ -235,16 +353,18 @@ pub async fn get_entity_version(
             {
                 if current_version == version_number {
                     // This is the current version, fetch from entity view using repository
-                    if let Ok(Some(data_json)) =
+                    if let Ok(Some(mut data_json)) =
                         repo.get_current_entity_data(uuid, &entity_type).await
                     {
+                        redact_snapshot(&mut data_json, &hidden);
                         let payload = VersionPayload {
                             version_number,
                             created_at: updated_at,
                             created_by: updated_by,
                             data: data_json,
                         };
-                        return ApiResponse::ok(payload);
+                        let version = format!("{version_number}:{updated_at}");
+                        return ok_with_etag(if_none_match.as_deref(), &version, payload);
                     }
                 }
             }
@@ -271,6 +391,8 @@ pub async fn get_entity_version(
     responses(
         (status = 200, description = "List of entities matching the query", body = Vec<DynamicEntityResponse>),
         (status = 401, description = "Unauthorized - No valid authentication provided"),
+        (status = 403, description = "API key lacks the required scope"),
+        (status = 404, description = "Unknown entity type"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -278,12 +400,26 @@ pub async fn get_entity_version(
         ("apiKey" = [])
     )
 )]
-#[post("/entities/query")]
+#[post(
+    "/entities/query",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::ENTITIES_READ)"
+)]
 pub async fn query_entities(
     data: web::Data<ApiStateWrapper>,
     body: web::Json<EntityQueryRequest>,
-    _: CombinedRequiredAuth,
+    auth: CombinedRequiredAuth,
 ) -> impl Responder {
+    if let Err(response) = ensure_entity_type(&data, &body.entity_type).await {
+        return response;
+    }
+    let visible = match visible_fields(&data, &auth, &body.entity_type).await {
+        Ok(visible) => visible,
+        Err(response) => return response,
+    };
+    let owner = match owner_scope(&data, &auth, &body.entity_type, &PermissionType::Read).await {
+        Ok(owner) => owner,
+        Err(response) => return response,
+    };
     let repository = DynamicEntityRepository::new(data.db_pool().clone());
 
     let limit = body.limit.unwrap_or(20).clamp(1, 100);
@@ -293,13 +429,13 @@ pub async fn query_entities(
         (Some(parent_uuid), _) => {
             // If parent_uuid is provided, use query_by_parent
             repository
-                .query_by_parent(&body.entity_type, parent_uuid, limit, offset)
+                .query_by_parent(&body.entity_type, parent_uuid, owner, limit, offset)
                 .await
         }
         (None, Some(path)) => {
             // If only path is provided, use query_by_path
             repository
-                .query_by_path(&body.entity_type, path, limit, offset)
+                .query_by_path(&body.entity_type, path, owner, limit, offset)
                 .await
         }
         (None, None) => {
@@ -311,14 +447,9 @@ pub async fn query_entities(
 
     match result {
         Ok(entities) => {
-            // Convert DynamicEntity to DynamicEntityResponse
             let responses: Vec<DynamicEntityResponse> = entities
                 .into_iter()
-                .map(|e| DynamicEntityResponse {
-                    entity_type: e.entity_type,
-                    field_data: e.field_data,
-                    children_count: None,
-                })
+                .map(|e| DynamicEntityResponse::from_entity(e, visible.as_deref(), None))
                 .collect();
 
             ApiResponse::ok(responses)
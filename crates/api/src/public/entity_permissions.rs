@@ -0,0 +1,364 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::{web, HttpResponse};
+use log::error;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::auth::auth_enum::CombinedRequiredAuth;
+use crate::response::ApiResponse;
+use r_data_core_core::permissions::role::{AccessLevel, PermissionType, Role};
+use r_data_core_core::permissions::{
+    DefaultPermissionService, PermissionChecker, PermissionContext, RolePermissionRepository,
+    ROLE_PERMISSIONS_CACHE_TTL_SECONDS,
+};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::dynamic_entity_mapper::retain_visible_fields;
+use r_data_core_persistence::{AdminUserRepository, ApiKeyRepository};
+use serde_json::Value;
+
+/// Permission checker over the caller's roles, caching resolved permissions per role set
+fn permission_checker(
+    data: &web::Data<ApiStateWrapper>,
+    roles: Vec<Role>,
+) -> DefaultPermissionService<RolePermissionRepository> {
+    DefaultPermissionService::new(RolePermissionRepository::new(roles)).with_cache(
+        data.cache_manager().clone(),
+        ROLE_PERMISSIONS_CACHE_TTL_SECONDS,
+    )
+}
+
+/// Resolve which fields of `entity_type` the caller may read.
+///
+/// Roles are loaded for admin JWTs and API keys; other callers (pre-shared keys, entity JWTs)
+/// and super admins see every field.
+///
+/// # Returns
+/// `None` when no field is hidden, otherwise the visible definition fields
+///
+/// # Errors
+/// Returns a 401 response for an unreadable token subject and a 500 response if the caller's
+/// roles or the entity definition cannot be loaded
+pub async fn visible_fields(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &CombinedRequiredAuth,
+    entity_type: &str,
+) -> Result<Option<Vec<String>>, HttpResponse> {
+    Ok(field_access(data, auth, entity_type)
+        .await?
+        .and_then(|(fields, visible)| (visible.len() < fields.len()).then_some(visible)))
+}
+
+/// Resolve which fields of `entity_type` the caller may not read, for data that is not loaded
+/// as an entity, e.g. version snapshots
+///
+/// # Errors
+/// Returns a 401 response for an unreadable token subject and a 500 response if the caller's
+/// roles or the entity definition cannot be loaded
+pub async fn hidden_fields(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &CombinedRequiredAuth,
+    entity_type: &str,
+) -> Result<Vec<String>, HttpResponse> {
+    Ok(field_access(data, auth, entity_type)
+        .await?
+        .map(|(fields, visible)| {
+            fields
+                .into_iter()
+                .filter(|field| !visible.contains(field))
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// Definition fields of `entity_type` and the subset the caller may read, or `None` when the
+/// caller's roles are not checked
+async fn field_access(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &CombinedRequiredAuth,
+    entity_type: &str,
+) -> Result<Option<(Vec<String>, Vec<String>)>, HttpResponse> {
+    let Some((user_uuid, roles)) = caller_roles(data, auth).await? else {
+        return Ok(None);
+    };
+    if roles.iter().any(|role| role.super_admin) {
+        return Ok(None);
+    }
+
+    let definition = data
+        .entity_definition_service()
+        .get_entity_definition_by_entity_type(entity_type)
+        .await
+        .map_err(|e| {
+            error!("Failed to load entity definition '{entity_type}': {e}");
+            ApiResponse::<()>::internal_error("Internal server error")
+        })?;
+    let fields: Vec<String> = definition.fields.iter().map(|f| f.name.clone()).collect();
+
+    let ctx = PermissionContext {
+        user_uuid,
+        organization_uuid: None,
+        roles: roles.iter().map(|role| role.name.clone()).collect(),
+    };
+    let checker = permission_checker(data, roles);
+    let visible = checker
+        .filter_visible_fields(&ctx, entity_type, &fields)
+        .await
+        .map_err(|e| {
+            error!("Failed to resolve visible fields of '{entity_type}': {e}");
+            ApiResponse::<()>::internal_error("Internal server error")
+        })?;
+
+    Ok(Some((fields, visible)))
+}
+
+/// Answer 400 when the filter or sort of a query names a field hidden from the caller, whose
+/// values would otherwise show through which entities match and how they are ordered
+///
+/// # Errors
+/// Returns a 400 response naming the hidden fields, a 401 response for an unreadable token
+/// subject and a 500 response if the caller's roles or the entity definition cannot be loaded
+pub async fn ensure_visible_criteria(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &CombinedRequiredAuth,
+    entity_type: &str,
+    criteria: &[String],
+) -> Result<(), HttpResponse> {
+    if criteria.is_empty() {
+        return Ok(());
+    }
+    let hidden = hidden_fields(data, auth, entity_type).await?;
+    let named: Vec<&str> = criteria
+        .iter()
+        .filter(|field| hidden.iter().any(|h| h.eq_ignore_ascii_case(field)))
+        .map(String::as_str)
+        .collect();
+    if named.is_empty() {
+        Ok(())
+    } else {
+        Err(ApiResponse::<()>::bad_request(&format!(
+            "Cannot filter or sort by fields you may not read: {}",
+            named.join(", ")
+        )))
+    }
+}
+
+/// Remove the definition fields outside `visible` from an entity about to be returned
+///
+/// Used by [`DynamicEntityResponse::from_entity`] and by every read path that returns entities
+/// in another shape; system fields are always kept.
+///
+/// [`DynamicEntityResponse::from_entity`]: crate::public::dynamic_entities::models::DynamicEntityResponse::from_entity
+pub fn redact_entity(entity: &mut DynamicEntity, visible: Option<&[String]>) {
+    if let Some(visible) = visible {
+        retain_visible_fields(entity, visible);
+    }
+}
+
+/// Remove `hidden` fields from a stored snapshot of an entity
+pub fn redact_snapshot(snapshot: &mut Value, hidden: &[String]) {
+    if let Some(object) = snapshot.as_object_mut() {
+        for field in hidden {
+            object.remove(field);
+        }
+    }
+}
+
+/// Remove `hidden` fields from a JSON Schema of an entity type, along with the `allOf`
+/// conditions naming them
+pub fn redact_schema(schema: &mut Value, hidden: &[String]) {
+    if hidden.is_empty() {
+        return;
+    }
+    let is_hidden = |field: &Value| {
+        field
+            .as_str()
+            .is_some_and(|f| hidden.iter().any(|h| h == f))
+    };
+    if let Some(properties) = schema.get_mut("properties").and_then(Value::as_object_mut) {
+        properties.retain(|field, _| !hidden.contains(field));
+    }
+    if let Some(required) = schema.get_mut("required").and_then(Value::as_array_mut) {
+        required.retain(|field| !is_hidden(field));
+    }
+    if let Some(conditions) = schema.get_mut("allOf").and_then(Value::as_array_mut) {
+        conditions.retain(|condition| {
+            ["if", "then"].iter().all(|key| {
+                condition[*key]["required"]
+                    .as_array()
+                    .is_none_or(|fields| !fields.iter().any(is_hidden))
+            })
+        });
+        if conditions.is_empty() {
+            if let Some(object) = schema.as_object_mut() {
+                object.remove("allOf");
+            }
+        }
+    }
+}
+
+/// Answer 403 unless the caller's roles grant `action` on entities of `entity_type`, for
+/// routes that are not otherwise restricted by the caller's permissions
+///
+/// Callers without roles (pre-shared keys, entity JWTs) and super admins pass.
+///
+/// # Errors
+/// Returns a 403 response without the permission, a 401 response for an unreadable token
+/// subject and a 500 response if the caller's roles cannot be loaded
+pub async fn ensure_permitted(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &CombinedRequiredAuth,
+    entity_type: &str,
+    action: &PermissionType,
+) -> Result<(), HttpResponse> {
+    let Some((user_uuid, roles)) = caller_roles(data, auth).await? else {
+        return Ok(());
+    };
+    if roles.iter().any(|role| role.super_admin) {
+        return Ok(());
+    }
+
+    let ctx = PermissionContext {
+        user_uuid,
+        organization_uuid: None,
+        roles: roles.iter().map(|role| role.name.clone()).collect(),
+    };
+    let level = permission_checker(data, roles)
+        .entity_access_level(&ctx, entity_type, action)
+        .await
+        .map_err(|e| {
+            error!("Failed to resolve access level for '{entity_type}': {e}");
+            ApiResponse::<()>::internal_error("Internal server error")
+        })?;
+    if level == AccessLevel::None {
+        return Err(ApiResponse::<()>::forbidden(&format!(
+            "No {action} permission for entities of type '{entity_type}'"
+        )));
+    }
+    Ok(())
+}
+
+/// Resolve whether the caller is limited to entities of `entity_type` they created.
+///
+/// Checked before querying: when the widest level the caller's roles grant for `action` is
+/// `AccessLevel::Own`, the caller's UUID is returned and must be used to restrict the query.
+/// Any other outcome leaves access as decided by the route's own checks.
+///
+/// # Errors
+/// Returns a 401 response for an unreadable token subject and a 500 response if the caller's
+/// roles cannot be loaded
+pub async fn owner_scope(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &CombinedRequiredAuth,
+    entity_type: &str,
+    action: &PermissionType,
+) -> Result<Option<Uuid>, HttpResponse> {
+    let Some((user_uuid, roles)) = caller_roles(data, auth).await? else {
+        return Ok(None);
+    };
+    if roles.iter().any(|role| role.super_admin) {
+        return Ok(None);
+    }
+
+    let ctx = PermissionContext {
+        user_uuid,
+        organization_uuid: None,
+        roles: roles.iter().map(|role| role.name.clone()).collect(),
+    };
+    let checker = permission_checker(data, roles);
+    let level = checker
+        .entity_access_level(&ctx, entity_type, action)
+        .await
+        .map_err(|e| {
+            error!("Failed to resolve access level for '{entity_type}': {e}");
+            ApiResponse::<()>::internal_error("Internal server error")
+        })?;
+
+    Ok((level == AccessLevel::Own).then_some(user_uuid))
+}
+
+/// Answer 404 when the caller is limited to their own entities of `entity_type` and `uuid` is
+/// not one of them, for routes that read entity data without loading the entity
+///
+/// # Errors
+/// Returns a 404 response for entities of other owners (or missing ones), a 401 response for an
+/// unreadable token subject and a 500 response if the caller's roles or the entity cannot be
+/// loaded
+pub async fn ensure_owned(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &CombinedRequiredAuth,
+    entity_type: &str,
+    uuid: Uuid,
+    action: &PermissionType,
+) -> Result<(), HttpResponse> {
+    let Some(owner) = owner_scope(data, auth, entity_type, action).await? else {
+        return Ok(());
+    };
+    let Some(service) = data.dynamic_entity_service() else {
+        return Err(ApiResponse::<()>::internal_error(
+            "Dynamic entity service not initialized",
+        ));
+    };
+    let entity = service
+        .get_entity_by_uuid(entity_type, &uuid, None)
+        .await
+        .map_err(|e| {
+            error!("Failed to load entity '{uuid}' for ownership check: {e}");
+            ApiResponse::<()>::internal_error("Internal server error")
+        })?;
+    if entity.is_some_and(|entity| is_owned_by(&entity, owner)) {
+        Ok(())
+    } else {
+        Err(ApiResponse::<()>::not_found(&format!(
+            "Entity of type '{entity_type}' with UUID '{uuid}' not found"
+        )))
+    }
+}
+
+/// Whether `entity` was created by `owner`
+#[must_use]
+pub fn is_owned_by(entity: &DynamicEntity, owner: Uuid) -> bool {
+    entity
+        .field_data
+        .get("created_by")
+        .and_then(serde_json::Value::as_str)
+        .and_then(|created_by| Uuid::parse_str(created_by).ok())
+        == Some(owner)
+}
+
+/// Roles of the caller, or `None` for callers without roles or with the super admin flag
+///
+/// # Errors
+/// Returns a 401 response for a JWT whose subject is not a user UUID and a 500 response if the
+/// roles cannot be loaded
+async fn caller_roles(
+    data: &web::Data<ApiStateWrapper>,
+    auth: &CombinedRequiredAuth,
+) -> Result<Option<(Uuid, Vec<Role>)>, HttpResponse> {
+    let pool = Arc::new(data.db_pool().clone());
+    let roles = if let Some(claims) = &auth.jwt_claims {
+        if claims.is_super_admin {
+            return Ok(None);
+        }
+        // An unreadable subject must not skip the permission filters
+        let Ok(user_uuid) = Uuid::parse_str(&claims.sub) else {
+            return Err(ApiResponse::<()>::unauthorized("Invalid token subject"));
+        };
+        data.role_service()
+            .get_roles_for_user(user_uuid, &AdminUserRepository::new(pool))
+            .await
+            .map(|roles| (user_uuid, roles))
+    } else if let Some(key) = &auth.api_key_info {
+        data.role_service()
+            .get_roles_for_api_key(key.uuid, &ApiKeyRepository::new(pool))
+            .await
+            .map(|roles| (key.user_uuid, roles))
+    } else {
+        return Ok(None);
+    };
+    roles.map(Some).map_err(|e| {
+        error!("Failed to load caller roles: {e}");
+        ApiResponse::<()>::internal_error("Internal server error")
+    })
+}
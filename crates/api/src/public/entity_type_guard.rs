@@ -0,0 +1,34 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::{web, HttpResponse};
+use log::error;
+
+use crate::api_state::{ApiStateTrait, ApiStateWrapper};
+use crate::response::ApiResponse;
+
+/// Resolve `entity_type` against the published entity definitions before touching the data layer.
+///
+/// Definitions are read through the entity definition service cache, so a known type costs no
+/// extra query once it has been loaded.
+///
+/// # Errors
+/// Returns the response to send: 404 for unknown or unpublished types, 500 if the lookup fails
+pub async fn ensure_entity_type(
+    data: &web::Data<ApiStateWrapper>,
+    entity_type: &str,
+) -> Result<(), HttpResponse> {
+    match data
+        .entity_definition_service()
+        .get_entity_definition_by_entity_type(entity_type)
+        .await
+    {
+        Ok(definition) if definition.published => Ok(()),
+        Ok(_) | Err(r_data_core_core::error::Error::NotFound(_)) => Err(
+            ApiResponse::<()>::not_found(&format!("Entity type '{entity_type}'")),
+        ),
+        Err(e) => {
+            error!("Failed to resolve entity type '{entity_type}': {e}");
+            Err(ApiResponse::<()>::internal_error("Internal server error"))
+        }
+    }
+}
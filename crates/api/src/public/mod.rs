@@ -2,8 +2,13 @@
 
 use actix_web::web;
 
+use crate::middleware::ApiKeyRateLimit;
+
 pub mod dynamic_entities;
 pub mod entities;
+pub mod entity_permissions;
+pub mod entity_type_guard;
+pub mod notifications;
 pub mod queries;
 pub mod workflows;
 
@@ -11,8 +16,10 @@ pub mod workflows;
 pub fn register_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/api/v1")
+            .wrap(ApiKeyRateLimit::default())
             .configure(entities::register_routes)
             .configure(queries::register_routes)
+            .configure(notifications::register_routes)
             .configure(workflows::register_routes) // Register workflows BEFORE dynamic_entities to avoid route conflicts
             .configure(dynamic_entities::register_routes),
     );
@@ -0,0 +1,5 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+pub mod routes;
+
+pub use routes::register_routes;
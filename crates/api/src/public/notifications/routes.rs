@@ -0,0 +1,74 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::{get, post, web, Responder};
+use uuid::Uuid;
+
+use crate::admin::notifications::models::{NotificationListQuery, NotificationResponse};
+use crate::admin::notifications::routes::{list_for_user, mark_read_for_user};
+use crate::api_state::ApiStateWrapper;
+use crate::auth::auth_enum::CombinedRequiredAuth;
+use crate::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/notifications",
+    tag = "notifications",
+    params(NotificationListQuery),
+    responses(
+        (status = 200, description = "Notifications of the authenticated user (or API key owner), newest first", body = [NotificationResponse]),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Server error")
+    ),
+    security(
+        ("jwt" = []),
+        ("apiKey" = [])
+    )
+)]
+#[get("")]
+pub async fn list_notifications(
+    data: web::Data<ApiStateWrapper>,
+    query: web::Query<NotificationListQuery>,
+    auth: CombinedRequiredAuth,
+) -> impl Responder {
+    let Some(user_uuid) = auth.get_user_uuid() else {
+        return ApiResponse::<()>::unauthorized("Notifications require a user or API key");
+    };
+    list_for_user(&data, user_uuid, query.include_read).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/notifications/{uuid}/read",
+    tag = "notifications",
+    params(("uuid" = Uuid, Path, description = "Notification UUID")),
+    responses(
+        (status = 200, description = "Marked as read"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Notification not found"),
+        (status = 500, description = "Server error")
+    ),
+    security(
+        ("jwt" = []),
+        ("apiKey" = [])
+    )
+)]
+#[post("/{uuid}/read")]
+pub async fn mark_notification_read(
+    data: web::Data<ApiStateWrapper>,
+    path: web::Path<Uuid>,
+    auth: CombinedRequiredAuth,
+) -> impl Responder {
+    let Some(user_uuid) = auth.get_user_uuid() else {
+        return ApiResponse::<()>::unauthorized("Notifications require a user or API key");
+    };
+    mark_read_for_user(&data, user_uuid, path.into_inner()).await
+}
+
+/// Register notification routes
+pub fn register_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/notifications")
+            .service(list_notifications)
+            .service(mark_notification_read),
+    );
+}
@@ -2,9 +2,18 @@
 
 use actix_web::{post, web, HttpResponse, Responder};
 use serde_json::json;
+use std::collections::HashMap;
 
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
 use crate::auth::auth_enum::CombinedRequiredAuth;
+use crate::middleware::RequireApiKeyScope;
+use crate::public::entity_permissions::{
+    ensure_visible_criteria, owner_scope, redact_entity, visible_fields,
+};
+use crate::public::entity_type_guard::ensure_entity_type;
+use crate::query::RelationCountsQuery;
+use r_data_core_core::admin_user::api_key_scopes;
+use r_data_core_core::permissions::role::PermissionType;
 use r_data_core_core::public_api::AdvancedEntityQuery;
 use r_data_core_core::DynamicEntity;
 use r_data_core_persistence::DynamicEntityQueryRepository;
@@ -15,12 +24,15 @@ use r_data_core_persistence::DynamicEntityQueryRepository;
     path = "/api/v1/{entity_type}/query",
     tag = "public",
     params(
-        ("entity_type" = String, Path, description = "Entity type to query")
+        ("entity_type" = String, Path, description = "Entity type to query"),
+        ("include_counts" = Option<String>, Query, description = "Comma-separated ManyToOne relations referencing this type to count (field name, referencing type, or type.field); adds `<relation>_count` to each result")
     ),
     request_body = AdvancedEntityQuery,
     responses(
         (status = 200, description = "Query results", body = Vec<DynamicEntity>),
+        (status = 400, description = "Unknown or ambiguous relation in include_counts, or filter or sort by a field the caller may not read"),
         (status = 401, description = "Unauthorized - No valid authentication provided"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 404, description = "Entity type not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -29,25 +41,58 @@ use r_data_core_persistence::DynamicEntityQueryRepository;
         ("apiKey" = [])
     )
 )]
-#[post("/{entity_type}/query")]
+#[post(
+    "/{entity_type}/query",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::ENTITIES_READ)"
+)]
 pub async fn query_entities(
     data: web::Data<ApiStateWrapper>,
     path: web::Path<String>,
     query: web::Json<AdvancedEntityQuery>,
-    _: CombinedRequiredAuth,
+    counts: web::Query<RelationCountsQuery>,
+    auth: CombinedRequiredAuth,
 ) -> impl Responder {
     let entity_type = path.into_inner();
-    let repository = DynamicEntityQueryRepository::new(data.db_pool().clone());
+    if let Err(response) = ensure_entity_type(&data, &entity_type).await {
+        return response;
+    }
+    let visible = match visible_fields(&data, &auth, &entity_type).await {
+        Ok(visible) => visible,
+        Err(response) => return response,
+    };
+    let owner = match owner_scope(&data, &auth, &entity_type, &PermissionType::Read).await {
+        Ok(owner) => owner,
+        Err(response) => return response,
+    };
+    let criteria: Vec<String> = query
+        .filter
+        .iter()
+        .flat_map(HashMap::keys)
+        .chain(&query.sort_by)
+        .cloned()
+        .collect();
+    if let Err(response) = ensure_visible_criteria(&data, &auth, &entity_type, &criteria).await {
+        return response;
+    }
+    let repository = DynamicEntityQueryRepository::new(data.db_pool().clone()).with_owner(owner);
 
     match repository
-        .query_entities(&entity_type, &query.into_inner())
+        .query_entities_with_counts(&entity_type, &query.into_inner(), &counts.get_relations())
         .await
     {
-        Ok(entities) => HttpResponse::Ok().json(entities),
+        Ok(mut entities) => {
+            for entity in &mut entities {
+                redact_entity(entity, visible.as_deref());
+            }
+            HttpResponse::Ok().json(entities)
+        }
         Err(e) => match e {
             r_data_core_core::error::Error::NotFound(msg) => HttpResponse::NotFound().json(json!({
                 "error": msg
             })),
+            r_data_core_core::error::Error::Validation(msg) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
             _ => HttpResponse::InternalServerError().json(json!({
                 "error": format!("Server error: {e}")
             })),
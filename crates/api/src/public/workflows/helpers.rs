@@ -1,6 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 #![allow(clippy::future_not_send)] // Async functions called from Actix handlers which are !Send
 
+use actix_web::http::header;
 use actix_web::{dev::Payload, web, FromRequest, HttpMessage, HttpRequest, HttpResponse};
 use serde_json::json;
 use serde_json::Value as JsonValue;
@@ -269,3 +270,36 @@ pub(super) fn extract_provider_auth_config(config: &serde_json::Value) -> Option
         .get("provider_auth")
         .and_then(|v| serde_json::from_value::<AuthConfig>(v.clone()).ok())
 }
+
+/// Read an ingest request body of at most `max_body_bytes`
+///
+/// A declared `Content-Length` above the limit is rejected before anything is read.
+///
+/// # Errors
+/// Returns a 413 response if the body exceeds the limit, or 400 if it cannot be read
+pub(super) async fn read_ingest_body(
+    req: &HttpRequest,
+    payload: web::Payload,
+    max_body_bytes: usize,
+) -> Result<web::Bytes, HttpResponse> {
+    let too_large = || {
+        HttpResponse::PayloadTooLarge().json(json!({
+            "error": "Payload too large",
+            "message": format!("The request body exceeds the limit of {max_body_bytes} bytes")
+        }))
+    };
+    let declared = req
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok());
+    if declared.is_some_and(|len| len > max_body_bytes) {
+        return Err(too_large());
+    }
+    match payload.to_bytes_limited(max_body_bytes).await {
+        Ok(Ok(body)) => Ok(body),
+        Ok(Err(e)) => Err(HttpResponse::BadRequest()
+            .json(json!({"error": format!("Failed to read request body: {e}")}))),
+        Err(_) => Err(too_large()),
+    }
+}
@@ -11,6 +11,7 @@ use r_data_core_core::error::Error;
 use r_data_core_workflow::data::adapters::format::csv::CsvFormatHandler;
 use r_data_core_workflow::data::adapters::format::json::JsonFormatHandler;
 use r_data_core_workflow::data::adapters::format::FormatHandler;
+use r_data_core_workflow::data::IdempotencyClaim;
 use r_data_core_workflow::dsl::{DslProgram, FormatConfig};
 
 use super::helpers::{
@@ -51,7 +52,7 @@ pub(super) async fn handle_provider_workflow(
     let run_uuid_param = query.run_uuid;
 
     if async_mode {
-        if let Some(resp) = match handle_async_get(uuid, run_uuid_param, state).await {
+        if let Some(resp) = match handle_async_get(uuid, run_uuid_param, state, None).await {
             Ok(resp_opt) => resp_opt,
             Err(resp) => return resp,
         } {
@@ -143,6 +144,7 @@ pub(super) async fn handle_trigger_consumer_workflow(
     workflow: &r_data_core_workflow::data::Workflow,
     state: &web::Data<ApiStateWrapper>,
     query: &web::Query<WorkflowQuery>,
+    config_override: Option<&JsonValue>,
 ) -> HttpResponse {
     // Check if workflow is enabled
     if !workflow.enabled {
@@ -157,13 +159,23 @@ pub(super) async fn handle_trigger_consumer_workflow(
         return resp;
     }
 
-    // Create a run
-    let run_uuid = match state.workflow_service().enqueue_run(uuid).await {
+    // Create a run (with the per-run config override, if any)
+    let run_uuid = match state
+        .workflow_service()
+        .enqueue_run_with_override(uuid, config_override)
+        .await
+    {
         Ok(run_uuid) => run_uuid,
         Err(Error::NotFound(msg)) => {
             log::error!("Workflow not found: {msg}");
             return HttpResponse::NotFound().json(json!({"error": "Workflow not found"}));
         }
+        Err(Error::Validation(msg) | Error::Deserialization(msg)) => {
+            return HttpResponse::BadRequest().json(json!({
+                "error": "Invalid config override",
+                "details": msg
+            }));
+        }
         Err(e) => {
             log::error!("Failed to enqueue run: {e}");
             return HttpResponse::InternalServerError()
@@ -176,10 +188,12 @@ pub(super) async fn handle_trigger_consumer_workflow(
     let run_uuid_param = query.run_uuid;
 
     if async_mode {
-        if let Some(resp) = match handle_async_get(uuid, run_uuid_param, state).await {
-            Ok(resp_opt) => resp_opt,
-            Err(resp) => return resp,
-        } {
+        if let Some(resp) =
+            match handle_async_get(uuid, run_uuid_param, state, config_override).await {
+                Ok(resp_opt) => resp_opt,
+                Err(resp) => return resp,
+            }
+        {
             return resp;
         }
     }
@@ -276,7 +290,12 @@ pub(super) async fn handle_inline_auth_workflow(
     {
         Ok(output) => HttpResponse::Ok().json(output),
         Err(Error::Auth(msg)) => HttpResponse::Unauthorized().json(json!({"error": msg})),
-        Err(Error::Validation(msg)) => HttpResponse::BadRequest().json(json!({"error": msg})),
+        Err(Error::Validation(msg) | Error::Deserialization(msg)) => {
+            HttpResponse::BadRequest().json(json!({"error": msg}))
+        }
+        Err(Error::FieldValidation(errors)) => {
+            HttpResponse::BadRequest().json(json!({"error": "Validation failed", "fields": errors}))
+        }
         Err(e) => {
             log::error!("Inline auth workflow {uuid} failed: {e}");
             HttpResponse::InternalServerError().json(json!({"error": "Internal server error"}))
@@ -337,13 +356,20 @@ async fn serialize_api_output(
 async fn enqueue_run_for_api(
     workflow_uuid: Uuid,
     state: &web::Data<ApiStateWrapper>,
+    config_override: Option<&JsonValue>,
 ) -> Result<Uuid, HttpResponse> {
     let run_uuid = match state
         .workflow_service()
-        .enqueue_run_for_fetch(workflow_uuid, None)
+        .enqueue_run_for_fetch_with_override(workflow_uuid, config_override)
         .await
     {
         Ok(result) => result,
+        Err(Error::Validation(msg) | Error::Deserialization(msg)) => {
+            return Err(HttpResponse::BadRequest().json(json!({
+                "error": "Invalid config override",
+                "details": msg
+            })));
+        }
         Err(e) => {
             log::error!("Failed to enqueue run: {e}");
             return Err(HttpResponse::InternalServerError()
@@ -357,10 +383,11 @@ async fn handle_async_get(
     workflow_uuid: Uuid,
     run_uuid_param: Option<Uuid>,
     state: &web::Data<ApiStateWrapper>,
+    config_override: Option<&JsonValue>,
 ) -> Result<Option<HttpResponse>, HttpResponse> {
     // If no run_uuid provided, enqueue and return queued
     let Some(run_uuid) = run_uuid_param else {
-        let run_uuid = enqueue_run_for_api(workflow_uuid, state).await?;
+        let run_uuid = enqueue_run_for_api(workflow_uuid, state, config_override).await?;
         return Ok(Some(HttpResponse::Accepted().json(json!({
             "status": "queued",
             "run_uuid": run_uuid,
@@ -396,3 +423,180 @@ async fn handle_async_get(
         }
     }
 }
+
+/// Stage an ingest payload as a new run and dispatch its processing
+///
+/// With an `Idempotency-Key` header, a key already used for this workflow replays the
+/// original response instead of staging the payload again. If the request fails, the key is
+/// released so the client can retry with it.
+pub(super) async fn handle_staged_ingest(
+    uuid: Uuid,
+    body: &[u8],
+    req: &HttpRequest,
+    state: &web::Data<ApiStateWrapper>,
+) -> HttpResponse {
+    let idempotency_key = match idempotency_key(req) {
+        Ok(key) => key,
+        Err(resp) => return resp,
+    };
+    let mut claim = None;
+    if let Some(key) = idempotency_key {
+        match state
+            .workflow_service()
+            .claim_ingest_idempotency_key(uuid, &key)
+            .await
+        {
+            Ok(IdempotencyClaim::Claimed) => {
+                claim = Some(ClaimedIdempotencyKey {
+                    state: state.clone(),
+                    workflow_uuid: uuid,
+                    key: Some(key),
+                });
+            }
+            Ok(IdempotencyClaim::Replay(response)) => {
+                return HttpResponse::Accepted()
+                    .insert_header(("Idempotent-Replayed", "true"))
+                    .json(response);
+            }
+            Ok(IdempotencyClaim::InProgress) => {
+                return HttpResponse::Conflict().json(json!({
+                    "error": "A request with this Idempotency-Key is still being processed"
+                }));
+            }
+            Err(e) => {
+                log::error!("Failed to claim idempotency key for workflow {uuid}: {e}");
+                return HttpResponse::InternalServerError()
+                    .json(json!({"error": "Internal server error"}));
+            }
+        }
+    }
+
+    // Create a run and stage items
+    let (run_uuid, staged_count) = match state
+        .workflow_service()
+        .run_now_upload_bytes(uuid, body)
+        .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            if let Some(claim) = claim {
+                claim.release().await;
+            }
+            if let Error::PayloadTooLarge(message) = e {
+                return HttpResponse::PayloadTooLarge()
+                    .json(json!({"error": "Payload too large", "message": message}));
+            }
+            log::error!("Failed to stage workflow data: {e}");
+            return HttpResponse::InternalServerError()
+                .json(json!({"error": "Failed to process workflow"}));
+        }
+    };
+
+    let response = if let Err(e) = state
+        .workflow_service()
+        .dispatch_fetch_for_existing_run(uuid, run_uuid)
+        .await
+    {
+        log::warn!("Failed to dispatch fetch job for workflow {uuid} (run: {run_uuid}): {e}");
+        json!({
+            "run_uuid": run_uuid,
+            "staged_items": staged_count,
+            "status": "queued_with_warning",
+            "warning": "Items staged but dispatch failed - processing may be delayed"
+        })
+    } else {
+        log::info!(
+            "Successfully enqueued workflow {uuid} (run: {run_uuid}, staged: {staged_count})"
+        );
+        json!({
+            "run_uuid": run_uuid,
+            "staged_items": staged_count,
+            "status": "queued"
+        })
+    };
+
+    if let Some(claim) = claim {
+        claim.complete(run_uuid, &response).await;
+    }
+
+    HttpResponse::Accepted().json(response)
+}
+
+/// Idempotency key claimed by an ingest request
+///
+/// A key that is neither completed nor released, e.g. because the handler panicked or the
+/// request was cancelled, is released in the background when the claim is dropped. Otherwise
+/// it would answer 409 to every retry until it expires.
+struct ClaimedIdempotencyKey {
+    state: web::Data<ApiStateWrapper>,
+    workflow_uuid: Uuid,
+    key: Option<String>,
+}
+
+impl ClaimedIdempotencyKey {
+    /// Store the response of the request for replays; the key is released if that fails
+    async fn complete(mut self, run_uuid: Uuid, response: &JsonValue) {
+        let Some(key) = self.key.take() else {
+            return;
+        };
+        if let Err(e) = self
+            .state
+            .workflow_service()
+            .complete_ingest_idempotency_key(self.workflow_uuid, &key, run_uuid, response)
+            .await
+        {
+            log::warn!(
+                "Failed to store idempotency key for workflow {} (run: {run_uuid}): {e}",
+                self.workflow_uuid
+            );
+            release_idempotency_key(&self.state, self.workflow_uuid, &key).await;
+        }
+    }
+
+    /// Release the key of a failed request before answering, so an immediate retry can claim it
+    async fn release(mut self) {
+        if let Some(key) = self.key.take() {
+            release_idempotency_key(&self.state, self.workflow_uuid, &key).await;
+        }
+    }
+}
+
+impl Drop for ClaimedIdempotencyKey {
+    fn drop(&mut self) {
+        let Some(key) = self.key.take() else {
+            return;
+        };
+        let state = self.state.clone();
+        let workflow_uuid = self.workflow_uuid;
+        actix_web::rt::spawn(async move {
+            release_idempotency_key(&state, workflow_uuid, &key).await;
+        });
+    }
+}
+
+async fn release_idempotency_key(
+    state: &web::Data<ApiStateWrapper>,
+    workflow_uuid: Uuid,
+    key: &str,
+) {
+    if let Err(e) = state
+        .workflow_service()
+        .release_ingest_idempotency_key(workflow_uuid, key)
+        .await
+    {
+        log::warn!("Failed to release idempotency key for workflow {workflow_uuid}: {e}");
+    }
+}
+
+/// Read the optional `Idempotency-Key` header of an ingest request
+fn idempotency_key(req: &HttpRequest) -> Result<Option<String>, HttpResponse> {
+    let Some(value) = req.headers().get("Idempotency-Key") else {
+        return Ok(None);
+    };
+    match value.to_str().map(str::trim) {
+        Ok(key) if !key.is_empty() && key.len() <= 255 => Ok(Some(key.to_string())),
+        _ => Err(HttpResponse::BadRequest().json(json!({
+            "error": "Idempotency-Key must be 1 to 255 visible ASCII characters"
+        }))),
+    }
+}
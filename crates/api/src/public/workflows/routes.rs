@@ -7,15 +7,21 @@ use uuid::Uuid;
 
 use crate::api_state::{ApiStateTrait, ApiStateWrapper};
 use crate::auth::auth_enum::CombinedRequiredAuth;
+use crate::middleware::RequireApiKeyScope;
+use r_data_core_core::admin_user::api_key_scopes;
 use r_data_core_core::error::Error;
 use r_data_core_workflow::data::adapters::auth::AuthConfig;
+use r_data_core_workflow::data::requests::RunWorkflowRequest;
 use r_data_core_workflow::data::WorkflowKind;
 use r_data_core_workflow::dsl::{DslProgram, FromDef, OutputMode, ToDef};
 use serde::Deserialize;
 
-use super::helpers::{extract_provider_auth_config, validate_and_authenticate_workflow};
+use super::helpers::{
+    extract_provider_auth_config, read_ingest_body, validate_and_authenticate_workflow,
+};
 use super::orchestration::{
-    handle_inline_auth_workflow, handle_provider_workflow, handle_trigger_consumer_workflow,
+    handle_inline_auth_workflow, handle_provider_workflow, handle_staged_ingest,
+    handle_trigger_consumer_workflow,
 };
 use r_data_core_workflow::dsl::Transform;
 
@@ -59,6 +65,7 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
         (status = 200, description = "Workflow data in configured format (CSV or JSON)", content_type = "text/csv,application/json"),
         (status = 202, description = "Workflow execution queued (use /workflows/{uuid} again to check status)"),
         (status = 401, description = "Unauthorized - authentication required"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 404, description = "Workflow not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -68,7 +75,10 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
         ("preSharedKey" = [])
     )
 )]
-#[get("/{uuid}")]
+#[get(
+    "/{uuid}",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::WORKFLOWS_READ)"
+)]
 pub async fn get_workflow_data(
     path: web::Path<Uuid>,
     req: HttpRequest,
@@ -114,7 +124,8 @@ pub async fn get_workflow_data(
 
 /// Trigger workflow execution (Consumer workflows with trigger type only)
 /// Accepts GET requests to trigger workflow execution at /api/v1/workflows/{uuid}/trigger
-/// No data payload - just triggers the workflow to run
+/// No data payload - just triggers the workflow to run; an optional JSON `config` body is merged
+/// over the stored config for this run only
 /// Supports sync and async modes via ?async=true query parameter
 /// Authentication is required (JWT, API key, or pre-shared key)
 #[utoipa::path(
@@ -128,10 +139,13 @@ pub async fn get_workflow_data(
         ("async" = Option<bool>, Query, description = "Execute async (202) or sync (200)"),
         ("run_uuid" = Option<Uuid>, Query, description = "Run UUID to poll when async=true")
     ),
+    request_body(content = Option<RunWorkflowRequest>, description = "Optional per-run config override"),
     responses(
         (status = 200, description = "Workflow execution completed", body = serde_json::Value),
+        (status = 400, description = "Invalid config override"),
         (status = 202, description = "Workflow execution queued (use /workflows/{uuid}/trigger again to check status)"),
         (status = 401, description = "Unauthorized - authentication required"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 404, description = "Workflow not found or not a Consumer workflow with trigger type"),
         (status = 500, description = "Internal server error")
     ),
@@ -141,14 +155,19 @@ pub async fn get_workflow_data(
         ("preSharedKey" = [])
     )
 )]
-#[get("/{uuid}/trigger")]
+#[get(
+    "/{uuid}/trigger",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::WORKFLOWS_TRIGGER)"
+)]
 pub async fn trigger_workflow(
     path: web::Path<Uuid>,
     req: HttpRequest,
     state: web::Data<ApiStateWrapper>,
     query: web::Query<WorkflowQuery>,
+    body: Option<web::Json<RunWorkflowRequest>>,
 ) -> impl Responder {
     let uuid = path.into_inner();
+    let config_override = body.and_then(|b| b.into_inner().config);
 
     // Get workflow config and validate auth
     let workflow = match state.workflow_service().get(uuid).await {
@@ -198,7 +217,15 @@ pub async fn trigger_workflow(
         }));
     }
 
-    handle_trigger_consumer_workflow(uuid, &req, &workflow, &state, &query).await
+    handle_trigger_consumer_workflow(
+        uuid,
+        &req,
+        &workflow,
+        &state,
+        &query,
+        config_override.as_ref(),
+    )
+    .await
 }
 
 /// Get workflow stats/metadata
@@ -213,6 +240,7 @@ pub async fn trigger_workflow(
     responses(
         (status = 200, description = "Workflow metadata", body = serde_json::Value),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 404, description = "Workflow not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -221,7 +249,10 @@ pub async fn trigger_workflow(
         ("apiKey" = [])
     )
 )]
-#[get("/{uuid}/stats")]
+#[get(
+    "/{uuid}/stats",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::WORKFLOWS_READ)"
+)]
 pub async fn get_workflow_stats(
     path: web::Path<Uuid>,
     state: web::Data<ApiStateWrapper>,
@@ -304,7 +335,8 @@ pub async fn get_workflow_stats(
     summary = "Ingest data into workflow",
     description = "POST endpoint for ingesting data into a Consumer workflow with from.api source. Accepts CSV or JSON data payload.",
     params(
-        ("uuid" = Uuid, Path, description = "Workflow UUID")
+        ("uuid" = Uuid, Path, description = "Workflow UUID"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Client key (max 255 characters) making retries safe: a repeated key for the same workflow within 24 hours returns the original run instead of staging the data again")
     ),
     request_body(
         content = String,
@@ -316,8 +348,11 @@ pub async fn get_workflow_stats(
         (status = 202, description = "Data accepted and processing started", body = serde_json::Value),
         (status = 400, description = "Bad request - workflow does not support API ingestion"),
         (status = 401, description = "Unauthorized - authentication required or invalid credentials"),
+        (status = 403, description = "API key lacks the required scope"),
         (status = 404, description = "Workflow not found"),
         (status = 405, description = "Method not allowed - only consumer workflows accept POST"),
+        (status = 409, description = "A request with the same Idempotency-Key is still being processed"),
+        (status = 413, description = "Body larger than WORKFLOW_INGEST_MAX_BODY_BYTES or more records than WORKFLOW_INGEST_MAX_RECORDS"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -326,10 +361,13 @@ pub async fn get_workflow_stats(
         ("preSharedKey" = [])
     )
 )]
-#[post("/{uuid}")]
+#[post(
+    "/{uuid}",
+    wrap = "RequireApiKeyScope::new(api_key_scopes::WORKFLOWS_TRIGGER)"
+)]
 pub async fn post_workflow_ingest(
     path: web::Path<Uuid>,
-    body: web::Bytes,
+    payload: web::Payload,
     req: HttpRequest,
     state: web::Data<ApiStateWrapper>,
 ) -> impl Responder {
@@ -378,6 +416,17 @@ pub async fn post_workflow_ingest(
         }
     };
 
+    let body = match read_ingest_body(
+        &req,
+        payload,
+        state.workflow_service().ingest_limits().max_body_bytes,
+    )
+    .await
+    {
+        Ok(body) => body,
+        Err(resp) => return resp,
+    };
+
     // Check for from.api source WITHOUT endpoint field (accepts POST)
     let has_api_source_accepting_post = program.steps.iter().any(|step| {
         if let FromDef::Format { source, .. } = &step.from {
@@ -409,38 +458,5 @@ pub async fn post_workflow_ingest(
         return handle_inline_auth_workflow(uuid, &payload, &state).await;
     }
 
-    // Create a run and stage items
-    let (run_uuid, staged_count) = match state
-        .workflow_service()
-        .run_now_upload_bytes(uuid, &body)
-        .await
-    {
-        Ok(result) => result,
-        Err(e) => {
-            log::error!("Failed to stage workflow data: {e}");
-            return HttpResponse::InternalServerError()
-                .json(json!({"error": "Failed to process workflow"}));
-        }
-    };
-
-    if let Err(e) = state
-        .workflow_service()
-        .dispatch_fetch_for_existing_run(uuid, run_uuid)
-        .await
-    {
-        log::warn!("Failed to dispatch fetch job for workflow {uuid} (run: {run_uuid}): {e}");
-        return HttpResponse::Accepted().json(json!({
-            "run_uuid": run_uuid,
-            "staged_items": staged_count,
-            "status": "queued_with_warning",
-            "warning": "Items staged but dispatch failed - processing may be delayed"
-        }));
-    }
-
-    log::info!("Successfully enqueued workflow {uuid} (run: {run_uuid}, staged: {staged_count})");
-    HttpResponse::Accepted().json(json!({
-        "run_uuid": run_uuid,
-        "staged_items": staged_count,
-        "status": "queued"
-    }))
+    handle_staged_ingest(uuid, &body, &req, &state).await
 }
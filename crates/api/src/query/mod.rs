@@ -1,3 +1,5 @@
+use r_data_core_core::count::CountMode;
+use r_data_core_core::sort::SortCollation;
 use serde::Deserialize;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -242,6 +244,8 @@ pub struct SortingQuery {
     pub sort_by: Option<String>,
     /// Sort order (asc or desc)
     pub sort_order: Option<String>,
+    /// Text ordering: `default`, `ci` (case-insensitive) or `ci_ai` (case- and accent-insensitive)
+    pub collation: Option<String>,
 }
 
 impl SortingQuery {
@@ -267,6 +271,16 @@ impl SortingQuery {
         )
     }
 
+    /// Validate and get the collation (defaults to the database ordering)
+    ///
+    /// # Errors
+    /// Returns an error if `collation` is not `default`, `ci` or `ci_ai`
+    pub fn validate_collation(&self) -> Result<SortCollation, String> {
+        self.collation
+            .as_deref()
+            .map_or(Ok(SortCollation::Default), SortCollation::parse)
+    }
+
     /// Get the sort order as uppercase (defaults to ASC)
     /// This method does not validate - use `validate_sort_order` for validation
     #[must_use]
@@ -371,6 +385,11 @@ pub struct IncludeQuery {
     /// Whether to include the count of child entities in the response
     #[serde(deserialize_with = "deserialize_optional_bool", default)]
     pub include_children_count: Option<bool>,
+    /// Whether to include soft-deleted entities (honored for admins only)
+    #[serde(deserialize_with = "deserialize_optional_bool", default)]
+    pub include_deleted: Option<bool>,
+    /// Comma-separated list of relation fields whose referenced entities are embedded
+    pub expand: Option<String>,
 }
 
 impl IncludeQuery {
@@ -386,11 +405,84 @@ impl IncludeQuery {
         })
     }
 
+    /// Parse expand into a vector of relation field names
+    #[must_use]
+    pub fn get_expands(&self) -> Vec<String> {
+        self.expand
+            .as_deref()
+            .map(|expand| {
+                expand
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Check if children count should be included
     #[must_use]
     pub fn should_include_children_count(&self) -> bool {
         self.include_children_count.unwrap_or(false)
     }
+
+    /// Check if soft-deleted entities were requested
+    #[must_use]
+    pub fn should_include_deleted(&self) -> bool {
+        self.include_deleted.unwrap_or(false)
+    }
+}
+
+/// Relation count query parameters
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RelationCountsQuery {
+    /// Comma-separated list of relations referencing the queried type to count
+    pub include_counts: Option<String>,
+}
+
+impl RelationCountsQuery {
+    /// Parse `include_counts` into a vector of relation names
+    #[must_use]
+    pub fn get_relations(&self) -> Vec<String> {
+        self.include_counts
+            .as_deref()
+            .map(|relations| {
+                relations
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Counting query parameters
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CountOnlyQuery {
+    /// Whether to return only the number of matching entities
+    #[serde(deserialize_with = "deserialize_optional_bool", default)]
+    pub count_only: Option<bool>,
+    /// How the list total is counted: `exact` (default), `approximate` or `none`
+    pub count: Option<String>,
+}
+
+impl CountOnlyQuery {
+    /// Check if only the count was requested
+    #[must_use]
+    pub fn is_count_only(&self) -> bool {
+        self.count_only.unwrap_or(false)
+    }
+
+    /// Validate and get the count mode (defaults to exact)
+    ///
+    /// # Errors
+    /// Returns an error if `count` is not `exact`, `approximate` or `none`
+    pub fn validate_count_mode(&self) -> Result<CountMode, String> {
+        self.count
+            .as_deref()
+            .map_or(Ok(CountMode::Exact), CountMode::parse)
+    }
 }
 
 /// Comprehensive standardized query parameters for API endpoints
@@ -176,6 +176,44 @@ fn test_include_query_with_boolean_false_string() {
     assert!(!result.should_include_children_count());
 }
 
+#[test]
+fn test_include_query_expand() {
+    let json = serde_json::json!({
+        "expand": "author, tags,,"
+    });
+
+    let result: IncludeQuery = serde_json::from_value(json).unwrap();
+    assert_eq!(result.get_expands(), vec!["author", "tags"]);
+}
+
+#[test]
+fn test_count_only_query() {
+    let result: CountOnlyQuery =
+        serde_json::from_value(serde_json::json!({ "count_only": "true" })).unwrap();
+    assert!(result.is_count_only());
+
+    let result: CountOnlyQuery = serde_json::from_value(serde_json::json!({})).unwrap();
+    assert!(!result.is_count_only());
+}
+
+#[test]
+fn test_count_mode_query() {
+    let result: CountOnlyQuery = serde_json::from_value(serde_json::json!({})).unwrap();
+    assert_eq!(result.validate_count_mode(), Ok(CountMode::Exact));
+
+    let result: CountOnlyQuery =
+        serde_json::from_value(serde_json::json!({ "count": "approximate" })).unwrap();
+    assert_eq!(result.validate_count_mode(), Ok(CountMode::Approximate));
+
+    let result: CountOnlyQuery =
+        serde_json::from_value(serde_json::json!({ "count": "none" })).unwrap();
+    assert_eq!(result.validate_count_mode(), Ok(CountMode::None));
+
+    let result: CountOnlyQuery =
+        serde_json::from_value(serde_json::json!({ "count": "fast" })).unwrap();
+    assert!(result.validate_count_mode().is_err());
+}
+
 #[test]
 fn test_include_query_with_missing_boolean() {
     let json = serde_json::json!({
@@ -186,3 +224,18 @@ fn test_include_query_with_missing_boolean() {
     assert_eq!(result.include_children_count, None);
     assert!(!result.should_include_children_count());
 }
+
+#[test]
+fn test_sorting_query_collation() {
+    let sorting: SortingQuery =
+        serde_json::from_value(serde_json::json!({ "sort_by": "name", "collation": "ci" }))
+            .unwrap();
+    assert_eq!(sorting.validate_collation(), Ok(SortCollation::Ci));
+
+    let sorting: SortingQuery = serde_json::from_value(serde_json::json!({})).unwrap();
+    assert_eq!(sorting.validate_collation(), Ok(SortCollation::Default));
+
+    let sorting: SortingQuery =
+        serde_json::from_value(serde_json::json!({ "collation": "binary" })).unwrap();
+    assert!(sorting.validate_collation().is_err());
+}
@@ -1,4 +1,8 @@
-use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use actix_web::{
+    http::header::{HeaderValue, RETRY_AFTER},
+    http::StatusCode,
+    HttpResponse, ResponseError,
+};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use time::format_description::well_known::Rfc3339;
@@ -6,6 +10,13 @@ use time::OffsetDateTime;
 use ts_rs::TS;
 use uuid::Uuid;
 
+use r_data_core_core::count::ListTotal;
+use r_data_core_core::error::FieldErrors;
+use r_data_core_core::request_id::current_request_id;
+use r_data_core_services::query_validation::ValidatedListQuery;
+
+pub mod negotiation;
+
 /// Individual validation violation for Symfony-style errors
 #[derive(Debug, Serialize, Deserialize, Clone, TS)]
 #[ts(export)]
@@ -18,6 +29,21 @@ pub struct ValidationViolation {
     pub code: Option<String>,
 }
 
+impl ValidationViolation {
+    /// One `INVALID` violation per field with an error
+    #[must_use]
+    pub fn from_field_errors(errors: &FieldErrors) -> Vec<Self> {
+        errors
+            .iter()
+            .map(|(field, message)| Self {
+                field: field.to_string(),
+                message: message.to_string(),
+                code: Some("INVALID".to_string()),
+            })
+            .collect()
+    }
+}
+
 /// Validation error response in Symfony format
 #[derive(Debug, Serialize, Deserialize, Clone, TS)]
 #[ts(export)]
@@ -39,24 +65,86 @@ pub enum Status {
 #[derive(Debug, Serialize, Deserialize, Clone, Default, TS)]
 #[ts(export)]
 pub struct PaginationMeta {
-    /// Total number of items available
-    #[ts(type = "number")]
-    pub total: i64,
+    /// Total number of items available (`null` if counting was skipped)
+    #[ts(type = "number | null")]
+    pub total: Option<i64>,
+    /// Whether `total` is an estimate rather than an exact count
+    #[serde(default)]
+    pub total_is_estimate: bool,
     /// Current page number
     #[ts(type = "number")]
     pub page: i64,
     /// Items per page
     #[ts(type = "number")]
     pub per_page: i64,
-    /// Total number of pages
-    #[ts(type = "number")]
-    pub total_pages: i64,
+    /// Total number of pages (`null` if counting was skipped)
+    #[ts(type = "number | null")]
+    pub total_pages: Option<i64>,
     /// If there is a previous page
     pub has_previous: bool,
     /// If there is a next page
     pub has_next: bool,
 }
 
+impl PaginationMeta {
+    /// Page math for `total` items shown `per_page` at a time; a non-positive `per_page` (no
+    /// limit) puts everything on one page
+    #[must_use]
+    pub const fn new(total: i64, page: i64, per_page: i64) -> Self {
+        let total_pages = if per_page <= 0 {
+            1
+        } else {
+            total.saturating_add(per_page - 1) / per_page
+        };
+        Self {
+            total: Some(total),
+            total_is_estimate: false,
+            page,
+            per_page,
+            total_pages: Some(total_pages),
+            has_previous: page > 1,
+            has_next: page < total_pages,
+        }
+    }
+
+    /// Page math for a total that may be estimated or skipped
+    ///
+    /// Without an exact total, `has_next` is inferred from whether `returned` items fill the
+    /// page.
+    #[must_use]
+    pub fn for_total(total: ListTotal, page: i64, per_page: i64, returned: usize) -> Self {
+        let full_page = per_page > 0 && i64::try_from(returned).is_ok_and(|n| n >= per_page);
+        match total {
+            ListTotal::Exact(total) => Self::new(total, page, per_page),
+            ListTotal::Approximate(total) => {
+                let mut meta = Self::new(total, page, per_page);
+                meta.total_is_estimate = true;
+                meta.has_next = full_page;
+                meta
+            }
+            ListTotal::Skipped => Self {
+                total: None,
+                total_is_estimate: false,
+                page,
+                per_page,
+                total_pages: None,
+                has_previous: page > 1,
+                has_next: full_page,
+            },
+        }
+    }
+}
+
+/// Request ID for response metadata
+///
+/// The propagated request ID when it is a UUID, a fresh UUID otherwise.
+#[must_use]
+pub fn response_request_id() -> Uuid {
+    current_request_id()
+        .and_then(|id| Uuid::parse_str(&id).ok())
+        .unwrap_or_else(Uuid::now_v7)
+}
+
 /// Metadata for API responses
 #[derive(Debug, Serialize, Deserialize, Clone, Default, TS)]
 #[ts(export)]
@@ -123,26 +211,19 @@ where
     /// Create a paginated success response
     ///
     /// # Panics
-    /// May panic if `total + per_page - 1` overflows with large values
+    /// May panic if the current time cannot be formatted as RFC 3339
     pub fn paginated(data: T, total: i64, page: i64, per_page: i64) -> Self {
-        let total_pages = if per_page <= 0 {
-            1
-        } else {
-            total.saturating_add(per_page - 1) / per_page
-        };
-
-        let pagination = PaginationMeta {
-            total,
-            page,
-            per_page,
-            total_pages,
-            has_previous: page > 1,
-            has_next: page < total_pages,
-        };
+        Self::with_pagination(data, PaginationMeta::new(total, page, per_page))
+    }
 
+    /// Create a paginated success response with the given pagination metadata
+    ///
+    /// # Panics
+    /// May panic if the current time cannot be formatted as RFC 3339
+    pub fn with_pagination(data: T, pagination: PaginationMeta) -> Self {
         let meta = ResponseMeta {
             pagination: Some(pagination),
-            request_id: Some(Uuid::now_v7()),
+            request_id: Some(response_request_id()),
             timestamp: Some(OffsetDateTime::now_utc().format(&Rfc3339).unwrap()),
             custom: None,
         };
@@ -155,6 +236,11 @@ where
         }
     }
 
+    /// Create a paginated success response for one page of a validated list query
+    pub fn paginated_list(data: T, total: i64, query: &ValidatedListQuery) -> Self {
+        Self::paginated(data, total, query.page, query.per_page)
+    }
+
     #[must_use]
     pub fn error(message: &str) -> ApiResponse<()> {
         ApiResponse {
@@ -181,7 +267,7 @@ where
 
         let meta = meta.unwrap_or_else(|| ResponseMeta {
             pagination: None,
-            request_id: Some(Uuid::now_v7()),
+            request_id: Some(response_request_id()),
             timestamp: Some(OffsetDateTime::now_utc().format(&Rfc3339).unwrap()),
             custom: Some(custom),
         });
@@ -215,6 +301,11 @@ where
         response.to_http_response(StatusCode::OK)
     }
 
+    /// Return one page of a validated list query
+    pub fn ok_paginated_list(data: T, total: i64, query: &ValidatedListQuery) -> HttpResponse {
+        Self::paginated_list(data, total, query).to_http_response(StatusCode::OK)
+    }
+
     /// Create a resource that was created successfully
     pub fn created<D: Serialize>(data: D) -> HttpResponse {
         let response = ApiResponse {
@@ -260,7 +351,7 @@ impl ApiResponse<()> {
             data: None,
             meta: Some(ResponseMeta {
                 pagination: None,
-                request_id: Some(Uuid::now_v7()),
+                request_id: Some(response_request_id()),
                 timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
                 custom: Some(serde_json::json!({"error_code": "RESOURCE_NOT_FOUND"})),
             }),
@@ -276,7 +367,7 @@ impl ApiResponse<()> {
             data: None,
             meta: Some(ResponseMeta {
                 pagination: None,
-                request_id: Some(Uuid::now_v7()),
+                request_id: Some(response_request_id()),
                 timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
                 custom: Some(serde_json::json!({"error_code": "RESOURCE_CONFLICT"})),
             }),
@@ -284,6 +375,38 @@ impl ApiResponse<()> {
         response.to_http_response(StatusCode::CONFLICT)
     }
 
+    #[must_use]
+    pub fn not_acceptable(message: &str) -> HttpResponse {
+        let response = Self {
+            status: Status::Error,
+            message: message.to_string(),
+            data: None,
+            meta: Some(ResponseMeta {
+                pagination: None,
+                request_id: Some(response_request_id()),
+                timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
+                custom: Some(serde_json::json!({"error_code": "NOT_ACCEPTABLE"})),
+            }),
+        };
+        response.to_http_response(StatusCode::NOT_ACCEPTABLE)
+    }
+
+    #[must_use]
+    pub fn gone(message: &str) -> HttpResponse {
+        let response = Self {
+            status: Status::Error,
+            message: message.to_string(),
+            data: None,
+            meta: Some(ResponseMeta {
+                pagination: None,
+                request_id: Some(response_request_id()),
+                timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
+                custom: Some(serde_json::json!({"error_code": "RESOURCE_GONE"})),
+            }),
+        };
+        response.to_http_response(StatusCode::GONE)
+    }
+
     #[must_use]
     pub fn internal_error(message: &str) -> HttpResponse {
         let response = Self {
@@ -292,7 +415,7 @@ impl ApiResponse<()> {
             data: None,
             meta: Some(ResponseMeta {
                 pagination: None,
-                request_id: Some(Uuid::now_v7()),
+                request_id: Some(response_request_id()),
                 timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
                 custom: Some(serde_json::json!({"error_code": "INTERNAL_SERVER_ERROR"})),
             }),
@@ -308,7 +431,7 @@ impl ApiResponse<()> {
             data: None,
             meta: Some(ResponseMeta {
                 pagination: None,
-                request_id: Some(Uuid::now_v7()),
+                request_id: Some(response_request_id()),
                 timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
                 custom: Some(serde_json::json!({"error_code": "BAD_REQUEST"})),
             }),
@@ -324,7 +447,7 @@ impl ApiResponse<()> {
             data: None,
             meta: Some(ResponseMeta {
                 pagination: None,
-                request_id: Some(Uuid::now_v7()),
+                request_id: Some(response_request_id()),
                 timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
                 custom: Some(serde_json::json!({"error_code": "UNAUTHORIZED"})),
             }),
@@ -341,7 +464,7 @@ impl ApiResponse<()> {
             data: None,
             meta: Some(ResponseMeta {
                 pagination: None,
-                request_id: Some(Uuid::now_v7()),
+                request_id: Some(response_request_id()),
                 timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
                 custom: Some(serde_json::json!({"error_code": "FORBIDDEN"})),
             }),
@@ -357,7 +480,7 @@ impl ApiResponse<()> {
             data: None,
             meta: Some(ResponseMeta {
                 pagination: None,
-                request_id: Some(Uuid::now_v7()),
+                request_id: Some(response_request_id()),
                 timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
                 custom: Some(serde_json::json!({"error_code": "RESOURCE_INACTIVE"})),
             }),
@@ -373,7 +496,7 @@ impl ApiResponse<()> {
             data: None,
             meta: Some(ResponseMeta {
                 pagination: None,
-                request_id: Some(Uuid::now_v7()),
+                request_id: Some(response_request_id()),
                 timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
                 custom: Some(serde_json::json!({"error_code": "VALIDATION_ERROR"})),
             }),
@@ -381,6 +504,27 @@ impl ApiResponse<()> {
         response.to_http_response(StatusCode::UNPROCESSABLE_ENTITY)
     }
 
+    /// Create a 429 response telling the client to retry after `retry_after_secs` seconds
+    #[must_use]
+    pub fn too_many_requests(message: &str, retry_after_secs: u64) -> HttpResponse {
+        let response = Self {
+            status: Status::Error,
+            message: message.to_string(),
+            data: None,
+            meta: Some(ResponseMeta {
+                pagination: None,
+                request_id: Some(response_request_id()),
+                timestamp: Some(time::OffsetDateTime::now_utc().to_string()),
+                custom: Some(serde_json::json!({"error_code": "RATE_LIMITED"})),
+            }),
+        };
+        let mut http_response = response.to_http_response(StatusCode::TOO_MANY_REQUESTS);
+        http_response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(retry_after_secs));
+        http_response
+    }
+
     /// Create a validation error response with field-specific violations (Symfony-style)
     #[must_use]
     pub fn unprocessable_entity_with_violations(
@@ -445,3 +589,93 @@ impl ResponseError for ApiError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_query(page: i64, per_page: i64) -> ValidatedListQuery {
+        ValidatedListQuery {
+            limit: per_page,
+            offset: (page - 1) * per_page,
+            page,
+            per_page,
+            sort_by: None,
+            sort_order: None,
+        }
+    }
+
+    fn pagination(total: i64, page: i64, per_page: i64) -> PaginationMeta {
+        ApiResponse::paginated_list((), total, &list_query(page, per_page))
+            .meta
+            .and_then(|meta| meta.pagination)
+            .expect("pagination meta")
+    }
+
+    #[test]
+    fn pagination_with_exact_multiple_of_per_page() {
+        let first = pagination(40, 1, 20);
+        assert_eq!(
+            (first.total, first.page, first.per_page, first.total_pages),
+            (Some(40), 1, 20, Some(2))
+        );
+        assert!(!first.has_previous);
+        assert!(first.has_next);
+
+        let last = pagination(40, 2, 20);
+        assert_eq!(last.total_pages, Some(2));
+        assert!(last.has_previous);
+        assert!(!last.has_next);
+    }
+
+    #[test]
+    fn pagination_with_partial_last_page() {
+        let middle = pagination(45, 2, 20);
+        assert_eq!(middle.total_pages, Some(3));
+        assert!(middle.has_previous);
+        assert!(middle.has_next);
+
+        let last = pagination(45, 3, 20);
+        assert_eq!(last.total_pages, Some(3));
+        assert!(last.has_previous);
+        assert!(!last.has_next);
+    }
+
+    #[test]
+    fn pagination_without_items_or_limit() {
+        let empty = pagination(0, 1, 20);
+        assert_eq!(empty.total_pages, Some(0));
+        assert!(!empty.has_previous);
+        assert!(!empty.has_next);
+
+        // `per_page = -1` lists everything on one page
+        let unlimited = pagination(45, 1, -1);
+        assert_eq!(unlimited.total_pages, Some(1));
+        assert!(!unlimited.has_next);
+    }
+
+    #[test]
+    fn pagination_with_estimated_total() {
+        let meta = PaginationMeta::for_total(ListTotal::Approximate(95), 1, 20, 20);
+        assert_eq!((meta.total, meta.total_pages), (Some(95), Some(5)));
+        assert!(meta.total_is_estimate);
+        assert!(meta.has_next);
+
+        // A partly filled page is the last one, whatever the estimate says
+        let short = PaginationMeta::for_total(ListTotal::Approximate(95), 2, 20, 7);
+        assert!(!short.has_next);
+    }
+
+    #[test]
+    fn pagination_without_total() {
+        let meta = PaginationMeta::for_total(ListTotal::Skipped, 2, 20, 20);
+        assert_eq!((meta.total, meta.total_pages), (None, None));
+        assert!(!meta.total_is_estimate);
+        assert!(meta.has_previous);
+        assert!(meta.has_next);
+
+        let json = serde_json::to_value(&meta).expect("serialize");
+        assert!(json["total"].is_null());
+        assert!(json["total_pages"].is_null());
+    }
+}
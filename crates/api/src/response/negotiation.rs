@@ -0,0 +1,236 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::http::header::{Accept, HeaderName};
+use actix_web::web::Bytes;
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::IntoParams;
+
+use r_data_core_core::count::ListTotal;
+
+use super::{ApiResponse, PaginationMeta};
+
+/// Header carrying the total item count of CSV and NDJSON list responses
+pub const TOTAL_COUNT_HEADER: &str = "x-total-count";
+
+/// `?format=` query parameter of list endpoints
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct FormatQuery {
+    /// Output format: `json` (default), `csv` or `ndjson`; takes precedence over `Accept`
+    pub format: Option<String>,
+}
+
+/// Output format of a list endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListFormat {
+    Json,
+    Csv,
+    Ndjson,
+}
+
+impl ListFormat {
+    fn from_param(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            "ndjson" | "jsonl" => Some(Self::Ndjson),
+            _ => None,
+        }
+    }
+
+    fn from_media_type(essence: &str) -> Option<Self> {
+        match essence {
+            "application/json" | "application/*" | "*/*" => Some(Self::Json),
+            "text/csv" | "text/*" => Some(Self::Csv),
+            "application/x-ndjson" | "application/ndjson" | "application/jsonl" => {
+                Some(Self::Ndjson)
+            }
+            _ => None,
+        }
+    }
+
+    /// Pick the format from `?format=`, then from `Accept` (by quality), defaulting to JSON
+    ///
+    /// # Errors
+    /// Returns a 406 response for an unknown `format` or an `Accept` header listing no
+    /// supported media type
+    pub fn negotiate(format: Option<&str>, accept: Option<&Accept>) -> Result<Self, HttpResponse> {
+        if let Some(format) = format {
+            return Self::from_param(format).ok_or_else(|| {
+                ApiResponse::<()>::not_acceptable(&format!(
+                    "Unsupported format '{format}', expected json, csv or ndjson"
+                ))
+            });
+        }
+        let Some(accept) = accept.filter(|accept| !accept.is_empty()) else {
+            return Ok(Self::Json);
+        };
+        accept
+            .ranked()
+            .iter()
+            .find_map(|mime| Self::from_media_type(mime.essence_str()))
+            .ok_or_else(|| {
+                ApiResponse::<()>::not_acceptable(
+                    "None of the accepted media types is supported, expected application/json, text/csv or application/x-ndjson",
+                )
+            })
+    }
+}
+
+/// A list item that can be rendered as a CSV row
+pub trait TabularRow {
+    /// Value of `column` for this item, `None` when it has none
+    fn column(&self, column: &str) -> Option<&Value>;
+}
+
+/// Render a value as a CSV cell (strings unquoted, null empty, everything else as JSON)
+#[must_use]
+pub fn csv_cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+/// Encode rows as CSV, one record per row
+///
+/// # Errors
+/// Returns an error if a record cannot be written
+pub fn encode_csv<I, R>(rows: I) -> Result<Bytes, csv::Error>
+where
+    I: IntoIterator<Item = R>,
+    R: IntoIterator<Item = String>,
+{
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for row in rows {
+        writer.write_record(row)?;
+    }
+    writer
+        .into_inner()
+        .map(Bytes::from)
+        .map_err(|e| csv::Error::from(e.into_error()))
+}
+
+/// CSV rows of `items`, one cell per column
+pub fn csv_rows<'a, T: TabularRow>(
+    items: &'a [T],
+    columns: &'a [String],
+) -> impl Iterator<Item = Vec<String>> + 'a {
+    items.iter().map(move |item| {
+        columns
+            .iter()
+            .map(|column| csv_cell(item.column(column)))
+            .collect()
+    })
+}
+
+/// Paginated list response in the negotiated format
+///
+/// JSON is the regular paginated `ApiResponse`. CSV has a header row of `columns` and NDJSON
+/// one serialized item per line; both carry the total count in `X-Total-Count` unless counting
+/// was skipped.
+#[must_use]
+pub fn paginated<T: Serialize + TabularRow>(
+    format: ListFormat,
+    items: Vec<T>,
+    columns: &[String],
+    total: ListTotal,
+    page: i64,
+    per_page: i64,
+) -> HttpResponse {
+    let (content_type, body) = match format {
+        ListFormat::Json => {
+            let pagination = PaginationMeta::for_total(total, page, per_page, items.len());
+            return ApiResponse::with_pagination(items, pagination)
+                .to_http_response(actix_web::http::StatusCode::OK);
+        }
+        ListFormat::Csv => {
+            let header = columns.to_vec();
+            match encode_csv(std::iter::once(header).chain(csv_rows(&items, columns))) {
+                Ok(body) => ("text/csv; charset=utf-8", body),
+                Err(e) => {
+                    log::error!("Failed to encode CSV list: {e}");
+                    return ApiResponse::<()>::internal_error("Failed to encode CSV");
+                }
+            }
+        }
+        ListFormat::Ndjson => {
+            let mut body = Vec::new();
+            for item in &items {
+                if let Err(e) = serde_json::to_writer(&mut body, item) {
+                    log::error!("Failed to encode NDJSON list: {e}");
+                    return ApiResponse::<()>::internal_error("Failed to encode NDJSON");
+                }
+                body.push(b'\n');
+            }
+            ("application/x-ndjson", Bytes::from(body))
+        }
+    };
+
+    let mut response = HttpResponse::Ok();
+    response.content_type(content_type);
+    if let Some(total) = total.value() {
+        response.insert_header((HeaderName::from_static(TOTAL_COUNT_HEADER), total));
+    }
+    response.body(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_negotiate_format_param_wins() {
+        let accept = Accept(vec!["text/csv".parse().expect("media range")]);
+        assert_eq!(
+            ListFormat::negotiate(Some("NDJSON"), Some(&accept)).ok(),
+            Some(ListFormat::Ndjson)
+        );
+        let rejected = ListFormat::negotiate(Some("xml"), None).expect_err("406");
+        assert_eq!(rejected.status().as_u16(), 406);
+    }
+
+    #[test]
+    fn test_negotiate_accept_by_quality() {
+        assert_eq!(
+            ListFormat::negotiate(None, None).ok(),
+            Some(ListFormat::Json)
+        );
+
+        let accept = Accept(vec![
+            "application/json;q=0.5".parse().expect("media range"),
+            "application/x-ndjson".parse().expect("media range"),
+        ]);
+        assert_eq!(
+            ListFormat::negotiate(None, Some(&accept)).ok(),
+            Some(ListFormat::Ndjson)
+        );
+
+        let accept = Accept(vec!["application/xml".parse().expect("media range")]);
+        let rejected = ListFormat::negotiate(None, Some(&accept)).expect_err("406");
+        assert_eq!(rejected.status().as_u16(), 406);
+    }
+
+    #[test]
+    fn test_encode_csv_quotes_cells() {
+        let rows = vec![
+            vec!["name".to_string(), "note".to_string()],
+            vec![
+                csv_cell(Some(&json!("Doe, Jane"))),
+                csv_cell(Some(&json!(null))),
+            ],
+            vec![
+                csv_cell(Some(&json!(42))),
+                csv_cell(Some(&json!({"a": "b"}))),
+            ],
+        ];
+        let bytes = encode_csv(rows).expect("encodes");
+        assert_eq!(
+            std::str::from_utf8(&bytes).expect("utf-8"),
+            "name,note\n\"Doe, Jane\",\n42,\"{\"\"a\"\":\"\"b\"\"}\"\n"
+        );
+    }
+}
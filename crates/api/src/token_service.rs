@@ -82,6 +82,10 @@ mod tests {
             enable_docs: false,
             cors_origins: vec![],
             check_default_admin_password: false,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         }
     }
 
@@ -103,6 +107,9 @@ mod tests {
             is_admin: true,
             created_at: now,
             updated_at: now,
+            totp_secret: None,
+            totp_enabled: false,
+            totp_last_used_step: None,
             base: AbstractRDataEntity::new("/test".to_string()),
         }
     }
@@ -18,6 +18,16 @@ pub const REFRESH_TOKEN_EXPIRY_SECONDS: u64 = 2_592_000;
 /// Issuer claim for admin JWTs
 pub const ADMIN_JWT_ISSUER: &str = "r_data_core_admin";
 
+/// Issuer claim for two-factor login challenges — distinct from admin JWTs
+pub const TWO_FACTOR_CHALLENGE_ISSUER: &str = "r_data_core_admin_2fa";
+
+/// Two-factor login challenge expiry (5 minutes)
+pub const TWO_FACTOR_CHALLENGE_EXPIRY_SECONDS: u64 = 300;
+
+/// Suffix appended to the base JWT secret to derive the challenge signing key, so a challenge
+/// can never pass as an access token
+const TWO_FACTOR_CHALLENGE_SECRET_SUFFIX: &str = "_2fa";
+
 /// Claims for authentication
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct AuthUserClaims {
@@ -26,6 +36,9 @@ pub struct AuthUserClaims {
     /// Issuer
     #[serde(default)]
     pub iss: String,
+    /// Unique token ID, used to revoke the token before it expires
+    #[serde(default)]
+    pub jti: String,
     /// Username
     pub name: String,
     /// Email
@@ -109,6 +122,7 @@ pub fn generate_jwt(
     let claims = AuthUserClaims {
         sub: user_uuid.to_string(),
         iss: ADMIN_JWT_ISSUER.to_string(),
+        jti: uuid::Uuid::now_v7().to_string(),
         name: user.username.clone(),
         email: user.email.clone(),
         is_super_admin,
@@ -236,6 +250,79 @@ pub fn verify_jwt(token: &str, secret: &str) -> Result<AuthUserClaims> {
     }
 }
 
+/// Claims of a two-factor login challenge, issued after the password step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwoFactorChallengeClaims {
+    /// User UUID as string
+    pub sub: String,
+    /// Issuer — always `TWO_FACTOR_CHALLENGE_ISSUER`
+    pub iss: String,
+    /// Expiration timestamp
+    pub exp: usize,
+    /// Issued at timestamp
+    pub iat: usize,
+}
+
+/// Generate a short-lived challenge for a user who passed the password step
+///
+/// Returns the token and its expiry. The token only identifies the user; it grants no access
+/// until it is exchanged together with a valid TOTP code.
+///
+/// # Errors
+/// Returns an error if token generation fails
+pub fn generate_two_factor_challenge(
+    user: &AdminUser,
+    config: &ApiConfig,
+) -> Result<(String, OffsetDateTime)> {
+    let now = OffsetDateTime::now_utc();
+    let expiration = now
+        .checked_add(Duration::seconds(
+            i64::try_from(TWO_FACTOR_CHALLENGE_EXPIRY_SECONDS).unwrap_or(i64::MAX),
+        ))
+        .ok_or_else(|| {
+            crate::error::Error::Auth("Could not create challenge expiration".to_string())
+        })?;
+
+    let claims = TwoFactorChallengeClaims {
+        sub: user.uuid.to_string(),
+        iss: TWO_FACTOR_CHALLENGE_ISSUER.to_string(),
+        exp: usize::try_from(expiration.unix_timestamp()).unwrap_or(0),
+        iat: usize::try_from(now.unix_timestamp()).unwrap_or(0),
+    };
+    let secret = format!("{}{TWO_FACTOR_CHALLENGE_SECRET_SUFFIX}", config.jwt_secret);
+
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .map_err(|e| crate::error::Error::Auth(format!("Challenge generation error: {e}")))?;
+
+    Ok((token, expiration))
+}
+
+/// Verify and decode a two-factor login challenge
+///
+/// # Errors
+/// Returns an error if the challenge is invalid, expired or has an unexpected issuer
+pub fn verify_two_factor_challenge(token: &str, secret: &str) -> Result<TwoFactorChallengeClaims> {
+    let secret = format!("{secret}{TWO_FACTOR_CHALLENGE_SECRET_SUFFIX}");
+    let token_data = decode::<TwoFactorChallengeClaims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::default(),
+    )
+    .map_err(|e| crate::error::Error::Auth(format!("Challenge validation error: {e}")))?;
+
+    if token_data.claims.iss != TWO_FACTOR_CHALLENGE_ISSUER {
+        return Err(crate::error::Error::Auth(
+            "Challenge has unexpected issuer".to_string(),
+        ));
+    }
+
+    Ok(token_data.claims)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -274,6 +361,9 @@ mod tests {
             is_admin: true,
             created_at: OffsetDateTime::now_utc(),
             updated_at: OffsetDateTime::now_utc(),
+            totp_secret: None,
+            totp_enabled: false,
+            totp_last_used_step: None,
         }
     }
 
@@ -287,6 +377,10 @@ mod tests {
             enable_docs: true,
             cors_origins: vec!["*".to_string()],
             check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         }
     }
 
@@ -363,6 +457,7 @@ mod tests {
         let claims = AuthUserClaims {
             sub: user.uuid.to_string(),
             iss: ADMIN_JWT_ISSUER.to_string(),
+            jti: String::new(),
             name: user.username.clone(),
             email: user.email,
             is_super_admin: false,
@@ -388,6 +483,7 @@ mod tests {
         let claims = AuthUserClaims {
             sub: "test-uuid".to_string(),
             iss: ADMIN_JWT_ISSUER.to_string(),
+            jti: String::new(),
             name: "test_user".to_string(),
             email: "test@example.com".to_string(),
             is_super_admin: false,
@@ -453,4 +549,28 @@ mod tests {
         let token = result.unwrap();
         assert!(!token.is_empty());
     }
+
+    #[test]
+    fn test_two_factor_challenge_round_trip() {
+        let user = create_test_user();
+        let config = create_test_config();
+
+        let (token, expires_at) = generate_two_factor_challenge(&user, &config).unwrap();
+        let claims = verify_two_factor_challenge(&token, &config.jwt_secret).unwrap();
+        assert_eq!(claims.sub, user.uuid.to_string());
+        assert_eq!(claims.iss, TWO_FACTOR_CHALLENGE_ISSUER);
+        assert!(expires_at <= OffsetDateTime::now_utc() + Duration::minutes(5));
+    }
+
+    #[test]
+    fn test_two_factor_challenge_is_not_an_access_token() {
+        let user = create_test_user();
+        let config = create_test_config();
+
+        let (challenge, _) = generate_two_factor_challenge(&user, &config).unwrap();
+        assert!(verify_jwt(&challenge, &config.jwt_secret).is_err());
+
+        let access = generate_access_token(&user, &config, &[]).unwrap();
+        assert!(verify_two_factor_challenge(&access, &config.jwt_secret).is_err());
+    }
 }
@@ -1,7 +1,12 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+pub mod api_key_cidrs;
+pub mod api_key_scopes;
 pub mod model;
 #[cfg(test)]
 mod model_tests;
+pub mod password_policy;
+pub mod totp;
 
 pub use model::{AdminUser, ApiKey, UserStatus};
+pub use password_policy::PasswordPolicy;
@@ -0,0 +1,108 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Source IP ranges an API key may be used from.
+//!
+//! Ranges are CIDR blocks (`10.0.0.0/8`, `2001:db8::/32`); a bare address covers only itself.
+//! A key without ranges can be used from anywhere.
+
+use std::net::IpAddr;
+
+use crate::error::{Error, Result};
+
+/// Parse a CIDR block into its network address and prefix length
+fn parse(cidr: &str) -> Option<(IpAddr, u8)> {
+    let (addr, prefix) = match cidr.split_once('/') {
+        Some((addr, prefix)) => (
+            addr.parse::<IpAddr>().ok()?,
+            Some(prefix.parse::<u8>().ok()?),
+        ),
+        None => (cidr.parse::<IpAddr>().ok()?, None),
+    };
+    let max = if addr.is_ipv4() { 32 } else { 128 };
+    let prefix = prefix.unwrap_or(max);
+    (prefix <= max).then_some((addr, prefix))
+}
+
+/// Whether the leading `prefix` bits of two `bits`-wide addresses are equal
+const fn prefix_matches(network: u128, ip: u128, prefix: u8, bits: u8) -> bool {
+    if prefix == 0 {
+        return true;
+    }
+    let shift = (bits - prefix) as u32;
+    network >> shift == ip >> shift
+}
+
+/// Whether `ip` lies within `cidr`; invalid blocks contain nothing
+#[must_use]
+pub fn contains(cidr: &str, ip: IpAddr) -> bool {
+    let Some((network, prefix)) = parse(cidr) else {
+        return false;
+    };
+    // IPv4-mapped IPv6 addresses (::ffff:a.b.c.d) are matched against IPv4 blocks
+    match (network, ip.to_canonical()) {
+        (IpAddr::V4(network), IpAddr::V4(ip)) => prefix_matches(
+            u128::from(u32::from(network)),
+            u128::from(u32::from(ip)),
+            prefix,
+            32,
+        ),
+        (IpAddr::V6(network), IpAddr::V6(ip)) => {
+            prefix_matches(u128::from(network), u128::from(ip), prefix, 128)
+        }
+        _ => false,
+    }
+}
+
+/// Validate CIDR blocks and return them trimmed, sorted and deduplicated
+///
+/// # Errors
+/// Returns a validation error for a block that is not a valid IPv4 or IPv6 CIDR
+pub fn normalize(cidrs: &[String]) -> Result<Vec<String>> {
+    let mut normalized = Vec::with_capacity(cidrs.len());
+    for cidr in cidrs {
+        let cidr = cidr.trim();
+        if parse(cidr).is_none() {
+            return Err(Error::Validation(format!(
+                "Invalid CIDR '{cidr}'; expected e.g. 10.0.0.0/8 or 2001:db8::/32"
+            )));
+        }
+        normalized.push(cidr.to_string());
+    }
+    normalized.sort();
+    normalized.dedup();
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn contains_matches_ipv4_and_ipv6_ranges() {
+        assert!(contains("10.0.0.0/8", ip("10.20.30.40")));
+        assert!(!contains("10.0.0.0/8", ip("11.0.0.1")));
+        assert!(contains("192.168.1.7", ip("192.168.1.7")));
+        assert!(!contains("192.168.1.7", ip("192.168.1.8")));
+        assert!(contains("0.0.0.0/0", ip("8.8.8.8")));
+        assert!(contains("10.0.0.0/8", ip("::ffff:10.1.2.3")));
+        assert!(contains("2001:db8::/32", ip("2001:db8:1::1")));
+        assert!(!contains("2001:db8::/32", ip("2001:db9::1")));
+        assert!(!contains("2001:db8::/32", ip("10.0.0.1")));
+    }
+
+    #[test]
+    fn normalize_rejects_invalid_cidrs() {
+        for cidr in ["10.0.0.0/33", "10.0.0/8", "not-an-ip", "::1/129", ""] {
+            let err = normalize(&[cidr.to_string()]).unwrap_err();
+            assert!(matches!(err, Error::Validation(_)), "{cidr}");
+        }
+        assert_eq!(
+            normalize(&[" 10.0.0.0/8".to_string(), "10.0.0.0/8".to_string()]).unwrap(),
+            vec!["10.0.0.0/8".to_string()]
+        );
+    }
+}
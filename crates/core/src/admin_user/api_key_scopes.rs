@@ -0,0 +1,70 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Scopes restricting what an API key may do on the public API.
+//!
+//! A key without scopes keeps full access, so keys created before scopes existed
+//! behave as before.
+
+use crate::error::{Error, Result};
+
+/// Read entities (lists, single entities, queries and versions)
+pub const ENTITIES_READ: &str = "entities:read";
+/// Create, update and delete entities
+pub const ENTITIES_WRITE: &str = "entities:write";
+/// Read data exposed by provider workflows and workflow stats
+pub const WORKFLOWS_READ: &str = "workflows:read";
+/// Trigger workflows and ingest data into consumer workflows
+pub const WORKFLOWS_TRIGGER: &str = "workflows:trigger";
+
+/// All known scopes
+pub const ALL: &[&str] = &[
+    ENTITIES_READ,
+    ENTITIES_WRITE,
+    WORKFLOWS_READ,
+    WORKFLOWS_TRIGGER,
+];
+
+/// Validate requested scopes and return them trimmed, sorted and deduplicated
+///
+/// # Errors
+/// Returns a validation error for an unknown scope
+pub fn normalize(scopes: &[String]) -> Result<Vec<String>> {
+    let mut normalized = Vec::with_capacity(scopes.len());
+    for scope in scopes {
+        let scope = scope.trim();
+        if !ALL.contains(&scope) {
+            return Err(Error::Validation(format!(
+                "Unknown API key scope '{scope}'; expected one of: {}",
+                ALL.join(", ")
+            )));
+        }
+        normalized.push(scope.to_string());
+    }
+    normalized.sort();
+    normalized.dedup();
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_sorts_and_dedups_known_scopes() {
+        let scopes = vec![
+            " workflows:trigger".to_string(),
+            "entities:read".to_string(),
+            "entities:read".to_string(),
+        ];
+        assert_eq!(
+            normalize(&scopes).unwrap(),
+            vec!["entities:read".to_string(), "workflows:trigger".to_string()]
+        );
+    }
+
+    #[test]
+    fn normalize_rejects_unknown_scope() {
+        let err = normalize(&["entities:admin".to_string()]).unwrap_err();
+        assert!(matches!(err, Error::Validation(msg) if msg.contains("entities:admin")));
+    }
+}
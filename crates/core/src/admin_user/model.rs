@@ -1,5 +1,6 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use crate::admin_user::api_key_cidrs;
 use crate::crypto;
 use crate::domain::AbstractRDataEntity;
 use crate::error::{Error, Result};
@@ -11,6 +12,7 @@ use sqlx::{
     postgres::{PgRow, PgTypeInfo, PgValueRef},
     FromRow, Row, Type,
 };
+use std::net::IpAddr;
 use time::OffsetDateTime;
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -83,6 +85,18 @@ pub struct AdminUser {
     pub is_admin: bool,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
+
+    /// Base32 TOTP secret, set once two-factor enrollment starts (not returned in API)
+    #[serde(skip_serializing)]
+    pub totp_secret: Option<String>,
+
+    /// Whether a TOTP code is required at login
+    #[serde(default)]
+    pub totp_enabled: bool,
+
+    /// Time step of the last accepted TOTP code, so codes cannot be reused (not returned in API)
+    #[serde(skip_serializing)]
+    pub totp_last_used_step: Option<i64>,
 }
 
 impl<'r> FromRow<'r, PgRow> for AdminUser {
@@ -114,6 +128,9 @@ impl<'r> FromRow<'r, PgRow> for AdminUser {
         let failed_login_attempts = 0; // Default value
         let super_admin = row.try_get("super_admin").unwrap_or(false); // Default to false
         let is_admin = false; // Default value
+        let totp_secret: Option<String> = row.try_get("totp_secret").ok().flatten();
+        let totp_enabled = row.try_get("totp_enabled").unwrap_or(false);
+        let totp_last_used_step: Option<i64> = row.try_get("totp_last_used_step").ok().flatten();
 
         Ok(Self {
             uuid,
@@ -131,6 +148,9 @@ impl<'r> FromRow<'r, PgRow> for AdminUser {
             is_admin,
             created_at,
             updated_at,
+            totp_secret,
+            totp_enabled,
+            totp_last_used_step,
             base: AbstractRDataEntity::new("/admin/users".to_string()),
         })
     }
@@ -149,6 +169,15 @@ pub struct ApiKey {
     pub last_used_at: Option<OffsetDateTime>,
     pub created_by: Uuid,
     pub published: bool,
+    /// Public API scopes the key is restricted to; empty means unrestricted
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// Maximum public API requests per minute; `None` means unlimited
+    #[serde(default)]
+    pub rate_limit_per_minute: Option<i32>,
+    /// Source IP ranges (CIDR) the key may be used from; empty means any address
+    #[serde(default)]
+    pub allowed_cidrs: Vec<String>,
 }
 
 /// Builder for creating new `AdminUser` instances
@@ -217,6 +246,9 @@ impl AdminUserBuilder {
             is_admin: self.is_admin,
             created_at: now,
             updated_at: now,
+            totp_secret: None,
+            totp_enabled: false,
+            totp_last_used_step: None,
         }
     }
 }
@@ -373,9 +405,40 @@ impl ApiKey {
             last_used_at: None,
             created_by,
             published: true,
+            scopes: Vec::new(),
+            rate_limit_per_minute: None,
+            allowed_cidrs: Vec::new(),
         }
     }
 
+    /// Whether the key may be used for `scope`; keys without scopes are unrestricted
+    #[must_use]
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.is_empty() || self.scopes.iter().any(|s| s == scope)
+    }
+
+    /// Whether the key may be used from `ip`; keys without allowed ranges accept any address
+    ///
+    /// A restricted key is rejected if the client address is unknown.
+    #[must_use]
+    pub fn allows_ip(&self, ip: Option<IpAddr>) -> bool {
+        self.allowed_cidrs.is_empty()
+            || ip.is_some_and(|ip| {
+                self.allowed_cidrs
+                    .iter()
+                    .any(|cidr| api_key_cidrs::contains(cidr, ip))
+            })
+    }
+
+    /// Whether the key is active and expires after `now` but no later than `now + window`
+    #[must_use]
+    pub fn expires_within(&self, now: OffsetDateTime, window: time::Duration) -> bool {
+        self.is_active
+            && self
+                .expires_at
+                .is_some_and(|expires_at| expires_at > now && expires_at <= now + window)
+    }
+
     /// Generate a secure random API key
     #[must_use]
     pub fn generate_key() -> String {
@@ -115,3 +115,66 @@ fn test_admin_user_has_permission_with_super_admin_role() {
         Some("/any/path")
     ));
 }
+
+#[test]
+fn test_api_key_has_scope() {
+    let mut key = ApiKey::new(
+        uuid::Uuid::now_v7(),
+        "reporting".to_string(),
+        None,
+        None,
+        uuid::Uuid::now_v7(),
+    );
+
+    // Keys without scopes are unrestricted
+    assert!(key.has_scope("entities:write"));
+
+    key.scopes = vec!["entities:read".to_string()];
+    assert!(key.has_scope("entities:read"));
+    assert!(!key.has_scope("entities:write"));
+}
+
+#[test]
+fn test_api_key_allows_ip() {
+    let mut key = ApiKey::new(
+        uuid::Uuid::now_v7(),
+        "server".to_string(),
+        None,
+        None,
+        uuid::Uuid::now_v7(),
+    );
+    let inside = "10.1.2.3".parse().ok();
+
+    // Keys without ranges accept any address
+    assert!(key.allows_ip(inside));
+    assert!(key.allows_ip(None));
+
+    key.allowed_cidrs = vec!["10.0.0.0/8".to_string()];
+    assert!(key.allows_ip(inside));
+    assert!(!key.allows_ip("192.168.0.1".parse().ok()));
+    assert!(!key.allows_ip(None));
+}
+
+#[test]
+fn test_api_key_expires_within() {
+    let now = time::OffsetDateTime::now_utc();
+    let mut key = ApiKey::new(
+        uuid::Uuid::now_v7(),
+        "expiring".to_string(),
+        None,
+        Some(now + time::Duration::days(3)),
+        uuid::Uuid::now_v7(),
+    );
+
+    assert!(key.expires_within(now, time::Duration::days(7)));
+    assert!(!key.expires_within(now, time::Duration::days(1)));
+
+    // Already expired, never expiring and revoked keys are not "expiring"
+    key.expires_at = Some(now - time::Duration::days(1));
+    assert!(!key.expires_within(now, time::Duration::days(7)));
+    key.expires_at = None;
+    assert!(!key.expires_within(now, time::Duration::days(7)));
+    key.expires_at = Some(now + time::Duration::days(3));
+    key.is_active = false;
+    assert!(!key.expires_within(now, time::Duration::days(7)));
+}
@@ -0,0 +1,194 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Password strength rules for admin users.
+//!
+//! The rules come from [`PasswordPolicyConfig`]; the optional breached-password list is read
+//! once when the policy is built and compared case-insensitively.
+
+use std::collections::HashSet;
+
+use crate::config::PasswordPolicyConfig;
+use crate::error::{Error, Result};
+
+/// Password policy with its breached-password list loaded
+#[derive(Debug, Clone, Default)]
+pub struct PasswordPolicy {
+    config: PasswordPolicyConfig,
+    breached: HashSet<String>,
+}
+
+impl PasswordPolicy {
+    /// Build a policy, reading the breached-password list if one is configured
+    ///
+    /// # Errors
+    /// Returns a configuration error if the breached-password file cannot be read
+    pub fn from_config(config: PasswordPolicyConfig) -> Result<Self> {
+        let breached = match &config.breached_passwords_file {
+            Some(path) => std::fs::read_to_string(path)
+                .map_err(|e| {
+                    Error::Config(format!("Failed to read breached password list {path}: {e}"))
+                })?
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_lowercase)
+                .collect(),
+            None => HashSet::new(),
+        };
+        Ok(Self { config, breached })
+    }
+
+    /// Build a policy with an in-memory breached-password list
+    #[must_use]
+    pub fn with_breached_passwords<I, S>(config: PasswordPolicyConfig, passwords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            config,
+            breached: passwords
+                .into_iter()
+                .map(|p| p.as_ref().to_lowercase())
+                .collect(),
+        }
+    }
+
+    /// The configured rules
+    #[must_use]
+    pub const fn config(&self) -> &PasswordPolicyConfig {
+        &self.config
+    }
+
+    /// Check `password` against the policy
+    ///
+    /// # Errors
+    /// Returns a validation error naming the first rule the password does not meet
+    pub fn check(&self, password: &str) -> Result<()> {
+        let config = &self.config;
+        if password.chars().count() < config.min_length {
+            return Err(Error::Validation(format!(
+                "Password must be at least {} characters",
+                config.min_length
+            )));
+        }
+        if config.require_uppercase && !password.chars().any(char::is_uppercase) {
+            return Err(Error::Validation(
+                "Password must contain an uppercase letter".to_string(),
+            ));
+        }
+        if config.require_lowercase && !password.chars().any(char::is_lowercase) {
+            return Err(Error::Validation(
+                "Password must contain a lowercase letter".to_string(),
+            ));
+        }
+        if config.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            return Err(Error::Validation(
+                "Password must contain a digit".to_string(),
+            ));
+        }
+        if config.require_symbol && password.chars().all(char::is_alphanumeric) {
+            return Err(Error::Validation(
+                "Password must contain a symbol".to_string(),
+            ));
+        }
+        if self.breached.contains(&password.to_lowercase()) {
+            return Err(Error::Validation(
+                "Password appears in a list of breached passwords".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strict() -> PasswordPolicy {
+        PasswordPolicy::with_breached_passwords(
+            PasswordPolicyConfig {
+                min_length: 12,
+                require_uppercase: true,
+                require_lowercase: true,
+                require_digit: true,
+                require_symbol: true,
+                breached_passwords_file: None,
+            },
+            ["Password123!456"],
+        )
+    }
+
+    fn message(result: Result<()>) -> String {
+        match result {
+            Err(Error::Validation(msg)) => msg,
+            other => panic!("Expected validation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_short_passwords() {
+        assert_eq!(
+            message(strict().check("Ab1!")),
+            "Password must be at least 12 characters"
+        );
+    }
+
+    #[test]
+    fn rejects_missing_character_classes() {
+        let policy = strict();
+        assert_eq!(
+            message(policy.check("lowercase1!only")),
+            "Password must contain an uppercase letter"
+        );
+        assert_eq!(
+            message(policy.check("UPPERCASE1!ONLY")),
+            "Password must contain a lowercase letter"
+        );
+        assert_eq!(
+            message(policy.check("NoDigitsHere!!")),
+            "Password must contain a digit"
+        );
+        assert_eq!(
+            message(policy.check("NoSymbolsHere12")),
+            "Password must contain a symbol"
+        );
+    }
+
+    #[test]
+    fn rejects_breached_passwords_case_insensitively() {
+        assert_eq!(
+            message(strict().check("pASSWORD123!456")),
+            "Password appears in a list of breached passwords"
+        );
+    }
+
+    #[test]
+    fn accepts_strong_password() {
+        assert!(strict().check("Correct-Horse-7-Battery").is_ok());
+        assert!(PasswordPolicy::default().check("password").is_ok());
+    }
+
+    #[test]
+    fn from_config_reads_breached_list() {
+        let path =
+            std::env::temp_dir().join(format!("breached-passwords-{}.txt", uuid::Uuid::now_v7()));
+        std::fs::write(&path, "# comment\nletmein123\n\nQwertyuiop\n").unwrap();
+        let policy = PasswordPolicy::from_config(PasswordPolicyConfig {
+            breached_passwords_file: Some(path.to_string_lossy().into_owned()),
+            ..PasswordPolicyConfig::default()
+        })
+        .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(policy.check("letmein123").is_err());
+        assert!(policy.check("qwertyuiop").is_err());
+        assert!(policy.check("# comment").is_ok());
+
+        let missing = PasswordPolicy::from_config(PasswordPolicyConfig {
+            breached_passwords_file: Some("/nonexistent/breached.txt".to_string()),
+            ..PasswordPolicyConfig::default()
+        });
+        assert!(matches!(missing, Err(Error::Config(_))));
+    }
+}
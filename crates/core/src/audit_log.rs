@@ -0,0 +1,54 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use time::OffsetDateTime;
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::system_log::SystemLogResourceType;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, ToSchema, TS, PartialEq, Eq)]
+#[sqlx(type_name = "audit_action", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+/// A recorded admin mutation with snapshots of the resource before and after it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    pub uuid: Uuid,
+    pub created_at: OffsetDateTime,
+    pub actor_uuid: Option<Uuid>,
+    pub action: AuditAction,
+    pub resource_type: SystemLogResourceType,
+    pub resource_uuid: Uuid,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn audit_action_serializes_snake_case() {
+        assert_eq!(
+            serde_json::to_string(&AuditAction::Create).unwrap(),
+            "\"create\""
+        );
+        assert_eq!(
+            serde_json::to_string(&AuditAction::Update).unwrap(),
+            "\"update\""
+        );
+        assert_eq!(
+            serde_json::to_string(&AuditAction::Delete).unwrap(),
+            "\"delete\""
+        );
+    }
+}
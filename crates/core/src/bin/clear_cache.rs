@@ -121,8 +121,8 @@ async fn execute_clear_all(cache: &RedisCache, dry_run: bool) -> ExitCode {
 
     println!("Clearing entire cache...");
     match cache.clear().await {
-        Ok(()) => {
-            println!("Cache cleared successfully.");
+        Ok(count) => {
+            println!("Cache cleared successfully. Deleted {count} keys.");
             ExitCode::SUCCESS
         }
         Err(e) => {
@@ -38,9 +38,12 @@ pub trait CacheBackend: Send + Sync {
 
     /// Clear the entire cache
     ///
+    /// # Returns
+    /// The number of entries removed
+    ///
     /// # Errors
     /// Returns an error if cache clearing fails
-    async fn clear(&self) -> Result<()>;
+    async fn clear(&self) -> Result<usize>;
 
     /// Delete all cache entries matching a prefix
     ///
@@ -53,4 +56,27 @@ pub trait CacheBackend: Send + Sync {
     /// # Errors
     /// Returns an error if cache deletion fails
     async fn delete_by_prefix(&self, prefix: &str) -> Result<usize>;
+
+    /// Atomically increment a counter, creating it with a lifetime of `ttl` seconds if missing
+    ///
+    /// The lifetime is not extended by later increments, so the counter covers a fixed window.
+    ///
+    /// # Returns
+    /// The new count and the seconds until the counter expires
+    ///
+    /// # Errors
+    /// Returns an error if the counter cannot be updated
+    async fn increment(&self, key: &str, ttl: u64) -> Result<(u64, u64)>;
+
+    /// Atomically take one token from a bucket holding up to `capacity` tokens
+    ///
+    /// The bucket refills evenly by `capacity` tokens per `window_secs` seconds of elapsed
+    /// time; a missing bucket starts full.
+    ///
+    /// # Returns
+    /// `None` if a token was taken, otherwise the seconds until the next token is available
+    ///
+    /// # Errors
+    /// Returns an error if the bucket cannot be updated
+    async fn take_token(&self, key: &str, capacity: u64, window_secs: u64) -> Result<Option<u64>>;
 }
@@ -4,10 +4,12 @@ use async_trait::async_trait;
 use lru::LruCache;
 use serde::{de::DeserializeOwned, Serialize};
 use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
 use crate::cache::backend::CacheBackend;
+use crate::config::CacheEvictionPolicy;
 use crate::error::{Error, Result};
 
 /// Cache entry with value and expiration time
@@ -16,14 +18,22 @@ struct CacheEntry {
     value: Vec<u8>,
     /// When this entry expires
     expires_at: Option<Instant>,
+    /// How often the entry was read, for LFU eviction
+    reads: u64,
 }
 
-/// In-memory cache implementation using LRU eviction
+/// In-memory cache bounded to `max_size` entries, evicting by a [`CacheEvictionPolicy`]
 pub struct InMemoryCache {
-    /// Cache data with expiration tracking
+    /// Cache data with expiration tracking, ordered by recency
     data: RwLock<LruCache<String, CacheEntry>>,
     /// Default TTL in seconds
     default_ttl: u64,
+    /// Which entry to evict when the cache is full
+    eviction_policy: CacheEvictionPolicy,
+    /// Number of entries, kept in step with `data` so it can be read without the lock
+    entries: AtomicUsize,
+    /// Entries evicted to make room for new ones since startup
+    evictions: AtomicU64,
 }
 
 impl InMemoryCache {
@@ -43,9 +53,72 @@ impl InMemoryCache {
         Self {
             data: RwLock::new(LruCache::new(capacity)),
             default_ttl,
+            eviction_policy: CacheEvictionPolicy::default(),
+            entries: AtomicUsize::new(0),
+            evictions: AtomicU64::new(0),
         }
     }
 
+    /// Use `policy` instead of LRU to pick the entry to evict when the cache is full
+    #[must_use]
+    pub const fn with_eviction_policy(mut self, policy: CacheEvictionPolicy) -> Self {
+        self.eviction_policy = policy;
+        self
+    }
+
+    /// Number of entries currently held, including expired ones not yet dropped
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.load(Ordering::Relaxed)
+    }
+
+    /// Whether the cache holds no entries
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of entries evicted to make room for new ones since startup
+    #[must_use]
+    pub fn evictions(&self) -> u64 {
+        self.evictions.load(Ordering::Relaxed)
+    }
+
+    /// Store an entry, first evicting one according to the policy if the cache is full
+    fn insert(&self, cache: &mut LruCache<String, CacheEntry>, key: String, entry: CacheEntry) {
+        let replacing = cache.contains(&key);
+        if !replacing && cache.len() == cache.cap().get() {
+            if let Some(victim) = self.pick_victim(cache) {
+                cache.pop(&victim);
+                self.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        // Only evicts when the policy found no victim above
+        if cache.push(key, entry).is_some() && !replacing {
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+        self.record_len(cache);
+    }
+
+    /// Key of the entry the eviction policy gives up first
+    fn pick_victim(&self, cache: &LruCache<String, CacheEntry>) -> Option<String> {
+        // Walk from the least recently used entry so ties go to it
+        let mut entries = cache.iter().rev();
+        let victim = match self.eviction_policy {
+            CacheEvictionPolicy::Lru => entries.next(),
+            CacheEvictionPolicy::Lfu => entries.min_by_key(|(_, entry)| entry.reads),
+            // Entries without expiry go last
+            CacheEvictionPolicy::TtlOnly => {
+                entries.min_by_key(|(_, entry)| (entry.expires_at.is_none(), entry.expires_at))
+            }
+        };
+        victim.map(|(key, _)| key.clone())
+    }
+
+    fn record_len(&self, cache: &LruCache<String, CacheEntry>) {
+        self.entries.store(cache.len(), Ordering::Relaxed);
+    }
+
     /// Check if an entry is expired
     fn is_expired(entry: &CacheEntry) -> bool {
         entry
@@ -62,13 +135,15 @@ impl CacheBackend for InMemoryCache {
         let value = {
             let mut cache = self.data.write().await;
 
-            if let Some(entry) = cache.get(key) {
+            if let Some(entry) = cache.get_mut(key) {
                 if Self::is_expired(entry) {
                     // Remove expired entry
                     cache.pop(key);
+                    self.record_len(&cache);
                     return Ok(None);
                 }
 
+                entry.reads += 1;
                 // Clone the value for deserialization outside the lock
                 Some(entry.value.clone())
             } else {
@@ -108,32 +183,39 @@ impl CacheBackend for InMemoryCache {
         let entry = CacheEntry {
             value: serialized.into_bytes(),
             expires_at,
+            reads: 0,
         };
 
         // Store in cache
         {
             #[allow(clippy::significant_drop_tightening)]
             let mut cache = self.data.write().await;
-            cache.put(key.to_string(), entry);
+            self.insert(&mut cache, key.to_string(), entry);
         }
 
         Ok(())
     }
 
+    #[allow(clippy::significant_drop_tightening)]
     async fn delete(&self, key: &str) -> Result<()> {
         {
             let mut cache = self.data.write().await;
             cache.pop(key);
+            self.record_len(&cache);
         }
         Ok(())
     }
 
-    async fn clear(&self) -> Result<()> {
-        {
+    #[allow(clippy::significant_drop_tightening)]
+    async fn clear(&self) -> Result<usize> {
+        let cleared = {
             let mut cache = self.data.write().await;
+            let len = cache.len();
             cache.clear();
-        }
-        Ok(())
+            self.record_len(&cache);
+            len
+        };
+        Ok(cleared)
     }
 
     async fn delete_by_prefix(&self, prefix: &str) -> Result<usize> {
@@ -156,8 +238,88 @@ impl CacheBackend for InMemoryCache {
                     deleted += 1;
                 }
             }
+            self.record_len(&cache);
         }
 
         Ok(deleted)
     }
+
+    #[allow(clippy::significant_drop_tightening)]
+    async fn increment(&self, key: &str, ttl: u64) -> Result<(u64, u64)> {
+        let now = Instant::now();
+
+        // Read and write under one lock so concurrent increments are not lost
+        let (count, expires_at) = {
+            let mut cache = self.data.write().await;
+            let current = cache
+                .get(key)
+                .filter(|entry| !Self::is_expired(entry))
+                .and_then(|entry| {
+                    let count = serde_json::from_slice::<u64>(&entry.value).ok()?;
+                    Some((count, entry.expires_at))
+                });
+            let (count, expires_at) = current.map_or_else(
+                || (1, (ttl > 0).then(|| now + Duration::from_secs(ttl))),
+                |(count, expires_at)| (count + 1, expires_at),
+            );
+            self.insert(
+                &mut cache,
+                key.to_string(),
+                CacheEntry {
+                    value: count.to_string().into_bytes(),
+                    expires_at,
+                    reads: 0,
+                },
+            );
+            (count, expires_at)
+        };
+
+        let remaining = expires_at.map_or(0, |at| {
+            let left = at.saturating_duration_since(now);
+            left.as_secs() + u64::from(left.subsec_nanos() > 0)
+        });
+        Ok((count, remaining))
+    }
+
+    async fn take_token(&self, key: &str, capacity: u64, window_secs: u64) -> Result<Option<u64>> {
+        #[allow(clippy::cast_precision_loss)]
+        let capacity = capacity.max(1) as f64;
+        let window = Duration::from_secs(window_secs.max(1));
+        let now = Instant::now();
+
+        // Read and write under one lock so concurrent requests cannot take the same token
+        let mut cache = self.data.write().await;
+        // Buckets expire a full window after their last refill, which dates the refill
+        let tokens = cache
+            .get(key)
+            .filter(|entry| !Self::is_expired(entry))
+            .and_then(|entry| {
+                let tokens = serde_json::from_slice::<f64>(&entry.value).ok()?;
+                let refilled_at = entry.expires_at?.checked_sub(window)?;
+                let elapsed = now.saturating_duration_since(refilled_at).as_secs_f64();
+                Some((tokens + elapsed * capacity / window.as_secs_f64()).min(capacity))
+            })
+            .unwrap_or(capacity);
+
+        let (tokens, wait) = if tokens >= 1.0 {
+            (tokens - 1.0, None)
+        } else {
+            let wait_secs = (1.0 - tokens) * window.as_secs_f64() / capacity;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            (tokens, Some((wait_secs.ceil() as u64).max(1)))
+        };
+        let value = serde_json::to_vec(&tokens)
+            .map_err(|e| Error::Cache(format!("Failed to serialize token bucket: {e}")))?;
+        self.insert(
+            &mut cache,
+            key.to_string(),
+            CacheEntry {
+                value,
+                expires_at: Some(now + window),
+                reads: 0,
+            },
+        );
+        drop(cache);
+        Ok(wait)
+    }
 }
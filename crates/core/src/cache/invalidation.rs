@@ -0,0 +1,134 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::cache::backend::CacheBackend;
+use crate::cache::in_memory::InMemoryCache;
+use crate::cache::redis::RedisCache;
+use crate::error::{Error, Result};
+
+/// Redis channel carrying cache invalidations between nodes
+pub const INVALIDATION_CHANNEL: &str = "cache:invalidations";
+
+/// Wait before subscribing again after losing the connection
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+
+/// Change to the cache that other nodes have to apply to their in-memory copy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", content = "key", rename_all = "snake_case")]
+pub enum Invalidation {
+    Delete(String),
+    DeleteByPrefix(String),
+    Clear,
+}
+
+/// Invalidation as published, tagged with the node it came from
+#[derive(Debug, Serialize, Deserialize)]
+struct InvalidationMessage {
+    origin: Uuid,
+    #[serde(flatten)]
+    invalidation: Invalidation,
+}
+
+impl Invalidation {
+    /// Publish the invalidation for all other nodes
+    ///
+    /// # Errors
+    /// Returns an error if the message cannot be published
+    pub async fn publish(self, redis: &RedisCache, origin: Uuid) -> Result<()> {
+        let message = serde_json::to_string(&InvalidationMessage {
+            origin,
+            invalidation: self,
+        })
+        .map_err(Error::Serialization)?;
+        redis.publish(INVALIDATION_CHANNEL, &message).await
+    }
+
+    async fn apply(self, in_memory: &InMemoryCache) -> Result<()> {
+        match self {
+            Self::Delete(key) => in_memory.delete(&key).await,
+            Self::DeleteByPrefix(prefix) => in_memory.delete_by_prefix(&prefix).await.map(|_| ()),
+            Self::Clear => in_memory.clear().await.map(|_| ()),
+        }
+    }
+}
+
+/// Apply invalidations published by other nodes to `in_memory` until the task is aborted
+///
+/// Subscribes before returning, so invalidations published afterwards are not missed. When
+/// the subscription drops, the in-memory cache is cleared after resubscribing, since
+/// invalidations sent in between are lost.
+///
+/// # Errors
+/// Returns an error if the initial subscription fails
+pub async fn spawn_listener(
+    redis: Arc<RedisCache>,
+    in_memory: Arc<InMemoryCache>,
+    node_id: Uuid,
+) -> Result<JoinHandle<()>> {
+    let mut pubsub = redis.subscribe(INVALIDATION_CHANNEL).await?;
+
+    Ok(tokio::spawn(async move {
+        loop {
+            let mut messages = pubsub.into_on_message();
+            while let Some(msg) = messages.next().await {
+                let message = msg
+                    .get_payload::<String>()
+                    .ok()
+                    .and_then(|payload| serde_json::from_str::<InvalidationMessage>(&payload).ok());
+                let Some(message) = message else {
+                    log::warn!("Ignoring malformed cache invalidation message");
+                    continue;
+                };
+                if message.origin == node_id {
+                    continue;
+                }
+                if let Err(e) = message.invalidation.apply(&in_memory).await {
+                    log::warn!("Failed to apply cache invalidation: {e}");
+                }
+            }
+
+            log::warn!("Cache invalidation subscription lost; resubscribing");
+            pubsub = loop {
+                tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+                match redis.subscribe(INVALIDATION_CHANNEL).await {
+                    Ok(pubsub) => break pubsub,
+                    Err(e) => log::warn!("Failed to resubscribe to cache invalidations: {e}"),
+                }
+            };
+            if let Err(e) = in_memory.clear().await {
+                log::warn!("Failed to clear in-memory cache after resubscribing: {e}");
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_round_trips() {
+        let origin = Uuid::now_v7();
+        for invalidation in [
+            Invalidation::Delete("entity_def:by_type:customer".to_string()),
+            Invalidation::DeleteByPrefix("api_key:".to_string()),
+            Invalidation::Clear,
+        ] {
+            let json = serde_json::to_string(&InvalidationMessage {
+                origin,
+                invalidation: invalidation.clone(),
+            })
+            .unwrap();
+            let parsed: InvalidationMessage = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed.origin, origin);
+            assert_eq!(parsed.invalidation, invalidation);
+        }
+    }
+}
@@ -1,18 +1,42 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
 use crate::cache::backend::CacheBackend;
 use crate::cache::in_memory::InMemoryCache;
+use crate::cache::invalidation::{self, Invalidation};
 use crate::cache::redis::RedisCache;
 use crate::config::CacheConfig;
 use crate::error::Result;
 
+/// Lookup and eviction counters of a cache manager since startup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups answered from Redis or the in-memory cache
+    pub hits: u64,
+    /// Lookups that found no value
+    pub misses: u64,
+    /// Entries currently held by the in-memory cache
+    pub entries: usize,
+    /// Entries the in-memory cache evicted because it reached `max_size`
+    pub evictions: u64,
+}
+
 /// Cache manager that handles multiple cache backends
 pub struct CacheManager {
     config: CacheConfig,
     in_memory: Arc<InMemoryCache>,
     redis: Option<Arc<RedisCache>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    /// Identifies this manager's own messages on the invalidation channel
+    node_id: Uuid,
+    /// Applies invalidations from other nodes when `broadcast_invalidations` is set
+    invalidation_listener: Option<JoinHandle<()>>,
 }
 
 impl CacheManager {
@@ -23,33 +47,62 @@ impl CacheManager {
     #[must_use]
     pub fn new(config: CacheConfig) -> Self {
         let max_size = config.max_size.try_into().unwrap_or(10000);
-        let in_memory = Arc::new(InMemoryCache::new(config.ttl, max_size));
+        let in_memory = Arc::new(
+            InMemoryCache::new(config.ttl, max_size).with_eviction_policy(config.eviction_policy),
+        );
 
         Self {
             config,
             in_memory,
             redis: None,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            node_id: Uuid::now_v7(),
+            invalidation_listener: None,
         }
     }
 
     /// Add a Redis cache backend
     ///
+    /// With `broadcast_invalidations` set, this also subscribes to invalidations from other
+    /// nodes sharing the Redis.
+    ///
     /// # Arguments
     /// * `redis_url` - Redis connection URL
     ///
     /// # Errors
-    /// Returns an error if Redis connection fails
+    /// Returns an error if Redis connection or the invalidation subscription fails
     pub async fn with_redis(mut self, redis_url: &str) -> Result<Self> {
         if redis_url.is_empty() {
             return Ok(self);
         }
 
-        let redis_cache = RedisCache::new(redis_url, self.config.ttl).await?;
-        self.redis = Some(Arc::new(redis_cache));
+        let redis_cache = Arc::new(RedisCache::new(redis_url, self.config.ttl).await?);
+        if self.config.broadcast_invalidations {
+            self.invalidation_listener = Some(
+                invalidation::spawn_listener(
+                    redis_cache.clone(),
+                    self.in_memory.clone(),
+                    self.node_id,
+                )
+                .await?,
+            );
+        }
+        self.redis = Some(redis_cache);
 
         Ok(self)
     }
 
+    /// Ping the Redis backend
+    ///
+    /// Returns `None` when no Redis backend is configured.
+    pub async fn ping_redis(&self) -> Option<Result<()>> {
+        match &self.redis {
+            Some(redis) => Some(redis.ping().await),
+            None => None,
+        }
+    }
+
     /// Get a value from the cache
     ///
     /// # Errors
@@ -65,7 +118,10 @@ impl CacheManager {
         // Try Redis first if available
         if let Some(redis) = &self.redis {
             match redis.get::<T>(key).await {
-                Ok(Some(value)) => return Ok(Some(value)),
+                Ok(Some(value)) => {
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+                    return Ok(Some(value));
+                }
                 Ok(None) => {}
                 Err(e) => {
                     log::warn!("Redis cache error: {e}");
@@ -75,7 +131,25 @@ impl CacheManager {
         }
 
         // Try in-memory cache
-        self.in_memory.get::<T>(key).await
+        let value = self.in_memory.get::<T>(key).await?;
+        let counter = if value.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        Ok(value)
+    }
+
+    /// Hit and miss counters of `get` since startup, with the in-memory cache's size and evictions
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.in_memory.len(),
+            evictions: self.in_memory.evictions(),
+        }
     }
 
     /// Set a value in the cache
@@ -121,6 +195,8 @@ impl CacheManager {
             if let Err(e) = redis.delete(key).await {
                 log::warn!("Redis cache error: {e}");
             }
+            self.broadcast(redis, Invalidation::Delete(key.to_string()))
+                .await;
         }
 
         // Always delete from in-memory cache
@@ -129,23 +205,31 @@ impl CacheManager {
 
     /// Clear the entire cache
     ///
+    /// # Returns
+    /// The number of entries removed
+    ///
     /// # Errors
     /// Returns an error if cache clearing fails
-    pub async fn clear(&self) -> Result<()> {
+    pub async fn clear(&self) -> Result<usize> {
         if !self.config.enabled {
-            return Ok(());
+            return Ok(0);
         }
 
+        let mut cleared = 0;
+
         // Clear Redis if available
         if let Some(redis) = &self.redis {
             // Ignore Redis errors, just log them
-            if let Err(e) = redis.clear().await {
-                log::warn!("Redis cache error: {e}");
+            match redis.clear().await {
+                Ok(count) => cleared = count,
+                Err(e) => log::warn!("Redis cache error: {e}"),
             }
+            self.broadcast(redis, Invalidation::Clear).await;
         }
 
-        // Always clear in-memory cache
-        self.in_memory.clear().await
+        // Always clear in-memory cache; as with prefix deletion, report the higher count
+        let count = self.in_memory.clear().await?;
+        Ok(cleared.max(count))
     }
 
     /// Delete all cache entries matching a prefix
@@ -175,6 +259,8 @@ impl CacheManager {
                     log::warn!("Redis cache error during prefix deletion: {e}");
                 }
             }
+            self.broadcast(redis, Invalidation::DeleteByPrefix(prefix.to_string()))
+                .await;
         }
 
         // Delete from in-memory cache
@@ -191,4 +277,78 @@ impl CacheManager {
 
         Ok(deleted_count)
     }
+
+    /// Increment a counter that lives for a fixed window of `window_secs` seconds
+    ///
+    /// Counters are shared through Redis when available, falling back to the in-memory cache.
+    /// Unlike cached values they are kept even if caching is disabled.
+    ///
+    /// # Returns
+    /// The count within the current window and the seconds until the window ends
+    ///
+    /// # Errors
+    /// Returns an error if the counter cannot be updated
+    pub async fn increment(&self, key: &str, window_secs: u64) -> Result<(u64, u64)> {
+        let window_secs = window_secs.max(1);
+
+        if let Some(redis) = &self.redis {
+            match redis.increment(key, window_secs).await {
+                Ok(result) => return Ok(result),
+                Err(e) => {
+                    log::warn!("Redis cache error: {e}");
+                    // Continue to in-memory counter
+                }
+            }
+        }
+
+        self.in_memory.increment(key, window_secs).await
+    }
+
+    /// Take one token from a rate limit bucket holding up to `capacity` tokens, refilled by
+    /// `capacity` tokens per `window_secs` seconds of elapsed time
+    ///
+    /// Buckets are shared through Redis when available, falling back to the in-memory cache.
+    /// Like counters they are kept even if caching is disabled.
+    ///
+    /// # Returns
+    /// `None` if a token was taken, otherwise the seconds until the next token is available
+    ///
+    /// # Errors
+    /// Returns an error if the bucket cannot be updated
+    pub async fn take_token(
+        &self,
+        key: &str,
+        capacity: u64,
+        window_secs: u64,
+    ) -> Result<Option<u64>> {
+        if let Some(redis) = &self.redis {
+            match redis.take_token(key, capacity, window_secs).await {
+                Ok(result) => return Ok(result),
+                Err(e) => {
+                    log::warn!("Redis cache error: {e}");
+                    // Continue to in-memory bucket
+                }
+            }
+        }
+
+        self.in_memory.take_token(key, capacity, window_secs).await
+    }
+
+    /// Tell other nodes to apply `invalidation` to their in-memory cache, if enabled
+    async fn broadcast(&self, redis: &RedisCache, invalidation: Invalidation) {
+        if !self.config.broadcast_invalidations {
+            return;
+        }
+        if let Err(e) = invalidation.publish(redis, self.node_id).await {
+            log::warn!("Failed to broadcast cache invalidation: {e}");
+        }
+    }
+}
+
+impl Drop for CacheManager {
+    fn drop(&mut self) {
+        if let Some(listener) = &self.invalidation_listener {
+            listener.abort();
+        }
+    }
 }
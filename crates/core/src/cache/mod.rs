@@ -2,10 +2,11 @@
 
 pub mod backend;
 pub mod in_memory;
+pub mod invalidation;
 pub mod manager;
 pub mod redis;
 
 pub use backend::CacheBackend;
 pub use in_memory::InMemoryCache;
-pub use manager::CacheManager;
+pub use manager::{CacheManager, CacheStats};
 pub use redis::{test_redis_connection, RedisCache};
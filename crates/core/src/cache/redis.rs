@@ -1,7 +1,8 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use async_trait::async_trait;
-use redis::{aio::MultiplexedConnection, AsyncCommands, Client};
+use redis::aio::{MultiplexedConnection, PubSub};
+use redis::{AsyncCommands, Client};
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::cache::backend::CacheBackend;
@@ -24,6 +25,43 @@ pub async fn test_redis_connection(conn: &mut MultiplexedConnection) -> Result<(
     Ok(())
 }
 
+/// Keys requested per `SCAN` step when deleting by prefix
+const SCAN_BATCH_SIZE: usize = 500;
+
+/// Escape the glob characters of `MATCH` patterns so a prefix only matches literally
+fn escape_glob(prefix: &str) -> String {
+    let mut escaped = String::with_capacity(prefix.len());
+    for c in prefix.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Token bucket of `take_token`, kept as a hash of the remaining tokens and the time of their
+/// last refill; the server clock keeps the refill consistent across nodes
+const TAKE_TOKEN_SCRIPT: &str = r"
+local capacity = tonumber(ARGV[1])
+local window_ms = tonumber(ARGV[2])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local state = redis.call('HMGET', KEYS[1], 'tokens', 'at')
+local tokens = tonumber(state[1]) or capacity
+local at = tonumber(state[2]) or now
+tokens = math.min(capacity, tokens + math.max(0, now - at) * capacity / window_ms)
+local wait_ms = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+else
+    wait_ms = math.ceil((1 - tokens) * window_ms / capacity)
+end
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'at', now)
+redis.call('PEXPIRE', KEYS[1], window_ms)
+return wait_ms
+";
+
 /// Redis cache implementation
 pub struct RedisCache {
     /// Redis client
@@ -59,6 +97,43 @@ impl RedisCache {
         })
     }
 
+    /// Check that Redis is reachable by sending a PING command
+    ///
+    /// # Errors
+    /// Returns an error if no connection can be established or the PING fails
+    pub async fn ping(&self) -> Result<()> {
+        let mut conn = self.get_connection().await?;
+        test_redis_connection(&mut conn).await
+    }
+
+    /// Publish `message` on a pub/sub `channel`
+    ///
+    /// # Errors
+    /// Returns an error if the message cannot be published
+    pub async fn publish(&self, channel: &str, message: &str) -> Result<()> {
+        let mut conn = self.get_connection().await?;
+        conn.publish::<_, _, ()>(channel, message)
+            .await
+            .map_err(|e| Error::Cache(format!("Failed to publish to Redis: {e}")))
+    }
+
+    /// Open a dedicated connection subscribed to a pub/sub `channel`
+    ///
+    /// # Errors
+    /// Returns an error if no connection can be established or the subscription fails
+    pub async fn subscribe(&self, channel: &str) -> Result<PubSub> {
+        let mut pubsub = self
+            .client
+            .get_async_pubsub()
+            .await
+            .map_err(|e| Error::Cache(format!("Failed to get Redis connection: {e}")))?;
+        pubsub
+            .subscribe(channel)
+            .await
+            .map_err(|e| Error::Cache(format!("Failed to subscribe to Redis: {e}")))?;
+        Ok(pubsub)
+    }
+
     async fn get_connection(&self) -> Result<MultiplexedConnection> {
         self.client
             .get_multiplexed_async_connection()
@@ -118,41 +193,43 @@ impl CacheBackend for RedisCache {
         Ok(())
     }
 
-    async fn clear(&self) -> Result<()> {
+    async fn clear(&self) -> Result<usize> {
         let mut conn = self.get_connection().await?;
 
-        let _: () = redis::cmd("FLUSHDB")
+        // Count and flush in one transaction so the count matches what was removed
+        let (cleared,): (usize,) = redis::pipe()
+            .atomic()
+            .cmd("DBSIZE")
+            .cmd("FLUSHDB")
+            .ignore()
             .query_async(&mut conn)
             .await
             .map_err(|e| Error::Cache(format!("Failed to clear Redis: {e}")))?;
 
-        Ok(())
+        Ok(cleared)
     }
 
     async fn delete_by_prefix(&self, prefix: &str) -> Result<usize> {
         let mut conn = self.get_connection().await?;
         let mut deleted = 0;
         let mut cursor = 0u64;
-        let pattern = format!("{prefix}*");
+        let pattern = format!("{}*", escape_glob(prefix));
 
         loop {
-            // Use SCAN to find keys matching the pattern
-            let result: (u64, Vec<String>) = redis::cmd("SCAN")
+            // SCAN walks the keyspace in small steps instead of blocking like KEYS
+            let (next, keys): (u64, Vec<String>) = redis::cmd("SCAN")
                 .arg(cursor)
                 .arg("MATCH")
                 .arg(&pattern)
                 .arg("COUNT")
-                .arg(100)
+                .arg(SCAN_BATCH_SIZE)
                 .query_async(&mut conn)
                 .await
                 .map_err(|e| Error::Cache(format!("Failed to scan Redis keys: {e}")))?;
 
-            cursor = result.0;
-            let keys = result.1;
-
             if !keys.is_empty() {
-                // Delete the keys
-                let count: u64 = redis::cmd("DEL")
+                // UNLINK frees the values in the background; keys SCAN returns twice count once
+                let count: u64 = redis::cmd("UNLINK")
                     .arg(&keys)
                     .query_async(&mut conn)
                     .await
@@ -160,12 +237,51 @@ impl CacheBackend for RedisCache {
                 deleted += usize::try_from(count).unwrap_or(0);
             }
 
-            // If cursor is 0, we've scanned all keys
-            if cursor == 0 {
+            // A zero cursor ends the iteration
+            if next == 0 {
                 break;
             }
+            cursor = next;
         }
 
         Ok(deleted)
     }
+
+    async fn increment(&self, key: &str, ttl: u64) -> Result<(u64, u64)> {
+        let mut conn = self.get_connection().await?;
+
+        // Create the counter with its lifetime first, so INCR never leaves a counter without TTL
+        let (count, remaining): (u64, i64) = redis::pipe()
+            .atomic()
+            .cmd("SET")
+            .arg(key)
+            .arg(0)
+            .arg("EX")
+            .arg(ttl)
+            .arg("NX")
+            .ignore()
+            .cmd("INCR")
+            .arg(key)
+            .cmd("TTL")
+            .arg(key)
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| Error::Cache(format!("Failed to increment Redis counter: {e}")))?;
+
+        Ok((count, u64::try_from(remaining).unwrap_or(0)))
+    }
+
+    async fn take_token(&self, key: &str, capacity: u64, window_secs: u64) -> Result<Option<u64>> {
+        let mut conn = self.get_connection().await?;
+
+        let wait_ms: u64 = redis::Script::new(TAKE_TOKEN_SCRIPT)
+            .key(key)
+            .arg(capacity.max(1))
+            .arg(window_secs.max(1) * 1000)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| Error::Cache(format!("Failed to take Redis rate limit token: {e}")))?;
+
+        Ok((wait_ms > 0).then(|| wait_ms.div_ceil(1000)))
+    }
 }
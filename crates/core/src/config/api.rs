@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 /// API configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)] // Configuration flags are intentionally separate
 pub struct ApiConfig {
     /// API host
     pub host: String,
@@ -28,4 +29,21 @@ pub struct ApiConfig {
 
     /// Check if default admin password is still in use
     pub check_default_admin_password: bool,
+
+    /// Answer `410 Gone` for soft-deleted entities instead of `404 Not Found`.
+    /// Off by default so public routes do not reveal that an entity once existed.
+    pub expose_deleted_entities: bool,
+
+    /// Reverse proxies (CIDR blocks) whose `X-Forwarded-For` header gives the client IP.
+    /// Requests from other addresses use the socket address; empty trusts no proxy.
+    pub trusted_proxies: Vec<String>,
+
+    /// Compress responses of at least this many bytes with gzip or deflate, as accepted by the
+    /// client. Streamed bodies of unknown size are always compressed. `None` disables
+    /// compression.
+    pub compression_min_size: Option<usize>,
+
+    /// Public API requests allowed per client IP and minute, refilled continuously. Applies
+    /// to every caller next to the limits of API keys; `None` disables it.
+    pub ip_rate_limit_per_minute: Option<u32>,
 }
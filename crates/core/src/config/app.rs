@@ -3,7 +3,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::config::{
-    ApiConfig, CacheConfig, DatabaseConfig, LicenseConfig, LogConfig, MailConfig, QueueConfig,
+    ApiConfig, CacheConfig, DatabaseConfig, LicenseConfig, LogConfig, MailConfig,
+    PasswordPolicyConfig, QueueConfig, WorkflowIngestLimits,
 };
 
 /// Application configuration
@@ -49,6 +50,12 @@ pub struct AppConfig {
     pub frontend_base_url: Option<String>,
     /// Minimum seconds between password-reset requests for the same account
     pub password_reset_throttle_seconds: u64,
+    /// Password rules for admin users
+    pub password_policy: PasswordPolicyConfig,
+    /// Seconds to let in-flight requests finish after a shutdown signal
+    pub shutdown_timeout_secs: u64,
+    /// Size limits of data posted to the workflow ingest endpoint
+    pub workflow_ingest: WorkflowIngestLimits,
 }
 
 /// Worker-specific configuration
@@ -89,6 +96,10 @@ pub struct WorkerConfig {
     pub license: LicenseConfig,
     /// Mail configuration
     pub mail: MailConfig,
+    /// Seconds to wait for executing workflow runs after a shutdown signal
+    pub shutdown_timeout_secs: u64,
+    /// Secret signing workflow completion webhooks; unsigned when not set
+    pub completion_webhook_secret: Option<String>,
 }
 
 /// Maintenance worker configuration
@@ -118,6 +129,12 @@ pub struct MaintenanceConfig {
     /// Retention window for terminal outbox rows, in days
     pub outbox_retention_days: Option<u32>,
 
+    /// Cron expression for the entity schema reconciliation check (disabled when unset)
+    pub schema_reconcile_cron: Option<String>,
+
+    /// Whether the reconciliation check fixes mismatches instead of only reporting them
+    pub schema_reconcile_fix: bool,
+
     /// Database configuration used by the maintenance worker
     pub database: DatabaseConfig,
 
@@ -1,7 +1,39 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
+/// How the in-memory cache picks the entry to evict once it holds `max_size` entries
+///
+/// Expired entries are dropped when they are next read, whatever the policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CacheEvictionPolicy {
+    /// Evict the least recently used entry
+    #[default]
+    Lru,
+    /// Evict the least frequently read entry, the least recently used among ties
+    Lfu,
+    /// Evict the entry closest to expiry, ignoring how it is used
+    TtlOnly,
+}
+
+impl FromStr for CacheEvictionPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "lru" => Ok(Self::Lru),
+            "lfu" => Ok(Self::Lfu),
+            "ttl-only" | "ttl_only" => Ok(Self::TtlOnly),
+            other => Err(format!(
+                "Unknown cache eviction policy '{other}'; expected lru, lfu or ttl-only"
+            )),
+        }
+    }
+}
+
 /// Cache configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheConfig {
@@ -14,11 +46,33 @@ pub struct CacheConfig {
     /// Maximum cache size (number of items)
     pub max_size: u64,
 
+    /// Which entry the in-memory cache evicts when it is full
+    #[serde(default)]
+    pub eviction_policy: CacheEvictionPolicy,
+
     /// TTL for entity definitions cache (0 = no expiration, use None when setting)
     pub entity_definition_ttl: u64,
 
     /// TTL for API keys cache in seconds
     pub api_key_ttl: u64,
+
+    /// TTL in seconds for remembering that an entity UUID does not exist (0 = disabled)
+    #[serde(default = "default_entity_miss_ttl")]
+    pub entity_miss_ttl: u64,
+
+    /// Load all published entity definitions into the cache when the API starts
+    #[serde(default)]
+    pub warm_on_startup: bool,
+
+    /// Tell other nodes sharing the Redis cache to drop deleted keys from their in-memory cache
+    ///
+    /// Only needed when several processes share one Redis; single-node deployments leave it off.
+    #[serde(default)]
+    pub broadcast_invalidations: bool,
+}
+
+const fn default_entity_miss_ttl() -> u64 {
+    0
 }
 
 impl Default for CacheConfig {
@@ -27,8 +81,12 @@ impl Default for CacheConfig {
             enabled: true,
             ttl: 3600,
             max_size: 10000,
+            eviction_policy: CacheEvictionPolicy::Lru,
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: default_entity_miss_ttl(),
+            warm_on_startup: false,
+            broadcast_invalidations: false,
         }
     }
 }
@@ -1,6 +1,9 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPoolOptions;
 
 /// Database configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +14,28 @@ pub struct DatabaseConfig {
     /// Maximum number of connections in the pool
     pub max_connections: u32,
 
-    /// Connection timeout in seconds
-    pub connection_timeout: u64,
+    /// Number of connections the pool keeps open even when idle
+    pub min_connections: u32,
+
+    /// Seconds to wait for a free connection before a query fails
+    pub acquire_timeout: u64,
+
+    /// Seconds an idle connection is kept before it is closed (0 keeps it forever)
+    pub idle_timeout: u64,
+
+    /// Connection strings of read replicas for read-only queries (empty reads from the primary)
+    #[serde(default)]
+    pub read_replica_urls: Vec<String>,
+}
+
+impl DatabaseConfig {
+    /// Pool options with the configured limits and timeouts
+    #[must_use]
+    pub fn pool_options(&self) -> PgPoolOptions {
+        PgPoolOptions::new()
+            .max_connections(self.max_connections)
+            .min_connections(self.min_connections)
+            .acquire_timeout(Duration::from_secs(self.acquire_timeout))
+            .idle_timeout((self.idle_timeout > 0).then(|| Duration::from_secs(self.idle_timeout)))
+    }
 }
@@ -5,7 +5,8 @@ use std::env;
 
 use crate::config::{
     ApiConfig, AppConfig, CacheConfig, DatabaseConfig, LicenseConfig, LogConfig, MailConfig,
-    MaintenanceConfig, QueueConfig, WorkerConfig, WorkflowConfig,
+    MaintenanceConfig, PasswordPolicyConfig, QueueConfig, WorkerConfig, WorkflowConfig,
+    WorkflowIngestLimits,
 };
 use crate::error::Result;
 use crate::utils;
@@ -49,6 +50,9 @@ pub fn load_app_config() -> Result<AppConfig> {
             .unwrap_or_else(|_| "60".to_string())
             .parse()
             .unwrap_or(60),
+        password_policy: get_password_policy_config(),
+        shutdown_timeout_secs: get_shutdown_timeout_secs(),
+        workflow_ingest: get_workflow_ingest_limits(),
     })
 }
 
@@ -97,6 +101,10 @@ pub fn load_worker_config() -> Result<WorkerConfig> {
         cache,
         license,
         mail,
+        shutdown_timeout_secs: get_shutdown_timeout_secs(),
+        completion_webhook_secret: env::var("WORKFLOW_WEBHOOK_SECRET")
+            .ok()
+            .filter(|s| !s.is_empty()),
     })
 }
 
@@ -197,32 +205,44 @@ fn load_outbox_config(include_stale_lease: bool) -> Result<OutboxConfigVars> {
 }
 
 fn load_database_config() -> Result<DatabaseConfig> {
-    Ok(DatabaseConfig {
-        connection_string: env::var("DATABASE_URL")
-            .map_err(|_| crate::error::Error::Config("DATABASE_URL not set".to_string()))?,
-        max_connections: env::var("DATABASE_MAX_CONNECTIONS")
-            .unwrap_or_else(|_| "10".to_string())
-            .parse()
-            .unwrap_or(10),
-        connection_timeout: env::var("DATABASE_CONNECTION_TIMEOUT")
-            .unwrap_or_else(|_| "30".to_string())
-            .parse()
-            .unwrap_or(30),
-    })
+    load_prefixed_database_config("")
 }
 
 fn load_worker_database_config() -> Result<DatabaseConfig> {
+    load_prefixed_database_config("WORKER_")
+}
+
+/// Database settings read from `<prefix>DATABASE_*`
+///
+/// `<prefix>DATABASE_CONNECTION_TIMEOUT` is still accepted when no acquire timeout is set.
+fn load_prefixed_database_config(prefix: &str) -> Result<DatabaseConfig> {
+    let url_var = format!("{prefix}DATABASE_URL");
+    let var = |name: &str| env::var(format!("{prefix}DATABASE_{name}")).ok();
     Ok(DatabaseConfig {
-        connection_string: env::var("WORKER_DATABASE_URL")
-            .map_err(|_| crate::error::Error::Config("WORKER_DATABASE_URL not set".to_string()))?,
-        max_connections: env::var("WORKER_DATABASE_MAX_CONNECTIONS")
-            .unwrap_or_else(|_| "10".to_string())
-            .parse()
+        connection_string: env::var(&url_var)
+            .map_err(|_| crate::error::Error::Config(format!("{url_var} not set")))?,
+        max_connections: var("MAX_CONNECTIONS")
+            .and_then(|v| v.trim().parse().ok())
             .unwrap_or(10),
-        connection_timeout: env::var("DATABASE_CONNECTION_TIMEOUT")
-            .unwrap_or_else(|_| "30".to_string())
-            .parse()
+        min_connections: var("MIN_CONNECTIONS")
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(0),
+        acquire_timeout: var("ACQUIRE_TIMEOUT")
+            .or_else(|| var("CONNECTION_TIMEOUT"))
+            .and_then(|v| v.trim().parse().ok())
             .unwrap_or(30),
+        idle_timeout: var("IDLE_TIMEOUT")
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(600),
+        read_replica_urls: var("READ_REPLICA_URLS")
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|url| !url.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default(),
     })
 }
 
@@ -258,6 +278,13 @@ fn load_runtime_api_config() -> Result<ApiConfig> {
             .unwrap_or_else(|_| "true".to_string())
             .parse()
             .unwrap_or(true),
+        expose_deleted_entities: env::var("API_EXPOSE_DELETED_ENTITIES")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false),
+        trusted_proxies: get_trusted_proxies()?,
+        compression_min_size: get_compression_min_size(),
+        ip_rate_limit_per_minute: get_ip_rate_limit_per_minute(),
     })
 }
 
@@ -303,6 +330,14 @@ pub fn load_maintenance_config() -> Result<MaintenanceConfig> {
         load_outbox_maintenance_config(outbox_enabled)?;
     let system_logs_purger_cron = load_required_cron("SYSTEM_LOGS_PURGER_CRON")?;
     let system_logs_retention_days = load_retention_days("SYSTEM_LOGS_RETENTION_DAYS", 90_u64)?;
+    let schema_reconcile_cron = env::var("SCHEMA_RECONCILE_CRON")
+        .ok()
+        .map(|_| load_required_cron("SCHEMA_RECONCILE_CRON"))
+        .transpose()?;
+    let schema_reconcile_fix = env::var("SCHEMA_RECONCILE_FIX")
+        .unwrap_or_else(|_| "false".to_string())
+        .parse()
+        .unwrap_or(false);
     let database = load_maintenance_database_config()?;
 
     let cache = get_cache_config();
@@ -320,6 +355,8 @@ pub fn load_maintenance_config() -> Result<MaintenanceConfig> {
         system_logs_retention_days,
         outbox_purger_cron,
         outbox_retention_days,
+        schema_reconcile_cron,
+        schema_reconcile_fix,
         database,
         cache,
         redis_url,
@@ -358,19 +395,7 @@ fn load_outbox_maintenance_config(outbox_enabled: bool) -> Result<(Option<String
 }
 
 fn load_maintenance_database_config() -> Result<DatabaseConfig> {
-    Ok(DatabaseConfig {
-        connection_string: env::var("MAINTENANCE_DATABASE_URL").map_err(|_| {
-            crate::error::Error::Config("MAINTENANCE_DATABASE_URL not set".to_string())
-        })?,
-        max_connections: env::var("MAINTENANCE_DATABASE_MAX_CONNECTIONS")
-            .unwrap_or_else(|_| "10".to_string())
-            .parse()
-            .unwrap_or(10),
-        connection_timeout: env::var("MAINTENANCE_DATABASE_CONNECTION_TIMEOUT")
-            .unwrap_or_else(|_| "30".to_string())
-            .parse()
-            .unwrap_or(30),
-    })
+    load_prefixed_database_config("MAINTENANCE_")
 }
 
 fn load_maintenance_api_config() -> Result<ApiConfig> {
@@ -406,6 +431,13 @@ fn load_maintenance_api_config() -> Result<ApiConfig> {
             .unwrap_or_else(|_| "true".to_string())
             .parse()
             .unwrap_or(true),
+        expose_deleted_entities: env::var("API_EXPOSE_DELETED_ENTITIES")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false),
+        trusted_proxies: get_trusted_proxies()?,
+        compression_min_size: get_compression_min_size(),
+        ip_rate_limit_per_minute: get_ip_rate_limit_per_minute(),
     })
 }
 
@@ -423,6 +455,10 @@ fn get_cache_config() -> CacheConfig {
             .unwrap_or_else(|_| "10000".to_string())
             .parse()
             .unwrap_or(10000),
+        eviction_policy: env::var("CACHE_EVICTION_POLICY")
+            .ok()
+            .and_then(|policy| policy.parse().ok())
+            .unwrap_or_default(),
         entity_definition_ttl: env::var("CACHE_ENTITY_DEFINITION_TTL")
             .unwrap_or_else(|_| "0".to_string())
             .parse()
@@ -431,9 +467,94 @@ fn get_cache_config() -> CacheConfig {
             .unwrap_or_else(|_| "600".to_string())
             .parse()
             .unwrap_or(600),
+        entity_miss_ttl: env::var("CACHE_ENTITY_MISS_TTL")
+            .unwrap_or_else(|_| "0".to_string())
+            .parse()
+            .unwrap_or(0),
+        warm_on_startup: env::var("CACHE_WARM_ON_STARTUP")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false),
+        broadcast_invalidations: env::var("CACHE_BROADCAST_INVALIDATIONS")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false),
+    }
+}
+
+fn get_password_policy_config() -> PasswordPolicyConfig {
+    let flag = |name: &str| {
+        env::var(name)
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false)
+    };
+    PasswordPolicyConfig {
+        min_length: env::var("PASSWORD_MIN_LENGTH")
+            .unwrap_or_else(|_| "8".to_string())
+            .parse()
+            .unwrap_or(8),
+        require_uppercase: flag("PASSWORD_REQUIRE_UPPERCASE"),
+        require_lowercase: flag("PASSWORD_REQUIRE_LOWERCASE"),
+        require_digit: flag("PASSWORD_REQUIRE_DIGIT"),
+        require_symbol: flag("PASSWORD_REQUIRE_SYMBOL"),
+        breached_passwords_file: env::var("PASSWORD_BREACHED_LIST_PATH")
+            .ok()
+            .filter(|s| !s.is_empty()),
+    }
+}
+
+/// Graceful shutdown timeout shared by the server and the worker (default: 30 seconds)
+fn get_shutdown_timeout_secs() -> u64 {
+    env::var("SHUTDOWN_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(30)
+}
+
+/// Workflow ingest limits (defaults: 10 MiB body, 10000 records)
+fn get_workflow_ingest_limits() -> WorkflowIngestLimits {
+    WorkflowIngestLimits {
+        max_body_bytes: env::var("WORKFLOW_INGEST_MAX_BODY_BYTES")
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(WorkflowIngestLimits::DEFAULT_MAX_BODY_BYTES),
+        max_records: env::var("WORKFLOW_INGEST_MAX_RECORDS")
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(WorkflowIngestLimits::DEFAULT_MAX_RECORDS),
     }
 }
 
+/// Response compression threshold in bytes (default: 1024); `off` disables compression
+fn get_trusted_proxies() -> Result<Vec<String>> {
+    let proxies: Vec<String> = env::var("API_TRUSTED_PROXIES")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(ToString::to_string)
+        .collect();
+    crate::admin_user::api_key_cidrs::normalize(&proxies)
+        .map_err(|e| crate::error::Error::Config(format!("API_TRUSTED_PROXIES: {e}")))
+}
+
+fn get_compression_min_size() -> Option<usize> {
+    match env::var("API_COMPRESSION_MIN_SIZE") {
+        Ok(v) if v.trim().eq_ignore_ascii_case("off") => None,
+        Ok(v) => Some(v.trim().parse().unwrap_or(1024)),
+        Err(_) => Some(1024),
+    }
+}
+
+/// Public API requests allowed per client IP and minute; unset, `0` or `off` disables the limit
+fn get_ip_rate_limit_per_minute() -> Option<u32> {
+    env::var("API_IP_RATE_LIMIT_PER_MINUTE")
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|limit| *limit > 0)
+}
+
 fn get_queue_config() -> Result<QueueConfig> {
     let config = QueueConfig {
         redis_url: env::var("REDIS_URL")
@@ -473,7 +594,16 @@ fn get_mail_config() -> MailConfig {
                 .ok()
         });
 
-    MailConfig { system, workflow }
+    let notification_dry_run = env::var("NOTIFICATION_EMAIL_DRY_RUN")
+        .unwrap_or_else(|_| "false".to_string())
+        .parse()
+        .unwrap_or(false);
+
+    MailConfig {
+        system,
+        workflow,
+        notification_dry_run,
+    }
 }
 
 fn get_license_config() -> LicenseConfig {
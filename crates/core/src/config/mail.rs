@@ -31,6 +31,9 @@ pub struct MailConfig {
     pub system: Option<SmtpConfig>,
     /// SMTP configuration used for workflow-triggered e-mails
     pub workflow: Option<SmtpConfig>,
+    /// Log e-mail notifications instead of sending them (local development)
+    #[serde(default)]
+    pub notification_dry_run: bool,
 }
 
 /// Parse an SMTP DSN string into an [`SmtpConfig`].
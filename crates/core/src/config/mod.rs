@@ -8,6 +8,7 @@ pub mod license;
 pub mod loader;
 pub mod log;
 pub mod mail;
+pub mod password_policy;
 pub mod queue;
 pub mod workflow;
 
@@ -15,13 +16,14 @@ pub use loader::{load_cache_config, load_license_config};
 
 pub use api::ApiConfig;
 pub use app::{AppConfig, MaintenanceConfig, WorkerConfig};
-pub use cache::CacheConfig;
+pub use cache::{CacheConfig, CacheEvictionPolicy};
 pub use database::DatabaseConfig;
 pub use license::LicenseConfig;
 pub use log::LogConfig;
 pub use mail::{parse_smtp_dsn, MailConfig, SmtpConfig};
+pub use password_policy::PasswordPolicyConfig;
 pub use queue::QueueConfig;
-pub use workflow::WorkflowConfig;
+pub use workflow::{WorkflowConfig, WorkflowIngestLimits};
 
 // Re-export loader functions
 pub use loader::{load_app_config, load_maintenance_config, load_worker_config};
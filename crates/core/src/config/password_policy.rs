@@ -0,0 +1,39 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use serde::{Deserialize, Serialize};
+
+/// Rules admin user passwords must satisfy on creation and change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)] // One flag per character class
+pub struct PasswordPolicyConfig {
+    /// Minimum number of characters
+    pub min_length: usize,
+
+    /// Require at least one uppercase letter
+    pub require_uppercase: bool,
+
+    /// Require at least one lowercase letter
+    pub require_lowercase: bool,
+
+    /// Require at least one digit
+    pub require_digit: bool,
+
+    /// Require at least one character that is neither a letter nor a digit
+    pub require_symbol: bool,
+
+    /// File of known breached passwords (one per line) that are rejected
+    pub breached_passwords_file: Option<String>,
+}
+
+impl Default for PasswordPolicyConfig {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            require_uppercase: false,
+            require_lowercase: false,
+            require_digit: false,
+            require_symbol: false,
+            breached_passwords_file: None,
+        }
+    }
+}
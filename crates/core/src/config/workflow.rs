@@ -14,3 +14,27 @@ pub struct WorkflowConfig {
     /// Max concurrent workflows
     pub max_concurrent: u32,
 }
+
+/// Size limits of data ingested into a workflow run, checked before anything is staged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowIngestLimits {
+    /// Largest accepted request body in bytes
+    pub max_body_bytes: usize,
+
+    /// Most records staged from a single ingest
+    pub max_records: usize,
+}
+
+impl WorkflowIngestLimits {
+    pub const DEFAULT_MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+    pub const DEFAULT_MAX_RECORDS: usize = 10_000;
+}
+
+impl Default for WorkflowIngestLimits {
+    fn default() -> Self {
+        Self {
+            max_body_bytes: Self::DEFAULT_MAX_BODY_BYTES,
+            max_records: Self::DEFAULT_MAX_RECORDS,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+/// How a list counts the total number of items
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CountMode {
+    /// Exact `COUNT(*)`
+    #[default]
+    Exact,
+    /// Estimate from the planner statistics of the table
+    Approximate,
+    /// No total
+    None,
+}
+
+impl CountMode {
+    /// Parse `exact`, `approximate` or `none` (any case)
+    ///
+    /// # Errors
+    /// Returns an error for any other value
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "exact" => Ok(Self::Exact),
+            "approximate" => Ok(Self::Approximate),
+            "none" => Ok(Self::None),
+            _ => Err(format!(
+                "Invalid count: '{value}'. Must be 'exact', 'approximate' or 'none'"
+            )),
+        }
+    }
+}
+
+/// Total number of items of a list, as counted by a [`CountMode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListTotal {
+    Exact(i64),
+    Approximate(i64),
+    Skipped,
+}
+
+impl ListTotal {
+    /// The total, exact or estimated; `None` when it was skipped
+    #[must_use]
+    pub const fn value(self) -> Option<i64> {
+        match self {
+            Self::Exact(total) | Self::Approximate(total) => Some(total),
+            Self::Skipped => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_count_modes() {
+        assert_eq!(CountMode::parse("exact"), Ok(CountMode::Exact));
+        assert_eq!(CountMode::parse("Approximate"), Ok(CountMode::Approximate));
+        assert_eq!(CountMode::parse(" none "), Ok(CountMode::None));
+        assert!(CountMode::parse("estimate").is_err());
+    }
+}
@@ -7,6 +7,8 @@ use uuid::Uuid;
 
 use crate::entity_definition::definition::EntityDefinition;
 use crate::error::Result;
+use crate::field::duration::IsoDuration;
+use crate::field::money::Money;
 use crate::field::{FieldDefinition, FieldType};
 
 // Create a ValidationContext struct to encapsulate common validation parameters
@@ -110,15 +112,17 @@ impl DynamicEntityValidator {
         }
 
         // Validate based on a field type
-        match field_def.field_type {
+        let type_result = match field_def.field_type {
             FieldType::String | FieldType::Text | FieldType::Wysiwyg | FieldType::Password => {
                 Self::validate_string(&ctx)
             }
             FieldType::Integer => Self::validate_integer(&ctx),
             FieldType::Float => Self::validate_float(&ctx),
+            FieldType::Money => Self::validate_money(&ctx),
             FieldType::Boolean => Self::validate_boolean(&ctx),
             FieldType::Date => Self::validate_date(&ctx),
             FieldType::DateTime => Self::validate_datetime(&ctx),
+            FieldType::Duration => Self::validate_duration(&ctx),
             FieldType::Uuid => Self::validate_uuid(&ctx),
             FieldType::Select => Self::validate_select(&ctx),
             FieldType::MultiSelect => Self::validate_multi_select(&ctx),
@@ -131,7 +135,16 @@ impl DynamicEntityValidator {
             | FieldType::ManyToMany
             | FieldType::Image
             | FieldType::File => Ok(()),
+        };
+        type_result?;
+
+        if matches!(
+            field_def.field_type,
+            FieldType::Json | FieldType::Object | FieldType::Array | FieldType::Text
+        ) {
+            field_def.validate_max_bytes(value)?;
         }
+        Ok(())
     }
 
     /// Validate string fields
@@ -216,6 +229,24 @@ impl DynamicEntityValidator {
         Ok(())
     }
 
+    /// Validate money fields
+    fn validate_money(ctx: &ValidationContext) -> Result<()> {
+        let money =
+            Money::from_json(ctx.value).map_err(|e| ctx.create_validation_error(&e.to_string()))?;
+        ctx.validate_number_range(money.amount_value())
+    }
+
+    /// Validate duration fields
+    fn validate_duration(ctx: &ValidationContext) -> Result<()> {
+        let s = ctx
+            .value
+            .as_str()
+            .ok_or_else(|| ctx.create_validation_error("must be a duration string"))?;
+        s.parse::<IsoDuration>()
+            .map(|_| ())
+            .map_err(|e| ctx.create_validation_error(&e.to_string()))
+    }
+
     /// Validate boolean fields
     fn validate_boolean(ctx: &ValidationContext) -> Result<()> {
         match ctx.value {
@@ -364,16 +395,11 @@ impl DynamicEntityValidator {
         };
 
         // Validate against options if present
-        if let Some(crate::field::OptionsSource::Fixed { options }) =
-            &ctx.field_def.validation.options_source
-        {
-            let valid_options: Vec<String> = options.iter().map(|opt| opt.value.clone()).collect();
-
-            if !valid_options.contains(option_value) {
-                return Err(ctx.create_validation_error(&format!(
-                    "must be one of: {}",
-                    valid_options.join(", ")
-                )));
+        if let Some(allowed) = ctx.field_def.validation.allowed_options() {
+            if !allowed.contains(&option_value.as_str()) {
+                return Err(
+                    ctx.create_validation_error(&format!("must be one of: {}", allowed.join(", ")))
+                );
             }
         }
 
@@ -397,16 +423,12 @@ impl DynamicEntityValidator {
         };
 
         // Validate against options if present
-        if let Some(crate::field::OptionsSource::Fixed { options }) =
-            &ctx.field_def.validation.options_source
-        {
-            let valid_options: Vec<String> = options.iter().map(|opt| opt.value.clone()).collect();
-
+        if let Some(allowed) = ctx.field_def.validation.allowed_options() {
             for value in &selected_values {
-                if !valid_options.contains(value) {
+                if !allowed.contains(&value.as_str()) {
                     return Err(ctx.create_validation_error(&format!(
                         "contains invalid option '{value}'. Valid options are: {}",
-                        valid_options.join(", ")
+                        allowed.join(", ")
                     )));
                 }
             }
@@ -533,6 +555,16 @@ pub fn validate_entity_with_violations(
                 field: field_def.name.clone(),
                 message: "This field is required".to_string(),
             });
+        } else if let Some(requirement) = &field_def.validation.requires {
+            if let Some(message) = field_def.unmet_requirement(
+                field_data.get(&requirement.field),
+                field_data.get(&field_def.name),
+            ) {
+                violations.push(FieldViolation {
+                    field: field_def.name.clone(),
+                    message,
+                });
+            }
         }
     }
 
@@ -569,6 +601,7 @@ pub fn validate_entity_with_violations(
                 "published",
                 "version",
                 "parent_uuid", // Parent entity reference
+                "deleted_at",  // Soft-delete marker
             ];
             if !system_fields.contains(&field_name.as_str()) {
                 violations.push(FieldViolation {
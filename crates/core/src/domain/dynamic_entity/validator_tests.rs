@@ -19,6 +19,7 @@ fn create_test_field(field_type: FieldType, required: bool) -> FieldDefinition {
         ui_settings: UiSettings::default(),
         constraints: std::collections::HashMap::new(),
         description: None,
+        display_order: 0,
     }
 }
 
@@ -36,6 +37,7 @@ fn create_named_field(name: &str, display_name: &str, field_type: FieldType) ->
         ui_settings: UiSettings::default(),
         constraints: std::collections::HashMap::new(),
         description: None,
+        display_order: 0,
     }
 }
 
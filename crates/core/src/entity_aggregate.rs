@@ -0,0 +1,131 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use time::OffsetDateTime;
+
+/// Cached count and numeric field sums of the live entities of one entity type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityAggregate {
+    pub entity_type: String,
+    /// Numeric fields whose sums are maintained
+    pub sum_fields: Vec<String>,
+    pub entity_count: i64,
+    pub sums: BTreeMap<String, f64>,
+    /// Last full recomputation; `None` if the aggregate was never rebuilt
+    pub rebuilt_at: Option<OffsetDateTime>,
+    pub updated_at: OffsetDateTime,
+}
+
+/// Change applied to an aggregate for a single entity write
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AggregateDelta {
+    /// `1` for a created entity, `-1` for a deleted one, `0` for an update
+    pub count: i64,
+    /// Numeric values to add to the sums
+    pub add: Map<String, Value>,
+    /// Numeric values to subtract from the sums
+    pub subtract: Map<String, Value>,
+}
+
+/// Numeric values of `field_data`; non-numeric and null values are left out
+fn numeric_values<'a, S: std::hash::BuildHasher>(
+    field_data: &'a HashMap<String, Value, S>,
+    keys: impl Iterator<Item = &'a String>,
+) -> Map<String, Value> {
+    keys.filter_map(|name| match field_data.get(name) {
+        Some(value @ Value::Number(_)) => Some((name.clone(), value.clone())),
+        _ => None,
+    })
+    .collect()
+}
+
+impl AggregateDelta {
+    /// Delta for a newly created (or restored) entity
+    #[must_use]
+    pub fn created<S: std::hash::BuildHasher>(after: &HashMap<String, Value, S>) -> Self {
+        Self {
+            count: 1,
+            add: numeric_values(after, after.keys()),
+            subtract: Map::new(),
+        }
+    }
+
+    /// Delta for an update; only fields present in `after` are considered changed,
+    /// so partial updates leave the sums of omitted fields untouched
+    #[must_use]
+    pub fn updated<S: std::hash::BuildHasher>(
+        before: &HashMap<String, Value, S>,
+        after: &HashMap<String, Value, S>,
+    ) -> Self {
+        Self {
+            count: 0,
+            add: numeric_values(after, after.keys()),
+            subtract: numeric_values(before, after.keys()),
+        }
+    }
+
+    /// Delta for a deleted entity
+    #[must_use]
+    pub fn deleted<S: std::hash::BuildHasher>(before: &HashMap<String, Value, S>) -> Self {
+        Self {
+            count: -1,
+            add: Map::new(),
+            subtract: numeric_values(before, before.keys()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fields(value: &Value) -> HashMap<String, Value> {
+        serde_json::from_value(value.clone()).unwrap()
+    }
+
+    #[test]
+    fn created_and_deleted_take_numeric_values_only() {
+        let data = fields(&json!({ "name": "a", "price": 2.5, "qty": 3, "note": null }));
+
+        let created = AggregateDelta::created(&data);
+        assert_eq!(created.count, 1);
+        assert_eq!(
+            Value::Object(created.add),
+            json!({ "price": 2.5, "qty": 3 })
+        );
+        assert!(created.subtract.is_empty());
+
+        let deleted = AggregateDelta::deleted(&data);
+        assert_eq!(deleted.count, -1);
+        assert!(deleted.add.is_empty());
+        assert_eq!(
+            Value::Object(deleted.subtract),
+            json!({ "price": 2.5, "qty": 3 })
+        );
+    }
+
+    #[test]
+    fn updated_ignores_fields_missing_from_partial_update() {
+        let before = fields(&json!({ "price": 2.5, "qty": 3 }));
+        let after = fields(&json!({ "qty": 5 }));
+
+        let delta = AggregateDelta::updated(&before, &after);
+        assert_eq!(delta.count, 0);
+        assert_eq!(Value::Object(delta.add), json!({ "qty": 5 }));
+        assert_eq!(Value::Object(delta.subtract), json!({ "qty": 3 }));
+    }
+
+    #[test]
+    fn updated_to_null_only_subtracts() {
+        let before = fields(&json!({ "price": 2.5 }));
+        let after = fields(&json!({ "price": null }));
+
+        let delta = AggregateDelta::updated(&before, &after);
+        assert!(delta.add.is_empty());
+        assert_eq!(Value::Object(delta.subtract), json!({ "price": 2.5 }));
+    }
+}
@@ -0,0 +1,104 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::definition::EntityDefinition;
+use super::schema::Schema;
+use super::validation_rule::ValidationRule;
+use crate::field::FieldDefinition;
+
+/// Portable JSON export of an entity definition for moving it between environments
+///
+/// Carries the definition and its fields only; UUIDs, audit data and the version counter
+/// belong to the environment and are assigned again on import.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EntityDefinitionBundle {
+    /// Bundle format, see `EntityDefinitionBundle::FORMAT_VERSION`
+    pub format_version: u32,
+    /// Entity type identifier, used to match existing definitions on import
+    pub entity_type: String,
+    /// Display name
+    pub display_name: String,
+    /// Optional description
+    pub description: Option<String>,
+    /// Optional group name
+    pub group_name: Option<String>,
+    /// Whether entities of this type can have children
+    pub allow_children: bool,
+    /// Optional icon identifier
+    pub icon: Option<String>,
+    /// Whether the definition is published
+    pub published: bool,
+    /// Field definitions
+    #[schema(value_type = Vec<Object>)]
+    pub fields: Vec<FieldDefinition>,
+    /// Groups of field names whose combined values must be unique
+    #[serde(default)]
+    pub unique_constraints: Vec<Vec<String>>,
+    /// Entity-level validation rules
+    #[serde(default)]
+    #[schema(value_type = Vec<Object>)]
+    pub validation_rules: Vec<ValidationRule>,
+    /// Table holding the entities, if not the derived `entity_<type>`
+    #[serde(default)]
+    pub table_name: Option<String>,
+}
+
+impl EntityDefinitionBundle {
+    /// Current bundle format
+    pub const FORMAT_VERSION: u32 = 1;
+
+    /// Definition created from the bundle by `actor`
+    ///
+    /// The UUID is nil until the definition is stored.
+    #[must_use]
+    pub fn into_definition(self, actor: Uuid) -> EntityDefinition {
+        let now = OffsetDateTime::now_utc();
+        let schema = Schema::new(HashMap::from([(
+            "entity_type".to_string(),
+            serde_json::Value::String(self.entity_type.clone()),
+        )]));
+        EntityDefinition {
+            entity_type: self.entity_type,
+            display_name: self.display_name,
+            description: self.description,
+            group_name: self.group_name,
+            allow_children: self.allow_children,
+            icon: self.icon,
+            fields: self.fields,
+            unique_constraints: self.unique_constraints,
+            validation_rules: self.validation_rules,
+            table_name: self.table_name,
+            schema,
+            created_at: now,
+            updated_at: now,
+            created_by: actor,
+            updated_by: Some(actor),
+            published: self.published,
+            ..EntityDefinition::default()
+        }
+    }
+}
+
+impl From<&EntityDefinition> for EntityDefinitionBundle {
+    fn from(definition: &EntityDefinition) -> Self {
+        Self {
+            format_version: Self::FORMAT_VERSION,
+            entity_type: definition.entity_type.clone(),
+            display_name: definition.display_name.clone(),
+            description: definition.description.clone(),
+            group_name: definition.group_name.clone(),
+            allow_children: definition.allow_children,
+            icon: definition.icon.clone(),
+            published: definition.published,
+            fields: definition.fields.clone(),
+            unique_constraints: definition.unique_constraints.clone(),
+            validation_rules: definition.validation_rules.clone(),
+            table_name: definition.table_name.clone(),
+        }
+    }
+}
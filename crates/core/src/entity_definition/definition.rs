@@ -1,6 +1,7 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
+use sha2::{Digest, Sha256};
 use sqlx::{postgres::PgRow, FromRow, Row};
 use std::collections::HashMap;
 use std::fmt::{Debug, Write};
@@ -8,6 +9,7 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use super::schema::Schema;
+use super::validation_rule::ValidationRule;
 use crate::error::{Error, Result};
 use crate::field::FieldDefinition;
 use crate::field::FieldType;
@@ -77,6 +79,18 @@ pub struct EntityDefinition {
     pub icon: Option<String>,
     /// Field definitions for this entity type
     pub fields: Vec<FieldDefinition>,
+    /// UUIDs of the entity definitions whose fields this one inherits, in precedence order
+    #[serde(default)]
+    pub extends: Vec<Uuid>,
+    /// Groups of field names whose combined values must be unique, e.g. `[["tenant_id", "email"]]`
+    #[serde(default)]
+    pub unique_constraints: Vec<Vec<String>>,
+    /// Entity-level invariants checked after the per-field validation
+    #[serde(default)]
+    pub validation_rules: Vec<ValidationRule>,
+    /// Table holding the entities, e.g. an existing legacy table; `entity_<type>` if unset
+    #[serde(default)]
+    pub table_name: Option<String>,
     /// Schema for this entity type
     pub schema: Schema,
     /// Created at timestamp
@@ -108,6 +122,10 @@ impl Default for EntityDefinition {
             allow_children: false,
             icon: None,
             fields: Vec::new(),
+            extends: Vec::new(),
+            unique_constraints: Vec::new(),
+            validation_rules: Vec::new(),
+            table_name: None,
             schema: Schema::default(),
             created_at: now,
             updated_at: now,
@@ -124,12 +142,31 @@ const fn default_version() -> i32 {
     1
 }
 
+/// Longest identifier `PostgreSQL` keeps without truncating it
+const MAX_IDENTIFIER_LEN: usize = 63;
+
+/// Hex digits of the field list hash ending unique constraint index names
+const UNIQUE_HASH_LEN: usize = 16;
+
+/// Cut `name` to at most `max_len` bytes without splitting a character
+fn truncate_identifier(name: &mut String, max_len: usize) {
+    while name.len() > max_len {
+        name.pop();
+    }
+}
+
 // Implement FromRow for EntityDefinition
 impl<'r> FromRow<'r, PgRow> for EntityDefinition {
     fn from_row(row: &'r PgRow) -> std::result::Result<Self, sqlx::Error> {
         let fields: Vec<FieldDefinition> =
             serde_json::from_value(row.try_get("field_definitions")?)
                 .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let unique_constraints: Vec<Vec<String>> =
+            serde_json::from_value(row.try_get("unique_constraints")?)
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let validation_rules: Vec<ValidationRule> =
+            serde_json::from_value(row.try_get("validation_rules")?)
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
 
         // Create schema
         let mut properties = HashMap::new();
@@ -139,7 +176,7 @@ impl<'r> FromRow<'r, PgRow> for EntityDefinition {
         );
         let schema = Schema::new(properties);
 
-        Ok(Self {
+        let mut definition = Self {
             uuid: row.try_get("uuid")?,
             entity_type: row.try_get("entity_type")?,
             display_name: row.try_get("display_name")?,
@@ -148,6 +185,10 @@ impl<'r> FromRow<'r, PgRow> for EntityDefinition {
             allow_children: row.try_get("allow_children")?,
             icon: row.try_get("icon")?,
             fields,
+            extends: row.try_get("extends")?,
+            unique_constraints,
+            validation_rules,
+            table_name: row.try_get("table_name")?,
             schema,
             created_at: row.try_get("created_at")?,
             updated_at: row.try_get("updated_at")?,
@@ -155,7 +196,9 @@ impl<'r> FromRow<'r, PgRow> for EntityDefinition {
             updated_by: row.try_get("updated_by")?,
             published: row.try_get("published")?,
             version: row.try_get("version")?,
-        })
+        };
+        definition.sort_fields();
+        Ok(definition)
     }
 }
 
@@ -189,6 +232,10 @@ impl EntityDefinition {
             allow_children,
             icon,
             fields,
+            extends: Vec::new(),
+            unique_constraints: Vec::new(),
+            validation_rules: Vec::new(),
+            table_name: None,
             schema: Schema::new(properties),
             created_at: now,
             updated_at: now,
@@ -226,9 +273,13 @@ impl EntityDefinition {
     }
 
     /// Get the SQL table name for this entity type
+    ///
+    /// The explicit `table_name` if set, otherwise `entity_<type>`.
     #[must_use]
     pub fn get_table_name(&self) -> String {
-        format!("entity_{}", self.entity_type.to_lowercase())
+        self.table_name
+            .clone()
+            .unwrap_or_else(|| format!("entity_{}", self.entity_type.to_lowercase()))
     }
 
     /// Get field definition by name
@@ -237,6 +288,22 @@ impl EntityDefinition {
         self.fields.iter().find(|f| f.name == name)
     }
 
+    /// Fill in the `default_value` of every field missing from `field_data`
+    ///
+    /// Only absent fields are filled; a field sent as explicit `null` keeps its `null`.
+    pub fn apply_field_defaults<S: std::hash::BuildHasher>(
+        &self,
+        field_data: &mut HashMap<String, JsonValue, S>,
+    ) {
+        for field in &self.fields {
+            if let Some(default) = &field.default_value {
+                if !default.is_null() && !field_data.contains_key(&field.name) {
+                    field_data.insert(field.name.clone(), default.clone());
+                }
+            }
+        }
+    }
+
     /// Get all field definitions
     #[must_use]
     pub const fn get_fields(&self) -> &Vec<FieldDefinition> {
@@ -290,6 +357,52 @@ impl EntityDefinition {
         }
     }
 
+    /// Sort the fields by their display order
+    ///
+    /// Fields with a `display_order` of 0 have not been ordered yet and follow the ordered
+    /// ones. The sort is stable, so fields with the same order keep their relative position.
+    pub fn sort_fields(&mut self) {
+        self.fields
+            .sort_by_key(|f| (f.display_order == 0, f.display_order));
+    }
+
+    /// Move the named fields to the front, in the given order, and renumber all fields
+    ///
+    /// Fields not listed follow in their current order. Display orders are assigned
+    /// sequentially starting at 1.
+    ///
+    /// # Errors
+    /// Returns `Error::Validation` if a name is not a field of the definition or is listed
+    /// more than once.
+    pub fn reorder_fields(&mut self, ordered_names: &[String]) -> Result<()> {
+        for (i, name) in ordered_names.iter().enumerate() {
+            if self.get_field(name).is_none() {
+                return Err(Error::Validation(format!(
+                    "Field '{name}' does not exist on '{}'",
+                    self.entity_type
+                )));
+            }
+            if ordered_names[..i].contains(name) {
+                return Err(Error::Validation(format!(
+                    "Field '{name}' is listed more than once"
+                )));
+            }
+        }
+
+        let mut reordered = Vec::with_capacity(self.fields.len());
+        for name in ordered_names {
+            if let Some(position) = self.fields.iter().position(|f| &f.name == name) {
+                reordered.push(self.fields.remove(position));
+            }
+        }
+        reordered.append(&mut self.fields);
+        for (field, order) in reordered.iter_mut().zip(1..) {
+            field.display_order = order;
+        }
+        self.fields = reordered;
+        Ok(())
+    }
+
     /// Validate the entity type definition
     ///
     /// # Panics
@@ -361,7 +474,7 @@ impl EntityDefinition {
         sql.push_str("    published BOOLEAN NOT NULL DEFAULT FALSE,\n");
         sql.push_str("    version INTEGER NOT NULL DEFAULT 1");
 
-        for field in &self.fields {
+        for field in self.user_fields() {
             let field_name = &field.name;
             if matches!(field.field_type, FieldType::ManyToMany) {
                 continue;
@@ -395,7 +508,7 @@ impl EntityDefinition {
 
     /// Generate `ManyToMany` relation tables
     fn generate_relation_tables_sql(&self, sql: &mut String, table_name: &str) {
-        for field in &self.fields {
+        for field in self.user_fields() {
             if !matches!(field.field_type, FieldType::ManyToMany) {
                 continue;
             }
@@ -431,10 +544,35 @@ impl EntityDefinition {
         }
     }
 
+    /// Prefix of the names of the unique indexes backing `unique_constraints`
+    ///
+    /// Long table names are cut so the prefix leaves room for the hash of the constraint.
+    #[must_use]
+    pub fn unique_constraint_prefix(&self) -> String {
+        let mut prefix = format!("uq_{}_", self.get_table_name());
+        truncate_identifier(&mut prefix, MAX_IDENTIFIER_LEN - UNIQUE_HASH_LEN - 1);
+        prefix
+    }
+
+    /// Name of the unique index backing the unique constraint over `fields`
+    ///
+    /// The name ends in a hash of the ordered field list, so constraints whose joined field
+    /// names read alike (`a_b, c` and `a, b_c`) get distinct indexes. The readable part is cut
+    /// to keep the name within the `PostgreSQL` identifier limit.
+    #[must_use]
+    pub fn unique_constraint_name(&self, fields: &[String]) -> String {
+        let columns: Vec<String> = fields.iter().map(|f| f.to_lowercase()).collect();
+        // Identifiers cannot contain NUL, so no two field lists hash the same input
+        let hash = Sha256::digest(columns.join("\0").as_bytes());
+        let mut name = format!("{}{}", self.unique_constraint_prefix(), columns.join("_"));
+        truncate_identifier(&mut name, MAX_IDENTIFIER_LEN - UNIQUE_HASH_LEN - 1);
+        format!("{name}_{}", hex::encode(&hash[..UNIQUE_HASH_LEN / 2]))
+    }
+
     /// Generate index creation and drop statements
     fn generate_indexes_sql(&self, sql: &mut String, table_name: &str) {
         // Create indexes for indexed fields
-        for field in &self.fields {
+        for field in self.user_fields() {
             if !field.indexed {
                 continue;
             }
@@ -451,7 +589,7 @@ impl EntityDefinition {
         }
 
         // Handle unique constraints
-        for field in &self.fields {
+        for field in self.user_fields() {
             let field_name = &field.name;
             if matches!(
                 field.field_type,
@@ -471,8 +609,20 @@ impl EntityDefinition {
             }
         }
 
+        // Unique constraints over several fields; indexes of removed constraints are dropped
+        // by the repository, which can list the existing ones
+        for fields in &self.unique_constraints {
+            let index_name = self.unique_constraint_name(fields);
+            let columns = fields.join(", ");
+            sql.push_str("-- UNIQUE: Composite unique constraint\n");
+            let _ = writeln!(
+                sql,
+                "CREATE UNIQUE INDEX IF NOT EXISTS {index_name} ON {table_name} ({columns});\n"
+            );
+        }
+
         // Drop indexes for non-indexed fields
-        for field in &self.fields {
+        for field in self.user_fields() {
             if field.indexed || matches!(field.field_type, FieldType::ManyToMany) {
                 continue;
             }
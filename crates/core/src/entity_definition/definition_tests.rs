@@ -28,7 +28,12 @@ fn create_test_entity_definition() -> EntityDefinition {
             validation: crate::field::options::FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: std::collections::HashMap::new(),
+            display_order: 0,
         }],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: Schema::default(),
         created_at: time::OffsetDateTime::now_utc(),
         updated_at: time::OffsetDateTime::now_utc(),
@@ -101,3 +106,125 @@ fn test_generate_schema_sql_unique_index_comment() {
         "SQL should contain unique constraint comment"
     );
 }
+
+#[test]
+fn test_generate_schema_sql_includes_composite_unique_index() {
+    let mut def = create_test_entity_definition();
+    let mut tenant = def.fields[0].clone();
+    tenant.name = "tenant_id".to_string();
+    def.fields.push(tenant);
+    def.unique_constraints = vec![vec!["tenant_id".to_string(), "name".to_string()]];
+
+    let sql = def.generate_schema_sql();
+
+    let index_name = def.unique_constraint_name(&def.unique_constraints[0]);
+    assert!(index_name.starts_with("uq_entity_test_tenant_id_name_"));
+    assert!(
+        sql.contains(&format!(
+            "CREATE UNIQUE INDEX IF NOT EXISTS {index_name} ON entity_test (tenant_id, name)"
+        )),
+        "SQL should contain composite unique index creation"
+    );
+    assert!(
+        sql.contains("-- UNIQUE: Composite unique constraint"),
+        "SQL should contain composite unique constraint comment"
+    );
+}
+
+#[test]
+fn test_unique_constraint_name_fits_identifier_limit() {
+    let def = create_test_entity_definition();
+    let fields = vec![
+        "a_rather_long_field_name".to_string(),
+        "another_rather_long_field_name".to_string(),
+    ];
+
+    let name = def.unique_constraint_name(&fields);
+
+    assert!(
+        name.len() <= 63,
+        "Index name must fit a PostgreSQL identifier"
+    );
+    assert!(name.starts_with(&def.unique_constraint_prefix()));
+    assert_eq!(name, def.unique_constraint_name(&fields));
+    assert_ne!(
+        name,
+        def.unique_constraint_name(&["other".to_string(), "fields".to_string()])
+    );
+
+    // Long table names leave room for the hash too
+    let mut long = create_test_entity_definition();
+    long.entity_type = "a_very_long_entity_type_name_reaching_the_limit".to_string();
+    let name = long.unique_constraint_name(&fields);
+    assert!(name.len() <= 63, "{name}");
+    assert!(name.starts_with(&long.unique_constraint_prefix()));
+}
+
+#[test]
+fn test_unique_constraint_names_do_not_collide() {
+    let def = create_test_entity_definition();
+    let names = |fields: &[&str]| {
+        def.unique_constraint_name(&fields.iter().map(ToString::to_string).collect::<Vec<_>>())
+    };
+
+    // Joined with underscores these all read "a_b_c"
+    let joined_alike = [
+        names(&["a_b", "c"]),
+        names(&["a", "b_c"]),
+        names(&["a_b_c"]),
+    ];
+    assert_ne!(joined_alike[0], joined_alike[1]);
+    assert_ne!(joined_alike[0], joined_alike[2]);
+    assert_ne!(joined_alike[1], joined_alike[2]);
+
+    // Column order makes a different index
+    assert_ne!(names(&["a", "b"]), names(&["b", "a"]));
+    // Columns are case-insensitive
+    assert_eq!(names(&["Tenant_Id", "Name"]), names(&["tenant_id", "name"]));
+}
+
+#[test]
+fn test_reorder_fields_renumbers_and_keeps_unlisted_fields() {
+    let mut def = create_test_entity_definition();
+    for name in ["email", "age"] {
+        def.add_field(FieldDefinition::new(
+            name.to_string(),
+            name.to_string(),
+            FieldType::String,
+        ))
+        .unwrap();
+    }
+
+    def.reorder_fields(&["age".to_string(), "name".to_string()])
+        .unwrap();
+
+    let order: Vec<(&str, i32)> = def
+        .fields
+        .iter()
+        .map(|f| (f.name.as_str(), f.display_order))
+        .collect();
+    assert_eq!(order, vec![("age", 1), ("name", 2), ("email", 3)]);
+
+    // Unordered fields follow the ordered ones
+    def.add_field(FieldDefinition::new(
+        "phone".to_string(),
+        "Phone".to_string(),
+        FieldType::String,
+    ))
+    .unwrap();
+    def.fields.reverse();
+    def.sort_fields();
+    let names: Vec<&str> = def.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["age", "name", "email", "phone"]);
+}
+
+#[test]
+fn test_reorder_fields_rejects_unknown_and_duplicate_names() {
+    let mut def = create_test_entity_definition();
+
+    assert!(def.reorder_fields(&["missing".to_string()]).is_err());
+    assert!(def
+        .reorder_fields(&["name".to_string(), "name".to_string()])
+        .is_err());
+    assert_eq!(def.fields[0].display_order, 0);
+}
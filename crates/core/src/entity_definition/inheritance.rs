@@ -0,0 +1,202 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Field inheritance between entity definitions.
+//!
+//! A definition lists the definitions it `extends`. Its effective fields are the effective
+//! fields of each parent in order followed by its own fields, where a field replaces any earlier
+//! field of the same name. Names are compared case-insensitively like the columns they map to.
+//! The `entity_definition_fields` database function applies the same rules when building tables.
+
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::definition::EntityDefinition;
+use crate::error::{Error, Result};
+use crate::field::FieldDefinition;
+
+impl EntityDefinition {
+    /// Copy of the definition with the fields inherited from its ancestors
+    ///
+    /// `ancestors` maps the UUIDs of all (transitive) parents to their definitions. An entry
+    /// for this definition itself is ignored, so stored versions can be passed while an update
+    /// is validated.
+    ///
+    /// # Errors
+    /// Returns `Error::Validation` if a parent is missing from `ancestors` or the definitions
+    /// extend each other in a cycle
+    pub fn resolve_inheritance(&self, ancestors: &HashMap<Uuid, Self>) -> Result<Self> {
+        let mut resolved = self.clone();
+        if self.extends.is_empty() {
+            return Ok(resolved);
+        }
+
+        let mut fields = Vec::new();
+        let mut path = vec![self];
+        collect_fields(self, ancestors, &mut path, &mut fields)?;
+        resolved.fields = fields;
+        Ok(resolved)
+    }
+}
+
+/// Append the effective fields of `definition` to `fields`
+fn collect_fields<'a>(
+    definition: &'a EntityDefinition,
+    ancestors: &'a HashMap<Uuid, EntityDefinition>,
+    path: &mut Vec<&'a EntityDefinition>,
+    fields: &mut Vec<FieldDefinition>,
+) -> Result<()> {
+    for parent_uuid in &definition.extends {
+        if let Some(start) = path.iter().position(|d| d.uuid == *parent_uuid) {
+            let cycle: Vec<&str> = path[start..]
+                .iter()
+                .chain(std::iter::once(&path[start]))
+                .map(|d| d.entity_type.as_str())
+                .collect();
+            return Err(Error::Validation(format!(
+                "Entity definition inheritance cycle: {}",
+                cycle.join(" -> ")
+            )));
+        }
+        let Some(parent) = ancestors.get(parent_uuid) else {
+            return Err(Error::Validation(format!(
+                "Entity definition '{}' extends unknown entity definition {parent_uuid}",
+                definition.entity_type
+            )));
+        };
+
+        path.push(parent);
+        collect_fields(parent, ancestors, path, fields)?;
+        path.pop();
+    }
+
+    for field in &definition.fields {
+        fields.retain(|existing| !existing.name.eq_ignore_ascii_case(&field.name));
+        fields.push(field.clone());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::ui::UiSettings;
+    use crate::field::{FieldType, FieldValidation};
+
+    fn field(name: &str, field_type: FieldType) -> FieldDefinition {
+        FieldDefinition {
+            name: name.to_string(),
+            display_name: name.to_string(),
+            field_type,
+            description: None,
+            required: false,
+            indexed: false,
+            filterable: false,
+            unique: false,
+            default_value: None,
+            validation: FieldValidation::default(),
+            ui_settings: UiSettings::default(),
+            constraints: HashMap::new(),
+            display_order: 0,
+        }
+    }
+
+    fn definition(
+        entity_type: &str,
+        fields: Vec<FieldDefinition>,
+        extends: Vec<Uuid>,
+    ) -> EntityDefinition {
+        EntityDefinition {
+            uuid: Uuid::now_v7(),
+            entity_type: entity_type.to_string(),
+            display_name: entity_type.to_string(),
+            fields,
+            extends,
+            ..EntityDefinition::default()
+        }
+    }
+
+    fn names(definition: &EntityDefinition) -> Vec<&str> {
+        definition.fields.iter().map(|f| f.name.as_str()).collect()
+    }
+
+    #[test]
+    fn inherits_parent_fields_transitively() -> Result<()> {
+        let audit = definition(
+            "Audited",
+            vec![field("reviewed_by", FieldType::String)],
+            vec![],
+        );
+        let tracked = definition(
+            "Tracked",
+            vec![field("source", FieldType::String)],
+            vec![audit.uuid],
+        );
+        let order = definition(
+            "Order",
+            vec![field("total", FieldType::Float)],
+            vec![tracked.uuid],
+        );
+        let ancestors = HashMap::from([(audit.uuid, audit), (tracked.uuid, tracked)]);
+
+        let resolved = order.resolve_inheritance(&ancestors)?;
+        assert_eq!(names(&resolved), vec!["reviewed_by", "source", "total"]);
+        assert_eq!(resolved.extends, order.extends);
+        // The stored definition keeps only its own fields
+        assert_eq!(names(&order), vec!["total"]);
+        Ok(())
+    }
+
+    #[test]
+    fn child_and_later_parent_fields_override_by_name() -> Result<()> {
+        let first = definition(
+            "First",
+            vec![
+                field("note", FieldType::String),
+                field("status", FieldType::String),
+            ],
+            vec![],
+        );
+        let second = definition("Second", vec![field("Note", FieldType::Text)], vec![]);
+        let child = definition(
+            "Child",
+            vec![field("status", FieldType::Integer)],
+            vec![first.uuid, second.uuid],
+        );
+        let ancestors = HashMap::from([(first.uuid, first), (second.uuid, second)]);
+
+        let resolved = child.resolve_inheritance(&ancestors)?;
+        assert_eq!(names(&resolved), vec!["Note", "status"]);
+        assert_eq!(resolved.fields[0].field_type, FieldType::Text);
+        assert_eq!(resolved.fields[1].field_type, FieldType::Integer);
+        Ok(())
+    }
+
+    #[test]
+    fn cycles_are_rejected() {
+        let mut a = definition("A", vec![field("a", FieldType::String)], vec![]);
+        let b = definition("B", vec![], vec![a.uuid]);
+        // Updating `A` to extend `B`, while the stored `A` has no parents
+        let ancestors = HashMap::from([(a.uuid, a.clone()), (b.uuid, b.clone())]);
+        a.extends = vec![b.uuid];
+
+        let err = a.resolve_inheritance(&ancestors).unwrap_err();
+        assert!(
+            matches!(&err, Error::Validation(msg) if msg.contains("cycle: A -> B -> A")),
+            "{err}"
+        );
+
+        let mut own = definition("Own", vec![], vec![]);
+        own.extends = vec![own.uuid];
+        assert!(matches!(
+            own.resolve_inheritance(&HashMap::new()),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn unknown_parent_is_rejected() {
+        let child = definition("Child", vec![], vec![Uuid::now_v7()]);
+        let err = child.resolve_inheritance(&HashMap::new()).unwrap_err();
+        assert!(matches!(err, Error::Validation(msg) if msg.contains("unknown")));
+    }
+}
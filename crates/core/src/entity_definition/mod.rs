@@ -1,7 +1,16 @@
+pub mod bundle;
 pub mod definition;
 #[cfg(test)]
 mod definition_tests;
+pub mod inheritance;
 pub mod repository_trait;
 pub mod schema;
+pub mod schema_diff;
+pub mod system_fields;
+pub mod validation_rule;
 
+pub use bundle::EntityDefinitionBundle;
 pub use definition::*;
+pub use schema_diff::{SchemaChange, SchemaChangeKind, SchemaDiff};
+pub use system_fields::{is_system_field_name, SYSTEM_FIELD_NAMES};
+pub use validation_rule::{RuleExpression, ValidationRule};
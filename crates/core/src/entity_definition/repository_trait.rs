@@ -25,6 +25,30 @@ pub trait EntityDefinitionRepositoryTrait: Send + Sync {
     /// Update an existing entity definition
     async fn update(&self, uuid: &Uuid, definition: &EntityDefinition) -> Result<()>;
 
+    /// Rename the column of field `old_name` to `new_name` and store `definition`, which
+    /// already carries the renamed field, in one transaction
+    ///
+    /// With `rename_in_versions`, the key is also renamed in the stored entity versions.
+    async fn rename_field(
+        &self,
+        uuid: &Uuid,
+        definition: &EntityDefinition,
+        old_name: &str,
+        new_name: &str,
+        rename_in_versions: bool,
+    ) -> Result<()>;
+
+    /// Entity type of the definition, other than `exclude`, storing its entities in `table_name`
+    async fn find_table_owner(
+        &self,
+        table_name: &str,
+        exclude: Option<Uuid>,
+    ) -> Result<Option<String>>;
+
+    /// Whether `table_name` exists but is not an entity table, e.g. a table of the
+    /// application itself or one without a `uuid` referencing the entities registry
+    async fn is_foreign_table(&self, table_name: &str) -> Result<bool>;
+
     /// Delete an entity definition
     async fn delete(&self, uuid: &Uuid) -> Result<()>;
 
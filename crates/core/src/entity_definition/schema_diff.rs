@@ -0,0 +1,385 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Preview of the column changes applying an entity definition makes to its entity table.
+//!
+//! Entity tables are synchronised by the `create_entity_table_and_view` database function,
+//! which adds columns for new fields, drops columns of removed fields and alters the type of
+//! changed fields. The diff mirrors those rules without touching the database.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use super::definition::EntityDefinition;
+use super::system_fields::is_system_field_name;
+use crate::field::FieldType;
+
+/// Kind of change to an entity table column
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum SchemaChangeKind {
+    /// A column is added for a new field
+    AddColumn,
+    /// The column of a removed field is dropped
+    DropColumn,
+    /// The column type changes with the field type
+    AlterColumnType,
+}
+
+/// A single column change
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema, TS)]
+#[ts(export)]
+pub struct SchemaChange {
+    /// Column name
+    pub column: String,
+    /// Kind of change
+    pub kind: SchemaChangeKind,
+    /// Current column type, as reported by `information_schema`
+    pub from_type: Option<String>,
+    /// SQL type after the change
+    pub to_type: Option<String>,
+    /// Whether the change can lose data (drops and narrowing type changes)
+    pub destructive: bool,
+}
+
+/// Column changes and the statements applying them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema, TS)]
+#[ts(export)]
+pub struct SchemaDiff {
+    /// Entity table the changes apply to
+    pub table_name: String,
+    /// Changes ordered by column name, drops and type changes before additions
+    pub changes: Vec<SchemaChange>,
+    /// `ALTER TABLE` statements applying the changes
+    pub statements: Vec<String>,
+}
+
+/// Columns of every entity table that are not backed by a field
+const MANAGED_COLUMNS: &[&str] = &[
+    "uuid",
+    "path",
+    "created_at",
+    "updated_at",
+    "created_by",
+    "updated_by",
+    "published",
+    "version",
+];
+
+impl SchemaDiff {
+    /// Diff `current_columns` (column name to `information_schema` data type) of the entity
+    /// table against `definition`
+    ///
+    /// A missing table (no columns) yields an `AddColumn` change per field.
+    #[must_use]
+    pub fn compute(
+        definition: &EntityDefinition,
+        current_columns: &HashMap<String, String>,
+    ) -> Self {
+        let table_name = definition.get_table_name();
+        let wanted: BTreeMap<String, &'static str> = definition
+            .user_fields()
+            .map(|field| {
+                (
+                    field.name.to_lowercase(),
+                    column_sql_type(&field.field_type),
+                )
+            })
+            .collect();
+        let current: BTreeMap<String, &str> = current_columns
+            .iter()
+            .map(|(name, data_type)| (name.to_lowercase(), data_type.as_str()))
+            .filter(|(name, _)| !MANAGED_COLUMNS.contains(&name.as_str()))
+            .collect();
+
+        let mut changes = Vec::new();
+        for (column, from_type) in &current {
+            match wanted.get(column) {
+                None if !is_system_field_name(column) => changes.push(SchemaChange {
+                    column: column.clone(),
+                    kind: SchemaChangeKind::DropColumn,
+                    from_type: Some((*from_type).to_string()),
+                    to_type: None,
+                    destructive: true,
+                }),
+                Some(to_type) if data_type_name(to_type) != *from_type => {
+                    changes.push(SchemaChange {
+                        column: column.clone(),
+                        kind: SchemaChangeKind::AlterColumnType,
+                        from_type: Some((*from_type).to_string()),
+                        to_type: Some((*to_type).to_string()),
+                        destructive: !is_widening(from_type, data_type_name(to_type)),
+                    });
+                }
+                _ => {}
+            }
+        }
+        for (column, to_type) in &wanted {
+            if !current.contains_key(column) {
+                changes.push(SchemaChange {
+                    column: column.clone(),
+                    kind: SchemaChangeKind::AddColumn,
+                    from_type: None,
+                    to_type: Some((*to_type).to_string()),
+                    destructive: false,
+                });
+            }
+        }
+
+        let statements = changes
+            .iter()
+            .map(|change| match change.kind {
+                SchemaChangeKind::AddColumn => format!(
+                    "ALTER TABLE {table_name} ADD COLUMN IF NOT EXISTS {} {}",
+                    change.column,
+                    change.to_type.as_deref().unwrap_or_default()
+                ),
+                SchemaChangeKind::DropColumn => format!(
+                    "ALTER TABLE {table_name} DROP COLUMN IF EXISTS {}",
+                    change.column
+                ),
+                SchemaChangeKind::AlterColumnType => format!(
+                    "ALTER TABLE {table_name} ALTER COLUMN {} TYPE {}",
+                    change.column,
+                    change.to_type.as_deref().unwrap_or_default()
+                ),
+            })
+            .collect();
+
+        Self {
+            table_name,
+            changes,
+            statements,
+        }
+    }
+
+    /// Whether any change can lose data
+    #[must_use]
+    pub fn has_destructive_changes(&self) -> bool {
+        self.changes.iter().any(|change| change.destructive)
+    }
+}
+
+/// SQL type `create_entity_table_and_view` uses for a field type
+#[must_use]
+pub const fn column_sql_type(field_type: &FieldType) -> &'static str {
+    match field_type {
+        FieldType::String | FieldType::Image | FieldType::File => "VARCHAR(255)",
+        FieldType::Text | FieldType::Wysiwyg | FieldType::Password => "TEXT",
+        FieldType::Integer => "INTEGER",
+        FieldType::Float => "DOUBLE PRECISION",
+        FieldType::Boolean => "BOOLEAN",
+        FieldType::DateTime => "TIMESTAMPTZ",
+        FieldType::Date => "DATE",
+        FieldType::Duration => "INTERVAL",
+        FieldType::Object
+        | FieldType::Array
+        | FieldType::Json
+        | FieldType::Money
+        | FieldType::ManyToMany
+        | FieldType::MultiSelect => "JSONB",
+        FieldType::Uuid | FieldType::ManyToOne => "UUID",
+        FieldType::Select => "VARCHAR(100)",
+    }
+}
+
+/// `information_schema.columns.data_type` reported for a SQL type
+fn data_type_name(sql_type: &str) -> &str {
+    match sql_type {
+        "VARCHAR(255)" | "VARCHAR(100)" => "character varying",
+        "TIMESTAMPTZ" => "timestamp with time zone",
+        "TEXT" => "text",
+        "INTEGER" => "integer",
+        "DOUBLE PRECISION" => "double precision",
+        "BOOLEAN" => "boolean",
+        "DATE" => "date",
+        "INTERVAL" => "interval",
+        "JSONB" => "jsonb",
+        "UUID" => "uuid",
+        other => other,
+    }
+}
+
+/// Whether converting `from` to `to` keeps every value
+fn is_widening(from: &str, to: &str) -> bool {
+    matches!(
+        (from, to),
+        (_, "text")
+            | (
+                "integer" | "smallint",
+                "bigint" | "double precision" | "numeric"
+            )
+            | ("bigint", "numeric")
+            | ("date", "timestamp with time zone")
+            | (
+                "integer" | "bigint" | "double precision" | "boolean" | "uuid" | "date",
+                "character varying"
+            )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity_definition::schema::Schema;
+    use crate::field::ui::UiSettings;
+    use crate::field::FieldDefinition;
+    use uuid::Uuid;
+
+    fn field(name: &str, field_type: FieldType) -> FieldDefinition {
+        FieldDefinition {
+            name: name.to_string(),
+            display_name: name.to_string(),
+            field_type,
+            description: None,
+            required: false,
+            indexed: false,
+            filterable: false,
+            unique: false,
+            default_value: None,
+            validation: crate::field::options::FieldValidation::default(),
+            ui_settings: UiSettings::default(),
+            constraints: HashMap::new(),
+            display_order: 0,
+        }
+    }
+
+    fn definition(fields: Vec<FieldDefinition>) -> EntityDefinition {
+        EntityDefinition {
+            uuid: Uuid::now_v7(),
+            entity_type: "Product".to_string(),
+            display_name: "Product".to_string(),
+            description: None,
+            group_name: None,
+            allow_children: false,
+            icon: None,
+            fields,
+            extends: Vec::new(),
+            unique_constraints: Vec::new(),
+            validation_rules: Vec::new(),
+            table_name: None,
+            schema: Schema::default(),
+            created_at: time::OffsetDateTime::now_utc(),
+            updated_at: time::OffsetDateTime::now_utc(),
+            created_by: Uuid::nil(),
+            updated_by: None,
+            published: false,
+            version: 1,
+        }
+    }
+
+    fn columns(columns: &[(&str, &str)]) -> HashMap<String, String> {
+        columns
+            .iter()
+            .map(|(name, data_type)| ((*name).to_string(), (*data_type).to_string()))
+            .collect()
+    }
+
+    fn current_product_table() -> HashMap<String, String> {
+        columns(&[
+            ("uuid", "uuid"),
+            ("path", "text"),
+            ("created_at", "timestamp with time zone"),
+            ("version", "integer"),
+            ("name", "character varying"),
+            ("price", "integer"),
+        ])
+    }
+
+    #[test]
+    fn unchanged_definition_has_no_changes() {
+        let def = definition(vec![
+            field("name", FieldType::String),
+            field("price", FieldType::Integer),
+        ]);
+        let diff = SchemaDiff::compute(&def, &current_product_table());
+        assert!(diff.changes.is_empty());
+        assert!(diff.statements.is_empty());
+        assert!(!diff.has_destructive_changes());
+    }
+
+    #[test]
+    fn added_field_adds_column() {
+        let def = definition(vec![
+            field("name", FieldType::String),
+            field("price", FieldType::Integer),
+            field("in_stock", FieldType::Boolean),
+        ]);
+        let diff = SchemaDiff::compute(&def, &current_product_table());
+        assert_eq!(
+            diff.changes,
+            vec![SchemaChange {
+                column: "in_stock".to_string(),
+                kind: SchemaChangeKind::AddColumn,
+                from_type: None,
+                to_type: Some("BOOLEAN".to_string()),
+                destructive: false,
+            }]
+        );
+        assert_eq!(
+            diff.statements,
+            vec!["ALTER TABLE entity_product ADD COLUMN IF NOT EXISTS in_stock BOOLEAN"]
+        );
+        assert!(!diff.has_destructive_changes());
+    }
+
+    #[test]
+    fn removed_field_drops_column_destructively() {
+        let def = definition(vec![field("name", FieldType::String)]);
+        let diff = SchemaDiff::compute(&def, &current_product_table());
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].kind, SchemaChangeKind::DropColumn);
+        assert_eq!(diff.changes[0].column, "price");
+        assert!(diff.changes[0].destructive);
+        assert_eq!(
+            diff.statements,
+            vec!["ALTER TABLE entity_product DROP COLUMN IF EXISTS price"]
+        );
+    }
+
+    #[test]
+    fn type_changes_flag_narrowing_as_destructive() {
+        let widened = definition(vec![
+            field("name", FieldType::Text),
+            field("price", FieldType::Float),
+        ]);
+        let diff = SchemaDiff::compute(&widened, &current_product_table());
+        assert_eq!(diff.changes.len(), 2);
+        assert!(diff
+            .changes
+            .iter()
+            .all(|c| c.kind == SchemaChangeKind::AlterColumnType && !c.destructive));
+        assert!(diff.statements.contains(
+            &"ALTER TABLE entity_product ALTER COLUMN price TYPE DOUBLE PRECISION".to_string()
+        ));
+
+        let narrowed = definition(vec![
+            field("name", FieldType::Integer),
+            field("price", FieldType::Integer),
+        ]);
+        let diff = SchemaDiff::compute(&narrowed, &current_product_table());
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(
+            diff.changes[0].from_type.as_deref(),
+            Some("character varying")
+        );
+        assert_eq!(diff.changes[0].to_type.as_deref(), Some("INTEGER"));
+        assert!(diff.has_destructive_changes());
+    }
+
+    #[test]
+    fn missing_table_adds_every_field() {
+        let def = definition(vec![
+            field("name", FieldType::String),
+            field("tags", FieldType::MultiSelect),
+        ]);
+        let diff = SchemaDiff::compute(&def, &HashMap::new());
+        assert_eq!(diff.changes.len(), 2);
+        assert!(diff
+            .changes
+            .iter()
+            .all(|c| c.kind == SchemaChangeKind::AddColumn));
+    }
+}
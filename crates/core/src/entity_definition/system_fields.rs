@@ -0,0 +1,88 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use super::definition::EntityDefinition;
+use crate::field::FieldDefinition;
+
+/// Field names managed by the system for every entity; user fields with these names are ignored
+/// when generating the entity table
+pub const SYSTEM_FIELD_NAMES: &[&str] = &[
+    "uuid",
+    "path",
+    "parent_uuid",
+    "entity_key",
+    "created_at",
+    "updated_at",
+    "created_by",
+    "updated_by",
+    "published",
+    "version",
+];
+
+/// Whether `name` is a system-managed field (case-insensitive)
+#[must_use]
+pub fn is_system_field_name(name: &str) -> bool {
+    SYSTEM_FIELD_NAMES
+        .iter()
+        .any(|system| system.eq_ignore_ascii_case(name))
+}
+
+impl EntityDefinition {
+    /// Fields defined by the user, excluding any that shadow a system field
+    pub fn user_fields(&self) -> impl Iterator<Item = &FieldDefinition> {
+        self.fields
+            .iter()
+            .filter(|field| !is_system_field_name(&field.name))
+    }
+
+    /// Whether the definition has at least one non-system field
+    #[must_use]
+    pub fn has_user_fields(&self) -> bool {
+        self.user_fields().next().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::FieldType;
+
+    fn definition_with(names: &[&str]) -> EntityDefinition {
+        EntityDefinition {
+            entity_type: "thing".to_string(),
+            display_name: "Thing".to_string(),
+            fields: names
+                .iter()
+                .map(|name| {
+                    FieldDefinition::new(
+                        (*name).to_string(),
+                        (*name).to_string(),
+                        FieldType::String,
+                    )
+                })
+                .collect(),
+            ..EntityDefinition::default()
+        }
+    }
+
+    #[test]
+    fn system_named_fields_are_not_user_fields() {
+        let definition = definition_with(&["Path", "version", "title"]);
+        let names: Vec<_> = definition.user_fields().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["title"]);
+        assert!(definition.has_user_fields());
+        assert!(!definition_with(&["uuid", "published"]).has_user_fields());
+        assert!(!definition_with(&[]).has_user_fields());
+    }
+
+    #[test]
+    fn schema_for_all_system_fields_has_only_system_columns() {
+        let sql = definition_with(&["path", "version"]).generate_schema_sql();
+        assert_eq!(sql.matches("    path ").count(), 1, "{sql}");
+        assert_eq!(sql.matches("    version ").count(), 1, "{sql}");
+        assert!(
+            sql.contains("version INTEGER NOT NULL DEFAULT 1\n);"),
+            "{sql}"
+        );
+        assert!(!sql.contains("INDEX"), "{sql}");
+    }
+}
@@ -0,0 +1,340 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime};
+
+use crate::error::{Error, Result};
+
+/// Entity-level invariant checked after the per-field validation, e.g. `end_date >= start_date`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ValidationRule {
+    /// Name identifying the rule in validation errors
+    pub name: String,
+    /// Comparison `<operand> <operator> <operand>`; see [`RuleExpression`]
+    pub expression: String,
+    /// Message returned when the rule is violated
+    pub message: String,
+}
+
+impl ValidationRule {
+    /// Parse the rule's expression
+    ///
+    /// # Errors
+    /// Returns a validation error naming the rule if the expression is malformed
+    pub fn parse(&self) -> Result<RuleExpression> {
+        self.expression.parse().map_err(|e| match e {
+            Error::Validation(msg) => {
+                Error::Validation(format!("Validation rule '{}': {msg}", self.name))
+            }
+            other => other,
+        })
+    }
+}
+
+/// Comparison operator of a [`RuleExpression`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleOperator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl RuleOperator {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+
+    /// Whether the operator holds for two values ordered as `ordering`; `None` means the
+    /// values are not comparable, which only satisfies `!=`
+    fn holds(self, ordering: Option<Ordering>) -> bool {
+        let Some(ordering) = ordering else {
+            return self == Self::Ne;
+        };
+        match self {
+            Self::Eq => ordering == Ordering::Equal,
+            Self::Ne => ordering != Ordering::Equal,
+            Self::Lt => ordering == Ordering::Less,
+            Self::Le => ordering != Ordering::Greater,
+            Self::Gt => ordering == Ordering::Greater,
+            Self::Ge => ordering != Ordering::Less,
+        }
+    }
+}
+
+/// Side of a [`RuleExpression`]: a field name or a literal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleOperand {
+    Field(String),
+    Literal(JsonValue),
+}
+
+impl fmt::Display for RuleOperand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field(name) => write!(f, "{name}"),
+            Self::Literal(JsonValue::String(s)) => write!(f, "'{s}'"),
+            Self::Literal(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Parsed comparison such as `end_date >= start_date`, `quantity > 0` or `status != 'draft'`
+///
+/// Operators are `==` (or `=`), `!=`, `<`, `<=`, `>` and `>=`. Literals are numbers, quoted
+/// strings, `true`, `false` and `null`; any other word names a field. Numbers compare
+/// numerically, strings holding RFC-3339 timestamps or `YYYY-MM-DD` dates compare
+/// chronologically and other strings lexically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleExpression {
+    pub left: RuleOperand,
+    pub operator: RuleOperator,
+    pub right: RuleOperand,
+}
+
+impl std::str::FromStr for RuleExpression {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let malformed =
+            |reason: &str| Error::Validation(format!("Malformed expression '{s}': {reason}"));
+
+        let mut rest = s.trim_start();
+        let left = take_operand(&mut rest).ok_or_else(|| malformed("expected a field or value"))?;
+        let operator =
+            take_operator(&mut rest).ok_or_else(|| malformed("expected a comparison operator"))?;
+        let right =
+            take_operand(&mut rest).ok_or_else(|| malformed("expected a field or value"))?;
+        if !rest.trim().is_empty() {
+            return Err(malformed("unexpected trailing input"));
+        }
+        if !matches!(left, RuleOperand::Field(_)) && !matches!(right, RuleOperand::Field(_)) {
+            return Err(malformed("must reference at least one field"));
+        }
+
+        Ok(Self {
+            left,
+            operator,
+            right,
+        })
+    }
+}
+
+impl fmt::Display for RuleExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.left, self.operator.as_str(), self.right)
+    }
+}
+
+impl RuleExpression {
+    /// Names of the fields the expression references
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        [&self.left, &self.right]
+            .into_iter()
+            .filter_map(|operand| match operand {
+                RuleOperand::Field(name) => Some(name.as_str()),
+                RuleOperand::Literal(_) => None,
+            })
+    }
+
+    /// Point references to the field `old_name` at `new_name`
+    pub fn rename_field(&mut self, old_name: &str, new_name: &str) {
+        for operand in [&mut self.left, &mut self.right] {
+            if matches!(operand, RuleOperand::Field(name) if name == old_name) {
+                *operand = RuleOperand::Field(new_name.to_string());
+            }
+        }
+    }
+
+    /// Evaluate against entity field data
+    ///
+    /// A rule referencing a missing or `null` field is not evaluated and counts as satisfied;
+    /// whether the field must be set is up to its `required` flag.
+    #[must_use]
+    pub fn is_satisfied<S: std::hash::BuildHasher>(
+        &self,
+        field_data: &HashMap<String, JsonValue, S>,
+    ) -> bool {
+        let resolve = |operand: &RuleOperand| match operand {
+            RuleOperand::Field(name) => field_data.get(name).filter(|v| !v.is_null()).cloned(),
+            RuleOperand::Literal(value) => Some(value.clone()),
+        };
+        let (Some(left), Some(right)) = (resolve(&self.left), resolve(&self.right)) else {
+            return true;
+        };
+        self.operator.holds(compare(&left, &right))
+    }
+}
+
+fn compare(left: &JsonValue, right: &JsonValue) -> Option<Ordering> {
+    match (left, right) {
+        (JsonValue::Number(a), JsonValue::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (JsonValue::String(a), JsonValue::String(b)) => {
+            if let (Ok(a), Ok(b)) = (
+                OffsetDateTime::parse(a, &Rfc3339),
+                OffsetDateTime::parse(b, &Rfc3339),
+            ) {
+                return Some(a.cmp(&b));
+            }
+            let format = format_description!("[year]-[month]-[day]");
+            if let (Ok(a), Ok(b)) = (Date::parse(a, &format), Date::parse(b, &format)) {
+                return Some(a.cmp(&b));
+            }
+            Some(a.cmp(b))
+        }
+        (JsonValue::Bool(a), JsonValue::Bool(b)) => Some(a.cmp(b)),
+        (JsonValue::Null, JsonValue::Null) => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+fn take_operator(rest: &mut &str) -> Option<RuleOperator> {
+    let (operator, len) = [
+        ("==", RuleOperator::Eq),
+        ("!=", RuleOperator::Ne),
+        ("<=", RuleOperator::Le),
+        (">=", RuleOperator::Ge),
+        ("=", RuleOperator::Eq),
+        ("<", RuleOperator::Lt),
+        (">", RuleOperator::Gt),
+    ]
+    .into_iter()
+    .find(|(token, _)| rest.starts_with(token))
+    .map(|(token, operator)| (operator, token.len()))?;
+    *rest = rest[len..].trim_start();
+    Some(operator)
+}
+
+fn take_operand(rest: &mut &str) -> Option<RuleOperand> {
+    let first = rest.chars().next()?;
+
+    if first == '\'' || first == '"' {
+        let end = rest[1..].find(first)? + 1;
+        let literal = rest[1..end].to_string();
+        *rest = rest[end + 1..].trim_start();
+        return Some(RuleOperand::Literal(JsonValue::String(literal)));
+    }
+
+    let end = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')))
+        .unwrap_or(rest.len());
+    let word = &rest[..end];
+    if word.is_empty() {
+        return None;
+    }
+
+    let operand = match word {
+        "true" => RuleOperand::Literal(JsonValue::Bool(true)),
+        "false" => RuleOperand::Literal(JsonValue::Bool(false)),
+        "null" => RuleOperand::Literal(JsonValue::Null),
+        _ if first.is_ascii_digit() || first == '-' => RuleOperand::Literal(
+            serde_json::from_str::<serde_json::Number>(word)
+                .ok()?
+                .into(),
+        ),
+        _ if (first.is_ascii_alphabetic() || first == '_')
+            && word.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') =>
+        {
+            RuleOperand::Field(word.to_string())
+        }
+        _ => return None,
+    };
+    *rest = rest[end..].trim_start();
+    Some(operand)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn data(pairs: &[(&str, JsonValue)]) -> HashMap<String, JsonValue> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_and_formats_expressions() {
+        for (input, expected) in [
+            ("end_date >= start_date", "end_date >= start_date"),
+            ("quantity>0", "quantity > 0"),
+            ("status = \"draft\"", "status == 'draft'"),
+            ("-1.5 < delta", "-1.5 < delta"),
+            ("active != false", "active != false"),
+        ] {
+            let expression: RuleExpression = input.parse().unwrap();
+            assert_eq!(expression.to_string(), expected, "parsing {input}");
+        }
+
+        let expression: RuleExpression = "end_date >= start_date".parse().unwrap();
+        assert_eq!(
+            expression.fields().collect::<Vec<_>>(),
+            vec!["end_date", "start_date"]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        for input in [
+            "",
+            "end_date",
+            "end_date >=",
+            "end_date => start_date",
+            "end_date >= start_date AND 1",
+            "1 < 2",
+            "name == 'unterminated",
+            "1abc > 0",
+        ] {
+            assert!(
+                input.parse::<RuleExpression>().is_err(),
+                "parsing {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn compares_dates_numbers_and_strings() {
+        let expression: RuleExpression = "end_date >= start_date".parse().unwrap();
+        assert!(expression.is_satisfied(&data(&[
+            ("start_date", json!("2026-01-31")),
+            ("end_date", json!("2026-02-01")),
+        ])));
+        assert!(!expression.is_satisfied(&data(&[
+            ("start_date", json!("2026-02-01T10:00:00+02:00")),
+            ("end_date", json!("2026-02-01T07:00:00Z")),
+        ])));
+
+        let expression: RuleExpression = "quantity > 2".parse().unwrap();
+        assert!(expression.is_satisfied(&data(&[("quantity", json!(10))])));
+        assert!(!expression.is_satisfied(&data(&[("quantity", json!(2.0))])));
+        assert!(!expression.is_satisfied(&data(&[("quantity", json!("10"))])));
+    }
+
+    #[test]
+    fn missing_fields_are_not_evaluated() {
+        let expression: RuleExpression = "end_date >= start_date".parse().unwrap();
+        assert!(expression.is_satisfied(&data(&[("end_date", json!("2026-01-01"))])));
+        assert!(expression.is_satisfied(&data(&[
+            ("start_date", json!("2026-02-01")),
+            ("end_date", JsonValue::Null),
+        ])));
+    }
+}
@@ -0,0 +1,135 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Webhook fired when any of `fields` changes on an entity of `entity_type`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityWebhookSubscription {
+    pub uuid: Uuid,
+    pub entity_type: String,
+    pub url: String,
+    pub fields: Vec<String>,
+    pub active: bool,
+    pub created_at: OffsetDateTime,
+    pub created_by: Option<Uuid>,
+}
+
+/// Old and new value of a changed field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub old: Value,
+    pub new: Value,
+}
+
+/// Fields whose value differs between `old` and `new`.
+///
+/// Only fields present in `new` are compared, so partial updates do not report the fields they
+/// leave out; a field missing from `old` is reported with an old value of `null`.
+#[must_use]
+pub fn changed_fields<S: std::hash::BuildHasher>(
+    old: &HashMap<String, Value, S>,
+    new: &HashMap<String, Value, S>,
+) -> BTreeMap<String, FieldChange> {
+    new.iter()
+        .filter_map(|(name, new_value)| {
+            let old_value = old.get(name).unwrap_or(&Value::Null);
+            (old_value != new_value).then(|| {
+                (
+                    name.clone(),
+                    FieldChange {
+                        old: old_value.clone(),
+                        new: new_value.clone(),
+                    },
+                )
+            })
+        })
+        .collect()
+}
+
+impl EntityWebhookSubscription {
+    /// The part of `changes` this subscription listens to, or `None` if none of its fields changed
+    #[must_use]
+    pub fn matching_changes(
+        &self,
+        changes: &BTreeMap<String, FieldChange>,
+    ) -> Option<BTreeMap<String, FieldChange>> {
+        let matching: BTreeMap<_, _> = changes
+            .iter()
+            .filter(|(name, _)| self.fields.iter().any(|field| field == *name))
+            .map(|(name, change)| (name.clone(), change.clone()))
+            .collect();
+        (!matching.is_empty()).then_some(matching)
+    }
+}
+
+/// Body posted to a subscription URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityWebhookPayload {
+    pub subscription_uuid: Uuid,
+    pub url: String,
+    pub entity_type: String,
+    pub entity_uuid: Uuid,
+    pub changes: BTreeMap<String, FieldChange>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub changed_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fields(value: &Value) -> HashMap<String, Value> {
+        serde_json::from_value(value.clone()).unwrap()
+    }
+
+    fn subscription(fields: &[&str]) -> EntityWebhookSubscription {
+        EntityWebhookSubscription {
+            uuid: Uuid::now_v7(),
+            entity_type: "order".to_string(),
+            url: "https://example.com/hook".to_string(),
+            fields: fields.iter().map(ToString::to_string).collect(),
+            active: true,
+            created_at: OffsetDateTime::now_utc(),
+            created_by: None,
+        }
+    }
+
+    #[test]
+    fn diff_reports_changed_and_new_fields_only() {
+        let old = fields(&json!({ "status": "open", "total": 10, "note": "x" }));
+        let new = fields(&json!({ "status": "paid", "total": 10, "tracking": "abc" }));
+
+        let changes = changed_fields(&old, &new);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes["status"],
+            FieldChange {
+                old: json!("open"),
+                new: json!("paid")
+            }
+        );
+        assert_eq!(changes["tracking"].old, Value::Null);
+        assert!(!changes.contains_key("note"));
+    }
+
+    #[test]
+    fn subscription_matches_only_its_fields() {
+        let old = fields(&json!({ "status": "open", "total": 10 }));
+        let new = fields(&json!({ "status": "open", "total": 12 }));
+        let changes = changed_fields(&old, &new);
+
+        assert!(subscription(&["status"])
+            .matching_changes(&changes)
+            .is_none());
+        let matching = subscription(&["status", "total"])
+            .matching_changes(&changes)
+            .unwrap();
+        assert_eq!(matching.keys().collect::<Vec<_>>(), vec!["total"]);
+    }
+}
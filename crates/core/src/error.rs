@@ -1,3 +1,6 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
 use thiserror::Error;
 
 /// Specific authentication error kinds for better error handling
@@ -63,6 +66,9 @@ pub enum Error {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    #[error("Validation failed with the following errors: {0}")]
+    FieldValidation(FieldErrors),
+
     #[error("Field not found: {0}")]
     FieldNotFound(String),
 
@@ -75,6 +81,9 @@ pub enum Error {
     #[error("Validation failed: {0}")]
     ValidationFailed(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error("Field conversion error for {0}: {1}")]
     FieldConversion(String, String),
 
@@ -95,6 +104,81 @@ pub enum Error {
 
     #[error("Invalid field type: {0}")]
     InvalidFieldType(String),
+
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+}
+
+/// Validation messages keyed by field name, collected so they can be reported together
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FieldErrors(BTreeMap<String, String>);
+
+impl FieldErrors {
+    /// Create an empty collection
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    /// Record `message` for `field`; further messages for the same field are appended
+    pub fn add(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        let message = message.into();
+        self.0
+            .entry(field.into())
+            .and_modify(|existing| {
+                existing.push_str("; ");
+                existing.push_str(&message);
+            })
+            .or_insert(message);
+    }
+
+    /// Whether no field has an error
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Number of fields with an error
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Message recorded for `field`
+    #[must_use]
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.0.get(field).map(String::as_str)
+    }
+
+    /// Field names and messages, ordered by field name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(field, message)| (field.as_str(), message.as_str()))
+    }
+
+    /// `Ok(())` when empty, otherwise an `Error::FieldValidation` with every message
+    ///
+    /// # Errors
+    /// Returns `Error::FieldValidation` if any field has an error
+    pub fn into_result(self) -> Result<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::FieldValidation(self))
+        }
+    }
+}
+
+impl fmt::Display for FieldErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self
+            .iter()
+            .map(|(field, message)| format!("Field '{field}': {message}"))
+            .collect();
+        write!(f, "{}", messages.join("; "))
+    }
 }
 
 impl From<String> for Error {
@@ -181,6 +265,31 @@ mod tests {
         assert!(matches!(err, Error::AuthError(AuthErrorKind::TokenExpired)));
     }
 
+    #[test]
+    fn test_field_errors_collect_messages_per_field() {
+        let mut errors = FieldErrors::new();
+        errors.add("name", "is required");
+        errors.add("age", "must be an integer");
+        errors.add("age", "must be at least 0");
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors.get("age"),
+            Some("must be an integer; must be at least 0")
+        );
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            serde_json::json!({"age": "must be an integer; must be at least 0", "name": "is required"})
+        );
+        let err = errors.into_result().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation failed with the following errors: \
+             Field 'age': must be an integer; must be at least 0; Field 'name': is required"
+        );
+        assert!(FieldErrors::new().into_result().is_ok());
+    }
+
     #[test]
     fn test_error_from_string() {
         let err: Error = "test error".to_string().into();
@@ -270,6 +379,7 @@ mod tests {
             Error::Unknown("unknown error".to_string()),
             Error::InvalidSchema("invalid schema".to_string()),
             Error::InvalidFieldType("invalid field type".to_string()),
+            Error::PayloadTooLarge("too many records".to_string()),
         ];
 
         // Verify all errors can be converted to string
@@ -3,6 +3,7 @@ use serde_json::Value;
 
 use crate::error::{Error, Result};
 use crate::field::definition::FieldDefinition;
+use crate::field::options::{FieldRequirement, OnDeletePolicy};
 use crate::field::types::FieldType;
 
 impl FieldDefinition {
@@ -15,12 +16,19 @@ impl FieldDefinition {
     /// # Errors
     /// Returns `Error::Validation` if the constraint value is invalid or the regex pattern is invalid.
     pub fn handle_constraint(&self, constraint_type: &str, constraint_value: &Value) -> Result<()> {
+        if constraint_type == REQUIRES_CONSTRAINT {
+            return parse_field_requirement(constraint_value).map(|_| ());
+        }
+
         match self.field_type {
             FieldType::String | FieldType::Text | FieldType::Wysiwyg => {
                 match constraint_type {
                     "min_length" | "max_length" => {
                         validate_number_constraint(constraint_value)?;
                     }
+                    MAX_BYTES_CONSTRAINT if self.field_type == FieldType::Text => {
+                        validate_max_bytes_constraint(constraint_value)?;
+                    }
                     "pattern" => {
                         validate_string_constraint(constraint_value)?;
 
@@ -33,7 +41,7 @@ impl FieldDefinition {
                     _ => {}
                 }
             }
-            FieldType::Integer | FieldType::Float => match constraint_type {
+            FieldType::Integer | FieldType::Float | FieldType::Money => match constraint_type {
                 "min" | "max" | "precision" => {
                     validate_number_constraint(constraint_value)?;
                 }
@@ -50,14 +58,27 @@ impl FieldDefinition {
             FieldType::Select | FieldType::MultiSelect if constraint_type == "options" => {
                 validate_array_constraint(constraint_value)?;
             }
+            FieldType::Select | FieldType::MultiSelect
+                if constraint_type == ALLOW_OTHER_CONSTRAINT =>
+            {
+                validate_boolean_constraint(constraint_value)?;
+            }
             FieldType::ManyToOne | FieldType::ManyToMany if constraint_type == "target_class" => {
                 validate_string_constraint(constraint_value)?;
             }
+            FieldType::ManyToOne if constraint_type == ON_DELETE_CONSTRAINT => {
+                parse_on_delete_policy(constraint_value)?;
+            }
             FieldType::Object | FieldType::Array | FieldType::Json
                 if constraint_type == "schema" =>
             {
                 validate_object_constraint(constraint_value)?;
             }
+            FieldType::Object | FieldType::Array | FieldType::Json
+                if constraint_type == MAX_BYTES_CONSTRAINT =>
+            {
+                validate_max_bytes_constraint(constraint_value)?;
+            }
             _ => {}
         }
 
@@ -65,6 +86,100 @@ impl FieldDefinition {
     }
 }
 
+/// Constraint key holding the `OnDeletePolicy` of a `ManyToOne` field
+pub const ON_DELETE_CONSTRAINT: &str = "on_delete";
+
+/// Constraint key limiting the stored size of `Json`, `Object`, `Array` and `Text` values
+pub const MAX_BYTES_CONSTRAINT: &str = "max_bytes";
+
+/// Constraint key letting `Select` and `MultiSelect` fields take values outside their options
+pub const ALLOW_OTHER_CONSTRAINT: &str = "allow_other";
+
+/// Constraint key making a field required while another field has a given value
+pub const REQUIRES_CONSTRAINT: &str = "requires";
+
+/// Parse a `requires` constraint: `{ "field": "<name>", "equals": <value> }`
+///
+/// # Errors
+/// Returns `Error::Validation` if the constraint is not an object with a field name and a value
+pub fn parse_field_requirement(value: &Value) -> Result<FieldRequirement> {
+    let invalid = || {
+        Error::Validation(
+            "Constraint 'requires' must be an object with a 'field' name and an 'equals' value"
+                .to_string(),
+        )
+    };
+    let field = value
+        .get("field")
+        .and_then(Value::as_str)
+        .filter(|name| !name.is_empty())
+        .ok_or_else(invalid)?;
+    let equals = value.get("equals").ok_or_else(invalid)?;
+    Ok(FieldRequirement {
+        field: field.to_string(),
+        equals: equals.clone(),
+    })
+}
+
+impl FieldDefinition {
+    /// Enforce the `max_bytes` limit of the field, if any.
+    ///
+    /// Strings are measured as stored (UTF-8 bytes); other values by their serialized JSON size.
+    ///
+    /// # Errors
+    /// Returns `Error::Validation` if the value is larger than the limit.
+    pub fn validate_max_bytes(&self, value: &Value) -> Result<()> {
+        let Some(max_bytes) = self.validation.max_bytes else {
+            return Ok(());
+        };
+        let size = match value {
+            Value::String(s) => s.len(),
+            other => serde_json::to_vec(other)?.len(),
+        };
+        if size > max_bytes {
+            return Err(Error::Validation(format!(
+                "Field '{}' must be at most {max_bytes} bytes, got {size}",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl FieldDefinition {
+    /// Delete policy of a `ManyToOne` field, read from its `on_delete` constraint
+    ///
+    /// Accepts both the flat form and the nested `constraints.constraints` form sent by the
+    /// admin UI. Missing policies default to `OnDeletePolicy::NoAction`.
+    ///
+    /// # Errors
+    /// Returns `Error::Validation` if the constraint is not a known policy.
+    pub fn on_delete_policy(&self) -> Result<OnDeletePolicy> {
+        let value = self.constraints.get(ON_DELETE_CONSTRAINT).or_else(|| {
+            self.constraints
+                .get("constraints")
+                .and_then(|nested| nested.get(ON_DELETE_CONSTRAINT))
+        });
+        match value {
+            None | Some(Value::Null) => Ok(OnDeletePolicy::default()),
+            Some(value) => parse_on_delete_policy(value),
+        }
+    }
+}
+
+/// Parse an `on_delete` constraint value
+///
+/// # Errors
+/// Returns `Error::Validation` if the value is not `no_action`, `restrict`, `cascade` or
+/// `set_null`.
+pub fn parse_on_delete_policy(constraint_value: &Value) -> Result<OnDeletePolicy> {
+    serde_json::from_value(constraint_value.clone()).map_err(|_| {
+        Error::Validation(format!(
+            "Invalid on_delete policy {constraint_value}; expected no_action, restrict, cascade or set_null"
+        ))
+    })
+}
+
 /// Validate that a constraint value is a valid number
 pub fn validate_number_constraint(constraint_value: &Value) -> Result<()> {
     if !constraint_value.is_number() {
@@ -76,6 +191,17 @@ pub fn validate_number_constraint(constraint_value: &Value) -> Result<()> {
     Ok(())
 }
 
+/// Validate that a `max_bytes` constraint is a positive integer
+pub fn validate_max_bytes_constraint(constraint_value: &Value) -> Result<()> {
+    if constraint_value.as_u64().is_none_or(|n| n == 0) {
+        return Err(Error::Validation(
+            "max_bytes constraint must be a positive integer".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 /// Validate that a constraint value is a valid string
 pub fn validate_string_constraint(constraint_value: &Value) -> Result<()> {
     if !constraint_value.is_string() {
@@ -1,7 +1,7 @@
 #![allow(clippy::unwrap_used)]
 
 use crate::field::definition::FieldDefinition;
-use crate::field::options::FieldValidation;
+use crate::field::options::{FieldValidation, OnDeletePolicy};
 use crate::field::types::FieldType;
 use crate::field::ui::UiSettings;
 use serde_json::json;
@@ -20,6 +20,7 @@ fn create_field_definition(field_type: FieldType) -> FieldDefinition {
         validation: FieldValidation::default(),
         ui_settings: UiSettings::default(),
         constraints: std::collections::HashMap::new(),
+        display_order: 0,
     }
 }
 
@@ -156,6 +157,39 @@ mod relation_constraints {
             .contains("String constraint"));
     }
 
+    #[test]
+    fn test_many_to_one_on_delete_accepts_known_policies() {
+        let field = create_field_definition(FieldType::ManyToOne);
+        for policy in ["no_action", "restrict", "cascade", "set_null"] {
+            assert!(field.handle_constraint("on_delete", &json!(policy)).is_ok());
+        }
+        assert!(field
+            .handle_constraint("on_delete", &json!("detach"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_on_delete_policy_defaults_to_no_action() {
+        let field = create_field_definition(FieldType::ManyToOne);
+        assert_eq!(field.on_delete_policy().unwrap(), OnDeletePolicy::NoAction);
+    }
+
+    #[test]
+    fn test_on_delete_policy_reads_flat_and_nested_constraints() {
+        let mut field = create_field_definition(FieldType::ManyToOne);
+        field
+            .constraints
+            .insert("on_delete".to_string(), json!("cascade"));
+        assert_eq!(field.on_delete_policy().unwrap(), OnDeletePolicy::Cascade);
+
+        let mut field = create_field_definition(FieldType::ManyToOne);
+        field.constraints.insert(
+            "constraints".to_string(),
+            json!({"target_class": "Customer", "on_delete": "set_null"}),
+        );
+        assert_eq!(field.on_delete_policy().unwrap(), OnDeletePolicy::SetNull);
+    }
+
     #[test]
     fn test_many_to_many_unknown_constraint_is_ignored() {
         let field = create_field_definition(FieldType::ManyToMany);
@@ -250,3 +284,47 @@ mod cross_type_guard_coverage {
         assert!(field.handle_constraint("schema", &json!("bad")).is_ok());
     }
 }
+
+mod max_bytes_constraints {
+    use super::*;
+
+    #[test]
+    fn test_max_bytes_accepts_positive_integer_on_sized_types() {
+        for field_type in [
+            FieldType::Json,
+            FieldType::Object,
+            FieldType::Array,
+            FieldType::Text,
+        ] {
+            let field = create_field_definition(field_type);
+            assert!(field.handle_constraint("max_bytes", &json!(1024)).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_max_bytes_rejects_invalid_values() {
+        let field = create_field_definition(FieldType::Json);
+        for bad in [json!(0), json!(-5), json!(1.5), json!("1024")] {
+            let result = field.handle_constraint("max_bytes", &bad);
+            assert!(result.is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_max_bytes_is_read_from_flat_and_nested_constraints() {
+        let flat: FieldDefinition = serde_json::from_value(json!({
+            "name": "payload", "display_name": "Payload", "field_type": "Json",
+            "required": false, "indexed": false, "constraints": { "max_bytes": 64 }
+        }))
+        .unwrap();
+        assert_eq!(flat.validation.max_bytes, Some(64));
+
+        let nested: FieldDefinition = serde_json::from_value(json!({
+            "name": "payload", "display_name": "Payload", "field_type": "Json",
+            "required": false, "indexed": false,
+            "constraints": { "type": "json", "constraints": { "max_bytes": 32 } }
+        }))
+        .unwrap();
+        assert_eq!(nested.validation.max_bytes, Some(32));
+    }
+}
@@ -16,6 +16,8 @@ mod validation;
 #[cfg(test)]
 mod validation_tests;
 
+pub use validation::RESERVED_SQL_KEYWORDS;
+
 /// Definition of a field in a class
 #[derive(Debug, Clone, Serialize)]
 pub struct FieldDefinition {
@@ -58,6 +60,10 @@ pub struct FieldDefinition {
     /// Extra field constraints or validation rules
     #[serde(default)]
     pub constraints: HashMap<String, Value>,
+
+    /// Position of the field in the definition's field list (lower numbers come first)
+    #[serde(default)]
+    pub display_order: i32,
 }
 
 /// Trait to define common operations for field definitions
@@ -98,6 +104,7 @@ impl FieldDefinition {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         }
     }
 }
@@ -129,8 +136,11 @@ impl FieldDefinitionModule for FieldDefinition {
             FieldType::Boolean => "BOOLEAN".to_string(),
             FieldType::DateTime => "TIMESTAMP WITH TIME ZONE".to_string(),
             FieldType::Date => "DATE".to_string(),
+            FieldType::Duration => "INTERVAL".to_string(),
             FieldType::Uuid | FieldType::ManyToOne => "UUID".to_string(),
-            FieldType::Json | FieldType::Object | FieldType::Array => "JSONB".to_string(),
+            FieldType::Json | FieldType::Object | FieldType::Array | FieldType::Money => {
+                "JSONB".to_string()
+            }
             FieldType::MultiSelect => "TEXT[]".to_string(),
             FieldType::ManyToMany => "UUID[]".to_string(),
         }
@@ -2,6 +2,9 @@ use serde::{Deserialize, Deserializer};
 use serde_json::Value;
 use std::collections::HashMap;
 
+use crate::field::definition::constraints::{
+    parse_field_requirement, ALLOW_OTHER_CONSTRAINT, MAX_BYTES_CONSTRAINT, REQUIRES_CONSTRAINT,
+};
 use crate::field::definition::FieldDefinition;
 use crate::field::options::FieldValidation;
 use crate::field::options::{OptionsSource, SelectOption};
@@ -33,6 +36,8 @@ impl<'de> Deserialize<'de> for FieldDefinition {
             pub ui_settings: UiSettings,
             #[serde(default)]
             pub constraints: HashMap<String, Value>,
+            #[serde(default)]
+            pub display_order: i32,
         }
 
         let mut helper = FieldDefinitionHelper::deserialize(deserializer)?;
@@ -98,24 +103,39 @@ impl<'de> Deserialize<'de> for FieldDefinition {
             }
         }
 
-        // Handle options source for Select/MultiSelect fields
-        if let Some(options) = inner_constraints.get("options").cloned() {
-            if let Some(options_array) = options.as_array() {
-                let mut select_options = Vec::new();
-                for opt in options_array {
-                    if let Some(opt_str) = opt.as_str() {
-                        select_options.push(SelectOption {
-                            value: opt_str.to_string(),
-                            label: opt_str.to_string(),
-                        });
-                    }
-                }
+        // Accepted both nested and flat, like `on_delete`
+        if let Some(max_bytes) = inner_constraints
+            .get(MAX_BYTES_CONSTRAINT)
+            .or_else(|| helper.constraints.get(MAX_BYTES_CONSTRAINT))
+            .and_then(Value::as_u64)
+        {
+            helper.validation.max_bytes = usize::try_from(max_bytes).ok();
+        }
 
-                if !select_options.is_empty() {
-                    helper.validation.options_source = Some(OptionsSource::Fixed {
-                        options: select_options,
-                    });
-                }
+        // Accepted both nested and flat, like `max_bytes`
+        if let Some(allow_other) = inner_constraints
+            .get(ALLOW_OTHER_CONSTRAINT)
+            .or_else(|| helper.constraints.get(ALLOW_OTHER_CONSTRAINT))
+            .and_then(Value::as_bool)
+        {
+            helper.validation.allow_other = Some(allow_other);
+        }
+
+        // Accepted both nested and flat; malformed rules are rejected when the definition is saved
+        if let Some(requires) = inner_constraints
+            .get(REQUIRES_CONSTRAINT)
+            .or_else(|| helper.constraints.get(REQUIRES_CONSTRAINT))
+        {
+            helper.validation.requires = parse_field_requirement(requires).ok();
+        }
+
+        // Handle options source for Select/MultiSelect fields
+        if let Some(options) = inner_constraints.get("options").and_then(Value::as_array) {
+            let select_options = parse_select_options(options);
+            if !select_options.is_empty() {
+                helper.validation.options_source = Some(OptionsSource::Fixed {
+                    options: select_options,
+                });
             }
         }
 
@@ -132,10 +152,33 @@ impl<'de> Deserialize<'de> for FieldDefinition {
             validation: helper.validation,
             ui_settings: helper.ui_settings,
             constraints: helper.constraints,
+            display_order: helper.display_order,
         })
     }
 }
 
+/// Parse fixed select options given as plain strings or `{ "value": ..., "label": ... }` objects
+fn parse_select_options(options: &[Value]) -> Vec<SelectOption> {
+    options
+        .iter()
+        .filter_map(|opt| {
+            if let Some(opt_str) = opt.as_str() {
+                return Some(SelectOption {
+                    value: opt_str.to_string(),
+                    label: opt_str.to_string(),
+                });
+            }
+            // Label defaults to the value
+            let value = opt.get("value").and_then(Value::as_str)?;
+            let label = opt.get("label").and_then(Value::as_str).unwrap_or(value);
+            Some(SelectOption {
+                value: value.to_string(),
+                label: label.to_string(),
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,9 +4,104 @@ use time::format_description::well_known::Rfc3339;
 use uuid::Uuid;
 
 use crate::error::{Error, Result};
+use crate::field::definition::constraints::{parse_field_requirement, REQUIRES_CONSTRAINT};
 use crate::field::definition::FieldDefinition;
+use crate::field::duration::IsoDuration;
+use crate::field::money::Money;
 use crate::field::types::FieldType;
 
+/// `PostgreSQL` reserved keywords that cannot be used as unquoted identifiers
+pub const RESERVED_SQL_KEYWORDS: &[&str] = &[
+    "all",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "authorization",
+    "binary",
+    "both",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "column",
+    "constraint",
+    "create",
+    "cross",
+    "current_date",
+    "current_role",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "deferrable",
+    "desc",
+    "distinct",
+    "do",
+    "else",
+    "end",
+    "except",
+    "false",
+    "for",
+    "foreign",
+    "freeze",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "initially",
+    "inner",
+    "intersect",
+    "into",
+    "is",
+    "isnull",
+    "join",
+    "leading",
+    "left",
+    "like",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "natural",
+    "not",
+    "notnull",
+    "null",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "outer",
+    "overlaps",
+    "placing",
+    "primary",
+    "references",
+    "right",
+    "select",
+    "session_user",
+    "similar",
+    "some",
+    "symmetric",
+    "table",
+    "then",
+    "to",
+    "trailing",
+    "true",
+    "union",
+    "unique",
+    "user",
+    "using",
+    "when",
+    "where",
+    "with",
+];
+
 impl FieldDefinition {
     /// Validate a field value against this definition
     ///
@@ -40,12 +135,18 @@ impl FieldDefinition {
             FieldType::Float => {
                 self.validate_float_value(value)?;
             }
+            FieldType::Money => {
+                self.validate_money_value(value)?;
+            }
             FieldType::Boolean => {
                 self.validate_boolean_value(value)?;
             }
             FieldType::DateTime | FieldType::Date => {
                 self.validate_date_value(value)?;
             }
+            FieldType::Duration => {
+                self.validate_duration_value(value)?;
+            }
             FieldType::Uuid => {
                 self.validate_uuid_value(value)?;
             }
@@ -71,6 +172,13 @@ impl FieldDefinition {
             | FieldType::File => {}
         }
 
+        if matches!(
+            self.field_type,
+            FieldType::Json | FieldType::Object | FieldType::Array | FieldType::Text
+        ) {
+            self.validate_max_bytes(value)?;
+        }
+
         Ok(())
     }
 
@@ -131,17 +239,8 @@ impl FieldDefinition {
         }
 
         // Check enum options if present
-        if let Some(crate::field::options::OptionsSource::Fixed { options }) =
-            &self.validation.options_source
-        {
-            let valid_options: Vec<&String> = options.iter().map(|opt| &opt.value).collect();
-
-            if !valid_options.contains(&&s.to_string()) {
-                return Err(Error::Validation(format!(
-                    "Field '{}' value must be one of {:?}",
-                    self.name, valid_options
-                )));
-            }
+        if let Some(allowed) = self.validation.allowed_options() {
+            self.check_option(s, &allowed)?;
         }
 
         Ok(())
@@ -215,6 +314,24 @@ impl FieldDefinition {
         self.validate_numeric_constraints(n)
     }
 
+    /// Validate a money value: a numeric amount within the numeric constraints and a known
+    /// ISO-4217 currency code
+    fn validate_money_value(&self, value: &Value) -> Result<()> {
+        let money = Money::from_json(value)
+            .map_err(|e| Error::Validation(format!("Field '{}' {e}", self.name)))?;
+        self.validate_numeric_constraints(money.amount_value())
+    }
+
+    /// Validate a duration value: an ISO-8601 duration string such as `PT1H30M`
+    fn validate_duration_value(&self, value: &Value) -> Result<()> {
+        let s = value.as_str().ok_or_else(|| {
+            Error::Validation(format!("Field '{}' must be a duration string", self.name))
+        })?;
+        s.parse::<IsoDuration>()
+            .map(|_| ())
+            .map_err(|e| Error::Validation(format!("Field '{}' {e}", self.name)))
+    }
+
     /// Validate numeric constraints (min, max, `positive_only`)
     fn validate_numeric_constraints(&self, n: f64) -> Result<()> {
         // Check min value
@@ -359,17 +476,8 @@ impl FieldDefinition {
         let selected = value.as_str().unwrap();
 
         // Check if selected value is in options
-        if let Some(crate::field::options::OptionsSource::Fixed { options }) =
-            &self.validation.options_source
-        {
-            let valid_options: Vec<&String> = options.iter().map(|opt| &opt.value).collect();
-
-            if !valid_options.contains(&&selected.to_string()) {
-                return Err(Error::Validation(format!(
-                    "Field '{}' value must be one of {:?}",
-                    self.name, valid_options
-                )));
-            }
+        if let Some(allowed) = self.validation.allowed_options() {
+            self.check_option(selected, &allowed)?;
         }
 
         Ok(())
@@ -397,25 +505,27 @@ impl FieldDefinition {
         }
 
         // Check if selected values are in options
-        if let Some(crate::field::options::OptionsSource::Fixed { options }) =
-            &self.validation.options_source
-        {
-            let valid_options: Vec<&String> = options.iter().map(|opt| &opt.value).collect();
-
+        if let Some(allowed) = self.validation.allowed_options() {
             for item in selected {
-                let item_str = item.as_str().unwrap();
-                if !valid_options.contains(&&item_str.to_string()) {
-                    return Err(Error::Validation(format!(
-                        "Field '{}' values must be one of {:?}",
-                        self.name, valid_options
-                    )));
-                }
+                self.check_option(item.as_str().unwrap(), &allowed)?;
             }
         }
 
         Ok(())
     }
 
+    /// Reject `value` unless it is one of the `allowed` options, listing them in the error
+    fn check_option(&self, value: &str, allowed: &[&str]) -> Result<()> {
+        if allowed.contains(&value) {
+            return Ok(());
+        }
+        Err(Error::Validation(format!(
+            "Field '{}' has invalid option '{value}'; allowed values: {}",
+            self.name,
+            allowed.join(", ")
+        )))
+    }
+
     /// Validate an array value
     fn validate_array_value(&self, value: &Value) -> Result<()> {
         if !value.is_array() {
@@ -459,98 +569,7 @@ impl FieldDefinition {
         }
 
         // Check for reserved SQL keywords
-        let reserved_keywords = [
-            "all",
-            "analyse",
-            "analyze",
-            "and",
-            "any",
-            "array",
-            "as",
-            "asc",
-            "asymmetric",
-            "authorization",
-            "binary",
-            "both",
-            "case",
-            "cast",
-            "check",
-            "collate",
-            "column",
-            "constraint",
-            "create",
-            "cross",
-            "current_date",
-            "current_role",
-            "current_time",
-            "current_timestamp",
-            "current_user",
-            "default",
-            "deferrable",
-            "desc",
-            "distinct",
-            "do",
-            "else",
-            "end",
-            "except",
-            "false",
-            "for",
-            "foreign",
-            "freeze",
-            "from",
-            "full",
-            "grant",
-            "group",
-            "having",
-            "in",
-            "initially",
-            "inner",
-            "intersect",
-            "into",
-            "is",
-            "isnull",
-            "join",
-            "leading",
-            "left",
-            "like",
-            "limit",
-            "localtime",
-            "localtimestamp",
-            "natural",
-            "not",
-            "notnull",
-            "null",
-            "offset",
-            "on",
-            "only",
-            "or",
-            "order",
-            "outer",
-            "overlaps",
-            "placing",
-            "primary",
-            "references",
-            "right",
-            "select",
-            "session_user",
-            "similar",
-            "some",
-            "symmetric",
-            "table",
-            "then",
-            "to",
-            "trailing",
-            "true",
-            "union",
-            "unique",
-            "user",
-            "using",
-            "when",
-            "where",
-            "with",
-        ];
-
-        if reserved_keywords.contains(&self.name.to_lowercase().as_str()) {
+        if RESERVED_SQL_KEYWORDS.contains(&self.name.to_lowercase().as_str()) {
             return Err(Error::Validation(format!(
                 "Field name '{}' is a reserved SQL keyword and cannot be used",
                 self.name
@@ -562,6 +581,38 @@ impl FieldDefinition {
             self.handle_constraint(constraint_type, constraint_value)?;
         }
 
+        // `requires` may also sit in the nested API constraints
+        if let Some(requires) = self
+            .constraints
+            .get("constraints")
+            .and_then(|nested| nested.get(REQUIRES_CONSTRAINT))
+        {
+            parse_field_requirement(requires)?;
+        }
+
         Ok(())
     }
+
+    /// Message if this field is left empty while its `requires` rule is triggered
+    ///
+    /// `dependency_value` is the entity's value of the field the rule depends on; `value` is
+    /// this field's value. Missing, `null`, blank strings and empty arrays count as empty.
+    #[must_use]
+    pub fn unmet_requirement(
+        &self,
+        dependency_value: Option<&Value>,
+        value: Option<&Value>,
+    ) -> Option<String> {
+        let requirement = self.validation.requires.as_ref()?;
+        if !requirement.is_triggered(dependency_value) {
+            return None;
+        }
+        let is_empty = match value {
+            None | Some(Value::Null) => true,
+            Some(Value::String(s)) => s.trim().is_empty(),
+            Some(Value::Array(items)) => items.is_empty(),
+            Some(_) => false,
+        };
+        is_empty.then(|| requirement.message())
+    }
 }
@@ -20,6 +20,7 @@ fn create_field_definition(name: &str, field_type: FieldType) -> FieldDefinition
         validation: FieldValidation::default(),
         ui_settings: UiSettings::default(),
         constraints: std::collections::HashMap::new(),
+        display_order: 0,
     }
 }
 
@@ -188,3 +189,250 @@ mod boolean_field_validation {
             .contains("must be a boolean"));
     }
 }
+
+mod max_bytes_validation {
+    use super::*;
+
+    fn sized_field(field_type: FieldType, max_bytes: usize) -> FieldDefinition {
+        let mut field = create_field_definition("payload", field_type);
+        field.validation.max_bytes = Some(max_bytes);
+        field
+    }
+
+    #[test]
+    fn test_json_within_limit_is_accepted() {
+        let field = sized_field(FieldType::Json, 32);
+        // {"a":[1,2,3]} serializes to 13 bytes
+        assert!(field.validate_value(&json!({"a": [1, 2, 3]})).is_ok());
+        assert!(sized_field(FieldType::Json, 13)
+            .validate_value(&json!({"a": [1, 2, 3]}))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_oversized_json_is_rejected() {
+        let field = sized_field(FieldType::Json, 12);
+        let err = field
+            .validate_value(&json!({"a": [1, 2, 3]}))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Field 'payload' must be at most 12 bytes, got 13"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_object_array_and_text_limits_are_enforced() {
+        let big = "x".repeat(100);
+        assert!(sized_field(FieldType::Object, 50)
+            .validate_value(&json!({ "note": big }))
+            .is_err());
+        assert!(sized_field(FieldType::Array, 50)
+            .validate_value(&json!([big]))
+            .is_err());
+        assert!(sized_field(FieldType::Text, 99)
+            .validate_value(&json!(big))
+            .is_err());
+        assert!(sized_field(FieldType::Text, 100)
+            .validate_value(&json!(big))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_limit_is_ignored_for_other_types() {
+        let field = sized_field(FieldType::String, 1);
+        assert!(field.validate_value(&json!("longer than one byte")).is_ok());
+    }
+}
+
+mod money_field_validation {
+    use super::*;
+
+    fn price_field() -> FieldDefinition {
+        let mut field = create_field_definition("price", FieldType::Money);
+        field.validation.min_value = Some(json!(1));
+        field.validation.max_value = Some(json!(1000));
+        field
+    }
+
+    #[test]
+    fn test_money_field_accepts_valid_amount() {
+        let field = price_field();
+        assert!(field
+            .validate_value(&json!({"amount": 19.99, "currency": "EUR"}))
+            .is_ok());
+        assert!(field
+            .validate_value(&json!({"amount": "250.50", "currency": "USD"}))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_money_field_rejects_unknown_currency() {
+        let err = price_field()
+            .validate_value(&json!({"amount": 10, "currency": "XYZ"}))
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown currency code 'XYZ'"));
+    }
+
+    #[test]
+    fn test_money_field_rejects_amount_below_min() {
+        let err = price_field()
+            .validate_value(&json!({"amount": 0.5, "currency": "EUR"}))
+            .unwrap_err();
+        assert!(err.to_string().contains("must be at least 1"));
+    }
+
+    #[test]
+    fn test_money_field_rejects_missing_amount() {
+        let field = price_field();
+        assert!(field.validate_value(&json!({"currency": "EUR"})).is_err());
+        assert!(field.validate_value(&json!(19.99)).is_err());
+    }
+
+    #[test]
+    fn test_money_field_uses_jsonb() {
+        use crate::field::definition::FieldDefinitionModule;
+        assert_eq!(price_field().get_sql_type(), "JSONB");
+    }
+}
+
+mod duration_field_validation {
+    use super::*;
+
+    #[test]
+    fn test_duration_field_accepts_iso_8601_duration() {
+        let field = create_field_definition("sla", FieldType::Duration);
+        assert!(field.validate_value(&json!("PT1H30M")).is_ok());
+        assert!(field.validate_value(&json!("P30D")).is_ok());
+    }
+
+    #[test]
+    fn test_duration_field_rejects_garbage() {
+        let field = create_field_definition("sla", FieldType::Duration);
+        let err = field.validate_value(&json!("90 minutes")).unwrap_err();
+        assert!(err.to_string().contains("ISO-8601 duration"));
+        assert!(field.validate_value(&json!(5400)).is_err());
+    }
+
+    #[test]
+    fn test_duration_field_uses_interval() {
+        use crate::field::definition::FieldDefinitionModule;
+        let field = create_field_definition("sla", FieldType::Duration);
+        assert_eq!(field.get_sql_type(), "INTERVAL");
+    }
+}
+
+mod select_field_validation {
+    use super::*;
+
+    fn status_field(field_type: &str, allow_other: Option<bool>) -> FieldDefinition {
+        let mut constraints = json!({ "options": ["draft", { "value": "live", "label": "Live" }] });
+        if let Some(allow_other) = allow_other {
+            constraints["allow_other"] = json!(allow_other);
+        }
+        serde_json::from_value(json!({
+            "name": "status",
+            "display_name": "Status",
+            "field_type": field_type,
+            "required": false,
+            "indexed": false,
+            "constraints": { "type": "select", "constraints": constraints },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_select_field_accepts_listed_option() {
+        assert!(status_field("Select", None)
+            .validate_value(&json!("live"))
+            .is_ok());
+        assert!(status_field("MultiSelect", None)
+            .validate_value(&json!(["draft", "live"]))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_select_field_rejects_unlisted_option() {
+        let err = status_field("Select", None)
+            .validate_value(&json!("archived"))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("invalid option 'archived'; allowed values: draft, live"));
+
+        let err = status_field("MultiSelect", Some(false))
+            .validate_value(&json!(["draft", "archived"]))
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid option 'archived'"));
+    }
+
+    #[test]
+    fn test_select_field_with_allow_other_accepts_novel_value() {
+        assert!(status_field("Select", Some(true))
+            .validate_value(&json!("archived"))
+            .is_ok());
+        assert!(status_field("MultiSelect", Some(true))
+            .validate_value(&json!(["draft", "archived"]))
+            .is_ok());
+    }
+}
+
+mod conditional_requirement {
+    use super::*;
+
+    fn shipping_address() -> FieldDefinition {
+        serde_json::from_value(json!({
+            "name": "shipping_address",
+            "display_name": "Shipping address",
+            "field_type": "String",
+            "required": false,
+            "indexed": false,
+            "constraints": {
+                "type": "string",
+                "constraints": { "requires": { "field": "needs_shipping", "equals": true } }
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_requires_rule_is_parsed() {
+        let requirement = shipping_address().validation.requires.unwrap();
+        assert_eq!(requirement.field, "needs_shipping");
+        assert_eq!(requirement.equals, json!(true));
+        assert!(shipping_address().validate().is_ok());
+    }
+
+    #[test]
+    fn test_empty_field_is_rejected_when_dependency_matches() {
+        let field = shipping_address();
+        for value in [None, Some(json!(null)), Some(json!("  "))] {
+            let message = field.unmet_requirement(Some(&json!(true)), value.as_ref());
+            assert_eq!(
+                message.as_deref(),
+                Some("is required when 'needs_shipping' is true"),
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rule_is_not_triggered_otherwise() {
+        let field = shipping_address();
+        assert!(field.unmet_requirement(Some(&json!(false)), None).is_none());
+        assert!(field.unmet_requirement(None, None).is_none());
+        assert!(field
+            .unmet_requirement(Some(&json!(true)), Some(&json!("1 Main St")))
+            .is_none());
+    }
+
+    #[test]
+    fn test_malformed_requires_rule_is_rejected() {
+        let mut field = create_field_definition("shipping_address", FieldType::String);
+        field
+            .constraints
+            .insert("requires".to_string(), json!({ "field": "needs_shipping" }));
+        assert!(field.validate().is_err());
+    }
+}
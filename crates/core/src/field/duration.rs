@@ -0,0 +1,273 @@
+use std::fmt;
+use std::str::FromStr;
+
+use sqlx::postgres::types::PgInterval;
+use thiserror::Error;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SECOND;
+const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+
+/// Why a string is not a valid ISO-8601 duration
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DurationError {
+    #[error("must be an ISO-8601 duration such as 'PT1H30M'")]
+    Invalid,
+    #[error("is out of range")]
+    OutOfRange,
+}
+
+/// Value of a `Duration` field, split like a `PostgreSQL` `INTERVAL`
+///
+/// Written as an ISO-8601 duration (`P[nY][nM][nW][nD][T[nH][nM][nS]]`); years are kept as
+/// 12 months and weeks as 7 days, so a value round-trips through the database unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IsoDuration {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+impl FromStr for IsoDuration {
+    type Err = DurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let body = s.strip_prefix('P').ok_or(DurationError::Invalid)?;
+        if body.is_empty() || body.ends_with('T') {
+            return Err(DurationError::Invalid);
+        }
+
+        let mut duration = Self::default();
+        let mut in_time = false;
+        // Designators must appear in this order; each at most once
+        let mut last_rank = 0;
+        let mut number = String::new();
+        for c in body.chars() {
+            match c {
+                '0'..='9' | '.' | ',' => number.push(if c == ',' { '.' } else { c }),
+                'T' if !in_time && number.is_empty() => {
+                    in_time = true;
+                    last_rank = 4;
+                }
+                _ => {
+                    let rank = match (in_time, c) {
+                        (false, 'Y') => 1,
+                        (false, 'M') => 2,
+                        (false, 'W') => 3,
+                        (false, 'D') => 4,
+                        (true, 'H') => 5,
+                        (true, 'M') => 6,
+                        (true, 'S') => 7,
+                        _ => return Err(DurationError::Invalid),
+                    };
+                    if rank <= last_rank || number.is_empty() {
+                        return Err(DurationError::Invalid);
+                    }
+                    last_rank = rank;
+                    duration.add(c, in_time, &number)?;
+                    number.clear();
+                }
+            }
+        }
+        if number.is_empty() {
+            Ok(duration)
+        } else {
+            Err(DurationError::Invalid)
+        }
+    }
+}
+
+impl IsoDuration {
+    /// Add the `number` of `designator` units; only seconds may have a fraction
+    fn add(&mut self, designator: char, in_time: bool, number: &str) -> Result<(), DurationError> {
+        if in_time && designator == 'S' {
+            let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+            if whole.is_empty() || fraction.len() > 6 || fraction.contains('.') {
+                return Err(DurationError::Invalid);
+            }
+            let seconds: i64 = whole.parse().map_err(|_| DurationError::OutOfRange)?;
+            let fraction: i64 = format!("{fraction:0<6}")
+                .parse()
+                .map_err(|_| DurationError::Invalid)?;
+            let micros = seconds
+                .checked_mul(MICROS_PER_SECOND)
+                .and_then(|m| m.checked_add(fraction))
+                .ok_or(DurationError::OutOfRange)?;
+            return self.add_micros(micros);
+        }
+
+        if number.contains('.') {
+            return Err(DurationError::Invalid);
+        }
+        let n: i32 = number.parse().map_err(|_| DurationError::OutOfRange)?;
+        let overflow = || DurationError::OutOfRange;
+        match (in_time, designator) {
+            (false, 'Y') => {
+                self.months = n
+                    .checked_mul(12)
+                    .and_then(|m| self.months.checked_add(m))
+                    .ok_or_else(overflow)?;
+            }
+            (false, 'M') => self.months = self.months.checked_add(n).ok_or_else(overflow)?,
+            (false, 'W') => {
+                self.days = n
+                    .checked_mul(7)
+                    .and_then(|d| self.days.checked_add(d))
+                    .ok_or_else(overflow)?;
+            }
+            (false, 'D') => self.days = self.days.checked_add(n).ok_or_else(overflow)?,
+            (true, 'H') => self.add_micros(i64::from(n) * MICROS_PER_HOUR)?,
+            (true, 'M') => self.add_micros(i64::from(n) * MICROS_PER_MINUTE)?,
+            _ => return Err(DurationError::Invalid),
+        }
+        Ok(())
+    }
+
+    fn add_micros(&mut self, micros: i64) -> Result<(), DurationError> {
+        self.microseconds = self
+            .microseconds
+            .checked_add(micros)
+            .ok_or(DurationError::OutOfRange)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for IsoDuration {
+    /// Canonical ISO-8601 form, e.g. `P1Y2M3DT4H5M6.5S`; a zero duration is `PT0S`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Self::default() {
+            return write!(f, "PT0S");
+        }
+        write!(f, "P")?;
+        let (years, months) = (self.months / 12, self.months % 12);
+        if years != 0 {
+            write!(f, "{years}Y")?;
+        }
+        if months != 0 {
+            write!(f, "{months}M")?;
+        }
+        if self.days != 0 {
+            write!(f, "{}D", self.days)?;
+        }
+        if self.microseconds == 0 {
+            return Ok(());
+        }
+        write!(f, "T")?;
+        let hours = self.microseconds / MICROS_PER_HOUR;
+        let minutes = self.microseconds % MICROS_PER_HOUR / MICROS_PER_MINUTE;
+        let micros = self.microseconds % MICROS_PER_MINUTE;
+        if hours != 0 {
+            write!(f, "{hours}H")?;
+        }
+        if minutes != 0 {
+            write!(f, "{minutes}M")?;
+        }
+        if micros != 0 {
+            let (seconds, fraction) = (micros / MICROS_PER_SECOND, micros % MICROS_PER_SECOND);
+            if fraction == 0 {
+                write!(f, "{seconds}S")?;
+            } else {
+                let fraction = format!("{:06}", fraction.abs());
+                write!(f, "{seconds}.{}S", fraction.trim_end_matches('0'))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<IsoDuration> for PgInterval {
+    fn from(duration: IsoDuration) -> Self {
+        Self {
+            months: duration.months,
+            days: duration.days,
+            microseconds: duration.microseconds,
+        }
+    }
+}
+
+impl From<PgInterval> for IsoDuration {
+    fn from(interval: PgInterval) -> Self {
+        Self {
+            months: interval.months,
+            days: interval.days,
+            microseconds: interval.microseconds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_date_and_time_parts() {
+        let duration: IsoDuration = "PT1H30M".parse().unwrap();
+        assert_eq!(
+            duration,
+            IsoDuration {
+                months: 0,
+                days: 0,
+                microseconds: 90 * MICROS_PER_MINUTE,
+            }
+        );
+
+        let duration: IsoDuration = "P1Y2M1W3DT4H5M6.25S".parse().unwrap();
+        assert_eq!(duration.months, 14);
+        assert_eq!(duration.days, 10);
+        assert_eq!(
+            duration.microseconds,
+            4 * MICROS_PER_HOUR + 5 * MICROS_PER_MINUTE + 6_250_000
+        );
+    }
+
+    #[test]
+    fn formats_canonically() {
+        for (input, expected) in [
+            ("PT1H30M", "PT1H30M"),
+            ("PT90M", "PT1H30M"),
+            ("P1Y2M1W3DT4H5M6.25S", "P1Y2M10DT4H5M6.25S"),
+            ("P0D", "PT0S"),
+            ("PT0.5S", "PT0.5S"),
+        ] {
+            let duration: IsoDuration = input.parse().unwrap();
+            assert_eq!(duration.to_string(), expected, "formatting {input}");
+        }
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        for input in [
+            "",
+            "P",
+            "PT",
+            "1H30M",
+            "PT1H30",
+            "P1H",
+            "PT1D",
+            "PT30M1H",
+            "P1.5D",
+            "PT1.1234567S",
+            "P1Y1Y",
+            "one hour",
+        ] {
+            assert_eq!(
+                input.parse::<IsoDuration>(),
+                Err(DurationError::Invalid),
+                "parsing {input:?}"
+            );
+        }
+        assert_eq!(
+            "PT9999999999H".parse::<IsoDuration>(),
+            Err(DurationError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn converts_to_and_from_interval() {
+        let duration: IsoDuration = "P1MT2S".parse().unwrap();
+        let interval = PgInterval::from(duration);
+        assert_eq!(interval.months, 1);
+        assert_eq!(interval.microseconds, 2 * MICROS_PER_SECOND);
+        assert_eq!(IsoDuration::from(interval), duration);
+    }
+}
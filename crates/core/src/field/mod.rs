@@ -1,4 +1,6 @@
 pub mod definition;
+pub mod duration;
+pub mod money;
 pub mod options;
 pub mod types;
 pub mod ui;
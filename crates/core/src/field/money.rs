@@ -0,0 +1,111 @@
+use serde_json::Value;
+use thiserror::Error;
+
+/// Active ISO-4217 currency codes, sorted
+pub const ISO_4217_CODES: &[&str] = &[
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD",
+    "CAD", "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUC", "CUP",
+    "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP",
+    "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS",
+    "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW",
+    "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD",
+    "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN",
+    "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR",
+    "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SLL",
+    "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY",
+    "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES",
+    "VND", "VUV", "WST", "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XCG", "XDR",
+    "XOF", "XPD", "XPF", "XPT", "XSU", "XUA", "YER", "ZAR", "ZMW", "ZWG", "ZWL",
+];
+
+/// Check whether `code` is an active ISO-4217 currency code (upper case)
+#[must_use]
+pub fn is_iso_4217_code(code: &str) -> bool {
+    ISO_4217_CODES.binary_search(&code).is_ok()
+}
+
+/// Why a value is not a valid `Money` value
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MoneyError {
+    #[error("must be an object with 'amount' and 'currency'")]
+    NotAnObject,
+    #[error("must have a numeric amount")]
+    InvalidAmount,
+    #[error("has unknown currency code '{0}'")]
+    UnknownCurrency(String),
+}
+
+/// Value of a `Money` field: a decimal amount with its ISO-4217 currency code
+///
+/// Stored as `{ "amount": <number or decimal string>, "currency": "<code>" }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Money {
+    /// Amount as decimal text, so no precision is lost on the way to SQL `numeric`
+    pub amount: String,
+    /// ISO-4217 currency code
+    pub currency: String,
+}
+
+impl Money {
+    /// Read a `Money` value from JSON
+    ///
+    /// # Errors
+    /// Returns why the value is not an object with a numeric amount and a known currency code
+    pub fn from_json(value: &Value) -> Result<Self, MoneyError> {
+        let object = value.as_object().ok_or(MoneyError::NotAnObject)?;
+        let amount = match object.get("amount") {
+            Some(Value::Number(n)) => n.to_string(),
+            Some(Value::String(s)) if s.trim().parse::<f64>().is_ok_and(f64::is_finite) => {
+                s.trim().to_string()
+            }
+            _ => return Err(MoneyError::InvalidAmount),
+        };
+        let currency = match object.get("currency") {
+            Some(Value::String(code)) if is_iso_4217_code(code) => code.clone(),
+            Some(Value::String(code)) => return Err(MoneyError::UnknownCurrency(code.clone())),
+            Some(other) => return Err(MoneyError::UnknownCurrency(other.to_string())),
+            None => return Err(MoneyError::NotAnObject),
+        };
+        Ok(Self { amount, currency })
+    }
+
+    /// Amount as a float, for range checks
+    #[must_use]
+    pub fn amount_value(&self) -> f64 {
+        self.amount.parse().unwrap_or(f64::NAN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn currency_codes_are_sorted_and_unique() {
+        assert!(ISO_4217_CODES.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(is_iso_4217_code("EUR"));
+        assert!(!is_iso_4217_code("eur"));
+        assert!(!is_iso_4217_code("ABC"));
+    }
+
+    #[test]
+    fn reads_number_and_decimal_string_amounts() {
+        let money = Money::from_json(&json!({ "amount": 19.99, "currency": "EUR" })).unwrap();
+        assert_eq!(money.amount, "19.99");
+        assert_eq!(money.currency, "EUR");
+
+        let money = Money::from_json(&json!({ "amount": " 1250.50 ", "currency": "JPY" })).unwrap();
+        assert_eq!(money.amount, "1250.50");
+
+        assert_eq!(
+            Money::from_json(&json!({ "amount": "NaN", "currency": "EUR" })),
+            Err(MoneyError::InvalidAmount)
+        );
+        assert_eq!(
+            Money::from_json(&json!(19.99)),
+            Err(MoneyError::NotAnObject)
+        );
+    }
+}
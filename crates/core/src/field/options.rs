@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 use serde_json;
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 /// Source of options for select fields
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -30,6 +32,46 @@ pub struct SelectOption {
     pub label: String,
 }
 
+/// Makes a field required while another field of the same entity has a given value
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FieldRequirement {
+    /// Name of the field the requirement depends on
+    pub field: String,
+    /// Value of that field which makes this field required
+    pub equals: serde_json::Value,
+}
+
+impl FieldRequirement {
+    /// Whether `dependency_value`, the value of the `field` this depends on, triggers it
+    #[must_use]
+    pub fn is_triggered(&self, dependency_value: Option<&serde_json::Value>) -> bool {
+        dependency_value == Some(&self.equals)
+    }
+
+    /// Message for a field left empty while the requirement is triggered
+    #[must_use]
+    pub fn message(&self) -> String {
+        format!("is required when '{}' is {}", self.field, self.equals)
+    }
+}
+
+/// What happens to entities referencing a parent through a `ManyToOne` field when the parent
+/// is deleted
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema, TS, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum OnDeletePolicy {
+    /// Leave the children and their reference untouched (behavior before delete policies)
+    #[default]
+    NoAction,
+    /// Refuse to delete the parent while children reference it
+    Restrict,
+    /// Delete the children together with the parent
+    Cascade,
+    /// Clear the reference on the children
+    SetNull,
+}
+
 /// Validation rules for fields
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FieldValidation {
@@ -62,6 +104,34 @@ pub struct FieldValidation {
 
     /// For select fields: options source
     pub options_source: Option<OptionsSource>,
+
+    /// Maximum stored size in bytes for `Json`, `Object`, `Array` and `Text` fields
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_bytes: Option<usize>,
+
+    /// For select fields: accept values outside a fixed options list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_other: Option<bool>,
+
+    /// Required only while another field has a given value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires: Option<FieldRequirement>,
+}
+
+impl FieldValidation {
+    /// Values a select field must take, if it has a fixed options list and `allow_other` is off
+    #[must_use]
+    pub fn allowed_options(&self) -> Option<Vec<&str>> {
+        if self.allow_other == Some(true) {
+            return None;
+        }
+        match &self.options_source {
+            Some(OptionsSource::Fixed { options }) => {
+                Some(options.iter().map(|opt| opt.value.as_str()).collect())
+            }
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
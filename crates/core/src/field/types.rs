@@ -12,6 +12,8 @@ pub enum FieldType {
     // Numeric types
     Integer,
     Float,
+    /// Decimal amount with an ISO-4217 currency code, stored as `{ amount, currency }`
+    Money,
 
     // Boolean type
     Boolean,
@@ -19,6 +21,8 @@ pub enum FieldType {
     // Date types
     DateTime,
     Date,
+    /// ISO-8601 duration such as `PT1H30M`, stored as `INTERVAL`
+    Duration,
 
     // Complex data types
     Object,
@@ -49,6 +53,12 @@ impl FieldType {
         matches!(self, Self::ManyToOne | Self::ManyToMany)
     }
 
+    /// Check if this field type holds a number
+    #[must_use]
+    pub const fn is_numeric(&self) -> bool {
+        matches!(self, Self::Integer | Self::Float)
+    }
+
     /// Check if this field type is write-only (never returned in API responses)
     #[must_use]
     pub const fn is_write_only(&self) -> bool {
@@ -65,9 +75,11 @@ impl fmt::Display for FieldType {
             Self::Wysiwyg => write!(f, "Wysiwyg"),
             Self::Integer => write!(f, "Integer"),
             Self::Float => write!(f, "Float"),
+            Self::Money => write!(f, "Money"),
             Self::Boolean => write!(f, "Boolean"),
             Self::DateTime => write!(f, "DateTime"),
             Self::Date => write!(f, "Date"),
+            Self::Duration => write!(f, "Duration"),
             Self::Object => write!(f, "Object"),
             Self::Array => write!(f, "Array"),
             Self::Uuid => write!(f, "Uuid"),
@@ -105,13 +117,16 @@ pub fn get_sql_type_for_field(
         FieldType::Boolean => "BOOLEAN".to_string(),
         FieldType::DateTime => "TIMESTAMP WITH TIME ZONE".to_string(),
         FieldType::Date => "DATE".to_string(),
+        FieldType::Duration => "INTERVAL".to_string(),
         FieldType::Uuid => "UUID".to_string(),
         FieldType::Select => {
             // If this is an enum-backed select, use enum type
             enum_name.map_or_else(|| "TEXT".to_string(), |name| format!("{name}_enum"))
         }
         FieldType::MultiSelect => "TEXT[]".to_string(),
-        FieldType::Object | FieldType::Array | FieldType::Json => "JSONB".to_string(), // Complex types as JSON
+        FieldType::Object | FieldType::Array | FieldType::Json | FieldType::Money => {
+            "JSONB".to_string() // Complex types as JSON
+        }
         _ => "TEXT".to_string(), // Default for any other types (including Image, File)
     }
 }
@@ -126,9 +141,11 @@ pub fn is_valid_field_type(field_type: &str) -> bool {
             | "Wysiwyg"
             | "Integer"
             | "Float"
+            | "Money"
             | "Boolean"
             | "DateTime"
             | "Date"
+            | "Duration"
             | "Object"
             | "Array"
             | "Uuid"
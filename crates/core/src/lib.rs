@@ -2,22 +2,29 @@
 
 pub mod admin_jwt;
 pub mod admin_user;
+pub mod audit_log;
 pub mod cache;
 pub mod config;
+pub mod count;
 pub mod crypto;
 pub mod domain;
 pub mod email_template;
+pub mod entity_aggregate;
 pub mod entity_definition;
 pub mod entity_jwt;
+pub mod entity_webhook;
 pub mod error;
 pub mod field;
 pub mod maintenance;
+pub mod notification;
 pub mod outbox;
 pub mod password_reset_token;
 pub mod permissions;
 pub mod public_api;
 pub mod refresh_token;
+pub mod request_id;
 pub mod settings;
+pub mod sort;
 pub mod system_log;
 pub mod utils;
 pub mod validation;
@@ -1,5 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 pub mod task;
+pub mod version_retention;
 
 pub use task::MaintenanceTask;
+pub use version_retention::{apply_retention, RetentionReport};
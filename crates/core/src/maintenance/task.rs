@@ -49,6 +49,10 @@ pub trait TaskContext: Send + Sync {
             Arc::new(CacheManager::new(crate::config::CacheConfig {
                 entity_definition_ttl: 3600,
                 api_key_ttl: 600,
+                entity_miss_ttl: 0,
+                warm_on_startup: false,
+                eviction_policy: crate::config::CacheEvictionPolicy::Lru,
+                broadcast_invalidations: false,
                 enabled: true,
                 ttl: 3600,
                 max_size: 10000,
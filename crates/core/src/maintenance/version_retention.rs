@@ -0,0 +1,156 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use log::info;
+
+use crate::error::Result;
+use crate::settings::EntityVersioningSettings;
+use crate::versioning::purger_trait::VersionPurger;
+
+/// Outcome of a retention run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetentionReport {
+    /// Versions deleted because they exceeded `max_age_days`
+    pub pruned_by_age: u64,
+    /// Versions deleted because they exceeded `max_versions`
+    pub pruned_by_count: u64,
+}
+
+impl RetentionReport {
+    /// Total number of deleted versions
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.pruned_by_age + self.pruned_by_count
+    }
+}
+
+/// Apply the configured retention policy to all given version stores.
+///
+/// Age-based pruning runs first, then count-based pruning keeps the latest
+/// `max_versions` per item. Implementations never delete the current version.
+/// Nothing is pruned when versioning is disabled.
+///
+/// # Errors
+/// Returns the first error raised by a purger, or a validation error for invalid settings
+pub async fn apply_retention(
+    purgers: &[Box<dyn VersionPurger>],
+    settings: &EntityVersioningSettings,
+) -> Result<RetentionReport> {
+    let mut report = RetentionReport::default();
+    if !settings.enabled {
+        info!("[version_retention] Entity versioning disabled; prune skipped");
+        return Ok(report);
+    }
+    settings.validate()?;
+
+    if let Some(days) = settings.max_age_days {
+        for purger in purgers {
+            let count = purger.prune_older_than_days(days).await?;
+            info!(
+                "[version_retention] Pruned {count} versions older than {days} days from {}",
+                purger.repository_name()
+            );
+            report.pruned_by_age += count;
+        }
+    }
+
+    if let Some(keep) = settings.max_versions {
+        for purger in purgers {
+            let count = purger.prune_keep_latest(keep).await?;
+            info!(
+                "[version_retention] Pruned {count} versions, kept latest {keep} per item from {}",
+                purger.repository_name()
+            );
+            report.pruned_by_count += count;
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// In-memory purger holding version numbers for a single item; `current` is never pruned
+    struct MemoryPurger {
+        versions: Mutex<Vec<i32>>,
+        current: i32,
+    }
+
+    #[async_trait]
+    impl VersionPurger for MemoryPurger {
+        fn repository_name(&self) -> &'static str {
+            "memory"
+        }
+
+        async fn prune_older_than_days(&self, _days: i32) -> Result<u64> {
+            Ok(0)
+        }
+
+        async fn prune_keep_latest(&self, keep: i32) -> Result<u64> {
+            let mut versions = self.versions.lock().unwrap();
+            let before = versions.len();
+            let mut latest = versions.clone();
+            latest.sort_unstable_by(|a, b| b.cmp(a));
+            latest.truncate(usize::try_from(keep).unwrap_or(0));
+            let current = self.current;
+            versions.retain(|v| *v == current || latest.contains(v));
+            Ok((before - versions.len()) as u64)
+        }
+    }
+
+    fn purgers(current: i32) -> Vec<Box<dyn VersionPurger>> {
+        vec![Box::new(MemoryPurger {
+            versions: Mutex::new((1..=20).collect()),
+            current,
+        })]
+    }
+
+    #[tokio::test]
+    async fn disabled_settings_prune_nothing() {
+        let settings = EntityVersioningSettings {
+            enabled: false,
+            max_versions: Some(1),
+            max_age_days: Some(1),
+        };
+        let report = apply_retention(&purgers(20), &settings).await.unwrap();
+        assert_eq!(report.total(), 0);
+    }
+
+    #[tokio::test]
+    async fn count_retention_reports_deleted_versions() {
+        let settings = EntityVersioningSettings {
+            enabled: true,
+            max_versions: Some(5),
+            max_age_days: None,
+        };
+        let report = apply_retention(&purgers(20), &settings).await.unwrap();
+        assert_eq!(report.pruned_by_count, 15);
+        assert_eq!(report.pruned_by_age, 0);
+    }
+
+    #[tokio::test]
+    async fn count_retention_keeps_current_version_outside_latest() {
+        let settings = EntityVersioningSettings {
+            enabled: true,
+            max_versions: Some(5),
+            max_age_days: None,
+        };
+        let report = apply_retention(&purgers(3), &settings).await.unwrap();
+        // Versions 16..=20 are the latest five; version 3 survives as the current one
+        assert_eq!(report.pruned_by_count, 14);
+        assert_eq!(report.pruned_by_age, 0);
+    }
+
+    #[tokio::test]
+    async fn invalid_settings_are_rejected() {
+        let settings = EntityVersioningSettings {
+            enabled: true,
+            max_versions: Some(0),
+            max_age_days: None,
+        };
+        assert!(apply_retention(&purgers(20), &settings).await.is_err());
+    }
+}
@@ -0,0 +1,184 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::error::Result;
+
+/// Lifecycle state of a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationStatus {
+    /// Created, not yet delivered
+    Pending,
+    /// Delivered once its scheduled time has passed
+    Scheduled,
+    /// Delivered: listed in-app or e-mailed
+    Sent,
+    /// Withdrawn before it was sent
+    Cancelled,
+    /// Delivery failed; see `error_message`
+    Failed,
+}
+
+impl NotificationStatus {
+    /// Return the database representation of the status.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Scheduled => "scheduled",
+            Self::Sent => "sent",
+            Self::Cancelled => "cancelled",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl std::fmt::Display for NotificationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for NotificationStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "scheduled" => Ok(Self::Scheduled),
+            "sent" => Ok(Self::Sent),
+            "cancelled" => Ok(Self::Cancelled),
+            "failed" => Ok(Self::Failed),
+            other => Err(format!("Invalid notification status: {other}")),
+        }
+    }
+}
+
+/// How a notification reaches its user.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationType {
+    /// Listed in the admin UI / notification endpoints
+    #[default]
+    InApp,
+    /// Sent by e-mail to the user's address
+    Email,
+}
+
+impl NotificationType {
+    /// Return the database representation of the type.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::InApp => "in_app",
+            Self::Email => "email",
+        }
+    }
+}
+
+impl std::fmt::Display for NotificationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for NotificationType {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value {
+            "in_app" => Ok(Self::InApp),
+            "email" => Ok(Self::Email),
+            other => Err(format!("Invalid notification type: {other}")),
+        }
+    }
+}
+
+/// Notification addressed to an admin user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub uuid: Uuid,
+    pub user_uuid: Uuid,
+    pub notification_type: NotificationType,
+    pub title: String,
+    pub message: String,
+    /// Free-form context, e.g. the UUID of the workflow run the notification is about
+    pub data: Option<Value>,
+    pub status: NotificationStatus,
+    pub scheduled_for: Option<OffsetDateTime>,
+    pub sent_at: Option<OffsetDateTime>,
+    pub read_at: Option<OffsetDateTime>,
+    /// Error of the last failed delivery attempt
+    pub error_message: Option<String>,
+    /// Number of failed delivery attempts
+    pub retry_count: i32,
+    pub created_at: OffsetDateTime,
+}
+
+impl Notification {
+    #[must_use]
+    pub const fn is_read(&self) -> bool {
+        self.read_at.is_some()
+    }
+}
+
+/// Content of a notification to create
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewNotification {
+    pub user_uuid: Uuid,
+    #[serde(default)]
+    pub notification_type: NotificationType,
+    pub title: String,
+    pub message: String,
+    pub data: Option<Value>,
+}
+
+/// Creates, delivers and tracks notifications
+#[async_trait]
+pub trait NotificationManager: Send + Sync {
+    /// Create a notification and deliver it right away
+    ///
+    /// In-app notifications become visible to their user; e-mail notifications are sent when
+    /// an e-mail sender is configured and stay pending otherwise.
+    ///
+    /// # Errors
+    /// Returns a validation error for an empty title, or an error if storing it fails
+    async fn send_notification(&self, notification: NewNotification) -> Result<Notification>;
+
+    /// Create a notification that is delivered once `send_at` has passed
+    ///
+    /// # Errors
+    /// Returns a validation error for an empty title, or an error if storing it fails
+    async fn schedule_notification(
+        &self,
+        notification: NewNotification,
+        send_at: OffsetDateTime,
+    ) -> Result<Notification>;
+
+    /// Withdraw a notification that has not been sent yet
+    ///
+    /// # Errors
+    /// Returns `NotFound` if there is no pending or scheduled notification with this UUID
+    async fn cancel_notification(&self, uuid: Uuid) -> Result<()>;
+
+    /// Mark one of the user's sent notifications as read
+    ///
+    /// # Errors
+    /// Returns `NotFound` if the user has no sent notification with this UUID
+    async fn mark_as_read(&self, user_uuid: Uuid, uuid: Uuid) -> Result<()>;
+
+    /// Sent in-app notifications of a user, newest first; read ones only with `include_read`
+    ///
+    /// # Errors
+    /// Returns an error if the notifications cannot be loaded
+    async fn get_user_notifications(
+        &self,
+        user_uuid: Uuid,
+        include_read: bool,
+    ) -> Result<Vec<Notification>>;
+}
@@ -16,6 +16,12 @@ pub const WORKFLOW_PUSH_TOPIC: &str = "workflow.push.enqueue";
 /// Outbox message kind for workflow push deliveries.
 pub const WORKFLOW_PUSH_ENQUEUE_KIND: &str = "http.uri";
 
+/// Outbox message topic for entity field-change webhook deliveries.
+pub const ENTITY_WEBHOOK_TOPIC: &str = "entity.webhook.deliver";
+
+/// Outbox message kind for entity field-change webhook deliveries.
+pub const ENTITY_WEBHOOK_KIND: &str = "http.webhook";
+
 /// `PostgreSQL` notification channel used to wake the workflow outbox worker.
 pub const WORKFLOW_OUTBOX_NOTIFY_CHANNEL: &str = "workflow_outbox_available";
 
@@ -4,10 +4,13 @@ pub mod role;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::cache::CacheManager;
 use crate::error::Result;
-use role::{AccessLevel, Permission, PermissionType, ResourceNamespace};
+use role::{AccessLevel, Permission, PermissionType, ResourceNamespace, Role};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PermissionContext {
@@ -22,6 +25,11 @@ pub enum PermissionScope {
         entity_type: String,
         entity_uuid: Option<Uuid>,
     },
+    /// A single field of an entity type
+    EntityField {
+        entity_type: String,
+        field: String,
+    },
     Workflow {
         workflow_uuid: Option<Uuid>,
     },
@@ -35,16 +43,184 @@ pub trait PermissionRepository: Send + Sync {
 
 #[async_trait]
 pub trait PermissionChecker: Send + Sync {
+    /// Whether the context may perform `action` within `scope`
+    ///
+    /// For entity scopes, the permissions whose resource pattern matches the entity type
+    /// most specifically decide (see `ResourcePattern`).
     async fn is_allowed(
         &self,
         ctx: &PermissionContext,
         scope: &PermissionScope,
         action: &PermissionType,
     ) -> Result<bool>;
+
+    /// Return the subset of `fields` of `entity_type` the context may read, in the given order
+    ///
+    /// Field-level entries are optional: while none of the context's permissions restrict
+    /// fields of `entity_type`, every field is visible.
+    async fn filter_visible_fields(
+        &self,
+        ctx: &PermissionContext,
+        entity_type: &str,
+        fields: &[String],
+    ) -> Result<Vec<String>>;
+
+    /// Widest access level the context holds for `action` on entities of `entity_type`
+    ///
+    /// Only the permissions with the most specific matching resource pattern count, and
+    /// `Admin` permissions count for every action. Returns `AccessLevel::None` without a
+    /// matching permission.
+    async fn entity_access_level(
+        &self,
+        ctx: &PermissionContext,
+        entity_type: &str,
+        action: &PermissionType,
+    ) -> Result<AccessLevel>;
+}
+
+/// Permission repository over roles that are already loaded, e.g. the roles of the caller
+pub struct RolePermissionRepository {
+    roles: Vec<Role>,
+}
+
+impl RolePermissionRepository {
+    /// Create a repository over `roles`, looked up by role name
+    #[must_use]
+    pub const fn new(roles: Vec<Role>) -> Self {
+        Self { roles }
+    }
+}
+
+#[async_trait]
+impl PermissionRepository for RolePermissionRepository {
+    async fn get_permissions_for_roles(&self, roles: &[String]) -> Result<Vec<Permission>> {
+        Ok(self
+            .roles
+            .iter()
+            .filter(|role| roles.contains(&role.name))
+            .flat_map(|role| role.permissions.iter().cloned())
+            .collect())
+    }
+}
+
+/// Fields a permission is limited to via its `fields` constraint, if any
+///
+/// Field-level entries look like `{"entity_type": "employee", "fields": ["name", "email"]}`.
+fn constrained_fields(permission: &Permission) -> Option<Vec<&str>> {
+    permission
+        .constraints
+        .as_ref()
+        .and_then(|c| c.get("fields"))
+        .and_then(serde_json::Value::as_array)
+        .map(|fields| {
+            fields
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .collect()
+        })
+}
+
+/// How specifically `permission` covers entities of `entity_type`, if it covers them at all
+fn entity_type_specificity(permission: &Permission, entity_type: &str) -> Option<usize> {
+    if !matches!(permission.resource_type, ResourceNamespace::Entities) {
+        return None;
+    }
+    permission.resource_pattern().match_specificity(entity_type)
+}
+
+/// Whether `permission` is an `Entities` permission covering `entity_type`
+fn covers_entity_type(permission: &Permission, entity_type: &str) -> bool {
+    entity_type_specificity(permission, entity_type).is_some()
 }
 
+/// Permissions accepted by `filter` whose pattern matches `entity_type` most specifically
+///
+/// A permission for `entities:Customer` thereby overrides one for `entities:*`, including
+/// when it only grants `AccessLevel::None`.
+fn most_specific_for_entity_type<'a>(
+    perms: &'a [Permission],
+    entity_type: &str,
+    filter: impl Fn(&Permission) -> bool,
+) -> Vec<&'a Permission> {
+    let matching: Vec<(usize, &Permission)> = perms
+        .iter()
+        .filter(|p| filter(p))
+        .filter_map(|p| entity_type_specificity(p, entity_type).map(|s| (s, p)))
+        .collect();
+    let Some(best) = matching.iter().map(|(s, _)| *s).max() else {
+        return Vec::new();
+    };
+    matching
+        .into_iter()
+        .filter(|(s, _)| *s == best)
+        .map(|(_, p)| p)
+        .collect()
+}
+
+/// Whether `roles` allow `action` on entities of `entity_type`, optionally under `path`
+///
+/// Super admin roles allow everything and `Admin` permissions count for every action. Of the
+/// remaining permissions, those with the most specific resource pattern decide, so a
+/// permission for `entities:Customer` only covers `Customer` and a narrower permission with
+/// `AccessLevel::None` denies despite a broader grant.
+#[must_use]
+pub fn roles_allow_entity(
+    roles: &[Role],
+    entity_type: &str,
+    action: &PermissionType,
+    path: Option<&str>,
+) -> bool {
+    if roles.iter().any(|role| role.super_admin) {
+        return true;
+    }
+    let perms: Vec<Permission> = roles
+        .iter()
+        .flat_map(|role| role.permissions.iter().cloned())
+        .collect();
+    most_specific_for_entity_type(&perms, entity_type, |p| {
+        (&p.permission_type == action || p.permission_type == PermissionType::Admin)
+            && allows_path(p, path)
+    })
+    .iter()
+    .any(|p| p.access_level != AccessLevel::None)
+}
+
+/// Whether the `path` constraint of `permission` allows `path`; without a requested path only
+/// permissions without a path constraint apply
+fn allows_path(permission: &Permission, path: Option<&str>) -> bool {
+    path.map_or_else(
+        || {
+            permission
+                .constraints
+                .as_ref()
+                .and_then(|c| c.get("path"))
+                .is_none()
+        },
+        |path| Role::check_path_constraint(permission.constraints.as_ref(), path),
+    )
+}
+
+/// Whether `permission` grants `action` on `field` of `entity_type`
+fn grants_field(
+    permission: &Permission,
+    action: &PermissionType,
+    entity_type: &str,
+    field: &str,
+) -> bool {
+    &permission.permission_type == action
+        && covers_entity_type(permission, entity_type)
+        && constrained_fields(permission).is_none_or(|fields| fields.contains(&field))
+}
+
+/// Cache key prefix for permissions resolved per role set
+pub const ROLE_PERMISSIONS_CACHE_PREFIX: &str = "role_permissions:";
+
+/// Default TTL in seconds for permissions resolved per role set
+pub const ROLE_PERMISSIONS_CACHE_TTL_SECONDS: u64 = 60;
+
 pub struct DefaultPermissionService<R: PermissionRepository> {
     repository: R,
+    cache: Option<(Arc<CacheManager>, u64)>,
 }
 
 impl<R: PermissionRepository> DefaultPermissionService<R> {
@@ -54,7 +230,45 @@ impl<R: PermissionRepository> DefaultPermissionService<R> {
     /// * `repository` - Permission repository implementation
     #[must_use]
     pub const fn new(repository: R) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            cache: None,
+        }
+    }
+
+    /// Cache the permissions resolved for each role set for `ttl_seconds`
+    ///
+    /// Entries live under `ROLE_PERMISSIONS_CACHE_PREFIX`; role changes clear them all.
+    #[must_use]
+    pub fn with_cache(mut self, cache_manager: Arc<CacheManager>, ttl_seconds: u64) -> Self {
+        self.cache = Some((cache_manager, ttl_seconds));
+        self
+    }
+
+    /// Cache key for a role set, independent of role order and duplicates
+    fn cache_key(roles: &[String]) -> String {
+        let mut roles: Vec<&str> = roles.iter().map(String::as_str).collect();
+        roles.sort_unstable();
+        roles.dedup();
+        let digest = Sha256::digest(roles.join("\n").as_bytes());
+        format!("{ROLE_PERMISSIONS_CACHE_PREFIX}{}", hex::encode(digest))
+    }
+
+    /// Permissions of `roles`, read through the cache when enabled
+    async fn permissions_for(&self, roles: &[String]) -> Result<Vec<Permission>> {
+        let Some((cache, ttl)) = &self.cache else {
+            return self.repository.get_permissions_for_roles(roles).await;
+        };
+
+        let key = Self::cache_key(roles);
+        if let Ok(Some(cached)) = cache.get::<Vec<Permission>>(&key).await {
+            return Ok(cached);
+        }
+        let permissions = self.repository.get_permissions_for_roles(roles).await?;
+        if let Err(e) = cache.set(&key, &permissions, Some(*ttl)).await {
+            log::warn!("Failed to cache role permissions: {e}");
+        }
+        Ok(permissions)
     }
 }
 
@@ -66,33 +280,413 @@ impl<R: PermissionRepository> PermissionChecker for DefaultPermissionService<R>
         scope: &PermissionScope,
         action: &PermissionType,
     ) -> Result<bool> {
-        let perms = self
-            .repository
-            .get_permissions_for_roles(&ctx.roles)
-            .await?;
+        let perms = self.permissions_for(&ctx.roles).await?;
+        if let PermissionScope::EntityField { entity_type, field } = scope {
+            return Ok(perms
+                .iter()
+                .any(|p| grants_field(p, action, entity_type, field)));
+        }
         let matches_action = |p: &Permission| &p.permission_type == action;
-        let within_scope = |p: &Permission| {
-            matches!(
-                scope,
-                PermissionScope::System | PermissionScope::Workflow { .. }
-            ) || {
-                if let PermissionScope::Entity { entity_uuid, .. } = scope {
-                    // For entity scope, permission must be for Entities namespace
-                    // Note: The old code checked resource_type != "*" && resource_type != entity_type
-                    // With enum, we check if it's Entities namespace
-                    // Entity type matching would need to be done via constraints or resource_uuids
-                    if !matches!(p.resource_type, ResourceNamespace::Entities) {
-                        return false;
-                    }
-                    matches!(
-                        &p.access_level,
-                        AccessLevel::All | AccessLevel::Own | AccessLevel::Group
-                    ) || entity_uuid.is_none()
-                } else {
-                    false
-                }
+        if let PermissionScope::Entity { entity_type, .. } = scope {
+            // The most specific pattern decides, so a narrower `AccessLevel::None` denies
+            return Ok(
+                most_specific_for_entity_type(&perms, entity_type, matches_action)
+                    .iter()
+                    .any(|p| p.access_level != AccessLevel::None),
+            );
+        }
+        Ok(perms.iter().any(matches_action))
+    }
+
+    async fn filter_visible_fields(
+        &self,
+        ctx: &PermissionContext,
+        entity_type: &str,
+        fields: &[String],
+    ) -> Result<Vec<String>> {
+        let perms = self.permissions_for(&ctx.roles).await?;
+        let read = PermissionType::Read;
+        let restricted = perms.iter().any(|p| {
+            p.permission_type == read
+                && covers_entity_type(p, entity_type)
+                && constrained_fields(p).is_some()
+        });
+        if !restricted {
+            return Ok(fields.to_vec());
+        }
+        Ok(fields
+            .iter()
+            .filter(|field| {
+                perms
+                    .iter()
+                    .any(|p| grants_field(p, &read, entity_type, field))
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn entity_access_level(
+        &self,
+        ctx: &PermissionContext,
+        entity_type: &str,
+        action: &PermissionType,
+    ) -> Result<AccessLevel> {
+        let perms = self.permissions_for(&ctx.roles).await?;
+        Ok(most_specific_for_entity_type(&perms, entity_type, |p| {
+            &p.permission_type == action || p.permission_type == PermissionType::Admin
+        })
+        .into_iter()
+        .map(|p| p.access_level.clone())
+        .max()
+        .unwrap_or(AccessLevel::None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn read_entities(constraints: Option<serde_json::Value>) -> Permission {
+        Permission {
+            resource_type: ResourceNamespace::Entities,
+            permission_type: PermissionType::Read,
+            access_level: AccessLevel::All,
+            resource_uuids: vec![],
+            constraints,
+        }
+    }
+
+    fn role(name: &str, permissions: Vec<Permission>) -> Role {
+        let mut role = Role::new(name.to_string());
+        role.permissions = permissions;
+        role
+    }
+
+    fn service() -> DefaultPermissionService<RolePermissionRepository> {
+        DefaultPermissionService::new(RolePermissionRepository::new(vec![
+            role("manager", vec![read_entities(None)]),
+            role(
+                "staff",
+                vec![read_entities(Some(json!({
+                    "entity_type": "employee",
+                    "fields": ["name", "email"]
+                })))],
+            ),
+        ]))
+    }
+
+    fn ctx(roles: &[&str]) -> PermissionContext {
+        PermissionContext {
+            user_uuid: Uuid::now_v7(),
+            organization_uuid: None,
+            roles: roles.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    fn employee_fields() -> Vec<String> {
+        vec![
+            "name".to_string(),
+            "email".to_string(),
+            "salary".to_string(),
+        ]
+    }
+
+    #[tokio::test]
+    async fn filter_visible_fields_redacts_fields_outside_field_entries() {
+        let service = service();
+
+        let all = service
+            .filter_visible_fields(&ctx(&["manager"]), "employee", &employee_fields())
+            .await
+            .unwrap();
+        assert_eq!(all, employee_fields());
+
+        let redacted = service
+            .filter_visible_fields(&ctx(&["staff"]), "employee", &employee_fields())
+            .await
+            .unwrap();
+        assert_eq!(redacted, vec!["name".to_string(), "email".to_string()]);
+
+        // An unrestricted role grants every field on top of the field entries
+        let combined = service
+            .filter_visible_fields(&ctx(&["staff", "manager"]), "employee", &employee_fields())
+            .await
+            .unwrap();
+        assert_eq!(combined, employee_fields());
+
+        // Field entries only restrict the entity type they name
+        let other = service
+            .filter_visible_fields(&ctx(&["staff"]), "project", &["budget".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(other, vec!["budget".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn entity_access_level_returns_widest_matching_level() {
+        let mut own = read_entities(None);
+        own.access_level = AccessLevel::Own;
+        let mut admin = read_entities(Some(json!({ "entity_type": "project" })));
+        admin.permission_type = PermissionType::Admin;
+        let service = DefaultPermissionService::new(RolePermissionRepository::new(vec![
+            role("author", vec![own]),
+            role("project_admin", vec![admin]),
+        ]));
+        let level = |roles: &'static [&'static str], entity_type: &'static str| {
+            let service = &service;
+            async move {
+                service
+                    .entity_access_level(&ctx(roles), entity_type, &PermissionType::Read)
+                    .await
+                    .unwrap()
             }
         };
-        Ok(perms.iter().any(|p| matches_action(p) && within_scope(p)))
+
+        assert_eq!(level(&["author"], "project").await, AccessLevel::Own);
+        assert_eq!(
+            level(&["author", "project_admin"], "project").await,
+            AccessLevel::All
+        );
+        assert_eq!(
+            level(&["author", "project_admin"], "employee").await,
+            AccessLevel::Own
+        );
+        assert_eq!(
+            level(&["project_admin"], "employee").await,
+            AccessLevel::None
+        );
+    }
+
+    /// Repository counting how often permissions are loaded
+    struct CountingRepository {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl PermissionRepository for CountingRepository {
+        async fn get_permissions_for_roles(&self, _roles: &[String]) -> Result<Vec<Permission>> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(vec![read_entities(None)])
+        }
+    }
+
+    #[tokio::test]
+    async fn cached_service_loads_permissions_once_per_role_set() {
+        let cache = Arc::new(CacheManager::new(crate::config::CacheConfig::default()));
+        let service = DefaultPermissionService::new(CountingRepository {
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        })
+        .with_cache(cache.clone(), ROLE_PERMISSIONS_CACHE_TTL_SECONDS);
+        let scope = PermissionScope::Entity {
+            entity_type: "employee".to_string(),
+            entity_uuid: None,
+        };
+        let calls = || {
+            service
+                .repository
+                .calls
+                .load(std::sync::atomic::Ordering::SeqCst)
+        };
+
+        for roles in [
+            &["staff", "manager"][..],
+            &["manager", "staff", "staff"][..],
+        ] {
+            assert!(service
+                .is_allowed(&ctx(roles), &scope, &PermissionType::Read)
+                .await
+                .unwrap());
+        }
+        assert_eq!(calls(), 1);
+
+        service
+            .is_allowed(&ctx(&["staff"]), &scope, &PermissionType::Read)
+            .await
+            .unwrap();
+        assert_eq!(calls(), 2);
+
+        cache
+            .delete_by_prefix(ROLE_PERMISSIONS_CACHE_PREFIX)
+            .await
+            .unwrap();
+        service
+            .is_allowed(&ctx(&["manager", "staff"]), &scope, &PermissionType::Read)
+            .await
+            .unwrap();
+        assert_eq!(calls(), 3);
+    }
+
+    fn read(pattern: &str, access_level: AccessLevel) -> Permission {
+        Permission::for_resource(
+            &role::ResourcePattern::parse(pattern).unwrap(),
+            PermissionType::Read,
+            access_level,
+        )
+    }
+
+    fn entity_scope(entity_type: &str) -> PermissionScope {
+        PermissionScope::Entity {
+            entity_type: entity_type.to_string(),
+            entity_uuid: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn is_allowed_matches_exact_and_wildcard_resource_patterns() {
+        let service = DefaultPermissionService::new(RolePermissionRepository::new(vec![
+            role(
+                "customers",
+                vec![read("entities:Customer", AccessLevel::All)],
+            ),
+            role("crm", vec![read("entities:crm_*", AccessLevel::All)]),
+            role("everything", vec![read("entities:*", AccessLevel::All)]),
+        ]));
+        let allowed = |roles: &'static [&'static str], entity_type: &'static str| {
+            let service = &service;
+            async move {
+                service
+                    .is_allowed(
+                        &ctx(roles),
+                        &entity_scope(entity_type),
+                        &PermissionType::Read,
+                    )
+                    .await
+                    .unwrap()
+            }
+        };
+
+        assert!(allowed(&["customers"], "Customer").await);
+        assert!(!allowed(&["customers"], "CustomerNote").await);
+        assert!(allowed(&["crm"], "crm_lead").await);
+        assert!(!allowed(&["crm"], "Customer").await);
+        assert!(allowed(&["everything"], "Invoice").await);
+        assert!(allowed(&["customers", "crm"], "crm_lead").await);
+    }
+
+    #[tokio::test]
+    async fn more_specific_deny_overrides_broad_allow() {
+        let service = DefaultPermissionService::new(RolePermissionRepository::new(vec![
+            role("reader", vec![read("entities:*", AccessLevel::All)]),
+            role(
+                "no_payroll",
+                vec![
+                    read("entities:payroll_*", AccessLevel::None),
+                    read("entities:payroll_summary", AccessLevel::All),
+                ],
+            ),
+        ]));
+        let allowed = |entity_type: &'static str| {
+            let service = &service;
+            async move {
+                service
+                    .is_allowed(
+                        &ctx(&["reader", "no_payroll"]),
+                        &entity_scope(entity_type),
+                        &PermissionType::Read,
+                    )
+                    .await
+                    .unwrap()
+            }
+        };
+
+        assert!(allowed("employee").await);
+        assert!(!allowed("payroll_run").await);
+        assert!(allowed("payroll_summary").await);
+        assert_eq!(
+            service
+                .entity_access_level(
+                    &ctx(&["reader", "no_payroll"]),
+                    "payroll_run",
+                    &PermissionType::Read
+                )
+                .await
+                .unwrap(),
+            AccessLevel::None
+        );
+    }
+
+    #[test]
+    fn roles_allow_entity_resolves_most_specific_pattern() {
+        let mut admin = read("entities:crm_*", AccessLevel::All);
+        admin.permission_type = PermissionType::Admin;
+        let roles = [
+            role(
+                "customers",
+                vec![read("entities:Customer", AccessLevel::All)],
+            ),
+            role(
+                "no_payroll",
+                vec![
+                    read("entities:*", AccessLevel::All),
+                    read("entities:payroll", AccessLevel::None),
+                ],
+            ),
+            role("crm_admin", vec![admin]),
+        ];
+        let allowed = |names: &[&str], entity_type: &str, action: &PermissionType| {
+            let roles: Vec<Role> = roles
+                .iter()
+                .filter(|role| names.contains(&role.name.as_str()))
+                .cloned()
+                .collect();
+            roles_allow_entity(&roles, entity_type, action, None)
+        };
+
+        assert!(allowed(&["customers"], "Customer", &PermissionType::Read));
+        assert!(!allowed(&["customers"], "Invoice", &PermissionType::Read));
+        assert!(!allowed(
+            &["customers"],
+            "Customer",
+            &PermissionType::Update
+        ));
+        assert!(allowed(&["no_payroll"], "Invoice", &PermissionType::Read));
+        assert!(!allowed(&["no_payroll"], "payroll", &PermissionType::Read));
+        assert!(!allowed(
+            &["no_payroll", "customers"],
+            "payroll",
+            &PermissionType::Read
+        ));
+        assert!(allowed(&["crm_admin"], "crm_lead", &PermissionType::Delete));
+        assert!(!allowed(
+            &["crm_admin"],
+            "Customer",
+            &PermissionType::Delete
+        ));
+
+        let mut super_admin = Role::new("root".to_string());
+        super_admin.super_admin = true;
+        assert!(roles_allow_entity(
+            &[super_admin],
+            "payroll",
+            &PermissionType::Delete,
+            None
+        ));
+    }
+
+    #[tokio::test]
+    async fn is_allowed_checks_entity_field_scope() {
+        let service = service();
+        let scope = |field: &str| PermissionScope::EntityField {
+            entity_type: "employee".to_string(),
+            field: field.to_string(),
+        };
+
+        assert!(service
+            .is_allowed(&ctx(&["staff"]), &scope("name"), &PermissionType::Read)
+            .await
+            .unwrap());
+        assert!(!service
+            .is_allowed(&ctx(&["staff"]), &scope("salary"), &PermissionType::Read)
+            .await
+            .unwrap());
+        assert!(!service
+            .is_allowed(&ctx(&["staff"]), &scope("name"), &PermissionType::Update)
+            .await
+            .unwrap());
+        assert!(service
+            .is_allowed(&ctx(&["manager"]), &scope("salary"), &PermissionType::Read)
+            .await
+            .unwrap());
     }
 }
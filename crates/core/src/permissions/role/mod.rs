@@ -49,7 +49,11 @@ impl Display for PermissionType {
 }
 
 /// Access level for a permission
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema, TS)]
+///
+/// Levels are ordered from narrowest (`None`) to widest (`All`).
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema, TS,
+)]
 pub enum AccessLevel {
     /// No access granted
     None,
@@ -158,10 +162,119 @@ pub struct Permission {
     pub resource_uuids: Vec<Uuid>,
 
     /// Additional constraints on this permission
-    /// For entities namespace, can contain: {"path": "/projects"} for path-based permissions
+    /// For entities namespace, can contain: {"path": "/projects"} for path-based permissions,
+    /// or `{"entity_type": "employee", "fields": ["name"]}` to limit reads to specific fields.
+    /// `entity_type` also accepts wildcard names, see `ResourcePattern`
     pub constraints: Option<serde_json::Value>,
 }
 
+impl Permission {
+    /// Create a permission for the resources matched by `pattern`
+    ///
+    /// Named entity patterns are stored as the `entity_type` constraint.
+    #[must_use]
+    pub fn for_resource(
+        pattern: &ResourcePattern,
+        permission_type: PermissionType,
+        access_level: AccessLevel,
+    ) -> Self {
+        let constraints = (pattern.name != ResourcePattern::WILDCARD)
+            .then(|| serde_json::json!({ "entity_type": pattern.name }));
+        Self {
+            resource_type: pattern.namespace.clone(),
+            permission_type,
+            access_level,
+            resource_uuids: vec![],
+            constraints,
+        }
+    }
+
+    /// Pattern of the resources this permission applies to
+    ///
+    /// Entities permissions are narrowed by their `entity_type` constraint; every other
+    /// permission covers its whole namespace.
+    #[must_use]
+    pub fn resource_pattern(&self) -> ResourcePattern {
+        let name = match self.resource_type {
+            ResourceNamespace::Entities => self
+                .constraints
+                .as_ref()
+                .and_then(|c| c.get("entity_type"))
+                .and_then(serde_json::Value::as_str),
+            _ => None,
+        };
+        ResourcePattern {
+            namespace: self.resource_type.clone(),
+            name: name.unwrap_or(ResourcePattern::WILDCARD).to_string(),
+        }
+    }
+}
+
+/// Resources a permission applies to, written as `{namespace}:{name}`
+///
+/// The name is an exact resource name (`entities:Customer`), a prefix followed by `*`
+/// (`entities:crm_*`) or `*` alone for the whole namespace (`entities:*`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResourcePattern {
+    /// Namespace the pattern applies to
+    pub namespace: ResourceNamespace,
+
+    /// Resource name, prefix pattern or wildcard
+    pub name: String,
+}
+
+impl ResourcePattern {
+    /// Name matching every resource of the namespace
+    pub const WILDCARD: &'static str = "*";
+
+    /// Parse a pattern such as `entities:Customer`
+    ///
+    /// A bare namespace (`entities`) is the same as `entities:*`.
+    ///
+    /// # Errors
+    /// Returns a validation error for unknown namespaces, empty names, a `*` anywhere but
+    /// at the end, or named patterns outside the entities namespace
+    pub fn parse(pattern: &str) -> Result<Self> {
+        let (namespace, name) = pattern.split_once(':').unwrap_or((pattern, Self::WILDCARD));
+        let namespace = ResourceNamespace::try_from_str(namespace).ok_or_else(|| {
+            Error::Validation(format!("Unknown resource namespace in pattern '{pattern}'"))
+        })?;
+        let name = name.trim();
+        if name.is_empty() || name.trim_end_matches('*').contains('*') {
+            return Err(Error::Validation(format!(
+                "Invalid resource name in pattern '{pattern}'"
+            )));
+        }
+        if name != Self::WILDCARD && namespace != ResourceNamespace::Entities {
+            return Err(Error::Validation(format!(
+                "Named resources are only supported for entities, got '{pattern}'"
+            )));
+        }
+        Ok(Self {
+            namespace,
+            name: name.to_string(),
+        })
+    }
+
+    /// How specifically the pattern matches `name`, or `None` if it does not match
+    ///
+    /// Exact names rank above every wildcard, and longer prefixes above shorter ones, so
+    /// `entities:*` ranks lowest.
+    #[must_use]
+    pub fn match_specificity(&self, name: &str) -> Option<usize> {
+        self.name.strip_suffix('*').map_or_else(
+            || (self.name == name).then_some(usize::MAX),
+            |prefix| name.starts_with(prefix).then_some(prefix.len()),
+        )
+    }
+}
+
+impl Display for ResourcePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.namespace, self.name)
+    }
+}
+
 /// Entity for defining a role
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Role {
@@ -284,7 +397,9 @@ impl Role {
     /// `true` if the role has the permission, `false` otherwise
     ///
     /// # Note
-    /// If `super_admin` is true, this always returns `true`.
+    /// If `super_admin` is true, this always returns `true`. Permissions with access level
+    /// `None` and entity permissions narrowed to an entity type never grant the whole
+    /// namespace; check those with [`roles_allow_entity`](crate::permissions::roles_allow_entity).
     ///
     /// # Examples
     /// ```
@@ -324,29 +439,35 @@ impl Role {
             return true;
         }
 
+        // Only granting permissions that cover the whole namespace count: entity permissions
+        // narrowed to an entity type are resolved by `roles_allow_entity`
+        let namespace_permissions = || {
+            self.permissions.iter().filter(|p| {
+                p.resource_type == *namespace
+                    && p.access_level != AccessLevel::None
+                    && p.resource_pattern().name == ResourcePattern::WILDCARD
+            })
+        };
+
         // Resource-level admin: Check if role has Admin permission for this namespace
         // Admin permission grants all permission types for the namespace
-        let has_admin_for_namespace = self.permissions.iter().any(|p| {
-            p.resource_type == *namespace && matches!(p.permission_type, PermissionType::Admin)
-        });
+        let has_admin_for_namespace =
+            namespace_permissions().any(|p| matches!(p.permission_type, PermissionType::Admin));
 
         if has_admin_for_namespace {
             // For entities namespace with Admin, still need to check path constraints if provided
             if matches!(namespace, ResourceNamespace::Entities) {
                 if let Some(requested_path) = path {
                     // Check if any Admin permission for this namespace allows the path
-                    return self.permissions.iter().any(|p| {
-                        p.resource_type == *namespace
-                            && matches!(p.permission_type, PermissionType::Admin)
+                    return namespace_permissions().any(|p| {
+                        matches!(p.permission_type, PermissionType::Admin)
                             && Self::check_path_constraint(p.constraints.as_ref(), requested_path)
                     });
                 }
                 // If no path provided but Admin permission has path constraint, deny access
                 // (Admin with path constraint only grants access when path matches)
-                if self.permissions.iter().any(|p| {
-                    p.resource_type == *namespace
-                        && matches!(p.permission_type, PermissionType::Admin)
-                        && p.constraints.is_some()
+                if namespace_permissions().any(|p| {
+                    matches!(p.permission_type, PermissionType::Admin) && p.constraints.is_some()
                 }) {
                     return false;
                 }
@@ -355,12 +476,7 @@ impl Role {
         }
 
         // Exact permission match
-        self.permissions.iter().any(|p| {
-            // Check namespace matches
-            if p.resource_type != *namespace {
-                return false;
-            }
-
+        namespace_permissions().any(|p| {
             // Check permission type matches
             if p.permission_type != *permission_type {
                 return false;
@@ -445,19 +561,32 @@ impl Role {
     /// Get all permissions formatted as namespace strings
     ///
     /// # Returns
-    /// Vector of permission strings in format: `"{namespace}:{permission_type}"` or
-    /// `"{namespace}:{path}:{permission_type}"` for entities with path constraints
+    /// Vector of permission strings in format: `"{namespace}:{permission_type}"`,
+    /// `"{namespace}:{path}:{permission_type}"` for entities with path constraints or
+    /// `"{namespace}:{entity_type}:{permission_type}"` for entities narrowed to an entity type.
+    /// Permissions with access level `None` grant nothing and are left out.
     ///
     /// # Examples
     /// - `["workflows:read", "workflows:create"]`
     /// - `["entities:/projects:read", "entities:/projects:delete"]`
+    /// - `["entities:Customer:read"]`
     #[must_use]
     pub fn get_permissions_as_strings(&self) -> Vec<String> {
         let mut result = Vec::new();
         for perm in &self.permissions {
+            if perm.access_level == AccessLevel::None {
+                continue;
+            }
             let perm_str = format!("{}", perm.permission_type);
             let perm_str_lower = perm_str.to_lowercase();
 
+            // Entity type patterns never start with `/`, so they cannot be taken for a path
+            let pattern = perm.resource_pattern();
+            if pattern.name != ResourcePattern::WILDCARD {
+                result.push(format!("{pattern}:{perm_str_lower}"));
+                continue;
+            }
+
             // For entities namespace, include path in permission string if present
             if matches!(perm.resource_type, ResourceNamespace::Entities) {
                 if let Some(constraints) = &perm.constraints {
@@ -385,3 +385,79 @@ fn test_admin_permission_with_entities_path_constraint() {
     // Should NOT have permissions without path (when Admin has path constraint)
     assert!(!role.has_permission(&ResourceNamespace::Entities, &PermissionType::Read, None));
 }
+
+#[test]
+fn test_resource_pattern_parse_and_specificity() {
+    let exact = ResourcePattern::parse("entities:Customer").unwrap();
+    let prefix = ResourcePattern::parse("entities:Cust*").unwrap();
+    let wildcard = ResourcePattern::parse("entities").unwrap();
+    assert_eq!(wildcard.to_string(), "entities:*");
+    assert_eq!(exact.to_string(), "entities:Customer");
+
+    assert_eq!(exact.match_specificity("Customer"), Some(usize::MAX));
+    assert_eq!(exact.match_specificity("CustomerNote"), None);
+    assert_eq!(prefix.match_specificity("CustomerNote"), Some(4));
+    assert_eq!(prefix.match_specificity("Invoice"), None);
+    assert_eq!(wildcard.match_specificity("Invoice"), Some(0));
+
+    assert!(ResourcePattern::parse("unknown:Customer").is_err());
+    assert!(ResourcePattern::parse("entities:").is_err());
+    assert!(ResourcePattern::parse("entities:*_archive").is_err());
+    assert!(ResourcePattern::parse("workflows:import").is_err());
+    assert!(ResourcePattern::parse("workflows:*").is_ok());
+}
+
+#[test]
+fn test_permission_resource_pattern_round_trip() {
+    let pattern = ResourcePattern::parse("entities:crm_*").unwrap();
+    let permission = Permission::for_resource(&pattern, PermissionType::Read, AccessLevel::All);
+    assert_eq!(
+        permission.constraints,
+        Some(serde_json::json!({ "entity_type": "crm_*" }))
+    );
+    assert_eq!(permission.resource_pattern(), pattern);
+
+    let wildcard = ResourcePattern::parse("entities:*").unwrap();
+    let permission = Permission::for_resource(&wildcard, PermissionType::Read, AccessLevel::All);
+    assert_eq!(permission.constraints, None);
+    assert_eq!(permission.resource_pattern(), wildcard);
+}
+
+#[test]
+fn test_narrowed_and_denying_permissions_do_not_grant_namespace() {
+    let pattern = |p: &str| ResourcePattern::parse(p).unwrap();
+    let mut role = Role::new("Customers".to_string());
+    role.permissions = vec![
+        Permission::for_resource(
+            &pattern("entities:Customer"),
+            PermissionType::Read,
+            AccessLevel::All,
+        ),
+        Permission::for_resource(
+            &pattern("entities:Customer"),
+            PermissionType::Admin,
+            AccessLevel::All,
+        ),
+        Permission::for_resource(
+            &pattern("entities:*"),
+            PermissionType::Delete,
+            AccessLevel::None,
+        ),
+    ];
+
+    for permission_type in [PermissionType::Read, PermissionType::Delete] {
+        assert!(!role.has_permission(&ResourceNamespace::Entities, &permission_type, None));
+        assert!(!role.has_permission(
+            &ResourceNamespace::Entities,
+            &permission_type,
+            Some("/projects")
+        ));
+    }
+    assert_eq!(
+        role.get_permissions_as_strings(),
+        vec![
+            "entities:Customer:read".to_string(),
+            "entities:Customer:admin".to_string()
+        ]
+    );
+}
@@ -72,4 +72,7 @@ pub struct AdvancedEntityQuery {
     pub offset: Option<i64>,
     pub sort_by: Option<String>,
     pub sort_direction: Option<String>,
+    /// `default`, `ci` (case-insensitive) or `ci_ai` (case- and accent-insensitive) ordering
+    /// of a text `sort_by` field; other field types ignore it
+    pub collation: Option<String>,
 }
@@ -0,0 +1,96 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::future::Future;
+
+/// Header carrying the request correlation ID
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longest accepted incoming request ID
+pub const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Request ID of the request handled by the current task, if any
+///
+/// Only set inside futures run through [`with_request_id`], i.e. while the API handles a
+/// request; tasks spawned from there do not inherit it.
+#[must_use]
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Run `future` with `request_id` as the current request ID
+pub async fn with_request_id<F: Future>(request_id: String, future: F) -> F::Output {
+    REQUEST_ID.scope(request_id, future).await
+}
+
+/// Call `f` synchronously with `request_id` as the current request ID
+pub fn call_with_request_id<R>(request_id: String, f: impl FnOnce() -> R) -> R {
+    REQUEST_ID.sync_scope(request_id, f)
+}
+
+/// Whether an incoming request ID is safe to adopt (non-empty, bounded, visible ASCII)
+#[must_use]
+pub fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LENGTH
+        && value.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Add the current request ID to workflow run log metadata
+///
+/// Object metadata gets a `request_id` key (unless it already has one); missing metadata
+/// becomes `{"request_id": ...}`. Other metadata is returned unchanged.
+#[must_use]
+pub fn annotate_meta(meta: Option<serde_json::Value>) -> Option<serde_json::Value> {
+    let Some(request_id) = current_request_id() else {
+        return meta;
+    };
+    match meta {
+        None => Some(serde_json::json!({ "request_id": request_id })),
+        Some(serde_json::Value::Object(mut map)) => {
+            map.entry("request_id")
+                .or_insert(serde_json::Value::String(request_id));
+            Some(serde_json::Value::Object(map))
+        }
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_request_id_is_scoped_to_future() {
+        assert_eq!(current_request_id(), None);
+        let inside = with_request_id("abc".to_string(), async { current_request_id() }).await;
+        assert_eq!(inside.as_deref(), Some("abc"));
+        assert_eq!(current_request_id(), None);
+    }
+
+    #[tokio::test]
+    async fn test_annotate_meta() {
+        assert_eq!(annotate_meta(None), None);
+        with_request_id("abc".to_string(), async {
+            assert_eq!(annotate_meta(None), Some(json!({"request_id": "abc"})));
+            assert_eq!(
+                annotate_meta(Some(json!({"items": 3}))),
+                Some(json!({"items": 3, "request_id": "abc"}))
+            );
+            assert_eq!(annotate_meta(Some(json!([1]))), Some(json!([1])));
+        })
+        .await;
+    }
+
+    #[test]
+    fn test_is_valid_request_id() {
+        assert!(is_valid_request_id("0190f0e4-7c2a-7000-8000-000000000000"));
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id("with space"));
+        assert!(!is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LENGTH + 1)));
+    }
+}
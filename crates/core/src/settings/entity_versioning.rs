@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Error, Result};
+
 /// Entity versioning configuration settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EntityVersioningSettings {
@@ -22,3 +24,50 @@ impl Default for EntityVersioningSettings {
         }
     }
 }
+
+impl EntityVersioningSettings {
+    /// Validate the retention limits
+    ///
+    /// # Errors
+    /// Returns `Error::Validation` if `max_versions` or `max_age_days` is less than 1
+    pub fn validate(&self) -> Result<()> {
+        if self.max_versions.is_some_and(|n| n < 1) {
+            return Err(Error::Validation(
+                "max_versions must be at least 1".to_string(),
+            ));
+        }
+        if self.max_age_days.is_some_and(|d| d < 1) {
+            return Err(Error::Validation(
+                "max_age_days must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_settings_are_valid() {
+        assert!(EntityVersioningSettings::default().validate().is_ok());
+    }
+
+    #[test]
+    fn non_positive_limits_are_rejected() {
+        let zero_versions = EntityVersioningSettings {
+            enabled: true,
+            max_versions: Some(0),
+            max_age_days: None,
+        };
+        assert!(zero_versions.validate().is_err());
+
+        let negative_days = EntityVersioningSettings {
+            enabled: true,
+            max_versions: None,
+            max_age_days: Some(-1),
+        };
+        assert!(negative_days.validate().is_err());
+    }
+}
@@ -0,0 +1,210 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+/// Most fields a list can be sorted by at once
+pub const MAX_SORT_FIELDS: usize = 5;
+
+/// Direction of one sort field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    /// Parse `asc` or `desc` (any case)
+    ///
+    /// # Errors
+    /// Returns an error for any other value
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_uppercase().as_str() {
+            "ASC" => Ok(Self::Asc),
+            "DESC" => Ok(Self::Desc),
+            _ => Err(format!(
+                "Invalid sort direction: '{value}'. Must be 'asc' or 'desc'"
+            )),
+        }
+    }
+
+    #[must_use]
+    pub const fn as_sql(self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+
+    #[must_use]
+    pub const fn is_descending(self) -> bool {
+        matches!(self, Self::Desc)
+    }
+}
+
+/// One field of a list sort
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortField {
+    pub field: String,
+    pub direction: SortDirection,
+}
+
+impl SortField {
+    /// The field as a quoted SQL identifier
+    #[must_use]
+    pub fn quoted_field(&self) -> String {
+        format!("\"{}\"", self.field.replace('"', "\"\""))
+    }
+}
+
+/// Parse a `sort_by` value into its fields
+///
+/// `sort_by` is a single field (`name`) or a comma-separated list with optional per-field
+/// directions (`status:asc,created_at:desc`). Fields without a direction use `sort_order`,
+/// which defaults to ascending. Field names are not checked against any table here.
+///
+/// # Errors
+/// Returns an error for empty or repeated fields, invalid directions or more than
+/// [`MAX_SORT_FIELDS`] fields
+pub fn parse_sort(sort_by: &str, sort_order: Option<&str>) -> Result<Vec<SortField>, String> {
+    let default_direction = sort_order
+        .map(SortDirection::parse)
+        .transpose()?
+        .unwrap_or(SortDirection::Asc);
+
+    let mut fields: Vec<SortField> = Vec::new();
+    for part in sort_by.split(',') {
+        let (field, direction) = match part.split_once(':') {
+            Some((field, direction)) => (field.trim(), SortDirection::parse(direction)?),
+            None => (part.trim(), default_direction),
+        };
+        if field.is_empty() {
+            return Err(format!("Invalid sort: '{sort_by}' contains an empty field"));
+        }
+        if fields.iter().any(|sort| sort.field == field) {
+            return Err(format!("Invalid sort: field '{field}' is repeated"));
+        }
+        fields.push(SortField {
+            field: field.to_string(),
+            direction,
+        });
+    }
+    if fields.len() > MAX_SORT_FIELDS {
+        return Err(format!(
+            "Invalid sort: at most {MAX_SORT_FIELDS} fields can be sorted by"
+        ));
+    }
+    Ok(fields)
+}
+
+/// SQL `ORDER BY` list for a validated `sort_by`/`sort_order`, one `term` per field
+///
+/// Returns `None` without `sort_by` or when it does not parse; callers then use their default
+/// order. Field names must have been validated before they reach `term`.
+pub fn order_by_clause(
+    sort_by: Option<&str>,
+    sort_order: Option<&str>,
+    term: impl Fn(&SortField) -> String,
+) -> Option<String> {
+    let fields = parse_sort(sort_by?, sort_order).ok()?;
+    Some(fields.iter().map(term).collect::<Vec<_>>().join(", "))
+}
+
+/// How text columns are compared when sorting
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortCollation {
+    /// Database ordering (byte order, so `Zebra` sorts before `apple`)
+    #[default]
+    Default,
+    /// Case-insensitive
+    Ci,
+    /// Case- and accent-insensitive
+    CiAi,
+}
+
+impl SortCollation {
+    /// Parse `default`, `ci` or `ci_ai` (any case)
+    ///
+    /// # Errors
+    /// Returns an error for any other value
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "default" => Ok(Self::Default),
+            "ci" => Ok(Self::Ci),
+            "ci_ai" => Ok(Self::CiAi),
+            _ => Err(format!(
+                "Invalid collation: '{value}'. Must be 'default', 'ci' or 'ci_ai'"
+            )),
+        }
+    }
+
+    /// Sort expression for a validated text `column`
+    #[must_use]
+    pub fn apply(self, column: &str) -> String {
+        match self {
+            Self::Default => column.to_string(),
+            Self::Ci => format!("lower({column})"),
+            Self::CiAi => format!("lower(unaccent({column}))"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sort(field: &str, direction: SortDirection) -> SortField {
+        SortField {
+            field: field.to_string(),
+            direction,
+        }
+    }
+
+    #[test]
+    fn parses_single_and_multiple_fields() {
+        assert_eq!(
+            parse_sort("name", Some("desc")).unwrap(),
+            vec![sort("name", SortDirection::Desc)]
+        );
+        assert_eq!(
+            parse_sort("status:asc, created_at:DESC, name", Some("desc")).unwrap(),
+            vec![
+                sort("status", SortDirection::Asc),
+                sort("created_at", SortDirection::Desc),
+                sort("name", SortDirection::Desc),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_sorts() {
+        assert!(parse_sort("status:up", None).is_err());
+        assert!(parse_sort("status,", None).is_err());
+        assert!(parse_sort("status,status:desc", None).is_err());
+        assert!(parse_sort("name", Some("sideways")).is_err());
+        assert!(parse_sort("a,b,c,d,e,f", None).is_err());
+    }
+
+    #[test]
+    fn builds_order_by_clause() {
+        assert_eq!(
+            order_by_clause(Some("status,created_at:desc"), None, |sort| format!(
+                "{} {}",
+                sort.quoted_field(),
+                sort.direction.as_sql()
+            ))
+            .as_deref(),
+            Some("\"status\" ASC, \"created_at\" DESC")
+        );
+        assert!(order_by_clause(None, Some("desc"), |_| String::new()).is_none());
+    }
+
+    #[test]
+    fn parses_and_applies_collation() {
+        assert_eq!(SortCollation::parse("CI"), Ok(SortCollation::Ci));
+        assert_eq!(SortCollation::parse("ci_ai"), Ok(SortCollation::CiAi));
+        assert_eq!(SortCollation::parse("default"), Ok(SortCollation::Default));
+        assert!(SortCollation::parse("binary").is_err());
+
+        assert_eq!(SortCollation::Default.apply("name"), "name");
+        assert_eq!(SortCollation::Ci.apply("name"), "lower(name)");
+        assert_eq!(SortCollation::CiAi.apply("name"), "lower(unaccent(name))");
+    }
+}
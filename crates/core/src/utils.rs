@@ -3,6 +3,13 @@
 use chrono::{DateTime, Utc};
 use cron::Schedule;
 use std::str::FromStr;
+use time::OffsetDateTime;
+
+/// Timezone cron schedules are evaluated in (the worker scheduler runs on UTC)
+pub const CRON_TIMEZONE: &str = "UTC";
+
+/// Upper bound for the number of occurrences returned by a cron preview
+pub const MAX_CRON_PREVIEW_COUNT: usize = 20;
 
 /// Validate a cron expression
 ///
@@ -36,3 +43,33 @@ pub fn preview_next(expr: &str, count: usize) -> Result<Vec<String>, String> {
         .collect();
     Ok(times)
 }
+
+/// Compute the next occurrences of a cron schedule strictly after `after`
+///
+/// Occurrences are evaluated in [`CRON_TIMEZONE`] and `count` is capped at
+/// [`MAX_CRON_PREVIEW_COUNT`]. Schedules that never fire again yield an empty list.
+///
+/// # Arguments
+/// * `expr` - The cron expression
+/// * `after` - Point in time to start searching from
+/// * `count` - Number of occurrences to compute
+///
+/// # Errors
+/// Returns an error if the cron expression is invalid or `after` is out of range
+pub fn preview_runs_after(
+    expr: &str,
+    after: OffsetDateTime,
+    count: usize,
+) -> Result<Vec<OffsetDateTime>, String> {
+    let schedule = Schedule::from_str(expr).map_err(|e| format!("Invalid cron expression: {e}"))?;
+    let start = DateTime::<Utc>::from_timestamp(after.unix_timestamp(), after.nanosecond())
+        .ok_or_else(|| format!("Start time out of range: {after}"))?;
+    schedule
+        .after(&start)
+        .take(count.min(MAX_CRON_PREVIEW_COUNT))
+        .map(|dt: DateTime<Utc>| {
+            OffsetDateTime::from_unix_timestamp(dt.timestamp())
+                .map_err(|e| format!("Occurrence out of range: {e}"))
+        })
+        .collect()
+}
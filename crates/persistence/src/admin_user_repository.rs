@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use log::error;
 use r_data_core_core::admin_user::AdminUser;
 use r_data_core_core::error::Result;
+use r_data_core_core::sort::order_by_clause;
 use sqlx::{Pool, Postgres};
 use std::sync::Arc;
 use time::OffsetDateTime;
@@ -316,6 +317,41 @@ impl AdminUserRepositoryTrait for AdminUserRepository {
         Ok(())
     }
 
+    async fn update_totp(&self, uuid: &Uuid, secret: Option<String>, enabled: bool) -> Result<()> {
+        sqlx::query(
+            "UPDATE admin_users SET totp_secret = $1, totp_enabled = $2, totp_last_used_step = NULL, updated_at = NOW() WHERE uuid = $3",
+        )
+        .bind(secret)
+        .bind(enabled)
+        .bind(uuid)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| {
+            error!("Error updating TOTP settings: {e:?}");
+            r_data_core_core::error::Error::Database(e)
+        })?;
+
+        Ok(())
+    }
+
+    async fn record_totp_step(&self, uuid: &Uuid, step: i64) -> Result<bool> {
+        // Conditional update so concurrent logins cannot both use the same code
+        let result = sqlx::query(
+            "UPDATE admin_users SET totp_last_used_step = $1
+             WHERE uuid = $2 AND (totp_last_used_step IS NULL OR totp_last_used_step < $1)",
+        )
+        .bind(step)
+        .bind(uuid)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| {
+            error!("Error recording TOTP step: {e:?}");
+            r_data_core_core::error::Error::Database(e)
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     async fn list_admin_users(
         &self,
         limit: i64,
@@ -324,32 +360,19 @@ impl AdminUserRepositoryTrait for AdminUserRepository {
         sort_order: Option<String>,
     ) -> Result<Vec<AdminUser>> {
         // Build ORDER BY clause - field is already validated and sanitized by route handler
-        let order_by = match sort_by.as_deref() {
-            // Virtual field: sort by number of roles assigned to the user
-            Some("roles") => {
-                let order = sort_order
-                    .as_ref()
-                    .map(|o| o.to_uppercase())
-                    .filter(|o| o == "ASC" || o == "DESC")
-                    .unwrap_or_else(|| "ASC".to_string());
+        let order_by = order_by_clause(sort_by.as_deref(), sort_order.as_deref(), |sort| {
+            let order = sort.direction.as_sql();
+            if sort.field == "roles" {
+                // Virtual field: sort by number of roles assigned to the user
                 format!(
                     "(SELECT COUNT(*) FROM user_roles ur WHERE ur.user_uuid = admin_users.uuid) {order}"
                 )
+            } else {
+                // Field name is validated, but we still quote it for safety
+                format!("{} {order}", sort.quoted_field())
             }
-            _ => sort_by.map_or_else(
-                || "\"created_at\" DESC".to_string(),
-                |field| {
-                    // Field name is validated, but we still quote it for safety
-                    let quoted_field = format!("\"{}\"", field.replace('"', "\"\""));
-                    let order = sort_order
-                        .as_ref()
-                        .map(|o| o.to_uppercase())
-                        .filter(|o| o == "ASC" || o == "DESC")
-                        .unwrap_or_else(|| "ASC".to_string());
-                    format!("{quoted_field} {order}")
-                },
-            ),
-        };
+        })
+        .unwrap_or_else(|| "\"created_at\" DESC".to_string());
 
         // Build query with or without LIMIT
         let query = if limit == i64::MAX {
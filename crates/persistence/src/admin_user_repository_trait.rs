@@ -66,13 +66,15 @@ pub trait ApiKeyRepositoryTrait: Send + Sync {
     /// Get an API key by its hash value
     async fn get_by_hash(&self, api_key: &str) -> Result<Option<ApiKey>>;
 
-    /// Create a new API key with full details
+    /// Create a new API key with full details; empty `scopes` leave the key unrestricted
     async fn create_new_api_key(
         &self,
         name: &str,
         description: &str,
         created_by: Uuid,
         expires_in_days: i32,
+        scopes: &[String],
+        allowed_cidrs: &[String],
     ) -> Result<(Uuid, String)>;
 
     /// Update an API key's last used timestamp
@@ -81,6 +83,14 @@ pub trait ApiKeyRepositoryTrait: Send + Sync {
     /// Reassign an API key to a different user
     async fn reassign(&self, uuid: Uuid, new_user_uuid: Uuid) -> Result<()>;
 
+    /// Set an API key's requests-per-minute limit (`None` for unlimited)
+    async fn update_rate_limit(&self, uuid: Uuid, rate_limit_per_minute: Option<i32>)
+        -> Result<()>;
+
+    /// Set when an API key expires (`None` for never)
+    async fn update_expires_at(&self, uuid: Uuid, expires_at: Option<OffsetDateTime>)
+        -> Result<()>;
+
     /// Get all roles assigned to an API key
     async fn get_api_key_roles(&self, api_key_uuid: Uuid) -> Result<Vec<Uuid>>;
 
@@ -116,6 +126,16 @@ pub trait AdminUserRepositoryTrait: Send + Sync {
     /// Delete an admin user
     async fn delete_admin_user(&self, uuid: &Uuid) -> Result<()>;
 
+    /// Store a user's TOTP secret and whether codes are required at login
+    ///
+    /// Also forgets the last accepted code, so a new secret starts fresh.
+    async fn update_totp(&self, uuid: &Uuid, secret: Option<String>, enabled: bool) -> Result<()>;
+
+    /// Record `step` as the time step of the user's last accepted TOTP code
+    ///
+    /// Returns `false` without changes if a code of this or a later step was already accepted.
+    async fn record_totp_step(&self, uuid: &Uuid, step: i64) -> Result<bool>;
+
     /// List admin users with pagination and sorting
     ///
     /// # Arguments
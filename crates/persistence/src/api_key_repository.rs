@@ -5,7 +5,8 @@ use async_trait::async_trait;
 use log::{debug, error};
 use r_data_core_core::admin_user::ApiKey;
 use r_data_core_core::error::Result;
-use sqlx::{Pool, Postgres};
+use r_data_core_core::sort::order_by_clause;
+use sqlx::{PgExecutor, Pool, Postgres, Transaction};
 use std::sync::Arc;
 use time::{Duration, OffsetDateTime};
 use uuid::Uuid;
@@ -117,10 +118,30 @@ impl ApiKeyRepository {
             r_data_core_core::error::Error::Database(e)
         })?;
 
+        Self::update_api_key_roles_tx(&mut tx, api_key_uuid, role_uuids).await?;
+
+        // Commit transaction
+        tx.commit().await.map_err(|e| {
+            error!("Error committing transaction: {e:?}");
+            r_data_core_core::error::Error::Database(e)
+        })?;
+
+        Ok(())
+    }
+
+    /// Update all roles for an API key within a caller-owned transaction
+    ///
+    /// # Errors
+    /// Returns an error if a database operation fails
+    pub async fn update_api_key_roles_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        api_key_uuid: Uuid,
+        role_uuids: &[Uuid],
+    ) -> Result<()> {
         // Delete all existing assignments
         sqlx::query("DELETE FROM api_key_roles WHERE api_key_uuid = $1")
             .bind(api_key_uuid)
-            .execute(&mut *tx)
+            .execute(&mut **tx)
             .await
             .map_err(|e| {
                 error!("Error deleting existing roles: {e:?}");
@@ -132,7 +153,7 @@ impl ApiKeyRepository {
             sqlx::query("INSERT INTO api_key_roles (api_key_uuid, role_uuid) VALUES ($1, $2)")
                 .bind(api_key_uuid)
                 .bind(role_uuid)
-                .execute(&mut *tx)
+                .execute(&mut **tx)
                 .await
                 .map_err(|e| {
                     error!("Error assigning role: {e:?}");
@@ -140,14 +161,87 @@ impl ApiKeyRepository {
                 })?;
         }
 
-        // Commit transaction
-        tx.commit().await.map_err(|e| {
-            error!("Error committing transaction: {e:?}");
+        Ok(())
+    }
+
+    /// Create a new API key within a caller-owned transaction
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn create_tx(tx: &mut Transaction<'_, Postgres>, key: &ApiKey) -> Result<Uuid> {
+        insert_key(&mut **tx, key).await
+    }
+
+    /// Set when an API key expires within a caller-owned transaction
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn update_expires_at_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        uuid: Uuid,
+        expires_at: Option<OffsetDateTime>,
+    ) -> Result<()> {
+        set_expires_at(&mut **tx, uuid, expires_at).await
+    }
+}
+
+/// Insert an API key row
+async fn insert_key<'e, E: PgExecutor<'e>>(executor: E, key: &ApiKey) -> Result<Uuid> {
+    sqlx::query_scalar(
+        "
+        INSERT INTO api_keys
+        (uuid, user_uuid, key_hash, name, description, is_active, created_at, expires_at, created_by, published, scopes, rate_limit_per_minute, allowed_cidrs)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        RETURNING uuid
+        ",
+    )
+    .bind(key.uuid)
+    .bind(key.user_uuid)
+    .bind(&key.key_hash)
+    .bind(&key.name)
+    .bind(&key.description)
+    .bind(key.is_active)
+    .bind(key.created_at)
+    .bind(key.expires_at)
+    .bind(key.created_by)
+    .bind(key.published)
+    .bind(&key.scopes)
+    .bind(key.rate_limit_per_minute)
+    .bind(&key.allowed_cidrs)
+    .fetch_one(executor)
+    .await
+    .map_err(|e| {
+        // Log foreign key constraint violations at debug level since they're often expected in tests
+        if let sqlx::Error::Database(db_err) = &e {
+            if db_err.code().as_deref() == Some("23503") {
+                debug!("Foreign key constraint violation creating API key (expected in some tests): {e:?}");
+            } else {
+                error!("Error creating API key: {e:?}");
+            }
+        } else {
+            error!("Error creating API key: {e:?}");
+        }
+        r_data_core_core::error::Error::Database(e)
+    })
+}
+
+/// Update the expiry of an API key row
+async fn set_expires_at<'e, E: PgExecutor<'e>>(
+    executor: E,
+    uuid: Uuid,
+    expires_at: Option<OffsetDateTime>,
+) -> Result<()> {
+    sqlx::query("UPDATE api_keys SET expires_at = $1 WHERE uuid = $2")
+        .bind(expires_at)
+        .bind(uuid)
+        .execute(executor)
+        .await
+        .map_err(|e| {
+            error!("Error updating API key expiry: {e:?}");
             r_data_core_core::error::Error::Database(e)
         })?;
 
-        Ok(())
-    }
+    Ok(())
 }
 
 #[async_trait]
@@ -207,41 +301,7 @@ impl ApiKeyRepositoryTrait for ApiKeyRepository {
 
     /// Create a new API key
     async fn create(&self, key: &ApiKey) -> Result<Uuid> {
-        let result = sqlx::query!(
-            "
-            INSERT INTO api_keys 
-            (uuid, user_uuid, key_hash, name, description, is_active, created_at, expires_at, created_by, published)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            RETURNING uuid
-            ",
-            key.uuid,
-            key.user_uuid,
-            key.key_hash,
-            key.name,
-            key.description,
-            key.is_active,
-            key.created_at,
-            key.expires_at,
-            key.created_by,
-            key.published
-        )
-        .fetch_one(&*self.pool)
-        .await
-        .map_err(|e| {
-            // Log foreign key constraint violations at debug level since they're often expected in tests
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.code().as_deref() == Some("23503") {
-                    debug!("Foreign key constraint violation creating API key (expected in some tests): {e:?}");
-                } else {
-                    error!("Error creating API key: {e:?}");
-                }
-            } else {
-                error!("Error creating API key: {e:?}");
-            }
-            r_data_core_core::error::Error::Database(e)
-        })?;
-
-        Ok(result.uuid)
+        insert_key(&*self.pool, key).await
     }
 
     /// List all API keys for a user
@@ -254,24 +314,16 @@ impl ApiKeyRepositoryTrait for ApiKeyRepository {
         sort_order: Option<String>,
     ) -> Result<Vec<ApiKey>> {
         // Build ORDER BY clause - field is already validated and sanitized by route handler
-        let order_by = sort_by.map_or_else(
-            || "\"created_at\" DESC".to_string(),
-            |field| {
-                let quoted_field = format!("\"{}\"", field.replace('"', "\"\""));
-                let order = sort_order
-                    .as_ref()
-                    .map(|o| o.to_uppercase())
-                    .filter(|o| o == "ASC" || o == "DESC")
-                    .unwrap_or_else(|| "ASC".to_string());
-
-                // Handle NULL values for last_used_at and expires_at
-                if field == "last_used_at" || field == "expires_at" {
-                    format!("{quoted_field} {order} NULLS LAST")
-                } else {
-                    format!("{quoted_field} {order}")
-                }
-            },
-        );
+        let order_by = order_by_clause(sort_by.as_deref(), sort_order.as_deref(), |sort| {
+            let order = sort.direction.as_sql();
+            // Handle NULL values for last_used_at and expires_at
+            if sort.field == "last_used_at" || sort.field == "expires_at" {
+                format!("{} {order} NULLS LAST", sort.quoted_field())
+            } else {
+                format!("{} {order}", sort.quoted_field())
+            }
+        })
+        .unwrap_or_else(|| "\"created_at\" DESC".to_string());
 
         // Build query with or without LIMIT
         let query = if limit == -1 {
@@ -375,6 +427,8 @@ impl ApiKeyRepositoryTrait for ApiKeyRepository {
         description: &str,
         created_by: Uuid,
         expires_in_days: i32,
+        scopes: &[String],
+        allowed_cidrs: &[String],
     ) -> Result<(Uuid, String)> {
         // Validate input parameters
         if name.trim().is_empty() {
@@ -402,23 +456,25 @@ impl ApiKeyRepositoryTrait for ApiKeyRepository {
             None
         };
 
-        let result = sqlx::query!(
+        let result: Uuid = sqlx::query_scalar(
             "
-            INSERT INTO api_keys 
-            (user_uuid, key_hash, name, description, is_active, created_at, expires_at, created_by, published)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            INSERT INTO api_keys
+            (user_uuid, key_hash, name, description, is_active, created_at, expires_at, created_by, published, scopes, allowed_cidrs)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             RETURNING uuid
             ",
-            created_by,  // Use the creator as the owner initially
-            key_hash,
-            name,
-            description,
-            true,        // Active by default
-            created_at,
-            expires_at,
-            created_by,
-            true         // Published by default
         )
+        .bind(created_by) // Use the creator as the owner initially
+        .bind(key_hash)
+        .bind(name)
+        .bind(description)
+        .bind(true) // Active by default
+        .bind(created_at)
+        .bind(expires_at)
+        .bind(created_by)
+        .bind(true) // Published by default
+        .bind(scopes)
+        .bind(allowed_cidrs)
         .fetch_one(&*self.pool)
         .await
         .map_err(|e| {
@@ -435,7 +491,7 @@ impl ApiKeyRepositoryTrait for ApiKeyRepository {
             r_data_core_core::error::Error::Database(e)
         })?;
 
-        Ok((result.uuid, key_value))
+        Ok((result, key_value))
     }
 
     /// Update an API key's last used timestamp
@@ -474,6 +530,34 @@ impl ApiKeyRepositoryTrait for ApiKeyRepository {
         Ok(())
     }
 
+    /// Set an API key's requests-per-minute limit
+    async fn update_rate_limit(
+        &self,
+        uuid: Uuid,
+        rate_limit_per_minute: Option<i32>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE api_keys SET rate_limit_per_minute = $1 WHERE uuid = $2")
+            .bind(rate_limit_per_minute)
+            .bind(uuid)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| {
+                error!("Error updating API key rate limit: {e:?}");
+                r_data_core_core::error::Error::Database(e)
+            })?;
+
+        Ok(())
+    }
+
+    /// Set when an API key expires
+    async fn update_expires_at(
+        &self,
+        uuid: Uuid,
+        expires_at: Option<OffsetDateTime>,
+    ) -> Result<()> {
+        set_expires_at(&*self.pool, uuid, expires_at).await
+    }
+
     /// Get all roles assigned to an API key
     async fn get_api_key_roles(&self, api_key_uuid: Uuid) -> Result<Vec<Uuid>> {
         Self::get_api_key_roles(self, api_key_uuid).await
@@ -0,0 +1,162 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use uuid::Uuid;
+
+use crate::audit_log_repository_trait::{AuditLogFilter, AuditLogRepositoryTrait};
+use r_data_core_core::audit_log::{AuditAction, AuditLogEntry};
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::system_log::SystemLogResourceType;
+
+/// Repository for audit log operations
+pub struct AuditLogRepository {
+    pool: PgPool,
+}
+
+impl AuditLogRepository {
+    /// Create a new audit log repository
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Decode an `AuditLogEntry` from a raw `sqlx::postgres::PgRow`
+fn row_to_audit_log_entry(
+    row: &sqlx::postgres::PgRow,
+) -> std::result::Result<AuditLogEntry, sqlx::Error> {
+    Ok(AuditLogEntry {
+        uuid: row.try_get("uuid")?,
+        created_at: row.try_get("created_at")?,
+        actor_uuid: row.try_get("actor_uuid")?,
+        action: row.try_get("action")?,
+        resource_type: row.try_get("resource_type")?,
+        resource_uuid: row.try_get("resource_uuid")?,
+        before: row.try_get("before")?,
+        after: row.try_get("after")?,
+    })
+}
+
+#[async_trait]
+impl AuditLogRepositoryTrait for AuditLogRepository {
+    async fn insert(
+        &self,
+        actor_uuid: Option<Uuid>,
+        action: AuditAction,
+        resource_type: SystemLogResourceType,
+        resource_uuid: Uuid,
+        before: Option<serde_json::Value>,
+        after: Option<serde_json::Value>,
+    ) -> Result<Uuid> {
+        sqlx::query_scalar(
+            r"
+            INSERT INTO audit_log (actor_uuid, action, resource_type, resource_uuid, before, after)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING uuid
+            ",
+        )
+        .bind(actor_uuid)
+        .bind(action)
+        .bind(resource_type)
+        .bind(resource_uuid)
+        .bind(before)
+        .bind(after)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(Error::Database)
+    }
+
+    async fn list_paginated(
+        &self,
+        limit: i64,
+        offset: i64,
+        filter: &AuditLogFilter,
+    ) -> Result<(Vec<AuditLogEntry>, i64)> {
+        // Build a dynamic WHERE clause
+        let mut conditions: Vec<String> = Vec::new();
+        let mut param_index: i32 = 1;
+
+        for (is_set, column) in [
+            (filter.actor_uuid.is_some(), "actor_uuid = "),
+            (filter.resource_type.is_some(), "resource_type = "),
+            (filter.resource_uuid.is_some(), "resource_uuid = "),
+            (filter.date_from.is_some(), "created_at >= "),
+            (filter.date_to.is_some(), "created_at <= "),
+        ] {
+            if is_set {
+                conditions.push(format!("{column}${param_index}"));
+                param_index += 1;
+            }
+        }
+
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+
+        let limit_param = param_index;
+        let offset_param = param_index + 1;
+
+        let data_query = format!(
+            r"
+            SELECT uuid, created_at, actor_uuid, action, resource_type,
+                   resource_uuid, before, after
+            FROM audit_log
+            {where_clause}
+            ORDER BY created_at DESC
+            LIMIT ${limit_param} OFFSET ${offset_param}
+            "
+        );
+
+        let count_query = format!("SELECT COUNT(*) FROM audit_log {where_clause}");
+
+        // Bind parameters in the same order for both queries
+        macro_rules! bind_filters {
+            ($q:expr) => {{
+                let mut q = $q;
+                if let Some(v) = filter.actor_uuid {
+                    q = q.bind(v);
+                }
+                if let Some(ref v) = filter.resource_type {
+                    q = q.bind(v.clone());
+                }
+                if let Some(v) = filter.resource_uuid {
+                    q = q.bind(v);
+                }
+                if let Some(v) = filter.date_from {
+                    q = q.bind(v);
+                }
+                if let Some(v) = filter.date_to {
+                    q = q.bind(v);
+                }
+                q
+            }};
+        }
+
+        let count_row = bind_filters!(sqlx::query(&count_query))
+            .fetch_one(&self.pool)
+            .await
+            .map_err(Error::Database)?;
+        let total: i64 = count_row.try_get(0).map_err(Error::Database)?;
+
+        let rows = bind_filters!(sqlx::query(&data_query))
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(Error::Database)?;
+
+        let entries = rows
+            .iter()
+            .map(row_to_audit_log_entry)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(Error::Database)?;
+
+        Ok((entries, total))
+    }
+}
@@ -0,0 +1,49 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use r_data_core_core::audit_log::{AuditAction, AuditLogEntry};
+use r_data_core_core::error::Result;
+use r_data_core_core::system_log::SystemLogResourceType;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Filter criteria for querying the audit log
+#[derive(Debug, Default, Clone)]
+pub struct AuditLogFilter {
+    pub actor_uuid: Option<Uuid>,
+    pub resource_type: Option<SystemLogResourceType>,
+    pub resource_uuid: Option<Uuid>,
+    pub date_from: Option<OffsetDateTime>,
+    pub date_to: Option<OffsetDateTime>,
+}
+
+/// Trait for audit log repository operations
+#[async_trait]
+pub trait AuditLogRepositoryTrait: Send + Sync {
+    /// Insert a new audit log entry
+    ///
+    /// # Errors
+    /// Returns an error if the database insert fails
+    async fn insert(
+        &self,
+        actor_uuid: Option<Uuid>,
+        action: AuditAction,
+        resource_type: SystemLogResourceType,
+        resource_uuid: Uuid,
+        before: Option<serde_json::Value>,
+        after: Option<serde_json::Value>,
+    ) -> Result<Uuid>;
+
+    /// List audit log entries, newest first, with pagination and optional filters
+    ///
+    /// Returns a tuple of `(entries, total_count)`.
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    async fn list_paginated(
+        &self,
+        limit: i64,
+        offset: i64,
+        filter: &AuditLogFilter,
+    ) -> Result<(Vec<AuditLogEntry>, i64)>;
+}
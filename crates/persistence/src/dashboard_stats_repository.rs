@@ -6,6 +6,7 @@ use crate::dashboard_stats_repository_trait::{
     DashboardStats, DashboardStatsRepositoryTrait, EntityStats, EntityTypeCount, WorkflowStats,
     WorkflowWithLatestStatus,
 };
+use crate::workflow_repository::query_run_statistics;
 use r_data_core_core::error::Result;
 
 /// Repository for dashboard statistics
@@ -84,7 +85,7 @@ async fn get_entity_stats(pool: &PgPool) -> Result<EntityStats> {
     Ok(EntityStats { total, by_type })
 }
 
-/// Get workflow statistics (count and latest run statuses)
+/// Get workflow statistics (count, latest run statuses and run statistics across all workflows)
 async fn get_workflow_stats(pool: &PgPool) -> Result<WorkflowStats> {
     // Single query using DISTINCT ON to get latest run status for each workflow
     let rows = sqlx::query!(
@@ -119,7 +120,13 @@ async fn get_workflow_stats(pool: &PgPool) -> Result<WorkflowStats> {
         })
         .collect();
 
-    Ok(WorkflowStats { total, workflows })
+    let runs = query_run_statistics(pool, None).await?;
+
+    Ok(WorkflowStats {
+        total,
+        workflows,
+        runs,
+    })
 }
 
 /// Get online users count (users with active refresh tokens)
@@ -3,6 +3,7 @@
 use async_trait::async_trait;
 
 use r_data_core_core::error::Result;
+use r_data_core_workflow::data::WorkflowRunStats;
 
 /// Entity count by type
 #[derive(Debug, Clone)]
@@ -40,6 +41,8 @@ pub struct WorkflowStats {
     pub total: i64,
     /// List of workflows with their latest run status
     pub workflows: Vec<WorkflowWithLatestStatus>,
+    /// Run statistics across all workflows
+    pub runs: WorkflowRunStats,
 }
 
 /// Dashboard statistics
@@ -0,0 +1,95 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Primary database pool with optional read replicas.
+//!
+//! Writes always go to the primary. Read-only repository operations take a pool from
+//! [`Database::read`], which rotates over the replicas and falls back to the primary when
+//! none are configured. Replicas may lag behind the primary.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use sqlx::PgPool;
+
+/// Database pools for writes and reads
+#[derive(Debug, Clone)]
+pub struct Database {
+    primary: PgPool,
+    replicas: Arc<[PgPool]>,
+    next_replica: Arc<AtomicUsize>,
+}
+
+impl Database {
+    /// Route reads and writes to `primary`
+    #[must_use]
+    pub fn new(primary: PgPool) -> Self {
+        Self {
+            primary,
+            replicas: Arc::from([]),
+            next_replica: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Route reads to `replicas`; an empty list keeps reads on the primary
+    #[must_use]
+    pub fn with_read_replicas(mut self, replicas: Vec<PgPool>) -> Self {
+        self.replicas = Arc::from(replicas);
+        self
+    }
+
+    /// Pool for writes and reads that must see the latest data
+    #[must_use]
+    pub const fn primary(&self) -> &PgPool {
+        &self.primary
+    }
+
+    /// Pool for read-only queries, rotating over the replicas
+    #[must_use]
+    pub fn read(&self) -> &PgPool {
+        if self.replicas.is_empty() {
+            return &self.primary;
+        }
+        let index = self.next_replica.fetch_add(1, Ordering::Relaxed) % self.replicas.len();
+        &self.replicas[index]
+    }
+
+    /// Whether reads go to at least one replica
+    #[must_use]
+    pub fn has_read_replicas(&self) -> bool {
+        !self.replicas.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lazy_pool(database: &str) -> PgPool {
+        PgPool::connect_lazy(&format!("postgres://localhost/{database}")).unwrap()
+    }
+
+    fn database_name(pool: &PgPool) -> Option<String> {
+        pool.connect_options().get_database().map(String::from)
+    }
+
+    #[tokio::test]
+    async fn reads_fall_back_to_the_primary() {
+        let database = Database::new(lazy_pool("primary"));
+        assert!(!database.has_read_replicas());
+        assert_eq!(database_name(database.read()).as_deref(), Some("primary"));
+    }
+
+    #[tokio::test]
+    async fn reads_rotate_over_the_replicas() {
+        let database = Database::new(lazy_pool("primary"))
+            .with_read_replicas(vec![lazy_pool("replica_a"), lazy_pool("replica_b")]);
+        let reads: Vec<_> = (0..3)
+            .map(|_| database_name(database.read()).unwrap_or_default())
+            .collect();
+        assert_eq!(reads, ["replica_a", "replica_b", "replica_a"]);
+        assert_eq!(
+            database_name(database.primary()).as_deref(),
+            Some("primary")
+        );
+    }
+}
@@ -1,12 +1,16 @@
 use log::{debug, error};
 use serde_json::Value as JsonValue;
-use sqlx::{postgres::PgRow, Column, Row};
+use sqlx::{
+    postgres::{types::PgInterval, PgRow},
+    Column, Row,
+};
 use std::collections::HashMap;
 use std::sync::Arc;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
 use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::field::duration::IsoDuration;
 use r_data_core_core::DynamicEntity;
 
 /// Extract an integer field value from a database row
@@ -110,6 +114,22 @@ fn extract_date_field(row: &PgRow, column_name: &str) -> JsonValue {
         )
 }
 
+/// Extract an interval field value from a database row as an ISO-8601 duration
+fn extract_interval_field(row: &PgRow, column_name: &str) -> JsonValue {
+    row.try_get::<Option<PgInterval>, _>(column_name)
+        .map_or_else(
+            |_| {
+                debug!("Failed to extract interval value for column: {column_name}");
+                JsonValue::Null
+            },
+            |value| {
+                value.map_or(JsonValue::Null, |v| {
+                    JsonValue::String(IsoDuration::from(v).to_string())
+                })
+            },
+        )
+}
+
 /// Extract a JSON field value from a database row
 fn extract_json_field(row: &PgRow, column_name: &str) -> JsonValue {
     row.try_get::<Option<JsonValue>, _>(column_name)
@@ -161,6 +181,8 @@ pub fn extract_field_data(row: &PgRow) -> HashMap<String, JsonValue> {
             "timestamp" | "timestamptz" => extract_timestamp_field(row, column_name),
             // Date types
             "date" => extract_date_field(row, column_name),
+            // Durations
+            "interval" => extract_interval_field(row, column_name),
             // JSON types
             "json" | "jsonb" => extract_json_field(row, column_name),
             // Handle unsupported types
@@ -217,6 +239,14 @@ pub fn map_row_to_entity(
     ];
 
     for (db_column_name, value) in &field_data {
+        // Only surface the soft-delete marker on deleted rows (listed with `include_deleted`)
+        if db_column_name == "deleted_at" {
+            if !value.is_null() {
+                mapped_field_data.insert(db_column_name.clone(), value.clone());
+            }
+            continue;
+        }
+
         // Check if this is a system field - if so, keep it as-is
         if system_fields.contains(&db_column_name.as_str()) {
             mapped_field_data.insert(db_column_name.clone(), value.clone());
@@ -249,3 +279,14 @@ pub fn map_row_to_entity(
         entity_def.clone(),
     )
 }
+
+/// Remove definition fields outside `visible_fields` from an entity, e.g. fields a role may not read
+///
+/// System fields are always kept.
+pub fn retain_visible_fields(entity: &mut DynamicEntity, visible_fields: &[String]) {
+    for field_def in &entity.definition.fields {
+        if !visible_fields.contains(&field_def.name) {
+            entity.field_data.remove(&field_def.name);
+        }
+    }
+}
@@ -75,14 +75,14 @@ fn normalize_path(raw_path: &str) -> String {
 async fn query_paths(db_pool: &PgPool, prefix: &str) -> Result<Vec<RowRec>> {
     if prefix == "/" {
         sqlx::query_as::<_, RowRec>(
-            "SELECT uuid, entity_type, path, entity_key, published FROM entities_registry WHERE path = '/' OR path LIKE '/%'",
+            "SELECT uuid, entity_type, path, entity_key, published FROM entities_registry WHERE deleted_at IS NULL AND (path = '/' OR path LIKE '/%')",
         )
         .fetch_all(db_pool)
         .await
         .map_err(Into::into)
     } else {
         sqlx::query_as::<_, RowRec>(
-            "SELECT uuid, entity_type, path, entity_key, published FROM entities_registry WHERE path = $1 OR path LIKE $1 || '/%'",
+            "SELECT uuid, entity_type, path, entity_key, published FROM entities_registry WHERE deleted_at IS NULL AND (path = $1 OR path LIKE $1 || '/%')",
         )
         .bind(prefix)
         .fetch_all(db_pool)
@@ -266,7 +266,7 @@ async fn check_folder_children(
     }
 
     let query = format!(
-        "SELECT DISTINCT path FROM entities_registry WHERE {}",
+        "SELECT DISTINCT path FROM entities_registry WHERE deleted_at IS NULL AND ({})",
         query_parts.join(" OR ")
     );
 
@@ -302,7 +302,7 @@ async fn check_children(db_pool: &PgPool, nodes: &mut [BrowseNode]) -> Result<()
         HashSet::new()
     } else {
         sqlx::query_scalar::<_, Uuid>(
-            "SELECT DISTINCT parent_uuid FROM entities_registry WHERE parent_uuid = ANY($1::uuid[])",
+            "SELECT DISTINCT parent_uuid FROM entities_registry WHERE parent_uuid = ANY($1::uuid[]) AND deleted_at IS NULL",
         )
         .bind(&data.uuids_to_check)
         .fetch_all(db_pool)
@@ -317,7 +317,7 @@ async fn check_children(db_pool: &PgPool, nodes: &mut [BrowseNode]) -> Result<()
         HashSet::new()
     } else {
         sqlx::query_scalar::<_, String>(
-            "SELECT DISTINCT path FROM entities_registry WHERE path = ANY($1::text[])",
+            "SELECT DISTINCT path FROM entities_registry WHERE path = ANY($1::text[]) AND deleted_at IS NULL",
         )
         .bind(&data.file_full_paths_to_check)
         .fetch_all(db_pool)
@@ -395,8 +395,9 @@ pub async fn search_by_path_prefix(
     let rows = sqlx::query_as::<_, RowRec>(
         r"SELECT uuid, entity_type, path, entity_key, published
           FROM entities_registry
-          WHERE (CASE WHEN path = '/' THEN '/' || entity_key ELSE path || '/' || entity_key END) ILIKE $1
-             OR entity_key ILIKE $2
+          WHERE deleted_at IS NULL
+            AND ((CASE WHEN path = '/' THEN '/' || entity_key ELSE path || '/' || entity_key END) ILIKE $1
+             OR entity_key ILIKE $2)
           ORDER BY path, entity_key
           LIMIT $3",
     )
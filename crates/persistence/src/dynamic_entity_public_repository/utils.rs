@@ -10,7 +10,7 @@ use sqlx::PgPool;
 /// # Errors
 /// Returns an error if the database query fails
 pub async fn get_entity_count(pool: &PgPool, entity_type: &str) -> Result<i64> {
-    let table_name = format!("entity_{}", entity_type.to_lowercase());
+    let table_name = crate::dynamic_entity_utils::get_table_name(pool, entity_type).await?;
 
     // Check if table exists first
     let table_exists: bool = sqlx::query_scalar!(
@@ -30,7 +30,10 @@ pub async fn get_entity_count(pool: &PgPool, entity_type: &str) -> Result<i64> {
         return Ok(0);
     }
 
-    let query = format!("SELECT COUNT(*) FROM \"{table_name}\"");
+    let query = format!(
+        "SELECT COUNT(*) FROM {}",
+        crate::dynamic_entity_utils::quote_ident(&table_name)?
+    );
     let count: i64 = sqlx::query_scalar(&query).fetch_one(pool).await?;
 
     Ok(count)
@@ -2,28 +2,48 @@
 
 use async_trait::async_trait;
 use log::debug;
+use serde_json::Value;
+use sqlx::postgres::PgArguments;
+use sqlx::query::QueryScalar;
+use sqlx::Postgres;
+use std::collections::HashMap;
 use std::fmt::Write;
 
 use crate::dynamic_entity_mapper;
 use crate::dynamic_entity_query_repository_trait::DynamicEntityQueryRepositoryTrait;
 use crate::dynamic_entity_utils;
+use crate::entity_relations::{load_child_relations, resolve_child_relation};
+use r_data_core_core::entity_definition::definition::EntityDefinition;
 use r_data_core_core::error::Result;
 use r_data_core_core::public_api::AdvancedEntityQuery;
+use r_data_core_core::sort::SortCollation;
 use r_data_core_core::DynamicEntity;
 use sqlx::PgPool;
+use uuid::Uuid;
 
 /// Repository for public API advanced query operations on dynamic entities
 ///
 /// Provides advanced querying capabilities for dynamic entity instances.
 pub struct DynamicEntityQueryRepository {
     db_pool: PgPool,
+    owner: Option<Uuid>,
 }
 
 impl DynamicEntityQueryRepository {
     /// Create a new dynamic entity query repository
     #[must_use]
     pub const fn new(db_pool: PgPool) -> Self {
-        Self { db_pool }
+        Self {
+            db_pool,
+            owner: None,
+        }
+    }
+
+    /// Only return entities created by `owner`, for callers limited to their own rows
+    #[must_use]
+    pub const fn with_owner(mut self, owner: Option<Uuid>) -> Self {
+        self.owner = owner;
+        self
     }
 
     /// Query dynamic entity instances with advanced filtering
@@ -34,26 +54,36 @@ impl DynamicEntityQueryRepository {
         &self,
         entity_type: &str,
         query: &AdvancedEntityQuery,
+    ) -> Result<Vec<DynamicEntity>> {
+        self.query_entities_with_counts(entity_type, query, &[])
+            .await
+    }
+
+    /// Query dynamic entity instances and add a `<relation>_count` field per requested relation
+    ///
+    /// Each relation names a `ManyToOne` field on another entity type that references
+    /// `entity_type` (by field name, referencing entity type, or `<entity_type>.<field>`).
+    /// Counts only include live (not soft-deleted) referencing entities.
+    ///
+    /// # Errors
+    /// Returns a validation error for unknown or ambiguous relations, or an error if the entity
+    /// type doesn't exist or the query fails
+    pub async fn query_entities_with_counts(
+        &self,
+        entity_type: &str,
+        query: &AdvancedEntityQuery,
+        include_counts: &[String],
     ) -> Result<Vec<DynamicEntity>> {
         // Get entity definition (no caching in this repository)
         let entity_def =
             dynamic_entity_utils::get_entity_definition(&self.db_pool, entity_type, None).await?;
 
         // Build the query
-        let view_name = dynamic_entity_utils::get_view_name(entity_type);
-
-        let mut sql = format!("SELECT * FROM {view_name}");
-        let mut params: Vec<String> = Vec::new();
-
-        // Add WHERE clause for filters
-        if let Some(filters) = &query.filter {
-            if !filters.is_empty() {
-                let (where_clause, filter_params) =
-                    dynamic_entity_utils::build_where_clause(filters, &entity_def);
-                let _ = write!(sql, " WHERE {where_clause}");
-                params = filter_params;
-            }
-        }
+        let count_columns = self
+            .relation_count_columns(entity_type, include_counts)
+            .await?;
+        let (from_clause, params) = self.filtered_from(&entity_def, query.filter.as_ref())?;
+        let mut sql = format!("SELECT v.*{count_columns} {from_clause}");
 
         // Add ORDER BY
         if let Some(sort_by) = &query.sort_by {
@@ -64,12 +94,15 @@ impl DynamicEntityQueryRepository {
                     "ASC"
                 }
             });
-            // Sanitize sort_by to prevent SQL injection (only allow alphanumeric and underscore)
-            if sort_by.chars().all(|c| c.is_alphanumeric() || c == '_') {
-                let _ = write!(sql, " ORDER BY {sort_by} {direction}");
-            } else {
-                sql.push_str(" ORDER BY created_at DESC");
-            }
+            let collation = query
+                .collation
+                .as_deref()
+                .map(SortCollation::parse)
+                .transpose()
+                .map_err(r_data_core_core::error::Error::Validation)?
+                .unwrap_or_default();
+            let sort_by = dynamic_entity_utils::sort_expression(&entity_def, sort_by, collation)?;
+            let _ = write!(sql, " ORDER BY {sort_by} {direction}");
         } else {
             sql.push_str(" ORDER BY created_at DESC");
         }
@@ -88,6 +121,9 @@ impl DynamicEntityQueryRepository {
         for param in &params {
             sql_query = sql_query.bind(param);
         }
+        if let Some(owner) = self.owner {
+            sql_query = sql_query.bind(owner);
+        }
 
         let rows = sql_query
             .fetch_all(&self.db_pool)
@@ -102,6 +138,135 @@ impl DynamicEntityQueryRepository {
 
         Ok(entities)
     }
+
+    /// Whether any live entity matches `filter`
+    ///
+    /// Filters are applied exactly as in [`Self::query_entities`]; the query stops at the first
+    /// matching row instead of fetching or counting all of them.
+    ///
+    /// # Errors
+    /// Returns an error if the entity type doesn't exist or the query fails
+    pub async fn exists(
+        &self,
+        entity_type: &str,
+        filter: Option<&HashMap<String, Value>>,
+    ) -> Result<bool> {
+        let (from_clause, params) = self.filtered_from_definition(entity_type, filter).await?;
+        let sql = exists_sql(&from_clause);
+        debug!("Executing exists query: {sql}");
+        self.bind_filter(sqlx::query_scalar(&sql), &params)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(r_data_core_core::error::Error::Database)
+    }
+
+    /// Number of live entities matching `filter`, filtered as in [`Self::query_entities`]
+    ///
+    /// # Errors
+    /// Returns an error if the entity type doesn't exist or the query fails
+    pub async fn count(
+        &self,
+        entity_type: &str,
+        filter: Option<&HashMap<String, Value>>,
+    ) -> Result<i64> {
+        let (from_clause, params) = self.filtered_from_definition(entity_type, filter).await?;
+        let sql = format!("SELECT COUNT(*) {from_clause}");
+        debug!("Executing count query: {sql}");
+        self.bind_filter(sqlx::query_scalar(&sql), &params)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(r_data_core_core::error::Error::Database)
+    }
+
+    async fn filtered_from_definition(
+        &self,
+        entity_type: &str,
+        filter: Option<&HashMap<String, Value>>,
+    ) -> Result<(String, Vec<String>)> {
+        let entity_def =
+            dynamic_entity_utils::get_entity_definition(&self.db_pool, entity_type, None).await?;
+        self.filtered_from(&entity_def, filter)
+    }
+
+    /// `FROM` and `WHERE` clauses selecting the live entities matching `filter` (and owned by
+    /// the owner, if set), with the filter parameters; the owner is bound after them
+    fn filtered_from(
+        &self,
+        entity_def: &EntityDefinition,
+        filter: Option<&HashMap<String, Value>>,
+    ) -> Result<(String, Vec<String>)> {
+        let view_name = dynamic_entity_utils::quoted_view_name(&entity_def.entity_type)?;
+        let mut sql = format!("FROM {view_name} v WHERE v.deleted_at IS NULL");
+        let mut params: Vec<String> = Vec::new();
+
+        // Add WHERE clause for filters
+        if let Some(filters) = filter {
+            if !filters.is_empty() {
+                let (where_clause, filter_params) =
+                    dynamic_entity_utils::build_where_clause(filters, entity_def)?;
+                let _ = write!(sql, " AND ({where_clause})");
+                params = filter_params;
+            }
+        }
+
+        // Row-level ownership: bound after the filter parameters
+        if self.owner.is_some() {
+            let _ = write!(sql, " AND v.created_by = ${}", params.len() + 1);
+        }
+        Ok((sql, params))
+    }
+
+    /// Bind the parameters of [`Self::filtered_from`] to a scalar query
+    fn bind_filter<'q, O>(
+        &self,
+        mut query: QueryScalar<'q, Postgres, O, PgArguments>,
+        params: &'q [String],
+    ) -> QueryScalar<'q, Postgres, O, PgArguments> {
+        for param in params {
+            query = query.bind(param);
+        }
+        if let Some(owner) = self.owner {
+            query = query.bind(owner);
+        }
+        query
+    }
+
+    /// Correlated count subqueries (with a leading comma) for the requested relations
+    async fn relation_count_columns(
+        &self,
+        entity_type: &str,
+        include_counts: &[String],
+    ) -> Result<String> {
+        if include_counts.is_empty() {
+            return Ok(String::new());
+        }
+
+        let relations = load_child_relations(&self.db_pool, entity_type).await?;
+        let mut columns = String::new();
+        for name in include_counts {
+            let relation = resolve_child_relation(entity_type, &relations, name)?;
+            let child_table = dynamic_entity_utils::quote_ident(
+                &dynamic_entity_utils::get_table_name(&self.db_pool, &relation.entity_type).await?,
+            )?;
+            let column = dynamic_entity_utils::quote_ident(&relation.column)?;
+            let alias = dynamic_entity_utils::quote_ident(&format!(
+                "{}_count",
+                name.to_lowercase().replace('.', "_")
+            ))?;
+            let _ = write!(
+                columns,
+                ", (SELECT COUNT(*) FROM {child_table} c \
+                JOIN entities_registry cr ON cr.uuid = c.uuid \
+                WHERE c.{column} = v.uuid AND cr.deleted_at IS NULL) AS {alias}"
+            );
+        }
+        Ok(columns)
+    }
+}
+
+/// Existence check over `from_clause` that stops at the first matching row
+fn exists_sql(from_clause: &str) -> String {
+    format!("SELECT EXISTS (SELECT 1 {from_clause} LIMIT 1)")
 }
 
 #[async_trait]
@@ -113,6 +278,31 @@ impl DynamicEntityQueryRepositoryTrait for DynamicEntityQueryRepository {
     ) -> Result<Vec<DynamicEntity>> {
         Self::query_entities(self, entity_type, query).await
     }
+
+    async fn query_entities_with_counts(
+        &self,
+        entity_type: &str,
+        query: &AdvancedEntityQuery,
+        include_counts: &[String],
+    ) -> Result<Vec<DynamicEntity>> {
+        Self::query_entities_with_counts(self, entity_type, query, include_counts).await
+    }
+
+    async fn exists(
+        &self,
+        entity_type: &str,
+        filter: Option<&HashMap<String, Value>>,
+    ) -> Result<bool> {
+        Self::exists(self, entity_type, filter).await
+    }
+
+    async fn count(
+        &self,
+        entity_type: &str,
+        filter: Option<&HashMap<String, Value>>,
+    ) -> Result<i64> {
+        Self::count(self, entity_type, filter).await
+    }
 }
 
 #[cfg(test)]
@@ -125,4 +315,13 @@ mod tests {
         fn assert_send_sync<T: Send + Sync>() {}
         assert_send_sync::<DynamicEntityQueryRepository>();
     }
+
+    #[test]
+    fn test_exists_sql_stops_at_first_row() {
+        let sql = exists_sql("FROM entity_post_view v WHERE v.deleted_at IS NULL");
+        assert_eq!(
+            sql,
+            "SELECT EXISTS (SELECT 1 FROM entity_post_view v WHERE v.deleted_at IS NULL LIMIT 1)"
+        );
+    }
 }
@@ -1,6 +1,9 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use serde_json::Value;
 
 use r_data_core_core::error::Result;
 use r_data_core_core::public_api::AdvancedEntityQuery;
@@ -22,4 +25,48 @@ pub trait DynamicEntityQueryRepositoryTrait: Send + Sync {
         entity_type: &str,
         query: &AdvancedEntityQuery,
     ) -> Result<Vec<DynamicEntity>>;
+
+    /// Query dynamic entity instances, adding a `<relation>_count` field per requested relation
+    ///
+    /// # Arguments
+    /// * `entity_type` - Type of entity to query
+    /// * `query` - Advanced query parameters
+    /// * `include_counts` - `ManyToOne` relations referencing `entity_type` to count
+    ///
+    /// # Errors
+    /// Returns an error if a relation is unknown or the query cannot be executed
+    async fn query_entities_with_counts(
+        &self,
+        entity_type: &str,
+        query: &AdvancedEntityQuery,
+        include_counts: &[String],
+    ) -> Result<Vec<DynamicEntity>>;
+
+    /// Whether any entity matches `filter`, without fetching rows
+    ///
+    /// # Arguments
+    /// * `entity_type` - Type of entity to query
+    /// * `filter` - Field filters, applied as in [`Self::query_entities`]
+    ///
+    /// # Errors
+    /// Returns an error if the query cannot be executed
+    async fn exists(
+        &self,
+        entity_type: &str,
+        filter: Option<&HashMap<String, Value>>,
+    ) -> Result<bool>;
+
+    /// Number of entities matching `filter`, without fetching rows
+    ///
+    /// # Arguments
+    /// * `entity_type` - Type of entity to query
+    /// * `filter` - Field filters, applied as in [`Self::query_entities`]
+    ///
+    /// # Errors
+    /// Returns an error if the query cannot be executed
+    async fn count(
+        &self,
+        entity_type: &str,
+        filter: Option<&HashMap<String, Value>>,
+    ) -> Result<i64>;
 }
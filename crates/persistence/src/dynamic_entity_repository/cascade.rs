@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+
+use log::debug;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::dynamic_entity_utils;
+use crate::entity_relations::load_child_relations;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::field::OnDeletePolicy;
+
+/// Apply the delete policies of every relation pointing at the entity, then soft-delete it
+///
+/// `no_action` leaves the children untouched, `restrict` fails with `Error::Conflict` when live
+/// children exist, `cascade` soft-deletes the
+/// children (applying their own policies), and `set_null` clears the child references.
+/// `visited` guards against reference cycles.
+pub fn soft_delete_with_policies<'a>(
+    tx: &'a mut Transaction<'_, Postgres>,
+    entity_type: &'a str,
+    uuid: Uuid,
+    visited: &'a mut HashSet<Uuid>,
+) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+    Box::pin(async move {
+        if !visited.insert(uuid) {
+            return Ok(());
+        }
+
+        for relation in load_child_relations(&mut **tx, entity_type).await? {
+            if relation.policy == OnDeletePolicy::NoAction {
+                continue;
+            }
+            let table_name =
+                dynamic_entity_utils::get_table_name(&mut **tx, &relation.entity_type).await?;
+            let table = dynamic_entity_utils::quote_ident(&table_name)?;
+            let column = dynamic_entity_utils::quote_ident(&relation.column)?;
+            let children: Vec<Uuid> = sqlx::query_scalar(&format!(
+                "SELECT t.uuid FROM {table} t
+                JOIN entities_registry r ON r.uuid = t.uuid
+                WHERE t.{column} = $1 AND r.deleted_at IS NULL"
+            ))
+            .bind(uuid)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(Error::Database)?;
+
+            if children.is_empty() {
+                continue;
+            }
+
+            match relation.policy {
+                OnDeletePolicy::NoAction => {}
+                OnDeletePolicy::Restrict => {
+                    return Err(Error::Conflict(format!(
+                        "Cannot delete {entity_type} {uuid}: {} {} entities reference it via '{}'",
+                        children.len(),
+                        relation.entity_type,
+                        relation.column
+                    )));
+                }
+                OnDeletePolicy::Cascade => {
+                    debug!(
+                        "Cascading delete of {entity_type} {uuid} to {} {} entities",
+                        children.len(),
+                        relation.entity_type
+                    );
+                    for child in children {
+                        soft_delete_with_policies(tx, &relation.entity_type, child, visited)
+                            .await?;
+                    }
+                }
+                OnDeletePolicy::SetNull => {
+                    sqlx::query(&format!(
+                        "UPDATE {table} SET {column} = NULL WHERE {column} = $1"
+                    ))
+                    .bind(uuid)
+                    .execute(&mut **tx)
+                    .await
+                    .map_err(Error::Database)?;
+                }
+            }
+        }
+
+        let result = sqlx::query(
+            "UPDATE entities_registry SET deleted_at = NOW()
+            WHERE uuid = $1 AND entity_type = $2 AND deleted_at IS NULL",
+        )
+        .bind(uuid)
+        .bind(entity_type)
+        .execute(&mut **tx)
+        .await
+        .map_err(Error::Database)?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::NotFound(format!(
+                "Entity {entity_type} with UUID {uuid} not found"
+            )));
+        }
+
+        Ok(())
+    })
+}
@@ -25,6 +25,11 @@ pub async fn create_entity(repo: &DynamicEntityRepository, entity: &DynamicEntit
     )
     .await?;
 
+    // Fill in defaults for fields the caller left out
+    let mut entity = entity.clone();
+    entity_def.apply_field_defaults(&mut entity.field_data);
+    let entity = &entity;
+
     // Validate the entity against the entity definition
     entity.validate()?;
 
@@ -166,7 +171,7 @@ async fn insert_into_entity_table(
     uuid: &Uuid,
     entity_def: &EntityDefinition,
 ) -> Result<()> {
-    let table_name = dynamic_entity_utils::get_table_name(&entity.entity_type);
+    let table_name = dynamic_entity_utils::get_table_name(&mut **tx, &entity.entity_type).await?;
 
     // Get column names for this table
     let valid_columns = dynamic_entity_utils::fetch_valid_columns(&mut **tx, &table_name).await?;
@@ -200,24 +205,27 @@ async fn insert_into_entity_table(
         // If we have more than just the UUID
         let query = format!(
             "INSERT INTO {} ({}) VALUES ({})",
-            table_name,
-            columns.join(", "),
+            dynamic_entity_utils::quote_ident(&table_name)?,
+            dynamic_entity_utils::quote_ident_list(&columns)?,
             values.join(", ")
         );
 
         sqlx::query(&query).execute(&mut **tx).await
     } else {
         // If we only have the UUID, just insert that
-        sqlx::query(&format!("INSERT INTO {table_name} (uuid) VALUES ($1)"))
-            .bind(uuid)
-            .execute(&mut **tx)
-            .await
+        sqlx::query(&format!(
+            "INSERT INTO {} (uuid) VALUES ($1)",
+            dynamic_entity_utils::quote_ident(&table_name)?
+        ))
+        .bind(uuid)
+        .execute(&mut **tx)
+        .await
     };
 
     // Handle unique constraint violations
     result
         .map(|_| ())
-        .map_err(|e| dynamic_entity_utils::map_entity_unique_violation(e, &table_name))
+        .map_err(|e| dynamic_entity_utils::map_entity_unique_violation(e, &table_name, entity_def))
 }
 
 /// Extract timestamp from field data
@@ -1,15 +1,19 @@
 use log::{debug, error, warn};
 use serde_json::Value as JsonValue;
+use sqlx::postgres::types::PgInterval;
 use std::fmt::Write;
 use uuid::Uuid;
 
 use crate::dynamic_entity_mapper;
 use crate::dynamic_entity_repository_trait::FilterEntitiesParams;
 use crate::dynamic_entity_utils;
+use r_data_core_core::entity_definition::definition::EntityDefinition;
 use r_data_core_core::error::Result;
+use r_data_core_core::field::duration::IsoDuration;
+use r_data_core_core::field::money::Money;
 use r_data_core_core::DynamicEntity;
 
-use super::DynamicEntityRepository;
+use super::{DynamicEntityRepository, SqlCacheKey};
 
 /// Check if an error is the "cached plan must not change result type" error
 fn is_cached_plan_error(err: &r_data_core_core::error::Error) -> bool {
@@ -29,47 +33,34 @@ pub async fn filter_entities_impl(
     entity_type: &str,
     params: &FilterEntitiesParams,
 ) -> Result<Vec<DynamicEntity>> {
-    let view_name = dynamic_entity_utils::get_view_name(entity_type);
+    filter_entities_on(repo, repo.read_pool(), entity_type, params).await
+}
 
-    // Build query prefix with field selection
-    let query_prefix = build_query_prefix(&view_name, params.fields.as_ref());
+/// Filter entities on `pool`
+///
+/// Listings run on the read pool; lookups that feed a write (upserts, auth checks) pass the
+/// primary so they see the caller's own writes.
+pub(super) async fn filter_entities_on(
+    repo: &DynamicEntityRepository,
+    pool: &sqlx::PgPool,
+    entity_type: &str,
+    params: &FilterEntitiesParams,
+) -> Result<Vec<DynamicEntity>> {
+    // Get the entity definition for filtering, sorting and mapping
+    let entity_def =
+        dynamic_entity_utils::get_entity_definition(pool, entity_type, repo.cache_manager.clone())
+            .await?;
 
-    // Build WHERE clause with filters and search
-    let (mut query, _param_index) = build_where_clause(
-        query_prefix,
-        params.filters.as_ref(),
-        params.filter_operators.as_ref(),
-        params.search.as_ref(),
-    );
-
-    // Add sort and pagination
-    add_sort_and_pagination(
-        &mut query,
-        params.sort.as_ref(),
-        params.limit,
-        params.offset,
-    );
+    let query = repo
+        .sql_cache
+        .get_or_build(filter_query_key(entity_type, &entity_def, params), || {
+            build_filter_query(entity_type, &entity_def, params)
+        })?;
 
     debug!("Executing filter query: {query}");
 
-    // Get the entity definition for mapping
-    let entity_def = dynamic_entity_utils::get_entity_definition(
-        &repo.pool,
-        entity_type,
-        repo.cache_manager.clone(),
-    )
-    .await?;
-
     // Execute query with proper parameter binding
-    let rows = execute_filter_query(
-        &query,
-        &repo.pool,
-        params.filters.as_ref(),
-        params.filter_operators.as_ref(),
-        params.search.as_ref(),
-        &entity_def,
-    )
-    .await?;
+    let rows = execute_filter_query(&query, pool, params, &entity_def).await?;
 
     // Map rows to DynamicEntity objects
     let entities: Vec<DynamicEntity> = rows
@@ -80,10 +71,109 @@ pub async fn filter_entities_impl(
     Ok(entities)
 }
 
+/// Cache key of the filter query for `params`
+///
+/// The SQL depends on the filtered fields, their operators and the kind of their values (null,
+/// array length, money, duration), never on the values themselves. Filters are listed in the
+/// map's iteration order, which is also the order their values are bound in.
+pub fn filter_query_key(
+    entity_type: &str,
+    entity_def: &EntityDefinition,
+    params: &FilterEntitiesParams,
+) -> SqlCacheKey {
+    let mut shape = format!(
+        "filter;version={};select_all={};deleted={};owner={}",
+        entity_def.version,
+        params.fields.is_none(),
+        params.include_deleted,
+        params.owner.is_some()
+    );
+    for (field, value) in params.filters.iter().flatten() {
+        let operator = params
+            .filter_operators
+            .as_ref()
+            .and_then(|ops| ops.get(field))
+            .map_or("=", String::as_str);
+        let kind = if money_filter(field, value, entity_def).is_some() {
+            "money".to_string()
+        } else if duration_filter(field, value, entity_def).is_some() {
+            "duration".to_string()
+        } else if value.is_null() {
+            "null".to_string()
+        } else if let Some(items) = value.as_array() {
+            format!("array{}", items.len())
+        } else {
+            "value".to_string()
+        };
+        let _ = write!(shape, ";filter={field:?} {operator:?} {kind}");
+    }
+    if let Some((_, search_fields)) = &params.search {
+        let _ = write!(shape, ";search={search_fields:?}");
+    }
+    if let Some((field, direction)) = &params.sort {
+        let _ = write!(
+            shape,
+            ";sort={field:?} {direction:?} {:?}",
+            params.collation
+        );
+    }
+
+    SqlCacheKey {
+        entity_type: entity_type.to_string(),
+        column_set: params.fields.clone().unwrap_or_default(),
+        filter_shape: shape,
+    }
+}
+
+/// Filter query for `params`, with its values (and limit and offset) left as parameters
+///
+/// # Errors
+/// Returns an error if the entity type, a field or the sort direction is invalid
+pub fn build_filter_query(
+    entity_type: &str,
+    entity_def: &EntityDefinition,
+    params: &FilterEntitiesParams,
+) -> Result<String> {
+    let view_name = dynamic_entity_utils::get_view_name(entity_type)?;
+
+    // Build query prefix with field selection
+    let query_prefix = build_query_prefix(&view_name, params.fields.as_ref())?;
+
+    // Build WHERE clause with filters and search
+    let (mut query, mut param_index) = build_where_clause(
+        query_prefix,
+        entity_def,
+        params.filters.as_ref(),
+        params.filter_operators.as_ref(),
+        params.search.as_ref(),
+        params.include_deleted,
+    )?;
+
+    // Row-level ownership: bound after all filter and search parameters
+    if params.owner.is_some() {
+        let keyword = if query.contains(" WHERE ") {
+            " AND "
+        } else {
+            " WHERE "
+        };
+        let _ = write!(query, "{keyword}created_by = ${param_index}");
+        param_index += 1;
+    }
+
+    // Add sort and pagination
+    add_sort_and_pagination(&mut query, entity_def, params, param_index)?;
+    Ok(query)
+}
+
 /// Build query prefix with field selection
-fn build_query_prefix(view_name: &str, fields: Option<&Vec<String>>) -> String {
+fn build_query_prefix(view_name: &str, fields: Option<&Vec<String>>) -> Result<String> {
     fields.map_or_else(
-        || format!("SELECT * FROM {view_name}"),
+        || {
+            Ok(format!(
+                "SELECT * FROM {}",
+                dynamic_entity_utils::quote_ident(view_name)?
+            ))
+        },
         |field_list| {
             // Always include system fields
             let mut selected_fields = vec![
@@ -100,12 +190,17 @@ fn build_query_prefix(view_name: &str, fields: Option<&Vec<String>>) -> String {
 
             // Add requested fields
             for field in field_list {
-                if !selected_fields.contains(field) {
-                    selected_fields.push(field.clone());
+                let column = dynamic_entity_utils::sql_column_name(field)?;
+                if !selected_fields.contains(&column) {
+                    selected_fields.push(column);
                 }
             }
 
-            format!("SELECT {} FROM {view_name}", selected_fields.join(", "))
+            Ok(format!(
+                "SELECT {} FROM {}",
+                dynamic_entity_utils::quote_ident_list(&selected_fields)?,
+                dynamic_entity_utils::quote_ident(view_name)?
+            ))
         },
     )
 }
@@ -113,16 +208,25 @@ fn build_query_prefix(view_name: &str, fields: Option<&Vec<String>>) -> String {
 /// Build WHERE clause with filters and search
 fn build_where_clause(
     mut query: String,
+    entity_def: &EntityDefinition,
     filters: Option<&std::collections::HashMap<String, JsonValue>>,
     filter_operators: Option<&std::collections::HashMap<String, String>>,
     search: Option<&(String, Vec<String>)>,
-) -> (String, i32) {
+    include_deleted: bool,
+) -> Result<(String, i32)> {
     let mut param_index = 1;
 
+    // Soft-deleted rows are hidden unless explicitly requested
+    let mut has_where = !include_deleted;
+    if has_where {
+        query.push_str(" WHERE deleted_at IS NULL");
+    }
+
     // Add filter conditions if provided
     if let Some(filter_map) = filters {
         if !filter_map.is_empty() {
-            query.push_str(" WHERE ");
+            query.push_str(if has_where { " AND " } else { " WHERE " });
+            has_where = true;
             let mut is_first = true;
 
             for (field, value) in filter_map {
@@ -135,7 +239,24 @@ fn build_where_clause(
                     .and_then(|ops| ops.get(field))
                     .map_or("=", std::string::String::as_str);
 
-                param_index = add_filter_condition(&mut query, field, value, operator, param_index);
+                if money_filter(field, value, entity_def).is_some() {
+                    query.push_str(&dynamic_entity_utils::money_amount_condition(
+                        field,
+                        operator,
+                        param_index,
+                    )?);
+                    param_index += 2;
+                } else if duration_filter(field, value, entity_def).is_some() {
+                    query.push_str(&dynamic_entity_utils::duration_condition(
+                        field,
+                        operator,
+                        param_index,
+                    )?);
+                    param_index += 1;
+                } else {
+                    param_index =
+                        add_filter_condition(&mut query, field, value, operator, param_index)?;
+                }
                 is_first = false;
             }
         }
@@ -144,20 +265,17 @@ fn build_where_clause(
     // Add search condition if provided
     if let Some((_search_term, search_fields)) = search {
         if !search_fields.is_empty() {
-            if filters.is_none_or(std::collections::HashMap::is_empty) {
-                query.push_str(" WHERE ");
-            } else {
-                query.push_str(" AND ");
-            }
+            query.push_str(if has_where { " AND " } else { " WHERE " });
 
-            let search_conditions: Vec<String> = search_fields
+            let search_conditions = search_fields
                 .iter()
                 .map(|field| {
-                    let condition = format!("{field} ILIKE ${param_index}");
+                    let column = dynamic_entity_utils::quoted_column_name(field)?;
+                    let condition = format!("{column} ILIKE ${param_index}");
                     param_index += 1;
-                    condition
+                    Ok(condition)
                 })
-                .collect();
+                .collect::<Result<Vec<String>>>()?;
 
             // Note: search_term is used in execute_filter_query for binding
 
@@ -169,7 +287,7 @@ fn build_where_clause(
         }
     }
 
-    (query, param_index)
+    Ok((query, param_index))
 }
 
 /// Add a single filter condition to the query
@@ -183,13 +301,16 @@ fn build_where_clause(
 ///
 /// # Returns
 /// The next parameter index to use
+///
+/// # Errors
+/// Returns a validation error if the field is not a safe identifier
 fn add_filter_condition(
     query: &mut String,
     field: &str,
     value: &JsonValue,
     operator: &str,
     param_index: i32,
-) -> i32 {
+) -> Result<i32> {
     // Sanitize operator to prevent SQL injection - only allow whitelisted operators
     let sanitized_operator = match operator {
         "=" | ">" | "<" | "<=" | ">=" | "IN" | "NOT IN" => operator,
@@ -202,17 +323,19 @@ fn add_filter_condition(
     // Special handling for path-based filters (these ignore operator)
     if field == "path_prefix" {
         let _ = write!(query, "path LIKE ${param_index} || '/%'");
-        return param_index + 1;
+        return Ok(param_index + 1);
     }
     if field == "path_equals" || field == "path" {
         let _ = write!(query, "path = ${param_index}");
-        return param_index + 1;
+        return Ok(param_index + 1);
     }
 
+    let field = dynamic_entity_utils::quoted_column_name(field)?;
+
     // Handle NULL values
     if value == &JsonValue::Null {
         let _ = write!(query, "{field} IS NULL");
-        return param_index;
+        return Ok(param_index);
     }
 
     // Handle IN and NOT IN operators with array values
@@ -225,7 +348,7 @@ fn add_filter_condition(
                 } else {
                     "1 = 1"
                 });
-                return param_index;
+                return Ok(param_index);
             }
             // Build IN clause with multiple parameters
             let placeholders: Vec<String> = (0..arr.len())
@@ -239,33 +362,35 @@ fn add_filter_condition(
                 "{field} {sanitized_operator} ({})",
                 placeholders.join(", ")
             );
-            return param_index + i32::try_from(arr.len()).unwrap_or(i32::MAX);
+            return Ok(param_index + i32::try_from(arr.len()).unwrap_or(i32::MAX));
         }
         // If value is not an array for IN/NOT IN, treat as single value
         let _ = write!(query, "{field} {sanitized_operator} (${param_index})");
-        return param_index + 1;
+        return Ok(param_index + 1);
     }
 
     // Standard comparison operators (=, >, <, <=, >=)
     let _ = write!(query, "{field} {sanitized_operator} ${param_index}");
-    param_index + 1
+    Ok(param_index + 1)
 }
 
-/// Add sort and pagination to query
+/// Add sort and pagination to query, binding limit and offset at `$param_index` and
+/// `$param_index + 1`
 fn add_sort_and_pagination(
     query: &mut String,
-    sort: Option<&(String, String)>,
-    limit: i64,
-    offset: i64,
-) {
+    entity_def: &EntityDefinition,
+    params: &FilterEntitiesParams,
+    param_index: i32,
+) -> Result<()> {
     // Add sort if provided
-    if let Some((field, direction)) = sort {
+    if let Some((field, direction)) = &params.sort {
         // Sanitize the direction to prevent SQL injection
         let sanitized_direction = match direction.to_uppercase().as_str() {
             "ASC" => "ASC",
             _ => "DESC",
         };
 
+        let field = dynamic_entity_utils::sort_expression(entity_def, field, params.collation)?;
         let _ = write!(query, " ORDER BY {field} {sanitized_direction}");
     } else {
         // Default sort
@@ -273,22 +398,19 @@ fn add_sort_and_pagination(
     }
 
     // Add pagination
-    let _ = write!(query, " LIMIT {limit} OFFSET {offset}");
+    let _ = write!(query, " LIMIT ${param_index} OFFSET ${}", param_index + 1);
+    Ok(())
 }
 
 /// Execute the filter query with proper parameter binding and retry logic for schema changes
 async fn execute_filter_query(
     query: &str,
     pool: &sqlx::PgPool,
-    filters: Option<&std::collections::HashMap<String, JsonValue>>,
-    filter_operators: Option<&std::collections::HashMap<String, String>>,
-    search: Option<&(String, Vec<String>)>,
+    params: &FilterEntitiesParams,
     entity_def: &r_data_core_core::entity_definition::definition::EntityDefinition,
 ) -> Result<Vec<sqlx::postgres::PgRow>> {
     // First attempt
-    let result =
-        execute_filter_query_inner(query, pool, filters, filter_operators, search, entity_def)
-            .await;
+    let result = execute_filter_query_inner(query, pool, params, entity_def).await;
 
     match result {
         Err(ref e) if is_cached_plan_error(e) => {
@@ -300,8 +422,7 @@ async fn execute_filter_query(
                 .await
                 .map_err(r_data_core_core::error::Error::Database)?;
 
-            execute_filter_query_inner(query, pool, filters, filter_operators, search, entity_def)
-                .await
+            execute_filter_query_inner(query, pool, params, entity_def).await
         }
         other => other,
     }
@@ -311,11 +432,12 @@ async fn execute_filter_query(
 async fn execute_filter_query_inner(
     query: &str,
     pool: &sqlx::PgPool,
-    filters: Option<&std::collections::HashMap<String, JsonValue>>,
-    filter_operators: Option<&std::collections::HashMap<String, String>>,
-    search: Option<&(String, Vec<String>)>,
+    params: &FilterEntitiesParams,
     entity_def: &r_data_core_core::entity_definition::definition::EntityDefinition,
 ) -> Result<Vec<sqlx::postgres::PgRow>> {
+    let filters = params.filters.as_ref();
+    let filter_operators = params.filter_operators.as_ref();
+    let search = params.search.as_ref();
     let mut sql = sqlx::query(query);
 
     // Bind filter parameters with proper types
@@ -338,6 +460,18 @@ async fn execute_filter_query_inner(
                 continue;
             }
 
+            // Money filters compare the amount within the filter's currency
+            if let Some(money) = money_filter(field, value, entity_def) {
+                sql = sql.bind(money.currency).bind(money.amount);
+                continue;
+            }
+
+            // Duration filters compare intervals, e.g. longer than `PT1H`
+            if let Some(duration) = duration_filter(field, value, entity_def) {
+                sql = sql.bind(PgInterval::from(duration));
+                continue;
+            }
+
             // Get field type from entity definition or system fields
             let field_type = get_field_type(field, entity_def);
 
@@ -400,6 +534,12 @@ async fn execute_filter_query_inner(
         }
     }
 
+    if let Some(owner) = params.owner {
+        sql = sql.bind(owner);
+    }
+
+    sql = sql.bind(params.limit).bind(params.offset);
+
     let rows = sql.fetch_all(pool).await.map_err(|e| {
         error!("Database error: {e}");
         r_data_core_core::error::Error::Database(e)
@@ -430,6 +570,32 @@ fn get_field_type(
     }
 }
 
+/// The `{ amount, currency }` value of a filter on a `Money` field
+fn money_filter(
+    field: &str,
+    value: &JsonValue,
+    entity_def: &r_data_core_core::entity_definition::definition::EntityDefinition,
+) -> Option<Money> {
+    if get_field_type(field, entity_def) == Some(r_data_core_core::field::FieldType::Money) {
+        Money::from_json(value).ok()
+    } else {
+        None
+    }
+}
+
+/// The ISO-8601 value of a filter on a `Duration` field
+fn duration_filter(
+    field: &str,
+    value: &JsonValue,
+    entity_def: &r_data_core_core::entity_definition::definition::EntityDefinition,
+) -> Option<IsoDuration> {
+    if get_field_type(field, entity_def) == Some(r_data_core_core::field::FieldType::Duration) {
+        value.as_str().and_then(|s| s.parse().ok())
+    } else {
+        None
+    }
+}
+
 /// Convert a JSON value to the appropriate type based on field definition
 fn convert_value_to_type(
     value: &JsonValue,
@@ -497,4 +663,89 @@ mod tests {
         let err = r_data_core_core::error::Error::Database(sqlx_err);
         assert!(!is_cached_plan_error(&err));
     }
+
+    fn definition() -> EntityDefinition {
+        let mut definition = EntityDefinition {
+            entity_type: "customer".to_string(),
+            version: 3,
+            ..EntityDefinition::default()
+        };
+        for (name, field_type) in [
+            ("name", r_data_core_core::field::FieldType::String),
+            ("email", r_data_core_core::field::FieldType::String),
+            ("age", r_data_core_core::field::FieldType::Integer),
+            ("budget", r_data_core_core::field::FieldType::Money),
+        ] {
+            definition
+                .fields
+                .push(r_data_core_core::field::FieldDefinition::new(
+                    name.to_string(),
+                    name.to_string(),
+                    field_type,
+                ));
+        }
+        definition
+    }
+
+    fn params(name: &str, limit: i64) -> FilterEntitiesParams {
+        FilterEntitiesParams::new(limit, 0)
+            .with_filters(Some(std::collections::HashMap::from([(
+                "name".to_string(),
+                JsonValue::String(name.to_string()),
+            )])))
+            .with_search(Some(("acme".to_string(), vec!["email".to_string()])))
+            .with_sort(Some(("name".to_string(), "ASC".to_string())))
+            .with_fields(Some(vec!["name".to_string(), "age".to_string()]))
+    }
+
+    #[test]
+    fn same_filter_shape_reuses_cached_sql() {
+        let definition = definition();
+        let cache = super::super::SqlCache::default();
+        let builds = std::cell::Cell::new(0);
+        let lookup = |params: &FilterEntitiesParams| {
+            cache
+                .get_or_build(filter_query_key("customer", &definition, params), || {
+                    builds.set(builds.get() + 1);
+                    build_filter_query("customer", &definition, params)
+                })
+                .unwrap()
+        };
+
+        // Values, limit and offset are bound, so they do not change the statement
+        let first = lookup(&params("Alice", 10));
+        let second = lookup(&params("Bob", 50));
+        assert_eq!(first, second);
+        assert_eq!(builds.get(), 1);
+        assert_eq!(cache.hits(), 1);
+
+        // A NULL filter compiles to `IS NULL` and is a different shape
+        let mut null_filter = params("Alice", 10);
+        null_filter.filters = Some(std::collections::HashMap::from([(
+            "name".to_string(),
+            JsonValue::Null,
+        )]));
+        assert_ne!(lookup(&null_filter), first);
+        assert_eq!(builds.get(), 2);
+    }
+
+    #[test]
+    fn filter_query_binds_pagination() {
+        let sql = build_filter_query("customer", &definition(), &params("Alice", 10)).unwrap();
+        assert!(sql.ends_with(" LIMIT $3 OFFSET $4"), "{sql}");
+
+        let owned = params("Alice", 10).with_owner(Some(Uuid::now_v7()));
+        let sql = build_filter_query("customer", &definition(), &owned).unwrap();
+        assert!(sql.contains("created_by = $3"), "{sql}");
+        assert!(sql.ends_with(" LIMIT $4 OFFSET $5"), "{sql}");
+    }
+
+    #[test]
+    fn definition_version_is_part_of_the_key() {
+        let mut definition = definition();
+        let before = filter_query_key("customer", &definition, &params("Alice", 10));
+        definition.version += 1;
+        let after = filter_query_key("customer", &definition, &params("Alice", 10));
+        assert_ne!(before, after);
+    }
 }
@@ -1,65 +1,129 @@
 use sqlx::{PgPool, Row};
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::database::Database;
 use crate::dynamic_entity_repository_trait::{DynamicEntityRepositoryTrait, FilterEntitiesParams};
 use crate::dynamic_entity_utils;
 use r_data_core_core::cache::CacheManager;
 use r_data_core_core::error::Result;
 use r_data_core_core::DynamicEntity;
 
+mod cascade;
 mod create;
 mod filter;
 mod query;
+mod sql_cache;
 mod update;
 
+pub use filter::{build_filter_query, filter_query_key};
+pub use sql_cache::{SqlCache, SqlCacheKey};
+
 use r_data_core_core::entity_definition::definition::EntityDefinition;
 use r_data_core_core::field::types::FieldType;
 use serde_json::Value as JsonValue;
 
+/// Prefix of the keys marking entity UUIDs that were looked up but do not exist
+///
+/// Entities themselves are never cached under it, so a marker cannot be mistaken for one.
+pub const MISSING_ENTITY_CACHE_PREFIX: &str = "entity_missing:";
+
+/// Cache key marking that no entity of `entity_type` has this UUID
+#[must_use]
+pub fn missing_entity_cache_key(entity_type: &str, uuid: &Uuid) -> String {
+    format!("{MISSING_ENTITY_CACHE_PREFIX}{entity_type}:{uuid}")
+}
+
 use create::create_entity;
 use filter::filter_entities_impl;
 use query::{
-    count_children_impl, count_entities_impl, delete_by_type_impl, find_one_by_filters_impl,
-    get_all_by_type_impl, get_by_type_impl, get_by_uuid_any_type_impl, has_children_impl,
-    query_by_parent_impl, query_by_path_impl,
+    count_children_impl, count_deleted_entities_impl, count_entities_by_owner_impl,
+    count_entities_impl, delete_by_type_impl, estimate_entities_impl, find_one_by_filters_impl,
+    get_all_by_type_impl, get_by_type_impl, get_by_uuid_any_type_impl, get_many_by_type_impl,
+    hard_delete_impl, has_children_impl, is_deleted_impl, query_by_parent_impl, query_by_path_impl,
+    restore_by_type_impl,
 };
-use update::update_entity;
+use update::{update_entity, update_entity_tx};
 
 /// Repository for managing dynamic entities
 pub struct DynamicEntityRepository {
-    /// Database connection pool
+    /// Database connection pool (primary)
     pub pool: PgPool,
     /// Cache manager for entity definitions
     pub cache_manager: Option<Arc<CacheManager>>,
+    /// Pools for routing read-only queries
+    database: Database,
+    /// Generated SQL of `get_by_type` and `filter_entities`, keyed by query shape
+    sql_cache: SqlCache,
 }
 
 impl DynamicEntityRepository {
     /// Create a new repository instance
     #[must_use]
-    pub const fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool) -> Self {
         Self {
+            database: Database::new(pool.clone()),
             pool,
             cache_manager: None,
+            sql_cache: SqlCache::default(),
         }
     }
 
     /// Create a new repository instance with cache manager
     #[must_use]
-    pub const fn with_cache(pool: PgPool, cache_manager: Arc<CacheManager>) -> Self {
+    pub fn with_cache(pool: PgPool, cache_manager: Arc<CacheManager>) -> Self {
         Self {
-            pool,
             cache_manager: Some(cache_manager),
+            ..Self::new(pool)
         }
     }
 
+    /// Send writes, and the single-entity reads preceding them, to the primary of `database`
+    /// and list/count/filter queries to its read pool
+    #[must_use]
+    pub fn with_database(mut self, database: Database) -> Self {
+        self.pool = database.primary().clone();
+        self.database = database;
+        self
+    }
+
+    /// Pool for read-only queries
+    fn read_pool(&self) -> &PgPool {
+        self.database.read()
+    }
+
+    /// Drop the missing-entity marker of a UUID that now exists
+    ///
+    /// Runs on every create and restore, so callers going around the entity service do not
+    /// leave a stale miss behind.
+    async fn forget_missing(&self, entity_type: &str, uuid: &Uuid) {
+        let Some(cache) = &self.cache_manager else {
+            return;
+        };
+        if let Err(e) = cache
+            .delete(&missing_entity_cache_key(entity_type, uuid))
+            .await
+        {
+            log::warn!("Failed to clear missing entity marker: {e}");
+        }
+    }
+
+    /// Cache of generated query SQL, e.g. to inspect its hit rate
+    #[must_use]
+    pub const fn sql_cache(&self) -> &SqlCache {
+        &self.sql_cache
+    }
+
     /// Create a new dynamic entity
     ///
     /// # Errors
     /// Returns an error if the database operation fails or validation fails
     /// Returns the UUID
     pub async fn create(&self, entity: &DynamicEntity) -> Result<Uuid> {
-        create_entity(self, entity).await
+        let uuid = create_entity(self, entity).await?;
+        self.forget_missing(&entity.entity_type, &uuid).await;
+        Ok(uuid)
     }
 
     /// Update an existing dynamic entity
@@ -70,6 +134,18 @@ impl DynamicEntityRepository {
         update_entity(self, entity).await
     }
 
+    /// Update an existing dynamic entity within a caller-owned transaction
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails or validation fails
+    pub async fn update_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        entity: &DynamicEntity,
+    ) -> Result<()> {
+        update_entity_tx(self, tx, entity).await
+    }
+
     /// Count entities of a specific type
     ///
     /// # Errors
@@ -78,7 +154,7 @@ impl DynamicEntityRepository {
         count_entities_impl(self, entity_type).await
     }
 
-    /// Query entities by `parent_uuid`
+    /// Query entities by `parent_uuid`, only those created by `owner` when set
     ///
     /// # Errors
     /// Returns an error if the database query fails
@@ -86,13 +162,14 @@ impl DynamicEntityRepository {
         &self,
         entity_type: &str,
         parent_uuid: Uuid,
+        owner: Option<Uuid>,
         limit: i64,
         offset: i64,
     ) -> Result<Vec<DynamicEntity>> {
-        query_by_parent_impl(self, entity_type, parent_uuid, limit, offset).await
+        query_by_parent_impl(self, entity_type, parent_uuid, owner, limit, offset).await
     }
 
-    /// Query entities by exact `path`
+    /// Query entities by exact `path`, only those created by `owner` when set
     ///
     /// # Errors
     /// Returns an error if the database query fails
@@ -100,10 +177,11 @@ impl DynamicEntityRepository {
         &self,
         entity_type: &str,
         path: &str,
+        owner: Option<Uuid>,
         limit: i64,
         offset: i64,
     ) -> Result<Vec<DynamicEntity>> {
-        query_by_path_impl(self, entity_type, path, limit, offset).await
+        query_by_path_impl(self, entity_type, path, owner, limit, offset).await
     }
 
     /// Check if an entity has children
@@ -149,8 +227,8 @@ impl DynamicEntityRepository {
         uuid: &Uuid,
         field_name: &str,
     ) -> Result<Option<String>> {
-        let table_name = dynamic_entity_utils::get_table_name(entity_type);
-        let field_lower = field_name.to_lowercase();
+        let field_lower = dynamic_entity_utils::sql_column_name(field_name)?;
+        let table_name = dynamic_entity_utils::get_table_name(&self.pool, entity_type).await?;
 
         // Validate the field name is a valid column
         let valid_columns =
@@ -161,7 +239,11 @@ impl DynamicEntityRepository {
             ));
         }
 
-        let query = format!("SELECT {field_lower} FROM {table_name} WHERE uuid = $1");
+        let query = format!(
+            "SELECT {} FROM {} WHERE uuid = $1",
+            dynamic_entity_utils::quote_ident(&field_lower)?,
+            dynamic_entity_utils::quote_ident(&table_name)?
+        );
 
         let row = sqlx::query(&query)
             .bind(uuid)
@@ -221,6 +303,15 @@ impl DynamicEntityRepositoryTrait for DynamicEntityRepository {
         get_by_type_impl(self, entity_type, uuid, exclusive_fields).await
     }
 
+    async fn get_many_by_type(
+        &self,
+        entity_type: &str,
+        uuids: &[Uuid],
+        exclusive_fields: Option<Vec<String>>,
+    ) -> Result<HashMap<Uuid, Option<DynamicEntity>>> {
+        get_many_by_type_impl(self, entity_type, uuids, exclusive_fields).await
+    }
+
     async fn create(&self, entity: &DynamicEntity) -> Result<Uuid> {
         self.create(entity).await
     }
@@ -233,6 +324,20 @@ impl DynamicEntityRepositoryTrait for DynamicEntityRepository {
         delete_by_type_impl(self, entity_type, uuid).await
     }
 
+    async fn restore_by_type(&self, entity_type: &str, uuid: &Uuid) -> Result<()> {
+        restore_by_type_impl(self, entity_type, uuid).await?;
+        self.forget_missing(entity_type, uuid).await;
+        Ok(())
+    }
+
+    async fn hard_delete(&self, entity_type: &str, uuid: &Uuid) -> Result<()> {
+        hard_delete_impl(self, entity_type, uuid).await
+    }
+
+    async fn is_deleted(&self, entity_type: &str, uuid: &Uuid) -> Result<bool> {
+        is_deleted_impl(self, entity_type, uuid).await
+    }
+
     async fn filter_entities(
         &self,
         entity_type: &str,
@@ -245,6 +350,18 @@ impl DynamicEntityRepositoryTrait for DynamicEntityRepository {
         self.count_entities(entity_type).await
     }
 
+    async fn count_deleted_entities(&self, entity_type: &str) -> Result<i64> {
+        count_deleted_entities_impl(self, entity_type).await
+    }
+
+    async fn count_entities_by_owner(&self, entity_type: &str, owner: &Uuid) -> Result<i64> {
+        count_entities_by_owner_impl(self, entity_type, owner).await
+    }
+
+    async fn estimate_entities(&self, entity_type: &str) -> Result<Option<i64>> {
+        estimate_entities_impl(self, entity_type).await
+    }
+
     async fn count_children(&self, parent_uuid: &Uuid) -> Result<i64> {
         self.count_children(parent_uuid).await
     }
@@ -1,5 +1,6 @@
 use log::{debug, error, warn};
-use sqlx::PgPool;
+use sqlx::{PgPool, Row};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 use crate::dynamic_entity_mapper;
@@ -7,7 +8,8 @@ use crate::dynamic_entity_utils;
 use r_data_core_core::error::Result;
 use r_data_core_core::DynamicEntity;
 
-use super::DynamicEntityRepository;
+use super::cascade::soft_delete_with_policies;
+use super::{DynamicEntityRepository, SqlCacheKey};
 
 /// Check if an error is the "cached plan must not change result type" error
 /// This occurs when cached plan types change (aka an entity definition changes) and not every connection has gotten the updated cache yet
@@ -74,6 +76,7 @@ async fn fetch_optional_with_retry<'q>(
 #[derive(Clone)]
 enum QueryBind<'a> {
     Uuid(Uuid),
+    UuidArray(&'a [Uuid]),
     I64(i64),
     String(&'a str),
 }
@@ -88,6 +91,7 @@ async fn execute_query<'q>(
     for bind in binds {
         q = match bind {
             QueryBind::Uuid(v) => q.bind(*v),
+            QueryBind::UuidArray(v) => q.bind(*v),
             QueryBind::I64(v) => q.bind(*v),
             QueryBind::String(v) => q.bind(*v),
         };
@@ -105,6 +109,7 @@ async fn execute_query_optional<'q>(
     for bind in binds {
         q = match bind {
             QueryBind::Uuid(v) => q.bind(*v),
+            QueryBind::UuidArray(v) => q.bind(*v),
             QueryBind::I64(v) => q.bind(*v),
             QueryBind::String(v) => q.bind(*v),
         };
@@ -112,13 +117,71 @@ async fn execute_query_optional<'q>(
     q.fetch_optional(pool).await
 }
 
-/// Count entities of a specific type
+/// Count live entities of a specific type
 ///
 /// # Errors
 /// Returns an error if the database query fails
 pub async fn count_entities_impl(repo: &DynamicEntityRepository, entity_type: &str) -> Result<i64> {
+    count_view_rows(repo, entity_type, "deleted_at IS NULL").await
+}
+
+/// Count soft-deleted entities of a specific type
+///
+/// # Errors
+/// Returns an error if the database query fails
+pub async fn count_deleted_entities_impl(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+) -> Result<i64> {
+    count_view_rows(repo, entity_type, "deleted_at IS NOT NULL").await
+}
+
+/// Count live entities of a specific type created by `owner`
+///
+/// # Errors
+/// Returns an error if the database query fails
+pub async fn count_entities_by_owner_impl(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+    owner: &Uuid,
+) -> Result<i64> {
+    // A UUID renders as hex digits and dashes only, so it is safe to inline
+    count_view_rows(
+        repo,
+        entity_type,
+        &format!("deleted_at IS NULL AND created_by = '{owner}'"),
+    )
+    .await
+}
+
+/// Estimate the rows of an entity table from `pg_class.reltuples`
+///
+/// # Errors
+/// Returns an error if the database query fails
+pub async fn estimate_entities_impl(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+) -> Result<Option<i64>> {
+    let table_name = dynamic_entity_utils::get_table_name(repo.read_pool(), entity_type).await?;
+    // `reltuples` is -1 until the table is first vacuumed or analyzed
+    sqlx::query_scalar(
+        "SELECT reltuples::bigint FROM pg_class WHERE oid = to_regclass($1) AND reltuples >= 0",
+    )
+    .bind(table_name)
+    .fetch_optional(repo.read_pool())
+    .await
+    .map_err(r_data_core_core::error::Error::Database)
+}
+
+/// Count rows of an entity view matching a fixed condition
+async fn count_view_rows(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+    condition: &str,
+) -> Result<i64> {
     // Use the view for this entity type
-    let view_name = dynamic_entity_utils::get_view_name(entity_type);
+    let view_name = dynamic_entity_utils::get_view_name(entity_type)?;
+    let view = dynamic_entity_utils::quote_ident(&view_name)?;
 
     // Check if view exists
     let view_exists = sqlx::query_scalar!(
@@ -132,7 +195,7 @@ pub async fn count_entities_impl(repo: &DynamicEntityRepository, entity_type: &s
         "#,
         &view_name
     )
-    .fetch_one(&repo.pool)
+    .fetch_one(repo.read_pool())
     .await
     .map_err(r_data_core_core::error::Error::Database)?;
 
@@ -143,9 +206,9 @@ pub async fn count_entities_impl(repo: &DynamicEntityRepository, entity_type: &s
     }
 
     // Query count
-    let query = format!("SELECT COUNT(*) FROM {view_name}");
+    let query = format!("SELECT COUNT(*) FROM {view} WHERE {condition}");
     let count: i64 = sqlx::query_scalar(&query)
-        .fetch_one(&repo.pool)
+        .fetch_one(repo.read_pool())
         .await
         .map_err(r_data_core_core::error::Error::Database)?;
 
@@ -160,11 +223,12 @@ pub async fn query_by_parent_impl(
     repo: &DynamicEntityRepository,
     entity_type: &str,
     parent_uuid: Uuid,
+    owner: Option<Uuid>,
     limit: i64,
     offset: i64,
 ) -> Result<Vec<DynamicEntity>> {
     let entity_def = dynamic_entity_utils::get_entity_definition(
-        &repo.pool,
+        repo.read_pool(),
         entity_type,
         repo.cache_manager.clone(),
     )
@@ -172,31 +236,35 @@ pub async fn query_by_parent_impl(
 
     // Use the view which properly handles all columns including UUID
     // The view already has UUID as r.uuid, so we don't need to worry about duplicates
-    let view_name = dynamic_entity_utils::get_view_name(entity_type);
+    let view_name = dynamic_entity_utils::quoted_view_name(entity_type)?;
 
     // Build query using the view - it already has all fields properly structured
+    // Row-level ownership: the owner is bound last
+    let owner_clause = if owner.is_some() {
+        " AND created_by = $4"
+    } else {
+        ""
+    };
     let query = format!(
         "SELECT * FROM {view_name}
-        WHERE parent_uuid = $1
+        WHERE parent_uuid = $1 AND deleted_at IS NULL{owner_clause}
         ORDER BY created_at DESC LIMIT $2 OFFSET $3"
     );
 
     debug!("Query by parent: {query}");
 
-    let rows = fetch_all_with_retry(
-        &repo.pool,
-        &query,
-        vec![
-            QueryBind::Uuid(parent_uuid),
-            QueryBind::I64(limit),
-            QueryBind::I64(offset),
-        ],
-    )
-    .await
-    .map_err(|e| {
-        error!("Error querying entities by parent: {e:?}");
-        r_data_core_core::error::Error::Database(e)
-    })?;
+    let mut binds = vec![
+        QueryBind::Uuid(parent_uuid),
+        QueryBind::I64(limit),
+        QueryBind::I64(offset),
+    ];
+    binds.extend(owner.map(QueryBind::Uuid));
+    let rows = fetch_all_with_retry(repo.read_pool(), &query, binds)
+        .await
+        .map_err(|e| {
+            error!("Error querying entities by parent: {e:?}");
+            r_data_core_core::error::Error::Database(e)
+        })?;
 
     // Convert rows to DynamicEntity objects
     let entities = rows
@@ -215,42 +283,48 @@ pub async fn query_by_path_impl(
     repo: &DynamicEntityRepository,
     entity_type: &str,
     path: &str,
+    owner: Option<Uuid>,
     limit: i64,
     offset: i64,
 ) -> Result<Vec<DynamicEntity>> {
-    let table_name = dynamic_entity_utils::get_table_name(entity_type);
+    let table_name = dynamic_entity_utils::get_table_name(repo.read_pool(), entity_type).await?;
     let entity_def = dynamic_entity_utils::get_entity_definition(
-        &repo.pool,
+        repo.read_pool(),
         entity_type,
         repo.cache_manager.clone(),
     )
     .await?;
 
     // Build the query - use e.uuid explicitly to ensure it's included (e.* might not include it if there's a conflict)
+    let table = dynamic_entity_utils::quote_ident(&table_name)?;
+    // Row-level ownership: the owner is bound last
+    let owner_clause = if owner.is_some() {
+        " AND r.created_by = $5"
+    } else {
+        ""
+    };
     let query = format!(
-        "SELECT e.*, e.uuid AS uuid, r.path, r.entity_key, r.parent_uuid FROM {table_name} e
+        "SELECT e.*, e.uuid AS uuid, r.path, r.entity_key, r.parent_uuid FROM {table} e
         INNER JOIN entities_registry r ON e.uuid = r.uuid
-        WHERE r.entity_type = $1 AND r.path = $2
+        WHERE r.entity_type = $1 AND r.path = $2 AND r.deleted_at IS NULL{owner_clause}
         ORDER BY r.created_at DESC LIMIT $3 OFFSET $4"
     );
 
     debug!("Query by path: {query}");
 
-    let rows = fetch_all_with_retry(
-        &repo.pool,
-        &query,
-        vec![
-            QueryBind::String(entity_type),
-            QueryBind::String(path),
-            QueryBind::I64(limit),
-            QueryBind::I64(offset),
-        ],
-    )
-    .await
-    .map_err(|e| {
-        error!("Error querying entities by path: {e:?}");
-        r_data_core_core::error::Error::Database(e)
-    })?;
+    let mut binds = vec![
+        QueryBind::String(entity_type),
+        QueryBind::String(path),
+        QueryBind::I64(limit),
+        QueryBind::I64(offset),
+    ];
+    binds.extend(owner.map(QueryBind::Uuid));
+    let rows = fetch_all_with_retry(repo.read_pool(), &query, binds)
+        .await
+        .map_err(|e| {
+            error!("Error querying entities by path: {e:?}");
+            r_data_core_core::error::Error::Database(e)
+        })?;
 
     // Convert rows to DynamicEntity objects
     let entities = rows
@@ -267,10 +341,10 @@ pub async fn query_by_path_impl(
 /// Returns an error if the database query fails
 pub async fn has_children_impl(repo: &DynamicEntityRepository, parent_uuid: &Uuid) -> Result<bool> {
     let exists: Option<bool> = sqlx::query_scalar(
-        "SELECT EXISTS(SELECT 1 FROM entities_registry WHERE parent_uuid = $1 LIMIT 1)",
+        "SELECT EXISTS(SELECT 1 FROM entities_registry WHERE parent_uuid = $1 AND deleted_at IS NULL LIMIT 1)",
     )
     .bind(parent_uuid)
-    .fetch_one(&repo.pool)
+    .fetch_one(repo.read_pool())
     .await
     .map_err(r_data_core_core::error::Error::Database)?;
 
@@ -285,18 +359,21 @@ pub async fn count_children_impl(
     repo: &DynamicEntityRepository,
     parent_uuid: &Uuid,
 ) -> Result<i64> {
-    let count: i64 =
-        sqlx::query_scalar("SELECT COUNT(*) FROM entities_registry WHERE parent_uuid = $1")
-            .bind(parent_uuid)
-            .fetch_one(&repo.pool)
-            .await
-            .map_err(r_data_core_core::error::Error::Database)?;
+    let count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM entities_registry WHERE parent_uuid = $1 AND deleted_at IS NULL",
+    )
+    .bind(parent_uuid)
+    .fetch_one(repo.read_pool())
+    .await
+    .map_err(r_data_core_core::error::Error::Database)?;
 
     Ok(count)
 }
 
 /// Get a specific entity by type and UUID
 ///
+/// Reads from the primary: updates, deletes and restores load the entity through this first.
+///
 /// # Errors
 /// Returns an error if the database query fails
 pub async fn get_by_type_impl(
@@ -315,12 +392,114 @@ pub async fn get_by_type_impl(
     )
     .await?;
 
+    // The statement only depends on the entity type and the selected fields
+    let key = SqlCacheKey {
+        entity_type: entity_type.to_string(),
+        column_set: exclusive_fields.clone().unwrap_or_default(),
+        filter_shape: format!("get_by_type;select_all={}", exclusive_fields.is_none()),
+    };
+    let query = repo.sql_cache.get_or_build(key, || {
+        build_get_by_type_query(entity_type, exclusive_fields)
+    })?;
+
+    debug!("Query: {query}");
+
+    let row = fetch_optional_with_retry(&repo.pool, &query, vec![QueryBind::Uuid(*uuid)])
+        .await
+        .map_err(|e| {
+            error!("Error fetching entity: {e:?}");
+            r_data_core_core::error::Error::Database(e)
+        })?;
+
+    row.map_or_else(
+        || Ok(None),
+        |row| {
+            // Map the row to a DynamicEntity
+            let entity = dynamic_entity_mapper::map_row_to_entity(&row, entity_type, &entity_def);
+            Ok(Some(entity))
+        },
+    )
+}
+
+/// `SELECT` of one live entity by UUID from the entity type's view
+fn build_get_by_type_query(
+    entity_type: &str,
+    exclusive_fields: Option<Vec<String>>,
+) -> Result<String> {
+    let view_name = dynamic_entity_utils::quoted_view_name(entity_type)?;
+
+    // Build the query with field selection
+    exclusive_fields.map_or_else(
+        || {
+            Ok(format!(
+                "SELECT * FROM {view_name} WHERE uuid = $1 AND deleted_at IS NULL"
+            ))
+        },
+        |fields| {
+            // Always include system fields
+            let mut selected_fields = vec![
+                "uuid".to_string(),
+                "created_at".to_string(),
+                "updated_at".to_string(),
+                "created_by".to_string(),
+                "updated_by".to_string(),
+                "published".to_string(),
+                "version".to_string(),
+                "path".to_string(),
+            ];
+
+            // Add requested fields
+            for field in fields {
+                let column = dynamic_entity_utils::sql_column_name(&field)?;
+                if !selected_fields.contains(&column) {
+                    selected_fields.push(column);
+                }
+            }
+
+            Ok(format!(
+                "SELECT {} FROM {view_name} WHERE uuid = $1 AND deleted_at IS NULL",
+                dynamic_entity_utils::quote_ident_list(&selected_fields)?
+            ))
+        },
+    )
+}
+
+/// Get several entities of a specific type by UUID with a single `uuid = ANY($1)` query
+///
+/// # Errors
+/// Returns an error if the database query fails
+pub async fn get_many_by_type_impl(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+    uuids: &[Uuid],
+    exclusive_fields: Option<Vec<String>>,
+) -> Result<HashMap<Uuid, Option<DynamicEntity>>> {
+    debug!("Getting {} entities of type {entity_type}", uuids.len());
+
+    let mut found: HashMap<Uuid, Option<DynamicEntity>> =
+        uuids.iter().map(|uuid| (*uuid, None)).collect();
+    if uuids.is_empty() {
+        return Ok(found);
+    }
+
+    // Get the entity definition to understand entity structure
+    let entity_def = dynamic_entity_utils::get_entity_definition(
+        repo.read_pool(),
+        entity_type,
+        repo.cache_manager.clone(),
+    )
+    .await?;
+
     // Get the view name
-    let view_name = dynamic_entity_utils::get_view_name(entity_type);
+    let view_name = dynamic_entity_utils::quoted_view_name(entity_type)?;
 
     // Build the query with field selection
-    let query = exclusive_fields.map_or_else(
-        || format!("SELECT * FROM {view_name} WHERE uuid = $1"),
+    let query = exclusive_fields.map_or_else::<Result<String>, _, _>(
+        || {
+            Ok(format!(
+                "SELECT * FROM {view_name} WHERE uuid = ANY($1) AND deleted_at IS NULL"
+            ))
+        },
         |fields| {
             // Always include system fields
             let mut selected_fields = vec![
@@ -336,35 +515,37 @@ pub async fn get_by_type_impl(
 
             // Add requested fields
             for field in fields {
-                if !selected_fields.contains(&field) {
-                    selected_fields.push(field.clone());
+                let column = dynamic_entity_utils::sql_column_name(&field)?;
+                if !selected_fields.contains(&column) {
+                    selected_fields.push(column);
                 }
             }
 
-            format!(
-                "SELECT {} FROM {view_name} WHERE uuid = $1",
-                selected_fields.join(", ")
-            )
+            Ok(format!(
+                "SELECT {} FROM {view_name} WHERE uuid = ANY($1) AND deleted_at IS NULL",
+                dynamic_entity_utils::quote_ident_list(&selected_fields)?
+            ))
         },
-    );
+    )?;
 
     debug!("Query: {query}");
 
-    let row = fetch_optional_with_retry(&repo.pool, &query, vec![QueryBind::Uuid(*uuid)])
+    let rows = fetch_all_with_retry(repo.read_pool(), &query, vec![QueryBind::UuidArray(uuids)])
         .await
         .map_err(|e| {
-            error!("Error fetching entity: {e:?}");
+            error!("Error fetching entities: {e:?}");
             r_data_core_core::error::Error::Database(e)
         })?;
 
-    row.map_or_else(
-        || Ok(None),
-        |row| {
-            // Map the row to a DynamicEntity
-            let entity = dynamic_entity_mapper::map_row_to_entity(&row, entity_type, &entity_def);
-            Ok(Some(entity))
-        },
-    )
+    for row in &rows {
+        let uuid: Uuid = row
+            .try_get("uuid")
+            .map_err(r_data_core_core::error::Error::Database)?;
+        let entity = dynamic_entity_mapper::map_row_to_entity(row, entity_type, &entity_def);
+        found.insert(uuid, Some(entity));
+    }
+
+    Ok(found)
 }
 
 /// Get all entities of a specific type with pagination
@@ -382,18 +563,23 @@ pub async fn get_all_by_type_impl(
 
     // Get the entity definition to understand entity structure
     let entity_def = dynamic_entity_utils::get_entity_definition(
-        &repo.pool,
+        repo.read_pool(),
         entity_type,
         repo.cache_manager.clone(),
     )
     .await?;
 
     // Get the view name
-    let view_name = dynamic_entity_utils::get_view_name(entity_type);
+    let view_name = dynamic_entity_utils::quoted_view_name(entity_type)?;
 
     // Build the query with field selection
-    let query = exclusive_fields.map_or_else(
-        || format!("SELECT * FROM {view_name} ORDER BY created_at DESC LIMIT $1 OFFSET $2"),
+    let query = exclusive_fields.map_or_else::<Result<String>, _, _>(
+        || {
+            Ok(format!(
+                "SELECT * FROM {view_name} WHERE deleted_at IS NULL \
+                 ORDER BY created_at DESC LIMIT $1 OFFSET $2"
+            ))
+        },
         |fields| {
             // Always include system fields
             let mut selected_fields = vec![
@@ -409,23 +595,25 @@ pub async fn get_all_by_type_impl(
 
             // Add requested fields
             for field in fields {
-                if !selected_fields.contains(&field) {
-                    selected_fields.push(field.clone());
+                let column = dynamic_entity_utils::sql_column_name(&field)?;
+                if !selected_fields.contains(&column) {
+                    selected_fields.push(column);
                 }
             }
 
-            format!(
-                "SELECT {} FROM {view_name} ORDER BY created_at DESC LIMIT $1 OFFSET $2",
-                selected_fields.join(", ")
-            )
+            Ok(format!(
+                "SELECT {} FROM {view_name} WHERE deleted_at IS NULL \
+                 ORDER BY created_at DESC LIMIT $1 OFFSET $2",
+                dynamic_entity_utils::quote_ident_list(&selected_fields)?
+            ))
         },
-    );
+    )?;
 
     debug!("Query: {query}");
 
     // Query all entities with retry logic for schema changes
     let rows = fetch_all_with_retry(
-        &repo.pool,
+        repo.read_pool(),
         &query,
         vec![QueryBind::I64(limit), QueryBind::I64(offset)],
     )
@@ -446,6 +634,9 @@ pub async fn get_all_by_type_impl(
 
 /// Find a single entity by filters
 ///
+/// Reads from the primary: upserts and authentication look entities up through this before
+/// writing.
+///
 /// # Arguments
 /// * `repo` - Repository instance
 /// * `entity_type` - Type of entity to find
@@ -463,32 +654,117 @@ pub async fn find_one_by_filters_impl(
     // Use filter_entities with limit 1 to get first match
     let params = FilterEntitiesParams::new(1, 0).with_filters(Some(filters.clone()));
 
-    let entities =
-        crate::dynamic_entity_repository::filter::filter_entities_impl(repo, entity_type, &params)
-            .await?;
+    let entities = crate::dynamic_entity_repository::filter::filter_entities_on(
+        repo,
+        &repo.pool,
+        entity_type,
+        &params,
+    )
+    .await?;
 
     Ok(entities.first().cloned())
 }
 
-/// Delete an entity by type and UUID
+/// Soft-delete an entity by type and UUID
+///
+/// Marks the registry row with `deleted_at`; the entity table row is kept for history and audit.
+/// Entities referencing it through `ManyToOne` fields are handled by their `on_delete` policy
+/// within the same transaction.
 ///
 /// # Errors
-/// Returns an error if the database operation fails
+/// Returns `NotFound` if no live entity matches, `Conflict` if a `restrict` relation still has
+/// children, or an error if the database operation fails
 pub async fn delete_by_type_impl(
     repo: &DynamicEntityRepository,
     entity_type: &str,
     uuid: &Uuid,
 ) -> Result<()> {
-    debug!("Deleting entity of type {entity_type} with UUID {uuid}");
+    debug!("Soft-deleting entity of type {entity_type} with UUID {uuid}");
+
+    let mut tx = repo.pool.begin().await?;
+    let mut visited = HashSet::new();
+    soft_delete_with_policies(&mut tx, entity_type, *uuid, &mut visited).await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Restore a soft-deleted entity by type and UUID
+///
+/// # Errors
+/// Returns `NotFound` if no soft-deleted entity matches, `ValidationFailed` if a live entity
+/// already uses the same path and key, or an error if the database operation fails
+pub async fn restore_by_type_impl(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+    uuid: &Uuid,
+) -> Result<()> {
+    debug!("Restoring entity of type {entity_type} with UUID {uuid}");
+
+    let result = sqlx::query(
+        "UPDATE entities_registry SET deleted_at = NULL
+        WHERE uuid = $1 AND entity_type = $2 AND deleted_at IS NOT NULL",
+    )
+    .bind(uuid)
+    .bind(entity_type)
+    .execute(&repo.pool)
+    .await
+    .map_err(dynamic_entity_utils::map_registry_unique_violation)?;
+
+    if result.rows_affected() == 0 {
+        return Err(r_data_core_core::error::Error::NotFound(format!(
+            "Deleted entity {entity_type} with UUID {uuid} not found"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Whether an entity with this type and UUID exists but is soft-deleted
+///
+/// # Errors
+/// Returns an error if the database query fails
+pub async fn is_deleted_impl(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+    uuid: &Uuid,
+) -> Result<bool> {
+    let deleted: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM entities_registry
+        WHERE uuid = $1 AND entity_type = $2 AND deleted_at IS NOT NULL)",
+    )
+    .bind(uuid)
+    .bind(entity_type)
+    .fetch_one(&repo.pool)
+    .await?;
+
+    Ok(deleted)
+}
+
+/// Permanently delete an entity by type and UUID, including soft-deleted ones
+///
+/// Intended for GDPR erasure; versions are removed through the registry cascade.
+///
+/// # Errors
+/// Returns an error if the database operation fails
+pub async fn hard_delete_impl(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+    uuid: &Uuid,
+) -> Result<()> {
+    debug!("Hard-deleting entity of type {entity_type} with UUID {uuid}");
 
     // Get the table name
-    let table_name = dynamic_entity_utils::get_table_name(entity_type);
+    let table_name = dynamic_entity_utils::get_table_name(&repo.pool, entity_type).await?;
 
     // Start a transaction
     let mut tx = repo.pool.begin().await?;
 
     // First, delete from the entity-specific table
-    let query = format!("DELETE FROM {table_name} WHERE uuid = $1");
+    let query = format!(
+        "DELETE FROM {} WHERE uuid = $1",
+        dynamic_entity_utils::quote_ident(&table_name)?
+    );
 
     let result = sqlx::query(&query).bind(uuid).execute(&mut *tx).await;
 
@@ -518,12 +794,13 @@ pub async fn get_by_uuid_any_type_impl(
     uuid: &Uuid,
 ) -> Result<Option<DynamicEntity>> {
     // First, find the entity type from entities_registry
-    let entity_type_opt: Option<String> =
-        sqlx::query_scalar("SELECT entity_type FROM entities_registry WHERE uuid = $1")
-            .bind(uuid)
-            .fetch_optional(&repo.pool)
-            .await
-            .map_err(r_data_core_core::error::Error::Database)?;
+    let entity_type_opt: Option<String> = sqlx::query_scalar(
+        "SELECT entity_type FROM entities_registry WHERE uuid = $1 AND deleted_at IS NULL",
+    )
+    .bind(uuid)
+    .fetch_optional(&repo.pool)
+    .await
+    .map_err(r_data_core_core::error::Error::Database)?;
 
     match entity_type_opt {
         Some(entity_type) => {
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use r_data_core_core::error::Result;
+
+/// Most statements kept before the cache is emptied and refilled
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// Shape of a generated query: everything its SQL text depends on, but none of its values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SqlCacheKey {
+    /// Entity type the query reads
+    pub entity_type: String,
+    /// Selected columns, in order; empty for `SELECT *`
+    pub column_set: Vec<String>,
+    /// Query kind, definition version, filter fields and operators, sort and paging flags
+    pub filter_shape: String,
+}
+
+/// Generated SQL of the hot dynamic entity queries
+///
+/// Building a `get_by_type` or `filter_entities` statement validates and quotes every
+/// identifier and walks the entity definition. Statements of the same shape are identical, so
+/// they are built once and reused; values are always bound, which also lets sqlx reuse its
+/// prepared statement (and `PostgreSQL` its plan) for each distinct text.
+#[derive(Debug)]
+pub struct SqlCache {
+    statements: RwLock<HashMap<SqlCacheKey, Arc<str>>>,
+    capacity: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl Default for SqlCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl SqlCache {
+    /// Create a cache holding at most `capacity` statements
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            statements: RwLock::new(HashMap::new()),
+            capacity,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// SQL cached for `key`, calling `build` to generate it on a miss
+    ///
+    /// Build errors are returned and not cached. Once `capacity` is reached the cache is
+    /// emptied, so a burst of one-off shapes cannot grow it without bound.
+    ///
+    /// # Errors
+    /// Returns the error of `build`
+    pub fn get_or_build(
+        &self,
+        key: SqlCacheKey,
+        build: impl FnOnce() -> Result<String>,
+    ) -> Result<Arc<str>> {
+        if let Some(sql) = self
+            .statements
+            .read()
+            .ok()
+            .and_then(|statements| statements.get(&key).cloned())
+        {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(sql);
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let sql: Arc<str> = build()?.into();
+        if let Ok(mut statements) = self.statements.write() {
+            if statements.len() >= self.capacity {
+                statements.clear();
+            }
+            statements.insert(key, Arc::clone(&sql));
+        }
+        Ok(sql)
+    }
+
+    /// Lookups answered from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Lookups that had to build the statement
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Number of cached statements
+    pub fn len(&self) -> usize {
+        self.statements
+            .read()
+            .map_or(0, |statements| statements.len())
+    }
+
+    /// Whether no statement is cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn key(entity_type: &str, filter_shape: &str) -> SqlCacheKey {
+        SqlCacheKey {
+            entity_type: entity_type.to_string(),
+            column_set: vec!["uuid".to_string(), "name".to_string()],
+            filter_shape: filter_shape.to_string(),
+        }
+    }
+
+    #[test]
+    fn second_lookup_of_same_shape_is_a_hit() {
+        let cache = SqlCache::default();
+        let builds = Cell::new(0);
+        let build = || {
+            builds.set(builds.get() + 1);
+            Ok("SELECT \"uuid\", \"name\" FROM \"entity_customer_view\"".to_string())
+        };
+
+        let first = cache.get_or_build(key("customer", "get"), build).unwrap();
+        let second = cache.get_or_build(key("customer", "get"), build).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(builds.get(), 1);
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+    }
+
+    #[test]
+    fn different_shapes_are_built_separately() {
+        let cache = SqlCache::default();
+        cache
+            .get_or_build(key("customer", "get"), || Ok("a".to_string()))
+            .unwrap();
+        cache
+            .get_or_build(key("customer", "filter"), || Ok("b".to_string()))
+            .unwrap();
+        cache
+            .get_or_build(key("order", "get"), || Ok("c".to_string()))
+            .unwrap();
+
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.misses(), 3);
+    }
+
+    #[test]
+    fn build_errors_are_not_cached() {
+        let cache = SqlCache::default();
+        let result = cache.get_or_build(key("customer", "get"), || {
+            Err(r_data_core_core::error::Error::Validation(
+                "bad".to_string(),
+            ))
+        });
+        assert!(result.is_err());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn full_cache_is_emptied_before_inserting() {
+        let cache = SqlCache::new(2);
+        for shape in ["a", "b", "c"] {
+            cache
+                .get_or_build(key("customer", shape), || Ok(shape.to_string()))
+                .unwrap();
+        }
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -8,6 +8,7 @@ use crate::dynamic_entity_utils;
 use crate::dynamic_entity_versioning;
 use r_data_core_core::entity_definition::definition::EntityDefinition;
 use r_data_core_core::error::Result;
+use r_data_core_core::field::FieldType;
 use r_data_core_core::DynamicEntity;
 
 use super::{hash_if_password_field, DynamicEntityRepository};
@@ -23,6 +24,21 @@ fn try_parse_timestamp(s: &str) -> Option<OffsetDateTime> {
 /// # Errors
 /// Returns an error if the database operation fails or validation fails
 pub async fn update_entity(repo: &DynamicEntityRepository, entity: &DynamicEntity) -> Result<()> {
+    let mut tx = repo.pool.begin().await?;
+    update_entity_tx(repo, &mut tx, entity).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Update an existing dynamic entity within a caller-owned transaction
+///
+/// # Errors
+/// Returns an error if the database operation fails or validation fails
+pub async fn update_entity_tx(
+    repo: &DynamicEntityRepository,
+    tx: &mut Transaction<'_, Postgres>,
+    entity: &DynamicEntity,
+) -> Result<()> {
     // Get the entity definition for password field detection
     let entity_def = dynamic_entity_utils::get_entity_definition(
         &repo.pool,
@@ -43,15 +59,12 @@ pub async fn update_entity(repo: &DynamicEntityRepository, entity: &DynamicEntit
                 )
             })?;
 
-    // Start a transaction
-    let mut tx = repo.pool.begin().await?;
-
     // Get the current entity_type from the registry to avoid stale WHERE clauses
     let current_entity_type = sqlx::query_scalar::<_, Option<String>>(
         "SELECT entity_type FROM entities_registry WHERE uuid = $1",
     )
     .bind(uuid)
-    .fetch_one(&mut *tx)
+    .fetch_one(&mut **tx)
     .await?;
 
     // Check for internal flag to skip versioning (used by workflows with opt-out)
@@ -65,19 +78,14 @@ pub async fn update_entity(repo: &DynamicEntityRepository, entity: &DynamicEntit
         dynamic_entity_utils::extract_uuid_from_entity_field_data(&entity.field_data, "updated_by");
     if !skip_versioning {
         // Create snapshot BEFORE incrementing version - must be within transaction
-        dynamic_entity_versioning::snapshot_pre_update(&mut tx, uuid, updated_by).await?;
+        dynamic_entity_versioning::snapshot_pre_update(tx, uuid, updated_by).await?;
     }
 
     // Update entities_registry table
-    update_registry(&mut tx, entity, uuid).await?;
+    update_registry(tx, entity, uuid).await?;
 
     // Update entity-specific table
-    update_entity_table(&mut tx, entity, uuid, current_entity_type, &entity_def).await?;
-
-    // Commit the transaction
-    tx.commit().await?;
-
-    Ok(())
+    update_entity_table(tx, entity, uuid, current_entity_type, &entity_def).await
 }
 
 /// Update `entities_registry` table
@@ -184,7 +192,7 @@ async fn update_entity_table(
     // Use current_entity_type from the registry, not entity.entity_type
     // This ensures we're updating the correct table even if entity was created as different type
     let table_name = if let Some(ref current_type) = current_entity_type {
-        dynamic_entity_utils::get_table_name(current_type)
+        dynamic_entity_utils::get_table_name(&mut **tx, current_type).await?
     } else {
         return Err(r_data_core_core::error::Error::Database(
             sqlx::Error::RowNotFound,
@@ -213,7 +221,17 @@ async fn update_entity_table(
             let store_value = hash_if_password_field(key, value, entity_def)?;
 
             // Database columns are lowercase, so use lowercase for column name
-            set_clauses.push(format!("{key_lower} = ${param_index}"));
+            let column = dynamic_entity_utils::quote_ident(&key_lower)?;
+            // Durations are bound as ISO-8601 text, which Postgres casts to interval
+            let is_duration = entity_def
+                .fields
+                .iter()
+                .any(|f| f.name.eq_ignore_ascii_case(key) && f.field_type == FieldType::Duration);
+            if is_duration {
+                set_clauses.push(format!("{column} = ${param_index}::interval"));
+            } else {
+                set_clauses.push(format!("{column} = ${param_index}"));
+            }
             entity_params.push((param_index, store_value));
             param_index += 1;
         }
@@ -225,7 +243,7 @@ async fn update_entity_table(
         let uuid_pos = param_index;
         let update_entity_query = format!(
             "UPDATE {} SET {} WHERE uuid = ${}",
-            table_name,
+            dynamic_entity_utils::quote_ident(&table_name)?,
             set_clauses.join(", "),
             uuid_pos
         );
@@ -264,7 +282,9 @@ async fn update_entity_table(
             .execute(&mut **tx)
             .await
             .map(|_| ())
-            .map_err(|e| dynamic_entity_utils::map_entity_unique_violation(e, &table_name))?;
+            .map_err(|e| {
+                dynamic_entity_utils::map_entity_unique_violation(e, &table_name, entity_def)
+            })?;
     }
 
     Ok(())
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use uuid::Uuid;
 
 use r_data_core_core::error::Result;
+use r_data_core_core::sort::SortCollation;
 use r_data_core_core::DynamicEntity;
 
 /// Parameters for filtering entities
@@ -21,8 +22,14 @@ pub struct FilterEntitiesParams {
     pub search: Option<(String, Vec<String>)>,
     /// Sort parameters: (field, direction)
     pub sort: Option<(String, String)>,
+    /// How a text sort field is compared; ignored for other field types
+    pub collation: SortCollation,
     /// Fields to include in the result
     pub fields: Option<Vec<String>>,
+    /// Include soft-deleted entities (admin only)
+    pub include_deleted: bool,
+    /// Only return entities created by this user (row-level `Own` access)
+    pub owner: Option<Uuid>,
 }
 
 impl FilterEntitiesParams {
@@ -36,7 +43,10 @@ impl FilterEntitiesParams {
             filter_operators: None,
             search: None,
             sort: None,
+            collation: SortCollation::Default,
             fields: None,
+            include_deleted: false,
+            owner: None,
         }
     }
 
@@ -68,12 +78,33 @@ impl FilterEntitiesParams {
         self
     }
 
+    /// Set the collation for text sort fields
+    #[must_use]
+    pub const fn with_collation(mut self, collation: SortCollation) -> Self {
+        self.collation = collation;
+        self
+    }
+
     /// Set fields to include
     #[must_use]
     pub fn with_fields(mut self, fields: Option<Vec<String>>) -> Self {
         self.fields = fields;
         self
     }
+
+    /// Include soft-deleted entities
+    #[must_use]
+    pub const fn with_include_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = include_deleted;
+        self
+    }
+
+    /// Restrict results to entities created by `owner`
+    #[must_use]
+    pub const fn with_owner(mut self, owner: Option<Uuid>) -> Self {
+        self.owner = owner;
+        self
+    }
 }
 
 /// Trait defining the contract for dynamic entity repositories
@@ -96,6 +127,17 @@ pub trait DynamicEntityRepositoryTrait: Send + Sync {
         exclusive_fields: Option<Vec<String>>,
     ) -> Result<Option<DynamicEntity>>;
 
+    /// Get several entities of a type by UUID in a single query
+    ///
+    /// Every requested UUID is a key of the result; UUIDs without a live entity of this type
+    /// map to `None`.
+    async fn get_many_by_type(
+        &self,
+        entity_type: &str,
+        uuids: &[Uuid],
+        exclusive_fields: Option<Vec<String>>,
+    ) -> Result<HashMap<Uuid, Option<DynamicEntity>>>;
+
     /// Create a new dynamic entity
     /// Returns the UUID
     async fn create(&self, entity: &DynamicEntity) -> Result<Uuid>;
@@ -103,9 +145,18 @@ pub trait DynamicEntityRepositoryTrait: Send + Sync {
     /// Update an existing dynamic entity
     async fn update(&self, entity: &DynamicEntity) -> Result<()>;
 
-    /// Delete a dynamic entity by type and UUID
+    /// Soft-delete a dynamic entity by type and UUID
     async fn delete_by_type(&self, entity_type: &str, uuid: &Uuid) -> Result<()>;
 
+    /// Restore a soft-deleted dynamic entity by type and UUID
+    async fn restore_by_type(&self, entity_type: &str, uuid: &Uuid) -> Result<()>;
+
+    /// Permanently remove a dynamic entity (GDPR erasure)
+    async fn hard_delete(&self, entity_type: &str, uuid: &Uuid) -> Result<()>;
+
+    /// Whether an entity with this type and UUID exists but is soft-deleted
+    async fn is_deleted(&self, entity_type: &str, uuid: &Uuid) -> Result<bool>;
+
     /// Filter entities by field values with advanced options
     async fn filter_entities(
         &self,
@@ -116,6 +167,18 @@ pub trait DynamicEntityRepositoryTrait: Send + Sync {
     /// Count entities of a specific type
     async fn count_entities(&self, entity_type: &str) -> Result<i64>;
 
+    /// Count soft-deleted entities of a specific type
+    async fn count_deleted_entities(&self, entity_type: &str) -> Result<i64>;
+
+    /// Count live entities of a specific type created by `owner`
+    async fn count_entities_by_owner(&self, entity_type: &str, owner: &Uuid) -> Result<i64>;
+
+    /// Estimate the number of entities of a specific type from the table statistics
+    ///
+    /// The estimate includes soft-deleted entities. Returns `None` if the table has not been
+    /// analyzed yet.
+    async fn estimate_entities(&self, entity_type: &str) -> Result<Option<i64>>;
+
     /// Count children for an entity
     async fn count_children(&self, parent_uuid: &Uuid) -> Result<i64>;
 
@@ -1,7 +1,8 @@
 use r_data_core_core::cache::CacheManager;
 use r_data_core_core::entity_definition::definition::EntityDefinition;
 use r_data_core_core::error::Result;
-use r_data_core_core::field::FieldDefinition;
+use r_data_core_core::field::{FieldDefinition, FieldType, RESERVED_SQL_KEYWORDS};
+use r_data_core_core::sort::SortCollation;
 use serde_json::{self, Value as JsonValue};
 use sqlx::{PgPool, Row};
 use std::sync::Arc;
@@ -14,6 +15,8 @@ fn cache_key_by_entity_type(entity_type: &str) -> String {
 
 /// Get a entity definition by entity type
 ///
+/// The fields include those inherited from the definitions it extends.
+///
 /// # Errors
 /// Returns an error if the database query fails or the entity type is not found
 pub async fn get_entity_definition(
@@ -32,7 +35,8 @@ pub async fn get_entity_definition(
         "
         SELECT entity_type, display_name, description,
                group_name, allow_children, icon, created_by,
-               field_definitions
+               entity_definition_fields(uuid) AS field_definitions, unique_constraints,
+               validation_rules, table_name
         FROM entity_definitions
         WHERE entity_type = $1
         ",
@@ -51,7 +55,7 @@ pub async fn get_entity_definition(
         )
         .map_err(r_data_core_core::error::Error::Serialization)?;
 
-        let definition = EntityDefinition::from_params(
+        let mut definition = EntityDefinition::from_params(
             r_data_core_core::entity_definition::definition::EntityDefinitionParams {
                 entity_type: row
                     .try_get("entity_type")
@@ -77,6 +81,20 @@ pub async fn get_entity_definition(
                     .map_err(r_data_core_core::error::Error::Database)?,
             },
         );
+        definition.unique_constraints = serde_json::from_value(
+            row.try_get("unique_constraints")
+                .map_err(r_data_core_core::error::Error::Database)?,
+        )
+        .map_err(r_data_core_core::error::Error::Serialization)?;
+        definition.validation_rules = serde_json::from_value(
+            row.try_get("validation_rules")
+                .map_err(r_data_core_core::error::Error::Database)?,
+        )
+        .map_err(r_data_core_core::error::Error::Serialization)?;
+        definition.table_name = row
+            .try_get("table_name")
+            .map_err(r_data_core_core::error::Error::Database)?;
+        definition.sort_fields();
 
         // Cache the result if cache manager is provided
         if let Some(cache) = &cache_manager {
@@ -94,24 +112,213 @@ pub async fn get_entity_definition(
     }
 }
 
+/// Longest identifier `PostgreSQL` stores without truncating it
+const MAX_SQL_IDENTIFIER_LEN: usize = 63;
+
+/// Validate a table or column name before it is interpolated into SQL.
+///
+/// Only `[a-z_][a-z0-9_]*` of at most 63 bytes is accepted and reserved keywords are rejected.
+/// Callers lowercase names first, as `PostgreSQL` folds unquoted identifiers anyway, and
+/// interpolate the result through [`quote_ident`].
+///
+/// # Errors
+/// Returns a validation error naming the rejected identifier
+pub fn validate_sql_identifier(identifier: &str) -> Result<()> {
+    let mut chars = identifier.chars();
+    let valid_start = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_');
+    let valid_rest = chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid_start || !valid_rest || identifier.len() > MAX_SQL_IDENTIFIER_LEN {
+        return Err(r_data_core_core::error::Error::Validation(format!(
+            "Invalid SQL identifier '{identifier}'"
+        )));
+    }
+    if RESERVED_SQL_KEYWORDS.contains(&identifier) {
+        return Err(r_data_core_core::error::Error::Validation(format!(
+            "SQL identifier '{identifier}' is a reserved keyword"
+        )));
+    }
+    Ok(())
+}
+
+/// Validate an identifier and double-quote it for interpolation into SQL
+///
+/// Every table, view and column name in dynamically built SQL goes through here; anything
+/// [`validate_sql_identifier`] rejects never reaches the statement.
+///
+/// # Errors
+/// Returns a validation error if the name is not a safe identifier
+pub fn quote_ident(identifier: &str) -> Result<String> {
+    validate_sql_identifier(identifier)?;
+    Ok(format!("\"{identifier}\""))
+}
+
+/// Comma-separated list of quoted identifiers, e.g. for a `SELECT` or `INSERT` column list
+///
+/// # Errors
+/// Returns a validation error if any name is not a safe identifier
+pub fn quote_ident_list<S: AsRef<str>>(identifiers: &[S]) -> Result<String> {
+    Ok(identifiers
+        .iter()
+        .map(|identifier| quote_ident(identifier.as_ref()))
+        .collect::<Result<Vec<_>>>()?
+        .join(", "))
+}
+
+/// Lowercase a field name and validate it for use as a column identifier
+///
+/// Returns the bare column name, for comparing with catalog columns or reading rows; use
+/// [`quoted_column_name`] to interpolate it into SQL.
+///
+/// # Errors
+/// Returns a validation error if the name is not a safe identifier
+pub fn sql_column_name(field_name: &str) -> Result<String> {
+    let column = field_name.to_lowercase();
+    validate_sql_identifier(&column)?;
+    Ok(column)
+}
+
+/// Lowercase a field name and quote it as a column identifier
+///
+/// # Errors
+/// Returns a validation error if the name is not a safe identifier
+pub fn quoted_column_name(field_name: &str) -> Result<String> {
+    quote_ident(&field_name.to_lowercase())
+}
+
+/// Text system columns of every entity view
+const TEXT_SYSTEM_COLUMNS: &[&str] = &["path", "entity_key"];
+
+/// `ORDER BY` expression for `field`, applying `collation` to text-typed columns only
+///
+/// Other columns (numbers, dates, booleans, ...) ignore the collation and sort as stored.
+///
+/// # Errors
+/// Returns a validation error if the name is not a safe identifier
+pub fn sort_expression(
+    entity_def: &EntityDefinition,
+    field_name: &str,
+    collation: SortCollation,
+) -> Result<String> {
+    let column = sql_column_name(field_name)?;
+    let is_text = TEXT_SYSTEM_COLUMNS.contains(&column.as_str())
+        || entity_def.fields.iter().any(|field| {
+            field.name.to_lowercase() == column
+                && matches!(
+                    field.field_type,
+                    FieldType::String | FieldType::Text | FieldType::Wysiwyg
+                )
+        });
+    let column = quote_ident(&column)?;
+    Ok(if is_text {
+        collation.apply(&column)
+    } else {
+        column
+    })
+}
+
+/// Condition comparing the amount of a `Money` column within one currency
+///
+/// Binds the currency code at `$param_index` and the amount, as decimal text, at
+/// `$param_index + 1`. Values in another currency never match.
+///
+/// # Errors
+/// Returns a validation error if the name is not a safe identifier or `operator` is not a
+/// comparison
+pub fn money_amount_condition(
+    field_name: &str,
+    operator: &str,
+    param_index: i32,
+) -> Result<String> {
+    let column = quoted_column_name(field_name)?;
+    if !matches!(operator, "=" | ">" | "<" | ">=" | "<=") {
+        return Err(r_data_core_core::error::Error::Validation(format!(
+            "Operator '{operator}' cannot compare money field '{field_name}'"
+        )));
+    }
+    Ok(format!(
+        "({column}->>'currency' = ${param_index} AND ({column}->>'amount')::numeric {operator} ${}::numeric)",
+        param_index + 1
+    ))
+}
+
+/// Condition comparing a `Duration` (interval) column with the interval bound at `$param_index`
+///
+/// # Errors
+/// Returns a validation error if the name is not a safe identifier or `operator` is not a
+/// comparison
+pub fn duration_condition(field_name: &str, operator: &str, param_index: i32) -> Result<String> {
+    let column = quoted_column_name(field_name)?;
+    if !matches!(operator, "=" | ">" | "<" | ">=" | "<=") {
+        return Err(r_data_core_core::error::Error::Validation(format!(
+            "Operator '{operator}' cannot compare duration field '{field_name}'"
+        )));
+    }
+    Ok(format!("{column} {operator} ${param_index}"))
+}
+
 /// Get the view name for an entity type
-#[must_use]
-pub fn get_view_name(entity_type: &str) -> String {
-    format!("entity_{}_view", entity_type.to_lowercase())
+///
+/// # Errors
+/// Returns a validation error if the entity type does not form a safe identifier
+pub fn get_view_name(entity_type: &str) -> Result<String> {
+    let view_name = format!("entity_{}_view", entity_type.to_lowercase());
+    validate_sql_identifier(&view_name)?;
+    Ok(view_name)
+}
+
+/// View name for an entity type, quoted for interpolation into SQL
+///
+/// # Errors
+/// Returns a validation error if the entity type does not form a safe identifier
+pub fn quoted_view_name(entity_type: &str) -> Result<String> {
+    quote_ident(&get_view_name(entity_type)?)
 }
 
 /// Get the table name for an entity type
-#[must_use]
-pub fn get_table_name(entity_type: &str) -> String {
-    format!("entity_{}", entity_type.to_lowercase())
+///
+/// The `table_name` set on the entity definition, falling back to [`default_table_name`].
+///
+/// # Errors
+/// Returns an error if the database query fails or the name is not a safe identifier
+pub async fn get_table_name<'e, E>(executor: E, entity_type: &str) -> Result<String>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+{
+    let table_name: Option<String> =
+        sqlx::query_scalar("SELECT table_name FROM entity_definitions WHERE entity_type = $1")
+            .bind(entity_type)
+            .fetch_optional(executor)
+            .await?
+            .flatten();
+    match table_name {
+        Some(table_name) => {
+            validate_sql_identifier(&table_name)?;
+            Ok(table_name)
+        }
+        None => default_table_name(entity_type),
+    }
+}
+
+/// Table name derived from the entity type, used unless the definition sets one
+///
+/// # Errors
+/// Returns a validation error if the entity type does not form a safe identifier
+pub fn default_table_name(entity_type: &str) -> Result<String> {
+    let table_name = format!("entity_{}", entity_type.to_lowercase());
+    validate_sql_identifier(&table_name)?;
+    Ok(table_name)
 }
 
 /// Build a dynamic WHERE clause from filters
-#[must_use]
+///
+/// # Errors
+/// Returns a validation error if a filtered field is not a safe identifier
 pub fn build_where_clause<H: std::hash::BuildHasher>(
     filters: &std::collections::HashMap<String, JsonValue, H>,
     entity_def: &EntityDefinition,
-) -> (String, Vec<String>) {
+) -> Result<(String, Vec<String>)> {
     let mut where_clauses = Vec::new();
     let mut params = Vec::new();
     let mut param_idx = 1;
@@ -119,6 +326,7 @@ pub fn build_where_clause<H: std::hash::BuildHasher>(
     // Add filters based on field types
     for (field_name, value) in filters {
         if let Some(field_def) = entity_def.get_field(field_name) {
+            let field_name = quoted_column_name(field_name)?;
             match field_def.field_type {
                 r_data_core_core::field::types::FieldType::String
                 | r_data_core_core::field::types::FieldType::Integer
@@ -164,7 +372,7 @@ pub fn build_where_clause<H: std::hash::BuildHasher>(
         format!("1=1 AND {}", where_clauses.join(" AND "))
     };
 
-    (clause, params)
+    Ok((clause, params))
 }
 
 /// Extract UUID from a `JsonValue` field
@@ -256,14 +464,30 @@ pub fn map_registry_unique_violation(err: sqlx::Error) -> r_data_core_core::erro
 
 /// Map a sqlx unique constraint violation on an entity-specific table to a validation error,
 /// extracting the field name from the constraint.
+///
+/// Violations of the definition's `unique_constraints` become `Error::Validation` naming the
+/// constraint and its fields.
 /// Non-unique-violation errors are mapped to `Error::Database`.
 #[must_use]
 pub fn map_entity_unique_violation(
     err: sqlx::Error,
     table_name: &str,
+    entity_def: &EntityDefinition,
 ) -> r_data_core_core::error::Error {
     if let sqlx::Error::Database(ref db_err) = err {
         if db_err.code().as_deref() == Some("23505") {
+            if let Some(constraint) = db_err.constraint() {
+                if let Some(fields) = entity_def
+                    .unique_constraints
+                    .iter()
+                    .find(|fields| entity_def.unique_constraint_name(fields) == constraint)
+                {
+                    return r_data_core_core::error::Error::Validation(format!(
+                        "Unique constraint '{constraint}' violated: a record with the same values for ({}) already exists",
+                        fields.join(", ")
+                    ));
+                }
+            }
             let field_name = extract_field_from_unique_constraint(db_err.constraint(), table_name);
             return r_data_core_core::error::Error::ValidationFailed(format!(
                 "Field '{field_name}' must be unique. A record with this value already exists."
@@ -277,6 +501,22 @@ pub fn map_entity_unique_violation(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_money_amount_condition_compares_within_currency() {
+        assert_eq!(
+            money_amount_condition("Price", ">=", 3).unwrap(),
+            "(\"price\"->>'currency' = $3 AND (\"price\"->>'amount')::numeric >= $4::numeric)"
+        );
+        assert!(money_amount_condition("price", "IN", 1).is_err());
+        assert!(money_amount_condition("price; drop", "=", 1).is_err());
+    }
+
+    #[test]
+    fn test_duration_condition_compares_intervals() {
+        assert_eq!(duration_condition("Sla", ">", 2).unwrap(), "\"sla\" > $2");
+        assert!(duration_condition("sla", "NOT IN", 1).is_err());
+    }
+
     mod extract_field_from_unique_constraint_tests {
         use super::*;
 
@@ -320,4 +560,125 @@ mod tests {
             assert_eq!(result, "unknown");
         }
     }
+    mod sql_identifier_tests {
+        use super::*;
+
+        const UNSAFE: &[&str] = &[
+            "name'; DROP TABLE users; --",
+            "name\"; DROP TABLE users; --",
+            "\"name\"",
+            "first name",
+            "name;",
+            "name--",
+            "1name",
+            "",
+            "na-me",
+            "näme",
+        ];
+
+        #[test]
+        fn test_valid_identifiers_pass() {
+            for identifier in ["name", "_private", "field_2", "entity_customer_view"] {
+                assert!(validate_sql_identifier(identifier).is_ok(), "{identifier}");
+            }
+            assert!(validate_sql_identifier(&"a".repeat(MAX_SQL_IDENTIFIER_LEN)).is_ok());
+        }
+
+        #[test]
+        fn test_unsafe_identifiers_are_rejected() {
+            for identifier in UNSAFE {
+                assert!(validate_sql_identifier(identifier).is_err(), "{identifier}");
+            }
+            assert!(validate_sql_identifier("Name").is_err());
+            assert!(validate_sql_identifier(&"a".repeat(MAX_SQL_IDENTIFIER_LEN + 1)).is_err());
+        }
+
+        #[test]
+        fn test_reserved_keywords_are_rejected() {
+            for identifier in ["select", "table", "order", "user"] {
+                let err = validate_sql_identifier(identifier).unwrap_err();
+                assert!(err.to_string().contains("reserved keyword"), "{err}");
+            }
+        }
+
+        #[test]
+        fn test_column_names_are_lowercased_then_validated() {
+            assert_eq!(sql_column_name("FirstName").unwrap(), "firstname");
+            for identifier in UNSAFE {
+                assert!(sql_column_name(identifier).is_err(), "{identifier}");
+            }
+        }
+
+        #[test]
+        fn test_identifiers_are_quoted_after_validation() {
+            assert_eq!(
+                quote_ident("entity_customer").unwrap(),
+                "\"entity_customer\""
+            );
+            assert_eq!(quoted_column_name("FirstName").unwrap(), "\"firstname\"");
+            assert_eq!(
+                quoted_view_name("Customer").unwrap(),
+                "\"entity_customer_view\""
+            );
+            assert_eq!(
+                quote_ident_list(&["uuid", "name"]).unwrap(),
+                "\"uuid\", \"name\""
+            );
+            for identifier in UNSAFE {
+                assert!(quote_ident(identifier).is_err(), "{identifier}");
+                assert!(quoted_column_name(identifier).is_err(), "{identifier}");
+                assert!(
+                    quote_ident_list(&["uuid", identifier]).is_err(),
+                    "{identifier}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_conditions_and_sorts_reject_unsafe_field_names() {
+            let definition = EntityDefinition::default();
+            for field in UNSAFE {
+                assert!(money_amount_condition(field, "=", 1).is_err(), "{field}");
+                assert!(duration_condition(field, "=", 1).is_err(), "{field}");
+                assert!(
+                    sort_expression(&definition, field, SortCollation::Ci).is_err(),
+                    "{field}"
+                );
+            }
+            assert_eq!(
+                sort_expression(&definition, "path", SortCollation::Ci).unwrap(),
+                "lower(\"path\")"
+            );
+        }
+
+        #[test]
+        fn test_table_and_view_names_reject_unsafe_entity_types() {
+            assert_eq!(default_table_name("Customer").unwrap(), "entity_customer");
+            assert_eq!(get_view_name("customer").unwrap(), "entity_customer_view");
+            for entity_type in UNSAFE
+                .iter()
+                .filter(|t| !t.is_empty() && !t.starts_with('1'))
+            {
+                assert!(default_table_name(entity_type).is_err(), "{entity_type}");
+                assert!(get_view_name(entity_type).is_err(), "{entity_type}");
+                assert!(quoted_view_name(entity_type).is_err(), "{entity_type}");
+            }
+        }
+
+        #[test]
+        fn test_where_clause_rejects_unsafe_field_names() {
+            let field = FieldDefinition::new(
+                "Bad Name".to_string(),
+                "Bad".to_string(),
+                r_data_core_core::field::FieldType::String,
+            );
+            let mut definition = EntityDefinition::default();
+            definition.fields.push(field);
+            let filters = std::collections::HashMap::from([(
+                "Bad Name".to_string(),
+                JsonValue::String("x".to_string()),
+            )]);
+            assert!(build_where_clause(&filters, &definition).is_err());
+        }
+    }
 }
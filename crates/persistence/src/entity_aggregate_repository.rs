@@ -0,0 +1,147 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use sqlx::{PgPool, Row};
+
+use crate::dynamic_entity_utils;
+use crate::entity_aggregate_repository_trait::EntityAggregateRepositoryTrait;
+use r_data_core_core::entity_aggregate::{AggregateDelta, EntityAggregate};
+use r_data_core_core::error::{Error, Result};
+
+const SELECT_COLUMNS: &str =
+    "SELECT entity_type, sum_fields, entity_count, sums, rebuilt_at, updated_at FROM entity_aggregates";
+
+/// Repository for cached per-entity-type aggregates
+pub struct EntityAggregateRepository {
+    pool: PgPool,
+}
+
+impl EntityAggregateRepository {
+    /// Create a new entity aggregate repository
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Decode an `EntityAggregate` from a raw `sqlx::postgres::PgRow`
+fn row_to_aggregate(row: &sqlx::postgres::PgRow) -> Result<EntityAggregate> {
+    let sums: Value = row.try_get("sums")?;
+    Ok(EntityAggregate {
+        entity_type: row.try_get("entity_type")?,
+        sum_fields: row.try_get("sum_fields")?,
+        entity_count: row.try_get("entity_count")?,
+        sums: serde_json::from_value::<BTreeMap<String, f64>>(sums)?,
+        rebuilt_at: row.try_get("rebuilt_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait]
+impl EntityAggregateRepositoryTrait for EntityAggregateRepository {
+    async fn get(&self, entity_type: &str) -> Result<Option<EntityAggregate>> {
+        let row = sqlx::query(&format!("{SELECT_COLUMNS} WHERE entity_type = $1"))
+            .bind(entity_type)
+            .fetch_optional(&self.pool)
+            .await?;
+        row.as_ref().map(row_to_aggregate).transpose()
+    }
+
+    async fn list(&self) -> Result<Vec<EntityAggregate>> {
+        let rows = sqlx::query(&format!("{SELECT_COLUMNS} ORDER BY entity_type"))
+            .fetch_all(&self.pool)
+            .await?;
+        rows.iter().map(row_to_aggregate).collect()
+    }
+
+    async fn upsert_config(&self, entity_type: &str, sum_fields: &[String]) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO entity_aggregates (entity_type, sum_fields)
+            VALUES ($1, $2)
+            ON CONFLICT (entity_type)
+            DO UPDATE SET sum_fields = EXCLUDED.sum_fields, updated_at = NOW()",
+        )
+        .bind(entity_type)
+        .bind(sum_fields)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, entity_type: &str) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM entity_aggregates WHERE entity_type = $1")
+            .bind(entity_type)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn apply_delta(&self, entity_type: &str, delta: &AggregateDelta) -> Result<()> {
+        // The row lock taken by the UPDATE serialises concurrent writes to the same type
+        sqlx::query(
+            "UPDATE entity_aggregates a
+            SET entity_count = a.entity_count + $2,
+                sums = COALESCE((
+                    SELECT jsonb_object_agg(
+                        f,
+                        COALESCE((a.sums ->> f)::numeric, 0)
+                            + COALESCE(($3::jsonb ->> f)::numeric, 0)
+                            - COALESCE(($4::jsonb ->> f)::numeric, 0)
+                    )
+                    FROM unnest(a.sum_fields) AS f
+                ), '{}'::jsonb),
+                updated_at = NOW()
+            WHERE a.entity_type = $1",
+        )
+        .bind(entity_type)
+        .bind(delta.count)
+        .bind(Value::Object(delta.add.clone()))
+        .bind(Value::Object(delta.subtract.clone()))
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn rebuild(&self, entity_type: &str) -> Result<EntityAggregate> {
+        let aggregate = self.get(entity_type).await?.ok_or_else(|| {
+            Error::NotFound(format!(
+                "Aggregates are not enabled for entity type '{entity_type}'"
+            ))
+        })?;
+
+        let view_name = dynamic_entity_utils::quoted_view_name(entity_type)?;
+        let mut sum_exprs = Vec::with_capacity(aggregate.sum_fields.len());
+        for (i, field) in aggregate.sum_fields.iter().enumerate() {
+            let column = dynamic_entity_utils::quoted_column_name(field)?;
+            sum_exprs.push(format!(
+                "${}::text, COALESCE(SUM({column}), 0)::numeric",
+                i + 2
+            ));
+        }
+        let sql = format!(
+            "UPDATE entity_aggregates a
+            SET entity_count = s.entity_count, sums = s.sums, rebuilt_at = NOW(), updated_at = NOW()
+            FROM (
+                SELECT COUNT(*) AS entity_count, jsonb_build_object({}) AS sums
+                FROM {view_name}
+                WHERE deleted_at IS NULL
+            ) s
+            WHERE a.entity_type = $1
+            RETURNING a.entity_type, a.sum_fields, a.entity_count, a.sums, a.rebuilt_at, a.updated_at",
+            sum_exprs.join(", ")
+        );
+
+        let mut query = sqlx::query(&sql).bind(entity_type);
+        for field in &aggregate.sum_fields {
+            query = query.bind(field);
+        }
+        let row = query.fetch_one(&self.pool).await?;
+        row_to_aggregate(&row)
+    }
+}
@@ -0,0 +1,47 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use r_data_core_core::entity_aggregate::{AggregateDelta, EntityAggregate};
+use r_data_core_core::error::Result;
+
+/// Trait for cached entity aggregate repository operations
+#[async_trait]
+pub trait EntityAggregateRepositoryTrait: Send + Sync {
+    /// Get the aggregate of an entity type, if aggregates are enabled for it
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    async fn get(&self, entity_type: &str) -> Result<Option<EntityAggregate>>;
+
+    /// List all configured aggregates
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    async fn list(&self) -> Result<Vec<EntityAggregate>>;
+
+    /// Enable aggregates for an entity type or replace its summed fields
+    ///
+    /// The stored values are only valid again after a rebuild.
+    ///
+    /// # Errors
+    /// Returns an error if the database upsert fails
+    async fn upsert_config(&self, entity_type: &str, sum_fields: &[String]) -> Result<()>;
+
+    /// Disable aggregates for an entity type; returns whether they were enabled
+    ///
+    /// # Errors
+    /// Returns an error if the database delete fails
+    async fn delete(&self, entity_type: &str) -> Result<bool>;
+
+    /// Apply the delta of a single entity write; a no-op if aggregates are not enabled
+    ///
+    /// # Errors
+    /// Returns an error if the database update fails
+    async fn apply_delta(&self, entity_type: &str, delta: &AggregateDelta) -> Result<()>;
+
+    /// Recompute the aggregate from the live entities of the type
+    ///
+    /// # Errors
+    /// Returns `NotFound` if aggregates are not enabled, or an error if the database query fails
+    async fn rebuild(&self, entity_type: &str) -> Result<EntityAggregate>;
+}
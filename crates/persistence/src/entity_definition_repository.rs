@@ -100,7 +100,8 @@ impl EntityDefinitionRepository {
     /// # Errors
     /// Returns an error if the database query fails
     pub async fn count_view_records(&self, table_name: &str) -> Result<i64> {
-        let count = sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM {table_name}"))
+        let table = crate::dynamic_entity_utils::quote_ident(table_name)?;
+        let count = sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM {table}"))
             .fetch_one(&self.db_pool)
             .await
             .map_err(Error::Database)?;
@@ -146,108 +147,22 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
 
     /// Get a entity definition by UUID
     async fn get_by_uuid(&self, uuid: &Uuid) -> Result<Option<EntityDefinition>> {
-        // Use custom query with explicit type casting
-        let entity_def = sqlx::query!(
-            r#"
-            SELECT
-                uuid, entity_type, display_name, description, group_name,
-                allow_children, icon, field_definitions as "field_definitions: serde_json::Value",
-                created_at, updated_at,
-                created_by as "created_by: Uuid", updated_by,
-                published, version
-            FROM entity_definitions
-            WHERE uuid = $1
-            "#,
-            uuid
-        )
-        .fetch_optional(&self.db_pool)
-        .await
-        .map_err(Error::Database)?;
-
-        if let Some(entity_def) = entity_def {
-            // Create schema
-            let mut properties = HashMap::new();
-            properties.insert(
-                "entity_type".to_string(),
-                serde_json::Value::String(entity_def.entity_type.clone()),
-            );
-            let schema = r_data_core_core::entity_definition::schema::Schema::new(properties);
-
-            // Convert to EntityDefinition
-            let definition = EntityDefinition {
-                uuid: entity_def.uuid,
-                entity_type: entity_def.entity_type,
-                display_name: entity_def.display_name,
-                description: entity_def.description,
-                group_name: entity_def.group_name,
-                allow_children: entity_def.allow_children,
-                icon: entity_def.icon,
-                fields: serde_json::from_value(entity_def.field_definitions)
-                    .map_err(Error::Serialization)?,
-                schema,
-                created_at: entity_def.created_at,
-                updated_at: entity_def.updated_at,
-                created_by: entity_def.created_by,
-                updated_by: entity_def.updated_by,
-                published: entity_def.published,
-                version: entity_def.version,
-            };
-            Ok(Some(definition))
-        } else {
-            Ok(None)
-        }
+        sqlx::query_as::<_, EntityDefinition>("SELECT * FROM entity_definitions WHERE uuid = $1")
+            .bind(uuid)
+            .fetch_optional(&self.db_pool)
+            .await
+            .map_err(Error::Database)
     }
 
     /// Get a entity definition by entity type
     async fn get_by_entity_type(&self, entity_type: &str) -> Result<Option<EntityDefinition>> {
-        let entity_def = sqlx::query!(
-            r#"
-            SELECT
-                uuid, entity_type, display_name, description, group_name,
-                allow_children, icon, field_definitions as "field_definitions: serde_json::Value",
-                created_at, updated_at,
-                created_by as "created_by: Uuid", updated_by,
-                published, version
-            FROM entity_definitions
-            WHERE entity_type = $1
-            "#,
-            entity_type
+        sqlx::query_as::<_, EntityDefinition>(
+            "SELECT * FROM entity_definitions WHERE entity_type = $1",
         )
+        .bind(entity_type)
         .fetch_optional(&self.db_pool)
         .await
-        .map_err(Error::Database)?;
-
-        if let Some(entity_def) = entity_def {
-            // Create schema
-            let mut properties = HashMap::new();
-            properties.insert(
-                "entity_type".to_string(),
-                serde_json::Value::String(entity_def.entity_type.clone()),
-            );
-            let schema = r_data_core_core::entity_definition::schema::Schema::new(properties);
-
-            // Convert to EntityDefinition
-            Ok(Some(EntityDefinition {
-                uuid: entity_def.uuid,
-                entity_type: entity_def.entity_type,
-                display_name: entity_def.display_name,
-                description: entity_def.description,
-                group_name: entity_def.group_name,
-                allow_children: entity_def.allow_children,
-                icon: entity_def.icon,
-                fields: serde_json::from_value(entity_def.field_definitions)
-                    .map_err(Error::Serialization)?,
-                schema,
-                created_at: entity_def.created_at,
-                updated_at: entity_def.updated_at,
-                created_by: entity_def.created_by,
-                updated_by: entity_def.updated_by,
-                published: entity_def.published,
-                version: entity_def.version,
-            }))
-        } else {
-            Ok(None)
-        }
+        .map_err(Error::Database)
     }
 
     /// Create a new entity definition
@@ -263,6 +178,11 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
         let allow_children = definition.allow_children;
         let icon = definition.icon.as_ref();
         let fields = serde_json::to_value(&definition.fields).map_err(Error::Serialization)?;
+        let extends = &definition.extends;
+        let unique_constraints =
+            serde_json::to_value(&definition.unique_constraints).map_err(Error::Serialization)?;
+        let validation_rules =
+            serde_json::to_value(&definition.validation_rules).map_err(Error::Serialization)?;
         let created_at = definition.created_at;
         let updated_at = definition.updated_at;
         let created_by: Uuid = definition.created_by;
@@ -284,9 +204,14 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
         let query = "INSERT INTO entity_definitions
                     (entity_type, display_name, description, group_name, allow_children,
                      icon, field_definitions, created_at, updated_at, created_by, updated_by,
-                     published, version)
+                     published, version, extends, unique_constraints, validation_rules,
+                     table_name, owns_table)
                     VALUES
-                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17,
+                     NOT EXISTS (
+                         SELECT FROM information_schema.tables
+                         WHERE table_schema = current_schema() AND table_name = $18
+                     ))
                     RETURNING uuid";
 
         let result = sqlx::query_scalar::<_, Uuid>(query)
@@ -303,6 +228,11 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
             .bind(updated_by)
             .bind(published)
             .bind(version)
+            .bind(extends)
+            .bind(unique_constraints)
+            .bind(validation_rules)
+            .bind(&definition.table_name)
+            .bind(definition.get_table_name())
             .fetch_one(&self.db_pool)
             .await
             .map_err(|e| {
@@ -328,6 +258,11 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
         let allow_children = definition.allow_children;
         let icon = definition.icon.as_ref();
         let fields = serde_json::to_value(&definition.fields).map_err(Error::Serialization)?;
+        let extends = &definition.extends;
+        let unique_constraints =
+            serde_json::to_value(&definition.unique_constraints).map_err(Error::Serialization)?;
+        let validation_rules =
+            serde_json::to_value(&definition.validation_rules).map_err(Error::Serialization)?;
         let updated_at = definition.updated_at;
         let updated_by = definition.updated_by;
         let published = definition.published;
@@ -355,6 +290,10 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
                     updated_at = $8,
                     updated_by = $9,
                     published = $10,
+                    extends = $12,
+                    unique_constraints = $13,
+                    validation_rules = $14,
+                    table_name = $15,
                     version = version + 1
                     WHERE uuid = $11";
 
@@ -370,6 +309,10 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
             .bind(updated_by)
             .bind(published)
             .bind(uuid)
+            .bind(extends)
+            .bind(unique_constraints)
+            .bind(validation_rules)
+            .bind(&definition.table_name)
             .execute(&mut *tx)
             .await
             .map_err(Error::Database)?;
@@ -380,6 +323,153 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
         Ok(())
     }
 
+    /// Rename a field and its column
+    async fn rename_field(
+        &self,
+        uuid: &Uuid,
+        definition: &EntityDefinition,
+        old_name: &str,
+        new_name: &str,
+        rename_in_versions: bool,
+    ) -> Result<()> {
+        // Entity table columns are the lowercased field names
+        let table = crate::dynamic_entity_utils::quote_ident(&definition.get_table_name())?;
+        let old_column = old_name.to_lowercase();
+        let new_column = new_name.to_lowercase();
+        let fields = serde_json::to_value(&definition.fields).map_err(Error::Serialization)?;
+
+        let mut tx = self.db_pool.begin().await?;
+
+        EntityDefinitionVersioningRepository::snapshot_pre_update_tx(
+            &mut tx,
+            *uuid,
+            definition.updated_by,
+        )
+        .await?;
+
+        if old_column != new_column {
+            sqlx::query(&format!(
+                "ALTER TABLE {table} RENAME COLUMN {} TO {}",
+                crate::dynamic_entity_utils::quote_ident(&old_column)?,
+                crate::dynamic_entity_utils::quote_ident(&new_column)?
+            ))
+            .execute(&mut *tx)
+            .await
+            .map_err(Error::Database)?;
+
+            // Indexes follow the column, but unique constraint indexes are named after it
+            for fields in &definition.unique_constraints {
+                if !fields.iter().any(|f| f.eq_ignore_ascii_case(new_name)) {
+                    continue;
+                }
+                let old_fields: Vec<String> = fields
+                    .iter()
+                    .map(|f| {
+                        if f.eq_ignore_ascii_case(new_name) {
+                            old_name.to_string()
+                        } else {
+                            f.clone()
+                        }
+                    })
+                    .collect();
+                sqlx::query(&format!(
+                    "ALTER INDEX IF EXISTS {} RENAME TO {}",
+                    crate::dynamic_entity_utils::quote_ident(
+                        &definition.unique_constraint_name(&old_fields)
+                    )?,
+                    crate::dynamic_entity_utils::quote_ident(
+                        &definition.unique_constraint_name(fields)
+                    )?
+                ))
+                .execute(&mut *tx)
+                .await
+                .map_err(Error::Database)?;
+            }
+
+            if rename_in_versions {
+                sqlx::query(
+                    "UPDATE entities_versions
+                     SET data = (data - $2) || jsonb_build_object($3::text, data -> $2)
+                     WHERE entity_type = $1 AND data ? $2",
+                )
+                .bind(&definition.entity_type)
+                .bind(&old_column)
+                .bind(&new_column)
+                .execute(&mut *tx)
+                .await
+                .map_err(Error::Database)?;
+            }
+        }
+
+        // Storing the definition re-runs the table sync, which finds the renamed column in place
+        sqlx::query(
+            "UPDATE entity_definitions SET
+                field_definitions = $1,
+                updated_at = $2,
+                updated_by = $3,
+                unique_constraints = $5,
+                validation_rules = $6,
+                version = version + 1
+             WHERE uuid = $4",
+        )
+        .bind(fields)
+        .bind(definition.updated_at)
+        .bind(definition.updated_by)
+        .bind(uuid)
+        .bind(serde_json::to_value(&definition.unique_constraints).map_err(Error::Serialization)?)
+        .bind(serde_json::to_value(&definition.validation_rules).map_err(Error::Serialization)?)
+        .execute(&mut *tx)
+        .await
+        .map_err(Error::Database)?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find_table_owner(
+        &self,
+        table_name: &str,
+        exclude: Option<Uuid>,
+    ) -> Result<Option<String>> {
+        sqlx::query_scalar(
+            "SELECT entity_type FROM entity_definitions
+             WHERE lower(COALESCE(table_name, 'entity_' || lower(entity_type))) = lower($1)
+             AND uuid IS DISTINCT FROM $2
+             LIMIT 1",
+        )
+        .bind(table_name)
+        .bind(exclude)
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(Error::Database)
+    }
+
+    async fn is_foreign_table(&self, table_name: &str) -> Result<bool> {
+        // Entity tables key their rows by a uuid referencing entities_registry
+        sqlx::query_scalar(
+            "SELECT EXISTS (
+                 SELECT FROM pg_class c
+                 WHERE c.relnamespace = current_schema()::text::regnamespace
+                 AND c.relname = $1
+             ) AND NOT EXISTS (
+                 SELECT FROM pg_constraint con
+                 JOIN pg_class c ON c.oid = con.conrelid
+                 JOIN pg_attribute a ON a.attrelid = c.oid AND a.attnum = ANY (con.conkey)
+                 WHERE c.relnamespace = current_schema()::text::regnamespace
+                 AND c.relname = $1
+                 AND c.relkind = 'r'
+                 AND con.contype = 'f'
+                 AND con.confrelid = 'entities_registry'::regclass
+                 AND a.attname = 'uuid'
+             )",
+        )
+        .bind(table_name)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(Error::Database)
+    }
+
     /// Delete a entity definition
     async fn delete(&self, uuid: &Uuid) -> Result<()> {
         // First, get the entity definition to get the entity type
@@ -387,10 +477,23 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
 
         if let Some(entity_definition) = entity_definition_result {
             let table_name = entity_definition.get_table_name();
+            crate::dynamic_entity_utils::validate_sql_identifier(&table_name)?;
+
+            // A table that existed before the definition is left in place
+            let owns_table: bool =
+                sqlx::query_scalar("SELECT owns_table FROM entity_definitions WHERE uuid = $1")
+                    .bind(uuid)
+                    .fetch_one(&self.db_pool)
+                    .await
+                    .map_err(Error::Database)?;
 
             // Drop the entity table if it exists
             let table_exists = self.check_view_exists(&table_name).await?;
-            if table_exists {
+            if table_exists && !owns_table {
+                log::info!(
+                    "Keeping entity table {table_name}, which the definition did not create"
+                );
+            } else if table_exists {
                 // Also drop any relation tables if they exist
                 for field in &entity_definition.fields {
                     if field.field_type == FieldType::ManyToMany {
@@ -403,8 +506,10 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
                         let rel_table_exists = self.check_view_exists(&relation_table_name).await?;
                         if rel_table_exists {
                             log::info!("Dropping relation table: {relation_table_name}");
-                            let drop_rel_sql =
-                                format!("DROP TABLE IF EXISTS {relation_table_name} CASCADE");
+                            let drop_rel_sql = format!(
+                                "DROP TABLE IF EXISTS {} CASCADE",
+                                crate::dynamic_entity_utils::quote_ident(&relation_table_name)?
+                            );
                             sqlx::query(&drop_rel_sql)
                                 .execute(&self.db_pool)
                                 .await
@@ -415,7 +520,10 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
 
                 // Drop the entity table
                 log::info!("Dropping entity table: {table_name}");
-                let drop_entity_sql = format!("DROP TABLE IF EXISTS {table_name} CASCADE");
+                let drop_entity_sql = format!(
+                    "DROP TABLE IF EXISTS {} CASCADE",
+                    crate::dynamic_entity_utils::quote_ident(&table_name)?
+                );
                 sqlx::query(&drop_entity_sql)
                     .execute(&self.db_pool)
                     .await
@@ -480,6 +588,33 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
         // Apply the schema using the Rust-generated SQL
         self.apply_schema(&schema_sql).await?;
 
+        // Drop the indexes of unique constraints removed from the definition
+        let prefix = entity_definition.unique_constraint_prefix();
+        let wanted: Vec<String> = entity_definition
+            .unique_constraints
+            .iter()
+            .map(|fields| entity_definition.unique_constraint_name(fields))
+            .collect();
+        let indexes: Vec<String> = sqlx::query_scalar(
+            "SELECT indexname::text FROM pg_indexes
+             WHERE schemaname = current_schema() AND tablename = $1",
+        )
+        .bind(entity_definition.get_table_name())
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(Error::Database)?;
+        for index in indexes {
+            if index.starts_with(&prefix) && !wanted.contains(&index) {
+                sqlx::query(&format!(
+                    "DROP INDEX IF EXISTS {}",
+                    crate::dynamic_entity_utils::quote_ident(&index)?
+                ))
+                .execute(&self.db_pool)
+                .await
+                .map_err(Error::Database)?;
+            }
+        }
+
         // Clear the prepared statement cache to avoid "cached plan must not change result type" errors
         // This is necessary because the view structure may have changed.
         // DISCARD PLANS clears all cached plans for the current session
@@ -524,9 +659,13 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepository {
         for row in tables {
             if let Some(table_name) = row.table_name {
                 if !defined_tables.contains(&table_name) {
+                    let Ok(table) = crate::dynamic_entity_utils::quote_ident(&table_name) else {
+                        log::warn!("Skipping orphaned table with unsafe name: {table_name}");
+                        continue;
+                    };
                     // Table has no corresponding entity definition, drop it
                     log::info!("Dropping orphaned entity table: {table_name}");
-                    let drop_sql = format!("DROP TABLE IF EXISTS {table_name} CASCADE");
+                    let drop_sql = format!("DROP TABLE IF EXISTS {table} CASCADE");
 
                     sqlx::query(&drop_sql)
                         .execute(&self.db_pool)
@@ -0,0 +1,212 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use sqlx::{PgExecutor, Row};
+
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::field::{FieldDefinition, FieldType, OnDeletePolicy};
+
+/// A `ManyToOne` field on another entity type that points at entities of a parent type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChildRelation {
+    /// Entity type holding the reference
+    pub entity_type: String,
+    /// Column storing the parent UUID
+    pub column: String,
+    /// Policy applied when the parent is deleted
+    pub policy: OnDeletePolicy,
+}
+
+impl ChildRelation {
+    /// Qualified relation name, `<entity_type>.<field>`
+    #[must_use]
+    pub fn qualified_name(&self) -> String {
+        format!("{}.{}", self.entity_type, self.column)
+    }
+
+    /// Whether `name` refers to this relation by field, by child entity type, or qualified
+    #[must_use]
+    pub fn matches(&self, name: &str) -> bool {
+        name.eq_ignore_ascii_case(&self.column)
+            || name.eq_ignore_ascii_case(&self.entity_type)
+            || name.eq_ignore_ascii_case(&self.qualified_name())
+    }
+}
+
+/// Resolve a relation name (as accepted by [`ChildRelation::matches`]) to exactly one relation
+///
+/// # Errors
+/// Returns a validation error if no relation or more than one relation matches
+pub fn resolve_child_relation<'a>(
+    parent_type: &str,
+    relations: &'a [ChildRelation],
+    name: &str,
+) -> Result<&'a ChildRelation> {
+    let mut matching = relations.iter().filter(|r| r.matches(name));
+    match (matching.next(), matching.next()) {
+        (Some(relation), None) => Ok(relation),
+        (None, _) => Err(Error::Validation(format!(
+            "Unknown relation '{name}': no ManyToOne field references '{parent_type}'"
+        ))),
+        (Some(_), Some(_)) => Err(Error::Validation(format!(
+            "Ambiguous relation '{name}' for '{parent_type}'; use '<entity_type>.<field>'"
+        ))),
+    }
+}
+
+/// Collect the `ManyToOne` relations targeting `parent_type` from stored field definitions
+///
+/// # Errors
+/// Returns an error if a field definition cannot be parsed, declares an invalid policy or has a
+/// name that is not a safe SQL identifier
+pub fn child_relations_for(
+    parent_type: &str,
+    definitions: &[(String, Vec<FieldDefinition>)],
+) -> Result<Vec<ChildRelation>> {
+    let mut relations = Vec::new();
+    for (entity_type, fields) in definitions {
+        for field in fields {
+            if field.field_type != FieldType::ManyToOne {
+                continue;
+            }
+            let targets_parent = field
+                .validation
+                .target_class
+                .as_deref()
+                .is_some_and(|target| target.eq_ignore_ascii_case(parent_type));
+            if !targets_parent {
+                continue;
+            }
+            relations.push(ChildRelation {
+                entity_type: entity_type.clone(),
+                column: crate::dynamic_entity_utils::sql_column_name(&field.name)?,
+                policy: field.on_delete_policy()?,
+            });
+        }
+    }
+    Ok(relations)
+}
+
+/// Load all entity definitions and return the relations pointing at `parent_type`
+///
+/// # Errors
+/// Returns an error if the definitions cannot be loaded or parsed
+pub async fn load_child_relations<'e, E>(
+    executor: E,
+    parent_type: &str,
+) -> Result<Vec<ChildRelation>>
+where
+    E: PgExecutor<'e>,
+{
+    let rows = sqlx::query(
+        "SELECT entity_type, entity_definition_fields(uuid) AS field_definitions
+         FROM entity_definitions",
+    )
+    .fetch_all(executor)
+    .await
+    .map_err(Error::Database)?;
+
+    let mut definitions = Vec::with_capacity(rows.len());
+    for row in rows {
+        let entity_type: String = row.try_get("entity_type").map_err(Error::Database)?;
+        let fields_json: serde_json::Value =
+            row.try_get("field_definitions").map_err(Error::Database)?;
+        let fields: Vec<FieldDefinition> = serde_json::from_value(fields_json)?;
+        definitions.push((entity_type, fields));
+    }
+
+    child_relations_for(parent_type, &definitions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn many_to_one(name: &str, target: &str, on_delete: Option<&str>) -> FieldDefinition {
+        let mut field =
+            FieldDefinition::new(name.to_string(), name.to_string(), FieldType::ManyToOne);
+        field.validation.target_class = Some(target.to_string());
+        if let Some(policy) = on_delete {
+            field
+                .constraints
+                .insert("on_delete".to_string(), json!(policy));
+        }
+        field
+    }
+
+    #[test]
+    fn collects_relations_targeting_parent_type() {
+        let definitions = vec![
+            (
+                "order".to_string(),
+                vec![
+                    many_to_one("Customer", "customer", Some("cascade")),
+                    FieldDefinition::new("total".into(), "Total".into(), FieldType::Float),
+                ],
+            ),
+            (
+                "invoice".to_string(),
+                vec![many_to_one("customer_ref", "Customer", None)],
+            ),
+            (
+                "shipment".to_string(),
+                vec![many_to_one("order_ref", "order", Some("set_null"))],
+            ),
+        ];
+
+        let relations = child_relations_for("customer", &definitions).unwrap();
+        assert_eq!(
+            relations,
+            vec![
+                ChildRelation {
+                    entity_type: "order".to_string(),
+                    column: "customer".to_string(),
+                    policy: OnDeletePolicy::Cascade,
+                },
+                ChildRelation {
+                    entity_type: "invoice".to_string(),
+                    column: "customer_ref".to_string(),
+                    policy: OnDeletePolicy::NoAction,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_policy_is_rejected() {
+        let definitions = vec![(
+            "order".to_string(),
+            vec![many_to_one("customer", "customer", Some("orphan"))],
+        )];
+        assert!(child_relations_for("customer", &definitions).is_err());
+    }
+
+    #[test]
+    fn resolves_relation_by_field_type_or_qualified_name() {
+        let definitions = vec![
+            (
+                "order".to_string(),
+                vec![many_to_one("customer", "customer", None)],
+            ),
+            (
+                "invoice".to_string(),
+                vec![
+                    many_to_one("customer", "customer", None),
+                    many_to_one("payer", "customer", None),
+                ],
+            ),
+        ];
+        let relations = child_relations_for("customer", &definitions).unwrap();
+
+        let by_type = resolve_child_relation("customer", &relations, "order").unwrap();
+        assert_eq!(by_type.qualified_name(), "order.customer");
+        let by_field = resolve_child_relation("customer", &relations, "payer").unwrap();
+        assert_eq!(by_field.qualified_name(), "invoice.payer");
+        let qualified = resolve_child_relation("customer", &relations, "Invoice.Customer").unwrap();
+        assert_eq!(qualified.entity_type, "invoice");
+
+        assert!(resolve_child_relation("customer", &relations, "customer").is_err());
+        assert!(resolve_child_relation("customer", &relations, "invoice").is_err());
+        assert!(resolve_child_relation("customer", &relations, "shipment").is_err());
+    }
+}
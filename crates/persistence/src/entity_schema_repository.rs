@@ -0,0 +1,192 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Repository for inspecting and repairing the per-type entity tables.
+//!
+//! Entity tables (`entity_<type>` unless the definition names one) are built from entity
+//! definitions by database triggers. This module reads their actual shape so it can be
+//! compared with the definitions.
+
+use sqlx::PgPool;
+
+use crate::core::error::{Error, Result};
+use crate::dynamic_entity_utils::{quote_ident, quoted_view_name};
+
+/// Columns of a per-type entity table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityTableSchema {
+    /// Table name (`entity_<type>` or the definition's `table_name`)
+    pub table_name: String,
+    /// Column names, excluding the `uuid` key
+    pub columns: Vec<String>,
+}
+
+/// Columns an entity definition expects on its table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefinitionSchema {
+    /// Entity type of the definition
+    pub entity_type: String,
+    /// Table name set on the definition, if any
+    pub table_name: Option<String>,
+    /// Lower-cased names of the effective (inherited and own) fields
+    pub columns: Vec<String>,
+}
+
+impl DefinitionSchema {
+    /// Name of the table built for this definition
+    #[must_use]
+    pub fn table_name(&self) -> String {
+        self.table_name
+            .clone()
+            .unwrap_or_else(|| format!("entity_{}", self.entity_type.to_lowercase()))
+    }
+}
+
+/// Repository for entity table schemas
+#[derive(Clone)]
+pub struct EntitySchemaRepository {
+    pool: PgPool,
+}
+
+impl EntitySchemaRepository {
+    /// Create a new repository
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List the per-type entity tables with their columns
+    ///
+    /// Entity tables are the `entity_*` tables, and those named by a definition, keyed by a
+    /// reference to `entities_registry`.
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    pub async fn entity_tables(&self) -> Result<Vec<EntityTableSchema>> {
+        let rows: Vec<(String, Vec<String>)> = sqlx::query_as(
+            r"
+            SELECT t.relname::text,
+                   COALESCE(
+                       array_agg(a.attname::text ORDER BY a.attnum)
+                           FILTER (WHERE a.attname <> 'uuid'),
+                       '{}'
+                   )
+            FROM pg_class t
+            JOIN pg_namespace n ON n.oid = t.relnamespace
+            JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum > 0 AND NOT a.attisdropped
+            WHERE n.nspname = current_schema()
+              AND t.relkind = 'r'
+              AND (
+                  t.relname LIKE 'entity\_%'
+                  OR t.relname IN (
+                      SELECT d.table_name FROM entity_definitions d WHERE d.table_name IS NOT NULL
+                  )
+              )
+              AND EXISTS (
+                  SELECT 1 FROM pg_constraint c
+                  WHERE c.conrelid = t.oid
+                    AND c.contype = 'f'
+                    AND c.confrelid = 'entities_registry'::regclass
+              )
+            GROUP BY t.relname
+            ORDER BY t.relname
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(table_name, columns)| EntityTableSchema {
+                table_name,
+                columns,
+            })
+            .collect())
+    }
+
+    /// List every entity definition with the columns its table should have
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    pub async fn definition_schemas(&self) -> Result<Vec<DefinitionSchema>> {
+        let rows: Vec<(String, Option<String>, Vec<String>)> = sqlx::query_as(
+            r"
+            SELECT d.entity_type,
+                   d.table_name,
+                   COALESCE(
+                       (SELECT array_agg(DISTINCT lower(f->>'name'))
+                        FROM jsonb_array_elements(entity_definition_fields(d.uuid)) AS f),
+                       '{}'
+                   )
+            FROM entity_definitions d
+            ORDER BY d.entity_type
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(entity_type, table_name, columns)| DefinitionSchema {
+                entity_type,
+                table_name,
+                columns,
+            })
+            .collect())
+    }
+
+    /// Drop an orphaned entity table and its view
+    ///
+    /// The view is named after the entity type (`entity_<type>_view`) like
+    /// `create_entity_table_and_view` names it, not after the table. A default `entity_<type>`
+    /// table identifies its type; the view is kept when a definition of that type still uses it
+    /// on a mapped table. Views on any other table go with the table through `CASCADE`.
+    ///
+    /// # Errors
+    /// Returns an error if the table name is not a safe identifier or the statements fail
+    pub async fn drop_entity_table(&self, table_name: &str) -> Result<()> {
+        let table = quote_ident(table_name)?;
+        let mut tx = self.pool.begin().await.map_err(Error::Database)?;
+        if let Some(entity_type) = table_name.strip_prefix("entity_") {
+            let defined: bool = sqlx::query_scalar(
+                "SELECT EXISTS (
+                    SELECT 1 FROM entity_definitions WHERE lower(entity_type) = lower($1)
+                )",
+            )
+            .bind(entity_type)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(Error::Database)?;
+            if !defined {
+                sqlx::query(&format!(
+                    "DROP VIEW IF EXISTS {} CASCADE",
+                    quoted_view_name(entity_type)?
+                ))
+                .execute(&mut *tx)
+                .await
+                .map_err(Error::Database)?;
+            }
+        }
+        sqlx::query(&format!("DROP TABLE IF EXISTS {table} CASCADE"))
+            .execute(&mut *tx)
+            .await
+            .map_err(Error::Database)?;
+        tx.commit().await.map_err(Error::Database)
+    }
+
+    /// Rebuild the table and view of an entity definition from its fields
+    ///
+    /// Creates a missing table, adds missing columns and drops columns without a field.
+    ///
+    /// # Errors
+    /// Returns an error if the definition does not exist or the rebuild fails
+    pub async fn rebuild_entity_table(&self, entity_type: &str) -> Result<()> {
+        sqlx::query("SELECT create_entity_table_and_view($1)")
+            .bind(entity_type)
+            .execute(&self.pool)
+            .await
+            .map_err(Error::Database)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use uuid::Uuid;
+
+use crate::entity_webhook_repository_trait::EntityWebhookRepositoryTrait;
+use r_data_core_core::entity_webhook::EntityWebhookSubscription;
+use r_data_core_core::error::{Error, Result};
+
+const SELECT_COLUMNS: &str =
+    "SELECT uuid, entity_type, url, fields, active, created_at, created_by FROM entity_webhook_subscriptions";
+
+/// Repository for entity webhook subscriptions
+pub struct EntityWebhookRepository {
+    pool: PgPool,
+}
+
+impl EntityWebhookRepository {
+    /// Create a new entity webhook repository
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Decode an `EntityWebhookSubscription` from a raw `sqlx::postgres::PgRow`
+fn row_to_subscription(
+    row: &sqlx::postgres::PgRow,
+) -> std::result::Result<EntityWebhookSubscription, sqlx::Error> {
+    Ok(EntityWebhookSubscription {
+        uuid: row.try_get("uuid")?,
+        entity_type: row.try_get("entity_type")?,
+        url: row.try_get("url")?,
+        fields: row.try_get("fields")?,
+        active: row.try_get("active")?,
+        created_at: row.try_get("created_at")?,
+        created_by: row.try_get("created_by")?,
+    })
+}
+
+#[async_trait]
+impl EntityWebhookRepositoryTrait for EntityWebhookRepository {
+    async fn create(
+        &self,
+        entity_type: &str,
+        url: &str,
+        fields: &[String],
+        created_by: Option<Uuid>,
+    ) -> Result<Uuid> {
+        sqlx::query_scalar(
+            "INSERT INTO entity_webhook_subscriptions (entity_type, url, fields, created_by)
+            VALUES ($1, $2, $3, $4)
+            RETURNING uuid",
+        )
+        .bind(entity_type)
+        .bind(url)
+        .bind(fields)
+        .bind(created_by)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(Error::Database)
+    }
+
+    async fn get_by_uuid(&self, uuid: Uuid) -> Result<Option<EntityWebhookSubscription>> {
+        let row = sqlx::query(&format!("{SELECT_COLUMNS} WHERE uuid = $1"))
+            .bind(uuid)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(Error::Database)?;
+        row.as_ref()
+            .map(row_to_subscription)
+            .transpose()
+            .map_err(Error::Database)
+    }
+
+    async fn list(&self, entity_type: Option<&str>) -> Result<Vec<EntityWebhookSubscription>> {
+        let rows = sqlx::query(&format!(
+            "{SELECT_COLUMNS} WHERE ($1::text IS NULL OR entity_type = $1) ORDER BY created_at"
+        ))
+        .bind(entity_type)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+        rows.iter()
+            .map(row_to_subscription)
+            .collect::<std::result::Result<_, _>>()
+            .map_err(Error::Database)
+    }
+
+    async fn list_active_for_entity_type(
+        &self,
+        entity_type: &str,
+    ) -> Result<Vec<EntityWebhookSubscription>> {
+        let rows = sqlx::query(&format!(
+            "{SELECT_COLUMNS} WHERE entity_type = $1 AND active ORDER BY created_at"
+        ))
+        .bind(entity_type)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+        rows.iter()
+            .map(row_to_subscription)
+            .collect::<std::result::Result<_, _>>()
+            .map_err(Error::Database)
+    }
+
+    async fn delete(&self, uuid: Uuid) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM entity_webhook_subscriptions WHERE uuid = $1")
+            .bind(uuid)
+            .execute(&self.pool)
+            .await
+            .map_err(Error::Database)?;
+        Ok(result.rows_affected() > 0)
+    }
+}
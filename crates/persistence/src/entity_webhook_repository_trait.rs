@@ -0,0 +1,49 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use r_data_core_core::entity_webhook::EntityWebhookSubscription;
+use r_data_core_core::error::Result;
+use uuid::Uuid;
+
+/// Trait for entity webhook subscription repository operations
+#[async_trait]
+pub trait EntityWebhookRepositoryTrait: Send + Sync {
+    /// Create a subscription
+    ///
+    /// # Errors
+    /// Returns an error if the database insert fails
+    async fn create(
+        &self,
+        entity_type: &str,
+        url: &str,
+        fields: &[String],
+        created_by: Option<Uuid>,
+    ) -> Result<Uuid>;
+
+    /// Get a subscription by UUID
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    async fn get_by_uuid(&self, uuid: Uuid) -> Result<Option<EntityWebhookSubscription>>;
+
+    /// List all subscriptions, optionally restricted to one entity type
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    async fn list(&self, entity_type: Option<&str>) -> Result<Vec<EntityWebhookSubscription>>;
+
+    /// List the active subscriptions of an entity type
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    async fn list_active_for_entity_type(
+        &self,
+        entity_type: &str,
+    ) -> Result<Vec<EntityWebhookSubscription>>;
+
+    /// Delete a subscription; returns whether it existed
+    ///
+    /// # Errors
+    /// Returns an error if the database delete fails
+    async fn delete(&self, uuid: Uuid) -> Result<bool>;
+}
@@ -0,0 +1,307 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use r_data_core_core::admin_user::ApiKey;
+use r_data_core_core::error::{Error, Result};
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use super::{cmp_nulls_last, paginate, read, sort_fields, write};
+use crate::admin_user_repository_trait::{is_key_valid, ApiKeyRepositoryTrait};
+
+/// In-memory `ApiKeyRepositoryTrait` implementation for tests
+#[derive(Debug, Default)]
+pub struct InMemoryApiKeyRepository {
+    keys: RwLock<HashMap<Uuid, ApiKey>>,
+    roles: RwLock<HashMap<Uuid, Vec<Uuid>>>,
+}
+
+impl InMemoryApiKeyRepository {
+    /// Create an empty repository
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Live, unexpired key with the hash of `api_key`
+    fn find_valid_by_value(&self, api_key: &str) -> Result<Option<ApiKey>> {
+        let key_hash = ApiKey::hash_api_key(api_key)?;
+        Ok(read(&self.keys)
+            .values()
+            .find(|key| key.key_hash == key_hash && is_key_valid(key))
+            .cloned())
+    }
+
+    /// Apply `change` to the key with `uuid`; unknown keys are ignored like an `UPDATE`
+    fn update_key(&self, uuid: Uuid, change: impl FnOnce(&mut ApiKey)) {
+        if let Some(key) = write(&self.keys).get_mut(&uuid) {
+            change(key);
+        }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepositoryTrait for InMemoryApiKeyRepository {
+    async fn find_api_key_for_auth(&self, api_key: &str) -> Result<Option<(ApiKey, Uuid)>> {
+        let Some(key) = self.find_valid_by_value(api_key)? else {
+            return Ok(None);
+        };
+        self.update_last_used(key.uuid).await?;
+        let user_uuid = key.user_uuid;
+        Ok(Some((key, user_uuid)))
+    }
+
+    async fn get_by_uuid(&self, uuid: Uuid) -> Result<Option<ApiKey>> {
+        Ok(read(&self.keys).get(&uuid).cloned())
+    }
+
+    async fn create(&self, key: &ApiKey) -> Result<Uuid> {
+        let mut keys = write(&self.keys);
+        if keys.contains_key(&key.uuid) {
+            return Err(Error::Conflict(format!(
+                "API key with UUID {} already exists",
+                key.uuid
+            )));
+        }
+        keys.insert(key.uuid, key.clone());
+        drop(keys);
+        Ok(key.uuid)
+    }
+
+    async fn list_by_user(
+        &self,
+        user_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+        sort_by: Option<String>,
+        sort_order: Option<String>,
+    ) -> Result<Vec<ApiKey>> {
+        let mut keys: Vec<ApiKey> = read(&self.keys)
+            .values()
+            .filter(|key| key.user_uuid == user_uuid)
+            .cloned()
+            .collect();
+
+        let sorts = sort_fields(sort_by.as_deref(), sort_order.as_deref());
+        if sorts.is_empty() {
+            // Default order of the SQL repository
+            keys.sort_by_key(|key| Reverse(key.created_at));
+        }
+        keys.sort_by(|a, b| {
+            sorts.iter().fold(Ordering::Equal, |ordering, sort| {
+                ordering.then_with(|| {
+                    let descending = sort.direction.is_descending();
+                    let ordering = match sort.field.as_str() {
+                        "last_used_at" => {
+                            return cmp_nulls_last(
+                                a.last_used_at.as_ref(),
+                                b.last_used_at.as_ref(),
+                                descending,
+                            )
+                        }
+                        "expires_at" => {
+                            return cmp_nulls_last(
+                                a.expires_at.as_ref(),
+                                b.expires_at.as_ref(),
+                                descending,
+                            )
+                        }
+                        "name" => a.name.cmp(&b.name),
+                        "is_active" => a.is_active.cmp(&b.is_active),
+                        _ => a.created_at.cmp(&b.created_at),
+                    };
+                    if descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                })
+            })
+        });
+
+        Ok(paginate(keys, limit, offset))
+    }
+
+    async fn count_by_user(&self, user_uuid: Uuid) -> Result<i64> {
+        let count = read(&self.keys)
+            .values()
+            .filter(|key| key.user_uuid == user_uuid)
+            .count();
+        Ok(i64::try_from(count).unwrap_or(i64::MAX))
+    }
+
+    async fn revoke(&self, uuid: Uuid) -> Result<()> {
+        self.update_key(uuid, |key| key.is_active = false);
+        Ok(())
+    }
+
+    async fn get_by_name(&self, user_uuid: Uuid, name: &str) -> Result<Option<ApiKey>> {
+        Ok(read(&self.keys)
+            .values()
+            .find(|key| key.user_uuid == user_uuid && key.name == name)
+            .cloned())
+    }
+
+    async fn get_by_hash(&self, api_key: &str) -> Result<Option<ApiKey>> {
+        self.find_valid_by_value(api_key)
+    }
+
+    async fn create_new_api_key(
+        &self,
+        name: &str,
+        description: &str,
+        created_by: Uuid,
+        expires_in_days: i32,
+        scopes: &[String],
+        allowed_cidrs: &[String],
+    ) -> Result<(Uuid, String)> {
+        if name.trim().is_empty() {
+            return Err(Error::Validation(
+                "API key name cannot be empty".to_string(),
+            ));
+        }
+        if expires_in_days < 0 {
+            return Err(Error::Validation(
+                "Expiration days cannot be negative".to_string(),
+            ));
+        }
+
+        let key_value = ApiKey::generate_key();
+        let created_at = OffsetDateTime::now_utc();
+        let key = ApiKey {
+            uuid: Uuid::now_v7(),
+            user_uuid: created_by,
+            key_hash: ApiKey::hash_api_key(&key_value)?,
+            name: name.to_string(),
+            description: Some(description.to_string()),
+            is_active: true,
+            created_at,
+            expires_at: (expires_in_days > 0)
+                .then(|| created_at + Duration::days(i64::from(expires_in_days))),
+            last_used_at: None,
+            created_by,
+            published: true,
+            scopes: scopes.to_vec(),
+            rate_limit_per_minute: None,
+            allowed_cidrs: allowed_cidrs.to_vec(),
+        };
+        let uuid = self.create(&key).await?;
+        Ok((uuid, key_value))
+    }
+
+    async fn update_last_used(&self, uuid: Uuid) -> Result<()> {
+        self.update_key(uuid, |key| {
+            key.last_used_at = Some(OffsetDateTime::now_utc());
+        });
+        Ok(())
+    }
+
+    async fn reassign(&self, uuid: Uuid, new_user_uuid: Uuid) -> Result<()> {
+        self.update_key(uuid, |key| key.user_uuid = new_user_uuid);
+        Ok(())
+    }
+
+    async fn update_rate_limit(
+        &self,
+        uuid: Uuid,
+        rate_limit_per_minute: Option<i32>,
+    ) -> Result<()> {
+        self.update_key(uuid, |key| {
+            key.rate_limit_per_minute = rate_limit_per_minute;
+        });
+        Ok(())
+    }
+
+    async fn update_expires_at(
+        &self,
+        uuid: Uuid,
+        expires_at: Option<OffsetDateTime>,
+    ) -> Result<()> {
+        self.update_key(uuid, |key| key.expires_at = expires_at);
+        Ok(())
+    }
+
+    async fn get_api_key_roles(&self, api_key_uuid: Uuid) -> Result<Vec<Uuid>> {
+        Ok(read(&self.roles)
+            .get(&api_key_uuid)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn assign_role(&self, api_key_uuid: Uuid, role_uuid: Uuid) -> Result<()> {
+        let mut roles = write(&self.roles);
+        let assigned = roles.entry(api_key_uuid).or_default();
+        if !assigned.contains(&role_uuid) {
+            assigned.push(role_uuid);
+        }
+        drop(roles);
+        Ok(())
+    }
+
+    async fn unassign_role(&self, api_key_uuid: Uuid, role_uuid: Uuid) -> Result<()> {
+        if let Some(assigned) = write(&self.roles).get_mut(&api_key_uuid) {
+            assigned.retain(|role| *role != role_uuid);
+        }
+        Ok(())
+    }
+
+    async fn update_api_key_roles(&self, api_key_uuid: Uuid, role_uuids: &[Uuid]) -> Result<()> {
+        write(&self.roles).insert(api_key_uuid, role_uuids.to_vec());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn keys_are_scoped_to_their_owner_and_paginated() -> Result<()> {
+        let repo = InMemoryApiKeyRepository::new();
+        let owner = Uuid::now_v7();
+        let other = Uuid::now_v7();
+        for name in ["a", "b", "c"] {
+            repo.create_new_api_key(name, "", owner, 0, &[], &[])
+                .await?;
+        }
+        repo.create_new_api_key("foreign", "", other, 0, &[], &[])
+            .await?;
+
+        assert_eq!(repo.count_by_user(owner).await?, 3);
+        let page = repo
+            .list_by_user(owner, 2, 1, Some("name".to_string()), None)
+            .await?;
+        let names: Vec<&str> = page.iter().map(|key| key.name.as_str()).collect();
+        assert_eq!(names, vec!["b", "c"]);
+        assert!(repo.get_by_name(owner, "foreign").await?.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn revoked_keys_no_longer_authenticate() -> Result<()> {
+        let repo = InMemoryApiKeyRepository::new();
+        let owner = Uuid::now_v7();
+        let (uuid, value) = repo
+            .create_new_api_key("key", "", owner, 30, &[], &[])
+            .await?;
+
+        let (key, user) = repo
+            .find_api_key_for_auth(&value)
+            .await?
+            .expect("valid key");
+        assert_eq!((key.uuid, user), (uuid, owner));
+        assert!(repo
+            .get_by_uuid(uuid)
+            .await?
+            .and_then(|k| k.last_used_at)
+            .is_some());
+
+        repo.revoke(uuid).await?;
+        assert!(repo.find_api_key_for_auth(&value).await?.is_none());
+        assert!(repo.get_by_hash(&value).await?.is_none());
+        Ok(())
+    }
+}
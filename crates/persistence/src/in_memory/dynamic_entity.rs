@@ -0,0 +1,701 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::sort::SortCollation;
+use r_data_core_core::DynamicEntity;
+use serde_json::Value as JsonValue;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{is_descending, paginate, read, write};
+use crate::dynamic_entity_repository::hash_if_password_field;
+use crate::dynamic_entity_repository_trait::{DynamicEntityRepositoryTrait, FilterEntitiesParams};
+use crate::dynamic_entity_utils::{extract_uuid_from_entity_field_data, REGISTRY_FIELDS};
+
+/// Fields always returned, even when only some fields are requested
+const SYSTEM_FIELDS: [&str; 8] = [
+    "uuid",
+    "created_at",
+    "updated_at",
+    "created_by",
+    "updated_by",
+    "published",
+    "version",
+    "path",
+];
+
+#[derive(Debug, Clone)]
+struct StoredEntity {
+    entity: DynamicEntity,
+    deleted: bool,
+}
+
+impl StoredEntity {
+    fn field(&self, name: &str) -> Option<&JsonValue> {
+        self.entity.field_data.get(name)
+    }
+
+    fn str_field(&self, name: &str) -> Option<&str> {
+        self.field(name).and_then(JsonValue::as_str)
+    }
+
+    fn is_live_of_type(&self, entity_type: &str) -> bool {
+        !self.deleted && self.entity.entity_type == entity_type
+    }
+
+    /// The entity as read through the view: write-only fields are redacted
+    fn to_entity(&self, exclusive_fields: Option<&[String]>) -> DynamicEntity {
+        let mut entity = self.entity.clone();
+        for field in &entity.definition.fields {
+            if field.field_type.is_write_only() {
+                entity
+                    .field_data
+                    .insert(field.name.clone(), JsonValue::Null);
+            }
+        }
+        if let Some(fields) = exclusive_fields {
+            entity.field_data.retain(|name, _| {
+                SYSTEM_FIELDS.contains(&name.as_str())
+                    || fields.iter().any(|f| f.eq_ignore_ascii_case(name))
+            });
+        }
+        if self.deleted {
+            entity
+                .field_data
+                .entry("deleted_at".to_string())
+                .or_insert(JsonValue::Bool(true));
+        }
+        entity
+    }
+}
+
+/// In-memory `DynamicEntityRepositoryTrait` implementation for tests
+///
+/// Entities keep the definition they were created with. Soft-deleted entities are hidden
+/// like in the views, and `owner` filters match `created_by`.
+#[derive(Debug, Default)]
+pub struct InMemoryDynamicEntityRepository {
+    entities: RwLock<HashMap<Uuid, StoredEntity>>,
+}
+
+impl InMemoryDynamicEntityRepository {
+    /// Create an empty repository
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Live entities of `entity_type` in the default order (newest first)
+    fn live_of_type(&self, entity_type: &str, include_deleted: bool) -> Vec<StoredEntity> {
+        let mut entities: Vec<StoredEntity> = read(&self.entities)
+            .values()
+            .filter(|stored| {
+                stored.entity.entity_type == entity_type && (include_deleted || !stored.deleted)
+            })
+            .cloned()
+            .collect();
+        entities.sort_by(|a, b| cmp_json(b.field("created_at"), a.field("created_at")));
+        entities
+    }
+
+    /// Whether a live entity other than `except` already uses `path` and `key`
+    fn path_key_taken(
+        entities: &HashMap<Uuid, StoredEntity>,
+        path: &str,
+        key: &str,
+        except: Option<Uuid>,
+    ) -> bool {
+        entities.iter().any(|(uuid, stored)| {
+            Some(*uuid) != except
+                && !stored.deleted
+                && stored.str_field("path") == Some(path)
+                && stored.str_field("entity_key") == Some(key)
+        })
+    }
+}
+
+/// Order JSON values of the same kind; `null`s and missing values sort last
+fn cmp_json(a: Option<&JsonValue>, b: Option<&JsonValue>) -> Ordering {
+    match (a.filter(|v| !v.is_null()), b.filter(|v| !v.is_null())) {
+        (Some(JsonValue::Number(a)), Some(JsonValue::Number(b))) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Some(JsonValue::Bool(a)), Some(JsonValue::Bool(b))) => a.cmp(b),
+        (Some(a), Some(b)) => a.to_string().cmp(&b.to_string()),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Lower-cased copy of a text value for case-insensitive sorting; accents are kept in memory
+fn fold_case(value: &JsonValue) -> JsonValue {
+    value
+        .as_str()
+        .map_or_else(|| value.clone(), |s| JsonValue::String(s.to_lowercase()))
+}
+
+/// Whether `value` satisfies `operator` against `expected`
+fn matches_filter(value: Option<&JsonValue>, operator: &str, expected: &JsonValue) -> bool {
+    let equals = |candidate: &JsonValue| {
+        value.is_some_and(|v| v == candidate || cmp_json(Some(v), Some(candidate)).is_eq())
+    };
+    let in_list = || {
+        expected
+            .as_array()
+            .is_some_and(|candidates| candidates.iter().any(equals))
+    };
+    let ordering = || value.map(|v| cmp_json(Some(v), Some(expected)));
+    match operator.to_uppercase().as_str() {
+        "!=" | "<>" => !equals(expected),
+        ">" => ordering() == Some(Ordering::Greater),
+        ">=" => ordering().is_some_and(Ordering::is_ge),
+        "<" => ordering() == Some(Ordering::Less),
+        "<=" => ordering().is_some_and(Ordering::is_le),
+        "IN" => in_list(),
+        "NOT IN" => !in_list(),
+        _ => equals(expected),
+    }
+}
+
+/// Whether `stored` matches the filters, search and owner of `params`
+fn matches_params(stored: &StoredEntity, params: &FilterEntitiesParams) -> bool {
+    if let Some(owner) = params.owner {
+        if extract_uuid_from_entity_field_data(&stored.entity.field_data, "created_by")
+            != Some(owner)
+        {
+            return false;
+        }
+    }
+
+    if let Some(filters) = &params.filters {
+        for (field, expected) in filters {
+            let operator = params
+                .filter_operators
+                .as_ref()
+                .and_then(|operators| operators.get(field))
+                .map_or("=", String::as_str);
+            if !matches_filter(stored.field(field), operator, expected) {
+                return false;
+            }
+        }
+    }
+
+    if let Some((term, fields)) = &params.search {
+        let term = term.to_lowercase();
+        let found = fields.iter().any(|field| {
+            stored
+                .str_field(field)
+                .is_some_and(|value| value.to_lowercase().contains(&term))
+        });
+        if !found {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn now_json() -> JsonValue {
+    JsonValue::String(
+        OffsetDateTime::now_utc()
+            .format(&Rfc3339)
+            .unwrap_or_default(),
+    )
+}
+
+fn count(entities: impl Iterator<Item = StoredEntity>) -> i64 {
+    i64::try_from(entities.count()).unwrap_or(i64::MAX)
+}
+
+#[async_trait]
+impl DynamicEntityRepositoryTrait for InMemoryDynamicEntityRepository {
+    async fn get_all_by_type(
+        &self,
+        entity_type: &str,
+        limit: i64,
+        offset: i64,
+        exclusive_fields: Option<Vec<String>>,
+    ) -> Result<Vec<DynamicEntity>> {
+        let entities = paginate(self.live_of_type(entity_type, false), limit, offset);
+        Ok(entities
+            .iter()
+            .map(|stored| stored.to_entity(exclusive_fields.as_deref()))
+            .collect())
+    }
+
+    async fn get_by_type(
+        &self,
+        entity_type: &str,
+        uuid: &Uuid,
+        exclusive_fields: Option<Vec<String>>,
+    ) -> Result<Option<DynamicEntity>> {
+        Ok(read(&self.entities)
+            .get(uuid)
+            .filter(|stored| stored.is_live_of_type(entity_type))
+            .map(|stored| stored.to_entity(exclusive_fields.as_deref())))
+    }
+
+    async fn get_many_by_type(
+        &self,
+        entity_type: &str,
+        uuids: &[Uuid],
+        exclusive_fields: Option<Vec<String>>,
+    ) -> Result<HashMap<Uuid, Option<DynamicEntity>>> {
+        let entities = read(&self.entities);
+        Ok(uuids
+            .iter()
+            .map(|uuid| {
+                let entity = entities
+                    .get(uuid)
+                    .filter(|stored| stored.is_live_of_type(entity_type))
+                    .map(|stored| stored.to_entity(exclusive_fields.as_deref()));
+                (*uuid, entity)
+            })
+            .collect())
+    }
+
+    async fn create(&self, entity: &DynamicEntity) -> Result<Uuid> {
+        entity.validate()?;
+
+        let path = entity
+            .field_data
+            .get("path")
+            .and_then(JsonValue::as_str)
+            .unwrap_or("/")
+            .to_string();
+        let key = entity
+            .field_data
+            .get("entity_key")
+            .and_then(JsonValue::as_str)
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .ok_or_else(|| Error::Validation("Missing required field 'entity_key'".to_string()))?
+            .to_string();
+
+        let mut entities = write(&self.entities);
+        if let Some(parent_uuid) =
+            extract_uuid_from_entity_field_data(&entity.field_data, "parent_uuid")
+        {
+            if !entities.contains_key(&parent_uuid) {
+                return Err(Error::Validation("Parent entity not found".to_string()));
+            }
+        }
+        if Self::path_key_taken(&entities, &path, &key, None) {
+            return Err(Error::ValidationFailed(
+                "An entity with the same key already exists in this path".to_string(),
+            ));
+        }
+
+        let uuid = Uuid::now_v7();
+        let mut stored = entity.clone();
+        for (name, value) in &entity.field_data {
+            if !REGISTRY_FIELDS.contains(&name.as_str()) {
+                let value = hash_if_password_field(name, value, &entity.definition)?;
+                stored.field_data.insert(name.clone(), value);
+            }
+        }
+        let data = &mut stored.field_data;
+        data.insert("uuid".to_string(), JsonValue::String(uuid.to_string()));
+        data.insert("path".to_string(), JsonValue::String(path));
+        data.insert("entity_key".to_string(), JsonValue::String(key));
+        data.entry("created_at".to_string())
+            .or_insert_with(now_json);
+        data.entry("updated_at".to_string())
+            .or_insert_with(now_json);
+        data.entry("published".to_string())
+            .or_insert(JsonValue::Bool(false));
+        data.entry("version".to_string())
+            .or_insert_with(|| JsonValue::from(1));
+
+        entities.insert(
+            uuid,
+            StoredEntity {
+                entity: stored,
+                deleted: false,
+            },
+        );
+        drop(entities);
+        Ok(uuid)
+    }
+
+    async fn update(&self, entity: &DynamicEntity) -> Result<()> {
+        entity.validate()?;
+        let uuid = extract_uuid_from_entity_field_data(&entity.field_data, "uuid")
+            .ok_or_else(|| Error::Validation("Entity is missing a valid UUID".to_string()))?;
+
+        let mut entities = write(&self.entities);
+        let Some(current) = entities.get(&uuid) else {
+            return Err(Error::NotFound(format!(
+                "Entity with UUID {uuid} not found"
+            )));
+        };
+        let path = entity
+            .field_data
+            .get("path")
+            .and_then(JsonValue::as_str)
+            .or_else(|| current.str_field("path"))
+            .unwrap_or("/")
+            .to_string();
+        let key = entity
+            .field_data
+            .get("entity_key")
+            .and_then(JsonValue::as_str)
+            .or_else(|| current.str_field("entity_key"))
+            .unwrap_or_default()
+            .to_string();
+        if Self::path_key_taken(&entities, &path, &key, Some(uuid)) {
+            return Err(Error::ValidationFailed(
+                "An entity with the same key already exists in this path".to_string(),
+            ));
+        }
+
+        let Some(current) = entities.get_mut(&uuid) else {
+            return Err(Error::NotFound(format!(
+                "Entity with UUID {uuid} not found"
+            )));
+        };
+        let version = current
+            .field("version")
+            .and_then(JsonValue::as_i64)
+            .unwrap_or(1);
+        for (name, value) in &entity.field_data {
+            // Creation metadata is kept, like the registry update does
+            if matches!(
+                name.as_str(),
+                "uuid" | "created_at" | "created_by" | "version"
+            ) {
+                continue;
+            }
+            let value = hash_if_password_field(name, value, &current.entity.definition)?;
+            current.entity.field_data.insert(name.clone(), value);
+        }
+        let data = &mut current.entity.field_data;
+        data.insert("updated_at".to_string(), now_json());
+        data.insert("version".to_string(), JsonValue::from(version + 1));
+        drop(entities);
+        Ok(())
+    }
+
+    async fn delete_by_type(&self, entity_type: &str, uuid: &Uuid) -> Result<()> {
+        match write(&self.entities).get_mut(uuid) {
+            Some(stored) if stored.is_live_of_type(entity_type) => {
+                stored.deleted = true;
+                Ok(())
+            }
+            _ => Err(Error::NotFound(format!(
+                "Entity {entity_type} with UUID {uuid} not found"
+            ))),
+        }
+    }
+
+    async fn restore_by_type(&self, entity_type: &str, uuid: &Uuid) -> Result<()> {
+        let mut entities = write(&self.entities);
+        let Some(stored) = entities
+            .get(uuid)
+            .filter(|stored| stored.deleted && stored.entity.entity_type == entity_type)
+        else {
+            return Err(Error::NotFound(format!(
+                "Deleted entity {entity_type} with UUID {uuid} not found"
+            )));
+        };
+        let path = stored.str_field("path").unwrap_or("/").to_string();
+        let key = stored
+            .str_field("entity_key")
+            .unwrap_or_default()
+            .to_string();
+        if Self::path_key_taken(&entities, &path, &key, Some(*uuid)) {
+            return Err(Error::ValidationFailed(
+                "An entity with the same key already exists in this path".to_string(),
+            ));
+        }
+        if let Some(stored) = entities.get_mut(uuid) {
+            stored.deleted = false;
+        }
+        drop(entities);
+        Ok(())
+    }
+
+    async fn hard_delete(&self, entity_type: &str, uuid: &Uuid) -> Result<()> {
+        let mut entities = write(&self.entities);
+        if entities
+            .get(uuid)
+            .is_some_and(|stored| stored.entity.entity_type == entity_type)
+        {
+            entities.remove(uuid);
+        }
+        drop(entities);
+        Ok(())
+    }
+
+    async fn is_deleted(&self, entity_type: &str, uuid: &Uuid) -> Result<bool> {
+        Ok(read(&self.entities)
+            .get(uuid)
+            .is_some_and(|stored| stored.deleted && stored.entity.entity_type == entity_type))
+    }
+
+    async fn filter_entities(
+        &self,
+        entity_type: &str,
+        params: &FilterEntitiesParams,
+    ) -> Result<Vec<DynamicEntity>> {
+        let mut entities: Vec<StoredEntity> = self
+            .live_of_type(entity_type, params.include_deleted)
+            .into_iter()
+            .filter(|stored| matches_params(stored, params))
+            .collect();
+        if let Some((field, direction)) = &params.sort {
+            let descending = is_descending(Some(direction));
+            entities.sort_by(|a, b| {
+                let ordering = if params.collation == SortCollation::Default {
+                    cmp_json(a.field(field), b.field(field))
+                } else {
+                    cmp_json(
+                        a.field(field).map(fold_case).as_ref(),
+                        b.field(field).map(fold_case).as_ref(),
+                    )
+                };
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+
+        Ok(paginate(entities, params.limit, params.offset)
+            .iter()
+            .map(|stored| stored.to_entity(params.fields.as_deref()))
+            .collect())
+    }
+
+    async fn count_entities(&self, entity_type: &str) -> Result<i64> {
+        Ok(count(self.live_of_type(entity_type, false).into_iter()))
+    }
+
+    async fn count_deleted_entities(&self, entity_type: &str) -> Result<i64> {
+        Ok(count(
+            self.live_of_type(entity_type, true)
+                .into_iter()
+                .filter(|stored| stored.deleted),
+        ))
+    }
+
+    async fn count_entities_by_owner(&self, entity_type: &str, owner: &Uuid) -> Result<i64> {
+        Ok(count(
+            self.live_of_type(entity_type, false)
+                .into_iter()
+                .filter(|stored| {
+                    extract_uuid_from_entity_field_data(&stored.entity.field_data, "created_by")
+                        == Some(*owner)
+                }),
+        ))
+    }
+
+    async fn estimate_entities(&self, entity_type: &str) -> Result<Option<i64>> {
+        Ok(Some(count(
+            self.live_of_type(entity_type, true).into_iter(),
+        )))
+    }
+
+    async fn count_children(&self, parent_uuid: &Uuid) -> Result<i64> {
+        let entities = read(&self.entities);
+        Ok(count(
+            entities
+                .values()
+                .filter(|stored| {
+                    !stored.deleted
+                        && extract_uuid_from_entity_field_data(
+                            &stored.entity.field_data,
+                            "parent_uuid",
+                        ) == Some(*parent_uuid)
+                })
+                .cloned(),
+        ))
+    }
+
+    async fn get_by_uuid_any_type(&self, uuid: &Uuid) -> Result<Option<DynamicEntity>> {
+        Ok(read(&self.entities)
+            .get(uuid)
+            .filter(|stored| !stored.deleted)
+            .map(|stored| stored.to_entity(None)))
+    }
+
+    async fn find_one_by_filters(
+        &self,
+        entity_type: &str,
+        filters: &HashMap<String, JsonValue>,
+    ) -> Result<Option<DynamicEntity>> {
+        let params = FilterEntitiesParams::new(1, 0).with_filters(Some(filters.clone()));
+        Ok(self
+            .filter_entities(entity_type, &params)
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    async fn get_raw_field_value(
+        &self,
+        entity_type: &str,
+        uuid: &Uuid,
+        field_name: &str,
+    ) -> Result<Option<String>> {
+        let entities = read(&self.entities);
+        let Some(stored) = entities
+            .get(uuid)
+            .filter(|stored| stored.entity.entity_type == entity_type)
+        else {
+            return Ok(None);
+        };
+        if stored.entity.definition.get_field(field_name).is_none() {
+            return Err(Error::FieldNotFound(field_name.to_string()));
+        }
+        let value = stored.str_field(field_name).map(ToString::to_string);
+        drop(entities);
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use r_data_core_core::entity_definition::definition::EntityDefinition;
+    use r_data_core_core::field::ui::UiSettings;
+    use r_data_core_core::field::{FieldDefinition, FieldType, FieldValidation};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn definition() -> Arc<EntityDefinition> {
+        let field = |name: &str, field_type| FieldDefinition {
+            name: name.to_string(),
+            display_name: name.to_string(),
+            field_type,
+            description: None,
+            required: false,
+            indexed: false,
+            filterable: true,
+            unique: false,
+            default_value: None,
+            validation: FieldValidation::default(),
+            ui_settings: UiSettings::default(),
+            constraints: HashMap::new(),
+            display_order: 0,
+        };
+        Arc::new(EntityDefinition {
+            entity_type: "note".to_string(),
+            fields: vec![
+                field("title", FieldType::String),
+                field("rank", FieldType::Integer),
+            ],
+            ..EntityDefinition::default()
+        })
+    }
+
+    fn note(key: &str, rank: i64, owner: Uuid) -> DynamicEntity {
+        DynamicEntity::from_data(
+            "note".to_string(),
+            HashMap::from([
+                ("entity_key".to_string(), json!(key)),
+                ("title".to_string(), json!(format!("Note {key}"))),
+                ("rank".to_string(), json!(rank)),
+                ("created_by".to_string(), json!(owner.to_string())),
+            ]),
+            definition(),
+        )
+    }
+
+    #[tokio::test]
+    async fn filters_paginates_and_scopes_by_owner() -> Result<()> {
+        let repo = InMemoryDynamicEntityRepository::new();
+        let owner = Uuid::now_v7();
+        for rank in 1..=4 {
+            repo.create(&note(&format!("n{rank}"), rank, owner)).await?;
+        }
+        repo.create(&note("foreign", 5, Uuid::now_v7())).await?;
+
+        let params = FilterEntitiesParams::new(2, 1)
+            .with_filters(Some(HashMap::from([("rank".to_string(), json!(1))])))
+            .with_filter_operators(Some(HashMap::from([("rank".to_string(), ">".to_string())])))
+            .with_sort(Some(("rank".to_string(), "ASC".to_string())))
+            .with_owner(Some(owner));
+        let ranks: Vec<JsonValue> = repo
+            .filter_entities("note", &params)
+            .await?
+            .into_iter()
+            .map(|entity| entity.field_data["rank"].clone())
+            .collect();
+        assert_eq!(ranks, vec![json!(3), json!(4)]);
+        assert_eq!(repo.count_entities_by_owner("note", &owner).await?, 4);
+        assert_eq!(repo.count_entities("note").await?, 5);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn case_insensitive_collation_ignores_case_of_text_only() -> Result<()> {
+        let repo = InMemoryDynamicEntityRepository::new();
+        let owner = Uuid::now_v7();
+        repo.create(&note("Zebra", 9, owner)).await?;
+        repo.create(&note("apple", 10, owner)).await?;
+
+        let keys = |field: &str, collation| {
+            let params = FilterEntitiesParams::new(10, 0)
+                .with_sort(Some((field.to_string(), "ASC".to_string())))
+                .with_collation(collation);
+            let repo = &repo;
+            async move {
+                repo.filter_entities("note", &params).await.map(|entities| {
+                    entities
+                        .into_iter()
+                        .map(|entity| entity.field_data["entity_key"].clone())
+                        .collect::<Vec<_>>()
+                })
+            }
+        };
+        assert_eq!(
+            keys("entity_key", SortCollation::Default).await?,
+            vec![json!("Zebra"), json!("apple")]
+        );
+        assert_eq!(
+            keys("entity_key", SortCollation::Ci).await?,
+            vec![json!("apple"), json!("Zebra")]
+        );
+        assert_eq!(
+            keys("rank", SortCollation::Ci).await?,
+            vec![json!("Zebra"), json!("apple")]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn soft_deleted_entities_are_hidden_until_restored() -> Result<()> {
+        let repo = InMemoryDynamicEntityRepository::new();
+        let uuid = repo.create(&note("a", 1, Uuid::now_v7())).await?;
+        assert!(matches!(
+            repo.create(&note("a", 2, Uuid::now_v7())).await,
+            Err(Error::ValidationFailed(_))
+        ));
+
+        repo.delete_by_type("note", &uuid).await?;
+        assert!(repo.get_by_type("note", &uuid, None).await?.is_none());
+        assert!(repo.is_deleted("note", &uuid).await?);
+        assert_eq!(repo.count_deleted_entities("note").await?, 1);
+        assert!(matches!(
+            repo.delete_by_type("note", &uuid).await,
+            Err(Error::NotFound(_))
+        ));
+
+        repo.restore_by_type("note", &uuid).await?;
+        let restored = repo.get_by_type("note", &uuid, None).await?;
+        assert_eq!(
+            restored.map(|e| e.field_data["rank"].clone()),
+            Some(json!(1))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! In-memory repositories for unit testing services without a database.
+//!
+//! Available with the `test-util` feature. Each fake keeps its rows in a
+//! `RwLock<HashMap<..>>` and follows the owner scoping, pagination and not-found behaviour of
+//! the `PostgreSQL` repository it stands in for. SQL specifics such as triggers, column types
+//! or versioning snapshots are not emulated.
+
+mod api_key;
+mod dynamic_entity;
+mod workflow;
+
+pub use api_key::InMemoryApiKeyRepository;
+pub use dynamic_entity::InMemoryDynamicEntityRepository;
+pub use workflow::InMemoryWorkflowRepository;
+
+use r_data_core_core::sort::{parse_sort, SortField};
+use std::cmp::Ordering;
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// Read access to a fake's table; a panicking test must not poison the others
+fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Write access to a fake's table
+fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Apply `LIMIT`/`OFFSET` semantics; a negative limit or `i64::MAX` means unlimited
+fn paginate<T>(items: Vec<T>, limit: i64, offset: i64) -> Vec<T> {
+    let offset = usize::try_from(offset).unwrap_or(0);
+    let items = items.into_iter().skip(offset);
+    match usize::try_from(limit) {
+        Ok(limit) => items.take(limit).collect(),
+        Err(_) => items.collect(),
+    }
+}
+
+/// Whether `sort_order` asks for a descending sort (`ASC` otherwise, like the SQL repositories)
+fn is_descending(sort_order: Option<&str>) -> bool {
+    sort_order.is_some_and(|order| order.eq_ignore_ascii_case("DESC"))
+}
+
+/// Fields of a validated `sort_by`/`sort_order`, empty without `sort_by`
+fn sort_fields(sort_by: Option<&str>, sort_order: Option<&str>) -> Vec<SortField> {
+    sort_by
+        .and_then(|sort_by| parse_sort(sort_by, sort_order).ok())
+        .unwrap_or_default()
+}
+
+/// Order two optional values with `NULL`s last regardless of direction
+fn cmp_nulls_last<T: Ord>(a: Option<&T>, b: Option<&T>, descending: bool) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) if descending => b.cmp(a),
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paginate_applies_limit_and_offset() {
+        let items: Vec<i32> = (1..=5).collect();
+        assert_eq!(paginate(items.clone(), 2, 1), vec![2, 3]);
+        assert_eq!(paginate(items.clone(), -1, 3), vec![4, 5]);
+        assert_eq!(paginate(items.clone(), i64::MAX, 0), items);
+        assert!(paginate(items, 2, 10).is_empty());
+    }
+
+    #[test]
+    fn nulls_sort_last_in_both_directions() {
+        let mut values = vec![None, Some(2), Some(1)];
+        values.sort_by(|a, b| cmp_nulls_last(a.as_ref(), b.as_ref(), false));
+        assert_eq!(values, vec![Some(1), Some(2), None]);
+        values.sort_by(|a, b| cmp_nulls_last(a.as_ref(), b.as_ref(), true));
+        assert_eq!(values, vec![Some(2), Some(1), None]);
+    }
+}
@@ -0,0 +1,772 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_workflow::data::requests::{CreateWorkflowRequest, UpdateWorkflowRequest};
+use r_data_core_workflow::data::{
+    IdempotencyClaim, RunStatus, Workflow, WorkflowKind, WorkflowRunError, WorkflowRunMetrics,
+    WorkflowRunProgress, WorkflowRunStats,
+};
+use serde_json::Value;
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::RwLock;
+use time::macros::format_description;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use super::{paginate, read, sort_fields, write};
+use crate::workflow_repository::WorkflowRepository;
+use crate::workflow_repository_trait::WorkflowRepositoryTrait;
+
+/// Run row as returned by the run listings
+type RunSummary = (
+    Uuid,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<i64>,
+    Option<i64>,
+);
+
+/// Run log row as returned by the log listing
+type RunLog = (Uuid, String, String, String, Option<Value>);
+
+#[derive(Debug, Clone)]
+struct Run {
+    uuid: Uuid,
+    workflow_uuid: Uuid,
+    status: String,
+    queued_at: OffsetDateTime,
+    started_at: Option<OffsetDateTime>,
+    finished_at: Option<OffsetDateTime>,
+    processed_items: Option<i64>,
+    failed_items: Option<i64>,
+    total_items: i64,
+    config_override: Option<Value>,
+}
+
+impl Run {
+    fn summary(&self) -> RunSummary {
+        (
+            self.uuid,
+            self.status.clone(),
+            Some(format_timestamp(self.queued_at)),
+            self.finished_at.map(format_timestamp),
+            self.processed_items,
+            self.failed_items,
+        )
+    }
+
+    fn duration_ms(&self) -> Option<f64> {
+        let duration = self.finished_at? - self.started_at?;
+        Some(duration.as_seconds_f64() * 1000.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RawItem {
+    run_uuid: Uuid,
+    seq_no: i64,
+    payload: Value,
+    status: String,
+}
+
+#[derive(Debug, Clone)]
+struct IdempotencyKey {
+    expires_at: OffsetDateTime,
+    response: Option<Value>,
+}
+
+/// In-memory `WorkflowRepositoryTrait` implementation for tests
+///
+/// Fetch outbox entries are not stored; `insert_run_queued_with_fetch_outbox` only returns a
+/// fresh outbox UUID next to the queued run.
+#[derive(Debug, Default)]
+pub struct InMemoryWorkflowRepository {
+    workflows: RwLock<HashMap<Uuid, Workflow>>,
+    runs: RwLock<HashMap<Uuid, Run>>,
+    run_logs: RwLock<Vec<(Uuid, OffsetDateTime, RunLog)>>,
+    raw_items: RwLock<HashMap<Uuid, RawItem>>,
+    run_errors: RwLock<Vec<WorkflowRunError>>,
+    idempotency_keys: RwLock<HashMap<(Uuid, String), IdempotencyKey>>,
+}
+
+impl InMemoryWorkflowRepository {
+    /// Create an empty repository
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn workflow_from_request(
+        uuid: Uuid,
+        name: &str,
+        description: Option<&String>,
+        kind: &str,
+        enabled: bool,
+        schedule_cron: Option<&String>,
+        config: &Value,
+        versioning_disabled: bool,
+    ) -> Result<Workflow> {
+        let kind = WorkflowKind::from_str(kind)
+            .map_err(|e| Error::Validation(format!("Invalid workflow kind '{kind}': {e}")))?;
+        Ok(Workflow {
+            uuid,
+            name: name.to_string(),
+            description: description.cloned(),
+            kind,
+            enabled,
+            schedule_cron: schedule_cron.cloned(),
+            paused: false,
+            config: config.clone(),
+            versioning_disabled,
+            completion_webhook_url: None,
+        })
+    }
+
+    fn queue_run(&self, workflow_uuid: Uuid) -> Uuid {
+        let uuid = Uuid::now_v7();
+        write(&self.runs).insert(
+            uuid,
+            Run {
+                uuid,
+                workflow_uuid,
+                status: "queued".to_string(),
+                queued_at: OffsetDateTime::now_utc(),
+                started_at: None,
+                finished_at: None,
+                processed_items: None,
+                failed_items: None,
+                total_items: 0,
+                config_override: None,
+            },
+        );
+        uuid
+    }
+
+    fn update_run(&self, run_uuid: Uuid, change: impl FnOnce(&mut Run)) {
+        if let Some(run) = write(&self.runs).get_mut(&run_uuid) {
+            change(run);
+        }
+    }
+
+    /// Runs newest first, optionally of one workflow only
+    fn runs_newest_first(&self, workflow_uuid: Option<Uuid>) -> Vec<Run> {
+        let mut runs: Vec<Run> = read(&self.runs)
+            .values()
+            .filter(|run| workflow_uuid.is_none_or(|uuid| run.workflow_uuid == uuid))
+            .cloned()
+            .collect();
+        runs.sort_by_key(|run| Reverse(run.queued_at));
+        runs
+    }
+}
+
+/// Timestamp in the format of the SQL run listings
+fn format_timestamp(timestamp: OffsetDateTime) -> String {
+    timestamp
+        .format(format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z"
+        ))
+        .unwrap_or_default()
+}
+
+fn count<T>(items: &[T]) -> i64 {
+    i64::try_from(items.len()).unwrap_or(i64::MAX)
+}
+
+/// Average and 95th percentile (`PERCENTILE_CONT`) of the run durations
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn duration_stats(runs: &[Run]) -> (Option<f64>, Option<f64>) {
+    let mut durations: Vec<f64> = runs.iter().filter_map(Run::duration_ms).collect();
+    if durations.is_empty() {
+        return (None, None);
+    }
+    durations.sort_by(f64::total_cmp);
+    let avg = durations.iter().sum::<f64>() / durations.len() as f64;
+    let rank = 0.95 * (durations.len() - 1) as f64;
+    let lower = durations[rank.floor() as usize];
+    let upper = durations[rank.ceil() as usize];
+    (
+        Some(avg),
+        Some((upper - lower).mul_add(rank.fract(), lower)),
+    )
+}
+
+fn count_status(runs: &[Run], status: &str) -> i64 {
+    count(
+        &runs
+            .iter()
+            .filter(|run| run.status == status)
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[async_trait]
+impl WorkflowRepositoryTrait for InMemoryWorkflowRepository {
+    async fn list_all(&self) -> Result<Vec<Workflow>> {
+        let mut workflows: Vec<Workflow> = read(&self.workflows).values().cloned().collect();
+        workflows.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(workflows)
+    }
+
+    async fn list_paginated(
+        &self,
+        limit: i64,
+        offset: i64,
+        sort_by: Option<String>,
+        sort_order: Option<String>,
+    ) -> Result<Vec<Workflow>> {
+        let mut workflows = self.list_all().await?;
+        let sorts = sort_fields(sort_by.as_deref(), sort_order.as_deref());
+        workflows.sort_by(|a, b| {
+            sorts.iter().fold(Ordering::Equal, |ordering, sort| {
+                ordering.then_with(|| {
+                    let ordering = match sort.field.as_str() {
+                        "kind" => a.kind.to_string().cmp(&b.kind.to_string()),
+                        "enabled" => a.enabled.cmp(&b.enabled),
+                        "schedule_cron" => a.schedule_cron.cmp(&b.schedule_cron),
+                        _ => a.name.cmp(&b.name),
+                    };
+                    if sort.direction.is_descending() {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                })
+            })
+        });
+        Ok(paginate(workflows, limit, offset))
+    }
+
+    async fn count_all(&self) -> Result<i64> {
+        Ok(i64::try_from(read(&self.workflows).len()).unwrap_or(i64::MAX))
+    }
+
+    async fn get_by_uuid(&self, uuid: Uuid) -> Result<Option<Workflow>> {
+        Ok(read(&self.workflows).get(&uuid).cloned())
+    }
+
+    async fn create(&self, req: &CreateWorkflowRequest, _created_by: Uuid) -> Result<Uuid> {
+        let uuid = Uuid::now_v7();
+        let mut workflow = Self::workflow_from_request(
+            uuid,
+            &req.name,
+            req.description.as_ref(),
+            &req.kind,
+            req.enabled,
+            req.schedule_cron.as_ref(),
+            &req.config,
+            req.versioning_disabled,
+        )?;
+        workflow
+            .completion_webhook_url
+            .clone_from(&req.completion_webhook_url);
+        write(&self.workflows).insert(uuid, workflow);
+        Ok(uuid)
+    }
+
+    async fn update(
+        &self,
+        uuid: Uuid,
+        req: &UpdateWorkflowRequest,
+        _updated_by: Uuid,
+    ) -> Result<()> {
+        let mut workflow = Self::workflow_from_request(
+            uuid,
+            &req.name,
+            req.description.as_ref(),
+            &req.kind,
+            req.enabled,
+            req.schedule_cron.as_ref(),
+            &req.config,
+            req.versioning_disabled,
+        )?;
+        workflow
+            .completion_webhook_url
+            .clone_from(&req.completion_webhook_url);
+        if let Some(existing) = write(&self.workflows).get_mut(&uuid) {
+            workflow.paused = existing.paused;
+            *existing = workflow;
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, uuid: Uuid) -> Result<()> {
+        write(&self.workflows).remove(&uuid);
+        Ok(())
+    }
+
+    async fn set_paused(&self, uuid: Uuid, paused: bool) -> Result<bool> {
+        Ok(write(&self.workflows)
+            .get_mut(&uuid)
+            .map(|workflow| workflow.paused = paused)
+            .is_some())
+    }
+
+    async fn list_scheduled_consumers(&self) -> Result<Vec<(Uuid, String)>> {
+        Ok(read(&self.workflows)
+            .values()
+            .filter(|wf| wf.enabled && !wf.paused && wf.kind == WorkflowKind::Consumer)
+            .filter(|wf| !WorkflowRepository::check_has_api_endpoint(&wf.config))
+            .filter_map(|wf| wf.schedule_cron.clone().map(|cron| (wf.uuid, cron)))
+            .collect())
+    }
+
+    async fn mark_run_running(&self, run_uuid: Uuid) -> Result<()> {
+        self.update_run(run_uuid, |run| {
+            if run.status == "queued" {
+                run.status = "running".to_string();
+                run.started_at = Some(OffsetDateTime::now_utc());
+            }
+        });
+        Ok(())
+    }
+
+    async fn mark_run_success(&self, run_uuid: Uuid, processed: i64, failed: i64) -> Result<()> {
+        self.update_run(run_uuid, |run| {
+            run.status = "success".to_string();
+            run.finished_at = Some(OffsetDateTime::now_utc());
+            run.processed_items = Some(processed);
+            run.failed_items = Some(failed);
+        });
+        Ok(())
+    }
+
+    async fn mark_run_failure(&self, run_uuid: Uuid, _message: &str) -> Result<()> {
+        self.update_run(run_uuid, |run| {
+            run.status = "failed".to_string();
+            run.finished_at = Some(OffsetDateTime::now_utc());
+        });
+        Ok(())
+    }
+
+    async fn get_run_status(&self, run_uuid: Uuid) -> Result<Option<String>> {
+        Ok(read(&self.runs)
+            .get(&run_uuid)
+            .map(|run| run.status.clone()))
+    }
+
+    async fn update_run_progress(
+        &self,
+        run_uuid: Uuid,
+        processed: i64,
+        failed: i64,
+        total: i64,
+    ) -> Result<()> {
+        self.update_run(run_uuid, |run| {
+            run.processed_items = Some(processed);
+            run.failed_items = Some(failed);
+            run.total_items = total;
+        });
+        Ok(())
+    }
+
+    async fn get_run_progress(&self, run_uuid: Uuid) -> Result<Option<WorkflowRunProgress>> {
+        read(&self.runs)
+            .get(&run_uuid)
+            .map(|run| {
+                Ok(WorkflowRunProgress {
+                    status: RunStatus::from_str(&run.status)
+                        .map_err(|e| Error::Unknown(format!("Run {run_uuid}: {e}")))?,
+                    processed_count: run.processed_items.unwrap_or_default(),
+                    failed_count: run.failed_items.unwrap_or_default(),
+                    total_count: run.total_items,
+                })
+            })
+            .transpose()
+    }
+
+    async fn set_run_config_override(&self, run_uuid: Uuid, config_override: &Value) -> Result<()> {
+        self.update_run(run_uuid, |run| {
+            run.config_override = Some(config_override.clone());
+        });
+        Ok(())
+    }
+
+    async fn get_run_config_override(&self, run_uuid: Uuid) -> Result<Option<Value>> {
+        Ok(read(&self.runs)
+            .get(&run_uuid)
+            .and_then(|run| run.config_override.clone()))
+    }
+
+    async fn insert_run_queued(&self, workflow_uuid: Uuid, _trigger_id: Uuid) -> Result<Uuid> {
+        Ok(self.queue_run(workflow_uuid))
+    }
+
+    async fn insert_run_queued_with_fetch_outbox(
+        &self,
+        workflow_uuid: Uuid,
+        _trigger_id: Uuid,
+    ) -> Result<(Uuid, Uuid)> {
+        Ok((self.queue_run(workflow_uuid), Uuid::now_v7()))
+    }
+
+    async fn list_runs_paginated(
+        &self,
+        workflow_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<RunSummary>, i64)> {
+        let runs = self.runs_newest_first(Some(workflow_uuid));
+        let total = count(&runs);
+        let page = paginate(runs, limit, offset);
+        Ok((page.iter().map(Run::summary).collect(), total))
+    }
+
+    async fn list_run_logs_paginated(
+        &self,
+        run_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<RunLog>, i64)> {
+        let mut logs: Vec<(OffsetDateTime, RunLog)> = read(&self.run_logs)
+            .iter()
+            .filter(|(run, _, _)| *run == run_uuid)
+            .map(|(_, ts, log)| (*ts, log.clone()))
+            .collect();
+        logs.sort_by_key(|(ts, _)| Reverse(*ts));
+        let total = count(&logs);
+        let page = paginate(logs, limit, offset);
+        Ok((page.into_iter().map(|(_, log)| log).collect(), total))
+    }
+
+    async fn run_exists(&self, run_uuid: Uuid) -> Result<bool> {
+        Ok(read(&self.runs).contains_key(&run_uuid))
+    }
+
+    async fn run_metrics(
+        &self,
+        workflow_uuid: Uuid,
+        window_secs: i64,
+    ) -> Result<WorkflowRunMetrics> {
+        let since = OffsetDateTime::now_utc() - Duration::seconds(window_secs);
+        let runs: Vec<Run> = self
+            .runs_newest_first(Some(workflow_uuid))
+            .into_iter()
+            .filter(|run| run.queued_at >= since)
+            .collect();
+        let (avg_duration_ms, p95_duration_ms) = duration_stats(&runs);
+        Ok(WorkflowRunMetrics {
+            total_runs: count(&runs),
+            successful_runs: count_status(&runs, "success"),
+            failed_runs: count_status(&runs, "failed"),
+            processed_items: runs.iter().filter_map(|run| run.processed_items).sum(),
+            failed_items: runs.iter().filter_map(|run| run.failed_items).sum(),
+            avg_duration_ms,
+            p95_duration_ms,
+        })
+    }
+
+    async fn run_statistics(&self, workflow_uuid: Uuid) -> Result<WorkflowRunStats> {
+        let runs = self.runs_newest_first(Some(workflow_uuid));
+        let since = OffsetDateTime::now_utc() - Duration::hours(24);
+        let recent: Vec<Run> = runs
+            .iter()
+            .filter(|run| run.queued_at >= since)
+            .cloned()
+            .collect();
+        let (avg_duration_ms, p95_duration_ms) = duration_stats(&runs);
+        Ok(WorkflowRunStats {
+            total_runs: count(&runs),
+            queued_runs: count_status(&runs, "queued"),
+            running_runs: count_status(&runs, "running"),
+            successful_runs: count_status(&runs, "success"),
+            failed_runs: count_status(&runs, "failed"),
+            cancelled_runs: count_status(&runs, "cancelled"),
+            runs_last_24h: count(&recent),
+            successful_runs_last_24h: count_status(&recent, "success"),
+            failed_runs_last_24h: count_status(&recent, "failed"),
+            avg_duration_ms,
+            p95_duration_ms,
+            last_run_status: runs.first().map(|run| run.status.clone()),
+            last_run_at: runs.first().map(|run| run.queued_at),
+        })
+    }
+
+    async fn list_all_runs_paginated(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<RunSummary>, i64)> {
+        let runs = self.runs_newest_first(None);
+        let total = count(&runs);
+        let page = paginate(runs, limit, offset);
+        Ok((page.iter().map(Run::summary).collect(), total))
+    }
+
+    async fn insert_run_log(
+        &self,
+        run_uuid: Uuid,
+        level: &str,
+        message: &str,
+        meta: Option<Value>,
+    ) -> Result<()> {
+        let ts = OffsetDateTime::now_utc();
+        write(&self.run_logs).push((
+            run_uuid,
+            ts,
+            (
+                Uuid::now_v7(),
+                format_timestamp(ts),
+                level.to_string(),
+                message.to_string(),
+                meta,
+            ),
+        ));
+        Ok(())
+    }
+
+    async fn insert_raw_items(
+        &self,
+        _workflow_uuid: Uuid,
+        run_uuid: Uuid,
+        payloads: Vec<Value>,
+    ) -> Result<i64> {
+        let mut items = write(&self.raw_items);
+        let last_seq_no = items
+            .values()
+            .filter(|item| item.run_uuid == run_uuid)
+            .map(|item| item.seq_no)
+            .max()
+            .unwrap_or(0);
+        let inserted = count(&payloads);
+        for (seq_no, payload) in (last_seq_no + 1..).zip(payloads) {
+            items.insert(
+                Uuid::now_v7(),
+                RawItem {
+                    run_uuid,
+                    seq_no,
+                    payload,
+                    status: "queued".to_string(),
+                },
+            );
+        }
+        drop(items);
+        Ok(inserted)
+    }
+
+    async fn count_raw_items_for_run(&self, run_uuid: Uuid) -> Result<i64> {
+        let items = read(&self.raw_items);
+        Ok(count(
+            &items
+                .values()
+                .filter(|item| item.run_uuid == run_uuid)
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    async fn mark_raw_items_processed(&self, run_uuid: Uuid) -> Result<()> {
+        for item in write(&self.raw_items).values_mut() {
+            if item.run_uuid == run_uuid && item.status == "queued" {
+                item.status = "processed".to_string();
+            }
+        }
+        Ok(())
+    }
+
+    async fn fetch_staged_raw_items(
+        &self,
+        run_uuid: Uuid,
+        limit: i64,
+    ) -> Result<Vec<(Uuid, i64, Value)>> {
+        let mut staged: Vec<(Uuid, i64, Value)> = read(&self.raw_items)
+            .iter()
+            .filter(|(_, item)| item.run_uuid == run_uuid && item.status == "queued")
+            .map(|(uuid, item)| (*uuid, item.seq_no, item.payload.clone()))
+            .collect();
+        staged.sort_by_key(|(_, seq_no, _)| *seq_no);
+        Ok(paginate(staged, limit, 0))
+    }
+
+    async fn set_raw_item_status(
+        &self,
+        item_uuid: Uuid,
+        status: &str,
+        _error: Option<&str>,
+    ) -> Result<()> {
+        if let Some(item) = write(&self.raw_items).get_mut(&item_uuid) {
+            item.status = status.to_string();
+        }
+        Ok(())
+    }
+
+    async fn insert_run_error(
+        &self,
+        run_uuid: Uuid,
+        record_index: i64,
+        error_message: &str,
+        raw_payload: &Value,
+    ) -> Result<()> {
+        write(&self.run_errors).push(WorkflowRunError {
+            uuid: Uuid::now_v7(),
+            run_uuid,
+            record_index,
+            error_message: error_message.to_string(),
+            raw_payload: raw_payload.clone(),
+            created_at: OffsetDateTime::now_utc(),
+        });
+        Ok(())
+    }
+
+    async fn list_run_errors_paginated(
+        &self,
+        run_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<WorkflowRunError>, i64)> {
+        let mut errors: Vec<WorkflowRunError> = read(&self.run_errors)
+            .iter()
+            .filter(|error| error.run_uuid == run_uuid)
+            .cloned()
+            .collect();
+        errors.sort_by(|a, b| {
+            a.record_index
+                .cmp(&b.record_index)
+                .then(a.created_at.cmp(&b.created_at))
+        });
+        let total = count(&errors);
+        Ok((paginate(errors, limit, offset), total))
+    }
+
+    async fn get_workflow_uuid_for_run(&self, run_uuid: Uuid) -> Result<Option<Uuid>> {
+        Ok(read(&self.runs).get(&run_uuid).map(|run| run.workflow_uuid))
+    }
+
+    async fn claim_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        ttl_secs: i64,
+    ) -> Result<IdempotencyClaim> {
+        let now = OffsetDateTime::now_utc();
+        let mut keys = write(&self.idempotency_keys);
+        keys.retain(|(workflow, _), key| *workflow != workflow_uuid || key.expires_at > now);
+
+        let id = (workflow_uuid, idempotency_key.to_string());
+        if let Some(existing) = keys.get(&id) {
+            return Ok(existing
+                .response
+                .clone()
+                .map_or(IdempotencyClaim::InProgress, IdempotencyClaim::Replay));
+        }
+        keys.insert(
+            id,
+            IdempotencyKey {
+                expires_at: now + Duration::seconds(ttl_secs),
+                response: None,
+            },
+        );
+        drop(keys);
+        Ok(IdempotencyClaim::Claimed)
+    }
+
+    async fn complete_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        _run_uuid: Uuid,
+        response: &Value,
+    ) -> Result<()> {
+        if let Some(key) =
+            write(&self.idempotency_keys).get_mut(&(workflow_uuid, idempotency_key.to_string()))
+        {
+            key.response = Some(response.clone());
+        }
+        Ok(())
+    }
+
+    async fn release_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+    ) -> Result<()> {
+        let mut keys = write(&self.idempotency_keys);
+        let id = (workflow_uuid, idempotency_key.to_string());
+        if keys.get(&id).is_some_and(|key| key.response.is_none()) {
+            keys.remove(&id);
+        }
+        drop(keys);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(name: &str) -> CreateWorkflowRequest {
+        CreateWorkflowRequest {
+            name: name.to_string(),
+            description: None,
+            kind: "consumer".to_string(),
+            enabled: true,
+            schedule_cron: Some("0 * * * * *".to_string()),
+            config: json!({}),
+            versioning_disabled: false,
+            completion_webhook_url: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn workflows_and_runs_are_paginated() -> Result<()> {
+        let repo = InMemoryWorkflowRepository::new();
+        let actor = Uuid::now_v7();
+        for name in ["c", "a", "b"] {
+            repo.create(&request(name), actor).await?;
+        }
+        let names: Vec<String> = repo
+            .list_paginated(2, 0, Some("name".to_string()), Some("DESC".to_string()))
+            .await?
+            .into_iter()
+            .map(|wf| wf.name)
+            .collect();
+        assert_eq!(names, vec!["c", "b"]);
+
+        let workflow = repo.list_all().await?[0].uuid;
+        assert!(repo.set_paused(workflow, true).await?);
+        assert_eq!(repo.list_scheduled_consumers().await?.len(), 2);
+
+        let first = repo.insert_run_queued(workflow, Uuid::now_v7()).await?;
+        repo.insert_run_queued(workflow, Uuid::now_v7()).await?;
+        repo.mark_run_running(first).await?;
+        repo.mark_run_success(first, 3, 1).await?;
+        let (runs, total) = repo.list_runs_paginated(workflow, 1, 1).await?;
+        assert_eq!(total, 2);
+        assert_eq!(runs[0].0, first);
+        assert_eq!(runs[0].1, "success");
+
+        let metrics = repo.run_metrics(workflow, 3600).await?;
+        assert_eq!((metrics.total_runs, metrics.processed_items), (2, 3));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn idempotency_keys_replay_completed_responses() -> Result<()> {
+        let repo = InMemoryWorkflowRepository::new();
+        let workflow = Uuid::now_v7();
+        assert_eq!(
+            repo.claim_idempotency_key(workflow, "k", 60).await?,
+            IdempotencyClaim::Claimed
+        );
+        assert_eq!(
+            repo.claim_idempotency_key(workflow, "k", 60).await?,
+            IdempotencyClaim::InProgress
+        );
+        repo.complete_idempotency_key(workflow, "k", Uuid::now_v7(), &json!({"ok": true}))
+            .await?;
+        assert_eq!(
+            repo.claim_idempotency_key(workflow, "k", 60).await?,
+            IdempotencyClaim::Replay(json!({"ok": true}))
+        );
+        Ok(())
+    }
+}
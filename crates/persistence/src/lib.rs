@@ -3,9 +3,12 @@
 pub mod admin_user_repository;
 pub mod admin_user_repository_trait;
 pub mod api_key_repository;
+pub mod audit_log_repository;
+pub mod audit_log_repository_trait;
 pub mod component_version_repository;
 pub mod dashboard_stats_repository;
 pub mod dashboard_stats_repository_trait;
+pub mod database;
 pub mod dynamic_entity_mapper;
 pub mod dynamic_entity_public_repository;
 pub mod dynamic_entity_query_repository;
@@ -16,10 +19,20 @@ pub mod dynamic_entity_utils;
 pub mod dynamic_entity_versioning;
 pub mod email_template_repository;
 pub mod email_template_repository_trait;
+pub mod entity_aggregate_repository;
+pub mod entity_aggregate_repository_trait;
 pub mod entity_definition_repository;
 pub mod entity_definition_versioning_repository;
 pub mod entity_definition_versioning_repository_trait;
+pub mod entity_relations;
+pub mod entity_schema_repository;
+pub mod entity_webhook_repository;
+pub mod entity_webhook_repository_trait;
+#[cfg(feature = "test-util")]
+pub mod in_memory;
 pub mod migration_service;
+pub mod notification_repository;
+pub mod notification_repository_trait;
 pub mod outbox_repository;
 pub mod outbox_repository_trait;
 pub mod password_reset_repository;
@@ -50,23 +63,37 @@ pub use admin_user_repository_trait::{
     is_key_valid, AdminUserRepositoryTrait, ApiKeyRepositoryTrait, CreateAdminUserParams,
 };
 pub use api_key_repository::ApiKeyRepository;
+pub use audit_log_repository::AuditLogRepository;
+pub use audit_log_repository_trait::{AuditLogFilter, AuditLogRepositoryTrait};
 pub use component_version_repository::{ComponentVersion, ComponentVersionRepository};
 pub use dashboard_stats_repository::DashboardStatsRepository;
 pub use dashboard_stats_repository_trait::DashboardStatsRepositoryTrait;
+pub use database::Database;
 pub use dynamic_entity_public_repository::DynamicEntityPublicRepository;
 pub use dynamic_entity_query_repository::DynamicEntityQueryRepository;
 pub use dynamic_entity_query_repository_trait::DynamicEntityQueryRepositoryTrait;
-pub use dynamic_entity_repository::DynamicEntityRepository;
+pub use dynamic_entity_repository::{
+    missing_entity_cache_key, DynamicEntityRepository, MISSING_ENTITY_CACHE_PREFIX,
+};
 pub use dynamic_entity_repository_trait::{DynamicEntityRepositoryTrait, FilterEntitiesParams};
 pub use email_template_repository::EmailTemplateRepository;
 pub use email_template_repository_trait::EmailTemplateRepositoryTrait;
+pub use entity_aggregate_repository::EntityAggregateRepository;
+pub use entity_aggregate_repository_trait::EntityAggregateRepositoryTrait;
 pub use entity_definition_repository::EntityDefinitionRepository;
 pub use entity_definition_versioning_repository::{
     EntityDefinitionVersionMeta, EntityDefinitionVersionPayload,
     EntityDefinitionVersioningRepository,
 };
 pub use entity_definition_versioning_repository_trait::EntityDefinitionVersioningRepositoryTrait;
-pub use migration_service::{AppliedMigration, MigrationService, MigrationStatus};
+pub use entity_schema_repository::{DefinitionSchema, EntitySchemaRepository, EntityTableSchema};
+pub use entity_webhook_repository::EntityWebhookRepository;
+pub use entity_webhook_repository_trait::EntityWebhookRepositoryTrait;
+pub use migration_service::{
+    AppliedMigration, MigrationService, MigrationStatus, PendingMigration,
+};
+pub use notification_repository::NotificationRepository;
+pub use notification_repository_trait::NotificationRepositoryTrait;
 pub use outbox_repository::{OutboxMessageRecord, OutboxRepository};
 pub use outbox_repository_trait::OutboxRepositoryTrait;
 pub use password_reset_repository::PasswordResetRepository;
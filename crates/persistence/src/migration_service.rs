@@ -4,12 +4,16 @@
 //!
 //! This service provides functionality for running and checking database migrations.
 
+use sqlx::migrate::Migrator;
 use sqlx::PgPool;
 
 use crate::core::error::{Error, Result};
 
+/// Migrations bundled with this build
+static MIGRATOR: Migrator = sqlx::migrate!("../../migrations");
+
 /// Information about an applied migration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AppliedMigration {
     /// Migration version number
     pub version: i64,
@@ -38,6 +42,21 @@ impl MigrationStatus {
     pub const fn has_migrations(&self) -> bool {
         self.table_exists && !self.applied_migrations.is_empty()
     }
+
+    /// Version of the most recently applied migration
+    #[must_use]
+    pub fn current_version(&self) -> Option<i64> {
+        self.applied_migrations.iter().map(|m| m.version).max()
+    }
+}
+
+/// A bundled migration that has not been applied yet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingMigration {
+    /// Migration version number
+    pub version: i64,
+    /// Migration description
+    pub description: String,
 }
 
 /// Migration service for database schema management
@@ -57,20 +76,17 @@ impl MigrationService {
     /// # Errors
     /// Returns an error if migrations fail to run
     pub async fn run_migrations(&self) -> Result<()> {
-        sqlx::migrate!("../../migrations")
-            .run(&self.pool)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("already exists") {
-                    // Some objects already exist, this is often fine in idempotent scenarios
-                    return Error::Database(sqlx::Error::Configuration(
-                        "Some migration objects already exist".into(),
-                    ));
-                }
-                Error::Database(sqlx::Error::Configuration(
-                    format!("Migration failed: {e}").into(),
-                ))
-            })?;
+        MIGRATOR.run(&self.pool).await.map_err(|e| {
+            if e.to_string().contains("already exists") {
+                // Some objects already exist, this is often fine in idempotent scenarios
+                return Error::Database(sqlx::Error::Configuration(
+                    "Some migration objects already exist".into(),
+                ));
+            }
+            Error::Database(sqlx::Error::Configuration(
+                format!("Migration failed: {e}").into(),
+            ))
+        })?;
         Ok(())
     }
 
@@ -119,6 +135,35 @@ impl MigrationService {
         })
     }
 
+    /// Version of the newest migration bundled with this build
+    #[must_use]
+    pub fn expected_version() -> Option<i64> {
+        MIGRATOR
+            .iter()
+            .filter(|m| m.migration_type.is_up_migration())
+            .map(|m| m.version)
+            .max()
+    }
+
+    /// Bundled migrations missing from `status`, oldest first
+    #[must_use]
+    pub fn pending_migrations(status: &MigrationStatus) -> Vec<PendingMigration> {
+        MIGRATOR
+            .iter()
+            .filter(|m| m.migration_type.is_up_migration())
+            .filter(|m| {
+                !status
+                    .applied_migrations
+                    .iter()
+                    .any(|applied| applied.version == m.version)
+            })
+            .map(|m| PendingMigration {
+                version: m.version,
+                description: m.description.to_string(),
+            })
+            .collect()
+    }
+
     /// Get reference to the underlying pool
     #[must_use]
     pub const fn pool(&self) -> &PgPool {
@@ -159,6 +204,33 @@ mod tests {
 
         assert!(status.has_migrations());
         assert_eq!(status.applied_count(), 2);
+        assert_eq!(status.current_version(), Some(2));
+    }
+
+    #[test]
+    fn test_pending_migrations_are_the_unapplied_bundled_ones() {
+        let empty = MigrationStatus {
+            table_exists: false,
+            applied_migrations: Vec::new(),
+        };
+        let pending = MigrationService::pending_migrations(&empty);
+        assert!(!pending.is_empty());
+        assert_eq!(
+            pending.last().map(|m| m.version),
+            MigrationService::expected_version()
+        );
+
+        let all_applied = MigrationStatus {
+            table_exists: true,
+            applied_migrations: pending
+                .iter()
+                .map(|m| AppliedMigration {
+                    version: m.version,
+                    description: m.description.clone(),
+                })
+                .collect(),
+        };
+        assert!(MigrationService::pending_migrations(&all_applied).is_empty());
     }
 
     #[test]
@@ -0,0 +1,196 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::notification_repository_trait::NotificationRepositoryTrait;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::notification::{NewNotification, Notification, NotificationStatus};
+
+const COLUMNS: &str = "uuid, user_uuid, notification_type, title, message, data, status, \
+     scheduled_for, sent_at, read_at, error_message, retry_count, created_at";
+
+/// Repository for in-app notifications
+pub struct NotificationRepository {
+    pool: PgPool,
+}
+
+impl NotificationRepository {
+    /// Create a new notification repository
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Decode a `Notification` from a raw `sqlx::postgres::PgRow`
+fn row_to_notification(row: &sqlx::postgres::PgRow) -> Result<Notification> {
+    let status: String = row.try_get("status")?;
+    let notification_type: String = row.try_get("notification_type")?;
+    Ok(Notification {
+        uuid: row.try_get("uuid")?,
+        user_uuid: row.try_get("user_uuid")?,
+        notification_type: notification_type.parse().map_err(Error::Deserialization)?,
+        title: row.try_get("title")?,
+        message: row.try_get("message")?,
+        data: row.try_get("data")?,
+        status: status.parse().map_err(Error::Deserialization)?,
+        scheduled_for: row.try_get("scheduled_for")?,
+        sent_at: row.try_get("sent_at")?,
+        read_at: row.try_get("read_at")?,
+        error_message: row.try_get("error_message")?,
+        retry_count: row.try_get("retry_count")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+#[async_trait]
+impl NotificationRepositoryTrait for NotificationRepository {
+    async fn create(
+        &self,
+        notification: &NewNotification,
+        status: NotificationStatus,
+        scheduled_for: Option<OffsetDateTime>,
+    ) -> Result<Notification> {
+        let row = sqlx::query(&format!(
+            "INSERT INTO notifications
+                (user_uuid, notification_type, title, message, data, status, scheduled_for)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {COLUMNS}"
+        ))
+        .bind(notification.user_uuid)
+        .bind(notification.notification_type.as_str())
+        .bind(&notification.title)
+        .bind(&notification.message)
+        .bind(&notification.data)
+        .bind(status.as_str())
+        .bind(scheduled_for)
+        .fetch_one(&self.pool)
+        .await?;
+        row_to_notification(&row)
+    }
+
+    async fn get_by_uuid(&self, uuid: Uuid) -> Result<Option<Notification>> {
+        let row = sqlx::query(&format!(
+            "SELECT {COLUMNS} FROM notifications WHERE uuid = $1"
+        ))
+        .bind(uuid)
+        .fetch_optional(&self.pool)
+        .await?;
+        row.as_ref().map(row_to_notification).transpose()
+    }
+
+    async fn mark_sent(&self, uuid: Uuid) -> Result<Option<Notification>> {
+        let row = sqlx::query(&format!(
+            "UPDATE notifications SET status = 'sent', sent_at = NOW(), error_message = NULL
+            WHERE uuid = $1 AND status IN ('pending', 'scheduled', 'failed')
+            RETURNING {COLUMNS}"
+        ))
+        .bind(uuid)
+        .fetch_optional(&self.pool)
+        .await?;
+        row.as_ref().map(row_to_notification).transpose()
+    }
+
+    async fn mark_failed(&self, uuid: Uuid, error: &str) -> Result<Option<Notification>> {
+        let row = sqlx::query(&format!(
+            "UPDATE notifications
+            SET status = 'failed', error_message = $2, retry_count = retry_count + 1
+            WHERE uuid = $1 AND status IN ('pending', 'scheduled', 'failed')
+            RETURNING {COLUMNS}"
+        ))
+        .bind(uuid)
+        .bind(error)
+        .fetch_optional(&self.pool)
+        .await?;
+        row.as_ref().map(row_to_notification).transpose()
+    }
+
+    async fn list_email_outstanding(
+        &self,
+        max_retries: i32,
+        limit: i64,
+    ) -> Result<Vec<Notification>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {COLUMNS} FROM notifications
+            WHERE notification_type = 'email'
+              AND (status = 'pending'
+                   OR (status = 'scheduled' AND scheduled_for <= NOW())
+                   OR (status = 'failed' AND retry_count < $1))
+            ORDER BY created_at
+            LIMIT $2"
+        ))
+        .bind(max_retries)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        rows.iter().map(row_to_notification).collect()
+    }
+
+    async fn user_email(&self, user_uuid: Uuid) -> Result<Option<String>> {
+        Ok(
+            sqlx::query_scalar("SELECT email FROM admin_users WHERE uuid = $1")
+                .bind(user_uuid)
+                .fetch_optional(&self.pool)
+                .await?,
+        )
+    }
+
+    async fn send_due(&self, user_uuid: Uuid) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE notifications SET status = 'sent', sent_at = scheduled_for
+            WHERE user_uuid = $1 AND notification_type = 'in_app'
+              AND status = 'scheduled' AND scheduled_for <= NOW()",
+        )
+        .bind(user_uuid)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn cancel(&self, uuid: Uuid) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE notifications SET status = 'cancelled'
+            WHERE uuid = $1 AND status IN ('pending', 'scheduled')",
+        )
+        .bind(uuid)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn mark_read(&self, user_uuid: Uuid, uuid: Uuid) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE notifications SET read_at = COALESCE(read_at, NOW())
+            WHERE uuid = $1 AND user_uuid = $2 AND status = 'sent'",
+        )
+        .bind(uuid)
+        .bind(user_uuid)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_sent_for_user(
+        &self,
+        user_uuid: Uuid,
+        include_read: bool,
+    ) -> Result<Vec<Notification>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {COLUMNS} FROM notifications
+            WHERE user_uuid = $1 AND notification_type = 'in_app' AND status = 'sent'
+              AND ($2 OR read_at IS NULL)
+            ORDER BY sent_at DESC, created_at DESC"
+        ))
+        .bind(user_uuid)
+        .bind(include_read)
+        .fetch_all(&self.pool)
+        .await?;
+        rows.iter().map(row_to_notification).collect()
+    }
+}
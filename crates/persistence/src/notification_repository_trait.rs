@@ -0,0 +1,89 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use r_data_core_core::error::Result;
+use r_data_core_core::notification::{NewNotification, Notification, NotificationStatus};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Trait for in-app notification repository operations
+#[async_trait]
+pub trait NotificationRepositoryTrait: Send + Sync {
+    /// Store a notification with the given status
+    ///
+    /// # Errors
+    /// Returns an error if the database insert fails
+    async fn create(
+        &self,
+        notification: &NewNotification,
+        status: NotificationStatus,
+        scheduled_for: Option<OffsetDateTime>,
+    ) -> Result<Notification>;
+
+    /// Get a notification by UUID
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    async fn get_by_uuid(&self, uuid: Uuid) -> Result<Option<Notification>>;
+
+    /// Mark an undelivered (pending, scheduled or failed) notification as sent and clear its
+    /// error; `None` if it is sent or cancelled already
+    ///
+    /// # Errors
+    /// Returns an error if the database update fails
+    async fn mark_sent(&self, uuid: Uuid) -> Result<Option<Notification>>;
+
+    /// Record a failed delivery attempt of an undelivered notification; `None` if it is sent
+    /// or cancelled already
+    ///
+    /// # Errors
+    /// Returns an error if the database update fails
+    async fn mark_failed(&self, uuid: Uuid, error: &str) -> Result<Option<Notification>>;
+
+    /// E-mail notifications waiting for delivery, oldest first: pending ones, scheduled ones
+    /// that are due and failed ones with fewer than `max_retries` attempts
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    async fn list_email_outstanding(
+        &self,
+        max_retries: i32,
+        limit: i64,
+    ) -> Result<Vec<Notification>>;
+
+    /// E-mail address of the admin user a notification is addressed to
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    async fn user_email(&self, user_uuid: Uuid) -> Result<Option<String>>;
+
+    /// Mark the user's scheduled in-app notifications that are due as sent; returns how many were
+    ///
+    /// # Errors
+    /// Returns an error if the database update fails
+    async fn send_due(&self, user_uuid: Uuid) -> Result<u64>;
+
+    /// Cancel a pending or scheduled notification; returns whether one was cancelled
+    ///
+    /// # Errors
+    /// Returns an error if the database update fails
+    async fn cancel(&self, uuid: Uuid) -> Result<bool>;
+
+    /// Set `read_at` on one of the user's sent notifications; returns whether it exists
+    ///
+    /// Marking an already read notification keeps its original `read_at`.
+    ///
+    /// # Errors
+    /// Returns an error if the database update fails
+    async fn mark_read(&self, user_uuid: Uuid, uuid: Uuid) -> Result<bool>;
+
+    /// Sent in-app notifications of a user, newest first
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    async fn list_sent_for_user(
+        &self,
+        user_uuid: Uuid,
+        include_read: bool,
+    ) -> Result<Vec<Notification>>;
+}
@@ -1,8 +1,8 @@
 use super::types::OutboxInsertMessage;
 use super::OutboxRepository;
 use r_data_core_core::outbox::{
-    WORKFLOW_FETCH_ENQUEUE_KIND, WORKFLOW_FETCH_TOPIC, WORKFLOW_OUTBOX_NOTIFY_CHANNEL,
-    WORKFLOW_PUSH_ENQUEUE_KIND, WORKFLOW_PUSH_TOPIC,
+    ENTITY_WEBHOOK_KIND, ENTITY_WEBHOOK_TOPIC, WORKFLOW_FETCH_ENQUEUE_KIND, WORKFLOW_FETCH_TOPIC,
+    WORKFLOW_OUTBOX_NOTIFY_CHANNEL, WORKFLOW_PUSH_ENQUEUE_KIND, WORKFLOW_PUSH_TOPIC,
 };
 use r_data_core_core::{error::Error, error::Result};
 use sqlx::{PgPool, Postgres, Row, Transaction};
@@ -95,6 +95,39 @@ impl OutboxRepository {
         .await
     }
 
+    /// Insert an entity field-change webhook delivery in the outbox.
+    ///
+    /// `change_uuid` identifies the change event, so each change is delivered once per subscription.
+    ///
+    /// # Errors
+    /// Returns an error if the insert fails.
+    pub async fn insert_entity_webhook_delivery(
+        &self,
+        subscription_uuid: Uuid,
+        entity_uuid: Uuid,
+        change_uuid: Uuid,
+        payload: serde_json::Value,
+    ) -> Result<Uuid> {
+        let headers = serde_json::json!({
+            "subscription_uuid": subscription_uuid,
+            "entity_uuid": entity_uuid,
+            "topic": ENTITY_WEBHOOK_TOPIC,
+        });
+        let idempotency_key =
+            format!("entity.webhook.deliver:{subscription_uuid}:{entity_uuid}:{change_uuid}");
+
+        self.insert_message(OutboxInsertMessage {
+            topic: ENTITY_WEBHOOK_TOPIC,
+            kind: ENTITY_WEBHOOK_KIND,
+            aggregate_type: "entity",
+            aggregate_id: entity_uuid.to_string(),
+            payload,
+            headers,
+            idempotency_key,
+        })
+        .await
+    }
+
     /// Insert a workflow fetch dispatch message in the outbox inside an existing transaction.
     ///
     /// # Errors
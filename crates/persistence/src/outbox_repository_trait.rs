@@ -27,6 +27,14 @@ pub trait OutboxRepositoryTrait: Send + Sync {
         destination_fingerprint: &str,
     ) -> Result<Uuid>;
 
+    async fn insert_entity_webhook_delivery(
+        &self,
+        subscription_uuid: Uuid,
+        entity_uuid: Uuid,
+        change_uuid: Uuid,
+        payload: serde_json::Value,
+    ) -> Result<Uuid>;
+
     async fn claim_due(&self, limit: i64, worker_id: &str) -> Result<Vec<OutboxMessage>>;
 
     async fn next_available_at(&self) -> Result<Option<OffsetDateTime>>;
@@ -82,6 +90,23 @@ impl OutboxRepositoryTrait for OutboxRepository {
         .await
     }
 
+    async fn insert_entity_webhook_delivery(
+        &self,
+        subscription_uuid: Uuid,
+        entity_uuid: Uuid,
+        change_uuid: Uuid,
+        payload: serde_json::Value,
+    ) -> Result<Uuid> {
+        Self::insert_entity_webhook_delivery(
+            self,
+            subscription_uuid,
+            entity_uuid,
+            change_uuid,
+            payload,
+        )
+        .await
+    }
+
     async fn claim_due(&self, limit: i64, worker_id: &str) -> Result<Vec<OutboxMessage>> {
         Self::claim_due(self, limit, worker_id)
             .await
@@ -6,6 +6,7 @@ use uuid::Uuid;
 
 use crate::core::error::{Error, Result};
 use crate::core::permissions::role::Role;
+use crate::core::sort::order_by_clause;
 use crate::role_repository_trait::RoleRepositoryTrait;
 
 /// Repository for role operations
@@ -201,18 +202,10 @@ impl RoleRepository {
         sort_order: Option<String>,
     ) -> Result<Vec<Role>> {
         // Build ORDER BY clause - field is already validated and sanitized by route handler
-        let order_by = sort_by.map_or_else(
-            || "\"created_at\" DESC".to_string(),
-            |field| {
-                let quoted_field = format!("\"{}\"", field.replace('"', "\"\""));
-                let order = sort_order
-                    .as_ref()
-                    .map(|o| o.to_uppercase())
-                    .filter(|o| o == "ASC" || o == "DESC")
-                    .unwrap_or_else(|| "ASC".to_string());
-                format!("{quoted_field} {order}")
-            },
-        );
+        let order_by = order_by_clause(sort_by.as_deref(), sort_order.as_deref(), |sort| {
+            format!("{} {}", sort.quoted_field(), sort.direction.as_sql())
+        })
+        .unwrap_or_else(|| "\"created_at\" DESC".to_string());
 
         // Build query with or without LIMIT
         let query = if limit == i64::MAX {
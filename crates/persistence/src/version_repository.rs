@@ -13,6 +13,7 @@ pub struct EntityVersionMeta {
     pub created_at: OffsetDateTime,
     pub created_by: Option<Uuid>,
     pub created_by_name: Option<String>,
+    pub comment: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -52,6 +53,7 @@ impl VersionRepository {
                 ev.version_number,
                 ev.created_at,
                 ev.created_by,
+                ev.comment,
                 COALESCE(
                     NULLIF(TRIM(COALESCE(au.first_name || ' ', '') || COALESCE(au.last_name, '')), ''),
                     au.username,
@@ -77,11 +79,13 @@ impl VersionRepository {
                 r.try_get("created_at").map_err(Error::Database)?;
             let created_by: Option<Uuid> = r.try_get("created_by").ok();
             let created_by_name: Option<String> = r.try_get("created_by_name").ok();
+            let comment: Option<String> = r.try_get("comment").ok();
             out.push(EntityVersionMeta {
                 version_number,
                 created_at,
                 created_by,
                 created_by_name,
+                comment,
             });
         }
         Ok(out)
@@ -207,7 +211,69 @@ impl VersionRepository {
         Ok(())
     }
 
-    /// Prune versions older than specified days
+    /// Record the current state of an entity as a version snapshot carrying a comment,
+    /// within a transaction.
+    /// This is an associated function (static method) since it doesn't require a `VersionRepository` instance.
+    ///
+    /// # Arguments
+    /// * `tx` - Database transaction
+    /// * `entity_uuid` - UUID of the entity
+    /// * `created_by` - UUID of the user creating the snapshot
+    /// * `comment` - Comment stored alongside the snapshot
+    ///
+    /// # Errors
+    /// Returns an error if database query or operation fails
+    pub async fn snapshot_current_with_comment_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        entity_uuid: Uuid,
+        created_by: Option<Uuid>,
+        comment: &str,
+    ) -> Result<()> {
+        let row = sqlx::query("SELECT entity_type, version FROM entities_registry WHERE uuid = $1")
+            .bind(entity_uuid)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(Error::Database)?;
+        let Some(row) = row else {
+            return Ok(()); // nothing to snapshot
+        };
+        let entity_type: String = row.try_get("entity_type").map_err(Error::Database)?;
+        let version: i32 = row.try_get("version").map_err(Error::Database)?;
+
+        let view_name = crate::dynamic_entity_utils::quoted_view_name(&entity_type)?;
+        // The soft-delete marker is registry state, not entity data
+        let current_json: Option<serde_json::Value> = sqlx::query_scalar(&format!(
+            "SELECT to_jsonb(t) - 'deleted_at' FROM (SELECT * FROM {view_name} WHERE uuid = $1) t"
+        ))
+        .bind(entity_uuid)
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(Error::Database)?;
+
+        if let Some(data) = current_json {
+            sqlx::query(
+                "
+                INSERT INTO entities_versions (entity_uuid, entity_type, version_number, data, created_at, created_by, comment)
+                VALUES ($1, $2, $3, $4, NOW(), $5, $6)
+                ON CONFLICT (entity_uuid, version_number) DO NOTHING
+                ",
+            )
+            .bind(entity_uuid)
+            .bind(&entity_type)
+            .bind(version)
+            .bind(data)
+            .bind(created_by)
+            .bind(comment)
+            .execute(&mut **tx)
+            .await
+            .map_err(Error::Database)?;
+        }
+
+        Ok(())
+    }
+
+    /// Prune versions older than specified days.
+    /// A snapshot of the entity's current version is never deleted.
     ///
     /// # Arguments
     /// * `days` - Number of days to keep
@@ -220,8 +286,12 @@ impl VersionRepository {
     pub async fn prune_older_than_days(&self, days: i32) -> Result<u64> {
         let res = sqlx::query(
             "
-            DELETE FROM entities_versions
-            WHERE created_at < NOW() - ($1::text || ' days')::interval
+            DELETE FROM entities_versions ev
+            WHERE ev.created_at < NOW() - ($1::text || ' days')::interval
+              AND NOT EXISTS (
+                  SELECT 1 FROM entities_registry er
+                  WHERE er.uuid = ev.entity_uuid AND er.version = ev.version_number
+              )
             ",
         )
         .bind(days.to_string())
@@ -231,7 +301,8 @@ impl VersionRepository {
         Ok(res.rows_affected())
     }
 
-    /// Prune versions keeping only the latest N per entity
+    /// Prune versions keeping only the latest N per entity.
+    /// A snapshot of the entity's current version is never deleted.
     ///
     /// # Arguments
     /// * `keep` - Number of latest versions to keep per entity
@@ -255,6 +326,10 @@ impl VersionRepository {
             WHERE ev.entity_uuid = r.entity_uuid
               AND ev.version_number = r.version_number
               AND r.rn > $1
+              AND NOT EXISTS (
+                  SELECT 1 FROM entities_registry er
+                  WHERE er.uuid = ev.entity_uuid AND er.version = ev.version_number
+              )
             ",
         )
         .bind(keep)
@@ -327,12 +402,10 @@ impl VersionRepository {
         entity_uuid: Uuid,
         entity_type: &str,
     ) -> Result<Option<serde_json::Value>> {
-        fn get_view_name(entity_type: &str) -> String {
-            format!("entity_{}_view", entity_type.to_lowercase())
-        }
-        let view_name = get_view_name(entity_type);
+        let view_name = crate::dynamic_entity_utils::quoted_view_name(entity_type)?;
+        // The soft-delete marker is registry state, not entity data
         let current_json: Option<serde_json::Value> = sqlx::query_scalar(&format!(
-            "SELECT row_to_json(t) FROM (SELECT * FROM {view_name} WHERE uuid = $1) t"
+            "SELECT to_jsonb(t) - 'deleted_at' FROM (SELECT * FROM {view_name} WHERE uuid = $1) t"
         ))
         .bind(entity_uuid)
         .fetch_optional(&self.pool)
@@ -355,10 +428,6 @@ impl VersionRepository {
         tx: &mut Transaction<'_, Postgres>,
         entity_uuid: Uuid,
     ) -> Result<()> {
-        fn get_view_name(entity_type: &str) -> String {
-            format!("entity_{}_view", entity_type.to_lowercase())
-        }
-
         // Read current entity_type, version, updated_by, and created_by in a single query
         let row = sqlx::query("SELECT entity_type, version, updated_by, created_by FROM entities_registry WHERE uuid = $1")
             .bind(entity_uuid)
@@ -380,9 +449,10 @@ impl VersionRepository {
         };
 
         // Build view name and read current row as JSON
-        let view_name = get_view_name(&entity_type);
+        let view_name = crate::dynamic_entity_utils::quoted_view_name(&entity_type)?;
+        // The soft-delete marker is registry state, not entity data
         let current_json: Option<serde_json::Value> = sqlx::query_scalar(&format!(
-            "SELECT row_to_json(t) FROM (SELECT * FROM {view_name} WHERE uuid = $1) t"
+            "SELECT to_jsonb(t) - 'deleted_at' FROM (SELECT * FROM {view_name} WHERE uuid = $1) t"
         ))
         .bind(entity_uuid)
         .fetch_optional(&mut **tx)
@@ -7,6 +7,7 @@ use uuid::Uuid;
 use super::WorkflowRepository;
 use crate::workflow_versioning_repository::WorkflowVersioningRepository;
 use r_data_core_core::error::Result;
+use r_data_core_core::sort::order_by_clause;
 use r_data_core_workflow::data::requests::{CreateWorkflowRequest, UpdateWorkflowRequest};
 use r_data_core_workflow::data::{Workflow, WorkflowKind};
 use std::str::FromStr;
@@ -22,7 +23,7 @@ impl WorkflowRepository {
     pub async fn get_by_uuid(&self, uuid: Uuid) -> Result<Option<Workflow>> {
         let row = sqlx::query(
             "
-            SELECT uuid, name, description, kind::text, enabled, schedule_cron, config, versioning_disabled
+            SELECT uuid, name, description, kind::text, enabled, schedule_cron, config, versioning_disabled, paused, completion_webhook_url
             FROM workflows
             WHERE uuid = $1
             ",
@@ -54,6 +55,8 @@ impl WorkflowRepository {
                     .try_get::<Option<bool>, _>(7)
                     .unwrap_or(Some(true))
                     .unwrap_or(true);
+                let paused: bool = r.try_get(8).unwrap_or(false);
+                let completion_webhook_url: Option<String> = r.try_get(9).ok().flatten();
                 let wf = Workflow {
                     uuid,
                     name,
@@ -61,8 +64,10 @@ impl WorkflowRepository {
                     kind,
                     enabled,
                     schedule_cron,
+                    paused,
                     config,
                     versioning_disabled,
+                    completion_webhook_url,
                 };
                 Ok(Some(wf))
             },
@@ -76,8 +81,8 @@ impl WorkflowRepository {
     pub async fn create(&self, req: &CreateWorkflowRequest, created_by: Uuid) -> Result<Uuid> {
         let row = sqlx::query(
             "
-            INSERT INTO workflows (name, description, kind, enabled, schedule_cron, config, versioning_disabled, created_by)
-            VALUES ($1, $2, $3::workflow_kind, $4, $5, $6, $7, $8)
+            INSERT INTO workflows (name, description, kind, enabled, schedule_cron, config, versioning_disabled, created_by, completion_webhook_url)
+            VALUES ($1, $2, $3::workflow_kind, $4, $5, $6, $7, $8, $9)
             RETURNING uuid
             ",
         )
@@ -89,6 +94,7 @@ impl WorkflowRepository {
         .bind(&req.config)
         .bind(req.versioning_disabled)
         .bind(created_by)
+        .bind(req.completion_webhook_url.as_deref())
         .fetch_one(&self.pool)
         .await?;
 
@@ -118,7 +124,7 @@ impl WorkflowRepository {
             "
             UPDATE workflows
             SET name = $2, description = $3, kind = $4::workflow_kind, enabled = $5,
-                schedule_cron = $6, config = $7, versioning_disabled = $8, updated_by = $9, version = version + 1, updated_at = NOW()
+                schedule_cron = $6, config = $7, versioning_disabled = $8, updated_by = $9, completion_webhook_url = $10, version = version + 1, updated_at = NOW()
             WHERE uuid = $1
             ",
         )
@@ -131,6 +137,7 @@ impl WorkflowRepository {
         .bind(&req.config)
         .bind(req.versioning_disabled)
         .bind(updated_by)
+        .bind(req.completion_webhook_url.as_deref())
         .execute(&self.pool)
         .await?;
         Ok(())
@@ -158,7 +165,7 @@ impl WorkflowRepository {
     pub async fn list_all(&self) -> Result<Vec<Workflow>> {
         let rows = sqlx::query(
             "
-            SELECT uuid, name, description, kind::text, enabled, schedule_cron, config, versioning_disabled
+            SELECT uuid, name, description, kind::text, enabled, schedule_cron, config, versioning_disabled, paused, completion_webhook_url
             FROM workflows
             ORDER BY name
             ",
@@ -184,11 +191,13 @@ impl WorkflowRepository {
                     .unwrap_or(Some(true))
                     .unwrap_or(true),
                 schedule_cron: r.try_get(5).ok(),
+                paused: r.try_get(8).unwrap_or(false),
                 config: r.try_get(6).unwrap_or_else(|_| serde_json::json!({})),
                 versioning_disabled: r
                     .try_get::<Option<bool>, _>(7)
                     .unwrap_or(Some(false))
                     .unwrap_or(false),
+                completion_webhook_url: r.try_get(9).ok().flatten(),
             });
         }
         Ok(out)
@@ -209,24 +218,16 @@ impl WorkflowRepository {
         sort_order: Option<String>,
     ) -> Result<Vec<Workflow>> {
         // Build ORDER BY clause - field is already validated and sanitized by route handler
-        let order_by = sort_by.map_or_else(
-            || "\"name\" ASC".to_string(),
-            |field| {
-                let quoted_field = format!("\"{}\"", field.replace('"', "\"\""));
-                let order = sort_order
-                    .as_ref()
-                    .map(|o| o.to_uppercase())
-                    .filter(|o| o == "ASC" || o == "DESC")
-                    .unwrap_or_else(|| "ASC".to_string());
-                format!("{quoted_field} {order}")
-            },
-        );
+        let order_by = order_by_clause(sort_by.as_deref(), sort_order.as_deref(), |sort| {
+            format!("{} {}", sort.quoted_field(), sort.direction.as_sql())
+        })
+        .unwrap_or_else(|| "\"name\" ASC".to_string());
 
         // Build query with or without LIMIT
         let query = if limit == i64::MAX {
             format!(
                 "
-                SELECT uuid, name, description, kind::text, enabled, schedule_cron, config, versioning_disabled
+                SELECT uuid, name, description, kind::text, enabled, schedule_cron, config, versioning_disabled, paused, completion_webhook_url
                 FROM workflows
                 ORDER BY {order_by} OFFSET $1
                 "
@@ -234,7 +235,7 @@ impl WorkflowRepository {
         } else {
             format!(
                 "
-                SELECT uuid, name, description, kind::text, enabled, schedule_cron, config, versioning_disabled
+                SELECT uuid, name, description, kind::text, enabled, schedule_cron, config, versioning_disabled, paused, completion_webhook_url
                 FROM workflows
                 ORDER BY {order_by} LIMIT $1 OFFSET $2
                 "
@@ -271,6 +272,8 @@ impl WorkflowRepository {
                 .try_get::<Option<bool>, _>(7)
                 .unwrap_or(Some(false))
                 .unwrap_or(false);
+            let paused: bool = r.try_get(8).unwrap_or(false);
+            let completion_webhook_url: Option<String> = r.try_get(9).ok().flatten();
             out.push(Workflow {
                 uuid,
                 name,
@@ -278,8 +281,10 @@ impl WorkflowRepository {
                 kind,
                 enabled,
                 schedule_cron,
+                paused,
                 config,
                 versioning_disabled,
+                completion_webhook_url,
             });
         }
         Ok(out)
@@ -298,7 +303,7 @@ impl WorkflowRepository {
 
     /// Check if a workflow config has from.api source type (accepts POST, cron disabled)
     /// or to.format.output.mode === 'api' (exports via GET, cron disabled)
-    fn check_has_api_endpoint(config: &Value) -> bool {
+    pub(crate) fn check_has_api_endpoint(config: &Value) -> bool {
         if let Some(steps) = config.get("steps").and_then(|v| v.as_array()) {
             for step in steps {
                 // Check for from.api source type (accepts POST, cron disabled)
@@ -352,7 +357,20 @@ impl WorkflowRepository {
         false
     }
 
-    /// List scheduled workflow consumers
+    /// Pause or resume the schedule of a workflow without touching its cron expression
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn set_paused(&self, uuid: Uuid, paused: bool) -> Result<bool> {
+        let result = sqlx::query("UPDATE workflows SET paused = $2 WHERE uuid = $1")
+            .bind(uuid)
+            .bind(paused)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// List scheduled workflow consumers; paused workflows are skipped
     ///
     /// # Errors
     /// Returns an error if the database query fails
@@ -362,7 +380,7 @@ impl WorkflowRepository {
     pub async fn list_scheduled_consumers(&self) -> Result<Vec<(Uuid, String)>> {
         // Fetch workflows with their config to check for from.api source type
         let rows = sqlx::query(
-            "SELECT uuid, schedule_cron, config FROM workflows WHERE enabled = true AND kind = 'consumer'::workflow_kind AND schedule_cron IS NOT NULL",
+            "SELECT uuid, schedule_cron, config FROM workflows WHERE enabled = true AND paused = false AND kind = 'consumer'::workflow_kind AND schedule_cron IS NOT NULL",
         )
         .fetch_all(&self.pool)
         .await
@@ -0,0 +1,106 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use uuid::Uuid;
+
+use super::WorkflowRepository;
+use r_data_core_core::error::Result;
+use r_data_core_workflow::data::IdempotencyClaim;
+
+impl WorkflowRepository {
+    /// Claim an idempotency key of a workflow for `ttl_secs` seconds
+    ///
+    /// Expired keys of the workflow are dropped first, so a key can be reused once its
+    /// window has passed.
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn claim_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        ttl_secs: i64,
+    ) -> Result<IdempotencyClaim> {
+        sqlx::query(
+            "DELETE FROM workflow_idempotency_keys WHERE workflow_uuid = $1 AND expires_at <= NOW()",
+        )
+        .bind(workflow_uuid)
+        .execute(&self.pool)
+        .await?;
+
+        let inserted = sqlx::query(
+            "
+            INSERT INTO workflow_idempotency_keys (workflow_uuid, idempotency_key, expires_at)
+            VALUES ($1, $2, NOW() + make_interval(secs => $3::float8))
+            ON CONFLICT (workflow_uuid, idempotency_key) DO NOTHING
+            ",
+        )
+        .bind(workflow_uuid)
+        .bind(idempotency_key)
+        .bind(ttl_secs)
+        .execute(&self.pool)
+        .await?;
+        if inserted.rows_affected() > 0 {
+            return Ok(IdempotencyClaim::Claimed);
+        }
+
+        let response: Option<Option<serde_json::Value>> = sqlx::query_scalar(
+            "SELECT response FROM workflow_idempotency_keys WHERE workflow_uuid = $1 AND idempotency_key = $2",
+        )
+        .bind(workflow_uuid)
+        .bind(idempotency_key)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(response
+            .flatten()
+            .map_or(IdempotencyClaim::InProgress, IdempotencyClaim::Replay))
+    }
+
+    /// Store the run and response of a claimed idempotency key
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn complete_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        run_uuid: Uuid,
+        response: &serde_json::Value,
+    ) -> Result<()> {
+        sqlx::query(
+            "
+            UPDATE workflow_idempotency_keys
+            SET run_uuid = $3, response = $4
+            WHERE workflow_uuid = $1 AND idempotency_key = $2
+            ",
+        )
+        .bind(workflow_uuid)
+        .bind(idempotency_key)
+        .bind(run_uuid)
+        .bind(response)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Release a claimed idempotency key whose request failed, so it can be retried
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn release_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+    ) -> Result<()> {
+        sqlx::query(
+            "
+            DELETE FROM workflow_idempotency_keys
+            WHERE workflow_uuid = $1 AND idempotency_key = $2 AND response IS NULL
+            ",
+        )
+        .bind(workflow_uuid)
+        .bind(idempotency_key)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
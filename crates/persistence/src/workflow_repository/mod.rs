@@ -1,16 +1,23 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 mod crud;
+mod idempotency;
 mod raw_items;
+mod run_errors;
 mod runs;
 
+pub(crate) use runs::query_run_statistics;
+
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use super::workflow_repository_trait::WorkflowRepositoryTrait;
 use r_data_core_core::error::Result;
 use r_data_core_workflow::data::requests::{CreateWorkflowRequest, UpdateWorkflowRequest};
-use r_data_core_workflow::data::Workflow;
+use r_data_core_workflow::data::{
+    IdempotencyClaim, Workflow, WorkflowRunError, WorkflowRunMetrics, WorkflowRunProgress,
+    WorkflowRunStats,
+};
 
 pub struct WorkflowRepository {
     pool: PgPool,
@@ -58,6 +65,9 @@ impl WorkflowRepositoryTrait for WorkflowRepository {
     async fn delete(&self, uuid: Uuid) -> Result<()> {
         self.delete(uuid).await
     }
+    async fn set_paused(&self, uuid: Uuid, paused: bool) -> Result<bool> {
+        self.set_paused(uuid, paused).await
+    }
     async fn list_scheduled_consumers(&self) -> Result<Vec<(Uuid, String)>> {
         self.list_scheduled_consumers().await
     }
@@ -84,6 +94,30 @@ impl WorkflowRepositoryTrait for WorkflowRepository {
     async fn get_run_status(&self, run_uuid: Uuid) -> Result<Option<String>> {
         self.get_run_status(run_uuid).await
     }
+    async fn update_run_progress(
+        &self,
+        run_uuid: Uuid,
+        processed: i64,
+        failed: i64,
+        total: i64,
+    ) -> Result<()> {
+        self.update_run_progress(run_uuid, processed, failed, total)
+            .await
+    }
+    async fn get_run_progress(&self, run_uuid: Uuid) -> Result<Option<WorkflowRunProgress>> {
+        self.get_run_progress(run_uuid).await
+    }
+    async fn set_run_config_override(
+        &self,
+        run_uuid: Uuid,
+        config_override: &serde_json::Value,
+    ) -> Result<()> {
+        self.set_run_config_override(run_uuid, config_override)
+            .await
+    }
+    async fn get_run_config_override(&self, run_uuid: Uuid) -> Result<Option<serde_json::Value>> {
+        self.get_run_config_override(run_uuid).await
+    }
     async fn list_runs_paginated(
         &self,
         workflow_uuid: Uuid,
@@ -116,6 +150,16 @@ impl WorkflowRepositoryTrait for WorkflowRepository {
     async fn run_exists(&self, run_uuid: Uuid) -> Result<bool> {
         self.run_exists(run_uuid).await
     }
+    async fn run_metrics(
+        &self,
+        workflow_uuid: Uuid,
+        window_secs: i64,
+    ) -> Result<WorkflowRunMetrics> {
+        self.run_metrics(workflow_uuid, window_secs).await
+    }
+    async fn run_statistics(&self, workflow_uuid: Uuid) -> Result<WorkflowRunStats> {
+        self.run_statistics(workflow_uuid).await
+    }
     async fn list_all_runs_paginated(
         &self,
         limit: i64,
@@ -161,7 +205,7 @@ impl WorkflowRepositoryTrait for WorkflowRepository {
         &self,
         run_uuid: Uuid,
         limit: i64,
-    ) -> Result<Vec<(Uuid, serde_json::Value)>> {
+    ) -> Result<Vec<(Uuid, i64, serde_json::Value)>> {
         self.fetch_staged_raw_items(run_uuid, limit).await
     }
     async fn set_raw_item_status(
@@ -172,9 +216,55 @@ impl WorkflowRepositoryTrait for WorkflowRepository {
     ) -> Result<()> {
         self.set_raw_item_status(item_uuid, status, error).await
     }
+    async fn insert_run_error(
+        &self,
+        run_uuid: Uuid,
+        record_index: i64,
+        error_message: &str,
+        raw_payload: &serde_json::Value,
+    ) -> Result<()> {
+        self.insert_run_error(run_uuid, record_index, error_message, raw_payload)
+            .await
+    }
+    async fn list_run_errors_paginated(
+        &self,
+        run_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<WorkflowRunError>, i64)> {
+        self.list_run_errors_paginated(run_uuid, limit, offset)
+            .await
+    }
     async fn get_workflow_uuid_for_run(&self, run_uuid: Uuid) -> Result<Option<Uuid>> {
         self.get_workflow_uuid_for_run_internal(run_uuid).await
     }
+    async fn claim_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        ttl_secs: i64,
+    ) -> Result<IdempotencyClaim> {
+        self.claim_idempotency_key(workflow_uuid, idempotency_key, ttl_secs)
+            .await
+    }
+    async fn complete_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        run_uuid: Uuid,
+        response: &serde_json::Value,
+    ) -> Result<()> {
+        self.complete_idempotency_key(workflow_uuid, idempotency_key, run_uuid, response)
+            .await
+    }
+    async fn release_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+    ) -> Result<()> {
+        self.release_idempotency_key(workflow_uuid, idempotency_key)
+            .await
+    }
 }
 
 /// Get provider workflow configuration
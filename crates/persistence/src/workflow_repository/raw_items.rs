@@ -7,8 +7,15 @@ use super::WorkflowRepository;
 use r_data_core_core::error::Result;
 
 impl WorkflowRepository {
+    /// Most raw items written by one `INSERT` statement of `insert_raw_items`
+    pub const RAW_ITEMS_INSERT_CHUNK_SIZE: usize = 500;
+
     /// Insert raw items for a workflow
     ///
+    /// Items are numbered after the last staged item of the run and written with one multi-row
+    /// `INSERT` per [`Self::RAW_ITEMS_INSERT_CHUNK_SIZE`] items, each committed on its own, so
+    /// large ingests neither build one huge statement nor hold one long transaction.
+    ///
     /// # Errors
     /// Returns an error if the database operation fails
     pub async fn insert_raw_items(
@@ -27,20 +34,24 @@ impl WorkflowRepository {
         .unwrap_or(0);
 
         let mut count: i64 = 0;
-        for (idx, payload) in payloads.into_iter().enumerate() {
-            let seq_no = start_seq + i64::try_from(idx).unwrap_or(0) + 1;
+        for chunk in payloads.chunks(Self::RAW_ITEMS_INSERT_CHUNK_SIZE) {
+            let seq_nos: Vec<i64> = (1..)
+                .take(chunk.len())
+                .map(|offset| start_seq + count + offset)
+                .collect();
             sqlx::query(
                 "
                 INSERT INTO workflow_raw_items (workflow_run_uuid, seq_no, payload, status)
-                VALUES ($1, $2, $3, 'queued')
+                SELECT $1, item.seq_no, item.payload, 'queued'
+                FROM UNNEST($2::bigint[], $3::jsonb[]) AS item(seq_no, payload)
                 ",
             )
             .bind(run_uuid)
-            .bind(seq_no)
-            .bind(payload)
+            .bind(&seq_nos)
+            .bind(chunk)
             .execute(&self.pool)
             .await?;
-            count += 1;
+            count += i64::try_from(chunk.len()).unwrap_or(0);
         }
         Ok(count)
     }
@@ -72,7 +83,7 @@ impl WorkflowRepository {
         Ok(())
     }
 
-    /// Fetch staged raw items for a workflow run
+    /// Fetch staged raw items for a workflow run as `(uuid, seq_no, payload)`
     ///
     /// # Errors
     /// Returns an error if the database query fails
@@ -80,10 +91,10 @@ impl WorkflowRepository {
         &self,
         run_uuid: Uuid,
         limit: i64,
-    ) -> Result<Vec<(Uuid, serde_json::Value)>> {
+    ) -> Result<Vec<(Uuid, i64, serde_json::Value)>> {
         let rows = sqlx::query(
             "
-            SELECT uuid, payload
+            SELECT uuid, seq_no, payload
             FROM workflow_raw_items
             WHERE workflow_run_uuid = $1 AND status = 'queued'
             ORDER BY seq_no ASC
@@ -97,8 +108,9 @@ impl WorkflowRepository {
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
             let uuid: Uuid = r.try_get("uuid")?;
+            let seq_no: i64 = r.try_get("seq_no")?;
             let payload: serde_json::Value = r.try_get("payload")?;
-            out.push((uuid, payload));
+            out.push((uuid, seq_no, payload));
         }
         Ok(out)
     }
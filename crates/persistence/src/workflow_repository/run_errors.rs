@@ -0,0 +1,81 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use sqlx::Row;
+use uuid::Uuid;
+
+use super::WorkflowRepository;
+use r_data_core_core::error::Result;
+use r_data_core_workflow::data::WorkflowRunError;
+
+impl WorkflowRepository {
+    /// Record the error of a single failed record of a run
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn insert_run_error(
+        &self,
+        run_uuid: Uuid,
+        record_index: i64,
+        error_message: &str,
+        raw_payload: &serde_json::Value,
+    ) -> Result<()> {
+        sqlx::query(
+            "
+            INSERT INTO workflow_run_errors (run_uuid, record_index, error_message, raw_payload)
+            VALUES ($1, $2, $3, $4)
+            ",
+        )
+        .bind(run_uuid)
+        .bind(record_index)
+        .bind(error_message)
+        .bind(raw_payload)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// List the record errors of a run ordered by record index, with the total count
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    pub async fn list_run_errors_paginated(
+        &self,
+        run_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<WorkflowRunError>, i64)> {
+        let rows = sqlx::query(
+            "
+            SELECT uuid, run_uuid, record_index, error_message, raw_payload, created_at
+            FROM workflow_run_errors
+            WHERE run_uuid = $1
+            ORDER BY record_index ASC, created_at ASC
+            LIMIT $2 OFFSET $3
+            ",
+        )
+        .bind(run_uuid)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM workflow_run_errors WHERE run_uuid = $1")
+                .bind(run_uuid)
+                .fetch_one(&self.pool)
+                .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            out.push(WorkflowRunError {
+                uuid: r.try_get("uuid")?,
+                run_uuid: r.try_get("run_uuid")?,
+                record_index: r.try_get("record_index")?,
+                error_message: r.try_get("error_message")?,
+                raw_payload: r.try_get("raw_payload")?,
+                created_at: r.try_get("created_at")?,
+            });
+        }
+        Ok((out, total))
+    }
+}
@@ -1,11 +1,73 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
-use sqlx::Row;
+use std::str::FromStr;
+
+use sqlx::{PgPool, Row};
 use uuid::Uuid;
 
 use super::WorkflowRepository;
 use crate::outbox_repository::OutboxRepository;
-use r_data_core_core::error::Result;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_workflow::data::{
+    RunStatus, WorkflowRunMetrics, WorkflowRunProgress, WorkflowRunStats,
+};
+
+/// Aggregate all-time run statistics of one workflow, or of all workflows if `workflow_uuid` is `None`
+///
+/// # Errors
+/// Returns an error if the database query fails
+pub async fn query_run_statistics(
+    pool: &PgPool,
+    workflow_uuid: Option<Uuid>,
+) -> Result<WorkflowRunStats> {
+    let row = sqlx::query(
+        r"
+        WITH runs AS (
+            SELECT status, queued_at,
+                   EXTRACT(EPOCH FROM (finished_at - started_at)) * 1000 AS duration_ms,
+                   queued_at >= NOW() - INTERVAL '24 hours' AS recent
+            FROM workflow_runs
+            WHERE $1::uuid IS NULL OR workflow_uuid = $1
+        ),
+        last_run AS (
+            SELECT status::text AS status, queued_at FROM runs ORDER BY queued_at DESC LIMIT 1
+        )
+        SELECT COUNT(*) AS total_runs,
+               COUNT(*) FILTER (WHERE status = 'queued') AS queued_runs,
+               COUNT(*) FILTER (WHERE status = 'running') AS running_runs,
+               COUNT(*) FILTER (WHERE status = 'success') AS successful_runs,
+               COUNT(*) FILTER (WHERE status = 'failed') AS failed_runs,
+               COUNT(*) FILTER (WHERE status = 'cancelled') AS cancelled_runs,
+               COUNT(*) FILTER (WHERE recent) AS runs_last_24h,
+               COUNT(*) FILTER (WHERE recent AND status = 'success') AS successful_runs_last_24h,
+               COUNT(*) FILTER (WHERE recent AND status = 'failed') AS failed_runs_last_24h,
+               AVG(duration_ms)::float8 AS avg_duration_ms,
+               (PERCENTILE_CONT(0.95) WITHIN GROUP (ORDER BY duration_ms))::float8 AS p95_duration_ms,
+               (SELECT status FROM last_run) AS last_run_status,
+               (SELECT queued_at FROM last_run) AS last_run_at
+        FROM runs
+        ",
+    )
+    .bind(workflow_uuid)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(WorkflowRunStats {
+        total_runs: row.try_get("total_runs")?,
+        queued_runs: row.try_get("queued_runs")?,
+        running_runs: row.try_get("running_runs")?,
+        successful_runs: row.try_get("successful_runs")?,
+        failed_runs: row.try_get("failed_runs")?,
+        cancelled_runs: row.try_get("cancelled_runs")?,
+        runs_last_24h: row.try_get("runs_last_24h")?,
+        successful_runs_last_24h: row.try_get("successful_runs_last_24h")?,
+        failed_runs_last_24h: row.try_get("failed_runs_last_24h")?,
+        avg_duration_ms: row.try_get("avg_duration_ms")?,
+        p95_duration_ms: row.try_get("p95_duration_ms")?,
+        last_run_status: row.try_get("last_run_status")?,
+        last_run_at: row.try_get("last_run_at")?,
+    })
+}
 
 impl WorkflowRepository {
     /// Get workflow UUID for a run UUID
@@ -91,6 +153,39 @@ impl WorkflowRepository {
         Ok(())
     }
 
+    /// Number of workflow runs per status
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    pub async fn count_runs_by_status(&self) -> Result<Vec<(String, i64)>> {
+        let rows = sqlx::query(
+            "SELECT status::text AS status, COUNT(*) AS count FROM workflow_runs GROUP BY status ORDER BY status",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            out.push((row.try_get("status")?, row.try_get("count")?));
+        }
+        Ok(out)
+    }
+
+    /// Put a running workflow run back into the queued state, e.g. when a worker shuts down mid-run
+    ///
+    /// Returns `false` if the run is not running (anymore).
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn requeue_running_run(&self, run_uuid: Uuid) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE workflow_runs SET status = 'queued', started_at = NULL WHERE uuid = $1 AND status = 'running'",
+        )
+        .bind(run_uuid)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     /// Mark a workflow run as successful
     ///
     /// # Errors
@@ -137,6 +232,91 @@ impl WorkflowRepository {
         Ok(row.and_then(|r| r.try_get::<String, _>("status").ok()))
     }
 
+    /// Store the item counts of a run that is being processed
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn update_run_progress(
+        &self,
+        run_uuid: Uuid,
+        processed: i64,
+        failed: i64,
+        total: i64,
+    ) -> Result<()> {
+        sqlx::query(
+            "UPDATE workflow_runs SET processed_items = $2, failed_items = $3, total_items = $4 WHERE uuid = $1",
+        )
+        .bind(run_uuid)
+        .bind(processed)
+        .bind(failed)
+        .bind(total)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Get status and item counts of a run
+    ///
+    /// # Errors
+    /// Returns an error if the query fails or the stored status is unknown
+    pub async fn get_run_progress(&self, run_uuid: Uuid) -> Result<Option<WorkflowRunProgress>> {
+        let row = sqlx::query(
+            "SELECT status::text AS status, processed_items::bigint AS processed_items,
+                    failed_items::bigint AS failed_items, total_items::bigint AS total_items
+             FROM workflow_runs WHERE uuid = $1",
+        )
+        .bind(run_uuid)
+        .fetch_optional(&self.pool)
+        .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let status: String = row.try_get("status")?;
+        Ok(Some(WorkflowRunProgress {
+            status: RunStatus::from_str(&status)
+                .map_err(|e| Error::Unknown(format!("Run {run_uuid}: {e} '{status}'")))?,
+            processed_count: row.try_get("processed_items")?,
+            failed_count: row.try_get("failed_items")?,
+            total_count: row.try_get("total_items")?,
+        }))
+    }
+
+    /// Store the per-run config override
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn set_run_config_override(
+        &self,
+        run_uuid: Uuid,
+        config_override: &serde_json::Value,
+    ) -> Result<()> {
+        sqlx::query("UPDATE workflow_runs SET config_override = $2 WHERE uuid = $1")
+            .bind(run_uuid)
+            .bind(config_override)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Get the per-run config override, if the run was started with one
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    pub async fn get_run_config_override(
+        &self,
+        run_uuid: Uuid,
+    ) -> Result<Option<serde_json::Value>> {
+        let row = sqlx::query("SELECT config_override FROM workflow_runs WHERE uuid = $1")
+            .bind(run_uuid)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.and_then(|r| {
+            r.try_get::<Option<serde_json::Value>, _>("config_override")
+                .ok()
+                .flatten()
+        }))
+    }
+
     /// Insert a log entry for a workflow run
     ///
     /// # Errors
@@ -152,7 +332,7 @@ impl WorkflowRepository {
             .bind(run_uuid)
             .bind(level)
             .bind(message)
-            .bind(meta)
+            .bind(r_data_core_core::request_id::annotate_meta(meta))
             .execute(&self.pool)
             .await
             ?;
@@ -290,6 +470,60 @@ impl WorkflowRepository {
         Ok(row.is_some())
     }
 
+    /// Aggregate run metrics for a workflow over runs queued within the last `window_secs` seconds
+    ///
+    /// Durations only consider runs with both a start and a finish time.
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    pub async fn run_metrics(
+        &self,
+        workflow_uuid: Uuid,
+        window_secs: i64,
+    ) -> Result<WorkflowRunMetrics> {
+        let row = sqlx::query(
+            r"
+            WITH runs AS (
+                SELECT status, processed_items, failed_items,
+                       EXTRACT(EPOCH FROM (finished_at - started_at)) * 1000 AS duration_ms
+                FROM workflow_runs
+                WHERE workflow_uuid = $1
+                  AND queued_at >= NOW() - $2 * INTERVAL '1 second'
+            )
+            SELECT COUNT(*) AS total_runs,
+                   COUNT(*) FILTER (WHERE status = 'success') AS successful_runs,
+                   COUNT(*) FILTER (WHERE status = 'failed') AS failed_runs,
+                   COALESCE(SUM(processed_items), 0)::bigint AS processed_items,
+                   COALESCE(SUM(failed_items), 0)::bigint AS failed_items,
+                   AVG(duration_ms)::float8 AS avg_duration_ms,
+                   (PERCENTILE_CONT(0.95) WITHIN GROUP (ORDER BY duration_ms))::float8 AS p95_duration_ms
+            FROM runs
+            ",
+        )
+        .bind(workflow_uuid)
+        .bind(window_secs)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(WorkflowRunMetrics {
+            total_runs: row.try_get("total_runs")?,
+            successful_runs: row.try_get("successful_runs")?,
+            failed_runs: row.try_get("failed_runs")?,
+            processed_items: row.try_get("processed_items")?,
+            failed_items: row.try_get("failed_items")?,
+            avg_duration_ms: row.try_get("avg_duration_ms")?,
+            p95_duration_ms: row.try_get("p95_duration_ms")?,
+        })
+    }
+
+    /// Aggregate all-time run statistics for a workflow
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    pub async fn run_statistics(&self, workflow_uuid: Uuid) -> Result<WorkflowRunStats> {
+        query_run_statistics(&self.pool, Some(workflow_uuid)).await
+    }
+
     /// List all runs with pagination (across all workflows)
     ///
     /// # Errors
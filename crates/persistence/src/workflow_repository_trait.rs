@@ -5,7 +5,8 @@ use uuid::Uuid;
 
 use r_data_core_workflow::data::{
     requests::{CreateWorkflowRequest, UpdateWorkflowRequest},
-    Workflow,
+    IdempotencyClaim, Workflow, WorkflowRunError, WorkflowRunMetrics, WorkflowRunProgress,
+    WorkflowRunStats,
 };
 
 /// Trait for workflow repository operations
@@ -89,6 +90,15 @@ pub trait WorkflowRepositoryTrait: Send + Sync {
     /// Returns an error if deletion fails
     async fn delete(&self, uuid: Uuid) -> r_data_core_core::error::Result<()>;
 
+    /// Pause or resume scheduled runs of a workflow
+    ///
+    /// # Returns
+    /// `false` if no workflow with `uuid` exists
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    async fn set_paused(&self, uuid: Uuid, paused: bool) -> r_data_core_core::error::Result<bool>;
+
     /// List scheduled consumer workflows
     ///
     /// # Errors
@@ -121,6 +131,34 @@ pub trait WorkflowRepositoryTrait: Send + Sync {
         run_uuid: Uuid,
     ) -> r_data_core_core::error::Result<Option<String>>;
 
+    /// Store how many staged items of a run were processed or failed so far, out of `total`
+    async fn update_run_progress(
+        &self,
+        run_uuid: Uuid,
+        processed: i64,
+        failed: i64,
+        total: i64,
+    ) -> r_data_core_core::error::Result<()>;
+
+    /// Get status and item counts of a run
+    async fn get_run_progress(
+        &self,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<Option<WorkflowRunProgress>>;
+
+    /// Store a partial config merged over the workflow config for this run only
+    async fn set_run_config_override(
+        &self,
+        run_uuid: Uuid,
+        config_override: &serde_json::Value,
+    ) -> r_data_core_core::error::Result<()>;
+
+    /// Get the per-run config override
+    async fn get_run_config_override(
+        &self,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<Option<serde_json::Value>>;
+
     /// Insert a new workflow run in queued status
     ///
     /// # Arguments
@@ -203,6 +241,32 @@ pub trait WorkflowRepositoryTrait: Send + Sync {
     /// Returns an error if database query fails
     async fn run_exists(&self, run_uuid: Uuid) -> r_data_core_core::error::Result<bool>;
 
+    /// Aggregate run metrics for a workflow over a time window
+    ///
+    /// # Arguments
+    /// * `workflow_uuid` - Workflow UUID
+    /// * `window_secs` - Window length in seconds, counted back from now
+    ///
+    /// # Errors
+    /// Returns an error if database query fails
+    async fn run_metrics(
+        &self,
+        workflow_uuid: Uuid,
+        window_secs: i64,
+    ) -> r_data_core_core::error::Result<WorkflowRunMetrics>;
+
+    /// Aggregate all-time run statistics for a workflow
+    ///
+    /// # Arguments
+    /// * `workflow_uuid` - Workflow UUID
+    ///
+    /// # Errors
+    /// Returns an error if database query fails
+    async fn run_statistics(
+        &self,
+        workflow_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<WorkflowRunStats>;
+
     /// List all runs with pagination
     ///
     /// # Arguments
@@ -281,7 +345,7 @@ pub trait WorkflowRepositoryTrait: Send + Sync {
     async fn mark_raw_items_processed(&self, run_uuid: Uuid)
         -> r_data_core_core::error::Result<()>;
 
-    /// Fetch staged raw items for processing
+    /// Fetch staged raw items for processing as `(uuid, seq_no, payload)`
     ///
     /// # Arguments
     /// * `run_uuid` - Run UUID
@@ -293,7 +357,7 @@ pub trait WorkflowRepositoryTrait: Send + Sync {
         &self,
         run_uuid: Uuid,
         limit: i64,
-    ) -> r_data_core_core::error::Result<Vec<(Uuid, serde_json::Value)>>;
+    ) -> r_data_core_core::error::Result<Vec<(Uuid, i64, serde_json::Value)>>;
 
     /// Set raw item status
     ///
@@ -311,6 +375,43 @@ pub trait WorkflowRepositoryTrait: Send + Sync {
         error: Option<&str>,
     ) -> r_data_core_core::error::Result<()>;
 
+    /// Record the error of a single failed record of a run
+    ///
+    /// # Arguments
+    /// * `run_uuid` - Run UUID
+    /// * `record_index` - Zero-based position of the record in the staged input
+    /// * `error_message` - Why the record failed
+    /// * `raw_payload` - The staged record
+    ///
+    /// # Errors
+    /// Returns an error if insert fails
+    async fn insert_run_error(
+        &self,
+        run_uuid: Uuid,
+        record_index: i64,
+        error_message: &str,
+        raw_payload: &serde_json::Value,
+    ) -> r_data_core_core::error::Result<()>;
+
+    /// List record errors of a run ordered by record index
+    ///
+    /// # Arguments
+    /// * `run_uuid` - Run UUID
+    /// * `limit` - Maximum number of errors to return
+    /// * `offset` - Number of errors to skip
+    ///
+    /// # Returns
+    /// Tuple of (errors, total count)
+    ///
+    /// # Errors
+    /// Returns an error if database query fails
+    async fn list_run_errors_paginated(
+        &self,
+        run_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> r_data_core_core::error::Result<(Vec<WorkflowRunError>, i64)>;
+
     /// Get workflow UUID for a run
     ///
     /// # Arguments
@@ -322,4 +423,52 @@ pub trait WorkflowRepositoryTrait: Send + Sync {
         &self,
         run_uuid: Uuid,
     ) -> r_data_core_core::error::Result<Option<Uuid>>;
+
+    /// Claim an idempotency key of a workflow, or look up its earlier use
+    ///
+    /// # Arguments
+    /// * `workflow_uuid` - Workflow UUID the key is scoped to
+    /// * `idempotency_key` - Client supplied key
+    /// * `ttl_secs` - How long a claimed key is remembered
+    ///
+    /// # Errors
+    /// Returns an error if database operation fails
+    async fn claim_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        ttl_secs: i64,
+    ) -> r_data_core_core::error::Result<IdempotencyClaim>;
+
+    /// Store the run and response of a claimed idempotency key
+    ///
+    /// # Arguments
+    /// * `workflow_uuid` - Workflow UUID the key is scoped to
+    /// * `idempotency_key` - Client supplied key
+    /// * `run_uuid` - Run created by the original request
+    /// * `response` - Response body returned to the original request
+    ///
+    /// # Errors
+    /// Returns an error if database update fails
+    async fn complete_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        run_uuid: Uuid,
+        response: &serde_json::Value,
+    ) -> r_data_core_core::error::Result<()>;
+
+    /// Release a claimed idempotency key that was never completed
+    ///
+    /// # Arguments
+    /// * `workflow_uuid` - Workflow UUID the key is scoped to
+    /// * `idempotency_key` - Client supplied key
+    ///
+    /// # Errors
+    /// Returns an error if database delete fails
+    async fn release_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+    ) -> r_data_core_core::error::Result<()>;
 }
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use r_data_core_core::admin_user::ApiKey;
 use r_data_core_core::error::Result;
 use r_data_core_persistence::{ApiKeyRepository, ApiKeyRepositoryTrait};
+use time::OffsetDateTime;
 use uuid::Uuid;
 
 /// Repository adapter for `ApiKeyRepository`
@@ -61,9 +62,18 @@ impl ApiKeyRepositoryTrait for ApiKeyRepositoryAdapter {
         description: &str,
         created_by: Uuid,
         expires_in_days: i32,
+        scopes: &[String],
+        allowed_cidrs: &[String],
     ) -> Result<(Uuid, String)> {
         self.inner
-            .create_new_api_key(name, description, created_by, expires_in_days)
+            .create_new_api_key(
+                name,
+                description,
+                created_by,
+                expires_in_days,
+                scopes,
+                allowed_cidrs,
+            )
             .await
     }
 
@@ -75,6 +85,24 @@ impl ApiKeyRepositoryTrait for ApiKeyRepositoryAdapter {
         self.inner.reassign(uuid, new_user_uuid).await
     }
 
+    async fn update_rate_limit(
+        &self,
+        uuid: Uuid,
+        rate_limit_per_minute: Option<i32>,
+    ) -> Result<()> {
+        self.inner
+            .update_rate_limit(uuid, rate_limit_per_minute)
+            .await
+    }
+
+    async fn update_expires_at(
+        &self,
+        uuid: Uuid,
+        expires_at: Option<OffsetDateTime>,
+    ) -> Result<()> {
+        self.inner.update_expires_at(uuid, expires_at).await
+    }
+
     async fn count_by_user(&self, user_uuid: Uuid) -> Result<i64> {
         self.inner.count_by_user(user_uuid).await
     }
@@ -77,6 +77,40 @@ impl EntityDefinitionRepositoryTrait for EntityDefinitionRepositoryAdapter {
         self.inner.update(uuid, definition).await
     }
 
+    async fn rename_field(
+        &self,
+        uuid: &Uuid,
+        definition: &EntityDefinition,
+        old_name: &str,
+        new_name: &str,
+        rename_in_versions: bool,
+    ) -> Result<()> {
+        log::debug!(
+            "EntityDefinitionRepositoryAdapter::rename_field called with uuid: {uuid}, {old_name} -> {new_name}"
+        );
+        self.inner
+            .rename_field(uuid, definition, old_name, new_name, rename_in_versions)
+            .await
+    }
+
+    async fn find_table_owner(
+        &self,
+        table_name: &str,
+        exclude: Option<Uuid>,
+    ) -> Result<Option<String>> {
+        log::debug!(
+            "EntityDefinitionRepositoryAdapter::find_table_owner called with table_name: {table_name}"
+        );
+        self.inner.find_table_owner(table_name, exclude).await
+    }
+
+    async fn is_foreign_table(&self, table_name: &str) -> Result<bool> {
+        log::debug!(
+            "EntityDefinitionRepositoryAdapter::is_foreign_table called with table_name: {table_name}"
+        );
+        self.inner.is_foreign_table(table_name).await
+    }
+
     async fn delete(&self, uuid: &Uuid) -> Result<()> {
         log::debug!("EntityDefinitionRepositoryAdapter::delete called with uuid: {uuid}");
         self.inner.delete(uuid).await
@@ -173,6 +207,18 @@ impl DynamicEntityRepositoryTrait for DynamicEntityRepositoryAdapter {
             .await
     }
 
+    /// Get several dynamic entities by type and UUIDs in one query
+    async fn get_many_by_type(
+        &self,
+        entity_type: &str,
+        uuids: &[Uuid],
+        exclusive_fields: Option<Vec<String>>,
+    ) -> Result<HashMap<Uuid, Option<DynamicEntity>>> {
+        self.inner
+            .get_many_by_type(entity_type, uuids, exclusive_fields)
+            .await
+    }
+
     /// Create a new entity
     /// Returns the UUID
     async fn create(&self, entity: &DynamicEntity) -> Result<Uuid> {
@@ -189,6 +235,21 @@ impl DynamicEntityRepositoryTrait for DynamicEntityRepositoryAdapter {
         self.inner.delete_by_type(entity_type, uuid).await
     }
 
+    /// Restore a soft-deleted entity by type and UUID
+    async fn restore_by_type(&self, entity_type: &str, uuid: &Uuid) -> Result<()> {
+        self.inner.restore_by_type(entity_type, uuid).await
+    }
+
+    /// Permanently delete an entity by type and UUID
+    async fn hard_delete(&self, entity_type: &str, uuid: &Uuid) -> Result<()> {
+        self.inner.hard_delete(entity_type, uuid).await
+    }
+
+    /// Whether an entity exists but is soft-deleted
+    async fn is_deleted(&self, entity_type: &str, uuid: &Uuid) -> Result<bool> {
+        self.inner.is_deleted(entity_type, uuid).await
+    }
+
     /// Filter entities by field values with advanced options
     async fn filter_entities(
         &self,
@@ -203,6 +264,21 @@ impl DynamicEntityRepositoryTrait for DynamicEntityRepositoryAdapter {
         self.inner.count_entities(entity_type).await
     }
 
+    /// Count soft-deleted entities of a specific type
+    async fn count_deleted_entities(&self, entity_type: &str) -> Result<i64> {
+        self.inner.count_deleted_entities(entity_type).await
+    }
+
+    /// Count live entities of a specific type created by `owner`
+    async fn count_entities_by_owner(&self, entity_type: &str, owner: &Uuid) -> Result<i64> {
+        self.inner.count_entities_by_owner(entity_type, owner).await
+    }
+
+    /// Estimate the number of entities of a specific type from the table statistics
+    async fn estimate_entities(&self, entity_type: &str) -> Result<Option<i64>> {
+        self.inner.estimate_entities(entity_type).await
+    }
+
     /// Count children for an entity
     async fn count_children(&self, parent_uuid: &Uuid) -> Result<i64> {
         self.inner.count_children(parent_uuid).await
@@ -312,4 +388,16 @@ impl r_data_core_persistence::AdminUserRepositoryTrait for AdminUserRepositoryAd
             .list_admin_users(limit, offset, sort_by, sort_order)
             .await
     }
+
+    async fn update_totp(&self, uuid: &Uuid, secret: Option<String>, enabled: bool) -> Result<()> {
+        log::debug!(
+            "AdminUserRepositoryAdapter::update_totp called with uuid: {uuid}, enabled: {enabled}"
+        );
+        self.inner.update_totp(uuid, secret, enabled).await
+    }
+
+    async fn record_totp_step(&self, uuid: &Uuid, step: i64) -> Result<bool> {
+        log::debug!("AdminUserRepositoryAdapter::record_totp_step called with uuid: {uuid}");
+        self.inner.record_totp_step(uuid, step).await
+    }
 }
@@ -1,17 +1,32 @@
-use r_data_core_core::admin_user::AdminUser;
+use r_data_core_core::admin_user::{totp, AdminUser, PasswordPolicy};
 use r_data_core_core::error::Result;
 use r_data_core_core::system_log::SystemLogResourceType;
 use r_data_core_persistence::AdminUserRepositoryTrait;
 use std::sync::Arc;
+use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::query_validation::{validate_list_query, FieldValidator, ValidatedListQuery};
-use crate::SystemLogService;
+use crate::{AuditLogService, SystemLogService};
+
+/// Issuer shown for admin accounts in authenticator apps
+const TOTP_ISSUER: &str = "RDataCore";
+
+/// Secret of a started TOTP enrollment, to be added to an authenticator app
+#[derive(Debug, Clone)]
+pub struct TotpEnrollment {
+    /// Base32 secret for manual entry
+    pub secret: String,
+    /// `otpauth://` URI, usually rendered as a QR code
+    pub provisioning_uri: String,
+}
 
 /// Service for admin user operations
 pub struct AdminUserService {
     repository: Arc<dyn AdminUserRepositoryTrait>,
     system_log: Option<Arc<SystemLogService>>,
+    audit_log: Option<Arc<AuditLogService>>,
+    password_policy: Arc<PasswordPolicy>,
 }
 
 impl AdminUserService {
@@ -21,6 +36,8 @@ impl AdminUserService {
         Self {
             repository,
             system_log: None,
+            audit_log: None,
+            password_policy: Arc::new(PasswordPolicy::default()),
         }
     }
 
@@ -30,6 +47,8 @@ impl AdminUserService {
         Self {
             repository: Arc::new(repository),
             system_log: None,
+            audit_log: None,
+            password_policy: Arc::new(PasswordPolicy::default()),
         }
     }
 
@@ -40,6 +59,41 @@ impl AdminUserService {
         self
     }
 
+    /// Set the audit log service recording before/after snapshots of mutations
+    #[must_use]
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Set the password policy enforced when passwords are set
+    #[must_use]
+    pub fn with_password_policy(mut self, policy: Arc<PasswordPolicy>) -> Self {
+        self.password_policy = policy;
+        self
+    }
+
+    /// Check a new password against the password policy
+    ///
+    /// # Errors
+    /// Returns a validation error naming the first rule the password does not meet
+    pub fn validate_password(&self, password: &str) -> Result<()> {
+        self.password_policy.check(password)
+    }
+
+    /// Validate a new password against the policy and store its hash on `user`
+    ///
+    /// The user still has to be saved with [`Self::update_user`].
+    ///
+    /// # Errors
+    /// Returns a validation error if the password does not meet the policy, or an error if
+    /// hashing fails
+    pub fn set_password(&self, user: &mut AdminUser, password: &str) -> Result<()> {
+        self.validate_password(password)?;
+        user.password_hash = r_data_core_core::crypto::hash_password_argon2(password)?;
+        Ok(())
+    }
+
     /// Authenticate a user with username/email and password
     ///
     /// # Errors
@@ -105,11 +159,7 @@ impl AdminUserService {
             ));
         }
 
-        if password.len() < 8 {
-            return Err(r_data_core_core::error::Error::Validation(
-                "Password must be at least 8 characters".to_string(),
-            ));
-        }
+        self.validate_password(password)?;
 
         // Check if username or email already exists
         let existing_user = self.repository.find_by_username_or_email(username).await?;
@@ -150,6 +200,23 @@ impl AdminUserService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            match self.repository.find_by_uuid(&user_uuid).await {
+                Ok(Some(created)) => {
+                    audit
+                        .record_created(
+                            Some(creator_uuid),
+                            SystemLogResourceType::AdminUser,
+                            user_uuid,
+                            &created,
+                        )
+                        .await;
+                }
+                Ok(None) => {}
+                Err(e) => log::error!("Failed to load admin user {user_uuid} for audit log: {e}"),
+            }
+        }
+
         Ok(user_uuid)
     }
 
@@ -167,13 +234,12 @@ impl AdminUserService {
     /// Returns an error if the user is not found or database operation fails
     pub async fn update_user(&self, user: &AdminUser, actor_uuid: Uuid) -> Result<()> {
         // Check if the user exists
-        let existing_user = self.repository.find_by_uuid(&user.uuid).await?;
-        if existing_user.is_none() {
+        let Some(existing) = self.repository.find_by_uuid(&user.uuid).await? else {
             return Err(r_data_core_core::error::Error::NotFound(format!(
                 "User with UUID {} not found",
                 user.uuid
             )));
-        }
+        };
 
         self.repository.update_admin_user(user).await?;
 
@@ -188,6 +254,18 @@ impl AdminUserService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_updated(
+                    Some(actor_uuid),
+                    SystemLogResourceType::AdminUser,
+                    user.uuid,
+                    Some(&existing),
+                    Some(user),
+                )
+                .await;
+        }
+
         Ok(())
     }
 
@@ -217,9 +295,130 @@ impl AdminUserService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_deleted(
+                    Some(actor_uuid),
+                    SystemLogResourceType::AdminUser,
+                    *uuid,
+                    Some(&existing),
+                )
+                .await;
+        }
+
         Ok(())
     }
 
+    /// Start TOTP enrollment for a user
+    ///
+    /// Stores a new secret that is not yet required at login; enrollment completes once
+    /// [`Self::confirm_totp_enrollment`] verifies a code generated from it.
+    ///
+    /// # Errors
+    /// Returns an error if the user is not found, already has two-factor authentication enabled,
+    /// or database operation fails
+    pub async fn begin_totp_enrollment(&self, user_uuid: &Uuid) -> Result<TotpEnrollment> {
+        let user = self
+            .repository
+            .find_by_uuid(user_uuid)
+            .await?
+            .ok_or_else(|| {
+                r_data_core_core::error::Error::NotFound(format!(
+                    "User with UUID {user_uuid} not found"
+                ))
+            })?;
+        if user.totp_enabled {
+            return Err(r_data_core_core::error::Error::Conflict(
+                "Two-factor authentication is already enabled".to_string(),
+            ));
+        }
+
+        let secret = totp::generate_secret();
+        self.repository
+            .update_totp(user_uuid, Some(secret.clone()), false)
+            .await?;
+
+        Ok(TotpEnrollment {
+            provisioning_uri: totp::provisioning_uri(TOTP_ISSUER, &user.username, &secret),
+            secret,
+        })
+    }
+
+    /// Complete TOTP enrollment with a code from the authenticator app
+    ///
+    /// From then on the user needs a code to log in.
+    ///
+    /// # Errors
+    /// Returns an error if the user is not found, no enrollment is in progress, the code is
+    /// invalid, or database operation fails
+    pub async fn confirm_totp_enrollment(&self, user_uuid: &Uuid, code: &str) -> Result<()> {
+        let user = self
+            .repository
+            .find_by_uuid(user_uuid)
+            .await?
+            .ok_or_else(|| {
+                r_data_core_core::error::Error::NotFound(format!(
+                    "User with UUID {user_uuid} not found"
+                ))
+            })?;
+        if user.totp_enabled {
+            return Err(r_data_core_core::error::Error::Conflict(
+                "Two-factor authentication is already enabled".to_string(),
+            ));
+        }
+        let Some(secret) = user.totp_secret.as_deref() else {
+            return Err(r_data_core_core::error::Error::Validation(
+                "No two-factor enrollment in progress".to_string(),
+            ));
+        };
+
+        let Some(step) = totp::verify(secret, code, OffsetDateTime::now_utc(), None) else {
+            return Err(r_data_core_core::error::Error::Validation(
+                "Invalid two-factor code".to_string(),
+            ));
+        };
+        self.repository
+            .update_totp(user_uuid, Some(secret.to_string()), true)
+            .await?;
+        // The confirming code must not also work for the next login
+        self.repository.record_totp_step(user_uuid, step).await?;
+
+        if let Some(ref log) = self.system_log {
+            log.log_entity_updated(
+                Some(*user_uuid),
+                SystemLogResourceType::AdminUser,
+                *user_uuid,
+                &format!("Two-factor authentication enabled for '{}'", user.username),
+                Some(serde_json::json!({"username": user.username, "totp_enabled": true})),
+            )
+            .await;
+        }
+
+        Ok(())
+    }
+
+    /// Check a login TOTP code of a user with two-factor authentication enabled
+    ///
+    /// Returns `false` for wrong or expired codes and for codes that were already used.
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn verify_totp_code(&self, user: &AdminUser, code: &str) -> Result<bool> {
+        let (true, Some(secret)) = (user.totp_enabled, user.totp_secret.as_deref()) else {
+            return Ok(false);
+        };
+        let Some(step) = totp::verify(
+            secret,
+            code,
+            OffsetDateTime::now_utc(),
+            user.totp_last_used_step,
+        ) else {
+            return Ok(false);
+        };
+
+        self.repository.record_totp_step(&user.uuid, step).await
+    }
+
     /// List users with pagination
     ///
     /// # Errors
@@ -309,6 +508,8 @@ mod tests {
             ) -> Result<Uuid>;
             async fn update_admin_user(&self, user: &AdminUser) -> Result<()>;
             async fn delete_admin_user(&self, uuid: &Uuid) -> Result<()>;
+            async fn update_totp(&self, uuid: &Uuid, secret: Option<String>, enabled: bool) -> Result<()>;
+            async fn record_totp_step(&self, uuid: &Uuid, step: i64) -> Result<bool>;
             async fn list_admin_users(&self, limit: i64, offset: i64, sort_by: Option<String>, sort_order: Option<String>) -> Result<Vec<AdminUser>>;
         }
     }
@@ -336,6 +537,9 @@ mod tests {
             is_admin: true,
             created_at: now,
             updated_at: now,
+            totp_secret: None,
+            totp_enabled: false,
+            totp_last_used_step: None,
             base: AbstractRDataEntity::new("/admin/users".to_string()),
         };
 
@@ -434,6 +638,9 @@ mod tests {
             is_admin: true,
             created_at: now,
             updated_at: now,
+            totp_secret: None,
+            totp_enabled: false,
+            totp_last_used_step: None,
             base: AbstractRDataEntity::new("/admin/users".to_string()),
         };
 
@@ -466,4 +673,116 @@ mod tests {
             _ => panic!("Expected validation error"),
         }
     }
+
+    fn totp_user(secret: Option<&str>, enabled: bool) -> AdminUser {
+        let now = OffsetDateTime::now_utc();
+        AdminUser {
+            uuid: Uuid::now_v7(),
+            username: "totpuser".to_string(),
+            email: "totp@example.com".to_string(),
+            password_hash: String::new(),
+            full_name: "Totp User".to_string(),
+            status: UserStatus::Active,
+            last_login: None,
+            failed_login_attempts: 0,
+            super_admin: false,
+            first_name: None,
+            last_name: None,
+            is_active: true,
+            is_admin: false,
+            created_at: now,
+            updated_at: now,
+            totp_secret: secret.map(ToString::to_string),
+            totp_enabled: enabled,
+            totp_last_used_step: None,
+            base: AbstractRDataEntity::new("/admin/users".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_totp_code_rejects_reused_step() {
+        let secret = totp::generate_secret();
+        let user = totp_user(Some(&secret), true);
+        let code = totp::code_at(&secret, totp::step_at(OffsetDateTime::now_utc())).unwrap();
+
+        let mut mock_repo = MockAdminUserRepo::new();
+        // The repository reports that this step was already recorded by a concurrent login
+        mock_repo
+            .expect_record_totp_step()
+            .times(1)
+            .returning(|_, _| Ok(false));
+        let service = AdminUserService::new(Arc::new(mock_repo));
+
+        assert!(!service.verify_totp_code(&user, &code).await.unwrap());
+        assert!(!service.verify_totp_code(&user, "000000x").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_confirm_totp_enrollment_rejects_invalid_code() {
+        let secret = totp::generate_secret();
+        let user = totp_user(Some(&secret), false);
+        let user_uuid = user.uuid;
+        let code = totp::code_at(&secret, totp::step_at(OffsetDateTime::now_utc()) - 5).unwrap();
+
+        let mut mock_repo = MockAdminUserRepo::new();
+        mock_repo
+            .expect_find_by_uuid()
+            .returning(move |_| Ok(Some(user.clone())));
+        mock_repo.expect_update_totp().never();
+        let service = AdminUserService::new(Arc::new(mock_repo));
+
+        match service.confirm_totp_enrollment(&user_uuid, &code).await {
+            Err(r_data_core_core::error::Error::Validation(msg)) => {
+                assert_eq!(msg, "Invalid two-factor code");
+            }
+            other => panic!("Expected validation error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_user_enforces_password_policy() {
+        let mut mock_repo = MockAdminUserRepo::new();
+        mock_repo.expect_find_by_username_or_email().never();
+        mock_repo.expect_create_admin_user().never();
+        let policy = PasswordPolicy::with_breached_passwords(
+            r_data_core_core::config::PasswordPolicyConfig {
+                require_digit: true,
+                ..Default::default()
+            },
+            ["password123"],
+        );
+        let service =
+            AdminUserService::new(Arc::new(mock_repo)).with_password_policy(Arc::new(policy));
+
+        for (password, expected) in [
+            ("short1", "Password must be at least 8 characters"),
+            ("no-digits-here", "Password must contain a digit"),
+            (
+                "password123",
+                "Password appears in a list of breached passwords",
+            ),
+        ] {
+            let result = service
+                .register_user(
+                    "newuser",
+                    "new@example.com",
+                    password,
+                    "New",
+                    "User",
+                    None,
+                    true,
+                    Uuid::now_v7(),
+                )
+                .await;
+            match result {
+                Err(r_data_core_core::error::Error::Validation(msg)) => assert_eq!(msg, expected),
+                other => panic!("Expected validation error, got {other:?}"),
+            }
+        }
+
+        let mut user = totp_user(None, false);
+        assert!(service.set_password(&mut user, "no-digits-here").is_err());
+        assert!(service.set_password(&mut user, "long-enough-42").is_ok());
+        assert!(user.verify_password("long-enough-42"));
+    }
 }
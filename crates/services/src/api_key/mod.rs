@@ -1,13 +1,32 @@
-use r_data_core_core::admin_user::ApiKey;
+use r_data_core_core::admin_user::{api_key_cidrs, api_key_scopes, ApiKey};
 use r_data_core_core::cache::CacheManager;
 use r_data_core_core::error::Result;
 use r_data_core_core::system_log::SystemLogResourceType;
-use r_data_core_persistence::{ApiKeyRepository, ApiKeyRepositoryTrait};
+use r_data_core_persistence::{is_key_valid, ApiKeyRepository, ApiKeyRepositoryTrait};
+use sqlx::PgPool;
 use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
 use uuid::Uuid;
 
 use crate::query_validation::{validate_list_query, FieldValidator, ValidatedListQuery};
-use crate::SystemLogService;
+use crate::{AuditLogService, SystemLogService};
+
+/// Largest window accepted by [`ApiKeyService::list_expiring`]
+pub const MAX_EXPIRING_WITHIN_DAYS: i64 = 365;
+
+/// Longest grace period accepted by [`ApiKeyService::rotate_key`] (30 days)
+pub const MAX_ROTATION_GRACE_HOURS: i64 = 720;
+
+/// Result of rotating an API key
+#[derive(Debug, Clone)]
+pub struct RotatedApiKey {
+    /// The newly issued key
+    pub new_key: ApiKey,
+    /// Plain value of the new key (only available once)
+    pub key_value: String,
+    /// The old key, now expiring at the end of the grace period
+    pub old_key: ApiKey,
+}
 
 /// Service for handling API key operations
 pub struct ApiKeyService {
@@ -15,6 +34,8 @@ pub struct ApiKeyService {
     cache_manager: Option<Arc<CacheManager>>,
     api_key_ttl: u64,
     system_log: Option<Arc<SystemLogService>>,
+    audit_log: Option<Arc<AuditLogService>>,
+    pool: Option<PgPool>,
 }
 
 impl ApiKeyService {
@@ -25,6 +46,8 @@ impl ApiKeyService {
             cache_manager: None,
             api_key_ttl: 600, // Default 10 minutes
             system_log: None,
+            audit_log: None,
+            pool: None,
         }
     }
 
@@ -39,6 +62,8 @@ impl ApiKeyService {
             cache_manager: Some(cache_manager),
             api_key_ttl,
             system_log: None,
+            audit_log: None,
+            pool: None,
         }
     }
 
@@ -50,6 +75,8 @@ impl ApiKeyService {
             cache_manager: None,
             api_key_ttl: 600, // Default 10 minutes
             system_log: None,
+            audit_log: None,
+            pool: None,
         }
     }
 
@@ -60,6 +87,29 @@ impl ApiKeyService {
         self
     }
 
+    /// Set the audit log service recording before/after snapshots of mutations
+    #[must_use]
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Set the database pool used to rotate keys in a single transaction
+    #[must_use]
+    pub fn with_pool(mut self, pool: PgPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Audit log snapshot of a key, without its hash
+    fn audit_snapshot(key: &ApiKey) -> serde_json::Value {
+        let mut snapshot = serde_json::to_value(key).unwrap_or_default();
+        if let Some(fields) = snapshot.as_object_mut() {
+            fields.remove("key_hash");
+        }
+        snapshot
+    }
+
     /// Generate cache key for API key by hash
     fn cache_key_by_hash(key_hash: &str) -> String {
         format!("api_key:hash:{key_hash}")
@@ -74,16 +124,20 @@ impl ApiKeyService {
         Ok(existing.is_some())
     }
 
-    /// Create a new API key
+    /// Create a new API key, optionally restricted to public API `scopes` and to source IP
+    /// ranges in `allowed_cidrs`
     ///
     /// # Errors
-    /// Returns an error if validation fails or database operation fails
+    /// Returns an error if validation fails (including unknown scopes and invalid CIDRs) or
+    /// database operation fails
     pub async fn create_api_key(
         &self,
         name: &str,
         description: &str,
         created_by: Uuid,
         expires_in_days: i32,
+        scopes: &[String],
+        allowed_cidrs: &[String],
     ) -> Result<(Uuid, String)> {
         // Validation
         if name.is_empty() {
@@ -98,9 +152,19 @@ impl ApiKeyService {
             ));
         }
 
+        let scopes = api_key_scopes::normalize(scopes)?;
+        let allowed_cidrs = api_key_cidrs::normalize(allowed_cidrs)?;
+
         let result = self
             .repository
-            .create_new_api_key(name, description, created_by, expires_in_days)
+            .create_new_api_key(
+                name,
+                description,
+                created_by,
+                expires_in_days,
+                &scopes,
+                &allowed_cidrs,
+            )
             .await?;
 
         if let Some(ref log) = self.system_log {
@@ -109,11 +173,32 @@ impl ApiKeyService {
                 SystemLogResourceType::ApiKey,
                 result.0,
                 &format!("API key '{name}' created"),
-                Some(serde_json::json!({"name": name})),
+                Some(serde_json::json!({
+                    "name": name,
+                    "scopes": scopes,
+                    "allowed_cidrs": allowed_cidrs,
+                })),
             )
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            match self.repository.get_by_uuid(result.0).await {
+                Ok(Some(created)) => {
+                    audit
+                        .record_created(
+                            Some(created_by),
+                            SystemLogResourceType::ApiKey,
+                            result.0,
+                            &Self::audit_snapshot(&created),
+                        )
+                        .await;
+                }
+                Ok(None) => {}
+                Err(e) => log::error!("Failed to load API key {} for audit log: {e}", result.0),
+            }
+        }
+
         Ok(result)
     }
 
@@ -140,8 +225,11 @@ impl ApiKeyService {
         if let Some(cache) = &self.cache_manager {
             let cache_key = Self::cache_key_by_hash(&key_hash);
             if let Ok(Some(cached)) = cache.get::<(ApiKey, Uuid)>(&cache_key).await {
-                // Cache hit - return cached result (skip last_used_at update for performance)
-                return Ok(Some(cached));
+                // Cache hit - return cached result (skip last_used_at update for performance),
+                // unless the key expired while cached (e.g. after a rotation grace period)
+                if is_key_valid(&cached.0) {
+                    return Ok(Some(cached));
+                }
             }
         }
 
@@ -263,6 +351,22 @@ impl ApiKeyService {
                         )
                         .await;
                     }
+
+                    if let Some(ref audit) = self.audit_log {
+                        let revoked = ApiKey {
+                            is_active: false,
+                            ..key.clone()
+                        };
+                        audit
+                            .record_updated(
+                                Some(user_uuid),
+                                SystemLogResourceType::ApiKey,
+                                key_uuid,
+                                Some(&Self::audit_snapshot(&key)),
+                                Some(&Self::audit_snapshot(&revoked)),
+                            )
+                            .await;
+                    }
                 }
 
                 result
@@ -284,6 +388,255 @@ impl ApiKeyService {
         self.repository.get_by_uuid(key_uuid).await
     }
 
+    /// Set the public API requests-per-minute limit of an API key (`None` for unlimited)
+    ///
+    /// # Errors
+    /// Returns an error if the limit is not positive, the key is not found or not owned by `user_uuid`,
+    /// or database operation fails
+    pub async fn set_rate_limit(
+        &self,
+        key_uuid: Uuid,
+        rate_limit_per_minute: Option<i32>,
+        user_uuid: Uuid,
+    ) -> Result<()> {
+        if rate_limit_per_minute.is_some_and(|limit| limit <= 0) {
+            return Err(r_data_core_core::error::Error::Validation(
+                "Rate limit must be a positive number of requests per minute".to_string(),
+            ));
+        }
+
+        let key = match self.repository.get_by_uuid(key_uuid).await? {
+            Some(key) if key.user_uuid == user_uuid => key,
+            Some(_) => {
+                return Err(r_data_core_core::error::Error::Forbidden(
+                    "You don't have permission to update this API key".to_string(),
+                ))
+            }
+            None => {
+                return Err(r_data_core_core::error::Error::NotFound(
+                    "API key not found".to_string(),
+                ))
+            }
+        };
+
+        self.repository
+            .update_rate_limit(key_uuid, rate_limit_per_minute)
+            .await?;
+
+        // Cached validation results carry the old limit
+        if let Some(cache) = &self.cache_manager {
+            let cache_key = Self::cache_key_by_hash(&key.key_hash);
+            if let Err(e) = cache.delete(&cache_key).await {
+                log::warn!("Failed to invalidate API key cache: {e}");
+            }
+        }
+
+        if let Some(ref log) = self.system_log {
+            log.log_entity_updated(
+                Some(user_uuid),
+                SystemLogResourceType::ApiKey,
+                key_uuid,
+                &format!("API key '{}' rate limit updated", key.name),
+                Some(serde_json::json!({
+                    "name": key.name,
+                    "rate_limit_per_minute": rate_limit_per_minute,
+                })),
+            )
+            .await;
+        }
+
+        if let Some(ref audit) = self.audit_log {
+            let updated = ApiKey {
+                rate_limit_per_minute,
+                ..key.clone()
+            };
+            audit
+                .record_updated(
+                    Some(user_uuid),
+                    SystemLogResourceType::ApiKey,
+                    key_uuid,
+                    Some(&Self::audit_snapshot(&key)),
+                    Some(&Self::audit_snapshot(&updated)),
+                )
+                .await;
+        }
+
+        Ok(())
+    }
+
+    /// List the active keys of a user that expire within the next `within_days` days,
+    /// soonest first
+    ///
+    /// # Errors
+    /// Returns an error if `within_days` is not between 1 and 365 or the database query fails
+    pub async fn list_expiring(&self, user_uuid: Uuid, within_days: i64) -> Result<Vec<ApiKey>> {
+        if !(1..=MAX_EXPIRING_WITHIN_DAYS).contains(&within_days) {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "Expiry window must be between 1 and {MAX_EXPIRING_WITHIN_DAYS} days"
+            )));
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let window = Duration::days(within_days);
+        let keys = self
+            .repository
+            .list_by_user(
+                user_uuid,
+                -1,
+                0,
+                Some("expires_at".to_string()),
+                Some("ASC".to_string()),
+            )
+            .await?;
+
+        Ok(keys
+            .into_iter()
+            .filter(|key| key.expires_within(now, window))
+            .collect())
+    }
+
+    /// Rotate an API key: issue a new key with the old key's name, description, scopes,
+    /// limits and roles, and let the old key expire after `grace_period_hours`
+    ///
+    /// The new key gets the same lifetime as the old one. The old key keeps working until
+    /// the grace period ends (or its own expiry, if sooner) so clients can cut over. With a
+    /// pool set, the new key, its roles and the old key's expiry are written atomically.
+    ///
+    /// # Errors
+    /// Returns an error if the grace period is not between 0 and 720 hours, the key is not
+    /// found, not owned by `user_uuid`, revoked or expired, or database operation fails
+    pub async fn rotate_key(
+        &self,
+        old_uuid: Uuid,
+        user_uuid: Uuid,
+        grace_period_hours: i64,
+    ) -> Result<RotatedApiKey> {
+        if !(0..=MAX_ROTATION_GRACE_HOURS).contains(&grace_period_hours) {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "Grace period must be between 0 and {MAX_ROTATION_GRACE_HOURS} hours"
+            )));
+        }
+
+        let mut old_key = match self.repository.get_by_uuid(old_uuid).await? {
+            Some(key) if key.user_uuid == user_uuid => key,
+            Some(_) => {
+                return Err(r_data_core_core::error::Error::Forbidden(
+                    "You don't have permission to rotate this API key".to_string(),
+                ))
+            }
+            None => {
+                return Err(r_data_core_core::error::Error::NotFound(
+                    "API key not found".to_string(),
+                ))
+            }
+        };
+        if !is_key_valid(&old_key) {
+            return Err(r_data_core_core::error::Error::Validation(
+                "Only active, unexpired API keys can be rotated".to_string(),
+            ));
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let key_value = ApiKey::generate_key();
+        let mut new_key = ApiKey::new(
+            old_key.user_uuid,
+            old_key.name.clone(),
+            old_key.description.clone(),
+            old_key
+                .expires_at
+                .map(|expires_at| now + (expires_at - old_key.created_at)),
+            user_uuid,
+        );
+        new_key.key_hash = ApiKey::hash_api_key(&key_value)?;
+        new_key.published = old_key.published;
+        new_key.scopes.clone_from(&old_key.scopes);
+        new_key.rate_limit_per_minute = old_key.rate_limit_per_minute;
+        new_key.allowed_cidrs.clone_from(&old_key.allowed_cidrs);
+
+        let roles = self.repository.get_api_key_roles(old_uuid).await?;
+        let grace_end = now + Duration::hours(grace_period_hours);
+        let old_expires_at = old_key
+            .expires_at
+            .map_or(grace_end, |expires_at| expires_at.min(grace_end));
+        self.persist_rotation(&new_key, &roles, old_uuid, old_expires_at)
+            .await?;
+        old_key.expires_at = Some(old_expires_at);
+
+        // Cached validation results carry the old expiry
+        if let Some(cache) = &self.cache_manager {
+            let cache_key = Self::cache_key_by_hash(&old_key.key_hash);
+            if let Err(e) = cache.delete(&cache_key).await {
+                log::warn!("Failed to invalidate API key cache: {e}");
+            }
+        }
+
+        if let Some(ref log) = self.system_log {
+            log.log_entity_created(
+                Some(user_uuid),
+                SystemLogResourceType::ApiKey,
+                new_key.uuid,
+                &format!("API key '{}' rotated", new_key.name),
+                Some(serde_json::json!({
+                    "name": new_key.name,
+                    "rotated_from": old_uuid,
+                    "old_key_expires_at": old_expires_at.to_string(),
+                })),
+            )
+            .await;
+        }
+
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_created(
+                    Some(user_uuid),
+                    SystemLogResourceType::ApiKey,
+                    new_key.uuid,
+                    &Self::audit_snapshot(&new_key),
+                )
+                .await;
+        }
+
+        Ok(RotatedApiKey {
+            new_key,
+            key_value,
+            old_key,
+        })
+    }
+
+    /// Store the new key with the old key's roles and shorten the old key's expiry
+    ///
+    /// With a pool the three writes share one transaction; repositories without one
+    /// (in-memory, mocks) apply them in turn.
+    async fn persist_rotation(
+        &self,
+        new_key: &ApiKey,
+        roles: &[Uuid],
+        old_uuid: Uuid,
+        old_expires_at: OffsetDateTime,
+    ) -> Result<()> {
+        let Some(pool) = &self.pool else {
+            self.repository.create(new_key).await?;
+            if !roles.is_empty() {
+                self.repository
+                    .update_api_key_roles(new_key.uuid, roles)
+                    .await?;
+            }
+            return self
+                .repository
+                .update_expires_at(old_uuid, Some(old_expires_at))
+                .await;
+        };
+
+        let mut tx = pool.begin().await?;
+        ApiKeyRepository::create_tx(&mut tx, new_key).await?;
+        if !roles.is_empty() {
+            ApiKeyRepository::update_api_key_roles_tx(&mut tx, new_key.uuid, roles).await?;
+        }
+        ApiKeyRepository::update_expires_at_tx(&mut tx, old_uuid, Some(old_expires_at)).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
     /// Reassign an API key to a different user
     ///
     /// # Errors
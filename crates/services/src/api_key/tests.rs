@@ -24,9 +24,13 @@ mock! {
             description: &str,
             created_by: Uuid,
             expires_in_days: i32,
+            scopes: &[String],
+            allowed_cidrs: &[String],
         ) -> Result<(Uuid, String)>;
         async fn update_last_used(&self, uuid: Uuid) -> Result<()>;
         async fn reassign(&self, uuid: Uuid, new_user_uuid: Uuid) -> Result<()>;
+        async fn update_rate_limit(&self, uuid: Uuid, rate_limit_per_minute: Option<i32>) -> Result<()>;
+        async fn update_expires_at(&self, uuid: Uuid, expires_at: Option<time::OffsetDateTime>) -> Result<()>;
         async fn count_by_user(&self, user_uuid: Uuid) -> Result<i64>;
         async fn get_api_key_roles(&self, api_key_uuid: Uuid) -> Result<Vec<Uuid>>;
         async fn assign_role(&self, api_key_uuid: Uuid, role_uuid: Uuid) -> Result<()>;
@@ -50,12 +54,14 @@ async fn test_create_api_key_with_valid_input() {
             eq("Test Description"),
             eq(user_uuid),
             eq(30),
+            always(),
+            always(),
         )
-        .returning(move |_, _, _, _| Ok((key_uuid, (*key_value).to_string())));
+        .returning(move |_, _, _, _, _, _| Ok((key_uuid, (*key_value).to_string())));
 
     let service = ApiKeyService::new(Arc::new(mock_repo));
     let result = service
-        .create_api_key("Test Key", "Test Description", user_uuid, 30)
+        .create_api_key("Test Key", "Test Description", user_uuid, 30, &[], &[])
         .await;
 
     assert!(result.is_ok());
@@ -71,7 +77,7 @@ async fn test_create_api_key_with_empty_name() {
 
     let service = ApiKeyService::new(Arc::new(mock_repo));
     let result = service
-        .create_api_key("", "Test Description", user_uuid, 30)
+        .create_api_key("", "Test Description", user_uuid, 30, &[], &[])
         .await;
 
     assert!(result.is_err());
@@ -101,6 +107,9 @@ async fn test_validate_api_key_success() {
         last_used_at: None,
         created_by: user_uuid,
         published: true,
+        scopes: vec![],
+        rate_limit_per_minute: None,
+        allowed_cidrs: vec![],
     };
 
     mock_repo
@@ -154,6 +163,9 @@ async fn test_reassign_key() {
         last_used_at: None,
         created_by: original_user_uuid,
         published: true,
+        scopes: vec![],
+        rate_limit_per_minute: None,
+        allowed_cidrs: vec![],
     };
 
     // Create mock API key with new user_uuid after reassignment
@@ -169,6 +181,9 @@ async fn test_reassign_key() {
         last_used_at: None,
         created_by: original_user_uuid,
         published: true,
+        scopes: vec![],
+        rate_limit_per_minute: None,
+        allowed_cidrs: vec![],
     };
 
     // Setup mock to return original key first, then reassigned key
@@ -247,3 +262,149 @@ async fn test_reassign_nonexistent_key() {
         _ => panic!("Expected NotFound error, got: {result:?}"),
     }
 }
+
+/// Unit test for rejecting non-positive rate limits before touching the repository
+#[tokio::test]
+async fn test_set_rate_limit_rejects_non_positive_limit() {
+    let mut mock_repo = MockApiKeyRepo::new();
+    mock_repo.expect_get_by_uuid().never();
+    mock_repo.expect_update_rate_limit().never();
+
+    let service = ApiKeyService::new(Arc::new(mock_repo));
+
+    for limit in [0, -5] {
+        let result = service
+            .set_rate_limit(Uuid::now_v7(), Some(limit), Uuid::now_v7())
+            .await;
+        assert!(
+            matches!(result, Err(r_data_core_core::error::Error::Validation(_))),
+            "Expected Validation error for {limit}, got: {result:?}"
+        );
+    }
+}
+
+/// Unit test for listing only keys that expire within the window
+#[tokio::test]
+async fn test_list_expiring_filters_by_window() {
+    let user_uuid = Uuid::now_v7();
+    let now = OffsetDateTime::now_utc();
+    let key = |name: &str, expires_at: Option<OffsetDateTime>| {
+        ApiKey::new(user_uuid, name.to_string(), None, expires_at, user_uuid)
+    };
+    let keys = vec![
+        key("soon", Some(now + time::Duration::days(2))),
+        key("later", Some(now + time::Duration::days(60))),
+        key("expired", Some(now - time::Duration::days(1))),
+        key("never", None),
+    ];
+
+    let mut mock_repo = MockApiKeyRepo::new();
+    mock_repo
+        .expect_list_by_user()
+        .with(eq(user_uuid), eq(-1), eq(0), always(), always())
+        .returning(move |_, _, _, _, _| Ok(keys.clone()));
+
+    let service = ApiKeyService::new(Arc::new(mock_repo));
+    let expiring = service.list_expiring(user_uuid, 7).await.unwrap();
+    let names: Vec<&str> = expiring.iter().map(|k| k.name.as_str()).collect();
+    assert_eq!(names, vec!["soon"]);
+
+    for days in [0, 366] {
+        let result = service.list_expiring(user_uuid, days).await;
+        assert!(matches!(
+            result,
+            Err(r_data_core_core::error::Error::Validation(_))
+        ));
+    }
+}
+
+/// Unit test for rotation keeping the old key valid during the grace period
+#[tokio::test]
+async fn test_rotate_key_issues_new_key_and_shortens_old_expiry() {
+    let user_uuid = Uuid::now_v7();
+    let now = OffsetDateTime::now_utc();
+    let mut old_key = ApiKey::new(
+        user_uuid,
+        "integration".to_string(),
+        Some("CI".to_string()),
+        Some(now + time::Duration::days(10)),
+        user_uuid,
+    );
+    old_key.created_at = now - time::Duration::days(20);
+    old_key.scopes = vec!["entities:read".to_string()];
+    let old_uuid = old_key.uuid;
+
+    let mut mock_repo = MockApiKeyRepo::new();
+    mock_repo
+        .expect_get_by_uuid()
+        .with(eq(old_uuid))
+        .returning(move |_| Ok(Some(old_key.clone())));
+    mock_repo
+        .expect_create()
+        .withf(move |key: &ApiKey| {
+            key.uuid != old_uuid
+                && key.name == "integration"
+                && key.scopes == vec!["entities:read".to_string()]
+        })
+        .returning(|key| Ok(key.uuid));
+    mock_repo
+        .expect_get_api_key_roles()
+        .returning(|_| Ok(vec![]));
+    mock_repo
+        .expect_update_expires_at()
+        .withf(move |uuid, expires_at| *uuid == old_uuid && expires_at.is_some())
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let service = ApiKeyService::new(Arc::new(mock_repo));
+    let rotated = service.rotate_key(old_uuid, user_uuid, 24).await.unwrap();
+
+    assert_ne!(rotated.new_key.uuid, old_uuid);
+    assert_eq!(
+        ApiKey::hash_api_key(&rotated.key_value).unwrap(),
+        rotated.new_key.key_hash
+    );
+    // The new key gets the old key's 30-day lifetime
+    let lifetime = rotated.new_key.expires_at.unwrap() - rotated.new_key.created_at;
+    assert!((lifetime - time::Duration::days(30)).abs() < time::Duration::minutes(1));
+    // The old key stays valid for the grace period only
+    let old_expires_at = rotated.old_key.expires_at.unwrap();
+    assert!(old_expires_at > now + time::Duration::hours(23));
+    assert!(old_expires_at < now + time::Duration::hours(25));
+
+    for hours in [-1, 721] {
+        let result = service.rotate_key(old_uuid, user_uuid, hours).await;
+        assert!(matches!(
+            result,
+            Err(r_data_core_core::error::Error::Validation(_))
+        ));
+    }
+}
+
+#[tokio::test]
+async fn test_revoke_key_enforces_ownership_with_in_memory_repository() {
+    use r_data_core_core::error::Error;
+    use r_data_core_persistence::in_memory::InMemoryApiKeyRepository;
+
+    let repo = Arc::new(InMemoryApiKeyRepository::new());
+    let owner = Uuid::now_v7();
+    let other_user = Uuid::now_v7();
+    let (key_uuid, _) = repo
+        .create_new_api_key("key", "", owner, 30, &[], &[])
+        .await
+        .unwrap();
+    let service = ApiKeyService::new(repo.clone());
+
+    let result = service.revoke_key(key_uuid, other_user).await;
+    assert!(matches!(result, Err(Error::Forbidden(_))));
+    assert!(repo.get_by_uuid(key_uuid).await.unwrap().unwrap().is_active);
+
+    service.revoke_key(key_uuid, owner).await.unwrap();
+    assert!(!repo.get_by_uuid(key_uuid).await.unwrap().unwrap().is_active);
+
+    let result = service.revoke_key(key_uuid, owner).await;
+    assert!(matches!(result, Err(Error::Validation(_))));
+
+    let result = service.revoke_key(Uuid::now_v7(), owner).await;
+    assert!(matches!(result, Err(Error::NotFound(_))));
+}
@@ -0,0 +1,107 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use r_data_core_core::audit_log::AuditAction;
+use r_data_core_core::system_log::SystemLogResourceType;
+use r_data_core_persistence::AuditLogRepositoryTrait;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Records admin mutations with before/after snapshots of the affected resource
+pub struct AuditLogService {
+    repo: Arc<dyn AuditLogRepositoryTrait>,
+}
+
+impl AuditLogService {
+    pub fn new(repo: Arc<dyn AuditLogRepositoryTrait>) -> Self {
+        Self { repo }
+    }
+
+    /// Record a created resource. Best-effort — errors are logged to stdout.
+    pub async fn record_created<T: Serialize + Sync>(
+        &self,
+        actor: Option<Uuid>,
+        resource_type: SystemLogResourceType,
+        resource_uuid: Uuid,
+        after: &T,
+    ) {
+        self.record(
+            actor,
+            AuditAction::Create,
+            resource_type,
+            resource_uuid,
+            None,
+            snapshot(after),
+        )
+        .await;
+    }
+
+    /// Record an updated resource. Best-effort — errors are logged to stdout.
+    pub async fn record_updated<T: Serialize + Sync>(
+        &self,
+        actor: Option<Uuid>,
+        resource_type: SystemLogResourceType,
+        resource_uuid: Uuid,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) {
+        self.record(
+            actor,
+            AuditAction::Update,
+            resource_type,
+            resource_uuid,
+            before.and_then(snapshot),
+            after.and_then(snapshot),
+        )
+        .await;
+    }
+
+    /// Record a deleted resource. Best-effort — errors are logged to stdout.
+    pub async fn record_deleted<T: Serialize + Sync>(
+        &self,
+        actor: Option<Uuid>,
+        resource_type: SystemLogResourceType,
+        resource_uuid: Uuid,
+        before: Option<&T>,
+    ) {
+        self.record(
+            actor,
+            AuditAction::Delete,
+            resource_type,
+            resource_uuid,
+            before.and_then(snapshot),
+            None,
+        )
+        .await;
+    }
+
+    async fn record(
+        &self,
+        actor: Option<Uuid>,
+        action: AuditAction,
+        resource_type: SystemLogResourceType,
+        resource_uuid: Uuid,
+        before: Option<serde_json::Value>,
+        after: Option<serde_json::Value>,
+    ) {
+        if let Err(e) = self
+            .repo
+            .insert(actor, action, resource_type, resource_uuid, before, after)
+            .await
+        {
+            log::error!("Failed to write audit log entry for {resource_uuid}: {e}");
+        }
+    }
+}
+
+/// Serialize a resource for the audit log; secrets are excluded by the types' serde attributes
+fn snapshot<T: Serialize>(value: &T) -> Option<serde_json::Value> {
+    match serde_json::to_value(value) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            log::error!("Failed to serialize audit log snapshot: {e}");
+            None
+        }
+    }
+}
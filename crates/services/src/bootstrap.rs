@@ -3,10 +3,9 @@
 use std::sync::Arc;
 
 use log::info;
-use sqlx::postgres::PgPoolOptions;
 
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, DatabaseConfig};
 
 /// Initialize logger with default level
 pub fn init_logger_with_default(default_level: &str) {
@@ -23,12 +22,11 @@ pub fn init_logger_with_default(default_level: &str) {
 /// # Errors
 /// Returns an error if the connection pool cannot be created
 pub async fn init_pg_pool(
-    connection_string: &str,
-    max_connections: u32,
+    config: &DatabaseConfig,
 ) -> r_data_core_core::error::Result<sqlx::Pool<sqlx::Postgres>> {
-    let pool = PgPoolOptions::new()
-        .max_connections(max_connections)
-        .connect(connection_string)
+    let pool = config
+        .pool_options()
+        .connect(&config.connection_string)
         .await?;
     Ok(pool)
 }
@@ -8,8 +8,33 @@
 use std::sync::Arc;
 
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
-use r_data_core_core::error::Result;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::permissions::ROLE_PERMISSIONS_CACHE_PREFIX;
+use r_data_core_core::settings::SystemSettingKey;
+use r_data_core_license::api::LICENSE_CACHE_KEY_PREFIX;
+use r_data_core_persistence::MISSING_ENTITY_CACHE_PREFIX;
+
+/// Cache namespaces that can be cleared on their own, with the key prefixes they cover
+const CACHE_NAMESPACES: &[(&str, &[&str])] = &[
+    ("entity_definitions", &["entity_def:"]),
+    ("entities", &[MISSING_ENTITY_CACHE_PREFIX]),
+    (
+        "api_keys",
+        &["api_key:", "api_key_roles:", "api_key_permissions:"],
+    ),
+    (
+        "roles",
+        &[
+            "role:",
+            ROLE_PERMISSIONS_CACHE_PREFIX,
+            "user_roles:",
+            "user_permissions:",
+        ],
+    ),
+    ("settings", &[SystemSettingKey::CACHE_PREFIX]),
+    ("license", &[LICENSE_CACHE_KEY_PREFIX]),
+];
 
 /// Result of a cache clear operation
 #[derive(Debug, Clone)]
@@ -49,6 +74,10 @@ impl CacheService {
             max_size: 10000,
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
         };
 
         let manager = CacheManager::new(config).with_redis(redis_url).await?;
@@ -57,12 +86,54 @@ impl CacheService {
         })
     }
 
-    /// Clear the entire cache
+    /// Clear every cache namespace
+    ///
+    /// Only keys under the namespace prefixes are removed, so data sharing the Redis database
+    /// (queued jobs, revoked tokens, rate-limit counters) survives.
+    ///
+    /// # Returns
+    /// The number of entries removed
     ///
     /// # Errors
-    /// Returns an error if cache clearing fails
-    pub async fn clear_all(&self) -> Result<()> {
-        self.manager.clear().await
+    /// Returns an error if cache deletion fails
+    pub async fn clear_all(&self) -> Result<usize> {
+        let mut deleted = 0;
+        for (_, prefixes) in CACHE_NAMESPACES {
+            deleted += self.clear_prefixes(prefixes).await?;
+        }
+        Ok(deleted)
+    }
+
+    /// Names of the namespaces accepted by [`Self::clear_namespace`]
+    pub fn namespaces() -> impl Iterator<Item = &'static str> {
+        CACHE_NAMESPACES.iter().map(|(name, _)| *name)
+    }
+
+    /// Clear every cache entry of a namespace such as `entity_definitions` or `api_keys`
+    ///
+    /// # Returns
+    /// The number of entries deleted
+    ///
+    /// # Errors
+    /// Returns a validation error for an unknown namespace, or an error if cache deletion fails
+    pub async fn clear_namespace(&self, namespace: &str) -> Result<usize> {
+        let Some((_, prefixes)) = CACHE_NAMESPACES.iter().find(|(name, _)| *name == namespace)
+        else {
+            return Err(Error::Validation(format!(
+                "Unknown cache namespace '{namespace}'; expected one of: {}",
+                Self::namespaces().collect::<Vec<_>>().join(", ")
+            )));
+        };
+
+        self.clear_prefixes(prefixes).await
+    }
+
+    async fn clear_prefixes(&self, prefixes: &[&str]) -> Result<usize> {
+        let mut deleted = 0;
+        for prefix in prefixes {
+            deleted += self.manager.delete_by_prefix(prefix).await?;
+        }
+        Ok(deleted)
     }
 
     /// Clear cache entries matching a prefix
@@ -83,7 +154,7 @@ impl CacheService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use r_data_core_core::config::CacheConfig;
+    use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 
     fn create_test_config() -> CacheConfig {
         CacheConfig {
@@ -92,6 +163,10 @@ mod tests {
             max_size: 1000,
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
         }
     }
 
@@ -113,17 +188,33 @@ mod tests {
         let service = CacheService::new(manager.clone());
 
         // Set some values
-        manager.set("key1", &"value1", None).await.unwrap();
-        manager.set("key2", &"value2", None).await.unwrap();
+        manager.set("entity_def:a", &"value1", None).await.unwrap();
+        manager.set("api_key:b", &"value2", None).await.unwrap();
 
         // Clear all
-        service.clear_all().await.unwrap();
+        let cleared = service.clear_all().await.unwrap();
+        assert_eq!(cleared, 2);
 
         // Values should be gone
-        let result: Option<String> = manager.get("key1").await.unwrap();
+        let result: Option<String> = manager.get("entity_def:a").await.unwrap();
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    async fn test_clear_all_keeps_non_cache_keys() {
+        let config = create_test_config();
+        let manager = Arc::new(CacheManager::new(config));
+        let service = CacheService::new(manager.clone());
+
+        manager.set("role:a", &"role", None).await.unwrap();
+        manager.set("jwt:revoked:a", &true, None).await.unwrap();
+
+        assert_eq!(service.clear_all().await.unwrap(), 1);
+
+        let revoked: Option<bool> = manager.get("jwt:revoked:a").await.unwrap();
+        assert_eq!(revoked, Some(true));
+    }
+
     #[tokio::test]
     async fn test_clear_by_prefix_with_in_memory() {
         let config = create_test_config();
@@ -174,4 +265,39 @@ mod tests {
         let result: Option<String> = manager.get("api_keys:1").await.unwrap();
         assert!(result.is_some());
     }
+
+    #[tokio::test]
+    async fn test_clear_namespace_with_in_memory() {
+        let config = create_test_config();
+        let manager = Arc::new(CacheManager::new(config));
+        let service = CacheService::new(manager.clone());
+
+        manager.set("api_key:hash:abc", &"key", None).await.unwrap();
+        manager
+            .set("api_key_permissions:1", &"perms", None)
+            .await
+            .unwrap();
+        manager
+            .set("entity_def:by_type:customer", &"def", None)
+            .await
+            .unwrap();
+
+        let deleted = service.clear_namespace("api_keys").await.unwrap();
+        assert_eq!(deleted, 2);
+
+        let result: Option<String> = manager.get("api_key:hash:abc").await.unwrap();
+        assert!(result.is_none());
+        let result: Option<String> = manager.get("entity_def:by_type:customer").await.unwrap();
+        assert!(result.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_clear_namespace_rejects_unknown_namespace() {
+        let config = create_test_config();
+        let manager = Arc::new(CacheManager::new(config));
+        let service = CacheService::new(manager);
+
+        let result = service.clear_namespace("everything").await;
+        assert!(matches!(result, Err(Error::Validation(_))));
+    }
 }
@@ -0,0 +1,108 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::{error, warn};
+use r_data_core_core::error::Result;
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::dynamic_entity_utils::extract_uuid_from_entity_field_data;
+use uuid::Uuid;
+
+use super::DynamicEntityService;
+
+/// Callback invoked after an entity has been written through the service
+#[async_trait]
+pub trait EntityChangeHook: Send + Sync {
+    /// Called with the stored state before the update and the entity as written
+    ///
+    /// # Errors
+    /// Errors are logged by the caller and never fail the update itself
+    async fn entity_updated(&self, before: &DynamicEntity, after: &DynamicEntity) -> Result<()>;
+
+    /// Called with the entity as written after it has been created or restored
+    ///
+    /// # Errors
+    /// Errors are logged by the caller and never fail the write itself
+    async fn entity_created(&self, _entity: &DynamicEntity) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with the last stored state after a live entity has been deleted
+    ///
+    /// # Errors
+    /// Errors are logged by the caller and never fail the delete itself
+    async fn entity_deleted(&self, _before: &DynamicEntity) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl DynamicEntityService {
+    /// Register a hook that is notified about every successful update
+    #[must_use]
+    pub fn with_change_hook(mut self, hook: Arc<dyn EntityChangeHook>) -> Self {
+        self.change_hooks.push(hook);
+        self
+    }
+
+    /// Stored state of `entity` before it is overwritten; only loaded when hooks are registered
+    pub(super) async fn load_before_update(&self, entity: &DynamicEntity) -> Option<DynamicEntity> {
+        let uuid = extract_uuid_from_entity_field_data(&entity.field_data, "uuid")?;
+        self.load_for_hooks(&entity.entity_type, &uuid).await
+    }
+
+    /// Live stored state of an entity; only loaded when hooks are registered
+    pub(super) async fn load_for_hooks(
+        &self,
+        entity_type: &str,
+        uuid: &Uuid,
+    ) -> Option<DynamicEntity> {
+        if self.change_hooks.is_empty() {
+            return None;
+        }
+        match self.repository.get_by_type(entity_type, uuid, None).await {
+            Ok(entity) => entity,
+            Err(e) => {
+                warn!("Failed to load {entity_type} {uuid} for change hooks: {e}");
+                None
+            }
+        }
+    }
+
+    /// Run all change hooks; failures are logged so they never roll back the update
+    pub(crate) async fn notify_updated(
+        &self,
+        before: Option<DynamicEntity>,
+        after: &DynamicEntity,
+    ) {
+        let Some(before) = before else {
+            return;
+        };
+        for hook in &self.change_hooks {
+            if let Err(e) = hook.entity_updated(&before, after).await {
+                error!("Entity change hook failed for {}: {e}", after.entity_type);
+            }
+        }
+    }
+
+    /// Run all change hooks for a created or restored entity
+    pub(super) async fn notify_created(&self, entity: &DynamicEntity) {
+        for hook in &self.change_hooks {
+            if let Err(e) = hook.entity_created(entity).await {
+                error!("Entity change hook failed for {}: {e}", entity.entity_type);
+            }
+        }
+    }
+
+    /// Run all change hooks for a deleted entity; `before` is `None` if it was not loaded
+    pub(super) async fn notify_deleted(&self, before: Option<DynamicEntity>) {
+        let Some(before) = before else {
+            return;
+        };
+        for hook in &self.change_hooks {
+            if let Err(e) = hook.entity_deleted(&before).await {
+                error!("Entity change hook failed for {}: {e}", before.entity_type);
+            }
+        }
+    }
+}
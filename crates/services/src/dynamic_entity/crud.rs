@@ -1,5 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use std::collections::HashMap;
+
 use r_data_core_core::error::Result;
 use r_data_core_core::DynamicEntity;
 use uuid::Uuid;
@@ -66,6 +68,8 @@ impl DynamicEntityService {
 
     /// Get an entity by UUID
     ///
+    /// Misses are remembered if a missing-entity cache is configured.
+    ///
     /// # Errors
     /// Returns an error if the entity type is not found, not published, or the database query fails
     pub async fn get_entity_by_uuid(
@@ -78,8 +82,38 @@ impl DynamicEntityService {
         self.check_entity_type_exists_and_published(entity_type)
             .await?;
 
-        self.repository
+        if self.is_known_missing(entity_type, uuid).await {
+            return Ok(None);
+        }
+
+        let entity = self
+            .repository
             .get_by_type(entity_type, uuid, exclusive_fields)
+            .await?;
+        if entity.is_none() {
+            self.remember_missing(entity_type, uuid).await;
+        }
+        Ok(entity)
+    }
+
+    /// Get several entities of one type by UUID in a single query
+    ///
+    /// Every requested UUID is a key of the result; missing or deleted entities map to `None`.
+    ///
+    /// # Errors
+    /// Returns an error if the entity type is not found, not published, or the database query fails
+    pub async fn get_entities_by_uuids(
+        &self,
+        entity_type: &str,
+        uuids: &[Uuid],
+        exclusive_fields: Option<Vec<String>>,
+    ) -> Result<HashMap<Uuid, Option<DynamicEntity>>> {
+        // Verify the entity type exists and is published
+        self.check_entity_type_exists_and_published(entity_type)
+            .await?;
+
+        self.repository
+            .get_many_by_type(entity_type, uuids, exclusive_fields)
             .await
     }
 
@@ -98,10 +132,17 @@ impl DynamicEntityService {
         self.check_entity_type_exists_and_published(entity_type)
             .await?;
 
+        if self.is_known_missing(entity_type, uuid).await {
+            return Ok((None, None));
+        }
+
         let entity = self
             .repository
             .get_by_type(entity_type, uuid, exclusive_fields)
             .await?;
+        if entity.is_none() {
+            self.remember_missing(entity_type, uuid).await;
+        }
 
         let children_count = if include_children_count && entity.is_some() {
             Some(self.repository.count_children(uuid).await?)
@@ -132,18 +173,27 @@ impl DynamicEntityService {
 
     /// Create a new entity with validation
     ///
+    /// Fields missing from the entity get their definition's `default_value` before validation.
+    ///
     /// # Errors
     /// Returns an error if the validation fails, the entity type is not found/not published, or creation fails
     /// Returns the UUID
     pub async fn create_entity(&self, entity: &DynamicEntity) -> Result<Uuid> {
         // Check if the entity type is published
-        self.check_entity_type_exists_and_published(&entity.entity_type)
+        let entity_definition = self
+            .check_entity_type_exists_and_published(&entity.entity_type)
             .await?;
 
+        let mut entity = entity.clone();
+        entity_definition.apply_field_defaults(&mut entity.field_data);
+
         // Validate entity against entity definition
-        Self::validate_entity(entity)?;
+        Self::validate_entity(&entity)?;
 
-        self.repository.create(entity).await
+        let uuid = self.repository.create(&entity).await?;
+        self.forget_missing(&entity.entity_type, &uuid).await;
+        self.notify_created(&entity).await;
+        Ok(uuid)
     }
 
     /// Update an existing entity with validation
@@ -158,7 +208,10 @@ impl DynamicEntityService {
         // Validate entity against entity definition
         Self::validate_entity(entity)?;
 
-        self.repository.update(entity).await
+        let before = self.load_before_update(entity).await;
+        self.repository.update(entity).await?;
+        self.notify_updated(before, entity).await;
+        Ok(())
     }
 
     /// Update an existing entity with options (e.g., skip versioning snapshots)
@@ -181,16 +234,19 @@ impl DynamicEntityService {
         // Validate entity against entity definition
         Self::validate_entity(entity)?;
 
+        let before = self.load_before_update(entity).await;
         if skip_versioning {
             // Temporary: inject internal flag until repository trait supports explicit param
             let mut cloned = entity.clone();
             cloned
                 .field_data
                 .insert("__skip_versioning".to_string(), serde_json::json!(true));
-            self.repository.update(&cloned).await
+            self.repository.update(&cloned).await?;
         } else {
-            self.repository.update(entity).await
+            self.repository.update(entity).await?;
         }
+        self.notify_updated(before, entity).await;
+        Ok(())
     }
 
     /// Delete an entity
@@ -202,7 +258,49 @@ impl DynamicEntityService {
         self.check_entity_type_exists_and_published(entity_type)
             .await?;
 
-        self.repository.delete_by_type(entity_type, uuid).await
+        let before = self.load_for_hooks(entity_type, uuid).await;
+        self.repository.delete_by_type(entity_type, uuid).await?;
+        self.notify_deleted(before).await;
+        Ok(())
+    }
+
+    /// Restore a soft-deleted entity
+    ///
+    /// # Errors
+    /// Returns an error if entity type is not found, not published, or the entity is not deleted
+    pub async fn restore_entity(&self, entity_type: &str, uuid: &Uuid) -> Result<()> {
+        self.check_entity_type_exists_and_published(entity_type)
+            .await?;
+
+        self.repository.restore_by_type(entity_type, uuid).await?;
+        self.forget_missing(entity_type, uuid).await;
+        if let Some(restored) = self.load_for_hooks(entity_type, uuid).await {
+            self.notify_created(&restored).await;
+        }
+        Ok(())
+    }
+
+    /// Permanently delete an entity (GDPR erasure)
+    ///
+    /// # Errors
+    /// Returns an error if entity type is not found, not published, or deletion fails
+    pub async fn hard_delete_entity(&self, entity_type: &str, uuid: &Uuid) -> Result<()> {
+        self.check_entity_type_exists_and_published(entity_type)
+            .await?;
+
+        // Soft-deleted entities are not loaded, so erasing them does not notify the hooks again
+        let before = self.load_for_hooks(entity_type, uuid).await;
+        self.repository.hard_delete(entity_type, uuid).await?;
+        self.notify_deleted(before).await;
+        Ok(())
+    }
+
+    /// Whether an entity exists but has been soft-deleted
+    ///
+    /// # Errors
+    /// Returns an error if the query fails
+    pub async fn is_entity_deleted(&self, entity_type: &str, uuid: &Uuid) -> Result<bool> {
+        self.repository.is_deleted(entity_type, uuid).await
     }
 
     /// Find a single entity by field filters
@@ -2,12 +2,15 @@
 
 use std::collections::HashMap;
 
+use r_data_core_core::count::{CountMode, ListTotal};
 use r_data_core_core::entity_definition::definition::EntityDefinition;
 use r_data_core_core::error::Result;
 use r_data_core_core::field::types::FieldType;
+use r_data_core_core::sort::SortCollation;
 use r_data_core_core::DynamicEntity;
 use r_data_core_persistence::FilterEntitiesParams;
 use serde_json::Value as JsonValue;
+use uuid::Uuid;
 
 use super::DynamicEntityService;
 
@@ -71,6 +74,11 @@ impl DynamicEntityService {
 
     /// List entities with advanced filtering options
     ///
+    /// Soft-deleted entities are only returned when `include_deleted` is set. With an `owner`,
+    /// only entities created by that user are listed and counted. The `collation` only affects
+    /// text sort fields. `count_mode` picks how the total is counted; approximate counts fall
+    /// back to exact ones for owner-scoped lists and tables without statistics.
+    ///
     /// # Errors
     /// Returns an error if entity type is not found, not published, or database query fails
     #[allow(clippy::too_many_arguments)] // Public API - parameters are clear and well-named
@@ -82,14 +90,33 @@ impl DynamicEntityService {
         fields: Option<Vec<String>>,
         sort_by: Option<String>,
         sort_direction: Option<String>,
+        collation: SortCollation,
         filter: Option<serde_json::Value>,
         search_query: Option<String>,
-    ) -> Result<(Vec<DynamicEntity>, i64)> {
+        include_deleted: bool,
+        owner: Option<Uuid>,
+        count_mode: CountMode,
+    ) -> Result<(Vec<DynamicEntity>, ListTotal)> {
         // Verify the entity type exists and is published
         let entity_def = self.get_entity_definition_for_query(entity_type).await?;
 
         // Count entities first for pagination
-        let total = self.repository.count_entities(entity_type).await?;
+        let total = match count_mode {
+            CountMode::None => ListTotal::Skipped,
+            CountMode::Approximate if owner.is_none() => {
+                match self.repository.estimate_entities(entity_type).await? {
+                    Some(estimate) => ListTotal::Approximate(estimate),
+                    None => ListTotal::Exact(
+                        self.count_listed(entity_type, include_deleted, owner)
+                            .await?,
+                    ),
+                }
+            }
+            CountMode::Exact | CountMode::Approximate => ListTotal::Exact(
+                self.count_listed(entity_type, include_deleted, owner)
+                    .await?,
+            ),
+        };
 
         // Build filter conditions from the structured filter
         let mut filter_conditions = HashMap::new();
@@ -160,7 +187,10 @@ impl DynamicEntityService {
             .with_filter_operators(None) // Default to "=" for all filters
             .with_search(search_fields)
             .with_sort(sort_info)
-            .with_fields(fields);
+            .with_collation(collation)
+            .with_fields(fields)
+            .with_include_deleted(include_deleted)
+            .with_owner(owner);
         let entities = self
             .repository
             .filter_entities(entity_type, &params)
@@ -169,6 +199,26 @@ impl DynamicEntityService {
         Ok((entities, total))
     }
 
+    /// Exact number of entities a list of `entity_type` pages through
+    async fn count_listed(
+        &self,
+        entity_type: &str,
+        include_deleted: bool,
+        owner: Option<Uuid>,
+    ) -> Result<i64> {
+        if let Some(owner) = &owner {
+            return self
+                .repository
+                .count_entities_by_owner(entity_type, owner)
+                .await;
+        }
+        let mut total = self.repository.count_entities(entity_type).await?;
+        if include_deleted {
+            total += self.repository.count_deleted_entities(entity_type).await?;
+        }
+        Ok(total)
+    }
+
     /// Helper method to get entity definition for query operations
     ///
     /// # Arguments
@@ -0,0 +1,71 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use log::warn;
+use r_data_core_core::cache::CacheManager;
+use r_data_core_persistence::missing_entity_cache_key;
+use uuid::Uuid;
+
+use super::DynamicEntityService;
+
+impl DynamicEntityService {
+    /// Remember lookups of non-existent entities for `ttl` seconds
+    ///
+    /// Repeated requests for an unknown UUID are then answered without a database round-trip.
+    /// Creating or restoring the entity clears its marker, in the service and in the repository
+    /// when it shares this cache; a `ttl` of 0 disables the cache.
+    #[must_use]
+    pub fn with_missing_entity_cache(mut self, cache_manager: Arc<CacheManager>, ttl: u64) -> Self {
+        self.missing_cache = (ttl > 0).then_some(cache_manager);
+        self.missing_ttl = ttl;
+        self
+    }
+
+    /// Whether a recent lookup found no entity with this UUID
+    pub(super) async fn is_known_missing(&self, entity_type: &str, uuid: &Uuid) -> bool {
+        let Some(cache) = &self.missing_cache else {
+            return false;
+        };
+        match cache
+            .get::<bool>(&missing_entity_cache_key(entity_type, uuid))
+            .await
+        {
+            Ok(marker) => marker.unwrap_or(false),
+            Err(e) => {
+                warn!("Failed to read missing entity marker: {e}");
+                false
+            }
+        }
+    }
+
+    /// Mark the UUID as not existing until the TTL runs out
+    pub(super) async fn remember_missing(&self, entity_type: &str, uuid: &Uuid) {
+        let Some(cache) = &self.missing_cache else {
+            return;
+        };
+        if let Err(e) = cache
+            .set(
+                &missing_entity_cache_key(entity_type, uuid),
+                &true,
+                Some(self.missing_ttl),
+            )
+            .await
+        {
+            warn!("Failed to store missing entity marker: {e}");
+        }
+    }
+
+    /// Drop the marker once the entity exists
+    pub(super) async fn forget_missing(&self, entity_type: &str, uuid: &Uuid) {
+        let Some(cache) = &self.missing_cache else {
+            return;
+        };
+        if let Err(e) = cache
+            .delete(&missing_entity_cache_key(entity_type, uuid))
+            .await
+        {
+            warn!("Failed to clear missing entity marker: {e}");
+        }
+    }
+}
@@ -1,7 +1,9 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+mod change_hook;
 mod crud;
 mod filtering;
+mod missing_cache;
 mod validation;
 
 #[cfg(test)]
@@ -9,6 +11,9 @@ mod tests;
 
 use std::sync::Arc;
 
+pub use change_hook::EntityChangeHook;
+use r_data_core_core::cache::CacheManager;
+
 use crate::entity_definition::EntityDefinitionService;
 use r_data_core_persistence::DynamicEntityRepositoryTrait;
 
@@ -17,6 +22,9 @@ use r_data_core_persistence::DynamicEntityRepositoryTrait;
 pub struct DynamicEntityService {
     repository: Arc<dyn DynamicEntityRepositoryTrait + Send + Sync>,
     entity_definition_service: Arc<EntityDefinitionService>,
+    change_hooks: Vec<Arc<dyn EntityChangeHook>>,
+    missing_cache: Option<Arc<CacheManager>>,
+    missing_ttl: u64,
 }
 
 impl DynamicEntityService {
@@ -33,6 +41,9 @@ impl DynamicEntityService {
         Self {
             repository,
             entity_definition_service,
+            change_hooks: Vec::new(),
+            missing_cache: None,
+            missing_ttl: 0,
         }
     }
 
@@ -25,14 +25,21 @@ mock! {
         async fn create(&self, entity: &DynamicEntity) -> Result<Uuid>;
         async fn update(&self, entity: &DynamicEntity) -> Result<()>;
         async fn get_by_type(&self, entity_type: &str, uuid: &Uuid, exclusive_fields: Option<Vec<String>>) -> Result<Option<DynamicEntity>>;
+        async fn get_many_by_type(&self, entity_type: &str, uuids: &[Uuid], exclusive_fields: Option<Vec<String>>) -> Result<HashMap<Uuid, Option<DynamicEntity>>>;
         async fn get_all_by_type(&self, entity_type: &str, limit: i64, offset: i64, exclusive_fields: Option<Vec<String>>) -> Result<Vec<DynamicEntity>>;
         async fn delete_by_type(&self, entity_type: &str, uuid: &Uuid) -> Result<()>;
+        async fn restore_by_type(&self, entity_type: &str, uuid: &Uuid) -> Result<()>;
+        async fn hard_delete(&self, entity_type: &str, uuid: &Uuid) -> Result<()>;
+        async fn is_deleted(&self, entity_type: &str, uuid: &Uuid) -> Result<bool>;
         async fn filter_entities(
             &self,
             entity_type: &str,
             params: &r_data_core_persistence::dynamic_entity_repository_trait::FilterEntitiesParams,
         ) -> Result<Vec<DynamicEntity>>;
         async fn count_entities(&self, entity_type: &str) -> Result<i64>;
+        async fn count_deleted_entities(&self, entity_type: &str) -> Result<i64>;
+        async fn count_entities_by_owner(&self, entity_type: &str, owner: &Uuid) -> Result<i64>;
+        async fn estimate_entities(&self, entity_type: &str) -> Result<Option<i64>>;
         async fn count_children(&self, parent_uuid: &Uuid) -> Result<i64>;
         async fn get_by_uuid_any_type(&self, uuid: &Uuid) -> Result<Option<DynamicEntity>>;
         async fn find_one_by_filters(&self, entity_type: &str, filters: &std::collections::HashMap<String, serde_json::Value>) -> Result<Option<DynamicEntity>>;
@@ -52,6 +59,7 @@ mock! {
         async fn get_by_entity_type(&self, entity_type: &str) -> r_data_core_core::error::Result<Option<EntityDefinition>>;
         async fn create(&self, definition: &EntityDefinition) -> r_data_core_core::error::Result<Uuid>;
         async fn update(&self, uuid: &Uuid, definition: &EntityDefinition) -> r_data_core_core::error::Result<()>;
+        async fn rename_field(&self, uuid: &Uuid, definition: &EntityDefinition, old_name: &str, new_name: &str, rename_in_versions: bool) -> r_data_core_core::error::Result<()>;
         async fn delete(&self, uuid: &Uuid) -> r_data_core_core::error::Result<()>;
         async fn apply_schema(&self, schema_sql: &str) -> r_data_core_core::error::Result<()>;
         async fn update_entity_view_for_entity_definition(&self, entity_definition: &EntityDefinition) -> r_data_core_core::error::Result<()>;
@@ -59,6 +67,8 @@ mock! {
         async fn get_view_columns_with_types(&self, view_name: &str) -> r_data_core_core::error::Result<HashMap<String, String>>;
         async fn count_view_records(&self, view_name: &str) -> r_data_core_core::error::Result<i64>;
         async fn cleanup_unused_entity_view(&self) -> r_data_core_core::error::Result<()>;
+        async fn find_table_owner(&self, table_name: &str, exclude: Option<Uuid>) -> r_data_core_core::error::Result<Option<String>>;
+        async fn is_foreign_table(&self, table_name: &str) -> r_data_core_core::error::Result<bool>;
     }
 }
 
@@ -96,6 +106,7 @@ fn create_test_entity_definition() -> EntityDefinition {
                 validation: FieldValidation::default(),
                 ui_settings: UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
             FieldDefinition {
                 name: "age".to_string(),
@@ -110,8 +121,13 @@ fn create_test_entity_definition() -> EntityDefinition {
                 validation: FieldValidation::default(),
                 ui_settings: UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
         ],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         published: true,
     }
 }
@@ -271,8 +287,8 @@ async fn test_create_entity_missing_required_field() -> Result<()> {
     // Check that we got a validation error
     assert!(result.is_err());
     match result {
-        Err(r_data_core_core::error::Error::Validation(msg)) => {
-            assert!(msg.contains("Required field 'name' is missing"));
+        Err(r_data_core_core::error::Error::FieldValidation(errors)) => {
+            assert_eq!(errors.get("name"), Some("is required"));
         }
         _ => panic!("Expected validation error, got: {result:?}"),
     }
@@ -510,3 +526,256 @@ async fn test_get_entity_by_uuid_with_children_count_entity_not_found() -> Resul
 
     Ok(())
 }
+
+fn entity_definition_with_age_default() -> EntityDefinition {
+    let mut entity_def = create_test_entity_definition();
+    entity_def.fields[1].default_value = Some(json!(18));
+    entity_def
+}
+
+fn service_with_age_default(repo: MockDynamicEntityRepo) -> DynamicEntityService {
+    let mut class_repo = MockEntityDefinitionRepo::new();
+    class_repo
+        .expect_get_by_entity_type()
+        .with(predicate::eq("test_entity"))
+        .returning(|_| Ok(Some(entity_definition_with_age_default())));
+    let class_service = EntityDefinitionService::new_without_cache(Arc::new(class_repo));
+    DynamicEntityService::new(Arc::new(repo), Arc::new(class_service))
+}
+
+fn entity_with_fields(fields: &[(&str, serde_json::Value)]) -> DynamicEntity {
+    DynamicEntity {
+        entity_type: "test_entity".to_string(),
+        field_data: fields
+            .iter()
+            .map(|(name, value)| ((*name).to_string(), value.clone()))
+            .collect(),
+        definition: Arc::new(entity_definition_with_age_default()),
+    }
+}
+
+#[tokio::test]
+async fn test_create_entity_applies_default_for_omitted_field() -> Result<()> {
+    let mut repo = MockDynamicEntityRepo::new();
+    repo.expect_create()
+        .with(predicate::function(|e: &DynamicEntity| {
+            e.field_data.get("age") == Some(&json!(18))
+        }))
+        .returning(|_| Ok(Uuid::now_v7()));
+    let service = service_with_age_default(repo);
+
+    service
+        .create_entity(&entity_with_fields(&[("name", json!("Ada"))]))
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_entity_keeps_explicit_null() -> Result<()> {
+    let mut repo = MockDynamicEntityRepo::new();
+    repo.expect_create()
+        .with(predicate::function(|e: &DynamicEntity| {
+            e.field_data.get("age") == Some(&serde_json::Value::Null)
+        }))
+        .returning(|_| Ok(Uuid::now_v7()));
+    let service = service_with_age_default(repo);
+
+    service
+        .create_entity(&entity_with_fields(&[
+            ("name", json!("Ada")),
+            ("age", serde_json::Value::Null),
+        ]))
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_entity_does_not_apply_defaults() -> Result<()> {
+    let mut repo = MockDynamicEntityRepo::new();
+    repo.expect_update()
+        .with(predicate::function(|e: &DynamicEntity| {
+            !e.field_data.contains_key("age")
+        }))
+        .returning(|_| Ok(()));
+    let service = service_with_age_default(repo);
+
+    service
+        .update_entity(&entity_with_fields(&[
+            ("uuid", json!(Uuid::nil().to_string())),
+            ("name", json!("Ada")),
+        ]))
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_entity_reports_all_field_errors() -> Result<()> {
+    use r_data_core_core::field::types::FieldType;
+    use r_data_core_core::field::FieldDefinition;
+
+    fn definition() -> EntityDefinition {
+        let mut entity_def = create_test_entity_definition();
+        let mut email =
+            FieldDefinition::new("email".to_string(), "Email".to_string(), FieldType::String);
+        email.required = true;
+        entity_def.fields.push(email);
+        entity_def
+    }
+
+    let mut class_repo = MockEntityDefinitionRepo::new();
+    class_repo
+        .expect_get_by_entity_type()
+        .with(predicate::eq("test_entity"))
+        .returning(|_| Ok(Some(definition())));
+    let class_service = EntityDefinitionService::new_without_cache(Arc::new(class_repo));
+    let service = DynamicEntityService::new(
+        Arc::new(MockDynamicEntityRepo::new()),
+        Arc::new(class_service),
+    );
+
+    let entity = DynamicEntity {
+        entity_type: "test_entity".to_string(),
+        field_data: HashMap::from([("age".to_string(), json!("thirty"))]),
+        definition: Arc::new(definition()),
+    };
+
+    match service.create_entity(&entity).await {
+        Err(r_data_core_core::error::Error::FieldValidation(errors)) => {
+            assert_eq!(errors.len(), 3, "{errors}");
+            assert_eq!(errors.get("name"), Some("is required"));
+            assert_eq!(errors.get("email"), Some("is required"));
+            assert!(errors.get("age").is_some_and(|m| m.contains("integer")));
+        }
+        other => panic!("Expected field validation errors, got: {other:?}"),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_entity_checks_validation_rules() -> Result<()> {
+    use r_data_core_core::entity_definition::ValidationRule;
+
+    fn definition() -> EntityDefinition {
+        let mut entity_def = create_test_entity_definition();
+        entity_def.validation_rules = vec![ValidationRule {
+            name: "adult".to_string(),
+            expression: "age >= 18".to_string(),
+            message: "Age must be at least 18".to_string(),
+        }];
+        entity_def
+    }
+
+    let mut repo = MockDynamicEntityRepo::new();
+    repo.expect_create()
+        .times(1)
+        .returning(|_| Ok(Uuid::now_v7()));
+    let mut class_repo = MockEntityDefinitionRepo::new();
+    class_repo
+        .expect_get_by_entity_type()
+        .with(predicate::eq("test_entity"))
+        .returning(|_| Ok(Some(definition())));
+    let class_service = EntityDefinitionService::new_without_cache(Arc::new(class_repo));
+    let service = DynamicEntityService::new(Arc::new(repo), Arc::new(class_service));
+
+    let entity = |age: i64| DynamicEntity {
+        entity_type: "test_entity".to_string(),
+        field_data: HashMap::from([
+            ("name".to_string(), json!("Test Entity")),
+            ("age".to_string(), json!(age)),
+        ]),
+        definition: Arc::new(definition()),
+    };
+
+    service.create_entity(&entity(30)).await?;
+
+    match service.create_entity(&entity(12)).await {
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            assert!(msg.contains("adult: Age must be at least 18"), "{msg}");
+        }
+        other => panic!("Expected validation rule error, got: {other:?}"),
+    }
+    Ok(())
+}
+
+fn missing_entity_cache() -> Arc<r_data_core_core::cache::CacheManager> {
+    use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
+    Arc::new(r_data_core_core::cache::CacheManager::new(CacheConfig {
+        enabled: true,
+        ttl: 3600,
+        max_size: 100,
+        entity_definition_ttl: 0,
+        api_key_ttl: 600,
+        entity_miss_ttl: 60,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
+    }))
+}
+
+#[tokio::test]
+async fn test_get_entity_by_uuid_caches_miss() -> Result<()> {
+    let uuid = Uuid::now_v7();
+    let mut repo = MockDynamicEntityRepo::new();
+    repo.expect_get_by_type()
+        .with(eq("test_entity"), eq(uuid), eq(None))
+        .times(1)
+        .returning(|_, _, _| Ok(None));
+    let mut class_repo = MockEntityDefinitionRepo::new();
+    class_repo
+        .expect_get_by_entity_type()
+        .returning(|_| Ok(Some(create_test_entity_definition())));
+    let class_service = EntityDefinitionService::new_without_cache(Arc::new(class_repo));
+    let service = DynamicEntityService::new(Arc::new(repo), Arc::new(class_service))
+        .with_missing_entity_cache(missing_entity_cache(), 60);
+
+    // The second lookup is answered from the cache; the mock only allows one call
+    assert!(service
+        .get_entity_by_uuid("test_entity", &uuid, None)
+        .await?
+        .is_none());
+    assert!(service
+        .get_entity_by_uuid("test_entity", &uuid, None)
+        .await?
+        .is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_entity_clears_cached_miss() -> Result<()> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let uuid = Uuid::now_v7();
+    let created = Arc::new(AtomicBool::new(false));
+    let mut repo = MockDynamicEntityRepo::new();
+    let stored = created.clone();
+    repo.expect_get_by_type()
+        .times(2)
+        .returning(move |_, _, _| Ok(stored.load(Ordering::SeqCst).then(create_test_entity)));
+    let stored = created.clone();
+    repo.expect_create().times(1).returning(move |_| {
+        stored.store(true, Ordering::SeqCst);
+        Ok(uuid)
+    });
+    let mut class_repo = MockEntityDefinitionRepo::new();
+    class_repo
+        .expect_get_by_entity_type()
+        .returning(|_| Ok(Some(create_test_entity_definition())));
+    let class_service = EntityDefinitionService::new_without_cache(Arc::new(class_repo));
+    let service = DynamicEntityService::new(Arc::new(repo), Arc::new(class_service))
+        .with_missing_entity_cache(missing_entity_cache(), 60);
+
+    assert!(service
+        .get_entity_by_uuid("test_entity", &uuid, None)
+        .await?
+        .is_none());
+
+    let mut entity = create_test_entity();
+    entity.field_data.remove("uuid");
+    assert_eq!(service.create_entity(&entity).await?, uuid);
+
+    assert!(service
+        .get_entity_by_uuid("test_entity", &uuid, None)
+        .await?
+        .is_some());
+    Ok(())
+}
@@ -1,7 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use log::debug;
-use r_data_core_core::error::Result;
+use r_data_core_core::error::{Error, FieldErrors, Result};
 use r_data_core_core::DynamicEntity;
 
 use super::DynamicEntityService;
@@ -15,16 +15,12 @@ impl DynamicEntityService {
     /// # Errors
     /// Returns an error if validation fails
     pub(crate) fn validate_entity(entity: &DynamicEntity) -> Result<()> {
-        // Collect all validation errors instead of returning on first error
-        let mut validation_errors = Vec::new();
+        // Collect every field error instead of returning on the first one
+        let mut errors = FieldErrors::new();
 
         // Check for unknown fields - fields in the data that are not defined in the entity definition
-        let unknown_fields = Self::check_unknown_fields(entity);
-        if !unknown_fields.is_empty() {
-            validation_errors.push(format!(
-                "Unknown fields found: {}. Only fields defined in the entity definition are allowed.",
-                unknown_fields.join(", ")
-            ));
+        for field_name in Self::check_unknown_fields(entity) {
+            errors.add(field_name, "is not defined in the entity definition");
         }
 
         // For update operations, we only need to validate the fields that are being submitted
@@ -34,21 +30,53 @@ impl DynamicEntityService {
 
         if !is_update {
             // This is a create operation, so check all required fields
-            Self::check_required_fields(entity, &mut validation_errors);
+            Self::check_required_fields(entity, &mut errors);
         }
 
+        // Fields required only while another field has a given value
+        Self::check_conditional_requirements(entity, is_update, &mut errors);
+
         // Validate field values against their types and constraints (only for fields that are present)
-        Self::validate_field_values(entity, &mut validation_errors);
-
-        // If we've collected any errors, return them all as one validation error
-        if !validation_errors.is_empty() {
-            return Err(r_data_core_core::error::Error::Validation(format!(
-                "Validation failed with the following errors: {}",
-                validation_errors.join("; ")
-            )));
+        Self::validate_field_values(entity, &mut errors);
+
+        // Report all errors together, keyed by field
+        errors.into_result()?;
+
+        // Entity-level rules compare fields, so they only run on valid field values
+        Self::check_validation_rules(entity)
+    }
+
+    /// Check the entity definition's validation rules
+    ///
+    /// # Arguments
+    /// * `entity` - Entity to check
+    ///
+    /// # Errors
+    /// Returns a validation error naming every violated rule with its message
+    fn check_validation_rules(entity: &DynamicEntity) -> Result<()> {
+        let mut violations = Vec::new();
+        for rule in &entity.definition.validation_rules {
+            // Rules are checked when the definition is saved, so this only skips legacy data
+            let expression = match rule.parse() {
+                Ok(expression) => expression,
+                Err(e) => {
+                    log::warn!("Skipping validation rule: {e}");
+                    continue;
+                }
+            };
+            if !expression.is_satisfied(&entity.field_data) {
+                violations.push(format!("{}: {}", rule.name, rule.message));
+            }
         }
 
-        Ok(())
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Validation(format!(
+                "Validation rules failed: {}",
+                violations.join("; ")
+            )))
+        }
     }
 
     /// Check if this is an update operation based on presence of UUID
@@ -108,11 +136,38 @@ impl DynamicEntityService {
     ///
     /// # Arguments
     /// * `entity` - Entity to check
-    /// * `validation_errors` - Mutable vector to append validation errors to
-    fn check_required_fields(entity: &DynamicEntity, validation_errors: &mut Vec<String>) {
+    /// * `errors` - Field errors to add missing fields to
+    fn check_required_fields(entity: &DynamicEntity, errors: &mut FieldErrors) {
         for field in &entity.definition.fields {
             if field.required && !entity.field_data.contains_key(&field.name) {
-                validation_errors.push(format!("Required field '{}' is missing", field.name));
+                errors.add(&field.name, "is required");
+            }
+        }
+    }
+
+    /// Check `requires` rules: a field must not be empty while its dependency has the given value
+    ///
+    /// # Arguments
+    /// * `entity` - Entity to check
+    /// * `is_update` - Updates only carry changed fields, so absent fields keep their stored value
+    /// * `errors` - Field errors to add unmet requirements to
+    fn check_conditional_requirements(
+        entity: &DynamicEntity,
+        is_update: bool,
+        errors: &mut FieldErrors,
+    ) {
+        for field in &entity.definition.fields {
+            let Some(requirement) = &field.validation.requires else {
+                continue;
+            };
+            let value = entity.field_data.get(&field.name);
+            if is_update && value.is_none() {
+                continue;
+            }
+            if let Some(message) =
+                field.unmet_requirement(entity.field_data.get(&requirement.field), value)
+            {
+                errors.add(&field.name, message);
             }
         }
     }
@@ -121,12 +176,14 @@ impl DynamicEntityService {
     ///
     /// # Arguments
     /// * `entity` - Entity to validate
-    /// * `validation_errors` - Mutable vector to append validation errors to
-    fn validate_field_values(entity: &DynamicEntity, validation_errors: &mut Vec<String>) {
+    /// * `errors` - Field errors to add invalid values to
+    fn validate_field_values(entity: &DynamicEntity, errors: &mut FieldErrors) {
         for field in &entity.definition.fields {
             if let Some(value) = entity.field_data.get(&field.name) {
-                if let Err(e) = field.validate_value(value) {
-                    validation_errors.push(format!("Field '{}' validation error: {e}", field.name));
+                match field.validate_value(value) {
+                    Ok(()) => {}
+                    Err(Error::Validation(message)) => errors.add(&field.name, message),
+                    Err(e) => errors.add(&field.name, e.to_string()),
                 }
             }
         }
@@ -0,0 +1,150 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use r_data_core_core::entity_aggregate::{AggregateDelta, EntityAggregate};
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::EntityAggregateRepositoryTrait;
+
+use crate::dynamic_entity::EntityChangeHook;
+
+/// Manages cached per-entity-type aggregates (entity count and numeric field sums)
+///
+/// Aggregates are kept current through the entity change hooks; writes that bypass
+/// `DynamicEntityService` (e.g. cascaded deletes) are only picked up by a rebuild.
+#[derive(Clone)]
+pub struct EntityAggregateService {
+    repository: Arc<dyn EntityAggregateRepositoryTrait>,
+}
+
+impl EntityAggregateService {
+    #[must_use]
+    pub fn new(repository: Arc<dyn EntityAggregateRepositoryTrait>) -> Self {
+        Self { repository }
+    }
+
+    /// Cached aggregate of an entity type
+    ///
+    /// # Errors
+    /// Returns `NotFound` if aggregates are not enabled for the entity type
+    pub async fn get(&self, entity_type: &str) -> Result<EntityAggregate> {
+        self.repository.get(entity_type).await?.ok_or_else(|| {
+            Error::NotFound(format!(
+                "Aggregates are not enabled for entity type '{entity_type}'"
+            ))
+        })
+    }
+
+    /// All configured aggregates
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    pub async fn list(&self) -> Result<Vec<EntityAggregate>> {
+        self.repository.list().await
+    }
+
+    /// Enable aggregates for an entity type, summing `sum_fields`, and compute them from scratch
+    ///
+    /// # Errors
+    /// Returns a validation error if a field does not exist or is not numeric
+    pub async fn configure(
+        &self,
+        definition: &EntityDefinition,
+        sum_fields: &[String],
+    ) -> Result<EntityAggregate> {
+        let mut sum_fields: Vec<String> = sum_fields
+            .iter()
+            .map(|field| field.trim().to_string())
+            .filter(|field| !field.is_empty())
+            .collect();
+        sum_fields.sort();
+        sum_fields.dedup();
+        validate_sum_fields(definition, &sum_fields)?;
+
+        self.repository
+            .upsert_config(&definition.entity_type, &sum_fields)
+            .await?;
+        self.repository.rebuild(&definition.entity_type).await
+    }
+
+    /// Disable aggregates for an entity type
+    ///
+    /// # Errors
+    /// Returns `NotFound` if aggregates were not enabled
+    pub async fn disable(&self, entity_type: &str) -> Result<()> {
+        if self.repository.delete(entity_type).await? {
+            Ok(())
+        } else {
+            Err(Error::NotFound(format!(
+                "Aggregates are not enabled for entity type '{entity_type}'"
+            )))
+        }
+    }
+
+    /// Recompute the aggregate of an entity type from its live entities
+    ///
+    /// # Errors
+    /// Returns `NotFound` if aggregates are not enabled, or a validation error if a summed field
+    /// was removed from the definition or is no longer numeric
+    pub async fn rebuild(&self, definition: &EntityDefinition) -> Result<EntityAggregate> {
+        let aggregate = self.get(&definition.entity_type).await?;
+        validate_sum_fields(definition, &aggregate.sum_fields)?;
+        self.repository.rebuild(&definition.entity_type).await
+    }
+}
+
+/// Every summed field must be a numeric field of the definition
+fn validate_sum_fields(definition: &EntityDefinition, sum_fields: &[String]) -> Result<()> {
+    for name in sum_fields {
+        match definition.get_field(name) {
+            Some(field) if field.field_type.is_numeric() => {}
+            Some(field) => {
+                return Err(Error::Validation(format!(
+                    "Field '{name}' of entity type '{}' is {} and cannot be summed",
+                    definition.entity_type, field.field_type
+                )));
+            }
+            None => {
+                return Err(Error::Validation(format!(
+                    "Field '{name}' does not exist on entity type '{}'",
+                    definition.entity_type
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl EntityChangeHook for EntityAggregateService {
+    async fn entity_updated(&self, before: &DynamicEntity, after: &DynamicEntity) -> Result<()> {
+        let delta = AggregateDelta::updated(&before.field_data, &after.field_data);
+        if delta.add.is_empty() && delta.subtract.is_empty() {
+            return Ok(());
+        }
+        self.repository
+            .apply_delta(&after.entity_type, &delta)
+            .await
+    }
+
+    async fn entity_created(&self, entity: &DynamicEntity) -> Result<()> {
+        self.repository
+            .apply_delta(
+                &entity.entity_type,
+                &AggregateDelta::created(&entity.field_data),
+            )
+            .await
+    }
+
+    async fn entity_deleted(&self, before: &DynamicEntity) -> Result<()> {
+        self.repository
+            .apply_delta(
+                &before.entity_type,
+                &AggregateDelta::deleted(&before.field_data),
+            )
+            .await
+    }
+}
@@ -0,0 +1,72 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use r_data_core_core::entity_definition::EntityDefinitionBundle;
+use r_data_core_core::error::{Error, Result};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::EntityDefinitionService;
+
+impl EntityDefinitionService {
+    /// Export an entity definition as a portable bundle
+    ///
+    /// # Errors
+    /// Returns an error if the entity definition is not found or the lookup fails
+    pub async fn export(&self, uuid: &Uuid) -> Result<EntityDefinitionBundle> {
+        let definition = self.get_entity_definition(uuid).await?;
+        Ok(EntityDefinitionBundle::from(&definition))
+    }
+
+    /// Import a bundle, creating its entity type or, with `overwrite`, replacing an existing one
+    ///
+    /// An overwritten definition keeps its UUID and creation data; its previous state is kept
+    /// as a version like for any update.
+    ///
+    /// # Returns
+    /// The UUID of the definition and whether it was created
+    ///
+    /// # Errors
+    /// Returns a validation error for unsupported bundle formats or invalid definitions, and
+    /// `Error::ClassAlreadyExists` if the entity type exists and `overwrite` is false
+    pub async fn import(
+        &self,
+        bundle: EntityDefinitionBundle,
+        overwrite: bool,
+        actor: Uuid,
+    ) -> Result<(Uuid, bool)> {
+        if bundle.format_version != EntityDefinitionBundle::FORMAT_VERSION {
+            return Err(Error::Validation(format!(
+                "Unsupported entity definition bundle format {} (expected {})",
+                bundle.format_version,
+                EntityDefinitionBundle::FORMAT_VERSION
+            )));
+        }
+
+        let existing = self
+            .repository
+            .get_by_entity_type(&bundle.entity_type)
+            .await?;
+        let mut definition = bundle.into_definition(actor);
+        definition.validate()?;
+
+        match existing {
+            None => {
+                let uuid = self.create_entity_definition(&definition).await?;
+                Ok((uuid, true))
+            }
+            Some(existing) if overwrite => {
+                definition.uuid = existing.uuid;
+                definition.created_at = existing.created_at;
+                definition.created_by = existing.created_by;
+                definition.updated_at = OffsetDateTime::now_utc();
+                self.update_entity_definition(&existing.uuid, &definition)
+                    .await?;
+                Ok((existing.uuid, false))
+            }
+            Some(existing) => Err(Error::ClassAlreadyExists(format!(
+                "Entity type '{}' already exists",
+                existing.entity_type
+            ))),
+        }
+    }
+}
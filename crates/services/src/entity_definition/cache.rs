@@ -1,5 +1,6 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use r_data_core_core::entity_definition::definition::EntityDefinition;
 use r_data_core_core::error::Result;
 use uuid::Uuid;
 
@@ -18,6 +19,53 @@ impl EntityDefinitionService {
         format!("entity_def:by_uuid:{uuid}")
     }
 
+    /// Store the effective definition by type and the stored one by UUID
+    ///
+    /// Entries have no TTL and stay cached until explicitly invalidated.
+    pub(crate) async fn cache_entity_definition(
+        &self,
+        definition: &EntityDefinition,
+        effective: &EntityDefinition,
+    ) {
+        let type_key = Self::cache_key_by_entity_type(&definition.entity_type);
+        let uuid_key = Self::cache_key_by_uuid(&definition.uuid);
+
+        if let Err(e) = self.cache_manager.set(&type_key, effective, None).await {
+            log::warn!("Failed to cache entity definition by type: {e}");
+        }
+        if let Err(e) = self.cache_manager.set(&uuid_key, definition, None).await {
+            log::warn!("Failed to cache entity definition by UUID: {e}");
+        }
+    }
+
+    /// Load all published entity definitions into the cache
+    ///
+    /// Avoids a database round-trip on the first request per entity type after startup.
+    ///
+    /// # Returns
+    /// The number of definitions cached
+    ///
+    /// # Errors
+    /// Returns an error if listing or resolving the definitions fails
+    pub async fn warm_cache(&self) -> Result<usize> {
+        const PAGE_SIZE: i64 = 100;
+
+        let mut warmed = 0;
+        let mut offset = 0;
+        loop {
+            let page = self.repository.list(PAGE_SIZE, offset).await?;
+            for definition in page.iter().filter(|d| d.published) {
+                let effective = self.resolve_inheritance(definition).await?;
+                self.cache_entity_definition(definition, &effective).await;
+                warmed += 1;
+            }
+            if i64::try_from(page.len()).unwrap_or(PAGE_SIZE) < PAGE_SIZE {
+                return Ok(warmed);
+            }
+            offset += PAGE_SIZE;
+        }
+    }
+
     /// Invalidate cache entries for an entity definition
     ///
     /// # Arguments
@@ -44,4 +92,18 @@ impl EntityDefinitionService {
 
         Ok(())
     }
+
+    /// Invalidate the by-type cache entries of all entity definitions
+    ///
+    /// Entries by type hold effective definitions, which change with any definition they
+    /// inherit from.
+    pub(crate) async fn invalidate_effective_definitions_cache(&self) {
+        if let Err(e) = self
+            .cache_manager
+            .delete_by_prefix(&Self::cache_key_by_entity_type(""))
+            .await
+        {
+            log::warn!("Failed to invalidate entity type cache keys: {e}");
+        }
+    }
 }
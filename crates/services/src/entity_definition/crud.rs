@@ -56,6 +56,8 @@ impl EntityDefinitionService {
 
     /// Get an entity definition by entity type
     ///
+    /// The definition includes the fields it inherits from the definitions it extends.
+    ///
     /// # Errors
     /// Returns an error if the entity definition is not found or database query fails
     pub async fn get_entity_definition_by_entity_type(
@@ -75,18 +77,11 @@ impl EntityDefinitionService {
             )));
         };
 
-        // Cache the result with both keys (no TTL - cache until explicitly invalidated)
-        let type_key = Self::cache_key_by_entity_type(entity_type);
-        let uuid_key = Self::cache_key_by_uuid(&definition.uuid);
+        let effective = self.resolve_inheritance(&definition).await?;
 
-        if let Err(e) = self.cache_manager.set(&type_key, &definition, None).await {
-            log::warn!("Failed to cache entity definition by type: {e}");
-        }
-        if let Err(e) = self.cache_manager.set(&uuid_key, &definition, None).await {
-            log::warn!("Failed to cache entity definition by UUID: {e}");
-        }
+        self.cache_entity_definition(&definition, &effective).await;
 
-        Ok(definition)
+        Ok(effective)
     }
 
     /// Create a new entity definition
@@ -99,6 +94,14 @@ impl EntityDefinitionService {
 
         // Validate field names and configurations
         Self::validate_fields(definition)?;
+        Self::validate_table_name(definition)?;
+
+        // Rejects unknown parents and inheritance cycles
+        let effective = self.resolve_inheritance(definition).await?;
+        Self::validate_publishable(&effective)?;
+        Self::validate_unique_constraints(&effective)?;
+        Self::validate_field_requirements(&effective)?;
+        Self::validate_validation_rules(&effective)?;
 
         // Check for duplicate entity type
         let existing = self
@@ -111,13 +114,14 @@ impl EntityDefinitionService {
                 definition.entity_type
             )));
         }
+        self.validate_table_name_available(definition, None).await?;
 
         // Create the entity definition
         let uuid = self.repository.create(definition).await?;
 
         // Create or update the database schema for this entity type
         self.repository
-            .update_entity_view_for_entity_definition(definition)
+            .update_entity_view_for_entity_definition(&effective)
             .await?;
 
         // Fetch the created definition from the database to get the correct UUID and all fields
@@ -130,10 +134,11 @@ impl EntityDefinitionService {
         // Cache the new definition with both keys (using the fetched definition with correct UUID)
         let type_key = Self::cache_key_by_entity_type(&created_definition.entity_type);
         let uuid_key = Self::cache_key_by_uuid(&uuid);
+        let created_effective = self.resolve_inheritance(&created_definition).await?;
 
         if let Err(e) = self
             .cache_manager
-            .set(&type_key, &created_definition, None)
+            .set(&type_key, &created_effective, None)
             .await
         {
             log::warn!("Failed to cache new entity definition by type: {e}");
@@ -157,6 +162,17 @@ impl EntityDefinitionService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_created(
+                    Some(definition.created_by),
+                    SystemLogResourceType::EntityDefinition,
+                    uuid,
+                    &created_definition,
+                )
+                .await;
+        }
+
         Ok(uuid)
     }
 
@@ -183,16 +199,39 @@ impl EntityDefinitionService {
         // Validate field names and configurations
         Self::validate_fields(definition)?;
 
-        // Invalidate old cache entries before update
+        // The entities would be left behind in the old table
+        if definition.table_name != existing.table_name {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "The table name of '{old_entity_type}' cannot be changed"
+            )));
+        }
+
+        // Rejects unknown parents and inheritance cycles
+        let effective = self
+            .resolve_inheritance(&EntityDefinition {
+                uuid: *uuid,
+                ..definition.clone()
+            })
+            .await?;
+        Self::validate_publishable(&effective)?;
+        Self::validate_unique_constraints(&effective)?;
+        Self::validate_field_requirements(&effective)?;
+        Self::validate_validation_rules(&effective)?;
+        self.validate_table_name_available(definition, Some(uuid))
+            .await?;
+
+        // Invalidate old cache entries before update, including the effective definitions
+        // of types inheriting from this one
         self.invalidate_entity_definition_cache(&old_entity_type, uuid)
             .await?;
+        self.invalidate_effective_definitions_cache().await;
 
         // Update the entity definition
         self.repository.update(uuid, definition).await?;
 
         // Update the database schema for this entity type
         self.repository
-            .update_entity_view_for_entity_definition(definition)
+            .update_entity_view_for_entity_definition(&effective)
             .await?;
 
         // Fetch the updated definition from the database to get the correct state
@@ -205,10 +244,11 @@ impl EntityDefinitionService {
         // Cache the updated definition with both keys (using the fetched definition)
         let type_key = Self::cache_key_by_entity_type(&updated_definition.entity_type);
         let uuid_key = Self::cache_key_by_uuid(uuid);
+        let updated_effective = self.resolve_inheritance(&updated_definition).await?;
 
         if let Err(e) = self
             .cache_manager
-            .set(&type_key, &updated_definition, None)
+            .set(&type_key, &updated_effective, None)
             .await
         {
             log::warn!("Failed to cache updated entity definition by type: {e}");
@@ -241,9 +281,206 @@ impl EntityDefinitionService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_updated(
+                    definition.updated_by,
+                    SystemLogResourceType::EntityDefinition,
+                    *uuid,
+                    Some(&existing),
+                    Some(&updated_definition),
+                )
+                .await;
+        }
+
         Ok(())
     }
 
+    /// Rename a field, keeping the data of its column
+    ///
+    /// The column is renamed in place and the updated definition stored in the same
+    /// transaction. With `rename_in_versions`, stored entity versions use the new name too, so
+    /// restoring them keeps the value.
+    ///
+    /// # Errors
+    /// Returns a not found error for unknown definitions, a validation error if `old_name` is
+    /// not a field or `new_name` is invalid, taken by another field or reserved for a system
+    /// field, and a database error if the rename fails
+    pub async fn rename_field(
+        &self,
+        uuid: &Uuid,
+        old_name: &str,
+        new_name: &str,
+        rename_in_versions: bool,
+        updated_by: Uuid,
+    ) -> Result<EntityDefinition> {
+        let Some(existing) = self.repository.get_by_uuid(uuid).await? else {
+            return Err(r_data_core_core::error::Error::NotFound(format!(
+                "Entity definition with UUID {uuid} not found"
+            )));
+        };
+
+        let Some(position) = existing.fields.iter().position(|f| f.name == old_name) else {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "Field '{old_name}' does not exist on '{}'",
+                existing.entity_type
+            )));
+        };
+        if existing
+            .fields
+            .iter()
+            .any(|f| f.name != old_name && f.name.eq_ignore_ascii_case(new_name))
+        {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "Field '{new_name}' already exists on '{}'",
+                existing.entity_type
+            )));
+        }
+
+        let mut renamed = existing.clone();
+        renamed.fields[position].name = new_name.to_string();
+        for name in renamed.unique_constraints.iter_mut().flatten() {
+            if name == old_name {
+                *name = new_name.to_string();
+            }
+        }
+        for rule in &mut renamed.validation_rules {
+            if let Ok(mut expression) = rule.parse() {
+                if expression.fields().any(|name| name == old_name) {
+                    expression.rename_field(old_name, new_name);
+                    rule.expression = expression.to_string();
+                }
+            }
+        }
+        renamed.updated_at = time::OffsetDateTime::now_utc();
+        renamed.updated_by = Some(updated_by);
+        // Rejects invalid names and system field names
+        Self::validate_fields(&renamed)?;
+
+        // Columns of inheriting types would be rebuilt under the new name without their data
+        let extending = self.extending_entity_types(uuid).await?;
+        if !extending.is_empty() {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "Fields of '{}' cannot be renamed while it is extended by {}",
+                existing.entity_type,
+                extending.join(", ")
+            )));
+        }
+
+        self.invalidate_entity_definition_cache(&existing.entity_type, uuid)
+            .await?;
+        self.repository
+            .rename_field(uuid, &renamed, old_name, new_name, rename_in_versions)
+            .await?;
+
+        if let Some(ref log) = self.system_log {
+            log.log_entity_updated(
+                Some(updated_by),
+                SystemLogResourceType::EntityDefinition,
+                *uuid,
+                &format!(
+                    "Field '{old_name}' of entity definition '{}' renamed to '{new_name}'",
+                    existing.entity_type
+                ),
+                Some(serde_json::json!({
+                    "entity_type": existing.entity_type,
+                    "old_name": old_name,
+                    "new_name": new_name,
+                    "rename_in_versions": rename_in_versions,
+                })),
+            )
+            .await;
+        }
+
+        let updated = self.repository.get_by_uuid(uuid).await?.ok_or_else(|| {
+            r_data_core_core::error::Error::NotFound(format!(
+                "Entity definition with UUID {uuid} not found after update"
+            ))
+        })?;
+
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_updated(
+                    Some(updated_by),
+                    SystemLogResourceType::EntityDefinition,
+                    *uuid,
+                    Some(&existing),
+                    Some(&updated),
+                )
+                .await;
+        }
+
+        Ok(updated)
+    }
+
+    /// Reorder the fields of an entity definition
+    ///
+    /// The listed fields come first, in the given order, followed by the remaining fields in
+    /// their current order. All fields are renumbered and the definition is stored in one
+    /// transaction; the entity table is not touched.
+    ///
+    /// # Errors
+    /// Returns a not found error for unknown definitions, a validation error if a name is not
+    /// a field of the definition or is listed twice, and a database error if the update fails
+    pub async fn reorder_fields(
+        &self,
+        uuid: &Uuid,
+        ordered_field_names: &[String],
+        updated_by: Uuid,
+    ) -> Result<EntityDefinition> {
+        let Some(existing) = self.repository.get_by_uuid(uuid).await? else {
+            return Err(r_data_core_core::error::Error::NotFound(format!(
+                "Entity definition with UUID {uuid} not found"
+            )));
+        };
+
+        let mut reordered = existing.clone();
+        reordered.reorder_fields(ordered_field_names)?;
+        reordered.updated_at = time::OffsetDateTime::now_utc();
+        reordered.updated_by = Some(updated_by);
+
+        self.invalidate_entity_definition_cache(&existing.entity_type, uuid)
+            .await?;
+        self.repository.update(uuid, &reordered).await?;
+
+        if let Some(ref log) = self.system_log {
+            log.log_entity_updated(
+                Some(updated_by),
+                SystemLogResourceType::EntityDefinition,
+                *uuid,
+                &format!(
+                    "Fields of entity definition '{}' reordered",
+                    existing.entity_type
+                ),
+                Some(serde_json::json!({
+                    "entity_type": existing.entity_type,
+                    "fields": reordered.fields.iter().map(|f| &f.name).collect::<Vec<_>>(),
+                })),
+            )
+            .await;
+        }
+
+        let updated = self.repository.get_by_uuid(uuid).await?.ok_or_else(|| {
+            r_data_core_core::error::Error::NotFound(format!(
+                "Entity definition with UUID {uuid} not found after update"
+            ))
+        })?;
+
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_updated(
+                    Some(updated_by),
+                    SystemLogResourceType::EntityDefinition,
+                    *uuid,
+                    Some(&existing),
+                    Some(&updated),
+                )
+                .await;
+        }
+
+        Ok(updated)
+    }
+
     /// Delete an entity definition
     ///
     /// # Errors
@@ -270,6 +507,14 @@ impl EntityDefinitionService {
             }
         }
 
+        let extending = self.extending_entity_types(uuid).await?;
+        if !extending.is_empty() {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "Cannot delete entity definition that is extended by {}",
+                extending.join(", ")
+            )));
+        }
+
         // Delete the entity definition and associated tables
         self.repository.delete(uuid).await?;
 
@@ -288,6 +533,17 @@ impl EntityDefinitionService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_deleted(
+                    Some(actor_uuid),
+                    SystemLogResourceType::EntityDefinition,
+                    *uuid,
+                    Some(&def),
+                )
+                .await;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,55 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::{Error, Result};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::EntityDefinitionService;
+
+impl EntityDefinitionService {
+    /// Resolve the fields `definition` inherits from the definitions it extends
+    ///
+    /// Ancestors are read in their stored state, so the result is the effective definition
+    /// used for entity validation and table creation.
+    ///
+    /// # Errors
+    /// Returns a validation error if a parent does not exist or the definitions extend each
+    /// other in a cycle, and a database error if an ancestor cannot be loaded
+    pub async fn resolve_inheritance(
+        &self,
+        definition: &EntityDefinition,
+    ) -> Result<EntityDefinition> {
+        let mut ancestors = HashMap::new();
+        let mut pending = definition.extends.clone();
+        while let Some(uuid) = pending.pop() {
+            if uuid == definition.uuid || ancestors.contains_key(&uuid) {
+                continue;
+            }
+            match self.get_entity_definition(&uuid).await {
+                Ok(parent) => {
+                    pending.extend(parent.extends.iter().copied());
+                    ancestors.insert(uuid, parent);
+                }
+                // Reported as an unknown parent while resolving
+                Err(Error::NotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        definition.resolve_inheritance(&ancestors)
+    }
+
+    /// Entity types of the definitions directly extending the definition `uuid`
+    ///
+    /// # Errors
+    /// Returns an error if the definitions cannot be listed
+    pub(crate) async fn extending_entity_types(&self, uuid: &Uuid) -> Result<Vec<String>> {
+        Ok(self
+            .list_entity_definitions(1000, 0)
+            .await?
+            .into_iter()
+            .filter(|definition| definition.extends.contains(uuid))
+            .map(|definition| definition.entity_type)
+            .collect())
+    }
+}
@@ -0,0 +1,172 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::Result;
+use r_data_core_core::field::types::FieldType;
+use r_data_core_core::field::FieldDefinition;
+use serde_json::{json, Map, Value};
+
+use super::EntityDefinitionService;
+
+/// JSON Schema dialect of the generated schemas
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl EntityDefinitionService {
+    /// JSON Schema describing the fields of entities of `entity_type`
+    ///
+    /// Includes inherited fields. Fixed select options become `enum`s, length, range and
+    /// pattern constraints their JSON Schema keywords, and conditional requirements
+    /// `if`/`then` clauses.
+    ///
+    /// # Errors
+    /// Returns an error if the entity type is not found or database query fails
+    pub async fn json_schema(&self, entity_type: &str) -> Result<Value> {
+        let definition = self
+            .get_entity_definition_by_entity_type(entity_type)
+            .await?;
+        Ok(entity_schema(&definition))
+    }
+}
+
+fn entity_schema(definition: &EntityDefinition) -> Value {
+    let properties: Map<String, Value> = definition
+        .fields
+        .iter()
+        .map(|field| (field.name.clone(), field_schema(field)))
+        .collect();
+    let required: Vec<&str> = definition
+        .fields
+        .iter()
+        .filter(|field| field.required)
+        .map(|field| field.name.as_str())
+        .collect();
+
+    let mut schema = json!({
+        "$schema": SCHEMA_DIALECT,
+        "title": definition.display_name,
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    });
+    if let Some(description) = &definition.description {
+        schema["description"] = json!(description);
+    }
+
+    let conditions: Vec<Value> = definition
+        .fields
+        .iter()
+        .filter_map(|field| {
+            let requirement = field.validation.requires.as_ref()?;
+            Some(json!({
+                "if": {
+                    "properties": { &requirement.field: { "const": requirement.equals } },
+                    "required": [requirement.field],
+                },
+                "then": { "required": [field.name] },
+            }))
+        })
+        .collect();
+    if !conditions.is_empty() {
+        schema["allOf"] = Value::Array(conditions);
+    }
+    schema
+}
+
+fn field_schema(field: &FieldDefinition) -> Value {
+    let validation = &field.validation;
+    let mut schema = type_schema(field);
+
+    schema.insert("title".to_string(), json!(field.display_name));
+    if let Some(description) = &field.description {
+        schema.insert("description".to_string(), json!(description));
+    }
+    if let Some(default) = &field.default_value {
+        schema.insert("default".to_string(), default.clone());
+    }
+
+    match field.field_type {
+        FieldType::String | FieldType::Text | FieldType::Wysiwyg | FieldType::Password => {
+            if let Some(min) = validation.min_length {
+                schema.insert("minLength".to_string(), json!(min));
+            }
+            if let Some(max) = validation.max_length {
+                schema.insert("maxLength".to_string(), json!(max));
+            }
+            if let Some(pattern) = &validation.pattern {
+                schema.insert("pattern".to_string(), json!(pattern));
+            }
+            let format = match field.ui_settings.input_type.as_deref() {
+                Some("email") => Some("email"),
+                Some("url") => Some("uri"),
+                _ => None,
+            };
+            if let Some(format) = format {
+                schema.insert("format".to_string(), json!(format));
+            }
+        }
+        FieldType::Integer | FieldType::Float => {
+            if let Some(min) = validation.min_value.as_ref().filter(|v| v.is_number()) {
+                schema.insert("minimum".to_string(), min.clone());
+            } else if validation.positive_only == Some(true) {
+                schema.insert("exclusiveMinimum".to_string(), json!(0));
+            }
+            if let Some(max) = validation.max_value.as_ref().filter(|v| v.is_number()) {
+                schema.insert("maximum".to_string(), max.clone());
+            }
+        }
+        FieldType::Select => {
+            if let Some(options) = validation.allowed_options() {
+                schema.insert("enum".to_string(), json!(options));
+            }
+        }
+        FieldType::MultiSelect => {
+            if let Some(options) = validation.allowed_options() {
+                schema.insert(
+                    "items".to_string(),
+                    json!({ "type": "string", "enum": options }),
+                );
+            }
+        }
+        _ => {}
+    }
+
+    Value::Object(schema)
+}
+
+/// Type and format of a field's values, without its constraints
+fn type_schema(field: &FieldDefinition) -> Map<String, Value> {
+    let schema = match field.field_type {
+        FieldType::String | FieldType::Text | FieldType::Wysiwyg | FieldType::Select => {
+            json!({ "type": "string" })
+        }
+        FieldType::Password => json!({ "type": "string", "writeOnly": true }),
+        FieldType::Integer => json!({ "type": "integer" }),
+        FieldType::Float => json!({ "type": "number" }),
+        FieldType::Money => json!({
+            "type": "object",
+            "properties": {
+                "amount": { "type": ["number", "string"] },
+                "currency": { "type": "string", "pattern": "^[A-Z]{3}$" },
+            },
+            "required": ["amount", "currency"],
+        }),
+        FieldType::Boolean => json!({ "type": "boolean" }),
+        FieldType::DateTime => json!({ "type": "string", "format": "date-time" }),
+        FieldType::Date => json!({ "type": "string", "format": "date" }),
+        FieldType::Duration => json!({ "type": "string", "format": "duration" }),
+        FieldType::Object => json!({ "type": "object" }),
+        FieldType::Array => json!({ "type": "array" }),
+        FieldType::Uuid | FieldType::ManyToOne => json!({ "type": "string", "format": "uuid" }),
+        FieldType::ManyToMany => json!({
+            "type": "array",
+            "items": { "type": "string", "format": "uuid" },
+        }),
+        FieldType::MultiSelect => json!({ "type": "array", "items": { "type": "string" } }),
+        // Any JSON value; assets are not validated on write either
+        FieldType::Json | FieldType::Image | FieldType::File => json!({}),
+    };
+    match schema {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    }
+}
@@ -1,8 +1,11 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+mod bundle;
 mod cache;
 mod crud;
 mod fields;
+mod inheritance;
+mod json_schema;
 mod schema;
 mod validation;
 
@@ -13,7 +16,7 @@ use r_data_core_core::cache::CacheManager;
 use r_data_core_core::entity_definition::repository_trait::EntityDefinitionRepositoryTrait;
 use std::sync::Arc;
 
-use crate::SystemLogService;
+use crate::{AuditLogService, SystemLogService};
 
 /// Service for managing entity definitions
 #[derive(Clone)]
@@ -21,6 +24,7 @@ pub struct EntityDefinitionService {
     repository: Arc<dyn EntityDefinitionRepositoryTrait>,
     cache_manager: Arc<CacheManager>,
     pub(crate) system_log: Option<Arc<SystemLogService>>,
+    pub(crate) audit_log: Option<Arc<AuditLogService>>,
 }
 
 /// Helper structure describing an entity field (including system fields)
@@ -51,6 +55,7 @@ impl EntityDefinitionService {
             repository,
             cache_manager,
             system_log: None,
+            audit_log: None,
         }
     }
 
@@ -60,18 +65,23 @@ impl EntityDefinitionService {
     /// * `repository` - Repository for entity definitions
     #[must_use]
     pub fn new_without_cache(repository: Arc<dyn EntityDefinitionRepositoryTrait>) -> Self {
-        use r_data_core_core::config::CacheConfig;
+        use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
         let config = CacheConfig {
             enabled: false,
             ttl: 3600,
             max_size: 10000,
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
         };
         Self {
             repository,
             cache_manager: Arc::new(CacheManager::new(config)),
             system_log: None,
+            audit_log: None,
         }
     }
 
@@ -81,4 +91,11 @@ impl EntityDefinitionService {
         self.system_log = Some(log);
         self
     }
+
+    /// Set the audit log service recording before/after snapshots of mutations
+    #[must_use]
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
 }
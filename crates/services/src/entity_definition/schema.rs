@@ -1,5 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::entity_definition::SchemaDiff;
 use r_data_core_core::error::Result;
 use uuid::Uuid;
 
@@ -14,6 +16,20 @@ impl EntityDefinitionService {
         self.repository.cleanup_unused_entity_view().await
     }
 
+    /// Preview the column changes applying `definition` would make, without applying them
+    ///
+    /// # Errors
+    /// Returns an error if the inherited fields cannot be resolved or the current table columns
+    /// cannot be read
+    pub async fn preview_schema(&self, definition: &EntityDefinition) -> Result<SchemaDiff> {
+        let effective = self.resolve_inheritance(definition).await?;
+        let columns = self
+            .repository
+            .get_view_columns_with_types(&definition.get_table_name())
+            .await?;
+        Ok(SchemaDiff::compute(&effective, &columns))
+    }
+
     /// Apply database schema for a specific entity definition or all if uuid is None
     ///
     /// # Errors
@@ -24,7 +40,9 @@ impl EntityDefinitionService {
     ) -> Result<(i32, Vec<(String, Uuid, String)>)> {
         if let Some(id) = uuid {
             // Apply schema for a specific entity definition
-            let definition = self.get_entity_definition(id).await?;
+            let definition = self
+                .resolve_inheritance(&self.get_entity_definition(id).await?)
+                .await?;
 
             match self
                 .repository
@@ -48,6 +66,17 @@ impl EntityDefinitionService {
             let mut failed = Vec::new();
 
             for definition in definitions {
+                let definition = match self.resolve_inheritance(&definition).await {
+                    Ok(effective) => effective,
+                    Err(e) => {
+                        failed.push((
+                            definition.entity_type.clone(),
+                            definition.uuid,
+                            e.to_string(),
+                        ));
+                        continue;
+                    }
+                };
                 match self
                     .repository
                     .update_entity_view_for_entity_definition(&definition)
@@ -9,7 +9,7 @@ use r_data_core_core::entity_definition::schema::Schema;
 use r_data_core_core::error::Result;
 use r_data_core_core::field::types::FieldType;
 use r_data_core_core::field::ui::UiSettings;
-use r_data_core_core::field::{FieldDefinition, FieldValidation};
+use r_data_core_core::field::{FieldDefinition, FieldRequirement, FieldValidation};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -28,6 +28,7 @@ mock! {
         async fn get_by_entity_type(&self, entity_type: &str) -> r_data_core_core::error::Result<Option<EntityDefinition>>;
         async fn create(&self, definition: &EntityDefinition) -> r_data_core_core::error::Result<Uuid>;
         async fn update(&self, uuid: &Uuid, definition: &EntityDefinition) -> r_data_core_core::error::Result<()>;
+        async fn rename_field(&self, uuid: &Uuid, definition: &EntityDefinition, old_name: &str, new_name: &str, rename_in_versions: bool) -> r_data_core_core::error::Result<()>;
         async fn delete(&self, uuid: &Uuid) -> r_data_core_core::error::Result<()>;
         async fn apply_schema(&self, schema_sql: &str) -> r_data_core_core::error::Result<()>;
         async fn update_entity_view_for_entity_definition(&self, entity_definition: &EntityDefinition) -> r_data_core_core::error::Result<()>;
@@ -35,6 +36,8 @@ mock! {
         async fn get_view_columns_with_types(&self, view_name: &str) -> r_data_core_core::error::Result<HashMap<String, String>>;
         async fn count_view_records(&self, view_name: &str) -> r_data_core_core::error::Result<i64>;
         async fn cleanup_unused_entity_view(&self) -> r_data_core_core::error::Result<()>;
+        async fn find_table_owner(&self, table_name: &str, exclude: Option<Uuid>) -> r_data_core_core::error::Result<Option<String>>;
+        async fn is_foreign_table(&self, table_name: &str) -> r_data_core_core::error::Result<bool>;
     }
 }
 
@@ -57,6 +60,7 @@ fn create_test_entity_definition() -> EntityDefinition {
             ui_settings: UiSettings::default(),
             constraints: HashMap::default(),
             validation: FieldValidation::default(),
+            display_order: 0,
         },
         FieldDefinition {
             name: "age".to_string(),
@@ -71,6 +75,7 @@ fn create_test_entity_definition() -> EntityDefinition {
             ui_settings: UiSettings::default(),
             constraints: HashMap::default(),
             validation: FieldValidation::default(),
+            display_order: 0,
         },
     ];
 
@@ -92,6 +97,10 @@ fn create_test_entity_definition() -> EntityDefinition {
         allow_children: false,
         icon: None,
         fields: field_definitions,
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: Schema::new(properties),
         created_at: now,
         updated_at: now,
@@ -175,6 +184,11 @@ async fn test_create_entity_definition_success() -> Result<()> {
         .with(eq(expected_uuid))
         .returning(move |_| Ok(Some(definition_clone.clone())));
 
+    mock_repo.expect_list().returning(|_, _| Ok(vec![]));
+    mock_repo
+        .expect_find_table_owner()
+        .returning(|_, _| Ok(None));
+    mock_repo.expect_is_foreign_table().returning(|_| Ok(false));
     let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
     let result = service.create_entity_definition(&definition).await?;
 
@@ -240,6 +254,7 @@ async fn test_create_entity_definition_duplicate_field_names() -> Result<()> {
         ui_settings: UiSettings::default(),
         constraints: HashMap::default(),
         validation: FieldValidation::default(),
+        display_order: 0,
     });
 
     let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
@@ -255,6 +270,94 @@ async fn test_create_entity_definition_duplicate_field_names() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_create_entity_definition_invalid_unique_constraints() -> Result<()> {
+    let service =
+        EntityDefinitionService::new_without_cache(Arc::new(MockEntityDefinitionRepo::new()));
+
+    for (fields, message) in [
+        (vec!["name"], "at least two fields"),
+        (vec!["name", "email"], "unknown field 'email'"),
+        (vec!["name", "Name"], "more than once"),
+    ] {
+        let mut definition = create_test_entity_definition();
+        definition.unique_constraints = vec![fields.iter().map(ToString::to_string).collect()];
+        match service.create_entity_definition(&definition).await {
+            Err(r_data_core_core::error::Error::Validation(msg)) => {
+                assert!(msg.contains(message), "{msg}");
+            }
+            other => panic!("Expected validation error, got {other:?}"),
+        }
+    }
+
+    let mut definition = create_test_entity_definition();
+    definition.unique_constraints = vec![
+        vec!["name".to_string(), "age".to_string()],
+        vec!["age".to_string(), "name".to_string()],
+    ];
+    let result = service.create_entity_definition(&definition).await;
+    assert!(
+        matches!(&result, Err(r_data_core_core::error::Error::Validation(msg)) if msg.contains("defined more than once")),
+        "{result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_entity_definition_invalid_requires_rule() -> Result<()> {
+    let service =
+        EntityDefinitionService::new_without_cache(Arc::new(MockEntityDefinitionRepo::new()));
+
+    for (dependency, message) in [
+        ("needs_shipping", "requires unknown field 'needs_shipping'"),
+        ("age", "cannot require itself"),
+    ] {
+        let mut definition = create_test_entity_definition();
+        definition.fields[1].validation.requires = Some(FieldRequirement {
+            field: dependency.to_string(),
+            equals: serde_json::json!(true),
+        });
+        match service.create_entity_definition(&definition).await {
+            Err(r_data_core_core::error::Error::Validation(msg)) => {
+                assert!(msg.contains(message), "{msg}");
+            }
+            other => panic!("Expected validation error, got {other:?}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_entity_definition_invalid_validation_rules() -> Result<()> {
+    use r_data_core_core::entity_definition::ValidationRule;
+
+    let service =
+        EntityDefinitionService::new_without_cache(Arc::new(MockEntityDefinitionRepo::new()));
+
+    for (expression, message) in [
+        ("end_date >= name", "unknown field 'end_date'"),
+        ("age >=", "Malformed expression"),
+        ("1 < 2", "must reference at least one field"),
+    ] {
+        let mut definition = create_test_entity_definition();
+        definition.validation_rules = vec![ValidationRule {
+            name: "rule".to_string(),
+            expression: expression.to_string(),
+            message: "Rule violated".to_string(),
+        }];
+        match service.create_entity_definition(&definition).await {
+            Err(r_data_core_core::error::Error::Validation(msg)) => {
+                assert!(msg.contains(message), "{msg}");
+            }
+            other => panic!("Expected validation error, got {other:?}"),
+        }
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_delete_entity_definition_with_records() -> Result<()> {
     let mut mock_repo = MockEntityDefinitionRepo::new();
@@ -298,6 +401,7 @@ async fn test_delete_entity_definition_success() -> Result<()> {
     mock_repo.expect_check_view_exists().returning(|_| Ok(true));
 
     mock_repo.expect_count_view_records().returning(|_| Ok(0)); // No records
+    mock_repo.expect_list().returning(|_, _| Ok(vec![]));
 
     mock_repo
         .expect_delete()
@@ -430,6 +534,10 @@ async fn test_update_entity_definition_success() -> Result<()> {
             Ok(Some(def))
         });
 
+    mock_repo.expect_list().returning(|_, _| Ok(vec![]));
+    mock_repo
+        .expect_find_table_owner()
+        .returning(|_, _| Ok(None));
     mock_repo
         .expect_update()
         .withf(move |id, _| id == &uuid)
@@ -517,6 +625,68 @@ async fn test_update_entity_definition_invalid_entity_type() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_update_rejects_publishing_fieldless_draft() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let mut draft = create_test_entity_definition();
+    draft.fields.clear();
+    let uuid = draft.uuid;
+
+    let stored = draft.clone();
+    mock_repo
+        .expect_get_by_uuid()
+        .returning(move |_| Ok(Some(stored.clone())));
+    mock_repo.expect_update().never();
+    mock_repo
+        .expect_update_entity_view_for_entity_definition()
+        .never();
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    draft.published = true;
+    match service.update_entity_definition(&uuid, &draft).await {
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            assert!(msg.contains("at least one non-system field"), "{msg}");
+        }
+        other => panic!("Expected validation error, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_publishes_draft_with_inherited_fields() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let mut parent = create_test_entity_definition();
+    parent.entity_type = "Audited".to_string();
+    let mut draft = create_test_entity_definition();
+    draft.fields.clear();
+    draft.extends = vec![parent.uuid];
+    let (parent_uuid, uuid) = (parent.uuid, draft.uuid);
+
+    let stored = draft.clone();
+    mock_repo.expect_get_by_uuid().returning(move |id| {
+        Ok(if *id == parent_uuid {
+            Some(parent.clone())
+        } else {
+            Some(stored.clone())
+        })
+    });
+    mock_repo.expect_list().returning(|_, _| Ok(vec![]));
+    mock_repo
+        .expect_find_table_owner()
+        .returning(|_, _| Ok(None));
+    mock_repo.expect_update().times(1).returning(|_, _| Ok(()));
+    mock_repo
+        .expect_update_entity_view_for_entity_definition()
+        .returning(|_| Ok(()));
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    draft.published = true;
+    service.update_entity_definition(&uuid, &draft).await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_cleanup_unused_entity_tables() -> Result<()> {
     let mut mock_repo = MockEntityDefinitionRepo::new();
@@ -532,3 +702,490 @@ async fn test_cleanup_unused_entity_tables() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_preview_schema_diffs_table_columns() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let mut definition = create_test_entity_definition();
+    definition.fields.remove(1);
+    definition.fields[0].field_type = FieldType::Text;
+    let table_name = definition.get_table_name();
+
+    mock_repo
+        .expect_get_view_columns_with_types()
+        .with(eq(table_name))
+        .returning(|_| {
+            Ok(HashMap::from([
+                ("uuid".to_string(), "uuid".to_string()),
+                ("name".to_string(), "character varying".to_string()),
+                ("age".to_string(), "integer".to_string()),
+            ]))
+        });
+    mock_repo
+        .expect_update_entity_view_for_entity_definition()
+        .never();
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    let diff = service.preview_schema(&definition).await?;
+
+    assert_eq!(diff.changes.len(), 2);
+    assert!(diff.has_destructive_changes());
+    assert!(diff
+        .statements
+        .iter()
+        .any(|s| s.ends_with("DROP COLUMN IF EXISTS age")));
+    assert!(diff
+        .statements
+        .iter()
+        .any(|s| s.ends_with("ALTER COLUMN name TYPE TEXT")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rename_field_renames_definition_field() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let uuid = Uuid::now_v7();
+    let actor = Uuid::now_v7();
+    let mut definition = create_test_entity_definition();
+    definition.unique_constraints = vec![vec!["name".to_string(), "age".to_string()]];
+    let mut renamed = definition.clone();
+    renamed.fields[1].name = "years".to_string();
+
+    mock_repo
+        .expect_get_by_uuid()
+        .times(1)
+        .returning(move |_| Ok(Some(definition.clone())));
+    mock_repo.expect_list().returning(|_, _| Ok(vec![]));
+    mock_repo
+        .expect_rename_field()
+        .withf(move |id, def, old_name, new_name, in_versions| {
+            id == &uuid
+                && def.fields[1].name == "years"
+                && def.unique_constraints == vec![vec!["name".to_string(), "years".to_string()]]
+                && def.updated_by == Some(actor)
+                && old_name == "age"
+                && new_name == "years"
+                && *in_versions
+        })
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(()));
+    mock_repo
+        .expect_get_by_uuid()
+        .times(1)
+        .returning(move |_| Ok(Some(renamed.clone())));
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    let updated = service
+        .rename_field(&uuid, "age", "years", true, actor)
+        .await?;
+
+    assert!(updated.get_field("years").is_some());
+    assert!(updated.get_field("age").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rename_field_rejects_collisions() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let definition = create_test_entity_definition();
+    mock_repo
+        .expect_get_by_uuid()
+        .returning(move |_| Ok(Some(definition.clone())));
+    mock_repo.expect_rename_field().never();
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    let uuid = Uuid::now_v7();
+
+    for (old_name, new_name, message) in [
+        ("age", "Name", "already exists"),
+        ("age", "created_at", "reserved for a system field"),
+        ("age", "1st", "must start with a letter"),
+        ("missing", "other", "does not exist"),
+    ] {
+        let result = service
+            .rename_field(&uuid, old_name, new_name, false, Uuid::now_v7())
+            .await;
+        match result {
+            Err(r_data_core_core::error::Error::Validation(msg)) => {
+                assert!(msg.contains(message), "{msg}");
+            }
+            other => panic!("Expected validation error, got {other:?}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reorder_fields_changes_returned_field_order() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let uuid = Uuid::now_v7();
+    let stored = Arc::new(std::sync::Mutex::new(create_test_entity_definition()));
+
+    let current = stored.clone();
+    mock_repo
+        .expect_get_by_uuid()
+        .times(2)
+        .returning(move |_| Ok(Some(current.lock().unwrap().clone())));
+    let updated = stored.clone();
+    mock_repo.expect_update().times(1).returning(move |_, def| {
+        *updated.lock().unwrap() = def.clone();
+        Ok(())
+    });
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    let reordered = service
+        .reorder_fields(&uuid, &["age".to_string()], Uuid::now_v7())
+        .await?;
+
+    let order: Vec<(&str, i32)> = reordered
+        .fields
+        .iter()
+        .map(|f| (f.name.as_str(), f.display_order))
+        .collect();
+    assert_eq!(order, vec![("age", 1), ("name", 2)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reorder_fields_rejects_unknown_field() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let definition = create_test_entity_definition();
+    mock_repo
+        .expect_get_by_uuid()
+        .returning(move |_| Ok(Some(definition.clone())));
+    mock_repo.expect_update().never();
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    let result = service
+        .reorder_fields(
+            &Uuid::now_v7(),
+            &["age".to_string(), "missing".to_string()],
+            Uuid::now_v7(),
+        )
+        .await;
+
+    match result {
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            assert!(msg.contains("'missing' does not exist"), "{msg}");
+        }
+        other => panic!("Expected validation error, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_rejects_invalid_or_taken_table_names() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    mock_repo
+        .expect_get_by_entity_type()
+        .returning(|_| Ok(None));
+    mock_repo
+        .expect_find_table_owner()
+        .returning(|table_name, _| {
+            Ok((table_name == "entity_customer").then(|| "customer".to_string()))
+        });
+    mock_repo
+        .expect_is_foreign_table()
+        .returning(|table_name| Ok(table_name == "entities_registry"));
+    mock_repo.expect_create().never();
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+
+    for (table_name, message) in [
+        ("entity_customer", "already used by entity type 'customer'"),
+        ("Legacy-Table", "Invalid SQL identifier"),
+        ("legacy_view", "must not end in '_view'"),
+        ("entities_registry", "is not an entity table"),
+    ] {
+        let mut definition = create_test_entity_definition();
+        definition.table_name = Some(table_name.to_string());
+        match service.create_entity_definition(&definition).await {
+            Err(r_data_core_core::error::Error::Validation(msg)) => {
+                assert!(msg.contains(message), "{msg}");
+            }
+            other => panic!("Expected validation error for {table_name}, got {other:?}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_export_drops_environment_specific_data() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let definition = create_test_entity_definition();
+    let expected = definition.clone();
+    mock_repo
+        .expect_get_by_uuid()
+        .returning(move |_| Ok(Some(definition.clone())));
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    let bundle = service.export(&expected.uuid).await?;
+
+    assert_eq!(
+        bundle.format_version,
+        r_data_core_core::entity_definition::EntityDefinitionBundle::FORMAT_VERSION
+    );
+    assert_eq!(bundle.entity_type, expected.entity_type);
+    assert_eq!(bundle.fields.len(), expected.fields.len());
+    let json = serde_json::to_value(&bundle)?;
+    for key in ["uuid", "created_at", "created_by", "version", "schema"] {
+        assert!(json.get(key).is_none(), "{key} should not be exported");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_import_existing_entity_type_requires_overwrite() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let existing = create_test_entity_definition();
+    let existing_uuid = existing.uuid;
+    let existing_creator = existing.created_by;
+    let bundle = r_data_core_core::entity_definition::EntityDefinitionBundle::from(&existing);
+    let stored = existing.clone();
+    mock_repo
+        .expect_get_by_entity_type()
+        .returning(move |_| Ok(Some(existing.clone())));
+    mock_repo
+        .expect_get_by_uuid()
+        .returning(move |_| Ok(Some(stored.clone())));
+    mock_repo
+        .expect_update_entity_view_for_entity_definition()
+        .returning(|_| Ok(()));
+    mock_repo
+        .expect_update()
+        .withf(move |id, def| {
+            id == &existing_uuid && def.uuid == existing_uuid && def.created_by == existing_creator
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mock_repo.expect_list().returning(|_, _| Ok(vec![]));
+    mock_repo
+        .expect_find_table_owner()
+        .returning(|_, _| Ok(None));
+    mock_repo.expect_create().never();
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+
+    let result = service.import(bundle.clone(), false, Uuid::now_v7()).await;
+    assert!(matches!(
+        result,
+        Err(r_data_core_core::error::Error::ClassAlreadyExists(_))
+    ));
+
+    let (uuid, created) = service.import(bundle, true, Uuid::now_v7()).await?;
+    assert_eq!(uuid, existing_uuid);
+    assert!(!created);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_import_rejects_unsupported_format() {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    mock_repo.expect_get_by_entity_type().never();
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+
+    let mut bundle = r_data_core_core::entity_definition::EntityDefinitionBundle::from(
+        &create_test_entity_definition(),
+    );
+    bundle.format_version = 99;
+
+    let result = service.import(bundle, false, Uuid::now_v7()).await;
+    assert!(matches!(
+        result,
+        Err(r_data_core_core::error::Error::Validation(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_get_by_entity_type_resolves_inherited_fields() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let mut parent = create_test_entity_definition();
+    parent.entity_type = "Audited".to_string();
+    parent.fields.truncate(1);
+    parent.fields[0].name = "reviewed_by".to_string();
+    let mut child = create_test_entity_definition();
+    child.extends = vec![parent.uuid];
+    let parent_uuid = parent.uuid;
+
+    mock_repo
+        .expect_get_by_entity_type()
+        .with(eq("TestEntity"))
+        .returning(move |_| Ok(Some(child.clone())));
+    mock_repo
+        .expect_get_by_uuid()
+        .withf(move |id| id == &parent_uuid)
+        .returning(move |_| Ok(Some(parent.clone())));
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    let effective = service
+        .get_entity_definition_by_entity_type("TestEntity")
+        .await?;
+
+    let names: Vec<&str> = effective.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["reviewed_by", "name", "age"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_rejects_inheritance_cycle() -> Result<()> {
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let parent = create_test_entity_definition();
+    let mut child = create_test_entity_definition();
+    child.entity_type = "ChildEntity".to_string();
+    child.extends = vec![parent.uuid];
+    let (parent_uuid, child_uuid) = (parent.uuid, child.uuid);
+
+    let stored_parent = parent.clone();
+    mock_repo.expect_get_by_uuid().returning(move |id| {
+        Ok(if *id == parent_uuid {
+            Some(stored_parent.clone())
+        } else if *id == child_uuid {
+            Some(child.clone())
+        } else {
+            None
+        })
+    });
+    mock_repo.expect_update().never();
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    let mut updated = parent;
+    updated.extends = vec![child_uuid];
+
+    let result = service
+        .update_entity_definition(&parent_uuid, &updated)
+        .await;
+    match result {
+        Err(r_data_core_core::error::Error::Validation(msg)) => {
+            assert!(
+                msg.contains("TestEntity -> ChildEntity -> TestEntity"),
+                "{msg}"
+            );
+        }
+        other => panic!("Expected validation error, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_warm_cache_serves_published_definitions_from_cache() -> Result<()> {
+    use r_data_core_core::cache::CacheManager;
+    use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
+
+    let mut published = create_test_entity_definition();
+    published.entity_type = "published_type".to_string();
+    published.published = true;
+    let mut draft = create_test_entity_definition();
+    draft.entity_type = "draft_type".to_string();
+    draft.published = false;
+
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let definitions = vec![published.clone(), draft];
+    mock_repo
+        .expect_list()
+        .times(1)
+        .returning(move |_, _| Ok(definitions.clone()));
+    // Lookups of warmed definitions must not reach the repository
+    mock_repo
+        .expect_get_by_entity_type()
+        .with(eq("published_type"))
+        .never();
+    mock_repo
+        .expect_get_by_entity_type()
+        .with(eq("draft_type"))
+        .times(1)
+        .returning(|_| Ok(None));
+
+    let cache_manager = Arc::new(CacheManager::new(CacheConfig {
+        enabled: true,
+        ttl: 3600,
+        max_size: 100,
+        entity_definition_ttl: 0,
+        api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: true,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
+    }));
+    let service = EntityDefinitionService::new(Arc::new(mock_repo), cache_manager);
+
+    assert_eq!(service.warm_cache().await?, 1);
+
+    let cached = service
+        .get_entity_definition_by_entity_type("published_type")
+        .await?;
+    assert_eq!(cached.uuid, published.uuid);
+    assert!(service
+        .get_entity_definition_by_entity_type("draft_type")
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_schema_reflects_field_constraints() -> Result<()> {
+    use r_data_core_core::field::{OptionsSource, SelectOption};
+
+    let mut mock_repo = MockEntityDefinitionRepo::new();
+    let mut definition = create_test_entity_definition();
+    definition.fields[1].validation = FieldValidation {
+        min_value: Some(serde_json::json!(0)),
+        max_value: Some(serde_json::json!(150)),
+        ..FieldValidation::default()
+    };
+    let mut status = definition.fields[0].clone();
+    status.name = "status".to_string();
+    status.display_name = "Status".to_string();
+    status.field_type = FieldType::Select;
+    status.required = false;
+    status.validation = FieldValidation {
+        options_source: Some(OptionsSource::Fixed {
+            options: ["draft", "active"]
+                .into_iter()
+                .map(|value| SelectOption {
+                    value: value.to_string(),
+                    label: value.to_string(),
+                })
+                .collect(),
+        }),
+        ..FieldValidation::default()
+    };
+    let mut email = definition.fields[0].clone();
+    email.name = "email".to_string();
+    email.required = false;
+    email.ui_settings.input_type = Some("email".to_string());
+    definition.fields.extend([status, email]);
+
+    mock_repo
+        .expect_get_by_entity_type()
+        .with(eq("TestEntity"))
+        .returning(move |_| Ok(Some(definition.clone())));
+
+    let service = EntityDefinitionService::new_without_cache(Arc::new(mock_repo));
+    let schema = service.json_schema("TestEntity").await?;
+
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["title"], "Test Entity");
+    assert_eq!(schema["required"], serde_json::json!(["name"]));
+    assert_eq!(schema["properties"]["name"]["type"], "string");
+    assert_eq!(schema["properties"]["age"]["type"], "integer");
+    assert_eq!(schema["properties"]["age"]["minimum"], 0);
+    assert_eq!(schema["properties"]["age"]["maximum"], 150);
+    assert_eq!(
+        schema["properties"]["status"]["enum"],
+        serde_json::json!(["draft", "active"])
+    );
+    assert_eq!(schema["properties"]["email"]["format"], "email");
+
+    Ok(())
+}
@@ -1,8 +1,11 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::entity_definition::is_system_field_name;
 use r_data_core_core::error::Result;
+use r_data_core_persistence::dynamic_entity_utils::validate_sql_identifier;
 use std::collections::HashMap;
+use uuid::Uuid;
 
 use super::EntityDefinitionService;
 
@@ -72,9 +75,206 @@ impl EntityDefinitionService {
                 )));
             }
 
+            if is_system_field_name(&field.name) {
+                return Err(r_data_core_core::error::Error::Validation(format!(
+                    "Field name '{}' is reserved for a system field",
+                    field.name
+                )));
+            }
+
             // Additional field-specific validations can be added here
         }
 
         Ok(())
     }
+
+    /// Validate the composite unique constraints against the effective fields
+    ///
+    /// # Arguments
+    /// * `definition` - Entity definition with its inherited fields resolved
+    ///
+    /// # Errors
+    /// Returns a validation error if a constraint has fewer than two fields, names a field
+    /// more than once, names an unknown or relation field, or repeats another constraint
+    pub(crate) fn validate_unique_constraints(definition: &EntityDefinition) -> Result<()> {
+        let mut seen: Vec<Vec<String>> = Vec::new();
+
+        for fields in &definition.unique_constraints {
+            if fields.len() < 2 {
+                return Err(r_data_core_core::error::Error::Validation(format!(
+                    "Unique constraint ({}) must span at least two fields; use the field's unique flag instead",
+                    fields.join(", ")
+                )));
+            }
+
+            let mut normalized: Vec<String> = fields.iter().map(|f| f.to_lowercase()).collect();
+            for name in fields {
+                let Some(field) = definition
+                    .fields
+                    .iter()
+                    .find(|f| f.name.eq_ignore_ascii_case(name))
+                else {
+                    return Err(r_data_core_core::error::Error::Validation(format!(
+                        "Unique constraint ({}) references unknown field '{name}'",
+                        fields.join(", ")
+                    )));
+                };
+                if field.field_type.is_relation() {
+                    return Err(r_data_core_core::error::Error::Validation(format!(
+                        "Unique constraint ({}) cannot include relation field '{name}'",
+                        fields.join(", ")
+                    )));
+                }
+            }
+
+            normalized.sort();
+            if normalized.windows(2).any(|pair| pair[0] == pair[1]) {
+                return Err(r_data_core_core::error::Error::Validation(format!(
+                    "Unique constraint ({}) names a field more than once",
+                    fields.join(", ")
+                )));
+            }
+            if seen.contains(&normalized) {
+                return Err(r_data_core_core::error::Error::Validation(format!(
+                    "Unique constraint ({}) is defined more than once",
+                    fields.join(", ")
+                )));
+            }
+            seen.push(normalized);
+        }
+
+        Ok(())
+    }
+
+    /// Ensure every `requires` rule depends on another field of the definition
+    ///
+    /// # Errors
+    /// Returns a validation error if a rule references an unknown field or the field itself
+    pub(crate) fn validate_field_requirements(definition: &EntityDefinition) -> Result<()> {
+        for field in &definition.fields {
+            let Some(requirement) = &field.validation.requires else {
+                continue;
+            };
+            if requirement.field == field.name {
+                return Err(r_data_core_core::error::Error::Validation(format!(
+                    "Field '{}' cannot require itself",
+                    field.name
+                )));
+            }
+            if definition.get_field(&requirement.field).is_none() {
+                return Err(r_data_core_core::error::Error::Validation(format!(
+                    "Field '{}' requires unknown field '{}'",
+                    field.name, requirement.field
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the entity-level validation rules against the effective fields
+    ///
+    /// # Arguments
+    /// * `definition` - Entity definition with its inherited fields resolved
+    ///
+    /// # Errors
+    /// Returns a validation error if a rule has no name or message, repeats another rule's
+    /// name, has a malformed expression or references an unknown field
+    pub(crate) fn validate_validation_rules(definition: &EntityDefinition) -> Result<()> {
+        let mut names: Vec<&str> = Vec::new();
+
+        for rule in &definition.validation_rules {
+            if rule.name.trim().is_empty() || rule.message.trim().is_empty() {
+                return Err(r_data_core_core::error::Error::Validation(format!(
+                    "Validation rule '{}' must have a name and a message",
+                    rule.expression
+                )));
+            }
+            if names.contains(&rule.name.as_str()) {
+                return Err(r_data_core_core::error::Error::Validation(format!(
+                    "Validation rule '{}' is defined more than once",
+                    rule.name
+                )));
+            }
+            names.push(&rule.name);
+
+            let expression = rule.parse()?;
+            for name in expression.fields() {
+                if definition.get_field(name).is_none() {
+                    return Err(r_data_core_core::error::Error::Validation(format!(
+                        "Validation rule '{}' references unknown field '{name}'",
+                        rule.name
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensure a published definition has at least one non-system field, own or inherited
+    ///
+    /// Called with the effective definition, so publishing a draft that only inherits its
+    /// fields is allowed. Drafts may be saved without fields so they can be built up
+    /// incrementally.
+    ///
+    /// # Errors
+    /// Returns a validation error if the definition is published without any user fields
+    pub(crate) fn validate_publishable(definition: &EntityDefinition) -> Result<()> {
+        if definition.published && !definition.has_user_fields() {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "Entity definition '{}' must define at least one non-system field before it can be published",
+                definition.entity_type
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate the explicit table name of a definition, if set
+    ///
+    /// # Errors
+    /// Returns a validation error if the name is not a safe identifier or ends in `_view` (the
+    /// suffix of entity views)
+    pub(crate) fn validate_table_name(definition: &EntityDefinition) -> Result<()> {
+        let Some(table_name) = &definition.table_name else {
+            return Ok(());
+        };
+        validate_sql_identifier(table_name)?;
+        if table_name.ends_with("_view") {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "Table name '{table_name}' must not end in '_view'"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Ensure no other definition stores its entities in the definition's table
+    ///
+    /// Compares the effective table names, so an explicit name also collides with the
+    /// `entity_<type>` table of another type. A new definition must also not be mapped onto
+    /// an existing table that is not an entity table, such as one of the application itself.
+    ///
+    /// # Errors
+    /// Returns a validation error naming the entity type already using the table, or if the
+    /// table exists and is not an entity table
+    pub(crate) async fn validate_table_name_available(
+        &self,
+        definition: &EntityDefinition,
+        uuid: Option<&Uuid>,
+    ) -> Result<()> {
+        let table_name = definition.get_table_name();
+        if let Some(entity_type) = self
+            .repository
+            .find_table_owner(&table_name, uuid.copied())
+            .await?
+        {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "Table '{table_name}' is already used by entity type '{entity_type}'"
+            )));
+        }
+        if uuid.is_none() && self.repository.is_foreign_table(&table_name).await? {
+            return Err(r_data_core_core::error::Error::Validation(format!(
+                "Table '{table_name}' already exists and is not an entity table"
+            )));
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,170 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use r_data_core_core::entity_webhook::{
+    changed_fields, EntityWebhookPayload, EntityWebhookSubscription,
+};
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::dynamic_entity_utils::extract_uuid_from_entity_field_data;
+use r_data_core_persistence::{EntityWebhookRepositoryTrait, OutboxRepositoryTrait};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::dynamic_entity::EntityChangeHook;
+
+/// Manages field-level entity webhooks and enqueues their deliveries in the outbox
+#[derive(Clone)]
+pub struct EntityWebhookService {
+    repository: Arc<dyn EntityWebhookRepositoryTrait>,
+    outbox: Arc<dyn OutboxRepositoryTrait>,
+}
+
+impl EntityWebhookService {
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EntityWebhookRepositoryTrait>,
+        outbox: Arc<dyn OutboxRepositoryTrait>,
+    ) -> Self {
+        Self { repository, outbox }
+    }
+
+    /// Subscribe `url` to changes of `fields` on entities of `entity_type`
+    ///
+    /// # Errors
+    /// Returns a validation error for a non-HTTP(S) URL or an empty field list
+    pub async fn create_subscription(
+        &self,
+        entity_type: &str,
+        url: &str,
+        fields: &[String],
+        created_by: Option<Uuid>,
+    ) -> Result<Uuid> {
+        if entity_type.trim().is_empty() {
+            return Err(Error::Validation(
+                "Entity type must not be empty".to_string(),
+            ));
+        }
+        let parsed = reqwest::Url::parse(url)
+            .map_err(|e| Error::Validation(format!("Invalid webhook URL '{url}': {e}")))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(Error::Validation(format!(
+                "Webhook URL must use http or https, got '{}'",
+                parsed.scheme()
+            )));
+        }
+        let mut fields: Vec<String> = fields
+            .iter()
+            .map(|field| field.trim().to_string())
+            .filter(|field| !field.is_empty())
+            .collect();
+        fields.sort();
+        fields.dedup();
+        if fields.is_empty() {
+            return Err(Error::Validation(
+                "A webhook must subscribe to at least one field".to_string(),
+            ));
+        }
+
+        self.repository
+            .create(entity_type, url, &fields, created_by)
+            .await
+    }
+
+    /// List subscriptions, optionally restricted to one entity type
+    ///
+    /// # Errors
+    /// Returns an error if the database query fails
+    pub async fn list_subscriptions(
+        &self,
+        entity_type: Option<&str>,
+    ) -> Result<Vec<EntityWebhookSubscription>> {
+        self.repository.list(entity_type).await
+    }
+
+    /// Get a subscription by UUID
+    ///
+    /// # Errors
+    /// Returns `NotFound` if the subscription does not exist
+    pub async fn get_subscription(&self, uuid: Uuid) -> Result<EntityWebhookSubscription> {
+        self.repository
+            .get_by_uuid(uuid)
+            .await?
+            .ok_or_else(|| Error::NotFound(format!("Webhook subscription {uuid} not found")))
+    }
+
+    /// Delete a subscription
+    ///
+    /// # Errors
+    /// Returns `NotFound` if the subscription does not exist
+    pub async fn delete_subscription(&self, uuid: Uuid) -> Result<()> {
+        if self.repository.delete(uuid).await? {
+            Ok(())
+        } else {
+            Err(Error::NotFound(format!(
+                "Webhook subscription {uuid} not found"
+            )))
+        }
+    }
+
+    /// Enqueue one delivery per subscription whose fields differ between `before` and `after`;
+    /// returns the number of deliveries enqueued
+    ///
+    /// # Errors
+    /// Returns an error if loading subscriptions or inserting an outbox message fails
+    pub async fn enqueue_changes(
+        &self,
+        before: &DynamicEntity,
+        after: &DynamicEntity,
+    ) -> Result<usize> {
+        let Some(entity_uuid) = extract_uuid_from_entity_field_data(&after.field_data, "uuid")
+        else {
+            return Ok(0);
+        };
+        let subscriptions = self
+            .repository
+            .list_active_for_entity_type(&after.entity_type)
+            .await?;
+        if subscriptions.is_empty() {
+            return Ok(0);
+        }
+
+        let changes = changed_fields(&before.field_data, &after.field_data);
+        let change_uuid = Uuid::now_v7();
+        let changed_at = OffsetDateTime::now_utc();
+        let mut enqueued = 0;
+        for subscription in subscriptions {
+            let Some(matching) = subscription.matching_changes(&changes) else {
+                continue;
+            };
+            let payload = EntityWebhookPayload {
+                subscription_uuid: subscription.uuid,
+                url: subscription.url,
+                entity_type: after.entity_type.clone(),
+                entity_uuid,
+                changes: matching,
+                changed_at,
+            };
+            let payload = serde_json::to_value(&payload)?;
+            self.outbox
+                .insert_entity_webhook_delivery(
+                    subscription.uuid,
+                    entity_uuid,
+                    change_uuid,
+                    payload,
+                )
+                .await?;
+            enqueued += 1;
+        }
+        Ok(enqueued)
+    }
+}
+
+#[async_trait]
+impl EntityChangeHook for EntityWebhookService {
+    async fn entity_updated(&self, before: &DynamicEntity, after: &DynamicEntity) -> Result<()> {
+        self.enqueue_changes(before, after).await.map(|_| ())
+    }
+}
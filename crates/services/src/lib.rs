@@ -3,17 +3,22 @@
 pub mod adapters;
 pub mod admin_user;
 pub mod api_key;
+pub mod audit_log;
 pub mod auth;
 pub mod bootstrap;
 pub mod cache;
 pub mod dashboard_stats;
 pub mod dynamic_entity;
+pub mod entity_aggregate;
 pub mod entity_definition;
+pub mod entity_webhook;
 pub mod license;
 pub mod mail;
+pub mod notification;
 pub mod password_reset;
 pub mod query_validation;
 pub mod role;
+pub mod schema_reconcile;
 pub mod settings;
 pub mod statistics;
 pub mod system_log;
@@ -26,24 +31,32 @@ pub use adapters::{
     AdminUserRepositoryAdapter, ApiKeyRepositoryAdapter, DynamicEntityRepositoryAdapter,
     EntityDefinitionRepositoryAdapter,
 };
-pub use admin_user::AdminUserService;
-pub use api_key::ApiKeyService;
+pub use admin_user::{AdminUserService, TotpEnrollment};
+pub use api_key::{ApiKeyService, RotatedApiKey};
+pub use audit_log::AuditLogService;
 pub use auth::AuthService;
 pub use bootstrap::{init_cache_manager, init_logger_with_default, init_pg_pool};
 pub use cache::CacheService;
 pub use dashboard_stats::DashboardStatsService;
-pub use dynamic_entity::DynamicEntityService;
+pub use dynamic_entity::{DynamicEntityService, EntityChangeHook};
+pub use entity_aggregate::EntityAggregateService;
 pub use entity_definition::{EntityDefinitionService, ServiceEntityFieldInfo};
+pub use entity_webhook::EntityWebhookService;
 pub use license::LicenseService;
 pub use mail::MailService;
+pub use notification::email::{EmailNotificationSender, EmailTransport};
+pub use notification::PgNotificationService;
 pub use password_reset::PasswordResetService;
 pub use query_validation::{
     validate_list_query, FieldValidator, ListQueryParams, ValidatedListQuery,
 };
 pub use role::RoleService;
+pub use schema_reconcile::{
+    compute_schema_reconcile_actions, SchemaReconcileAction, SchemaReconcileService,
+};
 pub use settings::SettingsService;
 pub use statistics::StatisticsService;
 pub use system_log::SystemLogService;
 pub use version::{VersionMetaWithName, VersionService};
 pub use worker::compute_reconcile_actions;
-pub use workflow::{WorkflowRepositoryAdapter, WorkflowService};
+pub use workflow::{RunEvent, RunEventBus, WorkflowRepositoryAdapter, WorkflowService};
@@ -0,0 +1,120 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::notification::{
+    NewNotification, Notification, NotificationManager, NotificationStatus, NotificationType,
+};
+use r_data_core_persistence::NotificationRepositoryTrait;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+pub mod email;
+
+use email::EmailNotificationSender;
+
+/// `NotificationManager` storing notifications in the `notifications` table
+///
+/// Scheduled in-app notifications are sent lazily: they are marked sent the next time their
+/// user lists notifications after the scheduled time. E-mail notifications are handed to the
+/// e-mail sender, if one is set; due scheduled ones go out with
+/// [`EmailNotificationSender::send_outstanding`].
+#[derive(Clone)]
+pub struct PgNotificationService {
+    repository: Arc<dyn NotificationRepositoryTrait>,
+    email_sender: Option<EmailNotificationSender>,
+}
+
+impl PgNotificationService {
+    #[must_use]
+    pub fn new(repository: Arc<dyn NotificationRepositoryTrait>) -> Self {
+        Self {
+            repository,
+            email_sender: None,
+        }
+    }
+
+    /// Deliver e-mail notifications with `sender`
+    #[must_use]
+    pub fn with_email_sender(mut self, sender: EmailNotificationSender) -> Self {
+        self.email_sender = Some(sender);
+        self
+    }
+}
+
+fn validate(notification: &NewNotification) -> Result<()> {
+    if notification.title.trim().is_empty() {
+        return Err(Error::Validation(
+            "Notification title must not be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl NotificationManager for PgNotificationService {
+    async fn send_notification(&self, notification: NewNotification) -> Result<Notification> {
+        validate(&notification)?;
+        let created = self
+            .repository
+            .create(&notification, NotificationStatus::Pending, None)
+            .await?;
+        if notification.notification_type == NotificationType::Email {
+            return match &self.email_sender {
+                Some(sender) => sender.deliver(&created).await,
+                None => Ok(created),
+            };
+        }
+        self.repository
+            .mark_sent(created.uuid)
+            .await?
+            .ok_or_else(|| {
+                Error::Conflict(format!(
+                    "Notification {} changed before it was sent",
+                    created.uuid
+                ))
+            })
+    }
+
+    async fn schedule_notification(
+        &self,
+        notification: NewNotification,
+        send_at: OffsetDateTime,
+    ) -> Result<Notification> {
+        validate(&notification)?;
+        self.repository
+            .create(&notification, NotificationStatus::Scheduled, Some(send_at))
+            .await
+    }
+
+    async fn cancel_notification(&self, uuid: Uuid) -> Result<()> {
+        if self.repository.cancel(uuid).await? {
+            Ok(())
+        } else {
+            Err(Error::NotFound(format!(
+                "No pending or scheduled notification {uuid}"
+            )))
+        }
+    }
+
+    async fn mark_as_read(&self, user_uuid: Uuid, uuid: Uuid) -> Result<()> {
+        if self.repository.mark_read(user_uuid, uuid).await? {
+            Ok(())
+        } else {
+            Err(Error::NotFound(format!("Notification {uuid} not found")))
+        }
+    }
+
+    async fn get_user_notifications(
+        &self,
+        user_uuid: Uuid,
+        include_read: bool,
+    ) -> Result<Vec<Notification>> {
+        self.repository.send_due(user_uuid).await?;
+        self.repository
+            .list_sent_for_user(user_uuid, include_read)
+            .await
+    }
+}
@@ -0,0 +1,203 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use r_data_core_core::config::SmtpConfig;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::notification::{Notification, NotificationStatus, NotificationType};
+use r_data_core_persistence::NotificationRepositoryTrait;
+
+use crate::mail::MailService;
+
+/// Failed attempts after which an e-mail notification is no longer retried
+pub const DEFAULT_MAX_RETRIES: i32 = 3;
+
+/// E-mail rendered from a notification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Hands e-mails to a mail server (or anything else that wants them)
+#[async_trait]
+pub trait EmailTransport: Send + Sync {
+    /// Send one e-mail
+    ///
+    /// # Errors
+    /// Returns an error if the e-mail could not be sent
+    async fn send(&self, message: &EmailMessage) -> Result<()>;
+}
+
+/// Sends e-mails through SMTP
+pub struct SmtpEmailTransport {
+    mail: MailService,
+}
+
+impl SmtpEmailTransport {
+    /// Connect to the server configured in `config`
+    ///
+    /// # Errors
+    /// Returns [`Error::Config`] if the from address is invalid or TLS relay setup fails
+    pub fn new(config: &SmtpConfig) -> Result<Self> {
+        Ok(Self {
+            mail: MailService::new(config)?,
+        })
+    }
+}
+
+#[async_trait]
+impl EmailTransport for SmtpEmailTransport {
+    async fn send(&self, message: &EmailMessage) -> Result<()> {
+        self.mail
+            .send_raw_email(
+                std::slice::from_ref(&message.to),
+                &message.subject,
+                &message.body,
+                None,
+            )
+            .await
+    }
+}
+
+/// Logs e-mails instead of sending them, for local development
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DryRunEmailTransport;
+
+#[async_trait]
+impl EmailTransport for DryRunEmailTransport {
+    async fn send(&self, message: &EmailMessage) -> Result<()> {
+        log::info!(
+            "[dry-run] E-mail notification to {}: {}\n{}",
+            message.to,
+            message.subject,
+            message.body
+        );
+        Ok(())
+    }
+}
+
+/// Delivers `email` notifications to their user's address and records the outcome
+///
+/// The notification title becomes the subject and its message the plain-text body. Successful
+/// deliveries are marked sent; failures are marked failed with the error and an incremented
+/// retry count, and are picked up again by [`Self::send_outstanding`] until `max_retries` is
+/// reached.
+#[derive(Clone)]
+pub struct EmailNotificationSender {
+    repository: Arc<dyn NotificationRepositoryTrait>,
+    transport: Arc<dyn EmailTransport>,
+    max_retries: i32,
+}
+
+impl EmailNotificationSender {
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn NotificationRepositoryTrait>,
+        transport: Arc<dyn EmailTransport>,
+    ) -> Self {
+        Self {
+            repository,
+            transport,
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+
+    /// Sender using SMTP, or logging only when `dry_run` is set
+    ///
+    /// # Errors
+    /// Returns [`Error::Config`] if the SMTP transport cannot be set up
+    pub fn from_smtp_config(
+        repository: Arc<dyn NotificationRepositoryTrait>,
+        config: &SmtpConfig,
+        dry_run: bool,
+    ) -> Result<Self> {
+        let transport: Arc<dyn EmailTransport> = if dry_run {
+            Arc::new(DryRunEmailTransport)
+        } else {
+            Arc::new(SmtpEmailTransport::new(config)?)
+        };
+        Ok(Self::new(repository, transport))
+    }
+
+    /// Override the number of failed attempts after which delivery is given up
+    #[must_use]
+    pub const fn with_max_retries(mut self, max_retries: i32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Send an e-mail notification and store whether it was sent or failed
+    ///
+    /// A failed send, including a user without e-mail address, is not an error: the returned
+    /// notification carries `Failed` status and the error message.
+    ///
+    /// # Errors
+    /// Returns a validation error for non-email notifications, `Conflict` if the notification
+    /// was sent or cancelled meanwhile, or an error if loading the recipient or updating the
+    /// notification fails
+    pub async fn deliver(&self, notification: &Notification) -> Result<Notification> {
+        if notification.notification_type != NotificationType::Email {
+            return Err(Error::Validation(format!(
+                "Notification {} is not an e-mail notification",
+                notification.uuid
+            )));
+        }
+        let sent = match self.repository.user_email(notification.user_uuid).await? {
+            Some(to) => {
+                let message = EmailMessage {
+                    to,
+                    subject: notification.title.clone(),
+                    body: notification.message.clone(),
+                };
+                self.transport.send(&message).await
+            }
+            None => Err(Error::NotFound(format!(
+                "No e-mail address for user {}",
+                notification.user_uuid
+            ))),
+        };
+
+        let updated = match sent {
+            Ok(()) => self.repository.mark_sent(notification.uuid).await?,
+            Err(e) => {
+                log::warn!(
+                    "Sending e-mail notification {} failed: {e}",
+                    notification.uuid
+                );
+                self.repository
+                    .mark_failed(notification.uuid, &e.to_string())
+                    .await?
+            }
+        };
+        updated.ok_or_else(|| {
+            Error::Conflict(format!(
+                "Notification {} changed before it was sent",
+                notification.uuid
+            ))
+        })
+    }
+
+    /// Deliver up to `limit` outstanding e-mail notifications: pending, due scheduled and
+    /// retryable failed ones; returns how many were sent
+    ///
+    /// # Errors
+    /// Returns an error if the outstanding notifications cannot be loaded
+    pub async fn send_outstanding(&self, limit: i64) -> Result<usize> {
+        let outstanding = self
+            .repository
+            .list_email_outstanding(self.max_retries, limit)
+            .await?;
+        let mut sent = 0;
+        for notification in &outstanding {
+            match self.deliver(notification).await {
+                Ok(updated) if updated.status == NotificationStatus::Sent => sent += 1,
+                Ok(_) => {}
+                Err(e) => log::warn!("Skipping e-mail notification {}: {e}", notification.uuid),
+            }
+        }
+        Ok(sent)
+    }
+}
@@ -3,6 +3,7 @@
 use std::sync::Arc;
 
 use base64::Engine as _;
+use r_data_core_core::admin_user::PasswordPolicy;
 use r_data_core_core::error::{Error, Result};
 use r_data_core_persistence::{EmailTemplateRepositoryTrait, PasswordResetRepositoryTrait};
 use r_data_core_workflow::data::job_queue::JobQueue;
@@ -20,6 +21,7 @@ pub struct PasswordResetService {
     mail_service: Arc<crate::mail::MailService>,
     throttle_seconds: u64,
     frontend_base_url: String,
+    password_policy: Arc<PasswordPolicy>,
 }
 
 impl PasswordResetService {
@@ -43,9 +45,17 @@ impl PasswordResetService {
             mail_service,
             throttle_seconds,
             frontend_base_url,
+            password_policy: Arc::new(PasswordPolicy::default()),
         }
     }
 
+    /// Set the password policy new passwords must meet
+    #[must_use]
+    pub fn with_password_policy(mut self, policy: Arc<PasswordPolicy>) -> Self {
+        self.password_policy = policy;
+        self
+    }
+
     /// Request a password reset for the given email address.
     ///
     /// Always returns `Ok` so that callers cannot determine whether a given
@@ -145,7 +155,8 @@ impl PasswordResetService {
     /// # Errors
     ///
     /// Returns [`Error::Validation`] if the token is invalid, expired, or
-    /// already used.  Returns other errors on infrastructure failures.
+    /// already used, or if the new password does not meet the password policy.
+    /// Returns other errors on infrastructure failures.
     /// Returns the UUID of the user whose password was reset.
     pub async fn reset_password(&self, token: &str, new_password: &str) -> Result<Uuid> {
         // Hash the provided token to look it up.
@@ -174,6 +185,8 @@ impl PasswordResetService {
             ));
         }
 
+        self.password_policy.check(new_password)?;
+
         // Hash the new password.
         let new_hash = r_data_core_core::crypto::hash_password_argon2(new_password)?;
 
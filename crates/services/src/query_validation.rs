@@ -1,5 +1,6 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use r_data_core_core::sort::{parse_sort, SortField};
 use sqlx::PgPool;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -204,21 +205,22 @@ pub async fn validate_list_query(
         }
     }
 
-    // Validate sort_by field if provided
+    // Validate every sort_by field ("name" or "status:asc,created_at:desc") if provided
     if let Some(ref sort_by) = params.sort_by {
-        // Allow whitelisted virtual fields (e.g., derived columns)
-        if allowed_virtual_fields
-            .iter()
-            .any(|field| field == &sort_by.as_str())
-        {
-            // Still sanitize to avoid injection
-            FieldValidator::sanitize_field_name(sort_by)
-                .map_err(|e| format!("Sort field validation failed: {e}"))?;
-        } else {
-            field_validator
-                .validate_field(table_name, sort_by)
-                .await
-                .map_err(|e| format!("Sort field validation failed: {e}"))?;
+        let fields = parse_sort(sort_by, params.sort_order.as_deref())
+            .map_err(|e| format!("Sort field validation failed: {e}"))?;
+        for SortField { field, .. } in &fields {
+            // Allow whitelisted virtual fields (e.g., derived columns)
+            if allowed_virtual_fields.contains(&field.as_str()) {
+                // Still sanitize to avoid injection
+                FieldValidator::sanitize_field_name(field)
+                    .map_err(|e| format!("Sort field validation failed: {e}"))?;
+            } else {
+                field_validator
+                    .validate_field(table_name, field)
+                    .await
+                    .map_err(|e| format!("Sort field validation failed: {e}"))?;
+            }
         }
     }
 
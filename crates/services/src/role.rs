@@ -8,6 +8,7 @@ use uuid::Uuid;
 use r_data_core_core::cache::CacheManager;
 use r_data_core_core::error::Result;
 use r_data_core_core::permissions::role::{Permission, Role};
+use r_data_core_core::permissions::ROLE_PERMISSIONS_CACHE_PREFIX;
 use r_data_core_core::system_log::SystemLogResourceType;
 use r_data_core_persistence::{
     AdminUserRepository, ApiKeyRepository, RoleRepository, RoleRepositoryTrait,
@@ -16,7 +17,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
 use crate::query_validation::{validate_list_query, FieldValidator, ValidatedListQuery};
-use crate::SystemLogService;
+use crate::{AuditLogService, SystemLogService};
 
 /// Cached user role UUIDs
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +38,7 @@ pub struct RoleService {
     cache_manager: Arc<CacheManager>,
     cache_ttl: Option<u64>,
     system_log: Option<Arc<SystemLogService>>,
+    audit_log: Option<Arc<AuditLogService>>,
 }
 
 impl RoleService {
@@ -57,6 +59,7 @@ impl RoleService {
             cache_manager,
             cache_ttl,
             system_log: None,
+            audit_log: None,
         }
     }
 
@@ -83,6 +86,7 @@ impl RoleService {
             cache_manager,
             cache_ttl,
             system_log: None,
+            audit_log: None,
         }
     }
 
@@ -93,6 +97,13 @@ impl RoleService {
         self
     }
 
+    /// Set the audit log service recording before/after snapshots of mutations
+    #[must_use]
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
     /// Generate cache key for role
     fn cache_key(uuid: &Uuid) -> String {
         format!("role:{uuid}")
@@ -293,6 +304,17 @@ impl RoleService {
         }
     }
 
+    /// Invalidate permissions cached per role set (see `DefaultPermissionService::with_cache`)
+    async fn invalidate_role_set_permissions_cache(&self) {
+        if let Err(e) = self
+            .cache_manager
+            .delete_by_prefix(ROLE_PERMISSIONS_CACHE_PREFIX)
+            .await
+        {
+            log::warn!("Failed to invalidate role set permissions cache: {e}");
+        }
+    }
+
     /// Invalidate all caches for users and API keys that reference a role
     ///
     /// This invalidates role caches, user role caches, API key role caches,
@@ -309,6 +331,9 @@ impl RoleService {
             log::warn!("Failed to invalidate role cache {role_uuid}: {e}");
         }
 
+        // Permissions resolved per role set may include this role
+        self.invalidate_role_set_permissions_cache().await;
+
         // Find all users with this role
         let user_repo = AdminUserRepository::new(Arc::new(self.pool.clone()));
         if let Ok(user_uuids) = user_repo.get_users_by_role(role_uuid).await {
@@ -381,12 +406,16 @@ impl RoleService {
         // Cache the new role - retrieve it from DB to ensure it has the UUID set
         let cache_key = Self::cache_key(&uuid);
         let ttl = self.cache_ttl;
-        if let Ok(Some(ref created_role)) = self.repository.get_by_uuid(uuid).await {
+        let created_role = self.repository.get_by_uuid(uuid).await.ok().flatten();
+        if let Some(ref created_role) = created_role {
             if let Err(e) = self.cache_manager.set(&cache_key, created_role, ttl).await {
                 log::warn!("Failed to cache new role {uuid}: {e}");
             }
         }
 
+        // Role sets naming this role may have been cached before it existed
+        self.invalidate_role_set_permissions_cache().await;
+
         if let Some(ref log) = self.system_log {
             log.log_entity_created(
                 Some(created_by),
@@ -398,6 +427,17 @@ impl RoleService {
             .await;
         }
 
+        if let (Some(ref audit), Some(ref created_role)) = (&self.audit_log, &created_role) {
+            audit
+                .record_created(
+                    Some(created_by),
+                    SystemLogResourceType::Role,
+                    uuid,
+                    created_role,
+                )
+                .await;
+        }
+
         Ok(uuid)
     }
 
@@ -410,6 +450,16 @@ impl RoleService {
     /// # Errors
     /// Returns an error if database update fails
     pub async fn update_role(&self, role: &Role, updated_by: Uuid) -> Result<()> {
+        let before = if self.audit_log.is_some() {
+            self.repository
+                .get_by_uuid(role.base.uuid)
+                .await
+                .ok()
+                .flatten()
+        } else {
+            None
+        };
+
         self.repository.update(role, updated_by).await?;
 
         // Invalidate all caches for this role and all users/API keys that reference it
@@ -426,6 +476,18 @@ impl RoleService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_updated(
+                    Some(updated_by),
+                    SystemLogResourceType::Role,
+                    role.base.uuid,
+                    before.as_ref(),
+                    Some(role),
+                )
+                .await;
+        }
+
         Ok(())
     }
 
@@ -439,13 +501,10 @@ impl RoleService {
     /// Returns an error if database delete fails
     pub async fn delete_role(&self, uuid: Uuid, actor_uuid: Uuid) -> Result<()> {
         // Capture the role name before deletion for audit log
-        let role_name = self
-            .repository
-            .get_by_uuid(uuid)
-            .await
-            .ok()
-            .flatten()
-            .map_or_else(|| uuid.to_string(), |r| r.name);
+        let existing = self.repository.get_by_uuid(uuid).await.ok().flatten();
+        let role_name = existing
+            .as_ref()
+            .map_or_else(|| uuid.to_string(), |r| r.name.clone());
 
         // Invalidate all caches before deleting (so reverse lookups still work)
         self.invalidate_all_caches_for_role(uuid).await;
@@ -463,6 +522,17 @@ impl RoleService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_deleted(
+                    Some(actor_uuid),
+                    SystemLogResourceType::Role,
+                    uuid,
+                    existing.as_ref(),
+                )
+                .await;
+        }
+
         Ok(())
     }
 
@@ -0,0 +1,213 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::{BTreeSet, HashMap};
+
+use log::info;
+use r_data_core_core::error::Result;
+use r_data_core_persistence::{DefinitionSchema, EntitySchemaRepository, EntityTableSchema};
+use sqlx::PgPool;
+
+/// Columns the table builder keeps even without a matching field
+const SYSTEM_COLUMNS: [&str; 7] = [
+    "created_at",
+    "updated_at",
+    "created_by",
+    "updated_by",
+    "published",
+    "version",
+    "path",
+];
+
+/// A mismatch between the entity definitions and the entity tables, with its fix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaReconcileAction {
+    /// The table has no entity definition and is dropped
+    DropOrphanTable { table_name: String },
+    /// The definition has no table and it is built
+    CreateTable {
+        entity_type: String,
+        table_name: String,
+    },
+    /// The table columns differ from the definition fields and it is rebuilt
+    SyncColumns {
+        entity_type: String,
+        table_name: String,
+        missing_columns: Vec<String>,
+        extra_columns: Vec<String>,
+    },
+}
+
+/// Compare entity definitions with the existing entity tables
+///
+/// Returns orphan tables first, then definitions without a table, then column drift, each
+/// ordered by name.
+#[must_use]
+pub fn compute_schema_reconcile_actions(
+    definitions: &[DefinitionSchema],
+    tables: &[EntityTableSchema],
+) -> Vec<SchemaReconcileAction> {
+    let by_table: HashMap<String, &DefinitionSchema> = definitions
+        .iter()
+        .map(|definition| (definition.table_name(), definition))
+        .collect();
+    let table_columns: HashMap<&str, &[String]> = tables
+        .iter()
+        .map(|table| (table.table_name.as_str(), table.columns.as_slice()))
+        .collect();
+
+    let mut orphans: Vec<&str> = tables
+        .iter()
+        .map(|table| table.table_name.as_str())
+        .filter(|name| !by_table.contains_key(*name))
+        .collect();
+    orphans.sort_unstable();
+
+    let mut definitions: Vec<&DefinitionSchema> = definitions.iter().collect();
+    definitions.sort_by(|a, b| a.entity_type.cmp(&b.entity_type));
+
+    let mut missing_tables = Vec::new();
+    let mut drift = Vec::new();
+    for definition in definitions {
+        let Some(columns) = table_columns.get(definition.table_name().as_str()) else {
+            missing_tables.push(SchemaReconcileAction::CreateTable {
+                entity_type: definition.entity_type.clone(),
+                table_name: definition.table_name(),
+            });
+            continue;
+        };
+        let expected: BTreeSet<&str> = definition.columns.iter().map(String::as_str).collect();
+        let actual: BTreeSet<&str> = columns.iter().map(String::as_str).collect();
+        let missing_columns: Vec<String> = expected
+            .difference(&actual)
+            .map(ToString::to_string)
+            .collect();
+        let extra_columns: Vec<String> = actual
+            .difference(&expected)
+            .filter(|column| !SYSTEM_COLUMNS.contains(column))
+            .map(ToString::to_string)
+            .collect();
+        if !missing_columns.is_empty() || !extra_columns.is_empty() {
+            drift.push(SchemaReconcileAction::SyncColumns {
+                entity_type: definition.entity_type.clone(),
+                table_name: definition.table_name(),
+                missing_columns,
+                extra_columns,
+            });
+        }
+    }
+
+    orphans
+        .into_iter()
+        .map(|table_name| SchemaReconcileAction::DropOrphanTable {
+            table_name: table_name.to_string(),
+        })
+        .chain(missing_tables)
+        .chain(drift)
+        .collect()
+}
+
+/// Service reporting and fixing mismatches between entity definitions and entity tables
+pub struct SchemaReconcileService {
+    repository: EntitySchemaRepository,
+}
+
+impl SchemaReconcileService {
+    /// Create a new service
+    #[must_use]
+    pub const fn new(pool: PgPool) -> Self {
+        Self {
+            repository: EntitySchemaRepository::new(pool),
+        }
+    }
+
+    /// List the actions needed to bring the entity tables in line with the definitions
+    ///
+    /// # Errors
+    /// Returns an error if the schemas cannot be read
+    pub async fn report(&self) -> Result<Vec<SchemaReconcileAction>> {
+        let definitions = self.repository.definition_schemas().await?;
+        let tables = self.repository.entity_tables().await?;
+        Ok(compute_schema_reconcile_actions(&definitions, &tables))
+    }
+
+    /// Apply `actions`; orphan tables are dropped with their data
+    ///
+    /// # Errors
+    /// Returns an error on the first action that fails
+    pub async fn fix(&self, actions: &[SchemaReconcileAction]) -> Result<()> {
+        for action in actions {
+            match action {
+                SchemaReconcileAction::DropOrphanTable { table_name } => {
+                    info!("Dropping orphan entity table {table_name}");
+                    self.repository.drop_entity_table(table_name).await?;
+                }
+                SchemaReconcileAction::CreateTable { entity_type, .. }
+                | SchemaReconcileAction::SyncColumns { entity_type, .. } => {
+                    info!("Rebuilding entity table for {entity_type}");
+                    self.repository.rebuild_entity_table(entity_type).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(entity_type: &str, columns: &[&str]) -> DefinitionSchema {
+        DefinitionSchema {
+            entity_type: entity_type.to_string(),
+            table_name: None,
+            columns: columns.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    fn table(table_name: &str, columns: &[&str]) -> EntityTableSchema {
+        EntityTableSchema {
+            table_name: table_name.to_string(),
+            columns: columns.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn reports_orphans_missing_tables_and_column_drift() {
+        let definitions = [
+            definition("Customer", &["name", "email"]),
+            definition("order", &["total"]),
+            definition("product", &["sku"]),
+        ];
+        let tables = [
+            table("entity_customer", &["name", "legacy", "created_at"]),
+            table("entity_product", &["sku", "path"]),
+            table("entity_gone", &["title"]),
+        ];
+
+        assert_eq!(
+            compute_schema_reconcile_actions(&definitions, &tables),
+            vec![
+                SchemaReconcileAction::DropOrphanTable {
+                    table_name: "entity_gone".to_string(),
+                },
+                SchemaReconcileAction::CreateTable {
+                    entity_type: "order".to_string(),
+                    table_name: "entity_order".to_string(),
+                },
+                SchemaReconcileAction::SyncColumns {
+                    entity_type: "Customer".to_string(),
+                    table_name: "entity_customer".to_string(),
+                    missing_columns: vec!["email".to_string()],
+                    extra_columns: vec!["legacy".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn matching_schemas_need_no_action() {
+        let definitions = [definition("customer", &["name"])];
+        let tables = [table("entity_customer", &["name", "version"])];
+        assert!(compute_schema_reconcile_actions(&definitions, &tables).is_empty());
+    }
+}
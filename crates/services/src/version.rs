@@ -1,15 +1,29 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::Value;
 use sqlx::PgPool;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use r_data_core_core::error::Result;
-use r_data_core_persistence::VersionRepository;
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::{
+    DynamicEntityRepository, DynamicEntityRepositoryTrait, VersionRepository,
+};
+
+use crate::dynamic_entity::DynamicEntityService;
+use crate::workflow::value_formatting::is_reserved_field;
 
 /// Service for managing entity versions with business logic
 pub struct VersionService {
+    pool: PgPool,
     version_repo: VersionRepository,
+    entity_repo: DynamicEntityRepository,
+    entity_service: Option<Arc<DynamicEntityService>>,
 }
 
 impl VersionService {
@@ -20,10 +34,21 @@ impl VersionService {
     #[must_use]
     pub fn new(pool: PgPool) -> Self {
         Self {
-            version_repo: VersionRepository::new(pool),
+            version_repo: VersionRepository::new(pool.clone()),
+            entity_repo: DynamicEntityRepository::new(pool.clone()),
+            pool,
+            entity_service: None,
         }
     }
 
+    /// Notify the change hooks of `entity_service` (webhooks, aggregates) about restores, as
+    /// for any other update
+    #[must_use]
+    pub fn with_entity_service(mut self, entity_service: Arc<DynamicEntityService>) -> Self {
+        self.entity_service = Some(entity_service);
+        self
+    }
+
     /// List all versions for an entity, including the current version if not in versions table.
     /// Creator names are resolved via SQL JOINs in the repository.
     ///
@@ -56,6 +81,7 @@ impl VersionService {
                     created_at: updated_at,
                     created_by: updated_by,
                     created_by_name: updated_by_name,
+                    comment: None,
                 });
             }
         }
@@ -67,6 +93,7 @@ impl VersionService {
                 created_at: r.created_at,
                 created_by: r.created_by,
                 created_by_name: r.created_by_name,
+                comment: r.comment,
             });
         }
 
@@ -75,6 +102,153 @@ impl VersionService {
 
         Ok(out)
     }
+
+    /// Restore an entity to the state of a previous version.
+    ///
+    /// The snapshot is validated against the current entity definition: fields that no
+    /// longer exist are dropped (with a warning), write-only fields are left untouched, and the
+    /// merged data must pass the same validation as an update. The result is written as a new
+    /// current version and recorded with a "Reverted to version N" comment in the same
+    /// transaction; change hooks are notified once it is committed.
+    ///
+    /// # Arguments
+    /// * `entity_uuid` - UUID of the entity
+    /// * `version` - Version number to restore
+    /// * `user_uuid` - UUID of the user performing the restore
+    ///
+    /// # Errors
+    /// Returns `Error::NotFound` if the entity or version does not exist,
+    /// `Error::Validation` if the version is already current or the restored data is invalid,
+    /// or a database error
+    pub async fn restore(&self, entity_uuid: Uuid, version: i32, user_uuid: Uuid) -> Result<()> {
+        let mut entity = self
+            .entity_repo
+            .get_by_uuid_any_type(&entity_uuid)
+            .await?
+            .ok_or_else(|| Error::NotFound(format!("Entity {entity_uuid} not found")))?;
+
+        let current_version = self
+            .version_repo
+            .get_current_entity_metadata(entity_uuid)
+            .await?
+            .map(|(v, _, _, _)| v)
+            .ok_or_else(|| Error::NotFound(format!("Entity {entity_uuid} not found")))?;
+        if current_version == version {
+            return Err(Error::Validation(format!(
+                "Version {version} is already the current version"
+            )));
+        }
+
+        let snapshot = self
+            .version_repo
+            .get_entity_version(entity_uuid, version)
+            .await?
+            .ok_or_else(|| {
+                Error::NotFound(format!(
+                    "Version {version} of entity {entity_uuid} not found"
+                ))
+            })?;
+
+        let (restored, dropped) = restorable_field_data(&entity.definition, &snapshot.data);
+        for field in &dropped {
+            log::warn!(
+                "Dropping field '{field}' while restoring version {version} of entity {entity_uuid}: field no longer exists in definition"
+            );
+        }
+
+        let before = entity.clone();
+        // Keep fields added since the snapshot; never write redacted write-only values back
+        entity
+            .field_data
+            .retain(|name, _| !is_write_only_field(&entity.definition, name));
+        entity.field_data.extend(restored);
+        entity
+            .field_data
+            .insert("uuid".to_string(), Value::String(entity_uuid.to_string()));
+        entity.field_data.insert(
+            "updated_by".to_string(),
+            Value::String(user_uuid.to_string()),
+        );
+        validate_restored(&entity)?;
+
+        // Write the restored state and its revert snapshot together
+        let mut tx = self.pool.begin().await?;
+        self.entity_repo.update_tx(&mut tx, &entity).await?;
+        VersionRepository::snapshot_current_with_comment_tx(
+            &mut tx,
+            entity_uuid,
+            Some(user_uuid),
+            &format!("Reverted to version {version}"),
+        )
+        .await?;
+        tx.commit().await?;
+
+        if let Some(entity_service) = &self.entity_service {
+            entity_service.notify_updated(Some(before), &entity).await;
+        }
+        Ok(())
+    }
+}
+
+/// Validate restored data like an update of the entity
+///
+/// Columns kept in the table after their field was removed from the definition are not part
+/// of the restored state, so only definition fields are checked.
+fn validate_restored(entity: &DynamicEntity) -> Result<()> {
+    let mut candidate = entity.clone();
+    candidate.field_data.retain(|name, _| {
+        name == "uuid" || entity.definition.fields.iter().any(|f| f.name == *name)
+    });
+    DynamicEntityService::validate_entity(&candidate)
+}
+
+/// Check whether a field is write-only (e.g. password) in the given definition
+fn is_write_only_field(definition: &EntityDefinition, name: &str) -> bool {
+    definition
+        .fields
+        .iter()
+        .any(|f| f.name.eq_ignore_ascii_case(name) && f.field_type.is_write_only())
+}
+
+/// Extract the field values from a version snapshot that can be restored
+/// under the current entity definition.
+///
+/// Snapshot keys are matched case-insensitively against definition fields
+/// (views expose lowercase column names). System fields and write-only fields
+/// are skipped.
+///
+/// # Returns
+/// Tuple of (restorable field values keyed by definition field name, names of dropped fields)
+#[must_use]
+pub fn restorable_field_data(
+    definition: &EntityDefinition,
+    snapshot: &Value,
+) -> (HashMap<String, Value>, Vec<String>) {
+    let mut restored = HashMap::new();
+    let mut dropped = Vec::new();
+    let Some(obj) = snapshot.as_object() else {
+        return (restored, dropped);
+    };
+
+    for (key, value) in obj {
+        if is_reserved_field(key) || key == "entity_type" {
+            continue;
+        }
+        match definition
+            .fields
+            .iter()
+            .find(|f| f.name.eq_ignore_ascii_case(key))
+        {
+            Some(field) if field.field_type.is_write_only() => {}
+            Some(field) => {
+                restored.insert(field.name.clone(), value.clone());
+            }
+            None => dropped.push(key.clone()),
+        }
+    }
+    dropped.sort();
+
+    (restored, dropped)
 }
 
 /// Version metadata with resolved creator name
@@ -88,4 +262,6 @@ pub struct VersionMetaWithName {
     pub created_by: Option<Uuid>,
     /// Creator name (resolved from `admin_users` table)
     pub created_by_name: Option<String>,
+    /// Optional comment describing the version (e.g. "Reverted to version 2")
+    pub comment: Option<String>,
 }
@@ -70,6 +70,10 @@ impl WorkflowRepositoryTraitDef for WorkflowRepositoryAdapter {
         self.inner.delete(uuid).await
     }
 
+    async fn set_paused(&self, uuid: Uuid, paused: bool) -> r_data_core_core::error::Result<bool> {
+        self.inner.set_paused(uuid, paused).await
+    }
+
     async fn list_scheduled_consumers(
         &self,
     ) -> r_data_core_core::error::Result<Vec<(Uuid, String)>> {
@@ -139,6 +143,21 @@ impl WorkflowRepositoryTraitDef for WorkflowRepositoryAdapter {
         self.inner.run_exists(run_uuid).await
     }
 
+    async fn run_metrics(
+        &self,
+        workflow_uuid: Uuid,
+        window_secs: i64,
+    ) -> r_data_core_core::error::Result<r_data_core_workflow::data::WorkflowRunMetrics> {
+        self.inner.run_metrics(workflow_uuid, window_secs).await
+    }
+
+    async fn run_statistics(
+        &self,
+        workflow_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<r_data_core_workflow::data::WorkflowRunStats> {
+        self.inner.run_statistics(workflow_uuid).await
+    }
+
     async fn list_all_runs_paginated(
         &self,
         limit: i64,
@@ -198,7 +217,7 @@ impl WorkflowRepositoryTraitDef for WorkflowRepositoryAdapter {
         &self,
         run_uuid: Uuid,
         limit: i64,
-    ) -> r_data_core_core::error::Result<Vec<(Uuid, serde_json::Value)>> {
+    ) -> r_data_core_core::error::Result<Vec<(Uuid, i64, serde_json::Value)>> {
         self.inner.fetch_staged_raw_items(run_uuid, limit).await
     }
 
@@ -213,6 +232,63 @@ impl WorkflowRepositoryTraitDef for WorkflowRepositoryAdapter {
             .await
     }
 
+    async fn insert_run_error(
+        &self,
+        run_uuid: Uuid,
+        record_index: i64,
+        error_message: &str,
+        raw_payload: &serde_json::Value,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .insert_run_error(run_uuid, record_index, error_message, raw_payload)
+            .await
+    }
+
+    async fn list_run_errors_paginated(
+        &self,
+        run_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> r_data_core_core::error::Result<(Vec<r_data_core_workflow::data::WorkflowRunError>, i64)>
+    {
+        self.inner
+            .list_run_errors_paginated(run_uuid, limit, offset)
+            .await
+    }
+
+    async fn claim_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        ttl_secs: i64,
+    ) -> r_data_core_core::error::Result<r_data_core_workflow::data::IdempotencyClaim> {
+        self.inner
+            .claim_idempotency_key(workflow_uuid, idempotency_key, ttl_secs)
+            .await
+    }
+
+    async fn complete_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        run_uuid: Uuid,
+        response: &serde_json::Value,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .complete_idempotency_key(workflow_uuid, idempotency_key, run_uuid, response)
+            .await
+    }
+
+    async fn release_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .release_idempotency_key(workflow_uuid, idempotency_key)
+            .await
+    }
+
     async fn mark_run_success(
         &self,
         run_uuid: Uuid,
@@ -239,6 +315,43 @@ impl WorkflowRepositoryTraitDef for WorkflowRepositoryAdapter {
         self.inner.get_run_status(run_uuid).await
     }
 
+    async fn update_run_progress(
+        &self,
+        run_uuid: Uuid,
+        processed: i64,
+        failed: i64,
+        total: i64,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .update_run_progress(run_uuid, processed, failed, total)
+            .await
+    }
+
+    async fn get_run_progress(
+        &self,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<Option<r_data_core_workflow::data::WorkflowRunProgress>>
+    {
+        self.inner.get_run_progress(run_uuid).await
+    }
+
+    async fn set_run_config_override(
+        &self,
+        run_uuid: Uuid,
+        config_override: &serde_json::Value,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .set_run_config_override(run_uuid, config_override)
+            .await
+    }
+
+    async fn get_run_config_override(
+        &self,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<Option<serde_json::Value>> {
+        self.inner.get_run_config_override(run_uuid).await
+    }
+
     async fn get_workflow_uuid_for_run(
         &self,
         run_uuid: Uuid,
@@ -0,0 +1,204 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use r_data_core_core::error::{Error, Result};
+use r_data_core_workflow::data::RunStatus;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Header carrying the HMAC-SHA256 signature of the request body, as `sha256=<hex>`
+pub const SIGNATURE_HEADER: &str = "X-RDataCore-Signature";
+
+/// Header naming the event of the request
+pub const EVENT_HEADER: &str = "X-RDataCore-Event";
+
+/// Event name of run completion notifications
+pub const RUN_COMPLETED_EVENT: &str = "workflow.run.completed";
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(1);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Body posted to a workflow's completion webhook once a run has finished
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunCompletionPayload {
+    pub event: String,
+    pub run_uuid: Uuid,
+    pub workflow_uuid: Uuid,
+    /// `success`, `failed` or `cancelled`
+    pub status: RunStatus,
+    pub processed_items: i64,
+    pub failed_items: i64,
+    pub error: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub finished_at: OffsetDateTime,
+}
+
+impl RunCompletionPayload {
+    /// Payload for a run that reached `status`
+    ///
+    /// Returns `None` for statuses that do not end a run.
+    #[must_use]
+    pub fn new(
+        run_uuid: Uuid,
+        workflow_uuid: Uuid,
+        status: RunStatus,
+        processed_items: i64,
+        failed_items: i64,
+        error: Option<String>,
+    ) -> Option<Self> {
+        status.is_terminal().then(|| Self {
+            event: RUN_COMPLETED_EVENT.to_string(),
+            run_uuid,
+            workflow_uuid,
+            status,
+            processed_items,
+            failed_items,
+            error,
+            finished_at: OffsetDateTime::now_utc(),
+        })
+    }
+}
+
+/// `sha256=<hex>` HMAC-SHA256 signature of `body` keyed with `secret`
+#[must_use]
+pub fn sign_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .unwrap_or_else(|_| unreachable!("HMAC accepts keys of any length"));
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Check a workflow's completion webhook URL
+///
+/// # Errors
+/// Returns a validation error for an unparsable or non-HTTP(S) URL
+pub fn validate_completion_webhook_url(url: Option<&str>) -> Result<()> {
+    let Some(url) = url else {
+        return Ok(());
+    };
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|e| Error::Validation(format!("Invalid completion webhook URL '{url}': {e}")))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(Error::Validation(format!(
+            "Completion webhook URL must use http or https, got '{}'",
+            parsed.scheme()
+        )));
+    }
+    Ok(())
+}
+
+/// Delivers run completion payloads to workflow webhooks
+///
+/// Requests are signed when a secret is configured and retried with a doubling delay on
+/// transport errors and non-2xx responses.
+#[derive(Clone)]
+pub struct CompletionWebhookNotifier {
+    client: reqwest::Client,
+    secret: Option<String>,
+    max_attempts: u32,
+    retry_delay: Duration,
+}
+
+impl CompletionWebhookNotifier {
+    #[must_use]
+    pub fn new(secret: Option<String>) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            secret,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_delay: DEFAULT_RETRY_DELAY,
+        }
+    }
+
+    /// Override the number of attempts (at least one) and the delay before the first retry
+    #[must_use]
+    pub fn with_retry(mut self, max_attempts: u32, retry_delay: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// POST `payload` to `url`
+    ///
+    /// # Errors
+    /// Returns an error describing the last failure once all attempts are used up
+    pub async fn deliver(&self, url: &str, payload: &RunCompletionPayload) -> Result<()> {
+        let body = serde_json::to_vec(payload)?;
+        let signature = self
+            .secret
+            .as_deref()
+            .map(|secret| sign_payload(secret, &body));
+
+        let mut delay = self.retry_delay;
+        let mut attempt = 1;
+        loop {
+            let mut request = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(EVENT_HEADER, RUN_COMPLETED_EVENT)
+                .body(body.clone());
+            if let Some(signature) = &signature {
+                request = request.header(SIGNATURE_HEADER, signature);
+            }
+
+            let failure = match request.send().await {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => format!("HTTP {}", response.status()),
+                Err(e) => e.to_string(),
+            };
+            if attempt >= self.max_attempts {
+                return Err(Error::Api(format!(
+                    "Completion webhook delivery failed after {attempt} attempt(s): {failure}"
+                )));
+            }
+            log::warn!(
+                "Completion webhook attempt {attempt} for run {} failed: {failure}",
+                payload.run_uuid
+            );
+            tokio::time::sleep(delay).await;
+            delay = delay.saturating_mul(2);
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_payload_is_hmac_sha256() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign_payload("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_payload_only_for_finished_runs() {
+        let run = Uuid::now_v7();
+        let workflow = Uuid::now_v7();
+        assert!(RunCompletionPayload::new(run, workflow, RunStatus::Running, 0, 0, None).is_none());
+        let payload = RunCompletionPayload::new(run, workflow, RunStatus::Failed, 3, 1, None)
+            .expect("finished run");
+        assert_eq!(payload.status, RunStatus::Failed);
+    }
+
+    #[test]
+    fn test_validate_completion_webhook_url() {
+        assert!(validate_completion_webhook_url(None).is_ok());
+        assert!(validate_completion_webhook_url(Some("https://example.com/hook")).is_ok());
+        assert!(validate_completion_webhook_url(Some("ftp://example.com")).is_err());
+        assert!(validate_completion_webhook_url(Some("not a url")).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use r_data_core_persistence::WorkflowRepositoryTrait;
+use r_data_core_workflow::data::adapters::http::HttpRetryLog;
+use serde_json::json;
+use uuid::Uuid;
+
+/// Write the retried attempts recorded in `retries` to the log of `run_uuid`
+///
+/// `request` names the request in the messages, e.g. "Source" or "Push". Logging is best effort.
+pub async fn log_http_retries(
+    repo: &dyn WorkflowRepositoryTrait,
+    run_uuid: Uuid,
+    retries: &HttpRetryLog,
+    request: &str,
+    item_uuid: Option<Uuid>,
+) {
+    for retry in retries.take() {
+        let _ = repo
+            .insert_run_log(
+                run_uuid,
+                "warn",
+                &format!(
+                    "{request} request attempt {} failed, retrying",
+                    retry.attempt
+                ),
+                Some(json!({
+                    "attempt": retry.attempt,
+                    "status": retry.status,
+                    "error": retry.error,
+                    "delay_ms": retry.delay_ms,
+                    "item_uuid": item_uuid,
+                })),
+            )
+            .await;
+    }
+}
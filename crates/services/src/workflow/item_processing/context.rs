@@ -2,6 +2,7 @@ use crate::dynamic_entity::DynamicEntityService;
 use crate::workflow::outbox::PushDispatchMode;
 use crate::workflow::transform_execution::{JwtConfig, MailContext};
 use r_data_core_persistence::WorkflowRepositoryTrait;
+use r_data_core_workflow::dsl::Clock;
 use std::sync::Arc;
 
 /// Shared context for workflow item processing
@@ -13,4 +14,6 @@ pub struct WorkflowItemContext<'a> {
     pub mail: &'a MailContext<'a>,
     pub workflow_name: Option<&'a str>,
     pub versioning_disabled: bool,
+    /// Clock used to resolve `now`/`today` operands outside the DSL program
+    pub clock: Clock,
 }
@@ -34,18 +34,29 @@ impl<'a> WorkflowPipelineExecutor<'a> {
 
     /// Process one staged item including output side-effects and status updates.
     ///
+    /// A failing item is marked failed and recorded as a run error at `record_index`
+    /// instead of aborting the run; `Ok(false)` is returned for it.
+    ///
     /// # Errors
     /// Returns an error if processing fails.
     pub async fn process_item(
         &self,
         payload: &JsonValue,
         item_uuid: Uuid,
+        record_index: i64,
     ) -> r_data_core_core::error::Result<bool> {
-        match self.step_executor().execute(payload, item_uuid).await {
-            Ok(outputs) => self.process_outputs(outputs, payload, item_uuid).await,
+        let result = match self.step_executor().execute(payload, item_uuid).await {
+            Ok(outputs) => {
+                self.process_outputs(outputs, payload, item_uuid, record_index)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(success) => Ok(success),
             Err(e) => {
                 self.status_handler()
-                    .handle_execution_error(e, item_uuid)
+                    .handle_execution_error(e, item_uuid, record_index, payload)
                     .await
             }
         }
@@ -83,6 +94,7 @@ impl<'a> WorkflowPipelineExecutor<'a> {
         processed_outputs: Vec<(usize, ToDef, JsonValue)>,
         payload: &JsonValue,
         item_uuid: Uuid,
+        record_index: i64,
     ) -> r_data_core_core::error::Result<bool> {
         let output_dispatcher = WorkflowOutputDispatcher::new(self.ctx);
         for (step_index, to_def, produced) in processed_outputs {
@@ -99,7 +111,7 @@ impl<'a> WorkflowPipelineExecutor<'a> {
             if !push_ok {
                 return self
                     .status_handler()
-                    .mark_entity_operation_failed(item_uuid)
+                    .mark_entity_operation_failed(item_uuid, record_index, payload)
                     .await;
             }
 
@@ -109,7 +121,7 @@ impl<'a> WorkflowPipelineExecutor<'a> {
             if !email_ok {
                 return self
                     .status_handler()
-                    .mark_entity_operation_failed(item_uuid)
+                    .mark_entity_operation_failed(item_uuid, record_index, payload)
                     .await;
             }
 
@@ -119,7 +131,7 @@ impl<'a> WorkflowPipelineExecutor<'a> {
             if !entity_ok {
                 return self
                     .status_handler()
-                    .mark_entity_operation_failed(item_uuid)
+                    .mark_entity_operation_failed(item_uuid, record_index, payload)
                     .await;
             }
         }
@@ -1,4 +1,5 @@
 use r_data_core_persistence::WorkflowRepositoryTrait;
+use serde_json::Value as JsonValue;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -33,15 +34,19 @@ impl<'a> WorkflowItemStatusHandler<'a> {
     pub(super) async fn mark_entity_operation_failed(
         &self,
         item_uuid: Uuid,
+        record_index: i64,
+        payload: &JsonValue,
     ) -> r_data_core_core::error::Result<bool> {
-        log::error!("[workflow] Item {item_uuid} failed: entity operation failed");
+        const MESSAGE: &str = "entity operation failed";
+        log::error!("[workflow] Item {item_uuid} failed: {MESSAGE}");
         if let Err(e) = self
             .repo
-            .set_raw_item_status(item_uuid, "failed", Some("entity operation failed"))
+            .set_raw_item_status(item_uuid, "failed", Some(MESSAGE))
             .await
         {
             log::error!("[workflow] Failed to mark item {item_uuid} as failed: {e}");
         }
+        self.record_run_error(record_index, MESSAGE, payload).await;
         Ok(false)
     }
 
@@ -49,6 +54,8 @@ impl<'a> WorkflowItemStatusHandler<'a> {
         &self,
         error: r_data_core_core::error::Error,
         item_uuid: Uuid,
+        record_index: i64,
+        payload: &JsonValue,
     ) -> r_data_core_core::error::Result<bool> {
         let error_msg = error.to_string();
         log::error!("[workflow] Item {item_uuid} failed: {error_msg}");
@@ -81,9 +88,25 @@ impl<'a> WorkflowItemStatusHandler<'a> {
             log::error!("[workflow] Failed to insert run log: {log_err}");
         }
 
+        self.record_run_error(record_index, &error_msg, payload)
+            .await;
         Ok(false)
     }
 
+    /// Keep the failed record with its position so it can be inspected after the run
+    async fn record_run_error(&self, record_index: i64, error_msg: &str, payload: &JsonValue) {
+        if let Err(e) = self
+            .repo
+            .insert_run_error(self.run_uuid, record_index, error_msg, payload)
+            .await
+        {
+            log::error!(
+                "[workflow] Failed to record error of record {record_index} in run {}: {e}",
+                self.run_uuid
+            );
+        }
+    }
+
     async fn log_status_update_error(
         &self,
         item_uuid: Uuid,
@@ -40,9 +40,16 @@ impl<'a> WorkflowStepExecutor<'a> {
         let mut previous_step_output: Option<JsonValue> = None;
 
         for step_idx in 0..self.program.steps.len() {
-            let (mut normalized, transform) =
+            let Some((mut normalized, transform)) =
                 self.program
-                    .prepare_step(step_idx, payload, previous_step_output.as_ref())?;
+                    .prepare_step(step_idx, payload, previous_step_output.as_ref())?
+            else {
+                previous_step_output = Some(DslProgram::skipped_step_input(
+                    payload,
+                    previous_step_output.take(),
+                ));
+                continue;
+            };
 
             if Self::is_async_transform(transform) {
                 if let Some(de_service) = self.ctx.dynamic_entity_service {
@@ -53,6 +60,7 @@ impl<'a> WorkflowStepExecutor<'a> {
                         self.run_uuid,
                         self.ctx.jwt,
                         self.ctx.mail,
+                        &self.ctx.clock,
                     )
                     .await
                     {
@@ -1,14 +1,18 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 pub mod adapter;
+pub mod completion_webhook;
 pub mod entity_persistence;
+mod http_retries;
 pub mod item_processing;
 pub mod outbox;
 pub mod output_handling;
 pub mod post_run;
+pub mod run_events;
 pub mod service;
 pub mod transform_execution;
 pub mod value_formatting;
 
 pub use adapter::WorkflowRepositoryAdapter;
+pub use run_events::{RunEvent, RunEventBus};
 pub use service::WorkflowService;
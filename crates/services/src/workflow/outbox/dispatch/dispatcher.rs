@@ -1,14 +1,16 @@
 use uuid::Uuid;
 
 use r_data_core_core::outbox::{
-    OutboxMessage, WORKFLOW_FETCH_ENQUEUE_KIND, WORKFLOW_FETCH_TOPIC, WORKFLOW_PUSH_ENQUEUE_KIND,
-    WORKFLOW_PUSH_TOPIC,
+    OutboxMessage, ENTITY_WEBHOOK_KIND, ENTITY_WEBHOOK_TOPIC, WORKFLOW_FETCH_ENQUEUE_KIND,
+    WORKFLOW_FETCH_TOPIC, WORKFLOW_PUSH_ENQUEUE_KIND, WORKFLOW_PUSH_TOPIC,
 };
 use r_data_core_persistence::{OutboxRepositoryTrait, WorkflowRepositoryTrait};
 use r_data_core_workflow::data::job_queue::JobQueue;
 use r_data_core_workflow::data::jobs::FetchAndStageJob;
 
-use super::super::policy::OutboxRetryPolicy;
+use super::super::policy::{workflow_outbox_retry_at, OutboxRetryPolicy};
+use super::super::support::is_permanent_outbox_failure;
+use super::super::WORKFLOW_OUTBOX_MAX_ATTEMPTS;
 
 /// Dispatcher component responsible for outbox record delivery and state transitions.
 pub struct WorkflowOutboxDispatcher<'a> {
@@ -93,6 +95,10 @@ impl<'a> WorkflowOutboxDispatcher<'a> {
             return self.dispatch_push_record(record).await;
         }
 
+        if record.topic == ENTITY_WEBHOOK_TOPIC && record.kind == ENTITY_WEBHOOK_KIND {
+            return self.dispatch_entity_webhook_record(record).await;
+        }
+
         self.outbox_repo
             .mark_dead_letter(record.uuid, "Unsupported outbox message type", locked_by)
             .await?;
@@ -109,4 +115,33 @@ impl<'a> WorkflowOutboxDispatcher<'a> {
             .mark_dead_letter(record_uuid, message, locked_by)
             .await
     }
+
+    /// Schedule a retry for a failed HTTP delivery, or dead-letter it when the failure is
+    /// permanent or the attempts are exhausted.
+    pub(super) async fn record_delivery_failure(
+        &self,
+        record: &OutboxMessage,
+        error: &r_data_core_core::error::Error,
+        locked_by: Option<&str>,
+    ) -> r_data_core_core::error::Result<()> {
+        let next_attempt_count = record.attempt_count.saturating_add(1);
+        if next_attempt_count >= WORKFLOW_OUTBOX_MAX_ATTEMPTS || is_permanent_outbox_failure(error)
+        {
+            return self
+                .outbox_repo
+                .mark_dead_letter(record.uuid, &error.to_string(), locked_by)
+                .await;
+        }
+        let default_policy = OutboxRetryPolicy::default();
+        let policy = self.retry_policy.map_or(&default_policy, |policy| policy);
+        let next_available_at = workflow_outbox_retry_at(next_attempt_count, policy);
+        self.outbox_repo
+            .mark_retry(
+                record.uuid,
+                &error.to_string(),
+                next_available_at,
+                locked_by,
+            )
+            .await
+    }
 }
@@ -5,6 +5,7 @@ mod enqueue;
 mod fetch;
 mod push;
 mod status;
+mod webhook;
 
 pub use dispatcher::WorkflowOutboxDispatcher;
 pub use enqueue::enqueue_workflow_push_outbox;
@@ -4,13 +4,14 @@ use r_data_core_workflow::data::adapters::auth::create_auth_provider;
 use r_data_core_workflow::data::adapters::destination::uri::UriDestination;
 use r_data_core_workflow::data::adapters::destination::DataDestination;
 use r_data_core_workflow::data::adapters::destination::{DestinationContext, HttpMethod};
+use r_data_core_workflow::data::adapters::http::HttpRetryLog;
 use r_data_core_workflow::dsl::{DslProgram, OutputMode, ToDef};
 
 use super::super::payload::WorkflowPushOutboxPayload;
-use super::super::policy::{workflow_outbox_retry_at, OutboxRetryPolicy};
-use super::super::support::{is_permanent_outbox_failure, parse_http_method};
-use super::super::{WORKFLOW_OUTBOX_MAX_ATTEMPTS, WORKFLOW_PUSH_OUTBOX_MAX_DATA_BYTES};
+use super::super::support::parse_http_method;
+use super::super::WORKFLOW_PUSH_OUTBOX_MAX_DATA_BYTES;
 use super::dispatcher::WorkflowOutboxDispatcher;
+use crate::workflow::http_retries::log_http_retries;
 
 impl WorkflowOutboxDispatcher<'_> {
     /// Dispatch a workflow push outbox record to its HTTP destination.
@@ -173,6 +174,7 @@ impl WorkflowOutboxDispatcher<'_> {
             auth: auth_provider,
             method: Some(method),
             config: payload.destination_config.clone(),
+            retries: HttpRetryLog::default(),
         };
         let destination = UriDestination::new();
         let data = match base64::engine::general_purpose::STANDARD.decode(payload.data_base64) {
@@ -199,6 +201,16 @@ impl WorkflowOutboxDispatcher<'_> {
             return Ok(());
         }
         let result = destination.push(&dest_ctx, bytes::Bytes::from(data)).await;
+        if let Some(workflow_repo) = self.workflow_repo {
+            log_http_retries(
+                workflow_repo,
+                payload.run_uuid,
+                &dest_ctx.retries,
+                "Push",
+                Some(payload.item_uuid),
+            )
+            .await;
+        }
         match result {
             Ok(()) => {
                 self.outbox_repo
@@ -206,25 +218,7 @@ impl WorkflowOutboxDispatcher<'_> {
                     .await?;
             }
             Err(e) => {
-                let default_policy = OutboxRetryPolicy::default();
-                let policy = self.retry_policy.map_or(&default_policy, |policy| policy);
-                let next_attempt_count = record.attempt_count.saturating_add(1);
-                if next_attempt_count >= WORKFLOW_OUTBOX_MAX_ATTEMPTS {
-                    self.outbox_repo
-                        .mark_dead_letter(record.uuid, &e.to_string(), locked_by)
-                        .await?;
-                } else {
-                    if is_permanent_outbox_failure(&e) {
-                        self.outbox_repo
-                            .mark_dead_letter(record.uuid, &e.to_string(), locked_by)
-                            .await?;
-                        return Ok(());
-                    }
-                    let next_available_at = workflow_outbox_retry_at(next_attempt_count, policy);
-                    self.outbox_repo
-                        .mark_retry(record.uuid, &e.to_string(), next_available_at, locked_by)
-                        .await?;
-                }
+                self.record_delivery_failure(record, &e, locked_by).await?;
             }
         }
 
@@ -0,0 +1,53 @@
+use r_data_core_core::entity_webhook::EntityWebhookPayload;
+use r_data_core_core::outbox::OutboxMessage;
+use r_data_core_workflow::data::adapters::destination::uri::UriDestination;
+use r_data_core_workflow::data::adapters::destination::DataDestination;
+use r_data_core_workflow::data::adapters::destination::{DestinationContext, HttpMethod};
+use r_data_core_workflow::data::adapters::http::HttpRetryLog;
+
+use super::dispatcher::WorkflowOutboxDispatcher;
+
+impl WorkflowOutboxDispatcher<'_> {
+    /// POST an entity field-change webhook payload to its subscription URL.
+    ///
+    /// # Errors
+    /// Returns an error if the database status update fails.
+    pub async fn dispatch_entity_webhook_record(
+        &self,
+        record: &OutboxMessage,
+    ) -> r_data_core_core::error::Result<()> {
+        let locked_by = self.locked_by.or(record.locked_by.as_deref());
+
+        let payload: EntityWebhookPayload = match serde_json::from_value(record.payload.clone()) {
+            Ok(payload) => payload,
+            Err(e) => {
+                self.mark_dead_letter_for_record(
+                    record.uuid,
+                    &format!("Invalid entity webhook payload: {e}"),
+                    locked_by,
+                )
+                .await?;
+                return Ok(());
+            }
+        };
+
+        let dest_ctx = DestinationContext {
+            auth: None,
+            method: Some(HttpMethod::Post),
+            config: serde_json::json!({ "uri": payload.url }),
+            retries: HttpRetryLog::default(),
+        };
+        let body = serde_json::to_vec(&payload)?;
+        match UriDestination::new()
+            .push(&dest_ctx, bytes::Bytes::from(body))
+            .await
+        {
+            Ok(()) => {
+                self.outbox_repo
+                    .mark_delivered(record.uuid, locked_by)
+                    .await
+            }
+            Err(e) => self.record_delivery_failure(record, &e, locked_by).await,
+        }
+    }
+}
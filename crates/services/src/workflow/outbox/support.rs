@@ -14,6 +14,7 @@ pub(super) const fn is_permanent_outbox_failure(error: &Error) -> bool {
         error,
         Error::Config(_)
             | Error::Validation(_)
+            | Error::FieldValidation(_)
             | Error::Deserialization(_)
             | Error::NotFound(_)
             | Error::FieldNotFound(_)
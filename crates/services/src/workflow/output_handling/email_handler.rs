@@ -38,9 +38,9 @@ impl<'a> WorkflowEmailOutputHandler<'a> {
             return Ok(true);
         };
 
-        let to_addrs = resolve_string_operands(to, produced);
+        let to_addrs = resolve_string_operands(to, produced, &self.ctx.clock);
         let cc_addrs = cc.as_ref().map_or_else(Vec::new, |cc_list| {
-            resolve_string_operands(cc_list, produced)
+            resolve_string_operands(cc_list, produced, &self.ctx.clock)
         });
 
         if to_addrs.is_empty() {
@@ -1,3 +1,4 @@
+use crate::workflow::http_retries::log_http_retries;
 use crate::workflow::item_processing::WorkflowItemContext;
 use crate::workflow::outbox::enqueue_workflow_push_outbox;
 use crate::workflow::outbox::PushDispatchMode;
@@ -158,6 +159,7 @@ impl<'a> WorkflowPushOutputHandler<'a> {
                 auth: auth_provider,
                 method: method.as_ref().copied(),
                 config: destination.config.clone(),
+                retries: r_data_core_workflow::data::adapters::http::HttpRetryLog::default(),
             },
         )
     }
@@ -205,6 +207,14 @@ impl<'a> WorkflowPushOutputHandler<'a> {
     ) -> r_data_core_core::error::Result<()> {
         use bytes::Bytes;
         let result = dest_adapter.push(dest_ctx, Bytes::from(data_bytes)).await;
+        log_http_retries(
+            self.ctx.repo.as_ref(),
+            run_uuid,
+            &dest_ctx.retries,
+            "Push",
+            Some(item_uuid),
+        )
+        .await;
 
         if let Err(ref e) = result {
             let _ = self
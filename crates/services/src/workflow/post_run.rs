@@ -56,6 +56,10 @@ fn resolve_const_recipients(operands: &[StringOperand]) -> Vec<String> {
         .iter()
         .filter_map(|op| match op {
             StringOperand::ConstString { value } => Some(value.clone()),
+            StringOperand::Now { .. } | StringOperand::Today { .. } => {
+                log::warn!("Post-run email: time operand is not a valid recipient -- skipping");
+                None
+            }
             StringOperand::Field { field } => {
                 log::warn!(
                     "Post-run email: field ref '{field}' used but no item context available -- skipping"
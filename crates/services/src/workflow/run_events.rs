@@ -0,0 +1,545 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use r_data_core_persistence::WorkflowRepositoryTrait;
+use r_data_core_workflow::data::requests::{CreateWorkflowRequest, UpdateWorkflowRequest};
+use r_data_core_workflow::data::{
+    IdempotencyClaim, RunStatus, Workflow, WorkflowRunError, WorkflowRunMetrics,
+    WorkflowRunProgress, WorkflowRunStats,
+};
+
+/// Events buffered per subscriber before it starts lagging
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Progress of a workflow run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RunEvent {
+    /// The run changed its status
+    Status {
+        run_uuid: Uuid,
+        status: RunStatus,
+        processed_items: Option<i64>,
+        failed_items: Option<i64>,
+        error: Option<String>,
+    },
+    /// A batch of staged items was processed
+    Progress {
+        run_uuid: Uuid,
+        processed_count: i64,
+        failed_count: i64,
+        total_count: i64,
+    },
+    /// A log entry was appended to the run
+    Log {
+        run_uuid: Uuid,
+        ts: String,
+        level: String,
+        message: String,
+        meta: Option<serde_json::Value>,
+    },
+}
+
+impl RunEvent {
+    #[must_use]
+    pub const fn run_uuid(&self) -> Uuid {
+        match self {
+            Self::Status { run_uuid, .. }
+            | Self::Progress { run_uuid, .. }
+            | Self::Log { run_uuid, .. } => *run_uuid,
+        }
+    }
+
+    /// SSE event name: `status`, `progress` or `log`
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Status { .. } => "status",
+            Self::Progress { .. } => "progress",
+            Self::Log { .. } => "log",
+        }
+    }
+
+    /// Whether this event ends the run
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        matches!(self, Self::Status { status, .. } if status.is_terminal())
+    }
+}
+
+/// In-process fan-out of run events
+///
+/// Only runs executed by this process are published; subscribers watching runs of other
+/// processes have to poll the database.
+#[derive(Clone)]
+pub struct RunEventBus {
+    sender: broadcast::Sender<RunEvent>,
+}
+
+impl Default for RunEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunEventBus {
+    #[must_use]
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    /// Publish an event to all current subscribers; dropped when nobody listens
+    pub fn publish(&self, event: RunEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    /// Receive events of all runs published from now on
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<RunEvent> {
+        self.sender.subscribe()
+    }
+}
+
+/// Workflow repository publishing run status changes, progress and log entries to a [`RunEventBus`]
+/// once they are stored
+pub struct RunEventPublishingRepository {
+    inner: Arc<dyn WorkflowRepositoryTrait>,
+    bus: RunEventBus,
+}
+
+impl RunEventPublishingRepository {
+    #[must_use]
+    pub fn new(inner: Arc<dyn WorkflowRepositoryTrait>, bus: RunEventBus) -> Self {
+        Self { inner, bus }
+    }
+
+    fn publish_status(
+        &self,
+        run_uuid: Uuid,
+        status: RunStatus,
+        counts: Option<(i64, i64)>,
+        error: Option<&str>,
+    ) {
+        self.bus.publish(RunEvent::Status {
+            run_uuid,
+            status,
+            processed_items: counts.map(|(processed, _)| processed),
+            failed_items: counts.map(|(_, failed)| failed),
+            error: error.map(ToString::to_string),
+        });
+    }
+}
+
+#[async_trait]
+impl WorkflowRepositoryTrait for RunEventPublishingRepository {
+    async fn list_all(&self) -> r_data_core_core::error::Result<Vec<Workflow>> {
+        self.inner.list_all().await
+    }
+
+    async fn list_paginated(
+        &self,
+        limit: i64,
+        offset: i64,
+        sort_by: Option<String>,
+        sort_order: Option<String>,
+    ) -> r_data_core_core::error::Result<Vec<Workflow>> {
+        self.inner
+            .list_paginated(limit, offset, sort_by, sort_order)
+            .await
+    }
+
+    async fn count_all(&self) -> r_data_core_core::error::Result<i64> {
+        self.inner.count_all().await
+    }
+
+    async fn get_by_uuid(&self, uuid: Uuid) -> r_data_core_core::error::Result<Option<Workflow>> {
+        self.inner.get_by_uuid(uuid).await
+    }
+
+    async fn create(
+        &self,
+        req: &CreateWorkflowRequest,
+        created_by: Uuid,
+    ) -> r_data_core_core::error::Result<Uuid> {
+        self.inner.create(req, created_by).await
+    }
+
+    async fn update(
+        &self,
+        uuid: Uuid,
+        req: &UpdateWorkflowRequest,
+        updated_by: Uuid,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner.update(uuid, req, updated_by).await
+    }
+
+    async fn delete(&self, uuid: Uuid) -> r_data_core_core::error::Result<()> {
+        self.inner.delete(uuid).await
+    }
+
+    async fn set_paused(&self, uuid: Uuid, paused: bool) -> r_data_core_core::error::Result<bool> {
+        self.inner.set_paused(uuid, paused).await
+    }
+
+    async fn list_scheduled_consumers(
+        &self,
+    ) -> r_data_core_core::error::Result<Vec<(Uuid, String)>> {
+        self.inner.list_scheduled_consumers().await
+    }
+
+    async fn mark_run_running(&self, run_uuid: Uuid) -> r_data_core_core::error::Result<()> {
+        self.inner.mark_run_running(run_uuid).await?;
+        self.publish_status(run_uuid, RunStatus::Running, None, None);
+        Ok(())
+    }
+
+    async fn mark_run_success(
+        &self,
+        run_uuid: Uuid,
+        processed: i64,
+        failed: i64,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .mark_run_success(run_uuid, processed, failed)
+            .await?;
+        self.publish_status(
+            run_uuid,
+            RunStatus::Success,
+            Some((processed, failed)),
+            None,
+        );
+        Ok(())
+    }
+
+    async fn mark_run_failure(
+        &self,
+        run_uuid: Uuid,
+        message: &str,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner.mark_run_failure(run_uuid, message).await?;
+        self.publish_status(run_uuid, RunStatus::Failed, None, Some(message));
+        Ok(())
+    }
+
+    async fn get_run_status(
+        &self,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<Option<String>> {
+        self.inner.get_run_status(run_uuid).await
+    }
+
+    async fn update_run_progress(
+        &self,
+        run_uuid: Uuid,
+        processed: i64,
+        failed: i64,
+        total: i64,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .update_run_progress(run_uuid, processed, failed, total)
+            .await?;
+        self.bus.publish(RunEvent::Progress {
+            run_uuid,
+            processed_count: processed,
+            failed_count: failed,
+            total_count: total,
+        });
+        Ok(())
+    }
+
+    async fn get_run_progress(
+        &self,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<Option<WorkflowRunProgress>> {
+        self.inner.get_run_progress(run_uuid).await
+    }
+
+    async fn set_run_config_override(
+        &self,
+        run_uuid: Uuid,
+        config_override: &serde_json::Value,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .set_run_config_override(run_uuid, config_override)
+            .await
+    }
+
+    async fn get_run_config_override(
+        &self,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<Option<serde_json::Value>> {
+        self.inner.get_run_config_override(run_uuid).await
+    }
+
+    async fn insert_run_queued(
+        &self,
+        workflow_uuid: Uuid,
+        trigger_id: Uuid,
+    ) -> r_data_core_core::error::Result<Uuid> {
+        self.inner
+            .insert_run_queued(workflow_uuid, trigger_id)
+            .await
+    }
+
+    async fn insert_run_queued_with_fetch_outbox(
+        &self,
+        workflow_uuid: Uuid,
+        trigger_id: Uuid,
+    ) -> r_data_core_core::error::Result<(Uuid, Uuid)> {
+        self.inner
+            .insert_run_queued_with_fetch_outbox(workflow_uuid, trigger_id)
+            .await
+    }
+
+    async fn list_runs_paginated(
+        &self,
+        workflow_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> r_data_core_core::error::Result<(
+        Vec<(
+            Uuid,
+            String,
+            Option<String>,
+            Option<String>,
+            Option<i64>,
+            Option<i64>,
+        )>,
+        i64,
+    )> {
+        self.inner
+            .list_runs_paginated(workflow_uuid, limit, offset)
+            .await
+    }
+
+    async fn list_run_logs_paginated(
+        &self,
+        run_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> r_data_core_core::error::Result<(
+        Vec<(Uuid, String, String, String, Option<serde_json::Value>)>,
+        i64,
+    )> {
+        self.inner
+            .list_run_logs_paginated(run_uuid, limit, offset)
+            .await
+    }
+
+    async fn run_exists(&self, run_uuid: Uuid) -> r_data_core_core::error::Result<bool> {
+        self.inner.run_exists(run_uuid).await
+    }
+
+    async fn run_metrics(
+        &self,
+        workflow_uuid: Uuid,
+        window_secs: i64,
+    ) -> r_data_core_core::error::Result<WorkflowRunMetrics> {
+        self.inner.run_metrics(workflow_uuid, window_secs).await
+    }
+
+    async fn run_statistics(
+        &self,
+        workflow_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<WorkflowRunStats> {
+        self.inner.run_statistics(workflow_uuid).await
+    }
+
+    async fn list_all_runs_paginated(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> r_data_core_core::error::Result<(
+        Vec<(
+            Uuid,
+            String,
+            Option<String>,
+            Option<String>,
+            Option<i64>,
+            Option<i64>,
+        )>,
+        i64,
+    )> {
+        self.inner.list_all_runs_paginated(limit, offset).await
+    }
+
+    async fn insert_run_log(
+        &self,
+        run_uuid: Uuid,
+        level: &str,
+        message: &str,
+        meta: Option<serde_json::Value>,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .insert_run_log(run_uuid, level, message, meta.clone())
+            .await?;
+        self.bus.publish(RunEvent::Log {
+            run_uuid,
+            ts: OffsetDateTime::now_utc()
+                .format(&Rfc3339)
+                .unwrap_or_default(),
+            level: level.to_string(),
+            message: message.to_string(),
+            meta,
+        });
+        Ok(())
+    }
+
+    async fn insert_raw_items(
+        &self,
+        workflow_uuid: Uuid,
+        run_uuid: Uuid,
+        payloads: Vec<serde_json::Value>,
+    ) -> r_data_core_core::error::Result<i64> {
+        self.inner
+            .insert_raw_items(workflow_uuid, run_uuid, payloads)
+            .await
+    }
+
+    async fn count_raw_items_for_run(
+        &self,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<i64> {
+        self.inner.count_raw_items_for_run(run_uuid).await
+    }
+
+    async fn mark_raw_items_processed(
+        &self,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner.mark_raw_items_processed(run_uuid).await
+    }
+
+    async fn fetch_staged_raw_items(
+        &self,
+        run_uuid: Uuid,
+        limit: i64,
+    ) -> r_data_core_core::error::Result<Vec<(Uuid, i64, serde_json::Value)>> {
+        self.inner.fetch_staged_raw_items(run_uuid, limit).await
+    }
+
+    async fn set_raw_item_status(
+        &self,
+        item_uuid: Uuid,
+        status: &str,
+        error: Option<&str>,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .set_raw_item_status(item_uuid, status, error)
+            .await
+    }
+
+    async fn insert_run_error(
+        &self,
+        run_uuid: Uuid,
+        record_index: i64,
+        error_message: &str,
+        raw_payload: &serde_json::Value,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .insert_run_error(run_uuid, record_index, error_message, raw_payload)
+            .await
+    }
+
+    async fn list_run_errors_paginated(
+        &self,
+        run_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> r_data_core_core::error::Result<(Vec<WorkflowRunError>, i64)> {
+        self.inner
+            .list_run_errors_paginated(run_uuid, limit, offset)
+            .await
+    }
+
+    async fn get_workflow_uuid_for_run(
+        &self,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<Option<Uuid>> {
+        self.inner.get_workflow_uuid_for_run(run_uuid).await
+    }
+
+    async fn claim_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        ttl_secs: i64,
+    ) -> r_data_core_core::error::Result<IdempotencyClaim> {
+        self.inner
+            .claim_idempotency_key(workflow_uuid, idempotency_key, ttl_secs)
+            .await
+    }
+
+    async fn complete_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        run_uuid: Uuid,
+        response: &serde_json::Value,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .complete_idempotency_key(workflow_uuid, idempotency_key, run_uuid, response)
+            .await
+    }
+
+    async fn release_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+    ) -> r_data_core_core::error::Result<()> {
+        self.inner
+            .release_idempotency_key(workflow_uuid, idempotency_key)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_bus_delivers_events_to_subscribers() {
+        let bus = RunEventBus::new();
+        let mut events = bus.subscribe();
+        let run_uuid = Uuid::now_v7();
+        let event = RunEvent::Status {
+            run_uuid,
+            status: RunStatus::Success,
+            processed_items: Some(2),
+            failed_items: Some(0),
+            error: None,
+        };
+
+        bus.publish(event.clone());
+
+        let received = events.recv().await.expect("event");
+        assert_eq!(received, event);
+        assert_eq!(received.run_uuid(), run_uuid);
+        assert_eq!(received.name(), "status");
+        assert!(received.is_terminal());
+    }
+
+    #[test]
+    fn test_event_serializes_with_type_tag() {
+        let event = RunEvent::Log {
+            run_uuid: Uuid::nil(),
+            ts: "2026-01-01T00:00:00Z".to_string(),
+            level: "info".to_string(),
+            message: "Fetched 3 items".to_string(),
+            meta: None,
+        };
+        let json = serde_json::to_value(&event).expect("serializable");
+        assert_eq!(json["type"], "log");
+        assert_eq!(json["message"], "Fetched 3 items");
+        assert!(!event.is_terminal());
+    }
+}
@@ -35,6 +35,17 @@ impl WorkflowService {
         PushDispatchMode::Direct
     }
 
+    /// Store the item counts of a running run; failures only delay the progress shown
+    async fn store_run_progress(&self, run_uuid: Uuid, processed: i64, failed: i64, total: i64) {
+        if let Err(e) = self
+            .repo
+            .update_run_progress(run_uuid, processed, failed, total)
+            .await
+        {
+            log::warn!("Failed to store progress of run {run_uuid}: {e}");
+        }
+    }
+
     /// Process staged raw items for a run using the workflow DSL
     ///
     /// # Errors
@@ -49,21 +60,23 @@ impl WorkflowService {
             r_data_core_core::error::Error::NotFound("Workflow not found".to_string())
         })?;
 
-        // Build DSL program from config; require presence and validation
-        let program = match r_data_core_workflow::dsl::DslProgram::from_config(&wf.config) {
+        // Build DSL program from config (plus any per-run override); require presence and validation
+        let config = self.run_config(&wf, run_uuid).await?;
+        let program = match r_data_core_workflow::dsl::DslProgram::from_config(&config) {
             Ok(p) => {
                 if let Err(e) = p.validate() {
                     return self
                         .fail_entire_run_due_to_invalid_dsl(run_uuid, e.to_string())
                         .await;
                 }
-                p
+                p.with_clock(self.clock)
             }
-            _ => {
+            // e.g. a strict variable the run's override did not supply
+            Err(e) => {
                 return self
                     .fail_entire_run_due_to_invalid_dsl(
                         run_uuid,
-                        "Missing or invalid DSL configuration".to_string(),
+                        format!("Missing or invalid DSL configuration: {e}"),
                     )
                     .await;
             }
@@ -72,8 +85,14 @@ impl WorkflowService {
         let run_started_at = time::OffsetDateTime::now_utc();
         let mut processed = 0_i64;
         let mut failed = 0_i64;
+        let total = self.repo.count_raw_items_for_run(run_uuid).await?;
+        self.store_run_progress(run_uuid, processed, failed, total)
+            .await;
         loop {
-            let items = self.repo.fetch_staged_raw_items(run_uuid, 200).await?;
+            let items = self
+                .repo
+                .fetch_staged_raw_items(run_uuid, self.staged_batch_size)
+                .await?;
             if items.is_empty() {
                 break;
             }
@@ -93,17 +112,23 @@ impl WorkflowService {
                 mail: &mail,
                 workflow_name: Some(&wf.name),
                 versioning_disabled: wf.versioning_disabled,
+                clock: self.clock,
             };
             let executor =
                 WorkflowPipelineExecutor::new(&program, workflow_uuid, run_uuid, &ctx, false);
-            for (item_uuid, payload) in items {
-                let success = executor.process_item(&payload, item_uuid).await?;
+            for (item_uuid, seq_no, payload) in items {
+                // Failing records are recorded as run errors and counted, not propagated
+                let success = executor
+                    .process_item(&payload, item_uuid, seq_no - 1)
+                    .await?;
                 if success {
                     processed += 1;
                 } else {
                     failed += 1;
                 }
             }
+            self.store_run_progress(run_uuid, processed, failed, total)
+                .await;
         }
 
         // Execute post-run hooks if configured
@@ -153,14 +178,8 @@ impl WorkflowService {
         })?;
 
         let program =
-            r_data_core_workflow::dsl::DslProgram::from_config(&wf.config).map_err(|e| {
-                r_data_core_core::error::Error::Validation(format!(
-                    "Invalid DSL configuration: {e}"
-                ))
-            })?;
-        program.validate().map_err(|e| {
-            r_data_core_core::error::Error::Validation(format!("DSL validation failed: {e}"))
-        })?;
+            r_data_core_workflow::dsl::DslProgram::from_config(&wf.config)?.with_clock(self.clock);
+        program.validate()?;
 
         // Create a run for logging/history
         let run_uuid = self
@@ -185,6 +204,7 @@ impl WorkflowService {
             mail: &mail,
             workflow_name: Some(&wf.name),
             versioning_disabled: wf.versioning_disabled,
+            clock: self.clock,
         };
         let executor = WorkflowPipelineExecutor::new(&program, workflow_uuid, run_uuid, &ctx, true);
 
@@ -223,7 +243,7 @@ impl WorkflowService {
             if items.is_empty() {
                 break;
             }
-            for (item_uuid, _payload) in items {
+            for (item_uuid, _seq_no, _payload) in items {
                 let _ = self
                     .repo
                     .set_raw_item_status(item_uuid, "failed", Some("Invalid DSL"))
@@ -0,0 +1,55 @@
+use uuid::Uuid;
+
+use r_data_core_core::error::Result;
+use r_data_core_workflow::data::IdempotencyClaim;
+
+use super::WorkflowService;
+
+/// How long an `Idempotency-Key` of the ingest endpoint is remembered: 24 hours
+const INGEST_IDEMPOTENCY_TTL_SECS: i64 = 86_400;
+
+impl WorkflowService {
+    /// Claim an ingest idempotency key of a workflow for [`INGEST_IDEMPOTENCY_TTL_SECS`]
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn claim_ingest_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+    ) -> Result<IdempotencyClaim> {
+        self.repo
+            .claim_idempotency_key(workflow_uuid, idempotency_key, INGEST_IDEMPOTENCY_TTL_SECS)
+            .await
+    }
+
+    /// Remember the run and response of a claimed ingest idempotency key for replays
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn complete_ingest_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+        run_uuid: Uuid,
+        response: &serde_json::Value,
+    ) -> Result<()> {
+        self.repo
+            .complete_idempotency_key(workflow_uuid, idempotency_key, run_uuid, response)
+            .await
+    }
+
+    /// Release a claimed ingest idempotency key after a failed request so the client can retry
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails
+    pub async fn release_ingest_idempotency_key(
+        &self,
+        workflow_uuid: Uuid,
+        idempotency_key: &str,
+    ) -> Result<()> {
+        self.repo
+            .release_idempotency_key(workflow_uuid, idempotency_key)
+            .await
+    }
+}
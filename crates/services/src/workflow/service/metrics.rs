@@ -0,0 +1,90 @@
+use uuid::Uuid;
+
+use r_data_core_core::error::{Error, Result};
+use r_data_core_workflow::data::{WorkflowRunMetrics, WorkflowRunStats};
+
+use super::WorkflowService;
+
+/// Longest window metrics can be aggregated over
+const MAX_METRICS_WINDOW_SECS: i64 = 90 * 86_400;
+
+/// Parse a metrics window such as `24h` or `7d` into seconds
+///
+/// # Errors
+/// Returns a validation error if the range is malformed, zero, or longer than 90 days
+fn parse_metrics_range(range: &str) -> Result<i64> {
+    let invalid = || {
+        Error::Validation(format!(
+            "Invalid range '{range}': expected a positive number of hours or days, e.g. 24h or 7d (max 90d)"
+        ))
+    };
+    let range = range.trim();
+    let (amount, unit_secs) = if let Some(hours) = range.strip_suffix('h') {
+        (hours, 3_600)
+    } else if let Some(days) = range.strip_suffix('d') {
+        (days, 86_400)
+    } else {
+        return Err(invalid());
+    };
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    match amount.checked_mul(unit_secs) {
+        Some(secs) if secs > 0 && secs <= MAX_METRICS_WINDOW_SECS => Ok(secs),
+        _ => Err(invalid()),
+    }
+}
+
+impl WorkflowService {
+    /// Aggregate run metrics of a workflow over the given range (e.g. `7d`)
+    ///
+    /// # Errors
+    /// Returns a validation error for an invalid range, a not-found error if the workflow
+    /// does not exist, or an error if the database query fails
+    pub async fn run_metrics(
+        &self,
+        workflow_uuid: Uuid,
+        range: &str,
+    ) -> Result<WorkflowRunMetrics> {
+        let window_secs = parse_metrics_range(range)?;
+        if self.repo.get_by_uuid(workflow_uuid).await?.is_none() {
+            return Err(Error::NotFound("Workflow not found".to_string()));
+        }
+        self.repo.run_metrics(workflow_uuid, window_secs).await
+    }
+
+    /// All-time run statistics of a workflow: counts by status, last-24h counts,
+    /// durations and the latest run
+    ///
+    /// # Errors
+    /// Returns a not-found error if the workflow does not exist, or an error if the
+    /// database query fails
+    pub async fn run_statistics(&self, workflow_uuid: Uuid) -> Result<WorkflowRunStats> {
+        if self.repo.get_by_uuid(workflow_uuid).await?.is_none() {
+            return Err(Error::NotFound("Workflow not found".to_string()));
+        }
+        self.repo.run_statistics(workflow_uuid).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hours_and_days() {
+        assert_eq!(parse_metrics_range("24h").unwrap(), 86_400);
+        assert_eq!(parse_metrics_range("7d").unwrap(), 7 * 86_400);
+        assert_eq!(parse_metrics_range("90d").unwrap(), MAX_METRICS_WINDOW_SECS);
+    }
+
+    #[test]
+    fn rejects_invalid_ranges() {
+        for range in [
+            "", "7", "d", "0d", "-1d", "91d", "2161h", "7w", "1.5d", "7 d",
+        ] {
+            assert!(
+                matches!(parse_metrics_range(range), Err(Error::Validation(_))),
+                "expected '{range}' to be rejected"
+            );
+        }
+    }
+}
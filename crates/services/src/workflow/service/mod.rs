@@ -1,14 +1,22 @@
 mod execution;
+mod idempotency;
+mod metrics;
+mod overrides;
+mod pause;
 mod staging;
 
 use crate::dynamic_entity::DynamicEntityService;
+use crate::workflow::completion_webhook::validate_completion_webhook_url;
 use crate::workflow::outbox::{EnqueueWorkflowFetchUseCase, FetchDispatchMode, OutboxRetryPolicy};
-use crate::{SettingsService, SystemLogService};
+use crate::workflow::run_events::{RunEventBus, RunEventPublishingRepository};
+use crate::{AuditLogService, SettingsService, SystemLogService};
 use cron::Schedule;
+use r_data_core_core::config::WorkflowIngestLimits;
 use r_data_core_core::system_log::SystemLogResourceType;
 use r_data_core_persistence::{OutboxRepositoryTrait, WorkflowRepositoryTrait};
 use r_data_core_workflow::data::requests::{CreateWorkflowRequest, UpdateWorkflowRequest};
-use r_data_core_workflow::data::Workflow;
+use r_data_core_workflow::data::{Workflow, WorkflowRunProgress};
+use r_data_core_workflow::dsl::Clock;
 use std::str::FromStr;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -31,11 +39,24 @@ pub struct WorkflowService {
     pub queue: Option<Arc<dyn r_data_core_workflow::data::job_queue::JobQueue>>,
     /// System log service for audit logging
     pub system_log: Option<Arc<SystemLogService>>,
+    /// Audit log service recording before/after snapshots of mutations
+    pub audit_log: Option<Arc<AuditLogService>>,
+    /// Bus run status changes and log entries of this process are published to
+    pub(super) run_events: Option<RunEventBus>,
+    /// Staged items processed per batch; run progress is stored after each batch
+    pub(super) staged_batch_size: i64,
+    /// Size limits of data ingested through `run_now_upload_bytes`
+    pub(super) ingest_limits: WorkflowIngestLimits,
+    /// Clock runs resolve `now`/`today` operands with
+    pub(super) clock: Clock,
 }
 
 /// Default JWT expiration: 24 hours
 const DEFAULT_JWT_EXPIRATION: u64 = 86_400;
 
+/// Default number of staged items processed per batch
+const DEFAULT_STAGED_BATCH_SIZE: i64 = 200;
+
 impl WorkflowService {
     pub fn new(repo: Arc<dyn WorkflowRepositoryTrait>) -> Self {
         Self {
@@ -51,6 +72,11 @@ impl WorkflowService {
             mail_service: None,
             queue: None,
             system_log: None,
+            audit_log: None,
+            run_events: None,
+            staged_batch_size: DEFAULT_STAGED_BATCH_SIZE,
+            ingest_limits: WorkflowIngestLimits::default(),
+            clock: Clock::System,
         }
     }
 
@@ -71,6 +97,11 @@ impl WorkflowService {
             mail_service: None,
             queue: None,
             system_log: None,
+            audit_log: None,
+            run_events: None,
+            staged_batch_size: DEFAULT_STAGED_BATCH_SIZE,
+            ingest_limits: WorkflowIngestLimits::default(),
+            clock: Clock::System,
         }
     }
 
@@ -82,6 +113,13 @@ impl WorkflowService {
         self
     }
 
+    /// Set the clock runs resolve `now`/`today` operands with (system clock by default)
+    #[must_use]
+    pub const fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Set the mail service for email transforms and email to-targets
     #[must_use]
     pub fn with_mail_service(mut self, svc: Option<Arc<crate::mail::MailService>>) -> Self {
@@ -106,6 +144,49 @@ impl WorkflowService {
         self
     }
 
+    /// Set the audit log service recording before/after snapshots of mutations
+    #[must_use]
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Publish run status changes and log entries to `bus`
+    ///
+    /// Wraps the repository, so every run executed through this service is published.
+    #[must_use]
+    pub fn with_run_events(mut self, bus: RunEventBus) -> Self {
+        self.repo = Arc::new(RunEventPublishingRepository::new(self.repo, bus.clone()));
+        self.run_events = Some(bus);
+        self
+    }
+
+    /// Bus this service publishes run events to, if any
+    #[must_use]
+    pub const fn run_events(&self) -> Option<&RunEventBus> {
+        self.run_events.as_ref()
+    }
+
+    /// Set the number of staged items processed per batch (at least one)
+    #[must_use]
+    pub fn with_staged_batch_size(mut self, batch_size: i64) -> Self {
+        self.staged_batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set the size limits of ingested data
+    #[must_use]
+    pub const fn with_ingest_limits(mut self, limits: WorkflowIngestLimits) -> Self {
+        self.ingest_limits = limits;
+        self
+    }
+
+    /// Size limits of ingested data
+    #[must_use]
+    pub const fn ingest_limits(&self) -> WorkflowIngestLimits {
+        self.ingest_limits
+    }
+
     /// Attach an outbox repository for deferred workflow deliveries.
     #[must_use]
     pub fn with_outbox_repository(
@@ -196,18 +277,10 @@ impl WorkflowService {
                 r_data_core_core::error::Error::Validation(format!("Invalid cron schedule: {e}"))
             })?;
         }
-        // Strict DSL: parse and validate
-        let program =
-            r_data_core_workflow::dsl::DslProgram::from_config(&req.config).map_err(|e| {
-                r_data_core_core::error::Error::Validation(format!(
-                    "Invalid workflow DSL configuration: {e}"
-                ))
-            })?;
-        program.validate().map_err(|e| {
-            r_data_core_core::error::Error::Validation(format!(
-                "Workflow DSL validation failed: {e}"
-            ))
-        })?;
+        validate_completion_webhook_url(req.completion_webhook_url.as_deref())?;
+        // Strict DSL: parse and validate; strict variables may still be supplied by a run
+        let program = r_data_core_workflow::dsl::DslProgram::from_stored_config(&req.config)?;
+        program.validate()?;
         let uuid = self.repo.create(req, created_by).await?;
 
         if let Some(ref log) = self.system_log {
@@ -221,6 +294,23 @@ impl WorkflowService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            match self.repo.get_by_uuid(uuid).await {
+                Ok(Some(created)) => {
+                    audit
+                        .record_created(
+                            Some(created_by),
+                            SystemLogResourceType::Workflow,
+                            uuid,
+                            &created,
+                        )
+                        .await;
+                }
+                Ok(None) => {}
+                Err(e) => log::error!("Failed to load workflow {uuid} for audit log: {e}"),
+            }
+        }
+
         Ok(uuid)
     }
 
@@ -239,18 +329,16 @@ impl WorkflowService {
                 r_data_core_core::error::Error::Validation(format!("Invalid cron schedule: {e}"))
             })?;
         }
-        // Strict DSL: parse and validate
-        let program =
-            r_data_core_workflow::dsl::DslProgram::from_config(&req.config).map_err(|e| {
-                r_data_core_core::error::Error::Validation(format!(
-                    "Invalid workflow DSL configuration: {e}"
-                ))
-            })?;
-        program.validate().map_err(|e| {
-            r_data_core_core::error::Error::Validation(format!(
-                "Workflow DSL validation failed: {e}"
-            ))
-        })?;
+        validate_completion_webhook_url(req.completion_webhook_url.as_deref())?;
+        // Strict DSL: parse and validate; strict variables may still be supplied by a run
+        let program = r_data_core_workflow::dsl::DslProgram::from_stored_config(&req.config)?;
+        program.validate()?;
+        let before = if self.audit_log.is_some() {
+            self.repo.get_by_uuid(uuid).await.ok().flatten()
+        } else {
+            None
+        };
+
         self.repo.update(uuid, req, updated_by).await?;
 
         if let Some(ref log) = self.system_log {
@@ -264,6 +352,19 @@ impl WorkflowService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            let after = self.repo.get_by_uuid(uuid).await.ok().flatten();
+            audit
+                .record_updated(
+                    Some(updated_by),
+                    SystemLogResourceType::Workflow,
+                    uuid,
+                    before.as_ref(),
+                    after.as_ref(),
+                )
+                .await;
+        }
+
         Ok(())
     }
 
@@ -277,13 +378,10 @@ impl WorkflowService {
         actor_uuid: Uuid,
     ) -> r_data_core_core::error::Result<()> {
         // Capture the workflow name before deletion for audit log
-        let workflow_name = self
-            .repo
-            .get_by_uuid(uuid)
-            .await
-            .ok()
-            .flatten()
-            .map_or_else(|| uuid.to_string(), |w| w.name);
+        let existing = self.repo.get_by_uuid(uuid).await.ok().flatten();
+        let workflow_name = existing
+            .as_ref()
+            .map_or_else(|| uuid.to_string(), |w| w.name.clone());
 
         self.repo.delete(uuid).await?;
 
@@ -298,6 +396,17 @@ impl WorkflowService {
             .await;
         }
 
+        if let Some(ref audit) = self.audit_log {
+            audit
+                .record_deleted(
+                    Some(actor_uuid),
+                    SystemLogResourceType::Workflow,
+                    uuid,
+                    existing.as_ref(),
+                )
+                .await;
+        }
+
         Ok(())
     }
 
@@ -407,6 +516,72 @@ impl WorkflowService {
             .await
     }
 
+    /// List the per-record errors of a run of a workflow with pagination
+    ///
+    /// # Errors
+    /// Returns `NotFound` if the run does not exist or belongs to another workflow,
+    /// or an error if the database query fails
+    pub async fn list_run_errors_paginated(
+        &self,
+        workflow_uuid: Uuid,
+        run_uuid: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> r_data_core_core::error::Result<(Vec<r_data_core_workflow::data::WorkflowRunError>, i64)>
+    {
+        if self.repo.get_workflow_uuid_for_run(run_uuid).await? != Some(workflow_uuid) {
+            return Err(r_data_core_core::error::Error::NotFound(
+                "Workflow run not found".to_string(),
+            ));
+        }
+        self.repo
+            .list_run_errors_paginated(run_uuid, limit, offset)
+            .await
+    }
+
+    /// Status of a run of the given workflow
+    ///
+    /// # Errors
+    /// Returns `NotFound` if the run does not belong to the workflow, or an error if the
+    /// database query fails
+    pub async fn get_workflow_run_status(
+        &self,
+        workflow_uuid: Uuid,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<String> {
+        if self.repo.get_workflow_uuid_for_run(run_uuid).await? != Some(workflow_uuid) {
+            return Err(r_data_core_core::error::Error::NotFound(
+                "Workflow run not found".to_string(),
+            ));
+        }
+        self.repo.get_run_status(run_uuid).await?.ok_or_else(|| {
+            r_data_core_core::error::Error::NotFound("Workflow run not found".to_string())
+        })
+    }
+
+    /// Status and item counts of a run of the given workflow
+    ///
+    /// The counts are updated after each processed batch, so they can be read while the run is
+    /// still running.
+    ///
+    /// # Errors
+    /// Returns `NotFound` if the run does not belong to the workflow, or an error if the
+    /// database query fails
+    pub async fn get_run_progress(
+        &self,
+        workflow_uuid: Uuid,
+        run_uuid: Uuid,
+    ) -> r_data_core_core::error::Result<WorkflowRunProgress> {
+        if self.repo.get_workflow_uuid_for_run(run_uuid).await? != Some(workflow_uuid) {
+            return Err(r_data_core_core::error::Error::NotFound(
+                "Workflow run not found".to_string(),
+            ));
+        }
+        self.repo.get_run_progress(run_uuid).await?.ok_or_else(|| {
+            r_data_core_core::error::Error::NotFound("Workflow run not found".to_string())
+        })
+    }
+
     /// Check if a run exists
     ///
     /// # Errors
@@ -0,0 +1,199 @@
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use r_data_core_core::error::{Error, Result};
+use r_data_core_workflow::data::Workflow;
+use r_data_core_workflow::dsl::{merge_config_override, DslProgram};
+
+use super::WorkflowService;
+
+impl WorkflowService {
+    /// Merge a per-run override over the stored workflow config and validate the result
+    ///
+    /// # Errors
+    /// Returns a validation error if the override is not an object or the merged config is not
+    /// a valid DSL program, and a deserialization error if the merged config cannot be parsed
+    pub fn resolve_config_override(
+        workflow: &Workflow,
+        config_override: &JsonValue,
+    ) -> Result<JsonValue> {
+        if !config_override.is_object() {
+            return Err(Error::Validation(
+                "Config override must be a JSON object".to_string(),
+            ));
+        }
+        let merged = merge_config_override(&workflow.config, config_override);
+        let program = DslProgram::from_config(&merged)?;
+        program.validate()?;
+        Ok(merged)
+    }
+
+    /// Config used for a run: the stored config with the run's override merged on top
+    ///
+    /// # Errors
+    /// Returns an error if the override cannot be loaded
+    pub(super) async fn run_config(
+        &self,
+        workflow: &Workflow,
+        run_uuid: Uuid,
+    ) -> Result<JsonValue> {
+        Ok(self
+            .repo
+            .get_run_config_override(run_uuid)
+            .await?
+            .map_or_else(
+                || workflow.config.clone(),
+                |config_override| merge_config_override(&workflow.config, &config_override),
+            ))
+    }
+
+    async fn validated_override(
+        &self,
+        workflow_uuid: Uuid,
+        config_override: &JsonValue,
+    ) -> Result<()> {
+        let workflow = self
+            .repo
+            .get_by_uuid(workflow_uuid)
+            .await?
+            .ok_or_else(|| Error::NotFound("Workflow not found".to_string()))?;
+        Self::resolve_config_override(&workflow, config_override)?;
+        Ok(())
+    }
+
+    /// Create a queued run that executes with `config_override` merged over the stored config.
+    /// The saved workflow is left unchanged.
+    ///
+    /// # Errors
+    /// Returns an error if the workflow is missing, the override is invalid, or the database
+    /// operation fails
+    pub async fn enqueue_run_with_override(
+        &self,
+        workflow_uuid: Uuid,
+        config_override: Option<&JsonValue>,
+    ) -> Result<Uuid> {
+        let Some(config_override) = config_override else {
+            return self.enqueue_run(workflow_uuid).await;
+        };
+        self.validated_override(workflow_uuid, config_override)
+            .await?;
+        let run_uuid = self.enqueue_run(workflow_uuid).await?;
+        self.repo
+            .set_run_config_override(run_uuid, config_override)
+            .await?;
+        Ok(run_uuid)
+    }
+
+    /// Like [`Self::enqueue_run_for_fetch`], but the run executes with `config_override` merged
+    /// over the stored config. The override is stored before the fetch job is dispatched.
+    ///
+    /// # Errors
+    /// Returns an error if the workflow is missing, the override is invalid, or enqueueing fails
+    pub async fn enqueue_run_for_fetch_with_override(
+        &self,
+        workflow_uuid: Uuid,
+        config_override: Option<&JsonValue>,
+    ) -> Result<Uuid> {
+        let Some(config_override) = config_override else {
+            return self.enqueue_run_for_fetch(workflow_uuid, None).await;
+        };
+        self.validated_override(workflow_uuid, config_override)
+            .await?;
+        let run_uuid = self
+            .repo
+            .insert_run_queued(workflow_uuid, Uuid::now_v7())
+            .await?;
+        self.repo
+            .set_run_config_override(run_uuid, config_override)
+            .await?;
+        let _ = self
+            .repo
+            .insert_run_log(
+                run_uuid,
+                "info",
+                "Run enqueued with config override",
+                Some(serde_json::json!({ "config_override": config_override })),
+            )
+            .await;
+        self.dispatch_fetch_for_existing_run(workflow_uuid, run_uuid)
+            .await?;
+        Ok(run_uuid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use r_data_core_workflow::data::WorkflowKind;
+    use serde_json::json;
+
+    fn workflow() -> Workflow {
+        Workflow {
+            uuid: Uuid::now_v7(),
+            name: "override".to_string(),
+            description: None,
+            kind: WorkflowKind::Consumer,
+            enabled: true,
+            schedule_cron: None,
+            paused: false,
+            config: json!({ "steps": [] }),
+            versioning_disabled: false,
+            completion_webhook_url: None,
+        }
+    }
+
+    #[test]
+    fn invalid_overrides_map_dsl_errors_to_core_variants() {
+        assert!(matches!(
+            WorkflowService::resolve_config_override(&workflow(), &json!({ "steps": "x" })),
+            Err(Error::Deserialization(msg)) if msg == "'steps' must be an array"
+        ));
+        assert!(matches!(
+            WorkflowService::resolve_config_override(&workflow(), &json!({ "steps": [] })),
+            Err(Error::Validation(msg)) if msg == "DSL must contain at least one step"
+        ));
+        assert!(matches!(
+            WorkflowService::resolve_config_override(&workflow(), &json!([])),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn strict_variables_must_be_supplied_by_the_override() {
+        let mut strict = workflow();
+        strict.config = json!({
+            "strict_variables": true,
+            "steps": [{
+                "from": {
+                    "type": "format",
+                    "source": {
+                        "source_type": "uri",
+                        "config": { "uri": "https://${FEED_HOST}/feed.csv" },
+                        "auth": null
+                    },
+                    "format": { "format_type": "csv", "options": {} },
+                    "mapping": { "price": "price" }
+                },
+                "transform": { "type": "none" },
+                "to": {
+                    "type": "format",
+                    "output": { "mode": "api" },
+                    "format": { "format_type": "json", "options": {} },
+                    "mapping": { "price": "price" }
+                }
+            }]
+        });
+
+        let merged = WorkflowService::resolve_config_override(
+            &strict,
+            &json!({ "variables": { "FEED_HOST": "feeds.example.com" } }),
+        )
+        .unwrap();
+        assert_eq!(merged["variables"]["FEED_HOST"], "feeds.example.com");
+
+        assert!(matches!(
+            WorkflowService::resolve_config_override(&strict, &json!({ "variables": {} })),
+            Err(Error::Validation(msg)) if msg.contains("${FEED_HOST}")
+        ));
+    }
+}
@@ -0,0 +1,34 @@
+use uuid::Uuid;
+
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::system_log::SystemLogResourceType;
+
+use super::WorkflowService;
+
+impl WorkflowService {
+    /// Pause or resume scheduled runs of a workflow.
+    ///
+    /// The cron schedule is kept; the worker drops or re-adds the job on its next reconcile tick.
+    ///
+    /// # Errors
+    /// Returns a not-found error if the workflow does not exist, or an error if the update fails
+    pub async fn set_paused(&self, uuid: Uuid, paused: bool, actor_uuid: Uuid) -> Result<()> {
+        if !self.repo.set_paused(uuid, paused).await? {
+            return Err(Error::NotFound("Workflow not found".to_string()));
+        }
+
+        if let Some(ref log) = self.system_log {
+            let action = if paused { "paused" } else { "resumed" };
+            log.log_entity_updated(
+                Some(actor_uuid),
+                SystemLogResourceType::Workflow,
+                uuid,
+                &format!("Workflow schedule {action}"),
+                Some(serde_json::json!({ "paused": paused })),
+            )
+            .await;
+        }
+
+        Ok(())
+    }
+}
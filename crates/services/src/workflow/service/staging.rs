@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use uuid::Uuid;
 
 use super::WorkflowService;
+use crate::workflow::http_retries::log_http_retries;
 
 impl WorkflowService {
     fn infer_input_type(cfg: &serde_json::Value) -> Option<String> {
@@ -105,14 +106,23 @@ impl WorkflowService {
 
     /// Upload bytes (CSV/JSON) and trigger workflow run synchronously
     ///
+    /// The run is only created once the data is parsed and within the ingest limits.
+    ///
     /// # Errors
-    /// Returns an error if parsing fails or database operation fails
+    /// Returns `PayloadTooLarge` if `bytes` or the parsed records exceed the ingest limits, or an
+    /// error if parsing fails or database operation fails
     pub async fn run_now_upload_bytes(
         &self,
         workflow_uuid: Uuid,
         bytes: &[u8],
     ) -> r_data_core_core::error::Result<(Uuid, i64)> {
-        let run_uuid = self.enqueue_run(workflow_uuid).await?;
+        if bytes.len() > self.ingest_limits.max_body_bytes {
+            return Err(r_data_core_core::error::Error::PayloadTooLarge(format!(
+                "Request body of {} bytes exceeds the limit of {} bytes",
+                bytes.len(),
+                self.ingest_limits.max_body_bytes
+            )));
+        }
 
         // Read workflow config for input options
         let wf = self.repo.get_by_uuid(workflow_uuid).await?.ok_or_else(|| {
@@ -120,12 +130,7 @@ impl WorkflowService {
         })?;
 
         // Try to infer format from DSL
-        let program =
-            r_data_core_workflow::dsl::DslProgram::from_config(&wf.config).map_err(|e| {
-                r_data_core_core::error::Error::Validation(format!(
-                    "Invalid workflow DSL configuration: {e}"
-                ))
-            })?;
+        let program = r_data_core_workflow::dsl::DslProgram::from_config(&wf.config)?;
         let format_type = program
             .steps
             .first()
@@ -195,7 +200,15 @@ impl WorkflowService {
                 )))
             }
         };
+        if payloads.len() > self.ingest_limits.max_records {
+            return Err(r_data_core_core::error::Error::PayloadTooLarge(format!(
+                "{} records exceed the limit of {} records per ingest",
+                payloads.len(),
+                self.ingest_limits.max_records
+            )));
+        }
 
+        let run_uuid = self.enqueue_run(workflow_uuid).await?;
         if payloads.is_empty() {
             self.repo
                 .insert_run_log(run_uuid, "warn", "Upload contained no data rows", None)
@@ -233,12 +246,8 @@ impl WorkflowService {
         })?;
 
         // Parse DSL program to get FromDef steps
-        let program =
-            r_data_core_workflow::dsl::DslProgram::from_config(&wf.config).map_err(|e| {
-                r_data_core_core::error::Error::Validation(format!(
-                    "Failed to parse DSL for fetch: {e}"
-                ))
-            })?;
+        let config = self.run_config(&wf, run_uuid).await?;
+        let program = r_data_core_workflow::dsl::DslProgram::from_config(&config)?;
 
         // Find Format-based and Entity-based FromDef steps that need fetching
         let mut total_staged = 0_i64;
@@ -366,6 +375,7 @@ impl WorkflowService {
         let source_ctx = r_data_core_workflow::data::adapters::source::SourceContext {
             auth: auth_provider,
             config: source.config.clone(),
+            retries: r_data_core_workflow::data::adapters::http::HttpRetryLog::default(),
         };
 
         let source_adapter: Box<dyn r_data_core_workflow::data::adapters::source::DataSource> =
@@ -381,7 +391,16 @@ impl WorkflowService {
                 }
             };
 
-        let mut stream = source_adapter.fetch(&source_ctx).await.map_err(|e| {
+        let fetched = source_adapter.fetch(&source_ctx).await;
+        log_http_retries(
+            self.repo.as_ref(),
+            run_uuid,
+            &source_ctx.retries,
+            "Source",
+            None,
+        )
+        .await;
+        let mut stream = fetched.map_err(|e| {
             r_data_core_core::error::Error::Api(format!("Failed to fetch data from source: {e}"))
         })?;
         let mut all_data = Vec::new();
@@ -3,8 +3,9 @@
 use std::collections::HashMap;
 
 use r_data_core_core::error::Result;
+use r_data_core_workflow::dsl::execution::eval_string_operand;
 use r_data_core_workflow::dsl::{build_path_from_fields, transform::Transform, StringOperand};
-use r_data_core_workflow::dsl::{get_nested, set_nested};
+use r_data_core_workflow::dsl::{get_nested, set_nested, Clock};
 use serde_json::Value;
 
 /// JWT configuration for authenticate transforms
@@ -36,6 +37,7 @@ use uuid::Uuid;
 /// * `run_uuid` - Workflow run UUID
 /// * `jwt` - JWT configuration for authenticate transforms
 /// * `mail` - Mail context for `SendEmail` transforms
+/// * `clock` - Clock used to resolve `now`/`today` operands
 ///
 /// # Returns
 /// Modified normalized data with transform results
@@ -49,18 +51,19 @@ pub async fn execute_async_transform(
     run_uuid: Uuid,
     jwt: &JwtConfig<'_>,
     mail: &MailContext<'_>,
+    clock: &Clock,
 ) -> Result<()> {
     match transform {
         Transform::ResolveEntityPath(rep) => {
-            handle_resolve_entity_path(rep, normalized, de_service).await
+            handle_resolve_entity_path(rep, normalized, de_service, clock).await
         }
         Transform::GetOrCreateEntity(goc) => {
-            handle_get_or_create_entity(goc, normalized, de_service, run_uuid).await
+            handle_get_or_create_entity(goc, normalized, de_service, run_uuid, clock).await
         }
         Transform::Authenticate(auth) => {
             handle_authenticate(auth, normalized, de_service, jwt).await
         }
-        Transform::SendEmail(se) => handle_send_email(se, normalized, mail, run_uuid).await,
+        Transform::SendEmail(se) => handle_send_email(se, normalized, mail, run_uuid, clock).await,
         _ => {
             // Other transforms are handled synchronously in DSL execution
             Ok(())
@@ -72,9 +75,10 @@ async fn handle_resolve_entity_path(
     rep: &r_data_core_workflow::dsl::transform::ResolveEntityPathTransform,
     normalized: &mut Value,
     de_service: &DynamicEntityService,
+    clock: &Clock,
 ) -> Result<()> {
     // Evaluate filter operands to get filter values
-    let filters = evaluate_filter_operands(&rep.filters, normalized)?;
+    let filters = evaluate_filter_operands(&rep.filters, normalized, clock)?;
 
     // Resolve entity path
     let result = resolve_entity_path(
@@ -114,6 +118,7 @@ async fn handle_get_or_create_entity(
     normalized: &mut Value,
     de_service: &DynamicEntityService,
     run_uuid: Uuid,
+    clock: &Clock,
 ) -> Result<()> {
     // First, build the path from template
     let path = build_path_from_fields::<std::collections::hash_map::RandomState>(
@@ -124,7 +129,8 @@ async fn handle_get_or_create_entity(
     )?;
 
     // Prepare field data for creation if needed
-    let create_field_data = prepare_create_field_data(goc.create_field_data.as_ref(), normalized)?;
+    let create_field_data =
+        prepare_create_field_data(goc.create_field_data.as_ref(), normalized, clock)?;
 
     // Get or create entity (returns path, parent_uuid, entity_uuid)
     let (path_result, _parent_uuid, entity_uuid) = get_or_create_entity_by_path(
@@ -153,6 +159,7 @@ async fn handle_get_or_create_entity(
 fn evaluate_filter_operands(
     filters: &std::collections::HashMap<String, StringOperand>,
     normalized: &Value,
+    clock: &Clock,
 ) -> Result<std::collections::HashMap<String, Value>> {
     let mut result = std::collections::HashMap::new();
     for (field, operand) in filters {
@@ -164,6 +171,10 @@ fn evaluate_filter_operands(
                     ))
                 })?,
             StringOperand::ConstString { value } => Value::String(value.clone()),
+            StringOperand::Now { .. } | StringOperand::Today { .. } => Value::String(
+                eval_string_operand(normalized, operand, clock)
+                    .map_err(r_data_core_core::error::Error::Validation)?,
+            ),
         };
         result.insert(field.clone(), filter_value);
     }
@@ -173,6 +184,7 @@ fn evaluate_filter_operands(
 fn prepare_create_field_data(
     create_data: Option<&std::collections::HashMap<String, StringOperand>>,
     normalized: &Value,
+    clock: &Clock,
 ) -> Result<Option<std::collections::HashMap<String, Value>>> {
     create_data.map_or(Ok(None), |create_data| {
         let mut field_data = std::collections::HashMap::new();
@@ -185,6 +197,10 @@ fn prepare_create_field_data(
                         ))
                     })?,
                 StringOperand::ConstString { value } => Value::String(value.clone()),
+                StringOperand::Now { .. } | StringOperand::Today { .. } => Value::String(
+                    eval_string_operand(normalized, operand, clock)
+                        .map_err(r_data_core_core::error::Error::Validation)?,
+                ),
             };
             field_data.insert(field.clone(), field_value);
         }
@@ -197,6 +213,7 @@ async fn handle_send_email(
     normalized: &mut Value,
     mail: &MailContext<'_>,
     run_uuid: Uuid,
+    clock: &Clock,
 ) -> Result<()> {
     let (Some(_service), Some(queue)) = (mail.service, mail.queue) else {
         set_nested(
@@ -207,11 +224,10 @@ async fn handle_send_email(
         return Ok(());
     };
 
-    let to_addrs = resolve_string_operands(&se.to, normalized);
-    let cc_addrs = se
-        .cc
-        .as_ref()
-        .map_or_else(Vec::new, |cc| resolve_string_operands(cc, normalized));
+    let to_addrs = resolve_string_operands(&se.to, normalized, clock);
+    let cc_addrs = se.cc.as_ref().map_or_else(Vec::new, |cc| {
+        resolve_string_operands(cc, normalized, clock)
+    });
 
     if to_addrs.is_empty() {
         set_nested(
@@ -258,9 +274,14 @@ async fn handle_send_email(
     Ok(())
 }
 
-/// Resolve a list of `StringOperand`s to concrete string values using the normalized context
+/// Resolve a list of `StringOperand`s to concrete string values using the normalized context;
+/// `now`/`today` operands read `clock`
 #[must_use]
-pub fn resolve_string_operands(operands: &[StringOperand], normalized: &Value) -> Vec<String> {
+pub fn resolve_string_operands(
+    operands: &[StringOperand],
+    normalized: &Value,
+    clock: &Clock,
+) -> Vec<String> {
     let mut result = Vec::new();
     for operand in operands {
         match operand {
@@ -274,6 +295,12 @@ pub fn resolve_string_operands(operands: &[StringOperand], normalized: &Value) -
             StringOperand::ConstString { value } => {
                 result.push(value.clone());
             }
+            StringOperand::Now { .. } | StringOperand::Today { .. } => {
+                match eval_string_operand(normalized, operand, clock) {
+                    Ok(s) => result.push(s),
+                    Err(e) => log::warn!("Failed to resolve time operand: {e}"),
+                }
+            }
         }
     }
     result
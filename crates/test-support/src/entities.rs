@@ -45,6 +45,7 @@ pub async fn create_test_entity_definition(pool: &PgPool, entity_type: &str) ->
         validation: FieldValidation::default(),
         ui_settings: UiSettings::default(),
         constraints: HashMap::new(),
+        display_order: 0,
     };
     fields.push(name_field);
 
@@ -62,6 +63,7 @@ pub async fn create_test_entity_definition(pool: &PgPool, entity_type: &str) ->
         validation: FieldValidation::default(),
         ui_settings: UiSettings::default(),
         constraints: HashMap::new(),
+        display_order: 0,
     };
     fields.push(email_field);
 
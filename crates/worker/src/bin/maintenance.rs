@@ -13,8 +13,9 @@ use r_data_core_core::cache::CacheManager;
 use r_data_core_core::config::MaintenanceConfig;
 use r_data_core_worker::registrars::{
     LicenseVerificationRegistrar, OutboxPurgerRegistrar, PasswordResetCleanupRegistrar,
-    RefreshTokenCleanupRegistrar, StatisticsCollectionRegistrar, SystemLogsPurgerRegistrar,
-    TaskRegistrar, VersionPurgerRegistrar, WorkflowRunLogsPurgerRegistrar,
+    RefreshTokenCleanupRegistrar, SchemaReconcileRegistrar, StatisticsCollectionRegistrar,
+    SystemLogsPurgerRegistrar, TaskRegistrar, VersionPurgerRegistrar,
+    WorkflowRunLogsPurgerRegistrar,
 };
 
 /// Current version from Cargo.toml
@@ -60,6 +61,9 @@ async fn init_scheduler(
     SystemLogsPurgerRegistrar
         .register(&scheduler, pool.clone(), cache_manager.clone(), config)
         .await?;
+    SchemaReconcileRegistrar
+        .register(&scheduler, pool.clone(), cache_manager.clone(), config)
+        .await?;
     if config.outbox_enabled {
         OutboxPurgerRegistrar
             .register(&scheduler, pool.clone(), cache_manager.clone(), config)
@@ -85,12 +89,7 @@ async fn init_application(
 
     info!("Configuration loaded successfully");
 
-    let pool = init_pg_pool(
-        &config.database.connection_string,
-        config.database.max_connections,
-    )
-    .await
-    .map_err(|e| {
+    let pool = init_pg_pool(&config.database).await.map_err(|e| {
         r_data_core_core::error::Error::Config(format!("Failed to initialize database pool: {e}"))
     })?;
     if config.outbox_enabled {
@@ -0,0 +1,131 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Cached entity aggregate rebuild utility for `r_data_core`.
+//!
+//! Recomputes the cached counts and field sums from the live entities, e.g. after
+//! bulk imports or cascaded deletes that bypassed the incremental updates.
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Rebuild all configured aggregates
+//! rebuild_aggregates
+//!
+//! # Rebuild the aggregate of a single entity type
+//! rebuild_aggregates product
+//! ```
+//!
+//! # Environment Variables
+//!
+//! - `DATABASE_URL` - `PostgreSQL` connection string (required)
+//!
+//! # Exit Codes
+//!
+//! - 0: Success
+//! - 1: Error (connection failed, unknown entity type, rebuild failed, etc.)
+
+use std::env;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use dotenvy::dotenv;
+use r_data_core_persistence::{
+    EntityAggregateRepository, EntityAggregateRepositoryTrait, EntityDefinitionRepository,
+};
+use r_data_core_services::{EntityAggregateService, EntityDefinitionService};
+use sqlx::postgres::PgPoolOptions;
+
+/// Main entry point for the aggregate rebuild utility
+#[tokio::main]
+async fn main() -> ExitCode {
+    // Load .env file if present
+    dotenv().ok();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.iter().any(|a| a == "--help" || a == "-h") {
+        print_help();
+        return ExitCode::SUCCESS;
+    }
+
+    let Ok(database_url) = env::var("DATABASE_URL") else {
+        eprintln!("Error: DATABASE_URL environment variable is not set");
+        return ExitCode::FAILURE;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(&database_url)
+        .await
+    {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("Error: Failed to connect to database: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let aggregate_repo = Arc::new(EntityAggregateRepository::new(pool.clone()));
+    let definitions =
+        EntityDefinitionService::new_without_cache(Arc::new(EntityDefinitionRepository::new(pool)));
+    let service = EntityAggregateService::new(aggregate_repo.clone());
+
+    let entity_types: Vec<String> = if args.is_empty() {
+        match aggregate_repo.list().await {
+            Ok(aggregates) => aggregates.into_iter().map(|a| a.entity_type).collect(),
+            Err(e) => {
+                eprintln!("Error: Failed to list aggregates: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        args
+    };
+
+    let mut failed = false;
+    for entity_type in entity_types {
+        let result = match definitions
+            .get_entity_definition_by_entity_type(&entity_type)
+            .await
+        {
+            Ok(definition) => service.rebuild(&definition).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(aggregate) => println!(
+                "{entity_type}: {} entities, sums {:?}",
+                aggregate.entity_count, aggregate.sums
+            ),
+            Err(e) => {
+                eprintln!("Error: Failed to rebuild {entity_type}: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Print help information
+fn print_help() {
+    println!("rebuild_aggregates - Cached entity aggregate rebuild utility for r_data_core");
+    println!();
+    println!("USAGE:");
+    println!("    rebuild_aggregates [ENTITY_TYPE...]");
+    println!();
+    println!("    Without arguments, every configured aggregate is rebuilt.");
+    println!();
+    println!("OPTIONS:");
+    println!("    -h, --help      Print this help message");
+    println!();
+    println!("ENVIRONMENT:");
+    println!("    DATABASE_URL     PostgreSQL connection string (required)");
+    println!();
+    println!("EXAMPLES:");
+    println!("    # In Docker Compose environment");
+    println!("    docker compose exec core /usr/local/bin/rebuild_aggregates product");
+}
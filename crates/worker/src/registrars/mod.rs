@@ -4,6 +4,7 @@ pub mod license;
 pub mod outbox_purger;
 pub mod password_reset_cleanup;
 pub mod refresh_token;
+pub mod schema_reconcile;
 pub mod statistics;
 pub mod system_logs_purger;
 pub mod trait_;
@@ -14,6 +15,7 @@ pub use license::LicenseVerificationRegistrar;
 pub use outbox_purger::OutboxPurgerRegistrar;
 pub use password_reset_cleanup::PasswordResetCleanupRegistrar;
 pub use refresh_token::RefreshTokenCleanupRegistrar;
+pub use schema_reconcile::SchemaReconcileRegistrar;
 pub use statistics::StatisticsCollectionRegistrar;
 pub use system_logs_purger::SystemLogsPurgerRegistrar;
 pub use trait_::TaskRegistrar;
@@ -3,12 +3,14 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use log::{error, info};
+use log::{error, info, warn};
+use r_data_core_services::workflow::completion_webhook::RunCompletionPayload;
 use r_data_core_services::MailService;
 use uuid::Uuid;
 
 use r_data_core_persistence::WorkflowRepository;
 use r_data_core_workflow::data::jobs::FetchAndStageJob;
+use r_data_core_workflow::data::RunStatus;
 
 use crate::runtime::WorkerRuntime;
 
@@ -27,6 +29,7 @@ pub fn spawn_consumer_loop(
             state.queue_fetch_key
         );
         consume_job_loop(state).await;
+        info!("Worker consumer loop stopped");
     });
 }
 
@@ -46,7 +49,12 @@ async fn consume_job_loop(state: ConsumerState) {
             );
         }
 
-        match state.queue.blocking_pop_fetch().await {
+        let popped = tokio::select! {
+            () = state.run_tracker.shutdown_requested() => break,
+            popped = state.queue.blocking_pop_fetch() => popped,
+        };
+
+        match popped {
             Ok(job) => {
                 retry_backoff_ms = 250;
                 info!(
@@ -60,7 +68,10 @@ async fn consume_job_loop(state: ConsumerState) {
                     "Queue pop failed from '{}': {e}. Retrying after {retry_backoff_ms}ms backoff...",
                     state.queue_fetch_key
                 );
-                tokio::time::sleep(Duration::from_millis(retry_backoff_ms)).await;
+                tokio::select! {
+                    () = state.run_tracker.shutdown_requested() => break,
+                    () = tokio::time::sleep(Duration::from_millis(retry_backoff_ms)) => {}
+                }
                 retry_backoff_ms = (retry_backoff_ms * BACKOFF_MULTIPLIER).min(MAX_BACKOFF_MS);
             }
         }
@@ -88,6 +99,8 @@ async fn handle_job(state: &ConsumerState, job: FetchAndStageJob) {
         }
     };
 
+    // Keeps the run visible to the shutdown drain until this function returns
+    let _run_guard = state.run_tracker.track(run_uuid);
     let _ = repo.mark_run_running(run_uuid).await;
     let staged_existing = repo.count_raw_items_for_run(run_uuid).await.unwrap_or(0);
     if staged_existing == 0 {
@@ -112,12 +125,34 @@ async fn handle_job(state: &ConsumerState, job: FetchAndStageJob) {
                     )
                     .await;
                 let _ = repo.mark_run_success(run_uuid, processed, failed).await;
+                notify_completion(
+                    state,
+                    RunCompletionPayload::new(
+                        run_uuid,
+                        wf_uuid,
+                        RunStatus::Success,
+                        processed,
+                        failed,
+                        None,
+                    ),
+                );
             }
             Err(e) => {
                 let _ = repo
                     .insert_run_log(run_uuid, "error", &format!("Run failed: {e}"), None)
                     .await;
                 let _ = repo.mark_run_failure(run_uuid, &format!("{e}")).await;
+                notify_completion(
+                    state,
+                    RunCompletionPayload::new(
+                        run_uuid,
+                        wf_uuid,
+                        RunStatus::Failed,
+                        0,
+                        0,
+                        Some(e.to_string()),
+                    ),
+                );
             }
         }
     } else {
@@ -129,3 +164,37 @@ async fn handle_job(state: &ConsumerState, job: FetchAndStageJob) {
             .await;
     }
 }
+
+/// POST the run outcome to the workflow's completion webhook, if it has one
+///
+/// Delivery runs in the background so retries do not hold up the next job. Failures are
+/// written to the run log and leave the run status untouched.
+fn notify_completion(state: &ConsumerState, payload: Option<RunCompletionPayload>) {
+    let Some(payload) = payload else {
+        return;
+    };
+    let repo = WorkflowRepository::new(state.pool.clone());
+    let notifier = state.completion_webhook.clone();
+    tokio::spawn(async move {
+        let url = match repo.get_by_uuid(payload.workflow_uuid).await {
+            Ok(Some(workflow)) => workflow.completion_webhook_url,
+            Ok(None) => None,
+            Err(e) => {
+                error!(
+                    "Failed to load workflow {} for completion webhook: {e}",
+                    payload.workflow_uuid
+                );
+                None
+            }
+        };
+        let Some(url) = url else {
+            return;
+        };
+        if let Err(e) = notifier.deliver(&url, &payload).await {
+            warn!("Run {}: {e}", payload.run_uuid);
+            let _ = repo
+                .insert_run_log(payload.run_uuid, "warn", &e.to_string(), None)
+                .await;
+        }
+    });
+}
@@ -4,14 +4,15 @@ use std::sync::Arc;
 
 use r_data_core_core::settings::OutboxSettings;
 use r_data_core_persistence::{
-    DynamicEntityRepository, EntityDefinitionRepository, SystemLogRepository, WorkflowRepository,
+    DynamicEntityRepository, EntityAggregateRepository, EntityDefinitionRepository,
+    EntityWebhookRepository, SystemLogRepository, WorkflowRepository,
 };
 use r_data_core_services::adapters::{
     DynamicEntityRepositoryAdapter, EntityDefinitionRepositoryAdapter,
 };
 use r_data_core_services::{
-    DynamicEntityService, EntityDefinitionService, SettingsService, SystemLogService,
-    WorkflowRepositoryAdapter, WorkflowService,
+    DynamicEntityService, EntityAggregateService, EntityDefinitionService, EntityWebhookService,
+    SettingsService, SystemLogService, WorkflowRepositoryAdapter, WorkflowService,
 };
 use r_data_core_workflow::data::job_queue::JobQueue;
 
@@ -46,7 +47,18 @@ pub(super) fn build_processing_service(state: &ConsumerState) -> WorkflowService
     let ed_adapter = EntityDefinitionRepositoryAdapter::new(ed_repo);
     let ed_service =
         EntityDefinitionService::new(Arc::new(ed_adapter), state.cache_manager.clone());
-    let de_service = DynamicEntityService::new(Arc::new(de_adapter), Arc::new(ed_service));
+    let mut de_service = DynamicEntityService::new(Arc::new(de_adapter), Arc::new(ed_service))
+        .with_missing_entity_cache(state.cache_manager.clone(), state.entity_miss_ttl)
+        .with_change_hook(Arc::new(EntityAggregateService::new(Arc::new(
+            EntityAggregateRepository::new(state.pool.clone()),
+        ))));
+    if let Some(outbox_repo) = state.outbox_repo.clone() {
+        let webhook_service = EntityWebhookService::new(
+            Arc::new(EntityWebhookRepository::new(state.pool.clone())),
+            outbox_repo,
+        );
+        de_service = de_service.with_change_hook(Arc::new(webhook_service));
+    }
     let system_log_service = Arc::new(SystemLogService::new(Arc::new(SystemLogRepository::new(
         state.pool.clone(),
     ))));
@@ -2,10 +2,12 @@
 
 use std::sync::Arc;
 
+use r_data_core_services::workflow::completion_webhook::CompletionWebhookNotifier;
 use r_data_core_services::workflow::outbox::OutboxRetryPolicy;
 use r_data_core_services::MailService;
 use r_data_core_workflow::data::job_queue::apalis_redis::ApalisRedisQueue;
 
+use crate::runtime::shutdown::RunTracker;
 use crate::runtime::WorkerRuntime;
 
 #[derive(Clone)]
@@ -14,6 +16,7 @@ pub(super) struct ConsumerState {
     pub(super) queue: Arc<ApalisRedisQueue>,
     pub(super) queue_fetch_key: String,
     pub(super) cache_manager: Arc<r_data_core_core::cache::CacheManager>,
+    pub(super) entity_miss_ttl: u64,
     pub(super) outbox_repo: Option<Arc<r_data_core_persistence::OutboxRepository>>,
     pub(super) outbox_retry_policy: Option<OutboxRetryPolicy>,
     pub(super) jwt_secret: Option<String>,
@@ -21,6 +24,8 @@ pub(super) struct ConsumerState {
     pub(super) outbox_fetch_enabled_default: bool,
     pub(super) outbox_push_enabled_default: bool,
     pub(super) workflow_mail_service: Option<Arc<MailService>>,
+    pub(super) run_tracker: Arc<RunTracker>,
+    pub(super) completion_webhook: CompletionWebhookNotifier,
 }
 
 impl ConsumerState {
@@ -33,6 +38,7 @@ impl ConsumerState {
             queue: runtime.queue.clone(),
             queue_fetch_key: runtime.queue_fetch_key.clone(),
             cache_manager: runtime.cache_manager.clone(),
+            entity_miss_ttl: runtime.entity_miss_ttl,
             outbox_repo: runtime.outbox_repo.clone(),
             outbox_retry_policy: runtime.outbox_retry_policy,
             jwt_secret: runtime.jwt_secret.clone(),
@@ -40,6 +46,8 @@ impl ConsumerState {
             outbox_fetch_enabled_default: runtime.outbox_fetch_enabled_default,
             outbox_push_enabled_default: runtime.outbox_push_enabled_default,
             workflow_mail_service,
+            run_tracker: runtime.run_tracker.clone(),
+            completion_webhook: runtime.completion_webhook.clone(),
         }
     }
 }
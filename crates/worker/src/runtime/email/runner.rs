@@ -1,14 +1,17 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use std::sync::Arc;
 use std::time::Duration;
 
 use log::{error, info};
 
+use crate::runtime::shutdown::RunTracker;
+
 use super::handler::handle_email_job;
 use super::state::EmailConsumerState;
 use super::EmailRuntime;
 
-pub fn spawn_email_consumer_loop(runtime: &EmailRuntime) {
+pub fn spawn_email_consumer_loop(runtime: &EmailRuntime, run_tracker: Arc<RunTracker>) {
     let state = EmailConsumerState::from_runtime(runtime);
 
     tokio::spawn(async move {
@@ -16,15 +19,21 @@ pub fn spawn_email_consumer_loop(runtime: &EmailRuntime) {
             "Email consumer loop started, waiting for email jobs from queue '{}'...",
             state.queue_email_key
         );
-        consume_email_loop(state).await;
+        consume_email_loop(state, &run_tracker).await;
+        info!("Email consumer loop stopped");
     });
 }
 
-async fn consume_email_loop(state: EmailConsumerState) {
+async fn consume_email_loop(state: EmailConsumerState, run_tracker: &RunTracker) {
     let mut retry_backoff_ms: u64 = 250;
 
     loop {
-        match state.queue.blocking_pop_email().await {
+        let popped = tokio::select! {
+            () = run_tracker.shutdown_requested() => break,
+            popped = state.queue.blocking_pop_email() => popped,
+        };
+
+        match popped {
             Ok(job) => {
                 retry_backoff_ms = 250;
                 info!("Popped email job: to={:?}, source={}", job.to, job.source);
@@ -1,8 +1,8 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use futures::future;
 use log::{debug, error, info, warn};
 use tokio::sync::Mutex;
 use tokio_cron_scheduler::JobScheduler;
@@ -11,6 +11,7 @@ use uuid::Uuid;
 use r_data_core_core::config::load_worker_config;
 use r_data_core_persistence::{ComponentVersionRepository, OutboxRepository, WorkflowRepository};
 use r_data_core_services::bootstrap::{init_cache_manager, init_logger_with_default, init_pg_pool};
+use r_data_core_services::workflow::completion_webhook::CompletionWebhookNotifier;
 use r_data_core_services::LicenseService;
 use r_data_core_workflow::data::job_queue::apalis_redis::ApalisRedisQueue;
 
@@ -18,11 +19,13 @@ pub mod consumer;
 pub mod email;
 pub mod outbox;
 pub mod scheduler;
+pub mod shutdown;
 
 use crate::runtime::consumer::spawn_consumer_loop;
 use crate::runtime::email::{bootstrap_email_runtime, spawn_email_consumer_loop, EmailRuntime};
 use crate::runtime::outbox::spawn_outbox_recovery_loop;
 use crate::runtime::scheduler::start_scheduler;
+use crate::runtime::shutdown::{requeue_unfinished_runs, wait_for_shutdown_signal, RunTracker};
 
 /// Current version from Cargo.toml.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -33,6 +36,7 @@ pub(crate) struct WorkerRuntime {
     pub(crate) workflow_repo: Arc<WorkflowRepository>,
     pub(crate) queue_fetch_key: String,
     pub(crate) cache_manager: Arc<r_data_core_core::cache::CacheManager>,
+    pub(crate) entity_miss_ttl: u64,
     pub(crate) outbox_repo: Option<Arc<r_data_core_persistence::OutboxRepository>>,
     pub(crate) outbox_retry_policy:
         Option<r_data_core_services::workflow::outbox::OutboxRetryPolicy>,
@@ -43,6 +47,9 @@ pub(crate) struct WorkerRuntime {
     pub(crate) jwt_expiration: u64,
     pub(crate) outbox_fetch_enabled_default: bool,
     pub(crate) outbox_push_enabled_default: bool,
+    pub(crate) shutdown_timeout_secs: u64,
+    pub(crate) run_tracker: Arc<RunTracker>,
+    pub(crate) completion_webhook: CompletionWebhookNotifier,
 }
 
 pub(crate) struct WorkerBootstrap {
@@ -63,20 +70,53 @@ pub async fn run() -> r_data_core_core::error::Result<()> {
     info!("Starting data workflow worker");
 
     let bootstrap = bootstrap_worker().await?;
-    let _scheduler = start_scheduler(&bootstrap).await?;
+    let mut scheduler = start_scheduler(&bootstrap).await?;
 
     spawn_consumer_loop(
         &bootstrap.runtime,
         bootstrap.email_runtime.workflow_mail_service(),
     );
-    spawn_email_consumer_loop(&bootstrap.email_runtime);
+    spawn_email_consumer_loop(
+        &bootstrap.email_runtime,
+        bootstrap.runtime.run_tracker.clone(),
+    );
     spawn_outbox_recovery_loop(&bootstrap.runtime);
 
-    // Park forever.
-    future::pending::<()>().await;
+    wait_for_shutdown_signal().await;
+    shutdown_worker(&bootstrap.runtime, &mut scheduler).await;
     Ok(())
 }
 
+/// Stop pulling new jobs and wait for executing runs, re-queueing those that outlast the timeout
+async fn shutdown_worker(runtime: &WorkerRuntime, scheduler: &mut JobScheduler) {
+    info!(
+        "Shutting down worker, waiting up to {}s for in-flight runs...",
+        runtime.shutdown_timeout_secs
+    );
+    runtime.run_tracker.request_shutdown();
+    if let Err(e) = scheduler.shutdown().await {
+        warn!("Failed to stop scheduler: {e}");
+    }
+
+    let unfinished = runtime
+        .run_tracker
+        .drain(Duration::from_secs(runtime.shutdown_timeout_secs))
+        .await;
+    if !unfinished.is_empty() {
+        warn!(
+            "{} run(s) still executing after the shutdown timeout, re-queueing",
+            unfinished.len()
+        );
+        requeue_unfinished_runs(
+            runtime.workflow_repo.as_ref(),
+            runtime.queue.as_ref(),
+            &unfinished,
+        )
+        .await;
+    }
+    info!("Worker stopped");
+}
+
 async fn bootstrap_worker() -> r_data_core_core::error::Result<WorkerBootstrap> {
     let config = match load_worker_config() {
         Ok(cfg) => {
@@ -90,12 +130,7 @@ async fn bootstrap_worker() -> r_data_core_core::error::Result<WorkerBootstrap>
         }
     };
 
-    let pool = init_pg_pool(
-        &config.database.connection_string,
-        config.database.max_connections,
-    )
-    .await
-    .map_err(|e| {
+    let pool = init_pg_pool(&config.database).await.map_err(|e| {
         r_data_core_core::error::Error::Config(format!("Failed to initialize database pool: {e}"))
     })?;
     if config.outbox_enabled {
@@ -148,6 +183,7 @@ async fn bootstrap_worker() -> r_data_core_core::error::Result<WorkerBootstrap>
         workflow_repo: Arc::new(WorkflowRepository::new(pool.clone())),
         queue_fetch_key: queue_cfg.fetch_key.clone(),
         cache_manager,
+        entity_miss_ttl: config.cache.entity_miss_ttl,
         outbox_repo,
         outbox_retry_policy,
         job_queue_update_interval_secs: config.job_queue_update_interval_secs,
@@ -160,6 +196,11 @@ async fn bootstrap_worker() -> r_data_core_core::error::Result<WorkerBootstrap>
             .unwrap_or(86_400),
         outbox_fetch_enabled_default: config.outbox_fetch_enabled,
         outbox_push_enabled_default: config.outbox_push_enabled,
+        shutdown_timeout_secs: config.shutdown_timeout_secs,
+        run_tracker: RunTracker::new(),
+        completion_webhook: CompletionWebhookNotifier::new(
+            config.completion_webhook_secret.clone(),
+        ),
     };
     let email_runtime = bootstrap_email_runtime(&config, pool.clone(), queue);
 
@@ -34,6 +34,7 @@ pub fn spawn_outbox_recovery_loop(runtime: &WorkerRuntime) {
 
     let outbox_retry_policy = runtime.outbox_retry_policy;
     let outbox_stale_lease_secs = runtime.outbox_stale_lease_secs;
+    let run_tracker = runtime.run_tracker.clone();
     let poll_interval =
         Duration::from_secs(std::cmp::max(5, runtime.job_queue_update_interval_secs));
 
@@ -44,6 +45,7 @@ pub fn spawn_outbox_recovery_loop(runtime: &WorkerRuntime) {
 
         loop {
             tokio::select! {
+                () = run_tracker.shutdown_requested() => break,
                 () = tokio::time::sleep_until(tokio::time::Instant::from_std(sleep_until)) => {}
                 () = outbox_notify.notified() => {}
             }
@@ -0,0 +1,188 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use log::{error, info, warn};
+use tokio::sync::{watch, Notify};
+use uuid::Uuid;
+
+use r_data_core_persistence::WorkflowRepository;
+use r_data_core_workflow::data::job_queue::JobQueue;
+use r_data_core_workflow::data::jobs::FetchAndStageJob;
+
+/// Shutdown flag shared by the worker loops plus the workflow runs currently executing
+#[derive(Debug)]
+pub struct RunTracker {
+    shutdown: watch::Sender<bool>,
+    in_flight: Mutex<HashSet<Uuid>>,
+    idle: Notify,
+}
+
+impl RunTracker {
+    #[must_use]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            shutdown: watch::Sender::new(false),
+            in_flight: Mutex::new(HashSet::new()),
+            idle: Notify::new(),
+        })
+    }
+
+    /// Ask all loops to stop pulling new jobs
+    pub fn request_shutdown(&self) {
+        self.shutdown.send_replace(true);
+    }
+
+    #[must_use]
+    pub fn is_shutting_down(&self) -> bool {
+        *self.shutdown.borrow()
+    }
+
+    /// Resolves once a shutdown was requested
+    pub async fn shutdown_requested(&self) {
+        let mut rx = self.shutdown.subscribe();
+        // The sender lives in `self`, so waiting can only end with the flag set
+        let _ = rx.wait_for(|stopping| *stopping).await;
+    }
+
+    /// Register `run_uuid` as executing until the returned guard is dropped
+    #[must_use]
+    pub fn track(self: &Arc<Self>, run_uuid: Uuid) -> RunGuard {
+        self.runs().insert(run_uuid);
+        RunGuard {
+            tracker: self.clone(),
+            run_uuid,
+        }
+    }
+
+    /// Runs currently executing
+    #[must_use]
+    pub fn in_flight(&self) -> Vec<Uuid> {
+        self.runs().iter().copied().collect()
+    }
+
+    /// Wait up to `timeout` for the executing runs to finish
+    ///
+    /// Returns the runs still executing when the timeout elapsed.
+    pub async fn drain(&self, timeout: Duration) -> Vec<Uuid> {
+        let wait_idle = async {
+            loop {
+                let notified = self.idle.notified();
+                if self.runs().is_empty() {
+                    return;
+                }
+                notified.await;
+            }
+        };
+        if tokio::time::timeout(timeout, wait_idle).await.is_ok() {
+            Vec::new()
+        } else {
+            self.in_flight()
+        }
+    }
+
+    fn runs(&self) -> MutexGuard<'_, HashSet<Uuid>> {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn finish(&self, run_uuid: Uuid) {
+        let idle = {
+            let mut runs = self.runs();
+            runs.remove(&run_uuid);
+            runs.is_empty()
+        };
+        if idle {
+            self.idle.notify_waiters();
+        }
+    }
+}
+
+/// Marks a tracked run as finished when dropped
+#[derive(Debug)]
+pub struct RunGuard {
+    tracker: Arc<RunTracker>,
+    run_uuid: Uuid,
+}
+
+impl Drop for RunGuard {
+    fn drop(&mut self) {
+        self.tracker.finish(self.run_uuid);
+    }
+}
+
+/// Resolves on SIGTERM or SIGINT (Ctrl+C)
+pub async fn wait_for_shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                tokio::select! {
+                    _ = sigterm.recv() => info!("Received SIGTERM"),
+                    _ = tokio::signal::ctrl_c() => info!("Received SIGINT"),
+                }
+            }
+            Err(e) => {
+                warn!("Failed to install SIGTERM handler: {e}");
+                let _ = tokio::signal::ctrl_c().await;
+                info!("Received SIGINT");
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+        info!("Received SIGINT");
+    }
+}
+
+/// Put runs that did not finish before the shutdown timeout back into the queue
+///
+/// Each run is reset from `running` to `queued` and a fetch job is enqueued for it, so the next
+/// worker resumes it from its staged items instead of the run staying stuck in `running`.
+pub async fn requeue_unfinished_runs(
+    repo: &WorkflowRepository,
+    queue: &dyn JobQueue,
+    run_uuids: &[Uuid],
+) {
+    for &run_uuid in run_uuids {
+        let workflow_id = match repo.get_workflow_uuid_for_run(run_uuid).await {
+            Ok(Some(workflow_id)) => workflow_id,
+            Ok(None) => continue,
+            Err(e) => {
+                error!("Failed to look up workflow of run {run_uuid}: {e}");
+                continue;
+            }
+        };
+        match repo.requeue_running_run(run_uuid).await {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(e) => {
+                error!("Failed to re-queue run {run_uuid}: {e}");
+                continue;
+            }
+        }
+        let _ = repo
+            .insert_run_log(
+                run_uuid,
+                "warn",
+                "Run re-queued: worker shut down before it finished",
+                None,
+            )
+            .await;
+        let job = FetchAndStageJob {
+            workflow_id,
+            trigger_id: Some(run_uuid),
+        };
+        if let Err(e) = queue.enqueue_fetch(job).await {
+            error!("Failed to enqueue re-queued run {run_uuid}: {e}");
+        } else {
+            info!("Re-queued unfinished run {run_uuid}");
+        }
+    }
+}
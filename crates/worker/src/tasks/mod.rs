@@ -4,6 +4,7 @@ pub mod license_verification;
 pub mod outbox_purger;
 pub mod password_reset_cleanup;
 pub mod refresh_token_cleanup;
+pub mod schema_reconcile;
 pub mod statistics_collection;
 pub mod system_logs_purger;
 pub mod version_purger;
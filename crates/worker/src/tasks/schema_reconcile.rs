@@ -0,0 +1,86 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use async_trait::async_trait;
+use log::{info, warn};
+
+use r_data_core_core::maintenance::task::TaskContext;
+use r_data_core_core::maintenance::MaintenanceTask;
+use r_data_core_services::{SchemaReconcileAction, SchemaReconcileService};
+
+/// Maintenance task that checks entity tables against the entity definitions.
+///
+/// Mismatches are only logged unless `fix` is set, in which case orphan tables are dropped and
+/// the tables of drifted definitions are rebuilt.
+pub struct SchemaReconcileTask {
+    cron: String,
+    fix: bool,
+}
+
+impl SchemaReconcileTask {
+    /// Create a new `SchemaReconcileTask`.
+    #[must_use]
+    pub const fn new(cron: String, fix: bool) -> Self {
+        Self { cron, fix }
+    }
+}
+
+#[async_trait]
+impl MaintenanceTask for SchemaReconcileTask {
+    fn name(&self) -> &'static str {
+        "schema_reconcile"
+    }
+
+    fn cron(&self) -> &str {
+        &self.cron
+    }
+
+    async fn execute(
+        &self,
+        context: &dyn TaskContext,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let service = SchemaReconcileService::new(context.pool().clone());
+        let actions = service.report().await.map_err(|e| {
+            warn!("[schema_reconcile] Failed to compare entity schemas: {e}");
+            Box::new(e) as Box<dyn std::error::Error + Send + Sync>
+        })?;
+
+        if actions.is_empty() {
+            info!("[schema_reconcile] Entity tables match their definitions");
+            return Ok(());
+        }
+        for action in &actions {
+            match action {
+                SchemaReconcileAction::DropOrphanTable { table_name } => {
+                    warn!("[schema_reconcile] Table {table_name} has no entity definition");
+                }
+                SchemaReconcileAction::CreateTable {
+                    entity_type,
+                    table_name,
+                } => {
+                    warn!(
+                        "[schema_reconcile] Entity definition {entity_type} has no table {table_name}"
+                    );
+                }
+                SchemaReconcileAction::SyncColumns {
+                    entity_type,
+                    table_name,
+                    missing_columns,
+                    extra_columns,
+                } => {
+                    warn!(
+                        "[schema_reconcile] Table {table_name} of {entity_type} drifted: missing {missing_columns:?}, extra {extra_columns:?}"
+                    );
+                }
+            }
+        }
+
+        if self.fix {
+            service.fix(&actions).await.map_err(|e| {
+                warn!("[schema_reconcile] Failed to fix entity schemas: {e}");
+                Box::new(e) as Box<dyn std::error::Error + Send + Sync>
+            })?;
+            info!("[schema_reconcile] Fixed {} mismatch(es)", actions.len());
+        }
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use log::{info, warn};
 
 use r_data_core_core::maintenance::task::TaskContext;
-use r_data_core_core::maintenance::MaintenanceTask;
+use r_data_core_core::maintenance::{apply_retention, MaintenanceTask};
 use r_data_core_core::versioning::purger_trait::VersionPurger;
 use r_data_core_persistence::{
     EntityDefinitionVersioningRepository, VersionRepository, WorkflowVersioningRepository,
@@ -58,80 +58,27 @@ impl MaintenanceTask for VersionPurgerTask {
             }
         };
 
-        let r_data_core_core::settings::EntityVersioningSettings {
-            enabled,
-            max_versions,
-            max_age_days,
-        } = settings;
-
         info!(
-            "[version_purger] Entity versioning settings - enabled: {enabled}, max_versions: {max_versions:?}, max_age_days: {max_age_days:?}"
+            "[version_purger] Entity versioning settings - enabled: {}, max_versions: {:?}, max_age_days: {:?}",
+            settings.enabled, settings.max_versions, settings.max_age_days
         );
 
-        if !enabled {
-            info!("[version_purger] Entity versioning disabled; prune skipped");
-            return Ok(());
-        }
-
         // Initialize all three version repositories
-        let entity_repo = VersionRepository::new(pool.clone());
-        let workflow_repo = WorkflowVersioningRepository::new(pool.clone());
-        let definition_repo = EntityDefinitionVersioningRepository::new(pool.clone());
-
         let repositories: Vec<Box<dyn VersionPurger>> = vec![
-            Box::new(entity_repo),
-            Box::new(workflow_repo),
-            Box::new(definition_repo),
+            Box::new(VersionRepository::new(pool.clone())),
+            Box::new(WorkflowVersioningRepository::new(pool.clone())),
+            Box::new(EntityDefinitionVersioningRepository::new(pool.clone())),
         ];
 
-        // Prune by age first if configured
-        if let Some(days) = max_age_days {
-            info!(
-                "[version_purger] Pruning versions older than {days} days across all repositories"
-            );
-            for repo in &repositories {
-                let repo_name = repo.repository_name();
-                match repo.prune_older_than_days(days).await {
-                    Ok(count) => {
-                        info!(
-                            "[version_purger] Pruned {count} versions older than {days} days from {repo_name}"
-                        );
-                    }
-                    Err(e) => {
-                        warn!(
-                            "[version_purger] Failed to prune versions by age from {repo_name}: {e}"
-                        );
-                        return Err(Box::new(e) as Box<dyn std::error::Error + Send + Sync>);
-                    }
-                }
-            }
-        } else {
-            info!("[version_purger] No max_age_days configured; skipping age-based pruning");
-        }
-
-        // Prune by count (preserve latest N per entity/workflow/definition)
-        if let Some(keep) = max_versions {
-            info!(
-                "[version_purger] Pruning versions, keeping latest {keep} per item across all repositories"
-            );
-            for repo in &repositories {
-                let repo_name = repo.repository_name();
-                match repo.prune_keep_latest(keep).await {
-                    Ok(count) => {
-                        info!(
-                            "[version_purger] Pruned {count} versions, kept latest {keep} per item from {repo_name}"
-                        );
-                    }
-                    Err(e) => {
-                        warn!(
-                            "[version_purger] Failed to prune versions by count from {repo_name}: {e}"
-                        );
-                        return Err(Box::new(e) as Box<dyn std::error::Error + Send + Sync>);
-                    }
-                }
+        match apply_retention(&repositories, &settings).await {
+            Ok(report) => info!(
+                "[version_purger] Pruned {} versions by age and {} by count",
+                report.pruned_by_age, report.pruned_by_count
+            ),
+            Err(e) => {
+                warn!("[version_purger] Failed to apply version retention: {e}");
+                return Err(Box::new(e) as Box<dyn std::error::Error + Send + Sync>);
             }
-        } else {
-            info!("[version_purger] No max_versions configured; skipping count-based pruning");
         }
 
         info!("[version_purger] Version purging task completed successfully");
@@ -1,6 +1,7 @@
 pub mod uri;
 
 use crate::data::adapters::auth::AuthProvider;
+use crate::data::adapters::http::HttpRetryLog;
 use async_trait::async_trait;
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
@@ -31,6 +32,8 @@ pub struct DestinationContext {
     pub auth: Option<Box<dyn AuthProvider>>,
     pub method: Option<HttpMethod>,
     pub config: serde_json::Value,
+    /// Retried attempts of the HTTP requests made with this context
+    pub retries: HttpRetryLog,
 }
 
 /// Trait for data destinations (URI, File, API, SFTP, etc.)
@@ -1,5 +1,5 @@
 use super::{DataDestination, DestinationContext, HttpMethod};
-use crate::data::adapters::http::uri_http_client;
+use crate::data::adapters::http::{send_with_retry, uri_http_client, HttpRetryPolicy};
 use async_trait::async_trait;
 use bytes::Bytes;
 
@@ -35,33 +35,34 @@ impl DataDestination for UriDestination {
                 )
             })?;
         let method = ctx.method.unwrap_or(HttpMethod::Post);
+        let policy = HttpRetryPolicy::from_config(&ctx.config)?;
         let client = uri_http_client()?;
 
-        let mut request = match method {
-            HttpMethod::Get => client.get(uri),
-            HttpMethod::Post => client.post(uri),
-            HttpMethod::Put => client.put(uri),
-            HttpMethod::Patch => client.patch(uri),
-            HttpMethod::Delete => client.delete(uri),
-            HttpMethod::Head => client.head(uri),
-            HttpMethod::Options => client.request(reqwest::Method::OPTIONS, uri),
-        };
+        let response = send_with_retry(&policy, &ctx.retries, || {
+            let mut request = match method {
+                HttpMethod::Get => client.get(uri),
+                HttpMethod::Post => client.post(uri),
+                HttpMethod::Put => client.put(uri),
+                HttpMethod::Patch => client.patch(uri),
+                HttpMethod::Delete => client.delete(uri),
+                HttpMethod::Head => client.head(uri),
+                HttpMethod::Options => client.request(reqwest::Method::OPTIONS, uri),
+            };
 
-        // Apply authentication if provided
-        if let Some(auth) = &ctx.auth {
-            request = auth
-                .apply_to_request(request)
-                .map_err(|e| r_data_core_core::error::Error::Api(e.to_string()))?;
-        }
-
-        // Add body for methods that require it
-        if method.requires_body() {
-            request = request.body(data);
-        }
+            // Apply authentication if provided
+            if let Some(auth) = &ctx.auth {
+                request = auth
+                    .apply_to_request(request)
+                    .map_err(|e| r_data_core_core::error::Error::Api(e.to_string()))?;
+            }
 
-        let response = request.send().await.map_err(|e| {
-            r_data_core_core::error::Error::Api(format!("Failed to send request: {e}"))
-        })?;
+            // Add body for methods that require it
+            if method.requires_body() {
+                request = request.body(data.clone());
+            }
+            Ok(request)
+        })
+        .await?;
         let status = response.status();
         if status.is_client_error() {
             if status.as_u16() == 408 || status.as_u16() == 429 {
@@ -100,6 +101,7 @@ impl DataDestination for UriDestination {
                 "URI must start with http:// or https://".to_string(),
             ));
         }
+        HttpRetryPolicy::from_config(config)?;
 
         Ok(())
     }
@@ -1,12 +1,26 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
-use std::sync::OnceLock;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
 use std::time::Duration;
 
+use reqwest::header::RETRY_AFTER;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::Serialize;
+use serde_json::Value;
+
 const URI_CONNECT_TIMEOUT_SECS: u64 = 5;
 const URI_REQUEST_TIMEOUT_SECS: u64 = 30;
 static URI_HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
 
+/// Retries of a failed `uri` request unless `max_retries` is set
+pub const DEFAULT_MAX_RETRIES: u32 = 0;
+/// Delay before the first retry unless `backoff_ms` is set; doubled for every further retry
+pub const DEFAULT_BACKOFF_MS: u64 = 500;
+/// Highest accepted `max_retries`
+pub const MAX_RETRIES_LIMIT: u32 = 10;
+/// Longest delay between two attempts, also for a longer `Retry-After`
+pub const MAX_RETRY_DELAY: Duration = Duration::from_mins(1);
+
 pub(super) fn uri_http_client() -> r_data_core_core::error::Result<&'static reqwest::Client> {
     if let Some(client) = URI_HTTP_CLIENT.get() {
         return Ok(client);
@@ -22,3 +36,212 @@ pub(super) fn uri_http_client() -> r_data_core_core::error::Result<&'static reqw
 
     Ok(URI_HTTP_CLIENT.get_or_init(|| client))
 }
+
+/// Timeout and retries of `uri` requests, read from `timeout_secs`, `max_retries` and
+/// `backoff_ms` of a source or destination config
+///
+/// Timeouts, connection errors, 5xx, 408 and 429 responses are retried with exponential
+/// backoff (or the `Retry-After` of the response); other 4xx responses are not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HttpRetryPolicy {
+    pub timeout: Duration,
+    pub max_retries: u32,
+    pub backoff: Duration,
+}
+
+impl Default for HttpRetryPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(URI_REQUEST_TIMEOUT_SECS),
+            max_retries: DEFAULT_MAX_RETRIES,
+            backoff: Duration::from_millis(DEFAULT_BACKOFF_MS),
+        }
+    }
+}
+
+impl HttpRetryPolicy {
+    /// Read the policy from a source or destination config, defaults for absent keys
+    ///
+    /// # Errors
+    /// Returns an error if a key is not a non-negative integer, `timeout_secs` is 0 or
+    /// `max_retries` exceeds [`MAX_RETRIES_LIMIT`]
+    pub fn from_config(config: &Value) -> r_data_core_core::error::Result<Self> {
+        let read = |key: &str| -> r_data_core_core::error::Result<Option<u64>> {
+            match config.get(key) {
+                None | Some(Value::Null) => Ok(None),
+                Some(value) => value.as_u64().map(Some).ok_or_else(|| {
+                    r_data_core_core::error::Error::Validation(format!(
+                        "'{key}' must be a non-negative integer"
+                    ))
+                }),
+            }
+        };
+
+        let mut policy = Self::default();
+        if let Some(secs) = read("timeout_secs")? {
+            if secs == 0 {
+                return Err(r_data_core_core::error::Error::Validation(
+                    "'timeout_secs' must be at least 1".to_string(),
+                ));
+            }
+            policy.timeout = Duration::from_secs(secs);
+        }
+        if let Some(retries) = read("max_retries")? {
+            policy.max_retries = u32::try_from(retries)
+                .ok()
+                .filter(|retries| *retries <= MAX_RETRIES_LIMIT)
+                .ok_or_else(|| {
+                    r_data_core_core::error::Error::Validation(format!(
+                        "'max_retries' must not exceed {MAX_RETRIES_LIMIT}"
+                    ))
+                })?;
+        }
+        if let Some(ms) = read("backoff_ms")? {
+            policy.backoff = Duration::from_millis(ms);
+        }
+        Ok(policy)
+    }
+
+    /// Delay before retry number `retry` (1-based)
+    fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        retry_after
+            .unwrap_or_else(|| {
+                self.backoff
+                    .saturating_mul(2_u32.saturating_pow(retry.saturating_sub(1)))
+            })
+            .min(MAX_RETRY_DELAY)
+    }
+}
+
+/// A failed attempt of a `uri` request that was retried
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HttpRetry {
+    /// Number of the failed attempt, starting at 1
+    pub attempt: u32,
+    /// Response status, `None` for timeouts and connection errors
+    pub status: Option<u16>,
+    pub error: String,
+    /// Wait before the next attempt
+    pub delay_ms: u64,
+}
+
+/// Retried attempts of the requests made with one source or destination context
+#[derive(Debug, Clone, Default)]
+pub struct HttpRetryLog(Arc<Mutex<Vec<HttpRetry>>>);
+
+impl HttpRetryLog {
+    /// Remove and return the recorded retries
+    #[must_use]
+    pub fn take(&self) -> Vec<HttpRetry> {
+        std::mem::take(&mut *self.0.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn push(&self, retry: HttpRetry) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(retry);
+    }
+}
+
+fn is_transient_status(status: StatusCode) -> bool {
+    status.is_server_error()
+        || matches!(
+            status,
+            StatusCode::REQUEST_TIMEOUT | StatusCode::TOO_MANY_REQUESTS
+        )
+}
+
+/// `Retry-After` of a response given in seconds
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Send the request built by `build`, retrying transient failures as set by `policy`
+///
+/// The response of the last attempt is returned whatever its status; each retried attempt is
+/// recorded in `retries`.
+///
+/// # Errors
+/// Returns an error if building the request fails or the last attempt could not be sent
+pub(super) async fn send_with_retry(
+    policy: &HttpRetryPolicy,
+    retries: &HttpRetryLog,
+    build: impl Fn() -> r_data_core_core::error::Result<RequestBuilder>,
+) -> r_data_core_core::error::Result<Response> {
+    let mut attempt = 1;
+    loop {
+        let result = build()?.timeout(policy.timeout).send().await;
+        let (status, error, delay) = match result {
+            Ok(response)
+                if attempt <= policy.max_retries && is_transient_status(response.status()) =>
+            {
+                let status = response.status();
+                (
+                    Some(status.as_u16()),
+                    format!("HTTP error: {status}"),
+                    policy.delay(attempt, retry_after(&response)),
+                )
+            }
+            Ok(response) => return Ok(response),
+            Err(e) if attempt <= policy.max_retries && (e.is_timeout() || e.is_connect()) => {
+                (None, e.to_string(), policy.delay(attempt, None))
+            }
+            Err(e) => {
+                return Err(r_data_core_core::error::Error::Api(format!(
+                    "Failed to send request: {e}"
+                )))
+            }
+        };
+        retries.push(HttpRetry {
+            attempt,
+            status,
+            error,
+            delay_ms: u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+        });
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn retry_policy_reads_config_with_defaults() {
+        assert_eq!(
+            HttpRetryPolicy::from_config(&json!({ "uri": "https://example.com" })).unwrap(),
+            HttpRetryPolicy::default()
+        );
+        let policy = HttpRetryPolicy::from_config(
+            &json!({ "timeout_secs": 5, "max_retries": 3, "backoff_ms": 100 }),
+        )
+        .unwrap();
+        assert_eq!(policy.timeout, Duration::from_secs(5));
+        assert_eq!(policy.max_retries, 3);
+        assert_eq!(policy.delay(1, None), Duration::from_millis(100));
+        assert_eq!(policy.delay(3, None), Duration::from_millis(400));
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(2))),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_hours(1))),
+            MAX_RETRY_DELAY
+        );
+
+        assert!(HttpRetryPolicy::from_config(&json!({ "timeout_secs": 0 })).is_err());
+        assert!(HttpRetryPolicy::from_config(&json!({ "max_retries": 11 })).is_err());
+        assert!(HttpRetryPolicy::from_config(&json!({ "backoff_ms": "fast" })).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 pub mod auth;
 pub mod destination;
 pub mod format;
-pub(crate) mod http;
+pub mod http;
 pub mod source;
 
 use sqlx::PgPool;
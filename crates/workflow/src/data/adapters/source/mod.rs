@@ -1,6 +1,7 @@
 pub mod uri;
 
 use crate::data::adapters::auth::AuthProvider;
+use crate::data::adapters::http::HttpRetryLog;
 use async_trait::async_trait;
 use bytes::Bytes;
 use futures::Stream;
@@ -9,6 +10,8 @@ use futures::Stream;
 pub struct SourceContext {
     pub auth: Option<Box<dyn AuthProvider>>,
     pub config: serde_json::Value,
+    /// Retried attempts of the HTTP requests made with this context
+    pub retries: HttpRetryLog,
 }
 
 /// Trait for data sources (URI, File, API, SFTP, etc.)
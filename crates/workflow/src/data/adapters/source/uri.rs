@@ -1,8 +1,154 @@
 use super::{DataSource, SourceContext};
-use crate::data::adapters::http::uri_http_client;
+use crate::data::adapters::http::{send_with_retry, uri_http_client, HttpRetryPolicy};
 use async_trait::async_trait;
 use bytes::Bytes;
 use futures::{stream, Stream};
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Pages fetched by a paginated `uri` source unless `pagination.max_pages` is set
+pub const DEFAULT_MAX_PAGES: u32 = 100;
+
+/// Pagination of a `uri` source, read from `config.pagination`
+///
+/// Pages are requested by number (`page_param`) or by following the URL found at
+/// `next_pointer` in each response, until a page has no records or no next URL. Paginated
+/// responses must be JSON; the records of each page (at `records_pointer`, the whole body by
+/// default) are emitted as NDJSON lines so all pages parse as one stream with the `json` format.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UriPagination {
+    /// Query parameter carrying the page number
+    #[serde(default)]
+    pub page_param: Option<String>,
+    /// Query parameter carrying the page size
+    #[serde(default)]
+    pub page_size_param: Option<String>,
+    /// Page size sent as `page_size_param`
+    #[serde(default)]
+    pub page_size: Option<u64>,
+    /// Number of the first page
+    #[serde(default = "default_start_page")]
+    pub start_page: u64,
+    /// JSON pointer to the URL of the next page, e.g. `/links/next`
+    #[serde(default)]
+    pub next_pointer: Option<String>,
+    /// JSON pointer to the records array of a page, e.g. `/data`
+    #[serde(default)]
+    pub records_pointer: Option<String>,
+    /// Pages fetched at most; a run needing more fails instead of staging a partial result
+    #[serde(default = "default_max_pages")]
+    pub max_pages: u32,
+}
+
+const fn default_start_page() -> u64 {
+    1
+}
+
+const fn default_max_pages() -> u32 {
+    DEFAULT_MAX_PAGES
+}
+
+impl UriPagination {
+    /// Read the pagination of a `uri` source config, `None` when it fetches a single URL
+    ///
+    /// # Errors
+    /// Returns an error if `pagination` is malformed, sets both or neither of `page_param` and
+    /// `next_pointer`, or uses a pointer that does not start with `/`
+    pub fn from_config(config: &Value) -> r_data_core_core::error::Result<Option<Self>> {
+        let Some(value) = config.get("pagination").filter(|v| !v.is_null()) else {
+            return Ok(None);
+        };
+        let pagination: Self = serde_json::from_value(value.clone()).map_err(|e| {
+            r_data_core_core::error::Error::Validation(format!("Invalid URI pagination: {e}"))
+        })?;
+
+        if pagination.page_param.is_some() == pagination.next_pointer.is_some() {
+            return Err(r_data_core_core::error::Error::Validation(
+                "URI pagination requires exactly one of 'page_param' and 'next_pointer'"
+                    .to_string(),
+            ));
+        }
+        if pagination.page_size.is_some() && pagination.page_size_param.is_none() {
+            return Err(r_data_core_core::error::Error::Validation(
+                "URI pagination 'page_size' requires 'page_size_param'".to_string(),
+            ));
+        }
+        for pointer in [&pagination.next_pointer, &pagination.records_pointer]
+            .into_iter()
+            .flatten()
+        {
+            if !pointer.starts_with('/') {
+                return Err(r_data_core_core::error::Error::Validation(format!(
+                    "URI pagination pointer '{pointer}' must start with '/'"
+                )));
+            }
+        }
+        if pagination.max_pages == 0 {
+            return Err(r_data_core_core::error::Error::Validation(
+                "URI pagination 'max_pages' must be at least 1".to_string(),
+            ));
+        }
+        Ok(Some(pagination))
+    }
+
+    /// URL of the numbered page `page`
+    fn page_url(&self, base: &Url, page: u64) -> Url {
+        let mut params: Vec<(String, String)> = Vec::new();
+        if let Some(param) = &self.page_param {
+            params.push((param.clone(), page.to_string()));
+        }
+        if let (Some(param), Some(size)) = (&self.page_size_param, self.page_size) {
+            params.push((param.clone(), size.to_string()));
+        }
+
+        let mut url = base.clone();
+        let kept: Vec<(String, String)> = base
+            .query_pairs()
+            .filter(|(name, _)| !params.iter().any(|(param, _)| param == name))
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        url.query_pairs_mut()
+            .clear()
+            .extend_pairs(kept)
+            .extend_pairs(params);
+        url
+    }
+
+    /// Records of a page body
+    fn records(&self, body: &[u8]) -> r_data_core_core::error::Result<Vec<Value>> {
+        let page: Value = serde_json::from_slice(body).map_err(|e| {
+            r_data_core_core::error::Error::Api(format!("Paginated response is not JSON: {e}"))
+        })?;
+        let records = match &self.records_pointer {
+            Some(pointer) => page.pointer(pointer).cloned().unwrap_or(Value::Null),
+            None => page,
+        };
+        match records {
+            Value::Array(records) => Ok(records),
+            Value::Null => Ok(Vec::new()),
+            _ => Err(r_data_core_core::error::Error::Api(format!(
+                "Paginated response records at '{}' are not an array",
+                self.records_pointer.as_deref().unwrap_or("/")
+            ))),
+        }
+    }
+
+    /// URL of the page after `current`, `None` on the last page
+    fn next_url(&self, current: &Url, body: &[u8]) -> r_data_core_core::error::Result<Option<Url>> {
+        let Some(pointer) = &self.next_pointer else {
+            return Ok(None);
+        };
+        let page: Value = serde_json::from_slice(body)?;
+        match page.pointer(pointer).and_then(Value::as_str) {
+            Some(next) if !next.trim().is_empty() => current.join(next).map(Some).map_err(|e| {
+                r_data_core_core::error::Error::Api(format!("Invalid next page URL '{next}': {e}"))
+            }),
+            _ => Ok(None),
+        }
+    }
+}
 
 /// URI-based data source (HTTP/HTTPS)
 #[derive(Default)]
@@ -13,6 +159,76 @@ impl UriSource {
     pub const fn new() -> Self {
         Self
     }
+
+    async fn fetch_page(
+        ctx: &SourceContext,
+        policy: &HttpRetryPolicy,
+        url: Url,
+    ) -> r_data_core_core::error::Result<Bytes> {
+        let client = uri_http_client()?;
+        let response = send_with_retry(policy, &ctx.retries, || {
+            let request = client.get(url.clone());
+            // Apply authentication if provided
+            match &ctx.auth {
+                Some(auth) => auth
+                    .apply_to_request(request)
+                    .map_err(|e| r_data_core_core::error::Error::Api(e.to_string())),
+                None => Ok(request),
+            }
+        })
+        .await?;
+        let response = response
+            .error_for_status()
+            .map_err(|e| r_data_core_core::error::Error::Api(format!("HTTP error: {e}")))?;
+        response.bytes().await.map_err(|e| {
+            r_data_core_core::error::Error::Api(format!("Failed to read response body: {e}"))
+        })
+    }
+
+    /// Fetch all pages, one NDJSON chunk of records per non-empty page
+    async fn fetch_pages(
+        ctx: &SourceContext,
+        policy: &HttpRetryPolicy,
+        base: Url,
+        pagination: &UriPagination,
+    ) -> r_data_core_core::error::Result<Vec<r_data_core_core::error::Result<Bytes>>> {
+        let mut chunks = Vec::new();
+        let mut page = pagination.start_page;
+        let mut url = if pagination.next_pointer.is_some() {
+            base.clone()
+        } else {
+            pagination.page_url(&base, page)
+        };
+
+        for _ in 0..pagination.max_pages {
+            let body = Self::fetch_page(ctx, policy, url.clone()).await?;
+            let records = pagination.records(&body)?;
+            if records.is_empty() {
+                return Ok(chunks);
+            }
+            let mut chunk = Vec::new();
+            for record in &records {
+                chunk.extend_from_slice(&serde_json::to_vec(record)?);
+                chunk.push(b'\n');
+            }
+            chunks.push(Ok(Bytes::from(chunk)));
+
+            url = if pagination.next_pointer.is_some() {
+                match pagination.next_url(&url, &body)? {
+                    Some(next) => next,
+                    None => return Ok(chunks),
+                }
+            } else {
+                page += 1;
+                pagination.page_url(&base, page)
+            };
+        }
+
+        Err(r_data_core_core::error::Error::Api(format!(
+            "URI source has more than {} pages; raise pagination.max_pages to fetch them all",
+            pagination.max_pages
+        )))
+    }
 }
 
 #[async_trait]
@@ -36,26 +252,18 @@ impl DataSource for UriSource {
                     "URI source requires 'uri' in config".to_string(),
                 )
             })?;
-        let client = uri_http_client()?;
-        let mut request = client.get(uri);
+        let url = Url::parse(uri).map_err(|e| {
+            r_data_core_core::error::Error::Config(format!("Invalid URI '{uri}': {e}"))
+        })?;
 
-        // Apply authentication if provided
-        if let Some(auth) = &ctx.auth {
-            request = auth
-                .apply_to_request(request)
-                .map_err(|e| r_data_core_core::error::Error::Api(e.to_string()))?;
-        }
+        let policy = HttpRetryPolicy::from_config(&ctx.config)?;
 
-        let response = request.send().await.map_err(|e| {
-            r_data_core_core::error::Error::Api(format!("Failed to send request: {e}"))
-        })?;
-        let response = response
-            .error_for_status()
-            .map_err(|e| r_data_core_core::error::Error::Api(format!("HTTP error: {e}")))?;
-        let body = response.bytes().await.map_err(|e| {
-            r_data_core_core::error::Error::Api(format!("Failed to read response body: {e}"))
-        })?;
+        if let Some(pagination) = UriPagination::from_config(&ctx.config)? {
+            let chunks = Self::fetch_pages(ctx, &policy, url, &pagination).await?;
+            return Ok(Box::new(stream::iter(chunks)));
+        }
 
+        let body = Self::fetch_page(ctx, &policy, url).await?;
         Ok(Box::new(stream::iter(vec![Ok(body)])))
     }
 
@@ -74,6 +282,8 @@ impl DataSource for UriSource {
                 "URI must start with http:// or https://".to_string(),
             ));
         }
+        HttpRetryPolicy::from_config(config)?;
+        UriPagination::from_config(config)?;
         Ok(())
     }
 }
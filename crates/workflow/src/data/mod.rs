@@ -56,6 +56,142 @@ pub enum RunStatus {
     Cancelled,
 }
 
+impl RunStatus {
+    /// Lowercase name as stored in the database
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Success => "success",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    /// Whether the run has finished and its status will not change anymore
+    #[must_use]
+    pub const fn is_terminal(self) -> bool {
+        matches!(self, Self::Success | Self::Failed | Self::Cancelled)
+    }
+}
+
+impl FromStr for RunStatus {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "queued" => Ok(Self::Queued),
+            "running" => Ok(Self::Running),
+            "success" => Ok(Self::Success),
+            "failed" => Ok(Self::Failed),
+            "cancelled" => Ok(Self::Cancelled),
+            _ => Err("invalid run status"),
+        }
+    }
+}
+
+/// Aggregated run metrics of a workflow over a time window
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowRunMetrics {
+    /// Runs queued within the window
+    pub total_runs: i64,
+    /// Runs that finished successfully
+    pub successful_runs: i64,
+    /// Runs that finished with a failure
+    pub failed_runs: i64,
+    /// Sum of processed items over all runs
+    pub processed_items: i64,
+    /// Sum of failed items over all runs
+    pub failed_items: i64,
+    /// Average run duration in milliseconds (runs with start and finish time only)
+    pub avg_duration_ms: Option<f64>,
+    /// 95th percentile run duration in milliseconds
+    pub p95_duration_ms: Option<f64>,
+}
+
+impl WorkflowRunMetrics {
+    /// Share of successful runs among finished (successful or failed) runs, between 0 and 1
+    ///
+    /// Returns `None` if no run finished within the window.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // run counts are far below f64 precision limits
+    pub fn success_rate(&self) -> Option<f64> {
+        let finished = self.successful_runs + self.failed_runs;
+        (finished > 0).then(|| self.successful_runs as f64 / finished as f64)
+    }
+}
+
+/// All-time run statistics of a workflow (or of all workflows) for dashboards
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowRunStats {
+    pub total_runs: i64,
+    pub queued_runs: i64,
+    pub running_runs: i64,
+    pub successful_runs: i64,
+    pub failed_runs: i64,
+    pub cancelled_runs: i64,
+    /// Runs queued within the last 24 hours
+    pub runs_last_24h: i64,
+    pub successful_runs_last_24h: i64,
+    pub failed_runs_last_24h: i64,
+    /// Average run duration in milliseconds (runs with start and finish time only)
+    pub avg_duration_ms: Option<f64>,
+    /// 95th percentile run duration in milliseconds
+    pub p95_duration_ms: Option<f64>,
+    /// Status of the most recently queued run
+    pub last_run_status: Option<String>,
+    /// When the most recent run was queued
+    pub last_run_at: Option<time::OffsetDateTime>,
+}
+
+impl WorkflowRunStats {
+    /// Share of successful runs among finished (successful or failed) runs, between 0 and 1
+    ///
+    /// Returns `None` if no run has finished yet.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // run counts are far below f64 precision limits
+    pub fn success_rate(&self) -> Option<f64> {
+        let finished = self.successful_runs + self.failed_runs;
+        (finished > 0).then(|| self.successful_runs as f64 / finished as f64)
+    }
+}
+
+/// Progress of a run through its staged items, updated as batches complete
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowRunProgress {
+    pub status: RunStatus,
+    /// Items processed successfully so far
+    pub processed_count: i64,
+    /// Items that failed so far
+    pub failed_count: i64,
+    /// Items staged for the run; zero until processing starts
+    pub total_count: i64,
+}
+
+/// Error of a single record that failed during staged processing of a run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowRunError {
+    pub uuid: Uuid,
+    pub run_uuid: Uuid,
+    /// Zero-based position of the record in the staged input of the run
+    pub record_index: i64,
+    pub error_message: String,
+    /// The staged record as it was fed into the pipeline
+    pub raw_payload: serde_json::Value,
+    pub created_at: time::OffsetDateTime,
+}
+
+/// Outcome of claiming an idempotency key of the ingest endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyClaim {
+    /// The key is new (or its previous use expired); the caller owns it now
+    Claimed,
+    /// The key was already used; carries the response of the original request
+    Replay(serde_json::Value),
+    /// The original request with this key is still being processed
+    InProgress,
+}
+
 /// Workflow data structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Workflow {
@@ -71,8 +207,14 @@ pub struct Workflow {
     pub enabled: bool,
     /// Cron schedule for the workflow
     pub schedule_cron: Option<String>,
+    /// Whether scheduled runs are paused; the schedule itself is kept
+    #[serde(default)]
+    pub paused: bool,
     /// Workflow configuration
     pub config: serde_json::Value,
     /// Whether versioning is disabled
     pub versioning_disabled: bool,
+    /// URL notified with a signed payload when a run finishes
+    #[serde(default)]
+    pub completion_webhook_url: Option<String>,
 }
@@ -23,6 +23,9 @@ pub struct CreateWorkflowRequest {
     /// Whether versioning is disabled
     #[serde(default)]
     pub versioning_disabled: bool,
+    /// URL notified with a signed payload when a run finishes
+    #[serde(default)]
+    pub completion_webhook_url: Option<String>,
 }
 
 /// Request to update an existing workflow
@@ -44,4 +47,15 @@ pub struct UpdateWorkflowRequest {
     /// Whether versioning is disabled
     #[serde(default)]
     pub versioning_disabled: bool,
+    /// URL notified with a signed payload when a run finishes
+    #[serde(default)]
+    pub completion_webhook_url: Option<String>,
+}
+
+/// Optional body for triggering a single run with a tweaked configuration
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct RunWorkflowRequest {
+    /// Partial config merged over the stored workflow config for this run only
+    #[serde(default)]
+    pub config: Option<Value>,
 }
@@ -0,0 +1,293 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use super::error::{DslError, DslResult};
+use super::execution;
+
+/// Coerce a field to another JSON type (e.g. CSV strings to booleans or numbers)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CastTransform {
+    /// Normalized field holding the value to convert
+    pub source: String,
+    /// Target normalized field to set
+    pub target: String,
+    /// Type to convert to
+    pub to: CastType,
+    /// Fail the item when the value cannot be converted instead of leaving the target unset
+    #[serde(default)]
+    pub strict: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CastType {
+    String,
+    Integer,
+    Float,
+    Boolean,
+}
+
+impl CastType {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::Boolean => "boolean",
+        }
+    }
+}
+
+/// Convert `value` to the requested type, returning `None` if it cannot be converted
+#[must_use]
+pub fn cast_value(value: &Value, to: CastType) -> Option<Value> {
+    match to {
+        CastType::String => match value {
+            Value::Null => None,
+            Value::String(s) => Some(Value::String(s.clone())),
+            other => Some(Value::String(other.to_string())),
+        },
+        CastType::Integer => to_integer(value).map(Value::from),
+        CastType::Float => to_float(value).map(Value::from),
+        CastType::Boolean => to_boolean(value).map(Value::Bool),
+    }
+}
+
+fn to_integer(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => n.as_i64().or_else(|| n.as_f64().and_then(integral_f64)),
+        Value::String(s) => {
+            let s = s.trim();
+            s.parse::<i64>()
+                .ok()
+                .or_else(|| s.parse::<f64>().ok().and_then(integral_f64))
+        }
+        Value::Bool(b) => Some(i64::from(*b)),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+/// Floats without a fractional part that fit in `i64`
+fn integral_f64(f: f64) -> Option<i64> {
+    // i64::MAX is not exactly representable as f64; stay strictly below 2^63
+    const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+    if f.is_finite() && f.fract() == 0.0 && f.abs() < LIMIT {
+        #[allow(clippy::cast_possible_truncation)]
+        // Value has no fractional part and is checked to be within the i64 range
+        Some(f as i64)
+    } else {
+        None
+    }
+}
+
+fn to_float(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse::<f64>().ok().filter(|f| f.is_finite()),
+        Value::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+fn to_boolean(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(b) => Some(*b),
+        Value::Number(n) => match n.as_f64() {
+            Some(0.0) => Some(false),
+            Some(1.0) => Some(true),
+            _ => None,
+        },
+        Value::String(s) => match s.trim().to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" | "y" | "on" => Some(true),
+            "false" | "0" | "no" | "n" | "off" => Some(false),
+            _ => None,
+        },
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+/// Apply the cast transform to normalized data; missing or null sources are left untouched
+///
+/// # Errors
+/// Returns an execution error if the value cannot be converted and the cast is strict
+pub fn apply_cast(
+    step_idx: usize,
+    transform: &CastTransform,
+    normalized: &mut Value,
+) -> DslResult<()> {
+    let Some(value) = execution::get_nested(normalized, &transform.source).filter(|v| !v.is_null())
+    else {
+        return Ok(());
+    };
+    match cast_value(&value, transform.to) {
+        Some(converted) => execution::set_nested(normalized, &transform.target, converted),
+        None if transform.strict => {
+            return Err(DslError::Execution(format!(
+                "Step {step_idx}: Cannot cast field '{}' value {value} to {}",
+                transform.source,
+                transform.to.as_str()
+            )));
+        }
+        None => {}
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_cast_transform(
+    idx: usize,
+    ct: &CastTransform,
+    safe_field: &Regex,
+) -> DslResult<()> {
+    if !safe_field.is_match(&ct.source) {
+        return Err(DslError::invalid(
+            idx,
+            "transform.cast.source",
+            format!("DSL step {idx}: transform.cast.source must be a safe identifier"),
+        ));
+    }
+    if !safe_field.is_match(&ct.target) {
+        return Err(DslError::invalid(
+            idx,
+            "transform.cast.target",
+            format!("DSL step {idx}: transform.cast.target must be a safe identifier"),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn cast_field(value: &Value, to: CastType, strict: bool) -> DslResult<Value> {
+        let mut normalized = json!({ "raw": value });
+        apply_cast(
+            0,
+            &CastTransform {
+                source: "raw".to_string(),
+                target: "out".to_string(),
+                to,
+                strict,
+            },
+            &mut normalized,
+        )?;
+        Ok(normalized.get("out").cloned().unwrap_or(Value::Null))
+    }
+
+    #[test]
+    fn casts_to_string() {
+        assert_eq!(
+            cast_field(&json!(42), CastType::String, false).unwrap(),
+            json!("42")
+        );
+        assert_eq!(
+            cast_field(&json!(true), CastType::String, false).unwrap(),
+            json!("true")
+        );
+        assert_eq!(
+            cast_field(&json!("x"), CastType::String, false).unwrap(),
+            json!("x")
+        );
+    }
+
+    #[test]
+    fn casts_to_integer() {
+        assert_eq!(
+            cast_field(&json!("42"), CastType::Integer, false).unwrap(),
+            json!(42)
+        );
+        assert_eq!(
+            cast_field(&json!(" -7 "), CastType::Integer, false).unwrap(),
+            json!(-7)
+        );
+        assert_eq!(
+            cast_field(&json!("3.0"), CastType::Integer, false).unwrap(),
+            json!(3)
+        );
+        assert_eq!(
+            cast_field(&json!(true), CastType::Integer, false).unwrap(),
+            json!(1)
+        );
+        // Fractional values are not silently truncated
+        assert_eq!(
+            cast_field(&json!("3.5"), CastType::Integer, false).unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn casts_to_float() {
+        assert_eq!(
+            cast_field(&json!("2.5"), CastType::Float, false).unwrap(),
+            json!(2.5)
+        );
+        assert_eq!(
+            cast_field(&json!(4), CastType::Float, false).unwrap(),
+            json!(4.0)
+        );
+        assert_eq!(
+            cast_field(&json!("abc"), CastType::Float, false).unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn casts_to_boolean() {
+        for truthy in ["true", "1", "yes", "Y", " On "] {
+            assert_eq!(
+                cast_field(&json!(truthy), CastType::Boolean, false).unwrap(),
+                json!(true)
+            );
+        }
+        for falsy in ["false", "0", "no", "N", "off"] {
+            assert_eq!(
+                cast_field(&json!(falsy), CastType::Boolean, false).unwrap(),
+                json!(false)
+            );
+        }
+        assert_eq!(
+            cast_field(&json!(1), CastType::Boolean, false).unwrap(),
+            json!(true)
+        );
+        assert_eq!(
+            cast_field(&json!("maybe"), CastType::Boolean, false).unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn strict_cast_fails_on_unconvertible_value() {
+        let err = cast_field(&json!("maybe"), CastType::Boolean, true).unwrap_err();
+        assert!(
+            matches!(&err, DslError::Execution(msg) if msg.contains("Cannot cast field 'raw'")),
+            "got {err:?}"
+        );
+        // Missing values are not a failed cast
+        assert_eq!(
+            cast_field(&Value::Null, CastType::Integer, true).unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn validation_rejects_unsafe_fields() {
+        let safe = Regex::new(r"^[A-Za-z_][A-Za-z0-9_.]*$").unwrap();
+        let valid = CastTransform {
+            source: "active".to_string(),
+            target: "active".to_string(),
+            to: CastType::Boolean,
+            strict: false,
+        };
+        assert!(validate_cast_transform(0, &valid, &safe).is_ok());
+        let bad = CastTransform {
+            target: "active flag".to_string(),
+            ..valid
+        };
+        assert!(validate_cast_transform(0, &bad, &safe).is_err());
+    }
+}
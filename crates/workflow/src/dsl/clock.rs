@@ -0,0 +1,112 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use time::format_description::well_known::Rfc3339;
+use time::format_description::{self, OwnedFormatItem};
+use time::{Date, OffsetDateTime};
+
+/// Default format for `today` string operands
+pub const DEFAULT_DATE_FORMAT: &str = "[year]-[month]-[day]";
+
+/// Source of the current time for `now`/`today` operands
+///
+/// Programs use the system clock by default; a fixed clock makes runs deterministic (tests, replays).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Clock {
+    /// Current UTC time from the system
+    #[default]
+    System,
+    /// Always returns the given instant
+    Fixed(OffsetDateTime),
+}
+
+impl Clock {
+    /// Current instant according to this clock
+    #[must_use]
+    pub fn now(&self) -> OffsetDateTime {
+        match self {
+            Self::System => OffsetDateTime::now_utc(),
+            Self::Fixed(at) => *at,
+        }
+    }
+
+    /// Current date according to this clock
+    #[must_use]
+    pub fn today(&self) -> Date {
+        self.now().date()
+    }
+}
+
+/// Parse a `time` format description (e.g. `[year]-[month]-[day]`)
+///
+/// # Errors
+/// Returns an error message if the format description is invalid
+pub fn parse_format(format: &str) -> Result<OwnedFormatItem, String> {
+    format_description::parse_owned::<2>(format)
+        .map_err(|e| format!("Invalid time format '{format}': {e}"))
+}
+
+/// Format the current instant; RFC 3339 is used when no format is given
+///
+/// # Errors
+/// Returns an error message if the format is invalid or formatting fails
+pub fn format_now(clock: &Clock, format: Option<&str>) -> Result<String, String> {
+    let now = clock.now();
+    match format {
+        Some(f) => now.format(&parse_format(f)?),
+        None => now.format(&Rfc3339),
+    }
+    .map_err(|e| format!("Failed to format current time: {e}"))
+}
+
+/// Format the current date; `[year]-[month]-[day]` is used when no format is given
+///
+/// # Errors
+/// Returns an error message if the format is invalid or formatting fails
+pub fn format_today(clock: &Clock, format: Option<&str>) -> Result<String, String> {
+    clock
+        .today()
+        .format(&parse_format(format.unwrap_or(DEFAULT_DATE_FORMAT))?)
+        .map_err(|e| format!("Failed to format current date: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    const FIXED: Clock = Clock::Fixed(datetime!(2024-03-05 14:30:15 UTC));
+
+    #[test]
+    fn fixed_clock_returns_fixed_instant() {
+        assert_eq!(FIXED.now(), datetime!(2024-03-05 14:30:15 UTC));
+        assert_eq!(FIXED.today(), time::macros::date!(2024 - 03 - 05));
+    }
+
+    #[test]
+    fn format_now_defaults_to_rfc3339() {
+        assert_eq!(format_now(&FIXED, None).unwrap(), "2024-03-05T14:30:15Z");
+    }
+
+    #[test]
+    fn format_now_with_custom_format() {
+        assert_eq!(
+            format_now(&FIXED, Some("[day].[month].[year] [hour]:[minute]")).unwrap(),
+            "05.03.2024 14:30"
+        );
+    }
+
+    #[test]
+    fn format_today_defaults_to_iso_date() {
+        assert_eq!(format_today(&FIXED, None).unwrap(), "2024-03-05");
+        assert_eq!(
+            format_today(&FIXED, Some("[month]/[day]/[year]")).unwrap(),
+            "03/05/2024"
+        );
+    }
+
+    #[test]
+    fn invalid_format_is_rejected() {
+        assert!(format_now(&FIXED, Some("[nonsense]")).is_err());
+        assert!(parse_format("[year").is_err());
+    }
+}
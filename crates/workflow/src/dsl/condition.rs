@@ -0,0 +1,192 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cmp::Ordering;
+use utoipa::ToSchema;
+
+use super::error::{DslError, DslResult};
+
+/// Comparison operator for step conditions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonOp {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    /// Field is present and not null
+    Exists,
+    /// Field is missing or null
+    NotExists,
+}
+
+impl ComparisonOp {
+    /// Whether the operator compares against a `value`
+    #[must_use]
+    pub const fn needs_value(self) -> bool {
+        !matches!(self, Self::Exists | Self::NotExists)
+    }
+}
+
+/// Predicate over normalized fields deciding whether a step runs
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StepCondition {
+    /// Normalized field to inspect (dot notation for nested fields)
+    pub field: String,
+    pub op: ComparisonOp,
+    /// Value to compare against (not used by `exists`/`not_exists`)
+    #[serde(default)]
+    pub value: Option<Value>,
+}
+
+impl StepCondition {
+    /// Evaluate the condition against normalized data.
+    ///
+    /// Numbers (and numeric strings) are compared numerically, other strings lexically.
+    /// Ordering comparisons with a missing or incomparable field are false.
+    #[must_use]
+    pub fn evaluate(&self, normalized: &Value) -> bool {
+        let actual = lookup(normalized, &self.field).filter(|v| !v.is_null());
+        match self.op {
+            ComparisonOp::Exists => actual.is_some(),
+            ComparisonOp::NotExists => actual.is_none(),
+            ComparisonOp::Eq => self.compare(actual) == Some(Ordering::Equal),
+            ComparisonOp::Ne => self.compare(actual) != Some(Ordering::Equal),
+            ComparisonOp::Gt => self.compare(actual) == Some(Ordering::Greater),
+            ComparisonOp::Gte => matches!(
+                self.compare(actual),
+                Some(Ordering::Greater | Ordering::Equal)
+            ),
+            ComparisonOp::Lt => self.compare(actual) == Some(Ordering::Less),
+            ComparisonOp::Lte => {
+                matches!(self.compare(actual), Some(Ordering::Less | Ordering::Equal))
+            }
+        }
+    }
+
+    fn compare(&self, actual: Option<&Value>) -> Option<Ordering> {
+        let (actual, expected) = (actual?, self.value.as_ref()?);
+        if let (Some(a), Some(b)) = (as_number(actual), as_number(expected)) {
+            return a.partial_cmp(&b);
+        }
+        match (actual, expected) {
+            (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+            (a, b) if a == b => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+/// Strict dot-path lookup; missing segments yield `None`
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |current, key| current.get(key))
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+/// Validate a step condition
+///
+/// # Errors
+/// Returns an error if the field path is unsafe or the value does not fit the operator
+pub fn validate_condition(
+    idx: usize,
+    condition: &StepCondition,
+    safe_field: &Regex,
+) -> DslResult<()> {
+    if !safe_field.is_match(&condition.field) {
+        return Err(DslError::invalid(
+            idx,
+            "when.field",
+            format!("DSL step {idx}: when.field must be a safe field path"),
+        ));
+    }
+    match (&condition.value, condition.op.needs_value()) {
+        (None | Some(Value::Null), true) => Err(DslError::invalid(
+            idx,
+            "when.value",
+            format!("DSL step {idx}: when.value is required for this operator"),
+        )),
+        (Some(v), false) if !v.is_null() => Err(DslError::invalid(
+            idx,
+            "when.value",
+            format!("DSL step {idx}: when.value is not allowed for exists/not_exists"),
+        )),
+        (Some(Value::Array(_) | Value::Object(_)), true) => Err(DslError::invalid(
+            idx,
+            "when.value",
+            format!("DSL step {idx}: when.value must be a string, number or boolean"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn cond(field: &str, op: ComparisonOp, value: Option<Value>) -> StepCondition {
+        StepCondition {
+            field: field.to_string(),
+            op,
+            value,
+        }
+    }
+
+    #[test]
+    fn exists_checks_presence_and_null() {
+        let data = json!({ "country": "DE", "empty": null, "address": { "zip": "10115" } });
+        assert!(cond("country", ComparisonOp::Exists, None).evaluate(&data));
+        assert!(cond("address.zip", ComparisonOp::Exists, None).evaluate(&data));
+        assert!(!cond("empty", ComparisonOp::Exists, None).evaluate(&data));
+        assert!(!cond("missing", ComparisonOp::Exists, None).evaluate(&data));
+        assert!(cond("address.city", ComparisonOp::NotExists, None).evaluate(&data));
+    }
+
+    #[test]
+    fn equality_compares_numbers_numerically() {
+        let data = json!({ "qty": "5", "country": "DE", "active": true });
+        assert!(cond("qty", ComparisonOp::Eq, Some(json!(5))).evaluate(&data));
+        assert!(cond("country", ComparisonOp::Eq, Some(json!("DE"))).evaluate(&data));
+        assert!(cond("country", ComparisonOp::Ne, Some(json!("AT"))).evaluate(&data));
+        assert!(cond("active", ComparisonOp::Eq, Some(json!(true))).evaluate(&data));
+        assert!(cond("missing", ComparisonOp::Ne, Some(json!("x"))).evaluate(&data));
+    }
+
+    #[test]
+    fn ordering_comparisons() {
+        let data = json!({ "qty": 10, "name": "beta" });
+        assert!(cond("qty", ComparisonOp::Gt, Some(json!(5))).evaluate(&data));
+        assert!(cond("qty", ComparisonOp::Gte, Some(json!(10))).evaluate(&data));
+        assert!(!cond("qty", ComparisonOp::Lt, Some(json!(10))).evaluate(&data));
+        assert!(cond("qty", ComparisonOp::Lte, Some(json!("10"))).evaluate(&data));
+        assert!(cond("name", ComparisonOp::Gt, Some(json!("alpha"))).evaluate(&data));
+        assert!(!cond("missing", ComparisonOp::Gt, Some(json!(1))).evaluate(&data));
+    }
+
+    #[test]
+    fn validation_rejects_bad_conditions() {
+        let safe = Regex::new(r"^[A-Za-z_][A-Za-z0-9_.]*$").unwrap();
+        assert!(validate_condition(0, &cond("country", ComparisonOp::Exists, None), &safe).is_ok());
+        assert!(validate_condition(0, &cond("a-b", ComparisonOp::Exists, None), &safe).is_err());
+        assert!(validate_condition(0, &cond("qty", ComparisonOp::Gt, None), &safe).is_err());
+        assert!(
+            validate_condition(0, &cond("qty", ComparisonOp::Eq, Some(json!([1]))), &safe).is_err()
+        );
+        assert!(
+            validate_condition(0, &cond("qty", ComparisonOp::Exists, Some(json!(1))), &safe)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,86 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use serde_json::Value;
+
+/// Merge a partial configuration over a stored workflow config for a single run
+///
+/// Objects are merged key by key, arrays element by element (so `steps[0]` can be patched
+/// without repeating the whole step), and any other value replaces the stored one.
+/// A `null` inside an object removes that key.
+#[must_use]
+pub fn merge_config_override(base: &Value, overrides: &Value) -> Value {
+    match (base, overrides) {
+        (Value::Object(base_map), Value::Object(override_map)) => {
+            let mut merged = base_map.clone();
+            for (key, value) in override_map {
+                if value.is_null() {
+                    merged.remove(key);
+                } else {
+                    let next = merged.get(key).map_or_else(
+                        || value.clone(),
+                        |existing| merge_config_override(existing, value),
+                    );
+                    merged.insert(key.clone(), next);
+                }
+            }
+            Value::Object(merged)
+        }
+        (Value::Array(base_items), Value::Array(override_items)) => {
+            let mut merged = base_items.clone();
+            for (idx, value) in override_items.iter().enumerate() {
+                if let Some(existing) = merged.get_mut(idx) {
+                    *existing = merge_config_override(existing, value);
+                } else {
+                    merged.push(value.clone());
+                }
+            }
+            Value::Array(merged)
+        }
+        (_, value) => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn patches_nested_step_fields_by_index() {
+        let base = json!({
+            "steps": [
+                {"from": {"source": {"source_type": "uri", "config": {"uri": "http://a/csv"}}}},
+                {"from": {"source": {"source_type": "uri", "config": {"uri": "http://b/csv"}}}}
+            ]
+        });
+        let overrides = json!({
+            "steps": [{"from": {"source": {"config": {"uri": "http://override/csv"}}}}]
+        });
+
+        let merged = merge_config_override(&base, &overrides);
+        assert_eq!(
+            merged["steps"][0]["from"]["source"]["config"]["uri"],
+            "http://override/csv"
+        );
+        assert_eq!(merged["steps"][0]["from"]["source"]["source_type"], "uri");
+        assert_eq!(
+            merged["steps"][1]["from"]["source"]["config"]["uri"],
+            "http://b/csv"
+        );
+    }
+
+    #[test]
+    fn null_removes_key_and_scalars_replace() {
+        let base = json!({"on_complete": {"action": "x"}, "limit": 5, "tags": ["a"]});
+        let overrides = json!({"on_complete": null, "limit": 10, "tags": "b"});
+
+        let merged = merge_config_override(&base, &overrides);
+        assert_eq!(merged, json!({"limit": 10, "tags": "b"}));
+    }
+
+    #[test]
+    fn extra_array_items_are_appended() {
+        let merged = merge_config_override(&json!([1]), &json!([2, 3]));
+        assert_eq!(merged, json!([2, 3]));
+    }
+}
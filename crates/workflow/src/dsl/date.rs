@@ -0,0 +1,340 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::format_description::well_known::Rfc3339;
+use time::format_description::{self, BorrowedFormatItem};
+use time::macros::format_description;
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
+use utoipa::ToSchema;
+
+use super::error::{DslError, DslResult};
+use super::execution;
+
+/// Parse a date string in a custom format into an ISO-8601 (RFC 3339) timestamp
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DateParseTransform {
+    /// Normalized field holding the date string
+    pub source: String,
+    /// Target normalized field to set
+    pub target: String,
+    /// Input format as a `time` format description, e.g. `[month]/[day]/[year]`.
+    /// Dates without a time are taken as midnight and values without an offset as UTC.
+    pub format: String,
+}
+
+/// Format an ISO-8601 date or timestamp using a custom pattern
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DateFormatTransform {
+    /// Normalized field holding the ISO-8601 value (`2023-03-14` or `2023-03-14T00:00:00Z`)
+    pub source: String,
+    /// Target normalized field to set
+    pub target: String,
+    /// Output format as a `time` format description, e.g. `[day].[month].[year]`
+    pub format: String,
+}
+
+fn parse_format(format: &str) -> Result<Vec<BorrowedFormatItem<'_>>, String> {
+    if format.trim().is_empty() {
+        return Err("must not be empty".to_string());
+    }
+    format_description::parse(format).map_err(|e| e.to_string())
+}
+
+/// Parse `input` with the given format items, accepting dates, local timestamps and timestamps
+/// with an offset
+fn parse_with(input: &str, items: &[BorrowedFormatItem<'_>]) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(input, items)
+        .ok()
+        .or_else(|| {
+            PrimitiveDateTime::parse(input, items)
+                .ok()
+                .map(PrimitiveDateTime::assume_utc)
+        })
+        .or_else(|| {
+            Date::parse(input, items)
+                .ok()
+                .map(|date| date.midnight().assume_utc())
+        })
+}
+
+/// Parse an RFC 3339 timestamp or a plain ISO-8601 date
+fn parse_iso(input: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(input, &Rfc3339).ok().or_else(|| {
+        Date::parse(input, format_description!("[year]-[month]-[day]"))
+            .ok()
+            .map(|date| date.midnight().assume_utc())
+    })
+}
+
+/// String value of the source field; missing or null sources yield `None`
+fn source_string(
+    step_idx: usize,
+    kind: &str,
+    source: &str,
+    normalized: &Value,
+) -> DslResult<Option<String>> {
+    match execution::get_nested(normalized, source) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.trim().to_string())),
+        Some(other) => Err(DslError::Execution(format!(
+            "Step {step_idx}: {kind} expects a string in field '{source}', got {other}"
+        ))),
+    }
+}
+
+/// Apply the date parse transform; missing or null sources are left untouched
+///
+/// # Errors
+/// Returns an execution error if the value is not a string matching the format
+pub fn apply_date_parse(
+    step_idx: usize,
+    transform: &DateParseTransform,
+    normalized: &mut Value,
+) -> DslResult<()> {
+    let Some(input) = source_string(step_idx, "date_parse", &transform.source, normalized)? else {
+        return Ok(());
+    };
+    let parsed = parse_format(&transform.format)
+        .ok()
+        .and_then(|items| parse_with(&input, &items))
+        .and_then(|dt| dt.format(&Rfc3339).ok())
+        .ok_or_else(|| {
+            DslError::Execution(format!(
+                "Step {step_idx}: Cannot parse date field '{}' value '{input}' with format '{}'",
+                transform.source, transform.format
+            ))
+        })?;
+    execution::set_nested(normalized, &transform.target, Value::String(parsed));
+    Ok(())
+}
+
+/// Apply the date format transform; missing or null sources are left untouched
+///
+/// # Errors
+/// Returns an execution error if the value is not an ISO-8601 date or timestamp
+pub fn apply_date_format(
+    step_idx: usize,
+    transform: &DateFormatTransform,
+    normalized: &mut Value,
+) -> DslResult<()> {
+    let Some(input) = source_string(step_idx, "date_format", &transform.source, normalized)? else {
+        return Ok(());
+    };
+    let formatted = parse_iso(&input)
+        .and_then(|dt| {
+            parse_format(&transform.format)
+                .ok()
+                .and_then(|items| dt.format(&items).ok())
+        })
+        .ok_or_else(|| {
+            DslError::Execution(format!(
+                "Step {step_idx}: Cannot format date field '{}' value '{input}' as '{}'",
+                transform.source, transform.format
+            ))
+        })?;
+    execution::set_nested(normalized, &transform.target, Value::String(formatted));
+    Ok(())
+}
+
+fn validate_date_fields(
+    idx: usize,
+    kind: &str,
+    source: &str,
+    target: &str,
+    format: &str,
+    safe_field: &Regex,
+) -> DslResult<()> {
+    if !safe_field.is_match(source) {
+        return Err(DslError::invalid(
+            idx,
+            format!("transform.{kind}.source"),
+            format!("DSL step {idx}: transform.{kind}.source must be a safe identifier"),
+        ));
+    }
+    if !safe_field.is_match(target) {
+        return Err(DslError::invalid(
+            idx,
+            format!("transform.{kind}.target"),
+            format!("DSL step {idx}: transform.{kind}.target must be a safe identifier"),
+        ));
+    }
+    if let Err(e) = parse_format(format) {
+        return Err(DslError::invalid(
+            idx,
+            format!("transform.{kind}.format"),
+            format!("DSL step {idx}: transform.{kind}.format is invalid: {e}"),
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_date_parse_transform(
+    idx: usize,
+    dp: &DateParseTransform,
+    safe_field: &Regex,
+) -> DslResult<()> {
+    validate_date_fields(
+        idx,
+        "date_parse",
+        &dp.source,
+        &dp.target,
+        &dp.format,
+        safe_field,
+    )
+}
+
+pub(crate) fn validate_date_format_transform(
+    idx: usize,
+    df: &DateFormatTransform,
+    safe_field: &Regex,
+) -> DslResult<()> {
+    validate_date_fields(
+        idx,
+        "date_format",
+        &df.source,
+        &df.target,
+        &df.format,
+        safe_field,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const US_DATE: &str = "[month]/[day]/[year]";
+
+    fn parse(format: &str) -> DateParseTransform {
+        DateParseTransform {
+            source: "raw".to_string(),
+            target: "iso".to_string(),
+            format: format.to_string(),
+        }
+    }
+
+    fn format(format: &str) -> DateFormatTransform {
+        DateFormatTransform {
+            source: "iso".to_string(),
+            target: "out".to_string(),
+            format: format.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_us_date_to_iso_and_back() {
+        let mut normalized = json!({ "raw": "03/14/2023" });
+        apply_date_parse(0, &parse(US_DATE), &mut normalized).unwrap();
+        assert_eq!(normalized["iso"], json!("2023-03-14T00:00:00Z"));
+
+        apply_date_format(0, &format(US_DATE), &mut normalized).unwrap();
+        assert_eq!(normalized["out"], json!("03/14/2023"));
+    }
+
+    #[test]
+    fn parses_timestamps_with_and_without_offset() {
+        let mut normalized = json!({ "raw": "14.03.2023 09:30" });
+        apply_date_parse(
+            0,
+            &parse("[day].[month].[year] [hour]:[minute]"),
+            &mut normalized,
+        )
+        .unwrap();
+        assert_eq!(normalized["iso"], json!("2023-03-14T09:30:00Z"));
+
+        let mut normalized = json!({ "raw": "2023-03-14 09:30 +02:00" });
+        apply_date_parse(
+            0,
+            &parse("[year]-[month]-[day] [hour]:[minute] [offset_hour]:[offset_minute]"),
+            &mut normalized,
+        )
+        .unwrap();
+        assert_eq!(normalized["iso"], json!("2023-03-14T09:30:00+02:00"));
+    }
+
+    #[test]
+    fn formats_plain_iso_dates() {
+        let mut normalized = json!({ "iso": "2023-03-14" });
+        apply_date_format(0, &format("[day].[month].[year]"), &mut normalized).unwrap();
+        assert_eq!(normalized["out"], json!("14.03.2023"));
+    }
+
+    #[test]
+    fn mismatching_values_fail_and_missing_values_are_skipped() {
+        let mut normalized = json!({ "raw": "2023-03-14" });
+        let err = apply_date_parse(2, &parse(US_DATE), &mut normalized).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Step 2: Cannot parse date field 'raw'"),
+            "got {err}"
+        );
+
+        let mut normalized = json!({ "iso": "03/14/2023" });
+        assert!(apply_date_format(0, &format(US_DATE), &mut normalized).is_err());
+
+        let mut normalized = json!({ "raw": null });
+        apply_date_parse(0, &parse(US_DATE), &mut normalized).unwrap();
+        assert_eq!(normalized, json!({ "raw": null }));
+    }
+
+    #[test]
+    fn validation_rejects_bad_formats_and_unsafe_fields() {
+        let safe = Regex::new(r"^[A-Za-z_][A-Za-z0-9_.]*$").unwrap();
+        assert!(validate_date_parse_transform(0, &parse(US_DATE), &safe).is_ok());
+        assert!(validate_date_format_transform(0, &format(US_DATE), &safe).is_ok());
+
+        for bad in ["", "[month]/[dya]/[year]", "[month"] {
+            let err = validate_date_parse_transform(1, &parse(bad), &safe).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("transform.date_parse.format is invalid"),
+                "{bad}: {err}"
+            );
+            assert!(validate_date_format_transform(1, &format(bad), &safe).is_err());
+        }
+
+        let unsafe_target = DateParseTransform {
+            target: "iso date".to_string(),
+            ..parse(US_DATE)
+        };
+        assert!(validate_date_parse_transform(0, &unsafe_target, &safe).is_err());
+    }
+
+    #[test]
+    fn program_validation_rejects_bad_format() {
+        let config = |format: &str| {
+            json!({
+                "steps": [{
+                    "from": {
+                        "type": "trigger",
+                        "mapping": { "raw": "raw" }
+                    },
+                    "transform": {
+                        "type": "date_parse",
+                        "source": "raw",
+                        "target": "iso",
+                        "format": format
+                    },
+                    "to": {
+                        "type": "format",
+                        "output": { "mode": "api" },
+                        "format": { "format_type": "json", "options": {} },
+                        "mapping": { "iso": "iso" }
+                    }
+                }]
+            })
+        };
+        let program = crate::dsl::DslProgram::from_config(&config(US_DATE)).unwrap();
+        program.validate().unwrap();
+
+        let program = crate::dsl::DslProgram::from_config(&config("[month]/[dya]")).unwrap();
+        let err = program.validate().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("DSL step 0: transform.date_parse.format is invalid"),
+            "got {err}"
+        );
+    }
+}
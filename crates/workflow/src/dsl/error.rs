@@ -0,0 +1,129 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use r_data_core_core::error::Error;
+
+/// Result of parsing, validating or executing a DSL program
+pub type DslResult<T> = std::result::Result<T, DslError>;
+
+/// Error of the workflow DSL
+///
+/// Lets callers tell a broken configuration apart from a record that failed while running
+/// it. Messages are the same as those reported before the DSL had its own error type.
+#[derive(Debug, thiserror::Error)]
+pub enum DslError {
+    /// The configuration could not be read into steps
+    #[error("{0}")]
+    Parse(String),
+
+    /// The program is not valid
+    #[error("{reason}")]
+    Validation {
+        /// Index of the offending step; `None` for program-wide checks and `on_complete`
+        step: Option<usize>,
+        /// Path of the offending setting, e.g. `from.format.source.config.uri`
+        field: Option<String>,
+        /// Full message, including step and field
+        reason: String,
+    },
+
+    /// A step failed on the record being processed
+    #[error("{0}")]
+    Execution(String),
+
+    /// A value that has to be read from outside the record could not be resolved
+    #[error("{0}")]
+    ExternalLookup(String),
+}
+
+impl DslError {
+    /// Validation error of `field` in step `step`
+    pub(crate) fn invalid(
+        step: usize,
+        field: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::Validation {
+            step: Some(step),
+            field: Some(field.into()),
+            reason: reason.into(),
+        }
+    }
+
+    /// Validation error not tied to a single step
+    pub(crate) fn invalid_program(field: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::Validation {
+            step: None,
+            field: Some(field.into()),
+            reason: reason.into(),
+        }
+    }
+
+    /// Index of the step a validation error belongs to
+    #[must_use]
+    pub const fn step(&self) -> Option<usize> {
+        match self {
+            Self::Validation { step, .. } => *step,
+            _ => None,
+        }
+    }
+
+    /// Path of the setting a validation error belongs to
+    #[must_use]
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            Self::Validation { field, .. } => field.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl From<DslError> for Error {
+    fn from(err: DslError) -> Self {
+        match err {
+            DslError::Parse(msg) => Self::Deserialization(msg),
+            DslError::Validation { reason, .. } => Self::Validation(reason),
+            DslError::Execution(msg) => Self::Conversion(msg),
+            DslError::ExternalLookup(msg) => Self::Entity(msg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validation_error_keeps_step_field_and_message() {
+        let err = DslError::invalid(
+            2,
+            "to.entity.entity_definition",
+            "DSL step 2: to.entity.entity_definition must not be empty",
+        );
+        assert_eq!(err.step(), Some(2));
+        assert_eq!(err.field(), Some("to.entity.entity_definition"));
+        assert_eq!(
+            err.to_string(),
+            "DSL step 2: to.entity.entity_definition must not be empty"
+        );
+    }
+
+    #[test]
+    fn test_conversion_to_core_error() {
+        assert!(matches!(
+            Error::from(DslError::Parse("bad".to_string())),
+            Error::Deserialization(msg) if msg == "bad"
+        ));
+        assert!(matches!(
+            Error::from(DslError::invalid(0, "when.field", "invalid")),
+            Error::Validation(msg) if msg == "invalid"
+        ));
+        assert!(matches!(
+            Error::from(DslError::Execution("failed".to_string())),
+            Error::Conversion(msg) if msg == "failed"
+        ));
+        assert!(matches!(
+            Error::from(DslError::ExternalLookup("missing".to_string())),
+            Error::Entity(msg) if msg == "missing"
+        ));
+    }
+}
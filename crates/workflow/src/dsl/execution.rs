@@ -2,6 +2,7 @@
 
 use serde_json::Value;
 
+use super::clock::{self, Clock};
 use super::transform::{Operand, StringOperand};
 
 /// Cast a JSON value to f64 with strict error handling
@@ -96,13 +97,14 @@ pub fn cast_to_string_smart(value: &Value, field_name: &str) -> Result<String, S
 /// # Arguments
 /// * `ctx` - Context JSON value
 /// * `op` - Operand to evaluate
+/// * `clock` - Clock used to resolve `now`/`today` operands
 ///
 /// # Returns
 /// Result with f64 value or error message
 ///
 /// # Errors
 /// Returns an error if the operand cannot be evaluated or cast to a number
-pub fn eval_operand(ctx: &Value, op: &Operand) -> Result<f64, String> {
+pub fn eval_operand(ctx: &Value, op: &Operand, clock: &Clock) -> Result<f64, String> {
     match op {
         Operand::Field { field } => {
             let value = get_nested(ctx, field)
@@ -110,6 +112,8 @@ pub fn eval_operand(ctx: &Value, op: &Operand) -> Result<f64, String> {
             cast_to_f64_strict(&value, field)
         }
         Operand::Const { value } => Ok(*value),
+        Operand::Now => Ok(unix_seconds(clock.now())),
+        Operand::Today => Ok(unix_seconds(clock.today().midnight().assume_utc())),
         Operand::ExternalEntityField { .. } => {
             // Future: resolve from repository; for now not supported
             Err("ExternalEntityField is not supported in calculations".to_string())
@@ -122,13 +126,18 @@ pub fn eval_operand(ctx: &Value, op: &Operand) -> Result<f64, String> {
 /// # Arguments
 /// * `ctx` - Context JSON value
 /// * `op` - String operand to evaluate
+/// * `clock` - Clock used to resolve `now`/`today` operands
 ///
 /// # Returns
 /// Result with String value or error message
 ///
 /// # Errors
 /// Returns an error if the operand cannot be evaluated or cast to a string
-pub fn eval_string_operand(ctx: &Value, op: &StringOperand) -> Result<String, String> {
+pub fn eval_string_operand(
+    ctx: &Value,
+    op: &StringOperand,
+    clock: &Clock,
+) -> Result<String, String> {
     match op {
         StringOperand::Field { field } => {
             let value = get_nested(ctx, field)
@@ -136,9 +145,16 @@ pub fn eval_string_operand(ctx: &Value, op: &StringOperand) -> Result<String, St
             cast_to_string_smart(&value, field)
         }
         StringOperand::ConstString { value } => Ok(value.clone()),
+        StringOperand::Now { format } => clock::format_now(clock, format.as_deref()),
+        StringOperand::Today { format } => clock::format_today(clock, format.as_deref()),
     }
 }
 
+#[allow(clippy::cast_precision_loss)] // Unix timestamps in seconds are well within f64's exact integer range
+const fn unix_seconds(at: time::OffsetDateTime) -> f64 {
+    at.unix_timestamp() as f64
+}
+
 /// Get a nested value from a JSON object using dot notation
 ///
 /// # Arguments
@@ -146,17 +162,14 @@ pub fn eval_string_operand(ctx: &Value, op: &StringOperand) -> Result<String, St
 /// * `path` - Dot-separated path (e.g., "user.name")
 ///
 /// # Returns
-/// Optional Value if the path exists
+/// Optional Value if the path exists. Every segment must exist: a missing key, or a segment
+/// below a non-object, yields `None` rather than the value reached so far.
 #[must_use]
 pub fn get_nested(input: &Value, path: &str) -> Option<Value> {
     let mut current = input;
     for key in path.split('.') {
         match current {
-            Value::Object(map) => {
-                if let Some(v) = map.get(key) {
-                    current = v;
-                }
-            }
+            Value::Object(map) => current = map.get(key)?,
             _ => return None,
         }
     }
@@ -165,18 +178,42 @@ pub fn get_nested(input: &Value, path: &str) -> Option<Value> {
 
 /// Set a nested value in a JSON object using dot notation
 ///
+/// Walks the path in place, creating missing intermediate objects and replacing non-object
+/// values on the way. An object set where one already exists is deep-merged into it.
+///
 /// # Arguments
 /// * `target` - Target JSON value (will be modified)
 /// * `path` - Dot-separated path (e.g., "user.name")
 /// * `val` - Value to set
 pub fn set_nested(target: &mut Value, path: &str, val: Value) {
-    let mut acc = val;
-    for key in path.split('.').rev() {
-        let mut map = serde_json::Map::new();
-        map.insert(key.to_string(), acc);
-        acc = Value::Object(map);
+    let mut current = target;
+    let mut keys = path.split('.').peekable();
+    while let Some(key) = keys.next() {
+        if !current.is_object() {
+            *current = Value::Object(serde_json::Map::new());
+        }
+        let Value::Object(map) = current else {
+            return;
+        };
+
+        if keys.peek().is_none() {
+            match map.get_mut(key) {
+                Some(existing) => merge_objects(existing, val),
+                None => {
+                    map.insert(key.to_string(), val);
+                }
+            }
+            return;
+        }
+
+        if !map.contains_key(key) {
+            map.insert(key.to_string(), Value::Object(serde_json::Map::new()));
+        }
+        let Some(next) = map.get_mut(key) else {
+            return;
+        };
+        current = next;
     }
-    merge_objects(target, &acc);
 }
 
 /// Merge two JSON objects, with the addition taking precedence
@@ -184,19 +221,19 @@ pub fn set_nested(target: &mut Value, path: &str, val: Value) {
 /// # Arguments
 /// * `target` - Target JSON value (will be modified)
 /// * `addition` - JSON value to merge into target
-fn merge_objects(target: &mut Value, addition: &Value) {
+fn merge_objects(target: &mut Value, addition: Value) {
     match (target, addition) {
         (Value::Object(tobj), Value::Object(aobj)) => {
             for (k, v) in aobj {
-                if let Some(existing) = tobj.get_mut(k) {
+                if let Some(existing) = tobj.get_mut(&k) {
                     merge_objects(existing, v);
                 } else {
-                    tobj.insert(k.clone(), v.clone());
+                    tobj.insert(k, v);
                 }
             }
         }
         (t, v) => {
-            *t = v.clone();
+            *t = v;
         }
     }
 }
@@ -309,3 +346,76 @@ mod literal_value_tests {
         assert_eq!(result, None);
     }
 }
+
+#[cfg(test)]
+mod get_nested_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_get_nested_existing_path() {
+        let data = json!({ "user": { "name": "Ada" } });
+        assert_eq!(get_nested(&data, "user.name"), Some(json!("Ada")));
+    }
+
+    #[test]
+    fn test_get_nested_missing_key_returns_none() {
+        // Regression: a missing key used to leave the lookup on the enclosing object
+        let data = json!({ "user": { "name": "Ada" } });
+        assert_eq!(get_nested(&data, "country"), None);
+        assert_eq!(get_nested(&data, "user.email"), None);
+        assert_eq!(get_nested(&data, "user.name.first"), None);
+    }
+}
+
+#[cfg(test)]
+mod set_nested_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_set_nested_creates_intermediate_objects() {
+        let mut target = json!({});
+        set_nested(&mut target, "user.address.city", json!("Berlin"));
+        assert_eq!(
+            target,
+            json!({ "user": { "address": { "city": "Berlin" } } })
+        );
+    }
+
+    #[test]
+    fn test_set_nested_paths_sharing_a_deep_prefix_are_merged() {
+        let mut target = json!({});
+        set_nested(&mut target, "a.b.c.d", json!(1));
+        set_nested(&mut target, "a.b.c.e", json!(2));
+        set_nested(&mut target, "a.b.f", json!(3));
+        set_nested(&mut target, "a.g", json!(4));
+        assert_eq!(
+            target,
+            json!({ "a": { "b": { "c": { "d": 1, "e": 2 }, "f": 3 }, "g": 4 } })
+        );
+
+        // Setting an object where one exists merges instead of replacing it
+        set_nested(&mut target, "a.b", json!({ "c": { "d": 5 }, "h": 6 }));
+        assert_eq!(
+            target,
+            json!({ "a": { "b": { "c": { "d": 5, "e": 2 }, "f": 3, "h": 6 }, "g": 4 } })
+        );
+    }
+
+    #[test]
+    fn test_set_nested_replaces_non_objects_on_the_path() {
+        let mut target = json!({ "a": "scalar", "b": { "c": [1, 2] } });
+        set_nested(&mut target, "a.x", json!(1));
+        set_nested(&mut target, "b.c", json!({ "d": 2 }));
+        set_nested(&mut target, "b.c.d", json!(null));
+        assert_eq!(
+            target,
+            json!({ "a": { "x": 1 }, "b": { "c": { "d": null } } })
+        );
+
+        let mut scalar = json!(42);
+        set_nested(&mut scalar, "y", json!(true));
+        assert_eq!(scalar, json!({ "y": true }));
+    }
+}
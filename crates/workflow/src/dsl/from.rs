@@ -1,5 +1,7 @@
 use crate::data::adapters::auth::AuthConfig;
-use crate::dsl::validate_mapping;
+use crate::data::adapters::http::HttpRetryPolicy;
+use crate::data::adapters::source::uri::UriPagination;
+use crate::dsl::{validate_mapping, DslError, DslResult};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -71,11 +73,7 @@ pub enum FromDef {
     },
 }
 
-pub(crate) fn validate_from(
-    idx: usize,
-    from: &FromDef,
-    safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+pub(crate) fn validate_from(idx: usize, from: &FromDef, safe_field: &Regex) -> DslResult<()> {
     match from {
         FromDef::Format {
             source,
@@ -98,20 +96,24 @@ fn validate_format_from(
     format: &FormatConfig,
     mapping: &std::collections::HashMap<String, String>,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if source.source_type.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: from.format.source.source_type must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "from.format.source.source_type",
+            format!("DSL step {idx}: from.format.source.source_type must not be empty"),
+        ));
     }
     if format.format_type.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: from.format.format.format_type must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "from.format.format.format_type",
+            format!("DSL step {idx}: from.format.format.format_type must not be empty"),
+        ));
     }
 
     validate_format_options(idx, format)?;
-    validate_source_config(idx, source)?;
+    validate_source_config(idx, source, format)?;
 
     // Validate auth config if present
     if let Some(auth) = &source.auth {
@@ -123,24 +125,21 @@ fn validate_format_from(
     Ok(())
 }
 
-fn validate_format_options(
-    idx: usize,
-    format: &FormatConfig,
-) -> r_data_core_core::error::Result<()> {
+fn validate_format_options(idx: usize, format: &FormatConfig) -> DslResult<()> {
     if format.format_type.as_str() == "csv" {
         if let Some(delimiter) = format.options.get("delimiter").and_then(|v| v.as_str()) {
             if delimiter.len() != 1 {
-                return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: from.format.format.options.delimiter must be a single character")));
+                return Err(DslError::invalid(idx, "from.format.format.options.delimiter", format!("DSL step {idx}: from.format.format.options.delimiter must be a single character")));
             }
         }
         if let Some(escape) = format.options.get("escape").and_then(|v| v.as_str()) {
             if !escape.is_empty() && escape.len() != 1 {
-                return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: from.format.format.options.escape must be a single character when set")));
+                return Err(DslError::invalid(idx, "from.format.format.options.escape", format!("DSL step {idx}: from.format.format.options.escape must be a single character when set")));
             }
         }
         if let Some(quote) = format.options.get("quote").and_then(|v| v.as_str()) {
             if !quote.is_empty() && quote.len() != 1 {
-                return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: from.format.format.options.quote must be a single character when set")));
+                return Err(DslError::invalid(idx, "from.format.format.options.quote", format!("DSL step {idx}: from.format.format.options.quote must be a single character when set")));
             }
         }
     }
@@ -152,10 +151,11 @@ fn validate_format_options(
 fn validate_source_config(
     idx: usize,
     source: &SourceConfig,
-) -> r_data_core_core::error::Result<()> {
+    format: &FormatConfig,
+) -> DslResult<()> {
     #[allow(clippy::match_same_arms)] // "file" and "_" have different semantic meanings
     match source.source_type.as_str() {
-        "uri" => validate_uri_source(idx, &source.config)?,
+        "uri" => validate_uri_source(idx, &source.config, format)?,
         "file" => {
             // File source is handled during manual runs
         }
@@ -167,32 +167,64 @@ fn validate_source_config(
     Ok(())
 }
 
-fn validate_uri_source(idx: usize, config: &Value) -> r_data_core_core::error::Result<()> {
+fn validate_uri_source(idx: usize, config: &Value, format: &FormatConfig) -> DslResult<()> {
     if let Some(uri) = config.get("uri").and_then(|v| v.as_str()) {
         if uri.trim().is_empty() {
-            return Err(r_data_core_core::error::Error::Validation(format!(
-                "DSL step {idx}: from.format.source.config.uri must not be empty"
-            )));
+            return Err(DslError::invalid(
+                idx,
+                "from.format.source.config.uri",
+                format!("DSL step {idx}: from.format.source.config.uri must not be empty"),
+            ));
         }
         if !uri.starts_with("http://") && !uri.starts_with("https://") {
-            return Err(r_data_core_core::error::Error::Validation(format!(
+            return Err(DslError::invalid(
+                idx,
+                "from.format.source.config.uri",
+                format!(
                 "DSL step {idx}: from.format.source.config.uri must start with http:// or https://"
-            )));
+            ),
+            ));
         }
     } else {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: from.format.source.config.uri is required for uri source"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "from.format.source.config.uri",
+            format!("DSL step {idx}: from.format.source.config.uri is required for uri source"),
+        ));
+    }
+    HttpRetryPolicy::from_config(config).map_err(|e| {
+        DslError::invalid(
+            idx,
+            "from.format.source.config",
+            format!("DSL step {idx}: from.format.source.config: {e}"),
+        )
+    })?;
+    let pagination = UriPagination::from_config(config).map_err(|e| {
+        DslError::invalid(
+            idx,
+            "from.format.source.config.pagination",
+            format!("DSL step {idx}: from.format.source.config.pagination: {e}"),
+        )
+    })?;
+    // Pages are merged as JSON records
+    if pagination.is_some() && format.format_type != "json" {
+        return Err(DslError::invalid(
+            idx,
+            "from.format.source.config.pagination",
+            format!(
+                "DSL step {idx}: from.format.source.config.pagination requires format_type 'json'"
+            ),
+        ));
     }
     Ok(())
 }
 
-fn validate_api_source(idx: usize, config: &Value) -> r_data_core_core::error::Result<()> {
+fn validate_api_source(idx: usize, config: &Value) -> DslResult<()> {
     // from.api source type = Accept data via POST to this workflow
     // No endpoint field needed (always /api/v1/workflows/{this-workflow-uuid})
     // If endpoint field is present, it's invalid (use from.uri instead to pull from provider workflows)
     if config.get("endpoint").is_some() {
-        return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: from.format.source.config.endpoint is not allowed for 'api' source type. Use 'uri' source type to pull from provider workflows.")));
+        return Err(DslError::invalid(idx, "from.format.source.config.endpoint", format!("DSL step {idx}: from.format.source.config.endpoint is not allowed for 'api' source type. Use 'uri' source type to pull from provider workflows.")));
     }
     Ok(())
 }
@@ -201,12 +233,14 @@ fn validate_trigger_from(
     idx: usize,
     mapping: &std::collections::HashMap<String, String>,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     // Trigger can only be used in step 0 (first step)
     if idx != 0 {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: from.trigger can only be used in the first step (step 0)."
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "from.trigger",
+            format!("DSL step {idx}: from.trigger can only be used in the first step (step 0)."),
+        ));
     }
     // Allow empty mappings (pass through all fields, though trigger has no input data)
     validate_mapping(idx, mapping, safe_field)?;
@@ -219,11 +253,13 @@ fn validate_entity_from(
     filter: Option<&EntityFilter>,
     mapping: &std::collections::HashMap<String, String>,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if entity_definition.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: from.entity.entity_definition must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "from.entity.entity_definition",
+            format!("DSL step {idx}: from.entity.entity_definition must not be empty"),
+        ));
     }
 
     if let Some(filter) = filter {
@@ -235,32 +271,34 @@ fn validate_entity_from(
     Ok(())
 }
 
-fn validate_entity_filter(
-    idx: usize,
-    filter: &EntityFilter,
-    safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+fn validate_entity_filter(idx: usize, filter: &EntityFilter, safe_field: &Regex) -> DslResult<()> {
     if filter.field.trim().is_empty()
         || filter.value.trim().is_empty()
         || filter.operator.trim().is_empty()
     {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: from.entity.filter requires field, operator, and value"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "from.entity.filter",
+            format!("DSL step {idx}: from.entity.filter requires field, operator, and value"),
+        ));
     }
 
     // Validate filter field name is safe (prevents SQL injection)
     if !safe_field.is_match(&filter.field) {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: from.entity.filter.field must be a safe identifier (got: '{}')",
-            filter.field
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "from.entity.filter.field",
+            format!(
+                "DSL step {idx}: from.entity.filter.field must be a safe identifier (got: '{}')",
+                filter.field
+            ),
+        ));
     }
 
     // Validate operator is one of the allowed values
     let allowed_operators = ["=", ">", "<", "<=", ">=", "IN", "NOT IN"];
     if !allowed_operators.contains(&filter.operator.as_str()) {
-        return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: from.entity.filter.operator must be one of: =, >, <, <=, >=, IN, NOT IN")));
+        return Err(DslError::invalid(idx, "from.entity.filter.operator", format!("DSL step {idx}: from.entity.filter.operator must be one of: =, >, <, <=, >=, IN, NOT IN")));
     }
 
     Ok(())
@@ -270,10 +308,10 @@ fn validate_previous_step_from(
     idx: usize,
     mapping: &std::collections::HashMap<String, String>,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     // PreviousStep can only be used in steps after step 0
     if idx == 0 {
-        return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: from.previous_step cannot be used in the first step (step 0). The first step must read from a Format or Entity source.")));
+        return Err(DslError::invalid(idx, "from.previous_step", format!("DSL step {idx}: from.previous_step cannot be used in the first step (step 0). The first step must read from a Format or Entity source.")));
     }
 
     // Allow empty mappings (pass through all fields from previous step)
@@ -282,37 +320,41 @@ fn validate_previous_step_from(
 }
 
 /// Validate authentication configuration
-fn validate_auth_config(
-    idx: usize,
-    auth: &AuthConfig,
-    context: &str,
-) -> r_data_core_core::error::Result<()> {
+fn validate_auth_config(idx: usize, auth: &AuthConfig, context: &str) -> DslResult<()> {
     match auth {
         AuthConfig::None => {
             // No validation needed
         }
         AuthConfig::ApiKey { key, header_name } => {
             if key.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: {context}.auth.api_key.key must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.api_key.key"),
+                    format!("DSL step {idx}: {context}.auth.api_key.key must not be empty"),
+                ));
             }
             if header_name.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: {context}.auth.api_key.header_name must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.api_key.header_name"),
+                    format!("DSL step {idx}: {context}.auth.api_key.header_name must not be empty"),
+                ));
             }
         }
         AuthConfig::BasicAuth { username, password } => {
             if username.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: {context}.auth.basic_auth.username must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.basic_auth.username"),
+                    format!("DSL step {idx}: {context}.auth.basic_auth.username must not be empty"),
+                ));
             }
             if password.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: {context}.auth.basic_auth.password must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.basic_auth.password"),
+                    format!("DSL step {idx}: {context}.auth.basic_auth.password must not be empty"),
+                ));
             }
         }
         AuthConfig::PreSharedKey {
@@ -321,21 +363,27 @@ fn validate_auth_config(
             field_name,
         } => {
             if key.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: {context}.auth.pre_shared_key.key must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.pre_shared_key.key"),
+                    format!("DSL step {idx}: {context}.auth.pre_shared_key.key must not be empty"),
+                ));
             }
             if field_name.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.pre_shared_key.field_name"),
+                    format!(
                     "DSL step {idx}: {context}.auth.pre_shared_key.field_name must not be empty"
-                )));
+                ),
+                ));
             }
         }
         AuthConfig::EntityJwt { required_claims } => {
             if let Some(claims) = required_claims {
                 for key in claims.keys() {
                     if key.trim().is_empty() {
-                        return Err(r_data_core_core::error::Error::Validation(format!(
+                        return Err(DslError::invalid(idx, format!("{context}.auth.entity_jwt.required_claims"), format!(
                             "DSL step {idx}: {context}.auth.entity_jwt.required_claims keys must not be empty"
                         )));
                     }
@@ -0,0 +1,144 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use super::error::{DslError, DslResult};
+use super::execution;
+
+/// Map a field through a static table (e.g. country code -> country name)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LookupTransform {
+    /// Normalized field whose value is looked up
+    pub source: String,
+    /// Target normalized field to set
+    pub target: String,
+    /// Lookup table keyed by the stringified source value
+    pub table: HashMap<String, Value>,
+    /// Value written when the source is missing from the table; the target is left unset if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+}
+
+/// Stringify a scalar for use as a table key; objects, arrays and null never match
+fn lookup_key(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+/// Apply the lookup transform to normalized data
+pub fn apply_lookup(transform: &LookupTransform, normalized: &mut Value) {
+    let hit = execution::get_nested(normalized, &transform.source)
+        .and_then(|v| lookup_key(&v))
+        .and_then(|key| transform.table.get(&key));
+    if let Some(value) = hit.or(transform.default.as_ref()).cloned() {
+        execution::set_nested(normalized, &transform.target, value);
+    }
+}
+
+pub(crate) fn validate_lookup_transform(
+    idx: usize,
+    lt: &LookupTransform,
+    safe_field: &Regex,
+) -> DslResult<()> {
+    if !safe_field.is_match(&lt.source) {
+        return Err(DslError::invalid(
+            idx,
+            "transform.lookup.source",
+            format!("DSL step {idx}: transform.lookup.source must be a safe identifier"),
+        ));
+    }
+    if !safe_field.is_match(&lt.target) {
+        return Err(DslError::invalid(
+            idx,
+            "transform.lookup.target",
+            format!("DSL step {idx}: transform.lookup.target must be a safe identifier"),
+        ));
+    }
+    if lt.table.is_empty() {
+        return Err(DslError::invalid(
+            idx,
+            "transform.lookup.table",
+            format!("DSL step {idx}: transform.lookup.table must not be empty"),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn countries(default: Option<Value>) -> LookupTransform {
+        LookupTransform {
+            source: "country".to_string(),
+            target: "country_name".to_string(),
+            table: HashMap::from([
+                ("DE".to_string(), json!("Germany")),
+                ("FR".to_string(), json!("France")),
+                ("49".to_string(), json!("Germany (dial code)")),
+            ]),
+            default,
+        }
+    }
+
+    #[test]
+    fn hit_writes_table_value() {
+        let mut normalized = json!({ "country": "DE" });
+        apply_lookup(&countries(None), &mut normalized);
+        assert_eq!(normalized["country_name"], json!("Germany"));
+
+        // Non-string scalars are stringified before the lookup
+        let mut normalized = json!({ "country": 49 });
+        apply_lookup(&countries(None), &mut normalized);
+        assert_eq!(normalized["country_name"], json!("Germany (dial code)"));
+    }
+
+    #[test]
+    fn miss_with_default_writes_default() {
+        let mut normalized = json!({ "country": "XX" });
+        apply_lookup(&countries(Some(json!("Unknown"))), &mut normalized);
+        assert_eq!(normalized["country_name"], json!("Unknown"));
+
+        let mut normalized = json!({});
+        apply_lookup(&countries(Some(json!("Unknown"))), &mut normalized);
+        assert_eq!(normalized["country_name"], json!("Unknown"));
+    }
+
+    #[test]
+    fn miss_without_default_leaves_target_unset() {
+        let mut normalized = json!({ "country": "XX" });
+        apply_lookup(&countries(None), &mut normalized);
+        assert_eq!(normalized, json!({ "country": "XX" }));
+    }
+
+    #[test]
+    fn validation_rejects_empty_table_and_unsafe_fields() {
+        let safe = Regex::new(r"^[A-Za-z_][A-Za-z0-9_.]*$").unwrap();
+        assert!(validate_lookup_transform(0, &countries(None), &safe).is_ok());
+
+        let empty = LookupTransform {
+            table: HashMap::new(),
+            ..countries(None)
+        };
+        assert!(validate_lookup_transform(0, &empty, &safe).is_err());
+        let bad_source = LookupTransform {
+            source: "country code".to_string(),
+            ..countries(None)
+        };
+        assert!(validate_lookup_transform(0, &bad_source, &safe).is_err());
+        let bad_target = LookupTransform {
+            target: "1name".to_string(),
+            ..countries(None)
+        };
+        assert!(validate_lookup_transform(0, &bad_target, &safe).is_err());
+    }
+}
@@ -1,27 +1,46 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+pub mod cast;
+pub mod clock;
+pub mod condition;
+pub mod config_override;
+pub mod date;
+mod error;
 pub mod execution;
 pub mod from;
+pub mod lookup;
 pub mod on_complete;
 pub mod path_resolution;
 mod program;
+pub mod round;
+mod stream;
 pub mod to;
 pub mod transform;
 mod validation;
+pub mod variables;
 
+pub use cast::{CastTransform, CastType};
+pub use clock::Clock;
+pub use condition::{ComparisonOp, StepCondition};
+pub use config_override::merge_config_override;
+pub use date::{DateFormatTransform, DateParseTransform};
+pub use error::{DslError, DslResult};
 pub use execution::{get_nested, set_nested};
 pub use from::{EntityFilter, FormatConfig, FromDef, SourceConfig};
+pub use lookup::LookupTransform;
 pub use on_complete::{OnComplete, PostRunAction, PostRunCondition, PostRunSendEmail};
 pub use path_resolution::{
     apply_filters_transforms, apply_value_transform, build_path_from_fields, parse_entity_path,
 };
 pub use program::DslProgram;
+pub use round::{RoundTransform, RoundingMode};
 pub use to::{EntityWriteMode, OutputMode, ToDef};
 pub use transform::{
     ArithmeticOp, ArithmeticTransform, AuthenticateTransform, ConcatTransform, Operand,
     StringOperand, Transform,
 };
 pub use validation::validate_mapping;
+pub use variables::{substitute_variables, Variables};
 
 /// Strict, explicit DSL step tying together from → transform → to
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
@@ -32,6 +51,16 @@ pub struct DslStep {
     pub to: ToDef,
     /// Transform to apply
     pub transform: Transform,
+    /// Optional predicate over normalized fields; the step is skipped when it evaluates to false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<StepCondition>,
+    /// Whether the step runs; a disabled step is still validated but always skipped
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+const fn default_enabled() -> bool {
+    true
 }
 
 #[cfg(test)]
@@ -522,6 +551,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_external_entity_operand_failure_is_an_external_lookup_error() {
+        let config = json!({
+            "steps": [{
+                "from": {
+                    "type": "format",
+                    "source": { "source_type": "api", "config": {} },
+                    "format": { "format_type": "json", "options": {} },
+                    "mapping": { "price": "price" }
+                },
+                "transform": {
+                    "type": "arithmetic",
+                    "target": "total",
+                    "left": { "kind": "field", "field": "price" },
+                    "op": "mul",
+                    "right": {
+                        "kind": "external_entity_field",
+                        "entity_definition": "tax_rate",
+                        "filter": { "field": "country", "operator": "=", "value": "DE" },
+                        "field": "rate"
+                    }
+                },
+                "to": {
+                    "type": "format",
+                    "output": { "mode": "api" },
+                    "format": { "format_type": "json", "options": {} },
+                    "mapping": { "total": "total" }
+                }
+            }]
+        });
+        let prog = DslProgram::from_config(&config).unwrap();
+        prog.validate().unwrap();
+
+        let err = prog.execute(&json!({ "price": 10 })).unwrap_err();
+        assert!(matches!(err, DslError::ExternalLookup(_)), "got {err:?}");
+        assert!(err.to_string().contains("Step 0: Arithmetic error"));
+
+        // A missing record field is a plain execution error
+        let err = prog.execute(&json!({})).unwrap_err();
+        assert!(matches!(err, DslError::Execution(_)), "got {err:?}");
+    }
+
     #[test]
     fn test_mapping_apply_consistency() {
         // Test that apply() method has the same behavior as execute() for mapping
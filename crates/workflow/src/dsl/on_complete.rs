@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use utoipa::ToSchema;
 
+use super::error::{DslError, DslResult};
 use super::transform::StringOperand;
 
 /// Actions to execute after all items in a workflow run have been processed.
@@ -55,10 +56,7 @@ pub enum PostRunCondition {
 ///
 /// # Errors
 /// Returns an error if any action has invalid fields.
-pub fn validate_on_complete(
-    on_complete: &OnComplete,
-    safe_field: &regex::Regex,
-) -> r_data_core_core::error::Result<()> {
+pub fn validate_on_complete(on_complete: &OnComplete, safe_field: &regex::Regex) -> DslResult<()> {
     for (idx, action) in on_complete.actions.iter().enumerate() {
         match action {
             PostRunAction::SendEmail(email) => {
@@ -73,23 +71,26 @@ fn validate_post_run_send_email(
     idx: usize,
     email: &PostRunSendEmail,
     safe_field: &regex::Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if email.template_uuid.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "on_complete.actions[{idx}]: template_uuid must not be empty"
-        )));
+        return Err(DslError::invalid_program(
+            format!("on_complete.actions[{idx}].template_uuid"),
+            format!("on_complete.actions[{idx}]: template_uuid must not be empty"),
+        ));
     }
     if email.to.is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "on_complete.actions[{idx}]: to must not be empty"
-        )));
+        return Err(DslError::invalid_program(
+            format!("on_complete.actions[{idx}].to"),
+            format!("on_complete.actions[{idx}]: to must not be empty"),
+        ));
     }
     for (i, op) in email.to.iter().enumerate() {
         if let StringOperand::Field { field } = op {
             if !safe_field.is_match(field) {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "on_complete.actions[{idx}].to[{i}]: field must be safe"
-                )));
+                return Err(DslError::invalid_program(
+                    format!("on_complete.actions[{idx}].to[{i}]"),
+                    format!("on_complete.actions[{idx}].to[{i}]: field must be safe"),
+                ));
             }
         }
     }
@@ -97,9 +98,10 @@ fn validate_post_run_send_email(
         for (i, op) in cc.iter().enumerate() {
             if let StringOperand::Field { field } = op {
                 if !safe_field.is_match(field) {
-                    return Err(r_data_core_core::error::Error::Validation(format!(
-                        "on_complete.actions[{idx}].cc[{i}]: field must be safe"
-                    )));
+                    return Err(DslError::invalid_program(
+                        format!("on_complete.actions[{idx}].cc[{i}]"),
+                        format!("on_complete.actions[{idx}].cc[{i}]: field must be safe"),
+                    ));
                 }
             }
         }
@@ -3,11 +3,20 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::collections::HashMap;
 
+use super::cast;
+use super::clock::Clock;
+use super::date;
+use super::error::{DslError, DslResult};
+use super::execution;
 use super::from;
+use super::lookup;
 use super::on_complete::OnComplete;
+use super::round;
 use super::to;
-use super::transform::{ArithmeticOp, Transform};
+use super::transform::{ArithmeticOp, Operand, Transform};
+use super::variables::{substitute_variables, Variables};
 use super::DslStep;
 
 /// DSL program containing multiple steps
@@ -18,35 +27,70 @@ pub struct DslProgram {
     /// Optional post-run actions executed once after all items are processed
     #[serde(default)]
     pub on_complete: Option<OnComplete>,
+    /// Clock used for `now`/`today` operands (system clock unless injected)
+    #[serde(skip)]
+    pub clock: Clock,
 }
 
 impl DslProgram {
     /// Create a DSL program from a configuration value
     ///
+    /// `${NAME}` placeholders in `from` and `to` are replaced with the `WORKFLOW_VAR_*`
+    /// environment variables and the config's own `variables` map.
+    ///
     /// # Arguments
     /// * `config` - JSON configuration containing a "steps" array
     ///
     /// # Errors
     /// Returns an error if the configuration is invalid
-    pub fn from_config(config: &Value) -> r_data_core_core::error::Result<Self> {
-        let steps_val = config.get("steps").ok_or_else(|| {
-            r_data_core_core::error::Error::Validation(
-                "Workflow config missing 'steps' array".to_string(),
-            )
-        })?;
-        let steps = steps_val.as_array().ok_or_else(|| {
-            r_data_core_core::error::Error::Validation("'steps' must be an array".to_string())
-        })?;
+    pub fn from_config(config: &Value) -> DslResult<Self> {
+        Self::from_config_with_variables(config, &Variables::from_env())
+    }
+
+    /// Like [`Self::from_config`], with `variables` in place of the environment
+    ///
+    /// The config's `variables` and `strict_variables` still take precedence.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid, or if a placeholder has no value and
+    /// variables are strict
+    pub fn from_config_with_variables(config: &Value, variables: &Variables) -> DslResult<Self> {
+        Self::parse(config, &variables.clone().with_config(config)?)
+    }
+
+    /// Like [`Self::from_config`] for a workflow being saved: placeholders without a value are
+    /// left in place even with `strict_variables`
+    ///
+    /// A run can still supply the values through its config override, so strict variables are
+    /// checked when the config of a run is parsed.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid
+    pub fn from_stored_config(config: &Value) -> DslResult<Self> {
+        let variables = Variables::from_env()
+            .with_config(config)?
+            .with_strict(false);
+        Self::parse(config, &variables)
+    }
+
+    /// Substitute `variables` into `config` and parse its steps
+    fn parse(config: &Value, variables: &Variables) -> DslResult<Self> {
+        let config = &substitute_variables(config, variables)?;
+        let steps_val = config
+            .get("steps")
+            .ok_or_else(|| DslError::Parse("Workflow config missing 'steps' array".to_string()))?;
+        let steps = steps_val
+            .as_array()
+            .ok_or_else(|| DslError::Parse("'steps' must be an array".to_string()))?;
 
         let parsed: Vec<DslStep> = steps
             .iter()
             .cloned()
             .map(|v| {
-                serde_json::from_value::<DslStep>(v).map_err(|e| {
-                    r_data_core_core::error::Error::Validation(format!("Invalid DSL step: {e}"))
-                })
+                serde_json::from_value::<DslStep>(v)
+                    .map_err(|e| DslError::Parse(format!("Invalid DSL step: {e}")))
             })
-            .collect::<r_data_core_core::error::Result<_>>()?;
+            .collect::<DslResult<_>>()?;
 
         let on_complete: Option<OnComplete> = config
             .get("on_complete")
@@ -55,36 +99,54 @@ impl DslProgram {
         Ok(Self {
             steps: parsed,
             on_complete,
+            clock: Clock::default(),
         })
     }
 
+    /// Use the given clock for `now`/`today` operands
+    #[must_use]
+    pub const fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Validate the DSL program
     ///
     /// # Errors
-    /// Returns an error if validation fails
+    /// Returns a [`DslError::Validation`] naming the offending step and setting if validation fails
     ///
-    pub fn validate(&self) -> r_data_core_core::error::Result<()> {
+    pub fn validate(&self) -> DslResult<()> {
         if self.steps.is_empty() {
-            return Err(r_data_core_core::error::Error::Validation(
-                "DSL must contain at least one step".to_string(),
+            return Err(DslError::invalid_program(
+                "steps",
+                "DSL must contain at least one step",
             ));
         }
         let safe_field = Regex::new(r"^[A-Za-z_][A-Za-z0-9_.]*$").map_err(|e| {
-            r_data_core_core::error::Error::Config(format!(
-                "Failed to compile field validation regex: {e}"
-            ))
+            DslError::invalid_program(
+                "steps",
+                format!("Failed to compile field validation regex: {e}"),
+            )
         })?;
         let last_step_idx = self.steps.len() - 1;
         for (idx, step) in self.steps.iter().enumerate() {
             from::validate_from(idx, &step.from, &safe_field)?;
+            self.validate_chained_from(idx, &step.from)?;
             to::validate_to(idx, &step.to, &safe_field)?;
             super::transform::validate_transform(idx, &step.transform, &safe_field)?;
+            if let Some(ref when) = step.when {
+                super::condition::validate_condition(idx, when, &safe_field)?;
+            }
             // NextStep cannot be used in the last step
             if idx == last_step_idx {
                 if let super::to::ToDef::NextStep { .. } = &step.to {
-                    return Err(r_data_core_core::error::Error::Validation(format!(
-                        "Step {idx} (last step) cannot use NextStep ToDef - there is no next step"
-                    )));
+                    return Err(DslError::invalid(
+                        idx,
+                        "to.next_step",
+                        format!(
+                            "Step {idx} (last step) cannot use NextStep ToDef - there is no next step"
+                        ),
+                    ));
                 }
             }
         }
@@ -94,6 +156,37 @@ impl DslProgram {
         Ok(())
     }
 
+    /// Check that a step after a `NextStep` step reads the data passed on to it
+    ///
+    /// Only a `previous_step` source reads that data; any other source would silently drop
+    /// it. A trigger step has no data to pass on, so it may be followed by any source.
+    fn validate_chained_from(&self, idx: usize, from: &super::from::FromDef) -> DslResult<()> {
+        use super::from::FromDef;
+
+        let Some(previous) = idx.checked_sub(1).and_then(|i| self.steps.get(i)) else {
+            return Ok(());
+        };
+        if !matches!(previous.to, super::to::ToDef::NextStep { .. })
+            || matches!(previous.from, FromDef::Trigger { .. })
+        {
+            return Ok(());
+        }
+        let source = match from {
+            FromDef::PreviousStep { .. } => return Ok(()),
+            FromDef::Format { .. } => "format",
+            FromDef::Entity { .. } => "entity",
+            FromDef::Trigger { .. } => "trigger",
+        };
+        Err(DslError::invalid(
+            idx,
+            format!("from.{source}"),
+            format!(
+                "DSL step {idx}: from.{source} cannot read the data step {} passes on with to.next_step; use from.previous_step",
+                idx - 1
+            ),
+        ))
+    }
+
     /// Execute all steps and return produced outputs per step along with their target (`to`) definitions.
     /// Supports step chaining via `PreviousStep` `FromDef` type.
     ///
@@ -101,184 +194,21 @@ impl DslProgram {
     /// * `input` - Input JSON value
     ///
     /// # Errors
-    /// Returns an error if execution fails
-    #[allow(clippy::too_many_lines)] // Complex but cohesive function
-    pub fn execute(
-        &self,
-        input: &Value,
-    ) -> r_data_core_core::error::Result<Vec<(super::to::ToDef, Value)>> {
-        use super::execution;
-        use super::from::FromDef;
-
-        let mut results: Vec<(super::to::ToDef, Value)> = Vec::new();
-        let mut step_outputs: Vec<Value> = Vec::new(); // Store normalized data from each step
-
-        for (step_idx, step) in self.steps.iter().enumerate() {
-            // Determine source data based on FromDef type
-            let source_data = match &step.from {
-                FromDef::PreviousStep { .. } => {
-                    // Read from previous step's normalized data
-                    if step_idx == 0 {
-                        return Err(r_data_core_core::error::Error::Validation(
-                            "Step 0 cannot use PreviousStep source".to_string(),
-                        ));
-                    }
-                    &step_outputs[step_idx - 1]
-                }
-                FromDef::Trigger { .. } => {
-                    // Trigger has no input data - use empty object
-                    &serde_json::json!({})
-                }
-                FromDef::Format { .. } | FromDef::Entity { .. } => {
-                    // Read from original input
-                    input
-                }
-            };
-
-            // Normalize
-            let mut normalized = json!({});
-            let mapping = from::mapping_of(&step.from);
-            if mapping.is_empty() {
-                // If mapping is empty, pass through all fields from source
-                if let Some(source_obj) = source_data.as_object() {
-                    for (k, v) in source_obj {
-                        execution::set_nested(&mut normalized, k, v.clone());
-                    }
-                }
+    /// Returns a [`DslError::Execution`] or [`DslError::ExternalLookup`] if a step fails
+    pub fn execute(&self, input: &Value) -> DslResult<Vec<(super::to::ToDef, Value)>> {
+        let mut results: Vec<(super::to::ToDef, Value)> = Vec::with_capacity(self.steps.len());
+        let mut previous_step_output: Option<Value> = None;
+
+        for step_idx in 0..self.steps.len() {
+            if let Some((to_def, produced, next_input)) =
+                self.run_step(step_idx, input, previous_step_output.as_ref())?
+            {
+                previous_step_output = Some(next_input);
+                results.push((to_def, produced));
             } else {
-                // Sort mapping entries to ensure deterministic execution
-                let mut sorted_mapping: Vec<_> = mapping.iter().collect();
-                sorted_mapping.sort_by_key(|(src, _)| *src);
-                for (src, dst) in sorted_mapping {
-                    let v = execution::get_nested(source_data, src).unwrap_or(Value::Null);
-                    execution::set_nested(&mut normalized, dst, v);
-                }
-            }
-
-            // Transform with proper error handling
-            match &step.transform {
-                Transform::Arithmetic(ar) => {
-                    let left_result = execution::eval_operand(&normalized, &ar.left);
-                    let right_result = execution::eval_operand(&normalized, &ar.right);
-
-                    match (left_result, right_result) {
-                        (Ok(left_val), Ok(right_val)) => {
-                            let new_val = match ar.op {
-                                ArithmeticOp::Add => left_val + right_val,
-                                ArithmeticOp::Sub => left_val - right_val,
-                                ArithmeticOp::Mul => left_val * right_val,
-                                ArithmeticOp::Div => {
-                                    #[allow(clippy::float_cmp)]
-                                    // We explicitly want exact comparison for zero
-                                    if right_val == 0.0 {
-                                        return Err(r_data_core_core::error::Error::Validation(format!(
-                                            "Step {step_idx}: Division by zero in target field '{}'",
-                                            ar.target
-                                        )));
-                                    }
-                                    left_val / right_val
-                                }
-                            };
-                            execution::set_nested(
-                                &mut normalized,
-                                &ar.target,
-                                Value::from(new_val),
-                            );
-                        }
-                        (Err(e), _) | (_, Err(e)) => {
-                            return Err(r_data_core_core::error::Error::Validation(format!(
-                                "Step {step_idx}: Arithmetic error in target field '{}': {}",
-                                ar.target, e
-                            )));
-                        }
-                    }
-                }
-                Transform::Concat(ct) => {
-                    let left_result = execution::eval_string_operand(&normalized, &ct.left);
-                    let right_result = execution::eval_string_operand(&normalized, &ct.right);
-
-                    match (left_result, right_result) {
-                        (Ok(left_str), Ok(right_str)) => {
-                            let sep = ct.separator.as_deref().unwrap_or("");
-                            let combined = format!("{left_str}{sep}{right_str}");
-                            execution::set_nested(
-                                &mut normalized,
-                                &ct.target,
-                                Value::from(combined),
-                            );
-                        }
-                        (Err(e), _) | (_, Err(e)) => {
-                            return Err(r_data_core_core::error::Error::Validation(format!(
-                                "Step {step_idx}: Concat error in target field '{}': {}",
-                                ct.target, e
-                            )));
-                        }
-                    }
-                }
-                Transform::BuildPath(bp) => {
-                    use super::path_resolution::build_path_from_fields;
-                    match build_path_from_fields::<std::collections::hash_map::RandomState>(
-                        &bp.template,
-                        &normalized,
-                        bp.separator.as_deref(),
-                        bp.field_transforms.as_ref(),
-                    ) {
-                        Ok(path) => {
-                            execution::set_nested(&mut normalized, &bp.target, Value::String(path));
-                        }
-                        Err(e) => {
-                            return Err(r_data_core_core::error::Error::Validation(format!(
-                                "Step {step_idx}: BuildPath error in target field '{}': {}",
-                                bp.target, e
-                            )));
-                        }
-                    }
-                }
-                Transform::ResolveEntityPath(_)
-                | Transform::GetOrCreateEntity(_)
-                | Transform::Authenticate(_)
-                | Transform::SendEmail(_)
-                | Transform::None => {
-                    // ResolveEntityPath, GetOrCreateEntity, Authenticate, and SendEmail require
-                    // async database/service access and are handled in the services layer during
-                    // workflow execution. They are validated here but execution happens in services.
-                    // Transform::None is a no-op.
-                }
+                previous_step_output =
+                    Some(Self::skipped_step_input(input, previous_step_output.take()));
             }
-
-            // Map to output
-            let out_mapping = to::mapping_of(&step.to);
-            let produced = if out_mapping.is_empty() {
-                // If no mapping for output, use normalized directly (pass through all fields)
-                normalized.clone()
-            } else {
-                // Sort mapping entries by destination to ensure deterministic execution
-                // This ensures reserved fields like 'path' are processed in a consistent order
-                // Mapping structure: { destination_field: normalized_field }
-                let mut produced = json!({});
-                let mut sorted_mapping: Vec<_> = out_mapping.iter().collect();
-                sorted_mapping.sort_by_key(|(dst, _)| *dst);
-                for (dst, src) in sorted_mapping {
-                    let v = execution::get_nested(&normalized, src).unwrap_or(Value::Null);
-                    execution::set_nested(&mut produced, dst, v);
-                }
-                produced
-            };
-
-            // Store data for next step (for PreviousStep references)
-            // For NextStep ToDef, store the mapped output; otherwise store normalized
-            match &step.to {
-                super::to::ToDef::NextStep { .. } => {
-                    // For NextStep, store the mapped output so next step sees the mapped fields
-                    step_outputs.push(produced.clone());
-                }
-                _ => {
-                    // For Format/Entity, store normalized (next step reads via PreviousStep mapping)
-                    step_outputs.push(normalized.clone());
-                }
-            }
-
-            results.push((step.to.clone(), produced));
         }
         Ok(results)
     }
@@ -289,187 +219,51 @@ impl DslProgram {
     /// 2) transform (arithmetic/concat) using operands
     /// 3) map to output using to.mapping (returned result is the last produced)
     ///
+    /// Shares the per-step pipeline with [`Self::execute`], so the returned value is
+    /// always identical to the output of the last step produced by `execute`.
+    ///
     /// # Arguments
     /// * `input` - Input JSON value
     ///
     /// # Errors
     /// Returns an error if execution fails
-    #[allow(clippy::too_many_lines)]
-    pub fn apply(&self, input: &Value) -> r_data_core_core::error::Result<Value> {
-        use super::execution;
-        use super::from::FromDef;
-
-        let mut last = json!({});
-        let mut step_outputs: Vec<Value> = Vec::new(); // Store normalized data from each step
-
-        for (step_idx, step) in self.steps.iter().enumerate() {
-            // Determine source data based on FromDef type
-            let source_data = match &step.from {
-                FromDef::PreviousStep { .. } => {
-                    // Read from previous step's normalized data
-                    if step_idx == 0 {
-                        return Err(r_data_core_core::error::Error::Validation(
-                            "Step 0 cannot use PreviousStep source".to_string(),
-                        ));
-                    }
-                    &step_outputs[step_idx - 1]
-                }
-                FromDef::Trigger { .. } => {
-                    // Trigger has no input data - use empty object
-                    &serde_json::json!({})
-                }
-                FromDef::Format { .. } | FromDef::Entity { .. } => {
-                    // Read from original input
-                    input
-                }
-            };
-
-            // Normalize
-            let mut normalized = json!({});
-            let mapping = from::mapping_of(&step.from);
-            if mapping.is_empty() {
-                // If mapping is empty, pass through all fields from source
-                if let Some(source_obj) = source_data.as_object() {
-                    for (k, v) in source_obj {
-                        execution::set_nested(&mut normalized, k, v.clone());
-                    }
-                }
-            } else {
-                // Sort mapping entries to ensure deterministic execution
-                let mut sorted_mapping: Vec<_> = mapping.iter().collect();
-                sorted_mapping.sort_by_key(|(src, _)| *src);
-                for (src, dst) in sorted_mapping {
-                    let v = execution::get_nested(source_data, src).unwrap_or(Value::Null);
-                    execution::set_nested(&mut normalized, dst, v);
-                }
-            }
-
-            // Transform with proper error handling
-            match &step.transform {
-                Transform::Arithmetic(ar) => {
-                    let left_result = execution::eval_operand(&normalized, &ar.left);
-                    let right_result = execution::eval_operand(&normalized, &ar.right);
-
-                    match (left_result, right_result) {
-                        (Ok(left_val), Ok(right_val)) => {
-                            let new_val = match ar.op {
-                                ArithmeticOp::Add => left_val + right_val,
-                                ArithmeticOp::Sub => left_val - right_val,
-                                ArithmeticOp::Mul => left_val * right_val,
-                                ArithmeticOp::Div => {
-                                    #[allow(clippy::float_cmp)]
-                                    // We explicitly want exact comparison for zero
-                                    if right_val == 0.0 {
-                                        return Err(r_data_core_core::error::Error::Validation(format!(
-                                            "Step {step_idx}: Division by zero in target field '{}'",
-                                            ar.target
-                                        )));
-                                    }
-                                    left_val / right_val
-                                }
-                            };
-                            execution::set_nested(
-                                &mut normalized,
-                                &ar.target,
-                                Value::from(new_val),
-                            );
-                        }
-                        (Err(e), _) | (_, Err(e)) => {
-                            return Err(r_data_core_core::error::Error::Validation(format!(
-                                "Step {step_idx}: Arithmetic error in target field '{}': {}",
-                                ar.target, e
-                            )));
-                        }
-                    }
-                }
-                Transform::Concat(ct) => {
-                    let left_result = execution::eval_string_operand(&normalized, &ct.left);
-                    let right_result = execution::eval_string_operand(&normalized, &ct.right);
-
-                    match (left_result, right_result) {
-                        (Ok(left_str), Ok(right_str)) => {
-                            let sep = ct.separator.as_deref().unwrap_or("");
-                            let combined = format!("{left_str}{sep}{right_str}");
-                            execution::set_nested(
-                                &mut normalized,
-                                &ct.target,
-                                Value::from(combined),
-                            );
-                        }
-                        (Err(e), _) | (_, Err(e)) => {
-                            return Err(r_data_core_core::error::Error::Validation(format!(
-                                "Step {step_idx}: Concat error in target field '{}': {}",
-                                ct.target, e
-                            )));
-                        }
-                    }
-                }
-                Transform::BuildPath(bp) => {
-                    use super::path_resolution::build_path_from_fields;
-                    match build_path_from_fields::<std::collections::hash_map::RandomState>(
-                        &bp.template,
-                        &normalized,
-                        bp.separator.as_deref(),
-                        bp.field_transforms.as_ref(),
-                    ) {
-                        Ok(path) => {
-                            execution::set_nested(&mut normalized, &bp.target, Value::String(path));
-                        }
-                        Err(e) => {
-                            return Err(r_data_core_core::error::Error::Validation(format!(
-                                "Step {step_idx}: BuildPath error in target field '{}': {}",
-                                bp.target, e
-                            )));
-                        }
-                    }
-                }
-                Transform::ResolveEntityPath(_)
-                | Transform::GetOrCreateEntity(_)
-                | Transform::Authenticate(_)
-                | Transform::SendEmail(_)
-                | Transform::None => {
-                    // ResolveEntityPath, GetOrCreateEntity, Authenticate, and SendEmail require
-                    // async database/service access and are handled in the services layer during
-                    // workflow execution. They are validated here but execution happens in services.
-                    // Transform::None is a no-op.
-                }
-            }
+    pub fn apply(&self, input: &Value) -> DslResult<Value> {
+        Ok(self
+            .execute(input)?
+            .pop()
+            .map_or_else(|| json!({}), |(_, produced)| produced))
+    }
 
-            // Map to output
-            let out_mapping = to::mapping_of(&step.to);
-            let produced = if out_mapping.is_empty() {
-                // If no mapping for output, use normalized directly (pass through all fields)
-                normalized.clone()
-            } else {
-                // Sort mapping entries by destination to ensure deterministic execution
-                // This ensures reserved fields like 'path' are processed in a consistent order
-                // Mapping structure: { destination_field: normalized_field }
-                let mut produced = json!({});
-                let mut sorted_out_mapping: Vec<_> = out_mapping.iter().collect();
-                sorted_out_mapping.sort_by_key(|(dst, _)| *dst);
-                for (dst, src) in sorted_out_mapping {
-                    let v = execution::get_nested(&normalized, src).unwrap_or(Value::Null);
-                    execution::set_nested(&mut produced, dst, v);
-                }
-                produced
-            };
-
-            // Store data for next step (for PreviousStep references)
-            // For NextStep ToDef, store the mapped output; otherwise store normalized
-            match &step.to {
-                super::to::ToDef::NextStep { .. } => {
-                    // For NextStep, store the mapped output so next step sees the mapped fields
-                    step_outputs.push(produced.clone());
-                }
-                _ => {
-                    // For Format/Entity, store normalized (next step reads via PreviousStep mapping)
-                    step_outputs.push(normalized.clone());
-                }
-            }
+    /// Run one step synchronously: normalize, transform (including `BuildPath`) and map.
+    ///
+    /// # Returns
+    /// `Some((ToDef, produced, next_step_input))`, or `None` if the step is disabled or its
+    /// `when` condition is false
+    fn run_step(
+        &self,
+        step_idx: usize,
+        original_input: &Value,
+        previous_step_output: Option<&Value>,
+    ) -> DslResult<Option<(super::to::ToDef, Value, Value)>> {
+        let Some((mut normalized, transform)) =
+            self.prepare_step(step_idx, original_input, previous_step_output)?
+        else {
+            return Ok(None);
+        };
+        Self::apply_build_path(step_idx, transform, &mut normalized)?;
+        let (to_def, produced) = self.finalize_step(step_idx, &normalized)?;
+        let next_input = self.get_next_step_input(step_idx, &normalized, &produced)?;
+        Ok(Some((to_def, produced, next_input)))
+    }
 
-            last = produced;
-        }
-        Ok(last)
+    /// Input passed on by a skipped step: the previous step's output, or the
+    /// original input if no step has produced output yet.
+    #[must_use]
+    pub fn skipped_step_input(
+        original_input: &Value,
+        previous_step_output: Option<Value>,
+    ) -> Value {
+        previous_step_output.unwrap_or_else(|| original_input.clone())
     }
 
     /// Execute a single step and return normalized data before output mapping.
@@ -481,9 +275,12 @@ impl DslProgram {
     /// * `previous_step_output` - Output from the previous step (used for `PreviousStep` source)
     ///
     /// # Returns
-    /// `(normalized, transform)` - The normalized data and the step's transform type.
+    /// `Some((normalized, transform))` - The normalized data and the step's transform type.
     /// For async transforms (`ResolveEntityPath`, `GetOrCreateEntity`), the caller should
     /// execute the transform and inject results into `normalized` before calling `finalize_step`.
+    /// `None` if the step is disabled or its `when` condition is false for the normalized data;
+    /// the step then produces no output and [`Self::skipped_step_input`] is passed to the next
+    /// step.
     ///
     /// # Errors
     /// Returns an error if step execution fails
@@ -492,27 +289,29 @@ impl DslProgram {
         step_idx: usize,
         original_input: &Value,
         previous_step_output: Option<&Value>,
-    ) -> r_data_core_core::error::Result<(Value, &Transform)> {
-        use super::execution;
+    ) -> DslResult<Option<(Value, &Transform)>> {
         use super::from::FromDef;
 
-        let step = self.steps.get(step_idx).ok_or_else(|| {
-            r_data_core_core::error::Error::Validation(format!(
-                "Step index {step_idx} out of bounds"
-            ))
-        })?;
+        let step = self
+            .steps
+            .get(step_idx)
+            .ok_or_else(|| DslError::Execution(format!("Step index {step_idx} out of bounds")))?;
+
+        if !step.enabled {
+            return Ok(None);
+        }
 
         // Determine source data based on FromDef type
         let empty_obj = json!({});
         let source_data = match &step.from {
             FromDef::PreviousStep { .. } => {
                 if step_idx == 0 {
-                    return Err(r_data_core_core::error::Error::Validation(
+                    return Err(DslError::Execution(
                         "Step 0 cannot use PreviousStep source".to_string(),
                     ));
                 }
                 previous_step_output.ok_or_else(|| {
-                    r_data_core_core::error::Error::Validation(
+                    DslError::Execution(
                         "PreviousStep source requires previous step output".to_string(),
                     )
                 })?
@@ -521,87 +320,17 @@ impl DslProgram {
             FromDef::Format { .. } | FromDef::Entity { .. } => original_input,
         };
 
-        // Normalize
-        let mut normalized = json!({});
-        let mapping = from::mapping_of(&step.from);
-        if mapping.is_empty() {
-            if let Some(source_obj) = source_data.as_object() {
-                for (k, v) in source_obj {
-                    execution::set_nested(&mut normalized, k, v.clone());
-                }
-            }
-        } else {
-            let mut sorted_mapping: Vec<_> = mapping.iter().collect();
-            sorted_mapping.sort_by_key(|(src, _)| *src);
-            for (src, dst) in sorted_mapping {
-                let v = execution::get_nested(source_data, src).unwrap_or(Value::Null);
-                execution::set_nested(&mut normalized, dst, v);
-            }
+        let mut normalized = normalize(source_data, from::mapping_of(&step.from));
+        if step
+            .when
+            .as_ref()
+            .is_some_and(|when| !when.evaluate(&normalized))
+        {
+            return Ok(None);
         }
+        apply_sync_transform(step_idx, &step.transform, &mut normalized, &self.clock)?;
 
-        // Apply sync transforms only
-        match &step.transform {
-            Transform::Arithmetic(ar) => {
-                let left_result = execution::eval_operand(&normalized, &ar.left);
-                let right_result = execution::eval_operand(&normalized, &ar.right);
-
-                match (left_result, right_result) {
-                    (Ok(left_val), Ok(right_val)) => {
-                        let new_val = match ar.op {
-                            ArithmeticOp::Add => left_val + right_val,
-                            ArithmeticOp::Sub => left_val - right_val,
-                            ArithmeticOp::Mul => left_val * right_val,
-                            ArithmeticOp::Div => {
-                                #[allow(clippy::float_cmp)]
-                                if right_val == 0.0 {
-                                    return Err(r_data_core_core::error::Error::Validation(
-                                        format!(
-                                            "Step {step_idx}: Division by zero in target field '{}'",
-                                            ar.target
-                                        ),
-                                    ));
-                                }
-                                left_val / right_val
-                            }
-                        };
-                        execution::set_nested(&mut normalized, &ar.target, Value::from(new_val));
-                    }
-                    (Err(e), _) | (_, Err(e)) => {
-                        return Err(r_data_core_core::error::Error::Validation(format!(
-                            "Step {step_idx}: Arithmetic error in target field '{}': {}",
-                            ar.target, e
-                        )));
-                    }
-                }
-            }
-            Transform::Concat(ct) => {
-                let left_result = execution::eval_string_operand(&normalized, &ct.left);
-                let right_result = execution::eval_string_operand(&normalized, &ct.right);
-
-                match (left_result, right_result) {
-                    (Ok(left_str), Ok(right_str)) => {
-                        let separator = ct.separator.as_deref().unwrap_or("");
-                        let combined = format!("{left_str}{separator}{right_str}");
-                        execution::set_nested(&mut normalized, &ct.target, Value::from(combined));
-                    }
-                    (Err(e), _) | (_, Err(e)) => {
-                        return Err(r_data_core_core::error::Error::Validation(format!(
-                            "Step {step_idx}: Concat error in target field '{}': {}",
-                            ct.target, e
-                        )));
-                    }
-                }
-            }
-            // Async transforms are NOT executed here - caller must handle them
-            Transform::ResolveEntityPath(_)
-            | Transform::GetOrCreateEntity(_)
-            | Transform::Authenticate(_)
-            | Transform::SendEmail(_)
-            | Transform::BuildPath(_)
-            | Transform::None => {}
-        }
-
-        Ok((normalized, &step.transform))
+        Ok(Some((normalized, &step.transform)))
     }
 
     /// Apply `BuildPath` transform to normalized data.
@@ -618,9 +347,7 @@ impl DslProgram {
         step_idx: usize,
         transform: &Transform,
         normalized: &mut Value,
-    ) -> r_data_core_core::error::Result<()> {
-        use super::execution;
-
+    ) -> DslResult<()> {
         if let Transform::BuildPath(bp) = transform {
             use super::path_resolution::build_path_from_fields;
             match build_path_from_fields::<std::collections::hash_map::RandomState>(
@@ -633,7 +360,7 @@ impl DslProgram {
                     execution::set_nested(normalized, &bp.target, Value::String(path));
                 }
                 Err(e) => {
-                    return Err(r_data_core_core::error::Error::Validation(format!(
+                    return Err(DslError::Execution(format!(
                         "Step {step_idx}: BuildPath error in target field '{}': {}",
                         bp.target, e
                     )));
@@ -658,32 +385,13 @@ impl DslProgram {
         &self,
         step_idx: usize,
         normalized: &Value,
-    ) -> r_data_core_core::error::Result<(super::to::ToDef, Value)> {
-        use super::execution;
+    ) -> DslResult<(super::to::ToDef, Value)> {
+        let step = self
+            .steps
+            .get(step_idx)
+            .ok_or_else(|| DslError::Execution(format!("Step index {step_idx} out of bounds")))?;
 
-        let step = self.steps.get(step_idx).ok_or_else(|| {
-            r_data_core_core::error::Error::Validation(format!(
-                "Step index {step_idx} out of bounds"
-            ))
-        })?;
-
-        let out_mapping = to::mapping_of(&step.to);
-        let produced = if out_mapping.is_empty() {
-            normalized.clone()
-        } else {
-            let mut produced = json!({});
-            let mut sorted_mapping: Vec<_> = out_mapping.iter().collect();
-            sorted_mapping.sort_by_key(|(dst, _)| *dst);
-            for (dst, src) in sorted_mapping {
-                // Check if the source is a literal value (e.g., @literal:true)
-                // Otherwise, read from the normalized input data
-                let v = execution::parse_literal_value(src)
-                    .or_else(|| execution::get_nested(normalized, src))
-                    .unwrap_or(Value::Null);
-                execution::set_nested(&mut produced, dst, v);
-            }
-            produced
-        };
+        let produced = map_output(to::mapping_of(&step.to), normalized);
 
         Ok((step.to.clone(), produced))
     }
@@ -705,12 +413,11 @@ impl DslProgram {
         step_idx: usize,
         normalized: &Value,
         produced: &Value,
-    ) -> r_data_core_core::error::Result<Value> {
-        let step = self.steps.get(step_idx).ok_or_else(|| {
-            r_data_core_core::error::Error::Validation(format!(
-                "Step index {step_idx} out of bounds"
-            ))
-        })?;
+    ) -> DslResult<Value> {
+        let step = self
+            .steps
+            .get(step_idx)
+            .ok_or_else(|| DslError::Execution(format!("Step index {step_idx} out of bounds")))?;
 
         Ok(match &step.to {
             super::to::ToDef::NextStep { .. } => produced.clone(),
@@ -718,3 +425,130 @@ impl DslProgram {
         })
     }
 }
+
+/// Normalize source data using a `from` mapping (`source_field` -> `normalized_field`).
+/// An empty mapping passes all top-level source fields through unchanged.
+fn normalize(source: &Value, mapping: &HashMap<String, String>) -> Value {
+    let mut normalized = json!({});
+    if mapping.is_empty() {
+        if let Some(source_obj) = source.as_object() {
+            for (k, v) in source_obj {
+                execution::set_nested(&mut normalized, k, v.clone());
+            }
+        }
+    } else {
+        // Sort mapping entries to ensure deterministic execution
+        let mut sorted_mapping: Vec<_> = mapping.iter().collect();
+        sorted_mapping.sort_by_key(|(src, _)| *src);
+        for (src, dst) in sorted_mapping {
+            let v = execution::get_nested(source, src).unwrap_or(Value::Null);
+            execution::set_nested(&mut normalized, dst, v);
+        }
+    }
+    normalized
+}
+
+/// Apply the transforms that need no async database/service access (arithmetic, concat, round, lookup, cast, dates).
+///
+/// `BuildPath` is applied separately via [`DslProgram::apply_build_path`] because it may
+/// depend on async transform results. All other transforms are handled in the services layer.
+fn apply_sync_transform(
+    step_idx: usize,
+    transform: &Transform,
+    normalized: &mut Value,
+    clock: &Clock,
+) -> DslResult<()> {
+    match transform {
+        Transform::Arithmetic(ar) => {
+            let left_result = execution::eval_operand(normalized, &ar.left, clock);
+            let right_result = execution::eval_operand(normalized, &ar.right, clock);
+
+            match (left_result, right_result) {
+                (Ok(left_val), Ok(right_val)) => {
+                    let new_val = match ar.op {
+                        ArithmeticOp::Add => left_val + right_val,
+                        ArithmeticOp::Sub => left_val - right_val,
+                        ArithmeticOp::Mul => left_val * right_val,
+                        ArithmeticOp::Div => {
+                            #[allow(clippy::float_cmp)]
+                            // We explicitly want exact comparison for zero
+                            if right_val == 0.0 {
+                                return Err(DslError::Execution(format!(
+                                    "Step {step_idx}: Division by zero in target field '{}'",
+                                    ar.target
+                                )));
+                            }
+                            left_val / right_val
+                        }
+                    };
+                    execution::set_nested(normalized, &ar.target, Value::from(new_val));
+                }
+                (Err(e), _) => return Err(operand_error(step_idx, &ar.target, &ar.left, &e)),
+                (_, Err(e)) => return Err(operand_error(step_idx, &ar.target, &ar.right, &e)),
+            }
+        }
+        Transform::Concat(ct) => {
+            let left_result = execution::eval_string_operand(normalized, &ct.left, clock);
+            let right_result = execution::eval_string_operand(normalized, &ct.right, clock);
+
+            match (left_result, right_result) {
+                (Ok(left_str), Ok(right_str)) => {
+                    let separator = ct.separator.as_deref().unwrap_or("");
+                    let combined = format!("{left_str}{separator}{right_str}");
+                    execution::set_nested(normalized, &ct.target, Value::from(combined));
+                }
+                (Err(e), _) | (_, Err(e)) => {
+                    return Err(DslError::Execution(format!(
+                        "Step {step_idx}: Concat error in target field '{}': {}",
+                        ct.target, e
+                    )));
+                }
+            }
+        }
+        Transform::Round(rt) => round::apply_round(rt, normalized),
+        Transform::Lookup(lt) => lookup::apply_lookup(lt, normalized),
+        Transform::Cast(ct) => cast::apply_cast(step_idx, ct, normalized)?,
+        Transform::DateParse(dp) => date::apply_date_parse(step_idx, dp, normalized)?,
+        Transform::DateFormat(df) => date::apply_date_format(step_idx, df, normalized)?,
+        Transform::ResolveEntityPath(_)
+        | Transform::GetOrCreateEntity(_)
+        | Transform::Authenticate(_)
+        | Transform::SendEmail(_)
+        | Transform::BuildPath(_)
+        | Transform::None => {}
+    }
+    Ok(())
+}
+
+/// Error of an arithmetic operand that could not be evaluated
+///
+/// External entity fields are read from outside the record, so their failures are reported
+/// as [`DslError::ExternalLookup`].
+fn operand_error(step_idx: usize, target: &str, operand: &Operand, err: &str) -> DslError {
+    let message = format!("Step {step_idx}: Arithmetic error in target field '{target}': {err}");
+    if matches!(operand, Operand::ExternalEntityField { .. }) {
+        DslError::ExternalLookup(message)
+    } else {
+        DslError::Execution(message)
+    }
+}
+
+/// Map normalized data to the step output using a `to` mapping (`destination_field` -> `normalized_field`).
+/// An empty mapping passes normalized data through; `@literal:` sources set constant values.
+fn map_output(mapping: &HashMap<String, String>, normalized: &Value) -> Value {
+    if mapping.is_empty() {
+        return normalized.clone();
+    }
+    // Sort mapping entries by destination to ensure deterministic execution
+    // This ensures reserved fields like 'path' are processed in a consistent order
+    let mut produced = json!({});
+    let mut sorted_mapping: Vec<_> = mapping.iter().collect();
+    sorted_mapping.sort_by_key(|(dst, _)| *dst);
+    for (dst, src) in sorted_mapping {
+        let v = execution::parse_literal_value(src)
+            .or_else(|| execution::get_nested(normalized, src))
+            .unwrap_or(Value::Null);
+        execution::set_nested(&mut produced, dst, v);
+    }
+    produced
+}
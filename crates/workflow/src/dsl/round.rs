@@ -0,0 +1,241 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use super::error::{DslError, DslResult};
+use super::execution;
+
+/// Largest supported number of decimal places
+pub const MAX_ROUND_PRECISION: u32 = 12;
+
+/// Round a numeric field to a fixed number of decimal places
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RoundTransform {
+    /// Normalized field holding the number to round
+    pub source: String,
+    /// Target normalized field to set
+    pub target: String,
+    /// Number of decimal places to keep (0 yields an integer)
+    #[serde(default)]
+    pub precision: u32,
+    /// How ties and remainders are resolved
+    #[serde(default)]
+    pub mode: RoundingMode,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Ties round away from zero (2.345 -> 2.35)
+    #[default]
+    HalfUp,
+    /// Ties round to the even neighbour (2.345 -> 2.34)
+    HalfEven,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+}
+
+impl RoundingMode {
+    const fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::HalfUp => value.round(),
+            Self::HalfEven => value.round_ties_even(),
+            Self::Floor => value.floor(),
+            Self::Ceil => value.ceil(),
+        }
+    }
+}
+
+/// Round `value` to `precision` decimal places.
+///
+/// The scaled value is first snapped to 9 extra decimal digits so binary representation noise
+/// (2.345 * 100 = 234.49999999999997) does not decide ties or floor/ceil.
+#[must_use]
+pub fn round_number(value: f64, precision: u32, mode: RoundingMode) -> f64 {
+    const SNAP: f64 = 1e9;
+    let factor = 10_f64.powi(i32::try_from(precision.min(MAX_ROUND_PRECISION)).unwrap_or(0));
+    let scaled = value * factor;
+    let snapped = (scaled * SNAP).round() / SNAP;
+    mode.apply(snapped) / factor
+}
+
+/// Apply the round transform to normalized data; non-numeric or missing sources are left untouched
+pub fn apply_round(transform: &RoundTransform, normalized: &mut Value) {
+    let Some(number) =
+        execution::get_nested(normalized, &transform.source).and_then(|v| v.as_f64())
+    else {
+        return;
+    };
+    let rounded = round_number(number, transform.precision, transform.mode);
+    let value = if transform.precision == 0 {
+        integer_value(rounded).unwrap_or_else(|| Value::from(rounded))
+    } else {
+        Value::from(rounded)
+    };
+    execution::set_nested(normalized, &transform.target, value);
+}
+
+/// Integral floats within the `i64` range as JSON integers
+fn integer_value(rounded: f64) -> Option<Value> {
+    // i64::MAX is not exactly representable as f64; stay strictly below 2^63
+    const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+    if rounded.is_finite() && rounded.abs() < LIMIT {
+        #[allow(clippy::cast_possible_truncation)]
+        // Value is integral (already rounded) and checked to be within the i64 range
+        Some(Value::from(rounded as i64))
+    } else {
+        None
+    }
+}
+
+pub(crate) fn validate_round_transform(
+    idx: usize,
+    rt: &RoundTransform,
+    safe_field: &Regex,
+) -> DslResult<()> {
+    if !safe_field.is_match(&rt.source) {
+        return Err(DslError::invalid(
+            idx,
+            "transform.round.source",
+            format!("DSL step {idx}: transform.round.source must be a safe identifier"),
+        ));
+    }
+    if !safe_field.is_match(&rt.target) {
+        return Err(DslError::invalid(
+            idx,
+            "transform.round.target",
+            format!("DSL step {idx}: transform.round.target must be a safe identifier"),
+        ));
+    }
+    if rt.precision > MAX_ROUND_PRECISION {
+        return Err(DslError::invalid(
+            idx,
+            "transform.round.precision",
+            format!(
+                "DSL step {idx}: transform.round.precision must be at most {MAX_ROUND_PRECISION}"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn round_field(value: &Value, precision: u32, mode: RoundingMode) -> Value {
+        let mut normalized = json!({ "price": value });
+        apply_round(
+            &RoundTransform {
+                source: "price".to_string(),
+                target: "rounded".to_string(),
+                precision,
+                mode,
+            },
+            &mut normalized,
+        );
+        normalized.get("rounded").cloned().unwrap_or(Value::Null)
+    }
+
+    #[test]
+    fn rounds_to_two_places_under_each_mode() {
+        assert_eq!(
+            round_field(&json!(2.345), 2, RoundingMode::HalfUp),
+            json!(2.35)
+        );
+        assert_eq!(
+            round_field(&json!(2.345), 2, RoundingMode::HalfEven),
+            json!(2.34)
+        );
+        assert_eq!(
+            round_field(&json!(2.345), 2, RoundingMode::Floor),
+            json!(2.34)
+        );
+        assert_eq!(
+            round_field(&json!(2.345), 2, RoundingMode::Ceil),
+            json!(2.35)
+        );
+    }
+
+    #[test]
+    fn negative_values_and_default_precision() {
+        assert_eq!(
+            round_field(&json!(-2.345), 2, RoundingMode::HalfUp),
+            json!(-2.35)
+        );
+        assert_eq!(
+            round_field(&json!(-2.345), 2, RoundingMode::Floor),
+            json!(-2.35)
+        );
+        assert_eq!(
+            round_field(&json!(-2.345), 2, RoundingMode::Ceil),
+            json!(-2.34)
+        );
+        assert_eq!(
+            round_field(&json!(2.5), 0, RoundingMode::HalfEven),
+            json!(2)
+        );
+        assert_eq!(round_field(&json!(2.5), 0, RoundingMode::HalfUp), json!(3));
+    }
+
+    #[test]
+    fn non_numeric_values_are_left_untouched() {
+        let mut normalized = json!({ "price": "2.345" });
+        apply_round(
+            &RoundTransform {
+                source: "price".to_string(),
+                target: "price".to_string(),
+                precision: 2,
+                mode: RoundingMode::HalfUp,
+            },
+            &mut normalized,
+        );
+        assert_eq!(normalized, json!({ "price": "2.345" }));
+        assert_eq!(
+            round_field(&Value::Null, 2, RoundingMode::HalfUp),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn validation_rejects_unsafe_fields_and_large_precision() {
+        let safe = Regex::new(r"^[A-Za-z_][A-Za-z0-9_.]*$").unwrap();
+        let valid = RoundTransform {
+            source: "price".to_string(),
+            target: "price_rounded".to_string(),
+            precision: 2,
+            mode: RoundingMode::HalfEven,
+        };
+        assert!(validate_round_transform(0, &valid, &safe).is_ok());
+
+        let bad_source = RoundTransform {
+            source: "price; drop".to_string(),
+            ..valid.clone()
+        };
+        assert!(validate_round_transform(0, &bad_source, &safe).is_err());
+        let bad_target = RoundTransform {
+            target: "1abc".to_string(),
+            ..valid.clone()
+        };
+        assert!(validate_round_transform(0, &bad_target, &safe).is_err());
+        let too_precise = RoundTransform {
+            precision: MAX_ROUND_PRECISION + 1,
+            ..valid
+        };
+        assert!(validate_round_transform(0, &too_precise, &safe).is_err());
+    }
+
+    #[test]
+    fn defaults_deserialize_to_integer_half_up() {
+        let rt: RoundTransform =
+            serde_json::from_value(json!({ "source": "a", "target": "b" })).unwrap();
+        assert_eq!(rt.precision, 0);
+        assert_eq!(rt.mode, RoundingMode::HalfUp);
+    }
+}
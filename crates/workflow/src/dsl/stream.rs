@@ -0,0 +1,132 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::borrow::Borrow;
+
+use serde_json::Value;
+
+use super::error::DslResult;
+use super::program::DslProgram;
+use super::to::ToDef;
+
+impl DslProgram {
+    /// Execute the program lazily over a sequence of records.
+    ///
+    /// Each record is run through [`Self::execute`] only when the returned iterator is advanced,
+    /// so large inputs (e.g. million-row CSVs read record by record) never have to be held in
+    /// memory at once. Every item holds the outputs of one record; a failing record yields an
+    /// error without ending the iteration.
+    ///
+    /// Like `execute`, this runs the synchronous pipeline only: transforms that need services
+    /// (entity lookups, authentication, emails) are not applied. It serves previews such as the
+    /// DSL test batch. Worker runs go through the services' step executor instead, which runs
+    /// those transforms and reads staged items in batches of `staged_batch_size`.
+    pub fn execute_stream<'a, I>(
+        &'a self,
+        inputs: I,
+    ) -> impl Iterator<Item = DslResult<Vec<(ToDef, Value)>>> + 'a
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Value>,
+        I::IntoIter: 'a,
+    {
+        inputs
+            .into_iter()
+            .map(move |input| self.execute(input.borrow()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{mpsc, Arc};
+
+    fn doubling_program() -> DslProgram {
+        let config = json!({
+            "steps": [{
+                "from": {
+                    "type": "format",
+                    "source": { "source_type": "api", "config": {}, "auth": null },
+                    "format": { "format_type": "csv", "options": {} },
+                    "mapping": { "n": "n" }
+                },
+                "transform": {
+                    "type": "arithmetic",
+                    "target": "doubled",
+                    "left": { "kind": "field", "field": "n" },
+                    "op": "mul",
+                    "right": { "kind": "const", "value": 2.0 }
+                },
+                "to": {
+                    "type": "format",
+                    "output": { "mode": "api" },
+                    "format": { "format_type": "json", "options": {} },
+                    "mapping": { "n": "n", "doubled": "doubled" }
+                }
+            }]
+        });
+        let program = DslProgram::from_config(&config).unwrap();
+        program.validate().unwrap();
+        program
+    }
+
+    #[test]
+    fn stream_matches_execute_and_continues_after_errors() {
+        let program = doubling_program();
+        let inputs = vec![json!({ "n": 1 }), json!({ "n": "x" }), json!({ "n": 3 })];
+
+        let results: Vec<_> = program.execute_stream(&inputs).collect();
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap()[0].1,
+            program.execute(&inputs[0]).unwrap()[0].1
+        );
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap()[0].1["doubled"], json!(6.0));
+    }
+
+    #[test]
+    fn stream_processes_records_through_bounded_channel() {
+        const RECORDS: usize = 10_000;
+        const CAPACITY: usize = 16;
+
+        let program = doubling_program();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let (tx, rx) = mpsc::sync_channel::<Value>(CAPACITY);
+
+        let producer = {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            std::thread::spawn(move || {
+                for n in 0..RECORDS {
+                    let pending = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(pending, Ordering::SeqCst);
+                    tx.send(json!({ "n": n })).unwrap();
+                }
+            })
+        };
+
+        let records = rx.into_iter().inspect(|_| {
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+        });
+        let mut processed = 0_usize;
+        for (n, outputs) in program.execute_stream(records).enumerate() {
+            let outputs = outputs.unwrap();
+            #[allow(clippy::cast_precision_loss)] // record numbers stay far below 2^52
+            let expected = n as f64 * 2.0;
+            assert_eq!(outputs[0].1["doubled"], json!(expected));
+            processed += 1;
+        }
+        producer.join().unwrap();
+
+        assert_eq!(processed, RECORDS);
+        // Buffered records plus the one blocked in `send` and the one being received
+        assert!(
+            max_in_flight.load(Ordering::SeqCst) <= CAPACITY + 2,
+            "{} records were in flight",
+            max_in_flight.load(Ordering::SeqCst)
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::data::adapters::auth::AuthConfig;
 use crate::data::adapters::destination::HttpMethod;
-use crate::dsl::validate_mapping;
+use crate::data::adapters::http::HttpRetryPolicy;
+use crate::dsl::{validate_mapping, DslError, DslResult};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -91,11 +92,7 @@ pub enum ToDef {
     },
 }
 
-pub(crate) fn validate_to(
-    idx: usize,
-    to: &ToDef,
-    safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+pub(crate) fn validate_to(idx: usize, to: &ToDef, safe_field: &Regex) -> DslResult<()> {
     match to {
         ToDef::Format {
             output,
@@ -103,26 +100,28 @@ pub(crate) fn validate_to(
             mapping,
         } => {
             if format.format_type.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: to.format.format.format_type must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    "to.format.format.format_type",
+                    format!("DSL step {idx}: to.format.format.format_type must not be empty"),
+                ));
             }
             // Validate format-specific options
             if format.format_type.as_str() == "csv" {
                 if let Some(serde_json::Value::String(delimiter)) = format.options.get("delimiter")
                 {
                     if delimiter.len() != 1 {
-                        return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: to.format.format.options.delimiter must be a single character")));
+                        return Err(DslError::invalid(idx, "to.format.format.options.delimiter", format!("DSL step {idx}: to.format.format.options.delimiter must be a single character")));
                     }
                 }
                 if let Some(serde_json::Value::String(escape)) = format.options.get("escape") {
                     if !escape.is_empty() && escape.len() != 1 {
-                        return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: to.format.format.options.escape must be a single character when set")));
+                        return Err(DslError::invalid(idx, "to.format.format.options.escape", format!("DSL step {idx}: to.format.format.options.escape must be a single character when set")));
                     }
                 }
                 if let Some(serde_json::Value::String(quote)) = format.options.get("quote") {
                     if !quote.is_empty() && quote.len() != 1 {
-                        return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: to.format.format.options.quote must be a single character when set")));
+                        return Err(DslError::invalid(idx, "to.format.format.options.quote", format!("DSL step {idx}: to.format.format.options.quote must be a single character when set")));
                     }
                 }
             } else {
@@ -138,46 +137,7 @@ pub(crate) fn validate_to(
                     destination,
                     method,
                     ..
-                } => {
-                    if destination.destination_type.trim().is_empty() {
-                        return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: to.format.output.push.destination.destination_type must not be empty")));
-                    }
-                    if destination.destination_type.as_str() == "uriformat!(" {
-                        if let Some(uri) = destination.config.get("uri").and_then(|v| v.as_str()) {
-                            if uri.trim().is_empty() {
-                                return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: to.format.output.push.destination.config.uri must not be empty")));
-                            }
-                            if !uri.starts_with("http://") && !uri.starts_with("https://format!(") {
-                                return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: to.format.output.push.destination.config.uri must start with http:// or https://")));
-                            }
-                        } else {
-                            return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: to.format.output.push.destination.config.uri is required for uri destination")));
-                        }
-                        // Validate HTTP method for URI destinations
-                        if let Some(m) = method {
-                            // HTTP method is validated by the enum itself (serde will reject invalid values)
-                            // But we can add additional validation if needed
-                            match m {
-                                HttpMethod::Get
-                                | HttpMethod::Head
-                                | HttpMethod::Options
-                                | HttpMethod::Post
-                                | HttpMethod::Put
-                                | HttpMethod::Patch
-                                | HttpMethod::Delete => {
-                                    // HTTP methods are validated by the enum itself
-                                    // All methods are acceptable
-                                }
-                            }
-                        }
-                    } else {
-                        // Other destination types will be validated by their handlers
-                    }
-                    // Validate auth config if present
-                    if let Some(auth) = &destination.auth {
-                        validate_auth_config(idx, auth, "toformat!(")?;
-                    }
-                }
+                } => validate_push_output(idx, destination, method.as_ref())?,
             }
             // Allow empty mappings
             validate_mapping(idx, mapping, safe_field)?;
@@ -191,16 +151,20 @@ pub(crate) fn validate_to(
             mapping,
         } => {
             if entity_definition.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: to.entity.entity_definition must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    "to.entity.entity_definition",
+                    format!("DSL step {idx}: to.entity.entity_definition must not be empty"),
+                ));
             }
             // Path is optional - if not provided, it will be derived from parent_uuid at runtime
             if let Some(p) = path {
                 if p.trim().is_empty() {
-                    return Err(r_data_core_core::error::Error::Validation(format!(
-                        "DSL step {idx}: to.entity.path must not be empty when provided"
-                    )));
+                    return Err(DslError::invalid(
+                        idx,
+                        "to.entity.path",
+                        format!("DSL step {idx}: to.entity.path must not be empty when provided"),
+                    ));
                 }
             }
             // Allow empty mappings
@@ -222,6 +186,61 @@ pub(crate) fn validate_to(
     Ok(())
 }
 
+fn validate_push_output(
+    idx: usize,
+    destination: &DestinationConfig,
+    method: Option<&HttpMethod>,
+) -> DslResult<()> {
+    if destination.destination_type.trim().is_empty() {
+        return Err(DslError::invalid(idx, "to.format.output.push.destination.destination_type", format!("DSL step {idx}: to.format.output.push.destination.destination_type must not be empty")));
+    }
+    if destination.destination_type == "uri" {
+        HttpRetryPolicy::from_config(&destination.config).map_err(|e| {
+            DslError::invalid(
+                idx,
+                "to.format.output.push.destination.config",
+                format!("DSL step {idx}: to.format.output.push.destination.config: {e}"),
+            )
+        })?;
+    }
+    if destination.destination_type.as_str() == "uriformat!(" {
+        if let Some(uri) = destination.config.get("uri").and_then(|v| v.as_str()) {
+            if uri.trim().is_empty() {
+                return Err(DslError::invalid(idx, "to.format.output.push.destination.config.uri", format!("DSL step {idx}: to.format.output.push.destination.config.uri must not be empty")));
+            }
+            if !uri.starts_with("http://") && !uri.starts_with("https://format!(") {
+                return Err(DslError::invalid(idx, "to.format.output.push.destination.config.uri", format!("DSL step {idx}: to.format.output.push.destination.config.uri must start with http:// or https://")));
+            }
+        } else {
+            return Err(DslError::invalid(idx, "to.format.output.push.destination.config.uri", format!("DSL step {idx}: to.format.output.push.destination.config.uri is required for uri destination")));
+        }
+        // Validate HTTP method for URI destinations
+        if let Some(m) = method {
+            // HTTP method is validated by the enum itself (serde will reject invalid values)
+            // But we can add additional validation if needed
+            match m {
+                HttpMethod::Get
+                | HttpMethod::Head
+                | HttpMethod::Options
+                | HttpMethod::Post
+                | HttpMethod::Put
+                | HttpMethod::Patch
+                | HttpMethod::Delete => {
+                    // HTTP methods are validated by the enum itself
+                    // All methods are acceptable
+                }
+            }
+        }
+    } else {
+        // Other destination types will be validated by their handlers
+    }
+    // Validate auth config if present
+    if let Some(auth) = &destination.auth {
+        validate_auth_config(idx, auth, "toformat!(")?;
+    }
+    Ok(())
+}
+
 fn validate_email_to(
     idx: usize,
     template_uuid: &str,
@@ -229,23 +248,29 @@ fn validate_email_to(
     cc: Option<&[super::transform::StringOperand]>,
     mapping: &std::collections::HashMap<String, String>,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if template_uuid.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: to.email.template_uuid must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "to.email.template_uuid",
+            format!("DSL step {idx}: to.email.template_uuid must not be empty"),
+        ));
     }
     if to.is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: to.email.to must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "to.email.to",
+            format!("DSL step {idx}: to.email.to must not be empty"),
+        ));
     }
     for (i, operand) in to.iter().enumerate() {
         if let super::transform::StringOperand::Field { field } = operand {
             if !safe_field.is_match(field) {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: to.email.to[{i}] field path must be safe"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("to.email.to[{i}]"),
+                    format!("DSL step {idx}: to.email.to[{i}] field path must be safe"),
+                ));
             }
         }
     }
@@ -253,9 +278,11 @@ fn validate_email_to(
         for (i, operand) in cc_list.iter().enumerate() {
             if let super::transform::StringOperand::Field { field } = operand {
                 if !safe_field.is_match(field) {
-                    return Err(r_data_core_core::error::Error::Validation(format!(
-                        "DSL step {idx}: to.email.cc[{i}] field path must be safe"
-                    )));
+                    return Err(DslError::invalid(
+                        idx,
+                        format!("to.email.cc[{i}]"),
+                        format!("DSL step {idx}: to.email.cc[{i}] field path must be safe"),
+                    ));
                 }
             }
         }
@@ -265,37 +292,41 @@ fn validate_email_to(
 }
 
 /// Validate authentication configuration
-fn validate_auth_config(
-    idx: usize,
-    auth: &AuthConfig,
-    context: &str,
-) -> r_data_core_core::error::Result<()> {
+fn validate_auth_config(idx: usize, auth: &AuthConfig, context: &str) -> DslResult<()> {
     match auth {
         AuthConfig::None => {
             // No validation needed
         }
         AuthConfig::ApiKey { key, header_name } => {
             if key.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: {context}.auth.api_key.key must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.api_key.key"),
+                    format!("DSL step {idx}: {context}.auth.api_key.key must not be empty"),
+                ));
             }
             if header_name.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: {context}.auth.api_key.header_name must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.api_key.header_name"),
+                    format!("DSL step {idx}: {context}.auth.api_key.header_name must not be empty"),
+                ));
             }
         }
         AuthConfig::BasicAuth { username, password } => {
             if username.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: {context}.auth.basic_auth.username must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.basic_auth.username"),
+                    format!("DSL step {idx}: {context}.auth.basic_auth.username must not be empty"),
+                ));
             }
             if password.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: {context}.auth.basic_auth.password must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.basic_auth.password"),
+                    format!("DSL step {idx}: {context}.auth.basic_auth.password must not be empty"),
+                ));
             }
         }
         AuthConfig::PreSharedKey {
@@ -304,21 +335,27 @@ fn validate_auth_config(
             field_name,
         } => {
             if key.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: {context}.auth.pre_shared_key.key must not be empty"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.pre_shared_key.key"),
+                    format!("DSL step {idx}: {context}.auth.pre_shared_key.key must not be empty"),
+                ));
             }
             if field_name.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!(
+                return Err(DslError::invalid(
+                    idx,
+                    format!("{context}.auth.pre_shared_key.field_name"),
+                    format!(
                     "DSL step {idx}: {context}.auth.pre_shared_key.field_name must not be empty"
-                )));
+                ),
+                ));
             }
         }
         AuthConfig::EntityJwt { required_claims } => {
             if let Some(claims) = required_claims {
                 for key in claims.keys() {
                     if key.trim().is_empty() {
-                        return Err(r_data_core_core::error::Error::Validation(format!(
+                        return Err(DslError::invalid(idx, format!("{context}.auth.entity_jwt.required_claims"), format!(
                             "DSL step {idx}: {context}.auth.entity_jwt.required_claims keys must not be empty"
                         )));
                     }
@@ -4,6 +4,15 @@ use std::collections::HashMap;
 use ts_rs::TS;
 use utoipa::ToSchema;
 
+use super::cast::{validate_cast_transform, CastTransform};
+use super::date::{
+    validate_date_format_transform, validate_date_parse_transform, DateFormatTransform,
+    DateParseTransform,
+};
+use super::error::{DslError, DslResult};
+use super::lookup::{validate_lookup_transform, LookupTransform};
+use super::round::{validate_round_transform, RoundTransform};
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Transform {
@@ -22,6 +31,16 @@ pub enum Transform {
     Authenticate(AuthenticateTransform),
     /// Send an email via SMTP using the workflow mail service
     SendEmail(SendEmailTransform),
+    /// Round a numeric field to a number of decimal places
+    Round(RoundTransform),
+    /// Map a field through a static lookup table
+    Lookup(LookupTransform),
+    /// Convert a field to another type (string, integer, float, boolean)
+    Cast(CastTransform),
+    /// Parse a date string in a custom format into an ISO-8601 timestamp
+    DateParse(DateParseTransform),
+    /// Format an ISO-8601 date or timestamp using a custom pattern
+    DateFormat(DateFormatTransform),
 }
 
 /// Arithmetic transform allows setting a target field to the result of left (op) right.
@@ -65,6 +84,10 @@ pub enum Operand {
     Const {
         value: f64,
     },
+    /// Current time as Unix timestamp (seconds)
+    Now,
+    /// Start of the current day (UTC) as Unix timestamp (seconds)
+    Today,
     /// Future extension: resolve from repository during processing (not implemented in `apply()`)
     ExternalEntityField {
         entity_definition: String,
@@ -78,8 +101,22 @@ pub enum Operand {
 #[serde(tag = "kind", rename_all = "snake_case")]
 #[ts(export)]
 pub enum StringOperand {
-    Field { field: String },
-    ConstString { value: String },
+    Field {
+        field: String,
+    },
+    ConstString {
+        value: String,
+    },
+    /// Current time, formatted with a `time` format description (RFC 3339 if omitted)
+    Now {
+        #[serde(default)]
+        format: Option<String>,
+    },
+    /// Current date, formatted with a `time` format description (`[year]-[month]-[day]` if omitted)
+    Today {
+        #[serde(default)]
+        format: Option<String>,
+    },
 }
 
 /// Resolve entity path transform - finds entity by filters and sets path and UUID
@@ -166,11 +203,7 @@ pub struct SendEmailTransform {
     pub target_status: String,
 }
 
-pub(crate) fn validate_transform(
-    idx: usize,
-    t: &Transform,
-    safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+pub(crate) fn validate_transform(idx: usize, t: &Transform, safe_field: &Regex) -> DslResult<()> {
     match t {
         Transform::Arithmetic(ar) => validate_arithmetic_transform(idx, ar, safe_field)?,
         Transform::Concat(ct) => validate_concat_transform(idx, ct, safe_field)?,
@@ -185,6 +218,11 @@ pub(crate) fn validate_transform(
             validate_authenticate_transform(idx, auth, safe_field)?;
         }
         Transform::SendEmail(se) => validate_send_email_transform(idx, se, safe_field)?,
+        Transform::Round(rt) => validate_round_transform(idx, rt, safe_field)?,
+        Transform::Lookup(lt) => validate_lookup_transform(idx, lt, safe_field)?,
+        Transform::Cast(ct) => validate_cast_transform(idx, ct, safe_field)?,
+        Transform::DateParse(dp) => validate_date_parse_transform(idx, dp, safe_field)?,
+        Transform::DateFormat(df) => validate_date_format_transform(idx, df, safe_field)?,
         Transform::None => {}
     }
     Ok(())
@@ -194,11 +232,13 @@ fn validate_arithmetic_transform(
     idx: usize,
     ar: &ArithmeticTransform,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if !safe_field.is_match(&ar.target) {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.arithmetic.target must be a safe identifier"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.arithmetic.target",
+            format!("DSL step {idx}: transform.arithmetic.target must be a safe identifier"),
+        ));
     }
     validate_operand(idx, "left", &ar.left, safe_field)?;
     validate_operand(idx, "right", &ar.right, safe_field)?;
@@ -209,11 +249,13 @@ fn validate_concat_transform(
     idx: usize,
     ct: &ConcatTransform,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if !safe_field.is_match(&ct.target) {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.concat.target must be a safe identifier"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.concat.target",
+            format!("DSL step {idx}: transform.concat.target must be a safe identifier"),
+        ));
     }
     validate_string_operand(idx, "left", &ct.left, safe_field)?;
     validate_string_operand(idx, "right", &ct.right, safe_field)?;
@@ -225,13 +267,34 @@ fn validate_string_operand(
     side: &str,
     operand: &StringOperand,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
-    if let StringOperand::Field { field } = operand {
-        if !safe_field.is_match(field) {
-            return Err(r_data_core_core::error::Error::Validation(format!(
-                "DSL step {idx}: transform.concat.{side} field path must be safe"
-            )));
+) -> DslResult<()> {
+    match operand {
+        StringOperand::Field { field } => {
+            if !safe_field.is_match(field) {
+                return Err(DslError::invalid(
+                    idx,
+                    format!("transform.concat.{side}"),
+                    format!("DSL step {idx}: transform.concat.{side} field path must be safe"),
+                ));
+            }
         }
+        StringOperand::Now {
+            format: Some(format),
+        }
+        | StringOperand::Today {
+            format: Some(format),
+        } => {
+            super::clock::parse_format(format).map_err(|e| {
+                DslError::invalid(
+                    idx,
+                    format!("transform.concat.{side}"),
+                    format!("DSL step {idx}: transform.concat.{side}: {e}"),
+                )
+            })?;
+        }
+        StringOperand::ConstString { .. }
+        | StringOperand::Now { format: None }
+        | StringOperand::Today { format: None } => {}
     }
     Ok(())
 }
@@ -240,33 +303,41 @@ fn validate_resolve_entity_path_transform(
     idx: usize,
     rep: &ResolveEntityPathTransform,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if !safe_field.is_match(&rep.target_path) {
-        return Err(r_data_core_core::error::Error::Validation(format!(
+        return Err(DslError::invalid(
+            idx,
+            "transform.resolve_entity_path.target_path",
+            format!(
             "DSL step {idx}: transform.resolve_entity_path.target_path must be a safe identifier"
-        )));
+        ),
+        ));
     }
     if let Some(ref target_uuid) = rep.target_uuid {
         if !safe_field.is_match(target_uuid) {
-            return Err(r_data_core_core::error::Error::Validation(format!(
+            return Err(DslError::invalid(idx, "transform.resolve_entity_path.target_uuid", format!(
                 "DSL step {idx}: transform.resolve_entity_path.target_uuid must be a safe identifier"
             )));
         }
     }
     if rep.entity_type.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.resolve_entity_path.entity_type must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.resolve_entity_path.entity_type",
+            format!("DSL step {idx}: transform.resolve_entity_path.entity_type must not be empty"),
+        ));
     }
     if rep.filters.is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.resolve_entity_path.filters must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.resolve_entity_path.filters",
+            format!("DSL step {idx}: transform.resolve_entity_path.filters must not be empty"),
+        ));
     }
     for (field, operand) in &rep.filters {
         if let StringOperand::Field { field: field_path } = operand {
             if !safe_field.is_match(field_path) {
-                return Err(r_data_core_core::error::Error::Validation(format!(
+                return Err(DslError::invalid(idx, format!("transform.resolve_entity_path.filters.{field}"), format!(
                     "DSL step {idx}: transform.resolve_entity_path.filters.{field} field path must be safe"
                 )));
             }
@@ -279,16 +350,20 @@ fn validate_build_path_transform(
     idx: usize,
     bp: &BuildPathTransform,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if !safe_field.is_match(&bp.target) {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.build_path.target must be a safe identifier"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.build_path.target",
+            format!("DSL step {idx}: transform.build_path.target must be a safe identifier"),
+        ));
     }
     if bp.template.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.build_path.template must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.build_path.template",
+            format!("DSL step {idx}: transform.build_path.template must not be empty"),
+        ));
     }
     Ok(())
 }
@@ -297,34 +372,44 @@ fn validate_get_or_create_entity_transform(
     idx: usize,
     goc: &GetOrCreateEntityTransform,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if !safe_field.is_match(&goc.target_path) {
-        return Err(r_data_core_core::error::Error::Validation(format!(
+        return Err(DslError::invalid(
+            idx,
+            "transform.get_or_create_entity.target_path",
+            format!(
             "DSL step {idx}: transform.get_or_create_entity.target_path must be a safe identifier"
-        )));
+        ),
+        ));
     }
     if let Some(ref target_uuid) = goc.target_uuid {
         if !safe_field.is_match(target_uuid) {
-            return Err(r_data_core_core::error::Error::Validation(format!(
+            return Err(DslError::invalid(idx, "transform.get_or_create_entity.target_uuid", format!(
                 "DSL step {idx}: transform.get_or_create_entity.target_uuid must be a safe identifier"
             )));
         }
     }
     if goc.entity_type.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.get_or_create_entity.entity_type must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.get_or_create_entity.entity_type",
+            format!("DSL step {idx}: transform.get_or_create_entity.entity_type must not be empty"),
+        ));
     }
     if goc.path_template.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.get_or_create_entity.path_template must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.get_or_create_entity.path_template",
+            format!(
+                "DSL step {idx}: transform.get_or_create_entity.path_template must not be empty"
+            ),
+        ));
     }
     if let Some(ref create_data) = goc.create_field_data {
         for (field, operand) in create_data {
             if let StringOperand::Field { field: field_path } = operand {
                 if !safe_field.is_match(field_path) {
-                    return Err(r_data_core_core::error::Error::Validation(format!(
+                    return Err(DslError::invalid(idx, format!("transform.get_or_create_entity.create_field_data.{field}"), format!(
                         "DSL step {idx}: transform.get_or_create_entity.create_field_data.{field} field path must be safe"
                     )));
                 }
@@ -338,16 +423,20 @@ fn validate_authenticate_transform(
     idx: usize,
     auth: &AuthenticateTransform,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if auth.entity_type.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.authenticate.entity_type must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.authenticate.entity_type",
+            format!("DSL step {idx}: transform.authenticate.entity_type must not be empty"),
+        ));
     }
     if !safe_field.is_match(&auth.entity_type) {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.authenticate.entity_type must be a safe identifier"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.authenticate.entity_type",
+            format!("DSL step {idx}: transform.authenticate.entity_type must be a safe identifier"),
+        ));
     }
     for (label, value) in [
         ("identifier_field", &auth.identifier_field),
@@ -357,19 +446,25 @@ fn validate_authenticate_transform(
         ("target_token", &auth.target_token),
     ] {
         if !safe_field.is_match(value) {
-            return Err(r_data_core_core::error::Error::Validation(format!(
-                "DSL step {idx}: transform.authenticate.{label} must be a safe identifier"
-            )));
+            return Err(DslError::invalid(
+                idx,
+                format!("transform.authenticate.{label}"),
+                format!("DSL step {idx}: transform.authenticate.{label} must be a safe identifier"),
+            ));
         }
     }
     for (claim_name, entity_field) in &auth.extra_claims {
         if claim_name.trim().is_empty() {
-            return Err(r_data_core_core::error::Error::Validation(format!(
-                "DSL step {idx}: transform.authenticate.extra_claims key must not be empty"
-            )));
+            return Err(DslError::invalid(
+                idx,
+                "transform.authenticate.extra_claims",
+                format!(
+                    "DSL step {idx}: transform.authenticate.extra_claims key must not be empty"
+                ),
+            ));
         }
         if !safe_field.is_match(entity_field) {
-            return Err(r_data_core_core::error::Error::Validation(format!(
+            return Err(DslError::invalid(idx, format!("transform.authenticate.extra_claims.{claim_name}"), format!(
                 "DSL step {idx}: transform.authenticate.extra_claims.{claim_name} field must be a safe identifier"
             )));
         }
@@ -381,23 +476,29 @@ fn validate_send_email_transform(
     idx: usize,
     se: &SendEmailTransform,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     if se.template_uuid.trim().is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.send_email.template_uuid must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.send_email.template_uuid",
+            format!("DSL step {idx}: transform.send_email.template_uuid must not be empty"),
+        ));
     }
     if se.to.is_empty() {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.send_email.to must not be empty"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.send_email.to",
+            format!("DSL step {idx}: transform.send_email.to must not be empty"),
+        ));
     }
     for (i, operand) in se.to.iter().enumerate() {
         if let StringOperand::Field { field } = operand {
             if !safe_field.is_match(field) {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: transform.send_email.to[{i}] field path must be safe"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("transform.send_email.to[{i}]"),
+                    format!("DSL step {idx}: transform.send_email.to[{i}] field path must be safe"),
+                ));
             }
         }
     }
@@ -405,51 +506,58 @@ fn validate_send_email_transform(
         for (i, operand) in cc.iter().enumerate() {
             if let StringOperand::Field { field } = operand {
                 if !safe_field.is_match(field) {
-                    return Err(r_data_core_core::error::Error::Validation(format!(
-                        "DSL step {idx}: transform.send_email.cc[{i}] field path must be safe"
-                    )));
+                    return Err(DslError::invalid(
+                        idx,
+                        format!("transform.send_email.cc[{i}]"),
+                        format!(
+                            "DSL step {idx}: transform.send_email.cc[{i}] field path must be safe"
+                        ),
+                    ));
                 }
             }
         }
     }
     if !safe_field.is_match(&se.target_status) {
-        return Err(r_data_core_core::error::Error::Validation(format!(
-            "DSL step {idx}: transform.send_email.target_status must be a safe identifier"
-        )));
+        return Err(DslError::invalid(
+            idx,
+            "transform.send_email.target_status",
+            format!("DSL step {idx}: transform.send_email.target_status must be a safe identifier"),
+        ));
     }
     Ok(())
 }
 
-fn validate_operand(
-    idx: usize,
-    side: &str,
-    op: &Operand,
-    safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+fn validate_operand(idx: usize, side: &str, op: &Operand, safe_field: &Regex) -> DslResult<()> {
     match op {
         Operand::Field { field } => {
             if !safe_field.is_match(field) {
-                return Err(r_data_core_core::error::Error::Validation(format!(
-                    "DSL step {idx}: transform.arithmetic.{side} field path must be safe"
-                )));
+                return Err(DslError::invalid(
+                    idx,
+                    format!("transform.arithmetic.{side}"),
+                    format!("DSL step {idx}: transform.arithmetic.{side} field path must be safe"),
+                ));
             }
         }
-        Operand::Const { .. } => {}
+        Operand::Const { .. } | Operand::Now | Operand::Today => {}
         Operand::ExternalEntityField {
             entity_definition,
             filter,
             field,
         } => {
             if entity_definition.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: transform.arithmetic.{side} external entity_definition required")));
+                return Err(DslError::invalid(idx, format!("transform.arithmetic.{side}"), format!("DSL step {idx}: transform.arithmetic.{side} external entity_definition required")));
             }
             if filter.field.trim().is_empty() || filter.value.trim().is_empty() {
-                return Err(r_data_core_core::error::Error::Validation(format!("DSL step {idx}: transform.arithmetic.{side} external filter requires field and value")));
+                return Err(DslError::invalid(idx, format!("transform.arithmetic.{side}"), format!("DSL step {idx}: transform.arithmetic.{side} external filter requires field and value")));
             }
             if !safe_field.is_match(field) {
-                return Err(r_data_core_core::error::Error::Validation(format!(
+                return Err(DslError::invalid(
+                    idx,
+                    format!("transform.arithmetic.{side}"),
+                    format!(
                     "DSL step {idx}: transform.arithmetic.{side} external field path must be safe"
-                )));
+                ),
+                ));
             }
         }
     }
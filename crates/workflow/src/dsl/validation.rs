@@ -3,6 +3,7 @@
 use regex::Regex;
 use std::collections::HashMap;
 
+use super::error::{DslError, DslResult};
 use super::execution::LITERAL_PREFIX;
 
 /// Check if a mapping value is a valid literal value
@@ -34,21 +35,23 @@ pub fn validate_mapping<H: std::hash::BuildHasher>(
     idx: usize,
     mapping: &HashMap<String, String, H>,
     safe_field: &Regex,
-) -> r_data_core_core::error::Result<()> {
+) -> DslResult<()> {
     // Allow empty mappings
     for (k, v) in mapping {
         // Destination (key) must always be a safe field name
         if !safe_field.is_match(k) {
-            return Err(r_data_core_core::error::Error::Validation(format!(
-                "DSL step {idx}: mapping destination contains unsafe field name ('{k}')"
-            )));
+            return Err(DslError::invalid(
+                idx,
+                "mapping",
+                format!("DSL step {idx}: mapping destination contains unsafe field name ('{k}')"),
+            ));
         }
 
         // Source (value) can be either:
         // 1. A safe field name (e.g., "field_name")
         // 2. A literal value (e.g., "@literal:true")
         if !safe_field.is_match(v) && !is_valid_literal_value(v) {
-            return Err(r_data_core_core::error::Error::Validation(format!(
+            return Err(DslError::invalid(idx, format!("mapping.{k}"), format!(
                 "DSL step {idx}: mapping source '{v}' is neither a valid field name nor a literal value"
             )));
         }
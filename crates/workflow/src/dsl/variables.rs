@@ -0,0 +1,301 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use super::error::{DslError, DslResult};
+
+/// Prefix of environment variables exposed to workflows: `WORKFLOW_VAR_API_HOST` is `${API_HOST}`
+pub const ENV_PREFIX: &str = "WORKFLOW_VAR_";
+
+/// Values substituted for `${NAME}` placeholders in the `from` and `to` of each step
+///
+/// Names match `^[A-Za-z_][A-Za-z0-9_]*$`; `$${` is written as a literal `${`. In strict mode a
+/// placeholder without a value is a validation error, otherwise it is left as it is.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Variables {
+    values: HashMap<String, String>,
+    strict: bool,
+}
+
+impl Variables {
+    /// Empty, lenient set of variables
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Variables taken from the `WORKFLOW_VAR_*` environment variables
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_env_vars(std::env::vars())
+    }
+
+    /// Variables taken from `vars` entries that start with [`ENV_PREFIX`]
+    #[must_use]
+    pub fn from_env_vars(vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self::new().with_values(vars.into_iter().filter_map(|(key, value)| {
+            key.strip_prefix(ENV_PREFIX)
+                .filter(|name| is_name(name))
+                .map(|name| (name.to_string(), value))
+        }))
+    }
+
+    /// Add or replace variables
+    #[must_use]
+    pub fn with_values(mut self, values: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.values.extend(values);
+        self
+    }
+
+    /// Fail on placeholders without a value instead of leaving them in place
+    #[must_use]
+    pub const fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Overlay the `variables` map and `strict_variables` flag of a workflow config
+    ///
+    /// A run override can set both, so values supplied when a run is triggered replace the
+    /// environment and the stored workflow.
+    ///
+    /// # Errors
+    /// Returns a parse error if `variables` is not an object of scalars or `strict_variables`
+    /// is not a boolean
+    pub fn with_config(mut self, config: &Value) -> DslResult<Self> {
+        if let Some(values) = config.get("variables") {
+            let values = values
+                .as_object()
+                .ok_or_else(|| DslError::Parse("'variables' must be an object".to_string()))?;
+            for (name, value) in values {
+                let value = match value {
+                    Value::String(s) => s.clone(),
+                    Value::Number(_) | Value::Bool(_) => value.to_string(),
+                    _ => {
+                        return Err(DslError::Parse(format!(
+                            "variables.{name} must be a string, number or boolean"
+                        )))
+                    }
+                };
+                self.values.insert(name.clone(), value);
+            }
+        }
+        if let Some(strict) = config.get("strict_variables") {
+            self.strict = strict.as_bool().ok_or_else(|| {
+                DslError::Parse("'strict_variables' must be a boolean".to_string())
+            })?;
+        }
+        Ok(self)
+    }
+
+    /// Value of variable `name`
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    /// Whether placeholders without a value are errors
+    #[must_use]
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Replace the placeholders in `text`; `Err` holds the first undefined name in strict mode
+    fn substitute(&self, text: &str) -> Result<String, String> {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(pos) = rest.find('$') {
+            out.push_str(&rest[..pos]);
+            let tail = &rest[pos..];
+            if let Some(after) = tail.strip_prefix("$${") {
+                out.push_str("${");
+                rest = after;
+                continue;
+            }
+            let name = tail
+                .strip_prefix("${")
+                .and_then(|after| after.find('}').map(|end| &after[..end]))
+                .filter(|name| is_name(name));
+            let Some(name) = name else {
+                out.push('$');
+                rest = &tail[1..];
+                continue;
+            };
+            match self.get(name) {
+                Some(value) => out.push_str(value),
+                None if self.strict => return Err(name.to_string()),
+                None => out.push_str(&tail[..name.len() + 3]),
+            }
+            rest = &tail[name.len() + 3..];
+        }
+        out.push_str(rest);
+        Ok(out)
+    }
+}
+
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Substitute `variables` into the string values of each step's `from` and `to`
+///
+/// Keys, transforms and conditions are left unchanged. Configs without a `steps` array are
+/// returned as they are, so parsing reports them.
+///
+/// # Errors
+/// In strict mode, returns a validation error naming the step and setting of the first
+/// placeholder without a value
+pub fn substitute_variables(config: &Value, variables: &Variables) -> DslResult<Value> {
+    let mut config = config.clone();
+    if let Some(steps) = config.get_mut("steps").and_then(Value::as_array_mut) {
+        for (idx, step) in steps.iter_mut().enumerate() {
+            for key in ["from", "to"] {
+                if let Some(def) = step.get_mut(key) {
+                    substitute_value(def, variables, idx, key)?;
+                }
+            }
+        }
+    }
+    Ok(config)
+}
+
+fn substitute_value(
+    value: &mut Value,
+    variables: &Variables,
+    step: usize,
+    path: &str,
+) -> DslResult<()> {
+    match value {
+        Value::String(text) if text.contains('$') => {
+            *text = variables.substitute(text).map_err(|name| {
+                DslError::invalid(
+                    step,
+                    path,
+                    format!("DSL step {step}: {path} uses undefined variable ${{{name}}}"),
+                )
+            })?;
+        }
+        Value::Object(map) => {
+            for (key, item) in map {
+                substitute_value(item, variables, step, &format!("{path}.{key}"))?;
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                substitute_value(item, variables, step, &format!("{path}[{i}]"))?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn vars(pairs: &[(&str, &str)]) -> Variables {
+        Variables::new().with_values(
+            pairs
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string())),
+        )
+    }
+
+    #[test]
+    fn substitutes_known_and_keeps_unknown_when_lenient() {
+        let variables = vars(&[("HOST", "api.example.com")]);
+        assert_eq!(
+            variables.substitute("https://${HOST}/${PATH}").unwrap(),
+            "https://api.example.com/${PATH}"
+        );
+    }
+
+    #[test]
+    fn escapes_and_non_placeholders_are_kept() {
+        let variables = vars(&[("A", "x")]);
+        assert_eq!(
+            variables
+                .substitute("$${A} ${A} $5 ${not a name} ${A")
+                .unwrap(),
+            "${A} x $5 ${not a name} ${A"
+        );
+    }
+
+    #[test]
+    fn strict_mode_reports_undefined_name() {
+        let variables = vars(&[]).with_strict(true);
+        assert_eq!(
+            variables.substitute("x${MISSING}"),
+            Err("MISSING".to_string())
+        );
+    }
+
+    #[test]
+    fn env_vars_need_prefix() {
+        let variables = Variables::from_env_vars([
+            ("WORKFLOW_VAR_HOST".to_string(), "a".to_string()),
+            ("DATABASE_URL".to_string(), "secret".to_string()),
+        ]);
+        assert_eq!(variables.get("HOST"), Some("a"));
+        assert_eq!(variables.get("DATABASE_URL"), None);
+    }
+
+    #[test]
+    fn config_values_replace_env_and_set_strict() {
+        let variables = vars(&[("HOST", "env"), ("PORT", "80")])
+            .with_config(&json!({
+                "variables": { "HOST": "run", "LIMIT": 5 },
+                "strict_variables": true
+            }))
+            .unwrap();
+        assert_eq!(variables.get("HOST"), Some("run"));
+        assert_eq!(variables.get("PORT"), Some("80"));
+        assert_eq!(variables.get("LIMIT"), Some("5"));
+        assert!(variables.is_strict());
+
+        assert!(matches!(
+            Variables::new().with_config(&json!({ "variables": { "X": [] } })),
+            Err(DslError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn only_from_and_to_are_substituted() {
+        let config = json!({
+            "steps": [{
+                "from": { "source": { "config": { "uri": "http://${HOST}/a" } } },
+                "transform": { "type": "concat", "separator": "${HOST}" },
+                "to": { "entity_definition": "${TYPE}" }
+            }]
+        });
+        let out = substitute_variables(&config, &vars(&[("HOST", "h"), ("TYPE", "t")])).unwrap();
+        assert_eq!(
+            out["steps"][0]["from"]["source"]["config"]["uri"],
+            "http://h/a"
+        );
+        assert_eq!(out["steps"][0]["transform"]["separator"], "${HOST}");
+        assert_eq!(out["steps"][0]["to"]["entity_definition"], "t");
+    }
+
+    #[test]
+    fn strict_error_names_step_and_field() {
+        let config = json!({
+            "steps": [
+                { "from": {}, "to": {} },
+                { "from": { "filters": [{ "value": "${MISSING}" }] }, "to": {} }
+            ]
+        });
+        let err = substitute_variables(&config, &Variables::new().with_strict(true)).unwrap_err();
+        assert_eq!(err.step(), Some(1));
+        assert_eq!(err.field(), Some("from.filters[0].value"));
+        assert!(err.to_string().contains("${MISSING}"), "got: {err}");
+    }
+}
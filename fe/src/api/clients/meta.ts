@@ -1,3 +1,4 @@
+import type { WorkflowRunStatsResponse } from '@/types/generated/WorkflowRunStatsResponse'
 import { BaseTypedHttpClient } from './base'
 
 interface EntityTypeCount {
@@ -19,6 +20,7 @@ interface WorkflowWithLatestStatus {
 interface WorkflowStats {
     total: number
     workflows: WorkflowWithLatestStatus[]
+    runs: WorkflowRunStatsResponse
 }
 
 export interface DashboardStats {
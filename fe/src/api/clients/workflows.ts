@@ -1,6 +1,7 @@
 import type { WorkflowDetail } from '@/types/generated/WorkflowDetail'
 import type { WorkflowSummary } from '@/types/generated/WorkflowSummary'
 import type { WorkflowRunLogDto } from '@/types/generated/WorkflowRunLogDto'
+import type { CronPreviewResponse } from '@/types/generated/CronPreviewResponse'
 import type { DslOptionsResponse, WorkflowRun, WorkflowConfig } from '@/types/schemas'
 import { BaseTypedHttpClient } from './base'
 import { useAuthStore } from '@/stores/auth'
@@ -86,9 +87,10 @@ export class WorkflowsClient extends BaseTypedHttpClient {
     }
 
     async previewCron(expr: string): Promise<string[]> {
-        return this.request<string[]>(
+        const preview = await this.request<CronPreviewResponse>(
             `/admin/api/v1/workflows/cron/preview?expr=${encodeURIComponent(expr)}`
         )
+        return preview.runs
     }
 
     async getWorkflowRuns(
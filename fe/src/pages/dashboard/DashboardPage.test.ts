@@ -46,6 +46,23 @@ const router = createRouter({
     ],
 })
 
+const emptyRunStats = {
+    total_runs: 0,
+    queued_runs: 0,
+    running_runs: 0,
+    successful_runs: 0,
+    failed_runs: 0,
+    cancelled_runs: 0,
+    success_rate: null,
+    runs_last_24h: 0,
+    successful_runs_last_24h: 0,
+    failed_runs_last_24h: 0,
+    avg_duration_ms: null,
+    p95_duration_ms: null,
+    last_run_status: null,
+    last_run_at: null,
+}
+
 describe('DashboardPage', () => {
     beforeEach(() => {
         vi.clearAllMocks()
@@ -61,6 +78,7 @@ describe('DashboardPage', () => {
             workflows: {
                 total: 3,
                 workflows: [],
+                runs: emptyRunStats,
             },
             online_users_count: 2,
         }
@@ -97,6 +115,7 @@ describe('DashboardPage', () => {
             workflows: {
                 total: 0,
                 workflows: [],
+                runs: emptyRunStats,
             },
             online_users_count: 0,
         }
@@ -134,6 +153,7 @@ describe('DashboardPage', () => {
                     { uuid: '1', name: 'Workflow 1', latest_status: 'finished' },
                     { uuid: '2', name: 'Workflow 2', latest_status: 'failed' },
                 ],
+                runs: emptyRunStats,
             },
             online_users_count: 0,
         }
@@ -168,6 +188,7 @@ describe('DashboardPage', () => {
             workflows: {
                 total: 0,
                 workflows: [],
+                runs: emptyRunStats,
             },
             online_users_count: 7,
         }
@@ -246,6 +267,7 @@ describe('DashboardPage', () => {
             workflows: {
                 total: 3,
                 workflows: [],
+                runs: emptyRunStats,
             },
             online_users_count: 2,
         }
@@ -282,6 +304,7 @@ describe('DashboardPage', () => {
             workflows: {
                 total: 3,
                 workflows: [],
+                runs: emptyRunStats,
             },
             online_users_count: 2,
         }
@@ -328,6 +351,7 @@ describe('DashboardPage', () => {
             workflows: {
                 total: 3,
                 workflows: [],
+                runs: emptyRunStats,
             },
             online_users_count: 2,
         }
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Login response for users with two-factor authentication enabled
+ *
+ * The challenge token must be exchanged together with a TOTP code at
+ * `/admin/api/v1/auth/2fa/login` for access and refresh tokens.
+ */
+export type AdminLoginChallengeResponse = { 
+/**
+ * Always true; distinguishes this response from a completed login
+ */
+two_factor_required: boolean, 
+/**
+ * Short-lived token identifying the login attempt
+ */
+challenge_token: string, 
+/**
+ * Challenge expiration (RFC3339 timestamp)
+ */
+challenge_expires_at: string, };
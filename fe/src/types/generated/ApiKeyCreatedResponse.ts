@@ -47,4 +47,16 @@ published: boolean,
 /**
  * When the API key was last used
  */
-last_used_at: string | null, };
+last_used_at: string | null, 
+/**
+ * Public API scopes the key is restricted to; empty for full access
+ */
+scopes: Array<string>, 
+/**
+ * Maximum public API requests per minute; null for unlimited
+ */
+rate_limit_per_minute: number | null, 
+/**
+ * Source IP ranges (CIDR) the key may be used from; empty for any address
+ */
+allowed_cidrs: Array<string>, };
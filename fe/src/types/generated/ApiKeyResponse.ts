@@ -43,4 +43,16 @@ user_uuid: string,
 /**
  * Whether the key is published
  */
-published: boolean, };
+published: boolean, 
+/**
+ * Public API scopes the key is restricted to; empty for full access
+ */
+scopes: Array<string>, 
+/**
+ * Maximum public API requests per minute; null for unlimited
+ */
+rate_limit_per_minute: number | null, 
+/**
+ * Source IP ranges (CIDR) the key may be used from; empty for any address
+ */
+allowed_cidrs: Array<string>, };
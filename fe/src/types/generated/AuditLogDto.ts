@@ -0,0 +1,38 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Single audit log entry response
+ */
+export type AuditLogDto = { 
+/**
+ * Entry UUID
+ */
+uuid: string, 
+/**
+ * When the change was made
+ */
+created_at: string, 
+/**
+ * UUID of the user that made the change (if known)
+ */
+actor_uuid: string | null, 
+/**
+ * Kind of change
+ */
+action: string, 
+/**
+ * Type of the changed resource
+ */
+resource_type: string, 
+/**
+ * UUID of the changed resource
+ */
+resource_uuid: string, 
+/**
+ * Resource before the change (absent for creations)
+ */
+before: unknown, 
+/**
+ * Resource after the change (absent for deletions)
+ */
+after: unknown, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Query parameters for filtering the audit log
+ */
+export type AuditLogQuery = { 
+/**
+ * Page number (1-based, default: 1)
+ */
+page: bigint | null, 
+/**
+ * Items per page (default: 20, max: 100)
+ */
+page_size: bigint | null, 
+/**
+ * Filter by the UUID of the user that made the change
+ */
+actor_uuid: string | null, 
+/**
+ * Filter by resource type
+ */
+resource_type: string | null, 
+/**
+ * Filter by resource UUID
+ */
+resource_uuid: string | null, 
+/**
+ * Filter entries created after this timestamp (ISO 8601)
+ */
+date_from: string | null, 
+/**
+ * Filter entries created before this timestamp (ISO 8601)
+ */
+date_to: string | null, };
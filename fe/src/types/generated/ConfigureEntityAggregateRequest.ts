@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request body for enabling or reconfiguring the aggregate of an entity type
+ */
+export type ConfigureEntityAggregateRequest = { 
+/**
+ * Numeric (Integer or Float) fields whose sums are maintained; may be empty to only count
+ */
+sum_fields: Array<string>, };
@@ -15,4 +15,12 @@ description: string | null,
 /**
  * Number of days until expiration (default: 365)
  */
-expires_in_days: number | null, };
+expires_in_days: number | null, 
+/**
+ * Public API scopes to restrict the key to (e.g. `entities:read`); empty for full access
+ */
+scopes: Array<string>, 
+/**
+ * Source IP ranges (CIDR) to restrict the key to; empty for any address
+ */
+allowed_cidrs: Array<string>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request body for creating a webhook subscription
+ */
+export type CreateEntityWebhookRequest = { 
+/**
+ * Entity type whose updates are watched
+ */
+entity_type: string, 
+/**
+ * HTTP(S) URL the field diff is posted to
+ */
+url: string, 
+/**
+ * Field names; the webhook fires when any of them changes
+ */
+fields: Array<string>, };
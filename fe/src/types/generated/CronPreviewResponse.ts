@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Upcoming fire times of a cron expression
+ */
+export type CronPreviewResponse = { 
+/**
+ * Timezone the schedule is evaluated in
+ */
+timezone: string, 
+/**
+ * Next occurrences as RFC3339 timestamps; empty if the schedule never fires again
+ */
+runs: Array<string>, };
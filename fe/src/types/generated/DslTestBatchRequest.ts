@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DslTestBatchRequest = { 
+/**
+ * The DSL steps array (JSON), same shape as for `/dsl/validate`
+ */
+steps: unknown[], 
+/**
+ * Sample input rows; each is executed independently
+ */
+inputs: unknown[], };
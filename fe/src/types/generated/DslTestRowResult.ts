@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DslTestStepOutput } from "./DslTestStepOutput";
+
+export type DslTestRowResult = { 
+/**
+ * Position of the row in the submitted inputs
+ */
+index: number, 
+/**
+ * Outputs of the steps that ran, in execution order (empty if the row failed)
+ */
+outputs: Array<DslTestStepOutput>, 
+/**
+ * Execution error for this row, if any
+ */
+error: string | null, };
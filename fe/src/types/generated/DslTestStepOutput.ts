@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DslTestStepOutput = { 
+/**
+ * Kind of target the step writes to (`format`, `entity`, `next_step` or `email`)
+ */
+target: string, 
+/**
+ * Entity type that would be written; entity writes are never persisted in a test run
+ */
+entity_definition: string | null, 
+/**
+ * Output produced by the step
+ */
+produced: unknown, };
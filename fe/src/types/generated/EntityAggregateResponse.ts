@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Cached entity aggregate response DTO
+ */
+export type EntityAggregateResponse = { 
+/**
+ * Aggregated entity type
+ */
+entity_type: string, 
+/**
+ * Summed field names
+ */
+sum_fields: Array<string>, 
+/**
+ * Number of live (not deleted) entities
+ */
+entity_count: number, 
+/**
+ * Sum per summed field
+ */
+sums: { [key in string]?: number }, 
+/**
+ * ISO 8601 timestamp of the last full rebuild
+ */
+rebuilt_at: string | null, 
+/**
+ * ISO 8601 timestamp of the last change
+ */
+updated_at: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Query parameters for listing webhook subscriptions
+ */
+export type EntityWebhookListQuery = { 
+/**
+ * Only list subscriptions of this entity type
+ */
+entity_type: string | null, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Webhook subscription response DTO
+ */
+export type EntityWebhookResponse = { 
+/**
+ * Subscription UUID
+ */
+uuid: string, 
+/**
+ * Watched entity type
+ */
+entity_type: string, 
+/**
+ * Delivery URL
+ */
+url: string, 
+/**
+ * Watched field names
+ */
+fields: Array<string>, 
+/**
+ * Whether deliveries are enqueued for this subscription
+ */
+active: boolean, 
+/**
+ * ISO 8601 creation timestamp
+ */
+created_at: string, 
+/**
+ * Creator UUID
+ */
+created_by: string | null, };
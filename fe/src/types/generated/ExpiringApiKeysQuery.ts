@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Query parameters for listing API keys nearing expiry
+ */
+export type ExpiringApiKeysQuery = { 
+/**
+ * Days ahead to look for expiring keys (default: 14, max: 365)
+ */
+within_days: number | null, };
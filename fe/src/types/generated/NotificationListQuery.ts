@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Query parameters for listing the caller's notifications
+ */
+export type NotificationListQuery = { 
+/**
+ * Also list notifications that were already read (default: false)
+ */
+include_read: boolean, };
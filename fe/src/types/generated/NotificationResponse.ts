@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Notification response DTO
+ */
+export type NotificationResponse = { 
+/**
+ * Notification UUID
+ */
+uuid: string, title: string, message: string, 
+/**
+ * Free-form context attached by the sender
+ */
+data: unknown, 
+/**
+ * ISO 8601 timestamp of when the notification was sent
+ */
+sent_at: string | null, 
+/**
+ * ISO 8601 timestamp of when the notification was read, null while unread
+ */
+read_at: string | null, 
+/**
+ * ISO 8601 creation timestamp
+ */
+created_at: string, };
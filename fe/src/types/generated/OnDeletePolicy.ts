@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What happens to entities referencing a parent through a `ManyToOne` field when the parent
+ * is deleted
+ */
+export type OnDeletePolicy = "no_action" | "restrict" | "cascade" | "set_null";
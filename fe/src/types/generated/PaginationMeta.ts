@@ -5,9 +5,13 @@
  */
 export type PaginationMeta = { 
 /**
- * Total number of items available
+ * Total number of items available (`null` if counting was skipped)
  */
-total: number, 
+total: number | null, 
+/**
+ * Whether `total` is an estimate rather than an exact count
+ */
+total_is_estimate: boolean, 
 /**
  * Current page number
  */
@@ -17,9 +21,9 @@ page: number,
  */
 per_page: number, 
 /**
- * Total number of pages
+ * Total number of pages (`null` if counting was skipped)
  */
-total_pages: number, 
+total_pages: number | null, 
 /**
  * If there is a previous page
  */
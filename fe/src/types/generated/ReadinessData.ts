@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ComponentStatus } from "./ComponentStatus";
+
+/**
+ * Readiness check response data
+ */
+export type ReadinessData = { 
+/**
+ * Overall status: `ok` when every configured dependency responded, `degraded` otherwise
+ */
+status: string, 
+/**
+ * `PostgreSQL` connectivity
+ */
+database: ComponentStatus, 
+/**
+ * Redis connectivity (`disabled` without a Redis cache backend)
+ */
+redis: ComponentStatus, };
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnDeletePolicy } from "./OnDeletePolicy";
 
 /**
  * Relation field constraints
@@ -7,4 +8,8 @@ export type RelationConstraints = {
 /**
  * Name of the related entity type
  */
-target_class: string, };
+target_class: string, 
+/**
+ * What happens to referencing entities when the target is deleted (`ManyToOne` only)
+ */
+on_delete: OnDeletePolicy | null, };
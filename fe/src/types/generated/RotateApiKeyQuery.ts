@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Query parameters for rotating an API key
+ */
+export type RotateApiKeyQuery = { 
+/**
+ * Hours the old key keeps working after rotation (default: 24, max: 720)
+ */
+grace_period_hours: number | null, };
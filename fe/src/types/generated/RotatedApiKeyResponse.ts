@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ApiKeyCreatedResponse } from "./ApiKeyCreatedResponse";
+import type { ApiKeyResponse } from "./ApiKeyResponse";
+
+/**
+ * Response when an API key is rotated
+ */
+export type RotatedApiKeyResponse = { 
+/**
+ * The new key, including its value (only shown once)
+ */
+new_key: ApiKeyCreatedResponse, 
+/**
+ * The old key, expiring at the end of the grace period
+ */
+old_key: ApiKeyResponse, };
@@ -3,4 +3,4 @@
 /**
  * String operand variant used by Concat transform
  */
-export type StringOperand = { "kind": "field", field: string, } | { "kind": "const_string", value: string, };
+export type StringOperand = { "kind": "field", field: string, } | { "kind": "const_string", value: string, } | { "kind": "now", format: string | null, } | { "kind": "today", format: string | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * TOTP code request body
+ */
+export type TotpCodeRequest = { 
+/**
+ * Current code from the authenticator app
+ */
+code: string, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Secret for adding the account to an authenticator app
+ */
+export type TotpEnrollmentResponse = { 
+/**
+ * Base32 secret for manual entry
+ */
+secret: string, 
+/**
+ * `otpauth://` URI, usually rendered as a QR code
+ */
+provisioning_uri: string, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Second login step for users with two-factor authentication enabled
+ */
+export type TwoFactorLoginRequest = { 
+/**
+ * Challenge token from the password step
+ */
+challenge_token: string, 
+/**
+ * Current code from the authenticator app
+ */
+code: string, };
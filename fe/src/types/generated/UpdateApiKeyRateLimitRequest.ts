@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to set the public API rate limit of an API key
+ */
+export type UpdateApiKeyRateLimitRequest = { 
+/**
+ * Maximum requests per minute; null removes the limit
+ */
+rate_limit_per_minute: number | null, };
@@ -1,3 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type WorkflowDetail = { uuid: string, name: string, description: string | null, kind: string, enabled: boolean, schedule_cron: string | null, config: unknown, versioning_disabled: boolean, };
+export type WorkflowDetail = { uuid: string, name: string, description: string | null, kind: string, enabled: boolean, schedule_cron: string | null, 
+/**
+ * Scheduled runs are skipped while paused; the schedule is kept
+ */
+paused: boolean, config: unknown, versioning_disabled: boolean, 
+/**
+ * URL notified with a signed payload when a run finishes
+ */
+completion_webhook_url: string | null, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One output produced by a dry run
+ */
+export type WorkflowDryRunOutput = { 
+/**
+ * Output target: `entity`, `format`, `next_step` or `email`
+ */
+target: string, 
+/**
+ * Whether a real run would write `produced` as an entity (nothing is persisted here)
+ */
+would_write_entity: boolean, 
+/**
+ * Entity definition written to, for entity targets
+ */
+entity_definition: string | null, 
+/**
+ * Entity write mode (`create`, `update`, `create_or_update`), for entity targets
+ */
+entity_mode: string | null, 
+/**
+ * Values the step produced
+ */
+produced: unknown, };
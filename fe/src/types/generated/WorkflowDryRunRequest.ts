@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request body for a workflow dry run
+ */
+export type WorkflowDryRunRequest = { 
+/**
+ * Sample input record the workflow is executed on
+ */
+input: unknown, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WorkflowDryRunOutput } from "./WorkflowDryRunOutput";
+
+/**
+ * Dry-run result: the outputs of every executed step
+ */
+export type WorkflowDryRunResponse = { outputs: Array<WorkflowDryRunOutput>, };
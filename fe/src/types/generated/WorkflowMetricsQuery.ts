@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Query parameters for workflow run metrics
+ */
+export type WorkflowMetricsQuery = { 
+/**
+ * Window to aggregate over, in hours or days (e.g. `24h`, `7d`; default `7d`, max `90d`)
+ */
+range: string | null, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Aggregated run metrics of a workflow over a time window
+ */
+export type WorkflowMetricsResponse = { 
+/**
+ * Requested window, e.g. `7d`
+ */
+range: string, total_runs: number, successful_runs: number, failed_runs: number, 
+/**
+ * Successful share of finished runs (0..1); null if no run finished in the window
+ */
+success_rate: number | null, 
+/**
+ * Average run duration in milliseconds; null if no run finished in the window
+ */
+avg_duration_ms: number | null, 
+/**
+ * 95th percentile run duration in milliseconds
+ */
+p95_duration_ms: number | null, total_processed_items: number, total_failed_items: number, };
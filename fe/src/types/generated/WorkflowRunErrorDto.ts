@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Error of a single record that failed during a workflow run
+ */
+export type WorkflowRunErrorDto = { uuid: string, 
+/**
+ * Zero-based position of the record in the staged input of the run
+ */
+record_index: number, error_message: string, 
+/**
+ * The record as it was fed into the pipeline
+ */
+raw_payload: unknown, 
+/**
+ * ISO 8601 timestamp
+ */
+created_at: string, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Progress of a workflow run through its staged items
+ */
+export type WorkflowRunProgressDto = { status: string, 
+/**
+ * Items processed successfully so far
+ */
+processed_count: number, 
+/**
+ * Items that failed so far
+ */
+failed_count: number, 
+/**
+ * Items staged for the run; zero until processing starts
+ */
+total_count: number, };
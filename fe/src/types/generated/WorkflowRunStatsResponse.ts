@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * All-time run statistics of a workflow
+ */
+export type WorkflowRunStatsResponse = { total_runs: number, queued_runs: number, running_runs: number, successful_runs: number, failed_runs: number, cancelled_runs: number, 
+/**
+ * Successful share of finished runs (0..1); null if no run finished yet
+ */
+success_rate: number | null, 
+/**
+ * Runs queued within the last 24 hours
+ */
+runs_last_24h: number, successful_runs_last_24h: number, failed_runs_last_24h: number, 
+/**
+ * Average run duration in milliseconds; null if no run finished yet
+ */
+avg_duration_ms: number | null, 
+/**
+ * 95th percentile run duration in milliseconds
+ */
+p95_duration_ms: number | null, 
+/**
+ * Status of the most recently queued run
+ */
+last_run_status: string | null, 
+/**
+ * When the most recent run was queued
+ */
+last_run_at: string | null, };
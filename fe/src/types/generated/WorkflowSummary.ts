@@ -1,6 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
 export type WorkflowSummary = { uuid: string, name: string, kind: string, enabled: boolean, schedule_cron: string | null, 
+/**
+ * Scheduled runs are skipped while paused; the schedule is kept
+ */
+paused: boolean, 
 /**
  * Indicates if this workflow has a from.api source type (accepts POST, cron disabled)
  */
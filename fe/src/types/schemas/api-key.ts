@@ -9,6 +9,8 @@ export const CreateApiKeyRequestSchema = z.object({
     name: z.string().min(API_KEY_NAME_MIN_LENGTH),
     description: z.string().optional(),
     expires_in_days: z.number().int().positive().optional(),
+    scopes: z.array(z.string()).optional(),
+    allowed_cidrs: z.array(z.string()).optional(),
 })
 
 export const ReassignApiKeyRequestSchema = z.object({
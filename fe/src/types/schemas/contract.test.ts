@@ -173,6 +173,9 @@ describe('Generated type contract tests', () => {
                 created_by: '01923e4a-bbbb-7d8e-9f01-234567890abc',
                 user_uuid: '01923e4a-cccc-7d8e-9f01-234567890abc',
                 published: true,
+                scopes: ['entities:read'],
+                rate_limit_per_minute: 120,
+                allowed_cidrs: ['10.0.0.0/8'],
             })
             expect(fixture.name).toBe('Production Key')
         })
@@ -190,6 +193,9 @@ describe('Generated type contract tests', () => {
                 user_uuid: '01923e4a-cccc-7d8e-9f01-234567890abc',
                 published: true,
                 last_used_at: null,
+                scopes: [],
+                rate_limit_per_minute: null,
+                allowed_cidrs: [],
             })
             expect(fixture.api_key).toBeTruthy()
         })
@@ -199,6 +205,8 @@ describe('Generated type contract tests', () => {
                 name: 'Test Key',
                 description: null,
                 expires_in_days: 365,
+                scopes: ['entities:read', 'workflows:trigger'],
+                allowed_cidrs: ['10.0.0.0/8', '2001:db8::/32'],
             })
             expect(fixture.name).toBe('Test Key')
         })
@@ -307,6 +315,7 @@ describe('Generated type contract tests', () => {
         it('PaginationMeta matches expected API shape', () => {
             const fixture = assertType<PaginationMeta>({
                 total: 100,
+                total_is_estimate: false,
                 page: 1,
                 per_page: 20,
                 total_pages: 5,
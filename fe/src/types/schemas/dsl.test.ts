@@ -536,6 +536,16 @@ describe('DSL Schemas', () => {
             expect(result.success).toBe(true)
         })
 
+        it('DslTransformConcatSchema accepts now/today operands', () => {
+            const result = DslTransformConcatSchema.safeParse({
+                type: 'concat',
+                target: 'imported_at',
+                left: { kind: 'now' },
+                right: { kind: 'today', format: '[day].[month].[year]' },
+            })
+            expect(result.success).toBe(true)
+        })
+
         it('DslTransformBuildPathSchema accepts optional fields', () => {
             const result = DslTransformBuildPathSchema.safeParse({
                 type: 'build_path',
@@ -153,9 +153,19 @@ export const DslStringOperandConstSchema = z.object({
     kind: z.literal('const_string'),
     value: z.string(),
 })
+export const DslStringOperandNowSchema = z.object({
+    kind: z.literal('now'),
+    format: z.string().nullable().optional(),
+})
+export const DslStringOperandTodaySchema = z.object({
+    kind: z.literal('today'),
+    format: z.string().nullable().optional(),
+})
 export const DslStringOperandSchema = z.discriminatedUnion('kind', [
     DslStringOperandFieldSchema,
     DslStringOperandConstSchema,
+    DslStringOperandNowSchema,
+    DslStringOperandTodaySchema,
 ])
 
 export const DslToEmailSchema = z.object({
@@ -187,9 +197,17 @@ export const DslOperandExternalSchema = z.object({
     filter: DslEntityFilterSchema,
     field: z.string(),
 })
+export const DslOperandNowSchema = z.object({
+    kind: z.literal('now'),
+})
+export const DslOperandTodaySchema = z.object({
+    kind: z.literal('today'),
+})
 export const DslOperandSchema = z.discriminatedUnion('kind', [
     DslOperandFieldSchema,
     DslOperandConstSchema,
+    DslOperandNowSchema,
+    DslOperandTodaySchema,
     DslOperandExternalSchema,
 ])
 
@@ -253,6 +271,39 @@ export const DslTransformSendEmailSchema = z.object({
     cc: z.array(DslStringOperandSchema).optional(),
     target_status: z.string(),
 })
+export const DslTransformRoundSchema = z.object({
+    type: z.literal('round'),
+    source: z.string(),
+    target: z.string(),
+    precision: z.number().int().min(0).optional(),
+    mode: z.enum(['half_up', 'half_even', 'floor', 'ceil']).optional(),
+})
+export const DslTransformLookupSchema = z.object({
+    type: z.literal('lookup'),
+    source: z.string(),
+    target: z.string(),
+    table: z.record(z.string(), z.unknown()),
+    default: z.unknown().optional(),
+})
+export const DslTransformCastSchema = z.object({
+    type: z.literal('cast'),
+    source: z.string(),
+    target: z.string(),
+    to: z.enum(['string', 'integer', 'float', 'boolean']),
+    strict: z.boolean().optional(),
+})
+export const DslTransformDateParseSchema = z.object({
+    type: z.literal('date_parse'),
+    source: z.string(),
+    target: z.string(),
+    format: z.string(),
+})
+export const DslTransformDateFormatSchema = z.object({
+    type: z.literal('date_format'),
+    source: z.string(),
+    target: z.string(),
+    format: z.string(),
+})
 export const DslTransformSchema = z.discriminatedUnion('type', [
     DslTransformNoneSchema,
     DslTransformArithmeticSchema,
@@ -262,12 +313,25 @@ export const DslTransformSchema = z.discriminatedUnion('type', [
     DslTransformGetOrCreateEntitySchema,
     DslTransformAuthenticateSchema,
     DslTransformSendEmailSchema,
+    DslTransformRoundSchema,
+    DslTransformLookupSchema,
+    DslTransformCastSchema,
+    DslTransformDateParseSchema,
+    DslTransformDateFormatSchema,
 ])
 
+export const DslStepConditionSchema = z.object({
+    field: z.string(),
+    op: z.enum(['eq', 'ne', 'gt', 'gte', 'lt', 'lte', 'exists', 'not_exists']),
+    value: z.union([z.string(), z.number(), z.boolean()]).nullable().optional(),
+})
+
 export const DslStepSchema = z.object({
     from: DslFromSchema,
     to: DslToSchema,
     transform: DslTransformSchema,
+    when: DslStepConditionSchema.optional(),
+    enabled: z.boolean().optional(),
 })
 
 export const DslValidateRequestSchema = z.object({
@@ -290,6 +354,7 @@ export const DslTypeSpecSchema = z.object({
     fields: z.array(DslFieldSpecSchema),
 })
 
+export type DslStepCondition = z.infer<typeof DslStepConditionSchema>
 export type DslStep = z.infer<typeof DslStepSchema>
 export type DslValidateRequest = z.infer<typeof DslValidateRequestSchema>
 export type DslValidateResponse = z.infer<typeof DslValidateResponseSchema>
@@ -20,9 +20,11 @@ export const FieldDefinitionSchema = z.object({
         'Wysiwyg',
         'Integer',
         'Float',
+        'Money',
         'Boolean',
         'Date',
         'DateTime',
+        'Duration',
         'Object',
         'Array',
         'Uuid',
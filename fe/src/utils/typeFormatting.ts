@@ -8,9 +8,11 @@ export type FieldType =
     | 'Wysiwyg'
     | 'Integer'
     | 'Float'
+    | 'Money'
     | 'Boolean'
     | 'Date'
     | 'DateTime'
+    | 'Duration'
     | 'Object'
     | 'Array'
     | 'Uuid'
@@ -67,12 +69,14 @@ export function formatValueToType(value: unknown, fieldType: FieldType): unknown
 
         case 'Date':
         case 'DateTime':
-            // Keep as string for date/datetime (ISO format expected)
+        case 'Duration':
+            // Keep as string for date/datetime/duration (ISO format expected)
             return typeof value === 'string' ? value : null
 
         case 'Object':
         case 'Array':
         case 'Json':
+        case 'Money':
             // If already an object/array, return as-is
             if (typeof value === 'object') {
                 return value
@@ -0,0 +1,65 @@
+-- Soft delete for dynamic entities.
+-- The marker lives on entities_registry next to the other shared metadata (published, version),
+-- so every entity_<type> table gets it without a per-table column.
+ALTER TABLE entities_registry ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+
+-- (path, entity_key) only has to be unique among live entities so a deleted key can be reused
+ALTER TABLE entities_registry DROP CONSTRAINT IF EXISTS entities_registry_path_entity_key_key;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_entities_registry_path_key_live
+    ON entities_registry (path, entity_key)
+    WHERE deleted_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_entities_registry_live
+    ON entities_registry (entity_type)
+    WHERE deleted_at IS NULL;
+
+-- Keep the original table/view builder and expose deleted_at on top of the view it creates.
+-- Appending a column via CREATE OR REPLACE VIEW keeps the INSTEAD OF triggers attached.
+ALTER FUNCTION create_entity_table_and_view(TEXT) RENAME TO create_entity_table_and_view_base;
+
+CREATE OR REPLACE FUNCTION create_entity_table_and_view(entity_type_param TEXT)
+RETURNS VOID AS $$
+DECLARE
+    entity_table TEXT;
+    entity_view TEXT;
+    column_record RECORD;
+    column_list TEXT := '';
+BEGIN
+    PERFORM create_entity_table_and_view_base(entity_type_param);
+
+    entity_table := 'entity_' || lower(entity_type_param);
+    entity_view := entity_table || '_view';
+
+    -- Same column order as the base view so the replacement only appends deleted_at
+    FOR column_record IN
+        SELECT c.column_name
+        FROM information_schema.columns c
+        WHERE c.table_schema = current_schema()
+          AND c.table_name = entity_table
+          AND c.column_name <> 'uuid'
+        ORDER BY c.ordinal_position
+    LOOP
+        column_list := column_list || ', e.' || quote_ident(column_record.column_name);
+    END LOOP;
+
+    EXECUTE format(
+        'CREATE OR REPLACE VIEW %I AS '
+        'SELECT r.uuid, r.path, r.entity_key, r.parent_uuid, r.created_at, r.updated_at, '
+        'r.created_by, r.updated_by, r.published, r.version%s, r.deleted_at '
+        'FROM entities_registry r LEFT JOIN %I e ON r.uuid = e.uuid '
+        'WHERE r.entity_type = %L',
+        entity_view, column_list, entity_table, entity_type_param
+    );
+END;
+$$ LANGUAGE plpgsql;
+
+-- Rebuild views for existing entity definitions
+DO $$
+DECLARE
+    r RECORD;
+BEGIN
+    FOR r IN SELECT entity_type FROM entity_definitions
+    LOOP
+        PERFORM create_entity_table_and_view(r.entity_type);
+    END LOOP;
+END $$;
@@ -0,0 +1,2 @@
+-- Partial workflow config applied to a single run only (merged over workflows.config at execution)
+ALTER TABLE workflow_runs ADD COLUMN IF NOT EXISTS config_override JSONB;
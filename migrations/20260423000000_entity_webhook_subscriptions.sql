@@ -0,0 +1,14 @@
+-- Per-entity-type webhooks fired when one of the listed fields changes
+CREATE TABLE IF NOT EXISTS entity_webhook_subscriptions (
+    uuid        UUID PRIMARY KEY DEFAULT uuidv7(),
+    entity_type VARCHAR(100) NOT NULL,
+    url         TEXT NOT NULL,
+    fields      TEXT[] NOT NULL,
+    active      BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    created_by  UUID
+);
+
+CREATE INDEX IF NOT EXISTS entity_webhook_subscriptions_entity_type_idx
+    ON entity_webhook_subscriptions (entity_type)
+    WHERE active;
@@ -0,0 +1,2 @@
+-- Paused workflows keep their schedule but are skipped by the scheduler until resumed
+ALTER TABLE workflows ADD COLUMN IF NOT EXISTS paused BOOLEAN NOT NULL DEFAULT false;
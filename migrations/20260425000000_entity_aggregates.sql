@@ -0,0 +1,10 @@
+-- Optional per-entity-type cached count and numeric field sums, maintained on entity writes
+CREATE TABLE IF NOT EXISTS entity_aggregates (
+    entity_type VARCHAR(100) PRIMARY KEY
+        REFERENCES entity_definitions (entity_type) ON DELETE CASCADE ON UPDATE CASCADE,
+    sum_fields   TEXT[] NOT NULL DEFAULT '{}',
+    entity_count BIGINT NOT NULL DEFAULT 0,
+    sums         JSONB NOT NULL DEFAULT '{}'::jsonb,
+    rebuilt_at   TIMESTAMPTZ,
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,12 @@
+-- Per-record errors of workflow runs; a failing record no longer aborts the run
+CREATE TABLE IF NOT EXISTS workflow_run_errors (
+    uuid          UUID PRIMARY KEY DEFAULT uuidv7(),
+    run_uuid      UUID NOT NULL REFERENCES workflow_runs (uuid) ON DELETE CASCADE,
+    record_index  BIGINT NOT NULL,
+    error_message TEXT NOT NULL,
+    raw_payload   JSONB NOT NULL,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_workflow_run_errors_run_index
+    ON workflow_run_errors (run_uuid, record_index);
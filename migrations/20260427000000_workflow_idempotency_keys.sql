@@ -0,0 +1,14 @@
+-- Idempotency keys of the public ingest endpoint, scoped per workflow.
+-- `response` stays NULL while the first request is still staging its run.
+CREATE TABLE IF NOT EXISTS workflow_idempotency_keys (
+    workflow_uuid   UUID NOT NULL REFERENCES workflows (uuid) ON DELETE CASCADE,
+    idempotency_key VARCHAR(255) NOT NULL,
+    run_uuid        UUID REFERENCES workflow_runs (uuid) ON DELETE CASCADE,
+    response        JSONB,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at      TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (workflow_uuid, idempotency_key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_workflow_idempotency_keys_expires_at
+    ON workflow_idempotency_keys (expires_at);
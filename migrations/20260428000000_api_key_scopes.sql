@@ -0,0 +1,2 @@
+-- Public API scopes of API keys; an empty array keeps the key unrestricted
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS scopes TEXT[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,4 @@
+-- Per-key request limit of the public API; NULL means unlimited
+ALTER TABLE api_keys
+    ADD COLUMN IF NOT EXISTS rate_limit_per_minute INTEGER
+        CHECK (rate_limit_per_minute IS NULL OR rate_limit_per_minute > 0);
@@ -0,0 +1,2 @@
+-- Source IP ranges (CIDR) API keys may be used from; an empty array allows any address
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS allowed_cidrs TEXT[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,5 @@
+-- Optional TOTP two-factor authentication for admin users
+ALTER TABLE admin_users
+    ADD COLUMN IF NOT EXISTS totp_secret TEXT,
+    ADD COLUMN IF NOT EXISTS totp_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN IF NOT EXISTS totp_last_used_step BIGINT;
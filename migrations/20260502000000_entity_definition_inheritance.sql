@@ -0,0 +1,103 @@
+-- Entity definitions can inherit the fields of other definitions.
+-- extends lists the parents in precedence order; field_definitions keeps only the definition's
+-- own fields and the effective fields are resolved whenever the entity table is built.
+ALTER TABLE entity_definitions ADD COLUMN IF NOT EXISTS extends UUID[] NOT NULL DEFAULT '{}';
+
+-- Append overrides to fields, dropping earlier fields with the same (case-insensitive) name
+CREATE OR REPLACE FUNCTION entity_definition_merge_fields(fields JSONB, overrides JSONB)
+RETURNS JSONB AS $$
+    SELECT COALESCE(jsonb_agg(merged.field ORDER BY merged.position), '[]'::jsonb)
+    FROM (
+        SELECT f.field, f.position
+        FROM jsonb_array_elements(fields) WITH ORDINALITY AS f(field, position)
+        WHERE lower(f.field->>'name') NOT IN (
+            SELECT lower(o->>'name') FROM jsonb_array_elements(overrides) AS o
+        )
+        UNION ALL
+        SELECT o.field, o.position + jsonb_array_length(fields)
+        FROM jsonb_array_elements(overrides) WITH ORDINALITY AS o(field, position)
+    ) merged
+$$ LANGUAGE sql IMMUTABLE;
+
+-- Effective fields of a definition: the effective fields of each parent in order, then its own.
+-- Mirrors EntityDefinition::resolve_inheritance; cycles are rejected before definitions are
+-- stored and only guarded against here.
+CREATE OR REPLACE FUNCTION entity_definition_fields(
+    definition_uuid UUID,
+    visited UUID[] DEFAULT '{}'
+)
+RETURNS JSONB AS $$
+DECLARE
+    def RECORD;
+    parent_uuid UUID;
+    fields JSONB := '[]'::jsonb;
+BEGIN
+    IF definition_uuid = ANY(visited) THEN
+        RAISE EXCEPTION 'Entity definition inheritance cycle at %', definition_uuid;
+    END IF;
+
+    SELECT field_definitions, extends INTO def
+    FROM entity_definitions
+    WHERE uuid = definition_uuid;
+
+    IF NOT FOUND THEN
+        RETURN fields;
+    END IF;
+
+    FOREACH parent_uuid IN ARRAY def.extends
+    LOOP
+        fields := entity_definition_merge_fields(
+            fields,
+            entity_definition_fields(parent_uuid, visited || definition_uuid)
+        );
+    END LOOP;
+
+    RETURN entity_definition_merge_fields(fields, def.field_definitions);
+END;
+$$ LANGUAGE plpgsql STABLE;
+
+-- Build entity tables from the effective fields. The table/view builder only reads
+-- field_definitions in two places, so its definition is patched instead of repeated here.
+DO $$
+DECLARE
+    source TEXT;
+    patched TEXT;
+BEGIN
+    source := pg_get_functiondef('create_entity_table_and_view_base(text)'::regprocedure);
+    patched := replace(
+        source,
+        'jsonb_array_elements(entity_def.field_definitions)',
+        'jsonb_array_elements(entity_definition_fields(entity_def.uuid))'
+    );
+    IF patched = source THEN
+        RAISE EXCEPTION 'create_entity_table_and_view_base no longer reads entity_def.field_definitions';
+    END IF;
+    EXECUTE patched;
+END $$;
+
+-- Definitions inheriting (directly or transitively) from a changed definition are rebuilt too
+CREATE OR REPLACE FUNCTION entity_view_on_class_change()
+RETURNS TRIGGER AS $$
+DECLARE
+    descendant RECORD;
+BEGIN
+    PERFORM create_entity_table_and_view(NEW.entity_type);
+
+    FOR descendant IN
+        WITH RECURSIVE descendants(uuid, entity_type) AS (
+            SELECT d.uuid, d.entity_type
+            FROM entity_definitions d
+            WHERE NEW.uuid = ANY(d.extends)
+            UNION
+            SELECT d.uuid, d.entity_type
+            FROM entity_definitions d
+            JOIN descendants p ON p.uuid = ANY(d.extends)
+        )
+        SELECT entity_type FROM descendants WHERE uuid <> NEW.uuid
+    LOOP
+        PERFORM create_entity_table_and_view(descendant.entity_type);
+    END LOOP;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
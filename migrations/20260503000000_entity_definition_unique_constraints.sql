@@ -0,0 +1,4 @@
+-- Unique constraints spanning several fields of an entity definition.
+-- Each entry lists field names; the schema sync keeps one unique index per entry.
+ALTER TABLE entity_definitions
+    ADD COLUMN IF NOT EXISTS unique_constraints JSONB NOT NULL DEFAULT '[]'::jsonb;
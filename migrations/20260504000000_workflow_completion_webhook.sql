@@ -0,0 +1,2 @@
+-- Optional URL notified with a signed payload once a run of the workflow finishes
+ALTER TABLE workflows ADD COLUMN IF NOT EXISTS completion_webhook_url TEXT;
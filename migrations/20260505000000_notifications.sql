@@ -0,0 +1,17 @@
+-- In-app notifications addressed to admin users
+CREATE TABLE IF NOT EXISTS notifications (
+    uuid          UUID PRIMARY KEY DEFAULT uuidv7(),
+    user_uuid     UUID NOT NULL REFERENCES admin_users(uuid) ON DELETE CASCADE,
+    title         VARCHAR(255) NOT NULL,
+    message       TEXT NOT NULL,
+    data          JSONB,
+    status        VARCHAR(20) NOT NULL DEFAULT 'pending'
+                  CHECK (status IN ('pending', 'scheduled', 'sent', 'cancelled')),
+    scheduled_for TIMESTAMPTZ,
+    sent_at       TIMESTAMPTZ,
+    read_at       TIMESTAMPTZ,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS notifications_user_uuid_idx
+    ON notifications (user_uuid, created_at DESC);
@@ -0,0 +1,14 @@
+-- E-mail notifications: delivery channel, failure details and retry bookkeeping
+ALTER TABLE notifications
+    ADD COLUMN IF NOT EXISTS notification_type VARCHAR(20) NOT NULL DEFAULT 'in_app'
+        CHECK (notification_type IN ('in_app', 'email')),
+    ADD COLUMN IF NOT EXISTS error_message TEXT,
+    ADD COLUMN IF NOT EXISTS retry_count INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE notifications DROP CONSTRAINT IF EXISTS notifications_status_check;
+ALTER TABLE notifications ADD CONSTRAINT notifications_status_check
+    CHECK (status IN ('pending', 'scheduled', 'sent', 'cancelled', 'failed'));
+
+CREATE INDEX IF NOT EXISTS notifications_email_outstanding_idx
+    ON notifications (created_at)
+    WHERE notification_type = 'email' AND status IN ('pending', 'scheduled', 'failed');
@@ -0,0 +1,2 @@
+-- Accent-insensitive (`ci_ai`) sorting of text fields uses unaccent()
+CREATE EXTENSION IF NOT EXISTS unaccent;
@@ -0,0 +1,17 @@
+-- Audit log of admin mutations with before/after snapshots
+CREATE TYPE audit_action AS ENUM ('create', 'update', 'delete');
+
+CREATE TABLE IF NOT EXISTS audit_log (
+    uuid          UUID PRIMARY KEY DEFAULT uuidv7(),
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    actor_uuid    UUID,
+    action        audit_action NOT NULL,
+    resource_type system_log_resource_type NOT NULL,
+    resource_uuid UUID NOT NULL,
+    before        JSONB,
+    after         JSONB
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_created_at ON audit_log(created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_log_actor_uuid ON audit_log(actor_uuid);
+CREATE INDEX IF NOT EXISTS idx_audit_log_resource ON audit_log(resource_type, resource_uuid);
@@ -0,0 +1,20 @@
+-- Money fields hold `{ "amount": ..., "currency": "<ISO-4217>" }` and are stored as JSONB.
+-- The table/view builder maps field types in one CASE, so its definition is patched instead of
+-- repeated here.
+DO $$
+DECLARE
+    source TEXT;
+    patched TEXT;
+BEGIN
+    source := pg_get_functiondef('create_entity_table_and_view_base(text)'::regprocedure);
+    patched := replace(
+        source,
+        $old$WHEN 'Json' THEN sql_type := 'JSONB';$old$,
+        $new$WHEN 'Json' THEN sql_type := 'JSONB';
+            WHEN 'Money' THEN sql_type := 'JSONB';$new$
+    );
+    IF patched = source THEN
+        RAISE EXCEPTION 'create_entity_table_and_view_base no longer maps the Json field type';
+    END IF;
+    EXECUTE patched;
+END $$;
@@ -0,0 +1,19 @@
+-- Duration fields hold ISO-8601 durations (`PT1H30M`) and are stored as INTERVAL, so they can
+-- be compared and summed in SQL. The table/view builder's CASE is patched like for Money.
+DO $$
+DECLARE
+    source TEXT;
+    patched TEXT;
+BEGIN
+    source := pg_get_functiondef('create_entity_table_and_view_base(text)'::regprocedure);
+    patched := replace(
+        source,
+        $old$WHEN 'Date' THEN sql_type := 'DATE';$old$,
+        $new$WHEN 'Date' THEN sql_type := 'DATE';
+            WHEN 'Duration' THEN sql_type := 'INTERVAL';$new$
+    );
+    IF patched = source THEN
+        RAISE EXCEPTION 'create_entity_table_and_view_base no longer maps the Date field type';
+    END IF;
+    EXECUTE patched;
+END $$;
@@ -0,0 +1,4 @@
+-- Entity-level validation rules of an entity definition.
+-- Each entry is { name, expression, message }, e.g. "end_date >= start_date".
+ALTER TABLE entity_definitions
+    ADD COLUMN IF NOT EXISTS validation_rules JSONB NOT NULL DEFAULT '[]'::jsonb;
@@ -0,0 +1,49 @@
+-- Entity definitions can name the table holding their entities, e.g. to map onto a legacy
+-- table. Unset keeps the derived entity_<type> name; the view stays entity_<type>_view.
+ALTER TABLE entity_definitions ADD COLUMN IF NOT EXISTS table_name TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_entity_definitions_table_name
+    ON entity_definitions (lower(table_name))
+    WHERE table_name IS NOT NULL;
+
+-- Table of an entity type, mirroring EntityDefinition::get_table_name
+CREATE OR REPLACE FUNCTION entity_table_name(entity_type_param TEXT)
+RETURNS TEXT AS $$
+    SELECT COALESCE(
+        (SELECT d.table_name FROM entity_definitions d WHERE d.entity_type = entity_type_param),
+        'entity_' || lower(entity_type_param)
+    )
+$$ LANGUAGE sql STABLE;
+
+-- Point the table/view builder and its soft-delete wrapper at the mapped table
+DO $$
+DECLARE
+    source TEXT;
+    patched TEXT;
+BEGIN
+    source := pg_get_functiondef('create_entity_table_and_view_base(text)'::regprocedure);
+    patched := replace(
+        source,
+        $old$table_name := 'entity_' || lower(entity_type_param);
+    view_name := table_name || '_view';$old$,
+        $new$table_name := entity_table_name(entity_type_param);
+    view_name := 'entity_' || lower(entity_type_param) || '_view';$new$
+    );
+    IF patched = source THEN
+        RAISE EXCEPTION 'create_entity_table_and_view_base no longer derives its table name';
+    END IF;
+    EXECUTE patched;
+
+    source := pg_get_functiondef('create_entity_table_and_view(text)'::regprocedure);
+    patched := replace(
+        source,
+        $old$entity_table := 'entity_' || lower(entity_type_param);
+    entity_view := entity_table || '_view';$old$,
+        $new$entity_table := entity_table_name(entity_type_param);
+    entity_view := 'entity_' || lower(entity_type_param) || '_view';$new$
+    );
+    IF patched = source THEN
+        RAISE EXCEPTION 'create_entity_table_and_view no longer derives its table name';
+    END IF;
+    EXECUTE patched;
+END $$;
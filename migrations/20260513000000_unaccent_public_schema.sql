@@ -0,0 +1,4 @@
+-- Keep unaccent() on the search path of every schema by moving it into public.
+-- Installs created by the earlier migration may have placed it in another schema.
+CREATE EXTENSION IF NOT EXISTS unaccent WITH SCHEMA public;
+ALTER EXTENSION unaccent SET SCHEMA public;
@@ -0,0 +1,6 @@
+-- Whether the entity table was created for the definition. A definition mapped onto a
+-- table that already existed must not drop it when deleted.
+ALTER TABLE entity_definitions ADD COLUMN IF NOT EXISTS owns_table BOOLEAN NOT NULL DEFAULT TRUE;
+
+-- Mapped tables may predate their definition, so keep every existing one on delete
+UPDATE entity_definitions SET owns_table = FALSE WHERE table_name IS NOT NULL;
@@ -6,17 +6,20 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use log::info;
-use sqlx::postgres::PgPoolOptions;
 use sqlx::PgPool;
+use std::io::Write;
 use std::sync::Arc;
 
 use r_data_core_api::ApiState;
+use r_data_core_core::admin_user::PasswordPolicy;
 use r_data_core_core::cache::CacheManager;
 use r_data_core_core::config::AppConfig;
+use r_data_core_core::request_id::current_request_id;
 use r_data_core_core::settings::OutboxSettings;
 use r_data_core_persistence::{
-    AdminUserRepository, ApiKeyRepository, DashboardStatsRepository, DynamicEntityRepository,
-    EmailTemplateRepository, EntityDefinitionRepository, OutboxRepository, PasswordResetRepository,
+    AdminUserRepository, ApiKeyRepository, AuditLogRepository, DashboardStatsRepository, Database,
+    DynamicEntityRepository, EmailTemplateRepository, EntityAggregateRepository,
+    EntityDefinitionRepository, EntityWebhookRepository, OutboxRepository, PasswordResetRepository,
     SystemLogRepository, WorkflowRepository,
 };
 use r_data_core_services::adapters::{
@@ -25,9 +28,10 @@ use r_data_core_services::adapters::{
 };
 use r_data_core_services::workflow::outbox::OutboxRetryPolicy;
 use r_data_core_services::{
-    AdminUserService, ApiKeyService, DashboardStatsService, DynamicEntityService,
-    EntityDefinitionService, LicenseService, MailService, PasswordResetService, RoleService,
-    SettingsService, SystemLogService, WorkflowRepositoryAdapter, WorkflowService,
+    AdminUserService, ApiKeyService, AuditLogService, DashboardStatsService, DynamicEntityService,
+    EntityAggregateService, EntityDefinitionService, EntityWebhookService, LicenseService,
+    MailService, PasswordResetService, RoleService, RunEventBus, SettingsService, SystemLogService,
+    WorkflowRepositoryAdapter, WorkflowService,
 };
 use r_data_core_workflow::data::job_queue::apalis_redis::ApalisRedisQueue;
 
@@ -35,9 +39,21 @@ use r_data_core_workflow::data::job_queue::apalis_redis::ApalisRedisQueue;
 pub fn init_logger(log_level: &str) {
     let env = env_logger::Env::new().default_filter_or(log_level);
     env_logger::Builder::from_env(env)
-        .format_timestamp(Some(env_logger::fmt::TimestampPrecision::Millis))
-        .format_module_path(true)
-        .format_target(true)
+        .format(|buf, record| {
+            let level_style = buf.default_level_style(record.level());
+            write!(
+                buf,
+                "[{} {level_style}{:<5}{level_style:#} {}",
+                buf.timestamp_millis(),
+                record.level(),
+                record.target()
+            )?;
+            // Requests handled by the API carry their request ID into every log line
+            if let Some(request_id) = current_request_id() {
+                write!(buf, " request_id={request_id}")?;
+            }
+            writeln!(buf, "] {}", record.args())
+        })
         .init();
 }
 
@@ -47,8 +63,9 @@ pub fn init_logger(log_level: &str) {
 /// Returns an error if the database connection fails
 pub async fn create_db_pool(config: &AppConfig) -> r_data_core_core::error::Result<PgPool> {
     info!("Connecting to database...");
-    PgPoolOptions::new()
-        .max_connections(config.database.max_connections)
+    config
+        .database
+        .pool_options()
         .connect(&config.database.connection_string)
         .await
         .map_err(|e| {
@@ -58,6 +75,35 @@ pub async fn create_db_pool(config: &AppConfig) -> r_data_core_core::error::Resu
         })
 }
 
+/// Create the primary pool and a pool per configured read replica
+///
+/// # Errors
+/// Returns an error if a database connection fails
+pub async fn create_database(config: &AppConfig) -> r_data_core_core::error::Result<Database> {
+    let primary = create_db_pool(config).await?;
+    let mut replicas = Vec::with_capacity(config.database.read_replica_urls.len());
+    for url in &config.database.read_replica_urls {
+        let replica = config
+            .database
+            .pool_options()
+            .connect(url)
+            .await
+            .map_err(|e| {
+                r_data_core_core::error::Error::Config(format!(
+                    "Failed to create read replica connection pool: {e}"
+                ))
+            })?;
+        replicas.push(replica);
+    }
+    if !replicas.is_empty() {
+        info!(
+            "Routing read-only entity queries to {} read replica(s)",
+            replicas.len()
+        );
+    }
+    Ok(Database::new(primary).with_read_replicas(replicas))
+}
+
 /// Initialise the cache manager with Redis backend
 ///
 /// # Errors
@@ -92,6 +138,19 @@ pub async fn verify_license_on_startup(config: &AppConfig, cache_manager: Arc<Ca
     license_service.verify_license_on_startup("core").await;
 }
 
+/// Preload all published entity definitions into the cache if `CACHE_WARM_ON_STARTUP` is set
+///
+/// A failure only logs a warning; the definitions are then cached on first use as usual.
+pub async fn warm_caches(config: &AppConfig, state: &ApiState) {
+    if !config.cache.warm_on_startup {
+        return;
+    }
+    match state.entity_definition_service.warm_cache().await {
+        Ok(count) => log::info!("Warmed cache with {count} entity definitions"),
+        Err(e) => log::warn!("Failed to warm entity definition cache: {e}"),
+    }
+}
+
 /// Initialise the Redis queue client for workflows
 ///
 /// # Errors
@@ -119,27 +178,33 @@ pub async fn create_queue_client(
 /// Build the complete API state with all services initialised
 ///
 /// # Errors
-/// Returns an error if queue initialisation fails
+/// Returns an error if the password policy or queue initialisation fails
 ///
 /// # Panics
 /// Does not panic under normal conditions
 pub async fn build_api_state(
     config: &AppConfig,
-    pool: PgPool,
+    database: Database,
     cache_manager: Arc<CacheManager>,
 ) -> r_data_core_core::error::Result<ApiState> {
+    let pool = database.primary().clone();
+
     // Create repositories
     let pool_arc = Arc::new(pool.clone());
     let api_key_repository = ApiKeyRepository::new(pool_arc.clone());
     let admin_user_repository = AdminUserRepository::new(pool_arc);
     let entity_definition_repository = EntityDefinitionRepository::new(pool.clone());
     let dynamic_entity_repository =
-        DynamicEntityRepository::with_cache(pool.clone(), cache_manager.clone());
+        DynamicEntityRepository::with_cache(pool.clone(), cache_manager.clone())
+            .with_database(database);
 
     // Create services with adapters
     // Initialise system log service (created early so it can be injected into other services)
     let system_log_repository = SystemLogRepository::new(pool.clone());
     let system_log_service = Arc::new(SystemLogService::new(Arc::new(system_log_repository)));
+    let audit_log_service = Arc::new(AuditLogService::new(Arc::new(AuditLogRepository::new(
+        pool.clone(),
+    ))));
 
     let api_key_adapter = ApiKeyRepositoryAdapter::new(api_key_repository);
     let api_key_service = ApiKeyService::with_cache(
@@ -147,24 +212,44 @@ pub async fn build_api_state(
         cache_manager.clone(),
         config.cache.api_key_ttl,
     )
-    .with_system_log(system_log_service.clone());
+    .with_system_log(system_log_service.clone())
+    .with_audit_log(audit_log_service.clone())
+    .with_pool(pool.clone());
+
+    let password_policy = Arc::new(PasswordPolicy::from_config(config.password_policy.clone())?);
 
     let admin_user_adapter = AdminUserRepositoryAdapter::new(admin_user_repository);
     let admin_user_service = AdminUserService::new(Arc::new(admin_user_adapter))
-        .with_system_log(system_log_service.clone());
+        .with_system_log(system_log_service.clone())
+        .with_audit_log(audit_log_service.clone())
+        .with_password_policy(password_policy.clone());
 
     let entity_definition_adapter =
         EntityDefinitionRepositoryAdapter::new(entity_definition_repository);
     let entity_definition_service =
         EntityDefinitionService::new(Arc::new(entity_definition_adapter), cache_manager.clone())
-            .with_system_log(system_log_service.clone());
+            .with_system_log(system_log_service.clone())
+            .with_audit_log(audit_log_service.clone());
 
     let dynamic_entity_adapter =
         DynamicEntityRepositoryAdapter::from_repository(dynamic_entity_repository);
-    let dynamic_entity_service = DynamicEntityService::new(
+    let mut dynamic_entity_service = DynamicEntityService::new(
         Arc::new(dynamic_entity_adapter),
         Arc::new(entity_definition_service.clone()),
-    );
+    )
+    .with_missing_entity_cache(cache_manager.clone(), config.cache.entity_miss_ttl)
+    .with_change_hook(Arc::new(EntityAggregateService::new(Arc::new(
+        EntityAggregateRepository::new(pool.clone()),
+    ))));
+    // Webhook deliveries go through the outbox, so they are only recorded when it is enabled
+    if config.outbox_enabled {
+        let entity_webhook_service = EntityWebhookService::new(
+            Arc::new(EntityWebhookRepository::new(pool.clone())),
+            Arc::new(OutboxRepository::new(pool.clone())),
+        );
+        dynamic_entity_service =
+            dynamic_entity_service.with_change_hook(Arc::new(entity_webhook_service));
+    }
 
     // Initialise queue client
     let queue_client = create_queue_client(config).await?;
@@ -175,6 +260,7 @@ pub async fn build_api_state(
         cache_manager.clone(),
         queue_client.clone(),
         system_log_service.clone(),
+        audit_log_service.clone(),
     );
 
     let role_service = RoleService::new(
@@ -182,7 +268,8 @@ pub async fn build_api_state(
         cache_manager.clone(),
         Some(config.cache.entity_definition_ttl),
     )
-    .with_system_log(system_log_service.clone());
+    .with_system_log(system_log_service.clone())
+    .with_audit_log(audit_log_service);
 
     let dashboard_stats_repository = DashboardStatsRepository::new(pool.clone());
     let dashboard_stats_service = DashboardStatsService::new(Arc::new(dashboard_stats_repository));
@@ -191,7 +278,8 @@ pub async fn build_api_state(
     let license_service = LicenseService::new(config.license.clone(), cache_manager.clone());
 
     // Initialise password reset service if system mail is configured
-    let password_reset_service = build_password_reset_service(config, &pool, queue_client.clone());
+    let password_reset_service = build_password_reset_service(config, &pool, queue_client.clone())
+        .map(|service| service.with_password_policy(password_policy));
 
     Ok(ApiState {
         db_pool: pool,
@@ -217,6 +305,7 @@ fn build_workflow_service(
     cache_manager: Arc<CacheManager>,
     queue_client: Arc<ApalisRedisQueue>,
     system_log_service: Arc<SystemLogService>,
+    audit_log_service: Arc<AuditLogService>,
 ) -> WorkflowService {
     let workflow_repo = WorkflowRepository::new(pool.clone());
     let workflow_adapter = WorkflowRepositoryAdapter::new(workflow_repo);
@@ -248,7 +337,10 @@ fn build_workflow_service(
         .with_settings_service(settings_service)
         .with_queue(Some(queue_client))
         .with_mail_service(workflow_mail_service)
-        .with_system_log(system_log_service);
+        .with_system_log(system_log_service)
+        .with_audit_log(audit_log_service)
+        .with_run_events(RunEventBus::new())
+        .with_ingest_limits(config.workflow_ingest);
 
     if config.outbox_enabled {
         let outbox_repo = OutboxRepository::new(pool.clone());
@@ -1,6 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 pub mod bootstrap;
+pub mod state_builder;
 
 pub use r_data_core_core::utils;
 pub use r_data_core_core::versioning;
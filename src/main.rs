@@ -1,13 +1,14 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use actix_cors::Cors;
-use actix_web::middleware::Logger;
+use actix_web::middleware::{Condition, Logger};
 use actix_web::{web, App, HttpServer};
 use log::{debug, info};
 
 use r_data_core::bootstrap::{
-    build_api_state, create_cache_manager, create_db_pool, init_logger, verify_license_on_startup,
+    create_cache_manager, create_database, init_logger, verify_license_on_startup, warm_caches,
 };
+use r_data_core::state_builder::ApiStateBuilder;
 use r_data_core_api::{ApiResponse, ApiStateWrapper};
 use r_data_core_core::config::load_app_config;
 use r_data_core_persistence::OutboxRepository;
@@ -35,14 +36,14 @@ async fn main() -> r_data_core_core::error::Result<()> {
     info!("Log level: {}", config.log.level);
     info!("API docs enabled: {}", config.api.enable_docs);
 
-    // Create database pool
-    let pool = create_db_pool(&config).await.map_err(|e| {
+    // Create database pools
+    let database = create_database(&config).await.map_err(|e| {
         r_data_core_core::error::Error::Config(format!(
             "Failed to create database connection pool: {e}"
         ))
     })?;
     if config.outbox_enabled {
-        OutboxRepository::ensure_table_exists(&pool).await?;
+        OutboxRepository::ensure_table_exists(database.primary()).await?;
     }
 
     info!("Using SQLx migrations (run with 'cargo sqlx migrate run')");
@@ -58,16 +59,22 @@ async fn main() -> r_data_core_core::error::Result<()> {
     verify_license_on_startup(&config, cache_manager.clone()).await;
 
     // Build API state with all services
-    let api_state = build_api_state(&config, pool, cache_manager)
+    let api_state = ApiStateBuilder::new(&config)
+        .database(database)
+        .cache_manager(cache_manager)
+        .build()
         .await
         .map_err(|e| {
             r_data_core_core::error::Error::Config(format!("Failed to initialize API state: {e}"))
         })?;
+    warm_caches(&config, &api_state).await;
 
     let app_state = web::Data::new(ApiStateWrapper::new(api_state));
 
     let bind_address = format!("{}:{}", config.api.host, config.api.port);
     let bind_address_clone = bind_address.clone();
+    let shutdown_timeout_secs = config.shutdown_timeout_secs;
+    let compression_min_size = config.api.compression_min_size;
     info!("Starting HTTP server at http://{bind_address}");
 
     // Start HTTP server
@@ -89,8 +96,16 @@ async fn main() -> r_data_core_core::error::Result<()> {
         App::new()
             .app_data(app_state.clone())
             .wrap(r_data_core_api::middleware::create_error_handlers())
+            .wrap(r_data_core_api::middleware::RequestMetrics)
+            .wrap(Condition::new(
+                compression_min_size.is_some(),
+                r_data_core_api::middleware::ResponseCompression::new(
+                    compression_min_size.unwrap_or_default(),
+                ),
+            ))
             .wrap(Logger::new("%a %{User-Agent}i %r %s %D"))
             .wrap(cors)
+            .wrap(r_data_core_api::middleware::RequestIdPropagation)
             .configure(move |cfg| r_data_core_api::configure_app_with_options(cfg, &api_config))
             .default_service(web::route().to(default_404_handler))
     })
@@ -100,6 +115,8 @@ async fn main() -> r_data_core_core::error::Result<()> {
             "Failed to bind to address {bind_address_clone}: {e}"
         ))
     })?
+    // Stop accepting connections on SIGTERM/SIGINT and let in-flight requests finish
+    .shutdown_timeout(shutdown_timeout_secs)
     .run()
     .await
     .map_err(|e| r_data_core_core::error::Error::Api(format!("HTTP server error: {e}")))
@@ -0,0 +1,134 @@
+//! Validating builder for the API state
+//!
+//! Collects the dependencies `build_api_state` needs and checks them up front, so a
+//! misconfigured deployment fails at startup with one error naming every problem instead of
+//! panicking or failing later on the first request.
+
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use r_data_core_api::ApiState;
+use r_data_core_core::cache::CacheManager;
+use r_data_core_core::config::AppConfig;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_persistence::Database;
+
+use crate::bootstrap::build_api_state;
+
+/// Minimum accepted length of the JWT signing secret, in bytes
+pub const MIN_JWT_SECRET_LENGTH: usize = 16;
+
+/// Builder for [`ApiState`] that validates required dependencies before wiring services
+pub struct ApiStateBuilder<'a> {
+    config: &'a AppConfig,
+    database: Option<Database>,
+    cache_manager: Option<Arc<CacheManager>>,
+}
+
+impl<'a> ApiStateBuilder<'a> {
+    /// Start a builder for the given application configuration
+    #[must_use]
+    pub const fn new(config: &'a AppConfig) -> Self {
+        Self {
+            config,
+            database: None,
+            cache_manager: None,
+        }
+    }
+
+    /// Set the database connection pool, used for reads and writes
+    #[must_use]
+    pub fn pool(mut self, pool: PgPool) -> Self {
+        self.database = Some(Database::new(pool));
+        self
+    }
+
+    /// Set the database pools, with read replicas for read-only entity queries
+    #[must_use]
+    pub fn database(mut self, database: Database) -> Self {
+        self.database = Some(database);
+        self
+    }
+
+    /// Set the initialised cache manager
+    #[must_use]
+    pub fn cache_manager(mut self, cache_manager: Arc<CacheManager>) -> Self {
+        self.cache_manager = Some(cache_manager);
+        self
+    }
+
+    /// Check all dependencies and return every problem found
+    pub async fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        match &self.database {
+            None => problems.push("database pool is not configured".to_string()),
+            Some(database) => {
+                if let Err(e) = sqlx::query("SELECT 1").execute(database.primary()).await {
+                    problems.push(format!("database is not reachable: {e}"));
+                }
+            }
+        }
+        if self.cache_manager.is_none() {
+            problems.push("cache manager is not initialized".to_string());
+        }
+        if let Some(problem) = jwt_secret_problem(&self.config.api.jwt_secret) {
+            problems.push(problem);
+        }
+        if let Some(path) = &self.config.password_policy.breached_passwords_file {
+            if std::fs::metadata(path).is_err() {
+                problems.push(format!(
+                    "breached password list {path} is not readable (check PASSWORD_BREACHED_LIST_PATH)"
+                ));
+            }
+        }
+        problems
+    }
+
+    /// Validate the dependencies without building the state
+    ///
+    /// # Errors
+    /// Returns a configuration error listing every missing or invalid dependency
+    pub async fn validate(&self) -> Result<()> {
+        let problems = self.problems().await;
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Config(format!(
+                "Invalid API state configuration: {}",
+                problems.join("; ")
+            )))
+        }
+    }
+
+    /// Validate the dependencies and build the API state
+    ///
+    /// # Errors
+    /// Returns a configuration error listing every missing or invalid dependency, or an error
+    /// if service initialisation fails
+    pub async fn build(self) -> Result<ApiState> {
+        self.validate().await?;
+        let (Some(database), Some(cache_manager)) = (self.database, self.cache_manager) else {
+            return Err(Error::Config(
+                "Invalid API state configuration: missing dependencies".to_string(),
+            ));
+        };
+        build_api_state(self.config, database, cache_manager).await
+    }
+}
+
+/// Describe why a JWT secret is unusable, if it is
+#[must_use]
+pub fn jwt_secret_problem(secret: &str) -> Option<String> {
+    if secret.trim().is_empty() {
+        Some("JWT secret is missing (set JWT_SECRET)".to_string())
+    } else if secret.len() < MIN_JWT_SECRET_LENGTH {
+        Some(format!(
+            "JWT secret is too short ({} bytes, at least {MIN_JWT_SECRET_LENGTH} required)",
+            secret.len()
+        ))
+    } else {
+        None
+    }
+}
@@ -6,6 +6,7 @@ use r_data_core_workflow::data::adapters::destination::uri::UriDestination;
 use r_data_core_workflow::data::adapters::destination::{
     DataDestination, DestinationContext, HttpMethod,
 };
+use r_data_core_workflow::data::adapters::http::HttpRetryLog;
 use serde_json::json;
 
 #[test]
@@ -58,6 +59,7 @@ async fn test_uri_destination_with_auth() {
         auth: Some(auth_provider),
         method: Some(HttpMethod::Post),
         config: json!({"uri": "https://example.com/api"}),
+        retries: HttpRetryLog::default(),
     };
 
     let data = Bytes::from("test data");
@@ -73,6 +75,7 @@ async fn test_uri_destination_without_auth() {
         auth: None,
         method: Some(HttpMethod::Post),
         config: json!({"uri": "https://example.com/api"}),
+        retries: HttpRetryLog::default(),
     };
 
     let data = Bytes::from("test data");
@@ -87,6 +90,7 @@ async fn test_uri_destination_default_method() {
         auth: None,
         method: None, // Should default to Post
         config: json!({"uri": "https://example.com/api"}),
+        retries: HttpRetryLog::default(),
     };
 
     let data = Bytes::from("test data");
@@ -101,6 +105,7 @@ async fn test_uri_destination_get_method() {
         auth: None,
         method: Some(HttpMethod::Get),
         config: json!({"uri": "https://example.com/api"}),
+        retries: HttpRetryLog::default(),
     };
 
     let data = Bytes::from("test data");
@@ -122,9 +127,74 @@ async fn test_uri_destination_with_basic_auth() {
         auth: Some(auth_provider),
         method: Some(HttpMethod::Post),
         config: json!({"uri": "https://example.com/api"}),
+        retries: HttpRetryLog::default(),
     };
 
     let data = Bytes::from("test data");
     let result = dest.push(&ctx, data).await;
     assert!(result.is_err() || result.is_ok());
 }
+
+#[tokio::test]
+async fn test_uri_destination_retries_rate_limit_and_server_errors() {
+    use super::source::flaky_server;
+    use std::sync::atomic::Ordering;
+
+    let (url, hits) = flaky_server(
+        vec!["429 Too Many Requests\r\nRetry-After: 0", "502 Bad Gateway"],
+        "",
+    )
+    .await;
+    let ctx = DestinationContext {
+        auth: None,
+        method: Some(HttpMethod::Post),
+        config: json!({ "uri": format!("{url}/push"), "max_retries": 2, "backoff_ms": 10 }),
+        retries: HttpRetryLog::default(),
+    };
+
+    UriDestination::new()
+        .push(&ctx, Bytes::from("payload"))
+        .await
+        .expect("push should succeed after retries");
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+
+    let retries = ctx.retries.take();
+    assert_eq!(
+        retries
+            .iter()
+            .map(|retry| (retry.attempt, retry.status, retry.delay_ms))
+            .collect::<Vec<_>>(),
+        // Retry-After replaces the backoff
+        vec![(1, Some(429), 0), (2, Some(502), 20)]
+    );
+}
+
+#[tokio::test]
+async fn test_uri_destination_gives_up_after_max_retries() {
+    use super::source::flaky_server;
+    use std::sync::atomic::Ordering;
+
+    let (url, hits) = flaky_server(
+        vec![
+            "500 Internal Server Error",
+            "500 Internal Server Error",
+            "500 Internal Server Error",
+        ],
+        "",
+    )
+    .await;
+    let ctx = DestinationContext {
+        auth: None,
+        method: Some(HttpMethod::Post),
+        config: json!({ "uri": format!("{url}/push"), "max_retries": 1, "backoff_ms": 10 }),
+        retries: HttpRetryLog::default(),
+    };
+
+    let error = UriDestination::new()
+        .push(&ctx, Bytes::from("payload"))
+        .await
+        .expect_err("push should fail");
+    assert!(error.to_string().contains("500"));
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+    assert_eq!(ctx.retries.take().len(), 1);
+}
@@ -1,9 +1,67 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use r_data_core_workflow::data::adapters::auth::{create_auth_provider, AuthConfig};
+use r_data_core_workflow::data::adapters::format::json::JsonFormatHandler;
+use r_data_core_workflow::data::adapters::format::FormatHandler;
+use r_data_core_workflow::data::adapters::http::HttpRetryLog;
 use r_data_core_workflow::data::adapters::source::uri::UriSource;
 use r_data_core_workflow::data::adapters::source::{DataSource, SourceContext};
 use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Start an HTTP server answering with each of `failures` (status line and extra header lines)
+/// in turn, then `200 OK` with `body`; returns its base URL and the number of requests served
+///
+/// # Panics
+/// Panics if the server socket cannot be bound
+pub async fn flaky_server(
+    failures: Vec<&'static str>,
+    body: &'static str,
+) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let url = format!("http://{}", listener.local_addr().expect("local address"));
+    let hits = Arc::new(AtomicUsize::new(0));
+    let served = hits.clone();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            // Read the request head and body before answering
+            let mut request = Vec::new();
+            let mut buf = [0_u8; 4096];
+            while let Ok(read) = socket.read(&mut buf).await {
+                request.extend_from_slice(&buf[..read]);
+                let text = String::from_utf8_lossy(&request).to_lowercase();
+                if let Some(head_end) = text.find("\r\n\r\n") {
+                    let length = text
+                        .lines()
+                        .find_map(|line| line.strip_prefix("content-length:"))
+                        .and_then(|value| value.trim().parse::<usize>().ok())
+                        .unwrap_or(0);
+                    if read == 0 || request.len() >= head_end + 4 + length {
+                        break;
+                    }
+                }
+                if read == 0 {
+                    break;
+                }
+            }
+
+            let attempt = served.fetch_add(1, Ordering::SeqCst);
+            let (status, body) = failures
+                .get(attempt)
+                .map_or(("200 OK", body), |failure| (*failure, ""));
+            let response = format!(
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+            let _ = socket.shutdown().await;
+        }
+    });
+    (url, hits)
+}
 
 #[tokio::test]
 async fn test_uri_source_validate() {
@@ -59,6 +117,7 @@ async fn test_uri_source_with_auth() {
     let ctx = SourceContext {
         auth: Some(auth_provider),
         config: json!({ "uri": server.url("/data") }),
+        retries: HttpRetryLog::default(),
     };
 
     // Perform fetch against mock server
@@ -90,6 +149,7 @@ async fn test_uri_source_without_auth() {
     let ctx = SourceContext {
         auth: None,
         config: json!({ "uri": server.url("/data") }),
+        retries: HttpRetryLog::default(),
     };
 
     // Should fetch from local server quickly
@@ -126,6 +186,7 @@ async fn test_uri_source_with_basic_auth() {
     let ctx = SourceContext {
         auth: Some(auth_provider),
         config: json!({ "uri": server.url("/data") }),
+        retries: HttpRetryLog::default(),
     };
 
     let mut stream = source.fetch(&ctx).await.expect("fetch should succeed");
@@ -134,3 +195,220 @@ async fn test_uri_source_with_basic_auth() {
 
     mock.assert_async().await;
 }
+
+#[tokio::test]
+async fn test_uri_source_page_param_pagination() {
+    use futures::StreamExt;
+    use httpmock::{Method::GET, MockServer};
+
+    let server = MockServer::start_async().await;
+    let mut pages = Vec::new();
+    for (page, records) in [
+        ("1", json!({ "data": [{ "id": 1 }, { "id": 2 }] })),
+        ("2", json!({ "data": [{ "id": 3 }, { "id": 4 }] })),
+        ("3", json!({ "data": [{ "id": 5 }] })),
+        ("4", json!({ "data": [] })),
+    ] {
+        pages.push(
+            server
+                .mock_async(|when, then| {
+                    when.method(GET)
+                        .path("/items")
+                        .query_param("page", page)
+                        .query_param("per_page", "2")
+                        .query_param("status", "active");
+                    then.status(200).json_body(records);
+                })
+                .await,
+        );
+    }
+
+    let source = UriSource::new();
+    let ctx = SourceContext {
+        auth: None,
+        config: json!({
+            "uri": server.url("/items?status=active"),
+            "pagination": {
+                "page_param": "page",
+                "page_size_param": "per_page",
+                "page_size": 2,
+                "records_pointer": "/data"
+            }
+        }),
+        retries: HttpRetryLog::default(),
+    };
+
+    let mut stream = source.fetch(&ctx).await.expect("fetch should succeed");
+    let mut body = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        body.extend_from_slice(&chunk.unwrap());
+    }
+    let records = JsonFormatHandler::new()
+        .parse(&body, &json!({}))
+        .expect("pages parse as json");
+    let ids: Vec<i64> = records.iter().filter_map(|r| r["id"].as_i64()).collect();
+    assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+
+    for page in pages {
+        page.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_uri_source_next_link_pagination() {
+    use futures::StreamExt;
+    use httpmock::{Method::GET, MockServer};
+
+    let server = MockServer::start_async().await;
+    let first = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/items");
+            then.status(200)
+                .json_body(json!({ "items": [{ "id": 1 }], "next": "/items/2" }));
+        })
+        .await;
+    let second = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/items/2");
+            then.status(200).json_body(
+                json!({ "items": [{ "id": 2 }, { "id": 3 }], "next": server.url("/items/3") }),
+            );
+        })
+        .await;
+    let third = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/items/3");
+            then.status(200)
+                .json_body(json!({ "items": [{ "id": 4 }], "next": null }));
+        })
+        .await;
+
+    let source = UriSource::new();
+    let ctx = SourceContext {
+        auth: None,
+        config: json!({
+            "uri": server.url("/items"),
+            "pagination": { "next_pointer": "/next", "records_pointer": "/items" }
+        }),
+        retries: HttpRetryLog::default(),
+    };
+
+    let mut stream = source.fetch(&ctx).await.expect("fetch should succeed");
+    let mut body = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        body.extend_from_slice(&chunk.unwrap());
+    }
+    let records = JsonFormatHandler::new()
+        .parse(&body, &json!({}))
+        .expect("pages parse as json");
+    let ids: Vec<i64> = records.iter().filter_map(|r| r["id"].as_i64()).collect();
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+
+    first.assert_async().await;
+    second.assert_async().await;
+    third.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_uri_source_pagination_max_pages() {
+    use httpmock::{Method::GET, MockServer};
+
+    // Every page links to itself
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/items");
+            then.status(200)
+                .json_body(json!({ "items": [{ "id": 1 }], "next": "/items" }));
+        })
+        .await;
+
+    let source = UriSource::new();
+    let ctx = SourceContext {
+        auth: None,
+        config: json!({
+            "uri": server.url("/items"),
+            "pagination": { "next_pointer": "/next", "records_pointer": "/items", "max_pages": 3 }
+        }),
+        retries: HttpRetryLog::default(),
+    };
+
+    let Err(error) = source.fetch(&ctx).await else {
+        panic!("fetch should stop at max_pages");
+    };
+    assert!(error.to_string().contains("more than 3 pages"));
+    mock.assert_calls_async(3).await;
+}
+
+#[tokio::test]
+async fn test_uri_source_retries_transient_errors() {
+    use futures::StreamExt;
+
+    let (url, hits) = flaky_server(
+        vec!["503 Service Unavailable", "503 Service Unavailable"],
+        "ok",
+    )
+    .await;
+    let ctx = SourceContext {
+        auth: None,
+        config: json!({ "uri": format!("{url}/data"), "max_retries": 3, "backoff_ms": 10 }),
+        retries: HttpRetryLog::default(),
+    };
+
+    let mut stream = UriSource::new()
+        .fetch(&ctx)
+        .await
+        .expect("fetch should succeed after retries");
+    let first = stream.next().await.expect("one chunk expected").unwrap();
+    assert_eq!(&first[..], b"ok");
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+
+    let retries = ctx.retries.take();
+    assert_eq!(
+        retries
+            .iter()
+            .map(|retry| (retry.attempt, retry.status, retry.delay_ms))
+            .collect::<Vec<_>>(),
+        vec![(1, Some(503), 10), (2, Some(503), 20)]
+    );
+}
+
+#[tokio::test]
+async fn test_uri_source_does_not_retry_client_errors() {
+    let (url, hits) = flaky_server(vec!["404 Not Found"], "ok").await;
+    let ctx = SourceContext {
+        auth: None,
+        config: json!({ "uri": format!("{url}/data"), "max_retries": 3, "backoff_ms": 10 }),
+        retries: HttpRetryLog::default(),
+    };
+
+    assert!(UriSource::new().fetch(&ctx).await.is_err());
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert!(ctx.retries.take().is_empty());
+
+    // Without max_retries a transient error fails right away
+    let (url, hits) = flaky_server(vec!["503 Service Unavailable"], "ok").await;
+    let ctx = SourceContext {
+        auth: None,
+        config: json!({ "uri": format!("{url}/data") }),
+        retries: HttpRetryLog::default(),
+    };
+    assert!(UriSource::new().fetch(&ctx).await.is_err());
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_uri_source_validate_retry_config() {
+    let source = UriSource::new();
+    let config = json!({
+        "uri": "https://example.com/data",
+        "timeout_secs": 10,
+        "max_retries": 3,
+        "backoff_ms": 250
+    });
+    assert!(source.validate(&config).is_ok());
+    let config = json!({ "uri": "https://example.com/data", "timeout_secs": 0 });
+    assert!(source.validate(&config).is_err());
+    let config = json!({ "uri": "https://example.com/data", "max_retries": -1 });
+    assert!(source.validate(&config).is_err());
+}
@@ -0,0 +1,134 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for revoking admin access tokens on logout and when revoking all sessions
+
+use crate::api::workflows::common::create_test_api_state;
+use actix_web::{test, web, App};
+use r_data_core_api::{configure_app, ApiStateWrapper};
+use r_data_core_test_support::{create_test_admin_user, setup_test_db};
+use serde_json::{json, Value};
+
+/// Log in through the admin API, returning the access and refresh tokens
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn login<S, B>(app: &S, username: &str) -> (String, String)
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    B: actix_web::body::MessageBody,
+{
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/auth/login")
+        .set_json(json!({ "username": username, "password": "adminadmin" }))
+        .to_request();
+    let resp = test::call_service(app, req).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    (
+        body["data"]["access_token"]
+            .as_str()
+            .expect("access token")
+            .to_string(),
+        body["data"]["refresh_token"]
+            .as_str()
+            .expect("refresh token")
+            .to_string(),
+    )
+}
+
+fn permissions_request(access_token: &str) -> actix_http::Request {
+    test::TestRequest::get()
+        .uri("/admin/api/v1/auth/permissions")
+        .insert_header(("Authorization", format!("Bearer {access_token}")))
+        .to_request()
+}
+
+async fn test_username(pool: &r_data_core_test_support::TestDatabase) -> anyhow::Result<String> {
+    let user_uuid = create_test_admin_user(pool).await?;
+    Ok(
+        sqlx::query_scalar("SELECT username FROM admin_users WHERE uuid = $1")
+            .bind(user_uuid)
+            .fetch_one(&pool.pool)
+            .await?,
+    )
+}
+
+#[actix_web::test]
+async fn test_logged_out_access_token_is_rejected() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let username = test_username(&pool).await?;
+    let api_state = create_test_api_state(&pool, "test_secret").await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+
+    let (access_token, refresh_token) = login(&app, &username).await;
+    let (other_access_token, _) = login(&app, &username).await;
+
+    let resp = test::call_service(&app, permissions_request(&access_token)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/auth/logout")
+        .insert_header(("Authorization", format!("Bearer {access_token}")))
+        .set_json(json!({ "refresh_token": refresh_token }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    let resp = test::call_service(&app, permissions_request(&access_token)).await;
+    assert_eq!(resp.status().as_u16(), 401);
+
+    // Another session of the same user is unaffected
+    let resp = test::call_service(&app, permissions_request(&other_access_token)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_revoke_all_rejects_every_earlier_access_token() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let username = test_username(&pool).await?;
+    let other_username = test_username(&pool).await?;
+    let api_state = create_test_api_state(&pool, "test_secret").await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+
+    let (access_token, _) = login(&app, &username).await;
+    let (second_access_token, _) = login(&app, &username).await;
+    let (unrelated_access_token, _) = login(&app, &other_username).await;
+
+    // Tokens issued within the same second as the revocation are kept
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/auth/revoke-all")
+        .insert_header(("Authorization", format!("Bearer {access_token}")))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    let resp = test::call_service(&app, permissions_request(&access_token)).await;
+    assert_eq!(resp.status().as_u16(), 401);
+    let resp = test::call_service(&app, permissions_request(&second_access_token)).await;
+    assert_eq!(resp.status().as_u16(), 401);
+    let resp = test::call_service(&app, permissions_request(&unrelated_access_token)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    // Logging in again after revoking works
+    let (new_access_token, _) = login(&app, &username).await;
+    let resp = test::call_service(&app, permissions_request(&new_access_token)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ mod tests {
     use actix_web::{http::StatusCode, test, web, App};
     use r_data_core_api::{configure_app, ApiState, ApiStateWrapper};
     use r_data_core_core::cache::CacheManager;
-    use r_data_core_core::config::{CacheConfig, LicenseConfig};
+    use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
     use r_data_core_core::system_log::{SystemLogResourceType, SystemLogType};
     use r_data_core_persistence::{
         AdminUserRepository, AdminUserRepositoryTrait, ApiKeyRepository, CreateAdminUserParams,
@@ -101,6 +101,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -125,6 +129,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: check_default_password,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: RoleService::new(pool.pool.clone(), cache_manager.clone(), Some(0)),
             cache_manager: cache_manager.clone(),
@@ -314,6 +322,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -341,6 +353,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: false,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: RoleService::new(pool.pool.clone(), cache_manager.clone(), Some(0)),
             cache_manager: cache_manager.clone(),
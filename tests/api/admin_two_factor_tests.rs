@@ -0,0 +1,168 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for TOTP two-factor authentication of admin users
+
+use crate::api::workflows::common::create_test_api_state;
+use actix_web::{test, web, App};
+use r_data_core_api::{configure_app, ApiStateWrapper};
+use r_data_core_core::admin_user::totp;
+use r_data_core_test_support::{create_test_admin_user, setup_test_db};
+use serde_json::{json, Value};
+use time::OffsetDateTime;
+
+fn current_code(secret: &str, step_offset: i64) -> String {
+    totp::code_at(
+        secret,
+        totp::step_at(OffsetDateTime::now_utc()) + step_offset,
+    )
+    .expect("valid secret")
+}
+
+#[actix_web::test]
+async fn test_two_factor_enrollment_and_login() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    let username: String = sqlx::query_scalar("SELECT username FROM admin_users WHERE uuid = $1")
+        .bind(user_uuid)
+        .fetch_one(&pool.pool)
+        .await?;
+    let api_state = create_test_api_state(&pool, "test_secret").await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+
+    let login = || {
+        test::TestRequest::post()
+            .uri("/admin/api/v1/auth/login")
+            .set_json(json!({ "username": username, "password": "adminadmin" }))
+            .to_request()
+    };
+
+    // Without two-factor authentication the login issues tokens directly
+    let resp = test::call_service(&app, login()).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let token = body["data"]["access_token"]
+        .as_str()
+        .expect("access token")
+        .to_string();
+
+    let authed_post = |uri: &str, body: Value| {
+        test::TestRequest::post()
+            .uri(uri)
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .set_json(body)
+            .to_request()
+    };
+
+    let resp = test::call_service(
+        &app,
+        authed_post("/admin/api/v1/auth/2fa/enroll", json!({})),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let secret = body["data"]["secret"].as_str().expect("secret").to_string();
+    assert!(body["data"]["provisioning_uri"]
+        .as_str()
+        .is_some_and(|uri| uri.starts_with("otpauth://totp/") && uri.contains(&secret)));
+
+    let resp = test::call_service(
+        &app,
+        authed_post(
+            "/admin/api/v1/auth/2fa/verify",
+            json!({ "code": "000000x" }),
+        ),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 422);
+
+    let resp = test::call_service(
+        &app,
+        authed_post(
+            "/admin/api/v1/auth/2fa/verify",
+            json!({ "code": current_code(&secret, 0) }),
+        ),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    // Enrolling again is refused while two-factor authentication is enabled
+    let resp = test::call_service(
+        &app,
+        authed_post("/admin/api/v1/auth/2fa/enroll", json!({})),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 409);
+
+    // The password alone now only yields a challenge
+    let resp = test::call_service(&app, login()).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["two_factor_required"], true);
+    assert!(body["data"]["access_token"].is_null());
+    let challenge = body["data"]["challenge_token"]
+        .as_str()
+        .expect("challenge token")
+        .to_string();
+
+    let two_factor_login = |challenge: &str, code: &str| {
+        test::TestRequest::post()
+            .uri("/admin/api/v1/auth/2fa/login")
+            .set_json(json!({ "challenge_token": challenge, "code": code }))
+            .to_request()
+    };
+
+    // The challenge is not an access token
+    let req = test::TestRequest::get()
+        .uri("/admin/api/v1/api-keys")
+        .insert_header(("Authorization", format!("Bearer {challenge}")))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 401);
+
+    // Codes outside the accepted window are rejected
+    let resp = test::call_service(
+        &app,
+        two_factor_login(&challenge, &current_code(&secret, -3)),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 401);
+
+    // The code used to confirm enrollment (or any earlier one) cannot be replayed, so use the next step
+    let code = current_code(&secret, 1);
+    let resp = test::call_service(&app, two_factor_login(&challenge, &code)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert!(body["data"]["access_token"].as_str().is_some());
+    assert!(body["data"]["refresh_token"].as_str().is_some());
+
+    let resp = test::call_service(&app, two_factor_login(&challenge, &code)).await;
+    assert_eq!(resp.status().as_u16(), 401);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_two_factor_login_rejects_invalid_challenge() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let api_state = create_test_api_state(&pool, "test_secret").await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/auth/2fa/login")
+        .set_json(json!({ "challenge_token": "not-a-token", "code": "123456" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 401);
+
+    Ok(())
+}
@@ -10,7 +10,7 @@ use r_data_core_api::{
 };
 use r_data_core_core::admin_jwt::AuthUserClaims;
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_core::error::Result;
 use r_data_core_persistence::ApiKeyRepositoryTrait;
 use r_data_core_persistence::{AdminUserRepository, ApiKeyRepository};
@@ -57,6 +57,7 @@ fn create_test_jwt_token(user_uuid: &Uuid, secret: &str) -> String {
     let claims = AuthUserClaims {
         sub: user_uuid.to_string(),
         iss: r_data_core_core::admin_jwt::ADMIN_JWT_ISSUER.to_string(),
+        jti: String::new(),
         name: "test_user".to_string(),
         email: "test@example.com".to_string(),
         permissions,
@@ -95,11 +96,11 @@ mod tests {
 
         // Create some API keys
         let (key1_uuid, key1_value) = repo
-            .create_new_api_key("Key 1", "First key", user_uuid, 30)
+            .create_new_api_key("Key 1", "First key", user_uuid, 30, &[], &[])
             .await?;
 
         let (key2_uuid, _key2_value) = repo
-            .create_new_api_key("Key 2", "Second key", user_uuid, 30)
+            .create_new_api_key("Key 2", "Second key", user_uuid, 30, &[], &[])
             .await?;
 
         // Create test app
@@ -112,6 +113,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -137,6 +142,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -215,7 +224,7 @@ mod tests {
 
         // Create API key
         let (key_uuid, key_value) = repo
-            .create_new_api_key("Test Key", "Test description", user_uuid, 30)
+            .create_new_api_key("Test Key", "Test description", user_uuid, 30, &[], &[])
             .await?;
 
         // Verify the key exists and is valid
@@ -232,6 +241,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -257,6 +270,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -359,7 +376,7 @@ mod tests {
 
         // Create an API key
         let (_key_uuid, key_value) = repo
-            .create_new_api_key("Test Key", "Test description", user_uuid, 30)
+            .create_new_api_key("Test Key", "Test description", user_uuid, 30, &[], &[])
             .await?;
 
         // Create the test app
@@ -372,6 +389,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -393,6 +414,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -468,7 +493,7 @@ mod tests {
 
         // Create API key with very short expiration (1 second)
         let (key_uuid, key_value) = repo
-            .create_new_api_key("Expired Key", "Test description", user_uuid, 1) // 1 day expiration
+            .create_new_api_key("Expired Key", "Test description", user_uuid, 1, &[], &[]) // 1 day expiration
             .await?;
 
         // Manually expire the key by setting expires_at to the past
@@ -489,6 +514,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -510,6 +539,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -585,7 +618,7 @@ mod tests {
 
         // Create API key
         let (key_uuid, key_value) = repo
-            .create_new_api_key("Test Key", "Test description", user_uuid, 30)
+            .create_new_api_key("Test Key", "Test description", user_uuid, 30, &[], &[])
             .await?;
 
         // Get initial key info
@@ -613,6 +646,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -634,6 +671,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -713,19 +754,19 @@ mod tests {
 
         // Test empty name validation
         let result = repo
-            .create_new_api_key("", "Test description", user_uuid, 30)
+            .create_new_api_key("", "Test description", user_uuid, 30, &[], &[])
             .await;
         assert!(result.is_err());
 
         // Test negative expiration validation
         let result = repo
-            .create_new_api_key("Test Key", "Test description", user_uuid, -5)
+            .create_new_api_key("Test Key", "Test description", user_uuid, -5, &[], &[])
             .await;
         assert!(result.is_err());
 
         // Test valid creation
         let result = repo
-            .create_new_api_key("Valid Key", "Valid description", user_uuid, 30)
+            .create_new_api_key("Valid Key", "Valid description", user_uuid, 30, &[], &[])
             .await;
         assert!(result.is_ok());
 
@@ -747,7 +788,7 @@ mod tests {
 
         // Create API key for user1
         let (key_uuid, _key_value) = repo
-            .create_new_api_key("Test Key", "Test description", user1_uuid, 30)
+            .create_new_api_key("Test Key", "Test description", user1_uuid, 30, &[], &[])
             .await?;
 
         // Verify initial ownership
@@ -778,7 +819,7 @@ mod tests {
 
         // Create API key
         let (_key_uuid, key_value) = repo
-            .create_new_api_key("Test Key", "Test description", user_uuid, 30)
+            .create_new_api_key("Test Key", "Test description", user_uuid, 30, &[], &[])
             .await?;
 
         // Create test app
@@ -791,6 +832,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -812,6 +857,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -898,6 +947,8 @@ mod tests {
                 &format!("Description {i}"),
                 user_uuid,
                 30,
+                &[],
+                &[],
             )
             .await?;
         }
@@ -955,6 +1006,8 @@ mod tests {
                 &format!("Description {i}"),
                 user_uuid,
                 30,
+                &[],
+                &[],
             )
             .await?;
         }
@@ -969,6 +1022,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -990,6 +1047,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
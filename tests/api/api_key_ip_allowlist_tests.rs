@@ -0,0 +1,161 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for restricting API keys to source IP ranges
+
+use crate::api::workflows::common::{create_test_api_state, setup_app_with_entities};
+use actix_web::{test, web, App};
+use r_data_core_api::{configure_app, ApiStateWrapper};
+use r_data_core_persistence::{ApiKeyRepository, ApiKeyRepositoryTrait};
+use r_data_core_test_support::{create_test_admin_user, setup_test_db};
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+async fn create_restricted_key(
+    pool: &sqlx::PgPool,
+    user_uuid: Uuid,
+    cidrs: &[&str],
+) -> anyhow::Result<String> {
+    let cidrs: Vec<String> = cidrs.iter().map(ToString::to_string).collect();
+    let repo = ApiKeyRepository::new(Arc::new(pool.clone()));
+    let (_uuid, key_value) = repo
+        .create_new_api_key(
+            "restricted-key",
+            "IP restricted key",
+            user_uuid,
+            30,
+            &[],
+            &cidrs,
+        )
+        .await?;
+    Ok(key_value)
+}
+
+fn entities_request(key: &str, peer: &str) -> actix_http::Request {
+    test::TestRequest::get()
+        .uri("/api/v1/entities")
+        .insert_header(("X-API-Key", key))
+        .peer_addr(peer.parse::<SocketAddr>().expect("socket address"))
+        .to_request()
+}
+
+#[actix_web::test]
+async fn test_restricted_key_allows_ip_in_range() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let user_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+    let key =
+        create_restricted_key(&pool.pool, user_uuid, &["10.0.0.0/8", "2001:db8::/32"]).await?;
+
+    let resp = test::call_service(&app, entities_request(&key, "10.1.2.3:40000")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let resp = test::call_service(&app, entities_request(&key, "[2001:db8::7]:40000")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_restricted_key_rejects_ip_out_of_range() -> anyhow::Result<()> {
+    let (app, pool, _token, unrestricted_key) = setup_app_with_entities().await?;
+    let user_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+    let key = create_restricted_key(&pool.pool, user_uuid, &["10.0.0.0/8"]).await?;
+
+    let resp = test::call_service(&app, entities_request(&key, "192.168.1.1:40000")).await;
+    assert_eq!(resp.status().as_u16(), 403);
+
+    // Forwarding headers are ignored unless proxies are trusted
+    let req = test::TestRequest::get()
+        .uri("/api/v1/entities")
+        .insert_header(("X-API-Key", key.as_str()))
+        .insert_header(("X-Forwarded-For", "10.1.2.3"))
+        .peer_addr("192.168.1.1:40000".parse()?)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 403);
+
+    // Keys without ranges are not affected
+    let resp = test::call_service(
+        &app,
+        entities_request(&unrestricted_key, "192.168.1.1:40000"),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_restricted_key_uses_forwarded_ip_behind_trusted_proxy() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    let key = create_restricted_key(&pool.pool, user_uuid, &["10.0.0.0/8"]).await?;
+    let mut api_state = create_test_api_state(&pool, "test_secret").await;
+    api_state.api_config.trusted_proxies = vec!["172.16.0.0/12".to_string()];
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+
+    let forwarded = |client: &str, peer: &str| {
+        test::TestRequest::get()
+            .uri("/api/v1/entities")
+            .insert_header(("X-API-Key", key.as_str()))
+            .insert_header(("X-Forwarded-For", client))
+            .peer_addr(peer.parse().expect("socket address"))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, forwarded("10.1.2.3", "172.16.0.1:40000")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let resp = test::call_service(&app, forwarded("192.168.1.1", "172.16.0.1:40000")).await;
+    assert_eq!(resp.status().as_u16(), 403);
+
+    // Hops appended by trusted proxies are skipped
+    let resp =
+        test::call_service(&app, forwarded("10.1.2.3, 172.16.0.9", "172.16.0.1:40000")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    // A forged leading entry is ignored: the proxy appended the real client address
+    let resp =
+        test::call_service(&app, forwarded("10.1.2.3, 192.168.1.1", "172.16.0.1:40000")).await;
+    assert_eq!(resp.status().as_u16(), 403);
+
+    // Requests that do not come through a trusted proxy cannot forward an address
+    let resp = test::call_service(&app, forwarded("10.1.2.3", "192.168.1.1:40000")).await;
+    assert_eq!(resp.status().as_u16(), 403);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_create_api_key_rejects_invalid_cidr() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+
+    let create = |name: &str, cidrs: Value| {
+        test::TestRequest::post()
+            .uri("/admin/api/v1/api-keys")
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .set_json(json!({ "name": name, "allowed_cidrs": cidrs }))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, create("bad-cidr", json!(["10.0.0.0/33"]))).await;
+    assert_eq!(resp.status().as_u16(), 422);
+    let resp = test::call_service(&app, create("bad-cidr", json!(["not-an-ip"]))).await;
+    assert_eq!(resp.status().as_u16(), 422);
+
+    let resp = test::call_service(&app, create("good-cidr", json!(["10.0.0.0/8"]))).await;
+    assert_eq!(resp.status().as_u16(), 201);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["allowed_cidrs"], json!(["10.0.0.0/8"]));
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for per-key and per-IP rate limiting of the public API
+
+use crate::api::workflows::common::{create_test_api_state, setup_app_with_entities};
+use actix_web::{http::header::RETRY_AFTER, test, web, App, HttpResponse};
+use r_data_core_api::middleware::ApiKeyRateLimit;
+use r_data_core_api::ApiStateWrapper;
+use r_data_core_persistence::{ApiKeyRepository, ApiKeyRepositoryTrait};
+use r_data_core_test_support::{create_test_admin_user, setup_test_db};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Create an API key limited to `limit` requests per window
+async fn create_limited_key(
+    pool: &sqlx::PgPool,
+    user_uuid: Uuid,
+    limit: i32,
+) -> anyhow::Result<(Uuid, String)> {
+    let repo = ApiKeyRepository::new(Arc::new(pool.clone()));
+    let (uuid, key_value) = repo
+        .create_new_api_key("limited-key", "Rate limited key", user_uuid, 30, &[], &[])
+        .await?;
+    repo.update_rate_limit(uuid, Some(limit)).await?;
+    Ok((uuid, key_value))
+}
+
+#[actix_web::test]
+async fn test_rate_limited_key_gets_429_when_exhausted() -> anyhow::Result<()> {
+    let (app, pool, _token, unlimited_key) = setup_app_with_entities().await?;
+    let user_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+    let (_uuid, key) = create_limited_key(&pool.pool, user_uuid, 3).await?;
+
+    for _ in 0..3 {
+        let req = test::TestRequest::get()
+            .uri("/api/v1/entities")
+            .insert_header(("X-API-Key", key.clone()))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().as_u16(), 200);
+    }
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/entities")
+        .insert_header(("X-API-Key", key))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 429);
+    let retry_after: u64 = resp
+        .headers()
+        .get(RETRY_AFTER)
+        .expect("Retry-After header")
+        .to_str()?
+        .parse()?;
+    assert!((1..=60).contains(&retry_after), "Retry-After {retry_after}");
+
+    // Keys without a limit are not affected
+    let req = test::TestRequest::get()
+        .uri("/api/v1/entities")
+        .insert_header(("X-API-Key", unlimited_key))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_rate_limited_key_recovers_after_window() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    let (_uuid, key) = create_limited_key(&pool.pool, user_uuid, 2).await?;
+    let api_state = create_test_api_state(&pool, "test_secret").await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .service(
+                web::scope("")
+                    .wrap(ApiKeyRateLimit::with_window(1))
+                    .route("/ping", web::get().to(HttpResponse::Ok)),
+            ),
+    )
+    .await;
+    let ping = || {
+        test::TestRequest::get()
+            .uri("/ping")
+            .insert_header(("X-API-Key", key.clone()))
+            .to_request()
+    };
+
+    assert_eq!(
+        test::call_service(&app, ping()).await.status().as_u16(),
+        200
+    );
+    assert_eq!(
+        test::call_service(&app, ping()).await.status().as_u16(),
+        200
+    );
+    let resp = test::call_service(&app, ping()).await;
+    assert_eq!(resp.status().as_u16(), 429);
+    assert_eq!(resp.headers().get(RETRY_AFTER).unwrap(), "1");
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert_eq!(
+        test::call_service(&app, ping()).await.status().as_u16(),
+        200
+    );
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_ip_rate_limit_applies_to_every_caller() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let mut api_state = create_test_api_state(&pool, "test_secret").await;
+    api_state.api_config.ip_rate_limit_per_minute = Some(2);
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .service(
+                web::scope("")
+                    .wrap(ApiKeyRateLimit::default())
+                    .route("/ping", web::get().to(HttpResponse::Ok)),
+            ),
+    )
+    .await;
+    let ping = |peer: &str| {
+        test::TestRequest::get()
+            .uri("/ping")
+            .peer_addr(peer.parse().expect("socket address"))
+            .to_request()
+    };
+
+    for _ in 0..2 {
+        let resp = test::call_service(&app, ping("10.1.2.3:4000")).await;
+        assert_eq!(resp.status().as_u16(), 200);
+    }
+    // Unauthenticated requests are limited too, whatever port they come from
+    let resp = test::call_service(&app, ping("10.1.2.3:4001")).await;
+    assert_eq!(resp.status().as_u16(), 429);
+    let retry_after: u64 = resp
+        .headers()
+        .get(RETRY_AFTER)
+        .expect("Retry-After header")
+        .to_str()?
+        .parse()?;
+    assert!((1..=30).contains(&retry_after), "Retry-After {retry_after}");
+
+    // Other clients have their own bucket
+    let resp = test::call_service(&app, ping("10.9.8.7:4000")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_update_api_key_rate_limit() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    // Created through the API so the key belongs to the token's user
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/api-keys")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "name": "to-limit" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 201);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["rate_limit_per_minute"], Value::Null);
+    let key_uuid: Uuid = body["data"]["uuid"].as_str().expect("uuid").parse()?;
+    let repo = ApiKeyRepository::new(Arc::new(pool.pool.clone()));
+
+    let update = |limit: Value| {
+        test::TestRequest::put()
+            .uri(&format!("/admin/api/v1/api-keys/{key_uuid}/rate-limit"))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .set_json(json!({ "rate_limit_per_minute": limit }))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, update(json!(0))).await;
+    assert_eq!(resp.status().as_u16(), 422);
+
+    let resp = test::call_service(&app, update(json!(120))).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let key = repo.get_by_uuid(key_uuid).await?.expect("key");
+    assert_eq!(key.rate_limit_per_minute, Some(120));
+
+    let resp = test::call_service(&app, update(Value::Null)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let key = repo.get_by_uuid(key_uuid).await?.expect("key");
+    assert_eq!(key.rate_limit_per_minute, None);
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for listing expiring API keys and rotating keys with a grace period
+
+use crate::api::workflows::common::setup_app_with_entities;
+use actix_web::test;
+use serde_json::{json, Value};
+use time::OffsetDateTime;
+
+/// Create a key through the admin API, returning its UUID and value
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn create_key<S, B>(
+    app: &S,
+    token: &str,
+    name: &str,
+    expires_in_days: i64,
+) -> anyhow::Result<(String, String)>
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    B: actix_web::body::MessageBody,
+{
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/api-keys")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "name": name, "expires_in_days": expires_in_days }))
+        .to_request();
+    let resp = test::call_service(app, req).await;
+    assert_eq!(resp.status().as_u16(), 201);
+    let body: Value = test::read_body_json(resp).await;
+    Ok((
+        body["data"]["uuid"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        body["data"]["api_key"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+    ))
+}
+
+fn entities_request(key: &str) -> actix_http::Request {
+    test::TestRequest::get()
+        .uri("/api/v1/entities")
+        .insert_header(("X-API-Key", key))
+        .to_request()
+}
+
+#[actix_web::test]
+async fn test_list_expiring_api_keys() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+    create_key(&app, &token, "expires-soon", 3).await?;
+    create_key(&app, &token, "expires-later", 90).await?;
+    create_key(&app, &token, "never-expires", 0).await?;
+
+    let list = |within_days: i64| {
+        test::TestRequest::get()
+            .uri(&format!(
+                "/admin/api/v1/api-keys/expiring?within_days={within_days}"
+            ))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, list(7)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let names: Vec<&str> = body["data"]
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|key| key["name"].as_str())
+        .collect();
+    assert!(names.contains(&"expires-soon"));
+    assert!(!names.contains(&"expires-later"));
+    assert!(!names.contains(&"never-expires"));
+
+    let resp = test::call_service(&app, list(0)).await;
+    assert_eq!(resp.status().as_u16(), 422);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_rotate_api_key_keeps_old_key_valid_during_grace_period() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+    let (old_uuid, old_key) = create_key(&app, &token, "rotating", 30).await?;
+
+    let rotate = |uuid: &str, grace_period_hours: i64| {
+        test::TestRequest::post()
+            .uri(&format!(
+                "/admin/api/v1/api-keys/{uuid}/rotate?grace_period_hours={grace_period_hours}"
+            ))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, rotate(&old_uuid, 1)).await;
+    assert_eq!(resp.status().as_u16(), 201);
+    let body: Value = test::read_body_json(resp).await;
+    let new_uuid = body["data"]["new_key"]["uuid"]
+        .as_str()
+        .expect("uuid")
+        .to_string();
+    let new_key = body["data"]["new_key"]["api_key"]
+        .as_str()
+        .expect("key")
+        .to_string();
+    assert_ne!(new_uuid, old_uuid);
+    assert_eq!(body["data"]["new_key"]["name"], "rotating");
+    assert_eq!(body["data"]["old_key"]["uuid"], old_uuid.as_str());
+    let old_expires_at = OffsetDateTime::parse(
+        body["data"]["old_key"]["expires_at"]
+            .as_str()
+            .expect("expires_at"),
+        &time::format_description::well_known::Rfc3339,
+    )?;
+    assert!(old_expires_at <= OffsetDateTime::now_utc() + time::Duration::hours(1));
+
+    // Both keys work during the grace window
+    let resp = test::call_service(&app, entities_request(&old_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let resp = test::call_service(&app, entities_request(&new_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    // Without a grace period the rotated key stops working immediately
+    let resp = test::call_service(&app, rotate(&new_uuid, 0)).await;
+    assert_eq!(resp.status().as_u16(), 201);
+    let body: Value = test::read_body_json(resp).await;
+    let newest_key = body["data"]["new_key"]["api_key"]
+        .as_str()
+        .expect("key")
+        .to_string();
+    let resp = test::call_service(&app, entities_request(&new_key)).await;
+    assert_eq!(resp.status().as_u16(), 401);
+    let resp = test::call_service(&app, entities_request(&newest_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    // Expired keys cannot be rotated again
+    let resp = test::call_service(&app, rotate(&new_uuid, 1)).await;
+    assert_eq!(resp.status().as_u16(), 422);
+
+    Ok(())
+}
@@ -27,7 +27,7 @@ mod tests {
 
         // Create a test API key
         let (key_uuid, key_value) = repo
-            .create_new_api_key("TestKey", "Test key for JWT test", user_uuid, 30)
+            .create_new_api_key("TestKey", "Test key for JWT test", user_uuid, 30, &[], &[])
             .await?;
 
         // Get the API key to check its initial state
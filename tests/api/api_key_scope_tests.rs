@@ -0,0 +1,107 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for API key scopes restricting access to the public API
+
+use crate::api::workflows::common::{generate_entity_type, setup_app_with_entities};
+use actix_web::test;
+use r_data_core_persistence::{ApiKeyRepository, ApiKeyRepositoryTrait};
+use r_data_core_test_support::create_test_entity_definition;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+async fn create_scoped_key(pool: &sqlx::PgPool, scopes: &[&str]) -> anyhow::Result<String> {
+    let user_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(pool)
+        .await?;
+    let scopes: Vec<String> = scopes.iter().map(ToString::to_string).collect();
+    let repo = ApiKeyRepository::new(Arc::new(pool.clone()));
+    let (_uuid, key_value) = repo
+        .create_new_api_key("scoped-key", "Scoped test key", user_uuid, 30, &scopes, &[])
+        .await?;
+    Ok(key_value)
+}
+
+#[actix_web::test]
+async fn test_scoped_key_allows_routes_within_scope() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("scoped_read");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+    let key = create_scoped_key(&pool.pool, &["entities:read"]).await?;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/v1/{entity_type}"))
+        .insert_header(("X-API-Key", key))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_scoped_key_denies_routes_outside_scope() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("scoped_write");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+    let key = create_scoped_key(&pool.pool, &["entities:read"]).await?;
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/v1/{entity_type}"))
+        .insert_header(("X-API-Key", key))
+        .set_json(json!({ "name": "Denied", "email": "denied@example.com" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 403);
+    let body: Value = test::read_body_json(resp).await;
+    assert!(body["message"]
+        .as_str()
+        .is_some_and(|message| message.contains("entities:write")));
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_unscoped_key_keeps_full_access() -> anyhow::Result<()> {
+    let (app, pool, _token, key) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("unscoped");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/v1/{entity_type}"))
+        .insert_header(("X-API-Key", key))
+        .set_json(json!({ "name": "Allowed", "email": "allowed@example.com" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_ne!(resp.status().as_u16(), 403);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_create_api_key_rejects_unknown_scope() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/api-keys")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "name": "bad-scope", "scopes": ["entities:delete"] }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 422);
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/api-keys")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "name": "good-scope", "scopes": ["workflows:trigger", "entities:read"] }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 201);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body["data"]["scopes"],
+        json!(["entities:read", "workflows:trigger"])
+    );
+
+    Ok(())
+}
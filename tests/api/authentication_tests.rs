@@ -10,7 +10,7 @@ use r_data_core_api::{
 };
 use r_data_core_core::admin_jwt::AuthUserClaims;
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_core::error::Result;
 use r_data_core_persistence::{
     AdminUserRepository, AdminUserRepositoryTrait, ApiKeyRepository, ApiKeyRepositoryTrait,
@@ -43,13 +43,17 @@ mod tests {
 
         // Create API key
         let (key_uuid, key_value) = api_key_repo
-            .create_new_api_key("TestKey", "Test Description", user_uuid, 30)
+            .create_new_api_key("TestKey", "Test Description", user_uuid, 30, &[], &[])
             .await?;
 
         // Create cache config
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -71,6 +75,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -157,6 +165,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -178,6 +190,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -258,6 +274,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -279,6 +299,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -358,13 +382,17 @@ mod tests {
 
         // Create the API key
         let (_key_uuid, key_value) = api_key_repo
-            .create_new_api_key("TestKey", "Test Description", user_uuid, 30)
+            .create_new_api_key("TestKey", "Test Description", user_uuid, 30, &[], &[])
             .await?;
 
         // Create cache config
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -386,6 +414,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -480,6 +512,8 @@ mod tests {
                 "Test Description",
                 create_test_admin_user(&pool).await?,
                 30,
+                &[],
+                &[],
             )
             .await?;
 
@@ -487,6 +521,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -513,6 +551,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -602,7 +644,7 @@ mod tests {
 
         // Create API key with no expiration
         let (key_uuid, key_value) = api_key_repo
-            .create_new_api_key("TestKey", "Test Description", user_uuid, 0) // No expiration
+            .create_new_api_key("TestKey", "Test Description", user_uuid, 0, &[], &[]) // No expiration
             .await?;
 
         // Manually expire the key by setting expires_at to the past
@@ -617,6 +659,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -643,6 +689,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -724,7 +774,7 @@ mod tests {
 
         // Create API key
         let (key_uuid, key_value) = api_key_repo
-            .create_new_api_key("TestKey", "Test Description", user_uuid, 30)
+            .create_new_api_key("TestKey", "Test Description", user_uuid, 30, &[], &[])
             .await?;
 
         // Revoke the API key
@@ -734,6 +784,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -755,6 +809,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -835,6 +893,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -854,6 +916,10 @@ mod tests {
             enable_docs: true,
             cors_origins: vec![],
             check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         };
         let token = r_data_core_core::admin_jwt::generate_access_token(&user, &api_config, &[])
             .expect("Failed to generate JWT token");
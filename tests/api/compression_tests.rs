@@ -0,0 +1,86 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::http::header;
+use actix_web::web::Bytes;
+use actix_web::{test, web, App, HttpResponse};
+use r_data_core_api::middleware::ResponseCompression;
+use r_data_core_api::ApiResponse;
+
+const MIN_SIZE: usize = 1024;
+
+/// First bytes of a gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+async fn large_json() -> HttpResponse {
+    let items: Vec<String> = (0..500).map(|i| format!("entity-{i}")).collect();
+    ApiResponse::ok(items)
+}
+
+async fn small_json() -> HttpResponse {
+    ApiResponse::ok("tiny")
+}
+
+async fn streamed_csv() -> HttpResponse {
+    let rows = (0..200).map(|i| Ok::<_, actix_web::Error>(Bytes::from(format!("row-{i},value\n"))));
+    HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .streaming(futures::stream::iter(rows))
+}
+
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn get(uri: &str, accept_encoding: Option<&str>) -> (Option<String>, Bytes) {
+    let app = test::init_service(
+        App::new()
+            .wrap(ResponseCompression::new(MIN_SIZE))
+            .route("/large", web::get().to(large_json))
+            .route("/small", web::get().to(small_json))
+            .route("/export.csv", web::get().to(streamed_csv)),
+    )
+    .await;
+    let mut req = test::TestRequest::get().uri(uri);
+    if let Some(accept_encoding) = accept_encoding {
+        req = req.insert_header((header::ACCEPT_ENCODING, accept_encoding));
+    }
+    let resp = test::call_service(&app, req.to_request()).await;
+    assert!(resp.status().is_success());
+    let encoding = resp
+        .headers()
+        .get(header::CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .map(ToString::to_string);
+    (encoding, test::read_body(resp).await)
+}
+
+#[actix_web::test]
+async fn test_large_json_is_gzipped() {
+    let (encoding, body) = get("/large", Some("gzip, deflate")).await;
+    assert_eq!(encoding.as_deref(), Some("gzip"));
+    assert!(body.starts_with(&GZIP_MAGIC));
+}
+
+#[actix_web::test]
+async fn test_small_json_is_not_compressed() {
+    let (encoding, body) = get("/small", Some("gzip")).await;
+    assert_eq!(encoding, None);
+    assert!(body.len() < MIN_SIZE);
+    serde_json::from_slice::<serde_json::Value>(&body).expect("plain JSON body");
+}
+
+#[actix_web::test]
+async fn test_compression_honors_accept_encoding() {
+    let (encoding, _) = get("/large", None).await;
+    assert_eq!(encoding, None);
+
+    let (encoding, _) = get("/large", Some("br")).await;
+    assert_eq!(encoding, None);
+
+    let (encoding, _) = get("/large", Some("deflate, gzip;q=0.5")).await;
+    assert_eq!(encoding.as_deref(), Some("deflate"));
+}
+
+#[actix_web::test]
+async fn test_streamed_export_is_gzipped() {
+    let (encoding, body) = get("/export.csv", Some("gzip")).await;
+    assert_eq!(encoding.as_deref(), Some("gzip"));
+    assert!(body.starts_with(&GZIP_MAGIC));
+}
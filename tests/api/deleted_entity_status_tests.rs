@@ -0,0 +1,128 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Soft-deleted entities answer 410 Gone only when the API is configured to expose deletions;
+// otherwise they are indistinguishable from unknown UUIDs
+
+use crate::api::workflows::common::create_test_api_state;
+use actix_web::{test, web, App};
+use r_data_core_api::{configure_app, ApiStateWrapper};
+use r_data_core_core::error::Result;
+use uuid::Uuid;
+
+use r_data_core_test_support::{
+    clear_test_db, create_test_api_key, create_test_entity, create_test_entity_definition,
+    setup_test_db,
+};
+
+const API_KEY: &str = "test_api_key_deleted_status";
+
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn setup_test_app(
+    expose_deleted_entities: bool,
+) -> Result<(
+    impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    r_data_core_test_support::TestDatabase,
+)> {
+    let pool = setup_test_db().await;
+    clear_test_db(&pool.pool).await?;
+
+    let _ = create_test_entity_definition(&pool, "user").await?;
+    create_test_api_key(&pool, API_KEY.to_string()).await?;
+
+    let mut api_state = create_test_api_state(&pool, "test_secret").await;
+    api_state.api_config.expose_deleted_entities = expose_deleted_entities;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+
+    Ok((app, pool))
+}
+
+/// Status codes for GET, PUT and DELETE on the given entity
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn statuses<S>(app: &S, uuid: Uuid) -> [u16; 3]
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let uri = format!("/api/v1/user/{uuid}");
+    let get = test::TestRequest::get()
+        .uri(&uri)
+        .insert_header(("X-API-Key", API_KEY))
+        .to_request();
+    let put = test::TestRequest::put()
+        .uri(&uri)
+        .insert_header(("X-API-Key", API_KEY))
+        .set_json(serde_json::json!({ "name": "Renamed" }))
+        .to_request();
+    let delete = test::TestRequest::delete()
+        .uri(&uri)
+        .insert_header(("X-API-Key", API_KEY))
+        .to_request();
+
+    [
+        test::call_service(app, get).await.status().as_u16(),
+        test::call_service(app, put).await.status().as_u16(),
+        test::call_service(app, delete).await.status().as_u16(),
+    ]
+}
+
+/// Create a user entity and soft-delete it through the API
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn create_deleted_user<S>(
+    app: &S,
+    db: &r_data_core_test_support::TestDatabase,
+) -> Result<Uuid>
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let uuid = create_test_entity(db, "user", "Gone User", "gone@example.com").await?;
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/v1/user/{uuid}"))
+        .insert_header(("X-API-Key", API_KEY))
+        .to_request();
+    let resp = test::call_service(app, req).await;
+    assert!(
+        resp.status().is_success(),
+        "delete failed: {}",
+        resp.status()
+    );
+    Ok(uuid)
+}
+
+#[actix_web::test]
+async fn deleted_entity_is_gone_when_exposed() -> Result<()> {
+    let (app, db) = setup_test_app(true).await?;
+    let deleted = create_deleted_user(&app, &db).await?;
+
+    assert_eq!(statuses(&app, deleted).await, [410, 410, 410]);
+    assert_eq!(statuses(&app, Uuid::now_v7()).await, [404, 404, 404]);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn deleted_entity_is_not_found_by_default() -> Result<()> {
+    let (app, db) = setup_test_app(false).await?;
+    let deleted = create_deleted_user(&app, &db).await?;
+
+    assert_eq!(statuses(&app, deleted).await, [404, 404, 404]);
+    assert_eq!(statuses(&app, Uuid::now_v7()).await, [404, 404, 404]);
+
+    Ok(())
+}
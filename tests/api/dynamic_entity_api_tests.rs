@@ -50,6 +50,7 @@ mod tests {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
         fields.push(name_field);
 
@@ -67,6 +68,7 @@ mod tests {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
         fields.push(email_field);
 
@@ -84,6 +86,7 @@ mod tests {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
         fields.push(age_field);
 
@@ -101,6 +104,7 @@ mod tests {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
         fields.push(active_field);
 
@@ -199,6 +203,8 @@ mod tests {
                 "Test API key for dynamic entity tests",
                 admin_uuid,
                 30,
+                &[],
+                &[],
             )
             .await?;
 
@@ -218,6 +224,10 @@ mod tests {
         let cache_config = r_data_core_core::config::CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: r_data_core_core::config::CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 10000,
@@ -265,6 +275,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 db_pool.clone(),
@@ -3,7 +3,7 @@
 use actix_web::{test, web, App};
 use r_data_core_api::{configure_app, ApiState};
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_core::error::Result;
 use r_data_core_persistence::DynamicEntityRepository;
 use r_data_core_persistence::EntityDefinitionRepository;
@@ -40,6 +40,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0, // No expiration
             api_key_ttl: 600,         // 10 minutes for tests
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600, // 1-hour default
             max_size: 10000,
@@ -125,6 +129,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
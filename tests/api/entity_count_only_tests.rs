@@ -0,0 +1,74 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for counting (`?count_only=true`) and existence checks (`HEAD`) on the entity list route
+
+use crate::api::workflows::common::{generate_entity_type, setup_app_with_entities};
+use actix_web::test;
+use r_data_core_test_support::{create_test_entity, create_test_entity_definition};
+use serde_json::Value;
+
+#[actix_web::test]
+async fn test_count_only_and_exists() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("count_only");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+    create_test_entity(&pool.pool, &entity_type, "Ada", "ada@example.com").await?;
+    create_test_entity(&pool.pool, &entity_type, "Grace", "grace@example.com").await?;
+
+    let request = |method: test::TestRequest, query: &str| {
+        method
+            .uri(&format!("/api/v1/{entity_type}{query}"))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .to_request()
+    };
+
+    let resp =
+        test::call_service(&app, request(test::TestRequest::get(), "?count_only=true")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["total"], 2);
+    assert!(!body["data"].is_array());
+    assert!(body.get("items").is_none());
+    assert!(body["data"].get("items").is_none());
+
+    let resp = test::call_service(
+        &app,
+        request(
+            test::TestRequest::get(),
+            "?count_only=true&filter=%7B%22name%22%3A%22Ada%22%7D",
+        ),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["total"], 1);
+
+    // Search is not applied when only counting
+    let resp = test::call_service(
+        &app,
+        request(test::TestRequest::get(), "?count_only=true&q=Ada"),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 400);
+
+    let resp = test::call_service(
+        &app,
+        request(
+            test::TestRequest::default().method(actix_web::http::Method::HEAD),
+            "?filter=name:Ada",
+        ),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let resp = test::call_service(
+        &app,
+        request(
+            test::TestRequest::default().method(actix_web::http::Method::HEAD),
+            "?filter=name:Linus",
+        ),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 404);
+
+    Ok(())
+}
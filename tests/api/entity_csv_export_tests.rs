@@ -0,0 +1,127 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// The CSV export streams matching entities with a header row derived from the definition
+
+use crate::api::workflows::common::create_test_api_state;
+use actix_web::http::header;
+use actix_web::{test, web, App};
+use r_data_core_api::{configure_app, ApiStateWrapper};
+use r_data_core_core::error::Result;
+
+use r_data_core_test_support::{
+    clear_test_db, create_test_api_key, create_test_entity, create_test_entity_definition,
+    setup_test_db,
+};
+
+pub const API_KEY: &str = "test_api_key_entity_csv_export";
+
+/// App over the full route config with a `user` definition and the test API key
+///
+/// # Errors
+/// Returns an error if the test database cannot be prepared
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+pub async fn setup_test_app() -> Result<(
+    impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    r_data_core_test_support::TestDatabase,
+)> {
+    let pool = setup_test_db().await;
+    clear_test_db(&pool.pool).await?;
+
+    let _ = create_test_entity_definition(&pool, "user").await?;
+    create_test_api_key(&pool, API_KEY.to_string()).await?;
+
+    let api_state = create_test_api_state(&pool, "test_secret").await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+
+    Ok((app, pool))
+}
+
+/// Export with the test API key and return status, headers and body
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn export<S>(app: &S, query: &str) -> (u16, actix_web::http::header::HeaderMap, String)
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/v1/entities/user/export.csv{query}"))
+        .insert_header(("X-API-Key", API_KEY))
+        .to_request();
+    let resp = test::call_service(app, req).await;
+    let status = resp.status().as_u16();
+    let headers = resp.headers().clone();
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).expect("utf-8 body");
+    (status, headers, body)
+}
+
+#[actix_web::test]
+async fn csv_export_has_header_row_and_sorted_rows() -> Result<()> {
+    let (app, db) = setup_test_app().await?;
+    for name in ["Charlie", "Alice", "Bob"] {
+        create_test_entity(&db, "user", name, &format!("{name}@example.com")).await?;
+    }
+
+    let (status, headers, body) = export(&app, "?sort_by=name&sort_order=asc").await;
+    assert_eq!(status, 200, "{body}");
+    assert!(headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/csv")));
+    assert_eq!(
+        headers
+            .get(header::CONTENT_DISPOSITION)
+            .and_then(|v| v.to_str().ok()),
+        Some("attachment; filename=\"user.csv\"")
+    );
+
+    let mut lines = body.lines();
+    let columns: Vec<&str> = lines.next().expect("header row").split(',').collect();
+    assert_eq!(columns.first(), Some(&"uuid"));
+    let name = columns
+        .iter()
+        .position(|c| *c == "name")
+        .expect("name column");
+    assert!(columns.contains(&"email"));
+
+    let names: Vec<String> = lines
+        .map(|line| line.split(',').nth(name).unwrap_or_default().to_string())
+        .collect();
+    assert_eq!(names, ["Alice", "Bob", "Charlie"]);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn csv_export_applies_filters_and_fields() -> Result<()> {
+    let (app, db) = setup_test_app().await?;
+    for name in ["Alice", "Bob"] {
+        create_test_entity(&db, "user", name, &format!("{name}@example.com")).await?;
+    }
+
+    // filter={"name":"Bob"}
+    let (status, _, body) = export(
+        &app,
+        "?fields=name,email&filter=%7B%22name%22%3A%22Bob%22%7D",
+    )
+    .await;
+    assert_eq!(status, 200, "{body}");
+    assert_eq!(body, "name,email\nBob,Bob@example.com\n");
+
+    let (status, _, _) = export(&app, "?fields=nosuchfield").await;
+    assert_eq!(status, 422);
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use actix_web::{
 use r_data_core_api::ApiState;
 use r_data_core_core::admin_jwt::AuthUserClaims;
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_core::entity_definition::repository_trait::EntityDefinitionRepositoryTrait;
 use r_data_core_core::error::Result;
 use r_data_core_persistence::{AdminUserRepository, ApiKeyRepository};
@@ -55,6 +55,7 @@ fn create_test_jwt_token(user_uuid: &Uuid, secret: &str) -> String {
     let claims = AuthUserClaims {
         sub: user_uuid.to_string(),
         iss: r_data_core_core::admin_jwt::ADMIN_JWT_ISSUER.to_string(),
+        jti: String::new(),
         name: "test_user".to_string(),
         email: "test@example.com".to_string(),
         permissions,
@@ -106,6 +107,10 @@ mod tests {
                 allow_children: false,
                 icon: Some("mdi-test".to_string()),
                 fields: vec![],
+                extends: Vec::new(),
+                unique_constraints: Vec::new(),
+                validation_rules: Vec::new(),
+                table_name: None,
                 schema: r_data_core_core::entity_definition::schema::Schema::default(),
                 created_at: OffsetDateTime::now_utc(),
                 updated_at: OffsetDateTime::now_utc(),
@@ -125,6 +130,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -151,6 +160,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -333,6 +346,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 3600, // Enable caching with TTL
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -359,6 +376,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -409,7 +430,17 @@ mod tests {
             "group_name": "",
             "allow_children": false,
             "icon": "user",
-            "fields": [],
+            "fields": [
+                {
+                    "name": "name",
+                    "display_name": "Name",
+                    "field_type": "String",
+                    "required": false,
+                    "indexed": false,
+                    "filterable": false,
+                    "unique": false
+                }
+            ],
             "published": true
         });
 
@@ -516,6 +547,10 @@ mod tests {
         let cache_config = CacheConfig {
             entity_definition_ttl: 3600,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 1000,
@@ -542,6 +577,10 @@ mod tests {
                 enable_docs: true,
                 cors_origins: vec![],
                 check_default_admin_password: true,
+                expose_deleted_entities: false,
+                trusted_proxies: Vec::new(),
+                compression_min_size: None,
+                ip_rate_limit_per_minute: None,
             },
             role_service: r_data_core_services::RoleService::new(
                 pool.pool.clone(),
@@ -0,0 +1,136 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Tests for exporting entity definitions as bundles and importing them again.
+
+use super::common::{create_test_app, create_test_jwt_token};
+use actix_web::{
+    http::{header, StatusCode},
+    test,
+};
+use r_data_core_core::error::Result;
+use r_data_core_test_support::{create_test_admin_user, setup_test_db};
+use serde_json::{json, Value};
+use serial_test::serial;
+
+#[tokio::test]
+#[serial]
+async fn test_export_and_import_round_trip() -> Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    let app = create_test_app(&pool).await;
+    let token = create_test_jwt_token(&user_uuid, "test_secret");
+    let auth = (header::AUTHORIZATION, format!("Bearer {token}"));
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/entity-definitions")
+        .insert_header(auth.clone())
+        .set_json(json!({
+            "entity_type": "bundle_article",
+            "display_name": "Bundle Article",
+            "group_name": "content",
+            "fields": [{
+                "name": "title",
+                "display_name": "Title",
+                "field_type": "String",
+                "required": true,
+                "indexed": true,
+                "filterable": true,
+                "unique": false,
+                "constraints": { "type": "string", "constraints": { "max_length": 80 } }
+            }],
+            "published": true
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body: Value = test::read_body_json(resp).await;
+    let uuid = body["data"]["uuid"].as_str().expect("uuid").to_string();
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/admin/api/v1/entity-definitions/{uuid}/export"))
+        .insert_header(auth.clone())
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    let bundle = body["data"].clone();
+    assert_eq!(bundle["format_version"], 1);
+    assert_eq!(bundle["entity_type"], "bundle_article");
+    assert!(bundle.get("uuid").is_none());
+
+    // Importing over the existing type needs `overwrite`
+    let import = |uri: &str| {
+        test::TestRequest::post()
+            .uri(uri)
+            .insert_header(auth.clone())
+            .set_json(&bundle)
+            .to_request()
+    };
+    let resp = test::call_service(&app, import("/admin/api/v1/entity-definitions/import")).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let resp = test::call_service(
+        &app,
+        import("/admin/api/v1/entity-definitions/import?overwrite=true"),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["uuid"], uuid.as_str());
+    assert_eq!(body["data"]["created"], false);
+
+    // Into an environment without the type
+    let req = test::TestRequest::delete()
+        .uri(&format!("/admin/api/v1/entity-definitions/{uuid}"))
+        .insert_header(auth.clone())
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let resp = test::call_service(&app, import("/admin/api/v1/entity-definitions/import")).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["created"], true);
+    let imported_uuid = body["data"]["uuid"].as_str().expect("uuid").to_string();
+    assert_ne!(imported_uuid, uuid);
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/admin/api/v1/entity-definitions/{imported_uuid}/export"
+        ))
+        .insert_header(auth.clone())
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"], bundle);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn test_import_rejects_unsupported_format_version() -> Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    let app = create_test_app(&pool).await;
+    let token = create_test_jwt_token(&user_uuid, "test_secret");
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/entity-definitions/import")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {token}")))
+        .set_json(json!({
+            "format_version": 2,
+            "entity_type": "bundle_future",
+            "display_name": "Future",
+            "description": null,
+            "group_name": null,
+            "allow_children": false,
+            "icon": null,
+            "published": false,
+            "fields": []
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    Ok(())
+}
@@ -6,7 +6,7 @@ use actix_web::{test, web, App};
 use r_data_core_api::ApiState;
 use r_data_core_core::admin_jwt::AuthUserClaims;
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_persistence::{AdminUserRepository, ApiKeyRepository, EntityDefinitionRepository};
 use r_data_core_services::{
     AdminUserService, ApiKeyService, EntityDefinitionService, LicenseService,
@@ -38,6 +38,7 @@ pub fn create_test_jwt_token(user_uuid: &Uuid, secret: &str) -> String {
     let claims = AuthUserClaims {
         sub: user_uuid.to_string(),
         iss: r_data_core_core::admin_jwt::ADMIN_JWT_ISSUER.to_string(),
+        jti: String::new(),
         name: "test_user".to_string(),
         email: "test@example.com".to_string(),
         permissions,
@@ -70,6 +71,10 @@ pub async fn create_test_app(
     let cache_config = CacheConfig {
         entity_definition_ttl: 0, // Disable cache for tests
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 1000,
@@ -96,6 +101,10 @@ pub async fn create_test_app(
             enable_docs: true,
             cors_origins: vec![],
             check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         },
         role_service: r_data_core_services::RoleService::new(
             pool.pool.clone(),
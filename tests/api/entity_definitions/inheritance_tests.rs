@@ -0,0 +1,169 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Tests for entity definitions inheriting the fields of the definitions they extend.
+
+use super::common::{create_test_app, create_test_jwt_token};
+use actix_web::{
+    http::{header, StatusCode},
+    test,
+};
+use r_data_core_core::error::Result;
+use r_data_core_test_support::{create_test_admin_user, setup_test_db};
+use serde_json::{json, Value};
+use serial_test::serial;
+
+fn field(name: &str, field_type: &str) -> Value {
+    json!({
+        "name": name,
+        "display_name": name,
+        "field_type": field_type,
+        "required": false,
+        "indexed": false,
+        "filterable": false,
+        "unique": false
+    })
+}
+
+async fn table_columns(pool: &sqlx::PgPool, table: &str) -> Result<Vec<(String, String)>> {
+    Ok(sqlx::query_as(
+        "SELECT column_name::text, data_type::text FROM information_schema.columns
+         WHERE table_schema = current_schema() AND table_name = $1",
+    )
+    .bind(table)
+    .fetch_all(pool)
+    .await?)
+}
+
+#[tokio::test]
+#[serial]
+async fn test_child_table_gets_inherited_and_overridden_fields() -> Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    let app = create_test_app(&pool).await;
+    let token = create_test_jwt_token(&user_uuid, "test_secret");
+    let auth = (header::AUTHORIZATION, format!("Bearer {token}"));
+
+    let create = |body: Value| {
+        test::TestRequest::post()
+            .uri("/admin/api/v1/entity-definitions")
+            .insert_header(auth.clone())
+            .set_json(body)
+            .to_request()
+    };
+
+    let resp = test::call_service(
+        &app,
+        create(json!({
+            "entity_type": "inherit_audited",
+            "display_name": "Audited",
+            "fields": [field("reviewed_by", "String"), field("score", "Integer")],
+            "published": true
+        })),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body: Value = test::read_body_json(resp).await;
+    let parent_uuid = body["data"]["uuid"].as_str().expect("uuid").to_string();
+
+    let resp = test::call_service(
+        &app,
+        create(json!({
+            "entity_type": "inherit_review",
+            "display_name": "Review",
+            "extends": [parent_uuid],
+            "fields": [field("title", "String"), field("score", "Float")],
+            "published": true
+        })),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body: Value = test::read_body_json(resp).await;
+    let child_uuid = body["data"]["uuid"].as_str().expect("uuid").to_string();
+
+    let columns = table_columns(&pool.pool, "entity_inherit_review").await?;
+    let column_type = |name: &str| {
+        columns
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, data_type)| data_type.clone())
+    };
+    assert_eq!(
+        column_type("reviewed_by").as_deref(),
+        Some("character varying")
+    );
+    assert_eq!(column_type("title").as_deref(), Some("character varying"));
+    // The child's own definition of `score` wins
+    assert_eq!(column_type("score").as_deref(), Some("double precision"));
+
+    // The stored child keeps only its own fields
+    let req = test::TestRequest::get()
+        .uri(&format!("/admin/api/v1/entity-definitions/{child_uuid}"))
+        .insert_header(auth.clone())
+        .to_request();
+    let body: Value = test::read_body_json(test::call_service(&app, req).await).await;
+    assert_eq!(body["data"]["extends"], json!([parent_uuid]));
+    assert_eq!(body["data"]["fields"].as_array().expect("fields").len(), 2);
+
+    // New parent fields reach the child table
+    let update = |uuid: &str, body: Value| {
+        test::TestRequest::put()
+            .uri(&format!("/admin/api/v1/entity-definitions/{uuid}"))
+            .insert_header(auth.clone())
+            .set_json(body)
+            .to_request()
+    };
+    let resp = test::call_service(
+        &app,
+        update(
+            &parent_uuid,
+            json!({
+                "entity_type": "inherit_audited",
+                "display_name": "Audited",
+                "fields": [
+                    field("reviewed_by", "String"),
+                    field("score", "Integer"),
+                    field("approved", "Boolean")
+                ],
+                "published": true
+            }),
+        ),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let columns = table_columns(&pool.pool, "entity_inherit_review").await?;
+    assert!(columns.iter().any(|(column, _)| column == "approved"));
+
+    // Extending the child from the parent would be a cycle
+    let resp = test::call_service(
+        &app,
+        update(
+            &parent_uuid,
+            json!({
+                "entity_type": "inherit_audited",
+                "display_name": "Audited",
+                "extends": [child_uuid],
+                "fields": [field("reviewed_by", "String")],
+                "published": true
+            }),
+        ),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(resp).await;
+    assert!(
+        body["message"]
+            .as_str()
+            .is_some_and(|msg| msg.contains("cycle")),
+        "{body}"
+    );
+
+    // A parent cannot be deleted while it is extended
+    let req = test::TestRequest::delete()
+        .uri(&format!("/admin/api/v1/entity-definitions/{parent_uuid}"))
+        .insert_header(auth.clone())
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
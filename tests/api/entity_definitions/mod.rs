@@ -9,11 +9,19 @@
 //! - Pattern/regex constraints
 //! - Min/max length constraints for strings
 //! - Min/max value constraints for numeric fields
+//! - Previewing schema changes without applying them
+//! - Renaming fields without losing column data
+//! - Exporting and importing definitions as portable bundles
+//! - Inheriting fields from extended definitions
 
+pub mod bundle_tests;
 pub mod common;
 pub mod edge_cases_tests;
 pub mod enum_constraints_tests;
+pub mod inheritance_tests;
 pub mod numeric_constraints_tests;
+pub mod rename_field_tests;
+pub mod schema_preview_tests;
 pub mod string_constraints_tests;
 pub mod unique_field_tests;
 pub mod update_constraints_tests;
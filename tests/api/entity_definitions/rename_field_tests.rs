@@ -0,0 +1,116 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Tests for renaming entity definition fields without losing column data.
+
+use super::common::{create_test_app, create_test_jwt_token};
+use actix_web::{
+    http::{header, StatusCode},
+    test,
+};
+use r_data_core_core::error::Result;
+use r_data_core_test_support::{
+    create_test_admin_user, create_test_entity, create_test_entity_definition, setup_test_db,
+};
+use serde_json::{json, Value};
+use serial_test::serial;
+use uuid::Uuid;
+
+#[tokio::test]
+#[serial]
+async fn test_rename_field_keeps_column_data() -> Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    let app = create_test_app(&pool).await;
+    let token = create_test_jwt_token(&user_uuid, "test_secret");
+
+    let definition_uuid = create_test_entity_definition(&pool.pool, "rename_contact").await?;
+    let entity_uuid =
+        create_test_entity(&pool.pool, "rename_contact", "Ada", "ada@example.com").await?;
+    sqlx::query(
+        "INSERT INTO entities_versions (entity_uuid, entity_type, version_number, data)
+         VALUES ($1, 'rename_contact', 1, $2)",
+    )
+    .bind(entity_uuid)
+    .bind(json!({ "name": "Ada", "email": "old@example.com" }))
+    .execute(&pool.pool)
+    .await?;
+
+    let rename = |field: &str, body: Value| {
+        test::TestRequest::post()
+            .uri(&format!(
+                "/admin/api/v1/entity-definitions/{definition_uuid}/fields/{field}/rename"
+            ))
+            .insert_header((header::AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(body)
+            .to_request()
+    };
+
+    let resp = test::call_service(
+        &app,
+        rename(
+            "email",
+            json!({ "new_name": "contact_email", "rename_in_versions": true }),
+        ),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    let names: Vec<&str> = body["data"]["fields"]
+        .as_array()
+        .expect("fields")
+        .iter()
+        .filter_map(|field| field["name"].as_str())
+        .collect();
+    assert!(names.contains(&"contact_email"));
+    assert!(!names.contains(&"email"));
+
+    // The value moved with the column
+    let email: Option<String> =
+        sqlx::query_scalar("SELECT contact_email FROM entity_rename_contact WHERE uuid = $1")
+            .bind(entity_uuid)
+            .fetch_one(&pool.pool)
+            .await?;
+    assert_eq!(email.as_deref(), Some("ada@example.com"));
+
+    // Stored versions use the new name
+    let version: Value = sqlx::query_scalar(
+        "SELECT data FROM entities_versions WHERE entity_uuid = $1 AND version_number = 1",
+    )
+    .bind(entity_uuid)
+    .fetch_one(&pool.pool)
+    .await?;
+    assert_eq!(version["contact_email"], "old@example.com");
+    assert!(version.get("email").is_none());
+
+    // Collisions with other fields and system columns are rejected
+    let resp =
+        test::call_service(&app, rename("name", json!({ "new_name": "contact_email" }))).await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let resp = test::call_service(&app, rename("name", json!({ "new_name": "version" }))).await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let resp = test::call_service(&app, rename("email", json!({ "new_name": "mail" }))).await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let name: Option<String> =
+        sqlx::query_scalar("SELECT name FROM entity_rename_contact WHERE uuid = $1")
+            .bind(entity_uuid)
+            .fetch_one(&pool.pool)
+            .await?;
+    assert_eq!(name.as_deref(), Some("Ada"));
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::post()
+            .uri(&format!(
+                "/admin/api/v1/entity-definitions/{}/fields/name/rename",
+                Uuid::now_v7()
+            ))
+            .insert_header((header::AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(json!({ "new_name": "title" }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
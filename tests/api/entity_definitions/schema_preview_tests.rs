@@ -0,0 +1,142 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Tests for previewing the table changes of an entity definition without applying them.
+
+use super::common::{create_test_app, create_test_jwt_token};
+use actix_web::{
+    http::{header, StatusCode},
+    test,
+};
+use r_data_core_core::error::Result;
+use r_data_core_test_support::{create_test_admin_user, setup_test_db};
+use serde_json::{json, Value};
+use serial_test::serial;
+
+fn field(name: &str, field_type: &str) -> Value {
+    json!({
+        "name": name,
+        "display_name": name,
+        "field_type": field_type,
+        "required": false,
+        "indexed": false,
+        "filterable": false,
+        "unique": false
+    })
+}
+
+fn change<'a>(body: &'a Value, column: &str) -> &'a Value {
+    body["data"]["changes"]
+        .as_array()
+        .expect("changes")
+        .iter()
+        .find(|change| change["column"] == column)
+        .unwrap_or_else(|| panic!("no change for {column}"))
+}
+
+#[tokio::test]
+#[serial]
+async fn test_schema_preview_lists_changes_without_applying_them() -> Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    let app = create_test_app(&pool).await;
+    let token = create_test_jwt_token(&user_uuid, "test_secret");
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/entity-definitions")
+        .insert_header((header::AUTHORIZATION, format!("Bearer {token}")))
+        .set_json(json!({
+            "entity_type": "preview_product",
+            "display_name": "Preview Product",
+            "fields": [field("title", "Text"), field("price", "Integer")],
+            "published": true
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body: Value = test::read_body_json(resp).await;
+    let uuid = body["data"]["uuid"].as_str().expect("uuid").to_string();
+    let preview_uri = format!("/admin/api/v1/entity-definitions/{uuid}/schema/preview");
+
+    // The stored definition matches its table
+    let get_preview = || {
+        test::TestRequest::get()
+            .uri(&preview_uri)
+            .insert_header((header::AUTHORIZATION, format!("Bearer {token}")))
+            .to_request()
+    };
+    let resp = test::call_service(&app, get_preview()).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["table_name"], "entity_preview_product");
+    assert_eq!(body["data"]["changes"], json!([]));
+
+    // Removing `price`, adding `in_stock` and narrowing `title` to an integer
+    let req = test::TestRequest::post()
+        .uri(&preview_uri)
+        .insert_header((header::AUTHORIZATION, format!("Bearer {token}")))
+        .set_json(json!({
+            "entity_type": "preview_product",
+            "display_name": "Preview Product",
+            "fields": [field("title", "Integer"), field("in_stock", "Boolean")],
+            "published": true
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body["data"]["changes"].as_array().expect("changes").len(),
+        3
+    );
+
+    let added = change(&body, "in_stock");
+    assert_eq!(added["kind"], "add_column");
+    assert_eq!(added["to_type"], "BOOLEAN");
+    assert_eq!(added["destructive"], false);
+
+    let dropped = change(&body, "price");
+    assert_eq!(dropped["kind"], "drop_column");
+    assert_eq!(dropped["destructive"], true);
+
+    let altered = change(&body, "title");
+    assert_eq!(altered["kind"], "alter_column_type");
+    assert_eq!(altered["from_type"], "text");
+    assert_eq!(altered["to_type"], "INTEGER");
+    assert_eq!(altered["destructive"], true);
+
+    let statements = body["data"]["statements"].as_array().expect("statements");
+    assert!(statements.contains(&json!(
+        "ALTER TABLE entity_preview_product DROP COLUMN IF EXISTS price"
+    )));
+    assert!(statements.contains(&json!(
+        "ALTER TABLE entity_preview_product ADD COLUMN IF NOT EXISTS in_stock BOOLEAN"
+    )));
+
+    // Previewing did not touch the table
+    let resp = test::call_service(&app, get_preview()).await;
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["changes"], json!([]));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn test_schema_preview_unknown_definition_returns_404() -> Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    let app = create_test_app(&pool).await;
+    let token = create_test_jwt_token(&user_uuid, "test_secret");
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/admin/api/v1/entity-definitions/{}/schema/preview",
+            uuid::Uuid::now_v7()
+        ))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {token}")))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
@@ -147,7 +147,17 @@ async fn test_update_immutable_fields_are_preserved() -> Result<()> {
         "description": "",
         "allow_children": false,
         "icon": "box",
-        "fields": [],
+        "fields": [
+            {
+                "name": "title",
+                "display_name": "Title",
+                "field_type": "String",
+                "required": false,
+                "indexed": false,
+                "filterable": false,
+                "unique": false
+            }
+        ],
         "published": true
     });
 
@@ -183,7 +193,17 @@ async fn test_update_immutable_fields_are_preserved() -> Result<()> {
         "description": "updated",
         "allow_children": false,
         "icon": "box",
-        "fields": [],
+        "fields": [
+            {
+                "name": "title",
+                "display_name": "Title",
+                "field_type": "String",
+                "required": false,
+                "indexed": false,
+                "filterable": false,
+                "unique": false
+            }
+        ],
         "published": true
     });
 
@@ -247,7 +267,17 @@ async fn test_update_sets_server_controlled_fields() -> Result<()> {
         "description": "",
         "allow_children": false,
         "icon": "box",
-        "fields": [],
+        "fields": [
+            {
+                "name": "title",
+                "display_name": "Title",
+                "field_type": "String",
+                "required": false,
+                "indexed": false,
+                "filterable": false,
+                "unique": false
+            }
+        ],
         "published": true
     });
 
@@ -281,7 +311,17 @@ async fn test_update_sets_server_controlled_fields() -> Result<()> {
         "description": "",
         "allow_children": false,
         "icon": "box",
-        "fields": [],
+        "fields": [
+            {
+                "name": "title",
+                "display_name": "Title",
+                "field_type": "String",
+                "required": false,
+                "indexed": false,
+                "filterable": false,
+                "unique": false
+            }
+        ],
         "published": true
     });
 
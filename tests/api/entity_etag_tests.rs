@@ -0,0 +1,115 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Entity read routes answer with a strong ETag and 304 when `If-None-Match` still matches
+
+use crate::api::workflows::common::create_test_api_state;
+use actix_web::http::header;
+use actix_web::{test, web, App};
+use r_data_core_api::{configure_app, ApiStateWrapper};
+use r_data_core_core::error::Result;
+
+use r_data_core_test_support::{
+    clear_test_db, create_test_api_key, create_test_entity, create_test_entity_definition,
+    setup_test_db,
+};
+
+const API_KEY: &str = "test_api_key_entity_etag";
+
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn setup_test_app() -> Result<(
+    impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    r_data_core_test_support::TestDatabase,
+)> {
+    let pool = setup_test_db().await;
+    clear_test_db(&pool.pool).await?;
+
+    let _ = create_test_entity_definition(&pool, "user").await?;
+    create_test_api_key(&pool, API_KEY.to_string()).await?;
+
+    let api_state = create_test_api_state(&pool, "test_secret").await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+
+    Ok((app, pool))
+}
+
+/// Send a GET with the test API key and return status and `ETag` header
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn get<S>(app: &S, uri: &str, if_none_match: Option<&str>) -> (u16, Option<String>)
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let mut req = test::TestRequest::get()
+        .uri(uri)
+        .insert_header(("X-API-Key", API_KEY));
+    if let Some(etag) = if_none_match {
+        req = req.insert_header((header::IF_NONE_MATCH, etag));
+    }
+    let resp = test::call_service(app, req.to_request()).await;
+    let etag = resp
+        .headers()
+        .get(header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(ToString::to_string);
+    (resp.status().as_u16(), etag)
+}
+
+#[actix_web::test]
+async fn entity_reads_honor_if_none_match() -> Result<()> {
+    let (app, db) = setup_test_app().await?;
+    let uuid = create_test_entity(&db, "user", "Etag User", "etag@example.com").await?;
+
+    for uri in [
+        format!("/api/v1/user/{uuid}"),
+        format!("/api/v1/entities/user/{uuid}/versions"),
+        format!("/api/v1/entities/user/{uuid}/versions/1"),
+    ] {
+        let (status, etag) = get(&app, &uri, None).await;
+        assert_eq!(status, 200, "{uri}");
+        let etag = etag.unwrap_or_else(|| panic!("{uri} returned no ETag"));
+        assert!(etag.starts_with('"'), "strong ETag expected, got {etag}");
+
+        let (status, unchanged) = get(&app, &uri, Some(&etag)).await;
+        assert_eq!(status, 304, "{uri}");
+        assert_eq!(unchanged.as_deref(), Some(etag.as_str()));
+
+        let (status, _) = get(&app, &uri, Some("\"stale\"")).await;
+        assert_eq!(status, 200, "{uri}");
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn entity_etag_changes_after_update() -> Result<()> {
+    let (app, db) = setup_test_app().await?;
+    let uuid = create_test_entity(&db, "user", "Etag User", "etag@example.com").await?;
+    let uri = format!("/api/v1/user/{uuid}");
+
+    let (_, before) = get(&app, &uri, None).await;
+    sqlx::query(
+        "UPDATE entities_registry SET version = version + 1, updated_at = NOW() WHERE uuid = $1",
+    )
+    .bind(uuid)
+    .execute(&db.pool)
+    .await?;
+
+    let (status, after) = get(&app, &uri, before.as_deref()).await;
+    assert_eq!(status, 200);
+    assert_ne!(after, before);
+
+    Ok(())
+}
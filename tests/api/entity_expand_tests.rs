@@ -0,0 +1,205 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for `?expand=` embedding the entities referenced by relation fields in public reads
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::api::field_permission_tests::create_key_with_read_permission;
+use crate::api::workflows::common::{
+    create_entity_definition_with_fields, generate_entity_type, setup_app_with_entities,
+};
+use actix_web::test;
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::field::{FieldDefinition, FieldType};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::DynamicEntityRepository;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+fn string_field(name: &str) -> FieldDefinition {
+    FieldDefinition::new(name.to_string(), name.to_string(), FieldType::String)
+}
+
+fn relation_field(name: &str, field_type: FieldType, target: &str) -> FieldDefinition {
+    let mut field = FieldDefinition::new(name.to_string(), name.to_string(), field_type);
+    field.validation.target_class = Some(target.to_string());
+    field
+}
+
+async fn create_entity(
+    pool: &sqlx::PgPool,
+    entity_type: &str,
+    fields: Value,
+) -> anyhow::Result<Uuid> {
+    let mut field_data: HashMap<String, Value> = serde_json::from_value(fields)?;
+    field_data.insert("entity_key".to_string(), json!(Uuid::now_v7().to_string()));
+    field_data.insert("path".to_string(), json!("/"));
+    field_data.insert("published".to_string(), json!(true));
+    Ok(DynamicEntityRepository::new(pool.clone())
+        .create(&DynamicEntity {
+            entity_type: entity_type.to_string(),
+            field_data,
+            definition: Arc::new(EntityDefinition::default()),
+        })
+        .await?)
+}
+
+/// Author and tag types, and a post type referencing one author and many tags
+async fn create_types(pool: &sqlx::PgPool) -> anyhow::Result<(String, String, String)> {
+    let author_type = generate_entity_type("expand_author");
+    let tag_type = generate_entity_type("expand_tag");
+    let post_type = generate_entity_type("expand_post");
+    create_entity_definition_with_fields(
+        pool,
+        &author_type,
+        vec![string_field("name"), string_field("email")],
+    )
+    .await?;
+    create_entity_definition_with_fields(pool, &tag_type, vec![string_field("label")]).await?;
+    create_entity_definition_with_fields(
+        pool,
+        &post_type,
+        vec![
+            string_field("title"),
+            relation_field("author", FieldType::ManyToOne, &author_type),
+            relation_field("tags", FieldType::ManyToMany, &tag_type),
+        ],
+    )
+    .await?;
+    Ok((author_type, tag_type, post_type))
+}
+
+fn get(uri: &str, auth: (&str, String)) -> actix_http::Request {
+    test::TestRequest::get()
+        .uri(uri)
+        .insert_header(auth)
+        .to_request()
+}
+
+#[actix_web::test]
+async fn test_expand_many_to_one_field() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let (author_type, _, post_type) = create_types(&pool.pool).await?;
+    let author = create_entity(
+        &pool.pool,
+        &author_type,
+        json!({ "name": "Ada", "email": "ada@example.com" }),
+    )
+    .await?;
+    let post = create_entity(
+        &pool.pool,
+        &post_type,
+        json!({ "title": "Engines", "author": author.to_string() }),
+    )
+    .await?;
+    let bearer = || ("Authorization", format!("Bearer {token}"));
+
+    // Without expand the reference stays a UUID
+    let resp =
+        test::call_service(&app, get(&format!("/api/v1/{post_type}/{post}"), bearer())).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["field_data"]["author"], author.to_string());
+
+    let resp = test::call_service(
+        &app,
+        get(
+            &format!("/api/v1/{post_type}/{post}?expand=author"),
+            bearer(),
+        ),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let expanded = &body["data"]["field_data"]["author"];
+    assert_eq!(expanded["uuid"], author.to_string());
+    assert_eq!(expanded["name"], "Ada");
+    assert_eq!(expanded["email"], "ada@example.com");
+
+    let resp = test::call_service(
+        &app,
+        get(&format!("/api/v1/{post_type}?expand=author"), bearer()),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let entity = &body["data"].as_array().expect("array")[0];
+    assert_eq!(entity["field_data"]["author"]["name"], "Ada");
+
+    // Expanded entities honor the field permissions of their own type
+    let restricted_key = create_key_with_read_permission(
+        &pool.pool,
+        &format!("{author_type}_names"),
+        Some(json!({ "entity_type": author_type, "fields": ["name"] })),
+    )
+    .await?;
+    let resp = test::call_service(
+        &app,
+        get(
+            &format!("/api/v1/{post_type}/{post}?expand=author"),
+            ("X-API-Key", restricted_key),
+        ),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let expanded = &body["data"]["field_data"]["author"];
+    assert_eq!(expanded["name"], "Ada");
+    assert!(expanded.get("email").is_none());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_expand_many_to_many_field() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let (_, tag_type, post_type) = create_types(&pool.pool).await?;
+    let rust = create_entity(&pool.pool, &tag_type, json!({ "label": "rust" })).await?;
+    let sql = create_entity(&pool.pool, &tag_type, json!({ "label": "sql" })).await?;
+    let post = create_entity(
+        &pool.pool,
+        &post_type,
+        json!({
+            "title": "Queries",
+            "tags": [rust.to_string(), sql.to_string(), Uuid::now_v7().to_string()],
+        }),
+    )
+    .await?;
+    let bearer = || ("Authorization", format!("Bearer {token}"));
+
+    let resp = test::call_service(
+        &app,
+        get(&format!("/api/v1/{post_type}/{post}?expand=tags"), bearer()),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    // Unresolvable references are left out
+    let labels: Vec<&str> = body["data"]["field_data"]["tags"]
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|tag| tag["label"].as_str())
+        .collect();
+    assert_eq!(labels, vec!["rust", "sql"]);
+
+    // Only relation fields can be expanded, and only one level deep
+    let resp = test::call_service(
+        &app,
+        get(
+            &format!("/api/v1/{post_type}/{post}?expand=title"),
+            bearer(),
+        ),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 422);
+    let resp = test::call_service(
+        &app,
+        get(&format!("/api/v1/{post_type}?expand=tags.author"), bearer()),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 400);
+
+    Ok(())
+}
@@ -0,0 +1,370 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for `AccessLevel::Own` limiting dynamic entity access to the caller's own rows
+
+use crate::api::workflows::common::{generate_entity_type, setup_app_with_entities};
+use actix_web::test;
+use r_data_core_core::admin_jwt::{generate_access_token, AuthUserClaims, ADMIN_JWT_ISSUER};
+use r_data_core_core::cache::CacheManager;
+use r_data_core_core::config::{ApiConfig, CacheConfig};
+use r_data_core_core::permissions::role::{
+    AccessLevel, Permission, PermissionType, ResourceNamespace, Role,
+};
+use r_data_core_persistence::{
+    AdminUserRepository, AdminUserRepositoryTrait, ApiKeyRepository, ApiKeyRepositoryTrait,
+    CreateAdminUserParams,
+};
+use r_data_core_services::RoleService;
+use r_data_core_test_support::create_test_entity_definition;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Create a user whose only role grants `Own` access to entities
+async fn create_owner(pool: &sqlx::PgPool, name: &str) -> anyhow::Result<(Uuid, Role)> {
+    let creator: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(pool)
+        .await?;
+    let users = AdminUserRepository::new(Arc::new(pool.clone()));
+    let user_uuid = users
+        .create_admin_user(&CreateAdminUserParams {
+            username: name,
+            email: &format!("{name}@example.com"),
+            password: "password123",
+            first_name: "Owner",
+            last_name: "Test",
+            role: None,
+            is_active: true,
+            creator_uuid: creator,
+        })
+        .await?;
+
+    let mut role = Role::new(format!("{name}_role"));
+    for permission_type in [
+        PermissionType::Read,
+        PermissionType::Create,
+        PermissionType::Update,
+        PermissionType::Delete,
+    ] {
+        role.permissions.push(Permission {
+            resource_type: ResourceNamespace::Entities,
+            permission_type,
+            access_level: AccessLevel::Own,
+            resource_uuids: vec![],
+            constraints: None,
+        });
+    }
+    let role_service = RoleService::new(
+        pool.clone(),
+        Arc::new(CacheManager::new(CacheConfig::default())),
+        None,
+    );
+    let role_uuid = role_service.create_role(&role, creator).await?;
+    role.base.uuid = role_uuid;
+    Ok((user_uuid, role))
+}
+
+/// Create a user with an API key whose only role grants `Own` access to entities
+async fn create_owner_key(pool: &sqlx::PgPool, name: &str) -> anyhow::Result<String> {
+    let (user_uuid, role) = create_owner(pool, name).await?;
+    let keys = ApiKeyRepository::new(Arc::new(pool.clone()));
+    let (key_uuid, key_value) = keys
+        .create_new_api_key(name, "Owner key", user_uuid, 30, &[], &[])
+        .await?;
+    keys.assign_role(key_uuid, role.base.uuid).await?;
+    Ok(key_value)
+}
+
+/// Create a user whose admin JWT only grants `Own` access to entities
+async fn create_owner_token(pool: &sqlx::PgPool, name: &str) -> anyhow::Result<String> {
+    let (user_uuid, role) = create_owner(pool, name).await?;
+    let users = AdminUserRepository::new(Arc::new(pool.clone()));
+    users.assign_role(user_uuid, role.base.uuid).await?;
+    let user = users.find_by_uuid(&user_uuid).await?.expect("user");
+    Ok(generate_access_token(&user, &test_api_config(), &[role])?)
+}
+
+fn test_api_config() -> ApiConfig {
+    ApiConfig {
+        host: "0.0.0.0".to_string(),
+        port: 8888,
+        use_tls: false,
+        jwt_secret: "test_secret".to_string(),
+        jwt_expiration: 3600,
+        enable_docs: true,
+        cors_origins: vec![],
+        check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
+    }
+}
+
+fn names(body: &Value) -> Vec<String> {
+    body["data"]
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|entity| entity["field_data"]["name"].as_str())
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[actix_web::test]
+async fn test_own_access_limits_entities_to_their_creator() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("owned");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+    let alice = create_owner_key(&pool.pool, &format!("{entity_type}_alice")).await?;
+    let bob = create_owner_key(&pool.pool, &format!("{entity_type}_bob")).await?;
+
+    let mut uuids = Vec::new();
+    for (key, name) in [(&alice, "Alice"), (&bob, "Bob")] {
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/{entity_type}"))
+            .insert_header(("X-API-Key", key.as_str()))
+            .set_json(json!({
+                "name": name,
+                "email": format!("{}@example.com", name.to_lowercase()),
+                "entity_key": format!("{}-{}", name.to_lowercase(), Uuid::now_v7().simple()),
+                "path": "/",
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().as_u16(), 201);
+        let body: Value = test::read_body_json(resp).await;
+        uuids.push(body["data"]["uuid"].as_str().expect("uuid").to_string());
+    }
+    let (alice_entity, bob_entity) = (&uuids[0], &uuids[1]);
+
+    // Listing and querying only return the caller's rows
+    for (key, own, other) in [(&alice, "Alice", "Bob"), (&bob, "Bob", "Alice")] {
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/v1/{entity_type}"))
+            .insert_header(("X-API-Key", key.as_str()))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().as_u16(), 200);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(names(&body), vec![own.to_string()]);
+        assert_eq!(body["meta"]["pagination"]["total"], 1);
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/{entity_type}/query"))
+            .insert_header(("X-API-Key", key.as_str()))
+            .set_json(json!({}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().as_u16(), 200);
+        let body: Value = test::read_body_json(resp).await;
+        let queried: Vec<&str> = body
+            .as_array()
+            .expect("array")
+            .iter()
+            .filter_map(|entity| entity["field_data"]["name"].as_str())
+            .collect();
+        assert_eq!(queried, vec![own]);
+        assert!(!queried.contains(&other));
+    }
+
+    // Other users' rows cannot be read, updated or deleted
+    let entity_request = |method: test::TestRequest, key: &str, uuid: &str| {
+        method
+            .uri(&format!("/api/v1/{entity_type}/{uuid}"))
+            .insert_header(("X-API-Key", key))
+            .to_request()
+    };
+    let resp = test::call_service(
+        &app,
+        entity_request(test::TestRequest::get(), &alice, bob_entity),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 404);
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::put()
+            .uri(&format!("/api/v1/{entity_type}/{bob_entity}"))
+            .insert_header(("X-API-Key", alice.as_str()))
+            .set_json(json!({ "name": "Hijacked" }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 404);
+    let resp = test::call_service(
+        &app,
+        entity_request(test::TestRequest::delete(), &alice, bob_entity),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 404);
+
+    // Own rows remain fully accessible
+    let resp = test::call_service(
+        &app,
+        entity_request(test::TestRequest::get(), &bob, bob_entity),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["field_data"]["name"], "Bob");
+    let resp = test::call_service(
+        &app,
+        entity_request(test::TestRequest::delete(), &alice, alice_entity),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_own_access_limits_path_queries_and_versions() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("owned_versions");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+    let alice = create_owner_key(&pool.pool, &format!("{entity_type}_alice")).await?;
+    let bob = create_owner_key(&pool.pool, &format!("{entity_type}_bob")).await?;
+
+    let mut uuids = Vec::new();
+    for (key, name) in [(&alice, "Alice"), (&bob, "Bob")] {
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/{entity_type}"))
+            .insert_header(("X-API-Key", key.as_str()))
+            .set_json(json!({
+                "name": name,
+                "email": format!("{}@example.com", name.to_lowercase()),
+                "entity_key": format!("{}-{}", name.to_lowercase(), Uuid::now_v7().simple()),
+                "path": "/",
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().as_u16(), 201);
+        let body: Value = test::read_body_json(resp).await;
+        uuids.push(body["data"]["uuid"].as_str().expect("uuid").to_string());
+    }
+    let (alice_entity, bob_entity) = (&uuids[0], &uuids[1]);
+
+    // Path queries only return the caller's rows
+    for (key, own) in [(&alice, "Alice"), (&bob, "Bob")] {
+        let req = test::TestRequest::post()
+            .uri("/api/v1/entities/query")
+            .insert_header(("X-API-Key", key.as_str()))
+            .set_json(json!({ "entity_type": entity_type, "path": "/" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().as_u16(), 200);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(names(&body), vec![own.to_string()]);
+    }
+
+    // Versions of other users' rows answer 404, own versions stay readable
+    let versions = |key: &str, uuid: &str, suffix: &str| {
+        test::TestRequest::get()
+            .uri(&format!(
+                "/api/v1/entities/{entity_type}/{uuid}/versions{suffix}"
+            ))
+            .insert_header(("X-API-Key", key))
+            .to_request()
+    };
+    for suffix in ["", "/1"] {
+        let resp = test::call_service(&app, versions(&alice, bob_entity, suffix)).await;
+        assert_eq!(
+            resp.status().as_u16(),
+            404,
+            "versions{suffix} of another owner"
+        );
+        let resp = test::call_service(&app, versions(&alice, alice_entity, suffix)).await;
+        assert_eq!(
+            resp.status().as_u16(),
+            200,
+            "versions{suffix} of own entity"
+        );
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_own_access_limits_admin_version_restore() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("owned_restore");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+    let alice = create_owner_token(&pool.pool, &format!("{entity_type}_alice")).await?;
+    let bob = create_owner_token(&pool.pool, &format!("{entity_type}_bob")).await?;
+
+    // Each user creates an entity and updates it once, so version 1 is a snapshot
+    let mut uuids = Vec::new();
+    for (token, name) in [(&alice, "Alice"), (&bob, "Bob")] {
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/{entity_type}"))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .set_json(json!({
+                "name": name,
+                "email": format!("{}@example.com", name.to_lowercase()),
+                "entity_key": format!("{}-{}", name.to_lowercase(), Uuid::now_v7().simple()),
+                "path": "/",
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().as_u16(), 201);
+        let body: Value = test::read_body_json(resp).await;
+        let uuid = body["data"]["uuid"].as_str().expect("uuid").to_string();
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/{entity_type}/{uuid}"))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .set_json(json!({ "name": format!("{name} renamed") }))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status().as_u16(), 200);
+        uuids.push(uuid);
+    }
+    let (alice_entity, bob_entity) = (&uuids[0], &uuids[1]);
+
+    let restore = |token: &str, uuid: &str| {
+        test::TestRequest::post()
+            .uri(&format!("/admin/api/v1/entities/{uuid}/versions/1/restore"))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .to_request()
+    };
+    let resp = test::call_service(&app, restore(&alice, bob_entity)).await;
+    assert_eq!(resp.status().as_u16(), 404);
+    let resp = test::call_service(&app, restore(&alice, alice_entity)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_unparsable_token_subject_is_rejected() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("bad_subject");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+
+    let now = time::OffsetDateTime::now_utc();
+    let claims = AuthUserClaims {
+        sub: "not-a-uuid".to_string(),
+        iss: ADMIN_JWT_ISSUER.to_string(),
+        jti: Uuid::now_v7().to_string(),
+        name: "bad_subject".to_string(),
+        email: "bad_subject@example.com".to_string(),
+        is_super_admin: false,
+        permissions: vec!["entities:read".to_string()],
+        exp: usize::try_from((now + time::Duration::hours(1)).unix_timestamp())?,
+        iat: usize::try_from(now.unix_timestamp())?,
+    };
+    let token = jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(b"test_secret"),
+    )?;
+
+    // Without a user the field and ownership filters cannot apply, so the request must fail
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/v1/{entity_type}"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 401);
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for entity permissions narrowed to entity types by resource patterns
+
+use crate::api::workflows::common::{generate_entity_type, setup_app_with_entities};
+use actix_web::test;
+use r_data_core_core::admin_jwt::generate_access_token;
+use r_data_core_core::cache::CacheManager;
+use r_data_core_core::config::{ApiConfig, CacheConfig};
+use r_data_core_core::permissions::role::{
+    AccessLevel, Permission, PermissionType, ResourcePattern, Role,
+};
+use r_data_core_persistence::{
+    AdminUserRepository, AdminUserRepositoryTrait, CreateAdminUserParams,
+};
+use r_data_core_services::RoleService;
+use r_data_core_test_support::create_test_entity_definition;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Create a user holding a role with `permissions` and return their access token
+async fn create_user_token(
+    pool: &sqlx::PgPool,
+    name: &str,
+    permissions: Vec<Permission>,
+) -> anyhow::Result<String> {
+    let creator: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(pool)
+        .await?;
+    let users = AdminUserRepository::new(Arc::new(pool.clone()));
+    let user_uuid = users
+        .create_admin_user(&CreateAdminUserParams {
+            username: name,
+            email: &format!("{name}@example.com"),
+            password: "password123",
+            first_name: "Pattern",
+            last_name: "Test",
+            role: None,
+            is_active: true,
+            creator_uuid: creator,
+        })
+        .await?;
+
+    let mut role = Role::new(format!("{name}_role"));
+    role.permissions = permissions;
+    let role_service = RoleService::new(
+        pool.clone(),
+        Arc::new(CacheManager::new(CacheConfig::default())),
+        None,
+    );
+    let role_uuid = role_service.create_role(&role, creator).await?;
+    users.assign_role(user_uuid, role_uuid).await?;
+
+    let user = users.find_by_uuid(&user_uuid).await?.expect("user");
+    let roles = role_service.get_roles_for_user(user_uuid, &users).await?;
+    let api_config = ApiConfig {
+        host: "0.0.0.0".to_string(),
+        port: 8888,
+        use_tls: false,
+        jwt_secret: "test_secret".to_string(),
+        jwt_expiration: 3600,
+        enable_docs: true,
+        cors_origins: vec![],
+        check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
+    };
+    Ok(generate_access_token(&user, &api_config, &roles)?)
+}
+
+fn delete(pattern: &str, access_level: AccessLevel) -> Permission {
+    Permission::for_resource(
+        &ResourcePattern::parse(pattern).expect("pattern"),
+        PermissionType::Delete,
+        access_level,
+    )
+}
+
+/// Status of restoring a (missing) soft-deleted entity of `entity_type`: 403 when the
+/// permission check fails, 404 once it passes
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn restore_status<S>(app: &S, token: &str, entity_type: &str) -> u16
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let req = test::TestRequest::post()
+        .uri(&format!(
+            "/admin/api/v1/entities/{entity_type}/{}/restore",
+            Uuid::now_v7()
+        ))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request();
+    test::call_service(app, req).await.status().as_u16()
+}
+
+#[actix_web::test]
+async fn test_entity_type_permission_only_grants_that_type() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let customer = generate_entity_type("pattern_customer");
+    let invoice = generate_entity_type("pattern_invoice");
+    create_test_entity_definition(&pool.pool, &customer).await?;
+    create_test_entity_definition(&pool.pool, &invoice).await?;
+    let token = create_user_token(
+        &pool.pool,
+        &format!("{customer}_user"),
+        vec![delete(&format!("entities:{customer}"), AccessLevel::All)],
+    )
+    .await?;
+
+    assert_eq!(restore_status(&app, &token, &customer).await, 404);
+    assert_eq!(restore_status(&app, &token, &invoice).await, 403);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_more_specific_deny_overrides_broad_grant() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let payroll = generate_entity_type("pattern_payroll");
+    let invoice = generate_entity_type("pattern_invoice");
+    create_test_entity_definition(&pool.pool, &payroll).await?;
+    create_test_entity_definition(&pool.pool, &invoice).await?;
+    let token = create_user_token(
+        &pool.pool,
+        &format!("{payroll}_user"),
+        vec![
+            delete("entities:*", AccessLevel::All),
+            delete(&format!("entities:{payroll}"), AccessLevel::None),
+        ],
+    )
+    .await?;
+
+    assert_eq!(restore_status(&app, &token, &invoice).await, 404);
+    assert_eq!(restore_status(&app, &token, &payroll).await, 403);
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Entity routes resolve the entity type against published definitions first and answer
+// 404 for unknown types before any query against entity tables is made
+
+use crate::api::workflows::common::create_test_api_state;
+use actix_web::{test, web, App};
+use r_data_core_api::{configure_app, ApiStateWrapper};
+use r_data_core_core::error::Result;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use r_data_core_test_support::{
+    clear_test_db, create_test_api_key, create_test_entity_definition, setup_test_db,
+};
+
+const API_KEY: &str = "test_api_key_entity_type_guard";
+
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn setup_test_app() -> Result<(
+    impl actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+    r_data_core_test_support::TestDatabase,
+)> {
+    let pool = setup_test_db().await;
+    clear_test_db(&pool.pool).await?;
+
+    let _ = create_test_entity_definition(&pool, "user").await?;
+    create_test_api_key(&pool, API_KEY.to_string()).await?;
+
+    let api_state = create_test_api_state(&pool, "test_secret").await;
+
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+
+    Ok((app, pool))
+}
+
+/// Send a request with the test API key and return status and JSON body
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn send<S>(app: &S, req: test::TestRequest) -> (u16, Value)
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let resp =
+        test::call_service(app, req.insert_header(("X-API-Key", API_KEY)).to_request()).await;
+    let status = resp.status().as_u16();
+    let body = test::read_body_json(resp).await;
+    (status, body)
+}
+
+#[actix_web::test]
+async fn unknown_entity_type_returns_404() -> Result<()> {
+    let (app, _db) = setup_test_app().await?;
+    let uuid = Uuid::now_v7();
+
+    let requests = [
+        test::TestRequest::get().uri("/api/v1/nosuchtype"),
+        test::TestRequest::post()
+            .uri("/api/v1/nosuchtype")
+            .set_json(json!({ "name": "x" })),
+        test::TestRequest::get().uri(&format!("/api/v1/nosuchtype/{uuid}")),
+        test::TestRequest::put()
+            .uri(&format!("/api/v1/nosuchtype/{uuid}"))
+            .set_json(json!({ "name": "x" })),
+        test::TestRequest::delete().uri(&format!("/api/v1/nosuchtype/{uuid}")),
+        test::TestRequest::post()
+            .uri("/api/v1/nosuchtype/query")
+            .set_json(json!({})),
+        test::TestRequest::get().uri(&format!("/api/v1/entities/nosuchtype/{uuid}/versions")),
+        test::TestRequest::post()
+            .uri("/api/v1/entities/query")
+            .set_json(json!({ "entity_type": "nosuchtype", "path": "/" })),
+    ];
+    for req in requests {
+        let (status, body) = send(&app, req).await;
+        assert_eq!(status, 404, "{body}");
+        assert_eq!(
+            body["message"],
+            json!("Entity type 'nosuchtype' not found"),
+            "{body}"
+        );
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unpublished_entity_type_returns_404() -> Result<()> {
+    let (app, db) = setup_test_app().await?;
+    sqlx::query("UPDATE entity_definitions SET published = false WHERE entity_type = 'user'")
+        .execute(&db.pool)
+        .await?;
+
+    let (status, _) = send(&app, test::TestRequest::get().uri("/api/v1/user")).await;
+    assert_eq!(status, 404);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn known_entity_type_proceeds() -> Result<()> {
+    let (app, _db) = setup_test_app().await?;
+
+    let (status, body) = send(&app, test::TestRequest::get().uri("/api/v1/user")).await;
+    assert_eq!(status, 200, "{body}");
+
+    // A missing entity of a known type still reaches the data layer and reports the entity
+    let uri = format!("/api/v1/user/{}", Uuid::now_v7());
+    let (status, body) = send(&app, test::TestRequest::get().uri(&uri)).await;
+    assert_eq!(status, 404);
+    assert_ne!(body["message"], json!("Entity type 'user' not found"));
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use actix_web::{
 use jsonwebtoken::{encode, EncodingKey, Header};
 use r_data_core_api::{configure_app, ApiState, ApiStateWrapper};
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_core::error::Result;
 use r_data_core_persistence::{AdminUserRepository, ApiKeyRepository, WorkflowRepository};
 use r_data_core_services::{
@@ -32,6 +32,10 @@ async fn test_invalid_uuid_in_jwt_token_returns_401() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 300,
         max_size: 10000,
@@ -73,6 +77,10 @@ async fn test_invalid_uuid_in_jwt_token_returns_401() -> Result<()> {
             enable_docs: true,
             cors_origins: vec![],
             check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         },
         role_service: r_data_core_services::RoleService::new(
             pool.pool.clone(),
@@ -0,0 +1,306 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for field-level read permissions redacting dynamic entity responses
+
+use crate::api::workflows::common::{generate_entity_type, setup_app_with_entities};
+use actix_web::test;
+use r_data_core_core::cache::CacheManager;
+use r_data_core_core::config::CacheConfig;
+use r_data_core_core::permissions::role::{
+    AccessLevel, Permission, PermissionType, ResourceNamespace, Role,
+};
+use r_data_core_persistence::{ApiKeyRepository, ApiKeyRepositoryTrait};
+use r_data_core_services::RoleService;
+use r_data_core_test_support::{create_test_entity, create_test_entity_definition};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Create an API key holding a single role with one `Entities` read permission
+///
+/// # Errors
+/// Returns an error if the role or the API key cannot be created
+pub async fn create_key_with_read_permission(
+    pool: &sqlx::PgPool,
+    role_name: &str,
+    constraints: Option<Value>,
+) -> anyhow::Result<String> {
+    let user_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(pool)
+        .await?;
+
+    let mut role = Role::new(role_name.to_string());
+    role.permissions.push(Permission {
+        resource_type: ResourceNamespace::Entities,
+        permission_type: PermissionType::Read,
+        access_level: AccessLevel::All,
+        resource_uuids: vec![],
+        constraints,
+    });
+    let role_service = RoleService::new(
+        pool.clone(),
+        Arc::new(CacheManager::new(CacheConfig::default())),
+        None,
+    );
+    let role_uuid = role_service.create_role(&role, user_uuid).await?;
+
+    let repo = ApiKeyRepository::new(Arc::new(pool.clone()));
+    let (key_uuid, key_value) = repo
+        .create_new_api_key(role_name, "Field permission key", user_uuid, 30, &[], &[])
+        .await?;
+    repo.assign_role(key_uuid, role_uuid).await?;
+    Ok(key_value)
+}
+
+#[actix_web::test]
+async fn test_field_permissions_redact_entity_responses() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("field_perms");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+    let uuid = create_test_entity(&pool.pool, &entity_type, "Ada", "ada@example.com").await?;
+
+    let full_key =
+        create_key_with_read_permission(&pool.pool, &format!("{entity_type}_full"), None).await?;
+    let restricted_key = create_key_with_read_permission(
+        &pool.pool,
+        &format!("{entity_type}_restricted"),
+        Some(json!({ "entity_type": entity_type, "fields": ["name"] })),
+    )
+    .await?;
+
+    let get = |key: &str| {
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/{entity_type}/{uuid}"))
+            .insert_header(("X-API-Key", key))
+            .to_request()
+    };
+    let list = |key: &str| {
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/{entity_type}"))
+            .insert_header(("X-API-Key", key))
+            .to_request()
+    };
+
+    // A role without field entries sees every field
+    let resp = test::call_service(&app, get(&full_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["field_data"]["name"], "Ada");
+    assert_eq!(body["data"]["field_data"]["email"], "ada@example.com");
+
+    // A role limited to `name` gets the email redacted, system fields stay
+    let resp = test::call_service(&app, get(&restricted_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let field_data = &body["data"]["field_data"];
+    assert_eq!(field_data["name"], "Ada");
+    assert!(field_data.get("email").is_none());
+    assert_eq!(field_data["uuid"], uuid.to_string());
+
+    let resp = test::call_service(&app, list(&restricted_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let entity = &body["data"].as_array().expect("array")[0];
+    assert_eq!(entity["field_data"]["name"], "Ada");
+    assert!(entity["field_data"].get("email").is_none());
+
+    let resp = test::call_service(&app, list(&full_key)).await;
+    let body: Value = test::read_body_json(resp).await;
+    let entity = &body["data"].as_array().expect("array")[0];
+    assert_eq!(entity["field_data"]["email"], "ada@example.com");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_field_permissions_redact_query_and_version_responses() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("field_perms_query");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+    let uuid = create_test_entity(&pool.pool, &entity_type, "Ada", "ada@example.com").await?;
+
+    let full_key =
+        create_key_with_read_permission(&pool.pool, &format!("{entity_type}_full"), None).await?;
+    let restricted_key = create_key_with_read_permission(
+        &pool.pool,
+        &format!("{entity_type}_restricted"),
+        Some(json!({ "entity_type": entity_type, "fields": ["name"] })),
+    )
+    .await?;
+
+    let advanced_query = |key: &str| {
+        test::TestRequest::post()
+            .uri(&format!("/api/v1/{entity_type}/query"))
+            .insert_header(("X-API-Key", key))
+            .set_json(json!({}))
+            .to_request()
+    };
+    let path_query = |key: &str| {
+        test::TestRequest::post()
+            .uri("/api/v1/entities/query")
+            .insert_header(("X-API-Key", key))
+            .set_json(json!({ "entity_type": entity_type, "path": "/" }))
+            .to_request()
+    };
+    let version = |key: &str| {
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/entities/{entity_type}/{uuid}/versions/1"))
+            .insert_header(("X-API-Key", key))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, advanced_query(&restricted_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let entity = &body.as_array().expect("array")[0];
+    assert_eq!(entity["field_data"]["name"], "Ada");
+    assert!(entity["field_data"].get("email").is_none());
+
+    let resp = test::call_service(&app, path_query(&restricted_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let entity = &body["data"].as_array().expect("array")[0];
+    assert_eq!(entity["field_data"]["name"], "Ada");
+    assert!(entity["field_data"].get("email").is_none());
+
+    let resp = test::call_service(&app, version(&restricted_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["data"]["name"], "Ada");
+    assert!(body["data"]["data"].get("email").is_none());
+
+    // Without field entries the same responses carry every field
+    let resp = test::call_service(&app, advanced_query(&full_key)).await;
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body[0]["field_data"]["email"], "ada@example.com");
+    let resp = test::call_service(&app, version(&full_key)).await;
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["data"]["email"], "ada@example.com");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_entity_schema_requires_read_and_omits_hidden_fields() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("field_perms_schema");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+
+    let full_key =
+        create_key_with_read_permission(&pool.pool, &format!("{entity_type}_full"), None).await?;
+    let restricted_key = create_key_with_read_permission(
+        &pool.pool,
+        &format!("{entity_type}_restricted"),
+        Some(json!({ "entity_type": entity_type, "fields": ["name"] })),
+    )
+    .await?;
+    let other_type_key = create_key_with_read_permission(
+        &pool.pool,
+        &format!("{entity_type}_other"),
+        Some(json!({ "entity_type": format!("{entity_type}_other") })),
+    )
+    .await?;
+
+    let schema = |key: &str| {
+        test::TestRequest::get()
+            .uri(&format!("/api/v1/entities/{entity_type}/schema"))
+            .insert_header(("X-API-Key", key))
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, schema(&full_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert!(body["properties"].get("email").is_some());
+    assert!(body["required"]
+        .as_array()
+        .expect("array")
+        .contains(&json!("email")));
+
+    let resp = test::call_service(&app, schema(&restricted_key)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert!(body["properties"].get("name").is_some());
+    assert!(body["properties"].get("email").is_none());
+    assert!(!body["required"]
+        .as_array()
+        .expect("array")
+        .contains(&json!("email")));
+
+    let resp = test::call_service(&app, schema(&other_type_key)).await;
+    assert_eq!(resp.status().as_u16(), 403);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_filter_and_sort_by_hidden_field_are_rejected() -> anyhow::Result<()> {
+    let (app, pool, _token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("field_perms_filter");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+    create_test_entity(&pool.pool, &entity_type, "Ada", "ada@example.com").await?;
+
+    let full_key =
+        create_key_with_read_permission(&pool.pool, &format!("{entity_type}_full"), None).await?;
+    let restricted_key = create_key_with_read_permission(
+        &pool.pool,
+        &format!("{entity_type}_restricted"),
+        Some(json!({ "entity_type": entity_type, "fields": ["name"] })),
+    )
+    .await?;
+
+    let email_filter = "%7B%22email%22%3A%22ada%40example.com%22%7D";
+    let name_filter = "%7B%22name%22%3A%22Ada%22%7D";
+    let get = |key: &str, uri: String| {
+        test::TestRequest::get()
+            .uri(&uri)
+            .insert_header(("X-API-Key", key))
+            .to_request()
+    };
+    let probes = [
+        format!("/api/v1/{entity_type}?filter={email_filter}"),
+        format!("/api/v1/{entity_type}?sort_by=email"),
+        format!("/api/v1/{entity_type}?count_only=true&filter={email_filter}"),
+        format!("/api/v1/entities/{entity_type}/export.csv?filter={email_filter}"),
+    ];
+
+    for uri in &probes {
+        let resp = test::call_service(&app, get(&restricted_key, uri.clone())).await;
+        assert_eq!(resp.status().as_u16(), 400, "{uri}");
+        let resp = test::call_service(&app, get(&full_key, uri.clone())).await;
+        assert_eq!(resp.status().as_u16(), 200, "{uri}");
+    }
+
+    let exists = |key: &str, filter: &str| {
+        test::TestRequest::default()
+            .method(actix_web::http::Method::HEAD)
+            .uri(&format!("/api/v1/{entity_type}?filter={filter}"))
+            .insert_header(("X-API-Key", key))
+            .to_request()
+    };
+    let resp = test::call_service(&app, exists(&restricted_key, email_filter)).await;
+    assert_eq!(resp.status().as_u16(), 400);
+    let resp = test::call_service(&app, exists(&restricted_key, name_filter)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    let query = |key: &str, body: Value| {
+        test::TestRequest::post()
+            .uri(&format!("/api/v1/{entity_type}/query"))
+            .insert_header(("X-API-Key", key))
+            .set_json(body)
+            .to_request()
+    };
+    let resp = test::call_service(
+        &app,
+        query(
+            &restricted_key,
+            json!({ "filter": { "email": "ada@example.com" } }),
+        ),
+    )
+    .await;
+    assert_eq!(resp.status().as_u16(), 400);
+    let resp = test::call_service(&app, query(&restricted_key, json!({ "sort_by": "name" }))).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    Ok(())
+}
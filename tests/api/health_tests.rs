@@ -0,0 +1,113 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::{http::StatusCode, test, web, App};
+use r_data_core_api::{health, ApiState, ApiStateWrapper};
+use r_data_core_core::cache::CacheManager;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
+use r_data_core_persistence::{AdminUserRepository, ApiKeyRepository, EntityDefinitionRepository};
+use r_data_core_services::{
+    AdminUserService, ApiKeyService, EntityDefinitionService, LicenseService,
+};
+use r_data_core_test_support::{setup_test_db, test_queue_client_async, TestDatabase};
+use serde_json::Value;
+use serial_test::serial;
+use std::sync::Arc;
+
+/// API state with the services the health and metrics endpoints need
+pub async fn create_api_state(pool: &TestDatabase) -> ApiState {
+    let cache_manager = Arc::new(CacheManager::new(CacheConfig {
+        entity_definition_ttl: 0,
+        api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
+        enabled: true,
+        ttl: 300,
+        max_size: 1000,
+    }));
+
+    ApiState {
+        db_pool: pool.pool.clone(),
+        api_config: r_data_core_core::config::ApiConfig {
+            host: "0.0.0.0".to_string(),
+            port: 8888,
+            use_tls: false,
+            jwt_secret: "test_secret".to_string(),
+            jwt_expiration: 3600,
+            enable_docs: true,
+            cors_origins: vec![],
+            check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
+        },
+        role_service: r_data_core_services::RoleService::new(
+            pool.pool.clone(),
+            cache_manager.clone(),
+            Some(0),
+        ),
+        cache_manager: cache_manager.clone(),
+        api_key_service: ApiKeyService::from_repository(ApiKeyRepository::new(Arc::new(
+            pool.pool.clone(),
+        ))),
+        admin_user_service: AdminUserService::from_repository(AdminUserRepository::new(Arc::new(
+            pool.pool.clone(),
+        ))),
+        entity_definition_service: EntityDefinitionService::new_without_cache(Arc::new(
+            EntityDefinitionRepository::new(pool.pool.clone()),
+        )),
+        dynamic_entity_service: None,
+        workflow_service: r_data_core_services::WorkflowService::new(Arc::new(
+            r_data_core_services::WorkflowRepositoryAdapter::new(
+                r_data_core_persistence::WorkflowRepository::new(pool.pool.clone()),
+            ),
+        )),
+        dashboard_stats_service: r_data_core_services::DashboardStatsService::new(Arc::new(
+            r_data_core_persistence::DashboardStatsRepository::new(pool.pool.clone()),
+        )),
+        queue: test_queue_client_async().await,
+        license_service: Arc::new(LicenseService::new(LicenseConfig::default(), cache_manager)),
+        password_reset_service: None,
+        system_log_service: None,
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_readiness_reports_ok_components() {
+    let pool = setup_test_db().await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(
+                create_api_state(&pool).await,
+            )))
+            .service(health::admin_health_check)
+            .service(health::admin_readiness_check)
+            .service(health::public_readiness_check),
+    )
+    .await;
+
+    for path in ["/admin/api/v1/health/ready", "/api/v1/health/ready"] {
+        let resp = test::call_service(&app, test::TestRequest::get().uri(path).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::OK, "{path}");
+
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["status"], "Success");
+        assert_eq!(body["data"]["status"], "ok");
+        assert_eq!(body["data"]["database"], "ok");
+        // The test cache manager has no Redis backend
+        assert_eq!(body["data"]["redis"], "disabled");
+    }
+
+    // Liveness stays a static check
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get()
+            .uri("/admin/api/v1/health")
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
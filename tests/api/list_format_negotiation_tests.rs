@@ -0,0 +1,105 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// List endpoints render JSON, CSV or NDJSON depending on `?format=` or the `Accept` header
+
+use actix_web::http::header;
+use actix_web::test;
+use r_data_core_core::error::Result;
+use serde_json::Value;
+
+use r_data_core_test_support::create_test_entity;
+
+use super::entity_csv_export_tests::{setup_test_app, API_KEY};
+
+/// List users with the test API key and return status, content type and body
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn list<S>(app: &S, query: &str, accept: Option<&str>) -> (u16, String, String)
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let mut req = test::TestRequest::get()
+        .uri(&format!("/api/v1/user?sort_by=name&sort_order=asc{query}"))
+        .insert_header(("X-API-Key", API_KEY));
+    if let Some(accept) = accept {
+        req = req.insert_header((header::ACCEPT, accept));
+    }
+    let resp = test::call_service(app, req.to_request()).await;
+    let status = resp.status().as_u16();
+    let content_type = resp
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).expect("utf-8 body");
+    (status, content_type, body)
+}
+
+#[actix_web::test]
+async fn list_negotiates_format_by_param_and_accept() -> Result<()> {
+    let (app, db) = setup_test_app().await?;
+    for name in ["Bob", "Alice"] {
+        create_test_entity(&db, "user", name, &format!("{name}@example.com")).await?;
+    }
+
+    for (query, accept) in [
+        ("&format=json", None),
+        ("", Some("application/json")),
+        ("", None),
+    ] {
+        let (status, content_type, body) = list(&app, query, accept).await;
+        assert_eq!(status, 200, "{body}");
+        assert!(
+            content_type.starts_with("application/json"),
+            "{content_type}"
+        );
+        let body: Value = serde_json::from_str(&body)?;
+        assert_eq!(body["data"][0]["field_data"]["name"], "Alice");
+        assert_eq!(body["meta"]["pagination"]["total"], 2);
+    }
+
+    for (query, accept) in [("&format=csv", None), ("", Some("text/csv"))] {
+        let (status, content_type, body) =
+            list(&app, &format!("{query}&fields=name,email"), accept).await;
+        assert_eq!(status, 200, "{body}");
+        assert!(content_type.starts_with("text/csv"), "{content_type}");
+        assert_eq!(
+            body,
+            "name,email\nAlice,Alice@example.com\nBob,Bob@example.com\n"
+        );
+    }
+
+    for (query, accept) in [
+        ("&format=ndjson", None),
+        ("", Some("application/x-ndjson, application/json;q=0.5")),
+    ] {
+        let (status, content_type, body) = list(&app, query, accept).await;
+        assert_eq!(status, 200, "{body}");
+        assert_eq!(content_type, "application/x-ndjson");
+        let names: Vec<Value> = body
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<Value>(line).map(|v| v["field_data"]["name"].clone())
+            })
+            .collect::<std::result::Result<_, _>>()?;
+        assert_eq!(names, ["Alice", "Bob"]);
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn list_rejects_unsupported_format() -> Result<()> {
+    let (app, _db) = setup_test_app().await?;
+
+    let (status, _, _) = list(&app, "&format=xml", None).await;
+    assert_eq!(status, 406);
+    let (status, _, _) = list(&app, "", Some("application/xml")).await;
+    assert_eq!(status, 406);
+
+    Ok(())
+}
@@ -2,7 +2,7 @@
 
 use actix_web::{http::StatusCode, test};
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_core::error::Result;
 use r_data_core_core::permissions::role::{
     AccessLevel, Permission, PermissionType, ResourceNamespace, Role,
@@ -50,6 +50,10 @@ async fn test_dashboard_without_permission_returns_forbidden() -> Result<()> {
         Arc::new(CacheManager::new(CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 10000,
@@ -86,6 +90,10 @@ async fn test_dashboard_without_permission_returns_forbidden() -> Result<()> {
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = generate_access_token(&user, &api_config, &roles)?;
 
@@ -138,6 +146,10 @@ async fn test_dashboard_with_read_permission_returns_ok() -> Result<()> {
         Arc::new(CacheManager::new(CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 10000,
@@ -174,6 +186,10 @@ async fn test_dashboard_with_read_permission_returns_ok() -> Result<()> {
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = generate_access_token(&user, &api_config, &roles)?;
 
@@ -230,6 +246,10 @@ async fn test_dashboard_super_admin_returns_ok() -> Result<()> {
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = generate_access_token(&user, &api_config, &[])?;
 
@@ -282,6 +302,10 @@ async fn test_dashboard_with_admin_permission_returns_ok() -> Result<()> {
         Arc::new(CacheManager::new(CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 10000,
@@ -318,6 +342,10 @@ async fn test_dashboard_with_admin_permission_returns_ok() -> Result<()> {
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = generate_access_token(&user, &api_config, &roles)?;
 
@@ -370,6 +398,10 @@ async fn test_dashboard_other_namespace_permission_returns_forbidden() -> Result
         Arc::new(CacheManager::new(CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 10000,
@@ -406,6 +438,10 @@ async fn test_dashboard_other_namespace_permission_returns_forbidden() -> Result
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = generate_access_token(&user, &api_config, &roles)?;
 
@@ -0,0 +1,114 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::{http::header, http::StatusCode, test, web, App};
+use r_data_core_api::middleware::RequestMetrics;
+use r_data_core_api::{health, metrics, ApiStateWrapper};
+use r_data_core_test_support::setup_test_db;
+use serial_test::serial;
+
+use super::health_tests::create_api_state;
+
+#[tokio::test]
+#[serial]
+async fn test_metrics_endpoint_exposes_request_counts() {
+    let pool = setup_test_db().await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(
+                create_api_state(&pool).await,
+            )))
+            .wrap(RequestMetrics)
+            .service(health::admin_health_check)
+            .service(metrics::metrics_endpoint),
+    )
+    .await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get()
+            .uri("/admin/api/v1/health")
+            .to_request(),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // Scrape twice: the first scrape must not show up in the second one
+    for _ in 0..2 {
+        let resp =
+            test::call_service(&app, test::TestRequest::get().uri("/metrics").to_request()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let content_type = resp
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        assert!(content_type.starts_with("text/plain; version=0.0.4"));
+
+        let body = String::from_utf8(test::read_body(resp).await.to_vec()).expect("utf-8 body");
+        assert!(body.contains("# TYPE http_requests_total counter"));
+        assert!(body.contains(
+            "http_requests_total{method=\"GET\",route=\"/admin/api/v1/health\",status=\"200\"}"
+        ));
+        assert!(body.contains("# TYPE http_request_duration_seconds histogram"));
+        assert!(body.contains("cache_hits_total"));
+        assert!(!body.contains("route=\"/metrics\""));
+    }
+}
+
+/// Value of the `in_use` pool gauge in a metrics scrape
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn scrape_pool_in_use<S, B>(app: &S) -> u32
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    B: actix_web::body::MessageBody,
+{
+    let resp = test::call_service(app, test::TestRequest::get().uri("/metrics").to_request()).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).expect("utf-8 body");
+    body.lines()
+        .find_map(|line| line.strip_prefix("db_pool_connections{state=\"in_use\"} "))
+        .and_then(|value| value.parse().ok())
+        .expect("in_use pool gauge")
+}
+
+#[tokio::test]
+#[serial]
+async fn test_metrics_endpoint_reflects_acquired_pool_connections() {
+    let pool = setup_test_db().await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(
+                create_api_state(&pool).await,
+            )))
+            .service(metrics::metrics_endpoint),
+    )
+    .await;
+
+    let before = scrape_pool_in_use(&app).await;
+
+    // Hold a connection with an open transaction while scraping
+    let mut held = pool.pool.begin().await.expect("begin transaction");
+    sqlx::query("SELECT 1")
+        .execute(&mut *held)
+        .await
+        .expect("query on held connection");
+    let during = scrape_pool_in_use(&app).await;
+    assert_eq!(during, before + 1);
+
+    held.rollback().await.expect("rollback");
+    // The connection goes back to the pool in a background task
+    let mut after = scrape_pool_in_use(&app).await;
+    for _ in 0..50 {
+        if after == before {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        after = scrape_pool_in_use(&app).await;
+    }
+    assert_eq!(after, before);
+}
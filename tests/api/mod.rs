@@ -2,19 +2,40 @@
 // Will contain API integration tests
 
 // API tests
+pub mod access_token_revocation_tests;
 pub mod admin_auth_tests;
+pub mod admin_two_factor_tests;
 pub mod api_key_integration_tests;
+pub mod api_key_ip_allowlist_tests;
+pub mod api_key_rate_limit_tests;
+pub mod api_key_rotation_tests;
 pub mod api_key_routes_tests;
+pub mod api_key_scope_tests;
 pub mod authentication_tests;
+pub mod compression_tests;
+pub mod deleted_entity_status_tests;
 pub mod dynamic_entity_api_tests;
 pub mod dynamic_entity_routes_tests;
+pub mod entity_count_only_tests;
+pub mod entity_csv_export_tests;
 pub mod entity_definition_integration_tests;
 pub mod entity_definitions;
+pub mod entity_etag_tests;
+pub mod entity_expand_tests;
+pub mod entity_ownership_tests;
+pub mod entity_pattern_permission_tests;
+pub mod entity_type_guard_tests;
 pub mod error_handling_tests;
+pub mod field_permission_tests;
+pub mod health_tests;
+pub mod list_format_negotiation_tests;
 pub mod meta;
+pub mod metrics_tests;
+pub mod notification_routes_tests;
 pub mod provider_workflow_endpoints_tests;
 pub mod query_validation_integration_tests;
 pub mod refresh_token_integration_tests;
+pub mod request_id_tests;
 pub mod roles;
 pub mod system_settings_tests;
 pub mod users;
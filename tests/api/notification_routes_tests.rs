@@ -0,0 +1,74 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// The public notification routes list the API key owner's unread notifications and mark them read
+
+use std::sync::Arc;
+
+use actix_web::test;
+use r_data_core_core::error::Result;
+use r_data_core_core::notification::{NewNotification, NotificationManager, NotificationType};
+use r_data_core_persistence::NotificationRepository;
+use r_data_core_services::PgNotificationService;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::entity_csv_export_tests::{setup_test_app, API_KEY};
+
+/// Send a request with the test API key and return status and JSON body
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+async fn send<S>(app: &S, req: test::TestRequest) -> (u16, Value)
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let resp =
+        test::call_service(app, req.insert_header(("X-API-Key", API_KEY)).to_request()).await;
+    let status = resp.status().as_u16();
+    let body = test::read_body_json(resp).await;
+    (status, body)
+}
+
+#[actix_web::test]
+async fn public_routes_list_and_mark_read() -> Result<()> {
+    let (app, db) = setup_test_app().await?;
+    let owner: Uuid = sqlx::query_scalar("SELECT user_uuid FROM api_keys LIMIT 1")
+        .fetch_one(&db.pool)
+        .await?;
+    let sent = PgNotificationService::new(Arc::new(NotificationRepository::new(db.pool.clone())))
+        .send_notification(NewNotification {
+            user_uuid: owner,
+            notification_type: NotificationType::InApp,
+            title: "Export ready".to_string(),
+            message: "Your export finished".to_string(),
+            data: None,
+        })
+        .await?;
+
+    let (status, body) = send(&app, test::TestRequest::get().uri("/api/v1/notifications")).await;
+    assert_eq!(status, 200, "{body}");
+    assert_eq!(body["data"][0]["uuid"], sent.uuid.to_string());
+    assert_eq!(body["data"][0]["title"], "Export ready");
+    assert!(body["data"][0]["read_at"].is_null());
+
+    let uri = format!("/api/v1/notifications/{}/read", sent.uuid);
+    let (status, body) = send(&app, test::TestRequest::post().uri(&uri)).await;
+    assert_eq!(status, 200, "{body}");
+
+    let (_, body) = send(&app, test::TestRequest::get().uri("/api/v1/notifications")).await;
+    assert_eq!(body["data"].as_array().map(Vec::len), Some(0), "{body}");
+    let (_, body) = send(
+        &app,
+        test::TestRequest::get().uri("/api/v1/notifications?include_read=true"),
+    )
+    .await;
+    assert!(body["data"][0]["read_at"].is_string(), "{body}");
+
+    let uri = format!("/api/v1/notifications/{}/read", Uuid::now_v7());
+    let (status, _) = send(&app, test::TestRequest::post().uri(&uri)).await;
+    assert_eq!(status, 404);
+
+    Ok(())
+}
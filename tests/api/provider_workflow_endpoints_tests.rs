@@ -4,7 +4,7 @@ use actix_web::{test, web, App};
 use r_data_core_api::{configure_app, ApiState, ApiStateWrapper};
 use r_data_core_core::admin_user::AdminUser;
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_persistence::{
     AdminUserRepository, ApiKeyRepository, ApiKeyRepositoryTrait, WorkflowRepository,
 };
@@ -35,6 +35,10 @@ async fn setup_app_with_entities() -> anyhow::Result<(
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 300,
         max_size: 10000,
@@ -86,6 +90,10 @@ async fn setup_app_with_entities() -> anyhow::Result<(
             enable_docs: true,
             cors_origins: vec![],
             check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         },
         role_service: r_data_core_services::RoleService::new(
             pool.pool.clone(),
@@ -129,13 +137,17 @@ async fn setup_app_with_entities() -> anyhow::Result<(
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = r_data_core_core::admin_jwt::generate_access_token(&user, &api_config, &[])?;
 
     // Create API key for testing - we need to use the repository directly to get the key value
     let api_key_repo = ApiKeyRepository::new(Arc::new(pool.pool.clone()));
     let (_api_key_uuid, api_key_value) = api_key_repo
-        .create_new_api_key("test-api-key", "Test key", user_uuid, 30)
+        .create_new_api_key("test-api-key", "Test key", user_uuid, 30, &[], &[])
         .await?;
 
     Ok((app, pool, token, api_key_value))
@@ -155,6 +167,7 @@ async fn create_provider_workflow(
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     repo.create(&create_req, creator_uuid)
         .await
@@ -175,6 +188,7 @@ async fn create_consumer_workflow_with_api_source(
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     repo.create(&create_req, creator_uuid)
         .await
@@ -622,6 +636,7 @@ async fn test_consumer_endpoint_post_inactive_workflow() -> anyhow::Result<()> {
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -700,6 +715,7 @@ async fn test_provider_endpoint_returns_404_for_consumer_workflow() -> anyhow::R
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
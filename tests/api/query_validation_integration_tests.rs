@@ -3,7 +3,7 @@
 
 use actix_web::{http::StatusCode, test, web, App};
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_core::error::Result;
 use r_data_core_persistence::{
     AdminUserRepository, ApiKeyRepository, DashboardStatsRepository, WorkflowRepository,
@@ -38,6 +38,10 @@ async fn setup_test_app() -> Result<(
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -75,6 +79,10 @@ async fn setup_test_app() -> Result<(
             enable_docs: true,
             cors_origins: vec![],
             check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         },
         role_service: RoleService::new(pool.pool.clone(), cache_manager.clone(), Some(3600)),
         cache_manager: cache_manager.clone(),
@@ -387,6 +395,89 @@ mod tests {
         assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
     }
 
+    /// Test workflows endpoint sorted by two fields with per-field directions
+    #[tokio::test]
+    #[serial]
+    async fn test_workflows_list_multi_field_sorting() {
+        use crate::api::workflows::common::{
+            create_consumer_workflow, create_test_entity_definition, generate_entity_type,
+            load_workflow_example,
+        };
+
+        let (app, pool, token) = setup_test_app().await.unwrap();
+        let entity_type = generate_entity_type("multi_sort");
+        create_test_entity_definition(&pool.pool, &entity_type)
+            .await
+            .unwrap();
+        let creator_uuid: uuid::Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+            .fetch_one(&pool.pool)
+            .await
+            .unwrap();
+        // Names ascend in creation order
+        let mut created = Vec::new();
+        for enabled in [false, true, false, true] {
+            let config =
+                load_workflow_example("workflow_csv_to_entity.json", &entity_type).unwrap();
+            created.push(
+                create_consumer_workflow(&pool.pool, creator_uuid, config, enabled, None)
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        let req = test::TestRequest::get()
+            .uri("/admin/api/v1/workflows?sort_by=enabled:desc,name:asc&per_page=100")
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        let listed: Vec<uuid::Uuid> = body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|workflow| workflow["uuid"].as_str()?.parse().ok())
+            .filter(|uuid| created.contains(uuid))
+            .collect();
+        assert_eq!(
+            listed,
+            vec![created[1], created[3], created[0], created[2]],
+            "Enabled workflows first, each group by name"
+        );
+    }
+
+    /// Test that every field of a multi-field sort is validated
+    #[tokio::test]
+    #[serial]
+    async fn test_workflows_list_multi_field_sorting_invalid_field() {
+        let (app, _pool, token) = setup_test_app().await.unwrap();
+
+        for sort in [
+            "enabled:desc,invalid_field:asc",
+            "name:sideways",
+            "name,name:desc",
+        ] {
+            let req = test::TestRequest::get()
+                .uri(&format!("/admin/api/v1/workflows?sort_by={sort}"))
+                .insert_header(("Authorization", format!("Bearer {token}")))
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{sort}");
+        }
+
+        let req = test::TestRequest::get()
+            .uri("/admin/api/v1/users?sort_by=username:asc,non_existing_field_xyz:desc")
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert!(body["message"]
+            .as_str()
+            .unwrap()
+            .contains("Invalid sort field: 'non_existing_field_xyz'"));
+    }
+
     /// Test case insensitivity for `sort_order`
     #[tokio::test]
     #[serial]
@@ -0,0 +1,76 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use actix_web::{test, web, App, HttpRequest, HttpResponse};
+use r_data_core_api::middleware::{request_id, RequestIdPropagation};
+use r_data_core_core::request_id::{current_request_id, REQUEST_ID_HEADER};
+use uuid::Uuid;
+
+/// Echoes the request ID seen by the handler and the one scoped to the current task
+#[allow(clippy::future_not_send, clippy::unused_async)] // Actix handler taking HttpRequest (!Send)
+async fn echo_request_id(req: HttpRequest) -> HttpResponse {
+    HttpResponse::Ok().json(serde_json::json!({
+        "extension": request_id(&req),
+        "current": current_request_id(),
+    }))
+}
+
+fn response_header(resp: &actix_web::dev::ServiceResponse) -> String {
+    resp.headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .expect("response carries a request id")
+        .to_string()
+}
+
+#[actix_web::test]
+async fn test_request_id_header_round_trips() {
+    let app = test::init_service(
+        App::new()
+            .wrap(RequestIdPropagation)
+            .route("/echo", web::get().to(echo_request_id)),
+    )
+    .await;
+
+    let resp = test::call_service(
+        &app,
+        test::TestRequest::get()
+            .uri("/echo")
+            .insert_header(("X-Request-Id", "client-trace-42"))
+            .to_request(),
+    )
+    .await;
+    assert!(resp.status().is_success());
+    assert_eq!(response_header(&resp), "client-trace-42");
+
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["extension"], "client-trace-42");
+    assert_eq!(body["current"], "client-trace-42");
+}
+
+#[actix_web::test]
+async fn test_request_id_generated_when_missing_or_invalid() {
+    let app = test::init_service(
+        App::new()
+            .wrap(RequestIdPropagation)
+            .route("/echo", web::get().to(echo_request_id)),
+    )
+    .await;
+
+    for request in [
+        test::TestRequest::get().uri("/echo").to_request(),
+        test::TestRequest::get()
+            .uri("/echo")
+            .insert_header(("X-Request-Id", "contains spaces"))
+            .to_request(),
+    ] {
+        let resp = test::call_service(&app, request).await;
+        assert!(resp.status().is_success());
+        let generated = response_header(&resp);
+        let uuid = Uuid::parse_str(&generated).expect("generated id is a UUID");
+        assert_eq!(uuid.get_version_num(), 7);
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["extension"], generated.as_str());
+        assert_eq!(body["current"], generated.as_str());
+    }
+}
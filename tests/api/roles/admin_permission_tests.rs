@@ -2,7 +2,7 @@
 
 use actix_web::{http::StatusCode, test};
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_core::error::Result;
 use r_data_core_core::permissions::role::{
     AccessLevel, Permission, PermissionType, ResourceNamespace, Role,
@@ -50,6 +50,10 @@ async fn test_admin_permission_grants_all_permissions() -> Result<()> {
         Arc::new(CacheManager::new(CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 10000,
@@ -87,6 +91,10 @@ async fn test_admin_permission_grants_all_permissions() -> Result<()> {
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = generate_access_token(&user, &api_config, &roles)?;
 
@@ -216,6 +224,10 @@ async fn test_admin_vs_super_admin_distinction() -> Result<()> {
         Arc::new(CacheManager::new(CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 10000,
@@ -249,6 +261,10 @@ async fn test_admin_vs_super_admin_distinction() -> Result<()> {
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token1 = generate_access_token(&user1, &api_config, &roles1)?;
 
@@ -1,7 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_core::error::Result;
 use r_data_core_core::permissions::role::{
     AccessLevel, Permission, PermissionType, ResourceNamespace, Role,
@@ -31,6 +31,10 @@ async fn test_permission_caching() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -161,6 +165,10 @@ async fn test_merged_permissions_caching() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -261,6 +269,10 @@ async fn test_cache_invalidation_on_role_deletion() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -326,6 +338,10 @@ async fn test_multiple_users_sharing_role() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -431,6 +447,10 @@ async fn test_api_key_permission_caching() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -444,7 +464,14 @@ async fn test_api_key_permission_caching() -> Result<()> {
 
     // Create an API key
     let api_key_uuid = api_key_repo
-        .create_new_api_key("Test API Key", "Test description", admin_user_uuid, 30)
+        .create_new_api_key(
+            "Test API Key",
+            "Test description",
+            admin_user_uuid,
+            30,
+            &[],
+            &[],
+        )
         .await?
         .0;
 
@@ -497,6 +524,10 @@ async fn test_cache_invalidation_on_role_unassignment() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -574,6 +605,10 @@ async fn test_automatic_cache_invalidation_on_update() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -650,6 +685,10 @@ async fn test_merged_permissions_multiple_roles() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -756,6 +795,10 @@ async fn test_cache_deserialization_failure_handling() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -833,6 +876,10 @@ async fn test_role_update_invalidates_all_related_caches() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -863,7 +910,14 @@ async fn test_role_update_invalidates_all_related_caches() -> Result<()> {
     user_repo.update_admin_user(&user).await?;
 
     let api_key_uuid = api_key_repo
-        .create_new_api_key("Test API Key", "Test description", admin_user_uuid, 30)
+        .create_new_api_key(
+            "Test API Key",
+            "Test description",
+            admin_user_uuid,
+            30,
+            &[],
+            &[],
+        )
         .await?
         .0;
 
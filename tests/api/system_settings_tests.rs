@@ -2,7 +2,7 @@
 
 use actix_web::{http::StatusCode, test};
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{ApiConfig, CacheConfig, LicenseConfig};
+use r_data_core_core::config::{ApiConfig, CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_core::error::Result;
 use r_data_core_persistence::{
     AdminUserRepository, ApiKeyRepository, DashboardStatsRepository, WorkflowRepository,
@@ -26,6 +26,7 @@ async fn maybe_setup_test_app() -> Option<(
         Error = actix_web::Error,
     >,
     r_data_core_test_support::TestDatabase,
+    Arc<CacheManager>,
 )> {
     let Some(pool) = r_data_core_test_support::try_setup_test_db().await else {
         eprintln!("Skipping API system settings test: test database not available");
@@ -47,6 +48,10 @@ async fn maybe_setup_test_app() -> Option<(
     let cache_manager = Arc::new(CacheManager::new(CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -81,9 +86,13 @@ async fn maybe_setup_test_app() -> Option<(
             enable_docs: true,
             cors_origins: vec![],
             check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         },
         role_service: RoleService::new(pool.pool.clone(), cache_manager.clone(), Some(3600)),
-        cache_manager,
+        cache_manager: cache_manager.clone(),
         api_key_service,
         admin_user_service,
         entity_definition_service,
@@ -103,13 +112,13 @@ async fn maybe_setup_test_app() -> Option<(
     )
     .await;
 
-    Some((app, pool))
+    Some((app, pool, cache_manager))
 }
 
 #[tokio::test]
 #[serial]
 async fn get_outbox_settings_requires_authentication() -> Result<()> {
-    let Some((app, pool)) = maybe_setup_test_app().await else {
+    let Some((app, pool, _)) = maybe_setup_test_app().await else {
         return Ok(());
     };
 
@@ -126,7 +135,7 @@ async fn get_outbox_settings_requires_authentication() -> Result<()> {
 #[tokio::test]
 #[serial]
 async fn get_outbox_settings_returns_defaults() -> Result<()> {
-    let Some((app, pool)) = maybe_setup_test_app().await else {
+    let Some((app, pool, _)) = maybe_setup_test_app().await else {
         return Ok(());
     };
     let token = get_auth_token(&app, &pool).await;
@@ -149,7 +158,7 @@ async fn get_outbox_settings_returns_defaults() -> Result<()> {
 #[tokio::test]
 #[serial]
 async fn update_outbox_settings_persists_and_is_readable() -> Result<()> {
-    let Some((app, pool)) = maybe_setup_test_app().await else {
+    let Some((app, pool, _)) = maybe_setup_test_app().await else {
         return Ok(());
     };
     let token = get_auth_token(&app, &pool).await;
@@ -181,3 +190,119 @@ async fn update_outbox_settings_persists_and_is_readable() -> Result<()> {
     clear_test_db(&pool.pool).await?;
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn get_migration_status_reports_all_migrations_applied() -> Result<()> {
+    let Some((app, pool, _)) = maybe_setup_test_app().await else {
+        return Ok(());
+    };
+
+    let req = test::TestRequest::get()
+        .uri("/admin/api/v1/system/migrations")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let token = get_auth_token(&app, &pool).await;
+    let req = test::TestRequest::get()
+        .uri("/admin/api/v1/system/migrations")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let applied = body["data"]["applied"].as_array().expect("applied list");
+    assert!(!applied.is_empty());
+    assert_eq!(body["data"]["pending"], serde_json::json!([]));
+    assert_eq!(body["data"]["up_to_date"], true);
+    assert_eq!(
+        body["data"]["current_version"],
+        body["data"]["expected_version"]
+    );
+
+    clear_test_db(&pool.pool).await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn clear_cache_removes_all_entries() -> Result<()> {
+    let Some((app, pool, cache_manager)) = maybe_setup_test_app().await else {
+        return Ok(());
+    };
+    let token = get_auth_token(&app, &pool).await;
+
+    cache_manager
+        .set("entity_def:by_type:customer", &"def", None)
+        .await?;
+    cache_manager.set("api_key:hash:abc", &"key", None).await?;
+    cache_manager.set("role:1", &"role", None).await?;
+    cache_manager.set("jwt:revoked:abc", &true, None).await?;
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/system/cache/clear")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(serde_json::json!({}))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    // Logging in may have cached entries of its own
+    assert!(body["data"]["cleared"].as_u64().expect("cleared count") >= 3);
+    assert_eq!(body["data"]["namespace"], serde_json::Value::Null);
+
+    let cached: Option<String> = cache_manager.get("api_key:hash:abc").await?;
+    assert!(cached.is_none());
+    // Data outside the cache namespaces shares the store and must survive
+    let revoked: Option<bool> = cache_manager.get("jwt:revoked:abc").await?;
+    assert_eq!(revoked, Some(true));
+
+    clear_test_db(&pool.pool).await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn clear_cache_by_namespace_keeps_other_entries() -> Result<()> {
+    let Some((app, pool, cache_manager)) = maybe_setup_test_app().await else {
+        return Ok(());
+    };
+    let token = get_auth_token(&app, &pool).await;
+
+    cache_manager
+        .set("entity_def:by_type:customer", &"def", None)
+        .await?;
+    cache_manager
+        .set("entity_def:by_uuid:1", &"def", None)
+        .await?;
+    cache_manager.set("api_key:hash:abc", &"key", None).await?;
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/system/cache/clear")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(serde_json::json!({ "namespace": "entity_definitions" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["cleared"], 2);
+    assert_eq!(body["data"]["namespace"], "entity_definitions");
+
+    let cached: Option<String> = cache_manager.get("entity_def:by_uuid:1").await?;
+    assert!(cached.is_none());
+    let cached: Option<String> = cache_manager.get("api_key:hash:abc").await?;
+    assert!(cached.is_some());
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/system/cache/clear")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(serde_json::json!({ "namespace": "everything" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    clear_test_db(&pool.pool).await?;
+    Ok(())
+}
@@ -3,7 +3,7 @@
 
 use actix_web::{http::StatusCode, test};
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_core::permissions::role::Role;
 use r_data_core_persistence::{AdminUserRepository, AdminUserRepositoryTrait};
 use serial_test::serial;
@@ -219,6 +219,10 @@ async fn test_assign_roles_to_user() {
         Arc::new(CacheManager::new(CacheConfig {
             entity_definition_ttl: 0,
             api_key_ttl: 600,
+            entity_miss_ttl: 0,
+            warm_on_startup: false,
+            eviction_policy: CacheEvictionPolicy::Lru,
+            broadcast_invalidations: false,
             enabled: true,
             ttl: 3600,
             max_size: 10000,
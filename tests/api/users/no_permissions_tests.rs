@@ -48,6 +48,10 @@ async fn test_user_with_no_permissions_can_authenticate() -> Result<()> {
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = generate_access_token(&user, &api_config, &[])?;
 
@@ -104,6 +108,10 @@ async fn test_user_with_no_permissions_gets_empty_allowed_routes() -> Result<()>
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = generate_access_token(&user, &api_config, &[])?;
 
@@ -172,6 +180,10 @@ async fn test_user_with_no_permissions_gets_empty_permissions() -> Result<()> {
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = generate_access_token(&user, &api_config, &[])?;
 
@@ -250,6 +262,10 @@ async fn test_user_with_no_permissions_can_access_user_info() -> Result<()> {
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = generate_access_token(&user, &api_config, &[])?;
 
@@ -0,0 +1,118 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for the `authenticate` transform: posted credentials are verified against the password
+// hash of an entity and answered inline with an entity JWT, failed logins end the run as failed
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::common::{
+    create_consumer_workflow, create_entity_definition_with_fields, create_test_api_state,
+    generate_entity_type, load_workflow_example,
+};
+use super::run_stream_tests::login;
+use actix_web::{test, web, App};
+use r_data_core_api::{configure_app, ApiStateWrapper};
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::entity_jwt::verify_entity_jwt;
+use r_data_core_core::field::{FieldDefinition, FieldType};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::DynamicEntityRepository;
+use r_data_core_test_support::setup_test_db;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const JWT_SECRET: &str = "test_secret";
+
+#[actix_web::test]
+async fn test_authenticate_issues_jwt_and_rejects_wrong_password() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let mut api_state = create_test_api_state(&pool, JWT_SECRET).await;
+    api_state.workflow_service = api_state
+        .workflow_service
+        .with_jwt_config(Some(JWT_SECRET.to_string()), 3600);
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+    let token = login(&app, &pool).await?;
+
+    // A user type with a hashed password field and a role copied into the token
+    let user_type = generate_entity_type("auth_user");
+    create_entity_definition_with_fields(
+        &pool.pool,
+        &user_type,
+        ["email", "password", "role"]
+            .into_iter()
+            .map(|name| {
+                let field_type = if name == "password" {
+                    FieldType::Password
+                } else {
+                    FieldType::String
+                };
+                FieldDefinition::new(name.to_string(), name.to_string(), field_type)
+            })
+            .collect(),
+    )
+    .await?;
+    let user_uuid = DynamicEntityRepository::new(pool.pool.clone())
+        .create(&DynamicEntity {
+            entity_type: user_type.clone(),
+            field_data: HashMap::from([
+                ("email".to_string(), json!("ada@example.com")),
+                ("password".to_string(), json!("correct horse")),
+                ("role".to_string(), json!("editor")),
+                ("entity_key".to_string(), json!(Uuid::now_v7().to_string())),
+                ("path".to_string(), json!("/")),
+            ]),
+            definition: Arc::new(EntityDefinition::default()),
+        })
+        .await?;
+
+    let mut config = load_workflow_example("workflow_authenticate_login.json", &user_type)?;
+    config["steps"][0]["transform"]["entity_type"] = json!(user_type);
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+    let wf_uuid = create_consumer_workflow(&pool, creator_uuid, config, true, None).await?;
+
+    let authenticate = |password: &str| {
+        test::TestRequest::post()
+            .uri(&format!("/api/v1/workflows/{wf_uuid}"))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .insert_header(("Content-Type", "application/json"))
+            .set_payload(json!({ "email": "ada@example.com", "password": password }).to_string())
+            .to_request()
+    };
+
+    let resp = test::call_service(&app, authenticate("correct horse")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let access_token = body["access_token"].as_str().expect("access token");
+    let claims = verify_entity_jwt(access_token, JWT_SECRET)?;
+    assert_eq!(claims.sub, user_uuid.to_string());
+    assert_eq!(claims.entity_type, user_type);
+    assert_eq!(claims.extra.get("role"), Some(&json!("editor")));
+    assert!(claims.exp - claims.iat <= 3600);
+
+    let resp = test::call_service(&app, authenticate("wrong horse")).await;
+    assert_eq!(resp.status().as_u16(), 401);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["error"], "Invalid credentials");
+    assert!(body.get("access_token").is_none());
+
+    // The failed login is recorded on its run
+    let (status, error): (String, Option<String>) = sqlx::query_as(
+        "SELECT status::text, error FROM workflow_runs
+         WHERE workflow_uuid = $1 ORDER BY queued_at DESC LIMIT 1",
+    )
+    .bind(wf_uuid)
+    .fetch_one(&pool.pool)
+    .await?;
+    assert_eq!(status, "failed");
+    assert!(error.unwrap_or_default().contains("Invalid credentials"));
+
+    Ok(())
+}
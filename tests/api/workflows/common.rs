@@ -6,7 +6,7 @@ use actix_web::{test, web, App};
 use r_data_core_api::{configure_app, ApiState, ApiStateWrapper};
 use r_data_core_core::admin_user::AdminUser;
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_core::field::FieldDefinition;
 use r_data_core_persistence::{
     AdminUserRepository, ApiKeyRepository, ApiKeyRepositoryTrait, WorkflowRepository,
@@ -39,10 +39,61 @@ pub async fn setup_app_with_entities() -> anyhow::Result<(
     String, // API key value
 )> {
     let pool = setup_test_db().await;
+    let jwt_secret = "test_secret".to_string();
+    let api_state = create_test_api_state(&pool, &jwt_secret).await;
+
+    let app_data = web::Data::new(ApiStateWrapper::new(api_state));
+
+    let app = test::init_service(
+        App::new()
+            .app_data(app_data.clone())
+            .configure(configure_app),
+    )
+    .await;
+
+    // Create test admin user and JWT
+    let user_uuid = create_test_admin_user(&pool).await?;
+    let user: AdminUser = sqlx::query_as("SELECT * FROM admin_users WHERE uuid = $1")
+        .bind(user_uuid)
+        .fetch_one(&pool.pool)
+        .await?;
+    let api_config = r_data_core_core::config::ApiConfig {
+        host: "0.0.0.0".to_string(),
+        port: 8888,
+        use_tls: false,
+        jwt_secret: jwt_secret.clone(),
+        jwt_expiration: 3600,
+        enable_docs: true,
+        cors_origins: vec![],
+        check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
+    };
+    let token = r_data_core_core::admin_jwt::generate_access_token(&user, &api_config, &[])?;
+
+    // Create API key for testing
+    let api_key_repo = ApiKeyRepository::new(Arc::new(pool.pool.clone()));
+    let (_api_key_uuid, api_key_value) = api_key_repo
+        .create_new_api_key("test-api-key", "Test key", user_uuid, 30, &[], &[])
+        .await?;
+
+    Ok((app, pool, token, api_key_value))
+}
 
+/// Build the API state used by [`setup_app_with_entities`], for tests that assemble their own app
+pub async fn create_test_api_state(
+    pool: &r_data_core_test_support::TestDatabase,
+    jwt_secret: &str,
+) -> ApiState {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 300,
         max_size: 10000,
@@ -53,7 +104,7 @@ pub async fn setup_app_with_entities() -> anyhow::Result<(
     let license_service = Arc::new(LicenseService::new(license_config, cache_manager.clone()));
 
     let api_key_repository = Arc::new(ApiKeyRepository::new(Arc::new(pool.pool.clone())));
-    let api_key_service = ApiKeyService::new(api_key_repository);
+    let api_key_service = ApiKeyService::new(api_key_repository).with_pool(pool.pool.clone());
 
     let admin_user_repository = Arc::new(AdminUserRepository::new(Arc::new(pool.pool.clone())));
     let admin_user_service = AdminUserService::new(admin_user_repository);
@@ -85,18 +136,21 @@ pub async fn setup_app_with_entities() -> anyhow::Result<(
     let dashboard_stats_service =
         r_data_core_services::DashboardStatsService::new(Arc::new(dashboard_stats_repository));
 
-    let jwt_secret = "test_secret".to_string();
-    let api_state = ApiState {
+    ApiState {
         db_pool: pool.pool.clone(),
         api_config: r_data_core_core::config::ApiConfig {
             host: "0.0.0.0".to_string(),
             port: 8888,
             use_tls: false,
-            jwt_secret: jwt_secret.clone(),
+            jwt_secret: jwt_secret.to_string(),
             jwt_expiration: 3600,
             enable_docs: true,
             cors_origins: vec![],
             check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         },
         role_service: r_data_core_services::RoleService::new(
             pool.pool.clone(),
@@ -114,42 +168,7 @@ pub async fn setup_app_with_entities() -> anyhow::Result<(
         license_service,
         password_reset_service: None,
         system_log_service: None,
-    };
-
-    let app_data = web::Data::new(ApiStateWrapper::new(api_state));
-
-    let app = test::init_service(
-        App::new()
-            .app_data(app_data.clone())
-            .configure(configure_app),
-    )
-    .await;
-
-    // Create test admin user and JWT
-    let user_uuid = create_test_admin_user(&pool).await?;
-    let user: AdminUser = sqlx::query_as("SELECT * FROM admin_users WHERE uuid = $1")
-        .bind(user_uuid)
-        .fetch_one(&pool.pool)
-        .await?;
-    let api_config = r_data_core_core::config::ApiConfig {
-        host: "0.0.0.0".to_string(),
-        port: 8888,
-        use_tls: false,
-        jwt_secret: jwt_secret.clone(),
-        jwt_expiration: 3600,
-        enable_docs: true,
-        cors_origins: vec![],
-        check_default_admin_password: true,
-    };
-    let token = r_data_core_core::admin_jwt::generate_access_token(&user, &api_config, &[])?;
-
-    // Create API key for testing
-    let api_key_repo = ApiKeyRepository::new(Arc::new(pool.pool.clone()));
-    let (_api_key_uuid, api_key_value) = api_key_repo
-        .create_new_api_key("test-api-key", "Test key", user_uuid, 30)
-        .await?;
-
-    Ok((app, pool, token, api_key_value))
+    }
 }
 
 /// Create a consumer workflow for testing
@@ -172,6 +191,7 @@ pub async fn create_consumer_workflow(
         schedule_cron,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     repo.create(&create_req, creator_uuid)
         .await
@@ -196,6 +216,7 @@ pub async fn create_provider_workflow(
         schedule_cron: None, // Provider workflows ignore cron
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     repo.create(&create_req, creator_uuid)
         .await
@@ -0,0 +1,108 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for the cron preview endpoint: next N fire times, count capping and timezone reporting
+
+use super::common::setup_app_with_entities;
+use actix_web::test;
+use serde_json::{json, Value};
+use time::format_description::well_known::Rfc3339;
+use time::{Duration, OffsetDateTime, Time};
+
+fn preview_request(token: &str, query: &str) -> actix_http::Request {
+    test::TestRequest::get()
+        .uri(&format!("/admin/api/v1/workflows/cron/preview?{query}"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request()
+}
+
+#[actix_web::test]
+async fn test_cron_preview_returns_next_daily_runs() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+
+    // Daily at midnight (6-field: second minute hour day month dow)
+    let resp = test::call_service(
+        &app,
+        preview_request(&token, "expr=0%200%200%20*%20*%20*&count=3"),
+    )
+    .await;
+    let status = resp.status().as_u16();
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["data"]["timezone"], json!("UTC"));
+
+    let runs: Vec<OffsetDateTime> = body["data"]["runs"]
+        .as_array()
+        .expect("runs array")
+        .iter()
+        .map(|v| OffsetDateTime::parse(v.as_str().expect("string"), &Rfc3339))
+        .collect::<Result<_, _>>()?;
+    let first = (OffsetDateTime::now_utc() + Duration::days(1)).replace_time(Time::MIDNIGHT);
+    // Tolerate the request straddling midnight
+    assert!(runs[0] == first || runs[0] == first + Duration::days(1));
+    assert_eq!(
+        runs,
+        vec![
+            runs[0],
+            runs[0] + Duration::days(1),
+            runs[0] + Duration::days(2)
+        ]
+    );
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_cron_preview_count_defaults_and_caps() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+
+    let resp = test::call_service(
+        &app,
+        preview_request(&token, "expr=0%20*%2F5%20*%20*%20*%20*"),
+    )
+    .await;
+    let status = resp.status().as_u16();
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["data"]["runs"].as_array().map(Vec::len), Some(5));
+
+    let resp = test::call_service(
+        &app,
+        preview_request(&token, "expr=0%20*%2F5%20*%20*%20*%20*&count=500"),
+    )
+    .await;
+    let status = resp.status().as_u16();
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["data"]["runs"].as_array().map(Vec::len), Some(20));
+
+    let resp = test::call_service(
+        &app,
+        preview_request(&token, "expr=0%20*%2F5%20*%20*%20*%20*&count=0"),
+    )
+    .await;
+    let status = resp.status().as_u16();
+    assert_eq!(status, 422);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_cron_preview_exhausted_schedule_returns_empty_list() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+
+    let resp = test::call_service(
+        &app,
+        preview_request(&token, "expr=0%200%200%201%201%20*%202020"),
+    )
+    .await;
+    let status = resp.status().as_u16();
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["data"]["runs"], json!([]));
+
+    let resp = test::call_service(&app, preview_request(&token, "expr=not-a-cron")).await;
+    let status = resp.status().as_u16();
+    assert_eq!(status, 422);
+
+    Ok(())
+}
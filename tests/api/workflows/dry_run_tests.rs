@@ -0,0 +1,119 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for POST /admin/api/v1/workflows/{uuid}/dry-run: the workflow runs on a sample input and
+// reports its outputs, marking entity writes, without persisting anything
+
+use super::common::{
+    create_consumer_workflow, create_test_entity_definition, generate_entity_type,
+    setup_app_with_entities,
+};
+use actix_web::test;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// Writes the input as an entity and also returns it through a format output
+fn entity_and_format_config(entity_type: &str) -> Value {
+    let from = json!({
+        "type": "format",
+        "source": { "source_type": "api", "config": {}, "auth": null },
+        "format": { "format_type": "json", "options": {} },
+        "mapping": { "name": "name", "email": "email" }
+    });
+    json!({
+        "steps": [
+            {
+                "from": from,
+                "transform": { "type": "none" },
+                "to": {
+                    "type": "entity",
+                    "entity_definition": entity_type,
+                    "path": "/",
+                    "mode": "create",
+                    "mapping": { "name": "name", "email": "email" }
+                }
+            },
+            {
+                "from": from,
+                "transform": { "type": "none" },
+                "to": {
+                    "type": "format",
+                    "output": { "mode": "api" },
+                    "format": { "format_type": "json", "options": {} },
+                    "mapping": { "contact": "email" }
+                }
+            }
+        ]
+    })
+}
+
+#[actix_web::test]
+async fn test_dry_run_reports_outputs_without_persisting_entities() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+    let entity_type = generate_entity_type("dry_run");
+    create_test_entity_definition(&pool, &entity_type).await?;
+    let wf_uuid = create_consumer_workflow(
+        &pool,
+        creator_uuid,
+        entity_and_format_config(&entity_type),
+        true,
+        None,
+    )
+    .await?;
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/api/v1/workflows/{wf_uuid}/dry-run"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "input": { "name": "Alice", "email": "alice@example.com" } }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let outputs = body["data"]["outputs"].as_array().expect("outputs array");
+    assert_eq!(outputs.len(), 2);
+
+    assert_eq!(outputs[0]["target"], json!("entity"));
+    assert_eq!(outputs[0]["would_write_entity"], json!(true));
+    assert_eq!(outputs[0]["entity_definition"], json!(entity_type));
+    assert_eq!(outputs[0]["entity_mode"], json!("create"));
+    assert_eq!(
+        outputs[0]["produced"],
+        json!({ "name": "Alice", "email": "alice@example.com" })
+    );
+
+    assert_eq!(outputs[1]["target"], json!("format"));
+    assert_eq!(outputs[1]["would_write_entity"], json!(false));
+    assert_eq!(
+        outputs[1]["produced"],
+        json!({ "contact": "alice@example.com" })
+    );
+
+    let written: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM entities_registry WHERE entity_type = $1")
+            .bind(&entity_type)
+            .fetch_one(&pool.pool)
+            .await?;
+    assert_eq!(written, 0, "a dry run must not persist entities");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_dry_run_unknown_workflow_is_not_found() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+
+    let req = test::TestRequest::post()
+        .uri(&format!(
+            "/admin/api/v1/workflows/{}/dry-run",
+            Uuid::now_v7()
+        ))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "input": {} }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 404);
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for POST /admin/api/v1/dsl/test-batch: a DSL is run against sample rows and each row
+// reports its own outputs or error, without persisting entity writes
+
+use super::common::{generate_entity_type, setup_app_with_entities};
+use actix_web::test;
+use r_data_core_api::admin::dsl::test_batch::MAX_TEST_BATCH_SIZE;
+use serde_json::{json, Value};
+
+fn price_steps(entity_type: &str) -> Value {
+    json!([
+        {
+            "from": {
+                "type": "format",
+                "source": { "source_type": "api", "config": {}, "auth": null },
+                "format": { "format_type": "json", "options": {} },
+                "mapping": { "name": "name", "price": "price" }
+            },
+            "transform": {
+                "type": "cast",
+                "source": "price",
+                "target": "price",
+                "to": "float",
+                "strict": true
+            },
+            "to": {
+                "type": "entity",
+                "entity_definition": entity_type,
+                "path": "/",
+                "mode": "create",
+                "mapping": { "name": "name", "price": "price" }
+            }
+        }
+    ])
+}
+
+#[actix_web::test]
+async fn test_batch_reports_per_row_outputs_and_errors() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let entity_type = generate_entity_type("dsl_batch");
+
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/dsl/test-batch")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({
+            "steps": price_steps(&entity_type),
+            "inputs": [
+                { "name": "Widget", "price": "12.5" },
+                { "name": "Gadget", "price": "n/a" }
+            ]
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let results = body["data"]["results"].as_array().expect("results array");
+    assert_eq!(results.len(), 2);
+
+    assert_eq!(results[0]["index"], json!(0));
+    assert!(results[0]["error"].is_null(), "{:?}", results[0]);
+    let output = &results[0]["outputs"][0];
+    assert_eq!(output["target"], json!("entity"));
+    assert_eq!(output["entity_definition"], json!(entity_type));
+    assert_eq!(
+        output["produced"],
+        json!({ "name": "Widget", "price": 12.5 })
+    );
+
+    assert_eq!(results[1]["index"], json!(1));
+    assert_eq!(results[1]["outputs"], json!([]));
+    let error = results[1]["error"].as_str().expect("row error");
+    assert!(error.contains("Cannot cast field 'price'"), "got: {error}");
+
+    // Entity writes are mocked: nothing reaches the registry
+    let written: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM entities_registry WHERE entity_type = $1")
+            .bind(&entity_type)
+            .fetch_one(&pool.pool)
+            .await?;
+    assert_eq!(written, 0);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_batch_rejects_oversized_batch() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+
+    let inputs = vec![json!({ "name": "x", "price": "1" }); MAX_TEST_BATCH_SIZE + 1];
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/dsl/test-batch")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "steps": price_steps("anything"), "inputs": inputs }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 422);
+    let body: Value = test::read_body_json(resp).await;
+    assert!(body.to_string().contains("BATCH_TOO_LARGE"), "got: {body}");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_batch_rejects_invalid_dsl() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+
+    let mut steps = price_steps("anything");
+    steps[0]["transform"]["target"] = json!("not a field");
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/dsl/test-batch")
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "steps": steps, "inputs": [{ "price": "1" }] }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 422);
+
+    Ok(())
+}
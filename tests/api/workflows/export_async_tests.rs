@@ -1,6 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use actix_web::test;
+use r_data_core_core::field::{FieldDefinition, FieldType};
 use serde_json::json;
 use uuid::Uuid;
 
@@ -15,7 +16,16 @@ async fn test_provider_get_async_enqueues_run() -> anyhow::Result<()> {
     let entity_type = generate_entity_type("async_api");
 
     // Minimal entity definition
-    create_entity_definition_with_fields(&pool.pool, &entity_type, vec![]).await?;
+    create_entity_definition_with_fields(
+        &pool.pool,
+        &entity_type,
+        vec![FieldDefinition::new(
+            "name".to_string(),
+            "Name".to_string(),
+            FieldType::String,
+        )],
+    )
+    .await?;
 
     // Load provider workflow that outputs API JSON
     let cfg = crate::api::workflows::common::load_workflow_example(
@@ -60,7 +70,16 @@ async fn test_admin_run_now_returns_queued() -> anyhow::Result<()> {
     let (app, pool, token, _) = setup_app_with_entities().await?;
     let entity_type = generate_entity_type("admin_run");
 
-    create_entity_definition_with_fields(&pool.pool, &entity_type, vec![]).await?;
+    create_entity_definition_with_fields(
+        &pool.pool,
+        &entity_type,
+        vec![FieldDefinition::new(
+            "name".to_string(),
+            "Name".to_string(),
+            FieldType::String,
+        )],
+    )
+    .await?;
 
     let cfg = crate::api::workflows::common::load_workflow_example(
         "workflow_format_to_api_json.json",
@@ -40,6 +40,7 @@ async fn test_entity_fetch_in_cron_job_with_logging() -> anyhow::Result<()> {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
         FieldDefinition {
             name: "status".to_string(),
@@ -54,6 +55,7 @@ async fn test_entity_fetch_in_cron_job_with_logging() -> anyhow::Result<()> {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
     ];
     let _ed_uuid = create_entity_definition_with_fields(&pool.pool, &entity_type, fields).await?;
@@ -112,6 +114,7 @@ async fn test_entity_fetch_in_cron_job_with_logging() -> anyhow::Result<()> {
         schedule_cron: Some("0 0 * * * *".to_string()), // 6-field cron: second minute hour day month dow
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = wf_service.create(&create_req, creator_uuid).await?;
 
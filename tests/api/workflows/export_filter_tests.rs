@@ -36,6 +36,7 @@ async fn test_workflow_export_with_entity_source_equality_filter() -> anyhow::Re
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
         FieldDefinition {
             name: "email".to_string(),
@@ -50,6 +51,7 @@ async fn test_workflow_export_with_entity_source_equality_filter() -> anyhow::Re
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
         FieldDefinition {
             name: "status".to_string(),
@@ -64,6 +66,7 @@ async fn test_workflow_export_with_entity_source_equality_filter() -> anyhow::Re
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
     ];
     let _ed_uuid = create_entity_definition_with_fields(&pool.pool, &entity_type, fields).await?;
@@ -134,6 +137,7 @@ async fn test_workflow_export_with_entity_source_equality_filter() -> anyhow::Re
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -178,6 +182,7 @@ async fn test_workflow_export_with_greater_than_filter() -> anyhow::Result<()> {
         validation: FieldValidation::default(),
         ui_settings: UiSettings::default(),
         constraints: HashMap::new(),
+        display_order: 0,
     }];
     let _ed_uuid = create_entity_definition_with_fields(&pool.pool, &entity_type, fields).await?;
 
@@ -228,6 +233,7 @@ async fn test_workflow_export_with_greater_than_filter() -> anyhow::Result<()> {
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -276,6 +282,7 @@ async fn test_workflow_export_with_in_operator() -> anyhow::Result<()> {
         validation: FieldValidation::default(),
         ui_settings: UiSettings::default(),
         constraints: HashMap::new(),
+        display_order: 0,
     }];
     let _ed_uuid = create_entity_definition_with_fields(&pool.pool, &entity_type, fields).await?;
 
@@ -338,6 +345,7 @@ async fn test_workflow_export_with_in_operator() -> anyhow::Result<()> {
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -395,6 +403,7 @@ async fn test_workflow_export_with_boolean_filter() -> anyhow::Result<()> {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
         FieldDefinition {
             name: "active".to_string(),
@@ -409,6 +418,7 @@ async fn test_workflow_export_with_boolean_filter() -> anyhow::Result<()> {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
     ];
 
@@ -501,6 +511,7 @@ async fn test_workflow_export_with_boolean_filter() -> anyhow::Result<()> {
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
 
     let wf_uuid = wf_service.create(&create_req, creator_uuid).await?;
@@ -558,6 +569,7 @@ async fn test_workflow_export_with_boolean_filter() -> anyhow::Result<()> {
         schedule_cron: None,
         config: config2,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
 
     let wf_uuid2 = wf_service.create(&create_req2, creator_uuid).await?;
@@ -35,6 +35,7 @@ async fn test_workflow_export_empty_mapping_fallback() -> anyhow::Result<()> {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
         FieldDefinition {
             name: "email".to_string(),
@@ -49,6 +50,7 @@ async fn test_workflow_export_empty_mapping_fallback() -> anyhow::Result<()> {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
         FieldDefinition {
             name: "age".to_string(),
@@ -63,6 +65,7 @@ async fn test_workflow_export_empty_mapping_fallback() -> anyhow::Result<()> {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
     ];
     let _ed_uuid = create_entity_definition_with_fields(&pool.pool, &entity_type, fields).await?;
@@ -114,6 +117,7 @@ async fn test_workflow_export_empty_mapping_fallback() -> anyhow::Result<()> {
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -35,6 +35,7 @@ async fn test_workflow_export_sql_injection_prevention() -> anyhow::Result<()> {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
         FieldDefinition {
             name: "value".to_string(),
@@ -49,6 +50,7 @@ async fn test_workflow_export_sql_injection_prevention() -> anyhow::Result<()> {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
     ];
     let _ed_uuid = create_entity_definition_with_fields(&pool.pool, &entity_type, fields).await?;
@@ -101,6 +103,7 @@ async fn test_workflow_export_sql_injection_prevention() -> anyhow::Result<()> {
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
 
     // This should fail validation because the field name is invalid
@@ -124,6 +127,7 @@ async fn test_workflow_export_sql_injection_prevention() -> anyhow::Result<()> {
         schedule_cron: None,
         config: config2,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
 
     // This should succeed because the value is parameterized
@@ -181,6 +185,7 @@ async fn test_workflow_export_sql_injection_prevention() -> anyhow::Result<()> {
         schedule_cron: None,
         config: config3,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
 
     // This should fail validation because the operator is invalid
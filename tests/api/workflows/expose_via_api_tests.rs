@@ -38,6 +38,7 @@ async fn test_expose_data_via_api_endpoint_csv_ignores_cron() -> anyhow::Result<
         schedule_cron: Some("*/5 * * * *".to_string()), // This should be ignored
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -117,6 +118,7 @@ async fn test_expose_data_via_api_endpoint_json_ignores_cron() -> anyhow::Result
         schedule_cron: Some("*/10 * * * *".to_string()), // This should be ignored
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -0,0 +1,188 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for the Idempotency-Key header of the public ingest endpoint
+
+use super::common::{
+    create_consumer_workflow, create_test_entity_definition, generate_entity_type,
+    load_workflow_example, setup_app_with_entities,
+};
+use actix_web::test;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const PAYLOAD: &str = r#"[{"name":"John Doe","email":"john@example.com"},{"name":"Jane Smith","email":"jane@example.com"}]"#;
+
+async fn create_ingest_workflow(
+    pool: &r_data_core_test_support::TestDatabase,
+) -> anyhow::Result<Uuid> {
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+    let entity_type = generate_entity_type("test_idempotency");
+    create_test_entity_definition(pool, &entity_type).await?;
+    let config = load_workflow_example("workflow_api_source_json_to_entity.json", &entity_type)?;
+    create_consumer_workflow(pool, creator_uuid, config, true, None).await
+}
+
+fn ingest_request(token: &str, wf_uuid: Uuid, key: Option<&str>) -> actix_http::Request {
+    let mut req = test::TestRequest::post()
+        .uri(&format!("/api/v1/workflows/{wf_uuid}"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .insert_header(("Content-Type", "application/json"))
+        .set_payload(PAYLOAD.as_bytes());
+    if let Some(key) = key {
+        req = req.insert_header(("Idempotency-Key", key));
+    }
+    req.to_request()
+}
+
+async fn count_runs(pool: &sqlx::PgPool, wf_uuid: Uuid) -> anyhow::Result<i64> {
+    Ok(
+        sqlx::query_scalar("SELECT COUNT(*) FROM workflow_runs WHERE workflow_uuid = $1")
+            .bind(wf_uuid)
+            .fetch_one(pool)
+            .await?,
+    )
+}
+
+#[actix_web::test]
+async fn test_ingest_with_same_idempotency_key_creates_one_run() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_uuid = create_ingest_workflow(&pool).await?;
+
+    let resp = test::call_service(&app, ingest_request(&token, wf_uuid, Some("order-42"))).await;
+    assert_eq!(resp.status().as_u16(), 202);
+    assert!(resp.headers().get("Idempotent-Replayed").is_none());
+    let first: Value = test::read_body_json(resp).await;
+    assert_eq!(first["staged_items"], json!(2));
+
+    let resp = test::call_service(&app, ingest_request(&token, wf_uuid, Some("order-42"))).await;
+    assert_eq!(resp.status().as_u16(), 202);
+    assert_eq!(
+        resp.headers()
+            .get("Idempotent-Replayed")
+            .and_then(|v| v.to_str().ok()),
+        Some("true")
+    );
+    let second: Value = test::read_body_json(resp).await;
+    assert_eq!(second, first);
+
+    assert_eq!(count_runs(&pool.pool, wf_uuid).await?, 1);
+    let staged: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM workflow_raw_items WHERE workflow_uuid = $1")
+            .bind(wf_uuid)
+            .fetch_one(&pool.pool)
+            .await?;
+    assert_eq!(staged, 2);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_ingest_with_different_idempotency_keys_creates_two_runs() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_uuid = create_ingest_workflow(&pool).await?;
+
+    let resp = test::call_service(&app, ingest_request(&token, wf_uuid, Some("order-1"))).await;
+    assert_eq!(resp.status().as_u16(), 202);
+    let first: Value = test::read_body_json(resp).await;
+
+    let resp = test::call_service(&app, ingest_request(&token, wf_uuid, Some("order-2"))).await;
+    assert_eq!(resp.status().as_u16(), 202);
+    let second: Value = test::read_body_json(resp).await;
+
+    assert_ne!(first["run_uuid"], second["run_uuid"]);
+    assert_eq!(count_runs(&pool.pool, wf_uuid).await?, 2);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_idempotency_keys_are_scoped_per_workflow() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_a = create_ingest_workflow(&pool).await?;
+    let wf_b = create_ingest_workflow(&pool).await?;
+
+    for wf_uuid in [wf_a, wf_b] {
+        let resp = test::call_service(&app, ingest_request(&token, wf_uuid, Some("shared"))).await;
+        assert_eq!(resp.status().as_u16(), 202);
+        assert!(resp.headers().get("Idempotent-Replayed").is_none());
+        assert_eq!(count_runs(&pool.pool, wf_uuid).await?, 1);
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_expired_idempotency_key_creates_new_run() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_uuid = create_ingest_workflow(&pool).await?;
+
+    let resp = test::call_service(&app, ingest_request(&token, wf_uuid, Some("retry"))).await;
+    assert_eq!(resp.status().as_u16(), 202);
+
+    sqlx::query(
+        "UPDATE workflow_idempotency_keys SET expires_at = NOW() - INTERVAL '1 second'
+         WHERE workflow_uuid = $1",
+    )
+    .bind(wf_uuid)
+    .execute(&pool.pool)
+    .await?;
+
+    let resp = test::call_service(&app, ingest_request(&token, wf_uuid, Some("retry"))).await;
+    assert_eq!(resp.status().as_u16(), 202);
+    assert!(resp.headers().get("Idempotent-Replayed").is_none());
+    assert_eq!(count_runs(&pool.pool, wf_uuid).await?, 2);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_ingest_rejects_invalid_idempotency_key() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_uuid = create_ingest_workflow(&pool).await?;
+
+    let too_long = "k".repeat(256);
+    let resp = test::call_service(&app, ingest_request(&token, wf_uuid, Some(&too_long))).await;
+    assert_eq!(resp.status().as_u16(), 400);
+    assert_eq!(count_runs(&pool.pool, wf_uuid).await?, 0);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_failed_ingest_releases_idempotency_key_for_retry() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_uuid = create_ingest_workflow(&pool).await?;
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/api/v1/workflows/{wf_uuid}"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .insert_header(("Content-Type", "application/json"))
+        .insert_header(("Idempotency-Key", "flaky"))
+        .set_payload("[{\"name\": ")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_server_error(), "{}", resp.status());
+    let keys: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM workflow_idempotency_keys WHERE workflow_uuid = $1",
+    )
+    .bind(wf_uuid)
+    .fetch_one(&pool.pool)
+    .await?;
+    assert_eq!(keys, 0, "failed request must not keep its key");
+
+    // The retry is processed instead of answering 409 or replaying the failure
+    let resp = test::call_service(&app, ingest_request(&token, wf_uuid, Some("flaky"))).await;
+    assert_eq!(resp.status().as_u16(), 202);
+    assert!(resp.headers().get("Idempotent-Replayed").is_none());
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["staged_items"], json!(2));
+
+    // Once it succeeded the key replays as usual
+    let resp = test::call_service(&app, ingest_request(&token, wf_uuid, Some("flaky"))).await;
+    assert_eq!(resp.status().as_u16(), 202);
+    assert!(resp.headers().get("Idempotent-Replayed").is_some());
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for the body size and record count limits of workflow ingest: oversized ingests are
+// rejected with 413 before a run is created
+
+use super::common::{
+    create_consumer_workflow, create_test_api_state, create_test_entity_definition,
+    generate_entity_type, load_workflow_example,
+};
+use super::run_stream_tests::login;
+use actix_web::{test, web, App};
+use r_data_core_api::{configure_app, ApiStateWrapper};
+use r_data_core_core::config::WorkflowIngestLimits;
+use r_data_core_test_support::setup_test_db;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+fn records(count: usize) -> String {
+    let records: Vec<Value> = (0..count)
+        .map(|i| json!({ "name": format!("User {i}"), "email": format!("user{i}@example.com") }))
+        .collect();
+    Value::Array(records).to_string()
+}
+
+#[actix_web::test]
+async fn test_ingest_limits_reject_oversized_payloads() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let mut api_state = create_test_api_state(&pool, "test_secret").await;
+    api_state.workflow_service =
+        api_state
+            .workflow_service
+            .with_ingest_limits(WorkflowIngestLimits {
+                max_body_bytes: 1024,
+                max_records: 3,
+            });
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+    let token = login(&app, &pool).await?;
+
+    let entity_type = generate_entity_type("ingest_limits");
+    create_test_entity_definition(&pool, &entity_type).await?;
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+    let config = load_workflow_example("workflow_api_source_json_to_entity.json", &entity_type)?;
+    let wf_uuid = create_consumer_workflow(&pool, creator_uuid, config, true, None).await?;
+
+    let ingest = |body: String| {
+        test::TestRequest::post()
+            .uri(&format!("/api/v1/workflows/{wf_uuid}"))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .insert_header(("Content-Type", "application/json"))
+            .set_payload(body)
+            .to_request()
+    };
+    let runs = || async {
+        sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM workflow_runs WHERE workflow_uuid = $1")
+            .bind(wf_uuid)
+            .fetch_one(&pool.pool)
+            .await
+    };
+
+    // One record more than allowed
+    let resp = test::call_service(&app, ingest(records(4))).await;
+    assert_eq!(resp.status().as_u16(), 413);
+    let body: Value = test::read_body_json(resp).await;
+    assert!(body["message"]
+        .as_str()
+        .unwrap_or_default()
+        .contains("limit of 3 records"));
+    assert_eq!(runs().await?, 0);
+
+    // Larger than the body limit
+    let resp = test::call_service(&app, ingest(records(20))).await;
+    assert_eq!(resp.status().as_u16(), 413);
+    assert_eq!(runs().await?, 0);
+
+    let resp = test::call_service(&app, ingest(records(3))).await;
+    assert_eq!(resp.status().as_u16(), 202);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["staged_items"], 3);
+    assert_eq!(runs().await?, 1);
+
+    Ok(())
+}
@@ -0,0 +1,230 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for workflow run metrics (success rate, durations and item totals over a time window)
+// and all-time run statistics
+
+use super::common::{create_consumer_workflow, setup_app_with_entities};
+use actix_web::test;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// Insert a run queued `age_hours` ago; finished runs get `duration_ms` between start and finish
+async fn insert_run(
+    pool: &sqlx::PgPool,
+    workflow_uuid: Uuid,
+    status: &str,
+    age_hours: i32,
+    duration_ms: Option<i32>,
+    processed: i32,
+    failed: i32,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        "INSERT INTO workflow_runs
+             (workflow_uuid, status, queued_at, started_at, finished_at, processed_items, failed_items)
+         SELECT $1, $2::workflow_run_status, q, q + INTERVAL '1 second',
+                q + INTERVAL '1 second' + make_interval(secs => $4::float8 / 1000), $5, $6
+         FROM (SELECT NOW() - make_interval(hours => $3) AS q) t",
+    )
+    .bind(workflow_uuid)
+    .bind(status)
+    .bind(age_hours)
+    .bind(duration_ms)
+    .bind(processed)
+    .bind(failed)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn create_workflow(pool: &sqlx::PgPool) -> anyhow::Result<Uuid> {
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(pool)
+        .await?;
+    create_consumer_workflow(pool, creator_uuid, json!({ "steps": [] }), true, None).await
+}
+
+fn metrics_request(token: &str, wf_uuid: Uuid, query: &str) -> actix_http::Request {
+    test::TestRequest::get()
+        .uri(&format!("/admin/api/v1/workflows/{wf_uuid}/metrics{query}"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request()
+}
+
+fn assert_close(value: &Value, expected: f64) {
+    let actual = value.as_f64().expect("number");
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[actix_web::test]
+async fn test_workflow_metrics_aggregate_runs_in_window() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_uuid = create_workflow(&pool.pool).await?;
+
+    insert_run(&pool.pool, wf_uuid, "success", 2, Some(1000), 10, 0).await?;
+    insert_run(&pool.pool, wf_uuid, "success", 5, Some(2000), 20, 1).await?;
+    insert_run(&pool.pool, wf_uuid, "success", 30, Some(3000), 30, 2).await?;
+    insert_run(&pool.pool, wf_uuid, "failed", 48, Some(4000), 5, 5).await?;
+    // Still running: counted as a run, but without a duration or outcome
+    insert_run(&pool.pool, wf_uuid, "running", 1, None, 0, 0).await?;
+    // Outside the default 7 day window
+    insert_run(
+        &pool.pool,
+        wf_uuid,
+        "success",
+        10 * 24,
+        Some(100_000),
+        100,
+        0,
+    )
+    .await?;
+
+    let resp = test::call_service(&app, metrics_request(&token, wf_uuid, "")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let data = &body["data"];
+    assert_eq!(data["range"], json!("7d"));
+    assert_eq!(data["total_runs"], json!(5));
+    assert_eq!(data["successful_runs"], json!(3));
+    assert_eq!(data["failed_runs"], json!(1));
+    assert_eq!(data["total_processed_items"], json!(65));
+    assert_eq!(data["total_failed_items"], json!(8));
+    assert_close(&data["success_rate"], 0.75);
+    assert_close(&data["avg_duration_ms"], 2500.0);
+    // Interpolated: 3000 + 0.85 * (4000 - 3000)
+    assert_close(&data["p95_duration_ms"], 3850.0);
+
+    // A wider window picks up the older run
+    let resp = test::call_service(&app, metrics_request(&token, wf_uuid, "?range=30d")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let data = &body["data"];
+    assert_eq!(data["total_runs"], json!(6));
+    assert_eq!(data["total_processed_items"], json!(165));
+    assert_close(&data["success_rate"], 0.8);
+    assert_close(&data["avg_duration_ms"], 22_000.0);
+
+    // A narrower window only sees the most recent runs
+    let resp = test::call_service(&app, metrics_request(&token, wf_uuid, "?range=3h")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let data = &body["data"];
+    assert_eq!(data["total_runs"], json!(2));
+    assert_close(&data["success_rate"], 1.0);
+    assert_close(&data["p95_duration_ms"], 1000.0);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_workflow_metrics_without_runs_has_null_rates() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_uuid = create_workflow(&pool.pool).await?;
+
+    let resp = test::call_service(&app, metrics_request(&token, wf_uuid, "?range=24h")).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let data = &body["data"];
+    assert_eq!(data["total_runs"], json!(0));
+    assert_eq!(data["total_processed_items"], json!(0));
+    assert_eq!(data["success_rate"], Value::Null);
+    assert_eq!(data["avg_duration_ms"], Value::Null);
+    assert_eq!(data["p95_duration_ms"], Value::Null);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_workflow_metrics_rejects_invalid_range() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_uuid = create_workflow(&pool.pool).await?;
+
+    for range in ["7", "0d", "91d", "1w", "abc"] {
+        let resp = test::call_service(
+            &app,
+            metrics_request(&token, wf_uuid, &format!("?range={range}")),
+        )
+        .await;
+        assert_eq!(resp.status().as_u16(), 422, "range {range}");
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_workflow_metrics_unknown_workflow_returns_404() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+
+    let resp = test::call_service(&app, metrics_request(&token, Uuid::now_v7(), "")).await;
+    assert_eq!(resp.status().as_u16(), 404);
+
+    Ok(())
+}
+
+fn stats_request(token: &str, wf_uuid: Uuid) -> actix_http::Request {
+    test::TestRequest::get()
+        .uri(&format!("/admin/api/v1/workflows/{wf_uuid}/stats"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request()
+}
+
+#[actix_web::test]
+async fn test_workflow_run_stats_aggregate_all_runs() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_uuid = create_workflow(&pool.pool).await?;
+
+    insert_run(&pool.pool, wf_uuid, "success", 2, Some(1000), 10, 0).await?;
+    insert_run(&pool.pool, wf_uuid, "success", 5, Some(2000), 20, 1).await?;
+    insert_run(&pool.pool, wf_uuid, "failed", 30, Some(3000), 30, 2).await?;
+    insert_run(&pool.pool, wf_uuid, "failed", 48, Some(4000), 5, 5).await?;
+    insert_run(&pool.pool, wf_uuid, "cancelled", 72, None, 0, 0).await?;
+    // Most recent run, still in progress
+    insert_run(&pool.pool, wf_uuid, "running", 1, None, 0, 0).await?;
+    // Runs of other workflows are not counted
+    let other_wf = create_workflow(&pool.pool).await?;
+    insert_run(&pool.pool, other_wf, "failed", 1, Some(9000), 0, 1).await?;
+
+    let resp = test::call_service(&app, stats_request(&token, wf_uuid)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let data = &body["data"];
+    assert_eq!(data["total_runs"], json!(6));
+    assert_eq!(data["queued_runs"], json!(0));
+    assert_eq!(data["running_runs"], json!(1));
+    assert_eq!(data["successful_runs"], json!(2));
+    assert_eq!(data["failed_runs"], json!(2));
+    assert_eq!(data["cancelled_runs"], json!(1));
+    assert_eq!(data["runs_last_24h"], json!(3));
+    assert_eq!(data["successful_runs_last_24h"], json!(2));
+    assert_eq!(data["failed_runs_last_24h"], json!(0));
+    assert_close(&data["success_rate"], 0.5);
+    assert_close(&data["avg_duration_ms"], 2500.0);
+    assert_close(&data["p95_duration_ms"], 3850.0);
+    assert_eq!(data["last_run_status"], json!("running"));
+    assert!(data["last_run_at"].is_string());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_workflow_run_stats_without_runs() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let wf_uuid = create_workflow(&pool.pool).await?;
+
+    let resp = test::call_service(&app, stats_request(&token, wf_uuid)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    let data = &body["data"];
+    assert_eq!(data["total_runs"], json!(0));
+    assert_eq!(data["success_rate"], Value::Null);
+    assert_eq!(data["avg_duration_ms"], Value::Null);
+    assert_eq!(data["last_run_status"], Value::Null);
+    assert_eq!(data["last_run_at"], Value::Null);
+
+    let resp = test::call_service(&app, stats_request(&token, Uuid::now_v7())).await;
+    assert_eq!(resp.status().as_u16(), 404);
+
+    Ok(())
+}
@@ -1,16 +1,29 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 // Workflow E2E tests organized by use case
 
+pub mod authenticate_transform_tests;
 pub mod common;
+pub mod cron_preview_tests;
+pub mod dry_run_tests;
+pub mod dsl_test_batch_tests;
 pub mod export_async_tests;
 pub mod export_cron_tests;
 pub mod export_filter_tests;
 pub mod export_mapping_tests;
 pub mod export_security_tests;
 pub mod expose_via_api_tests;
+pub mod idempotency_tests;
+pub mod ingest_limits_tests;
+pub mod metrics_tests;
+pub mod pause_tests;
 pub mod post_endpoint_tests;
 pub mod pull_from_remote_tests;
 pub mod push_to_remote_tests;
 pub mod route_conflict_tests;
+pub mod run_errors_tests;
+pub mod run_override_tests;
+pub mod run_progress_tests;
+pub mod run_stream_tests;
+pub mod source_retry_tests;
 pub mod trigger_endpoint_tests;
 pub mod trigger_example_tests;
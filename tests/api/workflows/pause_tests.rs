@@ -0,0 +1,110 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for pausing and resuming scheduled workflows: a paused workflow keeps its cron schedule
+// but is left out of the scheduled consumers until it is resumed
+
+use super::common::{
+    create_consumer_workflow, create_test_entity_definition, generate_entity_type,
+    setup_app_with_entities,
+};
+use actix_web::test;
+use r_data_core_persistence::WorkflowRepository;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const CRON: &str = "*/10 * * * *";
+
+fn uri_consumer_config(entity_type: &str) -> Value {
+    json!({
+        "steps": [{
+            "from": {
+                "type": "format",
+                "source": {
+                    "source_type": "uri",
+                    "config": { "uri": "http://example.com/data.csv" },
+                    "auth": null
+                },
+                "format": { "format_type": "csv", "options": { "has_header": true } },
+                "mapping": {}
+            },
+            "transform": { "type": "none" },
+            "to": {
+                "type": "entity",
+                "entity_definition": entity_type,
+                "path": "/",
+                "mode": "create",
+                "mapping": {}
+            }
+        }]
+    })
+}
+
+async fn is_scheduled(repo: &WorkflowRepository, wf_uuid: Uuid) -> anyhow::Result<bool> {
+    Ok(repo
+        .list_scheduled_consumers()
+        .await?
+        .iter()
+        .any(|(uuid, _)| *uuid == wf_uuid))
+}
+
+#[actix_web::test]
+async fn test_paused_workflow_is_not_scheduled_until_resumed() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+    let entity_type = generate_entity_type("pause_wf");
+    create_test_entity_definition(&pool, &entity_type).await?;
+    let wf_uuid = create_consumer_workflow(
+        &pool,
+        creator_uuid,
+        uri_consumer_config(&entity_type),
+        true,
+        Some(CRON.to_string()),
+    )
+    .await?;
+    let repo = WorkflowRepository::new(pool.pool.clone());
+    assert!(is_scheduled(&repo, wf_uuid).await?);
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/api/v1/workflows/{wf_uuid}/pause"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    assert!(!is_scheduled(&repo, wf_uuid).await?);
+
+    // The schedule survives the pause and the flag is reported on the workflow
+    let req = test::TestRequest::get()
+        .uri(&format!("/admin/api/v1/workflows/{wf_uuid}"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request();
+    let body: Value = test::read_body_json(test::call_service(&app, req).await).await;
+    assert_eq!(body["data"]["paused"], json!(true));
+    assert_eq!(body["data"]["schedule_cron"], json!(CRON));
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/api/v1/workflows/{wf_uuid}/resume"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    assert!(is_scheduled(&repo, wf_uuid).await?);
+    assert!(!repo.get_by_uuid(wf_uuid).await?.expect("workflow").paused);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_pause_unknown_workflow_returns_404() -> anyhow::Result<()> {
+    let (app, _pool, token, _) = setup_app_with_entities().await?;
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/api/v1/workflows/{}/pause", Uuid::now_v7()))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 404);
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for per-record run errors: a failing record is recorded with its index and payload
+// while the remaining records of the run are still processed
+
+use super::common::{
+    create_consumer_workflow, create_test_entity_definition, generate_entity_type,
+    load_workflow_example, setup_app_with_entities,
+};
+use actix_web::test;
+use r_data_core_persistence::{
+    DynamicEntityRepository, EntityDefinitionRepository, WorkflowRepository,
+};
+use r_data_core_services::{
+    DynamicEntityService, EntityDefinitionService, WorkflowRepositoryAdapter, WorkflowService,
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn errors_request(token: &str, wf_uuid: Uuid, run_uuid: Uuid) -> actix_http::Request {
+    test::TestRequest::get()
+        .uri(&format!(
+            "/admin/api/v1/workflows/{wf_uuid}/runs/{run_uuid}/errors"
+        ))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request()
+}
+
+/// Create an entity-import workflow and stage `records` in a new run of it
+///
+/// # Errors
+/// Returns an error if the workflow or the staged items cannot be created
+pub async fn stage_run(pool: &sqlx::PgPool, records: Vec<Value>) -> anyhow::Result<(Uuid, Uuid)> {
+    let entity_type = generate_entity_type("run_errors");
+    create_test_entity_definition(pool, &entity_type).await?;
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(pool)
+        .await?;
+    let config = load_workflow_example("workflow_csv_to_entity.json", &entity_type)?;
+    let wf_uuid = create_consumer_workflow(pool, creator_uuid, config, true, None).await?;
+
+    let repo = WorkflowRepository::new(pool.clone());
+    let run_uuid = repo.insert_run_queued(wf_uuid, Uuid::now_v7()).await?;
+    repo.insert_raw_items(wf_uuid, run_uuid, records).await?;
+    Ok((wf_uuid, run_uuid))
+}
+
+/// Workflow service processing staged items into entities
+#[must_use]
+pub fn processing_service(pool: &sqlx::PgPool) -> WorkflowService {
+    let definitions = Arc::new(EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(pool.clone()),
+    )));
+    WorkflowService::new_with_entities(
+        Arc::new(WorkflowRepositoryAdapter::new(WorkflowRepository::new(
+            pool.clone(),
+        ))),
+        Arc::new(DynamicEntityService::new(
+            Arc::new(DynamicEntityRepository::new(pool.clone())),
+            definitions,
+        )),
+    )
+}
+
+#[actix_web::test]
+async fn test_failing_record_is_recorded_and_run_continues() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    // The middle record lacks the required email field
+    let failing_record = json!({ "name": "No Email" });
+    let (wf_uuid, run_uuid) = stage_run(
+        &pool.pool,
+        vec![
+            json!({ "email": "first@example.com", "name": "First" }),
+            failing_record.clone(),
+            json!({ "email": "third@example.com", "name": "Third" }),
+        ],
+    )
+    .await?;
+
+    let (processed, failed) = processing_service(&pool.pool)
+        .process_staged_items(wf_uuid, run_uuid)
+        .await?;
+    assert_eq!((processed, failed), (2, 1));
+
+    let resp = test::call_service(&app, errors_request(&token, wf_uuid, run_uuid)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["meta"]["pagination"]["total"], json!(1));
+    let errors = body["data"].as_array().expect("errors array");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["record_index"], json!(1));
+    assert_eq!(errors[0]["raw_payload"], failing_record);
+    assert!(!errors[0]["error_message"]
+        .as_str()
+        .unwrap_or_default()
+        .is_empty());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_run_errors_of_other_workflow_returns_404() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let (_wf_uuid, run_uuid) = stage_run(&pool.pool, vec![json!({ "name": "x" })]).await?;
+
+    let resp = test::call_service(&app, errors_request(&token, Uuid::now_v7(), run_uuid)).await;
+    assert_eq!(resp.status().as_u16(), 404);
+
+    Ok(())
+}
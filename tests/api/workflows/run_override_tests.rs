@@ -0,0 +1,181 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for per-run config overrides on trigger/run-now: the override applies to that run only
+// and the stored workflow config is left untouched
+
+use super::common::{
+    create_consumer_workflow, create_test_entity_definition, generate_entity_type,
+    setup_app_with_entities,
+};
+use actix_web::test;
+use r_data_core_test_support::create_test_entity;
+use serde_json::json;
+use uuid::Uuid;
+
+/// Trigger step followed by an entity source filtered on `name`
+fn filtered_export_config(entity_type: &str, name: &str) -> serde_json::Value {
+    let api_output = json!({
+        "type": "format",
+        "output": { "mode": "api" },
+        "format": { "format_type": "json", "options": {} },
+        "mapping": {}
+    });
+    json!({
+        "steps": [
+            {
+                "from": { "type": "trigger", "mapping": {} },
+                "transform": { "type": "none" },
+                "to": api_output
+            },
+            {
+                "from": {
+                    "type": "entity",
+                    "entity_definition": entity_type,
+                    "filter": { "field": "name", "operator": "=", "value": name },
+                    "mapping": { "name": "name" }
+                },
+                "transform": { "type": "none" },
+                "to": api_output
+            }
+        ]
+    })
+}
+
+async fn stored_config(pool: &sqlx::PgPool, wf_uuid: Uuid) -> anyhow::Result<serde_json::Value> {
+    Ok(
+        sqlx::query_scalar("SELECT config FROM workflows WHERE uuid = $1")
+            .bind(wf_uuid)
+            .fetch_one(pool)
+            .await?,
+    )
+}
+
+#[actix_web::test]
+async fn test_trigger_override_applies_to_single_run_only() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+
+    let entity_type = generate_entity_type("run_override");
+    create_test_entity_definition(&pool, &entity_type).await?;
+    create_test_entity(&pool, &entity_type, "Alice", "alice@example.com").await?;
+    create_test_entity(&pool, &entity_type, "Bob", "bob1@example.com").await?;
+    create_test_entity(&pool, &entity_type, "Bob", "bob2@example.com").await?;
+
+    let config = filtered_export_config(&entity_type, "Alice");
+    let wf_uuid = create_consumer_workflow(&pool, creator_uuid, config.clone(), true, None).await?;
+
+    // Run once with the filter pointed at "Bob"
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/v1/workflows/{wf_uuid}/trigger"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({
+            "config": { "steps": [{}, { "from": { "filter": { "value": "Bob" } } }] }
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 202);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["staged_items"], 2, "override should select both Bobs");
+    let override_run: Uuid = serde_json::from_value(body["run_uuid"].clone())?;
+
+    // The next run without a body uses the stored config again
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/v1/workflows/{wf_uuid}/trigger"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 202);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["staged_items"], 1, "stored config selects only Alice");
+    let plain_run: Uuid = serde_json::from_value(body["run_uuid"].clone())?;
+
+    assert_eq!(stored_config(&pool.pool, wf_uuid).await?, config);
+
+    let override_of = |run: Uuid| {
+        sqlx::query_scalar::<_, Option<serde_json::Value>>(
+            "SELECT config_override FROM workflow_runs WHERE uuid = $1",
+        )
+        .bind(run)
+        .fetch_one(&pool.pool)
+    };
+    assert!(override_of(override_run).await?.is_some());
+    assert!(override_of(plain_run).await?.is_none());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_trigger_rejects_invalid_override() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+
+    let entity_type = generate_entity_type("run_override");
+    create_test_entity_definition(&pool, &entity_type).await?;
+    let config = filtered_export_config(&entity_type, "Alice");
+    let wf_uuid = create_consumer_workflow(&pool, creator_uuid, config.clone(), true, None).await?;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/v1/workflows/{wf_uuid}/trigger"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "config": { "steps": [{ "from": { "type": "unknown" } }] } }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 400);
+
+    let runs: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM workflow_runs WHERE workflow_uuid = $1")
+            .bind(wf_uuid)
+            .fetch_one(&pool.pool)
+            .await?;
+    assert_eq!(runs, 0, "invalid override must not create a run");
+    assert_eq!(stored_config(&pool.pool, wf_uuid).await?, config);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_admin_run_now_stores_override_on_run() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(&pool.pool)
+        .await?;
+
+    let entity_type = generate_entity_type("run_override");
+    create_test_entity_definition(&pool, &entity_type).await?;
+    let config = filtered_export_config(&entity_type, "Alice");
+    let wf_uuid = create_consumer_workflow(&pool, creator_uuid, config.clone(), true, None).await?;
+
+    let config_override = json!({ "steps": [{}, { "from": { "filter": { "value": "Bob" } } }] });
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/api/v1/workflows/{wf_uuid}/run"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "config": config_override }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.status().is_success(), "got {}", resp.status());
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    let run_uuid: Uuid = serde_json::from_value(body["data"]["run_uuid"].clone())?;
+
+    let stored_override: Option<serde_json::Value> =
+        sqlx::query_scalar("SELECT config_override FROM workflow_runs WHERE uuid = $1")
+            .bind(run_uuid)
+            .fetch_one(&pool.pool)
+            .await?;
+    assert_eq!(stored_override, Some(config_override));
+    assert_eq!(stored_config(&pool.pool, wf_uuid).await?, config);
+
+    // Invalid overrides are rejected before a run is created
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/api/v1/workflows/{wf_uuid}/run"))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .set_json(json!({ "config": { "steps": [{ "from": { "type": "unknown" } }] } }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status().as_u16(), 422);
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for run progress: processed/total counts are stored on the run after every batch of
+// staged items, so they can be read while the run is still going
+
+use super::common::setup_app_with_entities;
+use super::run_errors_tests::{processing_service, stage_run};
+use actix_web::test;
+use r_data_core_services::{RunEvent, RunEventBus};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+fn progress_request(token: &str, wf_uuid: Uuid, run_uuid: Uuid) -> actix_http::Request {
+    test::TestRequest::get()
+        .uri(&format!(
+            "/admin/api/v1/workflows/{wf_uuid}/runs/{run_uuid}/progress"
+        ))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request()
+}
+
+#[actix_web::test]
+async fn test_progress_is_stored_after_each_batch() -> anyhow::Result<()> {
+    let (app, pool, token, _) = setup_app_with_entities().await?;
+    let (wf_uuid, run_uuid) = stage_run(
+        &pool.pool,
+        vec![
+            json!({ "email": "first@example.com", "name": "First" }),
+            json!({ "name": "No Email" }),
+            json!({ "email": "third@example.com", "name": "Third" }),
+        ],
+    )
+    .await?;
+
+    // Nothing processed before the run starts
+    let resp = test::call_service(&app, progress_request(&token, wf_uuid, run_uuid)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["status"], "queued");
+    assert_eq!(body["data"]["processed_count"], 0);
+    assert_eq!(body["data"]["total_count"], 0);
+
+    let bus = RunEventBus::new();
+    let mut events = bus.subscribe();
+    let (processed, failed) = processing_service(&pool.pool)
+        .with_staged_batch_size(2)
+        .with_run_events(bus)
+        .process_staged_items(wf_uuid, run_uuid)
+        .await?;
+    assert_eq!((processed, failed), (2, 1));
+
+    // Progress events are published once the counts are stored: before the first batch, after
+    // the first batch of two items and after the last item
+    let mut progress = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        if let RunEvent::Progress {
+            processed_count,
+            failed_count,
+            total_count,
+            ..
+        } = event
+        {
+            progress.push((processed_count, failed_count, total_count));
+        }
+    }
+    assert_eq!(progress, vec![(0, 0, 3), (1, 1, 3), (2, 1, 3)]);
+
+    let resp = test::call_service(&app, progress_request(&token, wf_uuid, run_uuid)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["data"]["processed_count"], 2);
+    assert_eq!(body["data"]["failed_count"], 1);
+    assert_eq!(body["data"]["total_count"], 3);
+
+    // Runs are scoped to their workflow
+    let resp = test::call_service(&app, progress_request(&token, Uuid::now_v7(), run_uuid)).await;
+    assert_eq!(resp.status().as_u16(), 404);
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for the run progress SSE stream: status transitions and log entries are streamed until
+// the run reaches a terminal state, both for runs executed in-process (published on the run
+// event bus) and for runs of other processes (picked up by polling)
+
+use std::time::Duration;
+
+use super::common::{create_consumer_workflow, create_test_api_state, load_workflow_example};
+use actix_web::{test, web, App};
+use r_data_core_api::{configure_app, ApiStateWrapper};
+use r_data_core_persistence::WorkflowRepository;
+use r_data_core_services::{RunEventBus, WorkflowRepositoryAdapter, WorkflowService};
+use r_data_core_test_support::{create_test_admin_user, setup_test_db, TestDatabase};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Log in through the admin API as a new admin user, returning the access token
+///
+/// # Errors
+/// Returns an error if the admin user cannot be created
+///
+/// # Panics
+/// Panics if the login is rejected
+#[allow(clippy::future_not_send)] // actix-web test utilities use Rc internally
+pub async fn login<S, B>(app: &S, pool: &TestDatabase) -> anyhow::Result<String>
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    B: actix_web::body::MessageBody,
+{
+    let user_uuid = create_test_admin_user(pool).await?;
+    let username: String = sqlx::query_scalar("SELECT username FROM admin_users WHERE uuid = $1")
+        .bind(user_uuid)
+        .fetch_one(&pool.pool)
+        .await?;
+    let req = test::TestRequest::post()
+        .uri("/admin/api/v1/auth/login")
+        .set_json(json!({ "username": username, "password": "adminadmin" }))
+        .to_request();
+    let resp = test::call_service(app, req).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    let body: Value = test::read_body_json(resp).await;
+    Ok(body["data"]["access_token"]
+        .as_str()
+        .expect("access token")
+        .to_string())
+}
+
+/// Create a consumer workflow with a queued run
+async fn queued_run(pool: &sqlx::PgPool) -> anyhow::Result<(Uuid, Uuid)> {
+    let creator_uuid: Uuid = sqlx::query_scalar("SELECT uuid FROM admin_users LIMIT 1")
+        .fetch_one(pool)
+        .await?;
+    let config = load_workflow_example("workflow_csv_to_entity.json", "run_stream")?;
+    let wf_uuid = create_consumer_workflow(pool, creator_uuid, config, true, None).await?;
+    let run_uuid = WorkflowRepository::new(pool.clone())
+        .insert_run_queued(wf_uuid, Uuid::now_v7())
+        .await?;
+    Ok((wf_uuid, run_uuid))
+}
+
+fn stream_request(token: &str, wf_uuid: Uuid, run_uuid: Uuid) -> actix_http::Request {
+    test::TestRequest::get()
+        .uri(&format!(
+            "/admin/api/v1/workflows/{wf_uuid}/runs/{run_uuid}/stream"
+        ))
+        .insert_header(("Authorization", format!("Bearer {token}")))
+        .to_request()
+}
+
+/// Parse an SSE body into `(event, data)` pairs, skipping comments
+fn parse_events(body: &[u8]) -> Vec<(String, Value)> {
+    String::from_utf8_lossy(body)
+        .split("\n\n")
+        .filter_map(|frame| {
+            let mut event = None;
+            let mut data = None;
+            for line in frame.lines() {
+                if let Some(name) = line.strip_prefix("event: ") {
+                    event = Some(name.to_string());
+                } else if let Some(payload) = line.strip_prefix("data: ") {
+                    data = serde_json::from_str(payload).ok();
+                }
+            }
+            Some((event?, data?))
+        })
+        .collect()
+}
+
+/// Drive a run through running to success, logging in between
+async fn drive_run(service: WorkflowService, run_uuid: Uuid) -> anyhow::Result<()> {
+    service.mark_run_running(run_uuid).await?;
+    service
+        .insert_run_log(run_uuid, "info", "Fetched 2 items", None)
+        .await?;
+    service.mark_run_success(run_uuid, 2, 0).await?;
+    Ok(())
+}
+
+fn worker_service(pool: &sqlx::PgPool) -> WorkflowService {
+    WorkflowService::new(Arc::new(WorkflowRepositoryAdapter::new(
+        WorkflowRepository::new(pool.clone()),
+    )))
+}
+
+#[actix_web::test]
+async fn test_stream_reports_in_process_run_until_success() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let bus = RunEventBus::new();
+    let mut api_state = create_test_api_state(&pool, "test_secret").await;
+    api_state.workflow_service = api_state.workflow_service.with_run_events(bus.clone());
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+    let token = login(&app, &pool).await?;
+    let (wf_uuid, run_uuid) = queued_run(&pool.pool).await?;
+
+    let resp = test::call_service(&app, stream_request(&token, wf_uuid, run_uuid)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+    assert_eq!(
+        resp.headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok()),
+        Some("text/event-stream")
+    );
+
+    let driver = actix_web::rt::spawn(drive_run(
+        worker_service(&pool.pool).with_run_events(bus),
+        run_uuid,
+    ));
+    let body = tokio::time::timeout(Duration::from_secs(10), test::read_body(resp))
+        .await
+        .expect("stream ends after the terminal event");
+    driver.await??;
+
+    let events = parse_events(&body);
+    let statuses: Vec<&str> = events
+        .iter()
+        .filter(|(event, _)| event == "status")
+        .filter_map(|(_, data)| data["status"].as_str())
+        .collect();
+    assert_eq!(statuses, vec!["queued", "running", "success"]);
+    assert!(
+        events
+            .iter()
+            .any(|(event, data)| event == "log" && data["message"] == "Fetched 2 items"),
+        "{events:?}"
+    );
+    let (event, terminal) = events.last().expect("events");
+    assert_eq!(event, "status");
+    assert_eq!(terminal["run_uuid"], json!(run_uuid));
+    assert_eq!(terminal["processed_items"], json!(2));
+
+    // Runs are only streamed under their own workflow
+    let resp = test::call_service(&app, stream_request(&token, Uuid::now_v7(), run_uuid)).await;
+    assert_eq!(resp.status().as_u16(), 404);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_stream_polls_runs_of_other_processes() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let api_state = create_test_api_state(&pool, "test_secret").await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ApiStateWrapper::new(api_state)))
+            .configure(configure_app),
+    )
+    .await;
+    let token = login(&app, &pool).await?;
+    let (wf_uuid, run_uuid) = queued_run(&pool.pool).await?;
+
+    let resp = test::call_service(&app, stream_request(&token, wf_uuid, run_uuid)).await;
+    assert_eq!(resp.status().as_u16(), 200);
+
+    // A service without event bus, like the one of a worker process
+    let driver = actix_web::rt::spawn(drive_run(worker_service(&pool.pool), run_uuid));
+    let body = tokio::time::timeout(Duration::from_secs(15), test::read_body(resp))
+        .await
+        .expect("stream ends after the terminal event");
+    driver.await??;
+
+    let events = parse_events(&body);
+    assert!(
+        events
+            .iter()
+            .any(|(event, data)| event == "log" && data["message"] == "Fetched 2 items"),
+        "{events:?}"
+    );
+    let (event, terminal) = events.last().expect("events");
+    assert_eq!(event, "status");
+    assert_eq!(terminal["status"], "success");
+
+    Ok(())
+}
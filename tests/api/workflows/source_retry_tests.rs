@@ -0,0 +1,67 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for retries of a flaky `uri` source: the run stages the records once the source
+// answers and logs every retried attempt
+
+use std::sync::atomic::Ordering;
+
+use super::common::{
+    create_consumer_workflow, create_test_entity_definition, generate_entity_type,
+    load_workflow_example,
+};
+use super::run_errors_tests::processing_service;
+use crate::adapters::source::flaky_server;
+use r_data_core_persistence::WorkflowRepository;
+use r_data_core_test_support::{create_test_admin_user, setup_test_db};
+use serde_json::json;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_flaky_source_is_retried_and_logged() -> anyhow::Result<()> {
+    let pool = setup_test_db().await;
+    let (url, hits) = flaky_server(
+        vec!["503 Service Unavailable", "503 Service Unavailable"],
+        "email,name\nada@example.com,Ada\ngrace@example.com,Grace\n",
+    )
+    .await;
+
+    let entity_type = generate_entity_type("source_retry");
+    create_test_entity_definition(&pool.pool, &entity_type).await?;
+    let mut config = load_workflow_example("workflow_csv_to_entity.json", &entity_type)?;
+    config["steps"][0]["from"]["source"]["config"] = json!({
+        "uri": format!("{url}/data.csv"),
+        "max_retries": 3,
+        "backoff_ms": 10
+    });
+    let creator_uuid = create_test_admin_user(&pool.pool).await?;
+    let wf_uuid = create_consumer_workflow(&pool.pool, creator_uuid, config, true, None).await?;
+    let run_uuid = WorkflowRepository::new(pool.pool.clone())
+        .insert_run_queued(wf_uuid, Uuid::now_v7())
+        .await?;
+
+    let service = processing_service(&pool.pool);
+    let staged = service
+        .fetch_and_stage_from_config(wf_uuid, run_uuid)
+        .await?;
+    assert_eq!(staged, 2);
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+
+    let (logs, _) = service.list_run_logs_paginated(run_uuid, 50, 0).await?;
+    let mut retries: Vec<(String, serde_json::Value)> = logs
+        .into_iter()
+        .filter(|(_, _, level, _, _)| level == "warn")
+        .map(|(_, _, _, message, meta)| (message, meta.unwrap_or_default()))
+        .collect();
+    retries.sort_by_key(|(_, meta)| meta["attempt"].as_u64());
+    assert_eq!(retries.len(), 2);
+    for (attempt, (message, meta)) in (1..).zip(&retries) {
+        assert_eq!(
+            message,
+            &format!("Source request attempt {attempt} failed, retrying")
+        );
+        assert_eq!(meta["attempt"], attempt);
+        assert_eq!(meta["status"], 503);
+    }
+
+    Ok(())
+}
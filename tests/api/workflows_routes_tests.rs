@@ -4,7 +4,7 @@ use actix_web::{test, web, App};
 use r_data_core_api::{configure_app, ApiState, ApiStateWrapper};
 use r_data_core_core::admin_user::AdminUser;
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_persistence::WorkflowRepository;
 use r_data_core_persistence::{AdminUserRepository, ApiKeyRepository};
 use r_data_core_services::{
@@ -36,6 +36,10 @@ async fn setup_app_and_token() -> anyhow::Result<(
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 300,
         max_size: 10000,
@@ -79,6 +83,10 @@ async fn setup_app_and_token() -> anyhow::Result<(
             enable_docs: true,
             cors_origins: vec![],
             check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         },
         role_service: r_data_core_services::RoleService::new(
             pool.pool.clone(),
@@ -121,6 +129,10 @@ async fn setup_app_and_token() -> anyhow::Result<(
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
     let token = r_data_core_core::admin_jwt::generate_access_token(&user, &api_config, &[])?;
 
@@ -247,6 +259,7 @@ async fn update_workflow_sets_updated_by() -> anyhow::Result<()> {
             ]
         }),
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -465,6 +478,7 @@ async fn update_workflow_validates_dsl_config() -> anyhow::Result<()> {
             ]
         }),
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -555,6 +569,7 @@ async fn run_workflow_now_enqueues_job_to_redis_if_available() -> anyhow::Result
             ]
         }),
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -0,0 +1,151 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Tests for the validating ApiState builder: misconfiguration is reported as a single
+// configuration error naming each problem
+
+use std::sync::Arc;
+
+use r_data_core::state_builder::{ApiStateBuilder, MIN_JWT_SECRET_LENGTH};
+use r_data_core_core::cache::CacheManager;
+use r_data_core_core::config::{
+    ApiConfig, AppConfig, CacheConfig, DatabaseConfig, LicenseConfig, LogConfig, MailConfig,
+    PasswordPolicyConfig, QueueConfig, WorkflowIngestLimits,
+};
+use r_data_core_core::error::Error;
+use r_data_core_test_support::setup_test_db;
+
+fn test_config(jwt_secret: &str) -> AppConfig {
+    AppConfig {
+        environment: "test".to_string(),
+        outbox_enabled: false,
+        outbox_fetch_enabled: false,
+        outbox_push_enabled: false,
+        outbox_retry_base_delay_secs: 1,
+        outbox_retry_multiplier: 2,
+        outbox_retry_max_delay_secs: 60,
+        database: DatabaseConfig {
+            connection_string: String::new(),
+            max_connections: 1,
+            min_connections: 0,
+            acquire_timeout: 5,
+            idle_timeout: 0,
+            read_replica_urls: Vec::new(),
+        },
+        api: ApiConfig {
+            host: "127.0.0.1".to_string(),
+            port: 0,
+            use_tls: false,
+            jwt_secret: jwt_secret.to_string(),
+            jwt_expiration: 3600,
+            enable_docs: false,
+            cors_origins: vec!["*".to_string()],
+            check_default_admin_password: false,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
+        },
+        cache: CacheConfig::default(),
+        log: LogConfig {
+            level: "warn".to_string(),
+            file: None,
+        },
+        queue: QueueConfig {
+            redis_url: String::new(),
+            fetch_key: "queue:fetch".to_string(),
+            process_key: "queue:process".to_string(),
+            email_key: "queue:email".to_string(),
+        },
+        license: LicenseConfig::default(),
+        mail: MailConfig::default(),
+        frontend_base_url: None,
+        password_reset_throttle_seconds: 60,
+        shutdown_timeout_secs: 30,
+        password_policy: PasswordPolicyConfig::default(),
+        workflow_ingest: WorkflowIngestLimits::default(),
+    }
+}
+
+fn config_error(err: Error) -> String {
+    match err {
+        Error::Config(msg) => msg,
+        other => panic!("expected a configuration error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn missing_jwt_secret_is_a_configuration_error() {
+    let db = setup_test_db().await;
+    let config = test_config("");
+    let err = ApiStateBuilder::new(&config)
+        .pool(db.pool.clone())
+        .cache_manager(Arc::new(CacheManager::new(CacheConfig::default())))
+        .build()
+        .await
+        .err()
+        .expect("missing JWT secret must be rejected");
+
+    let msg = config_error(err);
+    assert!(msg.contains("JWT secret is missing"), "got: {msg}");
+    assert!(!msg.contains("database"), "got: {msg}");
+}
+
+#[tokio::test]
+async fn short_jwt_secret_is_a_configuration_error() {
+    let db = setup_test_db().await;
+    let config = test_config("short");
+    let err = ApiStateBuilder::new(&config)
+        .pool(db.pool.clone())
+        .cache_manager(Arc::new(CacheManager::new(CacheConfig::default())))
+        .validate()
+        .await
+        .unwrap_err();
+
+    let msg = config_error(err);
+    assert!(msg.contains("JWT secret is too short"), "got: {msg}");
+    assert!(
+        msg.contains(&format!("at least {MIN_JWT_SECRET_LENGTH}")),
+        "got: {msg}"
+    );
+}
+
+#[tokio::test]
+async fn all_missing_dependencies_are_listed() {
+    let config = test_config("");
+    let err = ApiStateBuilder::new(&config).validate().await.unwrap_err();
+
+    let msg = config_error(err);
+    assert!(
+        msg.contains("database pool is not configured"),
+        "got: {msg}"
+    );
+    assert!(
+        msg.contains("cache manager is not initialized"),
+        "got: {msg}"
+    );
+    assert!(msg.contains("JWT secret is missing"), "got: {msg}");
+}
+
+#[tokio::test]
+async fn valid_dependencies_pass_validation() {
+    let db = setup_test_db().await;
+    let config = test_config("a_sufficiently_long_secret");
+    let builder = ApiStateBuilder::new(&config)
+        .pool(db.pool.clone())
+        .cache_manager(Arc::new(CacheManager::new(CacheConfig::default())));
+
+    assert!(builder.problems().await.is_empty());
+}
+
+#[tokio::test]
+async fn unreadable_breached_password_list_is_listed() {
+    let mut config = test_config("a_sufficiently_long_secret");
+    config.password_policy.breached_passwords_file = Some("/nonexistent/breached.txt".to_string());
+    let err = ApiStateBuilder::new(&config).validate().await.unwrap_err();
+
+    let msg = config_error(err);
+    assert!(
+        msg.contains("breached password list /nonexistent/breached.txt is not readable"),
+        "got: {msg}"
+    );
+}
@@ -30,9 +30,13 @@ mock! {
             description: &str,
             created_by: Uuid,
             expires_in_days: i32,
+            scopes: &[String],
+            allowed_cidrs: &[String],
         ) -> Result<(Uuid, String)>;
         async fn update_last_used(&self, uuid: Uuid) -> Result<()>;
         async fn reassign(&self, uuid: Uuid, new_user_uuid: Uuid) -> Result<()>;
+        async fn update_rate_limit(&self, uuid: Uuid, rate_limit_per_minute: Option<i32>) -> Result<()>;
+        async fn update_expires_at(&self, uuid: Uuid, expires_at: Option<time::OffsetDateTime>) -> Result<()>;
         async fn count_by_user(&self, user_uuid: Uuid) -> Result<i64>;
         async fn get_api_key_roles(&self, api_key_uuid: Uuid) -> Result<Vec<Uuid>>;
         async fn assign_role(&self, api_key_uuid: Uuid, role_uuid: Uuid) -> Result<()>;
@@ -60,6 +64,9 @@ fn create_test_api_key() -> (ApiKey, Uuid) {
         last_used_at: None,
         created_by: user_uuid,
         published: true,
+        scopes: vec![],
+        rate_limit_per_minute: None,
+        allowed_cidrs: vec![],
     };
     (api_key, user_uuid)
 }
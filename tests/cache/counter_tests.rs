@@ -0,0 +1,70 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use super::create_test_cache_manager;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_counter_counts_within_window() {
+    let cache = create_test_cache_manager();
+
+    assert_eq!(cache.increment("counter:a", 60).await.unwrap().0, 1);
+    assert_eq!(cache.increment("counter:a", 60).await.unwrap().0, 2);
+    let (count, remaining) = cache.increment("counter:a", 60).await.unwrap();
+    assert_eq!(count, 3);
+    assert!(remaining > 0 && remaining <= 60, "remaining {remaining}");
+
+    // Counters are independent per key
+    assert_eq!(cache.increment("counter:b", 60).await.unwrap().0, 1);
+}
+
+#[tokio::test]
+async fn test_counter_restarts_after_window() {
+    let cache = create_test_cache_manager();
+
+    cache.increment("counter:window", 1).await.unwrap();
+    assert_eq!(cache.increment("counter:window", 1).await.unwrap().0, 2);
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert_eq!(cache.increment("counter:window", 1).await.unwrap().0, 1);
+}
+
+#[tokio::test]
+async fn test_token_bucket_rejects_when_empty() {
+    let cache = create_test_cache_manager();
+
+    for _ in 0..3 {
+        assert_eq!(cache.take_token("bucket:a", 3, 60).await.unwrap(), None);
+    }
+    // One token refills every 20 seconds
+    let retry_after = cache.take_token("bucket:a", 3, 60).await.unwrap();
+    assert!(
+        retry_after.is_some_and(|secs| (1..=20).contains(&secs)),
+        "retry after {retry_after:?}"
+    );
+
+    // Buckets are independent per key
+    assert_eq!(cache.take_token("bucket:b", 3, 60).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_token_bucket_refills_with_elapsed_time() {
+    let cache = create_test_cache_manager();
+
+    // Two tokens per second
+    for _ in 0..4 {
+        assert_eq!(cache.take_token("bucket:refill", 4, 2).await.unwrap(), None);
+    }
+    assert_eq!(
+        cache.take_token("bucket:refill", 4, 2).await.unwrap(),
+        Some(1)
+    );
+
+    // Part of the window refills part of the bucket
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    assert_eq!(cache.take_token("bucket:refill", 4, 2).await.unwrap(), None);
+    assert!(cache
+        .take_token("bucket:refill", 4, 2)
+        .await
+        .unwrap()
+        .is_some());
+}
@@ -26,6 +26,7 @@ mock! {
         async fn get_by_entity_type(&self, entity_type: &str) -> Result<Option<EntityDefinition>>;
         async fn create(&self, definition: &EntityDefinition) -> Result<Uuid>;
         async fn update(&self, uuid: &Uuid, definition: &EntityDefinition) -> Result<()>;
+        async fn rename_field(&self, uuid: &Uuid, definition: &EntityDefinition, old_name: &str, new_name: &str, rename_in_versions: bool) -> Result<()>;
         async fn delete(&self, uuid: &Uuid) -> Result<()>;
         async fn apply_schema(&self, schema_sql: &str) -> Result<()>;
         async fn update_entity_view_for_entity_definition(&self, entity_definition: &EntityDefinition) -> Result<()>;
@@ -33,6 +34,8 @@ mock! {
         async fn get_view_columns_with_types(&self, view_name: &str) -> Result<HashMap<String, String>>;
         async fn count_view_records(&self, view_name: &str) -> Result<i64>;
         async fn cleanup_unused_entity_view(&self) -> Result<()>;
+        async fn find_table_owner(&self, table_name: &str, exclude: Option<Uuid>) -> Result<Option<String>>;
+        async fn is_foreign_table(&self, table_name: &str) -> Result<bool>;
     }
 }
 
@@ -53,6 +56,7 @@ fn create_test_entity_definition() -> EntityDefinition {
         ui_settings: r_data_core_core::field::ui::UiSettings::default(),
         constraints: std::collections::HashMap::default(),
         validation: r_data_core_core::field::FieldValidation::default(),
+        display_order: 0,
     }];
 
     let mut properties = HashMap::new();
@@ -72,6 +76,10 @@ fn create_test_entity_definition() -> EntityDefinition {
         allow_children: false,
         icon: None,
         fields: field_definitions,
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: r_data_core_core::entity_definition::schema::Schema::new(properties),
         created_at: now,
         updated_at: now,
@@ -205,6 +213,9 @@ async fn test_cache_invalidation_on_update() -> Result<()> {
     updated_definition.display_name = "Updated Entity".to_string();
 
     let mut mock_repo2 = MockEntityDefinitionRepo::new();
+    mock_repo2
+        .expect_find_table_owner()
+        .returning(|_, _| Ok(None));
     let definition_clone_for_get = definition.clone();
     // First call: get existing definition before update
     mock_repo2
@@ -289,6 +300,7 @@ async fn test_cache_invalidation_on_delete() -> Result<()> {
         .expect_check_view_exists()
         .times(1)
         .returning(|_| Ok(false));
+    mock_repo2.expect_list().returning(|_, _| Ok(vec![]));
     mock_repo2
         .expect_delete()
         .withf(move |id| id == &uuid)
@@ -324,6 +336,10 @@ async fn test_cache_invalidation_on_delete() -> Result<()> {
 #[tokio::test]
 async fn test_cache_on_create() -> Result<()> {
     let mut mock_repo = MockEntityDefinitionRepo::new();
+    mock_repo
+        .expect_find_table_owner()
+        .returning(|_, _| Ok(None));
+    mock_repo.expect_is_foreign_table().returning(|_| Ok(false));
     let cache_manager = create_test_cache_manager();
     let definition = create_test_entity_definition();
     let entity_type = definition.entity_type.clone();
@@ -0,0 +1,93 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use r_data_core_core::cache::CacheManager;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
+
+fn cache_with_policy(eviction_policy: CacheEvictionPolicy, max_size: u64) -> CacheManager {
+    CacheManager::new(CacheConfig {
+        max_size,
+        eviction_policy,
+        ..CacheConfig::default()
+    })
+}
+
+async fn is_cached(cache: &CacheManager, key: &str) -> bool {
+    cache.get::<String>(key).await.unwrap().is_some()
+}
+
+#[tokio::test]
+async fn test_lru_evicts_least_recently_used_key() {
+    let cache = cache_with_policy(CacheEvictionPolicy::Lru, 3);
+    for key in ["a", "b", "c"] {
+        cache.set(key, &key, None).await.unwrap();
+    }
+
+    // Reading "a" makes "b" the least recently used key
+    assert!(is_cached(&cache, "a").await);
+    cache.set("d", &"d", None).await.unwrap();
+
+    assert!(!is_cached(&cache, "b").await);
+    for key in ["a", "c", "d"] {
+        assert!(is_cached(&cache, key).await, "{key} should be cached");
+    }
+    let stats = cache.stats();
+    assert_eq!(stats.entries, 3);
+    assert_eq!(stats.evictions, 1);
+}
+
+#[tokio::test]
+async fn test_overwriting_a_key_does_not_evict() {
+    let cache = cache_with_policy(CacheEvictionPolicy::Lru, 2);
+    cache.set("a", &"a", None).await.unwrap();
+    cache.set("b", &"b", None).await.unwrap();
+    cache.set("a", &"a2", None).await.unwrap();
+
+    assert!(is_cached(&cache, "b").await);
+    assert_eq!(cache.stats().evictions, 0);
+}
+
+#[tokio::test]
+async fn test_lfu_evicts_least_frequently_used_key() {
+    let cache = cache_with_policy(CacheEvictionPolicy::Lfu, 3);
+    for key in ["a", "b", "c"] {
+        cache.set(key, &key, None).await.unwrap();
+    }
+
+    // "a" is read most often but "c" most recently; "b" is read least
+    for _ in 0..3 {
+        assert!(is_cached(&cache, "a").await);
+    }
+    assert!(is_cached(&cache, "b").await);
+    for _ in 0..2 {
+        assert!(is_cached(&cache, "c").await);
+    }
+    cache.set("d", &"d", None).await.unwrap();
+
+    assert!(!is_cached(&cache, "b").await);
+    for key in ["a", "c", "d"] {
+        assert!(is_cached(&cache, key).await, "{key} should be cached");
+    }
+    assert_eq!(cache.stats().evictions, 1);
+}
+
+#[tokio::test]
+async fn test_ttl_only_evicts_key_closest_to_expiry() {
+    let cache = cache_with_policy(CacheEvictionPolicy::TtlOnly, 3);
+    cache.set("short", &"short", Some(60)).await.unwrap();
+    cache.set("long", &"long", Some(3600)).await.unwrap();
+    cache.set("forever", &"forever", Some(0)).await.unwrap();
+
+    // Recency does not matter: "short" goes first even though it was read last
+    assert!(is_cached(&cache, "short").await);
+    cache.set("new", &"new", Some(600)).await.unwrap();
+    assert!(!is_cached(&cache, "short").await);
+
+    // Entries without expiry are evicted last
+    cache.set("newer", &"newer", Some(7200)).await.unwrap();
+    assert!(!is_cached(&cache, "new").await);
+    assert!(is_cached(&cache, "forever").await);
+
+    let stats = cache.stats();
+    assert_eq!(stats.entries, 3);
+    assert_eq!(stats.evictions, 2);
+}
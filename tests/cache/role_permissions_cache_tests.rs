@@ -0,0 +1,67 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use super::create_test_cache_manager;
+use r_data_core_core::error::Result;
+use r_data_core_core::permissions::role::{
+    AccessLevel, Permission, PermissionType, ResourceNamespace, Role,
+};
+use r_data_core_core::permissions::{
+    DefaultPermissionService, PermissionChecker, PermissionContext, PermissionScope,
+    RolePermissionRepository, ROLE_PERMISSIONS_CACHE_TTL_SECONDS,
+};
+use r_data_core_services::RoleService;
+use r_data_core_test_support::{create_test_admin_user, setup_test_db, unique_entity_type};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn role_update_invalidates_permissions_cached_per_role_set() -> Result<()> {
+    let db = setup_test_db().await;
+    let cache = create_test_cache_manager();
+    let service = RoleService::new(db.pool.clone(), cache.clone(), None);
+    let actor = create_test_admin_user(&db.pool).await?;
+
+    let mut role = Role::new(unique_entity_type("cached_role"));
+    role.permissions = vec![Permission {
+        resource_type: ResourceNamespace::Entities,
+        permission_type: PermissionType::Read,
+        access_level: AccessLevel::All,
+        resource_uuids: vec![],
+        constraints: None,
+    }];
+    let uuid = service.create_role(&role, actor).await?;
+
+    let ctx = PermissionContext {
+        user_uuid: Uuid::now_v7(),
+        organization_uuid: None,
+        roles: vec![role.name.clone()],
+    };
+    let scope = PermissionScope::Entity {
+        entity_type: "customer".to_string(),
+        entity_uuid: None,
+    };
+    let checker = |role: Role| {
+        DefaultPermissionService::new(RolePermissionRepository::new(vec![role]))
+            .with_cache(cache.clone(), ROLE_PERMISSIONS_CACHE_TTL_SECONDS)
+    };
+
+    let stored = service.get_role(uuid).await?.expect("role should exist");
+    assert!(
+        checker(stored.clone())
+            .is_allowed(&ctx, &scope, &PermissionType::Read)
+            .await?
+    );
+
+    let mut revoked = stored;
+    revoked.permissions.clear();
+    service.update_role(&revoked, actor).await?;
+
+    let stored = service.get_role(uuid).await?.expect("role should exist");
+    assert!(
+        !checker(stored)
+            .is_allowed(&ctx, &scope, &PermissionType::Read)
+            .await?,
+        "permissions cached for the role set must not outlive the role update"
+    );
+
+    Ok(())
+}
@@ -5,7 +5,7 @@
 use std::sync::Arc;
 
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_services::CacheService;
 use uuid::Uuid;
 
@@ -16,6 +16,10 @@ const fn create_test_config() -> CacheConfig {
         max_size: 1000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     }
 }
 
@@ -35,24 +39,34 @@ async fn test_cache_service_clear_all_with_redis() {
         return;
     };
 
-    // Set some test values with unique prefixes
-    let prefix = format!("test:clear_all:{}", Uuid::now_v7().simple());
-    let key1 = format!("{prefix}:1");
-    let key2 = format!("{prefix}:2");
+    // Set some cache values plus a key outside every cache namespace
+    let id = Uuid::now_v7().simple();
+    let key1 = format!("entity_def:test_clear_all_{id}");
+    let key2 = format!("api_key:test_clear_all_{id}");
+    let revoked = format!("jwt:revoked:test_clear_all_{id}");
 
     manager.set(&key1, &"value1", None).await.unwrap();
     manager.set(&key2, &"value2", None).await.unwrap();
+    manager.set(&revoked, &true, None).await.unwrap();
 
     // Verify values exist
     let result: Option<String> = manager.get(&key1).await.unwrap();
     assert!(result.is_some());
 
-    // Clear all cache
-    service.clear_all().await.unwrap();
+    // Clear all cache; other tests may share the database, so only a lower bound holds
+    let cleared = service.clear_all().await.unwrap();
+    assert!(cleared >= 2);
 
     // Values should be gone
     let result: Option<String> = manager.get(&key1).await.unwrap();
     assert!(result.is_none());
+
+    // Keys outside the cache namespaces are left alone
+    let result: Option<bool> = manager.get(&revoked).await.unwrap();
+    assert_eq!(result, Some(true));
+
+    // Cleanup
+    manager.delete(&revoked).await.unwrap();
 }
 
 #[tokio::test]
@@ -103,8 +117,8 @@ async fn test_cache_service_with_in_memory_only() {
     let service = CacheService::new(manager.clone());
 
     // Set some values
-    let key1 = format!("test:inmem:{}:1", Uuid::now_v7().simple());
-    let key2 = format!("test:inmem:{}:2", Uuid::now_v7().simple());
+    let key1 = format!("role:test_inmem_{}", Uuid::now_v7().simple());
+    let key2 = format!("settings:test_inmem_{}", Uuid::now_v7().simple());
 
     manager.set(&key1, &"value1", None).await.unwrap();
     manager.set(&key2, &"value2", None).await.unwrap();
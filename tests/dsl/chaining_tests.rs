@@ -235,3 +235,112 @@ async fn test_next_step_empty_mapping() {
     assert_eq!(output["x"], 10.0);
     assert_eq!(output["y"], 11.0);
 }
+
+fn api_step(mapping: &serde_json::Value, to: &serde_json::Value) -> serde_json::Value {
+    json!({
+        "from": {
+            "type": "format",
+            "source": { "source_type": "api", "config": {} },
+            "format": { "format_type": "json", "options": {} },
+            "mapping": mapping
+        },
+        "transform": { "type": "none" },
+        "to": to
+    })
+}
+
+fn api_output() -> serde_json::Value {
+    json!({
+        "type": "format",
+        "output": { "mode": "api" },
+        "format": { "format_type": "json", "options": {} },
+        "mapping": {}
+    })
+}
+
+#[tokio::test]
+#[serial]
+async fn test_step_two_maps_fields_produced_by_step_one() {
+    let cfg = json!({
+        "steps": [
+            {
+                "from": {
+                    "type": "format",
+                    "source": { "source_type": "api", "config": {} },
+                    "format": { "format_type": "json", "options": {} },
+                    "mapping": { "first": "first", "last": "last" }
+                },
+                "transform": {
+                    "type": "concat",
+                    "target": "full_name",
+                    "left": { "kind": "field", "field": "first" },
+                    "separator": " ",
+                    "right": { "kind": "field", "field": "last" }
+                },
+                "to": { "type": "next_step", "mapping": { "customer.name": "full_name" } }
+            },
+            {
+                "from": { "type": "previous_step", "mapping": { "customer.name": "name" } },
+                "transform": { "type": "none" },
+                "to": {
+                    "type": "format",
+                    "output": { "mode": "api" },
+                    "format": { "format_type": "json", "options": {} },
+                    "mapping": { "display_name": "name" }
+                }
+            }
+        ]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+
+    let results = prog
+        .execute(&json!({ "first": "Ada", "last": "Lovelace", "email": "ada@example.com" }))
+        .expect("execute");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].1,
+        json!({ "customer": { "name": "Ada Lovelace" } })
+    );
+    // Step 2 only sees what step 1 passed on
+    assert_eq!(results[1].1, json!({ "display_name": "Ada Lovelace" }));
+}
+
+#[tokio::test]
+#[serial]
+async fn test_next_step_followed_by_other_source_validation_error() {
+    let cfg = json!({
+        "steps": [
+            api_step(&json!({ "value": "value" }), &json!({ "type": "next_step", "mapping": {} })),
+            api_step(&json!({ "value": "value" }), &api_output())
+        ]
+    });
+
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    let err = prog.validate().unwrap_err();
+    assert_eq!(err.step(), Some(1));
+    assert_eq!(err.field(), Some("from.format"));
+    assert!(
+        err.to_string().contains("use from.previous_step"),
+        "got: {err}"
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn test_trigger_next_step_may_be_followed_by_any_source() {
+    let cfg = json!({
+        "steps": [
+            {
+                "from": { "type": "trigger", "mapping": {} },
+                "transform": { "type": "none" },
+                "to": { "type": "next_step", "mapping": {} }
+            },
+            api_step(&json!({ "value": "value" }), &api_output())
+        ]
+    });
+
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+}
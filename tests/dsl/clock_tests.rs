@@ -0,0 +1,108 @@
+use r_data_core_workflow::dsl::{Clock, DslProgram};
+use serde_json::json;
+use time::macros::datetime;
+
+const FIXED: Clock = Clock::Fixed(datetime!(2024-03-05 14:30:15 UTC));
+
+fn program(transform: &serde_json::Value, mapping: &serde_json::Value) -> DslProgram {
+    let cfg = json!({
+        "steps": [{
+            "from": {
+                "type": "format",
+                "source": { "source_type": "uri", "config": { "uri": "http://example.com/data.csv" } },
+                "format": { "format_type": "csv", "options": {} },
+                "mapping": { "sku": "sku" }
+            },
+            "transform": transform,
+            "to": {
+                "type": "format",
+                "output": { "mode": "api" },
+                "format": { "format_type": "json", "options": {} },
+                "mapping": mapping
+            }
+        }]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+    prog.with_clock(FIXED)
+}
+
+#[test]
+fn test_concat_now_stamps_rfc3339_by_default() {
+    let prog = program(
+        &json!({
+            "type": "concat",
+            "target": "imported_at",
+            "left": { "kind": "now" },
+            "right": { "kind": "const_string", "value": "" }
+        }),
+        &json!({ "sku": "sku", "imported_at": "imported_at" }),
+    );
+
+    let out = prog.apply(&json!({ "sku": "A-1" })).unwrap();
+    assert_eq!(out["imported_at"], json!("2024-03-05T14:30:15Z"));
+    assert_eq!(out["sku"], json!("A-1"));
+}
+
+#[test]
+fn test_concat_today_with_custom_format() {
+    let prog = program(
+        &json!({
+            "type": "concat",
+            "target": "batch",
+            "left": { "kind": "field", "field": "sku" },
+            "separator": "@",
+            "right": { "kind": "today", "format": "[day].[month].[year]" }
+        }),
+        &json!({ "batch": "batch" }),
+    );
+
+    let out = prog.apply(&json!({ "sku": "A-1" })).unwrap();
+    assert_eq!(out["batch"], json!("A-1@05.03.2024"));
+}
+
+#[test]
+fn test_arithmetic_now_and_today_use_unix_seconds() {
+    let prog = program(
+        &json!({
+            "type": "arithmetic",
+            "target": "seconds_into_day",
+            "left": { "kind": "now" },
+            "op": "sub",
+            "right": { "kind": "today" }
+        }),
+        &json!({ "seconds_into_day": "seconds_into_day" }),
+    );
+
+    let out = prog.apply(&json!({ "sku": "A-1" })).unwrap();
+    assert_eq!(out["seconds_into_day"], json!(52215.0));
+}
+
+#[test]
+fn test_invalid_time_format_fails_validation() {
+    let cfg = json!({
+        "steps": [{
+            "from": {
+                "type": "format",
+                "source": { "source_type": "uri", "config": { "uri": "http://example.com/data.csv" } },
+                "format": { "format_type": "csv", "options": {} },
+                "mapping": {}
+            },
+            "transform": {
+                "type": "concat",
+                "target": "stamp",
+                "left": { "kind": "now", "format": "[year" },
+                "right": { "kind": "const_string", "value": "" }
+            },
+            "to": {
+                "type": "format",
+                "output": { "mode": "api" },
+                "format": { "format_type": "json", "options": {} },
+                "mapping": {}
+            }
+        }]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    let err = prog.validate().unwrap_err();
+    assert!(err.to_string().contains("Invalid time format"));
+}
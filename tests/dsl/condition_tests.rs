@@ -0,0 +1,244 @@
+use r_data_core_workflow::dsl::DslProgram;
+use serde_json::{json, Value};
+
+fn step(when: Option<Value>, to: &Value) -> Value {
+    let mut step = json!({
+        "from": {
+            "type": "format",
+            "source": { "source_type": "uri", "config": { "uri": "http://example.com/data.csv" } },
+            "format": { "format_type": "csv", "options": {} },
+            "mapping": { "name": "name", "country": "country", "street": "street" }
+        },
+        "transform": { "type": "none" },
+        "to": to
+    });
+    if let Some(when) = when {
+        step["when"] = when;
+    }
+    step
+}
+
+fn json_to(mapping: &Value) -> Value {
+    json!({
+        "type": "format",
+        "output": { "mode": "api" },
+        "format": { "format_type": "json", "options": {} },
+        "mapping": mapping
+    })
+}
+
+fn address_program() -> DslProgram {
+    let cfg = json!({
+        "steps": [
+            step(None, &json_to(&json!({ "name": "name" }))),
+            step(
+                Some(json!({ "field": "country", "op": "exists" })),
+                &json_to(&json!({ "address.street": "street", "address.country": "country" })),
+            )
+        ]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+    prog
+}
+
+#[test]
+fn test_step_with_false_condition_produces_no_output() {
+    let prog = address_program();
+
+    let outputs = prog
+        .execute(&json!({ "name": "Ada", "street": "Main St 1" }))
+        .unwrap();
+
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].1, json!({ "name": "Ada" }));
+}
+
+#[test]
+fn test_step_with_true_condition_runs_normally() {
+    let prog = address_program();
+
+    let outputs = prog
+        .execute(&json!({ "name": "Ada", "street": "Main St 1", "country": "DE" }))
+        .unwrap();
+
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(
+        outputs[1].1,
+        json!({ "address": { "street": "Main St 1", "country": "DE" } })
+    );
+}
+
+#[test]
+fn test_skipped_step_passes_previous_output_to_next_step() {
+    let cfg = json!({
+        "steps": [
+            {
+                "from": {
+                    "type": "format",
+                    "source": { "source_type": "uri", "config": { "uri": "http://example.com/data.csv" } },
+                    "format": { "format_type": "csv", "options": {} },
+                    "mapping": { "qty": "qty" }
+                },
+                "transform": { "type": "none" },
+                "to": { "type": "next_step", "mapping": { "qty": "qty" } }
+            },
+            {
+                "from": { "type": "previous_step", "mapping": { "qty": "qty" } },
+                "when": { "field": "qty", "op": "gt", "value": 100 },
+                "transform": {
+                    "type": "arithmetic",
+                    "target": "qty",
+                    "left": { "kind": "field", "field": "qty" },
+                    "op": "mul",
+                    "right": { "kind": "const", "value": 2.0 }
+                },
+                "to": { "type": "next_step", "mapping": { "qty": "qty" } }
+            },
+            {
+                "from": { "type": "previous_step", "mapping": { "qty": "qty" } },
+                "transform": { "type": "none" },
+                "to": json_to(&json!({ "qty": "qty" }))
+            }
+        ]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+
+    let outputs = prog.execute(&json!({ "qty": 5 })).unwrap();
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(
+        prog.apply(&json!({ "qty": 5 })).unwrap(),
+        json!({ "qty": 5 })
+    );
+    assert_eq!(
+        prog.apply(&json!({ "qty": 200 })).unwrap(),
+        json!({ "qty": 400.0 })
+    );
+}
+
+#[test]
+fn test_condition_with_unsafe_field_fails_validation() {
+    let cfg = json!({
+        "steps": [step(
+            Some(json!({ "field": "country; drop", "op": "exists" })),
+            &json_to(&json!({}))
+        )]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    let err = prog.validate().unwrap_err();
+    assert!(err.to_string().contains("when.field"));
+}
+
+#[test]
+fn test_condition_missing_value_fails_validation() {
+    let cfg = json!({
+        "steps": [step(
+            Some(json!({ "field": "country", "op": "eq" })),
+            &json_to(&json!({}))
+        )]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    assert!(prog.validate().is_err());
+}
+
+fn chained_program(second_step: &Value) -> DslProgram {
+    let cfg = json!({
+        "steps": [
+            {
+                "from": {
+                    "type": "format",
+                    "source": { "source_type": "uri", "config": { "uri": "http://example.com/data.csv" } },
+                    "format": { "format_type": "csv", "options": {} },
+                    "mapping": { "qty": "qty" }
+                },
+                "transform": { "type": "none" },
+                "to": { "type": "next_step", "mapping": { "qty": "qty" } }
+            },
+            second_step,
+            {
+                "from": { "type": "previous_step", "mapping": { "qty": "qty" } },
+                "transform": { "type": "none" },
+                "to": json_to(&json!({ "qty": "qty" }))
+            }
+        ]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+    prog
+}
+
+fn doubling_step(extra: &Value) -> Value {
+    let mut step = json!({
+        "from": { "type": "previous_step", "mapping": { "qty": "qty" } },
+        "transform": {
+            "type": "arithmetic",
+            "target": "qty",
+            "left": { "kind": "field", "field": "qty" },
+            "op": "mul",
+            "right": { "kind": "const", "value": 2.0 }
+        },
+        "to": { "type": "next_step", "mapping": { "qty": "qty" } }
+    });
+    if let (Some(step), Some(extra)) = (step.as_object_mut(), extra.as_object()) {
+        step.extend(extra.clone());
+    }
+    step
+}
+
+#[test]
+fn test_steps_are_enabled_by_default() {
+    let prog = chained_program(&doubling_step(&json!({})));
+    assert!(prog.steps.iter().all(|step| step.enabled));
+    assert_eq!(
+        prog.apply(&json!({ "qty": 5 })).unwrap(),
+        json!({ "qty": 10.0 })
+    );
+}
+
+#[test]
+fn test_disabled_step_is_skipped_and_chaining_continues() {
+    let prog = chained_program(&doubling_step(&json!({ "enabled": false })));
+
+    let outputs = prog.execute(&json!({ "qty": 5 })).unwrap();
+
+    // The disabled step produces nothing; the last step reads the first step's output
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(outputs[1].1, json!({ "qty": 5 }));
+}
+
+#[test]
+fn test_disabled_step_is_still_validated() {
+    let cfg = json!({
+        "steps": [{
+            "enabled": false,
+            "from": {
+                "type": "format",
+                "source": { "source_type": "uri", "config": { "uri": "http://example.com/data.csv" } },
+                "format": { "format_type": "csv", "options": {} },
+                "mapping": { "bad field": "name" }
+            },
+            "transform": { "type": "none" },
+            "to": json_to(&json!({}))
+        }]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    assert!(prog.validate().is_err());
+}
+
+#[test]
+fn test_when_on_chained_step_passes_and_fails() {
+    let prog = chained_program(&doubling_step(
+        &json!({ "when": { "field": "qty", "op": "lte", "value": 10 } }),
+    ));
+
+    // Passing: the step runs and its output reaches the last step
+    let outputs = prog.execute(&json!({ "qty": 4 })).unwrap();
+    assert_eq!(outputs.len(), 3);
+    assert_eq!(outputs[2].1, json!({ "qty": 8.0 }));
+
+    // Failing: the step is skipped like a disabled one
+    let outputs = prog.execute(&json!({ "qty": 40 })).unwrap();
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(outputs[1].1, json!({ "qty": 40 }));
+}
@@ -0,0 +1,131 @@
+use super::{assert_apply_matches_execute, load_test_fixture};
+use r_data_core_workflow::dsl::DslProgram;
+use serde_json::json;
+
+fn csv_from(mapping: &serde_json::Value) -> serde_json::Value {
+    json!({
+        "type": "format",
+        "source": { "source_type": "uri", "config": { "uri": "http://example.com/data.csv" } },
+        "format": { "format_type": "csv", "options": {} },
+        "mapping": mapping
+    })
+}
+
+fn json_to(mapping: &serde_json::Value) -> serde_json::Value {
+    json!({
+        "type": "format",
+        "output": { "mode": "api" },
+        "format": { "format_type": "json", "options": {} },
+        "mapping": mapping
+    })
+}
+
+#[test]
+fn test_empty_mapping_passthrough_matches() {
+    let cfg = json!({
+        "steps": [{
+            "from": csv_from(&json!({})),
+            "transform": { "type": "none" },
+            "to": json_to(&json!({}))
+        }]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+
+    let input = json!({ "name": "Alice", "address": { "city": "Berlin" }, "age": 30 });
+    let out = assert_apply_matches_execute(&prog, &input);
+    assert_eq!(out, input);
+}
+
+#[test]
+fn test_empty_mapping_passthrough_with_transform_matches() {
+    let cfg = json!({
+        "steps": [{
+            "from": csv_from(&json!({})),
+            "transform": {
+                "type": "concat",
+                "target": "full_name",
+                "left": { "kind": "field", "field": "first" },
+                "separator": " ",
+                "right": { "kind": "field", "field": "last" }
+            },
+            "to": json_to(&json!({}))
+        }]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+
+    let out = assert_apply_matches_execute(&prog, &json!({ "first": "Ada", "last": "Lovelace" }));
+    assert_eq!(out["full_name"], json!("Ada Lovelace"));
+    assert_eq!(out["first"], json!("Ada"));
+}
+
+#[test]
+fn test_empty_mapping_non_object_input_matches() {
+    let cfg = json!({
+        "steps": [{
+            "from": csv_from(&json!({})),
+            "transform": { "type": "none" },
+            "to": json_to(&json!({}))
+        }]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+
+    let out = assert_apply_matches_execute(&prog, &json!([1, 2, 3]));
+    assert_eq!(out, json!({}));
+}
+
+#[test]
+fn test_multi_step_chain_matches() {
+    let cfg = load_test_fixture("test_chained_three_steps.json");
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+
+    let out = assert_apply_matches_execute(&prog, &json!({ "price": 100.0 }));
+    assert_eq!(out["final_total"], json!(119.0));
+}
+
+#[test]
+fn test_multi_step_with_empty_previous_step_mapping_matches() {
+    let cfg = json!({
+        "steps": [
+            {
+                "from": csv_from(&json!({ "price": "price", "qty": "qty" })),
+                "transform": {
+                    "type": "arithmetic",
+                    "target": "total",
+                    "left": { "kind": "field", "field": "price" },
+                    "op": "mul",
+                    "right": { "kind": "field", "field": "qty" }
+                },
+                "to": { "type": "next_step", "mapping": { "line_total": "total" } }
+            },
+            {
+                "from": { "type": "previous_step", "mapping": {} },
+                "transform": { "type": "none" },
+                "to": json_to(&json!({}))
+            }
+        ]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+
+    let out = assert_apply_matches_execute(&prog, &json!({ "price": 2.5, "qty": 4 }));
+    assert_eq!(out, json!({ "line_total": 10.0 }));
+}
+
+#[test]
+fn test_literal_output_mapping_matches() {
+    let cfg = json!({
+        "steps": [{
+            "from": csv_from(&json!({ "sku": "sku" })),
+            "transform": { "type": "none" },
+            "to": json_to(&json!({ "sku": "sku", "published": "@literal:true" }))
+        }]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+
+    let out = assert_apply_matches_execute(&prog, &json!({ "sku": "A-1" }));
+    assert_eq!(out, json!({ "sku": "A-1", "published": true }));
+}
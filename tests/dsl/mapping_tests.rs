@@ -107,3 +107,103 @@ async fn test_mapping_fallback_with_empty_mapping() {
         "Should include status field"
     );
 }
+
+#[tokio::test]
+#[serial]
+async fn test_mapping_deep_shared_prefix_destinations_are_merged() {
+    // Destinations sharing a prefix must end up in one nested object, not overwrite each other
+    let cfg = json!({
+        "steps": [{
+            "from": {
+                "type": "format",
+                "source": { "source_type": "api", "config": {} },
+                "format": { "format_type": "json", "options": {} },
+                "mapping": {
+                    "customer.name.first": "first",
+                    "customer.name.last": "last",
+                    "customer.address.city": "city",
+                    "order.total": "total"
+                }
+            },
+            "transform": { "type": "none" },
+            "to": {
+                "type": "format",
+                "output": { "mode": "api" },
+                "format": { "format_type": "json", "options": {} },
+                "mapping": {
+                    "billing.contact.name.first": "first",
+                    "billing.contact.name.last": "last",
+                    "billing.contact.address.city": "city",
+                    "billing.total": "total",
+                    "shipping.city": "city"
+                }
+            }
+        }]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+
+    let input = json!({
+        "customer": {
+            "name": { "first": "Ada", "last": "Lovelace" },
+            "address": { "city": "London" }
+        },
+        "order": { "total": 42 }
+    });
+
+    let outputs = prog.execute(&input).expect("execute");
+    assert_eq!(outputs.len(), 1);
+
+    let (_, produced) = &outputs[0];
+    assert_eq!(
+        produced,
+        &json!({
+            "billing": {
+                "contact": {
+                    "name": { "first": "Ada", "last": "Lovelace" },
+                    "address": { "city": "London" }
+                },
+                "total": 42
+            },
+            "shipping": { "city": "London" }
+        })
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn test_mapping_missing_nested_source_maps_to_null() {
+    // A source path with a missing segment maps to null; it used to resolve to the enclosing
+    // object, copying e.g. the whole input into `country`
+    let cfg = json!({
+        "steps": [{
+            "from": {
+                "type": "format",
+                "source": { "source_type": "api", "config": {} },
+                "format": { "format_type": "json", "options": {} },
+                "mapping": {
+                    "address.city": "city",
+                    "address.zip": "zip",
+                    "country": "country"
+                }
+            },
+            "transform": { "type": "none" },
+            "to": {
+                "type": "format",
+                "output": { "mode": "api" },
+                "format": { "format_type": "json", "options": {} },
+                "mapping": { "city": "city", "zip": "zip", "country": "country" }
+            }
+        }]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+    prog.validate().expect("valid dsl");
+
+    let out = prog
+        .apply(&json!({ "address": { "city": "Berlin" } }))
+        .expect("apply");
+
+    assert_eq!(out["city"], json!("Berlin"));
+    assert_eq!(out["zip"], Value::Null);
+    assert_eq!(out["country"], Value::Null);
+}
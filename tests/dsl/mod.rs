@@ -1,5 +1,6 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
+use r_data_core_workflow::dsl::DslProgram;
 use serde_json::Value;
 use std::fs::read_to_string;
 
@@ -17,10 +18,26 @@ pub fn load_test_fixture(path: &str) -> Value {
     serde_json::from_str(&content).expect("parse json")
 }
 
+/// Assert that `apply` returns exactly the last output produced by `execute`
+pub fn assert_apply_matches_execute(prog: &DslProgram, input: &Value) -> Value {
+    let executed = prog.execute(input).expect("execute");
+    let applied = prog.apply(input).expect("apply");
+    let (_, last) = executed.last().expect("at least one step output");
+    assert_eq!(
+        &applied, last,
+        "apply() diverged from execute() for input {input}"
+    );
+    applied
+}
+
 pub mod casting_invalid_tests;
 pub mod casting_tests;
 pub mod chaining_tests;
+pub mod clock_tests;
+pub mod condition_tests;
+pub mod consistency_tests;
 pub mod edge_case_tests;
 pub mod fanout_tests;
 pub mod mapping_tests;
 pub mod validation_tests;
+pub mod variables_tests;
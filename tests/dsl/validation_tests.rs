@@ -1,5 +1,5 @@
 use super::load_example;
-use r_data_core_workflow::dsl::{DslProgram, FromDef, ToDef};
+use r_data_core_workflow::dsl::{DslError, DslProgram, FromDef, ToDef};
 use serde_json::json;
 use serde_json::Value;
 use serial_test::serial;
@@ -360,3 +360,157 @@ async fn test_validate_invalid_filter_operator() {
         }
     }
 }
+
+#[tokio::test]
+#[serial]
+async fn test_validate_uri_pagination() {
+    let cfg = |pagination: &Value, format_type: &str| {
+        json!({
+            "steps": [
+                {
+                    "from": {
+                        "type": "format",
+                        "source": {
+                            "source_type": "uri",
+                            "config": { "uri": "https://example.com/items", "pagination": pagination }
+                        },
+                        "format": { "format_type": format_type, "options": {} },
+                        "mapping": {}
+                    },
+                    "transform": { "type": "none" },
+                    "to": {
+                        "type": "format",
+                        "output": { "mode": "api" },
+                        "format": { "format_type": "json", "options": {} },
+                        "mapping": {}
+                    }
+                }
+            ]
+        })
+    };
+    let validate = |pagination: Value, format_type: &str| {
+        DslProgram::from_config(&cfg(&pagination, format_type))
+            .expect("parse dsl")
+            .validate()
+    };
+
+    assert!(validate(json!({ "page_param": "page" }), "json").is_ok());
+    assert!(validate(json!({ "next_pointer": "/links/next" }), "json").is_ok());
+    // Exactly one way to find the next page
+    assert!(validate(json!({}), "json").is_err());
+    assert!(validate(
+        json!({ "page_param": "page", "next_pointer": "/links/next" }),
+        "json"
+    )
+    .is_err());
+    assert!(validate(json!({ "next_pointer": "links.next" }), "json").is_err());
+    assert!(validate(json!({ "page_param": "page", "max_pages": 0 }), "json").is_err());
+    // Pages are merged as JSON records
+    assert!(validate(json!({ "page_param": "page" }), "csv").is_err());
+}
+
+fn next_step(mapping: &Value) -> Value {
+    json!({
+        "from": { "type": "previous_step", "mapping": {} },
+        "transform": { "type": "none" },
+        "to": { "type": "next_step", "mapping": mapping }
+    })
+}
+
+#[tokio::test]
+#[serial]
+async fn test_validation_error_names_offending_step() {
+    let cfg = json!({
+        "steps": [
+            {
+                "from": {
+                    "type": "format",
+                    "source": { "source_type": "api", "config": {} },
+                    "format": { "format_type": "json", "options": {} },
+                    "mapping": {}
+                },
+                "transform": { "type": "none" },
+                "to": { "type": "next_step", "mapping": {} }
+            },
+            next_step(&json!({})),
+            next_step(&json!({ "bad field": "name" })),
+            {
+                "from": { "type": "previous_step", "mapping": {} },
+                "transform": { "type": "none" },
+                "to": {
+                    "type": "format",
+                    "output": { "mode": "api" },
+                    "format": { "format_type": "json", "options": {} },
+                    "mapping": {}
+                }
+            }
+        ]
+    });
+    let prog = DslProgram::from_config(&cfg).expect("parse dsl");
+
+    match prog.validate() {
+        Err(DslError::Validation {
+            step,
+            field,
+            reason,
+        }) => {
+            assert_eq!(step, Some(2));
+            assert_eq!(field.as_deref(), Some("mapping"));
+            assert!(reason.contains("DSL step 2"), "got: {reason}");
+        }
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_validation_errors_are_told_apart_from_parse_and_execution_errors() {
+    let parsed = DslProgram::from_config(&json!({ "steps": {} }));
+    assert!(matches!(parsed, Err(DslError::Parse(_))), "got {parsed:?}");
+
+    let last_next_step = DslProgram::from_config(&json!({
+        "steps": [{
+            "from": {
+                "type": "format",
+                "source": { "source_type": "api", "config": {} },
+                "format": { "format_type": "json", "options": {} },
+                "mapping": {}
+            },
+            "transform": { "type": "none" },
+            "to": { "type": "next_step", "mapping": {} }
+        }]
+    }))
+    .expect("parse dsl");
+    let err = last_next_step.validate().unwrap_err();
+    assert_eq!(err.step(), Some(0));
+    assert_eq!(err.field(), Some("to.next_step"));
+
+    let dividing = DslProgram::from_config(&json!({
+        "steps": [{
+            "from": {
+                "type": "format",
+                "source": { "source_type": "api", "config": {} },
+                "format": { "format_type": "json", "options": {} },
+                "mapping": { "a": "a" }
+            },
+            "transform": {
+                "type": "arithmetic",
+                "target": "result",
+                "left": { "kind": "field", "field": "a" },
+                "op": "div",
+                "right": { "kind": "const", "value": 0.0 }
+            },
+            "to": {
+                "type": "format",
+                "output": { "mode": "api" },
+                "format": { "format_type": "json", "options": {} },
+                "mapping": {}
+            }
+        }]
+    }))
+    .expect("parse dsl");
+    dividing.validate().expect("valid dsl");
+    let err = dividing.execute(&json!({ "a": 1 })).unwrap_err();
+    assert!(matches!(err, DslError::Execution(_)), "got {err:?}");
+    assert_eq!(err.step(), None);
+}
@@ -0,0 +1,122 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use super::load_example;
+use r_data_core_workflow::dsl::{
+    merge_config_override, DslError, DslProgram, FromDef, ToDef, Variables,
+};
+use serde_json::json;
+
+fn variables(pairs: &[(&str, &str)]) -> Variables {
+    Variables::new().with_values(
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string())),
+    )
+}
+
+fn uri_config() -> serde_json::Value {
+    let mut cfg = load_example("workflow_uri_json_to_entity.json");
+    cfg["steps"][0]["from"]["source"]["config"]["uri"] = json!("https://${API_HOST}/data.json");
+    cfg
+}
+
+#[test]
+fn test_variables_substitute_uri_and_entity_type() {
+    let prog = DslProgram::from_config_with_variables(
+        &uri_config(),
+        &variables(&[
+            ("API_HOST", "staging.example.com"),
+            ("ENTITY_TYPE", "customer"),
+        ]),
+    )
+    .expect("parse dsl");
+    prog.validate().expect("valid dsl");
+
+    let FromDef::Format { source, .. } = &prog.steps[0].from else {
+        panic!("expected a format source");
+    };
+    assert_eq!(
+        source.config["uri"],
+        json!("https://staging.example.com/data.json")
+    );
+    let ToDef::Entity {
+        entity_definition, ..
+    } = &prog.steps[0].to
+    else {
+        panic!("expected an entity target");
+    };
+    assert_eq!(entity_definition, "customer");
+}
+
+#[test]
+fn test_run_override_variables_replace_stored_values() {
+    let mut stored = uri_config();
+    stored["variables"] = json!({ "API_HOST": "prod.example.com", "ENTITY_TYPE": "customer" });
+    let run = merge_config_override(
+        &stored,
+        &json!({ "variables": { "API_HOST": "staging.example.com" } }),
+    );
+
+    let prog = DslProgram::from_config_with_variables(&run, &Variables::new()).expect("parse dsl");
+    let FromDef::Format { source, .. } = &prog.steps[0].from else {
+        panic!("expected a format source");
+    };
+    assert_eq!(
+        source.config["uri"],
+        json!("https://staging.example.com/data.json")
+    );
+}
+
+#[test]
+fn test_undefined_variable_passes_through_when_lenient() {
+    let prog = DslProgram::from_config_with_variables(
+        &uri_config(),
+        &variables(&[("ENTITY_TYPE", "customer")]),
+    )
+    .expect("parse dsl");
+    let FromDef::Format { source, .. } = &prog.steps[0].from else {
+        panic!("expected a format source");
+    };
+    assert_eq!(source.config["uri"], json!("https://${API_HOST}/data.json"));
+}
+
+#[test]
+fn test_undefined_variable_fails_when_strict() {
+    let mut cfg = uri_config();
+    cfg["strict_variables"] = json!(true);
+
+    let err =
+        DslProgram::from_config_with_variables(&cfg, &variables(&[("ENTITY_TYPE", "customer")]))
+            .expect_err("undefined variable must fail");
+    assert!(matches!(err, DslError::Validation { .. }), "got: {err:?}");
+    assert_eq!(err.step(), Some(0));
+    assert_eq!(err.field(), Some("from.source.config.uri"));
+    assert!(err.to_string().contains("${API_HOST}"), "got: {err}");
+}
+
+#[test]
+fn test_strict_variables_are_checked_when_the_run_is_parsed() {
+    let mut stored = uri_config();
+    stored["strict_variables"] = json!(true);
+    stored["variables"] = json!({ "ENTITY_TYPE": "customer" });
+
+    // Saving leaves the value to the run
+    let prog = DslProgram::from_stored_config(&stored).expect("parse stored dsl");
+    prog.validate().expect("valid dsl");
+    let FromDef::Format { source, .. } = &prog.steps[0].from else {
+        panic!("expected a format source");
+    };
+    assert_eq!(source.config["uri"], json!("https://${API_HOST}/data.json"));
+
+    // A run supplying the value through its override parses
+    let run = merge_config_override(
+        &stored,
+        &json!({ "variables": { "API_HOST": "staging.example.com" } }),
+    );
+    DslProgram::from_config_with_variables(&run, &Variables::new()).expect("parse run dsl");
+
+    // A run without it fails
+    let err = DslProgram::from_config_with_variables(&stored, &Variables::new())
+        .expect_err("undefined variable must fail");
+    assert_eq!(err.field(), Some("from.source.config.uri"));
+}
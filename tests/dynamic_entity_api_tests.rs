@@ -1,11 +1,13 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use log::warn;
+use r_data_core_core::count::CountMode;
 use r_data_core_core::entity_definition::definition::{EntityDefinition, EntityDefinitionParams};
 use r_data_core_core::error::Result;
 use r_data_core_core::field::ui::UiSettings;
 use r_data_core_core::field::{FieldDefinition, FieldType, FieldValidation};
 use r_data_core_core::public_api::BrowseKind;
+use r_data_core_core::sort::SortCollation;
 use r_data_core_core::DynamicEntity;
 use r_data_core_persistence::DynamicEntityPublicRepository;
 use r_data_core_persistence::DynamicEntityRepository;
@@ -84,6 +86,7 @@ mod tests {
                 ..Default::default()
             },
             constraints: HashMap::new(),
+            display_order: 0,
         };
 
         let email_field = FieldDefinition {
@@ -103,6 +106,7 @@ mod tests {
             },
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
 
         let age_field = FieldDefinition {
@@ -122,6 +126,7 @@ mod tests {
             },
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
 
         let active_field = FieldDefinition {
@@ -137,6 +142,7 @@ mod tests {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
 
         // Add fields to the entity definition
@@ -470,8 +476,12 @@ mod tests {
                 None, // fields
                 None, // sort_by
                 None, // sort_direction
+                SortCollation::Default,
                 Some(json!({"role": "admin"})),
-                None, // search_query
+                None,  // search_query
+                false, // include_deleted
+                None,  // owner
+                CountMode::Exact,
             )
             .await?;
 
@@ -490,8 +500,12 @@ mod tests {
                 None, // fields
                 None, // sort_by
                 None, // sort_direction
+                SortCollation::Default,
                 Some(json!({"status": "active"})),
-                None, // search_query
+                None,  // search_query
+                false, // include_deleted
+                None,  // owner
+                CountMode::Exact,
             )
             .await?;
 
@@ -584,8 +598,12 @@ mod tests {
                 None, // fields
                 None, // sort_by
                 None, // sort_direction
-                None, // filter
-                None, // search_query
+                SortCollation::Default,
+                None,  // filter
+                None,  // search_query
+                false, // include_deleted
+                None,  // owner
+                CountMode::Exact,
             )
             .await?;
 
@@ -600,8 +618,12 @@ mod tests {
                 None, // fields
                 None, // sort_by
                 None, // sort_direction
-                None, // filter
-                None, // search_query
+                SortCollation::Default,
+                None,  // filter
+                None,  // search_query
+                false, // include_deleted
+                None,  // owner
+                CountMode::Exact,
             )
             .await?;
 
@@ -1,7 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use httpmock::{Method::GET, MockServer};
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_persistence::{DynamicEntityRepository, OutboxRepository, WorkflowRepository};
 use r_data_core_services::workflow::outbox::DispatchWorkflowOutboxBatchUseCase;
 use r_data_core_services::{WorkflowRepositoryAdapter, WorkflowService};
@@ -80,6 +80,7 @@ async fn end_to_end_workflow_outbox_dispatches_into_redis_and_processes_run() ->
         schedule_cron: None,
         config: cfg,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let workflow_uuid = workflow_service.create(&create_req, creator_uuid).await?;
 
@@ -101,6 +102,10 @@ async fn end_to_end_workflow_outbox_dispatches_into_redis_and_processes_run() ->
         max_size: 10_000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     }));
     let mut consumer_handle = spawn_test_consumer_loop(ConsumerLoopConfig {
         pool: pool.pool.clone(),
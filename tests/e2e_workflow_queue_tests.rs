@@ -85,6 +85,7 @@ async fn end_to_end_workflow_processing_via_redis_queue() -> anyhow::Result<()>
         schedule_cron: None,
         config: cfg,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = wf_service.create(&create_req, creator_uuid).await?;
 
@@ -223,6 +224,7 @@ async fn end_to_end_consumer_loop_processes_workflow_run() -> anyhow::Result<()>
         schedule_cron: None,
         config: cfg,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = wf_service.create(&create_req, creator_uuid).await?;
 
@@ -244,6 +246,10 @@ async fn end_to_end_consumer_loop_processes_workflow_run() -> anyhow::Result<()>
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: r_data_core_core::config::CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(r_data_core_core::cache::CacheManager::new(cache_config));
 
@@ -408,6 +414,7 @@ async fn consumer_loop_processes_staged_items() -> anyhow::Result<()> {
         schedule_cron: None,
         config: cfg,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = wf_service.create(&create_req, creator_uuid).await?;
 
@@ -440,6 +447,10 @@ async fn consumer_loop_processes_staged_items() -> anyhow::Result<()> {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: r_data_core_core::config::CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(r_data_core_core::cache::CacheManager::new(cache_config));
 
@@ -515,7 +526,7 @@ async fn post_to_workflow_endpoint_enqueues_fetch_job() -> anyhow::Result<()> {
     use r_data_core_api::{configure_app, ApiState, ApiStateWrapper};
     use r_data_core_core::admin_user::AdminUser;
     use r_data_core_core::cache::CacheManager;
-    use r_data_core_core::config::{ApiConfig, CacheConfig, LicenseConfig};
+    use r_data_core_core::config::{ApiConfig, CacheConfig, CacheEvictionPolicy, LicenseConfig};
     use r_data_core_persistence::{AdminUserRepository, ApiKeyRepository};
     use r_data_core_services::{AdminUserService, ApiKeyService, LicenseService, RoleService};
 
@@ -548,6 +559,10 @@ async fn post_to_workflow_endpoint_enqueues_fetch_job() -> anyhow::Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 300,
         max_size: 10000,
@@ -595,6 +610,10 @@ async fn post_to_workflow_endpoint_enqueues_fetch_job() -> anyhow::Result<()> {
         enable_docs: true,
         cors_origins: vec![],
         check_default_admin_password: true,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
 
     let api_state = ApiState {
@@ -646,6 +665,7 @@ async fn post_to_workflow_endpoint_enqueues_fetch_job() -> anyhow::Result<()> {
         schedule_cron: None,
         config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, admin_uuid).await?;
 
@@ -3,7 +3,7 @@
 use actix_web::{test, web, App};
 use r_data_core_api::{configure_app, ApiState};
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_core::entity_definition::definition::EntityDefinition;
 use r_data_core_core::error::Result;
 use r_data_core_core::field::options::FieldValidation;
@@ -75,6 +75,7 @@ async fn create_test_entity_definition(pool: &PgPool, entity_type: &str) -> Resu
         validation: FieldValidation::default(),
         ui_settings: UiSettings::default(),
         constraints: HashMap::new(),
+        display_order: 0,
     };
     fields.push(name_field);
 
@@ -92,6 +93,7 @@ async fn create_test_entity_definition(pool: &PgPool, entity_type: &str) -> Resu
         validation: FieldValidation::default(),
         ui_settings: UiSettings::default(),
         constraints: HashMap::new(),
+        display_order: 0,
     };
     fields.push(email_field);
 
@@ -250,6 +252,10 @@ async fn create_test_app_with_api_key_repo(
     let cache_config = CacheConfig {
         entity_definition_ttl: 0, // No expiration
         api_key_ttl: 600,         // 10 minutes for tests
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600, // 1-hour default
         max_size: 10000,
@@ -297,6 +303,10 @@ async fn create_test_app_with_api_key_repo(
             enable_docs: true,
             cors_origins: vec![],
             check_default_admin_password: true,
+            expose_deleted_entities: false,
+            trusted_proxies: Vec::new(),
+            compression_min_size: None,
+            ip_rate_limit_per_minute: None,
         },
         role_service: r_data_core_services::RoleService::new(
             pool.clone(),
@@ -355,6 +365,8 @@ async fn test_fixed_entity_type_column_issue() -> Result<()> {
         "Test Description",
         user_uuid,
         30,
+        &[],
+        &[],
     )
     .await?;
 
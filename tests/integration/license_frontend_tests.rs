@@ -4,7 +4,7 @@ use actix_web::{http::StatusCode, test, web, App};
 use httpmock::MockServer;
 use r_data_core_api::{configure_app, ApiState, ApiStateWrapper};
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{ApiConfig, CacheConfig, LicenseConfig};
+use r_data_core_core::config::{ApiConfig, CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_persistence::{
     AdminUserRepository, AdminUserRepositoryTrait, ApiKeyRepository, CreateAdminUserParams,
     DashboardStatsRepository, EntityDefinitionRepository,
@@ -36,6 +36,10 @@ async fn setup_test_app_with_license_config(
     let cache_config = CacheConfig {
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 300,
         max_size: 10000,
@@ -51,6 +55,10 @@ async fn setup_test_app_with_license_config(
         enable_docs: true,
         cors_origins: vec!["*".to_string()],
         check_default_admin_password: false,
+        expose_deleted_entities: false,
+        trusted_proxies: Vec::new(),
+        compression_min_size: None,
+        ip_rate_limit_per_minute: None,
     };
 
     // Use mock server for license verification
@@ -51,7 +51,12 @@ fn create_entity_definition_with_field(
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         }],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: Schema::new(schema_properties),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
@@ -2,7 +2,7 @@
 
 use httpmock::MockServer;
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_license::LicenseToolService;
 use r_data_core_license::{
     call_verification_api, create_license_key, decode_license_claims, LicenseType,
@@ -101,6 +101,10 @@ async fn test_license_check_valid() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -188,6 +192,10 @@ async fn test_license_check_invalid_api_response() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -257,6 +265,10 @@ async fn test_license_check_network_error() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -289,6 +301,10 @@ async fn test_license_check_none_state() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -349,6 +365,10 @@ async fn test_license_check_cache_invalidation_with_new_timestamp() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -763,6 +783,10 @@ async fn test_service_and_tool_use_same_api_implementation() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
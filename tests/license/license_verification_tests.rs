@@ -2,7 +2,7 @@
 
 use httpmock::MockServer;
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_services::license::service::LicenseState;
 use r_data_core_services::LicenseService;
 use serial_test::serial;
@@ -27,6 +27,10 @@ async fn test_license_verification_none() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -60,6 +64,10 @@ async fn test_license_verification_invalid_jwt() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -135,6 +143,10 @@ async fn test_license_verification_api_returns_invalid() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -220,6 +232,10 @@ async fn test_license_verification_api_returns_valid() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -297,6 +313,10 @@ async fn test_license_verification_network_error() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -344,6 +364,10 @@ async fn test_license_verification_caching() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -384,6 +408,10 @@ async fn test_get_cached_license_status_none() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
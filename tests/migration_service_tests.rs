@@ -104,3 +104,27 @@ async fn test_migration_service_pool_accessor() {
 
     assert_eq!(result.0, 1);
 }
+
+#[tokio::test]
+async fn test_no_pending_migrations_after_migrating() {
+    let Some(service) = get_migration_service().await else {
+        println!("Skipping test: DATABASE_URL not set");
+        return;
+    };
+
+    service
+        .run_migrations()
+        .await
+        .expect("migrations should run");
+    let status = service
+        .check_status()
+        .await
+        .expect("status check should work");
+
+    assert!(status.has_migrations());
+    assert!(MigrationService::pending_migrations(&status).is_empty());
+    assert_eq!(
+        status.current_version(),
+        MigrationService::expected_version()
+    );
+}
@@ -11,6 +11,7 @@ pub mod worker;
 pub mod workflow;
 
 // Top level integration test modules
+mod bootstrap_tests;
 mod clear_cache_tests;
 mod dsl_integration_tests;
 mod dynamic_entity_api_tests;
@@ -2,7 +2,10 @@
 
 use r_data_core_core::cache::backend::CacheBackend;
 use r_data_core_core::cache::redis::RedisCache;
+use r_data_core_core::cache::CacheManager;
+use r_data_core_core::config::CacheConfig;
 use serde_json::json;
+use std::time::Duration;
 use uuid::Uuid;
 
 async fn get_test_cache() -> Option<RedisCache> {
@@ -127,3 +130,103 @@ async fn test_redis_cache_query_async_api_if_available() {
         .await
         .expect("delete should succeed");
 }
+
+#[tokio::test]
+async fn test_redis_delete_by_prefix_removes_large_keyspace_if_available() {
+    let Some(cache) = get_test_cache().await else {
+        println!("Skipping test: REDIS_URL not set");
+        return;
+    };
+
+    // Several SCAN steps worth of keys, plus keys that only match if the prefix were a glob
+    let prefix = format!("test:bulk:{}:[a]*", Uuid::now_v7().simple());
+    for i in 0..1_200 {
+        cache
+            .set(&format!("{prefix}{i}"), &i, Some(60))
+            .await
+            .expect("set should succeed");
+    }
+    let lookalike = prefix.replace("[a]*", "a");
+    cache
+        .set(&format!("{lookalike}:kept"), &0, Some(60))
+        .await
+        .expect("set should succeed");
+
+    let deleted = cache
+        .delete_by_prefix(&prefix)
+        .await
+        .expect("delete_by_prefix should succeed");
+    assert_eq!(deleted, 1_200);
+
+    let remaining: Option<i32> = cache
+        .get(&format!("{prefix}0"))
+        .await
+        .expect("get should succeed");
+    assert_eq!(remaining, None);
+    let kept: Option<i32> = cache
+        .get(&format!("{lookalike}:kept"))
+        .await
+        .expect("get should succeed");
+    assert_eq!(kept, Some(0));
+
+    // Cleanup
+    cache
+        .delete(&format!("{lookalike}:kept"))
+        .await
+        .expect("delete should succeed");
+}
+
+async fn get_broadcasting_manager() -> Option<CacheManager> {
+    let url = std::env::var("REDIS_URL").ok()?;
+    CacheManager::new(CacheConfig {
+        broadcast_invalidations: true,
+        ..CacheConfig::default()
+    })
+    .with_redis(&url)
+    .await
+    .ok()
+}
+
+/// Poll `node` until `key` is gone from both Redis and its in-memory cache
+async fn wait_until_evicted(node: &CacheManager, key: &str) -> bool {
+    for _ in 0..50 {
+        if node.get::<String>(key).await.unwrap().is_none() {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    false
+}
+
+#[tokio::test]
+async fn test_delete_evicts_in_memory_entry_on_other_node_if_available() {
+    let (Some(node_a), Some(node_b)) = (
+        get_broadcasting_manager().await,
+        get_broadcasting_manager().await,
+    ) else {
+        println!("Skipping test: REDIS_URL not set");
+        return;
+    };
+
+    let prefix = format!("test:invalidation:{}", Uuid::now_v7().simple());
+    let key = format!("{prefix}:single");
+    let prefixed_key = format!("{prefix}:prefixed");
+
+    // Both nodes hold the entries in memory; Redis answers first while it has them
+    for node in [&node_a, &node_b] {
+        node.set(&key, &"value", Some(60)).await.unwrap();
+        node.set(&prefixed_key, &"value", Some(60)).await.unwrap();
+    }
+
+    node_a.delete(&key).await.unwrap();
+    assert!(
+        wait_until_evicted(&node_b, &key).await,
+        "stale in-memory entry survived on the other node"
+    );
+
+    node_a.delete_by_prefix(&prefix).await.unwrap();
+    assert!(
+        wait_until_evicted(&node_b, &prefixed_key).await,
+        "stale in-memory entry survived a prefix delete on the other node"
+    );
+}
@@ -25,7 +25,14 @@ async fn test_create_and_find_api_key() -> Result<()> {
 
     // Create a new key
     let (key_uuid, key_value) = repo
-        .create_new_api_key(&name, "Test key for integration tests", user_uuid, 30)
+        .create_new_api_key(
+            &name,
+            "Test key for integration tests",
+            user_uuid,
+            30,
+            &[],
+            &[],
+        )
         .await?;
 
     // Find the key we just created
@@ -86,7 +93,14 @@ async fn test_create_api_key_with_non_existent_user() -> Result<()> {
 
     // Attempt to create key with non-existent user UUID
     let result = repo
-        .create_new_api_key(&name, "Test key with invalid user", non_existent_uuid, 30)
+        .create_new_api_key(
+            &name,
+            "Test key with invalid user",
+            non_existent_uuid,
+            30,
+            &[],
+            &[],
+        )
         .await;
 
     // Verify the operation fails with a foreign key constraint error
@@ -120,7 +134,14 @@ async fn test_api_key_last_used_update() -> Result<()> {
 
     // Create a new key
     let (key_uuid, key_value) = repo
-        .create_new_api_key(&name, "Test key for last_used tracking", user_uuid, 30)
+        .create_new_api_key(
+            &name,
+            "Test key for last_used tracking",
+            user_uuid,
+            30,
+            &[],
+            &[],
+        )
         .await?;
 
     // Verify the initial state-last_used_at should be None
@@ -0,0 +1,89 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+//! Integration tests verifying that admin mutations write snapshots to `audit_log`.
+
+use r_data_core_core::audit_log::AuditAction;
+use r_data_core_core::entity_definition::definition::{EntityDefinition, EntityDefinitionParams};
+use r_data_core_core::field::types::FieldType;
+use r_data_core_core::field::ui::UiSettings;
+use r_data_core_core::field::{FieldDefinition, FieldValidation};
+use r_data_core_core::system_log::SystemLogResourceType;
+use r_data_core_persistence::{
+    AuditLogFilter, AuditLogRepository, AuditLogRepositoryTrait, EntityDefinitionRepository,
+};
+use r_data_core_services::{
+    AuditLogService, EntityDefinitionRepositoryAdapter, EntityDefinitionService,
+};
+use r_data_core_test_support::{clear_test_db, create_test_admin_user, setup_test_db};
+use serial_test::serial;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[tokio::test]
+#[serial]
+async fn test_entity_definition_create_writes_audit_log() {
+    let pool = setup_test_db().await;
+    clear_test_db(&pool).await.expect("clear_test_db failed");
+
+    let audit_log = Arc::new(AuditLogService::new(Arc::new(AuditLogRepository::new(
+        pool.pool.clone(),
+    ))));
+    let repo = EntityDefinitionRepository::new(pool.pool.clone());
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepositoryAdapter::new(repo),
+    ))
+    .with_audit_log(audit_log);
+
+    let creator = create_test_admin_user(&pool.pool).await.expect("creator");
+    let definition = EntityDefinition::from_params(EntityDefinitionParams {
+        entity_type: "auditlogtype".to_string(),
+        display_name: "Audit Log Type".to_string(),
+        description: None,
+        group_name: None,
+        allow_children: false,
+        icon: None,
+        fields: vec![FieldDefinition {
+            name: "title".to_string(),
+            display_name: "Title".to_string(),
+            description: None,
+            field_type: FieldType::String,
+            required: true,
+            indexed: false,
+            filterable: false,
+            unique: false,
+            default_value: None,
+            validation: FieldValidation::default(),
+            ui_settings: UiSettings::default(),
+            constraints: HashMap::new(),
+            display_order: 0,
+        }],
+        created_by: creator,
+    });
+    let uuid = service
+        .create_entity_definition(&definition)
+        .await
+        .expect("create_entity_definition");
+
+    let (entries, total) = AuditLogRepository::new(pool.pool.clone())
+        .list_paginated(
+            10,
+            0,
+            &AuditLogFilter {
+                actor_uuid: Some(creator),
+                resource_uuid: Some(uuid),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("list audit log");
+
+    assert_eq!(total, 1);
+    let entry = &entries[0];
+    assert_eq!(entry.action, AuditAction::Create);
+    assert_eq!(entry.resource_type, SystemLogResourceType::EntityDefinition);
+    assert!(entry.before.is_none());
+    let after = entry.after.as_ref().expect("after snapshot");
+    assert_eq!(after["entity_type"], "auditlogtype");
+
+    let _ = service.delete_entity_definition(&uuid, creator).await;
+}
@@ -0,0 +1,211 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::field::{FieldDefinition, FieldType};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::{
+    DynamicEntityRepository, DynamicEntityRepositoryTrait, EntityDefinitionRepository,
+};
+use r_data_core_services::EntityDefinitionService;
+use r_data_core_test_support::{setup_test_db, unique_entity_type};
+
+struct Family {
+    parent_type: String,
+    child_type: String,
+    parent: Uuid,
+    children: [Uuid; 2],
+}
+
+async fn create_definition(
+    service: &EntityDefinitionService,
+    entity_type: &str,
+    mut fields: Vec<FieldDefinition>,
+) -> Result<()> {
+    fields.insert(
+        0,
+        FieldDefinition::new("name".to_string(), "Name".to_string(), FieldType::String),
+    );
+    let definition = EntityDefinition {
+        entity_type: entity_type.to_string(),
+        display_name: entity_type.to_string(),
+        published: true,
+        created_by: Uuid::now_v7(),
+        fields,
+        ..EntityDefinition::default()
+    };
+    service.create_entity_definition(&definition).await?;
+    Ok(())
+}
+
+async fn create_entity(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+    mut field_data: HashMap<String, serde_json::Value>,
+) -> Result<Uuid> {
+    field_data.insert("entity_key".to_string(), json!(Uuid::now_v7().to_string()));
+    field_data.insert("path".to_string(), json!("/"));
+    field_data.insert("created_by".to_string(), json!(Uuid::now_v7().to_string()));
+    repo.create(&DynamicEntity {
+        entity_type: entity_type.to_string(),
+        field_data,
+        definition: Arc::new(EntityDefinition::default()),
+    })
+    .await
+}
+
+/// Parent type with two children referencing it through a `ManyToOne` field
+///
+/// Without `on_delete` the field carries no policy constraint at all.
+async fn create_family(pool: &PgPool, on_delete: Option<&str>) -> Result<Family> {
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(pool.clone()),
+    ));
+    let parent_type = unique_entity_type("parent");
+    let child_type = unique_entity_type("child");
+
+    create_definition(&service, &parent_type, Vec::new()).await?;
+    let mut parent_ref = FieldDefinition::new(
+        "parent_ref".to_string(),
+        "Parent".to_string(),
+        FieldType::ManyToOne,
+    );
+    parent_ref.validation.target_class = Some(parent_type.clone());
+    if let Some(on_delete) = on_delete {
+        parent_ref
+            .constraints
+            .insert("on_delete".to_string(), json!(on_delete));
+    }
+    create_definition(&service, &child_type, vec![parent_ref]).await?;
+
+    let repo = DynamicEntityRepository::new(pool.clone());
+    let parent = create_entity(
+        &repo,
+        &parent_type,
+        HashMap::from([("name".to_string(), json!("parent"))]),
+    )
+    .await?;
+    let mut children = [Uuid::nil(); 2];
+    for (idx, child) in children.iter_mut().enumerate() {
+        *child = create_entity(
+            &repo,
+            &child_type,
+            HashMap::from([
+                ("name".to_string(), json!(format!("child {idx}"))),
+                ("parent_ref".to_string(), json!(parent.to_string())),
+            ]),
+        )
+        .await?;
+    }
+
+    Ok(Family {
+        parent_type,
+        child_type,
+        parent,
+        children,
+    })
+}
+
+#[tokio::test]
+async fn restrict_refuses_to_delete_referenced_parent() -> Result<()> {
+    let db = setup_test_db().await;
+    let family = create_family(&db.pool, Some("restrict")).await?;
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+
+    let err = repo
+        .delete_by_type(&family.parent_type, &family.parent)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Conflict(_)), "got {err:?}");
+    assert!(repo
+        .get_by_type(&family.parent_type, &family.parent, None)
+        .await?
+        .is_some());
+
+    // Once the children are gone the parent can be deleted
+    for child in &family.children {
+        repo.delete_by_type(&family.child_type, child).await?;
+    }
+    repo.delete_by_type(&family.parent_type, &family.parent)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cascade_deletes_children_with_parent() -> Result<()> {
+    let db = setup_test_db().await;
+    let family = create_family(&db.pool, Some("cascade")).await?;
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+
+    repo.delete_by_type(&family.parent_type, &family.parent)
+        .await?;
+
+    assert!(repo
+        .get_by_type(&family.parent_type, &family.parent, None)
+        .await?
+        .is_none());
+    for child in &family.children {
+        assert!(repo
+            .get_by_type(&family.child_type, child, None)
+            .await?
+            .is_none());
+    }
+    assert_eq!(repo.count_deleted_entities(&family.child_type).await?, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn set_null_clears_child_references() -> Result<()> {
+    let db = setup_test_db().await;
+    let family = create_family(&db.pool, Some("set_null")).await?;
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+
+    repo.delete_by_type(&family.parent_type, &family.parent)
+        .await?;
+
+    for child in &family.children {
+        let entity = repo
+            .get_by_type(&family.child_type, child, None)
+            .await?
+            .expect("child should survive set_null");
+        assert!(entity.field_data["parent_ref"].is_null());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn relations_without_policy_keep_children_on_delete() -> Result<()> {
+    let db = setup_test_db().await;
+    let family = create_family(&db.pool, None).await?;
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+
+    repo.delete_by_type(&family.parent_type, &family.parent)
+        .await?;
+
+    assert!(repo
+        .get_by_type(&family.parent_type, &family.parent, None)
+        .await?
+        .is_none());
+    for child in &family.children {
+        let entity = repo
+            .get_by_type(&family.child_type, child, None)
+            .await?
+            .expect("child should survive no_action");
+        assert_eq!(
+            entity.field_data["parent_ref"],
+            json!(family.parent.to_string())
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,254 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Entity definitions with an explicit table name store their entities in that table
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::field::{FieldDefinition, FieldType};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::{
+    DynamicEntityRepository, DynamicEntityRepositoryTrait, EntityDefinitionRepository,
+    EntitySchemaRepository,
+};
+use r_data_core_services::EntityDefinitionService;
+use r_data_core_test_support::{setup_test_db, unique_entity_type};
+
+fn definition(entity_type: &str, table_name: Option<String>) -> EntityDefinition {
+    EntityDefinition {
+        entity_type: entity_type.to_string(),
+        display_name: entity_type.to_string(),
+        published: true,
+        created_by: Uuid::now_v7(),
+        fields: vec![FieldDefinition::new(
+            "name".to_string(),
+            "Name".to_string(),
+            FieldType::String,
+        )],
+        table_name,
+        ..EntityDefinition::default()
+    }
+}
+
+async fn table_exists(pool: &sqlx::PgPool, table_name: &str) -> Result<bool> {
+    Ok(sqlx::query_scalar(
+        "SELECT EXISTS (
+            SELECT FROM information_schema.tables
+            WHERE table_schema = current_schema() AND table_name = $1
+        )",
+    )
+    .bind(table_name)
+    .fetch_one(pool)
+    .await?)
+}
+
+#[tokio::test]
+async fn entities_round_trip_through_custom_table() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+    let entity_type = unique_entity_type("custom");
+    let table_name = format!("legacy_{entity_type}");
+
+    let definition_uuid = service
+        .create_entity_definition(&definition(&entity_type, Some(table_name.clone())))
+        .await?;
+    let stored = service.get_entity_definition(&definition_uuid).await?;
+    assert_eq!(stored.table_name.as_deref(), Some(table_name.as_str()));
+    assert_eq!(stored.get_table_name(), table_name);
+    assert!(table_exists(&db.pool, &table_name).await?);
+    assert!(!table_exists(&db.pool, &format!("entity_{entity_type}")).await?);
+
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+    let uuid = repo
+        .create(&DynamicEntity {
+            entity_type: entity_type.clone(),
+            field_data: HashMap::from([
+                ("name".to_string(), json!("Legacy row")),
+                ("entity_key".to_string(), json!(Uuid::now_v7().to_string())),
+                ("path".to_string(), json!("/")),
+                ("created_by".to_string(), json!(Uuid::now_v7().to_string())),
+            ]),
+            definition: Arc::new(EntityDefinition::default()),
+        })
+        .await?;
+
+    let rows: i64 = sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) FROM {table_name} WHERE uuid = $1"
+    ))
+    .bind(uuid)
+    .fetch_one(&db.pool)
+    .await?;
+    assert_eq!(rows, 1);
+
+    let entity = repo
+        .get_by_type(&entity_type, &uuid, None)
+        .await?
+        .expect("entity should be readable through its type");
+    assert_eq!(entity.field_data.get("name"), Some(&json!("Legacy row")));
+
+    Ok(())
+}
+
+async fn view_exists(pool: &sqlx::PgPool, view_name: &str) -> Result<bool> {
+    Ok(sqlx::query_scalar(
+        "SELECT EXISTS (
+            SELECT FROM information_schema.views
+            WHERE table_schema = current_schema() AND table_name = $1
+        )",
+    )
+    .bind(view_name)
+    .fetch_one(pool)
+    .await?)
+}
+
+#[tokio::test]
+async fn dropping_leftover_default_table_keeps_view_of_mapped_type() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+    let entity_type = unique_entity_type("remapped");
+    let default_table = format!("entity_{entity_type}");
+    let view_name = format!("entity_{entity_type}_view");
+    service
+        .create_entity_definition(&definition(
+            &entity_type,
+            Some(format!("legacy_{entity_type}")),
+        ))
+        .await?;
+
+    // A table left over from before the type was mapped onto legacy_<type>
+    sqlx::query(&format!(
+        "CREATE TABLE {default_table} (uuid UUID PRIMARY KEY REFERENCES entities_registry(uuid))"
+    ))
+    .execute(&db.pool)
+    .await?;
+
+    let schemas = EntitySchemaRepository::new(db.pool.clone());
+    schemas.drop_entity_table(&default_table).await?;
+    assert!(!table_exists(&db.pool, &default_table).await?);
+    assert!(view_exists(&db.pool, &view_name).await?);
+
+    // Once the definition is gone, its view goes with the table
+    let orphan_type = unique_entity_type("orphan");
+    let orphan_table = format!("entity_{orphan_type}");
+    sqlx::query(&format!(
+        "CREATE TABLE {orphan_table} (uuid UUID PRIMARY KEY REFERENCES entities_registry(uuid))"
+    ))
+    .execute(&db.pool)
+    .await?;
+    sqlx::query(&format!(
+        "CREATE VIEW entity_{orphan_type}_view AS SELECT uuid FROM entities_registry"
+    ))
+    .execute(&db.pool)
+    .await?;
+    schemas.drop_entity_table(&orphan_table).await?;
+    assert!(!view_exists(&db.pool, &format!("entity_{orphan_type}_view")).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn table_name_used_by_another_type_is_rejected() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+    let existing_type = unique_entity_type("existing");
+    service
+        .create_entity_definition(&definition(&existing_type, None))
+        .await?;
+
+    let result = service
+        .create_entity_definition(&definition(
+            &unique_entity_type("colliding"),
+            Some(format!("entity_{existing_type}")),
+        ))
+        .await;
+
+    match result {
+        Err(Error::Validation(msg)) => {
+            assert!(msg.contains(&existing_type), "got: {msg}");
+        }
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn table_name_of_non_entity_table_is_rejected() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+    let plain_table = format!("plain_{}", unique_entity_type("table"));
+    sqlx::query(&format!("CREATE TABLE {plain_table} (id INT PRIMARY KEY)"))
+        .execute(&db.pool)
+        .await?;
+
+    for table_name in [
+        "_sqlx_migrations",
+        "entity_definitions",
+        plain_table.as_str(),
+    ] {
+        let result = service
+            .create_entity_definition(&definition(
+                &unique_entity_type("foreign"),
+                Some(table_name.to_string()),
+            ))
+            .await;
+        match result {
+            Err(Error::Validation(msg)) => {
+                assert!(msg.contains("not an entity table"), "got: {msg}");
+            }
+            other => panic!("expected a validation error for {table_name}, got {other:?}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deleting_definition_keeps_table_it_did_not_create() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+
+    // A legacy table already shaped like an entity table
+    let entity_type = unique_entity_type("adopted");
+    let legacy_table = format!("legacy_{entity_type}");
+    sqlx::query(&format!(
+        "CREATE TABLE {legacy_table} (uuid UUID PRIMARY KEY REFERENCES entities_registry(uuid))"
+    ))
+    .execute(&db.pool)
+    .await?;
+    let adopted = service
+        .create_entity_definition(&definition(&entity_type, Some(legacy_table.clone())))
+        .await?;
+    service
+        .delete_entity_definition(&adopted, Uuid::now_v7())
+        .await?;
+    assert!(table_exists(&db.pool, &legacy_table).await?);
+
+    // A table created for the definition goes with it
+    let created_type = unique_entity_type("created");
+    let created_table = format!("legacy_{created_type}");
+    let created = service
+        .create_entity_definition(&definition(&created_type, Some(created_table.clone())))
+        .await?;
+    service
+        .delete_entity_definition(&created, Uuid::now_v7())
+        .await?;
+    assert!(!table_exists(&db.pool, &created_table).await?);
+
+    Ok(())
+}
@@ -26,6 +26,8 @@ async fn test_get_dashboard_stats_empty() {
     assert_eq!(stats.entities.by_type.len(), 0);
     assert_eq!(stats.workflows.total, 0);
     assert_eq!(stats.workflows.workflows.len(), 0);
+    assert_eq!(stats.workflows.runs.total_runs, 0);
+    assert_eq!(stats.workflows.runs.last_run_status, None);
     assert_eq!(stats.online_users_count, 0);
 }
 
@@ -70,13 +72,14 @@ async fn test_get_dashboard_stats_with_data() {
     .unwrap();
 
     // Create workflow
+    let workflow_uuid = Uuid::now_v7();
     sqlx::query(
         r"
         INSERT INTO workflows (uuid, name, kind, enabled, created_by, created_at, updated_at)
         VALUES ($1, $2, $3::workflow_kind, $4, $5, NOW(), NOW())
         ",
     )
-    .bind(Uuid::now_v7())
+    .bind(workflow_uuid)
     .bind("Test Workflow")
     .bind("consumer")
     .bind(true)
@@ -85,6 +88,18 @@ async fn test_get_dashboard_stats_with_data() {
     .await
     .unwrap();
 
+    // Create a finished run of the workflow
+    sqlx::query(
+        r"
+        INSERT INTO workflow_runs (workflow_uuid, status, queued_at, started_at, finished_at)
+        VALUES ($1, 'success', NOW(), NOW(), NOW() + INTERVAL '2 seconds')
+        ",
+    )
+    .bind(workflow_uuid)
+    .execute(&*pool)
+    .await
+    .unwrap();
+
     // Create refresh token (online user)
     sqlx::query!(
         r#"
@@ -108,5 +123,17 @@ async fn test_get_dashboard_stats_with_data() {
     assert_eq!(stats.workflows.total, 1);
     assert_eq!(stats.workflows.workflows.len(), 1);
     assert_eq!(stats.workflows.workflows[0].name, "Test Workflow");
+    assert_eq!(stats.workflows.runs.total_runs, 1);
+    assert_eq!(stats.workflows.runs.successful_runs, 1);
+    assert_eq!(stats.workflows.runs.runs_last_24h, 1);
+    assert!(stats
+        .workflows
+        .runs
+        .avg_duration_ms
+        .is_some_and(|ms| (ms - 2000.0).abs() < 1e-6));
+    assert_eq!(
+        stats.workflows.runs.last_run_status.as_deref(),
+        Some("success")
+    );
     assert_eq!(stats.online_users_count, 1);
 }
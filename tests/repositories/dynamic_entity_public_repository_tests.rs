@@ -45,7 +45,12 @@ async fn create_test_entity_definition(
             validation: r_data_core_core::field::FieldValidation::default(),
             ui_settings: r_data_core_core::field::ui::UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         }],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: r_data_core_core::entity_definition::schema::Schema::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
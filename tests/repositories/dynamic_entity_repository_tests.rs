@@ -15,7 +15,7 @@ use r_data_core_core::{
 use r_data_core_persistence::{
     DynamicEntityRepository, DynamicEntityRepositoryTrait, FilterEntitiesParams,
 };
-use r_data_core_test_support::setup_test_db;
+use r_data_core_test_support::{setup_test_db, unique_entity_type};
 
 // Helper function to create a test entity definition struct for dynamic entities
 fn create_test_entity_definition_struct() -> EntityDefinition {
@@ -41,6 +41,7 @@ fn create_test_entity_definition_struct() -> EntityDefinition {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: r_data_core_core::field::ui::UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
             FieldDefinition {
                 name: "age".to_string(),
@@ -55,8 +56,13 @@ fn create_test_entity_definition_struct() -> EntityDefinition {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: r_data_core_core::field::ui::UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
         ],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: Schema::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
@@ -198,6 +204,57 @@ async fn test_list_entities_by_type() -> Result<()> {
     Ok(())
 }
 
+// Test for fetching several entities by UUID in one call
+#[tokio::test]
+async fn test_get_many_by_type() -> Result<()> {
+    use r_data_core_persistence::EntityDefinitionRepository;
+    use r_data_core_services::EntityDefinitionService;
+
+    let pool = setup_test_db().await;
+    let repo: Box<dyn DynamicEntityRepositoryTrait> =
+        Box::new(DynamicEntityRepository::new(pool.pool.clone()));
+
+    let mut entity_def = create_test_entity_definition_struct();
+    entity_def.published = true;
+    entity_def.created_by = Uuid::now_v7();
+
+    let def_repo = EntityDefinitionRepository::new(pool.pool.clone());
+    let def_service = EntityDefinitionService::new_without_cache(Arc::new(def_repo));
+    def_service.create_entity_definition(&entity_def).await?;
+
+    // Wait for view creation
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    let created_def = def_service
+        .get_entity_definition_by_entity_type(&entity_def.entity_type)
+        .await?;
+
+    let mut existing = Vec::new();
+    for _ in 0..3 {
+        existing.push(
+            repo.create(&create_test_dynamic_entity(&created_def))
+                .await?,
+        );
+    }
+    let missing = [Uuid::now_v7(), Uuid::now_v7()];
+    let requested: Vec<Uuid> = existing.iter().chain(missing.iter()).copied().collect();
+
+    let found = repo
+        .get_many_by_type(&entity_def.entity_type, &requested, None)
+        .await?;
+
+    assert_eq!(found.len(), 5, "every requested UUID is reported");
+    for uuid in &existing {
+        let entity = found[uuid].as_ref().expect("existing entity is returned");
+        assert_eq!(entity.get::<String>("name")?, "John Doe");
+    }
+    for uuid in &missing {
+        assert!(found[uuid].is_none(), "missing entity {uuid} is absent");
+    }
+
+    Ok(())
+}
+
 // Test for retrieving entities with a specific parent
 #[tokio::test]
 async fn test_list_entities_by_parent() -> Result<()> {
@@ -394,6 +451,7 @@ async fn test_field_name_case_handling() -> Result<()> {
             validation: r_data_core_core::field::FieldValidation::default(),
             ui_settings: r_data_core_core::field::ui::UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
         FieldDefinition {
             name: "lastName".to_string(), // camelCase
@@ -408,6 +466,7 @@ async fn test_field_name_case_handling() -> Result<()> {
             validation: r_data_core_core::field::FieldValidation::default(),
             ui_settings: r_data_core_core::field::ui::UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
         FieldDefinition {
             name: "email".to_string(), // lowercase
@@ -422,6 +481,7 @@ async fn test_field_name_case_handling() -> Result<()> {
             validation: r_data_core_core::field::FieldValidation::default(),
             ui_settings: r_data_core_core::field::ui::UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         },
     ];
 
@@ -597,3 +657,50 @@ async fn test_field_name_case_handling() -> Result<()> {
 
     Ok(())
 }
+
+// Test that a composite unique constraint violation is reported with the constraint name
+#[tokio::test]
+async fn test_composite_unique_constraint_violation() -> Result<()> {
+    use r_data_core_persistence::EntityDefinitionRepository;
+    use r_data_core_services::EntityDefinitionService;
+
+    let pool = setup_test_db().await;
+    let repo = DynamicEntityRepository::new(pool.pool.clone());
+
+    let mut entity_def = create_test_entity_definition_struct();
+    entity_def.entity_type = unique_entity_type("composite_unique");
+    entity_def.published = true;
+    entity_def.unique_constraints = vec![vec!["name".to_string(), "age".to_string()]];
+
+    let def_repo = EntityDefinitionRepository::new(pool.pool.clone());
+    let def_service = EntityDefinitionService::new_without_cache(Arc::new(def_repo));
+    def_service.create_entity_definition(&entity_def).await?;
+    let created_def = def_service
+        .get_entity_definition_by_entity_type(&entity_def.entity_type)
+        .await?;
+    let constraint_name =
+        created_def.unique_constraint_name(&["name".to_string(), "age".to_string()]);
+
+    repo.create(&create_test_dynamic_entity(&created_def))
+        .await?;
+
+    // Same name and age
+    let err = repo
+        .create(&create_test_dynamic_entity(&created_def))
+        .await
+        .unwrap_err();
+    match err {
+        r_data_core_core::error::Error::Validation(msg) => {
+            assert!(msg.contains(&constraint_name), "{msg}");
+            assert!(msg.contains("name, age"), "{msg}");
+        }
+        other => panic!("Expected validation error, got {other:?}"),
+    }
+
+    // Only one of the fields matches
+    let mut other_age = create_test_dynamic_entity(&created_def);
+    other_age.set("age", 31)?;
+    repo.create(&other_age).await?;
+
+    Ok(())
+}
@@ -39,6 +39,7 @@ fn create_test_entity_definition_struct() -> EntityDefinition {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: r_data_core_core::field::ui::UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
             FieldDefinition {
                 name: "age".to_string(),
@@ -53,8 +54,13 @@ fn create_test_entity_definition_struct() -> EntityDefinition {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: r_data_core_core::field::ui::UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
         ],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: r_data_core_core::entity_definition::schema::Schema::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
@@ -146,7 +152,7 @@ async fn test_query_by_parent() -> Result<()> {
 
     // Test query by parent_uuid - should find both children
     let children = repo
-        .query_by_parent(&entity_type, parent_uuid, 100, 0)
+        .query_by_parent(&entity_type, parent_uuid, None, 100, 0)
         .await?;
     assert_eq!(children.len(), 2, "Should find 2 children");
 
@@ -252,7 +258,7 @@ async fn test_query_by_path() -> Result<()> {
 
     // Test query by path - should find entities at that path
     let path_entities = repo
-        .query_by_path(&entity_type, parent_path, 100, 0)
+        .query_by_path(&entity_type, parent_path, None, 100, 0)
         .await?;
     assert_eq!(path_entities.len(), 2, "Should find 2 entities at the path");
 
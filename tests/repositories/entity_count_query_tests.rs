@@ -0,0 +1,218 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// `exists` and `count` apply the advanced query's filters without fetching rows; list totals
+// are exact, estimated or skipped
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use r_data_core_core::count::{CountMode, ListTotal};
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::Result;
+use r_data_core_core::field::{FieldDefinition, FieldType};
+use r_data_core_core::public_api::AdvancedEntityQuery;
+use r_data_core_core::sort::SortCollation;
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::{
+    DynamicEntityQueryRepository, DynamicEntityRepository, DynamicEntityRepositoryTrait,
+    EntityDefinitionRepository,
+};
+use r_data_core_services::{DynamicEntityService, EntityDefinitionService};
+use r_data_core_test_support::{setup_test_db, unique_entity_type};
+
+async fn create_ticket(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+    status: &str,
+    priority: i64,
+    owner: Uuid,
+) -> Result<Uuid> {
+    let field_data = HashMap::from([
+        ("status".to_string(), json!(status)),
+        ("priority".to_string(), json!(priority)),
+        ("entity_key".to_string(), json!(Uuid::now_v7().to_string())),
+        ("path".to_string(), json!("/")),
+        ("created_by".to_string(), json!(owner.to_string())),
+    ]);
+    repo.create(&DynamicEntity {
+        entity_type: entity_type.to_string(),
+        field_data,
+        definition: Arc::new(EntityDefinition::default()),
+    })
+    .await
+}
+
+fn filter(entries: &[(&str, Value)]) -> HashMap<String, Value> {
+    entries
+        .iter()
+        .map(|(key, value)| ((*key).to_string(), value.clone()))
+        .collect()
+}
+
+#[tokio::test]
+async fn exists_and_count_match_the_full_query() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+    let ticket_type = unique_entity_type("ticket");
+    service
+        .create_entity_definition(&EntityDefinition {
+            entity_type: ticket_type.clone(),
+            display_name: ticket_type.clone(),
+            published: true,
+            created_by: Uuid::now_v7(),
+            fields: vec![
+                FieldDefinition::new(
+                    "status".to_string(),
+                    "Status".to_string(),
+                    FieldType::String,
+                ),
+                FieldDefinition::new(
+                    "priority".to_string(),
+                    "Priority".to_string(),
+                    FieldType::Integer,
+                ),
+            ],
+            ..EntityDefinition::default()
+        })
+        .await?;
+
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+    let alice = Uuid::now_v7();
+    let bob = Uuid::now_v7();
+    create_ticket(&repo, &ticket_type, "open", 1, alice).await?;
+    create_ticket(&repo, &ticket_type, "open", 2, bob).await?;
+    create_ticket(&repo, &ticket_type, "closed", 1, alice).await?;
+    // Soft-deleted entities neither exist nor count
+    let deleted = create_ticket(&repo, &ticket_type, "stale", 3, alice).await?;
+    repo.delete_by_type(&ticket_type, &deleted).await?;
+
+    let query_repo = DynamicEntityQueryRepository::new(db.pool.clone());
+    for (entries, expected) in [
+        (vec![], 3),
+        (vec![("status", json!("open"))], 2),
+        (vec![("status", json!("open")), ("priority", json!(1))], 1),
+        (vec![("status", json!("stale"))], 0),
+    ] {
+        let filter = filter(&entries);
+        let full = query_repo
+            .query_entities(
+                &ticket_type,
+                &AdvancedEntityQuery {
+                    filter: Some(filter.clone()),
+                    limit: Some(100),
+                    offset: None,
+                    sort_by: None,
+                    sort_direction: None,
+                    collation: None,
+                },
+            )
+            .await?;
+        assert_eq!(full.len(), expected, "{entries:?}");
+        assert_eq!(
+            query_repo.count(&ticket_type, Some(&filter)).await?,
+            i64::try_from(expected).expect("small count"),
+            "{entries:?}"
+        );
+        assert_eq!(
+            query_repo.exists(&ticket_type, Some(&filter)).await?,
+            expected > 0,
+            "{entries:?}"
+        );
+    }
+    assert_eq!(query_repo.count(&ticket_type, None).await?, 3);
+
+    // The owner scope applies as well
+    let bobs = DynamicEntityQueryRepository::new(db.pool.clone()).with_owner(Some(bob));
+    let closed = filter(&[("status", json!("closed"))]);
+    assert!(!bobs.exists(&ticket_type, Some(&closed)).await?);
+    assert_eq!(bobs.count(&ticket_type, None).await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_totals_are_exact_estimated_or_skipped() -> Result<()> {
+    const SEEDED: i64 = 120;
+
+    let db = setup_test_db().await;
+    let definitions = Arc::new(EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    )));
+    let ticket_type = unique_entity_type("ticket");
+    definitions
+        .create_entity_definition(&EntityDefinition {
+            entity_type: ticket_type.clone(),
+            display_name: ticket_type.clone(),
+            published: true,
+            created_by: Uuid::now_v7(),
+            fields: vec![
+                FieldDefinition::new(
+                    "status".to_string(),
+                    "Status".to_string(),
+                    FieldType::String,
+                ),
+                FieldDefinition::new(
+                    "priority".to_string(),
+                    "Priority".to_string(),
+                    FieldType::Integer,
+                ),
+            ],
+            ..EntityDefinition::default()
+        })
+        .await?;
+
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+    let owner = Uuid::now_v7();
+    for priority in 0..SEEDED {
+        create_ticket(&repo, &ticket_type, "open", priority, owner).await?;
+    }
+    // Table statistics are what the estimate reads
+    sqlx::query(&format!("ANALYZE entity_{}", ticket_type.to_lowercase()))
+        .execute(&db.pool)
+        .await
+        .map_err(r_data_core_core::error::Error::Database)?;
+
+    let service = DynamicEntityService::new(Arc::new(repo), definitions);
+    let list = |count_mode| {
+        service.list_entities_with_filters(
+            &ticket_type,
+            20,
+            0,
+            None,
+            None,
+            None,
+            SortCollation::Default,
+            None,
+            None,
+            false,
+            None,
+            count_mode,
+        )
+    };
+
+    let (entities, total) = list(CountMode::Exact).await?;
+    assert_eq!(entities.len(), 20);
+    assert_eq!(total, ListTotal::Exact(SEEDED));
+
+    let (entities, total) = list(CountMode::Approximate).await?;
+    assert_eq!(entities.len(), 20);
+    let ListTotal::Approximate(estimate) = total else {
+        panic!("expected an estimate, got {total:?}");
+    };
+    assert!(
+        (estimate - SEEDED).abs() <= SEEDED / 10,
+        "estimate {estimate} too far from {SEEDED}"
+    );
+
+    let (entities, total) = list(CountMode::None).await?;
+    assert_eq!(entities.len(), 20);
+    assert_eq!(total, ListTotal::Skipped);
+    assert_eq!(total.value(), None);
+
+    Ok(())
+}
@@ -42,6 +42,7 @@ async fn test_create_and_get_entity_definition() {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
             FieldDefinition {
                 name: "description".to_string(),
@@ -56,6 +57,7 @@ async fn test_create_and_get_entity_definition() {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
         ],
         created_by: creator_id,
@@ -121,6 +123,7 @@ async fn test_list_entity_definitions() {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             }],
             created_by: creator_id,
         });
@@ -178,6 +181,7 @@ async fn test_update_entity_definition() {
             validation: r_data_core_core::field::FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         }],
         created_by: creator_id,
     });
@@ -203,6 +207,7 @@ async fn test_update_entity_definition() {
         validation: r_data_core_core::field::FieldValidation::default(),
         ui_settings: UiSettings::default(),
         constraints: HashMap::new(),
+        display_order: 0,
     });
 
     // Save the update
@@ -266,6 +271,7 @@ async fn test_delete_entity_definition() {
             validation: r_data_core_core::field::FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         }],
         created_by: creator_id,
     });
@@ -322,6 +328,7 @@ async fn test_table_operations() {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
             FieldDefinition {
                 name: "column2".to_string(),
@@ -336,6 +343,7 @@ async fn test_table_operations() {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
         ],
         created_by: creator_id,
@@ -10,6 +10,7 @@ use r_data_core_core::entity_definition::definition::EntityDefinition;
 use r_data_core_core::error::Result;
 use r_data_core_core::field::ui::UiSettings;
 use r_data_core_core::field::{FieldDefinition, FieldType, FieldValidation};
+use r_data_core_core::sort::SortCollation;
 use r_data_core_core::DynamicEntity;
 use r_data_core_persistence::{
     DynamicEntityRepository, DynamicEntityRepositoryTrait, FilterEntitiesParams,
@@ -49,6 +50,7 @@ mod tests {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
         fields.push(name_field);
 
@@ -66,6 +68,7 @@ mod tests {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
         fields.push(email_field);
 
@@ -83,6 +86,7 @@ mod tests {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
         fields.push(age_field);
 
@@ -100,6 +104,7 @@ mod tests {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
         fields.push(active_field);
 
@@ -343,6 +348,59 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_filter_entities_sort_collation() -> Result<()> {
+        let db_pool = setup_test_db().await;
+        clear_test_db(&db_pool)
+            .await
+            .expect("Failed to clear test database");
+
+        let entity_type = unique_entity_type("testentity");
+        let _entity_uuid = create_test_entity_definition(&db_pool, &entity_type).await?;
+        let repository = DynamicEntityRepository::new(db_pool.pool.clone());
+
+        for (i, (name, age)) in [("Zebra", 9), ("apple", 10)].into_iter().enumerate() {
+            let mut field_data = HashMap::new();
+            field_data.insert("uuid".to_string(), json!(Uuid::now_v7().to_string()));
+            field_data.insert("entity_key".to_string(), json!(format!("collation-{i}")));
+            field_data.insert("name".to_string(), json!(name));
+            field_data.insert("age".to_string(), json!(age));
+            field_data.insert("created_by".to_string(), json!(Uuid::now_v7().to_string()));
+            repository
+                .create(&DynamicEntity {
+                    entity_type: entity_type.clone(),
+                    field_data,
+                    definition: Arc::new(EntityDefinition::default()),
+                })
+                .await?;
+        }
+
+        let sorted = |field: &str, collation| {
+            FilterEntitiesParams::new(100, 0)
+                .with_sort(Some((field.to_string(), "ASC".to_string())))
+                .with_collation(collation)
+        };
+        let names = |entities: &[DynamicEntity]| {
+            entities
+                .iter()
+                .map(|entity| entity.field_data["name"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let entities = repository
+            .filter_entities(&entity_type, &sorted("name", SortCollation::Ci))
+            .await?;
+        assert_eq!(names(&entities), ["apple", "Zebra"]);
+
+        // Numeric fields ignore the collation and keep sorting by value
+        let entities = repository
+            .filter_entities(&entity_type, &sorted("age", SortCollation::Ci))
+            .await?;
+        assert_eq!(names(&entities), ["Zebra", "apple"]);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_filter_entities_with_field_selection() -> Result<()> {
         // Setup database
@@ -452,4 +510,159 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_filter_entities_rejects_unsafe_identifiers() -> Result<()> {
+        let db_pool = setup_test_db().await;
+        let entity_type = unique_entity_type("testentity");
+        let _entity_uuid = create_test_entity_definition(&db_pool, &entity_type).await?;
+        let repository = DynamicEntityRepository::new(db_pool.pool.clone());
+
+        for unsafe_name in [
+            "name'; DROP TABLE entities_registry; --",
+            "first name",
+            "\"name\"",
+        ] {
+            let requests = [
+                FilterEntitiesParams::new(10, 0)
+                    .with_filters(Some(HashMap::from([(unsafe_name.to_string(), json!(1))]))),
+                FilterEntitiesParams::new(10, 0)
+                    .with_search(Some(("x".to_string(), vec![unsafe_name.to_string()]))),
+                FilterEntitiesParams::new(10, 0)
+                    .with_sort(Some((unsafe_name.to_string(), "ASC".to_string()))),
+                FilterEntitiesParams::new(10, 0).with_fields(Some(vec![unsafe_name.to_string()])),
+            ];
+            for params in &requests {
+                let result = repository.filter_entities(&entity_type, params).await;
+                assert!(
+                    matches!(result, Err(r_data_core_core::error::Error::Validation(_))),
+                    "{unsafe_name}: {result:?}"
+                );
+            }
+            let result = repository
+                .get_raw_field_value(&entity_type, &Uuid::now_v7(), unsafe_name)
+                .await;
+            assert!(result.is_err(), "{unsafe_name}");
+        }
+
+        let result = repository
+            .filter_entities("bad;type", &FilterEntitiesParams::new(10, 0))
+            .await;
+        assert!(matches!(
+            result,
+            Err(r_data_core_core::error::Error::Validation(_))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_money_filter_compares_amounts_within_currency() -> Result<()> {
+        let db = setup_test_db().await;
+        let entity_type = r_data_core_test_support::unique_entity_type("priced");
+        r_data_core_services::EntityDefinitionService::new_without_cache(Arc::new(
+            r_data_core_persistence::EntityDefinitionRepository::new(db.pool.clone()),
+        ))
+        .create_entity_definition(&EntityDefinition {
+            entity_type: entity_type.clone(),
+            display_name: entity_type.clone(),
+            published: true,
+            created_by: Uuid::now_v7(),
+            fields: vec![FieldDefinition::new(
+                "price".to_string(),
+                "Price".to_string(),
+                FieldType::Money,
+            )],
+            ..EntityDefinition::default()
+        })
+        .await?;
+
+        let repo = DynamicEntityRepository::new(db.pool.clone());
+        for (key, price) in [
+            ("cheap", json!({"amount": "9.99", "currency": "EUR"})),
+            ("dear", json!({"amount": 120, "currency": "EUR"})),
+            ("dollar", json!({"amount": 500, "currency": "USD"})),
+        ] {
+            repo.create(&DynamicEntity {
+                entity_type: entity_type.clone(),
+                field_data: HashMap::from([
+                    ("price".to_string(), price),
+                    ("entity_key".to_string(), json!(key)),
+                    ("path".to_string(), json!("/")),
+                ]),
+                definition: Arc::new(EntityDefinition::default()),
+            })
+            .await?;
+        }
+
+        let mut params = FilterEntitiesParams::new(10, 0);
+        params.filters = Some(HashMap::from([(
+            "price".to_string(),
+            json!({"amount": "10.00", "currency": "EUR"}),
+        )]));
+        params.filter_operators = Some(HashMap::from([("price".to_string(), ">=".to_string())]));
+        let found = repo.filter_entities(&entity_type, &params).await?;
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0].field_data.get("price"),
+            Some(&json!({"amount": 120, "currency": "EUR"}))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_duration_filter_finds_entities_longer_than_bound() -> Result<()> {
+        let db = setup_test_db().await;
+        let entity_type = r_data_core_test_support::unique_entity_type("sla");
+        r_data_core_services::EntityDefinitionService::new_without_cache(Arc::new(
+            r_data_core_persistence::EntityDefinitionRepository::new(db.pool.clone()),
+        ))
+        .create_entity_definition(&EntityDefinition {
+            entity_type: entity_type.clone(),
+            display_name: entity_type.clone(),
+            published: true,
+            created_by: Uuid::now_v7(),
+            fields: vec![FieldDefinition::new(
+                "response_time".to_string(),
+                "Response time".to_string(),
+                FieldType::Duration,
+            )],
+            ..EntityDefinition::default()
+        })
+        .await?;
+
+        let repo = DynamicEntityRepository::new(db.pool.clone());
+        for (key, response_time) in [("fast", "PT30M"), ("slow", "PT1H30M"), ("weekly", "P1W")] {
+            repo.create(&DynamicEntity {
+                entity_type: entity_type.clone(),
+                field_data: HashMap::from([
+                    ("response_time".to_string(), json!(response_time)),
+                    ("entity_key".to_string(), json!(key)),
+                    ("path".to_string(), json!("/")),
+                ]),
+                definition: Arc::new(EntityDefinition::default()),
+            })
+            .await?;
+        }
+
+        let mut params = FilterEntitiesParams::new(10, 0);
+        params.filters = Some(HashMap::from([(
+            "response_time".to_string(),
+            json!("PT1H"),
+        )]));
+        params.filter_operators = Some(HashMap::from([(
+            "response_time".to_string(),
+            ">".to_string(),
+        )]));
+        params.sort = Some(("response_time".to_string(), "ASC".to_string()));
+        let found = repo.filter_entities(&entity_type, &params).await?;
+
+        let durations: Vec<_> = found
+            .iter()
+            .map(|entity| entity.field_data.get("response_time").cloned())
+            .collect();
+        assert_eq!(durations, vec![Some(json!("PT1H30M")), Some(json!("P7D"))]);
+        Ok(())
+    }
 }
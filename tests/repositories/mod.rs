@@ -1,20 +1,29 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 pub mod admin_user_repository_tests;
 pub mod api_key_repository_tests;
+pub mod audit_log_tests;
+pub mod cascade_delete_tests;
 pub mod component_version_repository_tests;
+pub mod custom_table_name_tests;
 pub mod dashboard_stats_repository_tests;
 pub mod dynamic_entity_public_repository_tests;
 pub mod dynamic_entity_repository_tests;
 pub mod dynamic_entity_repository_tests_additional;
 pub mod email_template_tests;
+pub mod entity_count_query_tests;
 pub mod entity_definition_repository_tests;
 pub mod filter_entities_tests;
 pub mod outbox_repository_tests;
 pub mod password_reset_tests;
+pub mod read_replica_tests;
 pub mod refresh_token_repository_tests;
+pub mod relation_count_tests;
+pub mod soft_delete_tests;
+pub mod sql_identifier_tests;
 pub mod system_log_audit_tests;
 pub mod system_log_tests;
 pub mod version_repository_tests;
+pub mod workflow_raw_items_tests;
 
 use r_data_core_persistence::EntityDefinitionRepository;
 use r_data_core_test_support::{setup_test_db, TestDatabase};
@@ -146,6 +146,7 @@ async fn create_consumer_workflow(
                     ]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -287,6 +288,7 @@ async fn insert_run_queued_with_fetch_outbox_persists_claimable_message() -> any
                     ]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -474,6 +476,7 @@ async fn workflow_fetch_outbox_persists_only_job_reference_without_source_auth(
                     }]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -552,6 +555,7 @@ async fn workflow_processing_uses_push_outbox_mode_when_enabled() -> anyhow::Res
                     }]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -734,6 +738,7 @@ async fn workflow_push_outbox_resolves_auth_from_workflow_without_persisting_it(
                     }]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -988,6 +993,7 @@ async fn workflow_outbox_worker_reclaims_stale_processing_rows() -> anyhow::Resu
                     ]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -1085,6 +1091,7 @@ async fn workflow_outbox_worker_retries_when_queue_enqueue_fails() -> anyhow::Re
                     ]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -1178,6 +1185,7 @@ async fn workflow_outbox_worker_dead_letters_permanent_queue_failures() -> anyho
                     ]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
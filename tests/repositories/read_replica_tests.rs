@@ -0,0 +1,141 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Entity listings go to the read pool; writes and single-entity lookups to the primary. One test database
+// stands in for both; each pool counts the connections it hands out.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use serde_json::json;
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::Result;
+use r_data_core_core::field::{FieldDefinition, FieldType};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::{
+    Database, DynamicEntityRepository, DynamicEntityRepositoryTrait, EntityDefinitionRepository,
+    FilterEntitiesParams,
+};
+use r_data_core_services::EntityDefinitionService;
+use r_data_core_test_support::{setup_test_db, unique_entity_type, TestDatabase};
+
+/// Pool on the test schema that counts every connection it hands out
+async fn counting_pool(db: &TestDatabase, acquired: Arc<AtomicUsize>) -> PgPool {
+    let schema: String = sqlx::query_scalar("SELECT current_schema()::text")
+        .fetch_one(&db.pool)
+        .await
+        .expect("current schema");
+    let on_connect = acquired.clone();
+    PgPoolOptions::new()
+        .max_connections(2)
+        .after_connect(move |conn, _meta| {
+            let schema = schema.clone();
+            let acquired = on_connect.clone();
+            Box::pin(async move {
+                sqlx::query(&format!("SET search_path TO \"{schema}\", public"))
+                    .execute(conn)
+                    .await?;
+                acquired.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+        })
+        .before_acquire(move |_conn, _meta| {
+            let acquired = acquired.clone();
+            Box::pin(async move {
+                acquired.fetch_add(1, Ordering::SeqCst);
+                Ok(true)
+            })
+        })
+        .connect_lazy_with((*db.pool.connect_options()).clone())
+}
+
+#[tokio::test]
+async fn listings_use_the_read_pool_and_writes_the_primary() -> Result<()> {
+    let db = setup_test_db().await;
+    let entity_type = unique_entity_type("replica_note");
+    EntityDefinitionService::new_without_cache(Arc::new(EntityDefinitionRepository::new(
+        db.pool.clone(),
+    )))
+    .create_entity_definition(&EntityDefinition {
+        entity_type: entity_type.clone(),
+        display_name: entity_type.clone(),
+        published: true,
+        created_by: Uuid::now_v7(),
+        fields: vec![FieldDefinition::new(
+            "title".to_string(),
+            "Title".to_string(),
+            FieldType::String,
+        )],
+        ..EntityDefinition::default()
+    })
+    .await?;
+
+    let primary_uses = Arc::new(AtomicUsize::new(0));
+    let read_uses = Arc::new(AtomicUsize::new(0));
+    let primary = counting_pool(&db, primary_uses.clone()).await;
+    let replica = counting_pool(&db, read_uses.clone()).await;
+    let repo = DynamicEntityRepository::new(primary.clone())
+        .with_database(Database::new(primary).with_read_replicas(vec![replica]));
+
+    let uuid = repo
+        .create(&DynamicEntity {
+            entity_type: entity_type.clone(),
+            field_data: HashMap::from([
+                ("title".to_string(), json!("Routed")),
+                ("entity_key".to_string(), json!("routed")),
+                ("path".to_string(), json!("/")),
+            ]),
+            definition: Arc::new(EntityDefinition::default()),
+        })
+        .await?;
+    assert!(primary_uses.load(Ordering::SeqCst) > 0);
+    assert_eq!(read_uses.load(Ordering::SeqCst), 0);
+
+    // Single-entity lookups feed updates and upserts, so they stay on the primary
+    let writes = primary_uses.load(Ordering::SeqCst);
+    assert!(repo.get_by_type(&entity_type, &uuid, None).await?.is_some());
+    let filters = HashMap::from([("title".to_string(), json!("Routed"))]);
+    assert!(repo
+        .find_one_by_filters(&entity_type, &filters)
+        .await?
+        .is_some());
+    assert!(primary_uses.load(Ordering::SeqCst) > writes);
+    assert_eq!(read_uses.load(Ordering::SeqCst), 0);
+
+    let primary_reads = primary_uses.load(Ordering::SeqCst);
+    assert_eq!(
+        repo.get_all_by_type(&entity_type, 10, 0, None).await?.len(),
+        1
+    );
+    assert_eq!(repo.count_entities(&entity_type).await?, 1);
+    assert_eq!(
+        repo.filter_entities(&entity_type, &FilterEntitiesParams::new(10, 0))
+            .await?
+            .len(),
+        1
+    );
+    assert_eq!(primary_uses.load(Ordering::SeqCst), primary_reads);
+    assert!(read_uses.load(Ordering::SeqCst) >= 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn without_replicas_reads_use_the_primary() -> Result<()> {
+    let db = setup_test_db().await;
+    let primary_uses = Arc::new(AtomicUsize::new(0));
+    let primary = counting_pool(&db, primary_uses.clone()).await;
+    let database = Database::new(primary.clone());
+    assert!(!database.has_read_replicas());
+
+    let repo = DynamicEntityRepository::new(primary).with_database(database);
+    let entity_type = unique_entity_type("no_replica");
+    assert_eq!(repo.estimate_entities(&entity_type).await?, None);
+    assert!(primary_uses.load(Ordering::SeqCst) > 0);
+
+    Ok(())
+}
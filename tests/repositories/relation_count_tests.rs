@@ -0,0 +1,197 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::field::{FieldDefinition, FieldType};
+use r_data_core_core::public_api::AdvancedEntityQuery;
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::{
+    DynamicEntityQueryRepository, DynamicEntityRepository, DynamicEntityRepositoryTrait,
+    EntityDefinitionRepository,
+};
+use r_data_core_services::EntityDefinitionService;
+use r_data_core_test_support::{setup_test_db, unique_entity_type};
+
+async fn create_definition(
+    service: &EntityDefinitionService,
+    entity_type: &str,
+    mut fields: Vec<FieldDefinition>,
+) -> Result<()> {
+    fields.insert(
+        0,
+        FieldDefinition::new("name".to_string(), "Name".to_string(), FieldType::String),
+    );
+    let definition = EntityDefinition {
+        entity_type: entity_type.to_string(),
+        display_name: entity_type.to_string(),
+        published: true,
+        created_by: Uuid::now_v7(),
+        fields,
+        ..EntityDefinition::default()
+    };
+    service.create_entity_definition(&definition).await?;
+    Ok(())
+}
+
+async fn create_entity(
+    repo: &DynamicEntityRepository,
+    entity_type: &str,
+    mut field_data: HashMap<String, serde_json::Value>,
+) -> Result<Uuid> {
+    field_data.insert("entity_key".to_string(), json!(Uuid::now_v7().to_string()));
+    field_data.insert("path".to_string(), json!("/"));
+    field_data.insert("created_by".to_string(), json!(Uuid::now_v7().to_string()));
+    repo.create(&DynamicEntity {
+        entity_type: entity_type.to_string(),
+        field_data,
+        definition: Arc::new(EntityDefinition::default()),
+    })
+    .await
+}
+
+fn many_to_one(name: &str, target: &str) -> FieldDefinition {
+    let mut field = FieldDefinition::new(name.to_string(), name.to_string(), FieldType::ManyToOne);
+    field.validation.target_class = Some(target.to_string());
+    field
+}
+
+const fn query_all() -> AdvancedEntityQuery {
+    AdvancedEntityQuery {
+        filter: None,
+        limit: Some(100),
+        offset: None,
+        sort_by: None,
+        sort_direction: None,
+        collation: None,
+    }
+}
+
+fn count_by_name(entities: &[DynamicEntity], key: &str) -> HashMap<String, i64> {
+    entities
+        .iter()
+        .map(|e| {
+            (
+                e.field_data["name"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                e.field_data[key]
+                    .as_i64()
+                    .expect("count should be an integer"),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn relation_counts_match_child_counts() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+    let customer_type = unique_entity_type("customer");
+    let order_type = unique_entity_type("order");
+    let invoice_type = unique_entity_type("invoice");
+    create_definition(&service, &customer_type, Vec::new()).await?;
+    create_definition(
+        &service,
+        &order_type,
+        vec![many_to_one("customer", &customer_type)],
+    )
+    .await?;
+    create_definition(
+        &service,
+        &invoice_type,
+        vec![many_to_one("billed_to", &customer_type)],
+    )
+    .await?;
+
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+    let mut customers = HashMap::new();
+    for name in ["alice", "bob", "carol"] {
+        let uuid = create_entity(
+            &repo,
+            &customer_type,
+            HashMap::from([("name".to_string(), json!(name))]),
+        )
+        .await?;
+        customers.insert(name, uuid);
+    }
+
+    let order_for = |name: &str| {
+        HashMap::from([
+            ("name".to_string(), json!(format!("order for {name}"))),
+            ("customer".to_string(), json!(customers[name].to_string())),
+        ])
+    };
+    create_entity(&repo, &order_type, order_for("alice")).await?;
+    create_entity(&repo, &order_type, order_for("alice")).await?;
+    create_entity(&repo, &order_type, order_for("bob")).await?;
+    // Soft-deleted children are not counted
+    let deleted = create_entity(&repo, &order_type, order_for("bob")).await?;
+    repo.delete_by_type(&order_type, &deleted).await?;
+    create_entity(
+        &repo,
+        &invoice_type,
+        HashMap::from([
+            ("name".to_string(), json!("invoice")),
+            (
+                "billed_to".to_string(),
+                json!(customers["carol"].to_string()),
+            ),
+        ]),
+    )
+    .await?;
+
+    let query_repo = DynamicEntityQueryRepository::new(db.pool.clone());
+    let entities = query_repo
+        .query_entities_with_counts(
+            &customer_type,
+            &query_all(),
+            &["customer".to_string(), format!("{invoice_type}.billed_to")],
+        )
+        .await?;
+    assert_eq!(entities.len(), 3);
+
+    let orders = count_by_name(&entities, "customer_count");
+    assert_eq!(orders["alice"], 2);
+    assert_eq!(orders["bob"], 1);
+    assert_eq!(orders["carol"], 0);
+
+    let invoices = count_by_name(&entities, &format!("{invoice_type}_billed_to_count"));
+    assert_eq!(invoices["alice"], 0);
+    assert_eq!(invoices["carol"], 1);
+
+    // Counts are only added when requested
+    let plain = query_repo
+        .query_entities(&customer_type, &query_all())
+        .await?;
+    assert!(!plain[0].field_data.contains_key("customer_count"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unknown_relation_is_rejected() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+    let customer_type = unique_entity_type("customer");
+    create_definition(&service, &customer_type, Vec::new()).await?;
+
+    let query_repo = DynamicEntityQueryRepository::new(db.pool.clone());
+    let err = query_repo
+        .query_entities_with_counts(&customer_type, &query_all(), &["orders".to_string()])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Validation(_)), "got {err:?}");
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::Arc;
+
+use r_data_core_core::cache::CacheManager;
+use r_data_core_core::config::CacheConfig;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_persistence::{
+    missing_entity_cache_key, DynamicEntityRepository, DynamicEntityRepositoryTrait,
+    FilterEntitiesParams,
+};
+use r_data_core_test_support::{
+    create_test_entity, create_test_entity_definition, setup_test_db, unique_entity_type,
+};
+
+#[tokio::test]
+async fn soft_deleted_entity_is_hidden_from_normal_queries() -> Result<()> {
+    let db = setup_test_db().await;
+    let entity_type = unique_entity_type("softdel");
+    create_test_entity_definition(&db.pool, &entity_type).await?;
+    let kept = create_test_entity(&db.pool, &entity_type, "Kept", "kept@example.com").await?;
+    let deleted =
+        create_test_entity(&db.pool, &entity_type, "Deleted", "deleted@example.com").await?;
+
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+    repo.delete_by_type(&entity_type, &deleted).await?;
+
+    assert!(repo
+        .get_by_type(&entity_type, &deleted, None)
+        .await?
+        .is_none());
+    assert!(repo.get_by_uuid_any_type(&deleted).await?.is_none());
+    assert!(repo.get_by_type(&entity_type, &kept, None).await?.is_some());
+
+    let all = repo.get_all_by_type(&entity_type, 100, 0, None).await?;
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].field_data["uuid"], kept.to_string());
+
+    let filtered = repo
+        .filter_entities(&entity_type, &FilterEntitiesParams::new(100, 0))
+        .await?;
+    assert_eq!(filtered.len(), 1);
+
+    assert_eq!(repo.count_entities(&entity_type).await?, 1);
+    assert_eq!(repo.count_deleted_entities(&entity_type).await?, 1);
+
+    // The entity row itself is kept for history
+    let registry_rows: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM entities_registry WHERE uuid = $1")
+            .bind(deleted)
+            .fetch_one(&db.pool)
+            .await?;
+    assert_eq!(registry_rows, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn include_deleted_returns_soft_deleted_entities() -> Result<()> {
+    let db = setup_test_db().await;
+    let entity_type = unique_entity_type("softdel");
+    create_test_entity_definition(&db.pool, &entity_type).await?;
+    create_test_entity(&db.pool, &entity_type, "Kept", "kept@example.com").await?;
+    let deleted =
+        create_test_entity(&db.pool, &entity_type, "Deleted", "deleted@example.com").await?;
+
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+    repo.delete_by_type(&entity_type, &deleted).await?;
+
+    let params = FilterEntitiesParams::new(100, 0).with_include_deleted(true);
+    let entities = repo.filter_entities(&entity_type, &params).await?;
+    assert_eq!(entities.len(), 2);
+
+    let deleted_entity = entities
+        .iter()
+        .find(|e| e.field_data["uuid"] == deleted.to_string())
+        .expect("soft-deleted entity should be listed");
+    assert!(deleted_entity.field_data["deleted_at"].is_string());
+
+    let live_entity = entities
+        .iter()
+        .find(|e| e.field_data["uuid"] != deleted.to_string())
+        .expect("live entity should be listed");
+    assert!(!live_entity.field_data.contains_key("deleted_at"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn restore_makes_entity_visible_again() -> Result<()> {
+    let db = setup_test_db().await;
+    let entity_type = unique_entity_type("softdel");
+    create_test_entity_definition(&db.pool, &entity_type).await?;
+    let uuid = create_test_entity(&db.pool, &entity_type, "Alice", "alice@example.com").await?;
+
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+    repo.delete_by_type(&entity_type, &uuid).await?;
+    repo.restore_by_type(&entity_type, &uuid).await?;
+
+    assert!(repo.get_by_type(&entity_type, &uuid, None).await?.is_some());
+    assert_eq!(repo.count_deleted_entities(&entity_type).await?, 0);
+
+    // Restoring a live entity or deleting twice is reported as not found
+    let err = repo.restore_by_type(&entity_type, &uuid).await.unwrap_err();
+    assert!(matches!(err, Error::NotFound(_)));
+    repo.delete_by_type(&entity_type, &uuid).await?;
+    let err = repo.delete_by_type(&entity_type, &uuid).await.unwrap_err();
+    assert!(matches!(err, Error::NotFound(_)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn restore_clears_missing_entity_marker() -> Result<()> {
+    let db = setup_test_db().await;
+    let entity_type = unique_entity_type("softdel");
+    create_test_entity_definition(&db.pool, &entity_type).await?;
+    let uuid = create_test_entity(&db.pool, &entity_type, "Alice", "alice@example.com").await?;
+
+    let cache = Arc::new(CacheManager::new(CacheConfig::default()));
+    let repo = DynamicEntityRepository::with_cache(db.pool.clone(), cache.clone());
+    repo.delete_by_type(&entity_type, &uuid).await?;
+
+    // A lookup while deleted left a marker; restoring outside the service must drop it
+    let key = missing_entity_cache_key(&entity_type, &uuid);
+    cache.set(&key, &true, Some(60)).await?;
+    repo.restore_by_type(&entity_type, &uuid).await?;
+    assert_eq!(cache.get::<bool>(&key).await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hard_delete_removes_soft_deleted_entity() -> Result<()> {
+    let db = setup_test_db().await;
+    let entity_type = unique_entity_type("softdel");
+    create_test_entity_definition(&db.pool, &entity_type).await?;
+    let uuid = create_test_entity(&db.pool, &entity_type, "Alice", "alice@example.com").await?;
+
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+    repo.delete_by_type(&entity_type, &uuid).await?;
+    repo.hard_delete(&entity_type, &uuid).await?;
+
+    let registry_rows: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM entities_registry WHERE uuid = $1")
+            .bind(uuid)
+            .fetch_one(&db.pool)
+            .await?;
+    assert_eq!(registry_rows, 0);
+
+    let params = FilterEntitiesParams::new(100, 0).with_include_deleted(true);
+    assert!(repo
+        .filter_entities(&entity_type, &params)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deleted_key_can_be_reused_and_blocks_restore() -> Result<()> {
+    let db = setup_test_db().await;
+    let entity_type = unique_entity_type("softdel");
+    create_test_entity_definition(&db.pool, &entity_type).await?;
+    let deleted = create_test_entity(&db.pool, &entity_type, "Alice", "alice@example.com").await?;
+    let other = create_test_entity(&db.pool, &entity_type, "Bob", "bob@example.com").await?;
+
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+    repo.delete_by_type(&entity_type, &deleted).await?;
+
+    // A live entity may take over the key of a soft-deleted one
+    sqlx::query(
+        "UPDATE entities_registry SET entity_key = (SELECT entity_key FROM entities_registry WHERE uuid = $1)
+        WHERE uuid = $2",
+    )
+    .bind(deleted)
+    .bind(other)
+    .execute(&db.pool)
+    .await?;
+
+    let err = repo
+        .restore_by_type(&entity_type, &deleted)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::ValidationFailed(_)));
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Entity types and field names that are not safe SQL identifiers are rejected before any
+// dynamic statement is built from them
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::field::{FieldDefinition, FieldType};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::{
+    DynamicEntityRepository, DynamicEntityRepositoryTrait, EntityDefinitionRepository,
+    FilterEntitiesParams,
+};
+use r_data_core_services::EntityDefinitionService;
+use r_data_core_test_support::{setup_test_db, unique_entity_type};
+
+const ADVERSARIAL: &[&str] = &[
+    "name; DROP TABLE entities_registry; --",
+    "name\" FROM entities_registry --",
+    "name' OR '1'='1",
+    "name) VALUES (1); --",
+    "first name",
+    "näme",
+];
+
+fn assert_rejected<T: std::fmt::Debug>(result: Result<T>, input: &str) {
+    match result {
+        Err(Error::Validation(_)) => {}
+        other => panic!("expected {input:?} to be rejected, got {other:?}"),
+    }
+}
+
+async fn registry_exists(pool: &sqlx::PgPool) -> Result<bool> {
+    Ok(
+        sqlx::query_scalar("SELECT to_regclass('entities_registry') IS NOT NULL")
+            .fetch_one(pool)
+            .await?,
+    )
+}
+
+#[tokio::test]
+async fn adversarial_field_names_are_rejected() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+    let entity_type = unique_entity_type("ident");
+    service
+        .create_entity_definition(&EntityDefinition {
+            entity_type: entity_type.clone(),
+            display_name: entity_type.clone(),
+            published: true,
+            created_by: Uuid::now_v7(),
+            fields: vec![FieldDefinition::new(
+                "name".to_string(),
+                "Name".to_string(),
+                FieldType::String,
+            )],
+            ..EntityDefinition::default()
+        })
+        .await?;
+
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+    let uuid = repo
+        .create(&DynamicEntity {
+            entity_type: entity_type.clone(),
+            field_data: HashMap::from([
+                ("name".to_string(), json!("Row")),
+                ("entity_key".to_string(), json!(Uuid::now_v7().to_string())),
+                ("path".to_string(), json!("/")),
+                ("created_by".to_string(), json!(Uuid::now_v7().to_string())),
+            ]),
+            definition: Arc::new(EntityDefinition::default()),
+        })
+        .await?;
+
+    for field in ADVERSARIAL {
+        assert_rejected(
+            repo.get_raw_field_value(&entity_type, &uuid, field).await,
+            field,
+        );
+        assert_rejected(
+            repo.filter_entities(
+                &entity_type,
+                &FilterEntitiesParams::new(10, 0)
+                    .with_filters(Some(HashMap::from([((*field).to_string(), json!("x"))]))),
+            )
+            .await,
+            field,
+        );
+        assert_rejected(
+            repo.filter_entities(
+                &entity_type,
+                &FilterEntitiesParams::new(10, 0).with_fields(Some(vec![(*field).to_string()])),
+            )
+            .await,
+            field,
+        );
+        assert_rejected(
+            repo.filter_entities(
+                &entity_type,
+                &FilterEntitiesParams::new(10, 0)
+                    .with_sort(Some(((*field).to_string(), "ASC".to_string()))),
+            )
+            .await,
+            field,
+        );
+        assert_rejected(
+            repo.filter_entities(
+                &entity_type,
+                &FilterEntitiesParams::new(10, 0)
+                    .with_search(Some(("x".to_string(), vec![(*field).to_string()]))),
+            )
+            .await,
+            field,
+        );
+    }
+
+    assert!(registry_exists(&db.pool).await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn adversarial_entity_types_are_rejected() -> Result<()> {
+    let db = setup_test_db().await;
+    let repo = DynamicEntityRepository::new(db.pool.clone());
+
+    for entity_type in ADVERSARIAL {
+        assert_rejected(
+            repo.get_raw_field_value(entity_type, &Uuid::now_v7(), "name")
+                .await,
+            entity_type,
+        );
+        assert_rejected(repo.count_entities(entity_type).await, entity_type);
+        assert_rejected(
+            repo.hard_delete(entity_type, &Uuid::now_v7()).await,
+            entity_type,
+        );
+    }
+
+    assert!(registry_exists(&db.pool).await?);
+    Ok(())
+}
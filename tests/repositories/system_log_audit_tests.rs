@@ -3,7 +3,7 @@
 //! Integration tests verifying that service CRUD operations write audit entries to `system_logs`.
 
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_core::entity_definition::definition::{EntityDefinition, EntityDefinitionParams};
 use r_data_core_core::field::types::FieldType;
 use r_data_core_core::field::ui::UiSettings;
@@ -66,6 +66,10 @@ fn disabled_cache() -> Arc<CacheManager> {
         max_size: 10_000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     Arc::new(CacheManager::new(config))
 }
@@ -79,6 +83,7 @@ fn minimal_workflow_request(name: &str) -> CreateWorkflowRequest {
         enabled: true,
         schedule_cron: None,
         versioning_disabled: false,
+        completion_webhook_url: None,
         config: serde_json::json!({
             "steps": [{
                 "from": {
@@ -124,6 +129,7 @@ fn minimal_entity_definition(entity_type: &str, created_by: Uuid) -> EntityDefin
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         }],
         created_by,
     })
@@ -454,7 +460,14 @@ async fn test_api_key_create_logs_to_system_log() {
 
     let user_uuid = create_test_admin_user(&pool.pool).await.expect("user");
     let (key_uuid, _key_value) = service
-        .create_api_key("audit-create-key", "audit test key", user_uuid, 30)
+        .create_api_key(
+            "audit-create-key",
+            "audit test key",
+            user_uuid,
+            30,
+            &[],
+            &[],
+        )
         .await
         .expect("create_api_key");
 
@@ -484,7 +497,14 @@ async fn test_api_key_revoke_logs_to_system_log() {
 
     let user_uuid = create_test_admin_user(&pool.pool).await.expect("user");
     let (key_uuid, _key_value) = service
-        .create_api_key("audit-revoke-key", "audit test revoke key", user_uuid, 30)
+        .create_api_key(
+            "audit-revoke-key",
+            "audit test revoke key",
+            user_uuid,
+            30,
+            &[],
+            &[],
+        )
         .await
         .expect("create_api_key");
 
@@ -0,0 +1,71 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// `insert_raw_items` writes large ingests in bounded multi-row statements
+
+use r_data_core_core::error::Result;
+use r_data_core_persistence::WorkflowRepository;
+use r_data_core_test_support::{create_test_admin_user, setup_test_db};
+use r_data_core_workflow::data::requests::CreateWorkflowRequest;
+use r_data_core_workflow::data::WorkflowKind;
+use serde_json::json;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn large_ingests_are_inserted_in_chunks() -> Result<()> {
+    let db = setup_test_db().await;
+    let creator = create_test_admin_user(&db)
+        .await
+        .expect("create test admin user");
+    let repo = WorkflowRepository::new(db.pool.clone());
+    let workflow = repo
+        .create(
+            &CreateWorkflowRequest {
+                name: format!("raw-items-{}", Uuid::now_v7().simple()),
+                description: None,
+                kind: WorkflowKind::Consumer.to_string(),
+                enabled: true,
+                schedule_cron: None,
+                config: json!({ "steps": [] }),
+                versioning_disabled: false,
+                completion_webhook_url: None,
+            },
+            creator,
+        )
+        .await?;
+    let run = repo.insert_run_queued(workflow, Uuid::now_v7()).await?;
+
+    let chunk = WorkflowRepository::RAW_ITEMS_INSERT_CHUNK_SIZE;
+    let payloads = (0..=chunk * 2).map(|i| json!({ "i": i })).collect();
+    let staged = repo.insert_raw_items(workflow, run, payloads).await?;
+    assert_eq!(staged, i64::try_from(chunk * 2 + 1).expect("small count"));
+    // Appended items continue the numbering of the run
+    repo.insert_raw_items(workflow, run, vec![json!({ "i": "last" })])
+        .await?;
+
+    // Each statement commits on its own, so the rows of one statement share their `xmin`
+    let statements: i64 = sqlx::query_scalar(
+        "SELECT COUNT(DISTINCT xmin::text) FROM workflow_raw_items WHERE workflow_run_uuid = $1",
+    )
+    .bind(run)
+    .fetch_one(&db.pool)
+    .await?;
+    assert_eq!(statements, 4);
+
+    let seq_nos: Vec<i64> = sqlx::query_scalar(
+        "SELECT seq_no FROM workflow_raw_items WHERE workflow_run_uuid = $1 ORDER BY seq_no",
+    )
+    .bind(run)
+    .fetch_all(&db.pool)
+    .await?;
+    let expected: Vec<i64> = (1..=i64::try_from(chunk * 2 + 2).expect("small count")).collect();
+    assert_eq!(seq_nos, expected);
+    let first: serde_json::Value = sqlx::query_scalar(
+        "SELECT payload FROM workflow_raw_items WHERE workflow_run_uuid = $1 AND seq_no = 1",
+    )
+    .bind(run)
+    .fetch_one(&db.pool)
+    .await?;
+    assert_eq!(first, json!({ "i": 0 }));
+
+    Ok(())
+}
@@ -26,6 +26,7 @@ mockall::mock! {
         async fn get_by_entity_type(&self, entity_type: &str) -> Result<Option<EntityDefinition>>;
         async fn create(&self, definition: &EntityDefinition) -> Result<Uuid>;
         async fn update(&self, uuid: &Uuid, definition: &EntityDefinition) -> Result<()>;
+        async fn rename_field(&self, uuid: &Uuid, definition: &EntityDefinition, old_name: &str, new_name: &str, rename_in_versions: bool) -> Result<()>;
         async fn delete(&self, uuid: &Uuid) -> Result<()>;
         async fn apply_schema(&self, schema_sql: &str) -> Result<()>;
         async fn update_entity_view_for_entity_definition(&self, entity_definition: &EntityDefinition) -> Result<()>;
@@ -33,6 +34,8 @@ mockall::mock! {
         async fn get_view_columns_with_types(&self, view_name: &str) -> Result<HashMap<String, String>>;
         async fn count_view_records(&self, view_name: &str) -> Result<i64>;
         async fn cleanup_unused_entity_view(&self) -> Result<()>;
+        async fn find_table_owner(&self, table_name: &str, exclude: Option<Uuid>) -> Result<Option<String>>;
+        async fn is_foreign_table(&self, table_name: &str) -> Result<bool>;
     }
 }
 
@@ -60,6 +63,7 @@ fn create_test_entity_definition() -> EntityDefinition {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: r_data_core_core::field::ui::UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
             FieldDefinition {
                 name: "age".to_string(),
@@ -74,8 +78,13 @@ fn create_test_entity_definition() -> EntityDefinition {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: r_data_core_core::field::ui::UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
         ],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: Schema::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
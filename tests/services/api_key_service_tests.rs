@@ -33,9 +33,13 @@ mock! {
             description: &str,
             created_by: Uuid,
             expires_in_days: i32,
+            scopes: &[String],
+            allowed_cidrs: &[String],
         ) -> Result<(Uuid, String)>;
         async fn update_last_used(&self, uuid: Uuid) -> Result<()>;
         async fn reassign(&self, uuid: Uuid, new_user_uuid: Uuid) -> Result<()>;
+        async fn update_rate_limit(&self, uuid: Uuid, rate_limit_per_minute: Option<i32>) -> Result<()>;
+        async fn update_expires_at(&self, uuid: Uuid, expires_at: Option<time::OffsetDateTime>) -> Result<()>;
         async fn get_api_key_roles(&self, api_key_uuid: Uuid) -> Result<Vec<Uuid>>;
         async fn assign_role(&self, api_key_uuid: Uuid, role_uuid: Uuid) -> Result<()>;
         async fn unassign_role(&self, api_key_uuid: Uuid, role_uuid: Uuid) -> Result<()>;
@@ -51,7 +55,7 @@ async fn test_create_api_key_with_invalid_user_uuid() -> Result<()> {
     // Setup mock to simulate a foreign key constraint error
     mock_repo
         .expect_create_new_api_key()
-        .returning(|_, _, _, _| {
+        .returning(|_, _, _, _, _, _| {
             // Create a custom error message for the foreign key violation
             let error_message = "foreign key constraint violation".to_string();
             Err(r_data_core_core::error::Error::Database(
@@ -63,7 +67,14 @@ async fn test_create_api_key_with_invalid_user_uuid() -> Result<()> {
 
     // Attempt to create a key with the invalid user UUID
     let result = service
-        .create_api_key("Test Key", "Test Description", invalid_user_uuid, 30)
+        .create_api_key(
+            "Test Key",
+            "Test Description",
+            invalid_user_uuid,
+            30,
+            &[],
+            &[],
+        )
         .await;
 
     // Verify failure
@@ -125,8 +136,10 @@ async fn test_create_api_key_with_long_name() -> Result<()> {
             predicate::always(),
             predicate::always(),
             predicate::always(),
+            predicate::always(),
+            predicate::always(),
         )
-        .returning(|_, _, _, _| {
+        .returning(|_, _, _, _, _, _| {
             // Use a Protocol error instead of trying to construct a PgDatabaseError
             Err(r_data_core_core::error::Error::Database(
                 sqlx::Error::Protocol(
@@ -139,7 +152,7 @@ async fn test_create_api_key_with_long_name() -> Result<()> {
 
     // Attempt to create a key with the long name
     let result = service
-        .create_api_key(&long_name, "Test Description", user_uuid, 30)
+        .create_api_key(&long_name, "Test Description", user_uuid, 30, &[], &[])
         .await;
 
     // Verify failure
@@ -177,6 +190,9 @@ async fn test_validate_inactive_api_key() -> Result<()> {
         last_used_at: None,
         created_by: user_uuid,
         published: true,
+        scopes: vec![],
+        rate_limit_per_minute: None,
+        allowed_cidrs: vec![],
     };
 
     // The repository layer should filter out inactive keys and return None
@@ -245,6 +261,9 @@ async fn test_revoke_key_unauthorized() -> Result<()> {
         last_used_at: None,
         created_by: key_owner_uuid,
         published: true,
+        scopes: vec![],
+        rate_limit_per_minute: None,
+        allowed_cidrs: vec![],
     };
 
     // Mock returns the key but it belongs to a different user
@@ -279,7 +298,7 @@ async fn test_negative_expiration_days() -> Result<()> {
 
     // Attempt to create a key with negative expiration days
     let result = service
-        .create_api_key("Test Key", "Test Description", user_uuid, -10)
+        .create_api_key("Test Key", "Test Description", user_uuid, -10, &[], &[])
         .await;
 
     // Verify we get a validation error
@@ -314,6 +333,9 @@ async fn test_reassign_key() -> Result<()> {
         last_used_at: None,
         created_by: original_user_uuid,
         published: true,
+        scopes: vec![],
+        rate_limit_per_minute: None,
+        allowed_cidrs: vec![],
     };
 
     // Mock returns the key when requested
@@ -392,6 +414,9 @@ async fn test_reassign_key_detailed() -> Result<()> {
         last_used_at: None,
         created_by: original_user_uuid,
         published: true,
+        scopes: vec![],
+        rate_limit_per_minute: None,
+        allowed_cidrs: vec![],
     };
 
     // Create mock API key with new user_uuid after reassignment
@@ -407,6 +432,9 @@ async fn test_reassign_key_detailed() -> Result<()> {
         last_used_at: None,
         created_by: original_user_uuid,
         published: true,
+        scopes: vec![],
+        rate_limit_per_minute: None,
+        allowed_cidrs: vec![],
     };
 
     // Setup mock repository
@@ -27,9 +27,13 @@ mock! {
             description: &str,
             created_by: Uuid,
             expires_in_days: i32,
+            scopes: &[String],
+            allowed_cidrs: &[String],
         ) -> Result<(Uuid, String)>;
         async fn update_last_used(&self, uuid: Uuid) -> Result<()>;
         async fn reassign(&self, uuid: Uuid, new_user_uuid: Uuid) -> Result<()>;
+        async fn update_rate_limit(&self, uuid: Uuid, rate_limit_per_minute: Option<i32>) -> Result<()>;
+        async fn update_expires_at(&self, uuid: Uuid, expires_at: Option<time::OffsetDateTime>) -> Result<()>;
         async fn count_by_user(&self, user_uuid: Uuid) -> Result<i64>;
         async fn get_api_key_roles(&self, api_key_uuid: Uuid) -> Result<Vec<Uuid>>;
         async fn assign_role(&self, api_key_uuid: Uuid, role_uuid: Uuid) -> Result<()>;
@@ -62,6 +66,9 @@ mod tests {
             last_used_at: None,
             created_by: user_uuid,
             published: true,
+            scopes: vec![],
+            rate_limit_per_minute: None,
+            allowed_cidrs: vec![],
         };
 
         // Setup mock expectations
@@ -146,15 +153,22 @@ mod tests {
         // Test with empty name
         mock_repo
             .expect_create_new_api_key()
-            .with(eq(""), eq("Test description"), eq(user_uuid), eq(30))
-            .returning(|_, _, _, _| {
+            .with(
+                eq(""),
+                eq("Test description"),
+                eq(user_uuid),
+                eq(30),
+                always(),
+                always(),
+            )
+            .returning(|_, _, _, _, _, _| {
                 Err(r_data_core_core::error::Error::Validation(
                     "API key name cannot be empty".to_string(),
                 ))
             });
 
         let result = mock_repo
-            .create_new_api_key("", "Test description", user_uuid, 30)
+            .create_new_api_key("", "Test description", user_uuid, 30, &[], &[])
             .await;
 
         assert!(result.is_err());
@@ -172,15 +186,17 @@ mod tests {
                 eq("Test description"),
                 eq(user_uuid),
                 eq(-1),
+                always(),
+                always(),
             )
-            .returning(|_, _, _, _| {
+            .returning(|_, _, _, _, _, _| {
                 Err(r_data_core_core::error::Error::Validation(
                     "Expiration days cannot be negative".to_string(),
                 ))
             });
 
         let result = mock_repo
-            .create_new_api_key("Test Key", "Test description", user_uuid, -1)
+            .create_new_api_key("Test Key", "Test description", user_uuid, -1, &[], &[])
             .await;
 
         assert!(result.is_err());
@@ -271,6 +287,9 @@ mod tests {
                 last_used_at: None,
                 created_by: user_uuid,
                 published: true,
+                scopes: vec![],
+                rate_limit_per_minute: None,
+                allowed_cidrs: vec![],
             },
             ApiKey {
                 uuid: Uuid::now_v7(),
@@ -284,6 +303,9 @@ mod tests {
                 last_used_at: None,
                 created_by: user_uuid,
                 published: true,
+                scopes: vec![],
+                rate_limit_per_minute: None,
+                allowed_cidrs: vec![],
             },
         ];
 
@@ -0,0 +1,104 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Run completion webhooks: the notifier posts the run outcome as JSON, signs the exact body
+// with the configured secret and retries failed deliveries
+
+use std::time::Duration;
+
+use httpmock::{Method::POST, MockServer};
+use r_data_core_services::workflow::completion_webhook::{
+    sign_payload, CompletionWebhookNotifier, RunCompletionPayload, EVENT_HEADER,
+    RUN_COMPLETED_EVENT, SIGNATURE_HEADER,
+};
+use r_data_core_workflow::data::RunStatus;
+use serde_json::json;
+use uuid::Uuid;
+
+const SECRET: &str = "completion-webhook-test-secret";
+
+fn payload(status: RunStatus) -> RunCompletionPayload {
+    RunCompletionPayload::new(Uuid::now_v7(), Uuid::now_v7(), status, 42, 3, None)
+        .expect("finished run")
+}
+
+#[tokio::test]
+async fn delivery_posts_signed_payload() -> anyhow::Result<()> {
+    let server = MockServer::start_async().await;
+    let payload = payload(RunStatus::Success);
+    let body = serde_json::to_vec(&payload)?;
+    let signature = sign_payload(SECRET, &body);
+    assert!(signature.starts_with("sha256="));
+
+    let hook = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/done")
+                .header("content-type", "application/json")
+                .header(EVENT_HEADER, RUN_COMPLETED_EVENT)
+                .header(SIGNATURE_HEADER, &signature)
+                .json_body_includes(
+                    json!({
+                        "event": "workflow.run.completed",
+                        "run_uuid": payload.run_uuid,
+                        "workflow_uuid": payload.workflow_uuid,
+                        "status": "success",
+                        "processed_items": 42,
+                        "failed_items": 3,
+                        "error": null,
+                    })
+                    .to_string(),
+                )
+                .body(String::from_utf8(body.clone()).expect("JSON is UTF-8"));
+            then.status(204);
+        })
+        .await;
+
+    CompletionWebhookNotifier::new(Some(SECRET.to_string()))
+        .deliver(&server.url("/done"), &payload)
+        .await?;
+
+    hook.assert_async().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn delivery_without_secret_is_unsigned() -> anyhow::Result<()> {
+    let server = MockServer::start_async().await;
+    let hook = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/done")
+                .header_missing(SIGNATURE_HEADER)
+                .json_body_includes(r#"{ "status": "cancelled" }"#);
+            then.status(200);
+        })
+        .await;
+
+    CompletionWebhookNotifier::new(None)
+        .deliver(&server.url("/done"), &payload(RunStatus::Cancelled))
+        .await?;
+
+    hook.assert_async().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_delivery_is_retried_then_reported() {
+    let server = MockServer::start_async().await;
+    let hook = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/down");
+            then.status(503);
+        })
+        .await;
+
+    let result = CompletionWebhookNotifier::new(Some(SECRET.to_string()))
+        .with_retry(3, Duration::from_millis(10))
+        .deliver(&server.url("/down"), &payload(RunStatus::Failed))
+        .await;
+
+    let error = result.expect_err("delivery fails").to_string();
+    assert!(error.contains("after 3 attempt(s)"), "{error}");
+    assert!(error.contains("503"), "{error}");
+    hook.assert_calls_async(3).await;
+}
@@ -74,6 +74,7 @@ async fn test_consecutive_imports_produce_identical_outcomes() -> anyhow::Result
         validation: r_data_core_core::field::FieldValidation::default(),
         ui_settings: r_data_core_core::field::ui::UiSettings::default(),
         constraints: std::collections::HashMap::new(),
+        display_order: 0,
     };
     fields.push(email_field);
 
@@ -90,6 +91,7 @@ async fn test_consecutive_imports_produce_identical_outcomes() -> anyhow::Result
         validation: r_data_core_core::field::FieldValidation::default(),
         ui_settings: r_data_core_core::field::ui::UiSettings::default(),
         constraints: std::collections::HashMap::new(),
+        display_order: 0,
     };
     fields.push(name_field);
 
@@ -134,6 +136,7 @@ async fn test_consecutive_imports_produce_identical_outcomes() -> anyhow::Result
         schedule_cron: None,
         config: workflow_config,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = wf_service
         .create(&req, creator_uuid)
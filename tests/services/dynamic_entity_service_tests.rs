@@ -23,15 +23,22 @@ mockall::mock! {
     impl DynamicEntityRepositoryTrait for DynamicEntityRepositoryTrait {
         async fn get_all_by_type(&self, entity_type: &str, limit: i64, offset: i64, exclusive_fields: Option<Vec<String>>) -> Result<Vec<DynamicEntity>>;
         async fn get_by_type(&self, entity_type: &str, uuid: &Uuid, exclusive_fields: Option<Vec<String>>) -> Result<Option<DynamicEntity>>;
+        async fn get_many_by_type(&self, entity_type: &str, uuids: &[Uuid], exclusive_fields: Option<Vec<String>>) -> Result<HashMap<Uuid, Option<DynamicEntity>>>;
         async fn create(&self, entity: &DynamicEntity) -> Result<Uuid>;
         async fn update(&self, entity: &DynamicEntity) -> Result<()>;
         async fn delete_by_type(&self, entity_type: &str, uuid: &Uuid) -> Result<()>;
+        async fn restore_by_type(&self, entity_type: &str, uuid: &Uuid) -> Result<()>;
+        async fn hard_delete(&self, entity_type: &str, uuid: &Uuid) -> Result<()>;
+        async fn is_deleted(&self, entity_type: &str, uuid: &Uuid) -> Result<bool>;
         async fn filter_entities(
             &self,
             entity_type: &str,
             params: &FilterEntitiesParams,
         ) -> Result<Vec<DynamicEntity>>;
         async fn count_entities(&self, entity_type: &str) -> Result<i64>;
+        async fn count_deleted_entities(&self, entity_type: &str) -> Result<i64>;
+        async fn count_entities_by_owner(&self, entity_type: &str, owner: &Uuid) -> Result<i64>;
+        async fn estimate_entities(&self, entity_type: &str) -> Result<Option<i64>>;
         async fn count_children(&self, parent_uuid: &Uuid) -> Result<i64>;
         async fn get_by_uuid_any_type(&self, uuid: &Uuid) -> Result<Option<DynamicEntity>>;
         async fn find_one_by_filters(&self, entity_type: &str, filters: &std::collections::HashMap<String, serde_json::Value>) -> Result<Option<DynamicEntity>>;
@@ -82,6 +89,7 @@ impl MockEntityDefinitionService {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
 
         let optional_field = FieldDefinition {
@@ -97,6 +105,7 @@ impl MockEntityDefinitionService {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
 
         let string_field = FieldDefinition {
@@ -112,6 +121,7 @@ impl MockEntityDefinitionService {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
 
         let number_field = FieldDefinition {
@@ -127,6 +137,7 @@ impl MockEntityDefinitionService {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
 
         let enum_field = FieldDefinition {
@@ -142,6 +153,7 @@ impl MockEntityDefinitionService {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         };
 
         definition.fields = vec![
@@ -0,0 +1,258 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Cached entity aggregates: creates, updates, deletes and restores through `DynamicEntityService`
+// keep the count and field sums in step, and a rebuild matches a direct computation on the view
+
+use r_data_core_core::entity_aggregate::EntityAggregate;
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::Error;
+use r_data_core_core::field::ui::UiSettings;
+use r_data_core_core::field::{FieldDefinition, FieldType, FieldValidation};
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::dynamic_entity_utils::get_view_name;
+use r_data_core_persistence::{
+    DynamicEntityRepository, EntityAggregateRepository, EntityDefinitionRepository,
+};
+use r_data_core_services::{DynamicEntityService, EntityAggregateService, EntityDefinitionService};
+use r_data_core_test_support::{setup_test_db, unique_entity_type, TestDatabase};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Fixture {
+    db: TestDatabase,
+    definition: EntityDefinition,
+    entities: DynamicEntityService,
+    aggregates: EntityAggregateService,
+}
+
+fn field(name: &str, field_type: FieldType) -> FieldDefinition {
+    FieldDefinition {
+        name: name.to_string(),
+        display_name: name.to_string(),
+        field_type,
+        required: false,
+        description: None,
+        filterable: true,
+        indexed: false,
+        unique: false,
+        default_value: None,
+        validation: FieldValidation::default(),
+        ui_settings: UiSettings::default(),
+        constraints: HashMap::new(),
+        display_order: 0,
+    }
+}
+
+async fn setup() -> anyhow::Result<Fixture> {
+    let db = setup_test_db().await;
+    let definitions = Arc::new(EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    )));
+
+    let entity_type = unique_entity_type("agg");
+    let definition = EntityDefinition {
+        entity_type: entity_type.clone(),
+        display_name: format!("{entity_type} Class"),
+        published: true,
+        created_by: Uuid::now_v7(),
+        fields: vec![
+            field("name", FieldType::String),
+            field("price", FieldType::Float),
+            field("qty", FieldType::Integer),
+        ],
+        ..EntityDefinition::default()
+    };
+    definitions.create_entity_definition(&definition).await?;
+    let definition = definitions
+        .get_entity_definition_by_entity_type(&entity_type)
+        .await?;
+
+    let aggregates =
+        EntityAggregateService::new(Arc::new(EntityAggregateRepository::new(db.pool.clone())));
+    let entities = DynamicEntityService::new(
+        Arc::new(DynamicEntityRepository::new(db.pool.clone())),
+        definitions,
+    )
+    .with_change_hook(Arc::new(aggregates.clone()));
+
+    Ok(Fixture {
+        db,
+        definition,
+        entities,
+        aggregates,
+    })
+}
+
+impl Fixture {
+    async fn create(&self, key: &str, price: Value, qty: Value) -> anyhow::Result<Uuid> {
+        let mut field_data = HashMap::new();
+        field_data.insert("entity_key".to_string(), json!(key));
+        field_data.insert("path".to_string(), json!("/"));
+        field_data.insert("name".to_string(), json!(key));
+        field_data.insert("price".to_string(), price);
+        field_data.insert("qty".to_string(), qty);
+        field_data.insert("created_by".to_string(), json!(Uuid::now_v7().to_string()));
+        let entity = DynamicEntity {
+            entity_type: self.definition.entity_type.clone(),
+            field_data,
+            definition: Arc::new(self.definition.clone()),
+        };
+        Ok(self.entities.create_entity(&entity).await?)
+    }
+
+    async fn update(&self, uuid: Uuid, field: &str, value: Value) -> anyhow::Result<()> {
+        let mut entity = self
+            .entities
+            .get_entity_by_uuid(&self.definition.entity_type, &uuid, None)
+            .await?
+            .expect("entity exists");
+        entity.field_data.insert(field.to_string(), value);
+        self.entities.update_entity(&entity).await?;
+        Ok(())
+    }
+
+    async fn enable(&self) -> anyhow::Result<EntityAggregate> {
+        Ok(self
+            .aggregates
+            .configure(&self.definition, &["price".to_string(), "qty".to_string()])
+            .await?)
+    }
+
+    async fn cached(&self) -> anyhow::Result<EntityAggregate> {
+        Ok(self.aggregates.get(&self.definition.entity_type).await?)
+    }
+
+    /// Count and sums computed directly from the live entities
+    async fn direct(&self) -> anyhow::Result<(i64, f64, f64)> {
+        let view = get_view_name(&self.definition.entity_type)?;
+        Ok(sqlx::query_as(&format!(
+            "SELECT COUNT(*), COALESCE(SUM(price), 0)::float8, COALESCE(SUM(qty), 0)::float8
+             FROM {view} WHERE deleted_at IS NULL"
+        ))
+        .fetch_one(&self.db.pool)
+        .await?)
+    }
+}
+
+fn assert_aggregate(aggregate: &EntityAggregate, count: i64, price: f64, qty: f64) {
+    assert_eq!(aggregate.entity_count, count);
+    assert!(
+        (aggregate.sums["price"] - price).abs() < 1e-9,
+        "price sum {} != {price}",
+        aggregate.sums["price"]
+    );
+    assert!(
+        (aggregate.sums["qty"] - qty).abs() < 1e-9,
+        "qty sum {} != {qty}",
+        aggregate.sums["qty"]
+    );
+}
+
+#[tokio::test]
+async fn configure_computes_aggregate_of_existing_entities() -> anyhow::Result<()> {
+    let fx = setup().await?;
+    fx.create("a", json!(2.5), json!(3)).await?;
+    fx.create("b", json!(1.25), json!(4)).await?;
+
+    let aggregate = fx.enable().await?;
+    assert_eq!(aggregate.sum_fields, vec!["price", "qty"]);
+    assert!(aggregate.rebuilt_at.is_some());
+    assert_aggregate(&aggregate, 2, 3.75, 7.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn writes_keep_aggregate_accurate() -> anyhow::Result<()> {
+    let fx = setup().await?;
+    fx.enable().await?;
+
+    let a = fx.create("a", json!(2.5), json!(3)).await?;
+    let b = fx.create("b", json!(1.25), json!(4)).await?;
+    // Missing values count the entity but add nothing to the sums
+    fx.create("c", Value::Null, json!(10)).await?;
+    assert_aggregate(&fx.cached().await?, 3, 3.75, 17.0);
+
+    fx.update(a, "price", json!(5.0)).await?;
+    assert_aggregate(&fx.cached().await?, 3, 6.25, 17.0);
+
+    // Changing a field that is not summed leaves the sums alone
+    fx.update(a, "name", json!("renamed")).await?;
+    assert_aggregate(&fx.cached().await?, 3, 6.25, 17.0);
+
+    fx.entities
+        .delete_entity(&fx.definition.entity_type, &b)
+        .await?;
+    assert_aggregate(&fx.cached().await?, 2, 5.0, 13.0);
+
+    fx.entities
+        .restore_entity(&fx.definition.entity_type, &b)
+        .await?;
+    assert_aggregate(&fx.cached().await?, 3, 6.25, 17.0);
+
+    fx.entities
+        .hard_delete_entity(&fx.definition.entity_type, &a)
+        .await?;
+    assert_aggregate(&fx.cached().await?, 2, 1.25, 14.0);
+
+    let (count, price, qty) = fx.direct().await?;
+    assert_aggregate(&fx.cached().await?, count, price, qty);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rebuild_matches_direct_computation_after_bypassing_writes() -> anyhow::Result<()> {
+    let fx = setup().await?;
+    fx.enable().await?;
+    fx.create("a", json!(2.5), json!(3)).await?;
+    let b = fx.create("b", json!(1.25), json!(4)).await?;
+
+    // Soft-delete behind the service's back; the cached aggregate goes stale
+    sqlx::query("UPDATE entities_registry SET deleted_at = NOW() WHERE uuid = $1")
+        .bind(b)
+        .execute(&fx.db.pool)
+        .await?;
+    assert_aggregate(&fx.cached().await?, 2, 3.75, 7.0);
+
+    let rebuilt = fx.aggregates.rebuild(&fx.definition).await?;
+    let (count, price, qty) = fx.direct().await?;
+    assert_eq!(count, 1);
+    assert_aggregate(&rebuilt, count, price, qty);
+    assert_aggregate(&fx.cached().await?, count, price, qty);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn aggregates_are_not_maintained_until_enabled() -> anyhow::Result<()> {
+    let fx = setup().await?;
+    fx.create("a", json!(2.5), json!(3)).await?;
+
+    assert!(matches!(fx.cached().await, Err(e) if e.to_string().contains("not enabled")));
+
+    fx.enable().await?;
+    fx.aggregates.disable(&fx.definition.entity_type).await?;
+    fx.create("b", json!(1.0), json!(1)).await?;
+    assert!(fx.cached().await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn configure_rejects_non_numeric_and_unknown_fields() -> anyhow::Result<()> {
+    let fx = setup().await?;
+
+    for fields in [vec!["name".to_string()], vec!["missing".to_string()]] {
+        let result = fx.aggregates.configure(&fx.definition, &fields).await;
+        assert!(
+            matches!(result, Err(Error::Validation(_))),
+            "{fields:?}: {result:?}"
+        );
+    }
+    assert!(fx.cached().await.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Published entity definitions must declare at least one non-system field; drafts may be
+// empty and still get a usable table and view
+
+use std::sync::Arc;
+use uuid::Uuid;
+
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::field::{FieldDefinition, FieldType};
+use r_data_core_persistence::EntityDefinitionRepository;
+use r_data_core_services::EntityDefinitionService;
+use r_data_core_test_support::{setup_test_db, unique_entity_type};
+
+fn definition(entity_type: &str, published: bool, field_names: &[&str]) -> EntityDefinition {
+    EntityDefinition {
+        entity_type: entity_type.to_string(),
+        display_name: entity_type.to_string(),
+        published,
+        created_by: Uuid::now_v7(),
+        fields: field_names
+            .iter()
+            .map(|name| {
+                FieldDefinition::new((*name).to_string(), (*name).to_string(), FieldType::String)
+            })
+            .collect(),
+        ..EntityDefinition::default()
+    }
+}
+
+fn assert_no_user_fields_error(result: Result<Uuid>) {
+    match result {
+        Err(Error::Validation(msg)) => {
+            assert!(msg.contains("at least one non-system field"), "got: {msg}");
+        }
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn published_definition_without_fields_is_rejected() {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+
+    let entity_type = unique_entity_type("empty");
+    assert_no_user_fields_error(
+        service
+            .create_entity_definition(&definition(&entity_type, true, &[]))
+            .await,
+    );
+
+    let count: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM entity_definitions WHERE entity_type = $1")
+            .bind(&entity_type)
+            .fetch_one(&db.pool)
+            .await
+            .expect("count definitions");
+    assert_eq!(count, 0, "rejected definition must not be stored");
+}
+
+#[tokio::test]
+async fn fields_shadowing_system_columns_are_rejected() {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+
+    let entity_type = unique_entity_type("system_only");
+    match service
+        .create_entity_definition(&definition(&entity_type, false, &["Path"]))
+        .await
+    {
+        Err(Error::Validation(msg)) => {
+            assert!(msg.contains("reserved for a system field"), "got: {msg}");
+        }
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn minimal_one_field_definition_is_accepted() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+
+    let entity_type = unique_entity_type("minimal");
+    let uuid = service
+        .create_entity_definition(&definition(&entity_type, true, &["title"]))
+        .await?;
+    let stored = service.get_entity_definition(&uuid).await?;
+    assert!(stored.published);
+    assert_eq!(stored.fields.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fieldless_draft_is_kept_but_cannot_be_published() -> Result<()> {
+    let db = setup_test_db().await;
+    let service = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+
+    // A field-less draft still gets a table and view with only the system columns
+    let entity_type = unique_entity_type("draft");
+    let uuid = service
+        .create_entity_definition(&definition(&entity_type, false, &[]))
+        .await?;
+    let (applied, failed) = service.apply_schema(Some(&uuid)).await?;
+    assert_eq!((applied, failed.len()), (1, 0), "failures: {failed:?}");
+    let view = format!("entity_{}_view", entity_type.to_lowercase());
+    let view_columns: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM information_schema.columns WHERE table_schema = current_schema() AND table_name = $1",
+    )
+    .bind(&view)
+    .fetch_one(&db.pool)
+    .await
+    .map_err(Error::Database)?;
+    assert!(view_columns > 0, "{view} should exist");
+
+    let mut publish = service.get_entity_definition(&uuid).await?;
+    publish.published = true;
+    match service.update_entity_definition(&uuid, &publish).await {
+        Err(Error::Validation(msg)) => {
+            assert!(msg.contains("at least one non-system field"), "got: {msg}");
+        }
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+
+    publish.fields.push(FieldDefinition::new(
+        "title".to_string(),
+        "Title".to_string(),
+        FieldType::String,
+    ));
+    service.update_entity_definition(&uuid, &publish).await?;
+    assert!(service.get_entity_definition(&uuid).await?.published);
+
+    Ok(())
+}
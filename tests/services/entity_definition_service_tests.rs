@@ -27,6 +27,7 @@ mockall::mock! {
         async fn get_by_entity_type(&self, entity_type: &str) -> Result<Option<EntityDefinition>>;
         async fn create(&self, definition: &EntityDefinition) -> Result<Uuid>;
         async fn update(&self, uuid: &Uuid, definition: &EntityDefinition) -> Result<()>;
+        async fn rename_field(&self, uuid: &Uuid, definition: &EntityDefinition, old_name: &str, new_name: &str, rename_in_versions: bool) -> Result<()>;
         async fn delete(&self, uuid: &Uuid) -> Result<()>;
         async fn apply_schema(&self, schema_sql: &str) -> Result<()>;
         async fn update_entity_view_for_entity_definition(&self, entity_definition: &EntityDefinition) -> Result<()>;
@@ -34,6 +35,8 @@ mockall::mock! {
         async fn get_view_columns_with_types(&self, view_name: &str) -> Result<HashMap<String, String>>;
         async fn count_view_records(&self, view_name: &str) -> Result<i64>;
         async fn cleanup_unused_entity_view(&self) -> Result<()>;
+        async fn find_table_owner(&self, table_name: &str, exclude: Option<Uuid>) -> Result<Option<String>>;
+        async fn is_foreign_table(&self, table_name: &str) -> Result<bool>;
     }
 }
 
@@ -61,6 +64,7 @@ fn create_test_entity_definition() -> EntityDefinition {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: r_data_core_core::field::ui::UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
             FieldDefinition {
                 name: "age".to_string(),
@@ -75,8 +79,13 @@ fn create_test_entity_definition() -> EntityDefinition {
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: r_data_core_core::field::ui::UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
         ],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: Schema::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
@@ -110,6 +119,7 @@ fn create_entity_definition_with_duplicate_fields() -> EntityDefinition {
         validation: r_data_core_core::field::FieldValidation::default(),
         ui_settings: r_data_core_core::field::ui::UiSettings::default(),
         constraints: HashMap::new(),
+        display_order: 0,
     });
     def
 }
@@ -192,6 +202,10 @@ async fn test_get_entity_definition_by_uuid_not_found() -> Result<()> {
 async fn test_create_entity_definition_success() -> Result<()> {
     // Arrange
     let mut mock_repo = MockEntityDefRepository::new();
+    mock_repo
+        .expect_find_table_owner()
+        .returning(|_, _| Ok(None));
+    mock_repo.expect_is_foreign_table().returning(|_| Ok(false));
     let definition = create_test_entity_definition();
     let expected_uuid = definition.uuid;
 
@@ -323,6 +337,9 @@ async fn test_create_entity_definition_duplicate_field_names() -> Result<()> {
 async fn test_update_entity_definition_success() -> Result<()> {
     // Arrange
     let mut mock_repo = MockEntityDefRepository::new();
+    mock_repo
+        .expect_find_table_owner()
+        .returning(|_, _| Ok(None));
     let definition = create_test_entity_definition();
     let test_uuid = definition.uuid;
     let definition_clone = definition.clone();
@@ -421,6 +438,9 @@ async fn test_delete_entity_definition_success() -> Result<()> {
     // No records exist for this class
     mock_repo.expect_count_view_records().return_once(|_| Ok(0));
 
+    // No definitions extend this one
+    mock_repo.expect_list().return_once(|_, _| Ok(vec![]));
+
     // Delete should succeed
     mock_repo.expect_delete().return_once(|_| Ok(()));
 
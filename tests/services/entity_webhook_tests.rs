@@ -0,0 +1,165 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Field-level entity webhooks: updating a subscribed field enqueues the diff in the outbox and the
+// dispatcher posts it to the subscription URL; changes to other fields enqueue nothing
+
+use httpmock::{Method::POST, MockServer};
+use r_data_core_core::outbox::ENTITY_WEBHOOK_TOPIC;
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::{
+    DynamicEntityRepository, EntityDefinitionRepository, EntityWebhookRepository, OutboxRepository,
+};
+use r_data_core_services::workflow::outbox::WorkflowOutboxDispatcher;
+use r_data_core_services::{DynamicEntityService, EntityDefinitionService, EntityWebhookService};
+use r_data_core_test_support::{
+    create_test_entity, create_test_entity_definition, setup_test_db, unique_entity_type,
+    TestDatabase,
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Fixture {
+    db: TestDatabase,
+    entity_type: String,
+    entity_uuid: Uuid,
+    entities: DynamicEntityService,
+    webhooks: EntityWebhookService,
+}
+
+async fn setup() -> anyhow::Result<Fixture> {
+    let db = setup_test_db().await;
+    let entity_type = unique_entity_type("hook");
+    create_test_entity_definition(&db, &entity_type).await?;
+    let entity_uuid = create_test_entity(&db, &entity_type, "Alice", "alice@example.com").await?;
+
+    let webhooks = EntityWebhookService::new(
+        Arc::new(EntityWebhookRepository::new(db.pool.clone())),
+        Arc::new(OutboxRepository::new(db.pool.clone())),
+    );
+    let entities = DynamicEntityService::new(
+        Arc::new(DynamicEntityRepository::new(db.pool.clone())),
+        Arc::new(EntityDefinitionService::new_without_cache(Arc::new(
+            EntityDefinitionRepository::new(db.pool.clone()),
+        ))),
+    )
+    .with_change_hook(Arc::new(webhooks.clone()));
+
+    Ok(Fixture {
+        db,
+        entity_type,
+        entity_uuid,
+        entities,
+        webhooks,
+    })
+}
+
+impl Fixture {
+    async fn update(&self, field: &str, value: Value) -> anyhow::Result<()> {
+        let mut entity: DynamicEntity = self
+            .entities
+            .get_entity_by_uuid(&self.entity_type, &self.entity_uuid, None)
+            .await?
+            .expect("entity exists");
+        entity.field_data.insert(field.to_string(), value);
+        self.entities.update_entity(&entity).await?;
+        Ok(())
+    }
+
+    async fn webhook_payloads(&self) -> anyhow::Result<Vec<Value>> {
+        Ok(sqlx::query_scalar(
+            "SELECT payload FROM outbox_messages WHERE topic = $1 ORDER BY created_at",
+        )
+        .bind(ENTITY_WEBHOOK_TOPIC)
+        .fetch_all(&self.db.pool)
+        .await?)
+    }
+}
+
+#[tokio::test]
+async fn change_to_subscribed_field_fires_webhook() -> anyhow::Result<()> {
+    let fx = setup().await?;
+    let subscription = fx
+        .webhooks
+        .create_subscription(
+            &fx.entity_type,
+            "https://hooks.example.com/email",
+            &["email".to_string()],
+            None,
+        )
+        .await?;
+
+    fx.update("email", json!("alice@example.org")).await?;
+
+    let payloads = fx.webhook_payloads().await?;
+    assert_eq!(payloads.len(), 1);
+    let payload = &payloads[0];
+    assert_eq!(payload["subscription_uuid"], json!(subscription));
+    assert_eq!(payload["entity_uuid"], json!(fx.entity_uuid));
+    assert_eq!(
+        payload["changes"],
+        json!({ "email": { "old": "alice@example.com", "new": "alice@example.org" } })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn change_to_unsubscribed_field_does_not_fire_webhook() -> anyhow::Result<()> {
+    let fx = setup().await?;
+    fx.webhooks
+        .create_subscription(
+            &fx.entity_type,
+            "https://hooks.example.com/email",
+            &["email".to_string()],
+            None,
+        )
+        .await?;
+
+    fx.update("name", json!("Alice Smith")).await?;
+
+    assert!(fx.webhook_payloads().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn webhook_delivery_posts_diff_to_subscription_url() -> anyhow::Result<()> {
+    let fx = setup().await?;
+    let server = MockServer::start_async().await;
+    let hook_mock = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/hook").json_body_includes(
+                r#"{ "changes": { "name": { "old": "Alice", "new": "Bob" } } }"#,
+            );
+            then.status(200);
+        })
+        .await;
+    fx.webhooks
+        .create_subscription(
+            &fx.entity_type,
+            &server.url("/hook"),
+            &["name".to_string()],
+            None,
+        )
+        .await?;
+
+    fx.update("name", json!("Bob")).await?;
+
+    let outbox_repo = OutboxRepository::new(fx.db.pool.clone());
+    let claimed = outbox_repo.claim_due(10, "webhook-test-worker").await?;
+    assert_eq!(claimed.len(), 1);
+    WorkflowOutboxDispatcher::new(None, &outbox_repo, None, Some("webhook-test-worker"), None)
+        .dispatch_record(&claimed[0].clone().into_message())
+        .await?;
+
+    hook_mock.assert_async().await;
+    let status: String =
+        sqlx::query_scalar("SELECT status::text FROM outbox_messages WHERE uuid = $1")
+            .bind(claimed[0].uuid)
+            .fetch_one(&fx.db.pool)
+            .await?;
+    assert_eq!(status, "delivered");
+
+    Ok(())
+}
@@ -3,12 +3,20 @@
 pub mod adapter_tests;
 pub mod api_key_service_tests;
 pub mod authentication_service_tests;
+pub mod completion_webhook_tests;
 pub mod consecutive_import_tests;
 pub mod dashboard_stats_service_tests;
 pub mod dynamic_entity_service_tests;
+pub mod entity_aggregate_tests;
+pub mod entity_definition_fields_tests;
 pub mod entity_definition_service_tests;
+pub mod entity_webhook_tests;
+pub mod notification_email_tests;
+pub mod notification_service_tests;
 pub mod query_validation_tests;
+pub mod schema_reconcile_tests;
 pub mod settings_service_tests;
+pub mod version_service_tests;
 pub mod worker_processing_tests;
 pub mod workflow_entity_persistence_tests;
 pub mod workflow_transform_execution_tests;
@@ -0,0 +1,140 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// E-mail notifications: the sender hands title and message to the transport, addressed to the
+// user's e-mail, and records sent or failed deliveries; failed ones are retried
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use r_data_core_core::error::{Error, Result};
+use r_data_core_core::notification::{
+    NewNotification, NotificationManager, NotificationStatus, NotificationType,
+};
+use r_data_core_persistence::NotificationRepository;
+use r_data_core_services::notification::email::EmailMessage;
+use r_data_core_services::{EmailNotificationSender, EmailTransport, PgNotificationService};
+use r_data_core_test_support::{create_test_admin_user, setup_test_db, TestDatabase};
+use uuid::Uuid;
+
+/// Keeps sent e-mails in memory, or fails every send while `fail` is set
+#[derive(Default)]
+struct CapturingTransport {
+    sent: Mutex<Vec<EmailMessage>>,
+    fail: Mutex<bool>,
+}
+
+impl CapturingTransport {
+    fn sent(&self) -> Vec<EmailMessage> {
+        self.sent.lock().expect("lock").clone()
+    }
+
+    fn set_failing(&self, fail: bool) {
+        *self.fail.lock().expect("lock") = fail;
+    }
+}
+
+#[async_trait]
+impl EmailTransport for CapturingTransport {
+    async fn send(&self, message: &EmailMessage) -> Result<()> {
+        if *self.fail.lock().expect("lock") {
+            return Err(Error::Config("SMTP server unavailable".to_string()));
+        }
+        self.sent.lock().expect("lock").push(message.clone());
+        Ok(())
+    }
+}
+
+async fn setup() -> anyhow::Result<(
+    TestDatabase,
+    PgNotificationService,
+    EmailNotificationSender,
+    Arc<CapturingTransport>,
+    Uuid,
+)> {
+    let db = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&db).await?;
+    let repository = Arc::new(NotificationRepository::new(db.pool.clone()));
+    let transport = Arc::new(CapturingTransport::default());
+    let sender = EmailNotificationSender::new(repository.clone(), transport.clone());
+    let service = PgNotificationService::new(repository).with_email_sender(sender.clone());
+    Ok((db, service, sender, transport, user_uuid))
+}
+
+fn email(user_uuid: Uuid) -> NewNotification {
+    NewNotification {
+        user_uuid,
+        notification_type: NotificationType::Email,
+        title: "Import finished".to_string(),
+        message: "42 entities were imported".to_string(),
+        data: None,
+    }
+}
+
+#[tokio::test]
+async fn email_notification_is_sent_to_the_user() -> anyhow::Result<()> {
+    let (db, service, _sender, transport, user_uuid) = setup().await?;
+    let address: String = sqlx::query_scalar("SELECT email FROM admin_users WHERE uuid = $1")
+        .bind(user_uuid)
+        .fetch_one(&db.pool)
+        .await?;
+
+    let sent = service.send_notification(email(user_uuid)).await?;
+    assert_eq!(sent.status, NotificationStatus::Sent);
+    assert!(sent.sent_at.is_some());
+    assert!(sent.error_message.is_none());
+    assert_eq!(sent.retry_count, 0);
+
+    assert_eq!(
+        transport.sent(),
+        vec![EmailMessage {
+            to: address,
+            subject: "Import finished".to_string(),
+            body: "42 entities were imported".to_string(),
+        }]
+    );
+    // E-mail notifications are not listed in-app
+    assert!(service
+        .get_user_notifications(user_uuid, true)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_email_is_recorded_and_retried() -> anyhow::Result<()> {
+    let (_db, service, sender, transport, user_uuid) = setup().await?;
+
+    transport.set_failing(true);
+    let failed = service.send_notification(email(user_uuid)).await?;
+    assert_eq!(failed.status, NotificationStatus::Failed);
+    assert!(failed.sent_at.is_none());
+    assert_eq!(failed.retry_count, 1);
+    let error = failed.error_message.expect("error recorded");
+    assert!(error.contains("SMTP server unavailable"), "{error}");
+    assert!(transport.sent().is_empty());
+
+    transport.set_failing(false);
+    assert_eq!(sender.send_outstanding(10).await?, 1);
+    assert_eq!(transport.sent().len(), 1);
+    // Nothing is left to send
+    assert_eq!(sender.send_outstanding(10).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn email_is_given_up_after_max_retries() -> anyhow::Result<()> {
+    let (_db, service, sender, transport, user_uuid) = setup().await?;
+    let sender = sender.with_max_retries(2);
+
+    transport.set_failing(true);
+    service.send_notification(email(user_uuid)).await?;
+    assert_eq!(sender.send_outstanding(10).await?, 0);
+
+    transport.set_failing(false);
+    assert_eq!(sender.send_outstanding(10).await?, 0);
+    assert!(transport.sent().is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// In-app notifications: sent notifications are listed for their user until read, scheduled ones
+// show up once due and can be cancelled before that
+
+use std::sync::Arc;
+
+use r_data_core_core::error::Error;
+use r_data_core_core::notification::{
+    NewNotification, NotificationManager, NotificationStatus, NotificationType,
+};
+use r_data_core_persistence::NotificationRepository;
+use r_data_core_services::PgNotificationService;
+use r_data_core_test_support::{create_test_admin_user, setup_test_db, TestDatabase};
+use serde_json::json;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+async fn setup() -> anyhow::Result<(TestDatabase, PgNotificationService, Uuid)> {
+    let db = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&db).await?;
+    let service =
+        PgNotificationService::new(Arc::new(NotificationRepository::new(db.pool.clone())));
+    Ok((db, service, user_uuid))
+}
+
+fn notification(user_uuid: Uuid, title: &str) -> NewNotification {
+    NewNotification {
+        user_uuid,
+        notification_type: NotificationType::InApp,
+        title: title.to_string(),
+        message: format!("{title} message"),
+        data: Some(json!({ "source": "test" })),
+    }
+}
+
+#[tokio::test]
+async fn sent_notification_is_listed_until_read() -> anyhow::Result<()> {
+    let (_db, service, user_uuid) = setup().await?;
+
+    let sent = service
+        .send_notification(notification(user_uuid, "Import finished"))
+        .await?;
+    assert_eq!(sent.status, NotificationStatus::Sent);
+    assert!(sent.sent_at.is_some());
+    assert!(!sent.is_read());
+
+    let unread = service.get_user_notifications(user_uuid, false).await?;
+    assert_eq!(unread.len(), 1);
+    assert_eq!(unread[0].uuid, sent.uuid);
+    assert_eq!(unread[0].data, Some(json!({ "source": "test" })));
+
+    service.mark_as_read(user_uuid, sent.uuid).await?;
+    // Marking twice is fine and keeps the notification read
+    service.mark_as_read(user_uuid, sent.uuid).await?;
+
+    let all = service.get_user_notifications(user_uuid, true).await?;
+    assert_eq!(all.len(), 1);
+    assert!(all[0].is_read());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn include_read_false_hides_read_notifications() -> anyhow::Result<()> {
+    let (_db, service, user_uuid) = setup().await?;
+
+    let read = service
+        .send_notification(notification(user_uuid, "Old news"))
+        .await?;
+    let unread = service
+        .send_notification(notification(user_uuid, "Fresh news"))
+        .await?;
+    service.mark_as_read(user_uuid, read.uuid).await?;
+
+    let listed: Vec<Uuid> = service
+        .get_user_notifications(user_uuid, false)
+        .await?
+        .into_iter()
+        .map(|n| n.uuid)
+        .collect();
+    assert_eq!(listed, vec![unread.uuid]);
+
+    let listed = service.get_user_notifications(user_uuid, true).await?;
+    assert_eq!(listed.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn notifications_are_scoped_to_their_user() -> anyhow::Result<()> {
+    let (db, service, user_uuid) = setup().await?;
+    let other_user = create_test_admin_user(&db).await?;
+
+    let sent = service
+        .send_notification(notification(user_uuid, "Private"))
+        .await?;
+
+    assert!(service
+        .get_user_notifications(other_user, true)
+        .await?
+        .is_empty());
+    let result = service.mark_as_read(other_user, sent.uuid).await;
+    assert!(matches!(result, Err(Error::NotFound(_))), "{result:?}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn scheduled_notifications_appear_when_due_and_can_be_cancelled() -> anyhow::Result<()> {
+    let (_db, service, user_uuid) = setup().await?;
+    let now = OffsetDateTime::now_utc();
+
+    let due = service
+        .schedule_notification(notification(user_uuid, "Due"), now - Duration::minutes(1))
+        .await?;
+    let later = service
+        .schedule_notification(notification(user_uuid, "Later"), now + Duration::hours(1))
+        .await?;
+    assert_eq!(later.status, NotificationStatus::Scheduled);
+
+    let listed = service.get_user_notifications(user_uuid, false).await?;
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].uuid, due.uuid);
+    assert_eq!(listed[0].status, NotificationStatus::Sent);
+
+    service.cancel_notification(later.uuid).await?;
+    // Sent and cancelled notifications cannot be cancelled (again)
+    let result = service.cancel_notification(later.uuid).await;
+    assert!(matches!(result, Err(Error::NotFound(_))), "{result:?}");
+    let result = service.cancel_notification(due.uuid).await;
+    assert!(matches!(result, Err(Error::NotFound(_))), "{result:?}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn empty_title_is_rejected() -> anyhow::Result<()> {
+    let (_db, service, user_uuid) = setup().await?;
+
+    let result = service
+        .send_notification(notification(user_uuid, "  "))
+        .await;
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+
+    Ok(())
+}
@@ -204,6 +204,50 @@ mod tests {
         }
     }
 
+    /// Every field of a multi-field sort is validated against the table
+    #[tokio::test]
+    #[serial]
+    async fn test_validate_list_query_multi_field_sort() {
+        let pool = setup_test_db().await;
+        let validator = FieldValidator::new(Arc::new(pool.pool.clone()));
+        let params = |sort_by: &str| ListQueryParams {
+            page: Some(1),
+            per_page: Some(20),
+            limit: None,
+            offset: None,
+            sort_by: Some(sort_by.to_string()),
+            sort_order: None,
+        };
+
+        let result = validate_list_query(
+            &params("is_active:desc,roles,username:asc"),
+            "admin_users",
+            &validator,
+            20,
+            100,
+            true,
+            &["roles"],
+        )
+        .await;
+        assert!(result.is_ok(), "{result:?}");
+
+        let err = validate_list_query(
+            &params("username:asc,no_such_column:desc"),
+            "admin_users",
+            &validator,
+            20,
+            100,
+            true,
+            &[],
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            err.contains("Invalid sort field: 'no_such_column'"),
+            "{err}"
+        );
+    }
+
     /// Allow virtual sort fields when explicitly whitelisted (e.g., roles)
     #[tokio::test]
     #[serial]
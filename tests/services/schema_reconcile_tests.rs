@@ -0,0 +1,91 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+// Entity tables drifting from their definitions are reported, and fixed on request
+
+use std::sync::Arc;
+use uuid::Uuid;
+
+use r_data_core_core::entity_definition::definition::EntityDefinition;
+use r_data_core_core::error::Result;
+use r_data_core_core::field::{FieldDefinition, FieldType};
+use r_data_core_persistence::EntityDefinitionRepository;
+use r_data_core_services::{
+    EntityDefinitionService, SchemaReconcileAction, SchemaReconcileService,
+};
+use r_data_core_test_support::{setup_test_db, unique_entity_type};
+
+fn definition(entity_type: &str, field_names: &[&str]) -> EntityDefinition {
+    EntityDefinition {
+        entity_type: entity_type.to_string(),
+        display_name: entity_type.to_string(),
+        published: true,
+        created_by: Uuid::now_v7(),
+        fields: field_names
+            .iter()
+            .map(|name| {
+                FieldDefinition::new((*name).to_string(), (*name).to_string(), FieldType::String)
+            })
+            .collect(),
+        ..EntityDefinition::default()
+    }
+}
+
+#[tokio::test]
+async fn drifted_tables_are_reported_and_fixed() -> Result<()> {
+    let db = setup_test_db().await;
+    let definitions = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(db.pool.clone()),
+    ));
+
+    let drifted = unique_entity_type("drifted");
+    let tableless = unique_entity_type("tableless");
+    let orphan_table = format!("entity_{}", unique_entity_type("orphan"));
+    definitions
+        .create_entity_definition(&definition(&drifted, &["name", "email"]))
+        .await?;
+    definitions
+        .create_entity_definition(&definition(&tableless, &["title"]))
+        .await?;
+
+    for sql in [
+        format!("ALTER TABLE entity_{drifted} DROP COLUMN email CASCADE"),
+        format!("ALTER TABLE entity_{drifted} ADD COLUMN legacy TEXT"),
+        format!("DROP TABLE entity_{tableless} CASCADE"),
+        format!(
+            "CREATE TABLE {orphan_table} (uuid UUID PRIMARY KEY REFERENCES entities_registry(uuid))"
+        ),
+    ] {
+        sqlx::query(&sql).execute(&db.pool).await?;
+    }
+
+    let service = SchemaReconcileService::new(db.pool.clone());
+    let expected = [
+        SchemaReconcileAction::DropOrphanTable {
+            table_name: orphan_table.clone(),
+        },
+        SchemaReconcileAction::CreateTable {
+            entity_type: tableless.clone(),
+            table_name: format!("entity_{}", tableless.to_lowercase()),
+        },
+        SchemaReconcileAction::SyncColumns {
+            entity_type: drifted.clone(),
+            table_name: format!("entity_{}", drifted.to_lowercase()),
+            missing_columns: vec!["email".to_string()],
+            extra_columns: vec!["legacy".to_string()],
+        },
+    ];
+    let report = service.report().await?;
+    for action in &expected {
+        assert!(
+            report.contains(action),
+            "{action:?} missing from {report:?}"
+        );
+    }
+
+    service.fix(&expected).await?;
+    let report = service.report().await?;
+    for action in &expected {
+        assert!(!report.contains(action), "{action:?} still reported");
+    }
+    Ok(())
+}
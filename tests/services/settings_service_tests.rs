@@ -3,7 +3,7 @@
 use std::sync::Arc;
 
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_core::settings::{OutboxSettings, SystemSettingKey};
 use r_data_core_persistence::SystemSettingsRepository;
 use r_data_core_services::SettingsService;
@@ -24,6 +24,10 @@ fn create_cache_manager() -> Arc<CacheManager> {
         max_size: 10_000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     }))
 }
 
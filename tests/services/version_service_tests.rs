@@ -0,0 +1,344 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use r_data_core_core::DynamicEntity;
+use r_data_core_persistence::{DynamicEntityRepository, EntityDefinitionRepository};
+use r_data_core_services::{
+    DynamicEntityService, EntityChangeHook, EntityDefinitionService, VersionService,
+};
+use uuid::Uuid;
+
+use r_data_core_test_support::{
+    create_test_admin_user, create_test_entity, create_test_entity_definition, setup_test_db,
+    unique_entity_type,
+};
+
+async fn rename_entity(
+    pool: &sqlx::PgPool,
+    def_svc: &EntityDefinitionService,
+    entity_type: &str,
+    entity_uuid: Uuid,
+    name: &str,
+    user_uuid: Uuid,
+) {
+    let def = def_svc
+        .get_entity_definition_by_entity_type(entity_type)
+        .await
+        .unwrap();
+    let field_data = serde_json::json!({
+        "uuid": entity_uuid.to_string(),
+        "name": name,
+        "email": "alice@example.com",
+        "updated_by": user_uuid.to_string()
+    })
+    .as_object()
+    .unwrap()
+    .clone()
+    .into_iter()
+    .collect();
+    let entity = DynamicEntity {
+        entity_type: entity_type.to_string(),
+        field_data,
+        definition: Arc::new(def),
+    };
+    DynamicEntityRepository::new(pool.clone())
+        .update(&entity)
+        .await
+        .unwrap();
+}
+
+async fn current_name(pool: &sqlx::PgPool, entity_type: &str, entity_uuid: Uuid) -> String {
+    sqlx::query_scalar(&format!(
+        "SELECT name FROM entity_{}_view WHERE uuid = $1",
+        entity_type.to_lowercase()
+    ))
+    .bind(entity_uuid)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_restore_reverts_to_previous_version() {
+    let pool = setup_test_db().await;
+    let entity_type = unique_entity_type("restore_entity");
+    create_test_entity_definition(&pool, &entity_type)
+        .await
+        .unwrap();
+    let entity_uuid = create_test_entity(&pool, &entity_type, "Alice", "alice@example.com")
+        .await
+        .unwrap();
+    let user_uuid = create_test_admin_user(&pool).await.unwrap();
+    let def_svc = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(pool.pool.clone()),
+    ));
+
+    rename_entity(
+        &pool.pool,
+        &def_svc,
+        &entity_type,
+        entity_uuid,
+        "Bob",
+        user_uuid,
+    )
+    .await;
+    assert_eq!(
+        current_name(&pool.pool, &entity_type, entity_uuid).await,
+        "Bob"
+    );
+
+    let service = VersionService::new(pool.pool.clone());
+    service.restore(entity_uuid, 1, user_uuid).await.unwrap();
+
+    assert_eq!(
+        current_name(&pool.pool, &entity_type, entity_uuid).await,
+        "Alice"
+    );
+
+    let versions = service
+        .list_entity_versions_with_metadata(entity_uuid)
+        .await
+        .unwrap();
+    let latest = versions.first().unwrap();
+    assert_eq!(latest.version_number, 3);
+    assert_eq!(latest.comment.as_deref(), Some("Reverted to version 1"));
+    assert_eq!(latest.created_by, Some(user_uuid));
+
+    // The state before the restore is kept as its own version
+    let pre_restore = service
+        .list_entity_versions_with_metadata(entity_uuid)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|v| v.version_number == 2);
+    assert!(pre_restore.is_some());
+}
+
+#[tokio::test]
+async fn test_restore_drops_fields_removed_from_definition() {
+    let pool = setup_test_db().await;
+    let entity_type = unique_entity_type("restore_removed");
+    let def_uuid = create_test_entity_definition(&pool, &entity_type)
+        .await
+        .unwrap();
+    let entity_uuid = create_test_entity(&pool, &entity_type, "Alice", "alice@example.com")
+        .await
+        .unwrap();
+    let user_uuid = create_test_admin_user(&pool).await.unwrap();
+    let def_svc = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(pool.pool.clone()),
+    ));
+
+    rename_entity(
+        &pool.pool,
+        &def_svc,
+        &entity_type,
+        entity_uuid,
+        "Bob",
+        user_uuid,
+    )
+    .await;
+
+    // Remove the email field from the definition after version 1 was recorded
+    let mut def = def_svc
+        .get_entity_definition_by_entity_type(&entity_type)
+        .await
+        .unwrap();
+    def.fields.retain(|f| f.name != "email");
+    def_svc
+        .update_entity_definition(&def_uuid, &def)
+        .await
+        .unwrap();
+
+    let service = VersionService::new(pool.pool.clone());
+    let snapshot = r_data_core_persistence::VersionRepository::new(pool.pool.clone())
+        .get_entity_version(entity_uuid, 1)
+        .await
+        .unwrap()
+        .unwrap();
+    let (restored, dropped) =
+        r_data_core_services::version::restorable_field_data(&def, &snapshot.data);
+    assert!(restored.contains_key("name"));
+    assert!(!restored.contains_key("email"));
+    assert_eq!(dropped, vec!["email".to_string()]);
+
+    service.restore(entity_uuid, 1, user_uuid).await.unwrap();
+
+    assert_eq!(
+        current_name(&pool.pool, &entity_type, entity_uuid).await,
+        "Alice"
+    );
+    let versions = service
+        .list_entity_versions_with_metadata(entity_uuid)
+        .await
+        .unwrap();
+    assert_eq!(
+        versions.first().unwrap().comment.as_deref(),
+        Some("Reverted to version 1")
+    );
+}
+
+#[tokio::test]
+async fn test_restore_rejects_current_and_missing_versions() {
+    let pool = setup_test_db().await;
+    let entity_type = unique_entity_type("restore_invalid");
+    create_test_entity_definition(&pool, &entity_type)
+        .await
+        .unwrap();
+    let entity_uuid = create_test_entity(&pool, &entity_type, "Alice", "alice@example.com")
+        .await
+        .unwrap();
+    let user_uuid = create_test_admin_user(&pool).await.unwrap();
+    let service = VersionService::new(pool.pool.clone());
+
+    let current = service.restore(entity_uuid, 1, user_uuid).await;
+    assert!(matches!(
+        current,
+        Err(r_data_core_core::error::Error::Validation(_))
+    ));
+
+    let missing = service.restore(entity_uuid, 42, user_uuid).await;
+    assert!(matches!(
+        missing,
+        Err(r_data_core_core::error::Error::NotFound(_))
+    ));
+
+    let unknown_entity = service.restore(Uuid::now_v7(), 1, user_uuid).await;
+    assert!(matches!(
+        unknown_entity,
+        Err(r_data_core_core::error::Error::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_restore_rejects_data_invalid_under_current_definition() {
+    let pool = setup_test_db().await;
+    let entity_type = unique_entity_type("restore_validate");
+    let def_uuid = create_test_entity_definition(&pool, &entity_type)
+        .await
+        .unwrap();
+    let entity_uuid = create_test_entity(&pool, &entity_type, "Alice", "alice@example.com")
+        .await
+        .unwrap();
+    let user_uuid = create_test_admin_user(&pool).await.unwrap();
+    let def_svc = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(pool.pool.clone()),
+    ));
+
+    rename_entity(
+        &pool.pool,
+        &def_svc,
+        &entity_type,
+        entity_uuid,
+        "Bob",
+        user_uuid,
+    )
+    .await;
+
+    // Version 1 ("Alice") no longer fits the definition
+    let mut def = def_svc
+        .get_entity_definition_by_entity_type(&entity_type)
+        .await
+        .unwrap();
+    for field in &mut def.fields {
+        if field.name == "name" {
+            field.validation.max_length = Some(3);
+        }
+    }
+    def_svc
+        .update_entity_definition(&def_uuid, &def)
+        .await
+        .unwrap();
+
+    let service = VersionService::new(pool.pool.clone());
+    let result = service.restore(entity_uuid, 1, user_uuid).await;
+    assert!(
+        matches!(result, Err(r_data_core_core::error::Error::Validation(_))),
+        "{result:?}"
+    );
+
+    // Nothing was written
+    assert_eq!(
+        current_name(&pool.pool, &entity_type, entity_uuid).await,
+        "Bob"
+    );
+    let versions = service
+        .list_entity_versions_with_metadata(entity_uuid)
+        .await
+        .unwrap();
+    assert_eq!(versions.first().unwrap().version_number, 2);
+}
+
+/// Change hook recording the names an entity was updated from and to
+#[derive(Default)]
+struct RecordingHook {
+    updates: Mutex<Vec<(String, String)>>,
+}
+
+#[async_trait]
+impl EntityChangeHook for RecordingHook {
+    async fn entity_updated(
+        &self,
+        before: &DynamicEntity,
+        after: &DynamicEntity,
+    ) -> r_data_core_core::error::Result<()> {
+        let name = |entity: &DynamicEntity| {
+            entity
+                .field_data
+                .get("name")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        self.updates
+            .lock()
+            .unwrap()
+            .push((name(before), name(after)));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_restore_notifies_change_hooks() {
+    let pool = setup_test_db().await;
+    let entity_type = unique_entity_type("restore_hooks");
+    create_test_entity_definition(&pool, &entity_type)
+        .await
+        .unwrap();
+    let entity_uuid = create_test_entity(&pool, &entity_type, "Alice", "alice@example.com")
+        .await
+        .unwrap();
+    let user_uuid = create_test_admin_user(&pool).await.unwrap();
+    let def_svc = EntityDefinitionService::new_without_cache(Arc::new(
+        EntityDefinitionRepository::new(pool.pool.clone()),
+    ));
+
+    rename_entity(
+        &pool.pool,
+        &def_svc,
+        &entity_type,
+        entity_uuid,
+        "Bob",
+        user_uuid,
+    )
+    .await;
+
+    let hook = Arc::new(RecordingHook::default());
+    let entity_service = DynamicEntityService::new(
+        Arc::new(DynamicEntityRepository::new(pool.pool.clone())),
+        Arc::new(def_svc),
+    )
+    .with_change_hook(hook.clone());
+    VersionService::new(pool.pool.clone())
+        .with_entity_service(Arc::new(entity_service))
+        .restore(entity_uuid, 1, user_uuid)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *hook.updates.lock().unwrap(),
+        vec![("Bob".to_string(), "Alice".to_string())]
+    );
+}
@@ -59,6 +59,7 @@ async fn run_now_creates_queued_run_and_worker_marks_success() {
             ]
         }),
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = service
         .create(&req, creator_uuid)
@@ -1,14 +1,15 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use r_data_core_services::workflow::transform_execution::{
-    execute_async_transform, JwtConfig, MailContext,
+    execute_async_transform, resolve_string_operands, JwtConfig, MailContext,
 };
 use r_data_core_test_support::setup_test_db;
-use r_data_core_workflow::dsl::{DslProgram, Transform};
+use r_data_core_workflow::dsl::{Clock, DslProgram, StringOperand, Transform};
 use serde_json::json;
 use std::collections::HashMap;
 use std::fs::read_to_string;
 use std::sync::Arc;
+use time::macros::datetime;
 use uuid::Uuid;
 
 use r_data_core_persistence::{DynamicEntityRepository, EntityDefinitionRepository};
@@ -63,8 +64,13 @@ async fn create_test_entity_definition(
                 validation: r_data_core_core::field::FieldValidation::default(),
                 ui_settings: r_data_core_core::field::ui::UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
         ],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: r_data_core_core::entity_definition::schema::Schema::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
@@ -165,6 +171,7 @@ async fn test_execute_async_transform_resolve_entity_path() -> r_data_core_core:
             expiration: 86400,
         },
         &mail,
+        &Clock::System,
     )
     .await?;
 
@@ -256,6 +263,7 @@ async fn test_execute_async_transform_resolve_entity_path_not_found(
             expiration: 86400,
         },
         &mail,
+        &Clock::System,
     )
     .await?;
 
@@ -322,6 +330,7 @@ async fn test_execute_async_transform_get_or_create_entity() -> r_data_core_core
             expiration: 86400,
         },
         &mail,
+        &Clock::System,
     )
     .await?;
 
@@ -352,6 +361,7 @@ async fn test_execute_async_transform_get_or_create_entity() -> r_data_core_core
             expiration: 86400,
         },
         &mail,
+        &Clock::System,
     )
     .await?;
 
@@ -427,7 +437,9 @@ async fn test_step_by_step_execution_resolve_then_build_path() -> r_data_core_co
     // ========== Step-by-step execution (simulating item_processing flow) ==========
 
     // Step 0: Prepare
-    let (mut normalized_0, transform_0) = program.prepare_step(0, &payload, None)?;
+    let (mut normalized_0, transform_0) = program
+        .prepare_step(0, &payload, None)?
+        .expect("step 0 has no condition");
 
     // Step 0: Execute async transform (ResolveEntityPath)
     assert!(matches!(transform_0, Transform::ResolveEntityPath(_)));
@@ -445,6 +457,7 @@ async fn test_step_by_step_execution_resolve_then_build_path() -> r_data_core_co
             expiration: 86400,
         },
         &mail,
+        &Clock::System,
     )
     .await?;
 
@@ -468,8 +481,9 @@ async fn test_step_by_step_execution_resolve_then_build_path() -> r_data_core_co
     );
 
     // Step 1: Prepare (reads from previous step output)
-    let (mut normalized_1, transform_1) =
-        program.prepare_step(1, &payload, Some(&next_step_input))?;
+    let (mut normalized_1, transform_1) = program
+        .prepare_step(1, &payload, Some(&next_step_input))?
+        .expect("step 1 has no condition");
 
     // Verify instance_path is available in normalized data
     assert_eq!(
@@ -554,6 +568,7 @@ async fn test_send_email_transform_no_mail_configured() -> r_data_core_core::err
             expiration: 86400,
         },
         &mail,
+        &Clock::System,
     )
     .await?;
 
@@ -584,13 +599,16 @@ async fn test_step_by_step_execution_build_path_fails_without_async_result(
     });
 
     // Step 0
-    let (normalized_0, _transform_0) = program.prepare_step(0, &payload, None)?;
+    let (normalized_0, _transform_0) = program
+        .prepare_step(0, &payload, None)?
+        .expect("step 0 has no condition");
     let (_to_def_0, produced_0) = program.finalize_step(0, &normalized_0)?;
     let next_step_input = program.get_next_step_input(0, &normalized_0, &produced_0)?;
 
     // Step 1: Prepare
-    let (mut normalized_1, transform_1) =
-        program.prepare_step(1, &payload, Some(&next_step_input))?;
+    let (mut normalized_1, transform_1) = program
+        .prepare_step(1, &payload, Some(&next_step_input))?
+        .expect("step 1 has no condition");
 
     // Step 1: BuildPath should fail because instance_path is missing
     let result = DslProgram::apply_build_path(1, transform_1, &mut normalized_1);
@@ -603,3 +621,20 @@ async fn test_step_by_step_execution_build_path_fails_without_async_result(
 
     Ok(())
 }
+
+#[test]
+fn test_resolve_string_operands_uses_injected_clock() {
+    let clock = Clock::Fixed(datetime!(2024-03-05 14:30:15 UTC));
+    let operands = vec![
+        StringOperand::ConstString {
+            value: "ops@example.com".to_string(),
+        },
+        StringOperand::Today {
+            format: Some("[year][month][day]@example.com".to_string()),
+        },
+    ];
+
+    let resolved = resolve_string_operands(&operands, &json!({}), &clock);
+
+    assert_eq!(resolved, vec!["ops@example.com", "20240305@example.com"]);
+}
@@ -39,6 +39,10 @@ fn test_normalize_field_data_by_type() {
         allow_children: false,
         icon: None,
         fields,
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: r_data_core_core::entity_definition::schema::Schema::default(),
         created_at: time::OffsetDateTime::now_utc(),
         updated_at: time::OffsetDateTime::now_utc(),
@@ -1,6 +1,9 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
-use r_data_core_core::utils::{preview_next, validate_cron};
+use r_data_core_core::utils::{
+    preview_next, preview_runs_after, validate_cron, MAX_CRON_PREVIEW_COUNT,
+};
+use time::macros::datetime;
 
 #[test]
 fn test_validate_cron_success() {
@@ -21,3 +24,40 @@ fn test_preview_next_returns_items() {
     // Ensure ISO strings
     assert!(items[0].contains('T'));
 }
+
+#[test]
+fn test_preview_runs_after_daily_midnight_sequence() {
+    let start = datetime!(2025-03-10 12:34:56 UTC);
+    let runs = preview_runs_after("0 0 0 * * *", start, 5).unwrap();
+    assert_eq!(
+        runs,
+        vec![
+            datetime!(2025-03-11 00:00:00 UTC),
+            datetime!(2025-03-12 00:00:00 UTC),
+            datetime!(2025-03-13 00:00:00 UTC),
+            datetime!(2025-03-14 00:00:00 UTC),
+            datetime!(2025-03-15 00:00:00 UTC),
+        ]
+    );
+}
+
+#[test]
+fn test_preview_runs_after_caps_count() {
+    let start = datetime!(2025-01-01 00:00:00 UTC);
+    let runs = preview_runs_after("0 0 0 * * *", start, 1000).unwrap();
+    assert_eq!(runs.len(), MAX_CRON_PREVIEW_COUNT);
+}
+
+#[test]
+fn test_preview_runs_after_exhausted_schedule_is_empty() {
+    // Year field pinned in the past: the schedule never fires again
+    let start = datetime!(2025-01-01 00:00:00 UTC);
+    let runs = preview_runs_after("0 0 0 1 1 * 2020", start, 5).unwrap();
+    assert!(runs.is_empty());
+}
+
+#[test]
+fn test_preview_runs_after_invalid_expression() {
+    let start = datetime!(2025-01-01 00:00:00 UTC);
+    assert!(preview_runs_after("not-a-cron", start, 5).is_err());
+}
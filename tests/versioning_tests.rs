@@ -101,6 +101,7 @@ async fn test_workflow_update_creates_snapshot_and_increments_version() {
         schedule_cron: None,
         config: serde_json::json!({"steps": []}),
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&req, created_by).await.unwrap();
 
@@ -120,6 +121,7 @@ async fn test_workflow_update_creates_snapshot_and_increments_version() {
         schedule_cron: None,
         config: serde_json::json!({"steps": []}),
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let updated_by = create_test_admin_user(&pool).await.unwrap();
     repo.update(wf_uuid, &upd, updated_by).await.unwrap();
@@ -160,6 +162,11 @@ async fn test_entity_definition_update_creates_snapshot_and_increments_version()
         description: Some("d".to_string()),
         published: true,
         created_by: Uuid::now_v7(),
+        fields: vec![r_data_core_core::field::FieldDefinition::new(
+            "name".to_string(),
+            "Name".to_string(),
+            r_data_core_core::field::FieldType::String,
+        )],
         ..Default::default()
     };
     let def_uuid = def_service.create_entity_definition(&def).await.unwrap();
@@ -1,6 +1,6 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_persistence::WorkflowRepository;
 use r_data_core_services::WorkflowRepositoryAdapter;
 use r_data_core_services::WorkflowService;
@@ -102,6 +102,7 @@ async fn consumer_loop_processes_job_with_trigger_id() {
                     ]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -127,6 +128,10 @@ async fn consumer_loop_processes_job_with_trigger_id() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(r_data_core_core::cache::CacheManager::new(cache_config));
 
@@ -203,6 +208,7 @@ async fn consumer_loop_handles_job_without_trigger_id() {
                     ]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -224,6 +230,10 @@ async fn consumer_loop_handles_job_without_trigger_id() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(r_data_core_core::cache::CacheManager::new(cache_config));
 
@@ -304,6 +314,7 @@ async fn consumer_loop_processes_multiple_jobs_sequentially() {
                     ]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -333,6 +344,10 @@ async fn consumer_loop_processes_multiple_jobs_sequentially() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(r_data_core_core::cache::CacheManager::new(cache_config));
 
@@ -413,6 +428,7 @@ async fn consumer_loop_continues_after_error() {
                     ]
                 }),
                 versioning_disabled: false,
+                completion_webhook_url: None,
             },
             creator_uuid,
         )
@@ -448,6 +464,10 @@ async fn consumer_loop_continues_after_error() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(r_data_core_core::cache::CacheManager::new(cache_config));
 
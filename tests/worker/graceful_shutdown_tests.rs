@@ -0,0 +1,95 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use std::time::Duration;
+
+use r_data_core_persistence::WorkflowRepository;
+use r_data_core_test_support::{create_test_admin_user, create_test_queue, setup_test_db};
+use r_data_core_worker::runtime::shutdown::{requeue_unfinished_runs, RunTracker};
+use uuid::Uuid;
+
+use crate::api::workflows::common::create_consumer_workflow;
+
+#[tokio::test]
+async fn drain_waits_for_runs_finishing_within_timeout() {
+    let tracker = RunTracker::new();
+    let guard = tracker.track(Uuid::now_v7());
+
+    tracker.request_shutdown();
+    assert!(tracker.is_shutting_down());
+    // Loops waiting for new jobs wake up once shutdown is requested
+    tokio::time::timeout(Duration::from_secs(1), tracker.shutdown_requested())
+        .await
+        .expect("shutdown signal observed");
+
+    let finish = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        drop(guard);
+    });
+    let unfinished = tracker.drain(Duration::from_secs(5)).await;
+    assert!(unfinished.is_empty());
+    finish.await.expect("run task");
+}
+
+#[tokio::test]
+async fn drain_reports_runs_outlasting_timeout() {
+    let tracker = RunTracker::new();
+    let run_uuid = Uuid::now_v7();
+    let _guard = tracker.track(run_uuid);
+
+    tracker.request_shutdown();
+    let unfinished = tracker.drain(Duration::from_millis(50)).await;
+    assert_eq!(unfinished, vec![run_uuid]);
+}
+
+#[tokio::test]
+async fn shutdown_mid_run_requeues_the_run() {
+    let Some((queue, _fetch_key, _process_key)) = create_test_queue().await else {
+        println!("Skipping test: REDIS_URL not set");
+        return;
+    };
+
+    let pool = setup_test_db().await;
+    let creator_uuid = create_test_admin_user(&pool)
+        .await
+        .expect("create admin user");
+    let wf_uuid = create_consumer_workflow(
+        &pool.pool,
+        creator_uuid,
+        serde_json::json!({ "steps": [] }),
+        true,
+        None,
+    )
+    .await
+    .expect("create workflow");
+
+    let repo = WorkflowRepository::new(pool.pool.clone());
+    let run_uuid = repo
+        .insert_run_queued(wf_uuid, Uuid::now_v7())
+        .await
+        .expect("queue run");
+
+    // A consumer picked up the run and is still executing it when the signal arrives
+    let tracker = RunTracker::new();
+    let _guard = tracker.track(run_uuid);
+    repo.mark_run_running(run_uuid).await.expect("mark running");
+
+    tracker.request_shutdown();
+    let unfinished = tracker.drain(Duration::from_millis(100)).await;
+    assert_eq!(unfinished, vec![run_uuid]);
+    requeue_unfinished_runs(&repo, &queue, &unfinished).await;
+
+    let status = repo.get_run_status(run_uuid).await.expect("run status");
+    assert_eq!(
+        status.as_deref(),
+        Some("queued"),
+        "run must not stay running"
+    );
+
+    // The next worker picks the run up again
+    let job = tokio::time::timeout(Duration::from_secs(5), queue.blocking_pop_fetch())
+        .await
+        .expect("job enqueued")
+        .expect("pop job");
+    assert_eq!(job.workflow_id, wf_uuid);
+    assert_eq!(job.trigger_id, Some(run_uuid));
+}
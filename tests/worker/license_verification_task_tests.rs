@@ -1,7 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_core::maintenance::MaintenanceTask;
 use r_data_core_test_support::setup_test_db;
 use r_data_core_worker::context::TaskContext;
@@ -39,6 +39,10 @@ async fn test_license_verification_task_none_state() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -70,6 +74,10 @@ async fn test_license_verification_task_prevents_multiple_runs() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -1,8 +1,10 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 mod consumer_loop_tests;
+mod graceful_shutdown_tests;
 mod license_verification_task_tests;
 mod refresh_token_cleanup_task_tests;
 mod statistics_collection_task_tests;
 mod version_purger_task_tests;
+mod version_retention_tests;
 mod workflow_run_logs_purger_task_tests;
@@ -2,7 +2,7 @@
 
 use httpmock::MockServer;
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::{CacheConfig, LicenseConfig};
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy, LicenseConfig};
 use r_data_core_core::maintenance::MaintenanceTask;
 use r_data_core_test_support::setup_test_db;
 use r_data_core_worker::context::TaskContext;
@@ -53,6 +53,10 @@ async fn test_statistics_collection_task_executes() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -90,6 +94,10 @@ async fn test_statistics_collection_task_prevents_multiple_runs() {
         max_size: 10000,
         entity_definition_ttl: 0,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
     };
     let cache_manager = Arc::new(CacheManager::new(cache_config));
 
@@ -1,7 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_core::error::Result;
 use r_data_core_core::maintenance::MaintenanceTask;
 use r_data_core_core::settings::EntityVersioningSettings;
@@ -35,6 +35,10 @@ async fn test_version_purger_task_skips_when_disabled() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 3600,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -74,6 +78,10 @@ async fn test_version_purger_task_prunes_by_age() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 3600,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -178,6 +186,10 @@ async fn test_version_purger_task_prunes_by_count() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 3600,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -276,6 +288,10 @@ async fn test_version_purger_task_prunes_by_both_age_and_count() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 3600,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
@@ -390,6 +406,10 @@ async fn test_version_purger_task_with_no_versions() -> Result<()> {
     let cache_config = CacheConfig {
         entity_definition_ttl: 3600,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
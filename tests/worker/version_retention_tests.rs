@@ -0,0 +1,106 @@
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
+
+use r_data_core_core::error::Result;
+use r_data_core_core::maintenance::apply_retention;
+use r_data_core_core::settings::EntityVersioningSettings;
+use r_data_core_core::versioning::purger_trait::VersionPurger;
+use r_data_core_persistence::VersionRepository;
+use r_data_core_test_support::{create_test_admin_user, setup_test_db};
+use serial_test::serial;
+use uuid::Uuid;
+
+/// Register an entity at `current_version` and insert snapshots for `versions`, all `age_days` old
+async fn seed_entity_versions(
+    pool: &sqlx::PgPool,
+    user_uuid: Uuid,
+    current_version: i32,
+    versions: std::ops::RangeInclusive<i32>,
+    age_days: i32,
+) -> Result<Uuid> {
+    let entity_uuid = Uuid::now_v7();
+    sqlx::query(
+        "INSERT INTO entities_registry (uuid, entity_type, path, entity_key, version, created_at, updated_at, created_by, published)
+         VALUES ($1, 'dynamic_entity', '/', $1::text, $2, NOW(), NOW(), $3, true)",
+    )
+    .bind(entity_uuid)
+    .bind(current_version)
+    .bind(user_uuid)
+    .execute(pool)
+    .await?;
+
+    for v in versions {
+        sqlx::query(
+            "INSERT INTO entities_versions (entity_uuid, entity_type, version_number, data, created_at)
+             VALUES ($1, 'dynamic_entity', $2, $3, NOW() - ($4::text || ' days')::interval)",
+        )
+        .bind(entity_uuid)
+        .bind(v)
+        .bind(serde_json::json!({ "v": v }))
+        .bind(age_days.to_string())
+        .execute(pool)
+        .await?;
+    }
+    Ok(entity_uuid)
+}
+
+async fn remaining_versions(pool: &sqlx::PgPool, entity_uuid: Uuid) -> Result<Vec<i32>> {
+    Ok(sqlx::query_scalar(
+        "SELECT version_number FROM entities_versions WHERE entity_uuid = $1 ORDER BY version_number",
+    )
+    .bind(entity_uuid)
+    .fetch_all(pool)
+    .await?)
+}
+
+fn entity_purger(pool: &sqlx::PgPool) -> Vec<Box<dyn VersionPurger>> {
+    vec![Box::new(VersionRepository::new(pool.clone()))]
+}
+
+#[tokio::test]
+#[serial]
+async fn test_retention_keeps_configured_number_of_versions() -> Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    // 20 pre-update snapshots, entity is now at version 21
+    let entity_uuid = seed_entity_versions(&pool.pool, user_uuid, 21, 1..=20, 0).await?;
+
+    let settings = EntityVersioningSettings {
+        enabled: true,
+        max_versions: Some(5),
+        max_age_days: None,
+    };
+    let report = apply_retention(&entity_purger(&pool.pool), &settings).await?;
+
+    assert!(report.pruned_by_count >= 15);
+    assert_eq!(
+        remaining_versions(&pool.pool, entity_uuid).await?,
+        (16..=20).collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn test_retention_never_prunes_current_version() -> Result<()> {
+    let pool = setup_test_db().await;
+    let user_uuid = create_test_admin_user(&pool).await?;
+    // A snapshot of the current version exists (e.g. recorded by a restore)
+    // and is older than every retention limit
+    let entity_uuid = seed_entity_versions(&pool.pool, user_uuid, 3, 1..=20, 400).await?;
+
+    let settings = EntityVersioningSettings {
+        enabled: true,
+        max_versions: Some(2),
+        max_age_days: Some(30),
+    };
+    apply_retention(&entity_purger(&pool.pool), &settings).await?;
+
+    assert_eq!(remaining_versions(&pool.pool, entity_uuid).await?, vec![3]);
+
+    let current: i32 = sqlx::query_scalar("SELECT version FROM entities_registry WHERE uuid = $1")
+        .bind(entity_uuid)
+        .fetch_one(&pool.pool)
+        .await?;
+    assert_eq!(current, 3);
+    Ok(())
+}
@@ -1,7 +1,7 @@
 #![deny(clippy::all, clippy::pedantic, clippy::nursery, warnings)]
 
 use r_data_core_core::cache::CacheManager;
-use r_data_core_core::config::CacheConfig;
+use r_data_core_core::config::{CacheConfig, CacheEvictionPolicy};
 use r_data_core_core::error::Result;
 use r_data_core_core::maintenance::MaintenanceTask;
 use r_data_core_core::settings::WorkflowRunLogSettings;
@@ -17,6 +17,10 @@ fn make_cache() -> Arc<CacheManager> {
     Arc::new(CacheManager::new(CacheConfig {
         entity_definition_ttl: 3600,
         api_key_ttl: 600,
+        entity_miss_ttl: 0,
+        warm_on_startup: false,
+        eviction_policy: CacheEvictionPolicy::Lru,
+        broadcast_invalidations: false,
         enabled: true,
         ttl: 3600,
         max_size: 10000,
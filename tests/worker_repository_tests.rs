@@ -54,6 +54,7 @@ async fn get_workflow_uuid_for_run_round_trip() -> anyhow::Result<()> {
             ]
         }),
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&req, creator_uuid).await?;
 
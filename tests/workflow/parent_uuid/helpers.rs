@@ -42,7 +42,12 @@ pub fn create_instance_definition(entity_type: &str) -> EntityDefinition {
             validation: FieldValidation::default(),
             ui_settings: UiSettings::default(),
             constraints: HashMap::new(),
+            display_order: 0,
         }],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: Schema::new(schema_properties),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
@@ -84,6 +89,7 @@ pub fn create_submission_definition(entity_type: &str) -> EntityDefinition {
                 validation: FieldValidation::default(),
                 ui_settings: UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
             FieldDefinition {
                 name: "license_key_id".to_string(),
@@ -98,8 +104,13 @@ pub fn create_submission_definition(entity_type: &str) -> EntityDefinition {
                 validation: FieldValidation::default(),
                 ui_settings: UiSettings::default(),
                 constraints: HashMap::new(),
+                display_order: 0,
             },
         ],
+        extends: Vec::new(),
+        unique_constraints: Vec::new(),
+        validation_rules: Vec::new(),
+        table_name: None,
         schema: Schema::new(schema_properties),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
@@ -76,6 +76,7 @@ async fn workflow_creates_entity_with_run_uuid_as_created_by() -> anyhow::Result
         schedule_cron: None,
         config: cfg,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
 
     let wf_uuid = wf_service
@@ -277,6 +278,7 @@ async fn workflow_updates_entity_with_run_uuid_as_updated_by() -> anyhow::Result
         schedule_cron: None,
         config: cfg,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
 
     let wf_uuid = wf_service
@@ -59,6 +59,7 @@ async fn create_sets_created_by_and_fk_enforced() -> anyhow::Result<()> {
         schedule_cron: None,
         config: cfg,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
 
     // Create via repository (adapter only used to match service wiring)
@@ -134,6 +135,7 @@ async fn update_sets_updated_by_and_fk_enforced() -> anyhow::Result<()> {
         schedule_cron: None,
         config: cfg.clone(),
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     let wf_uuid = repo.create(&create_req, creator_uuid).await?;
 
@@ -146,6 +148,7 @@ async fn update_sets_updated_by_and_fk_enforced() -> anyhow::Result<()> {
         schedule_cron: Some("*/5 * * * *".to_string()),
         config: cfg,
         versioning_disabled: false,
+        completion_webhook_url: None,
     };
     repo.update(wf_uuid, &update_req, updater_uuid).await?;
 